@@ -5,7 +5,9 @@ use meilisearch_types::error::ResponseError;
 use meilisearch_types::keys::Key;
 use meilisearch_types::milli::update::IndexDocumentsMethod;
 use meilisearch_types::settings::Unchecked;
-use meilisearch_types::tasks::{Details, IndexSwap, KindWithContent, Status, Task, TaskId};
+use meilisearch_types::tasks::{
+    Details, DocumentTransactionOperation, IndexSwap, KindWithContent, Status, Task, TaskId,
+};
 use meilisearch_types::InstanceUid;
 use roaring::RoaringBitmap;
 use serde::{Deserialize, Serialize};
@@ -30,6 +32,13 @@ pub struct Metadata {
     pub db_version: String,
     #[serde(with = "time::serde::rfc3339")]
     pub dump_date: OffsetDateTime,
+    /// A settings-only dump contains the settings of the indexes but none of their documents.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub settings_only: bool,
+    /// An incremental dump only contains the tasks enqueued after this task and the indexes
+    /// they modified.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub since: Option<TaskId>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -97,8 +106,20 @@ pub enum KindDump {
         documents_count: u64,
         allow_index_creation: bool,
     },
+    DocumentTransaction {
+        primary_key: Option<String>,
+        operations: Vec<DocumentTransactionOperation>,
+        allow_index_creation: bool,
+    },
+    DocumentUrlImport {
+        url: String,
+        primary_key: Option<String>,
+        allow_index_creation: bool,
+    },
     DocumentDeletion {
         documents_ids: Vec<String>,
+        #[serde(default)]
+        report_outcomes: bool,
     },
     DocumentClear,
     DocumentDeletionByFilter {
@@ -135,6 +156,15 @@ pub enum KindDump {
     DumpCreation {
         keys: Vec<Key>,
         instance_uid: Option<InstanceUid>,
+        #[serde(default)]
+        settings_only: bool,
+        #[serde(default)]
+        since: Option<TaskId>,
+    },
+    DumpImport {
+        dump_uid: String,
+        namespace: Option<String>,
+        index_uids: Vec<String>,
     },
     SnapshotCreation,
 }
@@ -171,8 +201,17 @@ impl From<KindWithContent> for KindDump {
                 documents_count,
                 allow_index_creation,
             },
-            KindWithContent::DocumentDeletion { documents_ids, .. } => {
-                KindDump::DocumentDeletion { documents_ids }
+            KindWithContent::DocumentTransaction {
+                primary_key,
+                operations,
+                allow_index_creation,
+                ..
+            } => KindDump::DocumentTransaction { primary_key, operations, allow_index_creation },
+            KindWithContent::DocumentUrlImport {
+                url, primary_key, allow_index_creation, ..
+            } => KindDump::DocumentUrlImport { url, primary_key, allow_index_creation },
+            KindWithContent::DocumentDeletion { documents_ids, report_outcomes, .. } => {
+                KindDump::DocumentDeletion { documents_ids, report_outcomes }
             }
             KindWithContent::DocumentDeletionByFilter { filter_expr, .. } => {
                 KindDump::DocumentDeletionByFilter { filter: filter_expr }
@@ -201,8 +240,11 @@ impl From<KindWithContent> for KindDump {
             KindWithContent::TaskDeletion { query, tasks } => {
                 KindDump::TasksDeletion { query, tasks }
             }
-            KindWithContent::DumpCreation { keys, instance_uid } => {
-                KindDump::DumpCreation { keys, instance_uid }
+            KindWithContent::DumpCreation { keys, instance_uid, settings_only, since } => {
+                KindDump::DumpCreation { keys, instance_uid, settings_only, since }
+            }
+            KindWithContent::DumpImport { dump_uid, namespace, index_uids } => {
+                KindDump::DumpImport { dump_uid, namespace, index_uids }
             }
             KindWithContent::SnapshotCreation => KindDump::SnapshotCreation,
         }
@@ -282,11 +324,42 @@ pub(crate) mod test {
                 sort_facet_values_by: Setting::Set(
                     btreemap! { S("age") => FacetValuesSort::Count },
                 ),
+                normalization: Setting::NotSet,
             }),
             pagination: Setting::NotSet,
             embedders: Setting::NotSet,
             search_cutoff_ms: Setting::NotSet,
             localized_attributes: Setting::NotSet,
+            ignored_attributes: Setting::NotSet,
+            split_and_concat_words: Setting::NotSet,
+            recency_attribute: Setting::NotSet,
+            max_facet_values_per_attribute: Setting::NotSet,
+            content_types: Setting::NotSet,
+            read_only: Setting::NotSet,
+            attribute_transforms: Setting::NotSet,
+            decimal_attributes: Setting::NotSet,
+            attributes_prefix_search: Setting::NotSet,
+            max_documents: Setting::NotSet,
+            max_prefix_typos: Setting::NotSet,
+            min_prefix_length: Setting::NotSet,
+            stemming: Setting::NotSet,
+            max_flatten_depth: Setting::NotSet,
+            default_language: Setting::NotSet,
+            language_hint_field: Setting::NotSet,
+            duplicate_key_strategy: Setting::NotSet,
+            tokenizer_profiles: Setting::NotSet,
+            pinned_results: Setting::NotSet,
+            reserve_document_ids: Setting::NotSet,
+            user_metadata: Setting::NotSet,
+            store_max_length: Setting::NotSet,
+            normalized_facet_attributes: Setting::NotSet,
+            blocked_documents: Setting::NotSet,
+            number_tokenization: Setting::NotSet,
+            enable_formatting: Setting::NotSet,
+            recency_half_life_days: Setting::NotSet,
+            reranker: Setting::NotSet,
+            document_compression: Setting::NotSet,
+            max_synonym_expansions: Setting::NotSet,
             _kind: std::marker::PhantomData,
         };
         settings.check()
@@ -309,6 +382,9 @@ pub(crate) mod test {
                     details: Some(Details::DocumentAdditionOrUpdate {
                         received_documents: 12,
                         indexed_documents: Some(10),
+                        etag_conflicts: None,
+                        capped_facet_attributes: None,
+                        skipped_documents: None,
                     }),
                     error: None,
                     enqueued_at: datetime!(2022-11-11 0:00 UTC),
@@ -332,6 +408,9 @@ pub(crate) mod test {
                     details: Some(Details::DocumentAdditionOrUpdate {
                         received_documents: 2,
                         indexed_documents: None,
+                        etag_conflicts: None,
+                        capped_facet_attributes: None,
+                        skipped_documents: None,
                     }),
                     error: None,
                     enqueued_at: datetime!(2022-11-11 0:00 UTC),
@@ -370,6 +449,7 @@ pub(crate) mod test {
                 actions: vec![Action::DocumentsAll],
                 indexes: vec![IndexUidPattern::from_str("doggos").unwrap()],
                 expires_at: Some(datetime!(4130-03-14 12:21 UTC)),
+                rate_limit: None,
                 created_at: datetime!(1960-11-15 0:00 UTC),
                 updated_at: datetime!(2022-11-10 0:00 UTC),
             },
@@ -380,6 +460,7 @@ pub(crate) mod test {
                 actions: vec![Action::All],
                 indexes: vec![IndexUidPattern::all()],
                 expires_at: None,
+                rate_limit: None,
                 created_at: datetime!(0000-01-01 00:01 UTC),
                 updated_at: datetime!(1964-05-04 17:25 UTC),
             },
@@ -390,6 +471,7 @@ pub(crate) mod test {
                 actions: vec![],
                 indexes: vec![],
                 expires_at: None,
+                rate_limit: None,
                 created_at: datetime!(400-02-29 0:00 UTC),
                 updated_at: datetime!(1024-02-29 0:00 UTC),
             },
@@ -500,4 +582,65 @@ pub(crate) mod test {
         let expected = create_test_features();
         assert_eq!(dump.features().unwrap().unwrap(), expected);
     }
+
+    #[test]
+    fn test_creating_and_read_settings_only_dump() {
+        let dump = DumpWriter::new_settings_only(Some(create_test_instance_uid())).unwrap();
+
+        let mut index = dump.create_index("doggos", &create_test_index_metadata()).unwrap();
+        for document in &create_test_documents() {
+            index.push_document(document).unwrap();
+        }
+        index.flush().unwrap();
+        index.settings(&create_test_settings()).unwrap();
+        dump.create_tasks_queue().unwrap().flush().unwrap();
+        dump.create_keys().unwrap().flush().unwrap();
+
+        let mut file = tempfile::tempfile().unwrap();
+        dump.persist_to(&mut file).unwrap();
+        file.rewind().unwrap();
+
+        let dump = DumpReader::open(&mut file).unwrap();
+        assert!(dump.settings_only());
+
+        let mut indexes = dump.indexes().unwrap();
+        let mut index = indexes.next().unwrap().unwrap();
+        assert!(indexes.next().is_none());
+
+        assert_eq!(index.documents().unwrap().count(), 0);
+        assert_eq!(index.settings().unwrap(), create_test_settings());
+        assert_eq!(index.metadata(), &create_test_index_metadata());
+    }
+
+    #[test]
+    fn test_creating_and_read_incremental_dump() {
+        let dump = DumpWriter::new_incremental(Some(create_test_instance_uid()), 12).unwrap();
+
+        let mut index = dump.create_index("doggos", &create_test_index_metadata()).unwrap();
+        for document in &create_test_documents() {
+            index.push_document(document).unwrap();
+        }
+        index.flush().unwrap();
+        index.settings(&create_test_settings()).unwrap();
+        dump.create_tasks_queue().unwrap().flush().unwrap();
+        dump.create_keys().unwrap().flush().unwrap();
+        dump.create_deleted_indexes(&["catto".to_string()]).unwrap();
+
+        let mut file = tempfile::tempfile().unwrap();
+        dump.persist_to(&mut file).unwrap();
+        file.rewind().unwrap();
+
+        let dump = DumpReader::open(&mut file).unwrap();
+        assert_eq!(dump.since(), Some(12));
+        assert!(!dump.settings_only());
+        assert_eq!(dump.deleted_indexes(), ["catto"]);
+
+        let mut indexes = dump.indexes().unwrap();
+        let mut index = indexes.next().unwrap().unwrap();
+        assert!(indexes.next().is_none());
+
+        assert_eq!(index.documents().unwrap().count(), create_test_documents().len());
+        assert_eq!(index.settings().unwrap(), create_test_settings());
+        assert_eq!(index.metadata(), &create_test_index_metadata());
+    }
 }
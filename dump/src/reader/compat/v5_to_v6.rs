@@ -108,7 +108,7 @@ impl CompatV5ToV6 {
                         {
                             v5::tasks::DocumentDeletion::Clear => v6::Kind::DocumentClear,
                             v5::tasks::DocumentDeletion::Ids(documents_ids) => {
-                                v6::Kind::DocumentDeletion { documents_ids }
+                                v6::Kind::DocumentDeletion { documents_ids, report_outcomes: false }
                             }
                         },
                         v5::tasks::TaskContent::SettingsUpdate {
@@ -123,7 +123,12 @@ impl CompatV5ToV6 {
                         },
                         v5::tasks::TaskContent::Dump { uid: _ } => {
                             // in v6 we compute the dump_uid from the started_at processing time
-                            v6::Kind::DumpCreation { keys: keys.clone(), instance_uid }
+                            v6::Kind::DumpCreation {
+                                keys: keys.clone(),
+                                instance_uid,
+                                settings_only: false,
+                                since: None,
+                            }
                         }
                     },
                     canceled_by: None,
@@ -132,6 +137,9 @@ impl CompatV5ToV6 {
                             v6::Details::DocumentAdditionOrUpdate {
                                 received_documents: received_documents as u64,
                                 indexed_documents,
+                                etag_conflicts: None,
+                                capped_facet_attributes: None,
+                                skipped_documents: None,
                             }
                         }
                         v5::Details::Settings { settings } => {
@@ -146,6 +154,7 @@ impl CompatV5ToV6 {
                         } => v6::Details::DocumentDeletion {
                             provided_ids: received_document_ids,
                             deleted_documents,
+                            outcomes: None,
                         },
                         v5::Details::ClearAll { deleted_documents } => {
                             v6::Details::ClearAll { deleted_documents }
@@ -186,6 +195,7 @@ impl CompatV5ToV6 {
                     })
                     .collect(),
                 expires_at: key.expires_at,
+                rate_limit: None,
                 created_at: key.created_at,
                 updated_at: key.updated_at,
             })
@@ -359,6 +369,7 @@ impl<T> From<v5::Settings<T>> for v6::Settings<v6::Unchecked> {
                     },
                     disable_on_words: typo.disable_on_words.into(),
                     disable_on_attributes: typo.disable_on_attributes.into(),
+                    typo_penalty: v6::Setting::NotSet,
                 }),
                 v5::Setting::Reset => v6::Setting::Reset,
                 v5::Setting::NotSet => v6::Setting::NotSet,
@@ -367,6 +378,7 @@ impl<T> From<v5::Settings<T>> for v6::Settings<v6::Unchecked> {
                 v5::Setting::Set(faceting) => v6::Setting::Set(v6::FacetingSettings {
                     max_values_per_facet: faceting.max_values_per_facet.into(),
                     sort_facet_values_by: v6::Setting::NotSet,
+                    normalization: v6::Setting::NotSet,
                 }),
                 v5::Setting::Reset => v6::Setting::Reset,
                 v5::Setting::NotSet => v6::Setting::NotSet,
@@ -380,6 +392,36 @@ impl<T> From<v5::Settings<T>> for v6::Settings<v6::Unchecked> {
             },
             embedders: v6::Setting::NotSet,
             localized_attributes: v6::Setting::NotSet,
+            ignored_attributes: v6::Setting::NotSet,
+            split_and_concat_words: v6::Setting::NotSet,
+            recency_attribute: v6::Setting::NotSet,
+            max_facet_values_per_attribute: v6::Setting::NotSet,
+            content_types: v6::Setting::NotSet,
+            read_only: v6::Setting::NotSet,
+            attribute_transforms: v6::Setting::NotSet,
+            decimal_attributes: v6::Setting::NotSet,
+            attributes_prefix_search: v6::Setting::NotSet,
+            max_documents: v6::Setting::NotSet,
+            max_prefix_typos: v6::Setting::NotSet,
+            min_prefix_length: v6::Setting::NotSet,
+            stemming: v6::Setting::NotSet,
+            max_flatten_depth: v6::Setting::NotSet,
+            default_language: v6::Setting::NotSet,
+            language_hint_field: v6::Setting::NotSet,
+            duplicate_key_strategy: v6::Setting::NotSet,
+            tokenizer_profiles: v6::Setting::NotSet,
+            pinned_results: v6::Setting::NotSet,
+            reserve_document_ids: v6::Setting::NotSet,
+            user_metadata: v6::Setting::NotSet,
+            store_max_length: v6::Setting::NotSet,
+            normalized_facet_attributes: v6::Setting::NotSet,
+            blocked_documents: v6::Setting::NotSet,
+            number_tokenization: v6::Setting::NotSet,
+            enable_formatting: v6::Setting::NotSet,
+            recency_half_life_days: v6::Setting::NotSet,
+            reranker: v6::Setting::NotSet,
+            document_compression: v6::Setting::NotSet,
+            max_synonym_expansions: v6::Setting::NotSet,
             search_cutoff_ms: v6::Setting::NotSet,
             _kind: std::marker::PhantomData,
         }
@@ -70,6 +70,32 @@ impl DumpReader {
         }
     }
 
+    /// Whether the dump only contains the settings of the indexes and none of their documents.
+    /// Only dumps of the current version can be settings-only.
+    pub fn settings_only(&self) -> bool {
+        match self {
+            DumpReader::Current(current) => current.settings_only(),
+            DumpReader::Compat(_) => false,
+        }
+    }
+
+    /// The task after which the tasks of an incremental dump were enqueued, `None` for a full dump.
+    /// Only dumps of the current version can be incremental.
+    pub fn since(&self) -> Option<meilisearch_types::tasks::TaskId> {
+        match self {
+            DumpReader::Current(current) => current.since(),
+            DumpReader::Compat(_) => None,
+        }
+    }
+
+    /// The indexes an incremental dump deletes once imported, they were deleted since its checkpoint.
+    pub fn deleted_indexes(&self) -> &[String] {
+        match self {
+            DumpReader::Current(current) => current.deleted_indexes(),
+            DumpReader::Compat(_) => &[],
+        }
+    }
+
     pub fn instance_uid(&self) -> Result<Option<uuid::Uuid>> {
         match self {
             DumpReader::Current(current) => current.instance_uid(),
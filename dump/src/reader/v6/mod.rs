@@ -50,6 +50,7 @@ pub struct V6Reader {
     tasks: BufReader<File>,
     keys: BufReader<File>,
     features: Option<RuntimeTogglableFeatures>,
+    deleted_indexes: Vec<String>,
 }
 
 impl V6Reader {
@@ -78,12 +79,20 @@ impl V6Reader {
             None
         };
 
+        // Only the incremental dumps list the deleted indexes.
+        let deleted_indexes = match fs::read(dump.path().join("deleted-indexes.json")) {
+            Ok(deleted_indexes) => serde_json::from_reader(&*deleted_indexes)?,
+            Err(e) if e.kind() == ErrorKind::NotFound => Vec::new(),
+            Err(e) => return Err(e.into()),
+        };
+
         Ok(V6Reader {
             metadata: serde_json::from_reader(&*meta_file)?,
             instance_uid,
             tasks: BufReader::new(File::open(dump.path().join("tasks").join("queue.jsonl"))?),
             keys: BufReader::new(File::open(dump.path().join("keys.jsonl"))?),
             features,
+            deleted_indexes,
             dump,
         })
     }
@@ -96,6 +105,18 @@ impl V6Reader {
         Some(self.metadata.dump_date)
     }
 
+    pub fn settings_only(&self) -> bool {
+        self.metadata.settings_only
+    }
+
+    pub fn since(&self) -> Option<meilisearch_types::tasks::TaskId> {
+        self.metadata.since
+    }
+
+    pub fn deleted_indexes(&self) -> &[String] {
+        &self.deleted_indexes
+    }
+
     pub fn instance_uid(&self) -> Result<Option<Uuid>> {
         Ok(self.instance_uid)
     }
@@ -110,6 +131,7 @@ impl V6Reader {
                         let index = V6IndexReader::new(
                             entry.file_name().to_str().ok_or(Error::BadIndexName)?.to_string(),
                             &entry.path(),
+                            self.metadata.settings_only,
                         )?;
                         Ok(Some(index))
                     } else {
@@ -182,17 +204,23 @@ impl Iterator for UpdateFile {
 
 pub struct V6IndexReader {
     metadata: IndexMetadata,
-    documents: BufReader<File>,
+    /// `None` when reading a settings-only dump.
+    documents: Option<BufReader<File>>,
     settings: BufReader<File>,
 }
 
 impl V6IndexReader {
-    pub fn new(_name: String, path: &Path) -> Result<Self> {
+    pub fn new(_name: String, path: &Path, settings_only: bool) -> Result<Self> {
         let metadata = File::open(path.join("metadata.json"))?;
+        let documents = if settings_only {
+            None
+        } else {
+            Some(BufReader::new(File::open(path.join("documents.jsonl"))?))
+        };
 
         let ret = V6IndexReader {
             metadata: serde_json::from_reader(metadata)?,
-            documents: BufReader::new(File::open(path.join("documents.jsonl"))?),
+            documents,
             settings: BufReader::new(File::open(path.join("settings.json"))?),
         };
 
@@ -204,8 +232,10 @@ impl V6IndexReader {
     }
 
     pub fn documents(&mut self) -> Result<impl Iterator<Item = Result<Document>> + '_> {
-        Ok((&mut self.documents)
-            .lines()
+        Ok(self
+            .documents
+            .iter_mut()
+            .flat_map(|documents| documents.lines())
             .map(|line| -> Result<_> { Ok(serde_json::from_str(&line?)?) }))
     }
 
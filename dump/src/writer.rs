@@ -7,6 +7,7 @@ use flate2::Compression;
 use meilisearch_types::features::RuntimeTogglableFeatures;
 use meilisearch_types::keys::Key;
 use meilisearch_types::settings::{Checked, Settings};
+use meilisearch_types::tasks::TaskId;
 use serde_json::{Map, Value};
 use tempfile::TempDir;
 use time::OffsetDateTime;
@@ -17,10 +18,35 @@ use crate::{IndexMetadata, Metadata, Result, TaskDump, CURRENT_DUMP_VERSION};
 
 pub struct DumpWriter {
     dir: TempDir,
+    settings_only: bool,
 }
 
 impl DumpWriter {
     pub fn new(instance_uuid: Option<Uuid>) -> Result<DumpWriter> {
+        Self::create(instance_uuid, false, None)
+    }
+
+    /// Creates a dump that only contains the settings of the indexes, their documents
+    /// are never written.
+    pub fn new_settings_only(instance_uuid: Option<Uuid>) -> Result<DumpWriter> {
+        Self::create(instance_uuid, true, None)
+    }
+
+    /// Creates a dump that is meant to be applied on top of a database already containing the
+    /// tasks up to `since`, only the more recent tasks and the indexes they modified are written.
+    ///
+    /// The modified indexes are written in full, with all their documents, since the changes
+    /// made to a document aren't recorded. The deleted ones are listed with
+    /// [`Self::create_deleted_indexes`].
+    pub fn new_incremental(instance_uuid: Option<Uuid>, since: TaskId) -> Result<DumpWriter> {
+        Self::create(instance_uuid, false, Some(since))
+    }
+
+    fn create(
+        instance_uuid: Option<Uuid>,
+        settings_only: bool,
+        since: Option<TaskId>,
+    ) -> Result<DumpWriter> {
         let dir = TempDir::new()?;
 
         if let Some(instance_uuid) = instance_uuid {
@@ -34,16 +60,26 @@ impl DumpWriter {
             dump_version: CURRENT_DUMP_VERSION,
             db_version: env!("CARGO_PKG_VERSION").to_string(),
             dump_date: OffsetDateTime::now_utc(),
+            settings_only,
+            since,
         };
         fs::write(dir.path().join("metadata.json"), serde_json::to_string(&metadata)?)?;
 
         std::fs::create_dir(dir.path().join("indexes"))?;
 
-        Ok(DumpWriter { dir })
+        Ok(DumpWriter { dir, settings_only })
+    }
+
+    pub fn settings_only(&self) -> bool {
+        self.settings_only
     }
 
     pub fn create_index(&self, index_name: &str, metadata: &IndexMetadata) -> Result<IndexWriter> {
-        IndexWriter::new(self.dir.path().join("indexes").join(index_name), metadata)
+        IndexWriter::new(
+            self.dir.path().join("indexes").join(index_name),
+            metadata,
+            self.settings_only,
+        )
     }
 
     pub fn create_keys(&self) -> Result<KeyWriter> {
@@ -61,6 +97,15 @@ impl DumpWriter {
         )?)
     }
 
+    /// Lists the indexes deleted since the checkpoint of an incremental dump, they are deleted
+    /// when the dump is imported.
+    pub fn create_deleted_indexes(&self, index_uids: &[String]) -> Result<()> {
+        Ok(std::fs::write(
+            self.dir.path().join("deleted-indexes.json"),
+            serde_json::to_string(index_uids)?,
+        )?)
+    }
+
     pub fn persist_to(self, mut writer: impl Write) -> Result<()> {
         let gz_encoder = GzEncoder::new(&mut writer, Compression::default());
         let mut tar_encoder = tar::Builder::new(gz_encoder);
@@ -157,31 +202,41 @@ impl UpdateFile {
 }
 
 pub struct IndexWriter {
-    documents: BufWriter<File>,
+    /// `None` when writing a settings-only dump.
+    documents: Option<BufWriter<File>>,
     settings: File,
 }
 
 impl IndexWriter {
-    pub(self) fn new(path: PathBuf, metadata: &IndexMetadata) -> Result<Self> {
+    pub(self) fn new(path: PathBuf, metadata: &IndexMetadata, settings_only: bool) -> Result<Self> {
         std::fs::create_dir(&path)?;
 
         let metadata_file = File::create(path.join("metadata.json"))?;
         serde_json::to_writer(metadata_file, metadata)?;
 
-        let documents = File::create(path.join("documents.jsonl"))?;
+        let documents = if settings_only {
+            None
+        } else {
+            Some(BufWriter::new(File::create(path.join("documents.jsonl"))?))
+        };
         let settings = File::create(path.join("settings.json"))?;
 
-        Ok(IndexWriter { documents: BufWriter::new(documents), settings })
+        Ok(IndexWriter { documents, settings })
     }
 
+    /// Pushes a document in the dump, does nothing if the dump is settings-only.
     pub fn push_document(&mut self, document: &Map<String, Value>) -> Result<()> {
-        serde_json::to_writer(&mut self.documents, document)?;
-        self.documents.write_all(b"\n")?;
+        if let Some(documents) = self.documents.as_mut() {
+            serde_json::to_writer(&mut *documents, document)?;
+            documents.write_all(b"\n")?;
+        }
         Ok(())
     }
 
     pub fn flush(&mut self) -> Result<()> {
-        self.documents.flush()?;
+        if let Some(documents) = self.documents.as_mut() {
+            documents.flush()?;
+        }
         Ok(())
     }
 
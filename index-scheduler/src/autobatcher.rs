@@ -24,6 +24,12 @@ enum AutobatchKind {
         allow_index_creation: bool,
         primary_key: Option<String>,
     },
+    DocumentTransaction {
+        allow_index_creation: bool,
+    },
+    DocumentUrlImport {
+        allow_index_creation: bool,
+    },
     DocumentEdition,
     DocumentDeletion,
     DocumentDeletionByFilter,
@@ -35,6 +41,7 @@ enum AutobatchKind {
     IndexDeletion,
     IndexUpdate,
     IndexSwap,
+    DumpImport,
 }
 
 impl AutobatchKind {
@@ -42,6 +49,8 @@ impl AutobatchKind {
     fn allow_index_creation(&self) -> Option<bool> {
         match self {
             AutobatchKind::DocumentImport { allow_index_creation, .. }
+            | AutobatchKind::DocumentTransaction { allow_index_creation }
+            | AutobatchKind::DocumentUrlImport { allow_index_creation }
             | AutobatchKind::Settings { allow_index_creation, .. } => Some(*allow_index_creation),
             _ => None,
         }
@@ -64,6 +73,12 @@ impl From<KindWithContent> for AutobatchKind {
                 primary_key,
                 ..
             } => AutobatchKind::DocumentImport { method, allow_index_creation, primary_key },
+            KindWithContent::DocumentTransaction { allow_index_creation, .. } => {
+                AutobatchKind::DocumentTransaction { allow_index_creation }
+            }
+            KindWithContent::DocumentUrlImport { allow_index_creation, .. } => {
+                AutobatchKind::DocumentUrlImport { allow_index_creation }
+            }
             KindWithContent::DocumentEdition { .. } => AutobatchKind::DocumentEdition,
             KindWithContent::DocumentDeletion { .. } => AutobatchKind::DocumentDeletion,
             KindWithContent::DocumentClear { .. } => AutobatchKind::DocumentClear,
@@ -79,6 +94,7 @@ impl From<KindWithContent> for AutobatchKind {
             KindWithContent::IndexCreation { .. } => AutobatchKind::IndexCreation,
             KindWithContent::IndexUpdate { .. } => AutobatchKind::IndexUpdate,
             KindWithContent::IndexSwap { .. } => AutobatchKind::IndexSwap,
+            KindWithContent::DumpImport { .. } => AutobatchKind::DumpImport,
             KindWithContent::TaskCancelation { .. }
             | KindWithContent::TaskDeletion { .. }
             | KindWithContent::DumpCreation { .. }
@@ -100,6 +116,14 @@ pub enum BatchKind {
         primary_key: Option<String>,
         operation_ids: Vec<TaskId>,
     },
+    DocumentTransaction {
+        id: TaskId,
+        allow_index_creation: bool,
+    },
+    DocumentUrlImport {
+        id: TaskId,
+        allow_index_creation: bool,
+    },
     DocumentEdition {
         id: TaskId,
     },
@@ -137,6 +161,9 @@ pub enum BatchKind {
     IndexSwap {
         id: TaskId,
     },
+    DumpImport {
+        id: TaskId,
+    },
 }
 
 impl BatchKind {
@@ -144,6 +171,8 @@ impl BatchKind {
     fn allow_index_creation(&self) -> Option<bool> {
         match self {
             BatchKind::DocumentOperation { allow_index_creation, .. }
+            | BatchKind::DocumentTransaction { allow_index_creation, .. }
+            | BatchKind::DocumentUrlImport { allow_index_creation, .. }
             | BatchKind::ClearAndSettings { allow_index_creation, .. }
             | BatchKind::SettingsAndDocumentOperation { allow_index_creation, .. }
             | BatchKind::Settings { allow_index_creation, .. } => Some(*allow_index_creation),
@@ -180,6 +209,7 @@ impl BatchKind {
             K::IndexDeletion => (Break(BatchKind::IndexDeletion { ids: vec![task_id] }), false),
             K::IndexUpdate => (Break(BatchKind::IndexUpdate { id: task_id }), false),
             K::IndexSwap => (Break(BatchKind::IndexSwap { id: task_id }), false),
+            K::DumpImport => (Break(BatchKind::DumpImport { id: task_id }), false),
             K::DocumentClear => (Continue(BatchKind::DocumentClear { ids: vec![task_id] }), false),
             K::DocumentImport { method, allow_index_creation, primary_key: pk }
                 if primary_key.is_none() || pk.is_none() || primary_key == pk.as_deref() =>
@@ -204,6 +234,14 @@ impl BatchKind {
                 }),
                 allow_index_creation,
             ),
+            K::DocumentTransaction { allow_index_creation } => (
+                Break(BatchKind::DocumentTransaction { id: task_id, allow_index_creation }),
+                allow_index_creation,
+            ),
+            K::DocumentUrlImport { allow_index_creation } => (
+                Break(BatchKind::DocumentUrlImport { id: task_id, allow_index_creation }),
+                allow_index_creation,
+            ),
             K::DocumentEdition => (Break(BatchKind::DocumentEdition { id: task_id }), false),
             K::DocumentDeletion => {
                 (Continue(BatchKind::DocumentDeletion { deletion_ids: vec![task_id] }), false)
@@ -228,7 +266,7 @@ impl BatchKind {
 
         match (self, kind) {
             // We don't batch any of these operations
-            (this, K::IndexCreation | K::IndexUpdate | K::IndexSwap | K::DocumentEdition | K::DocumentDeletionByFilter) => Break(this),
+            (this, K::IndexCreation | K::IndexUpdate | K::IndexSwap | K::DumpImport | K::DocumentTransaction { .. } | K::DocumentUrlImport { .. } | K::DocumentEdition | K::DocumentDeletionByFilter) => Break(this),
             // We must not batch tasks that don't have the same index creation rights if the index doesn't already exists.
             (this, kind) if !index_already_exists && this.allow_index_creation() == Some(false) && kind.allow_index_creation() == Some(true) => {
                 Break(this)
@@ -525,6 +563,9 @@ impl BatchKind {
                 | BatchKind::IndexDeletion { .. }
                 | BatchKind::IndexUpdate { .. }
                 | BatchKind::IndexSwap { .. }
+                | BatchKind::DumpImport { .. }
+                | BatchKind::DocumentTransaction { .. }
+                | BatchKind::DocumentUrlImport { .. }
                 | BatchKind::DocumentEdition { .. }
                 | BatchKind::DocumentDeletionByFilter { .. },
                 _,
@@ -613,6 +654,7 @@ mod tests {
         KindWithContent::DocumentDeletion {
             index_uid: String::from("doggo"),
             documents_ids: Vec::new(),
+            report_outcomes: false,
         }
     }
 
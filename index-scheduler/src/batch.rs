@@ -17,16 +17,25 @@ tasks individually, but should be much faster since we are only performing
 one indexing operation.
 */
 
-use std::collections::{BTreeSet, HashSet};
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::ffi::OsStr;
 use std::fmt;
 use std::fs::{self, File};
-use std::io::BufWriter;
-
-use dump::IndexMetadata;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::num::NonZeroU64;
+use std::ops::Bound;
+use std::path::Path;
+use std::time::Duration;
+
+use dump::{IndexMetadata, KindDump};
+use meilisearch_types::compression::{self, TarGzWriter};
+use meilisearch_types::document_formats::read_ndjson;
 use meilisearch_types::error::Code;
 use meilisearch_types::heed::{RoTxn, RwTxn};
-use meilisearch_types::milli::documents::{obkv_to_object, DocumentsBatchReader};
+use meilisearch_types::milli::documents::{
+    documents_batch_reader_from_objects, obkv_to_object, DocumentsBatchBuilder,
+    DocumentsBatchReader,
+};
 use meilisearch_types::milli::heed::CompactionOption;
 use meilisearch_types::milli::update::{
     IndexDocumentsConfig, IndexDocumentsMethod, IndexerConfig, Settings as MilliSettings,
@@ -36,14 +45,18 @@ use meilisearch_types::milli::vector::parsed_vectors::{
 };
 use meilisearch_types::milli::{self, Filter, Object};
 use meilisearch_types::settings::{apply_settings_to_builder, Settings, Unchecked};
-use meilisearch_types::tasks::{Details, IndexSwap, Kind, KindWithContent, Status, Task};
-use meilisearch_types::{compression, Index, VERSION_FILE_NAME};
+use meilisearch_types::tasks::{
+    Details, DocumentDeletionOutcome, DocumentTransactionOperation, IndexSwap, Kind,
+    KindWithContent, Status, Task,
+};
+use meilisearch_types::{versioning, Index, VERSION_FILE_NAME};
 use roaring::RoaringBitmap;
 use time::macros::format_description;
 use time::OffsetDateTime;
 use uuid::Uuid;
 
 use crate::autobatcher::{self, BatchKind};
+use crate::index_mapper::snapshot_index_mapping;
 use crate::utils::{self, swap_index_uid_in_task};
 use crate::{Error, IndexScheduler, MustStopProcessing, ProcessingTasks, Result, TaskId};
 
@@ -87,6 +100,7 @@ pub(crate) enum Batch {
     IndexSwap {
         task: Task,
     },
+    DumpImport(Task),
 }
 
 #[derive(Debug)]
@@ -106,6 +120,17 @@ pub(crate) enum IndexOperation {
         operations: Vec<DocumentOperation>,
         tasks: Vec<Task>,
     },
+    DocumentTransaction {
+        index_uid: String,
+        task: Task,
+    },
+    DocumentUrlImport {
+        index_uid: String,
+        task: Task,
+        /// The update file of the downloaded documents along with their number,
+        /// filled before the index write transaction is opened.
+        content_file: Option<(Uuid, u64)>,
+    },
     DocumentEdition {
         index_uid: String,
         task: Task,
@@ -153,6 +178,7 @@ impl Batch {
         match self {
             Batch::TaskCancelation { task, .. }
             | Batch::Dump(task)
+            | Batch::DumpImport(task)
             | Batch::IndexCreation { task, .. }
             | Batch::IndexUpdate { task, .. } => {
                 RoaringBitmap::from_sorted_iter(std::iter::once(task.uid)).unwrap()
@@ -168,7 +194,9 @@ impl Batch {
                 | IndexOperation::DocumentClear { tasks, .. } => {
                     RoaringBitmap::from_iter(tasks.iter().map(|task| task.uid))
                 }
-                IndexOperation::DocumentEdition { task, .. }
+                IndexOperation::DocumentTransaction { task, .. }
+                | IndexOperation::DocumentUrlImport { task, .. }
+                | IndexOperation::DocumentEdition { task, .. }
                 | IndexOperation::IndexDocumentDeletionByFilter { task, .. } => {
                     RoaringBitmap::from_sorted_iter(std::iter::once(task.uid)).unwrap()
                 }
@@ -197,6 +225,7 @@ impl Batch {
             | TaskDeletions(_)
             | SnapshotCreation(_)
             | Dump(_)
+            | DumpImport(_)
             | IndexSwap { .. } => None,
             IndexOperation { op, .. } => Some(op.index_uid()),
             IndexCreation { index_uid, .. }
@@ -221,6 +250,7 @@ impl fmt::Display for Batch {
             Batch::IndexUpdate { .. } => f.write_str("IndexUpdate")?,
             Batch::IndexDeletion { .. } => f.write_str("IndexDeletion")?,
             Batch::IndexSwap { .. } => f.write_str("IndexSwap")?,
+            Batch::DumpImport(_) => f.write_str("DumpImport")?,
         };
         match index_uid {
             Some(name) => f.write_fmt(format_args!(" on {name:?} from tasks: {tasks:?}")),
@@ -233,6 +263,8 @@ impl IndexOperation {
     pub fn index_uid(&self) -> &str {
         match self {
             IndexOperation::DocumentOperation { index_uid, .. }
+            | IndexOperation::DocumentTransaction { index_uid, .. }
+            | IndexOperation::DocumentUrlImport { index_uid, .. }
             | IndexOperation::DocumentEdition { index_uid, .. }
             | IndexOperation::IndexDocumentDeletionByFilter { index_uid, .. }
             | IndexOperation::DocumentClear { index_uid, .. }
@@ -249,6 +281,12 @@ impl fmt::Display for IndexOperation {
             IndexOperation::DocumentOperation { .. } => {
                 f.write_str("IndexOperation::DocumentOperation")
             }
+            IndexOperation::DocumentTransaction { .. } => {
+                f.write_str("IndexOperation::DocumentTransaction")
+            }
+            IndexOperation::DocumentUrlImport { .. } => {
+                f.write_str("IndexOperation::DocumentUrlImport")
+            }
             IndexOperation::DocumentEdition { .. } => {
                 f.write_str("IndexOperation::DocumentEdition")
             }
@@ -304,6 +342,37 @@ impl IndexScheduler {
                     _ => unreachable!(),
                 }
             }
+            BatchKind::DocumentTransaction { id, .. } => {
+                let task = self.get_task(rtxn, id)?.ok_or(Error::CorruptedTaskQueue)?;
+                match &task.kind {
+                    KindWithContent::DocumentTransaction { index_uid, .. } => {
+                        Ok(Some(Batch::IndexOperation {
+                            op: IndexOperation::DocumentTransaction {
+                                index_uid: index_uid.clone(),
+                                task,
+                            },
+                            must_create_index,
+                        }))
+                    }
+                    _ => unreachable!(),
+                }
+            }
+            BatchKind::DocumentUrlImport { id, .. } => {
+                let task = self.get_task(rtxn, id)?.ok_or(Error::CorruptedTaskQueue)?;
+                match &task.kind {
+                    KindWithContent::DocumentUrlImport { index_uid, .. } => {
+                        Ok(Some(Batch::IndexOperation {
+                            op: IndexOperation::DocumentUrlImport {
+                                index_uid: index_uid.clone(),
+                                task,
+                                content_file: None,
+                            },
+                            must_create_index,
+                        }))
+                    }
+                    _ => unreachable!(),
+                }
+            }
             BatchKind::DocumentEdition { id } => {
                 let task = self.get_task(rtxn, id)?.ok_or(Error::CorruptedTaskQueue)?;
                 match &task.kind {
@@ -539,6 +608,10 @@ impl IndexScheduler {
                 let task = self.get_task(rtxn, id)?.ok_or(Error::CorruptedTaskQueue)?;
                 Ok(Some(Batch::IndexSwap { task }))
             }
+            BatchKind::DumpImport { id } => {
+                let task = self.get_task(rtxn, id)?.ok_or(Error::CorruptedTaskQueue)?;
+                Ok(Some(Batch::DumpImport(task)))
+            }
         }
     }
 
@@ -594,12 +667,14 @@ impl IndexScheduler {
         let task_id = if let Some(task_id) = enqueued.min() { task_id } else { return Ok(None) };
         let task = self.get_task(rtxn, task_id)?.ok_or(Error::CorruptedTaskQueue)?;
 
-        // If the task is not associated with any index, verify that it is an index swap and
-        // create the batch directly. Otherwise, get the index name associated with the task
-        // and use the autobatcher to batch the enqueued tasks associated with it
+        // If the task is not associated with any index, verify that it is an index swap or the
+        // import of an empty dump and create the batch directly. Otherwise, get the index name
+        // associated with the task and use the autobatcher to batch the enqueued tasks associated with it
 
         let index_name = if let Some(&index_name) = task.indexes().first() {
             index_name
+        } else if matches!(&task.kind, KindWithContent::DumpImport { .. }) {
+            return Ok(Some(Batch::DumpImport(task)));
         } else {
             assert!(matches!(&task.kind, KindWithContent::IndexSwap { swaps } if swaps.is_empty()));
             return Ok(Some(Batch::IndexSwap { task }));
@@ -658,6 +733,7 @@ impl IndexScheduler {
         {
             self.maybe_fail(crate::tests::FailureLocation::InsideProcessBatch)?;
             self.maybe_fail(crate::tests::FailureLocation::PanicInsideProcessBatch)?;
+            self.maybe_fail(crate::tests::FailureLocation::TransientFailureInsideProcessBatch)?;
             self.breakpoint(crate::Breakpoint::InsideProcessBatch);
         }
 
@@ -752,14 +828,31 @@ impl IndexScheduler {
                 Ok(tasks)
             }
             Batch::SnapshotCreation(mut tasks) => {
-                fs::create_dir_all(&self.snapshots_path)?;
+                // the settings are read once, so a snapshot is never split between two directories
+                let settings = self.snapshot_settings();
+                fs::create_dir_all(&settings.dir)?;
+
+                // The databases are copied one by one next to the tarball and removed once archived,
+                // this empty directory only gives its metadata to the directories of the tarball.
                 let temp_snapshot_dir = tempfile::tempdir()?;
 
-                // 1. Snapshot the version file.
-                let dst = temp_snapshot_dir.path().join(VERSION_FILE_NAME);
-                fs::copy(&self.version_file_path, dst)?;
+                // 1. Find the original name of the database
+                // TODO find a better way to get this path
+                let mut base_path = self.env.path().to_owned();
+                base_path.pop();
+                let db_name = base_path.file_name().and_then(OsStr::to_str).unwrap_or("data.ms");
+
+                // 2. Create the tarball in a tempfile, it gets its .snapshot extension once complete
+                let snapshot_path = settings.snapshot_path(db_name, 0);
+                let temp_snapshot_file = tempfile::NamedTempFile::new_in(&settings.dir)?;
+                let mut tarball =
+                    TarGzWriter::new(temp_snapshot_file.path(), settings.compression_level)?;
+                tarball.throttle(settings.max_bytes_per_second.and_then(NonZeroU64::new));
 
-                // 2. Snapshot the index-scheduler LMDB env
+                // 3. Snapshot the version file.
+                tarball.append_file(VERSION_FILE_NAME, &self.version_file_path)?;
+
+                // 4. Snapshot the index-scheduler LMDB env
                 //
                 // When we call copy_to_file, LMDB opens a read transaction by itself,
                 // we can't provide our own. It is an issue as we would like to know
@@ -771,42 +864,40 @@ impl IndexScheduler {
                 // Note that there cannot be any update files deleted between those
                 // two read operations as the task processing is synchronous.
 
-                // 2.1 First copy the LMDB env of the index-scheduler
-                let dst = temp_snapshot_dir.path().join("tasks");
-                fs::create_dir_all(&dst)?;
-                self.env.copy_to_file(dst.join("data.mdb"), CompactionOption::Enabled)?;
+                // 4.1 First copy the LMDB env of the index-scheduler
+                tarball.append_compacted_env("tasks/data.mdb", |path| {
+                    self.env.copy_to_file(path, CompactionOption::Enabled)?;
+                    Ok(())
+                })?;
 
-                // 2.2 Create a read transaction on the index-scheduler
+                // 4.2 Create a read transaction on the index-scheduler
                 let rtxn = self.env.read_txn()?;
 
-                // 2.3 Create the update files directory
-                let update_files_dir = temp_snapshot_dir.path().join("update_files");
-                fs::create_dir_all(&update_files_dir)?;
+                // 4.3 Create the update files directory
+                tarball.append_dir("update_files", temp_snapshot_dir.path())?;
 
-                // 2.4 Only copy the update files of the enqueued tasks
+                // 4.4 Only archive the update files of the enqueued tasks
                 for task_id in self.get_status(&rtxn, Status::Enqueued)? {
                     let task = self.get_task(&rtxn, task_id)?.ok_or(Error::CorruptedTaskQueue)?;
                     if let Some(content_uuid) = task.content_uuid() {
                         let src = self.file_store.get_update_path(content_uuid);
-                        let dst = update_files_dir.join(content_uuid.to_string());
-                        fs::copy(src, dst)?;
+                        tarball.append_file(format!("update_files/{content_uuid}"), src)?;
                     }
                 }
 
-                // 3. Snapshot every indexes
+                // 5. Snapshot every indexes
                 for result in self.index_mapper.index_mapping.iter(&rtxn)? {
                     let (name, uuid) = result?;
                     let index = self.index_mapper.index(&rtxn, name)?;
-                    let dst = temp_snapshot_dir.path().join("indexes").join(uuid.to_string());
-                    fs::create_dir_all(&dst)?;
-                    index.copy_to_file(dst.join("data.mdb"), CompactionOption::Enabled)?;
+                    tarball.append_compacted_env(format!("indexes/{uuid}/data.mdb"), |path| {
+                        index.copy_to_file(path, CompactionOption::Enabled)?;
+                        Ok(())
+                    })?;
                 }
 
                 drop(rtxn);
 
-                // 4. Snapshot the auth LMDB env
-                let dst = temp_snapshot_dir.path().join("auth");
-                fs::create_dir_all(&dst)?;
+                // 6. Snapshot the auth LMDB env
                 // TODO We can't use the open_auth_store_env function here but we should
                 let auth = unsafe {
                     milli::heed::EnvOpenOptions::new()
@@ -814,22 +905,29 @@ impl IndexScheduler {
                         .max_dbs(2)
                         .open(&self.auth_path)
                 }?;
-                auth.copy_to_file(dst.join("data.mdb"), CompactionOption::Enabled)?;
+                tarball.append_compacted_env("auth/data.mdb", |path| {
+                    auth.copy_to_file(path, CompactionOption::Enabled)?;
+                    Ok(())
+                })?;
 
-                // 5. Copy and tarball the flat snapshot
-                // 5.1 Find the original name of the database
-                // TODO find a better way to get this path
-                let mut base_path = self.env.path().to_owned();
-                base_path.pop();
-                let db_name = base_path.file_name().and_then(OsStr::to_str).unwrap_or("data.ms");
+                tarball.finish()?;
+
+                // 7. Shift the previous snapshots, only the retained ones are kept
+                let mut generation = settings.retained.max(1) - 1;
+                while settings.snapshot_path(db_name, generation).exists() {
+                    fs::remove_file(settings.snapshot_path(db_name, generation))?;
+                    generation += 1;
+                }
+                for generation in (1..settings.retained).rev() {
+                    let previous = settings.snapshot_path(db_name, generation - 1);
+                    if previous.exists() {
+                        fs::rename(previous, settings.snapshot_path(db_name, generation))?;
+                    }
+                }
 
-                // 5.2 Tarball the content of the snapshot in a tempfile with a .snapshot extension
-                let snapshot_path = self.snapshots_path.join(format!("{}.snapshot", db_name));
-                let temp_snapshot_file = tempfile::NamedTempFile::new_in(&self.snapshots_path)?;
-                compression::to_tar_gz(temp_snapshot_dir.path(), temp_snapshot_file.path())?;
                 let file = temp_snapshot_file.persist(snapshot_path)?;
 
-                // 5.3 Change the permission to make the snapshot readonly
+                // 8. Change the permission to make the snapshot readonly
                 let mut permissions = file.metadata()?.permissions();
                 permissions.set_readonly(true);
                 #[cfg(unix)]
@@ -850,13 +948,23 @@ impl IndexScheduler {
             }
             Batch::Dump(mut task) => {
                 let started_at = OffsetDateTime::now_utc();
-                let (keys, instance_uid) =
-                    if let KindWithContent::DumpCreation { keys, instance_uid } = &task.kind {
-                        (keys, instance_uid)
+                let (keys, instance_uid, settings_only, since) =
+                    if let KindWithContent::DumpCreation {
+                        keys,
+                        instance_uid,
+                        settings_only,
+                        since,
+                    } = &task.kind
+                    {
+                        (keys, instance_uid, *settings_only, *since)
                     } else {
                         unreachable!();
                     };
-                let dump = dump::DumpWriter::new(*instance_uid)?;
+                let dump = match since {
+                    Some(since) => dump::DumpWriter::new_incremental(*instance_uid, since)?,
+                    None if settings_only => dump::DumpWriter::new_settings_only(*instance_uid)?,
+                    None => dump::DumpWriter::new(*instance_uid)?,
+                };
 
                 // 1. dump the keys
                 let mut dump_keys = dump.create_keys()?;
@@ -867,17 +975,29 @@ impl IndexScheduler {
 
                 let rtxn = self.env.read_txn()?;
 
-                // 2. dump the tasks
+                // 2. dump the tasks, an incremental dump skips the ones up to its checkpoint
                 let mut dump_tasks = dump.create_tasks_queue()?;
-                for ret in self.all_tasks.iter(&rtxn)? {
+                let first_task = match since {
+                    Some(since) => Bound::Excluded(since),
+                    None => Bound::Unbounded,
+                };
+                let mut modified_indexes = BTreeSet::new();
+                for ret in self.all_tasks.range(&rtxn, &(first_task, Bound::Unbounded))? {
                     if self.must_stop_processing.get() {
                         return Err(Error::AbortedTask);
                     }
 
                     let (_, mut t) = ret?;
+                    modified_indexes.extend(t.indexes().into_iter().map(String::from));
                     let status = t.status;
                     let content_file = t.content_uuid();
 
+                    // A settings-only dump doesn't contain any document, we can't keep the
+                    // enqueued tasks that would need their content file once imported.
+                    if settings_only && status == Status::Enqueued && content_file.is_some() {
+                        continue;
+                    }
+
                     // In the case we're dumping ourselves we want to be marked as finished
                     // to not loop over ourselves indefinitely.
                     if t.uid == task.uid {
@@ -920,8 +1040,29 @@ impl IndexScheduler {
                 }
                 dump_tasks.flush()?;
 
-                // 3. Dump the indexes
+                // 2.2. An incremental dump lists the indexes deleted since its checkpoint
+                if since.is_some() {
+                    let mut deleted_indexes = Vec::new();
+                    for index_uid in modified_indexes {
+                        if !self.index_mapper.index_exists(&rtxn, &index_uid)? {
+                            deleted_indexes.push(index_uid);
+                        }
+                    }
+                    dump.create_deleted_indexes(&deleted_indexes)?;
+                }
+
+                // 3. Dump the indexes, an incremental dump skips the unmodified ones
                 self.index_mapper.try_for_each_index(&rtxn, |uid, index| -> Result<()> {
+                    if self.must_stop_processing.get() {
+                        return Err(Error::AbortedTask);
+                    }
+                    if let Some(since) = since {
+                        let last_task = self.index_tasks(&rtxn, uid)?.max();
+                        if last_task.map_or(true, |last_task| last_task <= since) {
+                            return Ok(());
+                        }
+                    }
+
                     let rtxn = index.read_txn()?;
                     let metadata = IndexMetadata {
                         uid: uid.to_owned(),
@@ -935,15 +1076,18 @@ impl IndexScheduler {
                     let all_fields: Vec<_> = fields_ids_map.iter().map(|(id, _)| id).collect();
                     let embedding_configs = index.embedding_configs(&rtxn)?;
 
-                    // 3.1. Dump the documents
-                    for ret in index.all_documents(&rtxn)? {
+                    // 3.1. Dump the documents, unless we're only dumping the settings
+                    let documents =
+                        if settings_only { None } else { Some(index.all_documents(&rtxn)?) };
+                    for ret in documents.into_iter().flatten() {
                         if self.must_stop_processing.get() {
                             return Err(Error::AbortedTask);
                         }
 
                         let (id, doc) = ret?;
 
-                        let mut document = milli::obkv_to_json(&all_fields, &fields_ids_map, doc)?;
+                        let mut document =
+                            milli::obkv_to_json(&all_fields, &fields_ids_map, doc.as_obkv())?;
 
                         'inject_vectors: {
                             let embeddings = index.embeddings(&rtxn, id)?;
@@ -1015,20 +1159,43 @@ impl IndexScheduler {
                     "[year repr:full][month repr:numerical][day padding:zero]-[hour padding:zero][minute padding:zero][second padding:zero][subsecond digits:3]"
                 )).unwrap();
 
+                if self.must_stop_processing.get() {
+                    return Err(Error::AbortedTask);
+                }
+                // The archive is written to a temporary file, removed if the dump fails or is
+                // canceled, to never leave a partial dump in the dumps directory.
+                let mut file = tempfile::NamedTempFile::new_in(&self.dumps_path)?;
+                dump.persist_to(BufWriter::new(&mut file))?;
+
+                // archiving a large dump takes a while, it may have been canceled in the meantime
                 if self.must_stop_processing.get() {
                     return Err(Error::AbortedTask);
                 }
                 let path = self.dumps_path.join(format!("{}.dump", dump_uid));
-                let file = File::create(path)?;
-                dump.persist_to(BufWriter::new(file))?;
+                file.persist(path)?;
 
                 // if we reached this step we can tell the scheduler we succeeded to dump ourselves.
                 task.status = Status::Succeeded;
                 task.details = Some(Details::Dump { dump_uid: Some(dump_uid) });
                 Ok(vec![task])
             }
-            Batch::IndexOperation { op, must_create_index } => {
+            Batch::IndexOperation { mut op, must_create_index } => {
                 let index_uid = op.index_uid().to_string();
+
+                // The documents of a URL import are downloaded before the index write transaction
+                // is opened, a slow server must neither block the index nor the task cancelation.
+                let downloaded_file = match &mut op {
+                    IndexOperation::DocumentUrlImport { task, content_file, .. } => {
+                        let KindWithContent::DocumentUrlImport { url, .. } = &task.kind else {
+                            unreachable!()
+                        };
+                        let downloaded = self.download_ndjson_documents(url)?;
+                        *content_file = Some(downloaded);
+                        Some(downloaded.0)
+                    }
+                    _ => None,
+                };
+
                 let index = if must_create_index {
                     // create the index if it doesn't already exist
                     let wtxn = self.env.write_txn()?;
@@ -1043,7 +1210,12 @@ impl IndexScheduler {
                     .set_currently_updating_index(Some((index_uid.clone(), index.clone())));
 
                 let mut index_wtxn = index.write_txn()?;
-                let tasks = self.apply_index_operation(&mut index_wtxn, &index, op)?;
+                let tasks = self.apply_index_operation(&mut index_wtxn, &index, op);
+                // the downloaded documents are only read by this batch
+                if let Some(uuid) = downloaded_file {
+                    self.file_store.delete(uuid)?;
+                }
+                let tasks = tasks?;
                 index_wtxn.commit()?;
 
                 // if the update processed successfully, we're going to store the new
@@ -1192,7 +1364,311 @@ impl IndexScheduler {
                 task.status = Status::Succeeded;
                 Ok(vec![task])
             }
+            Batch::DumpImport(mut task) => {
+                let (dump_uid, namespace, index_uids) =
+                    if let KindWithContent::DumpImport { dump_uid, namespace, index_uids } =
+                        &task.kind
+                    {
+                        (dump_uid, namespace.as_deref(), index_uids)
+                    } else {
+                        unreachable!()
+                    };
+
+                // The archives of the snapshots are imported like the dumps.
+                let snapshot_path = self.dumps_path.join(format!("{dump_uid}.snapshot"));
+                let mut dump = if snapshot_path.is_file() {
+                    None
+                } else {
+                    let reader = File::open(self.dumps_path.join(format!("{dump_uid}.dump")))?;
+                    Some(dump::DumpReader::open(reader)?)
+                };
+
+                // 1. The uids were free when the task was registered, but an index may have been created since.
+                //    An incremental dump is applied on top of the existing indexes and replaces them instead.
+                let incremental = dump.as_ref().map_or(false, |dump| dump.since().is_some());
+                let rtxn = self.env.read_txn()?;
+                for index_uid in index_uids {
+                    if !incremental && self.index_mapper.index_exists(&rtxn, index_uid)? {
+                        return Err(Error::IndexAlreadyExists(index_uid.clone()));
+                    }
+                }
+                drop(rtxn);
+                let deleted_indexes: Vec<_> = dump
+                    .as_ref()
+                    .map_or(&[][..], |dump| dump.deleted_indexes())
+                    .iter()
+                    .map(|uid| KindWithContent::dump_import_index_uid(namespace, uid))
+                    .collect();
+                // The dump was modified since the task was registered.
+                if deleted_indexes.iter().any(|uid| !index_uids.contains(uid)) {
+                    return Err(Error::CorruptedDump);
+                }
+
+                // 2. Fill the indexes while nobody can see them, the other indexes keep being served.
+                let mut imported = Vec::new();
+                let res = match &dump {
+                    Some(dump) => {
+                        self.import_dump_indexes(dump, namespace, index_uids, &mut imported)
+                    }
+                    None => self.import_snapshot_indexes(
+                        &snapshot_path,
+                        namespace,
+                        index_uids,
+                        &mut imported,
+                    ),
+                };
+                // 3. Register all the indexes at once.
+                let res = res.and_then(|()| {
+                    if self.must_stop_processing.get() {
+                        return Err(Error::AbortedTask);
+                    }
+                    let mut wtxn = self.env.write_txn()?;
+                    let mut replaced = Vec::new();
+                    for (index_uid, uuid) in &imported {
+                        replaced
+                            .extend(self.index_mapper.register_index(&mut wtxn, index_uid, uuid)?);
+                        let index = self.index_mapper.index(&wtxn, index_uid)?;
+                        let index_rtxn = index.read_txn()?;
+                        let stats = crate::index_mapper::IndexStats::new(&index, &index_rtxn)?;
+                        self.index_mapper.store_stats_of(&mut wtxn, index_uid, &stats)?;
+                    }
+                    // 3.1. The tasks of an incremental dump are appended to the task queue.
+                    if let Some(dump) = &mut dump {
+                        self.import_dump_tasks(&mut wtxn, dump, namespace)?;
+                    }
+                    wtxn.commit()?;
+                    Ok(replaced)
+                });
+
+                // Nothing was registered when the import failed or was canceled, the filled
+                // indexes can't be reached and are removed from the disk.
+                let replaced = match res {
+                    Ok(replaced) => replaced,
+                    Err(e) => {
+                        for (_, uuid) in imported {
+                            if let Err(e) = self.index_mapper.discard_unregistered_index(&uuid) {
+                                tracing::error!(
+                                    "Could not remove an index of a failed dump import: {e}"
+                                );
+                            }
+                        }
+                        return Err(e);
+                    }
+                };
+
+                // 4. The indexes replaced by an incremental dump can't be reached anymore.
+                for uuid in replaced {
+                    if let Err(e) = self.index_mapper.discard_unregistered_index(&uuid) {
+                        tracing::error!("Could not remove an index replaced by a dump import: {e}");
+                    }
+                }
+
+                // 5. The indexes deleted since the checkpoint of an incremental dump are deleted too.
+                for index_uid in deleted_indexes {
+                    // The write transaction is directly owned and committed inside.
+                    let wtxn = self.env.write_txn()?;
+                    match self.index_mapper.delete_index(wtxn, &index_uid) {
+                        Ok(()) | Err(Error::IndexNotFound(_)) => (),
+                        Err(e) => return Err(e),
+                    }
+                }
+
+                task.status = Status::Succeeded;
+                Ok(vec![task])
+            }
+        }
+    }
+
+    /// Fill an unregistered index with each index of the dump, pushing the uid it must be
+    /// registered under and its uuid into `imported` as soon as it is created.
+    fn import_dump_indexes(
+        &self,
+        dump: &dump::DumpReader,
+        namespace: Option<&str>,
+        index_uids: &[String],
+        imported: &mut Vec<(String, Uuid)>,
+    ) -> Result<()> {
+        let indexer_config = self.index_mapper.indexer_config();
+        let must_stop_processing = self.must_stop_processing.clone();
+
+        for index_reader in dump.indexes()? {
+            if must_stop_processing.get() {
+                return Err(Error::AbortedTask);
+            }
+            let mut index_reader = index_reader?;
+            let metadata = index_reader.metadata();
+            let index_uid = KindWithContent::dump_import_index_uid(namespace, &metadata.uid);
+            // The dump was modified since the task was registered.
+            if !index_uids.contains(&index_uid) {
+                return Err(Error::CorruptedDump);
+            }
+            let primary_key = metadata.primary_key.clone();
+            let date = Some((metadata.created_at, metadata.updated_at));
+            let (uuid, index) = self.index_mapper.create_unregistered_index(date)?;
+            imported.push((index_uid, uuid));
+
+            let mut wtxn = index.write_txn()?;
+
+            let mut builder = MilliSettings::new(&mut wtxn, &index, indexer_config);
+            if let Some(primary_key) = primary_key {
+                builder.set_primary_key(primary_key);
+            }
+            let settings = index_reader.settings()?;
+            apply_settings_to_builder(&settings, &mut builder);
+            builder.execute(
+                |indexing_step| tracing::debug!(update = ?indexing_step),
+                || must_stop_processing.get(),
+            )?;
+
+            let file = tempfile::tempfile()?;
+            let mut builder = DocumentsBatchBuilder::new(BufWriter::new(file));
+            for document in index_reader.documents()? {
+                if must_stop_processing.get() {
+                    return Err(Error::AbortedTask);
+                }
+                builder.append_json_object(&document?)?;
+            }
+            let file = builder.into_inner()?.into_inner().map_err(|e| e.into_error())?;
+            let reader = DocumentsBatchReader::from_reader(BufReader::new(file))
+                .map_err(milli::Error::from)?;
+
+            let embedder_configs = index.embedding_configs(&wtxn)?;
+            let embedders = self.embedders(embedder_configs)?;
+
+            let builder = milli::update::IndexDocuments::new(
+                &mut wtxn,
+                &index,
+                indexer_config,
+                IndexDocumentsConfig {
+                    update_method: IndexDocumentsMethod::ReplaceDocuments,
+                    ..Default::default()
+                },
+                |indexing_step| tracing::trace!(update = ?indexing_step),
+                || must_stop_processing.get(),
+            )?
+            .with_embedders(embedders);
+            let (builder, user_result) = builder.add_documents(reader)?;
+            user_result.map_err(milli::Error::from)?;
+            builder.execute()?;
+            wtxn.commit()?;
+        }
+
+        Ok(())
+    }
+
+    /// Append the tasks of an incremental dump to the task queue, after the registered tasks.
+    ///
+    /// The tasks are shifted to the uids following the last registered task, keeping their order
+    /// and the references between them. The references to the tasks up to the checkpoint of the
+    /// dump are dropped, they don't match the tasks of this instance. The enqueued tasks that
+    /// don't concern any index, such as the dump creations, were meant for the instance that
+    /// created the dump and are skipped.
+    fn import_dump_tasks(
+        &self,
+        wtxn: &mut RwTxn,
+        dump: &mut dump::DumpReader,
+        namespace: Option<&str>,
+    ) -> Result<()> {
+        let Some(since) = dump.since() else { return Ok(()) };
+        let first_uid = self.next_task_id(wtxn)?;
+        let shift = |uid: TaskId| (uid > since).then(|| uid - since - 1 + first_uid);
+
+        let mut indexes: HashMap<String, RoaringBitmap> = HashMap::new();
+        let mut statuses: HashMap<Status, RoaringBitmap> = HashMap::new();
+        let mut kinds: HashMap<Kind, RoaringBitmap> = HashMap::new();
+        for ret in dump.tasks()? {
+            let (mut task, content_file) = ret?;
+            let concerns_indexes =
+                task.index_uid.is_some() || matches!(task.kind, KindDump::IndexSwap { .. });
+            if task.status == Status::Enqueued && !concerns_indexes {
+                continue;
+            }
+
+            // An incremental dump only contains the tasks following its checkpoint.
+            task.uid = shift(task.uid).ok_or(Error::CorruptedDump)?;
+            task.canceled_by = task.canceled_by.and_then(shift);
+            task.index_uid =
+                task.index_uid.map(|uid| KindWithContent::dump_import_index_uid(namespace, &uid));
+            match &mut task.kind {
+                KindDump::IndexSwap { swaps } => {
+                    for IndexSwap { indexes: (lhs, rhs) } in swaps {
+                        *lhs = KindWithContent::dump_import_index_uid(namespace, lhs);
+                        *rhs = KindWithContent::dump_import_index_uid(namespace, rhs);
+                    }
+                }
+                KindDump::DumpImport { index_uids, .. } => {
+                    for index_uid in index_uids {
+                        *index_uid = KindWithContent::dump_import_index_uid(namespace, index_uid);
+                    }
+                }
+                KindDump::TaskCancelation { tasks, .. } | KindDump::TasksDeletion { tasks, .. } => {
+                    *tasks = tasks.iter().filter_map(shift).collect();
+                }
+                _ => (),
+            }
+
+            let task = self.task_from_dump(task, content_file)?;
+            self.all_tasks.put(wtxn, &task.uid, &task)?;
+            for index in task.indexes() {
+                indexes.entry(index.to_string()).or_default().insert(task.uid);
+            }
+            statuses.entry(task.status).or_default().insert(task.uid);
+            kinds.entry(task.kind.as_kind()).or_default().insert(task.uid);
+            utils::insert_task_datetime(wtxn, self.enqueued_at, task.enqueued_at, task.uid)?;
+            if let Some(started_at) = task.started_at {
+                utils::insert_task_datetime(wtxn, self.started_at, started_at, task.uid)?;
+            }
+            if let Some(finished_at) = task.finished_at {
+                utils::insert_task_datetime(wtxn, self.finished_at, finished_at, task.uid)?;
+            }
+        }
+
+        for (index, bitmap) in indexes {
+            self.update_index(wtxn, &index, |tasks| *tasks |= &bitmap)?;
+        }
+        for (status, bitmap) in statuses {
+            self.update_status(wtxn, status, |tasks| *tasks |= &bitmap)?;
+        }
+        for (kind, bitmap) in kinds {
+            self.update_kind(wtxn, kind, |tasks| *tasks |= &bitmap)?;
+        }
+
+        Ok(())
+    }
+
+    /// Copy each index of the snapshot into an unregistered index, pushing the uid it must be
+    /// registered under and its uuid into `imported` as soon as it is created.
+    ///
+    /// The indexes are copied as they are, their documents don't need to be indexed again.
+    fn import_snapshot_indexes(
+        &self,
+        snapshot_path: &Path,
+        namespace: Option<&str>,
+        index_uids: &[String],
+        imported: &mut Vec<(String, Uuid)>,
+    ) -> Result<()> {
+        // the snapshot is unpacked next to it, it is removed once the indexes are copied
+        let temp_snapshot_dir = tempfile::tempdir_in(&self.dumps_path)?;
+        compression::from_tar_gz(snapshot_path, temp_snapshot_dir.path())?;
+        versioning::check_version_file(temp_snapshot_dir.path())?;
+
+        let index_mapping = snapshot_index_mapping(&temp_snapshot_dir.path().join("tasks"))?;
+        for (name, uuid) in index_mapping {
+            if self.must_stop_processing.get() {
+                return Err(Error::AbortedTask);
+            }
+            let index_uid = KindWithContent::dump_import_index_uid(namespace, &name);
+            // The snapshot was modified since the task was registered.
+            if !index_uids.contains(&index_uid) {
+                return Err(Error::CorruptedDump);
+            }
+            let data_path =
+                temp_snapshot_dir.path().join("indexes").join(uuid.to_string()).join("data.mdb");
+            let (uuid, _index) = self.index_mapper.create_unregistered_index_from(&data_path)?;
+            imported.push((index_uid, uuid));
         }
+
+        Ok(())
     }
 
     /// Swap the index `lhs` with the index `rhs`.
@@ -1237,6 +1713,67 @@ impl IndexScheduler {
         Ok(())
     }
 
+    /// Downloads the NDJSON file served at the URL and converts it into a documents batch
+    /// stored in a new update file. The download is aborted as soon as the batch must stop.
+    ///
+    /// Returns the uuid of the update file along with the number of documents it contains.
+    fn download_ndjson_documents(&self, url: &str) -> Result<(Uuid, u64)> {
+        let error = |reason: String| Error::DocumentUrlImport { url: url.to_string(), reason };
+        let max_size = self.document_url_import_max_size;
+
+        // The redirections are not followed as they could lead to a host that is not allowed.
+        let agent = ureq::AgentBuilder::new()
+            .redirects(0)
+            .timeout_connect(Duration::from_secs(30))
+            .timeout_read(Duration::from_secs(30))
+            .build();
+        let response = match agent.get(url).call() {
+            Ok(response) if (200..300).contains(&response.status()) => response,
+            Ok(response) | Err(ureq::Error::Status(_, response)) => {
+                return Err(error(format!(
+                    "the server responded with the status `{}`",
+                    response.status()
+                )))
+            }
+            Err(e) => return Err(error(format!("the server is unreachable: {e}"))),
+        };
+
+        let content_type = response.content_type();
+        if !matches!(content_type, "application/x-ndjson" | "application/ndjson") {
+            return Err(error(format!(
+                "the content type `{content_type}` is not supported, the file must be served as `application/x-ndjson`"
+            )));
+        }
+
+        let mut file = tempfile::tempfile()?;
+        let mut body = response.into_reader().take(max_size as u64 + 1);
+        let mut buffer = vec![0; 64 * 1024];
+        let mut size = 0;
+        loop {
+            if self.must_stop_processing.get() {
+                return Err(Error::AbortedTask);
+            }
+            let read = match body.read(&mut buffer) {
+                Ok(0) => break,
+                Ok(read) => read,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(error(format!("the download was interrupted: {e}"))),
+            };
+            file.write_all(&buffer[..read])?;
+            size += read as u64;
+        }
+        if size > max_size as u64 {
+            return Err(error(format!("the file is larger than the limit of {max_size} bytes")));
+        }
+
+        let (uuid, mut update_file) = self.file_store.new_update()?;
+        let documents_count =
+            read_ndjson(&file, &mut update_file).map_err(|e| error(e.to_string()))?;
+        update_file.persist()?;
+
+        Ok((uuid, documents_count))
+    }
+
     /// Process the index operation on the given index.
     ///
     /// ## Return
@@ -1324,7 +1861,7 @@ impl IndexScheduler {
                     index,
                     indexer_config,
                     config,
-                    |indexing_step| tracing::trace!(?indexing_step, "Update"),
+                    self.progress_callback(),
                     || must_stop_processing.get(),
                 )?;
 
@@ -1334,29 +1871,35 @@ impl IndexScheduler {
                             let content_file = self.file_store.get_update(content_uuid)?;
                             let reader = DocumentsBatchReader::from_reader(content_file)
                                 .map_err(milli::Error::from)?;
-                            let (new_builder, user_result) = builder.add_documents(reader)?;
+                            let (new_builder, user_result) =
+                                builder.add_documents_reporting_etag_conflicts(reader)?;
                             builder = new_builder;
 
                             builder = builder.with_embedders(embedders.clone());
 
-                            let received_documents =
+                            let (received_documents, skipped_documents) =
                                 if let Some(Details::DocumentAdditionOrUpdate {
                                     received_documents,
+                                    skipped_documents,
                                     ..
-                                }) = task.details
+                                }) = task.details.take()
                                 {
-                                    received_documents
+                                    (received_documents, skipped_documents)
                                 } else {
                                     // In the case of a `documentAdditionOrUpdate` the details MUST be set
                                     unreachable!();
                                 };
 
                             match user_result {
-                                Ok(count) => {
+                                Ok((count, etag_conflicts)) => {
                                     task.status = Status::Succeeded;
                                     task.details = Some(Details::DocumentAdditionOrUpdate {
                                         received_documents,
                                         indexed_documents: Some(count),
+                                        etag_conflicts: (!etag_conflicts.is_empty())
+                                            .then_some(etag_conflicts),
+                                        capped_facet_attributes: None,
+                                        skipped_documents,
                                     })
                                 }
                                 Err(e) => {
@@ -1364,17 +1907,27 @@ impl IndexScheduler {
                                     task.details = Some(Details::DocumentAdditionOrUpdate {
                                         received_documents,
                                         indexed_documents: Some(0),
+                                        etag_conflicts: None,
+                                        capped_facet_attributes: None,
+                                        skipped_documents,
                                     });
                                     task.error = Some(milli::Error::from(e).into());
                                 }
                             }
                         }
                         DocumentOperation::Delete(document_ids) => {
+                            let report_outcomes = matches!(
+                                task.kind,
+                                KindWithContent::DocumentDeletion { report_outcomes: true, .. }
+                            );
+                            // We only keep the requested ids around if we must report on them.
+                            let requested_ids = report_outcomes.then(|| document_ids.clone());
                             let (new_builder, user_result) =
-                                builder.remove_documents(document_ids)?;
+                                builder.remove_documents_reporting_ids(document_ids)?;
                             builder = new_builder;
                             // Uses Invariant: remove documents actually always returns Ok for the inner result
-                            let count = user_result.unwrap();
+                            let deleted_ids = user_result.unwrap();
+                            let count = deleted_ids.len() as u64;
                             let provided_ids =
                                 if let Some(Details::DocumentDeletion { provided_ids, .. }) =
                                     task.details
@@ -1385,18 +1938,48 @@ impl IndexScheduler {
                                     unreachable!();
                                 };
 
+                            let outcomes = requested_ids.map(|requested_ids| {
+                                let deleted_ids: HashSet<_> = deleted_ids.into_iter().collect();
+                                requested_ids
+                                    .into_iter()
+                                    .map(|document_id| DocumentDeletionOutcome {
+                                        deleted: deleted_ids.contains(&document_id),
+                                        document_id,
+                                    })
+                                    .collect()
+                            });
+
                             task.status = Status::Succeeded;
                             task.details = Some(Details::DocumentDeletion {
                                 provided_ids,
                                 deleted_documents: Some(count),
+                                outcomes,
                             });
                         }
                     }
                 }
 
                 if !tasks.iter().all(|res| res.error.is_some()) {
+                    let previously_capped = index.capped_faceted_fields(index_wtxn)?;
                     let addition = builder.execute()?;
                     tracing::info!(indexing_result = ?addition, processed_in = ?started_processing_at.elapsed(), "document indexing done");
+
+                    let newly_capped: Vec<String> = index
+                        .capped_faceted_fields(index_wtxn)?
+                        .difference(&previously_capped)
+                        .cloned()
+                        .collect();
+                    if !newly_capped.is_empty() {
+                        for task in tasks.iter_mut().filter(|task| task.error.is_none()) {
+                            if let Some(Details::DocumentAdditionOrUpdate {
+                                capped_facet_attributes,
+                                ..
+                            }) = &mut task.details
+                            {
+                                *capped_facet_attributes = Some(newly_capped.clone());
+                            }
+                        }
+                    }
                 } else if primary_key_has_been_set {
                     // Everything failed but we've set a primary key.
                     // We need to remove it.
@@ -1411,6 +1994,212 @@ impl IndexScheduler {
 
                 Ok(tasks)
             }
+            IndexOperation::DocumentTransaction { mut task, .. } => {
+                let (primary_key, content_uuid, operations) =
+                    if let KindWithContent::DocumentTransaction {
+                        primary_key,
+                        content_file,
+                        operations,
+                        ..
+                    } = &task.kind
+                    {
+                        (primary_key.clone(), *content_file, operations.clone())
+                    } else {
+                        unreachable!()
+                    };
+                let started_processing_at = std::time::Instant::now();
+                let must_stop_processing = self.must_stop_processing.clone();
+                let indexer_config = self.index_mapper.indexer_config();
+
+                // Unlike the other document operations, any error is returned right away to make
+                // the batch fail, the index write transaction is then aborted with all the
+                // operations of the transaction that were already applied.
+                if let Some(primary_key) = primary_key {
+                    match index.primary_key(index_wtxn)? {
+                        Some(pk) if primary_key != pk => {
+                            return Err(milli::Error::from(
+                                milli::UserError::PrimaryKeyCannotBeChanged(pk.to_string()),
+                            )
+                            .into());
+                        }
+                        Some(_) => (),
+                        None => {
+                            let mut builder =
+                                milli::update::Settings::new(index_wtxn, index, indexer_config);
+                            builder.set_primary_key(primary_key);
+                            builder.execute(
+                                |indexing_step| tracing::debug!(update = ?indexing_step),
+                                || must_stop_processing.clone().get(),
+                            )?;
+                        }
+                    }
+                }
+
+                let content_file = self.file_store.get_update(content_uuid)?;
+                let reader =
+                    DocumentsBatchReader::from_reader(content_file).map_err(milli::Error::from)?;
+                let (mut cursor, fields_index) = reader.into_cursor_and_fields_index();
+
+                let embedder_configs = index.embedding_configs(index_wtxn)?;
+                let embedders = self.embedders(embedder_configs)?;
+
+                let method_of = |operation: &DocumentTransactionOperation| match operation {
+                    DocumentTransactionOperation::Add { method, .. } => Some(*method),
+                    DocumentTransactionOperation::Delete { .. } => None,
+                };
+
+                let mut indexed_documents = 0;
+                let mut deleted_documents = 0;
+                let mut remaining = &operations[..];
+                while !remaining.is_empty() {
+                    // The operations are applied by the same builder until
+                    // an addition comes with another update method.
+                    let method = remaining
+                        .iter()
+                        .find_map(method_of)
+                        .unwrap_or(IndexDocumentsMethod::ReplaceDocuments);
+                    let end = remaining
+                        .iter()
+                        .position(|operation| method_of(operation).map_or(false, |m| m != method))
+                        .unwrap_or(remaining.len());
+                    let (run, rest) = remaining.split_at(end);
+                    remaining = rest;
+
+                    let config =
+                        IndexDocumentsConfig { update_method: method, ..Default::default() };
+                    let mut builder = milli::update::IndexDocuments::new(
+                        index_wtxn,
+                        index,
+                        indexer_config,
+                        config,
+                        self.progress_callback(),
+                        || must_stop_processing.get(),
+                    )?;
+
+                    for operation in run {
+                        match operation {
+                            DocumentTransactionOperation::Add { documents_count, .. } => {
+                                let mut documents = Vec::new();
+                                for _ in 0..*documents_count {
+                                    let document = cursor
+                                        .next_document()
+                                        .map_err(milli::Error::from)?
+                                        .ok_or(Error::CorruptedTaskQueue)?;
+                                    documents.push(obkv_to_object(&document, &fields_index)?);
+                                }
+                                let reader = documents_batch_reader_from_objects(documents);
+                                let (new_builder, user_result) = builder.add_documents(reader)?;
+                                builder = new_builder.with_embedders(embedders.clone());
+                                user_result.map_err(milli::Error::from)?;
+                            }
+                            DocumentTransactionOperation::Delete { documents_ids } => {
+                                let (new_builder, user_result) = builder
+                                    .remove_documents_reporting_ids(documents_ids.clone())?;
+                                builder = new_builder;
+                                // Uses Invariant: remove documents actually always returns Ok for the inner result
+                                deleted_documents += user_result.unwrap().len() as u64;
+                            }
+                        }
+                    }
+
+                    indexed_documents += builder.execute()?.indexed_documents;
+                }
+                tracing::info!(indexed_documents, deleted_documents, processed_in = ?started_processing_at.elapsed(), "document transaction done");
+
+                let (received_documents, provided_ids) =
+                    if let Some(Details::DocumentTransaction {
+                        received_documents,
+                        provided_ids,
+                        ..
+                    }) = task.details
+                    {
+                        (received_documents, provided_ids)
+                    } else {
+                        // In the case of a `documentTransaction` the details MUST be set
+                        unreachable!();
+                    };
+
+                task.status = Status::Succeeded;
+                task.details = Some(Details::DocumentTransaction {
+                    received_documents,
+                    indexed_documents: Some(indexed_documents),
+                    provided_ids,
+                    deleted_documents: Some(deleted_documents),
+                });
+
+                Ok(vec![task])
+            }
+            IndexOperation::DocumentUrlImport { mut task, content_file, .. } => {
+                let (url, primary_key) =
+                    if let KindWithContent::DocumentUrlImport { url, primary_key, .. } = &task.kind
+                    {
+                        (url.clone(), primary_key.clone())
+                    } else {
+                        unreachable!()
+                    };
+                let started_processing_at = std::time::Instant::now();
+                let must_stop_processing = self.must_stop_processing.clone();
+                let indexer_config = self.index_mapper.indexer_config();
+
+                // The errors are returned right away to make the task fail with
+                // the reason why its documents could not be downloaded or indexed.
+                if let Some(primary_key) = primary_key {
+                    match index.primary_key(index_wtxn)? {
+                        Some(pk) if primary_key != pk => {
+                            return Err(milli::Error::from(
+                                milli::UserError::PrimaryKeyCannotBeChanged(pk.to_string()),
+                            )
+                            .into());
+                        }
+                        Some(_) => (),
+                        None => {
+                            let mut builder =
+                                milli::update::Settings::new(index_wtxn, index, indexer_config);
+                            builder.set_primary_key(primary_key);
+                            builder.execute(
+                                |indexing_step| tracing::debug!(update = ?indexing_step),
+                                || must_stop_processing.clone().get(),
+                            )?;
+                        }
+                    }
+                }
+
+                let (content_uuid, received_documents) = content_file
+                    .expect("the documents are downloaded before the batch is processed");
+                let documents = self.file_store.get_update(content_uuid)?;
+                let reader =
+                    DocumentsBatchReader::from_reader(documents).map_err(milli::Error::from)?;
+
+                let embedder_configs = index.embedding_configs(index_wtxn)?;
+                let embedders = self.embedders(embedder_configs)?;
+
+                let config = IndexDocumentsConfig {
+                    update_method: IndexDocumentsMethod::ReplaceDocuments,
+                    ..Default::default()
+                };
+                let builder = milli::update::IndexDocuments::new(
+                    index_wtxn,
+                    index,
+                    indexer_config,
+                    config,
+                    self.progress_callback(),
+                    || must_stop_processing.get(),
+                )?;
+                let (builder, user_result) = builder.add_documents(reader)?;
+                user_result.map_err(milli::Error::from)?;
+                let indexed_documents =
+                    builder.with_embedders(embedders).execute()?.indexed_documents;
+                tracing::info!(indexed_documents, processed_in = ?started_processing_at.elapsed(), "document url import done");
+
+                task.status = Status::Succeeded;
+                task.details = Some(Details::DocumentUrlImport {
+                    url,
+                    received_documents: Some(received_documents),
+                    indexed_documents: Some(indexed_documents),
+                });
+
+                Ok(vec![task])
+            }
             IndexOperation::DocumentEdition { mut task, .. } => {
                 let (filter, context, function) =
                     if let KindWithContent::DocumentEdition {
@@ -1531,10 +2320,7 @@ impl IndexScheduler {
                 }
 
                 let must_stop_processing = self.must_stop_processing.clone();
-                builder.execute(
-                    |indexing_step| tracing::debug!(update = ?indexing_step),
-                    || must_stop_processing.get(),
-                )?;
+                builder.execute(self.progress_callback(), || must_stop_processing.get())?;
 
                 Ok(tasks)
             }
@@ -1626,6 +2412,7 @@ impl IndexScheduler {
         let mut affected_statuses = HashSet::new();
         let mut affected_kinds = HashSet::new();
         let mut affected_canceled_by = RoaringBitmap::new();
+        let mut affected_batches = RoaringBitmap::new();
 
         for task_id in to_delete_tasks.iter() {
             let task = self.get_task(wtxn, task_id)?.ok_or(Error::CorruptedTaskQueue)?;
@@ -1647,6 +2434,9 @@ impl IndexScheduler {
             if let Some(canceled_by) = task.canceled_by {
                 affected_canceled_by.insert(canceled_by);
             }
+            if let Some(batch_uid) = task.batch_uid {
+                affected_batches.insert(batch_uid);
+            }
         }
 
         for index in affected_indexes {
@@ -1663,6 +2453,7 @@ impl IndexScheduler {
 
         for task in to_delete_tasks.iter() {
             self.all_tasks.delete(wtxn, &task)?;
+            self.dead_letter_tasks.delete(wtxn, &task)?;
         }
         for canceled_by in affected_canceled_by {
             if let Some(mut tasks) = self.canceled_by.get(wtxn, &canceled_by)? {
@@ -1674,6 +2465,25 @@ impl IndexScheduler {
                 }
             }
         }
+        // the batches are kept as long as one of their tasks is
+        for batch_uid in affected_batches {
+            if let Some(mut tasks) = self.batch_to_tasks_mapping.get(wtxn, &batch_uid)? {
+                tasks -= &to_delete_tasks;
+                if tasks.is_empty() {
+                    self.batch_to_tasks_mapping.delete(wtxn, &batch_uid)?;
+                    if let Some(batch) = self.get_batch(wtxn, batch_uid)? {
+                        for index in batch.stats.index_uids.keys() {
+                            self.update_index_batches(wtxn, index, |bitmap| {
+                                bitmap.remove(batch_uid);
+                            })?;
+                        }
+                    }
+                    self.all_batches.delete(wtxn, &batch_uid)?;
+                } else {
+                    self.batch_to_tasks_mapping.put(wtxn, &batch_uid, &tasks)?;
+                }
+            }
+        }
 
         Ok(to_delete_tasks)
     }
@@ -1,5 +1,6 @@
 use std::fmt::Display;
 
+use meilisearch_types::batches::BatchId;
 use meilisearch_types::error::{Code, ErrorCode};
 use meilisearch_types::tasks::{Kind, Status};
 use meilisearch_types::{heed, milli};
@@ -67,6 +68,12 @@ pub enum Error {
     SwapIndexNotFound(String),
     #[error("Meilisearch cannot receive write operations because the limit of the task database has been reached. Please delete tasks to continue performing write operations.")]
     NoSpaceLeftInTaskQueue,
+    #[error("Meilisearch is in maintenance mode and cannot receive write operations. Please retry once the maintenance mode is disabled.")]
+    MaintenanceMode,
+    #[error("Meilisearch is shutting down and cannot receive write operations. Please retry once it is restarted.")]
+    ShuttingDown,
+    #[error("Index `{0}` is read-only. Disable its `readOnly` setting to modify its documents or its settings.")]
+    IndexReadOnly(String),
     #[error(
         "Indexes {} not found.",
         .0.iter().map(|s| format!("`{}`", s)).collect::<Vec<_>>().join(", ")
@@ -106,12 +113,18 @@ pub enum Error {
     InvalidIndexUid { index_uid: String },
     #[error("Task `{0}` not found.")]
     TaskNotFound(TaskId),
+    #[error("Batch uid `{batch_uid}` is invalid. It should only contain numeric characters.")]
+    InvalidBatchUids { batch_uid: String },
+    #[error("Batch `{0}` not found.")]
+    BatchNotFound(BatchId),
     #[error("Query parameters to filter the tasks to delete are missing. Available query parameters are: `uids`, `indexUids`, `statuses`, `types`, `canceledBy`, `beforeEnqueuedAt`, `afterEnqueuedAt`, `beforeStartedAt`, `afterStartedAt`, `beforeFinishedAt`, `afterFinishedAt`.")]
     TaskDeletionWithEmptyQuery,
     #[error("Query parameters to filter the tasks to cancel are missing. Available query parameters are: `uids`, `indexUids`, `statuses`, `types`, `canceledBy`, `beforeEnqueuedAt`, `afterEnqueuedAt`, `beforeStartedAt`, `afterStartedAt`, `beforeFinishedAt`, `afterFinishedAt`.")]
     TaskCancelationWithEmptyQuery,
     #[error("Aborted task")]
     AbortedTask,
+    #[error("The documents could not be imported from `{url}`: {reason}.")]
+    DocumentUrlImport { url: String, reason: String },
 
     #[error(transparent)]
     Dump(#[from] dump::Error),
@@ -169,6 +182,9 @@ impl Error {
             | Error::SwapDuplicateIndexesFound(_)
             | Error::SwapIndexNotFound(_)
             | Error::NoSpaceLeftInTaskQueue
+            | Error::MaintenanceMode
+            | Error::ShuttingDown
+            | Error::IndexReadOnly(_)
             | Error::SwapIndexesNotFound(_)
             | Error::CorruptedDump
             | Error::InvalidTaskDate { .. }
@@ -178,9 +194,12 @@ impl Error {
             | Error::InvalidTaskCanceledBy { .. }
             | Error::InvalidIndexUid { .. }
             | Error::TaskNotFound(_)
+            | Error::InvalidBatchUids { .. }
+            | Error::BatchNotFound(_)
             | Error::TaskDeletionWithEmptyQuery
             | Error::TaskCancelationWithEmptyQuery
             | Error::AbortedTask
+            | Error::DocumentUrlImport { .. }
             | Error::Dump(_)
             | Error::Heed(_)
             | Error::Milli(_)
@@ -199,6 +218,23 @@ impl Error {
         }
     }
 
+    /// Whether processing the same batch again could succeed.
+    ///
+    /// The errors caused by the content of the tasks, like an invalid document, are permanent:
+    /// retrying the batch would fail the same way.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Error::IoError(_)
+            | Error::Persist(_)
+            | Error::Heed(heed::Error::Io(_))
+            | Error::Milli(milli::Error::IoError(_))
+            | Error::FileStore(file_store::Error::IoError(_))
+            | Error::FileStore(file_store::Error::PersistError(_)) => true,
+            Error::WithCustomErrorCode(_, error) => error.is_transient(),
+            _ => false,
+        }
+    }
+
     pub fn with_custom_error_code(self, code: Code) -> Self {
         Self::WithCustomErrorCode(code, Box::new(self))
     }
@@ -222,10 +258,16 @@ impl ErrorCode for Error {
             Error::InvalidTaskCanceledBy { .. } => Code::InvalidTaskCanceledBy,
             Error::InvalidIndexUid { .. } => Code::InvalidIndexUid,
             Error::TaskNotFound(_) => Code::TaskNotFound,
+            Error::InvalidBatchUids { .. } => Code::InvalidBatchUids,
+            Error::BatchNotFound(_) => Code::BatchNotFound,
             Error::TaskDeletionWithEmptyQuery => Code::MissingTaskFilters,
             Error::TaskCancelationWithEmptyQuery => Code::MissingTaskFilters,
             // TODO: not sure of the Code to use
             Error::NoSpaceLeftInTaskQueue => Code::NoSpaceLeftOnDevice,
+            Error::MaintenanceMode => Code::MaintenanceMode,
+            Error::ShuttingDown => Code::ShuttingDown,
+            Error::IndexReadOnly(_) => Code::IndexReadOnly,
+            Error::DocumentUrlImport { .. } => Code::DocumentUrlImportFailed,
             Error::Dump(e) => e.error_code(),
             Error::Milli(e) => e.error_code(),
             Error::ProcessBatchPanicked => Code::Internal,
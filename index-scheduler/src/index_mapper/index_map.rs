@@ -382,6 +382,25 @@ mod tests {
         assert_index_size(index, mapper.index_base_map_size + mapper.index_growth_amount * 2);
     }
 
+    #[test]
+    fn create_index_with_map_size_hint() {
+        let map_size_hint = 10 * 1000 * 1000; // 10 MB, bigger than the base map size of the tests.
+        let (index_scheduler, _handle) =
+            IndexScheduler::test_with_custom_config(vec![], |options| {
+                options.index_map_size_hint = Some(map_size_hint);
+            });
+        let (mapper, env) = (index_scheduler.index_mapper, index_scheduler.env);
+
+        let index = mapper.create_index(env.write_txn().unwrap(), "index", None).unwrap();
+        assert_index_size(index, map_size_hint);
+
+        // Exceeding the hint still grows the index.
+        mapper.resize_index(&env.read_txn().unwrap(), "index").unwrap();
+
+        let index = mapper.create_index(env.write_txn().unwrap(), "index", None).unwrap();
+        assert_index_size(index, map_size_hint + mapper.index_growth_amount);
+    }
+
     fn assert_index_size(index: Index, expected: usize) {
         let expected = clamp_to_page_size(expected);
         let index_map_size = index.map_size();
@@ -1,12 +1,12 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, RwLock};
 use std::time::Duration;
 use std::{fs, thread};
 
 use meilisearch_types::heed::types::{SerdeJson, Str};
-use meilisearch_types::heed::{Database, Env, RoTxn, RwTxn};
+use meilisearch_types::heed::{Database, Env, EnvOpenOptions, RoTxn, RwTxn};
 use meilisearch_types::milli::update::IndexerConfig;
-use meilisearch_types::milli::{FieldDistribution, Index};
+use meilisearch_types::milli::{DatabasesSizes, FieldDistribution, Index};
 use serde::{Deserialize, Serialize};
 use time::OffsetDateTime;
 use tracing::error;
@@ -14,6 +14,7 @@ use uuid::Uuid;
 
 use self::index_map::IndexMap;
 use self::IndexStatus::{Available, BeingDeleted, Closing, Missing};
+use crate::utils::clamp_to_page_size;
 use crate::uuid_codec::UuidCodec;
 use crate::{Error, Result};
 
@@ -22,6 +23,35 @@ mod index_map;
 const INDEX_MAPPING: &str = "index-mapping";
 const INDEX_STATS: &str = "index-stats";
 
+/// Returns the names and uuids of the indexes registered in the env of the scheduler of a
+/// snapshot, unpacked in `tasks_path`.
+pub fn snapshot_index_mapping(tasks_path: &Path) -> Result<Vec<(String, Uuid)>> {
+    let data_size = fs::metadata(tasks_path.join("data.mdb"))?.len() as usize;
+    let env = unsafe {
+        EnvOpenOptions::new()
+            .map_size(clamp_to_page_size(data_size) + page_size::get())
+            .max_dbs(1)
+            .open(tasks_path)
+    }?;
+
+    let mapping = (|| -> Result<Vec<(String, Uuid)>> {
+        let rtxn = env.read_txn()?;
+        let Some(index_mapping) =
+            env.open_database::<Str, UuidCodec>(&rtxn, Some(INDEX_MAPPING))?
+        else {
+            return Ok(Vec::new());
+        };
+        let mut mapping = Vec::new();
+        for result in index_mapping.iter(&rtxn)? {
+            let (name, uuid) = result?;
+            mapping.push((name.to_string(), uuid));
+        }
+        Ok(mapping)
+    })();
+    env.prepare_for_closing().wait();
+    mapping
+}
+
 /// Structure managing meilisearch's indexes.
 ///
 /// It is responsible for:
@@ -64,6 +94,8 @@ pub struct IndexMapper {
     base_path: PathBuf,
     /// The map size an index is opened with on the first time.
     index_base_map_size: usize,
+    /// The map size an index should be opened with, when larger than `index_base_map_size`.
+    index_map_size_hint: Option<usize>,
     /// The quantity by which the map size of an index is incremented upon reopening, in bytes.
     index_growth_amount: usize,
     /// Whether we open a meilisearch index with the MDB_WRITEMAP option or not.
@@ -105,6 +137,9 @@ pub struct IndexStats {
     /// As the DB backend does not return to the disk the pages that are not currently used by the DB,
     /// this value is typically smaller than `database_size`.
     pub used_database_size: u64,
+    /// Size of the pages used by each group of databases of the index, in bytes.
+    #[serde(default)]
+    pub databases_sizes: DatabasesSizes,
     /// Association of every field name with the number of times it occurs in the documents.
     pub field_distribution: FieldDistribution,
     /// Creation date of the index.
@@ -124,6 +159,7 @@ impl IndexStats {
             number_of_documents: index.number_of_documents(rtxn)?,
             database_size: index.on_disk_size()?,
             used_database_size: index.used_size()?,
+            databases_sizes: index.databases_sizes(rtxn)?,
             field_distribution: index.field_distribution(rtxn)?,
             created_at: index.created_at(rtxn)?,
             updated_at: index.updated_at(rtxn)?,
@@ -136,6 +172,7 @@ impl IndexMapper {
         env: &Env,
         base_path: PathBuf,
         index_base_map_size: usize,
+        index_map_size_hint: Option<usize>,
         index_growth_amount: usize,
         index_count: usize,
         enable_mdb_writemap: bool,
@@ -152,6 +189,7 @@ impl IndexMapper {
             index_stats,
             base_path,
             index_base_map_size,
+            index_map_size_hint,
             index_growth_amount,
             enable_mdb_writemap,
             indexer_config: Arc::new(indexer_config),
@@ -186,7 +224,7 @@ impl IndexMapper {
                     &index_path,
                     date,
                     self.enable_mdb_writemap,
-                    self.index_base_map_size,
+                    self.index_map_size(),
                 )?;
 
                 wtxn.commit()?;
@@ -197,6 +235,99 @@ impl IndexMapper {
         }
     }
 
+    /// Create an index that can't be reached by its name until [`Self::register_index`]
+    /// is called, which allows to fill it before anyone can see it.
+    pub fn create_unregistered_index(
+        &self,
+        date: Option<(OffsetDateTime, OffsetDateTime)>,
+    ) -> Result<(Uuid, Index)> {
+        let uuid = Uuid::new_v4();
+        let index_path = self.base_path.join(uuid.to_string());
+        fs::create_dir_all(&index_path)?;
+
+        let index = self.index_map.write().unwrap().create(
+            &uuid,
+            &index_path,
+            date,
+            self.enable_mdb_writemap,
+            self.index_map_size(),
+        );
+        match index {
+            Ok(index) => Ok((uuid, index)),
+            Err(e) => {
+                let _ = fs::remove_dir_all(&index_path);
+                Err(e)
+            }
+        }
+    }
+
+    /// Create an index from a copy of the `data.mdb` file of another index, like the ones of a
+    /// snapshot, without giving it a name. See [`Self::create_unregistered_index`].
+    pub fn create_unregistered_index_from(&self, data_path: &Path) -> Result<(Uuid, Index)> {
+        let uuid = Uuid::new_v4();
+        let index_path = self.base_path.join(uuid.to_string());
+        fs::create_dir_all(&index_path)?;
+
+        let copied = fs::copy(data_path, index_path.join("data.mdb")).map_err(Error::from);
+        // LMDB grows the map to the size of the copied file when it is larger.
+        let index = copied.and_then(|_| {
+            self.index_map.write().unwrap().create(
+                &uuid,
+                &index_path,
+                None,
+                self.enable_mdb_writemap,
+                self.index_map_size(),
+            )
+        });
+        match index {
+            Ok(index) => Ok((uuid, index)),
+            Err(e) => {
+                let _ = fs::remove_dir_all(&index_path);
+                Err(e)
+            }
+        }
+    }
+
+    /// Give a name to an index created by [`Self::create_unregistered_index`].
+    /// The index becomes visible once the transaction is committed.
+    ///
+    /// If another index already has this name, it is replaced and its uuid is returned.
+    /// It must then be removed with [`Self::discard_unregistered_index`] once the transaction
+    /// is committed.
+    pub fn register_index(
+        &self,
+        wtxn: &mut RwTxn,
+        name: &str,
+        uuid: &Uuid,
+    ) -> Result<Option<Uuid>> {
+        let replaced = self.index_mapping.get(wtxn, name)?;
+        if let Some(replaced) = replaced {
+            self.index_stats.delete(wtxn, &replaced)?;
+        }
+        self.index_mapping.put(wtxn, name, uuid)?;
+        Ok(replaced)
+    }
+
+    /// Close and remove from the disk an index created by [`Self::create_unregistered_index`]
+    /// that will never be registered.
+    pub fn discard_unregistered_index(&self, uuid: &Uuid) -> Result<()> {
+        let closing_event = self.index_map.write().unwrap().start_deletion(uuid);
+        if let Ok(Some(closing_event)) = closing_event {
+            closing_event.wait();
+        }
+        fs::remove_dir_all(self.base_path.join(uuid.to_string()))?;
+        self.index_map.write().unwrap().end_deletion(uuid);
+        Ok(())
+    }
+
+    /// The map size an index is opened with on the first time, taking the size hint into account.
+    fn index_map_size(&self) -> usize {
+        match self.index_map_size_hint {
+            Some(hint) => hint.max(self.index_base_map_size),
+            None => self.index_base_map_size,
+        }
+    }
+
     /// Removes the index from the mapping table and the in-memory index map
     /// but keeps the associated tasks.
     pub fn delete_index(&self, mut wtxn: RwTxn, name: &str) -> Result<()> {
@@ -375,7 +506,7 @@ impl IndexMapper {
                                 &index_path,
                                 None,
                                 self.enable_mdb_writemap,
-                                self.index_base_map_size,
+                                self.index_map_size(),
                             )?;
                         }
                         Available(index) => break index,
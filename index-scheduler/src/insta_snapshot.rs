@@ -31,13 +31,25 @@ pub fn snapshot_index_scheduler(scheduler: &IndexScheduler) -> String {
         enqueued_at,
         started_at,
         finished_at,
+        all_batches: _,
+        batch_to_tasks_mapping: _,
+        index_batches: _,
+        dead_letter_tasks: _,
         index_mapper,
         features: _,
+        webhooks: _,
+        search_events: _,
         max_number_of_tasks: _,
         max_number_of_batched_tasks: _,
+        document_url_import_max_size: _,
+        max_task_retries: _,
+        task_retry_backoff: _,
+        task_retention: _,
+        transient_failures: _,
         wake_up: _,
+        processed_batch: _,
         dumps_path: _,
-        snapshots_path: _,
+        snapshot_settings: _,
         auth_path: _,
         version_file_path: _,
         webhook_url: _,
@@ -46,6 +58,8 @@ pub fn snapshot_index_scheduler(scheduler: &IndexScheduler) -> String {
         planned_failures: _,
         run_loop_iteration: _,
         embedders: _,
+        maintenance: _,
+        shutting_down: _,
     } = scheduler;
 
     let rtxn = env.read_txn().unwrap();
@@ -153,6 +167,7 @@ pub fn snapshot_task(task: &Task) -> String {
         details,
         status,
         kind,
+        batch_uid: _,
     } = task;
     snap.push('{');
     snap.push_str(&format!("uid: {uid}, "));
@@ -177,9 +192,32 @@ fn snapshot_details(d: &Details) -> String {
         Details::DocumentAdditionOrUpdate {
             received_documents,
             indexed_documents,
+            etag_conflicts: None,
+            capped_facet_attributes: None,
+            skipped_documents: None,
         } => {
             format!("{{ received_documents: {received_documents}, indexed_documents: {indexed_documents:?} }}")
         }
+        Details::DocumentAdditionOrUpdate {
+            received_documents,
+            indexed_documents,
+            etag_conflicts,
+            capped_facet_attributes,
+            skipped_documents,
+        } => {
+            format!("{{ received_documents: {received_documents}, indexed_documents: {indexed_documents:?}, etag_conflicts: {etag_conflicts:?}, capped_facet_attributes: {capped_facet_attributes:?}, skipped_documents: {skipped_documents:?} }}")
+        }
+        Details::DocumentTransaction {
+            received_documents,
+            indexed_documents,
+            provided_ids,
+            deleted_documents,
+        } => {
+            format!("{{ received_documents: {received_documents}, indexed_documents: {indexed_documents:?}, received_document_ids: {provided_ids}, deleted_documents: {deleted_documents:?} }}")
+        }
+        Details::DocumentUrlImport { url, received_documents, indexed_documents } => {
+            format!("{{ url: {url:?}, received_documents: {received_documents:?}, indexed_documents: {indexed_documents:?} }}")
+        }
         Details::DocumentEdition {
             deleted_documents,
             edited_documents,
@@ -200,7 +238,13 @@ fn snapshot_details(d: &Details) -> String {
         Details::DocumentDeletion {
             provided_ids: received_document_ids,
             deleted_documents,
+            outcomes: None,
         } => format!("{{ received_document_ids: {received_document_ids}, deleted_documents: {deleted_documents:?} }}"),
+        Details::DocumentDeletion {
+            provided_ids: received_document_ids,
+            deleted_documents,
+            outcomes: Some(outcomes),
+        } => format!("{{ received_document_ids: {received_document_ids}, deleted_documents: {deleted_documents:?}, outcomes: {outcomes:?} }}"),
         Details::DocumentDeletionByFilter { original_filter, deleted_documents } => format!(
            "{{ original_filter: {original_filter}, deleted_documents: {deleted_documents:?} }}"
         ),
@@ -224,6 +268,9 @@ fn snapshot_details(d: &Details) -> String {
         Details::Dump { dump_uid } => {
             format!("{{ dump_uid: {dump_uid:?} }}")
         },
+        Details::DumpImport { dump_uid, namespace, imported_indexes } => {
+            format!("{{ dump_uid: {dump_uid:?}, namespace: {namespace:?}, imported_indexes: {imported_indexes:?} }}")
+        },
         Details::IndexSwap { swaps } => {
             format!("{{ swaps: {swaps:?} }}")
         }
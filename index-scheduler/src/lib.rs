@@ -26,54 +26,104 @@ mod index_mapper;
 #[cfg(test)]
 mod insta_snapshot;
 mod lru;
+mod search_events;
+mod snapshot_settings;
 mod utils;
 pub mod uuid_codec;
+mod webhooks;
 
 pub type Result<T> = std::result::Result<T, Error>;
 pub type TaskId = u32;
 
 use std::collections::{BTreeMap, HashMap};
-use std::io::{self, BufReader, Read};
+use std::io::{self, Write};
 use std::ops::{Bound, RangeBounds};
 use std::panic::{catch_unwind, AssertUnwindSafe};
 use std::path::{Path, PathBuf};
 use std::sync::atomic::Ordering::{self, Relaxed};
 use std::sync::atomic::{AtomicBool, AtomicU32};
-use std::sync::{Arc, RwLock};
-use std::time::Duration;
+use std::sync::{Arc, Condvar, Mutex, RwLock};
+use std::time::{Duration, Instant};
 
 use dump::{KindDump, TaskDump, UpdateFile};
 pub use error::Error;
 pub use features::RoFeatures;
 use file_store::FileStore;
-use flate2::bufread::GzEncoder;
+use flate2::write::GzEncoder;
 use flate2::Compression;
+use meilisearch_types::batches::{Batch, BatchId, BatchStats};
+use meilisearch_types::document_formats::SkippedDocument;
 use meilisearch_types::error::ResponseError;
 use meilisearch_types::features::{InstanceTogglableFeatures, RuntimeTogglableFeatures};
 use meilisearch_types::heed::byteorder::BE;
-use meilisearch_types::heed::types::{SerdeBincode, SerdeJson, Str, I128};
+use meilisearch_types::heed::types::{DecodeIgnore, SerdeBincode, SerdeJson, Str, I128};
 use meilisearch_types::heed::{self, Database, Env, PutFlags, RoTxn, RwTxn};
 use meilisearch_types::milli::documents::DocumentsBatchBuilder;
 use meilisearch_types::milli::index::IndexEmbeddingConfig;
-use meilisearch_types::milli::update::IndexerConfig;
+use meilisearch_types::milli::update::{IndexerConfig, Setting, UpdateIndexingStep};
 use meilisearch_types::milli::vector::{Embedder, EmbedderOptions, EmbeddingConfigs};
 use meilisearch_types::milli::{self, CboRoaringBitmapCodec, Index, RoaringBitmapCodec, BEU32};
 use meilisearch_types::task_view::TaskView;
-use meilisearch_types::tasks::{Kind, KindWithContent, Status, Task};
+use meilisearch_types::tasks::{Details, Kind, KindWithContent, Status, Task};
+use meilisearch_types::{compression, versioning, VERSION_FILE_NAME};
 use rayon::current_num_threads;
 use rayon::prelude::{IntoParallelIterator, ParallelIterator};
 use roaring::RoaringBitmap;
+pub use search_events::SearchEvent;
+pub use snapshot_settings::{
+    SnapshotSettings, DEFAULT_SNAPSHOT_COMPRESSION_LEVEL, MAX_SNAPSHOT_COMPRESSION_LEVEL,
+};
 use synchronoise::SignalEvent;
 use time::format_description::well_known::Rfc3339;
 use time::OffsetDateTime;
-use utils::{filter_out_references_to_newer_tasks, keep_tasks_within_datetimes, map_bound};
+use utils::{
+    filter_out_references_to_newer_tasks, is_batch_authorized, keep_tasks_within_datetimes,
+    map_bound,
+};
 use uuid::Uuid;
+pub use webhooks::Webhook;
 
 use crate::index_mapper::IndexMapper;
 use crate::utils::{check_index_swap_validity, clamp_to_page_size};
 
 pub(crate) type BEI128 = I128<BE>;
 
+/// How often the expired tasks are looked for while no task is enqueued.
+const TASK_RETENTION_CHECK_INTERVAL: Duration = Duration::from_secs(60 * 60);
+/// How often the end of the processing batch is checked for during a shutdown.
+const SHUTDOWN_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Wakes up the ones waiting for their tasks to be processed, once per processed batch.
+#[derive(Debug, Default)]
+pub struct ProcessedBatchSignal {
+    processed_batches: Mutex<u64>,
+    condvar: Condvar,
+}
+
+impl ProcessedBatchSignal {
+    /// The number of batches processed since the scheduler started.
+    pub fn processed_batches(&self) -> u64 {
+        *self.processed_batches.lock().unwrap()
+    }
+
+    /// Blocks until more than `processed_batches` batches are processed or the timeout is reached.
+    ///
+    /// Returns the number of batches processed when it stopped waiting.
+    pub fn wait(&self, processed_batches: u64, timeout: Duration) -> u64 {
+        let guard = self.processed_batches.lock().unwrap();
+        let (guard, _) = self
+            .condvar
+            .wait_timeout_while(guard, timeout, |processed| *processed <= processed_batches)
+            .unwrap();
+        *guard
+    }
+
+    fn signal(&self) {
+        *self.processed_batches.lock().unwrap() += 1;
+        self.condvar.notify_all();
+    }
+}
+
 /// Defines a subset of tasks to be retrieved from the [`IndexScheduler`].
 ///
 /// An empty/default query (where each field is set to `None`) matches all tasks.
@@ -96,13 +146,16 @@ pub struct Query {
     /// # }
     /// ```
     pub types: Option<Vec<Kind>>,
-    /// The allowed [index ids](meilisearch_types::tasks::Task::index_uid) of the matched tasks
+    /// The allowed [index ids](meilisearch_types::tasks::Task::index_uid) of the matched tasks,
+    /// an index id ending with a `*` matches all the index ids starting with what precedes it.
     pub index_uids: Option<Vec<String>>,
     /// The [task ids](`meilisearch_types::tasks::Task::uid`) to be matched
     pub uids: Option<Vec<TaskId>>,
     /// The [task ids](`meilisearch_types::tasks::Task::uid`) of the [`TaskCancelation`](meilisearch_types::tasks::Task::Kind::TaskCancelation) tasks
     /// that canceled the matched tasks.
     pub canceled_by: Option<Vec<TaskId>>,
+    /// The [batch ids](`meilisearch_types::batches::Batch::uid`) in which the matched tasks were processed.
+    pub batch_uids: Option<Vec<BatchId>>,
     /// Exclusive upper bound of the matched tasks' [`enqueued_at`](meilisearch_types::tasks::Task::enqueued_at) field.
     pub before_enqueued_at: Option<OffsetDateTime>,
     /// Exclusive lower bound of the matched tasks' [`enqueued_at`](meilisearch_types::tasks::Task::enqueued_at) field.
@@ -131,6 +184,7 @@ impl Query {
                 index_uids: None,
                 uids: None,
                 canceled_by: None,
+                batch_uids: None,
                 before_enqueued_at: None,
                 after_enqueued_at: None,
                 before_started_at: None,
@@ -161,22 +215,47 @@ struct ProcessingTasks {
     started_at: OffsetDateTime,
     /// The list of tasks ids that are currently running.
     processing: RoaringBitmap,
+    /// The batch of the running tasks, with the steps it already went through.
+    batch: Option<Batch>,
+    /// The last indexing step reported by milli while processing the batch.
+    progress: Option<UpdateIndexingStep>,
 }
 
 impl ProcessingTasks {
     /// Creates an empty `ProcessingAt` struct.
     fn new() -> ProcessingTasks {
-        ProcessingTasks { started_at: OffsetDateTime::now_utc(), processing: RoaringBitmap::new() }
+        ProcessingTasks {
+            started_at: OffsetDateTime::now_utc(),
+            processing: RoaringBitmap::new(),
+            batch: None,
+            progress: None,
+        }
     }
 
-    /// Stores the currently processing tasks, and the date time at which it started.
-    fn start_processing_at(&mut self, started_at: OffsetDateTime, processing: RoaringBitmap) {
+    /// Stores the currently processing tasks, their batch, and the date time at which it started.
+    fn start_processing_at(
+        &mut self,
+        started_at: OffsetDateTime,
+        processing: RoaringBitmap,
+        batch: Batch,
+    ) {
         self.started_at = started_at;
         self.processing = processing;
+        self.batch = Some(batch);
+        self.progress = None;
+    }
+
+    /// Starts a new step of the processing batch, finishing the current one.
+    fn start_batch_step(&mut self, name: &str) {
+        if let Some(batch) = &mut self.batch {
+            batch.start_step(name, OffsetDateTime::now_utc());
+        }
     }
 
     /// Set the processing tasks to an empty list
     fn stop_processing(&mut self) -> RoaringBitmap {
+        self.batch = None;
+        self.progress = None;
         std::mem::take(&mut self.processing)
     }
 
@@ -213,6 +292,34 @@ mod db_name {
     pub const ENQUEUED_AT: &str = "enqueued-at";
     pub const STARTED_AT: &str = "started-at";
     pub const FINISHED_AT: &str = "finished-at";
+    pub const ALL_BATCHES: &str = "all-batches";
+    pub const BATCH_TO_TASKS_MAPPING: &str = "batch-to-tasks-mapping";
+    pub const INDEX_BATCHES: &str = "index-batches";
+    pub const DEAD_LETTER_TASKS: &str = "dead-letter-tasks";
+}
+
+/// The names of the steps a batch goes through, reported by the `/batches` routes.
+mod batch_step {
+    use meilisearch_types::milli::update::UpdateIndexingStep;
+
+    pub const CREATING_BATCH: &str = "creating the batch";
+    pub const PROCESSING_TASKS: &str = "processing the tasks";
+    pub const REMAPPING_DOCUMENTS: &str = "remapping the documents";
+    pub const COMPUTING_DOCUMENTS_IDS: &str = "computing the documents ids";
+    pub const INDEXING_DOCUMENTS: &str = "indexing the documents";
+    pub const WRITING_TASKS: &str = "writing the tasks";
+
+    /// The step of the batch during which milli reports the given indexing step.
+    ///
+    /// The documents are extracted while the databases are written, both are a single step.
+    pub fn indexing_step(indexing_step: &UpdateIndexingStep) -> &'static str {
+        match indexing_step {
+            UpdateIndexingStep::RemapDocumentAddition { .. } => REMAPPING_DOCUMENTS,
+            UpdateIndexingStep::ComputeIdsAndMergeDocuments { .. } => COMPUTING_DOCUMENTS_IDS,
+            UpdateIndexingStep::IndexDocuments { .. }
+            | UpdateIndexingStep::MergeDataIntoFinalDatabase { .. } => INDEXING_DOCUMENTS,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -228,6 +335,7 @@ pub enum Breakpoint {
     AbortedIndexation,
     ProcessBatchSucceeded,
     ProcessBatchFailed,
+    ProcessBatchRetried,
     InsideProcessBatch,
 }
 
@@ -239,12 +347,16 @@ pub struct IndexSchedulerOptions {
     pub auth_path: PathBuf,
     /// The path to the folder containing the task databases.
     pub tasks_path: PathBuf,
+    /// The path to the folder containing the LMDB env of the search events.
+    pub search_events_path: PathBuf,
     /// The path to the file store containing the files associated to the tasks.
     pub update_file_path: PathBuf,
     /// The path to the folder containing meilisearch's indexes.
     pub indexes_path: PathBuf,
     /// The path to the folder containing the snapshots.
     pub snapshots_path: PathBuf,
+    /// The number of seconds between two scheduled snapshots, `None` when they are disabled.
+    pub snapshot_interval: Option<u64>,
     /// The path to the folder containing the dumps.
     pub dumps_path: PathBuf,
     /// The URL on which we must send the tasks statuses
@@ -255,6 +367,10 @@ pub struct IndexSchedulerOptions {
     pub task_db_size: usize,
     /// The size, in bytes, with which a meilisearch index is opened the first time of each meilisearch index.
     pub index_base_map_size: usize,
+    /// The size, in bytes, the indexes should be opened with when it is larger than `index_base_map_size`.
+    ///
+    /// The indexes are still resized when they outgrow it.
+    pub index_map_size_hint: Option<usize>,
     /// Whether we open a meilisearch index with the MDB_WRITEMAP option or not.
     pub enable_mdb_writemap: bool,
     /// The size, in bytes, by which the map size of an index is increased when it resized due to being full.
@@ -275,6 +391,17 @@ pub struct IndexSchedulerOptions {
     /// If the autobatcher is allowed to automatically batch tasks
     /// it will only batch this defined number of tasks at once.
     pub max_number_of_batched_tasks: usize,
+    /// The maximum size, in bytes, of the files downloaded by the document URL imports.
+    pub document_url_import_max_size: usize,
+    /// The number of times a batch failing with a transient error is processed again
+    /// before its tasks are marked as failed.
+    pub max_task_retries: u32,
+    /// The time waited before the first retry of a batch, doubled on every following retry.
+    pub task_retry_backoff: Duration,
+    /// The finished tasks older than this are automatically deleted, `None` to keep them.
+    pub task_retention: Option<Duration>,
+    /// The number of most recent searches kept in the search events, 0 to not record them.
+    pub search_events_capacity: usize,
     /// The experimental features enabled for this instance.
     pub instance_features: InstanceTogglableFeatures,
 }
@@ -317,15 +444,33 @@ pub struct IndexScheduler {
     /// Store the task ids of tasks which finished at a specific date
     pub(crate) finished_at: Database<BEI128, CboRoaringBitmapCodec>,
 
+    /// All the finished batches accessible by their Id.
+    pub(crate) all_batches: Database<BEU32, SerdeJson<Batch>>,
+    /// Store the task ids of the tasks processed in a batch.
+    pub(crate) batch_to_tasks_mapping: Database<BEU32, RoaringBitmapCodec>,
+    /// Store the batch ids of the batches that processed tasks of an index.
+    pub(crate) index_batches: Database<Str, RoaringBitmapCodec>,
+    /// Store the number of attempts of the tasks that failed once all their retries were exhausted.
+    pub(crate) dead_letter_tasks: Database<BEU32, BEU32>,
+
     /// In charge of creating, opening, storing and returning indexes.
     pub(crate) index_mapper: IndexMapper,
 
     /// In charge of fetching and setting the status of experimental features.
     features: features::FeatureData,
 
+    /// The webhooks registered at runtime, notified after every batch.
+    webhooks: webhooks::WebhooksData,
+
+    /// The most recent searches, aggregated by the `/stats/searches` route.
+    search_events: search_events::SearchEventsData,
+
     /// Get a signal when a batch needs to be processed.
     pub(crate) wake_up: Arc<SignalEvent>,
 
+    /// Sends a signal once a batch is processed.
+    processed_batch: Arc<ProcessedBatchSignal>,
+
     /// Whether auto-batching is enabled or not.
     pub(crate) autobatching_enabled: bool,
 
@@ -339,6 +484,21 @@ pub struct IndexScheduler {
     /// The maximum number of tasks that will be batched together.
     pub(crate) max_number_of_batched_tasks: usize,
 
+    /// The maximum size of the files downloaded by the document URL imports.
+    pub(crate) document_url_import_max_size: usize,
+
+    /// The number of times a batch failing with a transient error is retried.
+    pub(crate) max_task_retries: u32,
+
+    /// The time waited before the first retry of a batch.
+    pub(crate) task_retry_backoff: Duration,
+
+    /// The time after which the finished tasks are automatically deleted.
+    pub(crate) task_retention: Option<Duration>,
+
+    /// The batch that last failed with a transient error, retried once its backoff elapsed.
+    transient_failures: Arc<RwLock<Option<TransientFailure>>>,
+
     /// The webhook url we should send tasks to after processing every batches.
     pub(crate) webhook_url: Option<String>,
     /// The Authorization header to send to the webhook URL.
@@ -347,8 +507,8 @@ pub struct IndexScheduler {
     /// The path used to create the dumps.
     pub(crate) dumps_path: PathBuf,
 
-    /// The path used to create the snapshots.
-    pub(crate) snapshots_path: PathBuf,
+    /// The settings of the snapshots, starting with the path used to create them.
+    pub(crate) snapshot_settings: snapshot_settings::SnapshotSettingsData,
 
     /// The path to the folder containing the auth LMDB env.
     pub(crate) auth_path: PathBuf,
@@ -358,6 +518,14 @@ pub struct IndexScheduler {
 
     embedders: Arc<RwLock<HashMap<EmbedderOptions, Arc<Embedder>>>>,
 
+    /// Whether the scheduler refuses to register new tasks.
+    /// The tasks already enqueued keep being processed.
+    maintenance: Arc<AtomicBool>,
+
+    /// Whether the scheduler refuses to register new tasks and to start new batches
+    /// because Meilisearch is shutting down.
+    shutting_down: Arc<AtomicBool>,
+
     // ================= test
     // The next entry is dedicated to the tests.
     /// Provide a way to set a breakpoint in multiple part of the scheduler.
@@ -392,19 +560,31 @@ impl IndexScheduler {
             enqueued_at: self.enqueued_at,
             started_at: self.started_at,
             finished_at: self.finished_at,
+            all_batches: self.all_batches,
+            batch_to_tasks_mapping: self.batch_to_tasks_mapping,
+            index_batches: self.index_batches,
+            dead_letter_tasks: self.dead_letter_tasks,
             index_mapper: self.index_mapper.clone(),
             wake_up: self.wake_up.clone(),
+            processed_batch: self.processed_batch.clone(),
             autobatching_enabled: self.autobatching_enabled,
             cleanup_enabled: self.cleanup_enabled,
             max_number_of_tasks: self.max_number_of_tasks,
             max_number_of_batched_tasks: self.max_number_of_batched_tasks,
-            snapshots_path: self.snapshots_path.clone(),
+            document_url_import_max_size: self.document_url_import_max_size,
+            max_task_retries: self.max_task_retries,
+            task_retry_backoff: self.task_retry_backoff,
+            task_retention: self.task_retention,
+            transient_failures: self.transient_failures.clone(),
+            snapshot_settings: self.snapshot_settings.clone(),
             dumps_path: self.dumps_path.clone(),
             auth_path: self.auth_path.clone(),
             version_file_path: self.version_file_path.clone(),
             webhook_url: self.webhook_url.clone(),
             webhook_authorization_header: self.webhook_authorization_header.clone(),
             embedders: self.embedders.clone(),
+            maintenance: self.maintenance.clone(),
+            shutting_down: self.shutting_down.clone(),
             #[cfg(test)]
             test_breakpoint_sdr: self.test_breakpoint_sdr.clone(),
             #[cfg(test)]
@@ -412,6 +592,8 @@ impl IndexScheduler {
             #[cfg(test)]
             run_loop_iteration: self.run_loop_iteration.clone(),
             features: self.features.clone(),
+            webhooks: self.webhooks.clone(),
+            search_events: self.search_events.clone(),
         }
     }
 }
@@ -451,12 +633,21 @@ impl IndexScheduler {
 
         let env = unsafe {
             heed::EnvOpenOptions::new()
-                .max_dbs(11)
+                .max_dbs(17)
                 .map_size(budget.task_db_size)
                 .open(options.tasks_path)
         }?;
 
         let features = features::FeatureData::new(&env, options.instance_features)?;
+        let webhooks = webhooks::WebhooksData::new(&env)?;
+        let search_events = search_events::SearchEventsData::new(
+            &options.search_events_path,
+            options.search_events_capacity,
+        )?;
+        let snapshot_settings = snapshot_settings::SnapshotSettingsData::new(
+            &env,
+            SnapshotSettings::new(options.snapshots_path, options.snapshot_interval),
+        )?;
 
         let file_store = FileStore::new(&options.update_file_path)?;
 
@@ -469,6 +660,11 @@ impl IndexScheduler {
         let enqueued_at = env.create_database(&mut wtxn, Some(db_name::ENQUEUED_AT))?;
         let started_at = env.create_database(&mut wtxn, Some(db_name::STARTED_AT))?;
         let finished_at = env.create_database(&mut wtxn, Some(db_name::FINISHED_AT))?;
+        let all_batches = env.create_database(&mut wtxn, Some(db_name::ALL_BATCHES))?;
+        let batch_to_tasks_mapping =
+            env.create_database(&mut wtxn, Some(db_name::BATCH_TO_TASKS_MAPPING))?;
+        let index_batches = env.create_database(&mut wtxn, Some(db_name::INDEX_BATCHES))?;
+        let dead_letter_tasks = env.create_database(&mut wtxn, Some(db_name::DEAD_LETTER_TASKS))?;
         wtxn.commit()?;
 
         // allow unreachable_code to get rids of the warning in the case of a test build.
@@ -484,10 +680,15 @@ impl IndexScheduler {
             enqueued_at,
             started_at,
             finished_at,
+            all_batches,
+            batch_to_tasks_mapping,
+            index_batches,
+            dead_letter_tasks,
             index_mapper: IndexMapper::new(
                 &env,
                 options.indexes_path,
                 budget.map_size,
+                options.index_map_size_hint,
                 options.index_growth_amount,
                 budget.index_count,
                 options.enable_mdb_writemap,
@@ -496,17 +697,25 @@ impl IndexScheduler {
             env,
             // we want to start the loop right away in case meilisearch was ctrl+Ced while processing things
             wake_up: Arc::new(SignalEvent::auto(true)),
+            processed_batch: Arc::default(),
             autobatching_enabled: options.autobatching_enabled,
             cleanup_enabled: options.cleanup_enabled,
             max_number_of_tasks: options.max_number_of_tasks,
             max_number_of_batched_tasks: options.max_number_of_batched_tasks,
+            document_url_import_max_size: options.document_url_import_max_size,
+            max_task_retries: options.max_task_retries,
+            task_retry_backoff: options.task_retry_backoff,
+            task_retention: options.task_retention,
+            transient_failures: Default::default(),
             dumps_path: options.dumps_path,
-            snapshots_path: options.snapshots_path,
+            snapshot_settings,
             auth_path: options.auth_path,
             version_file_path: options.version_file_path,
             webhook_url: options.webhook_url,
             webhook_authorization_header: options.webhook_authorization_header,
             embedders: Default::default(),
+            maintenance: Default::default(),
+            shutting_down: Default::default(),
 
             #[cfg(test)]
             test_breakpoint_sdr,
@@ -515,6 +724,8 @@ impl IndexScheduler {
             #[cfg(test)]
             run_loop_iteration: Arc::new(RwLock::new(0)),
             features,
+            webhooks,
+            search_events,
         };
 
         this.run();
@@ -528,6 +739,33 @@ impl IndexScheduler {
         Ok(())
     }
 
+    /// The percentage of the task database in use, the new tasks are refused above 50%.
+    pub fn task_queue_usage(&self) -> Result<u64> {
+        Ok((self.env.non_free_pages_size()? * 100) / self.env.info().map_size as u64)
+    }
+
+    /// Returns the signal sent once a batch is processed, to wait for a task without polling it.
+    pub fn processed_batch_signal(&self) -> Arc<ProcessedBatchSignal> {
+        self.processed_batch.clone()
+    }
+
+    /// The uid of the next registered task, the tasks below it that can't be found were deleted.
+    pub fn next_task_uid(&self) -> Result<TaskId> {
+        let rtxn = self.env.read_txn()?;
+        self.next_task_id(&rtxn)
+    }
+
+    /// Return `true` if the last finished task of this kind failed.
+    ///
+    /// The canceled tasks are ignored, they don't tell whether the task works.
+    pub fn last_task_failed(&self, kind: Kind) -> Result<bool> {
+        let rtxn = self.env.read_txn()?;
+        let failed = self.get_status(&rtxn, Status::Failed)?;
+        let finished = self.get_status(&rtxn, Status::Succeeded)? | &failed;
+        let last = (self.get_kind(&rtxn, kind)? & finished).max();
+        Ok(last.map_or(false, |task_id| failed.contains(task_id)))
+    }
+
     fn index_budget(
         tasks_path: &Path,
         base_map_size: usize,
@@ -619,7 +857,16 @@ impl IndexScheduler {
                     let ret = catch_unwind(AssertUnwindSafe(|| run.tick()));
                     match ret {
                         Ok(Ok(TickOutcome::TickAgain(_))) => (),
-                        Ok(Ok(TickOutcome::WaitForSignal)) => run.wake_up.wait(),
+                        Ok(Ok(TickOutcome::WaitUntil(instant))) => {
+                            run.wake_up.wait_timeout(instant.saturating_duration_since(Instant::now()));
+                        }
+                        Ok(Ok(TickOutcome::WaitForSignal)) => match run.task_retention {
+                            // the tasks must expire even when no new task is enqueued
+                            Some(_) => {
+                                run.wake_up.wait_timeout(TASK_RETENTION_CHECK_INTERVAL);
+                            }
+                            None => run.wake_up.wait(),
+                        },
                         Ok(Err(e)) => {
                             tracing::error!("{e}");
                             // Wait one second when an irrecoverable error occurs.
@@ -748,6 +995,16 @@ impl IndexScheduler {
             }
         }
 
+        if let Some(batch_uids) = &query.batch_uids {
+            let mut batch_tasks = RoaringBitmap::new();
+            for batch_uid in batch_uids {
+                if let Some(tasks) = self.batch_to_tasks_mapping.get(rtxn, batch_uid)? {
+                    batch_tasks |= tasks;
+                }
+            }
+            tasks &= batch_tasks;
+        }
+
         if let Some(kind) = &query.types {
             let mut kind_tasks = RoaringBitmap::new();
             for kind in kind {
@@ -759,7 +1016,15 @@ impl IndexScheduler {
         if let Some(index) = &query.index_uids {
             let mut index_tasks = RoaringBitmap::new();
             for index in index {
-                index_tasks |= self.index_tasks(rtxn, index)?;
+                match index.strip_suffix('*') {
+                    Some(prefix) => {
+                        for result in self.index_tasks.prefix_iter(rtxn, prefix)? {
+                            let (_, tasks) = result?;
+                            index_tasks |= tasks;
+                        }
+                    }
+                    None => index_tasks |= self.index_tasks(rtxn, index)?,
+                }
             }
             tasks &= &index_tasks;
         }
@@ -977,6 +1242,130 @@ impl IndexScheduler {
         }
     }
 
+    /// Return the last indexing step of the task, if it is processing and its indexing started.
+    ///
+    /// The tasks of a batch are indexed together, they all report the progress of their batch.
+    pub fn task_progress(&self, task_uid: TaskId) -> Option<UpdateIndexingStep> {
+        let processing_tasks = self.processing_tasks.read().unwrap();
+        processing_tasks.progress.filter(|_| processing_tasks.processing.contains(task_uid))
+    }
+
+    /// Returns the callback given to milli to report the indexing steps of the processing tasks.
+    pub(crate) fn progress_callback(&self) -> impl Fn(UpdateIndexingStep) + Sync {
+        let processing_tasks = self.processing_tasks.clone();
+        move |indexing_step| {
+            tracing::trace!(?indexing_step, "Update");
+            let mut processing_tasks = processing_tasks.write().unwrap();
+            // the batch records how long each indexing step lasted
+            let name = batch_step::indexing_step(&indexing_step);
+            let new_step = processing_tasks
+                .batch
+                .as_ref()
+                .and_then(|batch| batch.current_step())
+                .map_or(true, |step| step.name != name);
+            if new_step {
+                processing_tasks.start_batch_step(name);
+            }
+            processing_tasks.progress = Some(indexing_step);
+        }
+    }
+
+    /// Return the batches from the most recent to the oldest, starting at the `from` batch,
+    /// along with the total number of batches by ignoring the from and limit parameters.
+    ///
+    /// The currently processing batch comes first. The batches that didn't process any task of
+    /// an index the user can access are skipped.
+    pub fn get_batches_from_authorized_indexes(
+        &self,
+        from: Option<BatchId>,
+        limit: u32,
+        filters: &meilisearch_auth::AuthFilter,
+    ) -> Result<(Vec<Batch>, u64)> {
+        let rtxn = self.env.read_txn()?;
+        let processing_batch = self
+            .processing_tasks
+            .read()
+            .map_err(|_| Error::CorruptedTaskQueue)?
+            .batch
+            .clone()
+            .filter(|batch| is_batch_authorized(batch, filters));
+
+        // the restricted users only see the batches of their indexes, found without reading the others
+        let authorized_batches = if filters.all_indexes_authorized() {
+            None
+        } else {
+            let mut batches = RoaringBitmap::new();
+            for entry in self.index_batches.iter(&rtxn)? {
+                let (index, index_batches) = entry?;
+                if filters.is_index_authorized(index) {
+                    batches |= index_batches;
+                }
+            }
+            Some(batches)
+        };
+
+        let total = match &authorized_batches {
+            Some(batches) => batches.len(),
+            None => self.all_batches.len(&rtxn)?,
+        } + processing_batch.is_some() as u64;
+
+        let mut batches = Vec::new();
+        if let Some(batch) =
+            processing_batch.filter(|batch| from.map_or(true, |from| batch.uid <= from))
+        {
+            batches.push(batch);
+        }
+        let limit = (limit as usize).saturating_sub(batches.len());
+        let batch_uids: Vec<BatchId> = match authorized_batches {
+            Some(authorized) => authorized
+                .iter()
+                .rev()
+                .filter(|uid| from.map_or(true, |from| *uid <= from))
+                .take(limit)
+                .collect(),
+            None => {
+                let range = ..=from.unwrap_or(BatchId::MAX);
+                let mut uids = Vec::new();
+                for entry in self
+                    .all_batches
+                    .remap_data_type::<DecodeIgnore>()
+                    .rev_range(&rtxn, &range)?
+                    .take(limit)
+                {
+                    let (batch_uid, ()) = entry?;
+                    uids.push(batch_uid);
+                }
+                uids
+            }
+        };
+        for batch_uid in batch_uids {
+            batches.push(self.get_batch(&rtxn, batch_uid)?.ok_or(Error::CorruptedTaskQueue)?);
+        }
+
+        Ok((batches, total))
+    }
+
+    /// Return the batch, finished or currently processing, if the user can access one of its indexes.
+    pub fn get_batch_from_authorized_indexes(
+        &self,
+        batch_uid: BatchId,
+        filters: &meilisearch_auth::AuthFilter,
+    ) -> Result<Option<Batch>> {
+        let rtxn = self.env.read_txn()?;
+        let processing_batch =
+            self.processing_tasks.read().map_err(|_| Error::CorruptedTaskQueue)?.batch.clone();
+
+        let batch = match processing_batch {
+            Some(batch) if batch.uid == batch_uid => batch,
+            _ => match self.get_batch(&rtxn, batch_uid)? {
+                Some(batch) => batch,
+                None => return Ok(None),
+            },
+        };
+
+        Ok(Some(batch).filter(|batch| is_batch_authorized(batch, filters)))
+    }
+
     /// Register a new task in the scheduler.
     ///
     /// If it fails and data was associated with the task, it tries to delete the associated data.
@@ -986,6 +1375,59 @@ impl IndexScheduler {
         task_id: Option<TaskId>,
         dry_run: bool,
     ) -> Result<Task> {
+        self.register_document_addition(kind, Vec::new(), task_id, dry_run)
+    }
+
+    /// Register a new task like [`Self::register`], reporting in the details of a document
+    /// addition the documents skipped while reading its payload.
+    pub fn register_document_addition(
+        &self,
+        kind: KindWithContent,
+        skipped_documents: Vec<SkippedDocument>,
+        task_id: Option<TaskId>,
+        dry_run: bool,
+    ) -> Result<Task> {
+        // in maintenance mode, only the dry runs that write nothing are accepted
+        if !dry_run && self.is_in_maintenance() {
+            return Err(Error::MaintenanceMode);
+        }
+        if !dry_run && self.is_shutting_down() {
+            return Err(Error::ShuttingDown);
+        }
+
+        let mut details = kind.default_details();
+        if let Some(Details::DocumentAdditionOrUpdate { skipped_documents: skipped, .. }) =
+            &mut details
+        {
+            *skipped = (!skipped_documents.is_empty()).then_some(skipped_documents);
+        }
+        self.register_with_details(kind, details, task_id, dry_run)
+    }
+
+    /// Register a task enqueued by the scheduler itself, such as the deletions cleaning up
+    /// the task queue.
+    ///
+    /// Unlike [`Self::register`], the task is registered in maintenance mode too, since they
+    /// are required to drain the task queue.
+    fn register_internal_task(
+        &self,
+        kind: KindWithContent,
+        task_id: Option<TaskId>,
+        dry_run: bool,
+    ) -> Result<Task> {
+        let details = kind.default_details();
+        self.register_with_details(kind, details, task_id, dry_run)
+    }
+
+    fn register_with_details(
+        &self,
+        kind: KindWithContent,
+        details: Option<Details>,
+        task_id: Option<TaskId>,
+        dry_run: bool,
+    ) -> Result<Task> {
+        self.check_index_read_only(&kind)?;
+
         let mut wtxn = self.env.write_txn()?;
 
         // if the task doesn't delete anything and 50% of the task queue is full, we must refuse to enqueue the incomming task
@@ -1010,9 +1452,10 @@ impl IndexScheduler {
             finished_at: None,
             error: None,
             canceled_by: None,
-            details: kind.default_details(),
+            details,
             status: Status::Enqueued,
             kind: kind.clone(),
+            batch_uid: None,
         };
         // For deletion and cancelation tasks, we want to make extra sure that they
         // don't attempt to delete/cancel tasks that are newer than themselves.
@@ -1085,6 +1528,145 @@ impl IndexScheduler {
         Ok(index)
     }
 
+    /// Converts a task of a dump into a task of the scheduler, with the same uid.
+    ///
+    /// The content file of an enqueued task is copied into the file store.
+    pub(crate) fn task_from_dump(
+        &self,
+        task: TaskDump,
+        content_file: Option<Box<UpdateFile>>,
+    ) -> Result<Task> {
+        let content_uuid = match content_file {
+            Some(content_file) if task.status == Status::Enqueued => {
+                let (uuid, mut file) = self.create_update_file(false)?;
+                let mut builder = DocumentsBatchBuilder::new(&mut file);
+                for doc in content_file {
+                    builder.append_json_object(&doc?)?;
+                }
+                builder.into_inner()?;
+                file.persist()?;
+
+                Some(uuid)
+            }
+            // If the task isn't `Enqueued` then just generate a recognisable `Uuid`
+            // in case we try to open it later.
+            _ if task.status != Status::Enqueued => Some(Uuid::nil()),
+            _ => None,
+        };
+
+        Ok(Task {
+            uid: task.uid,
+            enqueued_at: task.enqueued_at,
+            started_at: task.started_at,
+            finished_at: task.finished_at,
+            error: task.error,
+            canceled_by: task.canceled_by,
+            details: task.details,
+            status: task.status,
+            kind: match task.kind {
+                KindDump::DocumentImport {
+                    primary_key,
+                    method,
+                    documents_count,
+                    allow_index_creation,
+                } => KindWithContent::DocumentAdditionOrUpdate {
+                    index_uid: task.index_uid.ok_or(Error::CorruptedDump)?,
+                    primary_key,
+                    method,
+                    content_file: content_uuid.ok_or(Error::CorruptedDump)?,
+                    documents_count,
+                    allow_index_creation,
+                },
+                KindDump::DocumentTransaction { primary_key, operations, allow_index_creation } => {
+                    let content_file = match content_uuid {
+                        Some(uuid) => uuid,
+                        // The dump doesn't contain the content file of the transactions
+                        // that don't add any document, we must recreate an empty one.
+                        None => {
+                            let (uuid, mut file) = self.create_update_file(false)?;
+                            DocumentsBatchBuilder::new(&mut file).into_inner()?;
+                            file.persist()?;
+                            uuid
+                        }
+                    };
+                    KindWithContent::DocumentTransaction {
+                        index_uid: task.index_uid.ok_or(Error::CorruptedDump)?,
+                        primary_key,
+                        content_file,
+                        operations,
+                        allow_index_creation,
+                    }
+                }
+                KindDump::DocumentUrlImport { url, primary_key, allow_index_creation } => {
+                    KindWithContent::DocumentUrlImport {
+                        index_uid: task.index_uid.ok_or(Error::CorruptedDump)?,
+                        url,
+                        primary_key,
+                        allow_index_creation,
+                    }
+                }
+                KindDump::DocumentDeletion { documents_ids, report_outcomes } => {
+                    KindWithContent::DocumentDeletion {
+                        documents_ids,
+                        index_uid: task.index_uid.ok_or(Error::CorruptedDump)?,
+                        report_outcomes,
+                    }
+                }
+                KindDump::DocumentDeletionByFilter { filter } => {
+                    KindWithContent::DocumentDeletionByFilter {
+                        filter_expr: filter,
+                        index_uid: task.index_uid.ok_or(Error::CorruptedDump)?,
+                    }
+                }
+                KindDump::DocumentEdition { filter, context, function } => {
+                    KindWithContent::DocumentEdition {
+                        index_uid: task.index_uid.ok_or(Error::CorruptedDump)?,
+                        filter_expr: filter,
+                        context,
+                        function,
+                    }
+                }
+                KindDump::DocumentClear => KindWithContent::DocumentClear {
+                    index_uid: task.index_uid.ok_or(Error::CorruptedDump)?,
+                },
+                KindDump::Settings { settings, is_deletion, allow_index_creation } => {
+                    KindWithContent::SettingsUpdate {
+                        index_uid: task.index_uid.ok_or(Error::CorruptedDump)?,
+                        new_settings: settings,
+                        is_deletion,
+                        allow_index_creation,
+                    }
+                }
+                KindDump::IndexDeletion => KindWithContent::IndexDeletion {
+                    index_uid: task.index_uid.ok_or(Error::CorruptedDump)?,
+                },
+                KindDump::IndexCreation { primary_key } => KindWithContent::IndexCreation {
+                    index_uid: task.index_uid.ok_or(Error::CorruptedDump)?,
+                    primary_key,
+                },
+                KindDump::IndexUpdate { primary_key } => KindWithContent::IndexUpdate {
+                    index_uid: task.index_uid.ok_or(Error::CorruptedDump)?,
+                    primary_key,
+                },
+                KindDump::IndexSwap { swaps } => KindWithContent::IndexSwap { swaps },
+                KindDump::TaskCancelation { query, tasks } => {
+                    KindWithContent::TaskCancelation { query, tasks }
+                }
+                KindDump::TasksDeletion { query, tasks } => {
+                    KindWithContent::TaskDeletion { query, tasks }
+                }
+                KindDump::DumpCreation { keys, instance_uid, settings_only, since } => {
+                    KindWithContent::DumpCreation { keys, instance_uid, settings_only, since }
+                }
+                KindDump::DumpImport { dump_uid, namespace, index_uids } => {
+                    KindWithContent::DumpImport { dump_uid, namespace, index_uids }
+                }
+                KindDump::SnapshotCreation => KindWithContent::SnapshotCreation,
+            },
+            batch_uid: None,
+        })
+    }
+
     /// Create a file and register it in the index scheduler.
     ///
     /// The returned file and uuid can be used to associate
@@ -1135,10 +1717,17 @@ impl IndexScheduler {
             self.breakpoint(Breakpoint::Start);
         }
 
+        // the enqueued tasks are processed on the next launch
+        if self.is_shutting_down() {
+            return Ok(TickOutcome::WaitForSignal);
+        }
+
         if self.cleanup_enabled {
             self.cleanup_task_queue()?;
+            self.delete_expired_tasks()?;
         }
 
+        let batch_started_at = OffsetDateTime::now_utc();
         let rtxn = self.env.read_txn().map_err(Error::HeedTransaction)?;
         let batch =
             match self.create_next_batch(&rtxn).map_err(|e| Error::CreateBatch(Box::new(e)))? {
@@ -1146,16 +1735,39 @@ impl IndexScheduler {
                 None => return Ok(TickOutcome::WaitForSignal),
             };
         let index_uid = batch.index_uid().map(ToOwned::to_owned);
+        let ids = batch.ids();
+
+        // a batch retried after a transient failure waits for its backoff to elapse, the
+        // scheduler can still be woken up to process the cancelations in the meantime
+        if let Some(failure) = *self.transient_failures.read().unwrap() {
+            if ids.contains(failure.oldest) && Instant::now() < failure.not_before {
+                return Ok(TickOutcome::WaitUntil(failure.not_before));
+            }
+        }
+
+        let batch_uid = self.next_batch_id(&rtxn)?;
+        let mut processing_batch = Batch::new(batch_uid, batch_started_at);
+        processing_batch.stats = BatchStats::from_tasks(&self.get_existing_tasks(&rtxn, &ids)?);
+        processing_batch.stats.status = BTreeMap::from([(Status::Processing, ids.len() as u32)]);
+        processing_batch.start_step(batch_step::CREATING_BATCH, batch_started_at);
         drop(rtxn);
 
         // 1. store the starting date with the bitmap of processing tasks.
-        let ids = batch.ids();
         let processed_tasks = ids.len();
         let started_at = OffsetDateTime::now_utc();
+        processing_batch.start_step(batch_step::PROCESSING_TASKS, started_at);
 
         // We reset the must_stop flag to be sure that we don't stop processing tasks
         self.must_stop_processing.reset();
-        self.processing_tasks.write().unwrap().start_processing_at(started_at, ids.clone());
+        {
+            let mut processing_tasks = self.processing_tasks.write().unwrap();
+            // checked with the lock held, so a shutdown started while the batch was created
+            // either sees the batch as processing or prevents it from starting
+            if self.is_shutting_down() {
+                return Ok(TickOutcome::WaitForSignal);
+            }
+            processing_tasks.start_processing_at(started_at, ids.clone(), processing_batch);
+        }
 
         #[cfg(test)]
         self.breakpoint(Breakpoint::BatchCreated);
@@ -1172,6 +1784,7 @@ impl IndexScheduler {
 
         // Reset the currently updating index to relinquish the index handle
         self.index_mapper.set_currently_updating_index(None);
+        self.processing_tasks.write().unwrap().start_batch_step(batch_step::WRITING_TASKS);
 
         #[cfg(test)]
         self.maybe_fail(tests::FailureLocation::AcquiringWtxn)?;
@@ -1179,6 +1792,8 @@ impl IndexScheduler {
         let mut wtxn = self.env.write_txn().map_err(Error::HeedTransaction)?;
 
         let finished_at = OffsetDateTime::now_utc();
+        let mut batch_stats = BatchStats::default();
+        let mut batch_tasks = RoaringBitmap::new();
         match res {
             Ok(tasks) => {
                 #[cfg(test)]
@@ -1191,6 +1806,7 @@ impl IndexScheduler {
                 for (i, mut task) in tasks.into_iter().enumerate() {
                     task.started_at = Some(started_at);
                     task.finished_at = Some(finished_at);
+                    task.batch_uid = Some(batch_uid);
 
                     #[cfg(test)]
                     self.maybe_fail(
@@ -1206,6 +1822,8 @@ impl IndexScheduler {
 
                     self.update_task(&mut wtxn, &task)
                         .map_err(|e| Error::TaskDatabaseUpdate(Box::new(e)))?;
+                    batch_stats.register(&task);
+                    batch_tasks.insert(task.uid);
                 }
                 tracing::info!("A batch of tasks was successfully completed with {success} successful tasks and {failure} failed tasks.");
             }
@@ -1244,10 +1862,28 @@ impl IndexScheduler {
 
                 return Ok(TickOutcome::TickAgain(0));
             }
+            // If the error may not happen again we process the same tasks again,
+            // up to the number of retries allowed.
+            Err(err) if err.is_transient() && self.must_retry_batch(&ids) => {
+                #[cfg(test)]
+                self.breakpoint(Breakpoint::ProcessBatchRetried);
+                wtxn.abort();
+                self.processing_tasks.write().unwrap().stop_processing();
+
+                let retries = self.transient_failures.read().unwrap().map_or(0, |f| f.retries);
+                tracing::warn!(
+                    "A batch of tasks failed with a transient error, retrying it ({retries}/{}): {err}",
+                    self.max_task_retries
+                );
+
+                return Ok(TickOutcome::TickAgain(0));
+            }
             // In case of a failure we must get back and patch all the tasks with the error.
             Err(err) => {
                 #[cfg(test)]
                 self.breakpoint(Breakpoint::ProcessBatchFailed);
+                // the tasks that kept failing with a transient error exhausted their retries
+                let exhausted_retries = err.is_transient();
                 let error: ResponseError = err.into();
                 for id in ids.iter() {
                     let mut task = self
@@ -1259,6 +1895,7 @@ impl IndexScheduler {
                     task.status = Status::Failed;
                     task.error = Some(error.clone());
                     task.details = task.details.map(|d| d.to_failed());
+                    task.batch_uid = Some(batch_uid);
 
                     #[cfg(test)]
                     self.maybe_fail(tests::FailureLocation::UpdatingTaskAfterProcessBatchFailure)?;
@@ -1267,11 +1904,40 @@ impl IndexScheduler {
 
                     self.update_task(&mut wtxn, &task)
                         .map_err(|e| Error::TaskDatabaseUpdate(Box::new(e)))?;
+                    if exhausted_retries {
+                        self.dead_letter_tasks.put(
+                            &mut wtxn,
+                            &task.uid,
+                            &self.max_task_retries.saturating_add(1),
+                        )?;
+                    }
+                    batch_stats.register(&task);
+                    batch_tasks.insert(task.uid);
                 }
             }
         }
 
-        let processed = self.processing_tasks.write().unwrap().stop_processing();
+        // the retried batch was either processed or failed for good, its next retries start over
+        {
+            let mut transient_failures = self.transient_failures.write().unwrap();
+            if transient_failures.map_or(false, |failure| ids.contains(failure.oldest)) {
+                *transient_failures = None;
+            }
+        }
+        let mut processing_tasks = self.processing_tasks.write().unwrap();
+        if let Some(mut batch) = processing_tasks.batch.take() {
+            batch.stats = batch_stats;
+            batch.finish(OffsetDateTime::now_utc());
+            self.all_batches.put(&mut wtxn, &batch_uid, &batch)?;
+            self.batch_to_tasks_mapping.put(&mut wtxn, &batch_uid, &batch_tasks)?;
+            for index in batch.stats.index_uids.keys() {
+                self.update_index_batches(&mut wtxn, index, |bitmap| {
+                    bitmap.insert(batch_uid);
+                })?;
+            }
+        }
+        let processed = processing_tasks.stop_processing();
+        drop(processing_tasks);
 
         #[cfg(test)]
         self.maybe_fail(tests::FailureLocation::CommittingWtxn)?;
@@ -1300,6 +1966,8 @@ impl IndexScheduler {
             Ok(())
         })?;
 
+        self.processed_batch.signal();
+
         // We shouldn't crash the tick function if we can't send data to the webhook.
         let _ = self.notify_webhook(&processed);
 
@@ -1309,83 +1977,61 @@ impl IndexScheduler {
         Ok(TickOutcome::TickAgain(processed_tasks))
     }
 
-    /// Once the tasks changes have been committed we must send all the tasks that were updated to our webhook if there is one.
-    fn notify_webhook(&self, updated: &RoaringBitmap) -> Result<()> {
-        if let Some(ref url) = self.webhook_url {
-            struct TaskReader<'a, 'b> {
-                rtxn: &'a RoTxn<'a>,
-                index_scheduler: &'a IndexScheduler,
-                tasks: &'b mut roaring::bitmap::Iter<'b>,
-                buffer: Vec<u8>,
-                written: usize,
-            }
-
-            impl<'a, 'b> Read for TaskReader<'a, 'b> {
-                fn read(&mut self, mut buf: &mut [u8]) -> std::io::Result<usize> {
-                    if self.buffer.is_empty() {
-                        match self.tasks.next() {
-                            None => return Ok(0),
-                            Some(task_id) => {
-                                let task = self
-                                    .index_scheduler
-                                    .get_task(self.rtxn, task_id)
-                                    .map_err(|err| io::Error::new(io::ErrorKind::Other, err))?
-                                    .ok_or_else(|| {
-                                        io::Error::new(
-                                            io::ErrorKind::Other,
-                                            Error::CorruptedTaskQueue,
-                                        )
-                                    })?;
-
-                                serde_json::to_writer(
-                                    &mut self.buffer,
-                                    &TaskView::from_task(&task),
-                                )?;
-                                self.buffer.push(b'\n');
-                            }
-                        }
-                    }
-
-                    let mut to_write = &self.buffer[self.written..];
-                    let wrote = io::copy(&mut to_write, &mut buf)?;
-                    self.written += wrote as usize;
-
-                    // we wrote everything and must refresh our buffer on the next call
-                    if self.written == self.buffer.len() {
-                        self.written = 0;
-                        self.buffer.clear();
-                    }
-
-                    Ok(wrote as usize)
-                }
-            }
-
-            let rtxn = self.env.read_txn()?;
+    /// Counts one more retry of the batch containing the given tasks.
+    ///
+    /// Returns `false` once the batch was retried as many times as allowed. The batches are
+    /// identified by their oldest task, which is always part of the next batch created.
+    fn must_retry_batch(&self, ids: &RoaringBitmap) -> bool {
+        let Some(oldest) = ids.min() else { return false };
+        let mut transient_failures = self.transient_failures.write().unwrap();
+        let retries = match *transient_failures {
+            Some(failure) if failure.oldest == oldest => failure.retries + 1,
+            _ => 1,
+        };
+        if retries > self.max_task_retries {
+            return false;
+        }
+        let backoff = self.task_retry_backoff * 2u32.saturating_pow(retries - 1);
+        *transient_failures =
+            Some(TransientFailure { oldest, retries, not_before: Instant::now() + backoff });
+        true
+    }
 
-            let task_reader = TaskReader {
-                rtxn: &rtxn,
-                index_scheduler: self,
-                tasks: &mut updated.into_iter(),
-                buffer: Vec::with_capacity(50), // on average a task is around ~100 bytes
-                written: 0,
-            };
+    /// Returns the ids of the tasks that failed once all their retries were exhausted.
+    pub fn dead_letter_tasks(&self, rtxn: &RoTxn) -> Result<RoaringBitmap> {
+        let mut tasks = RoaringBitmap::new();
+        for entry in self.dead_letter_tasks.remap_data_type::<DecodeIgnore>().iter(rtxn)? {
+            let (task, ()) = entry?;
+            tasks.insert(task);
+        }
+        Ok(tasks)
+    }
 
-            // let reader = GzEncoder::new(BufReader::new(task_reader), Compression::default());
-            let reader = GzEncoder::new(BufReader::new(task_reader), Compression::default());
-            let request = ureq::post(url)
-                .timeout(Duration::from_secs(30))
-                .set("Content-Encoding", "gzip")
-                .set("Content-Type", "application/x-ndjson");
-            let request = match &self.webhook_authorization_header {
-                Some(header) => request.set("Authorization", header),
-                None => request,
-            };
+    /// Once the tasks changes have been committed we must send all the tasks that were updated to our webhooks.
+    ///
+    /// The tasks are serialized here, but sent by the thread of the webhooks.
+    fn notify_webhook(&self, updated: &RoaringBitmap) -> Result<()> {
+        let instance_webhook = self.webhook_url.as_ref().map(|url| Webhook {
+            url: url.clone(),
+            authorization: self.webhook_authorization_header.clone(),
+        });
+        let webhooks: Vec<_> =
+            instance_webhook.into_iter().chain(self.webhooks.webhooks()).collect();
+        if webhooks.is_empty() {
+            return Ok(());
+        }
 
-            if let Err(e) = request.send(reader) {
-                tracing::error!("While sending data to the webhook: {e}");
-            }
+        let rtxn = self.env.read_txn()?;
+        let mut payload = GzEncoder::new(Vec::new(), Compression::default());
+        for task_id in updated {
+            let task = self.get_task(&rtxn, task_id)?.ok_or(Error::CorruptedTaskQueue)?;
+            serde_json::to_writer(&mut payload, &TaskView::from_task(&task))
+                .map_err(io::Error::from)?;
+            payload.write_all(b"\n")?;
         }
+        drop(rtxn);
 
+        self.webhooks.notify(webhooks, payload.finish()?);
         Ok(())
     }
 
@@ -1427,7 +2073,7 @@ impl IndexScheduler {
         // increase time by one nanosecond so that the enqueuedAt of the last task to delete is also lower than that date.
         let delete_before = last_task_to_delete.enqueued_at + Duration::from_nanos(1);
 
-        self.register(
+        self.register_internal_task(
             KindWithContent::TaskDeletion {
                 query: format!(
                     "?beforeEnqueuedAt={}&statuses=succeeded,failed,canceled",
@@ -1442,24 +2088,207 @@ impl IndexScheduler {
         Ok(())
     }
 
-    pub fn index_stats(&self, index_uid: &str) -> Result<IndexStats> {
-        let is_indexing = self.is_index_processing(index_uid)?;
-        let rtxn = self.read_txn()?;
-        let index_stats = self.index_mapper.stats_of(&rtxn, index_uid)?;
-
-        Ok(IndexStats { is_indexing, inner_stats: index_stats })
+    /// Register a task deleting the finished tasks older than the retention period, if any.
+    fn delete_expired_tasks(&self) -> Result<()> {
+        let Some(retention) = self.task_retention else { return Ok(()) };
+        let rtxn = self.env.read_txn().map_err(Error::HeedTransaction)?;
+
+        // an enqueued deletion will take care of the expired tasks
+        let task_deletions = self.get_kind(&rtxn, Kind::TaskDeletion)?;
+        if !(&task_deletions & self.get_status(&rtxn, Status::Enqueued)?).is_empty() {
+            return Ok(());
+        }
+
+        let delete_before = OffsetDateTime::now_utc() - retention;
+        let query = Query {
+            statuses: Some(vec![Status::Succeeded, Status::Failed, Status::Canceled]),
+            before_finished_at: Some(delete_before),
+            ..Default::default()
+        };
+        let to_delete = self.get_task_ids(&rtxn, &query)?;
+        drop(rtxn);
+
+        // /!\ the deletions we enqueue ourselves expire too, we must not enqueue a new deletion
+        //     every time the previous one expires.
+        if (&to_delete - &task_deletions).is_empty() {
+            return Ok(());
+        }
+
+        tracing::info!(
+            "Deleting the {} finished tasks older than the retention period.",
+            to_delete.len()
+        );
+
+        self.register_internal_task(
+            KindWithContent::TaskDeletion {
+                query: format!(
+                    "?beforeFinishedAt={}&statuses=succeeded,failed,canceled",
+                    delete_before.format(&Rfc3339).map_err(|_| Error::CorruptedTaskQueue)?,
+                ),
+                tasks: to_delete,
+            },
+            None,
+            false,
+        )?;
+
+        Ok(())
+    }
+
+    pub fn index_stats(&self, index_uid: &str) -> Result<IndexStats> {
+        let is_indexing = self.is_index_processing(index_uid)?;
+        let rtxn = self.read_txn()?;
+        let index_stats = self.index_mapper.stats_of(&rtxn, index_uid)?;
+
+        Ok(IndexStats { is_indexing, inner_stats: index_stats })
+    }
+
+    pub fn features(&self) -> RoFeatures {
+        self.features.features()
+    }
+
+    pub fn put_runtime_features(&self, features: RuntimeTogglableFeatures) -> Result<()> {
+        let wtxn = self.env.write_txn().map_err(Error::HeedTransaction)?;
+        self.features.put_runtime_features(wtxn, features)?;
+        Ok(())
+    }
+
+    /// The webhooks registered at runtime, without the one of the launch options.
+    pub fn webhooks(&self) -> Vec<Webhook> {
+        self.webhooks.webhooks()
+    }
+
+    /// Replaces the webhooks registered at runtime, they are notified from the next batch on.
+    pub fn put_webhooks(&self, webhooks: Vec<Webhook>) -> Result<()> {
+        let wtxn = self.env.write_txn().map_err(Error::HeedTransaction)?;
+        self.webhooks.put_webhooks(wtxn, webhooks)?;
+        Ok(())
+    }
+
+    /// Whether the searches are recorded in the search events.
+    pub fn search_events_enabled(&self) -> bool {
+        self.search_events.is_enabled()
+    }
+
+    /// Records a search, deleting the oldest one once the capacity of the search events is reached.
+    ///
+    /// The searches are buffered and written together in the background, at most once per second.
+    pub fn record_search_event(&self, event: SearchEvent) {
+        self.search_events.record(event);
+    }
+
+    /// Returns the recorded searches, the oldest first.
+    pub fn search_events(&self) -> Result<Vec<SearchEvent>> {
+        self.search_events.events()
+    }
+
+    /// Returns an error if the task modifies the documents or the settings of an index
+    /// whose `readOnly` setting is enabled.
+    fn check_index_read_only(&self, kind: &KindWithContent) -> Result<()> {
+        let index_uid = match kind {
+            KindWithContent::DocumentAdditionOrUpdate { index_uid, .. }
+            | KindWithContent::DocumentTransaction { index_uid, .. }
+            | KindWithContent::DocumentUrlImport { index_uid, .. }
+            | KindWithContent::DocumentDeletion { index_uid, .. }
+            | KindWithContent::DocumentDeletionByFilter { index_uid, .. }
+            | KindWithContent::DocumentEdition { index_uid, .. }
+            | KindWithContent::DocumentClear { index_uid }
+            | KindWithContent::IndexUpdate { index_uid, .. } => index_uid,
+            // disabling the flag is the only settings update allowed on a read-only index
+            KindWithContent::SettingsUpdate { index_uid, new_settings, .. }
+                if !matches!(new_settings.read_only, Setting::Set(false) | Setting::Reset) =>
+            {
+                index_uid
+            }
+            _ => return Ok(()),
+        };
+
+        if !self.index_exists(index_uid)? {
+            return Ok(());
+        }
+
+        let index = self.index(index_uid)?;
+        let rtxn = index.read_txn()?;
+        if index.read_only(&rtxn)? {
+            return Err(Error::IndexReadOnly(index_uid.to_string()));
+        }
+
+        Ok(())
+    }
+
+    /// Returns `true` if the scheduler refuses to register new tasks.
+    pub fn is_in_maintenance(&self) -> bool {
+        self.maintenance.load(Relaxed)
+    }
+
+    /// Enables or disables the maintenance mode.
+    ///
+    /// While enabled no new task can be registered, but the enqueued and processing
+    /// tasks are still processed until the task queue is drained.
+    pub fn set_maintenance(&self, enabled: bool) {
+        self.maintenance.store(enabled, Relaxed);
+    }
+
+    /// Returns `true` once the shutdown of the scheduler started.
+    pub fn is_shutting_down(&self) -> bool {
+        self.shutting_down.load(Relaxed)
+    }
+
+    /// Stops registering new tasks and starting new batches, the batch being processed
+    /// is finished and the enqueued tasks are processed on the next launch.
+    pub fn start_shutdown(&self) {
+        // set with the lock of the processing tasks held, see the `tick` method
+        let _processing_tasks = self.processing_tasks.write().unwrap();
+        self.shutting_down.store(true, Relaxed);
+    }
+
+    /// Waits for the batch being processed to be finished and committed.
+    ///
+    /// Returns `false` if it is still being processed once the timeout is elapsed.
+    // TODO: checkpoint the long extraction phases of a batch, by persisting its completed grenad
+    // chunks, so that a batch interrupted once the timeout is elapsed resumes from its last
+    // checkpoint instead of being processed again from the start on the next launch.
+    pub fn wait_for_processing_batch(&self, timeout: Duration) -> bool {
+        let started_at = std::time::Instant::now();
+        loop {
+            // sound to unwrap, the lock is only held to update the processing tasks
+            if self.processing_tasks.read().unwrap().processing.is_empty() {
+                // the tasks stop processing right before the write transaction of the
+                // batch is committed, waiting for the write lock waits for the commit
+                drop(self.env.write_txn());
+                return true;
+            }
+            if started_at.elapsed() >= timeout {
+                return false;
+            }
+            std::thread::sleep(SHUTDOWN_CHECK_INTERVAL.min(timeout));
+        }
+    }
+
+    /// Returns the current settings of the snapshots.
+    pub fn snapshot_settings(&self) -> SnapshotSettings {
+        self.snapshot_settings.settings()
     }
 
-    pub fn features(&self) -> RoFeatures {
-        self.features.features()
+    /// Returns the path of the snapshot directory `dir` if it is inside the snapshot directory
+    /// of the launch options, a relative `dir` is relative to it.
+    pub fn resolve_snapshot_dir(&self, dir: &Path) -> Option<PathBuf> {
+        self.snapshot_settings.resolve_dir(dir)
     }
 
-    pub fn put_runtime_features(&self, features: RuntimeTogglableFeatures) -> Result<()> {
+    /// Replaces and persists the settings of the snapshots, the next snapshots are created
+    /// with them, including after a restart.
+    pub fn update_snapshot_settings(&self, settings: SnapshotSettings) -> Result<()> {
         let wtxn = self.env.write_txn().map_err(Error::HeedTransaction)?;
-        self.features.put_runtime_features(wtxn, features)?;
+        self.snapshot_settings.put_settings(wtxn, settings)?;
         Ok(())
     }
 
+    /// Returns the number of tasks that are enqueued or being processed.
+    pub fn pending_tasks(&self) -> Result<u64> {
+        let rtxn = self.read_txn()?;
+        Ok(self.get_status(&rtxn, Status::Enqueued)?.len())
+    }
+
     pub(crate) fn delete_persisted_task_data(&self, task: &Task) -> Result<()> {
         match task.content_uuid() {
             Some(content_file) => self.delete_update_file(content_file),
@@ -1532,6 +2361,24 @@ impl IndexScheduler {
     }
 }
 
+/// The entry of the env of the scheduler in the tarball of a snapshot.
+const SNAPSHOT_TASKS_ENV: &str = "tasks/data.mdb";
+
+/// Checks the version of the snapshot at `snapshot_path` and returns the names of its indexes.
+///
+/// Only the version file and the env of the scheduler are unpacked, in a temporary directory.
+pub fn snapshot_index_names(snapshot_path: &Path) -> Result<Vec<String>> {
+    let temp = tempfile::tempdir()?;
+    compression::from_tar_gz_entries(
+        snapshot_path,
+        temp.path(),
+        &[VERSION_FILE_NAME, SNAPSHOT_TASKS_ENV],
+    )?;
+    versioning::check_version_file(temp.path())?;
+    let mapping = index_mapper::snapshot_index_mapping(&temp.path().join("tasks"))?;
+    Ok(mapping.into_iter().map(|(name, _uuid)| name).collect())
+}
+
 pub struct Dump<'a> {
     index_scheduler: &'a IndexScheduler,
     wtxn: RwTxn<'a>,
@@ -1562,100 +2409,7 @@ impl<'a> Dump<'a> {
         task: TaskDump,
         content_file: Option<Box<UpdateFile>>,
     ) -> Result<Task> {
-        let content_uuid = match content_file {
-            Some(content_file) if task.status == Status::Enqueued => {
-                let (uuid, mut file) = self.index_scheduler.create_update_file(false)?;
-                let mut builder = DocumentsBatchBuilder::new(&mut file);
-                for doc in content_file {
-                    builder.append_json_object(&doc?)?;
-                }
-                builder.into_inner()?;
-                file.persist()?;
-
-                Some(uuid)
-            }
-            // If the task isn't `Enqueued` then just generate a recognisable `Uuid`
-            // in case we try to open it later.
-            _ if task.status != Status::Enqueued => Some(Uuid::nil()),
-            _ => None,
-        };
-
-        let task = Task {
-            uid: task.uid,
-            enqueued_at: task.enqueued_at,
-            started_at: task.started_at,
-            finished_at: task.finished_at,
-            error: task.error,
-            canceled_by: task.canceled_by,
-            details: task.details,
-            status: task.status,
-            kind: match task.kind {
-                KindDump::DocumentImport {
-                    primary_key,
-                    method,
-                    documents_count,
-                    allow_index_creation,
-                } => KindWithContent::DocumentAdditionOrUpdate {
-                    index_uid: task.index_uid.ok_or(Error::CorruptedDump)?,
-                    primary_key,
-                    method,
-                    content_file: content_uuid.ok_or(Error::CorruptedDump)?,
-                    documents_count,
-                    allow_index_creation,
-                },
-                KindDump::DocumentDeletion { documents_ids } => KindWithContent::DocumentDeletion {
-                    documents_ids,
-                    index_uid: task.index_uid.ok_or(Error::CorruptedDump)?,
-                },
-                KindDump::DocumentDeletionByFilter { filter } => {
-                    KindWithContent::DocumentDeletionByFilter {
-                        filter_expr: filter,
-                        index_uid: task.index_uid.ok_or(Error::CorruptedDump)?,
-                    }
-                }
-                KindDump::DocumentEdition { filter, context, function } => {
-                    KindWithContent::DocumentEdition {
-                        index_uid: task.index_uid.ok_or(Error::CorruptedDump)?,
-                        filter_expr: filter,
-                        context,
-                        function,
-                    }
-                }
-                KindDump::DocumentClear => KindWithContent::DocumentClear {
-                    index_uid: task.index_uid.ok_or(Error::CorruptedDump)?,
-                },
-                KindDump::Settings { settings, is_deletion, allow_index_creation } => {
-                    KindWithContent::SettingsUpdate {
-                        index_uid: task.index_uid.ok_or(Error::CorruptedDump)?,
-                        new_settings: settings,
-                        is_deletion,
-                        allow_index_creation,
-                    }
-                }
-                KindDump::IndexDeletion => KindWithContent::IndexDeletion {
-                    index_uid: task.index_uid.ok_or(Error::CorruptedDump)?,
-                },
-                KindDump::IndexCreation { primary_key } => KindWithContent::IndexCreation {
-                    index_uid: task.index_uid.ok_or(Error::CorruptedDump)?,
-                    primary_key,
-                },
-                KindDump::IndexUpdate { primary_key } => KindWithContent::IndexUpdate {
-                    index_uid: task.index_uid.ok_or(Error::CorruptedDump)?,
-                    primary_key,
-                },
-                KindDump::IndexSwap { swaps } => KindWithContent::IndexSwap { swaps },
-                KindDump::TaskCancelation { query, tasks } => {
-                    KindWithContent::TaskCancelation { query, tasks }
-                }
-                KindDump::TasksDeletion { query, tasks } => {
-                    KindWithContent::TaskDeletion { query, tasks }
-                }
-                KindDump::DumpCreation { keys, instance_uid } => {
-                    KindWithContent::DumpCreation { keys, instance_uid }
-                }
-                KindDump::SnapshotCreation => KindWithContent::SnapshotCreation,
-            },
-        };
+        let task = self.index_scheduler.task_from_dump(task, content_file)?;
 
         self.index_scheduler.all_tasks.put(&mut self.wtxn, &task.uid, &task)?;
 
@@ -1732,6 +2486,20 @@ pub enum TickOutcome {
     TickAgain(u64),
     /// The scheduler should wait for an external signal before attempting another `tick`.
     WaitForSignal,
+    /// The scheduler should wait until the given instant, or an external signal, before
+    /// attempting another `tick`.
+    WaitUntil(Instant),
+}
+
+/// The batch that last failed with a transient error.
+#[derive(Debug, Clone, Copy)]
+struct TransientFailure {
+    /// The oldest task of the batch, which is always part of the next batch created.
+    oldest: TaskId,
+    /// The number of times the batch was retried.
+    retries: u32,
+    /// The batch isn't processed again before this instant.
+    not_before: Instant,
 }
 
 /// How many indexes we can afford to have open simultaneously.
@@ -1791,6 +2559,7 @@ mod tests {
         InsideCreateBatch,
         InsideProcessBatch,
         PanicInsideProcessBatch,
+        TransientFailureInsideProcessBatch,
         AcquiringWtxn,
         UpdatingTaskAfterProcessBatchSuccess { task_uid: u32 },
         UpdatingTaskAfterProcessBatchFailure,
@@ -1820,14 +2589,17 @@ mod tests {
                 version_file_path: tempdir.path().join(VERSION_FILE_NAME),
                 auth_path: tempdir.path().join("auth"),
                 tasks_path: tempdir.path().join("db_path"),
+                search_events_path: tempdir.path().join("search_events"),
                 update_file_path: tempdir.path().join("file_store"),
                 indexes_path: tempdir.path().join("indexes"),
                 snapshots_path: tempdir.path().join("snapshots"),
+                snapshot_interval: None,
                 dumps_path: tempdir.path().join("dumps"),
                 webhook_url: None,
                 webhook_authorization_header: None,
                 task_db_size: 1000 * 1000, // 1 MB, we don't use MiB on purpose.
                 index_base_map_size: 1000 * 1000, // 1 MB, we don't use MiB on purpose.
+                index_map_size_hint: None,
                 enable_mdb_writemap: false,
                 index_growth_amount: 1000 * 1000 * 1000 * 1000, // 1 TB
                 index_count: 5,
@@ -1836,6 +2608,11 @@ mod tests {
                 cleanup_enabled: true,
                 max_number_of_tasks: 1_000_000,
                 max_number_of_batched_tasks: usize::MAX,
+                document_url_import_max_size: 1000 * 1000, // 1 MB
+                max_task_retries: 0,
+                task_retry_backoff: std::time::Duration::ZERO,
+                task_retention: None,
+                search_events_capacity: 0,
                 instance_features: Default::default(),
             };
             configuration(&mut options);
@@ -1871,6 +2648,12 @@ mod tests {
                     FailureLocation::PanicInsideProcessBatch => {
                         panic!("simulated panic")
                     }
+                    FailureLocation::TransientFailureInsideProcessBatch => {
+                        Err(Error::IoError(std::io::Error::new(
+                            std::io::ErrorKind::Other,
+                            "simulated transient failure",
+                        )))
+                    }
                     _ => Err(Error::PlannedFailure),
                 }
             } else {
@@ -2138,6 +2921,30 @@ mod tests {
         assert!(index_scheduler.is_task_processing().unwrap());
     }
 
+    #[test]
+    fn shutdown_finishes_the_processing_batch() {
+        let (index_scheduler, mut handle) = IndexScheduler::test(true, vec![]);
+
+        index_scheduler.register(index_creation_task("index_a", "id"), None, false).unwrap();
+        handle.advance_till([Start, BatchCreated]);
+
+        index_scheduler.start_shutdown();
+        let err = index_scheduler
+            .register(index_creation_task("index_b", "id"), None, false)
+            .unwrap_err();
+        snapshot!(err, @"Meilisearch is shutting down and cannot receive write operations. Please retry once it is restarted.");
+        // the dry runs write nothing
+        index_scheduler.register(index_creation_task("index_b", "id"), None, true).unwrap();
+
+        assert!(!index_scheduler.wait_for_processing_batch(std::time::Duration::ZERO));
+        handle.advance_till([InsideProcessBatch, ProcessBatchSucceeded, AfterProcessing]);
+        assert!(index_scheduler.wait_for_processing_batch(std::time::Duration::ZERO));
+
+        let rtxn = index_scheduler.read_txn().unwrap();
+        let task = index_scheduler.get_task(&rtxn, 0).unwrap().unwrap();
+        assert_eq!(task.status, Status::Succeeded);
+    }
+
     /// We send a lot of tasks but notify the tasks scheduler only once as
     /// we send them very fast, we must make sure that they are all processed.
     #[test]
@@ -2471,6 +3278,7 @@ mod tests {
                 KindWithContent::DocumentDeletion {
                     index_uid: S("doggos"),
                     documents_ids: vec![S("1"), S("2")],
+                    report_outcomes: false,
                 },
                 None,
                 false,
@@ -2488,7 +3296,7 @@ mod tests {
         let documents = index
             .all_documents(&rtxn)
             .unwrap()
-            .map(|ret| obkv_to_json(&field_ids, &field_ids_map, ret.unwrap().1).unwrap())
+            .map(|ret| obkv_to_json(&field_ids, &field_ids_map, ret.unwrap().1.as_obkv()).unwrap())
             .collect::<Vec<_>>();
         snapshot!(serde_json::to_string_pretty(&documents).unwrap(), name: "documents");
     }
@@ -2501,6 +3309,7 @@ mod tests {
                 KindWithContent::DocumentDeletion {
                     index_uid: S("doggos"),
                     documents_ids: vec![S("1"), S("2")],
+                    report_outcomes: false,
                 },
                 None,
                 false,
@@ -2548,7 +3357,7 @@ mod tests {
         let documents = index
             .all_documents(&rtxn)
             .unwrap()
-            .map(|ret| obkv_to_json(&field_ids, &field_ids_map, ret.unwrap().1).unwrap())
+            .map(|ret| obkv_to_json(&field_ids, &field_ids_map, ret.unwrap().1.as_obkv()).unwrap())
             .collect::<Vec<_>>();
         snapshot!(serde_json::to_string_pretty(&documents).unwrap(), name: "documents");
     }
@@ -2927,7 +3736,7 @@ mod tests {
         let documents = index
             .all_documents(&rtxn)
             .unwrap()
-            .map(|ret| obkv_to_json(&field_ids, &field_ids_map, ret.unwrap().1).unwrap())
+            .map(|ret| obkv_to_json(&field_ids, &field_ids_map, ret.unwrap().1.as_obkv()).unwrap())
             .collect::<Vec<_>>();
         snapshot!(serde_json::to_string_pretty(&documents).unwrap(), name: "documents");
     }
@@ -2978,7 +3787,7 @@ mod tests {
         let documents = index
             .all_documents(&rtxn)
             .unwrap()
-            .map(|ret| obkv_to_json(&field_ids, &field_ids_map, ret.unwrap().1).unwrap())
+            .map(|ret| obkv_to_json(&field_ids, &field_ids_map, ret.unwrap().1.as_obkv()).unwrap())
             .collect::<Vec<_>>();
         snapshot!(serde_json::to_string_pretty(&documents).unwrap(), name: "documents");
     }
@@ -3034,7 +3843,7 @@ mod tests {
         let documents = index
             .all_documents(&rtxn)
             .unwrap()
-            .map(|ret| obkv_to_json(&field_ids, &field_ids_map, ret.unwrap().1).unwrap())
+            .map(|ret| obkv_to_json(&field_ids, &field_ids_map, ret.unwrap().1.as_obkv()).unwrap())
             .collect::<Vec<_>>();
         snapshot!(serde_json::to_string_pretty(&documents).unwrap(), name: "documents");
     }
@@ -3154,7 +3963,7 @@ mod tests {
         let documents = index
             .all_documents(&rtxn)
             .unwrap()
-            .map(|ret| obkv_to_json(&field_ids, &field_ids_map, ret.unwrap().1).unwrap())
+            .map(|ret| obkv_to_json(&field_ids, &field_ids_map, ret.unwrap().1.as_obkv()).unwrap())
             .collect::<Vec<_>>();
         snapshot!(serde_json::to_string_pretty(&documents).unwrap(), name: "documents");
     }
@@ -3209,7 +4018,7 @@ mod tests {
         let documents = index
             .all_documents(&rtxn)
             .unwrap()
-            .map(|ret| obkv_to_json(&field_ids, &field_ids_map, ret.unwrap().1).unwrap())
+            .map(|ret| obkv_to_json(&field_ids, &field_ids_map, ret.unwrap().1.as_obkv()).unwrap())
             .collect::<Vec<_>>();
         snapshot!(serde_json::to_string_pretty(&documents).unwrap(), name: "documents");
     }
@@ -3923,7 +4732,7 @@ mod tests {
         let documents = index
             .all_documents(&rtxn)
             .unwrap()
-            .map(|ret| obkv_to_json(&field_ids, &field_ids_map, ret.unwrap().1).unwrap())
+            .map(|ret| obkv_to_json(&field_ids, &field_ids_map, ret.unwrap().1.as_obkv()).unwrap())
             .collect::<Vec<_>>();
         snapshot!(serde_json::to_string_pretty(&documents).unwrap(), name: "documents");
     }
@@ -3994,7 +4803,7 @@ mod tests {
         let documents = index
             .all_documents(&rtxn)
             .unwrap()
-            .map(|ret| obkv_to_json(&field_ids, &field_ids_map, ret.unwrap().1).unwrap())
+            .map(|ret| obkv_to_json(&field_ids, &field_ids_map, ret.unwrap().1.as_obkv()).unwrap())
             .collect::<Vec<_>>();
         snapshot!(serde_json::to_string_pretty(&documents).unwrap(), name: "documents");
     }
@@ -4062,7 +4871,7 @@ mod tests {
         let documents = index
             .all_documents(&rtxn)
             .unwrap()
-            .map(|ret| obkv_to_json(&field_ids, &field_ids_map, ret.unwrap().1).unwrap())
+            .map(|ret| obkv_to_json(&field_ids, &field_ids_map, ret.unwrap().1.as_obkv()).unwrap())
             .collect::<Vec<_>>();
         snapshot!(serde_json::to_string_pretty(&documents).unwrap(), name: "documents");
     }
@@ -4123,7 +4932,7 @@ mod tests {
         let documents = index
             .all_documents(&rtxn)
             .unwrap()
-            .map(|ret| obkv_to_json(&field_ids, &field_ids_map, ret.unwrap().1).unwrap())
+            .map(|ret| obkv_to_json(&field_ids, &field_ids_map, ret.unwrap().1.as_obkv()).unwrap())
             .collect::<Vec<_>>();
         snapshot!(serde_json::to_string_pretty(&documents).unwrap(), name: "documents");
     }
@@ -4188,7 +4997,7 @@ mod tests {
         let documents = index
             .all_documents(&rtxn)
             .unwrap()
-            .map(|ret| obkv_to_json(&field_ids, &field_ids_map, ret.unwrap().1).unwrap())
+            .map(|ret| obkv_to_json(&field_ids, &field_ids_map, ret.unwrap().1.as_obkv()).unwrap())
             .collect::<Vec<_>>();
         snapshot!(serde_json::to_string_pretty(&documents).unwrap(), name: "documents");
     }
@@ -4249,7 +5058,7 @@ mod tests {
         let documents = index
             .all_documents(&rtxn)
             .unwrap()
-            .map(|ret| obkv_to_json(&field_ids, &field_ids_map, ret.unwrap().1).unwrap())
+            .map(|ret| obkv_to_json(&field_ids, &field_ids_map, ret.unwrap().1.as_obkv()).unwrap())
             .collect::<Vec<_>>();
         snapshot!(serde_json::to_string_pretty(&documents).unwrap(), name: "documents");
     }
@@ -4332,7 +5141,7 @@ mod tests {
         let documents = index
             .all_documents(&rtxn)
             .unwrap()
-            .map(|ret| obkv_to_json(&field_ids, &field_ids_map, ret.unwrap().1).unwrap())
+            .map(|ret| obkv_to_json(&field_ids, &field_ids_map, ret.unwrap().1.as_obkv()).unwrap())
             .collect::<Vec<_>>();
         snapshot!(serde_json::to_string_pretty(&documents).unwrap(), name: "documents");
     }
@@ -4418,7 +5227,7 @@ mod tests {
         let documents = index
             .all_documents(&rtxn)
             .unwrap()
-            .map(|ret| obkv_to_json(&field_ids, &field_ids_map, ret.unwrap().1).unwrap())
+            .map(|ret| obkv_to_json(&field_ids, &field_ids_map, ret.unwrap().1.as_obkv()).unwrap())
             .collect::<Vec<_>>();
         snapshot!(serde_json::to_string_pretty(&documents).unwrap(), name: "documents");
     }
@@ -4497,7 +5306,7 @@ mod tests {
         let documents = index
             .all_documents(&rtxn)
             .unwrap()
-            .map(|ret| obkv_to_json(&field_ids, &field_ids_map, ret.unwrap().1).unwrap())
+            .map(|ret| obkv_to_json(&field_ids, &field_ids_map, ret.unwrap().1.as_obkv()).unwrap())
             .collect::<Vec<_>>();
         snapshot!(serde_json::to_string_pretty(&documents).unwrap(), name: "documents");
     }
@@ -4520,6 +5329,95 @@ mod tests {
         snapshot!(snapshot_index_scheduler(&index_scheduler), name: "index_creation_failed");
     }
 
+    #[test]
+    fn retry_batch_failing_with_transient_error() {
+        // the batch fails on each of its three attempts
+        let planned_failures =
+            (1..=3).map(|i| (i, FailureLocation::TransientFailureInsideProcessBatch)).collect();
+        let (index_scheduler, mut handle) =
+            IndexScheduler::test_with_custom_config(planned_failures, |config| {
+                config.max_task_retries = 2;
+            });
+
+        index_scheduler.register(index_creation_task("catto", "mouse"), None, false).unwrap();
+
+        // the task stays enqueued while the batch is retried
+        for _ in 0..2 {
+            handle.advance_till([Start, BatchCreated, ProcessBatchRetried]);
+            let rtxn = index_scheduler.read_txn().unwrap();
+            let task = index_scheduler.get_task(&rtxn, 0).unwrap().unwrap();
+            assert_eq!(task.status, Status::Enqueued);
+        }
+
+        // once the retries are exhausted the task is failed
+        handle.advance_till([Start, BatchCreated, ProcessBatchFailed, AfterProcessing]);
+        assert_eq!(*index_scheduler.run_loop_iteration.read().unwrap(), 3);
+        {
+            let rtxn = index_scheduler.read_txn().unwrap();
+            let query = Query { statuses: Some(vec![Status::Failed]), ..Default::default() };
+            let (tasks, _) = index_scheduler
+                .get_task_ids_from_authorized_indexes(&rtxn, &query, &AuthFilter::default())
+                .unwrap();
+            snapshot!(snapshot_bitmap(&tasks), @"[0,]");
+            let task = index_scheduler.get_task(&rtxn, 0).unwrap().unwrap();
+            assert!(task.error.unwrap().message.contains("simulated transient failure"));
+            // and lands in the dead-letter list
+            let dead_letters = index_scheduler.dead_letter_tasks(&rtxn).unwrap();
+            snapshot!(snapshot_bitmap(&dead_letters), @"[0,]");
+        }
+
+        // and the next tasks are processed
+        index_scheduler.register(index_creation_task("doggo", "bone"), None, false).unwrap();
+        handle.advance_one_successful_batch();
+        let rtxn = index_scheduler.read_txn().unwrap();
+        let task = index_scheduler.get_task(&rtxn, 1).unwrap().unwrap();
+        assert_eq!(task.status, Status::Succeeded);
+    }
+
+    #[test]
+    fn permanent_failures_are_not_retried() {
+        let (index_scheduler, mut handle) = IndexScheduler::test_with_custom_config(
+            vec![(1, FailureLocation::InsideProcessBatch)],
+            |config| {
+                config.max_task_retries = 2;
+            },
+        );
+
+        index_scheduler.register(index_creation_task("catto", "mouse"), None, false).unwrap();
+
+        handle.advance_till([Start, BatchCreated, ProcessBatchFailed, AfterProcessing]);
+        let rtxn = index_scheduler.read_txn().unwrap();
+        let task = index_scheduler.get_task(&rtxn, 0).unwrap().unwrap();
+        assert_eq!(task.status, Status::Failed);
+        assert!(index_scheduler.dead_letter_tasks(&rtxn).unwrap().is_empty());
+    }
+
+    #[test]
+    fn retried_batch_waits_for_its_backoff_without_blocking_the_scheduler() {
+        let (index_scheduler, mut handle) = IndexScheduler::test_with_custom_config(
+            vec![(1, FailureLocation::TransientFailureInsideProcessBatch)],
+            |config| {
+                config.max_task_retries = 2;
+                config.task_retry_backoff = std::time::Duration::from_secs(3600);
+            },
+        );
+
+        index_scheduler.register(index_creation_task("catto", "mouse"), None, false).unwrap();
+        handle.advance_till([Start, BatchCreated, ProcessBatchRetried]);
+
+        // the cancelation is processed while the retried batch waits for its backoff
+        let kind = KindWithContent::TaskCancelation {
+            query: "test_query".to_owned(),
+            tasks: RoaringBitmap::from_iter([0]),
+        };
+        index_scheduler.register(kind, None, false).unwrap();
+        handle.advance_one_successful_batch();
+
+        let rtxn = index_scheduler.read_txn().unwrap();
+        let task = index_scheduler.get_task(&rtxn, 0).unwrap().unwrap();
+        assert_eq!(task.status, Status::Canceled);
+    }
+
     #[test]
     fn test_task_queue_is_full() {
         let (index_scheduler, mut handle) =
@@ -4595,6 +5493,40 @@ mod tests {
         handle.advance_one_failed_batch();
     }
 
+    #[test]
+    fn search_events_are_kept_out_of_the_task_queue() {
+        let (index_scheduler, _handle) =
+            IndexScheduler::test_with_custom_config(vec![], |config| {
+                config.search_events_capacity = 10;
+            });
+        assert!(index_scheduler.search_events_enabled());
+
+        let event = SearchEvent {
+            index_uid: S("doggos"),
+            searched_at: OffsetDateTime::now_utc(),
+            query_length: 5,
+            zero_result_query: Some(S("bones")),
+            filtered: false,
+            processing_time_ms: 1,
+            hits_count: 0,
+        };
+        for _ in 0..3 {
+            index_scheduler.record_search_event(event.clone());
+        }
+
+        // the events are written in the background
+        let start = Instant::now();
+        while index_scheduler.search_events().unwrap().len() < 3 {
+            assert!(start.elapsed() < std::time::Duration::from_secs(10));
+            std::thread::sleep(std::time::Duration::from_millis(100));
+        }
+
+        let rtxn = index_scheduler.env.read_txn().unwrap();
+        let database: Option<Database<heed::Unspecified, heed::Unspecified>> =
+            index_scheduler.env.open_database(&rtxn, Some("search-events")).unwrap();
+        assert!(database.is_none());
+    }
+
     #[test]
     fn test_auto_deletion_of_tasks() {
         let (index_scheduler, mut handle) =
@@ -4736,6 +5668,79 @@ mod tests {
         drop(rtxn);
     }
 
+    #[test]
+    fn test_auto_deletion_of_tasks_in_maintenance_mode() {
+        let (index_scheduler, mut handle) =
+            IndexScheduler::test_with_custom_config(vec![], |config| {
+                config.max_number_of_tasks = 2;
+            });
+
+        index_scheduler
+            .register(
+                KindWithContent::IndexCreation { index_uid: S("doggo"), primary_key: None },
+                None,
+                false,
+            )
+            .unwrap();
+        handle.advance_one_successful_batch();
+        index_scheduler
+            .register(
+                KindWithContent::IndexCreation { index_uid: S("doggo"), primary_key: None },
+                None,
+                false,
+            )
+            .unwrap();
+        handle.advance_one_failed_batch();
+
+        // the users can't enqueue tasks anymore...
+        index_scheduler.set_maintenance(true);
+        let error = index_scheduler
+            .register(
+                KindWithContent::IndexCreation { index_uid: S("doggo"), primary_key: None },
+                None,
+                false,
+            )
+            .unwrap_err();
+        assert!(matches!(error, Error::MaintenanceMode), "{error}");
+
+        // ...but the scheduler still cleans up the task queue.
+        handle.advance_one_successful_batch();
+        let rtxn = index_scheduler.env.read_txn().unwrap();
+        let tasks = index_scheduler.get_task_ids(&rtxn, &Query::default()).unwrap();
+        assert_eq!(tasks.iter().collect::<Vec<_>>(), vec![2]);
+        let task = index_scheduler.get_task(&rtxn, 2).unwrap().unwrap();
+        assert_eq!(task.kind.as_kind(), Kind::TaskDeletion);
+        assert_eq!(task.status, Status::Succeeded);
+    }
+
+    #[test]
+    fn test_deletion_of_expired_tasks_in_maintenance_mode() {
+        let (index_scheduler, mut handle) =
+            IndexScheduler::test_with_custom_config(vec![], |config| {
+                config.task_retention = Some(std::time::Duration::ZERO);
+            });
+
+        index_scheduler
+            .register(
+                KindWithContent::IndexCreation { index_uid: S("doggo"), primary_key: None },
+                None,
+                false,
+            )
+            .unwrap();
+        handle.advance_one_successful_batch();
+
+        index_scheduler.set_maintenance(true);
+
+        // the index creation expired, its deletion is enqueued and processed in maintenance mode.
+        handle.advance_one_successful_batch();
+        let rtxn = index_scheduler.env.read_txn().unwrap();
+        let tasks = index_scheduler.get_task_ids(&rtxn, &Query::default()).unwrap();
+        assert_eq!(tasks.iter().collect::<Vec<_>>(), vec![1]);
+        let task = index_scheduler.get_task(&rtxn, 1).unwrap().unwrap();
+        assert_eq!(task.kind.as_kind(), Kind::TaskDeletion);
+        assert_eq!(task.status, Status::Succeeded);
+    }
+
     #[test]
     fn basic_get_stats() {
         let (index_scheduler, mut handle) = IndexScheduler::test(true, vec![]);
@@ -4894,7 +5899,12 @@ mod tests {
     fn cancel_processing_dump() {
         let (index_scheduler, mut handle) = IndexScheduler::test(true, vec![]);
 
-        let dump_creation = KindWithContent::DumpCreation { keys: Vec::new(), instance_uid: None };
+        let dump_creation = KindWithContent::DumpCreation {
+            keys: Vec::new(),
+            instance_uid: None,
+            settings_only: false,
+            since: None,
+        };
         let dump_cancellation = KindWithContent::TaskCancelation {
             query: "cancel dump".to_owned(),
             tasks: RoaringBitmap::from_iter([0]),
@@ -4912,6 +5922,52 @@ mod tests {
         snapshot!(snapshot_index_scheduler(&index_scheduler), name: "cancel_processed");
     }
 
+    #[test]
+    fn canceled_dump_leaves_no_partial_dump() {
+        let (index_scheduler, mut handle) = IndexScheduler::test(true, vec![]);
+        let dump_files = || {
+            std::fs::read_dir(&index_scheduler.dumps_path)
+                .unwrap()
+                .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+                .collect::<Vec<_>>()
+        };
+
+        let kind = KindWithContent::IndexCreation { index_uid: S("catto"), primary_key: None };
+        index_scheduler.register(kind, None, false).unwrap();
+        handle.advance_one_successful_batch();
+
+        let dump_creation = KindWithContent::DumpCreation {
+            keys: Vec::new(),
+            instance_uid: None,
+            settings_only: false,
+            since: None,
+        };
+        index_scheduler.register(dump_creation.clone(), None, false).unwrap();
+        handle.advance_till([Start, BatchCreated, InsideProcessBatch]);
+
+        let dump_cancellation = KindWithContent::TaskCancelation {
+            query: "cancel dump".to_owned(),
+            tasks: RoaringBitmap::from_iter([1]),
+        };
+        index_scheduler.register(dump_cancellation, None, false).unwrap();
+        snapshot!(format!("{:?}", handle.advance()), @"AbortedIndexation");
+        handle.advance_one_successful_batch();
+
+        let rtxn = index_scheduler.read_txn().unwrap();
+        let task = index_scheduler.get_task(&rtxn, 1).unwrap().unwrap();
+        assert_eq!(task.status, Status::Canceled);
+        assert_eq!(task.canceled_by, Some(2));
+        drop(rtxn);
+        assert!(dump_files().is_empty(), "{:?}", dump_files());
+
+        // a dump that runs to completion is the only file of the dumps directory
+        index_scheduler.register(dump_creation, None, false).unwrap();
+        handle.advance_one_successful_batch();
+        let files = dump_files();
+        assert_eq!(files.len(), 1, "{files:?}");
+        assert!(files[0].ends_with(".dump"), "{files:?}");
+    }
+
     #[test]
     fn basic_set_taskid() {
         let (index_scheduler, _handle) = IndexScheduler::test(true, vec![]);
@@ -5165,7 +6221,7 @@ mod tests {
             assert_json_snapshot!(embeddings[&simple_hf_name][0] == lab_embed, @"true");
             assert_json_snapshot!(embeddings[&fakerest_name][0] == beagle_embed, @"true");
 
-            let doc = index.documents(&rtxn, std::iter::once(0)).unwrap()[0].1;
+            let (_, doc) = index.documents(&rtxn, std::iter::once(0)).unwrap().remove(0);
             let fields_ids_map = index.fields_ids_map(&rtxn).unwrap();
             let doc = obkv_to_json(
                 &[
@@ -5174,7 +6230,7 @@ mod tests {
                     fields_ids_map.id("_vectors").unwrap(),
                 ],
                 &fields_ids_map,
-                doc,
+                doc.as_obkv(),
             )
             .unwrap();
             assert_json_snapshot!(doc, {"._vectors.A_fakerest.embeddings" => "[vector]"});
@@ -5242,7 +6298,7 @@ mod tests {
                 // remained beagle
                 assert_json_snapshot!(embeddings[&fakerest_name][0] == beagle_embed, @"true");
 
-                let doc = index.documents(&rtxn, std::iter::once(0)).unwrap()[0].1;
+                let (_, doc) = index.documents(&rtxn, std::iter::once(0)).unwrap().remove(0);
                 let fields_ids_map = index.fields_ids_map(&rtxn).unwrap();
                 let doc = obkv_to_json(
                     &[
@@ -5251,7 +6307,7 @@ mod tests {
                         fields_ids_map.id("_vectors").unwrap(),
                     ],
                     &fields_ids_map,
-                    doc,
+                    doc.as_obkv(),
                 )
                 .unwrap();
                 assert_json_snapshot!(doc, {"._vectors.A_fakerest.embeddings" => "[vector]"});
@@ -5340,7 +6396,7 @@ mod tests {
         let documents = index
             .all_documents(&rtxn)
             .unwrap()
-            .map(|ret| obkv_to_json(&field_ids, &field_ids_map, ret.unwrap().1).unwrap())
+            .map(|ret| obkv_to_json(&field_ids, &field_ids_map, ret.unwrap().1.as_obkv()).unwrap())
             .collect::<Vec<_>>();
         snapshot!(serde_json::to_string(&documents).unwrap(), name: "documents after initial push");
 
@@ -5379,7 +6435,7 @@ mod tests {
         let documents = index
             .all_documents(&rtxn)
             .unwrap()
-            .map(|ret| obkv_to_json(&field_ids, &field_ids_map, ret.unwrap().1).unwrap())
+            .map(|ret| obkv_to_json(&field_ids, &field_ids_map, ret.unwrap().1.as_obkv()).unwrap())
             .collect::<Vec<_>>();
         // the all the vectors linked to the new specified embedder have been removed
         // Only the unknown embedders stays in the document DB
@@ -5483,8 +6539,8 @@ mod tests {
         // the document with the id 3 should have its original embedding updated
         let rtxn = index.read_txn().unwrap();
         let docid = index.external_documents_ids.get(&rtxn, "3").unwrap().unwrap();
-        let doc = index.documents(&rtxn, Some(docid)).unwrap()[0];
-        let doc = obkv_to_json(&field_ids, &field_ids_map, doc.1).unwrap();
+        let (_, doc) = index.documents(&rtxn, Some(docid)).unwrap().remove(0);
+        let doc = obkv_to_json(&field_ids, &field_ids_map, doc.as_obkv()).unwrap();
         snapshot!(json_string!(doc), @r###"
         {
           "id": 3,
@@ -5587,6 +6643,7 @@ mod tests {
                 KindWithContent::DocumentDeletion {
                     index_uid: S("doggos"),
                     documents_ids: vec![S("1")],
+                    report_outcomes: false,
                 },
                 None,
                 false,
@@ -5601,7 +6658,7 @@ mod tests {
         let documents = index
             .all_documents(&rtxn)
             .unwrap()
-            .map(|ret| obkv_to_json(&field_ids, &field_ids_map, ret.unwrap().1).unwrap())
+            .map(|ret| obkv_to_json(&field_ids, &field_ids_map, ret.unwrap().1.as_obkv()).unwrap())
             .collect::<Vec<_>>();
         snapshot!(serde_json::to_string(&documents).unwrap(), @r###"[{"id":0,"doggo":"kefir"}]"###);
         let conf = index.embedding_configs(&rtxn).unwrap();
@@ -5641,7 +6698,7 @@ mod tests {
         let documents = index
             .all_documents(&rtxn)
             .unwrap()
-            .map(|ret| obkv_to_json(&field_ids, &field_ids_map, ret.unwrap().1).unwrap())
+            .map(|ret| obkv_to_json(&field_ids, &field_ids_map, ret.unwrap().1.as_obkv()).unwrap())
             .collect::<Vec<_>>();
         snapshot!(serde_json::to_string(&documents).unwrap(), @"[]");
         let conf = index.embedding_configs(&rtxn).unwrap();
@@ -5757,7 +6814,9 @@ mod tests {
             let documents = index
                 .all_documents(&rtxn)
                 .unwrap()
-                .map(|ret| obkv_to_json(&field_ids, &field_ids_map, ret.unwrap().1).unwrap())
+                .map(|ret| {
+                    obkv_to_json(&field_ids, &field_ids_map, ret.unwrap().1.as_obkv()).unwrap()
+                })
                 .collect::<Vec<_>>();
             snapshot!(serde_json::to_string(&documents).unwrap(), @r###"[{"id":0,"doggo":"kefir"},{"id":1,"doggo":"intel"}]"###);
         }
@@ -5792,7 +6851,9 @@ mod tests {
             let documents = index
                 .all_documents(&rtxn)
                 .unwrap()
-                .map(|ret| obkv_to_json(&field_ids, &field_ids_map, ret.unwrap().1).unwrap())
+                .map(|ret| {
+                    obkv_to_json(&field_ids, &field_ids_map, ret.unwrap().1.as_obkv()).unwrap()
+                })
                 .collect::<Vec<_>>();
             snapshot!(serde_json::to_string(&documents).unwrap(), @r###"[{"id":0,"doggo":"kefir","_vectors":{"manual":{"embeddings":[[0.0,0.0,0.0]],"regenerate":false}}},{"id":1,"doggo":"intel","_vectors":{"manual":{"embeddings":[[1.0,1.0,1.0]],"regenerate":false}}}]"###);
         }
@@ -5825,7 +6886,9 @@ mod tests {
             let documents = index
                 .all_documents(&rtxn)
                 .unwrap()
-                .map(|ret| obkv_to_json(&field_ids, &field_ids_map, ret.unwrap().1).unwrap())
+                .map(|ret| {
+                    obkv_to_json(&field_ids, &field_ids_map, ret.unwrap().1.as_obkv()).unwrap()
+                })
                 .collect::<Vec<_>>();
 
             // FIXME: redaction
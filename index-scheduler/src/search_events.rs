@@ -0,0 +1,165 @@
+use std::path::Path;
+use std::sync::mpsc::{self, Receiver, SyncSender, TrySendError};
+use std::time::Duration;
+
+use meilisearch_types::heed::types::SerdeJson;
+use meilisearch_types::heed::{Database, Env, EnvOpenOptions};
+use meilisearch_types::milli::BEU64;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+use crate::Result;
+
+const SEARCH_EVENTS: &str = "search-events";
+
+const SEARCH_EVENTS_DB_SIZE: usize = 1_073_741_824; // 1GiB
+
+/// How long the searches are buffered in memory before being written in a single transaction.
+const SEARCH_EVENTS_FLUSH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// A search as it is recorded in the search events, without anything identifying who searched.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchEvent {
+    pub index_uid: String,
+    #[serde(with = "time::serde::rfc3339")]
+    pub searched_at: OffsetDateTime,
+    /// The number of characters of the query.
+    pub query_length: usize,
+    /// The text of the query, only kept when no document matched it.
+    pub zero_result_query: Option<String>,
+    /// Whether the search had a filter, besides the ones of a tenant token.
+    pub filtered: bool,
+    pub processing_time_ms: u64,
+    /// The number of documents matching the search, or the number of hits
+    /// returned when the total number of hits wasn't computed.
+    pub hits_count: usize,
+}
+
+impl SearchEvent {
+    pub fn is_empty(&self) -> bool {
+        self.hits_count == 0
+    }
+}
+
+/// The most recent searches, persisted in their own env.
+///
+/// They are kept out of the env of the scheduler so that they don't count in the usage
+/// of the task queue, which would otherwise fill up with searches and reject new tasks.
+///
+/// The events are stored by increasing ids, once the capacity is reached the oldest
+/// events are deleted as new ones are recorded. The searches are buffered in a bounded
+/// channel and written by a dedicated thread, at most once per second.
+#[derive(Clone)]
+pub(crate) struct SearchEventsData {
+    /// The env storing the searches, `None` when the search events are disabled.
+    store: Option<SearchEventsStore>,
+}
+
+#[derive(Clone)]
+struct SearchEventsStore {
+    env: Env,
+    events: Database<BEU64, SerdeJson<SearchEvent>>,
+    /// The buffer of the searches to write.
+    buffer: SyncSender<SearchEvent>,
+}
+
+impl SearchEventsData {
+    /// Opens the env of the search events in the given folder, nothing is written when
+    /// the capacity is 0.
+    pub fn new(path: &Path, capacity: usize) -> Result<Self> {
+        if capacity == 0 {
+            return Ok(Self { store: None });
+        }
+
+        std::fs::create_dir_all(path)?;
+        let env =
+            unsafe { EnvOpenOptions::new().max_dbs(1).map_size(SEARCH_EVENTS_DB_SIZE).open(path) }?;
+        let mut wtxn = env.write_txn()?;
+        let events = env.create_database(&mut wtxn, Some(SEARCH_EVENTS))?;
+        wtxn.commit()?;
+
+        // the thread stops once every clone of the sender, and so of the scheduler, is dropped
+        let (buffer, receiver) = mpsc::sync_channel(capacity);
+        let thread_env = env.clone();
+        let capacity = capacity as u64;
+        std::thread::Builder::new()
+            .name(String::from("search-events"))
+            .spawn(move || write_buffered_events(thread_env, events, capacity, receiver))?;
+
+        Ok(Self { store: Some(SearchEventsStore { env, events, buffer }) })
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.store.is_some()
+    }
+
+    /// Buffers the search, it is written with the other searches of the same second.
+    ///
+    /// The search is dropped when the buffer is full, the database can't keep up.
+    pub fn record(&self, event: SearchEvent) {
+        let Some(SearchEventsStore { buffer, .. }) = &self.store else { return };
+        match buffer.try_send(event) {
+            Ok(()) => (),
+            Err(TrySendError::Full(_)) => {
+                tracing::warn!(
+                    "Too many searches are waiting to be recorded, dropping a search event."
+                )
+            }
+            Err(TrySendError::Disconnected(_)) => {
+                tracing::error!(
+                    "The search events can't be recorded anymore, their thread stopped."
+                )
+            }
+        }
+    }
+
+    /// Returns the recorded events, the oldest first.
+    pub fn events(&self) -> Result<Vec<SearchEvent>> {
+        let Some(store) = &self.store else { return Ok(Vec::new()) };
+        let rtxn = store.env.read_txn()?;
+        let mut events = Vec::new();
+        for result in store.events.iter(&rtxn)? {
+            let (_id, event) = result?;
+            events.push(event);
+        }
+        Ok(events)
+    }
+}
+
+fn write_buffered_events(
+    env: Env,
+    events: Database<BEU64, SerdeJson<SearchEvent>>,
+    capacity: u64,
+    receiver: Receiver<SearchEvent>,
+) {
+    // waits for a first search, then gives the following ones the time to be buffered too
+    while let Ok(first) = receiver.recv() {
+        std::thread::sleep(SEARCH_EVENTS_FLUSH_INTERVAL);
+        let buffered: Vec<_> = std::iter::once(first).chain(receiver.try_iter()).collect();
+        if let Err(error) = write_events(&env, events, capacity, &buffered) {
+            tracing::error!("While recording {} search events: {error}", buffered.len());
+        }
+    }
+}
+
+/// Writes the events in a single transaction, then deletes the ones exceeding the capacity.
+fn write_events(
+    env: &Env,
+    events: Database<BEU64, SerdeJson<SearchEvent>>,
+    capacity: u64,
+    buffered: &[SearchEvent],
+) -> Result<()> {
+    let mut wtxn = env.write_txn()?;
+    let mut id = events.last(&wtxn)?.map_or(0, |(id, _)| id + 1);
+    for event in buffered {
+        events.put(&mut wtxn, &id, event)?;
+        id += 1;
+    }
+    // also drops the events above a capacity lowered since the last launch
+    if let Some(oldest_kept) = id.checked_sub(capacity) {
+        events.delete_range(&mut wtxn, &(..oldest_kept))?;
+    }
+    wtxn.commit()?;
+    Ok(())
+}
@@ -0,0 +1,135 @@
+use std::path::{Component, Path, PathBuf};
+use std::sync::{Arc, RwLock};
+
+use meilisearch_types::heed::types::{SerdeJson, Str};
+use meilisearch_types::heed::{Database, Env, RwTxn};
+use serde::{Deserialize, Serialize};
+
+use crate::Result;
+
+const SNAPSHOT_SETTINGS: &str = "snapshot-settings";
+
+/// The compression level of the snapshots when it isn't changed, the default one of gzip.
+pub const DEFAULT_SNAPSHOT_COMPRESSION_LEVEL: u32 = 6;
+/// The highest compression level of the snapshots, the smallest and slowest one.
+pub const MAX_SNAPSHOT_COMPRESSION_LEVEL: u32 = 9;
+
+/// The settings of the snapshots, which can be changed while Meilisearch is running.
+///
+/// They are initialized from the launch options until they are changed, the changed
+/// settings are persisted and take precedence over the launch options on the next launches,
+/// the directory excepted when it is not inside the one of the launch options anymore.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotSettings {
+    /// The number of seconds between two scheduled snapshots, `None` when they are disabled.
+    pub interval: Option<u64>,
+    /// The directory in which the snapshots are created.
+    pub dir: PathBuf,
+    /// The gzip compression level of the snapshots, between 0 and 9.
+    pub compression_level: u32,
+    /// The number of snapshots kept in the directory, the latest one included.
+    ///
+    /// The latest snapshot is always named `<db_name>.snapshot`, the previous ones
+    /// are renamed `<db_name>.snapshot.1`, `<db_name>.snapshot.2`... from the newest to the oldest.
+    pub retained: u32,
+    /// The maximum number of bytes per second the databases are copied at while a snapshot
+    /// is created, `None` when the copy isn't throttled.
+    #[serde(default)]
+    pub max_bytes_per_second: Option<u64>,
+}
+
+impl SnapshotSettings {
+    pub fn new(dir: PathBuf, interval: Option<u64>) -> Self {
+        Self {
+            interval,
+            dir,
+            compression_level: DEFAULT_SNAPSHOT_COMPRESSION_LEVEL,
+            retained: 1,
+            max_bytes_per_second: None,
+        }
+    }
+
+    /// The path of the latest snapshot of the database, or of an older one when `generation`
+    /// is not zero.
+    pub(crate) fn snapshot_path(&self, db_name: &str, generation: u32) -> PathBuf {
+        if generation == 0 {
+            self.dir.join(format!("{db_name}.snapshot"))
+        } else {
+            self.dir.join(format!("{db_name}.snapshot.{generation}"))
+        }
+    }
+}
+
+/// The snapshot settings, persisted in the env of the scheduler once they are changed.
+#[derive(Clone)]
+pub(crate) struct SnapshotSettingsData {
+    persisted: Database<Str, SerdeJson<SnapshotSettings>>,
+    runtime: Arc<RwLock<SnapshotSettings>>,
+    /// The directory of the launch options, the snapshots can't be created outside of it.
+    root: PathBuf,
+}
+
+impl SnapshotSettingsData {
+    pub fn new(env: &Env, launch_settings: SnapshotSettings) -> Result<Self> {
+        let mut wtxn = env.write_txn()?;
+        let persisted = env.create_database(&mut wtxn, Some(SNAPSHOT_SETTINGS))?;
+        wtxn.commit()?;
+
+        let rtxn = env.read_txn()?;
+        let root = launch_settings.dir.clone();
+        let settings = match persisted.get(&rtxn, SNAPSHOT_SETTINGS)? {
+            Some(mut settings) => {
+                if !settings.dir.starts_with(&root) {
+                    tracing::warn!(
+                        "The snapshot directory `{}` changed at runtime is not inside the snapshot directory `{}` of the launch options anymore, the snapshots are created in the latter.",
+                        settings.dir.display(),
+                        root.display(),
+                    );
+                    settings.dir = root.clone();
+                } else if settings.dir != root {
+                    tracing::warn!(
+                        "The snapshots are created in the directory `{}` changed at runtime instead of the directory `{}` of the launch options.",
+                        settings.dir.display(),
+                        root.display(),
+                    );
+                }
+                if settings.interval != launch_settings.interval {
+                    tracing::warn!(
+                        "The snapshots are scheduled with the interval {:?} changed at runtime instead of the interval {:?} of the launch options.",
+                        settings.interval,
+                        launch_settings.interval,
+                    );
+                }
+                settings
+            }
+            None => launch_settings,
+        };
+        Ok(Self { persisted, runtime: Arc::new(RwLock::new(settings)), root })
+    }
+
+    /// Returns the path of the snapshot directory `dir` inside the directory of the launch
+    /// options, a relative `dir` is relative to it.
+    ///
+    /// Returns `None` when `dir` is outside of it or goes up to a parent directory.
+    pub fn resolve_dir(&self, dir: &Path) -> Option<PathBuf> {
+        if dir.components().any(|component| matches!(component, Component::ParentDir)) {
+            return None;
+        }
+        let dir = self.root.join(dir);
+        dir.starts_with(&self.root).then_some(dir)
+    }
+
+    pub fn put_settings(&self, mut wtxn: RwTxn, settings: SnapshotSettings) -> Result<()> {
+        self.persisted.put(&mut wtxn, SNAPSHOT_SETTINGS, &settings)?;
+        wtxn.commit()?;
+
+        // sound to unwrap, the lock is only held to copy or assign the settings
+        *self.runtime.write().unwrap() = settings;
+        Ok(())
+    }
+
+    pub fn settings(&self) -> SnapshotSettings {
+        self.runtime.read().unwrap().clone()
+    }
+}
@@ -3,6 +3,7 @@
 use std::collections::{BTreeSet, HashSet};
 use std::ops::Bound;
 
+use meilisearch_types::batches::{Batch, BatchId};
 use meilisearch_types::heed::types::DecodeIgnore;
 use meilisearch_types::heed::{Database, RoTxn, RwTxn};
 use meilisearch_types::milli::CboRoaringBitmapCodec;
@@ -25,6 +26,19 @@ impl IndexScheduler {
         Ok(self.last_task_id(rtxn)?.unwrap_or_default())
     }
 
+    pub(crate) fn next_batch_id(&self, rtxn: &RoTxn) -> Result<BatchId> {
+        Ok(self
+            .all_batches
+            .remap_data_type::<DecodeIgnore>()
+            .last(rtxn)?
+            .map(|(k, _)| k + 1)
+            .unwrap_or_default())
+    }
+
+    pub(crate) fn get_batch(&self, rtxn: &RoTxn, batch_id: BatchId) -> Result<Option<Batch>> {
+        Ok(self.all_batches.get(rtxn, &batch_id)?)
+    }
+
     pub(crate) fn get_task(&self, rtxn: &RoTxn, task_id: TaskId) -> Result<Option<Task>> {
         Ok(self.all_tasks.get(rtxn, &task_id)?)
     }
@@ -114,6 +128,23 @@ impl IndexScheduler {
         Ok(())
     }
 
+    pub(crate) fn update_index_batches(
+        &self,
+        wtxn: &mut RwTxn,
+        index: &str,
+        f: impl Fn(&mut RoaringBitmap),
+    ) -> Result<()> {
+        let mut batches = self.index_batches.get(wtxn, index)?.unwrap_or_default();
+        f(&mut batches);
+        if batches.is_empty() {
+            self.index_batches.delete(wtxn, index)?;
+        } else {
+            self.index_batches.put(wtxn, index, &batches)?;
+        }
+
+        Ok(())
+    }
+
     pub(crate) fn get_status(&self, rtxn: &RoTxn, status: Status) -> Result<RoaringBitmap> {
         Ok(self.status.get(rtxn, &status)?.unwrap_or_default())
     }
@@ -225,6 +256,12 @@ pub(crate) fn keep_tasks_within_datetimes(
 }
 
 // TODO: remove when Bound::map ( https://github.com/rust-lang/rust/issues/86026 ) is available on stable
+/// Returns `true` if the user can access one of the indexes the tasks of the batch are associated with.
+pub(crate) fn is_batch_authorized(batch: &Batch, filters: &meilisearch_auth::AuthFilter) -> bool {
+    filters.all_indexes_authorized()
+        || batch.stats.index_uids.keys().any(|index| filters.is_index_authorized(index))
+}
+
 pub(crate) fn map_bound<T, U>(bound: Bound<T>, map: impl FnOnce(T) -> U) -> Bound<U> {
     match bound {
         Bound::Included(x) => Bound::Included(map(x)),
@@ -238,6 +275,8 @@ pub fn swap_index_uid_in_task(task: &mut Task, swap: (&str, &str)) {
     let mut index_uids = vec![];
     match &mut task.kind {
         K::DocumentAdditionOrUpdate { index_uid, .. } => index_uids.push(index_uid),
+        K::DocumentTransaction { index_uid, .. } => index_uids.push(index_uid),
+        K::DocumentUrlImport { index_uid, .. } => index_uids.push(index_uid),
         K::DocumentEdition { index_uid, .. } => index_uids.push(index_uid),
         K::DocumentDeletion { index_uid, .. } => index_uids.push(index_uid),
         K::DocumentDeletionByFilter { index_uid, .. } => index_uids.push(index_uid),
@@ -260,7 +299,13 @@ pub fn swap_index_uid_in_task(task: &mut Task, swap: (&str, &str)) {
         | K::TaskDeletion { .. }
         | K::DumpCreation { .. }
         | K::SnapshotCreation => (),
+        K::DumpImport { index_uids: imported_index_uids, .. } => index_uids
+            .extend(imported_index_uids.iter_mut().filter(|uid| *uid == swap.0 || *uid == swap.1)),
     };
+    if let Some(Details::DumpImport { imported_indexes, .. }) = &mut task.details {
+        index_uids
+            .extend(imported_indexes.iter_mut().filter(|uid| *uid == swap.0 || *uid == swap.1));
+    }
     if let Some(Details::IndexSwap { swaps }) = &mut task.details {
         for IndexSwap { indexes: (lhs, rhs) } in swaps.iter_mut() {
             if lhs == swap.0 || lhs == swap.1 {
@@ -350,6 +395,7 @@ impl IndexScheduler {
                 details,
                 status,
                 kind,
+                batch_uid,
             } = task;
             assert_eq!(uid, task.uid);
             if let Some(task_index_uid) = &task_index_uid {
@@ -391,6 +437,12 @@ impl IndexScheduler {
                     _ => panic!(),
                 }
             }
+            if let Some(batch_uid) = batch_uid {
+                let db_batch_tasks =
+                    self.batch_to_tasks_mapping.get(&rtxn, &batch_uid).unwrap().unwrap();
+                assert!(db_batch_tasks.contains(uid));
+                assert!(self.all_batches.get(&rtxn, &batch_uid).unwrap().is_some());
+            }
             if let Some(details) = details {
                 match details {
                     Details::IndexSwap { swaps: sw1 } => {
@@ -398,7 +450,11 @@ impl IndexScheduler {
                             assert_eq!(&sw1, sw2);
                         }
                     }
-                    Details::DocumentAdditionOrUpdate { received_documents, indexed_documents } => {
+                    Details::DocumentAdditionOrUpdate {
+                        received_documents,
+                        indexed_documents,
+                        ..
+                    } => {
                         assert_eq!(kind.as_kind(), Kind::DocumentAdditionOrUpdate);
                         match indexed_documents {
                             Some(indexed_documents) => {
@@ -417,6 +473,50 @@ impl IndexScheduler {
                             }
                         }
                     }
+                    Details::DocumentTransaction {
+                        indexed_documents, deleted_documents, ..
+                    } => {
+                        assert_eq!(kind.as_kind(), Kind::DocumentTransaction);
+                        match (indexed_documents, deleted_documents) {
+                            (Some(indexed_documents), Some(deleted_documents)) => {
+                                assert!(matches!(
+                                    status,
+                                    Status::Succeeded | Status::Failed | Status::Canceled
+                                ));
+                                match status {
+                                    Status::Succeeded => (),
+                                    Status::Failed | Status::Canceled => {
+                                        assert_eq!(indexed_documents, 0);
+                                        assert_eq!(deleted_documents, 0);
+                                    }
+                                    status => panic!("DocumentTransaction can't have an indexed_documents set if it's {}", status),
+                                }
+                            }
+                            (None, None) => {
+                                assert!(matches!(status, Status::Enqueued | Status::Processing))
+                            }
+                            _ => panic!("DocumentTransaction must set both its indexed and deleted documents"),
+                        }
+                    }
+                    Details::DocumentUrlImport { indexed_documents, .. } => {
+                        assert_eq!(kind.as_kind(), Kind::DocumentUrlImport);
+                        match indexed_documents {
+                            Some(indexed_documents) => {
+                                assert!(matches!(
+                                    status,
+                                    Status::Succeeded | Status::Failed | Status::Canceled
+                                ));
+                                match status {
+                                    Status::Succeeded => (),
+                                    Status::Failed | Status::Canceled => assert_eq!(indexed_documents, 0),
+                                    status => panic!("DocumentUrlImport can't have an indexed_documents set if it's {}", status),
+                                }
+                            }
+                            None => {
+                                assert!(matches!(status, Status::Enqueued | Status::Processing))
+                            }
+                        }
+                    }
                     Details::DocumentEdition { edited_documents, .. } => {
                         assert_eq!(kind.as_kind(), Kind::DocumentEdition);
                         match edited_documents {
@@ -454,12 +554,14 @@ impl IndexScheduler {
                     Details::DocumentDeletion {
                         provided_ids: received_document_ids,
                         deleted_documents,
+                        ..
                     } => {
                         assert_eq!(kind.as_kind(), Kind::DocumentDeletion);
                         let (index_uid, documents_ids) =
                             if let KindWithContent::DocumentDeletion {
                                 ref index_uid,
                                 ref documents_ids,
+                                ..
                             } = kind
                             {
                                 (index_uid, documents_ids)
@@ -547,13 +649,18 @@ impl IndexScheduler {
                     Details::Dump { dump_uid: _ } => {
                         assert_eq!(kind.as_kind(), Kind::DumpCreation);
                     }
+                    Details::DumpImport { .. } => {
+                        assert_eq!(kind.as_kind(), Kind::DumpImport);
+                    }
                 }
             }
 
             assert!(self.get_status(&rtxn, status).unwrap().contains(uid));
             assert!(self.get_kind(&rtxn, kind.as_kind()).unwrap().contains(uid));
 
-            if let KindWithContent::DocumentAdditionOrUpdate { content_file, .. } = kind {
+            if let KindWithContent::DocumentAdditionOrUpdate { content_file, .. }
+            | KindWithContent::DocumentTransaction { content_file, .. } = kind
+            {
                 match status {
                     Status::Enqueued | Status::Processing => {
                         assert!(self
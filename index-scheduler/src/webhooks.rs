@@ -0,0 +1,140 @@
+use std::sync::mpsc::{self, Receiver, SyncSender, TrySendError};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use meilisearch_types::heed::types::{SerdeJson, Str};
+use meilisearch_types::heed::{Database, Env, RwTxn};
+use serde::{Deserialize, Serialize};
+
+use crate::Result;
+
+const WEBHOOKS: &str = "webhooks";
+
+/// The number of times the notification of a batch is sent again to a webhook that failed to receive it.
+const WEBHOOK_MAX_RETRIES: u32 = 3;
+/// The time waited before the first retry, doubled on every following one.
+const WEBHOOK_RETRY_BACKOFF: Duration = Duration::from_secs(1);
+/// The number of notifications waiting to be sent, the next ones are dropped once it is reached.
+const WEBHOOK_QUEUE_CAPACITY: usize = 100;
+
+/// A URL notified of the tasks processed by every batch.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Webhook {
+    pub url: String,
+    /// The value of the Authorization header sent with the notifications.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub authorization: Option<String>,
+}
+
+/// The notification of the tasks processed by a batch.
+struct Notification {
+    webhooks: Vec<Webhook>,
+    /// The processed tasks, as gzipped ndjson.
+    payload: Vec<u8>,
+}
+
+/// The webhooks registered through the `/webhooks` route.
+///
+/// They are persisted in the env of the scheduler, unlike the webhook of the launch options.
+/// The notifications are sent by a dedicated thread, so the batches never wait for the webhooks.
+#[derive(Clone)]
+pub(crate) struct WebhooksData {
+    persisted: Database<Str, SerdeJson<Vec<Webhook>>>,
+    runtime: Arc<RwLock<Vec<Webhook>>>,
+    notifications: SyncSender<Notification>,
+}
+
+impl WebhooksData {
+    pub fn new(env: &Env) -> Result<Self> {
+        let mut wtxn = env.write_txn()?;
+        let persisted = env.create_database(&mut wtxn, Some(WEBHOOKS))?;
+        wtxn.commit()?;
+
+        let rtxn = env.read_txn()?;
+        let webhooks = persisted.get(&rtxn, WEBHOOKS)?.unwrap_or_default();
+
+        // the thread stops once every clone of the sender, and so of the scheduler, is dropped
+        let (notifications, receiver) = mpsc::sync_channel(WEBHOOK_QUEUE_CAPACITY);
+        std::thread::Builder::new()
+            .name(String::from("webhooks"))
+            .spawn(move || send_notifications(receiver))?;
+
+        Ok(Self { persisted, runtime: Arc::new(RwLock::new(webhooks)), notifications })
+    }
+
+    pub fn put_webhooks(&self, mut wtxn: RwTxn, webhooks: Vec<Webhook>) -> Result<()> {
+        self.persisted.put(&mut wtxn, WEBHOOKS, &webhooks)?;
+        wtxn.commit()?;
+
+        *self.runtime.write().unwrap() = webhooks;
+        Ok(())
+    }
+
+    pub fn webhooks(&self) -> Vec<Webhook> {
+        self.runtime.read().unwrap().clone()
+    }
+
+    /// Queues the `payload`, the gzipped ndjson of the tasks processed by a batch, to be sent
+    /// to the `webhooks`.
+    ///
+    /// The notification is dropped when too many of them are already waiting to be sent.
+    pub fn notify(&self, webhooks: Vec<Webhook>, payload: Vec<u8>) {
+        match self.notifications.try_send(Notification { webhooks, payload }) {
+            Ok(()) => (),
+            Err(TrySendError::Full(_)) => tracing::error!(
+                "The webhooks are too slow to receive the notifications, the notification of a batch was dropped."
+            ),
+            Err(TrySendError::Disconnected(_)) => {
+                tracing::error!("The webhooks can't be notified anymore, their thread stopped.")
+            }
+        }
+    }
+}
+
+fn send_notifications(receiver: Receiver<Notification>) {
+    for Notification { webhooks, payload } in receiver {
+        for webhook in &webhooks {
+            send_notification(webhook, &payload);
+        }
+    }
+}
+
+/// Sends a notification to the webhook.
+///
+/// A webhook that can't be reached or answers with a server error is retried a few times with
+/// an exponential backoff before giving up on this notification.
+fn send_notification(webhook: &Webhook, payload: &[u8]) {
+    let mut retries = 0;
+    loop {
+        let request = ureq::post(&webhook.url)
+            .timeout(Duration::from_secs(30))
+            .set("Content-Encoding", "gzip")
+            .set("Content-Type", "application/x-ndjson");
+        let request = match &webhook.authorization {
+            Some(header) => request.set("Authorization", header),
+            None => request,
+        };
+
+        let error = match request.send(payload) {
+            Ok(_) => return,
+            Err(error) => error,
+        };
+        let retryable = match &error {
+            ureq::Error::Status(status, _) => *status == 429 || *status >= 500,
+            ureq::Error::Transport(_) => true,
+        };
+        if !retryable || retries >= WEBHOOK_MAX_RETRIES {
+            tracing::error!("While sending data to the webhook `{}`: {error}", webhook.url);
+            return;
+        }
+
+        let backoff = WEBHOOK_RETRY_BACKOFF * 2u32.pow(retries);
+        tracing::warn!(
+            "While sending data to the webhook `{}`: {error}, retrying in {backoff:?}",
+            webhook.url
+        );
+        std::thread::sleep(backoff);
+        retries += 1;
+    }
+}
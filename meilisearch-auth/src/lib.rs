@@ -1,16 +1,19 @@
 mod dump;
 pub mod error;
+mod rate_limit;
 mod store;
 
 use std::collections::{HashMap, HashSet};
 use std::path::Path;
 use std::sync::Arc;
+use std::time::Duration;
 
 use error::{AuthControllerError, Result};
 use maplit::hashset;
 use meilisearch_types::index_uid_pattern::IndexUidPattern;
 use meilisearch_types::keys::{Action, CreateApiKey, Key, PatchApiKey};
 use meilisearch_types::milli::update::Setting;
+use rate_limit::RateLimiter;
 use serde::{Deserialize, Serialize};
 pub use store::open_auth_store_env;
 use store::{generate_key_as_hexa, HeedAuthStore};
@@ -21,6 +24,7 @@ use uuid::Uuid;
 pub struct AuthController {
     store: Arc<HeedAuthStore>,
     master_key: Option<String>,
+    rate_limiter: Arc<RateLimiter>,
 }
 
 impl AuthController {
@@ -31,7 +35,11 @@ impl AuthController {
             generate_default_keys(&store)?;
         }
 
-        Ok(Self { store: Arc::new(store), master_key: master_key.clone() })
+        Ok(Self {
+            store: Arc::new(store),
+            master_key: master_key.clone(),
+            rate_limiter: Arc::default(),
+        })
     }
 
     /// Return `Ok(())` if the auth controller is able to access one of its database.
@@ -53,7 +61,11 @@ impl AuthController {
     pub fn create_key(&self, create_key: CreateApiKey) -> Result<Key> {
         match self.store.get_api_key(create_key.uid)? {
             Some(_) => Err(AuthControllerError::ApiKeyAlreadyExists(create_key.uid.to_string())),
-            None => self.store.put_api_key(create_key.to_key()),
+            None => {
+                let key = self.store.put_api_key(create_key.to_key())?;
+                self.rate_limiter.update(key.uid, key.rate_limit);
+                Ok(key)
+            }
         }
     }
 
@@ -67,8 +79,14 @@ impl AuthController {
             Setting::NotSet => (),
             name => key.name = name.set(),
         };
+        match patch.rate_limit {
+            Setting::NotSet => (),
+            rate_limit => key.rate_limit = rate_limit.set(),
+        };
         key.updated_at = OffsetDateTime::now_utc();
-        self.store.put_api_key(key)
+        let key = self.store.put_api_key(key)?;
+        self.rate_limiter.update(uid, key.rate_limit);
+        Ok(key)
     }
 
     pub fn get_key(&self, uid: Uuid) -> Result<Key> {
@@ -111,6 +129,7 @@ impl AuthController {
 
     pub fn delete_key(&self, uid: Uuid) -> Result<()> {
         if self.store.delete_api_key(uid)? {
+            self.rate_limiter.remove(uid);
             Ok(())
         } else {
             Err(AuthControllerError::ApiKeyNotFound(uid.to_string()))
@@ -127,6 +146,19 @@ impl AuthController {
         self.master_key.as_ref().map(|master_key| generate_key_as_hexa(uid, master_key.as_bytes()))
     }
 
+    /// Consumes a request from the rate limit of the key, if it has one.
+    ///
+    /// The key is only read in the database the first time, its rate limit is then cached.
+    /// Returns the time to wait before the next request when the key exceeded its rate limit.
+    pub fn acquire_rate_limit(&self, uid: Uuid) -> Result<Option<Duration>> {
+        if let Some(acquired) = self.rate_limiter.acquire(uid) {
+            return Ok(acquired.err());
+        }
+        let key = self.get_key(uid)?;
+        self.rate_limiter.insert(uid, key.rate_limit);
+        Ok(self.rate_limiter.acquire(uid).and_then(Result::err))
+    }
+
     /// Check if the provided key is authorized to make a specific action
     /// without checking if the key is valid.
     pub fn is_key_authorized(
@@ -156,12 +188,15 @@ impl AuthController {
 
     /// Delete all the keys in the DB.
     pub fn raw_delete_all_keys(&mut self) -> Result<()> {
-        self.store.delete_all_keys()
+        self.store.delete_all_keys()?;
+        self.rate_limiter.clear();
+        Ok(())
     }
 
     /// Delete all the keys in the DB.
     pub fn raw_insert_key(&mut self, key: Key) -> Result<()> {
-        self.store.put_api_key(key)?;
+        let key = self.store.put_api_key(key)?;
+        self.rate_limiter.update(key.uid, key.rate_limit);
         Ok(())
     }
 }
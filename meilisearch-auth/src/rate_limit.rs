@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use meilisearch_types::keys::RateLimit;
+use uuid::Uuid;
+
+/// The token buckets of the API keys with a rate limit.
+///
+/// The rate limits of the keys are cached along with their buckets so that a request doesn't
+/// read the key in the database, the cache must be updated whenever a key changes.
+/// The buckets are only kept in memory, a restart gives all the keys a full bucket.
+#[derive(Default)]
+pub struct RateLimiter {
+    /// The keys already seen, with `None` for the ones without a rate limit.
+    buckets: Mutex<HashMap<Uuid, Option<TokenBucket>>>,
+}
+
+struct TokenBucket {
+    rate_limit: RateLimit,
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+impl TokenBucket {
+    fn new(rate_limit: RateLimit, now: Instant) -> Self {
+        Self { rate_limit, tokens: rate_limit.capacity() as f64, refilled_at: now }
+    }
+
+    fn acquire(&mut self, now: Instant) -> Result<(), Duration> {
+        let rate = self.rate_limit.requests_per_second as f64;
+        let elapsed = now.saturating_duration_since(self.refilled_at).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * rate).min(self.rate_limit.capacity() as f64);
+        self.refilled_at = now;
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64((1.0 - self.tokens) / rate))
+        }
+    }
+}
+
+impl RateLimiter {
+    /// Consumes a request from the bucket of the key, `None` when the rate limit of the key
+    /// isn't cached yet.
+    ///
+    /// Returns the time to wait before the next request can be made when the bucket is empty.
+    pub fn acquire(&self, uid: Uuid) -> Option<Result<(), Duration>> {
+        let mut buckets = self.buckets.lock().unwrap();
+        match buckets.get_mut(&uid)? {
+            Some(bucket) => Some(bucket.acquire(Instant::now())),
+            None => Some(Ok(())),
+        }
+    }
+
+    /// Caches the rate limit read from the database, unless the key was updated meanwhile.
+    pub fn insert(&self, uid: Uuid, rate_limit: Option<RateLimit>) {
+        let now = Instant::now();
+        let mut buckets = self.buckets.lock().unwrap();
+        buckets.entry(uid).or_insert_with(|| rate_limit.map(|limit| TokenBucket::new(limit, now)));
+    }
+
+    /// Caches the new rate limit of a key, the bucket starts over when the rate limit changed.
+    pub fn update(&self, uid: Uuid, rate_limit: Option<RateLimit>) {
+        let now = Instant::now();
+        let mut buckets = self.buckets.lock().unwrap();
+        let bucket = buckets.entry(uid).or_default();
+        if bucket.as_ref().map(|bucket| bucket.rate_limit) != rate_limit {
+            *bucket = rate_limit.map(|limit| TokenBucket::new(limit, now));
+        }
+    }
+
+    /// Forgets a deleted key.
+    pub fn remove(&self, uid: Uuid) {
+        self.buckets.lock().unwrap().remove(&uid);
+    }
+
+    /// Forgets all the keys, when they are replaced in the database.
+    pub fn clear(&self) {
+        self.buckets.lock().unwrap().clear();
+    }
+}
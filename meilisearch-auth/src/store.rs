@@ -132,7 +132,11 @@ impl HeedAuthStore {
                     actions.insert(Action::DumpsCreate);
                 }
                 Action::SnapshotsAll => {
-                    actions.insert(Action::SnapshotsCreate);
+                    actions.extend([
+                        Action::SnapshotsCreate,
+                        Action::SnapshotsSettingsGet,
+                        Action::SnapshotsSettingsUpdate,
+                    ]);
                 }
                 Action::TasksAll => {
                     actions.extend([Action::TasksGet, Action::TasksDelete, Action::TasksCancel]);
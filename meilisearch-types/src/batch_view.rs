@@ -0,0 +1,65 @@
+use serde::Serialize;
+use time::{Duration, OffsetDateTime};
+
+use crate::batches::{Batch, BatchId, BatchStats, BatchStep};
+use crate::tasks::serialize_duration;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchView {
+    pub uid: BatchId,
+    pub progress: Option<BatchProgressView>,
+    pub stats: BatchStats,
+    pub steps: Vec<BatchStepView>,
+    #[serde(serialize_with = "serialize_duration", default)]
+    pub duration: Option<Duration>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub started_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339::option", default)]
+    pub finished_at: Option<OffsetDateTime>,
+}
+
+impl BatchView {
+    pub fn from_batch(batch: &Batch) -> BatchView {
+        BatchView {
+            uid: batch.uid,
+            progress: batch
+                .current_step()
+                .map(|step| BatchProgressView { current_step: step.name.clone() }),
+            stats: batch.stats.clone(),
+            steps: batch.steps.iter().map(BatchStepView::from_step).collect(),
+            duration: batch.finished_at.map(|finished_at| finished_at - batch.started_at),
+            started_at: batch.started_at,
+            finished_at: batch.finished_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchProgressView {
+    pub current_step: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchStepView {
+    pub name: String,
+    #[serde(serialize_with = "serialize_duration", default)]
+    pub duration: Option<Duration>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub started_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339::option", default)]
+    pub finished_at: Option<OffsetDateTime>,
+}
+
+impl BatchStepView {
+    fn from_step(step: &BatchStep) -> BatchStepView {
+        BatchStepView {
+            name: step.name.clone(),
+            duration: step.finished_at.map(|finished_at| finished_at - step.started_at),
+            started_at: step.started_at,
+            finished_at: step.finished_at,
+        }
+    }
+}
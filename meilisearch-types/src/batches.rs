@@ -0,0 +1,98 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+use crate::tasks::{Kind, Status, Task};
+
+pub type BatchId = u32;
+
+/// A set of tasks processed together by the scheduler.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Batch {
+    pub uid: BatchId,
+    pub stats: BatchStats,
+    /// The steps the batch went through, in the order they were executed.
+    pub steps: Vec<BatchStep>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub started_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub finished_at: Option<OffsetDateTime>,
+}
+
+impl Batch {
+    pub fn new(uid: BatchId, started_at: OffsetDateTime) -> Self {
+        Batch {
+            uid,
+            stats: BatchStats::default(),
+            steps: Vec::new(),
+            started_at,
+            finished_at: None,
+        }
+    }
+
+    /// Returns the step the batch is going through, `None` once it is finished.
+    pub fn current_step(&self) -> Option<&BatchStep> {
+        self.steps.last().filter(|step| step.finished_at.is_none())
+    }
+
+    /// Finishes the current step and starts a new one at the given date.
+    pub fn start_step(&mut self, name: &str, at: OffsetDateTime) {
+        self.finish_step(at);
+        self.steps.push(BatchStep { name: name.to_string(), started_at: at, finished_at: None });
+    }
+
+    /// Finishes the current step and the batch itself.
+    pub fn finish(&mut self, at: OffsetDateTime) {
+        self.finish_step(at);
+        self.finished_at = Some(at);
+    }
+
+    fn finish_step(&mut self, at: OffsetDateTime) {
+        if let Some(step) = self.steps.last_mut().filter(|step| step.finished_at.is_none()) {
+            step.finished_at = Some(at);
+        }
+    }
+}
+
+/// The number of tasks of a batch, grouped by status, type and index.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchStats {
+    pub total_nb_tasks: u32,
+    pub status: BTreeMap<Status, u32>,
+    pub types: BTreeMap<Kind, u32>,
+    pub index_uids: BTreeMap<String, u32>,
+}
+
+impl BatchStats {
+    pub fn from_tasks<'a>(tasks: impl IntoIterator<Item = &'a Task>) -> Self {
+        let mut stats = BatchStats::default();
+        for task in tasks {
+            stats.register(task);
+        }
+        stats
+    }
+
+    /// Counts one more task in the stats.
+    pub fn register(&mut self, task: &Task) {
+        self.total_nb_tasks += 1;
+        *self.status.entry(task.status).or_default() += 1;
+        *self.types.entry(task.kind.as_kind()).or_default() += 1;
+        if let Some(index_uid) = task.index_uid() {
+            *self.index_uids.entry(index_uid.to_string()).or_default() += 1;
+        }
+    }
+}
+
+/// An internal step of the processing of a batch, used to report its progress and timings.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchStep {
+    pub name: String,
+    #[serde(with = "time::serde::rfc3339")]
+    pub started_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub finished_at: Option<OffsetDateTime>,
+}
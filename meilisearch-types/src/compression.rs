@@ -1,15 +1,26 @@
 use std::fs::{create_dir_all, File};
-use std::io::Write;
-use std::path::Path;
+use std::io::{self, Read, Write};
+use std::num::NonZeroU64;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 
 use flate2::read::GzDecoder;
 use flate2::write::GzEncoder;
 use flate2::Compression;
-use tar::{Archive, Builder};
+use tar::{Archive, Builder, Header};
 
 pub fn to_tar_gz(src: impl AsRef<Path>, dest: impl AsRef<Path>) -> anyhow::Result<()> {
+    to_tar_gz_with_level(src, dest, Compression::default().level())
+}
+
+/// Tarballs the content of `src` into `dest`, compressed with a gzip level between 0 and 9.
+pub fn to_tar_gz_with_level(
+    src: impl AsRef<Path>,
+    dest: impl AsRef<Path>,
+    level: u32,
+) -> anyhow::Result<()> {
     let mut f = File::create(dest)?;
-    let gz_encoder = GzEncoder::new(&mut f, Compression::default());
+    let gz_encoder = GzEncoder::new(&mut f, Compression::new(level));
     let mut tar_encoder = Builder::new(gz_encoder);
     tar_encoder.append_dir_all(".", src)?;
     let gz_encoder = tar_encoder.into_inner()?;
@@ -18,6 +29,119 @@ pub fn to_tar_gz(src: impl AsRef<Path>, dest: impl AsRef<Path>) -> anyhow::Resul
     Ok(())
 }
 
+/// A tarball compressed with gzip written one entry at a time.
+///
+/// The files are streamed into the archive as they are appended, so a file that was created
+/// only to be archived can be removed before the next one is created.
+pub struct TarGzWriter {
+    builder: Builder<GzEncoder<File>>,
+    /// The directory of the tarball, the copies of the LMDB envs are written in it.
+    temp_dir: PathBuf,
+    /// The maximum number of bytes of the LMDB envs read per second, unlimited when `None`.
+    max_bytes_per_second: Option<NonZeroU64>,
+}
+
+impl TarGzWriter {
+    /// Creates the tarball at `dest`, compressed with a gzip level between 0 and 9.
+    pub fn new(dest: impl AsRef<Path>, level: u32) -> anyhow::Result<Self> {
+        let dest = dest.as_ref();
+        let file = File::create(dest)?;
+        let temp_dir = match dest.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+            _ => PathBuf::from("."),
+        };
+        Ok(Self {
+            builder: Builder::new(GzEncoder::new(file, Compression::new(level))),
+            temp_dir,
+            max_bytes_per_second: None,
+        })
+    }
+
+    /// Limits the number of bytes per second the copies of the LMDB envs are archived at, so it
+    /// doesn't saturate the disk used by the searches and the registration of the tasks.
+    pub fn throttle(&mut self, max_bytes_per_second: Option<NonZeroU64>) {
+        self.max_bytes_per_second = max_bytes_per_second;
+    }
+
+    /// Appends an empty directory named `name`, with the metadata of the `src` directory.
+    pub fn append_dir(
+        &mut self,
+        name: impl AsRef<Path>,
+        src: impl AsRef<Path>,
+    ) -> anyhow::Result<()> {
+        self.builder.append_dir(name, src)?;
+        Ok(())
+    }
+
+    /// Appends the content of the `src` file under the given `name`.
+    pub fn append_file(
+        &mut self,
+        name: impl AsRef<Path>,
+        src: impl AsRef<Path>,
+    ) -> anyhow::Result<()> {
+        self.builder.append_path_with_name(src, name)?;
+        Ok(())
+    }
+
+    /// Appends a compacted copy of an LMDB env under the given `name`.
+    ///
+    /// `copy` must write the copy of the env at the given path with the compaction enabled,
+    /// e.g. with `Env::copy_to_file`. The copy is written into a temporary directory next to
+    /// the tarball, streamed into it at the throttled rate, then removed.
+    pub fn append_compacted_env(
+        &mut self,
+        name: impl AsRef<Path>,
+        copy: impl FnOnce(&Path) -> anyhow::Result<()>,
+    ) -> anyhow::Result<()> {
+        let temp_dir = tempfile::tempdir_in(&self.temp_dir)?;
+        let path = temp_dir.path().join("data.mdb");
+        copy(&path)?;
+
+        let file = File::open(&path)?;
+        let mut header = Header::new_gnu();
+        header.set_metadata(&file.metadata()?);
+        let reader = Throttled::new(file, self.max_bytes_per_second);
+        self.builder.append_data(&mut header, name, reader)?;
+        Ok(())
+    }
+
+    /// Writes the end of the tarball and flushes it to the disk.
+    pub fn finish(self) -> anyhow::Result<()> {
+        let gz_encoder = self.builder.into_inner()?;
+        let mut file = gz_encoder.finish()?;
+        file.flush()?;
+        Ok(())
+    }
+}
+
+/// A reader that never reads more than a number of bytes per second on average.
+struct Throttled<R> {
+    inner: R,
+    max_bytes_per_second: Option<NonZeroU64>,
+    started_at: Instant,
+    read_bytes: u64,
+}
+
+impl<R> Throttled<R> {
+    fn new(inner: R, max_bytes_per_second: Option<NonZeroU64>) -> Self {
+        Self { inner, max_bytes_per_second, started_at: Instant::now(), read_bytes: 0 }
+    }
+}
+
+impl<R: Read> Read for Throttled<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.read_bytes += read as u64;
+        if let Some(max) = self.max_bytes_per_second {
+            let expected = Duration::from_secs_f64(self.read_bytes as f64 / max.get() as f64);
+            if let Some(ahead) = expected.checked_sub(self.started_at.elapsed()) {
+                std::thread::sleep(ahead);
+            }
+        }
+        Ok(read)
+    }
+}
+
 pub fn from_tar_gz(src: impl AsRef<Path>, dest: impl AsRef<Path>) -> anyhow::Result<()> {
     let f = File::open(&src)?;
     let gz = GzDecoder::new(f);
@@ -26,3 +150,24 @@ pub fn from_tar_gz(src: impl AsRef<Path>, dest: impl AsRef<Path>) -> anyhow::Res
     ar.unpack(&dest)?;
     Ok(())
 }
+
+/// Unpacks into `dest` only the entries of the tarball at `src` whose path is one of `paths`.
+pub fn from_tar_gz_entries(
+    src: impl AsRef<Path>,
+    dest: impl AsRef<Path>,
+    paths: &[&str],
+) -> anyhow::Result<()> {
+    let f = File::open(&src)?;
+    let gz = GzDecoder::new(f);
+    let mut ar = Archive::new(gz);
+    create_dir_all(&dest)?;
+    for entry in ar.entries()? {
+        let mut entry = entry?;
+        let path = entry.path()?;
+        let path = path.strip_prefix(".").unwrap_or(&path);
+        if paths.iter().any(|wanted| path == Path::new(wanted)) {
+            entry.unpack_in(&dest)?;
+        }
+    }
+    Ok(())
+}
@@ -12,6 +12,7 @@ use crate::error::{
     ParseOffsetDateTimeError,
 };
 use crate::index_uid::IndexUidFormatError;
+use crate::index_uid_pattern::IndexUidPatternFormatError;
 use crate::tasks::{ParseTaskKindError, ParseTaskStatusError};
 
 pub mod query_params;
@@ -159,6 +160,12 @@ make_missing_field_convenience_builder!(
     MissingDocumentEditionFunction,
     missing_document_edition_function
 );
+make_missing_field_convenience_builder!(
+    MissingDocumentTransactionOperations,
+    missing_document_transaction_operations
+);
+make_missing_field_convenience_builder!(MissingDocumentImportUrl, missing_document_import_url);
+make_missing_field_convenience_builder!(MissingDumpImportUrl, missing_dump_import_url);
 
 // Integrate a sub-error into a [`DeserrError`] by taking its error message but using
 // the default error code (C) from `Self`
@@ -192,6 +199,23 @@ merge_with_error_impl_take_error_message!(ParseOffsetDateTimeError);
 merge_with_error_impl_take_error_message!(ParseTaskKindError);
 merge_with_error_impl_take_error_message!(ParseTaskStatusError);
 merge_with_error_impl_take_error_message!(IndexUidFormatError);
+
+// The JSON errors already take the message of this error, in `keys.rs`.
+impl<C: Default + ErrorCode> MergeWithError<IndexUidPatternFormatError>
+    for DeserrQueryParamError<C>
+{
+    fn merge(
+        _self_: Option<Self>,
+        other: IndexUidPatternFormatError,
+        merge_location: ValuePointerRef,
+    ) -> ControlFlow<Self, Self> {
+        DeserrQueryParamError::<C>::error::<Infallible>(
+            None,
+            deserr::ErrorKind::Unexpected { msg: other.to_string() },
+            merge_location,
+        )
+    }
+}
 merge_with_error_impl_take_error_message!(InvalidMultiSearchWeight);
 merge_with_error_impl_take_error_message!(InvalidSearchSemanticRatio);
 merge_with_error_impl_take_error_message!(InvalidSearchRankingScoreThreshold);
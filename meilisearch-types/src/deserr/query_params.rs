@@ -19,6 +19,7 @@ use deserr::{DeserializeError, Deserr, MergeWithError, ValueKind};
 
 use super::{DeserrParseBoolError, DeserrParseIntError};
 use crate::index_uid::IndexUid;
+use crate::index_uid_pattern::IndexUidPattern;
 use crate::tasks::{Kind, Status};
 
 /// A wrapper type indicating that the inner value should be
@@ -88,6 +89,7 @@ macro_rules! impl_from_query_param_from_str {
 impl_from_query_param_from_str!(Kind);
 impl_from_query_param_from_str!(Status);
 impl_from_query_param_from_str!(IndexUid);
+impl_from_query_param_from_str!(IndexUidPattern);
 
 /// Implement `FromQueryParameter` for the given type using its `FromStr`
 /// trait implementation, replacing the returned error with a struct
@@ -7,7 +7,7 @@ use memmap2::MmapOptions;
 use milli::documents::{DocumentsBatchBuilder, Error};
 use milli::Object;
 use serde::de::{SeqAccess, Visitor};
-use serde::{Deserialize, Deserializer};
+use serde::{Deserialize, Deserializer, Serialize};
 use serde_json::error::Category;
 
 use crate::error::{Code, ErrorCode};
@@ -166,6 +166,48 @@ pub fn read_ndjson(file: &File, writer: impl Write) -> Result<u64> {
     Ok(count as u64)
 }
 
+/// A document of a payload that was skipped because it couldn't be parsed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkippedDocument {
+    /// The line of the document in the payload, starting at 1.
+    pub line: usize,
+    pub reason: String,
+}
+
+/// Reads NDJSON like [`read_ndjson`] but skips the lines that aren't JSON objects instead of failing.
+///
+/// Returns the number of documents written along with the skipped lines.
+pub fn read_ndjson_skipping_invalid(
+    file: &File,
+    writer: impl Write,
+) -> Result<(u64, Vec<SkippedDocument>)> {
+    let mut builder = DocumentsBatchBuilder::new(BufWriter::new(writer));
+    let mmap = unsafe { MmapOptions::new().map(file)? };
+    let mut skipped = Vec::new();
+
+    for (i, line) in mmap.split(|b| *b == b'\n').enumerate() {
+        if line.iter().all(u8::is_ascii_whitespace) {
+            continue;
+        }
+        match serde_json::from_slice::<Object>(line) {
+            Ok(object) => builder.append_json_object(&object).map_err(DocumentFormatError::Io)?,
+            Err(e) => {
+                let reason = match e.classify() {
+                    Category::Data => "the document is not an object".to_string(),
+                    _ => e.to_string(),
+                };
+                skipped.push(SkippedDocument { line: i + 1, reason });
+            }
+        }
+    }
+
+    let count = builder.documents_count();
+    let _ = builder.into_inner().map_err(DocumentFormatError::Io)?;
+
+    Ok((count as u64, skipped))
+}
+
 /// The actual handling of the deserialization process in serde
 /// avoids storing the deserialized object in memory.
 ///
@@ -20,6 +20,9 @@ pub struct ResponseError {
     error_type: String,
     #[serde(rename = "link")]
     error_link: String,
+    /// The number of seconds after which the request can be retried.
+    #[serde(skip)]
+    retry_after: Option<u64>,
 }
 
 impl ResponseError {
@@ -33,8 +36,15 @@ impl ResponseError {
             error_code: code.name(),
             error_type: code.type_(),
             error_link: code.url(),
+            retry_after: None,
         }
     }
+
+    /// Sends a `Retry-After` header with the given number of seconds along with the error.
+    pub fn with_retry_after(mut self, seconds: u64) -> Self {
+        self.retry_after = Some(seconds);
+        self
+    }
 }
 
 impl fmt::Display for ResponseError {
@@ -60,7 +70,9 @@ impl aweb::error::ResponseError for ResponseError {
         let mut builder = HttpResponseBuilder::new(self.status_code());
         builder.content_type("application/json");
 
-        if self.code == StatusCode::SERVICE_UNAVAILABLE {
+        if let Some(seconds) = self.retry_after {
+            builder.insert_header((header::RETRY_AFTER, seconds.to_string()));
+        } else if self.code == StatusCode::SERVICE_UNAVAILABLE {
             builder.insert_header((header::RETRY_AFTER, "10"));
         }
 
@@ -185,13 +197,18 @@ macro_rules! make_error_codes {
 make_error_codes! {
 ApiKeyAlreadyExists                   , InvalidRequest       , CONFLICT ;
 ApiKeyNotFound                        , InvalidRequest       , NOT_FOUND ;
+ApiKeyRateLimitExceeded               , Auth                 , TOO_MANY_REQUESTS ;
 BadParameter                          , InvalidRequest       , BAD_REQUEST;
 BadRequest                            , InvalidRequest       , BAD_REQUEST;
+BatchNotFound                         , InvalidRequest       , NOT_FOUND;
+ConsistencyTimeout                    , System               , SERVICE_UNAVAILABLE;
 DatabaseSizeLimitReached              , Internal             , INTERNAL_SERVER_ERROR;
 DocumentNotFound                      , InvalidRequest       , NOT_FOUND;
 DumpAlreadyProcessing                 , InvalidRequest       , CONFLICT;
 DumpNotFound                          , InvalidRequest       , NOT_FOUND;
 DumpProcessFailed                     , Internal             , INTERNAL_SERVER_ERROR;
+DumpUrlImportFailed                   , InvalidRequest       , BAD_REQUEST ;
+DuplicateDocumentId                   , InvalidRequest       , BAD_REQUEST;
 DuplicateIndexFound                   , InvalidRequest       , BAD_REQUEST;
 ImmutableApiKeyActions                , InvalidRequest       , BAD_REQUEST;
 ImmutableApiKeyCreatedAt              , InvalidRequest       , BAD_REQUEST;
@@ -209,8 +226,12 @@ IndexNotFound                         , InvalidRequest       , NOT_FOUND;
 IndexPrimaryKeyAlreadyExists          , InvalidRequest       , BAD_REQUEST ;
 IndexPrimaryKeyMultipleCandidatesFound, InvalidRequest       , BAD_REQUEST;
 IndexPrimaryKeyNoCandidateFound       , InvalidRequest       , BAD_REQUEST ;
+IndexReadOnly                         , InvalidRequest       , FORBIDDEN ;
 Internal                              , Internal             , INTERNAL_SERVER_ERROR ;
 InvalidApiKey                         , Auth                 , FORBIDDEN ;
+InvalidBatchFrom                      , InvalidRequest       , BAD_REQUEST ;
+InvalidBatchLimit                     , InvalidRequest       , BAD_REQUEST ;
+InvalidBatchUids                      , InvalidRequest       , BAD_REQUEST ;
 InvalidApiKeyActions                  , InvalidRequest       , BAD_REQUEST ;
 InvalidApiKeyDescription              , InvalidRequest       , BAD_REQUEST ;
 InvalidApiKeyExpiresAt                , InvalidRequest       , BAD_REQUEST ;
@@ -218,48 +239,81 @@ InvalidApiKeyIndexes                  , InvalidRequest       , BAD_REQUEST ;
 InvalidApiKeyLimit                    , InvalidRequest       , BAD_REQUEST ;
 InvalidApiKeyName                     , InvalidRequest       , BAD_REQUEST ;
 InvalidApiKeyOffset                   , InvalidRequest       , BAD_REQUEST ;
+InvalidApiKeyRateLimit                , InvalidRequest       , BAD_REQUEST ;
 InvalidApiKeyUid                      , InvalidRequest       , BAD_REQUEST ;
 InvalidContentType                    , InvalidRequest       , UNSUPPORTED_MEDIA_TYPE ;
 InvalidDocumentCsvDelimiter           , InvalidRequest       , BAD_REQUEST ;
 InvalidDocumentFields                 , InvalidRequest       , BAD_REQUEST ;
 InvalidDocumentRetrieveVectors        , InvalidRequest       , BAD_REQUEST ;
+InvalidDocumentReportOutcomes         , InvalidRequest       , BAD_REQUEST ;
+InvalidDocumentOnError                , InvalidRequest       , BAD_REQUEST ;
 MissingDocumentFilter                 , InvalidRequest       , BAD_REQUEST ;
 MissingDocumentEditionFunction        , InvalidRequest       , BAD_REQUEST ;
+MissingDocumentTransactionOperations  , InvalidRequest       , BAD_REQUEST ;
+MissingDocumentImportUrl              , InvalidRequest       , BAD_REQUEST ;
 InvalidDocumentFilter                 , InvalidRequest       , BAD_REQUEST ;
+InvalidDocumentFrom                   , InvalidRequest       , BAD_REQUEST ;
 InvalidDocumentGeoField               , InvalidRequest       , BAD_REQUEST ;
+InvalidDocumentEtag                   , InvalidRequest       , BAD_REQUEST ;
+InvalidDocumentAttributeTransform     , InvalidRequest       , BAD_REQUEST ;
+InvalidDocumentTransactionOperations  , InvalidRequest       , BAD_REQUEST ;
+InvalidDocumentImportUrl              , InvalidRequest       , BAD_REQUEST ;
+DocumentUrlImportFailed               , InvalidRequest       , BAD_REQUEST ;
 InvalidVectorDimensions               , InvalidRequest       , BAD_REQUEST ;
 InvalidVectorsType                    , InvalidRequest       , BAD_REQUEST ;
 InvalidDocumentId                     , InvalidRequest       , BAD_REQUEST ;
 InvalidDocumentLimit                  , InvalidRequest       , BAD_REQUEST ;
 InvalidDocumentOffset                 , InvalidRequest       , BAD_REQUEST ;
+InvalidDumpSettingsOnly               , InvalidRequest       , BAD_REQUEST ;
+InvalidDumpSince                      , InvalidRequest       , BAD_REQUEST ;
+InvalidDumpImportNamespace            , InvalidRequest       , BAD_REQUEST ;
+InvalidDumpImportUrl                  , InvalidRequest       , BAD_REQUEST ;
+InvalidDumpArchive                    , InvalidRequest       , BAD_REQUEST ;
+MissingDumpImportUrl                  , InvalidRequest       , BAD_REQUEST ;
 InvalidEmbedder                       , InvalidRequest       , BAD_REQUEST ;
 InvalidHybridQuery                    , InvalidRequest       , BAD_REQUEST ;
 InvalidIndexLimit                     , InvalidRequest       , BAD_REQUEST ;
 InvalidIndexOffset                    , InvalidRequest       , BAD_REQUEST ;
 InvalidIndexPrimaryKey                , InvalidRequest       , BAD_REQUEST ;
 InvalidIndexUid                       , InvalidRequest       , BAD_REQUEST ;
+InvalidMaintenanceEnabled             , InvalidRequest       , BAD_REQUEST ;
+InvalidSnapshotArchive                , InvalidRequest       , BAD_REQUEST ;
+InvalidSnapshotInterval               , InvalidRequest       , BAD_REQUEST ;
+InvalidSnapshotDir                    , InvalidRequest       , BAD_REQUEST ;
+InvalidSnapshotCompressionLevel       , InvalidRequest       , BAD_REQUEST ;
+InvalidSnapshotRetained               , InvalidRequest       , BAD_REQUEST ;
+InvalidSnapshotMaxBytesPerSecond      , InvalidRequest       , BAD_REQUEST ;
 InvalidMultiSearchFederated           , InvalidRequest       , BAD_REQUEST ;
 InvalidMultiSearchFederationOptions   , InvalidRequest       , BAD_REQUEST ;
 InvalidMultiSearchQueryPagination     , InvalidRequest       , BAD_REQUEST ;
 InvalidMultiSearchQueryRankingRules   , InvalidRequest       , BAD_REQUEST ;
 InvalidMultiSearchWeight              , InvalidRequest       , BAD_REQUEST ;
+InvalidSearchAttributesFormatting     , InvalidRequest       , BAD_REQUEST ;
 InvalidSearchAttributesToSearchOn     , InvalidRequest       , BAD_REQUEST ;
 InvalidSearchAttributesToCrop         , InvalidRequest       , BAD_REQUEST ;
 InvalidSearchAttributesToHighlight    , InvalidRequest       , BAD_REQUEST ;
+InvalidSearchBoostingFilter           , InvalidRequest       , BAD_REQUEST ;
 InvalidSimilarAttributesToRetrieve    , InvalidRequest       , BAD_REQUEST ;
 InvalidSimilarRetrieveVectors         , InvalidRequest       , BAD_REQUEST ;
 InvalidSearchAttributesToRetrieve     , InvalidRequest       , BAD_REQUEST ;
+InvalidSearchRankingRules             , InvalidRequest       , BAD_REQUEST ;
 InvalidSearchRankingScoreThreshold    , InvalidRequest       , BAD_REQUEST ;
 InvalidSimilarRankingScoreThreshold   , InvalidRequest       , BAD_REQUEST ;
 InvalidSearchRetrieveVectors          , InvalidRequest       , BAD_REQUEST ;
+InvalidSearchComputeTotalHits         , InvalidRequest       , BAD_REQUEST ;
+InvalidSearchCountOnly                , InvalidRequest       , BAD_REQUEST ;
 InvalidSearchCropLength               , InvalidRequest       , BAD_REQUEST ;
 InvalidSearchCropMarker               , InvalidRequest       , BAD_REQUEST ;
+InvalidSearchPrefixHighlight          , InvalidRequest       , BAD_REQUEST ;
+InvalidSearchCrossAttributeMatching   , InvalidRequest       , BAD_REQUEST ;
 InvalidSearchFacets                   , InvalidRequest       , BAD_REQUEST ;
 InvalidSearchSemanticRatio            , InvalidRequest       , BAD_REQUEST ;
 InvalidSearchLocales                  , InvalidRequest       , BAD_REQUEST ;
+InvalidSearchMinConsistency           , InvalidRequest       , BAD_REQUEST ;
 InvalidFacetSearchFacetName           , InvalidRequest       , BAD_REQUEST ;
 InvalidSimilarId                      , InvalidRequest       , BAD_REQUEST ;
 InvalidSearchFilter                   , InvalidRequest       , BAD_REQUEST ;
+InvalidSearchFilterRelaxation         , InvalidRequest       , BAD_REQUEST ;
 InvalidSimilarFilter                  , InvalidRequest       , BAD_REQUEST ;
 InvalidSearchHighlightPostTag         , InvalidRequest       , BAD_REQUEST ;
 InvalidSearchHighlightPreTag          , InvalidRequest       , BAD_REQUEST ;
@@ -267,27 +321,71 @@ InvalidSearchHitsPerPage              , InvalidRequest       , BAD_REQUEST ;
 InvalidSimilarLimit                   , InvalidRequest       , BAD_REQUEST ;
 InvalidSearchLimit                    , InvalidRequest       , BAD_REQUEST ;
 InvalidSearchMatchingStrategy         , InvalidRequest       , BAD_REQUEST ;
+InvalidSearchMaxSynonymExpansions     , InvalidRequest       , BAD_REQUEST ;
 InvalidSimilarOffset                  , InvalidRequest       , BAD_REQUEST ;
 InvalidSearchOffset                   , InvalidRequest       , BAD_REQUEST ;
 InvalidSearchPage                     , InvalidRequest       , BAD_REQUEST ;
 InvalidSearchQ                        , InvalidRequest       , BAD_REQUEST ;
+InvalidSuggestQ                       , InvalidRequest       , BAD_REQUEST ;
+InvalidSuggestAttribute               , InvalidRequest       , BAD_REQUEST ;
+InvalidSuggestLimit                   , InvalidRequest       , BAD_REQUEST ;
+InvalidSearchStatsLimit               , InvalidRequest       , BAD_REQUEST ;
 InvalidFacetSearchQuery               , InvalidRequest       , BAD_REQUEST ;
+InvalidFacetSearchFuzzy               , InvalidRequest       , BAD_REQUEST ;
 InvalidFacetSearchName                , InvalidRequest       , BAD_REQUEST ;
 InvalidSearchVector                   , InvalidRequest       , BAD_REQUEST ;
 InvalidSearchShowMatchesPosition      , InvalidRequest       , BAD_REQUEST ;
+InvalidSearchShowMatchesInfo          , InvalidRequest       , BAD_REQUEST ;
+InvalidSearchShowQueryCoverage        , InvalidRequest       , BAD_REQUEST ;
+InvalidSearchEnableFormatting         , InvalidRequest       , BAD_REQUEST ;
+InvalidSearchShowSuggestion           , InvalidRequest       , BAD_REQUEST ;
+InvalidSearchSuggestionMaxHits        , InvalidRequest       , BAD_REQUEST ;
+InvalidSearchShowQueryAnalysis        , InvalidRequest       , BAD_REQUEST ;
 InvalidSearchShowRankingScore         , InvalidRequest       , BAD_REQUEST ;
 InvalidSimilarShowRankingScore        , InvalidRequest       , BAD_REQUEST ;
 InvalidSearchShowRankingScoreDetails  , InvalidRequest       , BAD_REQUEST ;
 InvalidSimilarShowRankingScoreDetails , InvalidRequest       , BAD_REQUEST ;
 InvalidSearchSort                     , InvalidRequest       , BAD_REQUEST ;
 InvalidSearchDistinct                 , InvalidRequest       , BAD_REQUEST ;
+InvalidSearchGroupBy                  , InvalidRequest       , BAD_REQUEST ;
+InvalidSearchGroupLimit               , InvalidRequest       , BAD_REQUEST ;
 InvalidSettingsDisplayedAttributes    , InvalidRequest       , BAD_REQUEST ;
 InvalidSettingsDistinctAttribute      , InvalidRequest       , BAD_REQUEST ;
 InvalidSettingsProximityPrecision     , InvalidRequest       , BAD_REQUEST ;
 InvalidSettingsFaceting               , InvalidRequest       , BAD_REQUEST ;
 InvalidSettingsFilterableAttributes   , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsIgnoredAttributes      , InvalidRequest       , BAD_REQUEST ;
 InvalidSettingsPagination             , InvalidRequest       , BAD_REQUEST ;
 InvalidSettingsSearchCutoffMs         , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsSplitAndConcatWords    , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsRecencyAttribute       , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsMaxFacetValuesPerAttribute, InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsContentTypes           , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsReadOnly               , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsAttributeTransforms    , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsDecimalAttributes      , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsAttributesPrefixSearch , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsMaxDocuments           , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsMaxPrefixTypos         , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsMinPrefixLength        , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsStemming               , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsMaxFlattenDepth        , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsDefaultLanguage        , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsLanguageHintField      , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsDuplicateKeyStrategy   , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsTokenizerProfiles      , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsPinnedResults          , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsReserveDocumentIds     , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsUserMetadata           , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsStoreMaxLength         , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsNormalizedFacetAttributes, InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsBlockedDocuments       , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsNumberTokenization     , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsEnableFormatting       , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsRecencyHalfLifeDays    , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsReranker               , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsDocumentCompression    , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsMaxSynonymExpansions   , InvalidRequest       , BAD_REQUEST ;
 InvalidSettingsEmbedders              , InvalidRequest       , BAD_REQUEST ;
 InvalidSettingsRankingRules           , InvalidRequest       , BAD_REQUEST ;
 InvalidSettingsSearchableAttributes   , InvalidRequest       , BAD_REQUEST ;
@@ -306,6 +404,7 @@ InvalidSwapIndexes                    , InvalidRequest       , BAD_REQUEST ;
 InvalidTaskAfterEnqueuedAt            , InvalidRequest       , BAD_REQUEST ;
 InvalidTaskAfterFinishedAt            , InvalidRequest       , BAD_REQUEST ;
 InvalidTaskAfterStartedAt             , InvalidRequest       , BAD_REQUEST ;
+InvalidTaskBatchUids                  , InvalidRequest       , BAD_REQUEST ;
 InvalidTaskBeforeEnqueuedAt           , InvalidRequest       , BAD_REQUEST ;
 InvalidTaskBeforeFinishedAt           , InvalidRequest       , BAD_REQUEST ;
 InvalidTaskBeforeStartedAt            , InvalidRequest       , BAD_REQUEST ;
@@ -315,9 +414,13 @@ InvalidTaskLimit                      , InvalidRequest       , BAD_REQUEST ;
 InvalidTaskStatuses                   , InvalidRequest       , BAD_REQUEST ;
 InvalidTaskTypes                      , InvalidRequest       , BAD_REQUEST ;
 InvalidTaskUids                       , InvalidRequest       , BAD_REQUEST  ;
+InvalidTimeoutMs                      , InvalidRequest       , BAD_REQUEST ;
+InvalidWaitForCompletion              , InvalidRequest       , BAD_REQUEST ;
+InvalidWebhooks                       , InvalidRequest       , BAD_REQUEST ;
 IoError                               , System               , UNPROCESSABLE_ENTITY;
 FeatureNotEnabled                     , InvalidRequest       , BAD_REQUEST ;
 MalformedPayload                      , InvalidRequest       , BAD_REQUEST ;
+MaxDocumentsExceeded                  , InvalidRequest       , BAD_REQUEST ;
 MaxFieldsLimitExceeded                , InvalidRequest       , BAD_REQUEST ;
 MissingApiKeyActions                  , InvalidRequest       , BAD_REQUEST ;
 MissingApiKeyExpiresAt                , InvalidRequest       , BAD_REQUEST ;
@@ -327,14 +430,17 @@ MissingContentType                    , InvalidRequest       , UNSUPPORTED_MEDIA
 MissingDocumentId                     , InvalidRequest       , BAD_REQUEST ;
 MissingFacetSearchFacetName           , InvalidRequest       , BAD_REQUEST ;
 MissingIndexUid                       , InvalidRequest       , BAD_REQUEST ;
+MaintenanceMode                       , System               , SERVICE_UNAVAILABLE ;
 MissingMasterKey                      , Auth                 , UNAUTHORIZED ;
 MissingPayload                        , InvalidRequest       , BAD_REQUEST ;
 MissingSearchHybrid                   , InvalidRequest       , BAD_REQUEST ;
 MissingSwapIndexes                    , InvalidRequest       , BAD_REQUEST ;
 MissingTaskFilters                    , InvalidRequest       , BAD_REQUEST ;
 NoSpaceLeftOnDevice                   , System               , UNPROCESSABLE_ENTITY;
+ShuttingDown                          , System               , SERVICE_UNAVAILABLE ;
 PayloadTooLarge                       , InvalidRequest       , PAYLOAD_TOO_LARGE ;
 TooManySearchRequests                 , System               , SERVICE_UNAVAILABLE ;
+TooManyPendingTasks                   , System               , SERVICE_UNAVAILABLE ;
 TaskNotFound                          , InvalidRequest       , NOT_FOUND ;
 TooManyOpenFiles                      , System               , UNPROCESSABLE_ENTITY ;
 TooManyVectors                        , InvalidRequest       , BAD_REQUEST ;
@@ -376,10 +482,18 @@ impl ErrorCode for milli::Error {
                     UserError::AttributeLimitReached => Code::MaxFieldsLimitExceeded,
                     UserError::InvalidFilter(_) => Code::InvalidSearchFilter,
                     UserError::InvalidFilterExpression(..) => Code::InvalidSearchFilter,
+                    UserError::InvalidBoostingFilter(_) => Code::InvalidSearchBoostingFilter,
                     UserError::MissingDocumentId { .. } => Code::MissingDocumentId,
                     UserError::InvalidDocumentId { .. } | UserError::TooManyDocumentIds { .. } => {
                         Code::InvalidDocumentId
                     }
+                    UserError::InvalidDocumentEtag { .. } => Code::InvalidDocumentEtag,
+                    UserError::InvalidDocumentAttributeTransform { .. } => {
+                        Code::InvalidDocumentAttributeTransform
+                    }
+                    UserError::MaxDocumentsExceeded { .. } => Code::MaxDocumentsExceeded,
+                    UserError::DuplicateDocumentId { .. } => Code::DuplicateDocumentId,
+                    UserError::UnsupportedStemmingLanguage(_) => Code::InvalidSettingsStemming,
                     UserError::MissingDocumentField(_) => Code::InvalidDocumentFields,
                     UserError::InvalidFieldForSource { .. }
                     | UserError::MissingFieldForSource { .. }
@@ -396,7 +510,9 @@ impl ErrorCode for milli::Error {
                         Code::IndexPrimaryKeyMultipleCandidatesFound
                     }
                     UserError::PrimaryKeyCannotBeChanged(_) => Code::IndexPrimaryKeyAlreadyExists,
+                    UserError::MismatchingPrimaryKeys { .. } => Code::InvalidIndexPrimaryKey,
                     UserError::InvalidDistinctAttribute { .. } => Code::InvalidSearchDistinct,
+                    UserError::InvalidGroupByAttribute { .. } => Code::InvalidSearchGroupBy,
                     UserError::SortRankingRuleMissing => Code::InvalidSearchSort,
                     UserError::InvalidFacetsDistribution { .. } => Code::InvalidSearchFacets,
                     UserError::InvalidSortableAttribute { .. } => Code::InvalidSearchSort,
@@ -416,6 +532,12 @@ impl ErrorCode for milli::Error {
                     UserError::InvalidMinTypoWordLenSetting(_, _) => {
                         Code::InvalidSettingsTypoTolerance
                     }
+                    UserError::InvalidTypoPenaltySetting => Code::InvalidSettingsTypoTolerance,
+                    UserError::InvalidRecencyHalfLifeDaysSetting => {
+                        Code::InvalidSettingsRecencyHalfLifeDays
+                    }
+                    UserError::InvalidRerankerSetting(_) => Code::InvalidSettingsReranker,
+                    UserError::InvalidUserMetadataSize { .. } => Code::InvalidSettingsUserMetadata,
                     UserError::InvalidEmbedder(_) => Code::InvalidEmbedder,
                     UserError::VectorEmbeddingError(_) | UserError::DocumentEmbeddingError(_) => {
                         Code::VectorEmbeddingError
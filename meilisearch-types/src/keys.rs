@@ -47,11 +47,14 @@ pub struct CreateApiKey {
     pub indexes: Vec<IndexUidPattern>,
     #[deserr(error = DeserrJsonError<InvalidApiKeyExpiresAt>, try_from(Option<String>) = parse_expiration_date -> ParseOffsetDateTimeError, missing_field_error = DeserrJsonError::missing_api_key_expires_at)]
     pub expires_at: Option<OffsetDateTime>,
+    #[deserr(default, error = DeserrJsonError<InvalidApiKeyRateLimit>)]
+    pub rate_limit: Option<RateLimit>,
 }
 
 impl CreateApiKey {
     pub fn to_key(self) -> Key {
-        let CreateApiKey { description, name, uid, actions, indexes, expires_at } = self;
+        let CreateApiKey { description, name, uid, actions, indexes, expires_at, rate_limit } =
+            self;
         let now = OffsetDateTime::now_utc();
         Key {
             description,
@@ -60,12 +63,50 @@ impl CreateApiKey {
             actions,
             indexes,
             expires_at,
+            rate_limit,
             created_at: now,
             updated_at: now,
         }
     }
 }
 
+/// The number of requests an API key can make, enforced with a token bucket.
+///
+/// The bucket holds up to `burst` requests and is refilled by `requestsPerSecond` requests
+/// every second, a key without a `burst` can't make more requests than its rate at once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, Deserr)]
+#[serde(rename_all = "camelCase")]
+#[deserr(error = DeserrJsonError<InvalidApiKeyRateLimit>, rename_all = camelCase, deny_unknown_fields, validate = validate_rate_limit -> DeserrJsonError<InvalidApiKeyRateLimit>)]
+pub struct RateLimit {
+    pub requests_per_second: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[deserr(default)]
+    pub burst: Option<u32>,
+}
+
+impl RateLimit {
+    /// The maximum number of requests that can be made at once.
+    pub fn capacity(&self) -> u32 {
+        self.burst.unwrap_or(self.requests_per_second)
+    }
+}
+
+fn validate_rate_limit<E: DeserializeError>(
+    rate_limit: RateLimit,
+    location: ValuePointerRef,
+) -> Result<RateLimit, E> {
+    if rate_limit.requests_per_second == 0 || rate_limit.burst == Some(0) {
+        return Err(deserr::take_cf_content(E::error::<Infallible>(
+            None,
+            deserr::ErrorKind::Unexpected {
+                msg: "`requestsPerSecond` and `burst` must be greater than zero.".to_string(),
+            },
+            location,
+        )));
+    }
+    Ok(rate_limit)
+}
+
 fn deny_immutable_fields_api_key(
     field: &str,
     accepted: &[&str],
@@ -93,6 +134,8 @@ pub struct PatchApiKey {
     pub description: Setting<String>,
     #[deserr(default, error = DeserrJsonError<InvalidApiKeyName>)]
     pub name: Setting<String>,
+    #[deserr(default, error = DeserrJsonError<InvalidApiKeyRateLimit>)]
+    pub rate_limit: Setting<RateLimit>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
@@ -106,6 +149,8 @@ pub struct Key {
     pub indexes: Vec<IndexUidPattern>,
     #[serde(with = "time::serde::rfc3339::option")]
     pub expires_at: Option<OffsetDateTime>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rate_limit: Option<RateLimit>,
     #[serde(with = "time::serde::rfc3339")]
     pub created_at: OffsetDateTime,
     #[serde(with = "time::serde::rfc3339")]
@@ -123,6 +168,7 @@ impl Key {
             actions: vec![Action::All],
             indexes: vec![IndexUidPattern::all()],
             expires_at: None,
+            rate_limit: None,
             created_at: now,
             updated_at: now,
         }
@@ -138,6 +184,7 @@ impl Key {
             actions: vec![Action::Search],
             indexes: vec![IndexUidPattern::all()],
             expires_at: None,
+            rate_limit: None,
             created_at: now,
             updated_at: now,
         }
@@ -284,6 +331,24 @@ pub enum Action {
     #[serde(rename = "experimental.update")]
     #[deserr(rename = "experimental.update")]
     ExperimentalFeaturesUpdate,
+    #[serde(rename = "maintenance.get")]
+    #[deserr(rename = "maintenance.get")]
+    MaintenanceGet,
+    #[serde(rename = "maintenance.update")]
+    #[deserr(rename = "maintenance.update")]
+    MaintenanceUpdate,
+    #[serde(rename = "snapshots.settings.get")]
+    #[deserr(rename = "snapshots.settings.get")]
+    SnapshotsSettingsGet,
+    #[serde(rename = "snapshots.settings.update")]
+    #[deserr(rename = "snapshots.settings.update")]
+    SnapshotsSettingsUpdate,
+    #[serde(rename = "webhooks.get")]
+    #[deserr(rename = "webhooks.get")]
+    WebhooksGet,
+    #[serde(rename = "webhooks.update")]
+    #[deserr(rename = "webhooks.update")]
+    WebhooksUpdate,
 }
 
 impl Action {
@@ -323,6 +388,12 @@ impl Action {
             KEYS_DELETE => Some(Self::KeysDelete),
             EXPERIMENTAL_FEATURES_GET => Some(Self::ExperimentalFeaturesGet),
             EXPERIMENTAL_FEATURES_UPDATE => Some(Self::ExperimentalFeaturesUpdate),
+            MAINTENANCE_GET => Some(Self::MaintenanceGet),
+            MAINTENANCE_UPDATE => Some(Self::MaintenanceUpdate),
+            SNAPSHOTS_SETTINGS_GET => Some(Self::SnapshotsSettingsGet),
+            SNAPSHOTS_SETTINGS_UPDATE => Some(Self::SnapshotsSettingsUpdate),
+            WEBHOOKS_GET => Some(Self::WebhooksGet),
+            WEBHOOKS_UPDATE => Some(Self::WebhooksUpdate),
             _otherwise => None,
         }
     }
@@ -368,4 +439,10 @@ pub mod actions {
     pub const KEYS_DELETE: u8 = KeysDelete.repr();
     pub const EXPERIMENTAL_FEATURES_GET: u8 = ExperimentalFeaturesGet.repr();
     pub const EXPERIMENTAL_FEATURES_UPDATE: u8 = ExperimentalFeaturesUpdate.repr();
+    pub const MAINTENANCE_GET: u8 = MaintenanceGet.repr();
+    pub const MAINTENANCE_UPDATE: u8 = MaintenanceUpdate.repr();
+    pub const SNAPSHOTS_SETTINGS_GET: u8 = SnapshotsSettingsGet.repr();
+    pub const SNAPSHOTS_SETTINGS_UPDATE: u8 = SnapshotsSettingsUpdate.repr();
+    pub const WEBHOOKS_GET: u8 = WebhooksGet.repr();
+    pub const WEBHOOKS_UPDATE: u8 = WebhooksUpdate.repr();
 }
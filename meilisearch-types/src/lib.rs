@@ -1,3 +1,5 @@
+pub mod batch_view;
+pub mod batches;
 pub mod compression;
 pub mod deserr;
 pub mod document_formats;
@@ -10,14 +10,18 @@ use deserr::{DeserializeError, Deserr, ErrorKind, MergeWithError, ValuePointerRe
 use fst::IntoStreamer;
 use milli::index::IndexEmbeddingConfig;
 use milli::proximity::ProximityPrecision;
-use milli::update::Setting;
-use milli::{Criterion, CriterionError, Index, DEFAULT_VALUES_PER_FACET};
+use milli::update::{DuplicateKeyStrategy, Setting};
+use milli::{
+    AttributePrefixSearch, AttributeTransform, ContentType, Criterion, CriterionError, Index,
+    NumberTokenization, PinnedResultsRule, RerankerSettings, TokenizerProfile,
+    DEFAULT_VALUES_PER_FACET,
+};
 use serde::{Deserialize, Serialize, Serializer};
 
 use crate::deserr::DeserrJsonError;
 use crate::error::deserr_codes::*;
 use crate::facet_values_sort::FacetValuesSort;
-use crate::locales::LocalizedAttributesRuleView;
+use crate::locales::{Locale, LocalizedAttributesRuleView};
 
 /// The maximum number of results that the engine
 /// will be able to return in one search call.
@@ -97,6 +101,9 @@ pub struct TypoSettings {
     #[serde(default, skip_serializing_if = "Setting::is_not_set")]
     #[deserr(default)]
     pub disable_on_attributes: Setting<BTreeSet<String>>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    #[deserr(default)]
+    pub typo_penalty: Setting<u8>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Deserr)]
@@ -109,6 +116,18 @@ pub struct FacetingSettings {
     #[serde(default, skip_serializing_if = "Setting::is_not_set")]
     #[deserr(default)]
     pub sort_facet_values_by: Setting<BTreeMap<String, FacetValuesSort>>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    #[deserr(default)]
+    pub normalization: Setting<FacetNormalizationSettings>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Deserr)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[deserr(rename_all = camelCase, deny_unknown_fields)]
+pub struct FacetNormalizationSettings {
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    #[deserr(default)]
+    pub case_folding: Setting<bool>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Deserr)]
@@ -134,6 +153,24 @@ impl MergeWithError<milli::CriterionError> for DeserrJsonError<InvalidSettingsRa
     }
 }
 
+impl<Format> MergeWithError<milli::CriterionError>
+    for crate::deserr::DeserrError<Format, InvalidSearchRankingRules>
+where
+    crate::deserr::DeserrError<Format, InvalidSearchRankingRules>: DeserializeError,
+{
+    fn merge(
+        _self_: Option<Self>,
+        other: milli::CriterionError,
+        merge_location: ValuePointerRef,
+    ) -> ControlFlow<Self, Self> {
+        Self::error::<Infallible>(
+            None,
+            ErrorKind::Unexpected { msg: other.to_string() },
+            merge_location,
+        )
+    }
+}
+
 /// Holds all the settings for an index. `T` can either be `Checked` if they represents settings
 /// whose validity is guaranteed, or `Unchecked` if they need to be validated. In the later case, a
 /// call to `check` will return a `Settings<Checked>` from a `Settings<Unchecked>`.
@@ -202,6 +239,96 @@ pub struct Settings<T> {
     #[serde(default, skip_serializing_if = "Setting::is_not_set")]
     #[deserr(default, error = DeserrJsonError<InvalidSettingsLocalizedAttributes>)]
     pub localized_attributes: Setting<Vec<LocalizedAttributesRuleView>>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    #[deserr(default, error = DeserrJsonError<InvalidSettingsIgnoredAttributes>)]
+    pub ignored_attributes: Setting<BTreeSet<String>>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    #[deserr(default, error = DeserrJsonError<InvalidSettingsSplitAndConcatWords>)]
+    pub split_and_concat_words: Setting<bool>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    #[deserr(default, error = DeserrJsonError<InvalidSettingsRecencyAttribute>)]
+    pub recency_attribute: Setting<String>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    #[deserr(default, error = DeserrJsonError<InvalidSettingsMaxFacetValuesPerAttribute>)]
+    pub max_facet_values_per_attribute: Setting<u64>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    #[deserr(default, error = DeserrJsonError<InvalidSettingsContentTypes>)]
+    pub content_types: Setting<BTreeMap<String, ContentTypeView>>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    #[deserr(default, error = DeserrJsonError<InvalidSettingsReadOnly>)]
+    pub read_only: Setting<bool>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    #[deserr(default, error = DeserrJsonError<InvalidSettingsAttributeTransforms>)]
+    pub attribute_transforms: Setting<BTreeMap<String, Vec<AttributeTransformView>>>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    #[deserr(default, error = DeserrJsonError<InvalidSettingsDecimalAttributes>)]
+    pub decimal_attributes: Setting<BTreeSet<String>>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    #[deserr(default, error = DeserrJsonError<InvalidSettingsAttributesPrefixSearch>)]
+    pub attributes_prefix_search: Setting<BTreeMap<String, AttributePrefixSearchView>>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    #[deserr(default, error = DeserrJsonError<InvalidSettingsMaxDocuments>)]
+    pub max_documents: Setting<u64>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    #[deserr(default, error = DeserrJsonError<InvalidSettingsMaxPrefixTypos>)]
+    pub max_prefix_typos: Setting<u8>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    #[deserr(default, error = DeserrJsonError<InvalidSettingsMinPrefixLength>)]
+    pub min_prefix_length: Setting<u8>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    #[deserr(default, error = DeserrJsonError<InvalidSettingsStemming>)]
+    pub stemming: Setting<Vec<Locale>>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    #[deserr(default, error = DeserrJsonError<InvalidSettingsMaxFlattenDepth>)]
+    pub max_flatten_depth: Setting<usize>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    #[deserr(default, error = DeserrJsonError<InvalidSettingsDefaultLanguage>)]
+    pub default_language: Setting<Locale>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    #[deserr(default, error = DeserrJsonError<InvalidSettingsLanguageHintField>)]
+    pub language_hint_field: Setting<String>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    #[deserr(default, error = DeserrJsonError<InvalidSettingsDuplicateKeyStrategy>)]
+    pub duplicate_key_strategy: Setting<DuplicateKeyStrategyView>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    #[deserr(default, error = DeserrJsonError<InvalidSettingsTokenizerProfiles>)]
+    pub tokenizer_profiles: Setting<BTreeMap<String, TokenizerProfileView>>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    #[deserr(default, error = DeserrJsonError<InvalidSettingsPinnedResults>)]
+    pub pinned_results: Setting<Vec<PinnedResultsRuleView>>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    #[deserr(default, error = DeserrJsonError<InvalidSettingsReserveDocumentIds>)]
+    pub reserve_document_ids: Setting<bool>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    #[deserr(default, error = DeserrJsonError<InvalidSettingsUserMetadata>)]
+    pub user_metadata: Setting<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    #[deserr(default, error = DeserrJsonError<InvalidSettingsStoreMaxLength>)]
+    pub store_max_length: Setting<BTreeMap<String, usize>>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    #[deserr(default, error = DeserrJsonError<InvalidSettingsNormalizedFacetAttributes>)]
+    pub normalized_facet_attributes: Setting<BTreeSet<String>>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    #[deserr(default, error = DeserrJsonError<InvalidSettingsBlockedDocuments>)]
+    pub blocked_documents: Setting<BTreeSet<String>>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    #[deserr(default, error = DeserrJsonError<InvalidSettingsNumberTokenization>)]
+    pub number_tokenization: Setting<NumberTokenizationView>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    #[deserr(default, error = DeserrJsonError<InvalidSettingsEnableFormatting>)]
+    pub enable_formatting: Setting<bool>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    #[deserr(default, error = DeserrJsonError<InvalidSettingsRecencyHalfLifeDays>)]
+    pub recency_half_life_days: Setting<u32>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    #[deserr(default, error = DeserrJsonError<InvalidSettingsReranker>)]
+    pub reranker: Setting<RerankerSettings>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    #[deserr(default, error = DeserrJsonError<InvalidSettingsDocumentCompression>)]
+    pub document_compression: Setting<bool>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    #[deserr(default, error = DeserrJsonError<InvalidSettingsMaxSynonymExpansions>)]
+    pub max_synonym_expansions: Setting<usize>,
 
     #[serde(skip)]
     #[deserr(skip)]
@@ -210,6 +337,10 @@ pub struct Settings<T> {
 
 impl<T> Settings<T> {
     pub fn hide_secrets(&mut self) {
+        if let Setting::Set(RerankerSettings { api_key: Some(api_key), .. }) = &mut self.reranker {
+            hide_secret(api_key);
+        }
+
         let Setting::Set(embedders) = &mut self.embedders else {
             return;
         };
@@ -223,28 +354,55 @@ impl<T> Settings<T> {
                 continue;
             };
 
-            Self::hide_secret(api_key);
+            hide_secret(api_key);
         }
     }
 
-    fn hide_secret(secret: &mut String) {
-        match secret.len() {
-            x if x < 10 => {
-                secret.replace_range(.., "XXX...");
-            }
-            x if x < 20 => {
-                secret.replace_range(2.., "XXXX...");
-            }
-            x if x < 30 => {
-                secret.replace_range(3.., "XXXXX...");
-            }
-            _x => {
-                secret.replace_range(5.., "XXXXXX...");
+    /// Returns the path of the first secret that was hidden by [`Self::hide_secrets`], if any.
+    pub fn hidden_secret(&self) -> Option<String> {
+        if let Setting::Set(RerankerSettings { api_key: Some(api_key), .. }) = &self.reranker {
+            if is_hidden_secret(api_key) {
+                return Some("reranker.apiKey".to_string());
             }
         }
+
+        let Setting::Set(embedders) = &self.embedders else {
+            return None;
+        };
+
+        embedders.iter().find_map(|(name, embedder)| match embedder {
+            Setting::Set(milli::vector::settings::EmbeddingSettings {
+                api_key: Setting::Set(api_key),
+                ..
+            }) if is_hidden_secret(api_key) => Some(format!("embedders.{name}.apiKey")),
+            _ => None,
+        })
     }
 }
 
+/// Replaces all but the first characters of the secret, the longer it is the more are kept.
+pub fn hide_secret(secret: &mut String) {
+    match secret.len() {
+        x if x < 10 => {
+            secret.replace_range(.., "XXX...");
+        }
+        x if x < 20 => {
+            secret.replace_range(2.., "XXXX...");
+        }
+        x if x < 30 => {
+            secret.replace_range(3.., "XXXXX...");
+        }
+        _x => {
+            secret.replace_range(5.., "XXXXXX...");
+        }
+    }
+}
+
+/// Whether the secret was replaced by [`hide_secret`].
+pub fn is_hidden_secret(secret: &str) -> bool {
+    secret.ends_with("XXX...")
+}
+
 impl Settings<Checked> {
     pub fn cleared() -> Settings<Checked> {
         Settings {
@@ -266,6 +424,36 @@ impl Settings<Checked> {
             embedders: Setting::Reset,
             search_cutoff_ms: Setting::Reset,
             localized_attributes: Setting::Reset,
+            ignored_attributes: Setting::Reset,
+            split_and_concat_words: Setting::Reset,
+            recency_attribute: Setting::Reset,
+            max_facet_values_per_attribute: Setting::Reset,
+            content_types: Setting::Reset,
+            read_only: Setting::Reset,
+            attribute_transforms: Setting::Reset,
+            decimal_attributes: Setting::Reset,
+            attributes_prefix_search: Setting::Reset,
+            max_documents: Setting::Reset,
+            max_prefix_typos: Setting::Reset,
+            min_prefix_length: Setting::Reset,
+            stemming: Setting::Reset,
+            max_flatten_depth: Setting::Reset,
+            default_language: Setting::Reset,
+            language_hint_field: Setting::Reset,
+            duplicate_key_strategy: Setting::Reset,
+            tokenizer_profiles: Setting::Reset,
+            pinned_results: Setting::Reset,
+            reserve_document_ids: Setting::Reset,
+            user_metadata: Setting::Reset,
+            store_max_length: Setting::Reset,
+            normalized_facet_attributes: Setting::Reset,
+            blocked_documents: Setting::Reset,
+            number_tokenization: Setting::Reset,
+            enable_formatting: Setting::Reset,
+            recency_half_life_days: Setting::Reset,
+            reranker: Setting::Reset,
+            document_compression: Setting::Reset,
+            max_synonym_expansions: Setting::Reset,
             _kind: PhantomData,
         }
     }
@@ -290,6 +478,36 @@ impl Settings<Checked> {
             embedders,
             search_cutoff_ms,
             localized_attributes: localized_attributes_rules,
+            ignored_attributes,
+            split_and_concat_words,
+            recency_attribute,
+            max_facet_values_per_attribute,
+            content_types,
+            read_only,
+            attribute_transforms,
+            decimal_attributes,
+            attributes_prefix_search,
+            max_documents,
+            max_prefix_typos,
+            min_prefix_length,
+            stemming,
+            max_flatten_depth,
+            default_language,
+            language_hint_field,
+            duplicate_key_strategy,
+            tokenizer_profiles,
+            pinned_results,
+            reserve_document_ids,
+            user_metadata,
+            store_max_length,
+            normalized_facet_attributes,
+            blocked_documents,
+            number_tokenization,
+            enable_formatting,
+            recency_half_life_days,
+            reranker,
+            document_compression,
+            max_synonym_expansions,
             _kind,
         } = self;
 
@@ -312,6 +530,36 @@ impl Settings<Checked> {
             embedders,
             search_cutoff_ms,
             localized_attributes: localized_attributes_rules,
+            ignored_attributes,
+            split_and_concat_words,
+            recency_attribute,
+            max_facet_values_per_attribute,
+            content_types,
+            read_only,
+            attribute_transforms,
+            decimal_attributes,
+            attributes_prefix_search,
+            max_documents,
+            max_prefix_typos,
+            min_prefix_length,
+            stemming,
+            max_flatten_depth,
+            default_language,
+            language_hint_field,
+            duplicate_key_strategy,
+            tokenizer_profiles,
+            pinned_results,
+            reserve_document_ids,
+            user_metadata,
+            store_max_length,
+            normalized_facet_attributes,
+            blocked_documents,
+            number_tokenization,
+            enable_formatting,
+            recency_half_life_days,
+            reranker,
+            document_compression,
+            max_synonym_expansions,
             _kind: PhantomData,
         }
     }
@@ -360,6 +608,36 @@ impl Settings<Unchecked> {
             embedders: self.embedders,
             search_cutoff_ms: self.search_cutoff_ms,
             localized_attributes: self.localized_attributes,
+            ignored_attributes: self.ignored_attributes,
+            split_and_concat_words: self.split_and_concat_words,
+            recency_attribute: self.recency_attribute,
+            max_facet_values_per_attribute: self.max_facet_values_per_attribute,
+            content_types: self.content_types,
+            read_only: self.read_only,
+            attribute_transforms: self.attribute_transforms,
+            decimal_attributes: self.decimal_attributes,
+            attributes_prefix_search: self.attributes_prefix_search,
+            max_documents: self.max_documents,
+            max_prefix_typos: self.max_prefix_typos,
+            min_prefix_length: self.min_prefix_length,
+            stemming: self.stemming,
+            max_flatten_depth: self.max_flatten_depth,
+            default_language: self.default_language,
+            language_hint_field: self.language_hint_field,
+            duplicate_key_strategy: self.duplicate_key_strategy,
+            tokenizer_profiles: self.tokenizer_profiles,
+            pinned_results: self.pinned_results,
+            reserve_document_ids: self.reserve_document_ids,
+            user_metadata: self.user_metadata,
+            store_max_length: self.store_max_length,
+            normalized_facet_attributes: self.normalized_facet_attributes,
+            blocked_documents: self.blocked_documents,
+            number_tokenization: self.number_tokenization,
+            enable_formatting: self.enable_formatting,
+            recency_half_life_days: self.recency_half_life_days,
+            reranker: self.reranker,
+            document_compression: self.document_compression,
+            max_synonym_expansions: self.max_synonym_expansions,
             _kind: PhantomData,
         }
     }
@@ -368,6 +646,121 @@ impl Settings<Unchecked> {
         self.validate_embedding_settings()
     }
 
+    /// Resets the settings that are not set, so that applying them replaces
+    /// all the settings of an index instead of updating some of them.
+    pub fn or_reset(self) -> Self {
+        fn not_set_to_reset<T>(setting: Setting<T>) -> Setting<T> {
+            match setting {
+                Setting::NotSet => Setting::Reset,
+                otherwise => otherwise,
+            }
+        }
+
+        let Self {
+            displayed_attributes,
+            searchable_attributes,
+            filterable_attributes,
+            sortable_attributes,
+            ranking_rules,
+            stop_words,
+            non_separator_tokens,
+            separator_tokens,
+            dictionary,
+            synonyms,
+            distinct_attribute,
+            proximity_precision,
+            typo_tolerance,
+            faceting,
+            pagination,
+            embedders,
+            search_cutoff_ms,
+            localized_attributes,
+            ignored_attributes,
+            split_and_concat_words,
+            recency_attribute,
+            max_facet_values_per_attribute,
+            content_types,
+            read_only,
+            attribute_transforms,
+            decimal_attributes,
+            attributes_prefix_search,
+            max_documents,
+            max_prefix_typos,
+            min_prefix_length,
+            stemming,
+            max_flatten_depth,
+            default_language,
+            language_hint_field,
+            duplicate_key_strategy,
+            tokenizer_profiles,
+            pinned_results,
+            reserve_document_ids,
+            user_metadata,
+            store_max_length,
+            normalized_facet_attributes,
+            blocked_documents,
+            number_tokenization,
+            enable_formatting,
+            recency_half_life_days,
+            reranker,
+            document_compression,
+            max_synonym_expansions,
+            _kind,
+        } = self;
+
+        Self {
+            displayed_attributes: not_set_to_reset(displayed_attributes.0).into(),
+            searchable_attributes: not_set_to_reset(searchable_attributes.0).into(),
+            filterable_attributes: not_set_to_reset(filterable_attributes),
+            sortable_attributes: not_set_to_reset(sortable_attributes),
+            ranking_rules: not_set_to_reset(ranking_rules),
+            stop_words: not_set_to_reset(stop_words),
+            non_separator_tokens: not_set_to_reset(non_separator_tokens),
+            separator_tokens: not_set_to_reset(separator_tokens),
+            dictionary: not_set_to_reset(dictionary),
+            synonyms: not_set_to_reset(synonyms),
+            distinct_attribute: not_set_to_reset(distinct_attribute),
+            proximity_precision: not_set_to_reset(proximity_precision),
+            typo_tolerance: not_set_to_reset(typo_tolerance),
+            faceting: not_set_to_reset(faceting),
+            pagination: not_set_to_reset(pagination),
+            embedders: not_set_to_reset(embedders),
+            search_cutoff_ms: not_set_to_reset(search_cutoff_ms),
+            localized_attributes: not_set_to_reset(localized_attributes),
+            ignored_attributes: not_set_to_reset(ignored_attributes),
+            split_and_concat_words: not_set_to_reset(split_and_concat_words),
+            recency_attribute: not_set_to_reset(recency_attribute),
+            max_facet_values_per_attribute: not_set_to_reset(max_facet_values_per_attribute),
+            content_types: not_set_to_reset(content_types),
+            read_only: not_set_to_reset(read_only),
+            attribute_transforms: not_set_to_reset(attribute_transforms),
+            decimal_attributes: not_set_to_reset(decimal_attributes),
+            attributes_prefix_search: not_set_to_reset(attributes_prefix_search),
+            max_documents: not_set_to_reset(max_documents),
+            max_prefix_typos: not_set_to_reset(max_prefix_typos),
+            min_prefix_length: not_set_to_reset(min_prefix_length),
+            stemming: not_set_to_reset(stemming),
+            max_flatten_depth: not_set_to_reset(max_flatten_depth),
+            default_language: not_set_to_reset(default_language),
+            language_hint_field: not_set_to_reset(language_hint_field),
+            duplicate_key_strategy: not_set_to_reset(duplicate_key_strategy),
+            tokenizer_profiles: not_set_to_reset(tokenizer_profiles),
+            pinned_results: not_set_to_reset(pinned_results),
+            reserve_document_ids: not_set_to_reset(reserve_document_ids),
+            user_metadata: not_set_to_reset(user_metadata),
+            store_max_length: not_set_to_reset(store_max_length),
+            normalized_facet_attributes: not_set_to_reset(normalized_facet_attributes),
+            blocked_documents: not_set_to_reset(blocked_documents),
+            number_tokenization: not_set_to_reset(number_tokenization),
+            enable_formatting: not_set_to_reset(enable_formatting),
+            recency_half_life_days: not_set_to_reset(recency_half_life_days),
+            reranker: not_set_to_reset(reranker),
+            document_compression: not_set_to_reset(document_compression),
+            max_synonym_expansions: not_set_to_reset(max_synonym_expansions),
+            _kind,
+        }
+    }
+
     fn validate_embedding_settings(mut self) -> Result<Self, milli::Error> {
         let Setting::Set(mut configs) = self.embedders else { return Ok(self) };
         for (name, config) in configs.iter_mut() {
@@ -411,6 +804,36 @@ pub fn apply_settings_to_builder(
         embedders,
         search_cutoff_ms,
         localized_attributes: localized_attributes_rules,
+        ignored_attributes,
+        split_and_concat_words,
+        recency_attribute,
+        max_facet_values_per_attribute,
+        content_types,
+        read_only,
+        attribute_transforms,
+        decimal_attributes,
+        attributes_prefix_search,
+        max_documents,
+        max_prefix_typos,
+        min_prefix_length,
+        stemming,
+        max_flatten_depth,
+        default_language,
+        language_hint_field,
+        duplicate_key_strategy,
+        tokenizer_profiles,
+        pinned_results,
+        reserve_document_ids,
+        user_metadata,
+        store_max_length,
+        normalized_facet_attributes,
+        blocked_documents,
+        number_tokenization,
+        enable_formatting,
+        recency_half_life_days,
+        reranker,
+        document_compression,
+        max_synonym_expansions,
         _kind,
     } = settings;
 
@@ -501,6 +924,215 @@ pub fn apply_settings_to_builder(
         Setting::NotSet => (),
     }
 
+    match ignored_attributes {
+        Setting::Set(ref fields) => {
+            builder.set_ignored_attributes(fields.iter().cloned().collect())
+        }
+        Setting::Reset => builder.reset_ignored_attributes(),
+        Setting::NotSet => (),
+    }
+
+    match split_and_concat_words {
+        Setting::Set(flag) => builder.set_split_and_concat_words(*flag),
+        Setting::Reset => builder.reset_split_and_concat_words(),
+        Setting::NotSet => (),
+    }
+
+    match recency_attribute {
+        Setting::Set(ref attr) => builder.set_recency_field(attr.clone()),
+        Setting::Reset => builder.reset_recency_field(),
+        Setting::NotSet => (),
+    }
+
+    match max_facet_values_per_attribute {
+        Setting::Set(max) => builder.set_max_facet_values_per_attribute(*max),
+        Setting::Reset => builder.reset_max_facet_values_per_attribute(),
+        Setting::NotSet => (),
+    }
+
+    match content_types {
+        Setting::Set(ref content_types) => builder.set_content_types(
+            content_types.iter().map(|(attr, ty)| (attr.clone(), (*ty).into())).collect(),
+        ),
+        Setting::Reset => builder.reset_content_types(),
+        Setting::NotSet => (),
+    }
+
+    match read_only {
+        Setting::Set(flag) => builder.set_read_only(*flag),
+        Setting::Reset => builder.reset_read_only(),
+        Setting::NotSet => (),
+    }
+
+    match attribute_transforms {
+        Setting::Set(ref attribute_transforms) => builder.set_attribute_transforms(
+            attribute_transforms
+                .iter()
+                .map(|(attr, transforms)| {
+                    (attr.clone(), transforms.iter().map(|t| (*t).into()).collect())
+                })
+                .collect(),
+        ),
+        Setting::Reset => builder.reset_attribute_transforms(),
+        Setting::NotSet => (),
+    }
+
+    match decimal_attributes {
+        Setting::Set(ref fields) => {
+            builder.set_decimal_attributes(fields.iter().cloned().collect())
+        }
+        Setting::Reset => builder.reset_decimal_attributes(),
+        Setting::NotSet => (),
+    }
+
+    match attributes_prefix_search {
+        Setting::Set(ref attributes_prefix_search) => builder.set_attributes_prefix_search(
+            attributes_prefix_search
+                .iter()
+                .map(|(attr, prefix_search)| (attr.clone(), (*prefix_search).into()))
+                .collect(),
+        ),
+        Setting::Reset => builder.reset_attributes_prefix_search(),
+        Setting::NotSet => (),
+    }
+
+    match max_documents {
+        Setting::Set(max) => builder.set_max_documents(*max),
+        Setting::Reset => builder.reset_max_documents(),
+        Setting::NotSet => (),
+    }
+
+    match max_prefix_typos {
+        Setting::Set(max) => builder.set_max_prefix_typos(*max),
+        Setting::Reset => builder.reset_max_prefix_typos(),
+        Setting::NotSet => (),
+    }
+
+    match min_prefix_length {
+        Setting::Set(min) => builder.set_min_prefix_length(*min),
+        Setting::Reset => builder.reset_min_prefix_length(),
+        Setting::NotSet => (),
+    }
+
+    match stemming {
+        Setting::Set(locales) => {
+            builder.set_stemming(locales.iter().copied().map(Into::into).collect())
+        }
+        Setting::Reset => builder.reset_stemming(),
+        Setting::NotSet => (),
+    }
+
+    match max_flatten_depth {
+        Setting::Set(depth) => builder.set_max_flatten_depth(*depth),
+        Setting::Reset => builder.reset_max_flatten_depth(),
+        Setting::NotSet => (),
+    }
+
+    match default_language {
+        Setting::Set(locale) => builder.set_default_language((*locale).into()),
+        Setting::Reset => builder.reset_default_language(),
+        Setting::NotSet => (),
+    }
+
+    match language_hint_field {
+        Setting::Set(field) => builder.set_language_hint_field(field.clone()),
+        Setting::Reset => builder.reset_language_hint_field(),
+        Setting::NotSet => (),
+    }
+
+    match duplicate_key_strategy {
+        Setting::Set(strategy) => builder.set_duplicate_key_strategy((*strategy).into()),
+        Setting::Reset => builder.reset_duplicate_key_strategy(),
+        Setting::NotSet => (),
+    }
+
+    match tokenizer_profiles {
+        Setting::Set(ref tokenizer_profiles) => builder.set_tokenizer_profiles(
+            tokenizer_profiles
+                .iter()
+                .map(|(attr, profile)| (attr.clone(), (*profile).into()))
+                .collect(),
+        ),
+        Setting::Reset => builder.reset_tokenizer_profiles(),
+        Setting::NotSet => (),
+    }
+
+    match pinned_results {
+        Setting::Set(ref rules) => {
+            builder.set_pinned_results(rules.iter().cloned().map(Into::into).collect())
+        }
+        Setting::Reset => builder.reset_pinned_results(),
+        Setting::NotSet => (),
+    }
+
+    match reserve_document_ids {
+        Setting::Set(flag) => builder.set_reserve_document_ids(*flag),
+        Setting::Reset => builder.reset_reserve_document_ids(),
+        Setting::NotSet => (),
+    }
+
+    match user_metadata {
+        Setting::Set(metadata) => builder.set_user_metadata(metadata.clone()),
+        Setting::Reset => builder.reset_user_metadata(),
+        Setting::NotSet => (),
+    }
+
+    match store_max_length {
+        Setting::Set(lengths) => builder.set_store_max_length(lengths.clone()),
+        Setting::Reset => builder.reset_store_max_length(),
+        Setting::NotSet => (),
+    }
+
+    match normalized_facet_attributes {
+        Setting::Set(ref fields) => {
+            builder.set_normalized_facet_attributes(fields.iter().cloned().collect())
+        }
+        Setting::Reset => builder.reset_normalized_facet_attributes(),
+        Setting::NotSet => (),
+    }
+
+    match blocked_documents {
+        Setting::Set(ref documents_ids) => builder.set_blocked_documents(documents_ids.clone()),
+        Setting::Reset => builder.reset_blocked_documents(),
+        Setting::NotSet => (),
+    }
+
+    match number_tokenization {
+        Setting::Set(tokenization) => builder.set_number_tokenization((*tokenization).into()),
+        Setting::Reset => builder.reset_number_tokenization(),
+        Setting::NotSet => (),
+    }
+
+    match enable_formatting {
+        Setting::Set(enabled) => builder.set_enable_formatting(*enabled),
+        Setting::Reset => builder.reset_enable_formatting(),
+        Setting::NotSet => (),
+    }
+
+    match recency_half_life_days {
+        Setting::Set(half_life_days) => builder.set_recency_half_life_days(*half_life_days),
+        Setting::Reset => builder.reset_recency_half_life_days(),
+        Setting::NotSet => (),
+    }
+
+    match reranker {
+        Setting::Set(reranker) => builder.set_reranker(reranker.clone()),
+        Setting::Reset => builder.reset_reranker(),
+        Setting::NotSet => (),
+    }
+
+    match document_compression {
+        Setting::Set(enabled) => builder.set_document_compression(*enabled),
+        Setting::Reset => builder.reset_document_compression(),
+        Setting::NotSet => (),
+    }
+
+    match max_synonym_expansions {
+        Setting::Set(max) => builder.set_max_synonym_expansions(*max),
+        Setting::Reset => builder.reset_max_synonym_expansions(),
+        Setting::NotSet => (),
+    }
+
     match typo_tolerance {
         Setting::Set(ref value) => {
             match value.enabled {
@@ -544,6 +1176,12 @@ pub fn apply_settings_to_builder(
                 Setting::Reset => builder.reset_exact_attributes(),
                 Setting::NotSet => (),
             }
+
+            match value.typo_penalty {
+                Setting::Set(val) => builder.set_typo_penalty(val),
+                Setting::Reset => builder.reset_typo_penalty(),
+                Setting::NotSet => (),
+            }
         }
         Setting::Reset => {
             // all typo settings need to be reset here.
@@ -552,12 +1190,17 @@ pub fn apply_settings_to_builder(
             builder.reset_min_word_len_two_typos();
             builder.reset_exact_words();
             builder.reset_exact_attributes();
+            builder.reset_typo_penalty();
         }
         Setting::NotSet => (),
     }
 
     match faceting {
-        Setting::Set(FacetingSettings { max_values_per_facet, sort_facet_values_by }) => {
+        Setting::Set(FacetingSettings {
+            max_values_per_facet,
+            sort_facet_values_by,
+            normalization,
+        }) => {
             match max_values_per_facet {
                 Setting::Set(val) => builder.set_max_values_per_facet(*val),
                 Setting::Reset => builder.reset_max_values_per_facet(),
@@ -570,10 +1213,20 @@ pub fn apply_settings_to_builder(
                 Setting::Reset => builder.reset_sort_facet_values_by(),
                 Setting::NotSet => (),
             }
+            match normalization {
+                Setting::Set(setting) => match setting.case_folding {
+                    Setting::Set(val) => builder.set_facet_case_folding(val),
+                    Setting::Reset => builder.reset_facet_case_folding(),
+                    Setting::NotSet => (),
+                },
+                Setting::Reset => builder.reset_facet_case_folding(),
+                Setting::NotSet => (),
+            }
         }
         Setting::Reset => {
             builder.reset_max_values_per_facet();
             builder.reset_sort_facet_values_by();
+            builder.reset_facet_case_folding();
         }
         Setting::NotSet => (),
     }
@@ -638,6 +1291,8 @@ pub fn settings(
 
     let distinct_field = index.distinct_field(rtxn)?.map(String::from);
 
+    let recency_field = index.recency_field(rtxn)?.map(String::from);
+
     let proximity_precision = index.proximity_precision(rtxn)?.map(ProximityPrecisionView::from);
 
     let synonyms = index.user_defined_synonyms(rtxn)?;
@@ -659,6 +1314,7 @@ pub fn settings(
         min_word_size_for_typos: Setting::Set(min_typo_word_len),
         disable_on_words: Setting::Set(disabled_words),
         disable_on_attributes: Setting::Set(disabled_attributes),
+        typo_penalty: Setting::Set(index.typo_penalty(rtxn)?),
     };
 
     let faceting = FacetingSettings {
@@ -675,6 +1331,9 @@ pub fn settings(
                 .map(|(name, sort)| (name, sort.into()))
                 .collect(),
         ),
+        normalization: Setting::Set(FacetNormalizationSettings {
+            case_folding: Setting::Set(index.facet_case_folding(rtxn)?),
+        }),
     };
 
     let pagination = PaginationSettings {
@@ -695,8 +1354,69 @@ pub fn settings(
 
     let search_cutoff_ms = index.search_cutoff(rtxn)?;
 
+    let max_facet_values_per_attribute = index.max_facet_values_per_attribute(rtxn)?;
+
+    let max_documents = index.max_documents(rtxn)?;
+
+    let max_prefix_typos = index.max_prefix_typos(rtxn)?;
+
+    let min_prefix_length = index.min_prefix_length(rtxn)?;
+    let stemming: Vec<Locale> = index.stemming(rtxn)?.into_iter().map(Locale::from).collect();
+    let max_flatten_depth = index.max_flatten_depth(rtxn)?;
+    let default_language = index.default_language(rtxn)?.map(Locale::from);
+    let language_hint_field = index.language_hint_field(rtxn)?.map(String::from);
+
+    let duplicate_key_strategy =
+        DuplicateKeyStrategyView::from(index.duplicate_key_strategy(rtxn)?);
+    let tokenizer_profiles = index
+        .tokenizer_profiles(rtxn)?
+        .into_iter()
+        .map(|(attr, profile)| (attr, profile.into()))
+        .collect();
+    let pinned_results: Vec<PinnedResultsRuleView> =
+        index.pinned_results(rtxn)?.into_iter().map(Into::into).collect();
+    let reserve_document_ids = index.reserve_document_ids(rtxn)?;
+    let user_metadata = index.user_metadata(rtxn)?;
+    let store_max_length = index.store_max_length(rtxn)?;
+
+    let normalized_facet_attributes =
+        index.normalized_facet_attributes(rtxn)?.into_iter().map(String::from).collect();
+
+    let blocked_documents = index.blocked_documents(rtxn)?;
+
+    let number_tokenization = NumberTokenizationView::from(index.number_tokenization(rtxn)?);
+
+    let enable_formatting = index.enable_formatting(rtxn)?;
+
+    let recency_half_life_days = index.recency_half_life_days(rtxn)?;
+
+    let reranker = index.reranker(rtxn)?;
+    let document_compression = index.document_compression(rtxn)?;
+    let max_synonym_expansions = index.max_synonym_expansions(rtxn)?;
+
     let localized_attributes_rules = index.localized_attributes_rules(rtxn)?;
 
+    let ignored_attributes =
+        index.ignored_attributes(rtxn)?.into_iter().map(String::from).collect();
+
+    let content_types =
+        index.content_types(rtxn)?.into_iter().map(|(attr, ty)| (attr, ty.into())).collect();
+
+    let decimal_attributes =
+        index.decimal_attributes(rtxn)?.into_iter().map(String::from).collect();
+
+    let attributes_prefix_search = index
+        .attributes_prefix_search(rtxn)?
+        .into_iter()
+        .map(|(attr, prefix_search)| (attr, prefix_search.into()))
+        .collect();
+
+    let attribute_transforms = index
+        .attribute_transforms(rtxn)?
+        .into_iter()
+        .map(|(attr, transforms)| (attr, transforms.into_iter().map(|t| t.into()).collect()))
+        .collect();
+
     let mut settings = Settings {
         displayed_attributes: match displayed_attributes {
             Some(attrs) => Setting::Set(attrs),
@@ -733,6 +1453,69 @@ pub fn settings(
             Some(rules) => Setting::Set(rules.into_iter().map(|r| r.into()).collect()),
             None => Setting::Reset,
         },
+        ignored_attributes: Setting::Set(ignored_attributes),
+        split_and_concat_words: Setting::Set(index.split_and_concat_words(rtxn)?),
+        recency_attribute: match recency_field {
+            Some(field) => Setting::Set(field),
+            None => Setting::Reset,
+        },
+        max_facet_values_per_attribute: match max_facet_values_per_attribute {
+            Some(max) => Setting::Set(max),
+            None => Setting::Reset,
+        },
+        content_types: Setting::Set(content_types),
+        read_only: Setting::Set(index.read_only(rtxn)?),
+        attribute_transforms: Setting::Set(attribute_transforms),
+        decimal_attributes: Setting::Set(decimal_attributes),
+        attributes_prefix_search: Setting::Set(attributes_prefix_search),
+        max_documents: match max_documents {
+            Some(max) => Setting::Set(max),
+            None => Setting::Reset,
+        },
+        max_prefix_typos: match max_prefix_typos {
+            Some(max) => Setting::Set(max),
+            None => Setting::Reset,
+        },
+        min_prefix_length: match min_prefix_length {
+            Some(min) => Setting::Set(min),
+            None => Setting::Reset,
+        },
+        stemming: Setting::Set(stemming),
+        max_flatten_depth: match max_flatten_depth {
+            Some(depth) => Setting::Set(depth),
+            None => Setting::Reset,
+        },
+        default_language: match default_language {
+            Some(locale) => Setting::Set(locale),
+            None => Setting::Reset,
+        },
+        language_hint_field: match language_hint_field {
+            Some(field) => Setting::Set(field),
+            None => Setting::Reset,
+        },
+        duplicate_key_strategy: Setting::Set(duplicate_key_strategy),
+        tokenizer_profiles: Setting::Set(tokenizer_profiles),
+        pinned_results: Setting::Set(pinned_results),
+        reserve_document_ids: Setting::Set(reserve_document_ids),
+        user_metadata: match user_metadata {
+            Some(metadata) => Setting::Set(metadata),
+            None => Setting::Reset,
+        },
+        store_max_length: Setting::Set(store_max_length),
+        normalized_facet_attributes: Setting::Set(normalized_facet_attributes),
+        blocked_documents: Setting::Set(blocked_documents),
+        number_tokenization: Setting::Set(number_tokenization),
+        enable_formatting: Setting::Set(enable_formatting),
+        recency_half_life_days: match recency_half_life_days {
+            Some(half_life_days) => Setting::Set(half_life_days),
+            None => Setting::Reset,
+        },
+        reranker: match reranker {
+            Some(reranker) => Setting::Set(reranker),
+            None => Setting::Reset,
+        },
+        document_compression: Setting::Set(document_compression),
+        max_synonym_expansions: Setting::Set(max_synonym_expansions),
         _kind: PhantomData,
     };
 
@@ -765,6 +1548,8 @@ pub enum RankingRuleView {
     Asc(String),
     /// Sorted by the decreasing value of the field specified.
     Desc(String),
+    /// Sorted by the decreasing value of the recency attribute.
+    Recency,
 }
 impl Serialize for RankingRuleView {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -821,6 +1606,7 @@ impl From<Criterion> for RankingRuleView {
             Criterion::Exactness => RankingRuleView::Exactness,
             Criterion::Asc(x) => RankingRuleView::Asc(x),
             Criterion::Desc(x) => RankingRuleView::Desc(x),
+            Criterion::Recency => RankingRuleView::Recency,
         }
     }
 }
@@ -835,6 +1621,7 @@ impl From<RankingRuleView> for Criterion {
             RankingRuleView::Exactness => Criterion::Exactness,
             RankingRuleView::Asc(x) => Criterion::Asc(x),
             RankingRuleView::Desc(x) => Criterion::Desc(x),
+            RankingRuleView::Recency => Criterion::Recency,
         }
     }
 }
@@ -865,6 +1652,195 @@ impl From<ProximityPrecisionView> for ProximityPrecision {
     }
 }
 
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Deserr, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[deserr(error = DeserrJsonError<InvalidSettingsDuplicateKeyStrategy>, rename_all = camelCase, deny_unknown_fields)]
+pub enum DuplicateKeyStrategyView {
+    #[default]
+    LastWins,
+    FirstWins,
+    Error,
+}
+
+impl From<DuplicateKeyStrategy> for DuplicateKeyStrategyView {
+    fn from(value: DuplicateKeyStrategy) -> Self {
+        match value {
+            DuplicateKeyStrategy::LastWins => DuplicateKeyStrategyView::LastWins,
+            DuplicateKeyStrategy::FirstWins => DuplicateKeyStrategyView::FirstWins,
+            DuplicateKeyStrategy::Error => DuplicateKeyStrategyView::Error,
+        }
+    }
+}
+impl From<DuplicateKeyStrategyView> for DuplicateKeyStrategy {
+    fn from(value: DuplicateKeyStrategyView) -> Self {
+        match value {
+            DuplicateKeyStrategyView::LastWins => DuplicateKeyStrategy::LastWins,
+            DuplicateKeyStrategyView::FirstWins => DuplicateKeyStrategy::FirstWins,
+            DuplicateKeyStrategyView::Error => DuplicateKeyStrategy::Error,
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Deserr, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[deserr(error = DeserrJsonError<InvalidSettingsNumberTokenization>, rename_all = camelCase, deny_unknown_fields)]
+pub enum NumberTokenizationView {
+    #[default]
+    Split,
+    Keep,
+}
+
+impl From<NumberTokenization> for NumberTokenizationView {
+    fn from(value: NumberTokenization) -> Self {
+        match value {
+            NumberTokenization::Split => NumberTokenizationView::Split,
+            NumberTokenization::Keep => NumberTokenizationView::Keep,
+        }
+    }
+}
+impl From<NumberTokenizationView> for NumberTokenization {
+    fn from(value: NumberTokenizationView) -> Self {
+        match value {
+            NumberTokenizationView::Split => NumberTokenization::Split,
+            NumberTokenizationView::Keep => NumberTokenization::Keep,
+        }
+    }
+}
+
+/// The documents shown first, in order, for the queries matching the query pattern.
+#[derive(Debug, Clone, PartialEq, Eq, Deserr, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[deserr(rename_all = camelCase, deny_unknown_fields)]
+pub struct PinnedResultsRuleView {
+    pub query: String,
+    pub document_ids: Vec<String>,
+}
+
+impl From<PinnedResultsRule> for PinnedResultsRuleView {
+    fn from(rule: PinnedResultsRule) -> Self {
+        Self { query: rule.query, document_ids: rule.document_ids }
+    }
+}
+impl From<PinnedResultsRuleView> for PinnedResultsRule {
+    fn from(view: PinnedResultsRuleView) -> Self {
+        Self { query: view.query, document_ids: view.document_ids }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserr, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[deserr(error = DeserrJsonError<InvalidSettingsTokenizerProfiles>, rename_all = camelCase, deny_unknown_fields)]
+pub enum TokenizerProfileView {
+    Default,
+    Keyword,
+    Email,
+    Path,
+}
+
+impl From<TokenizerProfile> for TokenizerProfileView {
+    fn from(value: TokenizerProfile) -> Self {
+        match value {
+            TokenizerProfile::Default => TokenizerProfileView::Default,
+            TokenizerProfile::Keyword => TokenizerProfileView::Keyword,
+            TokenizerProfile::Email => TokenizerProfileView::Email,
+            TokenizerProfile::Path => TokenizerProfileView::Path,
+        }
+    }
+}
+impl From<TokenizerProfileView> for TokenizerProfile {
+    fn from(value: TokenizerProfileView) -> Self {
+        match value {
+            TokenizerProfileView::Default => TokenizerProfile::Default,
+            TokenizerProfileView::Keyword => TokenizerProfile::Keyword,
+            TokenizerProfileView::Email => TokenizerProfile::Email,
+            TokenizerProfileView::Path => TokenizerProfile::Path,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserr, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[deserr(error = DeserrJsonError<InvalidSettingsContentTypes>, rename_all = camelCase, deny_unknown_fields)]
+pub enum ContentTypeView {
+    Html,
+    Markdown,
+}
+
+impl From<ContentType> for ContentTypeView {
+    fn from(value: ContentType) -> Self {
+        match value {
+            ContentType::Html => ContentTypeView::Html,
+            ContentType::Markdown => ContentTypeView::Markdown,
+        }
+    }
+}
+impl From<ContentTypeView> for ContentType {
+    fn from(value: ContentTypeView) -> Self {
+        match value {
+            ContentTypeView::Html => ContentType::Html,
+            ContentTypeView::Markdown => ContentType::Markdown,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserr, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[deserr(error = DeserrJsonError<InvalidSettingsAttributeTransforms>, rename_all = camelCase, deny_unknown_fields)]
+pub enum AttributeTransformView {
+    Trim,
+    Lowercase,
+    ToNumber,
+}
+
+impl From<AttributeTransform> for AttributeTransformView {
+    fn from(value: AttributeTransform) -> Self {
+        match value {
+            AttributeTransform::Trim => AttributeTransformView::Trim,
+            AttributeTransform::Lowercase => AttributeTransformView::Lowercase,
+            AttributeTransform::ToNumber => AttributeTransformView::ToNumber,
+        }
+    }
+}
+impl From<AttributeTransformView> for AttributeTransform {
+    fn from(value: AttributeTransformView) -> Self {
+        match value {
+            AttributeTransformView::Trim => AttributeTransform::Trim,
+            AttributeTransformView::Lowercase => AttributeTransform::Lowercase,
+            AttributeTransformView::ToNumber => AttributeTransform::ToNumber,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserr, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "camelCase")]
+#[deserr(error = DeserrJsonError<InvalidSettingsAttributesPrefixSearch>, rename_all = camelCase, deny_unknown_fields)]
+pub struct AttributePrefixSearchView {
+    #[deserr(default = true)]
+    pub enabled: bool,
+    #[deserr(default)]
+    pub penalty: u16,
+}
+
+impl Default for AttributePrefixSearchView {
+    fn default() -> Self {
+        AttributePrefixSearch::default().into()
+    }
+}
+
+impl From<AttributePrefixSearch> for AttributePrefixSearchView {
+    fn from(value: AttributePrefixSearch) -> Self {
+        let AttributePrefixSearch { enabled, penalty } = value;
+        AttributePrefixSearchView { enabled, penalty }
+    }
+}
+
+impl From<AttributePrefixSearchView> for AttributePrefixSearch {
+    fn from(value: AttributePrefixSearchView) -> Self {
+        let AttributePrefixSearchView { enabled, penalty } = value;
+        AttributePrefixSearch { enabled, penalty }
+    }
+}
+
 #[derive(Debug, Clone, Default, Deserialize, PartialEq, Eq)]
 pub struct WildcardSetting(Setting<Vec<String>>);
 
@@ -926,6 +1902,36 @@ pub(crate) mod test {
             embedders: Setting::NotSet,
             localized_attributes: Setting::NotSet,
             search_cutoff_ms: Setting::NotSet,
+            ignored_attributes: Setting::NotSet,
+            split_and_concat_words: Setting::NotSet,
+            recency_attribute: Setting::NotSet,
+            max_facet_values_per_attribute: Setting::NotSet,
+            content_types: Setting::NotSet,
+            read_only: Setting::NotSet,
+            attribute_transforms: Setting::NotSet,
+            decimal_attributes: Setting::NotSet,
+            attributes_prefix_search: Setting::NotSet,
+            max_documents: Setting::NotSet,
+            max_prefix_typos: Setting::NotSet,
+            min_prefix_length: Setting::NotSet,
+            stemming: Setting::NotSet,
+            max_flatten_depth: Setting::NotSet,
+            default_language: Setting::NotSet,
+            language_hint_field: Setting::NotSet,
+            duplicate_key_strategy: Setting::NotSet,
+            tokenizer_profiles: Setting::NotSet,
+            pinned_results: Setting::NotSet,
+            reserve_document_ids: Setting::NotSet,
+            user_metadata: Setting::NotSet,
+            store_max_length: Setting::NotSet,
+            normalized_facet_attributes: Setting::NotSet,
+            blocked_documents: Setting::NotSet,
+            number_tokenization: Setting::NotSet,
+            enable_formatting: Setting::NotSet,
+            recency_half_life_days: Setting::NotSet,
+            reranker: Setting::NotSet,
+            document_compression: Setting::NotSet,
+            max_synonym_expansions: Setting::NotSet,
             _kind: PhantomData::<Unchecked>,
         };
 
@@ -955,6 +1961,36 @@ pub(crate) mod test {
             embedders: Setting::NotSet,
             localized_attributes: Setting::NotSet,
             search_cutoff_ms: Setting::NotSet,
+            ignored_attributes: Setting::NotSet,
+            split_and_concat_words: Setting::NotSet,
+            recency_attribute: Setting::NotSet,
+            max_facet_values_per_attribute: Setting::NotSet,
+            content_types: Setting::NotSet,
+            read_only: Setting::NotSet,
+            attribute_transforms: Setting::NotSet,
+            decimal_attributes: Setting::NotSet,
+            attributes_prefix_search: Setting::NotSet,
+            max_documents: Setting::NotSet,
+            max_prefix_typos: Setting::NotSet,
+            min_prefix_length: Setting::NotSet,
+            stemming: Setting::NotSet,
+            max_flatten_depth: Setting::NotSet,
+            default_language: Setting::NotSet,
+            language_hint_field: Setting::NotSet,
+            duplicate_key_strategy: Setting::NotSet,
+            tokenizer_profiles: Setting::NotSet,
+            pinned_results: Setting::NotSet,
+            reserve_document_ids: Setting::NotSet,
+            user_metadata: Setting::NotSet,
+            store_max_length: Setting::NotSet,
+            normalized_facet_attributes: Setting::NotSet,
+            blocked_documents: Setting::NotSet,
+            number_tokenization: Setting::NotSet,
+            enable_formatting: Setting::NotSet,
+            recency_half_life_days: Setting::NotSet,
+            reranker: Setting::NotSet,
+            document_compression: Setting::NotSet,
+            max_synonym_expansions: Setting::NotSet,
             _kind: PhantomData::<Unchecked>,
         };
 
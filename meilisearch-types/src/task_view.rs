@@ -1,12 +1,17 @@
+use milli::update::UpdateIndexingStep;
 use milli::Object;
 use serde::Serialize;
 use time::{Duration, OffsetDateTime};
 
+use crate::batches::BatchId;
+use crate::document_formats::SkippedDocument;
 use crate::error::ResponseError;
 use crate::settings::{Settings, Unchecked};
-use crate::tasks::{serialize_duration, Details, IndexSwap, Kind, Status, Task, TaskId};
+use crate::tasks::{
+    serialize_duration, Details, DocumentDeletionOutcome, IndexSwap, Kind, Status, Task, TaskId,
+};
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TaskView {
     pub uid: TaskId,
@@ -16,6 +21,8 @@ pub struct TaskView {
     #[serde(rename = "type")]
     pub kind: Kind,
     pub canceled_by: Option<TaskId>,
+    /// The batch in which the task was processed, `None` until it is finished.
+    pub batch_uid: Option<BatchId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub details: Option<DetailsView>,
     pub error: Option<ResponseError>,
@@ -27,6 +34,9 @@ pub struct TaskView {
     pub started_at: Option<OffsetDateTime>,
     #[serde(with = "time::serde::rfc3339::option", default)]
     pub finished_at: Option<OffsetDateTime>,
+    /// The progress of the indexing, only reported while the task is processing.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub progress: Option<TaskProgressView>,
 }
 
 impl TaskView {
@@ -37,12 +47,62 @@ impl TaskView {
             status: task.status,
             kind: task.kind.as_kind(),
             canceled_by: task.canceled_by,
+            batch_uid: task.batch_uid,
             details: task.details.clone().map(DetailsView::from),
             error: task.error.clone(),
             duration: task.started_at.zip(task.finished_at).map(|(start, end)| end - start),
             enqueued_at: task.enqueued_at,
             started_at: task.started_at,
             finished_at: task.finished_at,
+            progress: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskProgressView {
+    pub current_step: String,
+    pub finished_steps: u16,
+    pub total_steps: u16,
+    /// The percentage of the whole indexing already done, the steps being counted equally.
+    pub percentage: f32,
+}
+
+impl TaskProgressView {
+    pub fn from_indexing_step(indexing_step: UpdateIndexingStep) -> TaskProgressView {
+        use UpdateIndexingStep::*;
+
+        fn ratio(seen: usize, total: usize) -> f32 {
+            if total == 0 {
+                0.0
+            } else {
+                (seen as f32 / total as f32).min(1.0)
+            }
+        }
+
+        let (current_step, step_ratio) = match indexing_step {
+            // the number of documents to remap is unknown until they are all read
+            RemapDocumentAddition { .. } => ("remapping the documents", 0.0),
+            ComputeIdsAndMergeDocuments { documents_seen, total_documents } => {
+                ("computing the documents ids", ratio(documents_seen, total_documents))
+            }
+            IndexDocuments { documents_seen, total_documents } => {
+                ("extracting the documents", ratio(documents_seen, total_documents))
+            }
+            MergeDataIntoFinalDatabase { databases_seen, total_databases } => {
+                ("writing the databases", ratio(databases_seen, total_databases))
+            }
+        };
+
+        let finished_steps = indexing_step.step() as u16;
+        let total_steps = indexing_step.number_of_steps() as u16;
+        let percentage = (finished_steps as f32 + step_ratio) / total_steps as f32 * 100.0;
+        TaskProgressView {
+            current_step: current_step.to_string(),
+            finished_steps,
+            total_steps,
+            percentage,
         }
     }
 }
@@ -50,11 +110,19 @@ impl TaskView {
 #[derive(Default, Debug, PartialEq, Eq, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DetailsView {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub received_documents: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub indexed_documents: Option<Option<u64>>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub etag_conflicts: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capped_facet_attributes: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skipped_documents: Option<Vec<SkippedDocument>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub edited_documents: Option<Option<u64>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub primary_key: Option<Option<String>>,
@@ -63,6 +131,8 @@ pub struct DetailsView {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub deleted_documents: Option<Option<u64>>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub outcomes: Option<Vec<DocumentDeletionOutcome>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub matched_tasks: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub canceled_tasks: Option<Option<u64>>,
@@ -73,6 +143,10 @@ pub struct DetailsView {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub dump_uid: Option<Option<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<Option<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub imported_indexes: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub context: Option<Option<Object>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub function: Option<String>,
@@ -86,9 +160,36 @@ pub struct DetailsView {
 impl From<Details> for DetailsView {
     fn from(details: Details) -> Self {
         match details {
-            Details::DocumentAdditionOrUpdate { received_documents, indexed_documents } => {
+            Details::DocumentAdditionOrUpdate {
+                received_documents,
+                indexed_documents,
+                etag_conflicts,
+                capped_facet_attributes,
+                skipped_documents,
+            } => DetailsView {
+                received_documents: Some(received_documents),
+                indexed_documents: Some(indexed_documents),
+                etag_conflicts,
+                capped_facet_attributes,
+                skipped_documents,
+                ..DetailsView::default()
+            },
+            Details::DocumentTransaction {
+                received_documents,
+                indexed_documents,
+                provided_ids,
+                deleted_documents,
+            } => DetailsView {
+                received_documents: Some(received_documents),
+                indexed_documents: Some(indexed_documents),
+                provided_ids: Some(provided_ids),
+                deleted_documents: Some(deleted_documents),
+                ..DetailsView::default()
+            },
+            Details::DocumentUrlImport { url, received_documents, indexed_documents } => {
                 DetailsView {
-                    received_documents: Some(received_documents),
+                    url: Some(url),
+                    received_documents,
                     indexed_documents: Some(indexed_documents),
                     ..DetailsView::default()
                 }
@@ -117,9 +218,11 @@ impl From<Details> for DetailsView {
             Details::DocumentDeletion {
                 provided_ids: received_document_ids,
                 deleted_documents,
+                outcomes,
             } => DetailsView {
                 provided_ids: Some(received_document_ids),
                 deleted_documents: Some(deleted_documents),
+                outcomes,
                 original_filter: Some(None),
                 ..DetailsView::default()
             },
@@ -153,9 +256,45 @@ impl From<Details> for DetailsView {
             Details::Dump { dump_uid } => {
                 DetailsView { dump_uid: Some(dump_uid), ..DetailsView::default() }
             }
+            Details::DumpImport { dump_uid, namespace, imported_indexes } => DetailsView {
+                dump_uid: Some(Some(dump_uid)),
+                namespace: Some(namespace),
+                imported_indexes: Some(imported_indexes),
+                ..DetailsView::default()
+            },
             Details::IndexSwap { swaps } => {
                 DetailsView { swaps: Some(swaps), ..Default::default() }
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use milli::update::UpdateIndexingStep;
+
+    use super::TaskProgressView;
+
+    #[test]
+    fn progress_of_the_indexing_steps() {
+        let progress =
+            TaskProgressView::from_indexing_step(UpdateIndexingStep::RemapDocumentAddition {
+                documents_seen: 12,
+            });
+        meili_snap::snapshot!(format!("{progress:?}"), @r###"TaskProgressView { current_step: "remapping the documents", finished_steps: 0, total_steps: 4, percentage: 0.0 }"###);
+
+        let progress = TaskProgressView::from_indexing_step(UpdateIndexingStep::IndexDocuments {
+            documents_seen: 50,
+            total_documents: 100,
+        });
+        meili_snap::snapshot!(format!("{progress:?}"), @r###"TaskProgressView { current_step: "extracting the documents", finished_steps: 2, total_steps: 4, percentage: 62.5 }"###);
+
+        // an empty step doesn't report any progress of its own
+        let progress =
+            TaskProgressView::from_indexing_step(UpdateIndexingStep::MergeDataIntoFinalDatabase {
+                databases_seen: 0,
+                total_databases: 0,
+            });
+        meili_snap::snapshot!(format!("{progress:?}"), @r###"TaskProgressView { current_step: "writing the databases", finished_steps: 3, total_steps: 4, percentage: 75.0 }"###);
+    }
+}
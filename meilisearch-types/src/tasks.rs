@@ -11,6 +11,8 @@ use serde::{Deserialize, Serialize, Serializer};
 use time::{Duration, OffsetDateTime};
 use uuid::Uuid;
 
+use crate::batches::BatchId;
+use crate::document_formats::SkippedDocument;
 use crate::error::ResponseError;
 use crate::keys::Key;
 use crate::settings::{Settings, Unchecked};
@@ -36,6 +38,10 @@ pub struct Task {
 
     pub status: Status,
     pub kind: KindWithContent,
+
+    /// The batch in which the task was processed, `None` until it is finished.
+    #[serde(default)]
+    pub batch_uid: Option<BatchId>,
 }
 
 impl Task {
@@ -47,8 +53,11 @@ impl Task {
             | SnapshotCreation
             | TaskCancelation { .. }
             | TaskDeletion { .. }
-            | IndexSwap { .. } => None,
+            | IndexSwap { .. }
+            | DumpImport { .. } => None,
             DocumentAdditionOrUpdate { index_uid, .. }
+            | DocumentTransaction { index_uid, .. }
+            | DocumentUrlImport { index_uid, .. }
             | DocumentEdition { index_uid, .. }
             | DocumentDeletion { index_uid, .. }
             | DocumentDeletionByFilter { index_uid, .. }
@@ -68,8 +77,10 @@ impl Task {
     /// Return the content-uuid if there is one
     pub fn content_uuid(&self) -> Option<Uuid> {
         match self.kind {
-            KindWithContent::DocumentAdditionOrUpdate { content_file, .. } => Some(content_file),
-            KindWithContent::DocumentEdition { .. }
+            KindWithContent::DocumentAdditionOrUpdate { content_file, .. }
+            | KindWithContent::DocumentTransaction { content_file, .. } => Some(content_file),
+            KindWithContent::DocumentUrlImport { .. }
+            | KindWithContent::DocumentEdition { .. }
             | KindWithContent::DocumentDeletion { .. }
             | KindWithContent::DocumentDeletionByFilter { .. }
             | KindWithContent::DocumentClear { .. }
@@ -81,6 +92,7 @@ impl Task {
             | KindWithContent::TaskCancelation { .. }
             | KindWithContent::TaskDeletion { .. }
             | KindWithContent::DumpCreation { .. }
+            | KindWithContent::DumpImport { .. }
             | KindWithContent::SnapshotCreation => None,
         }
     }
@@ -97,9 +109,28 @@ pub enum KindWithContent {
         documents_count: u64,
         allow_index_creation: bool,
     },
+    /// An ordered list of additions and deletions applied all at once.
+    DocumentTransaction {
+        index_uid: String,
+        primary_key: Option<String>,
+        /// The documents of all the additions, in the order of the operations.
+        content_file: Uuid,
+        operations: Vec<DocumentTransactionOperation>,
+        allow_index_creation: bool,
+    },
+    /// The documents streamed by the server from a remote NDJSON file.
+    DocumentUrlImport {
+        index_uid: String,
+        url: String,
+        primary_key: Option<String>,
+        allow_index_creation: bool,
+    },
     DocumentDeletion {
         index_uid: String,
         documents_ids: Vec<String>,
+        /// Report, for each of the provided ids, whether the document was found and deleted.
+        #[serde(default)]
+        report_outcomes: bool,
     },
     DocumentDeletionByFilter {
         index_uid: String,
@@ -145,10 +176,40 @@ pub enum KindWithContent {
     DumpCreation {
         keys: Vec<Key>,
         instance_uid: Option<InstanceUid>,
+        /// Only dump the settings of the indexes, skipping their documents.
+        #[serde(default)]
+        settings_only: bool,
+        /// Only dump the tasks enqueued after this task and the indexes they modified.
+        /// The modified indexes are dumped with all their documents, the deleted ones are
+        /// deleted by the import.
+        #[serde(default)]
+        since: Option<TaskId>,
+    },
+    /// Imports the indexes of a dump of the dumps directory next to the existing indexes.
+    DumpImport {
+        dump_uid: String,
+        /// Prefixed, followed by a `-`, to the uids of the imported indexes.
+        namespace: Option<String>,
+        /// The uids the indexes of the dump are imported under.
+        index_uids: Vec<String>,
     },
     SnapshotCreation,
 }
 
+/// An operation of a [`KindWithContent::DocumentTransaction`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DocumentTransactionOperation {
+    /// Adds the next `documents_count` documents of the content file.
+    Add {
+        method: IndexDocumentsMethod,
+        documents_count: u64,
+    },
+    Delete {
+        documents_ids: Vec<String>,
+    },
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct IndexSwap {
@@ -159,6 +220,8 @@ impl KindWithContent {
     pub fn as_kind(&self) -> Kind {
         match self {
             KindWithContent::DocumentAdditionOrUpdate { .. } => Kind::DocumentAdditionOrUpdate,
+            KindWithContent::DocumentTransaction { .. } => Kind::DocumentTransaction,
+            KindWithContent::DocumentUrlImport { .. } => Kind::DocumentUrlImport,
             KindWithContent::DocumentEdition { .. } => Kind::DocumentEdition,
             KindWithContent::DocumentDeletion { .. } => Kind::DocumentDeletion,
             KindWithContent::DocumentDeletionByFilter { .. } => Kind::DocumentDeletion,
@@ -171,6 +234,7 @@ impl KindWithContent {
             KindWithContent::TaskCancelation { .. } => Kind::TaskCancelation,
             KindWithContent::TaskDeletion { .. } => Kind::TaskDeletion,
             KindWithContent::DumpCreation { .. } => Kind::DumpCreation,
+            KindWithContent::DumpImport { .. } => Kind::DumpImport,
             KindWithContent::SnapshotCreation => Kind::SnapshotCreation,
         }
     }
@@ -184,6 +248,8 @@ impl KindWithContent {
             | TaskCancelation { .. }
             | TaskDeletion { .. } => vec![],
             DocumentAdditionOrUpdate { index_uid, .. }
+            | DocumentTransaction { index_uid, .. }
+            | DocumentUrlImport { index_uid, .. }
             | DocumentEdition { index_uid, .. }
             | DocumentDeletion { index_uid, .. }
             | DocumentDeletionByFilter { index_uid, .. }
@@ -192,6 +258,7 @@ impl KindWithContent {
             | IndexCreation { index_uid, .. }
             | IndexUpdate { index_uid, .. }
             | IndexDeletion { index_uid } => vec![index_uid],
+            DumpImport { index_uids, .. } => index_uids.iter().map(String::as_str).collect(),
             IndexSwap { swaps } => {
                 let mut indexes = HashSet::<&str>::default();
                 for swap in swaps {
@@ -203,6 +270,26 @@ impl KindWithContent {
         }
     }
 
+    /// Returns the uid an index of a dump is imported under.
+    pub fn dump_import_index_uid(namespace: Option<&str>, index_uid: &str) -> String {
+        match namespace {
+            Some(namespace) => format!("{namespace}-{index_uid}"),
+            None => index_uid.to_string(),
+        }
+    }
+
+    /// Returns the number of documents added and the number of ids deleted by a transaction.
+    fn transaction_counts(operations: &[DocumentTransactionOperation]) -> (u64, usize) {
+        operations.iter().fold((0, 0), |(added, deleted), operation| match operation {
+            DocumentTransactionOperation::Add { documents_count, .. } => {
+                (added + documents_count, deleted)
+            }
+            DocumentTransactionOperation::Delete { documents_ids } => {
+                (added, deleted + documents_ids.len())
+            }
+        })
+    }
+
     /// Returns the default `Details` that correspond to this `KindWithContent`,
     /// `None` if it cannot be generated.
     pub fn default_details(&self) -> Option<Details> {
@@ -211,8 +298,25 @@ impl KindWithContent {
                 Some(Details::DocumentAdditionOrUpdate {
                     received_documents: *documents_count,
                     indexed_documents: None,
+                    etag_conflicts: None,
+                    capped_facet_attributes: None,
+                    skipped_documents: None,
+                })
+            }
+            KindWithContent::DocumentTransaction { operations, .. } => {
+                let (received_documents, provided_ids) = Self::transaction_counts(operations);
+                Some(Details::DocumentTransaction {
+                    received_documents,
+                    indexed_documents: None,
+                    provided_ids,
+                    deleted_documents: None,
                 })
             }
+            KindWithContent::DocumentUrlImport { url, .. } => Some(Details::DocumentUrlImport {
+                url: url.clone(),
+                received_documents: None,
+                indexed_documents: None,
+            }),
             KindWithContent::DocumentEdition { index_uid: _, filter_expr, context, function } => {
                 Some(Details::DocumentEdition {
                     deleted_documents: None,
@@ -222,10 +326,11 @@ impl KindWithContent {
                     function: function.clone(),
                 })
             }
-            KindWithContent::DocumentDeletion { index_uid: _, documents_ids } => {
+            KindWithContent::DocumentDeletion { index_uid: _, documents_ids, .. } => {
                 Some(Details::DocumentDeletion {
                     provided_ids: documents_ids.len(),
                     deleted_documents: None,
+                    outcomes: None,
                 })
             }
             KindWithContent::DocumentDeletionByFilter { index_uid: _, filter_expr } => {
@@ -258,6 +363,13 @@ impl KindWithContent {
                 original_filter: query.clone(),
             }),
             KindWithContent::DumpCreation { .. } => Some(Details::Dump { dump_uid: None }),
+            KindWithContent::DumpImport { dump_uid, namespace, index_uids } => {
+                Some(Details::DumpImport {
+                    dump_uid: dump_uid.clone(),
+                    namespace: namespace.clone(),
+                    imported_indexes: index_uids.clone(),
+                })
+            }
             KindWithContent::SnapshotCreation => None,
         }
     }
@@ -268,8 +380,25 @@ impl KindWithContent {
                 Some(Details::DocumentAdditionOrUpdate {
                     received_documents: *documents_count,
                     indexed_documents: Some(0),
+                    etag_conflicts: None,
+                    capped_facet_attributes: None,
+                    skipped_documents: None,
+                })
+            }
+            KindWithContent::DocumentTransaction { operations, .. } => {
+                let (received_documents, provided_ids) = Self::transaction_counts(operations);
+                Some(Details::DocumentTransaction {
+                    received_documents,
+                    indexed_documents: Some(0),
+                    provided_ids,
+                    deleted_documents: Some(0),
                 })
             }
+            KindWithContent::DocumentUrlImport { url, .. } => Some(Details::DocumentUrlImport {
+                url: url.clone(),
+                received_documents: Some(0),
+                indexed_documents: Some(0),
+            }),
             KindWithContent::DocumentEdition { index_uid: _, filter_expr, context, function } => {
                 Some(Details::DocumentEdition {
                     deleted_documents: Some(0),
@@ -279,10 +408,11 @@ impl KindWithContent {
                     function: function.clone(),
                 })
             }
-            KindWithContent::DocumentDeletion { index_uid: _, documents_ids } => {
+            KindWithContent::DocumentDeletion { index_uid: _, documents_ids, .. } => {
                 Some(Details::DocumentDeletion {
                     provided_ids: documents_ids.len(),
                     deleted_documents: Some(0),
+                    outcomes: None,
                 })
             }
             KindWithContent::DocumentDeletionByFilter { index_uid: _, filter_expr } => {
@@ -316,6 +446,13 @@ impl KindWithContent {
                 original_filter: query.clone(),
             }),
             KindWithContent::DumpCreation { .. } => Some(Details::Dump { dump_uid: None }),
+            KindWithContent::DumpImport { dump_uid, namespace, index_uids } => {
+                Some(Details::DumpImport {
+                    dump_uid: dump_uid.clone(),
+                    namespace: namespace.clone(),
+                    imported_indexes: index_uids.clone(),
+                })
+            }
             KindWithContent::SnapshotCreation => None,
         }
     }
@@ -328,8 +465,26 @@ impl From<&KindWithContent> for Option<Details> {
                 Some(Details::DocumentAdditionOrUpdate {
                     received_documents: *documents_count,
                     indexed_documents: None,
+                    etag_conflicts: None,
+                    capped_facet_attributes: None,
+                    skipped_documents: None,
                 })
             }
+            KindWithContent::DocumentTransaction { operations, .. } => {
+                let (received_documents, provided_ids) =
+                    KindWithContent::transaction_counts(operations);
+                Some(Details::DocumentTransaction {
+                    received_documents,
+                    indexed_documents: None,
+                    provided_ids,
+                    deleted_documents: None,
+                })
+            }
+            KindWithContent::DocumentUrlImport { url, .. } => Some(Details::DocumentUrlImport {
+                url: url.clone(),
+                received_documents: None,
+                indexed_documents: None,
+            }),
             KindWithContent::DocumentEdition { .. } => None,
             KindWithContent::DocumentDeletion { .. } => None,
             KindWithContent::DocumentDeletionByFilter { .. } => None,
@@ -356,12 +511,21 @@ impl From<&KindWithContent> for Option<Details> {
                 original_filter: query.clone(),
             }),
             KindWithContent::DumpCreation { .. } => Some(Details::Dump { dump_uid: None }),
+            KindWithContent::DumpImport { dump_uid, namespace, index_uids } => {
+                Some(Details::DumpImport {
+                    dump_uid: dump_uid.clone(),
+                    namespace: namespace.clone(),
+                    imported_indexes: index_uids.clone(),
+                })
+            }
             KindWithContent::SnapshotCreation => None,
         }
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Sequence)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, Sequence,
+)]
 #[serde(rename_all = "camelCase")]
 pub enum Status {
     Enqueued,
@@ -420,10 +584,14 @@ impl fmt::Display for ParseTaskStatusError {
 }
 impl std::error::Error for ParseTaskStatusError {}
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Sequence)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, Sequence,
+)]
 #[serde(rename_all = "camelCase")]
 pub enum Kind {
     DocumentAdditionOrUpdate,
+    DocumentTransaction,
+    DocumentUrlImport,
     DocumentEdition,
     DocumentDeletion,
     SettingsUpdate,
@@ -434,6 +602,7 @@ pub enum Kind {
     TaskCancelation,
     TaskDeletion,
     DumpCreation,
+    DumpImport,
     SnapshotCreation,
 }
 
@@ -441,6 +610,8 @@ impl Kind {
     pub fn related_to_one_index(&self) -> bool {
         match self {
             Kind::DocumentAdditionOrUpdate
+            | Kind::DocumentTransaction
+            | Kind::DocumentUrlImport
             | Kind::DocumentEdition
             | Kind::DocumentDeletion
             | Kind::SettingsUpdate
@@ -451,6 +622,7 @@ impl Kind {
             | Kind::TaskCancelation
             | Kind::TaskDeletion
             | Kind::DumpCreation
+            | Kind::DumpImport
             | Kind::SnapshotCreation => false,
         }
     }
@@ -459,6 +631,8 @@ impl Display for Kind {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Kind::DocumentAdditionOrUpdate => write!(f, "documentAdditionOrUpdate"),
+            Kind::DocumentTransaction => write!(f, "documentTransaction"),
+            Kind::DocumentUrlImport => write!(f, "documentUrlImport"),
             Kind::DocumentEdition => write!(f, "documentEdition"),
             Kind::DocumentDeletion => write!(f, "documentDeletion"),
             Kind::SettingsUpdate => write!(f, "settingsUpdate"),
@@ -469,6 +643,7 @@ impl Display for Kind {
             Kind::TaskCancelation => write!(f, "taskCancelation"),
             Kind::TaskDeletion => write!(f, "taskDeletion"),
             Kind::DumpCreation => write!(f, "dumpCreation"),
+            Kind::DumpImport => write!(f, "dumpImport"),
             Kind::SnapshotCreation => write!(f, "snapshotCreation"),
         }
     }
@@ -487,6 +662,10 @@ impl FromStr for Kind {
             Ok(Kind::IndexDeletion)
         } else if kind.eq_ignore_ascii_case("documentAdditionOrUpdate") {
             Ok(Kind::DocumentAdditionOrUpdate)
+        } else if kind.eq_ignore_ascii_case("documentTransaction") {
+            Ok(Kind::DocumentTransaction)
+        } else if kind.eq_ignore_ascii_case("documentUrlImport") {
+            Ok(Kind::DocumentUrlImport)
         } else if kind.eq_ignore_ascii_case("documentEdition") {
             Ok(Kind::DocumentEdition)
         } else if kind.eq_ignore_ascii_case("documentDeletion") {
@@ -499,6 +678,8 @@ impl FromStr for Kind {
             Ok(Kind::TaskDeletion)
         } else if kind.eq_ignore_ascii_case("dumpCreation") {
             Ok(Kind::DumpCreation)
+        } else if kind.eq_ignore_ascii_case("dumpImport") {
+            Ok(Kind::DumpImport)
         } else if kind.eq_ignore_ascii_case("snapshotCreation") {
             Ok(Kind::SnapshotCreation)
         } else {
@@ -533,6 +714,27 @@ pub enum Details {
     DocumentAdditionOrUpdate {
         received_documents: u64,
         indexed_documents: Option<u64>,
+        /// The ids of the documents rejected because their `_etag` didn't match the stored document.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        etag_conflicts: Option<Vec<String>>,
+        /// The facet attributes that reached `maxFacetValuesPerAttribute` during this update.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        capped_facet_attributes: Option<Vec<String>>,
+        /// The documents left out of the payload because they couldn't be parsed.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        skipped_documents: Option<Vec<SkippedDocument>>,
+    },
+    DocumentTransaction {
+        received_documents: u64,
+        indexed_documents: Option<u64>,
+        provided_ids: usize,
+        deleted_documents: Option<u64>,
+    },
+    DocumentUrlImport {
+        url: String,
+        /// Only known once the file has been downloaded.
+        received_documents: Option<u64>,
+        indexed_documents: Option<u64>,
     },
     SettingsUpdate {
         settings: Box<Settings<Unchecked>>,
@@ -543,6 +745,9 @@ pub enum Details {
     DocumentDeletion {
         provided_ids: usize,
         deleted_documents: Option<u64>,
+        /// Only set when the outcome of each id was requested.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        outcomes: Option<Vec<DocumentDeletionOutcome>>,
     },
     DocumentDeletionByFilter {
         original_filter: String,
@@ -571,11 +776,24 @@ pub enum Details {
     Dump {
         dump_uid: Option<String>,
     },
+    DumpImport {
+        dump_uid: String,
+        namespace: Option<String>,
+        imported_indexes: Vec<String>,
+    },
     IndexSwap {
         swaps: Vec<IndexSwap>,
     },
 }
 
+/// Whether a document requested for deletion was found and deleted.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentDeletionOutcome {
+    pub document_id: String,
+    pub deleted: bool,
+}
+
 impl Details {
     pub fn to_failed(&self) -> Self {
         let mut details = self.clone();
@@ -583,6 +801,11 @@ impl Details {
             Self::DocumentAdditionOrUpdate { indexed_documents, .. } => {
                 *indexed_documents = Some(0)
             }
+            Self::DocumentTransaction { indexed_documents, deleted_documents, .. } => {
+                *indexed_documents = Some(0);
+                *deleted_documents = Some(0);
+            }
+            Self::DocumentUrlImport { indexed_documents, .. } => *indexed_documents = Some(0),
             Self::DocumentEdition { edited_documents, .. } => *edited_documents = Some(0),
             Self::DocumentDeletion { deleted_documents, .. } => *deleted_documents = Some(0),
             Self::DocumentDeletionByFilter { deleted_documents, .. } => {
@@ -594,6 +817,7 @@ impl Details {
             Self::SettingsUpdate { .. }
             | Self::IndexInfo { .. }
             | Self::Dump { .. }
+            | Self::DumpImport { .. }
             | Self::IndexSwap { .. } => (),
         }
 
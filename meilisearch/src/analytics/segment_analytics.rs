@@ -1,6 +1,7 @@
 use std::collections::{BTreeSet, BinaryHeap, HashMap, HashSet};
 use std::fs;
 use std::mem::take;
+use std::num::NonZeroUsize;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
@@ -265,11 +266,23 @@ struct Infos {
     experimental_contains_filter: bool,
     experimental_enable_metrics: bool,
     experimental_search_queue_size: usize,
+    experimental_search_max_concurrency: Option<NonZeroUsize>,
+    experimental_search_log_size: usize,
+    experimental_search_log_hash_queries: bool,
+    experimental_search_stats_size: usize,
+    experimental_document_addition_max_pending_tasks: Option<NonZeroUsize>,
     experimental_logs_mode: LogMode,
     experimental_replication_parameters: bool,
     experimental_enable_logs_route: bool,
     experimental_reduce_indexing_memory_usage: bool,
     experimental_max_number_of_batched_tasks: usize,
+    experimental_document_url_import_allowed_hosts: bool,
+    experimental_document_url_import_max_size: Byte,
+    experimental_dump_import_allowed_hosts: bool,
+    experimental_max_task_retries: u32,
+    experimental_task_retention_days: Option<u64>,
+    experimental_graceful_shutdown_timeout: Option<u64>,
+    experimental_index_map_size_hint: Option<Byte>,
     gpu_enabled: bool,
     db_path: bool,
     import_dump: bool,
@@ -283,6 +296,8 @@ struct Infos {
     ignore_snapshot_if_db_exists: bool,
     http_addr: bool,
     http_payload_size_limit: Byte,
+    max_query_length: usize,
+    max_query_terms: usize,
     task_queue_webhook: bool,
     task_webhook_authorization_header: bool,
     log_level: String,
@@ -308,11 +323,22 @@ impl From<Opt> for Infos {
             experimental_contains_filter,
             experimental_enable_metrics,
             experimental_search_queue_size,
+            experimental_search_max_concurrency,
+            experimental_search_log_size,
+            experimental_search_log_hash_queries,
+            experimental_search_stats_size,
+            experimental_document_addition_max_pending_tasks,
             experimental_logs_mode,
             experimental_replication_parameters,
             experimental_enable_logs_route,
             experimental_reduce_indexing_memory_usage,
             experimental_max_number_of_batched_tasks,
+            experimental_document_url_import_allowed_hosts,
+            experimental_document_url_import_max_size,
+            experimental_dump_import_allowed_hosts,
+            experimental_max_task_retries,
+            experimental_task_retention_days,
+            experimental_graceful_shutdown_timeout,
             http_addr,
             master_key: _,
             env,
@@ -321,6 +347,8 @@ impl From<Opt> for Infos {
             max_index_size: _,
             max_task_db_size: _,
             http_payload_size_limit,
+            max_query_length,
+            max_query_terms,
             ssl_cert_path,
             ssl_key_path,
             ssl_auth_path,
@@ -349,8 +377,12 @@ impl From<Opt> for Infos {
             ScheduleSnapshot::Enabled(interval) => Some(interval),
         };
 
-        let IndexerOpts { max_indexing_memory, max_indexing_threads, skip_index_budget: _ } =
-            indexer_options;
+        let IndexerOpts {
+            max_indexing_memory,
+            max_indexing_threads,
+            experimental_index_map_size_hint,
+            skip_index_budget: _,
+        } = indexer_options;
 
         // We're going to override every sensible information.
         // We consider information sensible if it contains a path, an address, or a key.
@@ -359,6 +391,11 @@ impl From<Opt> for Infos {
             experimental_contains_filter,
             experimental_enable_metrics,
             experimental_search_queue_size,
+            experimental_search_max_concurrency,
+            experimental_search_log_size,
+            experimental_search_log_hash_queries,
+            experimental_search_stats_size,
+            experimental_document_addition_max_pending_tasks,
             experimental_logs_mode,
             experimental_replication_parameters,
             experimental_enable_logs_route,
@@ -376,7 +413,18 @@ impl From<Opt> for Infos {
             ignore_snapshot_if_db_exists,
             http_addr: http_addr != default_http_addr(),
             http_payload_size_limit,
+            max_query_length,
+            max_query_terms,
             experimental_max_number_of_batched_tasks,
+            experimental_document_url_import_allowed_hosts:
+                !experimental_document_url_import_allowed_hosts.is_empty(),
+            experimental_document_url_import_max_size,
+            experimental_dump_import_allowed_hosts: !experimental_dump_import_allowed_hosts
+                .is_empty(),
+            experimental_max_task_retries,
+            experimental_task_retention_days,
+            experimental_graceful_shutdown_timeout,
+            experimental_index_map_size_hint,
             task_queue_webhook: task_webhook_url.is_some(),
             task_webhook_authorization_header: task_webhook_authorization_header.is_some(),
             log_level: log_level.to_string(),
@@ -624,9 +672,13 @@ pub struct SearchAggregator {
     // distinct
     distinct: bool,
 
+    // group by
+    group_by: bool,
+
     // filter
     filter_with_geo_radius: bool,
     filter_with_geo_bounding_box: bool,
+    filter_relaxation: bool,
     // every time a request has a filter, this field must be incremented by the number of terms it contains
     filter_sum_of_criteria_terms: usize,
     // every time a request has a filter, this field must be incremented by one
@@ -670,7 +722,12 @@ pub struct SearchAggregator {
     max_attributes_to_crop: usize,
     crop_marker: bool,
     show_matches_position: bool,
+    show_matches_info: bool,
+    show_query_coverage: bool,
+    enable_formatting: bool,
     crop_length: bool,
+    show_suggestion: bool,
+    show_query_analysis: bool,
 
     // facets
     facets_sum_of_terms: usize,
@@ -692,23 +749,40 @@ impl SearchAggregator {
             limit,
             page,
             hits_per_page,
+            compute_total_hits: _,
+            count_only: _,
             attributes_to_retrieve: _,
             retrieve_vectors,
             attributes_to_crop: _,
             crop_length,
             attributes_to_highlight: _,
             show_matches_position,
+            show_matches_info,
+            show_query_coverage,
+            enable_formatting,
             show_ranking_score,
             show_ranking_score_details,
+            show_suggestion,
+            suggestion_max_hits: _,
+            show_query_analysis,
             filter,
+            filter_relaxation,
+            boosting_filter: _,
             sort,
+            ranking_rules: _,
             distinct,
+            group_by,
+            group_limit: _,
             facets: _,
             highlight_pre_tag,
             highlight_post_tag,
             crop_marker,
+            attributes_formatting: _,
+            prefix_highlight: _,
             matching_strategy,
+            max_synonym_expansions: _,
             attributes_to_search_on,
+            cross_attribute_matching: _,
             hybrid,
             ranking_score_threshold,
             locales,
@@ -727,6 +801,7 @@ impl SearchAggregator {
         }
 
         ret.distinct = distinct.is_some();
+        ret.group_by = group_by.is_some();
 
         if let Some(ref filter) = filter {
             static RE: Lazy<Regex> = Lazy::new(|| Regex::new("AND | OR").unwrap());
@@ -751,6 +826,7 @@ impl SearchAggregator {
             ret.filter_with_geo_bounding_box = stringified_filters.contains("_geoBoundingBox(");
             ret.filter_sum_of_criteria_terms = RE.split(&stringified_filters).count();
         }
+        ret.filter_relaxation = filter_relaxation.is_some();
 
         // attributes_to_search_on
         if attributes_to_search_on.is_some() {
@@ -788,6 +864,11 @@ impl SearchAggregator {
         ret.crop_marker = *crop_marker != DEFAULT_CROP_MARKER();
         ret.crop_length = *crop_length != DEFAULT_CROP_LENGTH();
         ret.show_matches_position = *show_matches_position;
+        ret.show_matches_info = *show_matches_info;
+        ret.show_query_coverage = *show_query_coverage;
+        ret.enable_formatting = enable_formatting.is_some();
+        ret.show_suggestion = *show_suggestion;
+        ret.show_query_analysis = *show_query_analysis;
 
         ret.show_ranking_score = *show_ranking_score;
         ret.show_ranking_score_details = *show_ranking_score_details;
@@ -809,8 +890,14 @@ impl SearchAggregator {
             processing_time_ms,
             hits_info: _,
             semantic_hit_count: _,
+            suggestion: _,
+            dropped_filters: _,
+            groups: _,
             facet_distribution: _,
             facet_stats: _,
+            facet_cache_key: _,
+            query_analysis: _,
+            rerank: _,
             degraded,
             used_negative_operator,
         } = result;
@@ -837,8 +924,10 @@ impl SearchAggregator {
             sort_sum_of_criteria_terms,
             sort_total_number_of_criteria,
             distinct,
+            group_by,
             filter_with_geo_radius,
             filter_with_geo_bounding_box,
+            filter_relaxation,
             filter_sum_of_criteria_terms,
             filter_total_number_of_criteria,
             used_syntax,
@@ -857,7 +946,12 @@ impl SearchAggregator {
             max_attributes_to_crop,
             crop_marker,
             show_matches_position,
+            show_matches_info,
+            show_query_coverage,
+            enable_formatting,
             crop_length,
+            show_suggestion,
+            show_query_analysis,
             facets_sum_of_terms,
             facets_total_number_of_facets,
             show_ranking_score,
@@ -898,9 +992,13 @@ impl SearchAggregator {
         // distinct
         self.distinct |= distinct;
 
+        // group by
+        self.group_by |= group_by;
+
         // filter
         self.filter_with_geo_radius |= filter_with_geo_radius;
         self.filter_with_geo_bounding_box |= filter_with_geo_bounding_box;
+        self.filter_relaxation |= filter_relaxation;
         self.filter_sum_of_criteria_terms =
             self.filter_sum_of_criteria_terms.saturating_add(filter_sum_of_criteria_terms);
         self.filter_total_number_of_criteria =
@@ -940,7 +1038,12 @@ impl SearchAggregator {
         self.max_attributes_to_crop = self.max_attributes_to_crop.max(max_attributes_to_crop);
         self.crop_marker |= crop_marker;
         self.show_matches_position |= show_matches_position;
+        self.show_matches_info |= show_matches_info;
+        self.show_query_coverage |= show_query_coverage;
+        self.enable_formatting |= enable_formatting;
         self.crop_length |= crop_length;
+        self.show_suggestion |= show_suggestion;
+        self.show_query_analysis |= show_query_analysis;
 
         // facets
         self.facets_sum_of_terms = self.facets_sum_of_terms.saturating_add(facets_sum_of_terms);
@@ -973,8 +1076,10 @@ impl SearchAggregator {
             sort_sum_of_criteria_terms,
             sort_total_number_of_criteria,
             distinct,
+            group_by,
             filter_with_geo_radius,
             filter_with_geo_bounding_box,
+            filter_relaxation,
             filter_sum_of_criteria_terms,
             filter_total_number_of_criteria,
             used_syntax,
@@ -993,7 +1098,12 @@ impl SearchAggregator {
             max_attributes_to_crop,
             crop_marker,
             show_matches_position,
+            show_matches_info,
+            show_query_coverage,
+            enable_formatting,
             crop_length,
+            show_suggestion,
+            show_query_analysis,
             facets_sum_of_terms,
             facets_total_number_of_facets,
             show_ranking_score,
@@ -1032,9 +1142,11 @@ impl SearchAggregator {
                     "avg_criteria_number": format!("{:.2}", sort_sum_of_criteria_terms as f64 / sort_total_number_of_criteria as f64),
                 },
                 "distinct": distinct,
+                "group_by": group_by,
                 "filter": {
                    "with_geoRadius": filter_with_geo_radius,
                    "with_geoBoundingBox": filter_with_geo_bounding_box,
+                   "with_relaxation": filter_relaxation,
                    "avg_criteria_number": format!("{:.2}", filter_sum_of_criteria_terms as f64 / filter_total_number_of_criteria as f64),
                    "most_used_syntax": used_syntax.iter().max_by_key(|(_, v)| *v).map(|(k, _)| json!(k)).unwrap_or_else(|| json!(null)),
                 },
@@ -1066,7 +1178,12 @@ impl SearchAggregator {
                     "max_attributes_to_crop": max_attributes_to_crop,
                     "crop_marker": crop_marker,
                     "show_matches_position": show_matches_position,
+                    "show_matches_info": show_matches_info,
+                    "show_query_coverage": show_query_coverage,
+                    "enable_formatting": enable_formatting,
                     "crop_length": crop_length,
+                    "show_suggestion": show_suggestion,
+                    "show_query_analysis": show_query_analysis,
                 },
                 "facets": {
                     "avg_facets_number": format!("{:.2}", facets_sum_of_terms as f64 / facets_total_number_of_facets as f64),
@@ -1146,6 +1263,8 @@ impl MultiSearchAggregator {
                     limit: _,
                     page: _,
                     hits_per_page: _,
+                    compute_total_hits: _,
+                    count_only: _,
                     attributes_to_retrieve: _,
                     retrieve_vectors: _,
                     attributes_to_crop: _,
@@ -1154,15 +1273,30 @@ impl MultiSearchAggregator {
                     show_ranking_score: _,
                     show_ranking_score_details: _,
                     show_matches_position: _,
+                    show_matches_info: _,
+                    show_query_coverage: _,
+                    enable_formatting: _,
+                    show_suggestion: _,
+                    suggestion_max_hits: _,
+                    show_query_analysis: _,
                     filter: _,
+                    filter_relaxation: _,
+                    boosting_filter: _,
                     sort: _,
+                    ranking_rules: _,
                     distinct: _,
+                    group_by: _,
+                    group_limit: _,
                     facets: _,
                     highlight_pre_tag: _,
                     highlight_post_tag: _,
                     crop_marker: _,
+                    attributes_formatting: _,
+                    prefix_highlight: _,
                     matching_strategy: _,
+                    max_synonym_expansions: _,
                     attributes_to_search_on: _,
+                    cross_attribute_matching: _,
                     hybrid: _,
                     ranking_score_threshold: _,
                     locales: _,
@@ -1316,6 +1450,7 @@ impl FacetSearchAggregator {
         let FacetSearchQuery {
             facet_query: _,
             facet_name,
+            fuzzy,
             vector,
             q,
             filter,
@@ -1333,7 +1468,8 @@ impl FacetSearchAggregator {
         ret.user_agents = extract_user_agents(request).into_iter().collect();
         ret.facet_names = Some(facet_name.clone()).into_iter().collect();
 
-        ret.additional_search_parameters_provided = q.is_some()
+        ret.additional_search_parameters_provided = *fuzzy
+            || q.is_some()
             || vector.is_some()
             || filter.is_some()
             || *matching_strategy != MatchingStrategy::default()
@@ -1453,7 +1589,13 @@ impl DocumentsAggregator {
         index_creation: bool,
         request: &HttpRequest,
     ) -> Self {
-        let UpdateDocumentsQuery { primary_key, csv_delimiter: _ } = documents_query;
+        let UpdateDocumentsQuery {
+            primary_key,
+            csv_delimiter: _,
+            on_error: _,
+            wait_for_completion: _,
+            timeout_ms: _,
+        } = documents_query;
 
         let mut primary_keys = HashSet::new();
         if let Some(primary_key) = primary_key.clone() {
@@ -4,6 +4,7 @@ use byte_unit::{Byte, UnitType};
 use meilisearch_types::document_formats::{DocumentFormatError, PayloadType};
 use meilisearch_types::error::{Code, ErrorCode, ResponseError};
 use meilisearch_types::index_uid::{IndexUid, IndexUidFormatError};
+use meilisearch_types::tasks::TaskId;
 use serde_json::Value;
 use tokio::task::JoinError;
 
@@ -16,6 +17,8 @@ pub enum MeilisearchHttpError {
     AlreadyUsedLogRoute,
     #[error("The Content-Type `{0}` does not support the use of a csv delimiter. The csv delimiter can only be used with the Content-Type `text/csv`.")]
     CsvDelimiterWithWrongContentType(String),
+    #[error("The Content-Type `{0}` does not support skipping the invalid documents. `onError=skip` can only be used with the Content-Type `application/x-ndjson`.")]
+    SkipOnErrorWithWrongContentType(String),
     #[error(
         "The Content-Type `{0}` is invalid. Accepted values for the Content-Type header are: {}",
         .1.iter().map(|s| format!("`{}`", s)).collect::<Vec<_>>().join(", ")
@@ -31,12 +34,38 @@ pub enum MeilisearchHttpError {
     FederationOptionsInNonFederatedRequest(usize),
     #[error("Inside `.queries[{0}]`: Using pagination options is not allowed in federated queries.\n Hint: remove `{1}` from query #{0} or remove `federation: {{}}` from the request")]
     PaginationInFederatedQuery(usize, &'static str),
+    #[error("Inside `.queries[{0}]`: Using `filterRelaxation` is not allowed in federated queries.\n Hint: remove `filterRelaxation` from query #{0} or remove `federation: {{}}` from the request")]
+    FilterRelaxationInFederatedQuery(usize),
+    #[error("Inside `.queries[{0}]`: Using `groupBy` is not allowed in federated queries.\n Hint: remove `groupBy` from query #{0} or remove `federation: {{}}` from the request")]
+    GroupByInFederatedQuery(usize),
+    #[error("Dump `{0}` not found.")]
+    DumpNotFound(String),
     #[error("A {0} payload is missing.")]
     MissingPayload(PayloadType),
+    #[error("The query is {length} bytes long, which exceeds the maximum query length of {limit} bytes.")]
+    QueryTooLong { length: usize, limit: usize },
+    #[error(
+        "The query contains {terms} terms, which exceeds the maximum of {limit} terms per query."
+    )]
+    TooManyQueryTerms { terms: usize, limit: usize },
     #[error("Too many search requests running at the same time: {0}. Retry after 10s.")]
     TooManySearchRequests(usize),
+    #[error("Too many tasks are enqueued or processing to receive more documents: {0}. Retry after 10s.")]
+    TooManyPendingTasks(u64),
+    #[error("The task `{0}` given as `minConsistency` was not processed in time. Retry later.")]
+    ConsistencyTimeout(TaskId),
     #[error("Internal error: Search limiter is down.")]
     SearchLimiterIsDown,
+    #[error(
+        "The search log is disabled. Use the `--experimental-search-log-size` option to enable it."
+    )]
+    SearchLogDisabled,
+    #[error(
+        "The search statistics are disabled. Use the `--experimental-search-stats-size` option to enable them."
+    )]
+    SearchStatsDisabled,
+    #[error("The settings cannot be imported: `{0}` is a secret hidden by the export.\n Hint: replace it with the actual secret, or remove it from the settings.")]
+    HiddenSecretInImport(String),
     #[error("The provided payload reached the size limit. The maximum accepted payload size is {}.",  Byte::from_u64(*.0 as u64).get_appropriate_unit(UnitType::Binary))]
     PayloadTooLarge(usize),
     #[error("Two indexes must be given for each swap. The list `[{}]` contains {} indexes.",
@@ -71,14 +100,26 @@ impl ErrorCode for MeilisearchHttpError {
             MeilisearchHttpError::MissingContentType(_) => Code::MissingContentType,
             MeilisearchHttpError::AlreadyUsedLogRoute => Code::BadRequest,
             MeilisearchHttpError::CsvDelimiterWithWrongContentType(_) => Code::InvalidContentType,
+            MeilisearchHttpError::SkipOnErrorWithWrongContentType(_) => Code::InvalidContentType,
             MeilisearchHttpError::MissingPayload(_) => Code::MissingPayload,
             MeilisearchHttpError::InvalidContentType(_, _) => Code::InvalidContentType,
             MeilisearchHttpError::DocumentNotFound(_) => Code::DocumentNotFound,
+            MeilisearchHttpError::DumpNotFound(_) => Code::DumpNotFound,
             MeilisearchHttpError::EmptyFilter => Code::InvalidDocumentFilter,
             MeilisearchHttpError::InvalidExpression(_, _) => Code::InvalidSearchFilter,
             MeilisearchHttpError::PayloadTooLarge(_) => Code::PayloadTooLarge,
+            MeilisearchHttpError::QueryTooLong { .. }
+            | MeilisearchHttpError::TooManyQueryTerms { .. } => Code::InvalidSearchQ,
             MeilisearchHttpError::TooManySearchRequests(_) => Code::TooManySearchRequests,
+            MeilisearchHttpError::TooManyPendingTasks(_) => Code::TooManyPendingTasks,
+            MeilisearchHttpError::ConsistencyTimeout(_) => Code::ConsistencyTimeout,
             MeilisearchHttpError::SearchLimiterIsDown => Code::Internal,
+            MeilisearchHttpError::SearchLogDisabled => Code::FeatureNotEnabled,
+            MeilisearchHttpError::SearchStatsDisabled => Code::FeatureNotEnabled,
+            MeilisearchHttpError::HiddenSecretInImport(field) if field.starts_with("reranker") => {
+                Code::InvalidSettingsReranker
+            }
+            MeilisearchHttpError::HiddenSecretInImport(_) => Code::InvalidSettingsEmbedders,
             MeilisearchHttpError::SwapIndexPayloadWrongLength(_) => Code::InvalidSwapIndexes,
             MeilisearchHttpError::IndexUid(e) => e.error_code(),
             MeilisearchHttpError::SerdeJson(_) => Code::Internal,
@@ -96,6 +137,10 @@ impl ErrorCode for MeilisearchHttpError {
             MeilisearchHttpError::PaginationInFederatedQuery(_, _) => {
                 Code::InvalidMultiSearchQueryPagination
             }
+            MeilisearchHttpError::FilterRelaxationInFederatedQuery(_) => {
+                Code::InvalidSearchFilterRelaxation
+            }
+            MeilisearchHttpError::GroupByInFederatedQuery(_) => Code::InvalidSearchGroupBy,
         }
     }
 }
@@ -139,6 +184,8 @@ pub enum PayloadError {
     MalformedPayload(serde_json::error::Error),
     #[error("A json payload is missing.")]
     MissingPayload,
+    #[error("The multipart payload provided is malformed: {0}.")]
+    MalformedMultipart(String),
     #[error("Error while receiving the playload. `{0}`.")]
     ReceivePayload(Box<dyn std::error::Error + Send + Sync + 'static>),
 }
@@ -174,6 +221,7 @@ impl ErrorCode for PayloadError {
             },
             PayloadError::MissingPayload => Code::MissingPayload,
             PayloadError::MalformedPayload(_) => Code::MalformedPayload,
+            PayloadError::MalformedMultipart(_) => Code::MalformedPayload,
             PayloadError::ReceivePayload(_) => Code::Internal,
         }
     }
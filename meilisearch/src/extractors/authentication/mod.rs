@@ -42,6 +42,9 @@ impl<P, D> GuardedData<P, D> {
                 None => Err(AuthenticationError::IrretrievableState.into()),
             },
             Err(_) if missing_master_key => Err(AuthenticationError::MissingMasterKey.into()),
+            Err(e @ AuthError::RateLimitExceeded { retry_after }) => {
+                Err(ResponseError::from(e).with_retry_after(retry_after))
+            }
             Err(e) => Err(ResponseError::from_msg(e.to_string(), Code::InvalidApiKey)),
         }
     }
@@ -172,6 +175,8 @@ pub mod policies {
         CouldNotDecodeTenantToken(jsonwebtoken::errors::Error),
         #[error("Invalid action `{0}`.")]
         InternalInvalidAction(u8),
+        #[error("The API key exceeded its rate limit. Retry after {retry_after}s.")]
+        RateLimitExceeded { retry_after: u64 },
     }
 
     impl From<jsonwebtoken::errors::Error> for AuthError {
@@ -189,6 +194,7 @@ pub mod policies {
         fn error_code(&self) -> Code {
             match self {
                 AuthError::InternalInvalidAction(_) => Code::Internal,
+                AuthError::RateLimitExceeded { .. } => Code::ApiKeyRateLimitExceeded,
                 _ => Code::InvalidApiKey,
             }
         }
@@ -287,6 +293,13 @@ pub mod policies {
                 }
             }
             if auth.is_key_authorized(key_uuid, action, index).unwrap_or(false) {
+                // the requests of the tenant tokens count in the rate limit of their API key
+                let retry_after =
+                    auth.acquire_rate_limit(key_uuid).map_err(|_e| AuthError::InvalidApiKey)?;
+                if let Some(retry_after) = retry_after {
+                    let retry_after = retry_after.as_secs_f64().ceil().max(1.0) as u64;
+                    return Err(AuthError::RateLimitExceeded { retry_after });
+                }
                 return Ok(auth_filter);
             }
 
@@ -9,7 +9,9 @@ pub mod middleware;
 pub mod option;
 pub mod routes;
 pub mod search;
+pub mod search_log;
 pub mod search_queue;
+pub mod search_stats;
 
 use std::fs::File;
 use std::io::{BufReader, BufWriter};
@@ -18,7 +20,7 @@ use std::path::Path;
 use std::str::FromStr;
 use std::sync::Arc;
 use std::thread::{self, available_parallelism};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use actix_cors::Cors;
 use actix_http::body::MessageBody;
@@ -41,6 +43,8 @@ use meilisearch_types::versioning::{check_version_file, create_version_file};
 use meilisearch_types::{compression, milli, VERSION_FILE_NAME};
 pub use option::Opt;
 use option::ScheduleSnapshot;
+use search::QueryLimits;
+use search_log::SearchLog;
 use search_queue::SearchQueue;
 use tracing::{error, info_span};
 use tracing_subscriber::filter::Targets;
@@ -75,6 +79,9 @@ const DEFAULT_INDEX_COUNT: usize = 4;
 #[cfg(not(windows))]
 const DEFAULT_INDEX_COUNT: usize = 20;
 
+/// How often the snapshot scheduler checks whether a snapshot must be registered.
+const SNAPSHOT_SCHEDULER_TICK: Duration = Duration::from_millis(100);
+
 /// Check if a db is empty. It does not provide any information on the
 /// validity of the data in it.
 /// We consider a database as non empty when it's a non empty directory.
@@ -259,20 +266,37 @@ pub fn setup_meilisearch(opt: &Opt) -> anyhow::Result<(Arc<IndexScheduler>, Arc<
         open_or_create_database(opt, empty_db)?
     };
 
-    // We create a loop in a thread that registers snapshotCreation tasks
+    // We create a loop in a thread that registers snapshotCreation tasks.
+    // The interval can be changed at runtime, so it is read again on every tick.
     let index_scheduler = Arc::new(index_scheduler);
     let auth_controller = Arc::new(auth_controller);
-    if let ScheduleSnapshot::Enabled(snapshot_delay) = opt.schedule_snapshot {
-        let snapshot_delay = Duration::from_secs(snapshot_delay);
-        let index_scheduler = index_scheduler.clone();
+    {
+        // the thread stops once the index scheduler is dropped
+        let weak_index_scheduler = Arc::downgrade(&index_scheduler);
+        let mut interval = index_scheduler.snapshot_settings().interval;
         thread::Builder::new()
             .name(String::from("register-snapshot-tasks"))
-            .spawn(move || loop {
-                thread::sleep(snapshot_delay);
-                if let Err(e) =
-                    index_scheduler.register(KindWithContent::SnapshotCreation, None, false)
-                {
-                    error!("Error while registering snapshot: {}", e);
+            .spawn(move || {
+                let mut last_snapshot = Instant::now();
+                loop {
+                    thread::sleep(SNAPSHOT_SCHEDULER_TICK);
+                    let Some(index_scheduler) = weak_index_scheduler.upgrade() else { break };
+                    let current_interval = index_scheduler.snapshot_settings().interval;
+                    // a new interval starts counting from the moment it is set
+                    if current_interval != interval {
+                        interval = current_interval;
+                        last_snapshot = Instant::now();
+                    }
+                    let Some(delay) = interval.map(Duration::from_secs) else { continue };
+                    if last_snapshot.elapsed() < delay {
+                        continue;
+                    }
+                    last_snapshot = Instant::now();
+                    if let Err(e) =
+                        index_scheduler.register(KindWithContent::SnapshotCreation, None, false)
+                    {
+                        error!("Error while registering snapshot: {}", e);
+                    }
                 }
             })
             .unwrap();
@@ -295,20 +319,37 @@ fn open_or_create_database_unchecked(
             version_file_path: opt.db_path.join(VERSION_FILE_NAME),
             auth_path: opt.db_path.join("auth"),
             tasks_path: opt.db_path.join("tasks"),
+            search_events_path: opt.db_path.join("search_events"),
             update_file_path: opt.db_path.join("update_files"),
             indexes_path: opt.db_path.join("indexes"),
             snapshots_path: opt.snapshot_dir.clone(),
+            snapshot_interval: match opt.schedule_snapshot {
+                ScheduleSnapshot::Enabled(interval) => Some(interval),
+                ScheduleSnapshot::Disabled => None,
+            },
             dumps_path: opt.dump_dir.clone(),
             webhook_url: opt.task_webhook_url.as_ref().map(|url| url.to_string()),
             webhook_authorization_header: opt.task_webhook_authorization_header.clone(),
             task_db_size: opt.max_task_db_size.as_u64() as usize,
             index_base_map_size: opt.max_index_size.as_u64() as usize,
+            index_map_size_hint: opt
+                .indexer_options
+                .experimental_index_map_size_hint
+                .map(|size| size.as_u64() as usize),
             enable_mdb_writemap: opt.experimental_reduce_indexing_memory_usage,
             indexer_config: (&opt.indexer_options).try_into()?,
             autobatching_enabled: true,
             cleanup_enabled: !opt.experimental_replication_parameters,
             max_number_of_tasks: 1_000_000,
             max_number_of_batched_tasks: opt.experimental_max_number_of_batched_tasks,
+            document_url_import_max_size: opt.experimental_document_url_import_max_size.as_u64()
+                as usize,
+            max_task_retries: opt.experimental_max_task_retries,
+            task_retry_backoff: Duration::from_secs(1),
+            task_retention: opt
+                .experimental_task_retention_days
+                .map(|days| Duration::from_secs(days * 24 * 60 * 60)),
+            search_events_capacity: opt.experimental_search_stats_size,
             index_growth_amount: byte_unit::Byte::from_str("10GiB").unwrap().as_u64() as usize,
             index_count: DEFAULT_INDEX_COUNT,
             instance_features,
@@ -364,6 +405,17 @@ fn import_dump(
         );
     }
 
+    if dump_reader.settings_only() {
+        tracing::info!("The dump only contains settings, no documents will be imported");
+    }
+
+    if let Some(since) = dump_reader.since() {
+        bail!(
+            "The dump only contains the changes made after the task {since} and can't create a database on its own. \
+            It must be imported into a running instance with the `/dumps/{{dump_uid}}/import` route instead."
+        );
+    }
+
     let instance_uid = dump_reader.instance_uid()?;
 
     // 1. Import the instance-uid.
@@ -465,6 +517,15 @@ fn import_dump(
     Ok(index_scheduler_dump.finish()?)
 }
 
+/// Creates the queue bounding the searches processed concurrently, as configured by the options.
+pub fn create_search_queue(opt: &Opt) -> SearchQueue {
+    SearchQueue::new(
+        opt.experimental_search_queue_size,
+        opt.experimental_search_max_concurrency
+            .unwrap_or_else(|| available_parallelism().unwrap_or(NonZeroUsize::new(2).unwrap())),
+    )
+}
+
 pub fn configure_data(
     config: &mut web::ServiceConfig,
     index_scheduler: Data<IndexScheduler>,
@@ -473,15 +534,20 @@ pub fn configure_data(
     (logs_route, logs_stderr): (LogRouteHandle, LogStderrHandle),
     analytics: Arc<dyn Analytics>,
 ) {
-    let search_queue = SearchQueue::new(
-        opt.experimental_search_queue_size,
-        available_parallelism().unwrap_or(NonZeroUsize::new(2).unwrap()),
-    );
+    let search_queue = create_search_queue(opt);
     let http_payload_size_limit = opt.http_payload_size_limit.as_u64() as usize;
     config
         .app_data(index_scheduler)
         .app_data(auth)
         .app_data(web::Data::new(search_queue))
+        .app_data(web::Data::new(SearchLog::new(
+            opt.experimental_search_log_size,
+            opt.experimental_search_log_hash_queries,
+        )))
+        .app_data(web::Data::new(QueryLimits {
+            max_length: opt.max_query_length,
+            max_terms: opt.max_query_terms,
+        }))
         .app_data(web::Data::from(analytics))
         .app_data(web::Data::new(logs_route))
         .app_data(web::Data::new(logs_stderr))
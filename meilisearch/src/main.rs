@@ -3,7 +3,9 @@ use std::io::{stderr, LineWriter, Write};
 use std::path::PathBuf;
 use std::str::FromStr;
 use std::sync::Arc;
+use std::time::Duration;
 
+use actix_web::dev::ServerHandle;
 use actix_web::http::KeepAlive;
 use actix_web::web::Data;
 use actix_web::HttpServer;
@@ -135,6 +137,7 @@ async fn run_http(
     let opt_clone = opt.clone();
     let index_scheduler = Data::from(index_scheduler);
     let auth_controller = Data::from(auth_controller);
+    let scheduler = index_scheduler.clone();
 
     let http_server = HttpServer::new(move || {
         create_app(
@@ -146,18 +149,79 @@ async fn run_http(
             enable_dashboard,
         )
     })
-    // Disable signals allows the server to terminate immediately when a user enter CTRL-C
+    // Disable signals allows the server to terminate immediately when a user enter CTRL-C,
+    // unless the graceful shutdown is enabled
     .disable_signals()
     .keep_alive(KeepAlive::Os);
 
-    if let Some(config) = opt_clone.get_ssl_config()? {
-        http_server.bind_rustls_0_23(opt_clone.http_addr, config)?.run().await?;
+    let server = if let Some(config) = opt_clone.get_ssl_config()? {
+        http_server.bind_rustls_0_23(opt_clone.http_addr, config)?.run()
     } else {
-        http_server.bind(&opt_clone.http_addr)?.run().await?;
+        http_server.bind(&opt_clone.http_addr)?.run()
+    };
+    if let Some(timeout) = opt_clone.experimental_graceful_shutdown_timeout {
+        tokio::spawn(shutdown_gracefully(scheduler, server.handle(), Duration::from_secs(timeout)));
     }
+    server.await?;
     Ok(())
 }
 
+/// On the first signal, stops the writes and waits for the batch being processed before
+/// stopping the server. A second signal exits immediately.
+async fn shutdown_gracefully(
+    index_scheduler: Data<IndexScheduler>,
+    server: ServerHandle,
+    timeout: Duration,
+) {
+    shutdown_signal().await;
+    tracing::info!("Shutting down, waiting up to {}s for the processing batch", timeout.as_secs());
+    index_scheduler.start_shutdown();
+
+    tokio::spawn(async {
+        shutdown_signal().await;
+        tracing::warn!(
+            "Exiting immediately, the processing batch is processed again on the next launch"
+        );
+        std::process::exit(130);
+    });
+
+    let finished =
+        tokio::task::spawn_blocking(move || index_scheduler.wait_for_processing_batch(timeout))
+            .await
+            .unwrap_or(false);
+    if !finished {
+        tracing::warn!(
+            "The processing batch didn't finish in time, it is processed again on the next launch"
+        );
+    }
+    server.stop(true).await;
+}
+
+/// Waits for a CTRL-C or, on unix, a SIGTERM.
+async fn shutdown_signal() {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{signal, SignalKind};
+
+        match signal(SignalKind::terminate()) {
+            Ok(mut terminate) => {
+                tokio::select! {
+                    _ = tokio::signal::ctrl_c() => (),
+                    _ = terminate.recv() => (),
+                }
+            }
+            Err(error) => {
+                tracing::error!("While listening for SIGTERM: {error}");
+                let _ = tokio::signal::ctrl_c().await;
+            }
+        }
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = tokio::signal::ctrl_c().await;
+    }
+}
+
 pub fn print_launch_resume(
     opt: &Opt,
     analytics: Arc<dyn Analytics>,
@@ -31,6 +31,16 @@ lazy_static! {
         &["index"]
     )
     .expect("Can't create a metric");
+    pub static ref MEILISEARCH_INDEX_SIZE_BYTES: IntGaugeVec = register_int_gauge_vec!(
+        opts!("meilisearch_index_size_bytes", "Meilisearch Index Size In Bytes"),
+        &["index"]
+    )
+    .expect("Can't create a metric");
+    pub static ref MEILISEARCH_INDEX_USED_SIZE_BYTES: IntGaugeVec = register_int_gauge_vec!(
+        opts!("meilisearch_index_used_size_bytes", "Meilisearch Index Used Size In Bytes"),
+        &["index"]
+    )
+    .expect("Can't create a metric");
     pub static ref MEILISEARCH_HTTP_RESPONSE_TIME_SECONDS: HistogramVec = register_histogram_vec!(
         "meilisearch_http_response_time_seconds",
         "Meilisearch HTTP response times",
@@ -49,4 +59,18 @@ lazy_static! {
     pub static ref MEILISEARCH_IS_INDEXING: IntGauge =
         register_int_gauge!(opts!("meilisearch_is_indexing", "Meilisearch Is Indexing"))
             .expect("Can't create a metric");
+    pub static ref MEILISEARCH_SEARCH_QUEUE_SIZE: IntGauge = register_int_gauge!(opts!(
+        "meilisearch_search_queue_size",
+        "Meilisearch Search Queue Size"
+    ))
+    .expect("Can't create a metric");
+    pub static ref MEILISEARCH_SEARCHES_RUNNING: IntGauge =
+        register_int_gauge!(opts!("meilisearch_searches_running", "Meilisearch Searches Running"))
+            .expect("Can't create a metric");
+    pub static ref MEILISEARCH_SEARCHES_WAITING_TO_BE_PROCESSED: IntGauge =
+        register_int_gauge!(opts!(
+            "meilisearch_searches_waiting_to_be_processed",
+            "Meilisearch Searches Waiting To Be Processed"
+        ))
+        .expect("Can't create a metric");
 }
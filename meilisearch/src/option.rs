@@ -2,7 +2,7 @@ use std::env::VarError;
 use std::ffi::OsStr;
 use std::fmt::Display;
 use std::io::{BufReader, Read};
-use std::num::ParseIntError;
+use std::num::{NonZeroUsize, ParseIntError};
 use std::ops::Deref;
 use std::path::PathBuf;
 use std::str::FromStr;
@@ -32,6 +32,8 @@ const MEILI_TASK_WEBHOOK_AUTHORIZATION_HEADER: &str = "MEILI_TASK_WEBHOOK_AUTHOR
 #[cfg(feature = "analytics")]
 const MEILI_NO_ANALYTICS: &str = "MEILI_NO_ANALYTICS";
 const MEILI_HTTP_PAYLOAD_SIZE_LIMIT: &str = "MEILI_HTTP_PAYLOAD_SIZE_LIMIT";
+const MEILI_MAX_QUERY_LENGTH: &str = "MEILI_MAX_QUERY_LENGTH";
+const MEILI_MAX_QUERY_TERMS: &str = "MEILI_MAX_QUERY_TERMS";
 const MEILI_SSL_CERT_PATH: &str = "MEILI_SSL_CERT_PATH";
 const MEILI_SSL_KEY_PATH: &str = "MEILI_SSL_KEY_PATH";
 const MEILI_SSL_AUTH_PATH: &str = "MEILI_SSL_AUTH_PATH";
@@ -55,16 +57,36 @@ const MEILI_EXPERIMENTAL_ENABLE_LOGS_ROUTE: &str = "MEILI_EXPERIMENTAL_ENABLE_LO
 const MEILI_EXPERIMENTAL_CONTAINS_FILTER: &str = "MEILI_EXPERIMENTAL_CONTAINS_FILTER";
 const MEILI_EXPERIMENTAL_ENABLE_METRICS: &str = "MEILI_EXPERIMENTAL_ENABLE_METRICS";
 const MEILI_EXPERIMENTAL_SEARCH_QUEUE_SIZE: &str = "MEILI_EXPERIMENTAL_SEARCH_QUEUE_SIZE";
+const MEILI_EXPERIMENTAL_SEARCH_MAX_CONCURRENCY: &str = "MEILI_EXPERIMENTAL_SEARCH_MAX_CONCURRENCY";
+const MEILI_EXPERIMENTAL_SEARCH_LOG_SIZE: &str = "MEILI_EXPERIMENTAL_SEARCH_LOG_SIZE";
+const MEILI_EXPERIMENTAL_SEARCH_LOG_HASH_QUERIES: &str =
+    "MEILI_EXPERIMENTAL_SEARCH_LOG_HASH_QUERIES";
+const MEILI_EXPERIMENTAL_SEARCH_STATS_SIZE: &str = "MEILI_EXPERIMENTAL_SEARCH_STATS_SIZE";
+const MEILI_EXPERIMENTAL_DOCUMENT_ADDITION_MAX_PENDING_TASKS: &str =
+    "MEILI_EXPERIMENTAL_DOCUMENT_ADDITION_MAX_PENDING_TASKS";
 const MEILI_EXPERIMENTAL_REDUCE_INDEXING_MEMORY_USAGE: &str =
     "MEILI_EXPERIMENTAL_REDUCE_INDEXING_MEMORY_USAGE";
 const MEILI_EXPERIMENTAL_MAX_NUMBER_OF_BATCHED_TASKS: &str =
     "MEILI_EXPERIMENTAL_MAX_NUMBER_OF_BATCHED_TASKS";
+const MEILI_EXPERIMENTAL_DOCUMENT_URL_IMPORT_ALLOWED_HOSTS: &str =
+    "MEILI_EXPERIMENTAL_DOCUMENT_URL_IMPORT_ALLOWED_HOSTS";
+const MEILI_EXPERIMENTAL_DOCUMENT_URL_IMPORT_MAX_SIZE: &str =
+    "MEILI_EXPERIMENTAL_DOCUMENT_URL_IMPORT_MAX_SIZE";
+const MEILI_EXPERIMENTAL_DUMP_IMPORT_ALLOWED_HOSTS: &str =
+    "MEILI_EXPERIMENTAL_DUMP_IMPORT_ALLOWED_HOSTS";
+const MEILI_EXPERIMENTAL_MAX_TASK_RETRIES: &str = "MEILI_EXPERIMENTAL_MAX_TASK_RETRIES";
+const MEILI_EXPERIMENTAL_TASK_RETENTION_DAYS: &str = "MEILI_EXPERIMENTAL_TASK_RETENTION_DAYS";
+const MEILI_EXPERIMENTAL_GRACEFUL_SHUTDOWN_TIMEOUT: &str =
+    "MEILI_EXPERIMENTAL_GRACEFUL_SHUTDOWN_TIMEOUT";
 
 const DEFAULT_CONFIG_FILE_PATH: &str = "./config.toml";
 const DEFAULT_DB_PATH: &str = "./data.ms";
 const DEFAULT_HTTP_ADDR: &str = "localhost:7700";
 const DEFAULT_ENV: &str = "development";
 const DEFAULT_HTTP_PAYLOAD_SIZE_LIMIT: &str = "100 MB";
+const DEFAULT_DOCUMENT_URL_IMPORT_MAX_SIZE: &str = "100 MB";
+const DEFAULT_MAX_QUERY_LENGTH: usize = 16_384;
+const DEFAULT_MAX_QUERY_TERMS: usize = 1000;
 const DEFAULT_SNAPSHOT_DIR: &str = "snapshots/";
 const DEFAULT_SNAPSHOT_INTERVAL_SEC: u64 = 86400;
 const DEFAULT_SNAPSHOT_INTERVAL_SEC_STR: &str = "86400";
@@ -72,6 +94,7 @@ const DEFAULT_DUMP_DIR: &str = "dumps/";
 
 const MEILI_MAX_INDEXING_MEMORY: &str = "MEILI_MAX_INDEXING_MEMORY";
 const MEILI_MAX_INDEXING_THREADS: &str = "MEILI_MAX_INDEXING_THREADS";
+const MEILI_EXPERIMENTAL_INDEX_MAP_SIZE_HINT: &str = "MEILI_EXPERIMENTAL_INDEX_MAP_SIZE_HINT";
 const DEFAULT_LOG_EVERY_N: usize = 100_000;
 
 // Each environment (index and task-db) is taking space in the virtual address space.
@@ -230,6 +253,18 @@ pub struct Opt {
     #[serde(default = "default_http_payload_size_limit")]
     pub http_payload_size_limit: Byte,
 
+    /// Sets the maximum length, in bytes, of the queries of the search requests.
+    /// Longer queries are rejected instead of being processed. The default value is 16384.
+    #[clap(long, env = MEILI_MAX_QUERY_LENGTH, default_value_t = default_max_query_length())]
+    #[serde(default = "default_max_query_length")]
+    pub max_query_length: usize,
+
+    /// Sets the maximum number of terms, separated by whitespaces, of the queries of the search requests.
+    /// Queries made of more terms are rejected instead of being processed. The default value is 1000.
+    #[clap(long, env = MEILI_MAX_QUERY_TERMS, default_value_t = default_max_query_terms())]
+    #[serde(default = "default_max_query_terms")]
+    pub max_query_terms: usize,
+
     /// Sets the server's SSL certificates.
     #[clap(long, env = MEILI_SSL_CERT_PATH, value_parser)]
     pub ssl_cert_path: Option<PathBuf>,
@@ -361,6 +396,51 @@ pub struct Opt {
     #[serde(default)]
     pub experimental_search_queue_size: usize,
 
+    /// Experimental maximum number of search requests processed at the same time.
+    ///
+    /// The other search requests wait in the search queue until a search finishes.
+    /// This limit is independent of the number of threads used for indexing.
+    /// Defaults to the number of available cores.
+    #[clap(long, env = MEILI_EXPERIMENTAL_SEARCH_MAX_CONCURRENCY)]
+    #[serde(default)]
+    pub experimental_search_max_concurrency: Option<NonZeroUsize>,
+
+    /// Experimental number of recent searches kept in memory for each index.
+    ///
+    /// The query, filter, number of hits and processing time of the most recent searches are
+    /// listed on the `GET /indexes/{indexUid}/recent-searches` route.
+    /// The default value is 0, which disables the search log.
+    #[clap(long, env = MEILI_EXPERIMENTAL_SEARCH_LOG_SIZE, default_value_t = 0)]
+    #[serde(default)]
+    pub experimental_search_log_size: usize,
+
+    /// Experimental hashing of the queries kept in the search log.
+    ///
+    /// Replaces the text of the logged queries by its SHA-256 hash, salted with a random value
+    /// drawn at launch: the hash of a query stays the same until Meilisearch restarts.
+    #[clap(long, env = MEILI_EXPERIMENTAL_SEARCH_LOG_HASH_QUERIES)]
+    #[serde(default)]
+    pub experimental_search_log_hash_queries: bool,
+
+    /// Experimental number of recent searches recorded on disk for the search statistics.
+    ///
+    /// The length of the query, the use of a filter, the number of hits and the processing time
+    /// of the searches are aggregated on the `GET /stats/searches` route. The text of a query is
+    /// only kept when it found no documents.
+    /// The default value is 0, which disables the search statistics.
+    #[clap(long, env = MEILI_EXPERIMENTAL_SEARCH_STATS_SIZE, default_value_t = 0)]
+    #[serde(default)]
+    pub experimental_search_stats_size: usize,
+
+    /// Experimental backpressure on the document additions.
+    ///
+    /// While at least this number of tasks are enqueued or processing, the payloads of the
+    /// document additions are no longer read, slowing down the clients streaming large imports
+    /// until the indexer catches up. By default the payloads are always read as fast as they are sent.
+    #[clap(long, env = MEILI_EXPERIMENTAL_DOCUMENT_ADDITION_MAX_PENDING_TASKS)]
+    #[serde(default)]
+    pub experimental_document_addition_max_pending_tasks: Option<NonZeroUsize>,
+
     /// Experimental logs mode feature. For more information, see: <https://github.com/orgs/meilisearch/discussions/723>
     ///
     /// Change the mode of the logs on the console.
@@ -395,6 +475,62 @@ pub struct Opt {
     #[serde(default = "default_limit_batched_tasks")]
     pub experimental_max_number_of_batched_tasks: usize,
 
+    /// Experimental import of documents from a remote URL.
+    ///
+    /// The comma-separated list of the hosts the `POST /indexes/{indexUid}/documents/import-url`
+    /// route is allowed to download documents from. By default no host is allowed.
+    #[clap(long, env = MEILI_EXPERIMENTAL_DOCUMENT_URL_IMPORT_ALLOWED_HOSTS, value_delimiter = ',')]
+    #[serde(default)]
+    pub experimental_document_url_import_allowed_hosts: Vec<String>,
+
+    /// Sets the maximum size of the files downloaded by the document URL imports. Value must be given
+    /// in bytes or explicitly stating a base unit (for instance: 107374182400, '107.7Gb', or '107374 Mb').
+    #[clap(long, env = MEILI_EXPERIMENTAL_DOCUMENT_URL_IMPORT_MAX_SIZE, default_value_t = default_document_url_import_max_size())]
+    #[serde(default = "default_document_url_import_max_size")]
+    pub experimental_document_url_import_max_size: Byte,
+
+    /// Experimental import of dumps from a remote URL.
+    ///
+    /// The comma-separated list of the hosts the `POST /dumps/import` route is allowed to
+    /// download dumps from. By default no host is allowed.
+    #[clap(long, env = MEILI_EXPERIMENTAL_DUMP_IMPORT_ALLOWED_HOSTS, value_delimiter = ',')]
+    #[serde(default)]
+    pub experimental_dump_import_allowed_hosts: Vec<String>,
+
+    /// Experimental retry of the batches failing with a transient error, like an I/O error.
+    ///
+    /// A failing batch is processed again up to this number of times, waiting one second
+    /// before the first retry and twice as long before each following one. Once the retries
+    /// are exhausted its tasks are marked as failed and can be listed with
+    /// `GET /tasks?statuses=failed`. The errors caused by the content of the tasks are never
+    /// retried. By default the batches are not retried.
+    #[clap(long, env = MEILI_EXPERIMENTAL_MAX_TASK_RETRIES, default_value_t)]
+    #[serde(default)]
+    pub experimental_max_task_retries: u32,
+
+    /// Experimental deletion of the old finished tasks.
+    ///
+    /// The succeeded, failed and canceled tasks that finished more than this number of days ago
+    /// are deleted by a `taskDeletion` task enqueued automatically. By default the finished tasks
+    /// are only deleted when the task queue holds too many tasks.
+    #[clap(long, env = MEILI_EXPERIMENTAL_TASK_RETENTION_DAYS)]
+    #[serde(default)]
+    pub experimental_task_retention_days: Option<u64>,
+
+    /// Experimental graceful shutdown, in seconds.
+    ///
+    /// On a SIGTERM or a CTRL-C, Meilisearch stops accepting write operations and waits up to
+    /// this number of seconds for the batch being processed to finish before exiting; the
+    /// enqueued tasks are processed on the next launch. A second signal exits immediately.
+    /// By default Meilisearch exits immediately and the interrupted batch is processed again
+    /// on the next launch.
+    ///
+    /// A batch that doesn't finish within the timeout is also processed again from the start
+    /// on the next launch, the intermediate results of its extraction are not checkpointed.
+    #[clap(long, env = MEILI_EXPERIMENTAL_GRACEFUL_SHUTDOWN_TIMEOUT)]
+    #[serde(default)]
+    pub experimental_graceful_shutdown_timeout: Option<u64>,
+
     #[serde(flatten)]
     #[clap(flatten)]
     pub indexer_options: IndexerOpts,
@@ -467,7 +603,15 @@ impl Opt {
             max_index_size: _,
             max_task_db_size: _,
             http_payload_size_limit,
+            max_query_length,
+            max_query_terms,
             experimental_max_number_of_batched_tasks,
+            experimental_document_url_import_allowed_hosts,
+            experimental_document_url_import_max_size,
+            experimental_dump_import_allowed_hosts,
+            experimental_max_task_retries,
+            experimental_task_retention_days,
+            experimental_graceful_shutdown_timeout,
             ssl_cert_path,
             ssl_key_path,
             ssl_auth_path,
@@ -492,6 +636,11 @@ impl Opt {
             experimental_contains_filter,
             experimental_enable_metrics,
             experimental_search_queue_size,
+            experimental_search_max_concurrency,
+            experimental_search_log_size,
+            experimental_search_log_hash_queries,
+            experimental_search_stats_size,
+            experimental_document_addition_max_pending_tasks,
             experimental_logs_mode,
             experimental_enable_logs_route,
             experimental_replication_parameters,
@@ -521,10 +670,41 @@ impl Opt {
             MEILI_HTTP_PAYLOAD_SIZE_LIMIT,
             http_payload_size_limit.to_string(),
         );
+        export_to_env_if_not_present(MEILI_MAX_QUERY_LENGTH, max_query_length.to_string());
+        export_to_env_if_not_present(MEILI_MAX_QUERY_TERMS, max_query_terms.to_string());
         export_to_env_if_not_present(
             MEILI_EXPERIMENTAL_MAX_NUMBER_OF_BATCHED_TASKS,
             experimental_max_number_of_batched_tasks.to_string(),
         );
+        export_to_env_if_not_present(
+            MEILI_EXPERIMENTAL_DOCUMENT_URL_IMPORT_ALLOWED_HOSTS,
+            experimental_document_url_import_allowed_hosts.join(","),
+        );
+        export_to_env_if_not_present(
+            MEILI_EXPERIMENTAL_DOCUMENT_URL_IMPORT_MAX_SIZE,
+            experimental_document_url_import_max_size.to_string(),
+        );
+        export_to_env_if_not_present(
+            MEILI_EXPERIMENTAL_DUMP_IMPORT_ALLOWED_HOSTS,
+            experimental_dump_import_allowed_hosts.join(","),
+        );
+        export_to_env_if_not_present(
+            MEILI_EXPERIMENTAL_MAX_TASK_RETRIES,
+            experimental_max_task_retries.to_string(),
+        );
+        if let Some(experimental_task_retention_days) = experimental_task_retention_days {
+            export_to_env_if_not_present(
+                MEILI_EXPERIMENTAL_TASK_RETENTION_DAYS,
+                experimental_task_retention_days.to_string(),
+            );
+        }
+        if let Some(experimental_graceful_shutdown_timeout) = experimental_graceful_shutdown_timeout
+        {
+            export_to_env_if_not_present(
+                MEILI_EXPERIMENTAL_GRACEFUL_SHUTDOWN_TIMEOUT,
+                experimental_graceful_shutdown_timeout.to_string(),
+            );
+        }
         if let Some(ssl_cert_path) = ssl_cert_path {
             export_to_env_if_not_present(MEILI_SSL_CERT_PATH, ssl_cert_path);
         }
@@ -559,6 +739,32 @@ impl Opt {
             MEILI_EXPERIMENTAL_SEARCH_QUEUE_SIZE,
             experimental_search_queue_size.to_string(),
         );
+        if let Some(experimental_search_max_concurrency) = experimental_search_max_concurrency {
+            export_to_env_if_not_present(
+                MEILI_EXPERIMENTAL_SEARCH_MAX_CONCURRENCY,
+                experimental_search_max_concurrency.to_string(),
+            );
+        }
+        export_to_env_if_not_present(
+            MEILI_EXPERIMENTAL_SEARCH_LOG_SIZE,
+            experimental_search_log_size.to_string(),
+        );
+        export_to_env_if_not_present(
+            MEILI_EXPERIMENTAL_SEARCH_LOG_HASH_QUERIES,
+            experimental_search_log_hash_queries.to_string(),
+        );
+        export_to_env_if_not_present(
+            MEILI_EXPERIMENTAL_SEARCH_STATS_SIZE,
+            experimental_search_stats_size.to_string(),
+        );
+        if let Some(experimental_document_addition_max_pending_tasks) =
+            experimental_document_addition_max_pending_tasks
+        {
+            export_to_env_if_not_present(
+                MEILI_EXPERIMENTAL_DOCUMENT_ADDITION_MAX_PENDING_TASKS,
+                experimental_document_addition_max_pending_tasks.to_string(),
+            );
+        }
         export_to_env_if_not_present(
             MEILI_EXPERIMENTAL_LOGS_MODE,
             experimental_logs_mode.to_string(),
@@ -646,6 +852,13 @@ pub struct IndexerOpts {
     #[serde(default)]
     pub max_indexing_threads: MaxThreads,
 
+    /// Experimentally sets the size with which the indexes are opened, so that large bulk imports don't have to
+    /// resize them midway. Value must be given in bytes or explicitly stating a base unit (for instance: 107374182400,
+    /// '107.7Gb', or '107374 Mb'). An index that outgrows this size is still resized automatically.
+    #[clap(long, env = MEILI_EXPERIMENTAL_INDEX_MAP_SIZE_HINT)]
+    #[serde(default)]
+    pub experimental_index_map_size_hint: Option<Byte>,
+
     /// Whether or not we want to determine the budget of virtual memory address space we have available dynamically
     /// (the default), or statically.
     ///
@@ -659,7 +872,12 @@ pub struct IndexerOpts {
 impl IndexerOpts {
     /// Exports the values to their corresponding env vars if they are not set.
     pub fn export_to_env(self) {
-        let IndexerOpts { max_indexing_memory, max_indexing_threads, skip_index_budget: _ } = self;
+        let IndexerOpts {
+            max_indexing_memory,
+            max_indexing_threads,
+            experimental_index_map_size_hint,
+            skip_index_budget: _,
+        } = self;
         if let Some(max_indexing_memory) = max_indexing_memory.0 {
             export_to_env_if_not_present(
                 MEILI_MAX_INDEXING_MEMORY,
@@ -670,6 +888,12 @@ impl IndexerOpts {
             MEILI_MAX_INDEXING_THREADS,
             max_indexing_threads.0.to_string(),
         );
+        if let Some(map_size_hint) = experimental_index_map_size_hint {
+            export_to_env_if_not_present(
+                MEILI_EXPERIMENTAL_INDEX_MAP_SIZE_HINT,
+                map_size_hint.to_string(),
+            );
+        }
     }
 }
 
@@ -874,6 +1098,18 @@ fn default_http_payload_size_limit() -> Byte {
     Byte::from_str(DEFAULT_HTTP_PAYLOAD_SIZE_LIMIT).unwrap()
 }
 
+fn default_document_url_import_max_size() -> Byte {
+    Byte::from_str(DEFAULT_DOCUMENT_URL_IMPORT_MAX_SIZE).unwrap()
+}
+
+fn default_max_query_length() -> usize {
+    DEFAULT_MAX_QUERY_LENGTH
+}
+
+fn default_max_query_terms() -> usize {
+    DEFAULT_MAX_QUERY_TERMS
+}
+
 fn default_limit_batched_tasks() -> usize {
     usize::MAX
 }
@@ -0,0 +1,125 @@
+use actix_web::web::Data;
+use actix_web::{web, HttpRequest, HttpResponse};
+use deserr::actix_web::AwebJson;
+use deserr::Deserr;
+use index_scheduler::IndexScheduler;
+use meilisearch_types::deserr::DeserrJsonError;
+use meilisearch_types::error::deserr_codes::InvalidIndexUid;
+use meilisearch_types::error::ResponseError;
+use meilisearch_types::index_uid::IndexUid;
+use meilisearch_types::settings::{Settings, Unchecked};
+use meilisearch_types::tasks::KindWithContent;
+use serde::Serialize;
+use serde_json::json;
+use tracing::debug;
+
+use super::{is_dry_run, SummarizedTaskView};
+use crate::analytics::Analytics;
+use crate::extractors::authentication::policies::*;
+use crate::extractors::authentication::{AuthenticationError, GuardedData};
+use crate::extractors::sequential_extractor::SeqHandler;
+use crate::routes::indexes::settings::validate_settings;
+use crate::Opt;
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(web::resource("/batch").route(web::post().to(SeqHandler(update_settings_batch))));
+}
+
+#[derive(Deserr, Debug)]
+#[deserr(error = DeserrJsonError, rename_all = camelCase, deny_unknown_fields)]
+pub struct IndexSettingsUpdate {
+    #[deserr(error = DeserrJsonError<InvalidIndexUid>, missing_field_error = DeserrJsonError::missing_index_uid)]
+    index_uid: IndexUid,
+    settings: Settings<Unchecked>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexSettingsUpdateResult {
+    index_uid: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    task: Option<SummarizedTaskView>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<ResponseError>,
+}
+
+#[derive(Debug, Serialize)]
+struct SettingsBatchResults {
+    results: Vec<IndexSettingsUpdateResult>,
+}
+
+/// Enqueues one settings update task per index.
+///
+/// Every index is processed independently: an index that doesn't exist, is not
+/// authorized or receives invalid settings gets an error in its own result entry
+/// without preventing the other updates from being enqueued.
+pub async fn update_settings_batch(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::SETTINGS_UPDATE }>, Data<IndexScheduler>>,
+    params: AwebJson<Vec<IndexSettingsUpdate>, DeserrJsonError>,
+    req: HttpRequest,
+    opt: web::Data<Opt>,
+    analytics: web::Data<dyn Analytics>,
+) -> Result<HttpResponse, ResponseError> {
+    let params = params.into_inner();
+    debug!(parameters = ?params, "Update settings batch");
+
+    analytics.publish(
+        "Settings Batch Updated".to_string(),
+        json!({ "total_indexes": params.len() }),
+        Some(&req),
+    );
+
+    // the authorization must be checked before moving the scheduler in the blocking task.
+    let updates: Vec<_> = params
+        .into_iter()
+        .map(|IndexSettingsUpdate { index_uid, settings }| {
+            let authorized = index_scheduler.filters().is_index_authorized(&index_uid);
+            (index_uid, settings, authorized)
+        })
+        .collect();
+
+    let dry_run = is_dry_run(&req, &opt)?;
+    let results = tokio::task::spawn_blocking(move || {
+        updates
+            .into_iter()
+            .map(|(index_uid, settings, authorized)| {
+                let result = if authorized {
+                    register_settings_update(&index_scheduler, &index_uid, settings, dry_run)
+                } else {
+                    Err(AuthenticationError::InvalidToken.into())
+                };
+                let (task, error) = match result {
+                    Ok(task) => (Some(task), None),
+                    Err(error) => (None, Some(error)),
+                };
+                IndexSettingsUpdateResult { index_uid: index_uid.into_inner(), task, error }
+            })
+            .collect::<Vec<_>>()
+    })
+    .await?;
+
+    let results = SettingsBatchResults { results };
+    debug!(returns = ?results, "Update settings batch");
+    Ok(HttpResponse::Accepted().json(results))
+}
+
+fn register_settings_update(
+    index_scheduler: &IndexScheduler,
+    index_uid: &IndexUid,
+    settings: Settings<Unchecked>,
+    dry_run: bool,
+) -> Result<SummarizedTaskView, ResponseError> {
+    // contrary to the single-index route, a batch update never creates the indexes.
+    if !index_scheduler.index_exists(index_uid)? {
+        return Err(index_scheduler::Error::IndexNotFound(index_uid.to_string()).into());
+    }
+
+    let new_settings = validate_settings(settings, index_scheduler)?;
+    let task = KindWithContent::SettingsUpdate {
+        index_uid: index_uid.to_string(),
+        new_settings: Box::new(new_settings),
+        is_deletion: false,
+        allow_index_creation: false,
+    };
+    Ok(index_scheduler.register(task, None, dry_run)?.into())
+}
@@ -0,0 +1,89 @@
+use actix_web::web::Data;
+use actix_web::{web, HttpResponse};
+use deserr::actix_web::AwebQueryParameter;
+use deserr::Deserr;
+use index_scheduler::IndexScheduler;
+use meilisearch_types::batch_view::BatchView;
+use meilisearch_types::batches::BatchId;
+use meilisearch_types::deserr::query_params::Param;
+use meilisearch_types::deserr::DeserrQueryParamError;
+use meilisearch_types::error::deserr_codes::*;
+use meilisearch_types::error::ResponseError;
+use serde::Serialize;
+
+use crate::extractors::authentication::policies::*;
+use crate::extractors::authentication::GuardedData;
+use crate::extractors::sequential_extractor::SeqHandler;
+
+const DEFAULT_LIMIT: u32 = 20;
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(web::resource("").route(web::get().to(SeqHandler(get_batches))))
+        .service(web::resource("/{batch_id}").route(web::get().to(SeqHandler(get_batch))));
+}
+
+#[derive(Debug, Deserr)]
+#[deserr(error = DeserrQueryParamError, rename_all = camelCase, deny_unknown_fields)]
+pub struct BatchesFilterQuery {
+    #[deserr(default = Param(DEFAULT_LIMIT), error = DeserrQueryParamError<InvalidBatchLimit>)]
+    pub limit: Param<u32>,
+    #[deserr(default, error = DeserrQueryParamError<InvalidBatchFrom>)]
+    pub from: Option<Param<BatchId>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AllBatches {
+    results: Vec<BatchView>,
+    total: u64,
+    limit: u32,
+    from: Option<u32>,
+    next: Option<u32>,
+}
+
+async fn get_batches(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::TASKS_GET }>, Data<IndexScheduler>>,
+    params: AwebQueryParameter<BatchesFilterQuery, DeserrQueryParamError>,
+) -> Result<HttpResponse, ResponseError> {
+    let params = params.into_inner();
+    // We +1 just to know if there is more after this "page" or not.
+    let limit = params.limit.0.saturating_add(1);
+
+    let filters = index_scheduler.filters();
+    let (batches, total) = index_scheduler.get_batches_from_authorized_indexes(
+        params.from.as_deref().copied(),
+        limit,
+        filters,
+    )?;
+    let mut results: Vec<_> = batches.iter().map(BatchView::from_batch).collect();
+
+    // If we were able to fetch the number +1 batches we asked
+    // it means that there is more to come.
+    let next = if results.len() == limit as usize { results.pop().map(|b| b.uid) } else { None };
+
+    let from = results.first().map(|b| b.uid);
+    let batches = AllBatches { results, limit: limit.saturating_sub(1), total, from, next };
+
+    Ok(HttpResponse::Ok().json(batches))
+}
+
+async fn get_batch(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::TASKS_GET }>, Data<IndexScheduler>>,
+    batch_uid: web::Path<String>,
+) -> Result<HttpResponse, ResponseError> {
+    let batch_uid_string = batch_uid.into_inner();
+
+    let batch_uid: BatchId = match batch_uid_string.parse() {
+        Ok(id) => id,
+        Err(_e) => {
+            return Err(
+                index_scheduler::Error::InvalidBatchUids { batch_uid: batch_uid_string }.into()
+            )
+        }
+    };
+
+    let filters = index_scheduler.filters();
+    match index_scheduler.get_batch_from_authorized_indexes(batch_uid, filters)? {
+        Some(batch) => Ok(HttpResponse::Ok().json(BatchView::from_batch(&batch))),
+        None => Err(index_scheduler::Error::BatchNotFound(batch_uid).into()),
+    }
+}
@@ -1,35 +1,88 @@
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
 use actix_web::web::Data;
-use actix_web::{web, HttpRequest, HttpResponse};
+use actix_web::{web, HttpMessage, HttpRequest, HttpResponse};
+use deserr::actix_web::{AwebJson, AwebQueryParameter};
+use deserr::Deserr;
+use futures_util::StreamExt;
 use index_scheduler::IndexScheduler;
 use meilisearch_auth::AuthController;
-use meilisearch_types::error::ResponseError;
-use meilisearch_types::tasks::KindWithContent;
+use meilisearch_types::deserr::query_params::Param;
+use meilisearch_types::deserr::{DeserrJsonError, DeserrQueryParamError};
+use meilisearch_types::error::deserr_codes::*;
+use meilisearch_types::error::{Code, ResponseError};
+use meilisearch_types::index_uid::IndexUid;
+use meilisearch_types::tasks::{KindWithContent, TaskId};
 use serde_json::json;
+use tokio::io::{AsyncWriteExt, BufWriter};
 use tracing::debug;
+use url::Url;
+use uuid::Uuid;
 
 use crate::analytics::Analytics;
+use crate::error::MeilisearchHttpError;
+use crate::error::PayloadError::{self, ReceivePayload};
 use crate::extractors::authentication::policies::*;
-use crate::extractors::authentication::GuardedData;
+use crate::extractors::authentication::{AuthenticationError, GuardedData};
+use crate::extractors::payload::Payload;
 use crate::extractors::sequential_extractor::SeqHandler;
 use crate::routes::{get_task_id, is_dry_run, SummarizedTaskView};
 use crate::Opt;
 
+/// The content types of the dumps and snapshots sent as the body of the import routes.
+const ARCHIVE_CONTENT_TYPES: [&str; 3] =
+    ["application/gzip", "application/x-gzip", "application/octet-stream"];
+
 pub fn configure(cfg: &mut web::ServiceConfig) {
-    cfg.service(web::resource("").route(web::post().to(SeqHandler(create_dump))));
+    cfg.service(web::resource("").route(web::post().to(SeqHandler(create_dump))))
+        .service(web::resource("/import").route(web::post().to(SeqHandler(import_dump_archive))))
+        .service(
+            web::resource("/{dump_uid}/import").route(web::post().to(SeqHandler(import_dump))),
+        );
+}
+
+#[derive(Debug, Deserr)]
+#[deserr(error = DeserrQueryParamError, rename_all = camelCase, deny_unknown_fields)]
+pub struct CreateDumpQuery {
+    #[deserr(default, error = DeserrQueryParamError<InvalidDumpSettingsOnly>)]
+    settings_only: Param<bool>,
+    #[deserr(default, error = DeserrQueryParamError<InvalidDumpSince>)]
+    since: Option<Param<TaskId>>,
 }
 
 pub async fn create_dump(
     index_scheduler: GuardedData<ActionPolicy<{ actions::DUMPS_CREATE }>, Data<IndexScheduler>>,
     auth_controller: GuardedData<ActionPolicy<{ actions::DUMPS_CREATE }>, Data<AuthController>>,
+    params: AwebQueryParameter<CreateDumpQuery, DeserrQueryParamError>,
     req: HttpRequest,
     opt: web::Data<Opt>,
     analytics: web::Data<dyn Analytics>,
 ) -> Result<HttpResponse, ResponseError> {
-    analytics.publish("Dump Created".to_string(), json!({}), Some(&req));
+    let CreateDumpQuery { settings_only, since } = params.into_inner();
+    let settings_only = settings_only.0;
+    let since = since.map(|since| since.0);
+
+    // An incremental dump replaces the indexes it's applied on, documents included.
+    if settings_only && since.is_some() {
+        return Err(ResponseError::from_msg(
+            "The `settingsOnly` and `since` parameters can't be used together.".to_string(),
+            Code::InvalidDumpSince,
+        ));
+    }
+
+    analytics.publish(
+        "Dump Created".to_string(),
+        json!({ "settings_only": settings_only, "incremental": since.is_some() }),
+        Some(&req),
+    );
 
     let task = KindWithContent::DumpCreation {
         keys: auth_controller.list_keys()?,
         instance_uid: analytics.instance_uid().cloned(),
+        settings_only,
+        since,
     };
     let uid = get_task_id(&req, &opt)?;
     let dry_run = is_dry_run(&req, &opt)?;
@@ -41,3 +94,501 @@ pub async fn create_dump(
     debug!(returns = ?task, "Create dump");
     Ok(HttpResponse::Accepted().json(task))
 }
+
+#[derive(Debug, Deserr)]
+#[deserr(error = DeserrJsonError, rename_all = camelCase, deny_unknown_fields)]
+pub struct ImportDumpPayload {
+    #[deserr(default, error = DeserrJsonError<InvalidDumpImportNamespace>)]
+    namespace: Option<IndexUid>,
+}
+
+/// Imports the indexes of a dump of the dumps directory without interrupting the existing
+/// indexes. Their uids are computed, and checked against the existing indexes, right away.
+pub async fn import_dump(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::DUMPS_CREATE }>, Data<IndexScheduler>>,
+    indexes_creator: IndexesCreator,
+    documents_adder: DocumentsAdder,
+    dump_uid: web::Path<String>,
+    params: AwebJson<ImportDumpPayload, DeserrJsonError>,
+    req: HttpRequest,
+    opt: web::Data<Opt>,
+    analytics: web::Data<dyn Analytics>,
+) -> Result<HttpResponse, ResponseError> {
+    let dump_uid = dump_uid.into_inner();
+    let ImportDumpPayload { namespace } = params.into_inner();
+    let namespace = namespace.map(IndexUid::into_inner);
+
+    analytics.publish(
+        "Dump Imported".to_string(),
+        json!({ "namespace": namespace.is_some() }),
+        Some(&req),
+    );
+
+    // The uid is part of the path to the dump, it must not point outside of the dumps directory.
+    if !dump_uid.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+        return Err(MeilisearchHttpError::DumpNotFound(dump_uid).into());
+    }
+    let dump_path = opt.dump_dir.join(format!("{dump_uid}.dump"));
+    if !dump_path.is_file() {
+        return Err(MeilisearchHttpError::DumpNotFound(dump_uid).into());
+    }
+
+    let (index_uids, incremental) = {
+        let namespace = namespace.clone();
+        tokio::task::spawn_blocking(move || read_dump_indexes(dump_path, namespace.as_deref()))
+            .await?
+            .map_err(index_scheduler::Error::from)?
+    };
+    check_indexes_write(&index_uids, &indexes_creator, &documents_adder)?;
+
+    let task = register_dump_import(
+        index_scheduler,
+        dump_uid,
+        namespace,
+        index_uids,
+        incremental,
+        &req,
+        &opt,
+    )
+    .await?;
+
+    debug!(returns = ?task, "Import dump");
+    Ok(HttpResponse::Accepted().json(task))
+}
+
+#[derive(Debug, Deserr)]
+#[deserr(error = DeserrQueryParamError, rename_all = camelCase, deny_unknown_fields)]
+pub struct ImportDumpArchiveQuery {
+    #[deserr(default, error = DeserrQueryParamError<InvalidDumpImportNamespace>)]
+    pub namespace: Option<IndexUid>,
+}
+
+#[derive(Debug, Deserr)]
+#[deserr(error = DeserrJsonError, rename_all = camelCase, deny_unknown_fields)]
+pub struct ImportDumpUrlPayload {
+    #[deserr(error = DeserrJsonError<InvalidDumpImportUrl>, missing_field_error = DeserrJsonError::missing_dump_import_url)]
+    url: String,
+}
+
+/// Imports the indexes of a dump sent in the body, or downloaded from the `url` of a JSON body.
+///
+/// The archive is streamed to the dumps directory under a new dump uid, then its version is
+/// checked and it is imported like the dumps of the dumps directory. It is kept there once
+/// imported, and removed when it can't be.
+pub async fn import_dump_archive(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::DUMPS_CREATE }>, Data<IndexScheduler>>,
+    indexes_creator: IndexesCreator,
+    documents_adder: DocumentsAdder,
+    params: AwebQueryParameter<ImportDumpArchiveQuery, DeserrQueryParamError>,
+    mut body: Payload,
+    req: HttpRequest,
+    opt: web::Data<Opt>,
+    analytics: web::Data<dyn Analytics>,
+) -> Result<HttpResponse, ResponseError> {
+    let namespace = params.into_inner().namespace.map(IndexUid::into_inner);
+    let upload = archive_upload(&req, true)?;
+    let from_url = matches!(upload, ArchiveUpload::Url);
+
+    analytics.publish(
+        "Dump Imported".to_string(),
+        json!({
+            "namespace": namespace.is_some(),
+            "from_url": from_url,
+            "multipart": matches!(upload, ArchiveUpload::Multipart { .. }),
+        }),
+        Some(&req),
+    );
+
+    // The URL is read before anything is written in the dumps directory.
+    let url = if from_url {
+        Some(read_dump_url(&mut body, &opt.experimental_dump_import_allowed_hosts).await?)
+    } else {
+        None
+    };
+
+    let dump_uid = format!("import-{}", Uuid::new_v4());
+    let dump_path = opt.dump_dir.join(format!("{dump_uid}.dump"));
+    let max_size = opt.http_payload_size_limit.as_u64() as usize;
+    let source = match (&upload, &url) {
+        (_, Some(url)) => ArchiveSource::Url(url),
+        (ArchiveUpload::Multipart { boundary }, None) => ArchiveSource::Multipart(boundary),
+        (_, None) => ArchiveSource::Body,
+    };
+    receive_archive(&mut body, source, &dump_path, max_size).await?;
+
+    let read = {
+        let dump_path = dump_path.clone();
+        let namespace = namespace.clone();
+        tokio::task::spawn_blocking(move || read_dump_indexes(dump_path, namespace.as_deref()))
+            .await?
+    };
+    let task = match read {
+        Ok((index_uids, incremental)) => {
+            match check_indexes_write(&index_uids, &indexes_creator, &documents_adder) {
+                Ok(()) => {
+                    register_dump_import(
+                        index_scheduler,
+                        dump_uid,
+                        namespace,
+                        index_uids,
+                        incremental,
+                        &req,
+                        &opt,
+                    )
+                    .await
+                }
+                Err(e) => Err(e),
+            }
+        }
+        Err(e) => Err(ResponseError::from_msg(
+            format!("The dump archive is invalid: {e}."),
+            Code::InvalidDumpArchive,
+        )),
+    };
+    let task = match task {
+        Ok(task) => task,
+        Err(e) => {
+            let _ = tokio::fs::remove_file(&dump_path).await;
+            return Err(e);
+        }
+    };
+
+    debug!(returns = ?task, "Import dump archive");
+    Ok(HttpResponse::Accepted().json(task))
+}
+
+/// A key allowed to create the indexes imported from a dump or a snapshot, the error when it isn't.
+pub(super) type IndexesCreator = Result<
+    GuardedData<ActionPolicy<{ actions::INDEXES_CREATE }>, Data<AuthController>>,
+    ResponseError,
+>;
+/// A key allowed to add the documents of the indexes imported from a dump or a snapshot, the
+/// error when it isn't.
+pub(super) type DocumentsAdder = Result<
+    GuardedData<ActionPolicy<{ actions::DOCUMENTS_ADD }>, Data<AuthController>>,
+    ResponseError,
+>;
+
+/// Checks the key is allowed to create each of the imported indexes and to add their documents,
+/// which the action of the import routes alone doesn't allow.
+pub(super) fn check_indexes_write(
+    index_uids: &[String],
+    indexes_creator: &IndexesCreator,
+    documents_adder: &DocumentsAdder,
+) -> Result<(), ResponseError> {
+    let (Ok(indexes_creator), Ok(documents_adder)) = (indexes_creator, documents_adder) else {
+        return Err(AuthenticationError::InvalidToken.into());
+    };
+    for index_uid in index_uids {
+        if !indexes_creator.filters().is_index_authorized(index_uid)
+            || !documents_adder.filters().is_index_authorized(index_uid)
+        {
+            return Err(AuthenticationError::InvalidToken.into());
+        }
+    }
+    Ok(())
+}
+
+/// How the archive of a dump or a snapshot is sent to an import route.
+pub(super) enum ArchiveUpload {
+    /// The archive is the body.
+    Raw,
+    /// The archive is the first file of a `multipart/form-data` body.
+    Multipart { boundary: String },
+    /// The body is a JSON object holding the URL the archive is downloaded from.
+    Url,
+}
+
+/// Reads how the archive is sent from the content type of the request, a JSON body holding
+/// a URL is only accepted when `accept_url` is true.
+pub(super) fn archive_upload(
+    req: &HttpRequest,
+    accept_url: bool,
+) -> Result<ArchiveUpload, ResponseError> {
+    let accepted = || -> Vec<String> {
+        accept_url
+            .then_some("application/json")
+            .into_iter()
+            .chain(ARCHIVE_CONTENT_TYPES)
+            .chain(["multipart/form-data"])
+            .map(String::from)
+            .collect()
+    };
+    let mime_type = match req.mime_type() {
+        Ok(Some(mime_type)) => mime_type,
+        Ok(None) => return Err(MeilisearchHttpError::MissingContentType(accepted()).into()),
+        Err(_) => {
+            let content_type = req.content_type().to_string();
+            return Err(MeilisearchHttpError::InvalidContentType(content_type, accepted()).into());
+        }
+    };
+
+    match mime_type.essence_str() {
+        "application/json" if accept_url => Ok(ArchiveUpload::Url),
+        "multipart/form-data" => match mime_type.get_param(mime::BOUNDARY) {
+            Some(boundary) => Ok(ArchiveUpload::Multipart { boundary: boundary.to_string() }),
+            None => Err(MeilisearchHttpError::Payload(PayloadError::MalformedMultipart(
+                "the content type has no boundary".to_string(),
+            ))
+            .into()),
+        },
+        essence if ARCHIVE_CONTENT_TYPES.contains(&essence) => Ok(ArchiveUpload::Raw),
+        essence => {
+            Err(MeilisearchHttpError::InvalidContentType(essence.to_string(), accepted()).into())
+        }
+    }
+}
+
+/// Where the archive streamed by [`receive_archive`] is read from.
+pub(super) enum ArchiveSource<'a> {
+    /// The body is the archive.
+    Body,
+    /// The body is a `multipart/form-data` with this boundary.
+    Multipart(&'a str),
+    /// The archive is downloaded from this URL.
+    Url(&'a str),
+}
+
+/// Streams the archive to `path`, through a temporary file only renamed once the archive is
+/// entirely written. A download can't be larger than `max_size`.
+pub(super) async fn receive_archive(
+    body: &mut Payload,
+    source: ArchiveSource<'_>,
+    path: &Path,
+    max_size: usize,
+) -> Result<(), ResponseError> {
+    let receive_error =
+        |e: std::io::Error| MeilisearchHttpError::Payload(ReceivePayload(Box::new(e)));
+    let file_name = path.file_name().map(|name| name.to_string_lossy()).unwrap_or_default();
+    let temp_path = path.with_file_name(format!(".{file_name}.tmp"));
+    if let Some(dir) = path.parent() {
+        tokio::fs::create_dir_all(dir).await.map_err(receive_error)?;
+    }
+    let mut file =
+        BufWriter::new(tokio::fs::File::create(&temp_path).await.map_err(receive_error)?);
+
+    let written = match source {
+        ArchiveSource::Body => write_payload(body, &mut file).await,
+        ArchiveSource::Multipart(boundary) => write_multipart(body, boundary, &mut file).await,
+        ArchiveSource::Url(url) => download_dump(url, &mut file, max_size).await,
+    };
+    let written = match written {
+        Ok(()) => file.flush().await.map_err(|e| receive_error(e).into()),
+        Err(e) => Err(e),
+    };
+    if let Err(e) = written {
+        let _ = tokio::fs::remove_file(&temp_path).await;
+        return Err(e);
+    }
+    drop(file);
+    tokio::fs::rename(&temp_path, path).await.map_err(receive_error)?;
+    Ok(())
+}
+
+/// Reads the URL of the JSON body and checks its host is allowed.
+async fn read_dump_url(
+    body: &mut Payload,
+    allowed_hosts: &[String],
+) -> Result<String, ResponseError> {
+    let mut payload = Vec::new();
+    while let Some(bytes) = body.next().await {
+        payload.extend_from_slice(&bytes?);
+    }
+    let value: serde_json::Value =
+        serde_json::from_slice(&payload).map_err(PayloadError::MalformedPayload)?;
+    let ImportDumpUrlPayload { url } = deserr::deserialize::<_, _, DeserrJsonError>(value)?;
+    check_dump_import_url(&url, allowed_hosts)?;
+    Ok(url)
+}
+
+/// Streams the archive sent in the body to the file.
+async fn write_payload(
+    body: &mut Payload,
+    file: &mut BufWriter<tokio::fs::File>,
+) -> Result<(), ResponseError> {
+    while let Some(bytes) = body.next().await {
+        file.write_all(&bytes?)
+            .await
+            .map_err(|e| MeilisearchHttpError::Payload(ReceivePayload(Box::new(e))))?;
+    }
+    Ok(())
+}
+
+/// Streams the first file of the `multipart/form-data` body to the file, the other parts of the
+/// form are ignored.
+async fn write_multipart(
+    body: &mut Payload,
+    boundary: &str,
+    file: &mut BufWriter<tokio::fs::File>,
+) -> Result<(), ResponseError> {
+    let malformed = |reason: &str| {
+        MeilisearchHttpError::Payload(PayloadError::MalformedMultipart(reason.to_string()))
+    };
+    let find = |haystack: &[u8], needle: &[u8]| {
+        haystack.windows(needle.len()).position(|window| window == needle)
+    };
+    // The first delimiter of the body isn't preceded by a line break.
+    let delimiter = format!("\r\n--{boundary}").into_bytes();
+    let mut buffer = b"\r\n".to_vec();
+    let mut in_file = false;
+
+    loop {
+        if in_file {
+            if let Some(end) = find(&buffer, &delimiter) {
+                file.write_all(&buffer[..end])
+                    .await
+                    .map_err(|e| MeilisearchHttpError::Payload(ReceivePayload(Box::new(e))))?;
+                return Ok(());
+            }
+            // The end of the buffer is kept as it may be the start of the delimiter.
+            let written = buffer.len().saturating_sub(delimiter.len() - 1);
+            file.write_all(&buffer[..written])
+                .await
+                .map_err(|e| MeilisearchHttpError::Payload(ReceivePayload(Box::new(e))))?;
+            buffer.drain(..written);
+        } else if let Some(start) = find(&buffer, &delimiter) {
+            match find(&buffer[start..], b"\r\n\r\n") {
+                Some(end) => {
+                    let headers =
+                        String::from_utf8_lossy(&buffer[start + delimiter.len()..start + end]);
+                    in_file = headers.lines().any(|line| {
+                        line.to_ascii_lowercase().starts_with("content-disposition:")
+                            && line.contains("filename=")
+                    });
+                    buffer.drain(..start + end + 4);
+                    continue;
+                }
+                // The headers of the part are not entirely received yet.
+                None => {
+                    buffer.drain(..start);
+                }
+            }
+        } else {
+            // The content of the parts that aren't files is skipped.
+            let skipped = buffer.len().saturating_sub(delimiter.len() - 1);
+            buffer.drain(..skipped);
+        }
+
+        match body.next().await {
+            Some(bytes) => buffer.extend_from_slice(&bytes?),
+            None if in_file => return Err(malformed("the file is not terminated").into()),
+            None => return Err(malformed("no file was sent in the form").into()),
+        }
+    }
+}
+
+/// Opens the dump, checking its version, and returns the uids its indexes are imported under
+/// along with whether it is an incremental dump.
+fn read_dump_indexes(
+    dump_path: PathBuf,
+    namespace: Option<&str>,
+) -> Result<(Vec<String>, bool), dump::Error> {
+    let dump = dump::DumpReader::open(File::open(dump_path)?)?;
+    let incremental = dump.since().is_some();
+    let mut index_uids = Vec::new();
+    for index in dump.indexes()? {
+        let index = index?;
+        index_uids.push(KindWithContent::dump_import_index_uid(namespace, &index.metadata().uid));
+    }
+    // the indexes deleted by an incremental dump are modified by the import too
+    for index_uid in dump.deleted_indexes() {
+        index_uids.push(KindWithContent::dump_import_index_uid(namespace, index_uid));
+    }
+    Ok((index_uids, incremental))
+}
+
+/// Checks the uids of the imported indexes against the existing indexes and registers the task.
+pub(super) async fn register_dump_import<P: Send + 'static>(
+    index_scheduler: GuardedData<P, Data<IndexScheduler>>,
+    dump_uid: String,
+    namespace: Option<String>,
+    index_uids: Vec<String>,
+    incremental: bool,
+    req: &HttpRequest,
+    opt: &Opt,
+) -> Result<SummarizedTaskView, ResponseError> {
+    let filters = index_scheduler.filters();
+    for index_uid in &index_uids {
+        let index_uid = IndexUid::try_from(index_uid.clone())?;
+        if !filters.is_index_authorized(&index_uid) {
+            return Err(AuthenticationError::InvalidToken.into());
+        }
+        // an incremental dump replaces the indexes it contains
+        if !incremental && index_scheduler.index_exists(&index_uid)? {
+            return Err(index_scheduler::Error::IndexAlreadyExists(index_uid.into_inner()).into());
+        }
+    }
+
+    let task = KindWithContent::DumpImport { dump_uid, namespace, index_uids };
+    let uid = get_task_id(req, opt)?;
+    let dry_run = is_dry_run(req, opt)?;
+    let task: SummarizedTaskView =
+        tokio::task::spawn_blocking(move || index_scheduler.register(task, uid, dry_run))
+            .await??
+            .into();
+    Ok(task)
+}
+
+/// Only accepts the `http` and `https` URLs whose host is one of the allowed hosts.
+fn check_dump_import_url(url: &str, allowed_hosts: &[String]) -> Result<(), ResponseError> {
+    let invalid = |message: String| ResponseError::from_msg(message, Code::InvalidDumpImportUrl);
+
+    let parsed =
+        Url::parse(url).map_err(|e| invalid(format!("`{url}` is not a valid URL: {e}.")))?;
+    if !matches!(parsed.scheme(), "http" | "https") {
+        return Err(invalid(format!("The URL `{url}` must use the `http` or `https` scheme.")));
+    }
+    match parsed.host_str() {
+        Some(host) if allowed_hosts.iter().any(|allowed| allowed.eq_ignore_ascii_case(host)) => {
+            Ok(())
+        }
+        _ => Err(invalid(format!(
+            "The host of the URL `{url}` is not allowed. The hosts dumps can be imported from are set with the `--experimental-dump-import-allowed-hosts` option."
+        ))),
+    }
+}
+
+/// Streams the dump served at the URL to the file.
+async fn download_dump(
+    url: &str,
+    file: &mut BufWriter<tokio::fs::File>,
+    max_size: usize,
+) -> Result<(), ResponseError> {
+    let failed = |reason: String| {
+        ResponseError::from_msg(
+            format!("The dump could not be downloaded from `{url}`: {reason}."),
+            Code::DumpUrlImportFailed,
+        )
+    };
+
+    // The redirections are not followed as they could lead to a host that is not allowed.
+    let client = reqwest::Client::builder()
+        .redirect(reqwest::redirect::Policy::none())
+        .connect_timeout(Duration::from_secs(30))
+        .build()
+        .map_err(|e| failed(e.to_string()))?;
+    let mut response = match client.get(url).send().await {
+        Ok(response) if response.status().is_success() => response,
+        Ok(response) => {
+            return Err(failed(format!(
+                "the server responded with the status `{}`",
+                response.status().as_u16()
+            )))
+        }
+        Err(e) => return Err(failed(format!("the server is unreachable: {e}"))),
+    };
+
+    let mut size = 0;
+    while let Some(chunk) =
+        response.chunk().await.map_err(|e| failed(format!("the download was interrupted: {e}")))?
+    {
+        size += chunk.len();
+        if size > max_size {
+            return Err(failed(format!("the file is larger than the limit of {max_size} bytes")));
+        }
+        file.write_all(&chunk)
+            .await
+            .map_err(|e| MeilisearchHttpError::Payload(ReceivePayload(Box::new(e))))?;
+    }
+    Ok(())
+}
@@ -0,0 +1,170 @@
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use actix_web::web::{self, Data};
+use actix_web::HttpResponse;
+use index_scheduler::IndexScheduler;
+use meilisearch_auth::AuthController;
+use meilisearch_types::keys::actions;
+use meilisearch_types::tasks::Kind;
+use serde::Serialize;
+use sysinfo::Disks;
+use tracing::{debug, error};
+
+use crate::extractors::authentication::policies::*;
+use crate::extractors::authentication::GuardedData;
+use crate::search_queue::SearchQueue;
+use crate::Opt;
+
+/// The percentage of the task database above which the task queue is reported as saturated,
+/// the new tasks are refused above 50%.
+const TASK_QUEUE_SATURATION_PERCENTAGE: u64 = 40;
+/// The percentage of free space of the disk of the database below which it is reported as low.
+const LOW_DISK_SPACE_PERCENTAGE: u64 = 5;
+/// How long the free space of the disk is cached, listing the disks is too slow to be done on
+/// every call of a route used as a liveness probe.
+const DISK_SPACE_CACHE_TTL: Duration = Duration::from_secs(30);
+
+/// When the free space of the disk was last checked, and whether it was low.
+static LOW_DISK_SPACE: Mutex<Option<(Instant, bool)>> = Mutex::new(None);
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(web::resource("").route(web::get().to(get_health)));
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+enum HealthStatus {
+    /// Meilisearch works as expected.
+    Available,
+    /// Meilisearch answers the requests, but some of them may fail or be slow.
+    Degraded,
+    /// One of the databases can't be read, Meilisearch can't answer the requests.
+    Unavailable,
+}
+
+/// Why Meilisearch reports itself as degraded.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+enum HealthIssue {
+    /// The write operations are refused until the maintenance mode is disabled.
+    MaintenanceMode,
+    /// The task queue is close to being full and refusing the new tasks.
+    TaskQueueSaturated,
+    /// The disk of the database is almost full.
+    LowDiskSpace,
+    /// The last snapshot creation failed.
+    SnapshotCreationFailed,
+    /// The last dump creation failed.
+    DumpCreationFailed,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct HealthView {
+    status: HealthStatus,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    reasons: Vec<HealthIssue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message: Option<String>,
+}
+
+/// Returns `available` or `degraded` with a `200 OK`, and `unavailable` with a
+/// `503 Service Unavailable` when Meilisearch can't answer the requests.
+///
+/// The route is public, so the reasons and the error message are only returned to the
+/// requests authorized to get the stats.
+///
+/// Meilisearch only starts listening once the database is opened, so a refused connection
+/// means it is still booting or importing a dump or a snapshot, while a `503` means it is broken.
+pub async fn get_health(
+    index_scheduler: Data<IndexScheduler>,
+    auth_controller: Data<AuthController>,
+    search_queue: Data<SearchQueue>,
+    opt: Data<Opt>,
+    authorized: Option<GuardedData<ActionPolicy<{ actions::STATS_GET }>, Data<IndexScheduler>>>,
+) -> HttpResponse {
+    let detailed = authorized.is_some();
+
+    let unavailable = search_queue
+        .health()
+        .map_err(|err| err.to_string())
+        .and_then(|()| index_scheduler.health().map_err(|err| err.to_string()))
+        .and_then(|()| auth_controller.health().map_err(|err| err.to_string()))
+        .and_then(|()| {
+            health_issues(&index_scheduler, &opt.db_path).map_err(|err| err.to_string())
+        });
+    let reasons = match unavailable {
+        Ok(reasons) => reasons,
+        Err(message) => {
+            error!("Meilisearch is unavailable: {message}");
+            return HttpResponse::ServiceUnavailable().json(HealthView {
+                status: HealthStatus::Unavailable,
+                reasons: Vec::new(),
+                message: detailed.then_some(message),
+            });
+        }
+    };
+
+    let status = if reasons.is_empty() { HealthStatus::Available } else { HealthStatus::Degraded };
+    let reasons = if detailed { reasons } else { Vec::new() };
+    let health = HealthView { status, reasons, message: None };
+    debug!(returns = ?health, "Get health");
+    HttpResponse::Ok().json(health)
+}
+
+fn health_issues(
+    index_scheduler: &IndexScheduler,
+    db_path: &Path,
+) -> index_scheduler::Result<Vec<HealthIssue>> {
+    let mut issues = Vec::new();
+    if index_scheduler.is_in_maintenance() {
+        issues.push(HealthIssue::MaintenanceMode);
+    }
+    if index_scheduler.task_queue_usage()? > TASK_QUEUE_SATURATION_PERCENTAGE {
+        issues.push(HealthIssue::TaskQueueSaturated);
+    }
+    if is_disk_space_low(db_path) {
+        issues.push(HealthIssue::LowDiskSpace);
+    }
+    if index_scheduler.last_task_failed(Kind::SnapshotCreation)? {
+        issues.push(HealthIssue::SnapshotCreationFailed);
+    }
+    if index_scheduler.last_task_failed(Kind::DumpCreation)? {
+        issues.push(HealthIssue::DumpCreationFailed);
+    }
+    Ok(issues)
+}
+
+/// Checks the free space of the disk of the database, at most once every [`DISK_SPACE_CACHE_TTL`].
+fn is_disk_space_low(db_path: &Path) -> bool {
+    let mut cache = LOW_DISK_SPACE.lock().unwrap();
+    match *cache {
+        Some((checked_at, low)) if checked_at.elapsed() < DISK_SPACE_CACHE_TTL => low,
+        _ => {
+            let low = check_disk_space(db_path);
+            *cache = Some((Instant::now(), low));
+            low
+        }
+    }
+}
+
+/// Checks the free space of the disk with the longest mount point containing the database.
+///
+/// The disk space is not reported as low when the disk can't be found.
+fn check_disk_space(db_path: &Path) -> bool {
+    let db_path = db_path.canonicalize().unwrap_or_else(|_| db_path.to_owned());
+    let disks = Disks::new_with_refreshed_list();
+    let disk = disks
+        .iter()
+        .filter(|disk| db_path.starts_with(disk.mount_point()))
+        .max_by_key(|disk| disk.mount_point().as_os_str().len());
+
+    match disk {
+        Some(disk) if disk.total_space() != 0 => {
+            disk.available_space() * 100 / disk.total_space() < LOW_DISK_SPACE_PERCENTAGE
+        }
+        _ => false,
+    }
+}
@@ -1,7 +1,9 @@
-use std::io::ErrorKind;
+use std::io::{ErrorKind, Write as _};
+use std::num::NonZeroUsize;
+use std::time::Duration;
 
-use actix_web::http::header::CONTENT_TYPE;
-use actix_web::web::Data;
+use actix_web::http::header::{ETag, EntityTag, CONTENT_TYPE};
+use actix_web::web::{Bytes, Data};
 use actix_web::{web, HttpMessage, HttpRequest, HttpResponse};
 use bstr::ByteSlice as _;
 use deserr::actix_web::{AwebJson, AwebQueryParameter};
@@ -10,25 +12,31 @@ use futures::StreamExt;
 use index_scheduler::{IndexScheduler, RoFeatures, TaskId};
 use meilisearch_types::deserr::query_params::Param;
 use meilisearch_types::deserr::{DeserrJsonError, DeserrQueryParamError};
-use meilisearch_types::document_formats::{read_csv, read_json, read_ndjson, PayloadType};
+use meilisearch_types::document_formats::{
+    read_csv, read_json, read_ndjson, read_ndjson_skipping_invalid, PayloadType,
+};
 use meilisearch_types::error::deserr_codes::*;
 use meilisearch_types::error::{Code, ResponseError};
 use meilisearch_types::heed::RoTxn;
 use meilisearch_types::index_uid::IndexUid;
+use meilisearch_types::milli::documents::DocumentsBatchBuilder;
 use meilisearch_types::milli::update::IndexDocumentsMethod;
 use meilisearch_types::milli::vector::parsed_vectors::ExplicitVectors;
 use meilisearch_types::milli::DocumentId;
 use meilisearch_types::star_or::OptionStarOrList;
-use meilisearch_types::tasks::KindWithContent;
+use meilisearch_types::tasks::{DocumentTransactionOperation, KindWithContent};
 use meilisearch_types::{milli, Document, Index};
 use mime::Mime;
 use once_cell::sync::Lazy;
-use serde::Deserialize;
+use roaring::RoaringBitmap;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use tempfile::tempfile;
 use tokio::fs::File;
 use tokio::io::{AsyncSeekExt, AsyncWriteExt, BufWriter};
+use tokio::sync::{mpsc, oneshot};
 use tracing::debug;
+use url::Url;
 
 use crate::analytics::{Analytics, DocumentDeletionKind, DocumentFetchKind};
 use crate::error::MeilisearchHttpError;
@@ -38,7 +46,8 @@ use crate::extractors::authentication::GuardedData;
 use crate::extractors::payload::Payload;
 use crate::extractors::sequential_extractor::SeqHandler;
 use crate::routes::{
-    get_task_id, is_dry_run, PaginationView, SummarizedTaskView, PAGINATION_DEFAULT_LIMIT,
+    get_task_id, is_dry_run, task_response, PaginationView, SummarizedTaskView,
+    WaitForCompletionQuery, PAGINATION_DEFAULT_LIMIT,
 };
 use crate::search::{parse_filter, RetrieveVectors};
 use crate::Opt;
@@ -47,6 +56,34 @@ static ACCEPTED_CONTENT_TYPE: Lazy<Vec<String>> = Lazy::new(|| {
     vec!["application/json".to_string(), "application/x-ndjson".to_string(), "text/csv".to_string()]
 });
 
+/// How often the task queue is checked while a document addition waits for it to drain.
+const BACKPRESSURE_POLL_INTERVAL: Duration = Duration::from_millis(50);
+/// How often the task queue is checked while the payload of a document addition is read.
+const BACKPRESSURE_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+/// How long a document addition waits for the task queue to drain before being refused.
+const BACKPRESSURE_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Waits until less than `max_pending_tasks` tasks are enqueued or processing.
+///
+/// The queue may never drain, when a batch is stuck or the maintenance mode is enabled, so the
+/// document addition is refused after [`BACKPRESSURE_TIMEOUT`] to free the connection.
+async fn wait_for_task_queue_to_drain(
+    index_scheduler: &IndexScheduler,
+    max_pending_tasks: NonZeroUsize,
+) -> Result<(), MeilisearchHttpError> {
+    let deadline = tokio::time::Instant::now() + BACKPRESSURE_TIMEOUT;
+    loop {
+        let pending_tasks = index_scheduler.pending_tasks()?;
+        if pending_tasks < max_pending_tasks.get() as u64 {
+            return Ok(());
+        }
+        if tokio::time::Instant::now() >= deadline {
+            return Err(MeilisearchHttpError::TooManyPendingTasks(pending_tasks));
+        }
+        tokio::time::sleep(BACKPRESSURE_POLL_INTERVAL).await;
+    }
+}
+
 /// Extracts the mime type from the content type and return
 /// a meilisearch error if anything bad happen.
 fn extract_mime_type(req: &HttpRequest) -> Result<Option<Mime>, MeilisearchHttpError> {
@@ -81,13 +118,21 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
     .service(
         web::resource("/delete-batch").route(web::post().to(SeqHandler(delete_documents_batch))),
     )
+    .service(web::resource("/batch").route(web::post().to(SeqHandler(documents_transaction))))
+    .service(
+        web::resource("/import-url").route(web::post().to(SeqHandler(import_documents_from_url))),
+    )
     .service(web::resource("/delete").route(web::post().to(SeqHandler(delete_documents_by_filter))))
     .service(web::resource("/edit").route(web::post().to(SeqHandler(edit_documents_by_function))))
     .service(web::resource("/fetch").route(web::post().to(SeqHandler(documents_by_query_post))))
+    .service(web::resource("/scan").route(web::post().to(SeqHandler(scan_documents))))
     .service(
         web::resource("/{document_id}")
             .route(web::get().to(SeqHandler(get_document)))
             .route(web::delete().to(SeqHandler(delete_document))),
+    )
+    .service(
+        web::resource("/{document_id}/obkv").route(web::get().to(SeqHandler(get_document_obkv))),
     );
 }
 
@@ -123,36 +168,92 @@ pub async fn get_document(
     );
 
     let index = index_scheduler.index(&index_uid)?;
-    let document =
+    let (document, etag) =
         retrieve_document(&index, &document_id, attributes_to_retrieve, retrieve_vectors)?;
     debug!(returns = ?document, "Get document");
+    // The etag can be sent back in the `_etag` field of the document to only update it if
+    // it wasn't modified in between.
+    Ok(HttpResponse::Ok().insert_header(ETag(EntityTag::new_strong(etag))).json(document))
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentObkvView {
+    internal_id: DocumentId,
+    fields: Vec<ObkvFieldView>,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ObkvFieldView {
+    field_id: milli::FieldId,
+    /// `None` when the field id is missing from the fields-ids-map.
+    name: Option<String>,
+    /// The stored bytes, that are expected to be serialized JSON.
+    value: String,
+}
+
+/// Returns the field ids and values of a document as they are stored, to debug the indexing.
+///
+/// Reserved to the master key and the keys allowed to perform all the actions.
+pub async fn get_document_obkv(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::DOCUMENTS_ALL }>, Data<IndexScheduler>>,
+    document_param: web::Path<DocumentParam>,
+) -> Result<HttpResponse, ResponseError> {
+    let DocumentParam { index_uid, document_id } = document_param.into_inner();
+    let index_uid = IndexUid::try_from(index_uid)?;
+
+    let index = index_scheduler.index(&index_uid)?;
+    let rtxn = index.read_txn()?;
+    let internal_id = index
+        .external_documents_ids()
+        .get(&rtxn, &document_id)?
+        .ok_or_else(|| MeilisearchHttpError::DocumentNotFound(document_id.clone()))?;
+    let obkv = index
+        .document_obkv(&rtxn, internal_id)?
+        .ok_or_else(|| MeilisearchHttpError::DocumentNotFound(document_id.clone()))?;
+
+    let fields_ids_map = index.fields_ids_map(&rtxn)?;
+    let fields = obkv
+        .into_iter()
+        .map(|(field_id, value)| ObkvFieldView {
+            field_id,
+            name: fields_ids_map.name(field_id).map(String::from),
+            value: String::from_utf8_lossy(&value).into_owned(),
+        })
+        .collect();
+
+    let document = DocumentObkvView { internal_id, fields };
+    debug!(returns = ?document, "Get document obkv");
     Ok(HttpResponse::Ok().json(document))
 }
 
 pub async fn delete_document(
     index_scheduler: GuardedData<ActionPolicy<{ actions::DOCUMENTS_DELETE }>, Data<IndexScheduler>>,
     path: web::Path<DocumentParam>,
+    params: AwebQueryParameter<WaitForCompletionQuery, DeserrQueryParamError>,
     req: HttpRequest,
     opt: web::Data<Opt>,
     analytics: web::Data<dyn Analytics>,
 ) -> Result<HttpResponse, ResponseError> {
     let DocumentParam { index_uid, document_id } = path.into_inner();
     let index_uid = IndexUid::try_from(index_uid)?;
+    let timeout = params.into_inner().timeout();
 
     analytics.delete_documents(DocumentDeletionKind::PerDocumentId, &req);
 
     let task = KindWithContent::DocumentDeletion {
         index_uid: index_uid.to_string(),
         documents_ids: vec![document_id],
+        report_outcomes: false,
     };
     let uid = get_task_id(&req, &opt)?;
     let dry_run = is_dry_run(&req, &opt)?;
+    let scheduler = index_scheduler.clone();
     let task: SummarizedTaskView =
-        tokio::task::spawn_blocking(move || index_scheduler.register(task, uid, dry_run))
-            .await??
-            .into();
+        tokio::task::spawn_blocking(move || scheduler.register(task, uid, dry_run)).await??.into();
     debug!("returns: {:?}", task);
-    Ok(HttpResponse::Accepted().json(task))
+    task_response(&index_scheduler, task, timeout, dry_run).await
 }
 
 #[derive(Debug, Deserr)]
@@ -168,6 +269,8 @@ pub struct BrowseQueryGet {
     retrieve_vectors: Param<bool>,
     #[deserr(default, error = DeserrQueryParamError<InvalidDocumentFilter>)]
     filter: Option<String>,
+    #[deserr(default, error = DeserrQueryParamError<InvalidDocumentFrom>)]
+    from: Option<String>,
 }
 
 #[derive(Debug, Deserr)]
@@ -183,6 +286,21 @@ pub struct BrowseQuery {
     retrieve_vectors: bool,
     #[deserr(default, error = DeserrJsonError<InvalidDocumentFilter>)]
     filter: Option<Value>,
+    /// The cursor from which the documents are returned, the `next` field of the previous page.
+    /// It is a document id, a string or an integer, and an empty string starts at the first page.
+    #[deserr(default, error = DeserrJsonError<InvalidDocumentFrom>)]
+    from: Option<Value>,
+}
+
+/// A page of documents returned by a cursor, which stays stable while documents are added or
+/// deleted, unlike an offset.
+#[derive(Debug, Serialize)]
+pub struct CursorPaginationView {
+    results: Vec<Document>,
+    total: u64,
+    limit: usize,
+    from: String,
+    next: Option<String>,
 }
 
 pub async fn documents_by_query_post(
@@ -217,7 +335,8 @@ pub async fn get_documents(
 ) -> Result<HttpResponse, ResponseError> {
     debug!(parameters = ?params, "Get documents GET");
 
-    let BrowseQueryGet { limit, offset, fields, retrieve_vectors, filter } = params.into_inner();
+    let BrowseQueryGet { limit, offset, fields, retrieve_vectors, filter, from } =
+        params.into_inner();
 
     let filter = match filter {
         Some(f) => match serde_json::from_str(&f) {
@@ -233,6 +352,7 @@ pub async fn get_documents(
         fields: fields.merge_star_and_none(),
         retrieve_vectors: retrieve_vectors.0,
         filter,
+        from: from.map(Value::String),
     };
 
     analytics.get_fetch_documents(
@@ -254,26 +374,189 @@ fn documents_by_query(
     query: BrowseQuery,
 ) -> Result<HttpResponse, ResponseError> {
     let index_uid = IndexUid::try_from(index_uid.into_inner())?;
-    let BrowseQuery { offset, limit, fields, retrieve_vectors, filter } = query;
+    let BrowseQuery { offset, limit, fields, retrieve_vectors, filter, from } = query;
+    let from = from.map(document_cursor).transpose()?;
 
     let features = index_scheduler.features();
     let retrieve_vectors = RetrieveVectors::new(retrieve_vectors, features)?;
 
     let index = index_scheduler.index(&index_uid)?;
-    let (total, documents) = retrieve_documents(
+    let (total, documents, next) = retrieve_documents(
         &index,
         offset,
         limit,
+        from.as_deref(),
         filter,
         fields,
         retrieve_vectors,
         index_scheduler.features(),
     )?;
 
-    let ret = PaginationView::new(offset, limit, total as usize, documents);
+    match from {
+        Some(from) => {
+            let ret = CursorPaginationView { results: documents, total, limit, from, next };
+            debug!(returns = ?ret, "Get documents");
+            Ok(HttpResponse::Ok().json(ret))
+        }
+        None => {
+            let ret = PaginationView::new(offset, limit, total as usize, documents);
+            debug!(returns = ?ret, "Get documents");
+            Ok(HttpResponse::Ok().json(ret))
+        }
+    }
+}
 
-    debug!(returns = ?ret, "Get documents");
-    Ok(HttpResponse::Ok().json(ret))
+/// Returns the external id a cursor starts from, an integer document id being accepted too.
+fn document_cursor(from: Value) -> Result<String, ResponseError> {
+    match from {
+        Value::String(from) => Ok(from),
+        Value::Number(number) if number.is_u64() || number.is_i64() => Ok(number.to_string()),
+        from => Err(ResponseError::from_msg(
+            format!(
+                "Invalid value type at `.from`: expected a string or an integer, but found `{from}`"
+            ),
+            Code::InvalidDocumentFrom,
+        )),
+    }
+}
+
+#[derive(Debug, Deserr)]
+#[deserr(error = DeserrJsonError, rename_all = camelCase, deny_unknown_fields)]
+pub struct ScanQuery {
+    #[deserr(default, error = DeserrJsonError<InvalidDocumentFields>)]
+    fields: Option<Vec<String>>,
+    #[deserr(default, error = DeserrJsonError<InvalidDocumentRetrieveVectors>)]
+    retrieve_vectors: bool,
+    #[deserr(default, error = DeserrJsonError<InvalidDocumentFilter>)]
+    filter: Option<Value>,
+}
+
+/// The number of chunks of documents that can be waiting to be sent to the client.
+const SCAN_CHANNEL_CAPACITY: usize = 16;
+/// The size after which a chunk of documents is sent to the client.
+const SCAN_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Streams all the documents matching the filter, without any pagination, as NDJSON.
+///
+/// The documents are returned in internal id order and are all read from the same
+/// read transaction, so the stream is consistent with the index as it was when it started.
+pub async fn scan_documents(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::DOCUMENTS_GET }>, Data<IndexScheduler>>,
+    index_uid: web::Path<String>,
+    body: AwebJson<ScanQuery, DeserrJsonError>,
+    req: HttpRequest,
+    analytics: web::Data<dyn Analytics>,
+) -> Result<HttpResponse, ResponseError> {
+    let ScanQuery { fields, retrieve_vectors, filter } = body.into_inner();
+    debug!(parameters = ?filter, "Scan documents");
+    let index_uid = IndexUid::try_from(index_uid.into_inner())?;
+
+    analytics.publish(
+        "Documents Scanned".to_string(),
+        serde_json::json!({ "with_filter": filter.is_some(), "retrieve_vectors": retrieve_vectors }),
+        Some(&req),
+    );
+
+    let features = index_scheduler.features();
+    let retrieve_vectors = RetrieveVectors::new(retrieve_vectors, features)?;
+    let index = index_scheduler.index(&index_uid)?;
+
+    let (ready_sender, ready_receiver) = oneshot::channel();
+    let (sender, receiver) = mpsc::channel(SCAN_CHANNEL_CAPACITY);
+    tokio::task::spawn_blocking(move || {
+        scan_documents_into(
+            &index,
+            filter,
+            fields,
+            retrieve_vectors,
+            features,
+            ready_sender,
+            sender,
+        )
+    });
+
+    // The errors happening before the first document is sent, like an invalid filter,
+    // are returned as a regular error response.
+    ready_receiver.await.map_err(|_| {
+        ResponseError::from_msg(
+            "The documents scan stopped unexpectedly".to_string(),
+            Code::Internal,
+        )
+    })??;
+
+    let stream = futures::stream::unfold(receiver, |mut receiver| async move {
+        receiver.recv().await.map(|chunk| (chunk, receiver))
+    });
+
+    Ok(HttpResponse::Ok().content_type("application/x-ndjson").streaming(stream))
+}
+
+/// Sends the matching documents in chunks of NDJSON through the sender. Stops as soon as
+/// the client goes away.
+fn scan_documents_into(
+    index: &Index,
+    filter: Option<Value>,
+    attributes_to_retrieve: Option<Vec<String>>,
+    retrieve_vectors: RetrieveVectors,
+    features: RoFeatures,
+    ready: oneshot::Sender<Result<(), ResponseError>>,
+    sender: mpsc::Sender<Result<Bytes, ResponseError>>,
+) {
+    // This read transaction is kept for the whole scan.
+    let rtxn = match index.read_txn() {
+        Ok(rtxn) => rtxn,
+        Err(e) => {
+            let _ = ready.send(Err(e.into()));
+            return;
+        }
+    };
+
+    let documents = filtered_documents_ids(index, &rtxn, filter.as_ref(), features)
+        .and_then(|candidates| some_documents(index, &rtxn, candidates, retrieve_vectors));
+    let documents = match documents {
+        Ok(documents) => documents,
+        Err(e) => {
+            let _ = ready.send(Err(e));
+            return;
+        }
+    };
+    if ready.send(Ok(())).is_err() {
+        return;
+    }
+
+    let mut chunk = Vec::new();
+    for document in documents {
+        let document = document.and_then(|document| {
+            let document = match &attributes_to_retrieve {
+                Some(attributes_to_retrieve) => permissive_json_pointer::select_values(
+                    &document,
+                    attributes_to_retrieve.iter().map(|s| s.as_ref()).chain(
+                        (retrieve_vectors == RetrieveVectors::Retrieve).then_some("_vectors"),
+                    ),
+                ),
+                None => document,
+            };
+            serde_json::to_writer(&mut chunk, &document).map_err(MeilisearchHttpError::from)?;
+            chunk.push(b'\n');
+            Ok(())
+        });
+
+        if let Err(e) = document {
+            let _ = sender.blocking_send(Err(e));
+            return;
+        }
+
+        if chunk.len() >= SCAN_CHUNK_SIZE
+            && sender.blocking_send(Ok(Bytes::from(std::mem::take(&mut chunk)))).is_err()
+        {
+            // the client is gone
+            return;
+        }
+    }
+
+    if !chunk.is_empty() {
+        let _ = sender.blocking_send(Ok(Bytes::from(chunk)));
+    }
 }
 
 #[derive(Deserialize, Debug, Deserr)]
@@ -283,6 +566,33 @@ pub struct UpdateDocumentsQuery {
     pub primary_key: Option<String>,
     #[deserr(default, try_from(char) = from_char_csv_delimiter -> DeserrQueryParamError<InvalidDocumentCsvDelimiter>, error = DeserrQueryParamError<InvalidDocumentCsvDelimiter>)]
     pub csv_delimiter: Option<u8>,
+    #[serde(skip)]
+    #[deserr(default, error = DeserrQueryParamError<InvalidDocumentOnError>)]
+    pub on_error: OnError,
+    #[serde(skip)]
+    #[deserr(default, error = DeserrQueryParamError<InvalidWaitForCompletion>)]
+    pub wait_for_completion: Param<bool>,
+    #[serde(skip)]
+    #[deserr(default, error = DeserrQueryParamError<InvalidTimeoutMs>)]
+    pub timeout_ms: Option<Param<u64>>,
+}
+
+/// What to do with the documents of a payload that cannot be parsed.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserr)]
+#[deserr(rename_all = camelCase)]
+pub enum OnError {
+    /// Reject the whole payload.
+    #[default]
+    Abort,
+    /// Index the valid documents and report the skipped ones in the response.
+    Skip,
+}
+
+impl UpdateDocumentsQuery {
+    fn timeout(&self) -> Option<Duration> {
+        let UpdateDocumentsQuery { wait_for_completion, timeout_ms, .. } = *self;
+        WaitForCompletionQuery { wait_for_completion, timeout_ms }.timeout()
+    }
 }
 
 fn from_char_csv_delimiter(
@@ -321,22 +631,26 @@ pub async fn replace_documents(
     let allow_index_creation = index_scheduler.filters().allow_index_creation(&index_uid);
     let uid = get_task_id(&req, &opt)?;
     let dry_run = is_dry_run(&req, &opt)?;
+    let timeout = params.timeout();
+    let scheduler = index_scheduler.clone();
     let task = document_addition(
         extract_mime_type(&req)?,
         index_scheduler,
         index_uid,
         params.primary_key,
         params.csv_delimiter,
+        params.on_error,
         body,
         IndexDocumentsMethod::ReplaceDocuments,
         uid,
         dry_run,
         allow_index_creation,
+        opt.experimental_document_addition_max_pending_tasks,
     )
     .await?;
     debug!(returns = ?task, "Replace documents");
 
-    Ok(HttpResponse::Accepted().json(task))
+    task_response(&scheduler, task, timeout, dry_run).await
 }
 
 pub async fn update_documents(
@@ -362,22 +676,26 @@ pub async fn update_documents(
     let allow_index_creation = index_scheduler.filters().allow_index_creation(&index_uid);
     let uid = get_task_id(&req, &opt)?;
     let dry_run = is_dry_run(&req, &opt)?;
+    let timeout = params.timeout();
+    let scheduler = index_scheduler.clone();
     let task = document_addition(
         extract_mime_type(&req)?,
         index_scheduler,
         index_uid,
         params.primary_key,
         params.csv_delimiter,
+        params.on_error,
         body,
         IndexDocumentsMethod::UpdateDocuments,
         uid,
         dry_run,
         allow_index_creation,
+        opt.experimental_document_addition_max_pending_tasks,
     )
     .await?;
     debug!(returns = ?task, "Update documents");
 
-    Ok(HttpResponse::Accepted().json(task))
+    task_response(&scheduler, task, timeout, dry_run).await
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -387,11 +705,13 @@ async fn document_addition(
     index_uid: IndexUid,
     primary_key: Option<String>,
     csv_delimiter: Option<u8>,
+    on_error: OnError,
     mut body: Payload,
     method: IndexDocumentsMethod,
     task_id: Option<TaskId>,
     dry_run: bool,
     allow_index_creation: bool,
+    max_pending_tasks: Option<NonZeroUsize>,
 ) -> Result<SummarizedTaskView, MeilisearchHttpError> {
     let format = match (
         mime_type.as_ref().map(|m| (m.type_().as_str(), m.subtype().as_str())),
@@ -423,6 +743,20 @@ async fn document_addition(
         }
     };
 
+    match (&format, on_error) {
+        (PayloadType::Ndjson, _) | (_, OnError::Abort) => (),
+        (PayloadType::Json, OnError::Skip) => {
+            return Err(MeilisearchHttpError::SkipOnErrorWithWrongContentType(String::from(
+                "application/json",
+            )))
+        }
+        (PayloadType::Csv { .. }, OnError::Skip) => {
+            return Err(MeilisearchHttpError::SkipOnErrorWithWrongContentType(String::from(
+                "text/csv",
+            )))
+        }
+    }
+
     let (uuid, mut update_file) = index_scheduler.create_update_file(dry_run)?;
 
     let temp_file = match tempfile() {
@@ -434,7 +768,20 @@ async fn document_addition(
     let mut buffer = BufWriter::new(async_file);
 
     let mut buffer_write_size: usize = 0;
-    while let Some(result) = body.next().await {
+    let mut last_backpressure_check: Option<tokio::time::Instant> = None;
+    loop {
+        // Stop reading the payload while the task queue is too deep, the client is slowed
+        // down by the network backpressure and the payload is never buffered in memory.
+        if let Some(max_pending_tasks) = max_pending_tasks {
+            if last_backpressure_check
+                .map_or(true, |at| at.elapsed() >= BACKPRESSURE_CHECK_INTERVAL)
+            {
+                wait_for_task_queue_to_drain(&index_scheduler, max_pending_tasks).await?;
+                last_backpressure_check = Some(tokio::time::Instant::now());
+            }
+        }
+
+        let Some(result) = body.next().await else { break };
         let byte = result?;
 
         if byte.is_empty() && buffer_write_size == 0 {
@@ -460,20 +807,27 @@ async fn document_addition(
     }
 
     let read_file = buffer.into_inner().into_std().await;
-    let documents_count = tokio::task::spawn_blocking(move || {
-        let documents_count = match format {
-            PayloadType::Json => read_json(&read_file, &mut update_file)?,
-            PayloadType::Csv { delimiter } => read_csv(&read_file, &mut update_file, delimiter)?,
-            PayloadType::Ndjson => read_ndjson(&read_file, &mut update_file)?,
+    let read_documents = tokio::task::spawn_blocking(move || {
+        let read_documents = match (format, on_error) {
+            (PayloadType::Json, _) => (read_json(&read_file, &mut update_file)?, Vec::new()),
+            (PayloadType::Csv { delimiter }, _) => {
+                (read_csv(&read_file, &mut update_file, delimiter)?, Vec::new())
+            }
+            (PayloadType::Ndjson, OnError::Abort) => {
+                (read_ndjson(&read_file, &mut update_file)?, Vec::new())
+            }
+            (PayloadType::Ndjson, OnError::Skip) => {
+                read_ndjson_skipping_invalid(&read_file, &mut update_file)?
+            }
         };
         // we NEED to persist the file here because we moved the `udpate_file` in another task.
         update_file.persist()?;
-        Ok(documents_count)
+        Ok(read_documents)
     })
     .await;
 
-    let documents_count = match documents_count {
-        Ok(Ok(documents_count)) => documents_count,
+    let (documents_count, skipped_documents) = match read_documents {
+        Ok(Ok(read_documents)) => read_documents,
         // in this case the file has not possibly be persisted.
         Ok(Err(e)) => return Err(e),
         Err(e) => {
@@ -505,8 +859,11 @@ async fn document_addition(
     };
 
     let scheduler = index_scheduler.clone();
-    let task = match tokio::task::spawn_blocking(move || scheduler.register(task, task_id, dry_run))
-        .await?
+    let skipped = skipped_documents.clone();
+    let task = match tokio::task::spawn_blocking(move || {
+        scheduler.register_document_addition(task, skipped, task_id, dry_run)
+    })
+    .await?
     {
         Ok(task) => task,
         Err(e) => {
@@ -515,12 +872,24 @@ async fn document_addition(
         }
     };
 
-    Ok(task.into())
+    Ok(SummarizedTaskView::from(task).with_skipped_documents(skipped_documents))
+}
+
+#[derive(Debug, Deserr)]
+#[deserr(error = DeserrQueryParamError, rename_all = camelCase, deny_unknown_fields)]
+pub struct DeleteDocumentsBatchQuery {
+    #[deserr(default, error = DeserrQueryParamError<InvalidDocumentReportOutcomes>)]
+    report_outcomes: Param<bool>,
+    #[deserr(default, error = DeserrQueryParamError<InvalidWaitForCompletion>)]
+    wait_for_completion: Param<bool>,
+    #[deserr(default, error = DeserrQueryParamError<InvalidTimeoutMs>)]
+    timeout_ms: Option<Param<u64>>,
 }
 
 pub async fn delete_documents_batch(
     index_scheduler: GuardedData<ActionPolicy<{ actions::DOCUMENTS_DELETE }>, Data<IndexScheduler>>,
     index_uid: web::Path<String>,
+    params: AwebQueryParameter<DeleteDocumentsBatchQuery, DeserrQueryParamError>,
     body: web::Json<Vec<Value>>,
     req: HttpRequest,
     opt: web::Data<Opt>,
@@ -528,6 +897,9 @@ pub async fn delete_documents_batch(
 ) -> Result<HttpResponse, ResponseError> {
     debug!(parameters = ?body, "Delete documents by batch");
     let index_uid = IndexUid::try_from(index_uid.into_inner())?;
+    let DeleteDocumentsBatchQuery { report_outcomes, wait_for_completion, timeout_ms } =
+        params.into_inner();
+    let timeout = WaitForCompletionQuery { wait_for_completion, timeout_ms }.timeout();
 
     analytics.delete_documents(DocumentDeletionKind::PerBatch, &req);
 
@@ -536,17 +908,215 @@ pub async fn delete_documents_batch(
         .map(|v| v.as_str().map(String::from).unwrap_or_else(|| v.to_string()))
         .collect();
 
-    let task =
-        KindWithContent::DocumentDeletion { index_uid: index_uid.to_string(), documents_ids: ids };
+    let task = KindWithContent::DocumentDeletion {
+        index_uid: index_uid.to_string(),
+        documents_ids: ids,
+        report_outcomes: report_outcomes.0,
+    };
     let uid = get_task_id(&req, &opt)?;
     let dry_run = is_dry_run(&req, &opt)?;
+    let scheduler = index_scheduler.clone();
     let task: SummarizedTaskView =
-        tokio::task::spawn_blocking(move || index_scheduler.register(task, uid, dry_run))
-            .await??
-            .into();
+        tokio::task::spawn_blocking(move || scheduler.register(task, uid, dry_run)).await??.into();
 
     debug!(returns = ?task, "Delete documents by batch");
-    Ok(HttpResponse::Accepted().json(task))
+    task_response(&index_scheduler, task, timeout, dry_run).await
+}
+
+#[derive(Debug, Deserr)]
+#[deserr(error = DeserrJsonError, rename_all = camelCase, deny_unknown_fields)]
+pub struct DocumentsTransaction {
+    #[deserr(error = DeserrJsonError<InvalidDocumentTransactionOperations>, missing_field_error = DeserrJsonError::missing_document_transaction_operations)]
+    operations: Vec<DocumentsTransactionOperation>,
+    #[deserr(default, error = DeserrJsonError<InvalidIndexPrimaryKey>)]
+    primary_key: Option<String>,
+}
+
+#[derive(Debug, Deserr)]
+#[deserr(error = DeserrJsonError<InvalidDocumentTransactionOperations>, rename_all = camelCase, deny_unknown_fields)]
+pub struct DocumentsTransactionOperation {
+    action: DocumentsTransactionAction,
+    #[deserr(default)]
+    documents: Option<Vec<Value>>,
+    #[deserr(default)]
+    document_ids: Option<Vec<Value>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserr)]
+#[deserr(rename_all = camelCase)]
+pub enum DocumentsTransactionAction {
+    Add,
+    Update,
+    Delete,
+}
+
+pub async fn documents_transaction(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::DOCUMENTS_ALL }>, Data<IndexScheduler>>,
+    index_uid: web::Path<String>,
+    body: AwebJson<DocumentsTransaction, DeserrJsonError>,
+    params: AwebQueryParameter<WaitForCompletionQuery, DeserrQueryParamError>,
+    req: HttpRequest,
+    opt: web::Data<Opt>,
+) -> Result<HttpResponse, ResponseError> {
+    debug!(parameters = ?body, "Documents transaction");
+    let index_uid = IndexUid::try_from(index_uid.into_inner())?;
+    let DocumentsTransaction { operations, primary_key } = body.into_inner();
+    let timeout = params.into_inner().timeout();
+
+    if operations.is_empty() {
+        return Err(ResponseError::from_msg(
+            "A documents batch must contain at least one operation.".to_string(),
+            Code::InvalidDocumentTransactionOperations,
+        ));
+    }
+
+    let allow_index_creation = index_scheduler.filters().allow_index_creation(&index_uid);
+    let uid = get_task_id(&req, &opt)?;
+    let dry_run = is_dry_run(&req, &opt)?;
+
+    // All the documents of the additions are written in the same update file, in order.
+    let (uuid, mut update_file) = index_scheduler.create_update_file(dry_run)?;
+    let mut builder = DocumentsBatchBuilder::new(std::io::BufWriter::new(&mut update_file));
+    let mut transaction_operations = Vec::with_capacity(operations.len());
+    for (position, operation) in operations.into_iter().enumerate() {
+        let DocumentsTransactionOperation { action, documents, document_ids } = operation;
+        let operation = match (action, documents, document_ids) {
+            (
+                DocumentsTransactionAction::Add | DocumentsTransactionAction::Update,
+                Some(documents),
+                None,
+            ) => {
+                for document in &documents {
+                    match document {
+                        Value::Object(object) => builder.append_json_object(object)?,
+                        _ => {
+                            return Err(ResponseError::from_msg(
+                                format!("The documents of the operation at position {position} must be objects."),
+                                Code::InvalidDocumentTransactionOperations,
+                            ))
+                        }
+                    }
+                }
+                let method = if action == DocumentsTransactionAction::Add {
+                    IndexDocumentsMethod::ReplaceDocuments
+                } else {
+                    IndexDocumentsMethod::UpdateDocuments
+                };
+                DocumentTransactionOperation::Add {
+                    method,
+                    documents_count: documents.len() as u64,
+                }
+            }
+            (DocumentsTransactionAction::Delete, None, Some(document_ids)) => {
+                DocumentTransactionOperation::Delete {
+                    documents_ids: document_ids
+                        .iter()
+                        .map(|v| v.as_str().map(String::from).unwrap_or_else(|| v.to_string()))
+                        .collect(),
+                }
+            }
+            (DocumentsTransactionAction::Delete, ..) => {
+                return Err(ResponseError::from_msg(
+                    format!("The `delete` operation at position {position} must only contain `documentIds`."),
+                    Code::InvalidDocumentTransactionOperations,
+                ))
+            }
+            (_, ..) => {
+                return Err(ResponseError::from_msg(
+                    format!("The `add` and `update` operations must only contain `documents`, the operation at position {position} doesn't."),
+                    Code::InvalidDocumentTransactionOperations,
+                ))
+            }
+        };
+        transaction_operations.push(operation);
+    }
+    builder.into_inner()?.flush()?;
+    update_file.persist()?;
+
+    let task = KindWithContent::DocumentTransaction {
+        index_uid: index_uid.to_string(),
+        primary_key,
+        content_file: uuid,
+        operations: transaction_operations,
+        allow_index_creation,
+    };
+
+    let scheduler = index_scheduler.clone();
+    let task: SummarizedTaskView =
+        match tokio::task::spawn_blocking(move || scheduler.register(task, uid, dry_run)).await? {
+            Ok(task) => task.into(),
+            Err(e) => {
+                index_scheduler.delete_update_file(uuid)?;
+                return Err(e.into());
+            }
+        };
+
+    debug!(returns = ?task, "Documents transaction");
+    task_response(&index_scheduler, task, timeout, dry_run).await
+}
+
+#[derive(Debug, Deserr)]
+#[deserr(error = DeserrJsonError, rename_all = camelCase, deny_unknown_fields)]
+pub struct DocumentUrlImport {
+    #[deserr(error = DeserrJsonError<InvalidDocumentImportUrl>, missing_field_error = DeserrJsonError::missing_document_import_url)]
+    url: String,
+    #[deserr(default, error = DeserrJsonError<InvalidIndexPrimaryKey>)]
+    primary_key: Option<String>,
+}
+
+pub async fn import_documents_from_url(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::DOCUMENTS_ADD }>, Data<IndexScheduler>>,
+    index_uid: web::Path<String>,
+    body: AwebJson<DocumentUrlImport, DeserrJsonError>,
+    params: AwebQueryParameter<WaitForCompletionQuery, DeserrQueryParamError>,
+    req: HttpRequest,
+    opt: web::Data<Opt>,
+) -> Result<HttpResponse, ResponseError> {
+    debug!(parameters = ?body, "Import documents from url");
+    let index_uid = IndexUid::try_from(index_uid.into_inner())?;
+    let DocumentUrlImport { url, primary_key } = body.into_inner();
+    let timeout = params.into_inner().timeout();
+
+    // The documents are downloaded by the server, we must ensure it
+    // can't be used to reach the hosts that are not explicitly allowed.
+    check_document_import_url(&url, &opt.experimental_document_url_import_allowed_hosts)?;
+
+    let allow_index_creation = index_scheduler.filters().allow_index_creation(&index_uid);
+    let task = KindWithContent::DocumentUrlImport {
+        index_uid: index_uid.to_string(),
+        url,
+        primary_key,
+        allow_index_creation,
+    };
+
+    let uid = get_task_id(&req, &opt)?;
+    let dry_run = is_dry_run(&req, &opt)?;
+    let scheduler = index_scheduler.clone();
+    let task: SummarizedTaskView =
+        tokio::task::spawn_blocking(move || scheduler.register(task, uid, dry_run)).await??.into();
+
+    debug!(returns = ?task, "Import documents from url");
+    task_response(&index_scheduler, task, timeout, dry_run).await
+}
+
+/// Only accepts the `http` and `https` URLs whose host is one of the allowed hosts.
+fn check_document_import_url(url: &str, allowed_hosts: &[String]) -> Result<(), ResponseError> {
+    let invalid =
+        |message: String| ResponseError::from_msg(message, Code::InvalidDocumentImportUrl);
+
+    let parsed =
+        Url::parse(url).map_err(|e| invalid(format!("`{url}` is not a valid URL: {e}.")))?;
+    if !matches!(parsed.scheme(), "http" | "https") {
+        return Err(invalid(format!("The URL `{url}` must use the `http` or `https` scheme.")));
+    }
+    match parsed.host_str() {
+        Some(host) if allowed_hosts.iter().any(|allowed| allowed.eq_ignore_ascii_case(host)) => {
+            Ok(())
+        }
+        _ => Err(invalid(format!(
+            "The host of the URL `{url}` is not allowed. The hosts documents can be imported from are set with the `--experimental-document-url-import-allowed-hosts` option."
+        ))),
+    }
 }
 
 #[derive(Debug, Deserr)]
@@ -560,6 +1130,7 @@ pub async fn delete_documents_by_filter(
     index_scheduler: GuardedData<ActionPolicy<{ actions::DOCUMENTS_DELETE }>, Data<IndexScheduler>>,
     index_uid: web::Path<String>,
     body: AwebJson<DocumentDeletionByFilter, DeserrJsonError>,
+    params: AwebQueryParameter<WaitForCompletionQuery, DeserrQueryParamError>,
     req: HttpRequest,
     opt: web::Data<Opt>,
     analytics: web::Data<dyn Analytics>,
@@ -568,6 +1139,7 @@ pub async fn delete_documents_by_filter(
     let index_uid = IndexUid::try_from(index_uid.into_inner())?;
     let index_uid = index_uid.into_inner();
     let filter = body.into_inner().filter;
+    let timeout = params.into_inner().timeout();
 
     analytics.delete_documents(DocumentDeletionKind::PerFilter, &req);
 
@@ -579,13 +1151,12 @@ pub async fn delete_documents_by_filter(
 
     let uid = get_task_id(&req, &opt)?;
     let dry_run = is_dry_run(&req, &opt)?;
+    let scheduler = index_scheduler.clone();
     let task: SummarizedTaskView =
-        tokio::task::spawn_blocking(move || index_scheduler.register(task, uid, dry_run))
-            .await??
-            .into();
+        tokio::task::spawn_blocking(move || scheduler.register(task, uid, dry_run)).await??.into();
 
     debug!(returns = ?task, "Delete documents by filter");
-    Ok(HttpResponse::Accepted().json(task))
+    task_response(&index_scheduler, task, timeout, dry_run).await
 }
 
 #[derive(Debug, Deserr)]
@@ -603,11 +1174,13 @@ pub async fn edit_documents_by_function(
     index_scheduler: GuardedData<ActionPolicy<{ actions::DOCUMENTS_ALL }>, Data<IndexScheduler>>,
     index_uid: web::Path<String>,
     params: AwebJson<DocumentEditionByFunction, DeserrJsonError>,
+    query: AwebQueryParameter<WaitForCompletionQuery, DeserrQueryParamError>,
     req: HttpRequest,
     opt: web::Data<Opt>,
     analytics: web::Data<dyn Analytics>,
 ) -> Result<HttpResponse, ResponseError> {
     debug!(parameters = ?params, "Edit documents by function");
+    let timeout = query.into_inner().timeout();
 
     index_scheduler
         .features()
@@ -656,35 +1229,35 @@ pub async fn edit_documents_by_function(
 
     let uid = get_task_id(&req, &opt)?;
     let dry_run = is_dry_run(&req, &opt)?;
+    let scheduler = index_scheduler.clone();
     let task: SummarizedTaskView =
-        tokio::task::spawn_blocking(move || index_scheduler.register(task, uid, dry_run))
-            .await??
-            .into();
+        tokio::task::spawn_blocking(move || scheduler.register(task, uid, dry_run)).await??.into();
 
     debug!(returns = ?task, "Edit documents by function");
-    Ok(HttpResponse::Accepted().json(task))
+    task_response(&index_scheduler, task, timeout, dry_run).await
 }
 
 pub async fn clear_all_documents(
     index_scheduler: GuardedData<ActionPolicy<{ actions::DOCUMENTS_DELETE }>, Data<IndexScheduler>>,
     index_uid: web::Path<String>,
+    params: AwebQueryParameter<WaitForCompletionQuery, DeserrQueryParamError>,
     req: HttpRequest,
     opt: web::Data<Opt>,
     analytics: web::Data<dyn Analytics>,
 ) -> Result<HttpResponse, ResponseError> {
     let index_uid = IndexUid::try_from(index_uid.into_inner())?;
+    let timeout = params.into_inner().timeout();
     analytics.delete_documents(DocumentDeletionKind::ClearAll, &req);
 
     let task = KindWithContent::DocumentClear { index_uid: index_uid.to_string() };
     let uid = get_task_id(&req, &opt)?;
     let dry_run = is_dry_run(&req, &opt)?;
+    let scheduler = index_scheduler.clone();
     let task: SummarizedTaskView =
-        tokio::task::spawn_blocking(move || index_scheduler.register(task, uid, dry_run))
-            .await??
-            .into();
+        tokio::task::spawn_blocking(move || scheduler.register(task, uid, dry_run)).await??.into();
 
     debug!(returns = ?task, "Delete all documents");
-    Ok(HttpResponse::Accepted().json(task))
+    task_response(&index_scheduler, task, timeout, dry_run).await
 }
 
 fn some_documents<'a, 't: 'a>(
@@ -696,10 +1269,14 @@ fn some_documents<'a, 't: 'a>(
     let fields_ids_map = index.fields_ids_map(rtxn)?;
     let all_fields: Vec<_> = fields_ids_map.iter().map(|(id, _)| id).collect();
     let embedding_configs = index.embedding_configs(rtxn)?;
+    let store_max_length = index.store_max_length(rtxn)?;
+    let primary_key = index.primary_key(rtxn)?.map(String::from);
 
     Ok(index.iter_documents(rtxn, doc_ids)?.map(move |ret| {
         ret.map_err(ResponseError::from).and_then(|(key, document)| -> Result<_, ResponseError> {
-            let mut document = milli::obkv_to_json(&all_fields, &fields_ids_map, document)?;
+            let mut document =
+                milli::obkv_to_json(&all_fields, &fields_ids_map, document.as_obkv())?;
+            milli::truncate_stored_values(&mut document, &store_max_length, primary_key.as_deref());
             match retrieve_vectors {
                 RetrieveVectors::Ignore => {}
                 RetrieveVectors::Hide => {
@@ -735,46 +1312,49 @@ fn some_documents<'a, 't: 'a>(
     }))
 }
 
+/// Returns the total number of documents matching the filter, the requested page of documents
+/// and, when there are more documents after this page, the cursor of the next page.
+///
+/// Without a cursor the documents are returned in internal id order. With a cursor they are
+/// returned in the lexicographic order of their external ids from the cursor, the external id
+/// of the first document of the page, so that a reused internal id can't move a document
+/// between the pages. The offset is applied after the cursor.
+#[allow(clippy::too_many_arguments)]
 fn retrieve_documents<S: AsRef<str>>(
     index: &Index,
     offset: usize,
     limit: usize,
+    from: Option<&str>,
     filter: Option<Value>,
     attributes_to_retrieve: Option<Vec<S>>,
     retrieve_vectors: RetrieveVectors,
     features: RoFeatures,
-) -> Result<(u64, Vec<Document>), ResponseError> {
+) -> Result<(u64, Vec<Document>, Option<String>), ResponseError> {
     let rtxn = index.read_txn()?;
-    let filter = &filter;
-    let filter = if let Some(filter) = filter {
-        parse_filter(filter, Code::InvalidDocumentFilter, features)?
-    } else {
-        None
-    };
-
-    let candidates = if let Some(filter) = filter {
-        filter.evaluate(&rtxn, index).map_err(|err| match err {
-            milli::Error::UserError(milli::UserError::InvalidFilter(_)) => {
-                ResponseError::from_msg(err.to_string(), Code::InvalidDocumentFilter)
-            }
-            e => e.into(),
-        })?
-    } else {
-        index.documents_ids(&rtxn)?
+    let candidates = filtered_documents_ids(index, &rtxn, filter.as_ref(), features)?;
+    let number_of_documents = candidates.len();
+
+    let (documents_ids, next) = match from {
+        Some(from) => {
+            let mut matching = index
+                .external_documents_ids()
+                .iter_from(&rtxn, from)?
+                .filter(|entry| {
+                    entry.as_ref().map_or(true, |(_, docid)| candidates.contains(*docid))
+                })
+                .skip(offset);
+            let documents_ids = (&mut matching)
+                .take(limit)
+                .map(|entry| entry.map(|(_, docid)| docid))
+                .collect::<Result<Vec<_>, _>>()?;
+            // the document following the page is where the next page starts
+            let next = matching.next().transpose()?.map(|(external_id, _)| external_id.to_string());
+            (documents_ids, next)
+        }
+        None => (candidates.iter().skip(offset).take(limit).collect(), None),
     };
 
-    let (it, number_of_documents) = {
-        let number_of_documents = candidates.len();
-        (
-            some_documents(
-                index,
-                &rtxn,
-                candidates.into_iter().skip(offset).take(limit),
-                retrieve_vectors,
-            )?,
-            number_of_documents,
-        )
-    };
+    let it = some_documents(index, &rtxn, documents_ids, retrieve_vectors)?;
 
     let documents: Vec<_> = it
         .map(|document| {
@@ -790,7 +1370,32 @@ fn retrieve_documents<S: AsRef<str>>(
         })
         .collect::<Result<_, ResponseError>>()?;
 
-    Ok((number_of_documents, documents))
+    Ok((number_of_documents, documents, next))
+}
+
+/// Returns the ids of the documents matching the filter, or all the documents if there is none.
+fn filtered_documents_ids(
+    index: &Index,
+    rtxn: &RoTxn,
+    filter: Option<&Value>,
+    features: RoFeatures,
+) -> Result<RoaringBitmap, ResponseError> {
+    let filter = if let Some(filter) = filter {
+        parse_filter(filter, Code::InvalidDocumentFilter, features)?
+    } else {
+        None
+    };
+
+    if let Some(filter) = filter {
+        filter.evaluate(rtxn, index).map_err(|err| match err {
+            milli::Error::UserError(milli::UserError::InvalidFilter(_)) => {
+                ResponseError::from_msg(err.to_string(), Code::InvalidDocumentFilter)
+            }
+            e => e.into(),
+        })
+    } else {
+        Ok(index.documents_ids(rtxn)?)
+    }
 }
 
 fn retrieve_document<S: AsRef<str>>(
@@ -798,7 +1403,7 @@ fn retrieve_document<S: AsRef<str>>(
     doc_id: &str,
     attributes_to_retrieve: Option<Vec<S>>,
     retrieve_vectors: RetrieveVectors,
-) -> Result<Document, ResponseError> {
+) -> Result<(Document, String), ResponseError> {
     let txn = index.read_txn()?;
 
     let internal_id = index
@@ -806,6 +1411,11 @@ fn retrieve_document<S: AsRef<str>>(
         .get(&txn, doc_id)?
         .ok_or_else(|| MeilisearchHttpError::DocumentNotFound(doc_id.to_string()))?;
 
+    let dictionary = index.document_decompression_dictionary(&txn)?;
+    let etag = index
+        .document_etag(&txn, internal_id, dictionary.as_deref())?
+        .ok_or_else(|| MeilisearchHttpError::DocumentNotFound(doc_id.to_string()))?;
+
     let document = some_documents(index, &txn, Some(internal_id), retrieve_vectors)?
         .next()
         .ok_or_else(|| MeilisearchHttpError::DocumentNotFound(doc_id.to_string()))??;
@@ -821,5 +1431,5 @@ fn retrieve_document<S: AsRef<str>>(
         None => document,
     };
 
-    Ok(document)
+    Ok((document, etag))
 }
@@ -0,0 +1,209 @@
+use std::ops::ControlFlow;
+
+use actix_web::web::{Bytes, Data};
+use actix_web::{web, HttpRequest, HttpResponse};
+use deserr::actix_web::AwebJson;
+use index_scheduler::{IndexScheduler, RoFeatures};
+use meilisearch_types::deserr::DeserrJsonError;
+use meilisearch_types::error::deserr_codes::*;
+use meilisearch_types::error::{Code, ResponseError};
+use meilisearch_types::heed::RoTxn;
+use meilisearch_types::index_uid::IndexUid;
+use meilisearch_types::milli::{self, FacetDistribution, OrderBy};
+use meilisearch_types::Index;
+use roaring::RoaringBitmap;
+use serde::Serialize;
+use serde_json::Value;
+use tokio::sync::{mpsc, oneshot};
+use tracing::debug;
+
+use crate::analytics::Analytics;
+use crate::error::MeilisearchHttpError;
+use crate::extractors::authentication::policies::*;
+use crate::extractors::authentication::GuardedData;
+use crate::extractors::sequential_extractor::SeqHandler;
+use crate::search::{add_search_rules, parse_filter};
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(web::resource("").route(web::post().to(SeqHandler(export_facet_distribution))));
+}
+
+#[derive(Debug, deserr::Deserr)]
+#[deserr(error = DeserrJsonError, rename_all = camelCase, deny_unknown_fields)]
+pub struct FacetDistributionQuery {
+    #[deserr(default, error = DeserrJsonError<InvalidSearchFacets>)]
+    facets: Option<Vec<String>>,
+    #[deserr(default, error = DeserrJsonError<InvalidSearchFilter>)]
+    filter: Option<Value>,
+}
+
+/// A line of the exported distribution.
+#[derive(Serialize)]
+struct FacetValueCount<'a> {
+    facet: &'a str,
+    value: &'a str,
+    count: u64,
+}
+
+/// The number of chunks of facet values that can be waiting to be sent to the client.
+const DISTRIBUTION_CHANNEL_CAPACITY: usize = 16;
+/// The size after which a chunk of facet values is sent to the client.
+const DISTRIBUTION_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Streams the complete distribution of the facets of the matching documents as NDJSON.
+///
+/// Unlike the `facetDistribution` of the search, the values aren't capped by the
+/// `maxValuesPerFacet` and are read directly from the facet databases, field after field
+/// and in lexicographic order.
+pub async fn export_facet_distribution(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::SEARCH }>, Data<IndexScheduler>>,
+    index_uid: web::Path<String>,
+    body: AwebJson<FacetDistributionQuery, DeserrJsonError>,
+    req: HttpRequest,
+    analytics: web::Data<dyn Analytics>,
+) -> Result<HttpResponse, ResponseError> {
+    let FacetDistributionQuery { facets, mut filter } = body.into_inner();
+    debug!(parameters = ?facets, "Export facet distribution");
+    let index_uid = IndexUid::try_from(index_uid.into_inner())?;
+
+    analytics.publish(
+        "Facet Distribution Exported".to_string(),
+        serde_json::json!({ "with_filter": filter.is_some(), "with_facets": facets.is_some() }),
+        Some(&req),
+    );
+
+    // Tenant token search_rules.
+    if let Some(search_rules) = index_scheduler.filters().get_index_search_rules(&index_uid) {
+        add_search_rules(&mut filter, search_rules);
+    }
+
+    let features = index_scheduler.features();
+    let index = index_scheduler.index(&index_uid)?;
+
+    let (ready_sender, ready_receiver) = oneshot::channel();
+    let (sender, receiver) = mpsc::channel(DISTRIBUTION_CHANNEL_CAPACITY);
+    tokio::task::spawn_blocking(move || {
+        facet_distribution_into(&index, facets, filter, features, ready_sender, sender)
+    });
+
+    // The errors happening before the first value is sent, like an invalid filter or
+    // a non-filterable facet, are returned as a regular error response.
+    ready_receiver.await.map_err(|_| {
+        ResponseError::from_msg(
+            "The facet distribution export stopped unexpectedly".to_string(),
+            Code::Internal,
+        )
+    })??;
+
+    let stream = futures::stream::unfold(receiver, |mut receiver| async move {
+        receiver.recv().await.map(|chunk| (chunk, receiver))
+    });
+
+    Ok(HttpResponse::Ok().content_type("application/x-ndjson").streaming(stream))
+}
+
+/// Sends the facet values of the matching documents in chunks of NDJSON through the sender.
+/// Stops as soon as the client goes away.
+fn facet_distribution_into(
+    index: &Index,
+    facets: Option<Vec<String>>,
+    filter: Option<Value>,
+    features: RoFeatures,
+    ready: oneshot::Sender<Result<(), ResponseError>>,
+    sender: mpsc::Sender<Result<Bytes, ResponseError>>,
+) {
+    // This read transaction is kept for the whole export.
+    let rtxn = match index.read_txn() {
+        Ok(rtxn) => rtxn,
+        Err(e) => {
+            let _ = ready.send(Err(e.into()));
+            return;
+        }
+    };
+
+    let candidates = match filtered_candidates(index, &rtxn, filter.as_ref(), features) {
+        Ok(candidates) => candidates,
+        Err(e) => {
+            let _ = ready.send(Err(e));
+            return;
+        }
+    };
+
+    let mut distribution = FacetDistribution::new(&rtxn, index);
+    if let Some(facets) = facets {
+        distribution.facets(facets.into_iter().map(|facet| (facet, OrderBy::Lexicographic)));
+    }
+    if let Some(candidates) = candidates {
+        distribution.candidates(candidates);
+    }
+
+    // The facets are only checked once the iteration starts,
+    // so we only answer the request with the first value.
+    let mut ready = Some(ready);
+    let mut chunk = Vec::new();
+    let mut error = None;
+    let result = distribution.for_each_value(|facet, value, count| {
+        if let Some(ready) = ready.take() {
+            if ready.send(Ok(())).is_err() {
+                return ControlFlow::Break(());
+            }
+        }
+
+        let line = FacetValueCount { facet, value, count };
+        if let Err(e) = serde_json::to_writer(&mut chunk, &line) {
+            error = Some(MeilisearchHttpError::from(e).into());
+            return ControlFlow::Break(());
+        }
+        chunk.push(b'\n');
+
+        if chunk.len() >= DISTRIBUTION_CHUNK_SIZE
+            && sender.blocking_send(Ok(Bytes::from(std::mem::take(&mut chunk)))).is_err()
+        {
+            // the client is gone
+            return ControlFlow::Break(());
+        }
+        ControlFlow::Continue(())
+    });
+
+    let error = match result {
+        Ok(()) => error,
+        Err(e) => Some(ResponseError::from(e)),
+    };
+    match (ready, error) {
+        (Some(ready), error) => {
+            let _ = ready.send(error.map_or(Ok(()), Err));
+        }
+        (None, Some(error)) => {
+            let _ = sender.blocking_send(Err(error));
+            return;
+        }
+        (None, None) => (),
+    }
+
+    if !chunk.is_empty() {
+        let _ = sender.blocking_send(Ok(Bytes::from(chunk)));
+    }
+}
+
+/// Returns the documents matching the filter, or `None` if there's no filter.
+fn filtered_candidates(
+    index: &Index,
+    rtxn: &RoTxn,
+    filter: Option<&Value>,
+    features: RoFeatures,
+) -> Result<Option<RoaringBitmap>, ResponseError> {
+    let filter = match filter {
+        Some(filter) => parse_filter(filter, Code::InvalidSearchFilter, features)?,
+        None => None,
+    };
+
+    match filter {
+        Some(filter) => filter.evaluate(rtxn, index).map(Some).map_err(|err| match err {
+            milli::Error::UserError(milli::UserError::InvalidFilter(_)) => {
+                ResponseError::from_msg(err.to_string(), Code::InvalidSearchFilter)
+            }
+            e => e.into(),
+        }),
+        None => Ok(None),
+    }
+}
@@ -15,9 +15,11 @@ use crate::extractors::authentication::policies::*;
 use crate::extractors::authentication::GuardedData;
 use crate::routes::indexes::search::search_kind;
 use crate::search::{
-    add_search_rules, perform_facet_search, HybridQuery, MatchingStrategy, RankingScoreThreshold,
-    SearchQuery, DEFAULT_CROP_LENGTH, DEFAULT_CROP_MARKER, DEFAULT_HIGHLIGHT_POST_TAG,
-    DEFAULT_HIGHLIGHT_PRE_TAG, DEFAULT_SEARCH_LIMIT, DEFAULT_SEARCH_OFFSET,
+    add_search_rules, perform_facet_search, HybridQuery, MatchingStrategy, QueryLimits,
+    RankingScoreThreshold, SearchQuery, DEFAULT_COMPUTE_TOTAL_HITS, DEFAULT_CROP_LENGTH,
+    DEFAULT_CROP_MARKER, DEFAULT_CROSS_ATTRIBUTE_MATCHING, DEFAULT_GROUP_LIMIT,
+    DEFAULT_HIGHLIGHT_POST_TAG, DEFAULT_HIGHLIGHT_PRE_TAG, DEFAULT_SEARCH_LIMIT,
+    DEFAULT_SEARCH_OFFSET, DEFAULT_SUGGESTION_MAX_HITS,
 };
 use crate::search_queue::SearchQueue;
 
@@ -35,6 +37,8 @@ pub struct FacetSearchQuery {
     pub facet_query: Option<String>,
     #[deserr(error = DeserrJsonError<InvalidFacetSearchFacetName>, missing_field_error = DeserrJsonError::missing_facet_search_facet_name)]
     pub facet_name: String,
+    #[deserr(default, error = DeserrJsonError<InvalidFacetSearchFuzzy>, default)]
+    pub fuzzy: bool,
     #[deserr(default, error = DeserrJsonError<InvalidSearchQ>)]
     pub q: Option<String>,
     #[deserr(default, error = DeserrJsonError<InvalidSearchVector>)]
@@ -56,6 +60,7 @@ pub struct FacetSearchQuery {
 pub async fn search(
     index_scheduler: GuardedData<ActionPolicy<{ actions::SEARCH }>, Data<IndexScheduler>>,
     search_queue: Data<SearchQueue>,
+    query_limits: Data<QueryLimits>,
     index_uid: web::Path<String>,
     params: AwebJson<FacetSearchQuery, DeserrJsonError>,
     req: HttpRequest,
@@ -65,11 +70,13 @@ pub async fn search(
 
     let query = params.into_inner();
     debug!(parameters = ?query, "Facet search");
+    query_limits.check(query.q.as_deref())?;
 
     let mut aggregate = FacetSearchAggregator::from_query(&query, &req);
 
     let facet_query = query.facet_query.clone();
     let facet_name = query.facet_name.clone();
+    let fuzzy = query.fuzzy;
     let locales = query.locales.clone().map(|l| l.into_iter().map(Into::into).collect());
     let mut search_query = SearchQuery::from(query);
 
@@ -88,6 +95,7 @@ pub async fn search(
             search_query,
             facet_query,
             facet_name,
+            fuzzy,
             search_kind,
             index_scheduler.features(),
             locales,
@@ -111,6 +119,7 @@ impl From<FacetSearchQuery> for SearchQuery {
         let FacetSearchQuery {
             facet_query: _,
             facet_name: _,
+            fuzzy: _,
             q,
             vector,
             filter,
@@ -127,24 +136,41 @@ impl From<FacetSearchQuery> for SearchQuery {
             limit: DEFAULT_SEARCH_LIMIT(),
             page: None,
             hits_per_page: None,
+            compute_total_hits: DEFAULT_COMPUTE_TOTAL_HITS(),
+            count_only: false,
             attributes_to_retrieve: None,
             retrieve_vectors: false,
             attributes_to_crop: None,
             crop_length: DEFAULT_CROP_LENGTH(),
             attributes_to_highlight: None,
             show_matches_position: false,
+            show_matches_info: false,
+            show_query_coverage: false,
+            enable_formatting: None,
             show_ranking_score: false,
             show_ranking_score_details: false,
+            show_suggestion: false,
+            suggestion_max_hits: DEFAULT_SUGGESTION_MAX_HITS(),
+            show_query_analysis: false,
             filter,
+            filter_relaxation: None,
+            boosting_filter: None,
             sort: None,
+            ranking_rules: None,
             distinct: None,
+            group_by: None,
+            group_limit: DEFAULT_GROUP_LIMIT(),
             facets: None,
             highlight_pre_tag: DEFAULT_HIGHLIGHT_PRE_TAG(),
             highlight_post_tag: DEFAULT_HIGHLIGHT_POST_TAG(),
             crop_marker: DEFAULT_CROP_MARKER(),
+            attributes_formatting: None,
+            prefix_highlight: Default::default(),
             matching_strategy,
+            max_synonym_expansions: None,
             vector,
             attributes_to_search_on,
+            cross_attribute_matching: DEFAULT_CROSS_ATTRIBUTE_MATCHING(),
             hybrid,
             ranking_score_threshold,
             locales,
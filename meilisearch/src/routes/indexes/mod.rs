@@ -1,4 +1,7 @@
+use std::collections::BTreeMap;
 use std::convert::Infallible;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 
 use actix_web::web::Data;
 use actix_web::{web, HttpRequest, HttpResponse};
@@ -10,7 +13,7 @@ use meilisearch_types::deserr::{immutable_field_error, DeserrJsonError, DeserrQu
 use meilisearch_types::error::deserr_codes::*;
 use meilisearch_types::error::{Code, ResponseError};
 use meilisearch_types::index_uid::IndexUid;
-use meilisearch_types::milli::{self, FieldDistribution, Index};
+use meilisearch_types::milli::{self, FieldDistribution, FieldSchema, Index};
 use meilisearch_types::tasks::KindWithContent;
 use serde::Serialize;
 use serde_json::json;
@@ -19,17 +22,22 @@ use tracing::debug;
 
 use super::{get_task_id, Pagination, SummarizedTaskView, PAGINATION_DEFAULT_LIMIT};
 use crate::analytics::Analytics;
+use crate::error::MeilisearchHttpError;
 use crate::extractors::authentication::policies::*;
 use crate::extractors::authentication::{AuthenticationError, GuardedData};
 use crate::extractors::sequential_extractor::SeqHandler;
 use crate::routes::is_dry_run;
+use crate::search_log::SearchLog;
 use crate::Opt;
 
 pub mod documents;
+pub mod facet_distribution;
 pub mod facet_search;
 pub mod search;
 pub mod settings;
 pub mod similar;
+pub mod suggest;
+pub mod vocabulary;
 
 pub fn configure(cfg: &mut web::ServiceConfig) {
     cfg.service(
@@ -46,10 +54,19 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
                     .route(web::delete().to(SeqHandler(delete_index))),
             )
             .service(web::resource("/stats").route(web::get().to(SeqHandler(get_index_stats))))
+            .service(web::resource("/warmup").route(web::post().to(SeqHandler(warmup_index))))
+            .service(web::resource("/schema").route(web::get().to(SeqHandler(get_index_schema))))
+            .service(
+                web::resource("/recent-searches")
+                    .route(web::get().to(SeqHandler(get_recent_searches))),
+            )
             .service(web::scope("/documents").configure(documents::configure))
             .service(web::scope("/search").configure(search::configure))
             .service(web::scope("/facet-search").configure(facet_search::configure))
+            .service(web::scope("/facet-distribution").configure(facet_distribution::configure))
             .service(web::scope("/similar").configure(similar::configure))
+            .service(web::scope("/suggest").configure(suggest::configure))
+            .service(web::scope("/vocabulary").configure(vocabulary::configure))
             .service(web::scope("/settings").configure(settings::configure)),
     );
 }
@@ -63,6 +80,9 @@ pub struct IndexView {
     #[serde(with = "time::serde::rfc3339")]
     pub updated_at: OffsetDateTime,
     pub primary_key: Option<String>,
+    /// Only returned when metadata was attached to the index with the `userMetadata` setting.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_metadata: Option<serde_json::Value>,
 }
 
 impl IndexView {
@@ -75,6 +95,7 @@ impl IndexView {
             created_at: index.created_at(&rtxn)?,
             updated_at: index.updated_at(&rtxn)?,
             primary_key: index.primary_key(&rtxn)?.map(String::from),
+            user_metadata: index.user_metadata(&rtxn)?,
         })
     }
 }
@@ -256,14 +277,33 @@ pub struct IndexStats {
     pub is_indexing: bool,
     /// Association of every field name with the number of times it occurs in the documents.
     pub field_distribution: FieldDistribution,
+    /// Size of the pages used by each group of databases of the index, in bytes.
+    pub databases_sizes: DatabasesSizes,
+    /// Average size of the documents in the documents database, in bytes.
+    pub avg_document_size: u64,
+    /// Size taken up by the index' DB, in bytes, only exposed through the metrics.
+    #[serde(skip)]
+    pub database_size: u64,
+    /// Size taken by the used pages of the index' DB, in bytes, only exposed through the metrics.
+    #[serde(skip)]
+    pub used_database_size: u64,
 }
 
 impl From<index_scheduler::IndexStats> for IndexStats {
     fn from(stats: index_scheduler::IndexStats) -> Self {
+        let databases_sizes = stats.inner_stats.databases_sizes;
+        let avg_document_size = databases_sizes
+            .documents
+            .checked_div(stats.inner_stats.number_of_documents)
+            .unwrap_or_default();
         IndexStats {
             number_of_documents: stats.inner_stats.number_of_documents,
             is_indexing: stats.is_indexing,
             field_distribution: stats.inner_stats.field_distribution,
+            databases_sizes,
+            avg_document_size,
+            database_size: stats.inner_stats.database_size,
+            used_database_size: stats.inner_stats.used_database_size,
         }
     }
 }
@@ -278,3 +318,91 @@ pub async fn get_index_stats(
     debug!(returns = ?stats, "Get index stats");
     Ok(HttpResponse::Ok().json(stats))
 }
+
+pub async fn get_recent_searches(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::STATS_GET }>, Data<IndexScheduler>>,
+    search_log: Data<SearchLog>,
+    index_uid: web::Path<String>,
+) -> Result<HttpResponse, ResponseError> {
+    if !search_log.is_enabled() {
+        return Err(MeilisearchHttpError::SearchLogDisabled.into());
+    }
+    let index_uid = IndexUid::try_from(index_uid.into_inner())?;
+    // only the searches of existing indexes are listed
+    index_scheduler.index(&index_uid)?;
+
+    let results = search_log.recent_searches(&index_uid);
+    debug!(returns = ?results, "Get recent searches");
+    Ok(HttpResponse::Ok().json(json!({ "results": results })))
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SchemaView {
+    fields: BTreeMap<String, FieldSchema>,
+}
+
+/// The schema holds a sample value of each field, hence the documents access it requires.
+pub async fn get_index_schema(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::DOCUMENTS_GET }>, Data<IndexScheduler>>,
+    index_uid: web::Path<String>,
+) -> Result<HttpResponse, ResponseError> {
+    let index_uid = IndexUid::try_from(index_uid.into_inner())?;
+    let index = index_scheduler.index(&index_uid)?;
+
+    // all the documents are read to infer the types of the fields
+    let fields = tokio::task::spawn_blocking(move || -> Result<_, ResponseError> {
+        let rtxn = index.read_txn()?;
+        Ok(index.schema(&rtxn)?)
+    })
+    .await
+    .map_err(|e| ResponseError::from_msg(e.to_string(), Code::Internal))??;
+
+    let schema = SchemaView { fields };
+    debug!(returns = ?schema, "Get index schema");
+    Ok(HttpResponse::Ok().json(schema))
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WarmupView {
+    index_uid: String,
+    /// The number of bytes read from the databases of the index.
+    bytes_read: u64,
+}
+
+/// Sets its flag when dropped, i.e. when the client stops waiting for the response.
+#[derive(Default)]
+struct StopOnDrop(Arc<AtomicBool>);
+
+impl Drop for StopOnDrop {
+    fn drop(&mut self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+}
+
+pub async fn warmup_index(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::INDEXES_UPDATE }>, Data<IndexScheduler>>,
+    index_uid: web::Path<String>,
+) -> Result<HttpResponse, ResponseError> {
+    let index_uid = IndexUid::try_from(index_uid.into_inner())?;
+    let index = index_scheduler.index(&index_uid)?;
+
+    // the warmup stops when the request is dropped, e.g. when the client disconnects
+    let stop = StopOnDrop::default();
+    let must_stop = stop.0.clone();
+    let bytes_read = tokio::task::spawn_blocking(move || -> Result<_, ResponseError> {
+        let rtxn = index.read_txn()?;
+        Ok(index.warmup(&rtxn, || must_stop.load(Ordering::Relaxed))?)
+    })
+    .await
+    .map_err(|e| ResponseError::from_msg(e.to_string(), Code::Internal))??;
+
+    // nobody is waiting for the response of a stopped warmup
+    let warmup = WarmupView {
+        index_uid: index_uid.into_inner(),
+        bytes_read: bytes_read.unwrap_or_default(),
+    };
+    debug!(returns = ?warmup, "Warmup index");
+    Ok(HttpResponse::Ok().json(warmup))
+}
@@ -1,6 +1,9 @@
-use actix_web::web::Data;
+use std::cell::Cell;
+
+use actix_web::web::{Bytes, Data};
 use actix_web::{web, HttpRequest, HttpResponse};
 use deserr::actix_web::{AwebJson, AwebQueryParameter};
+use futures::StreamExt;
 use index_scheduler::{IndexScheduler, RoFeatures};
 use meilisearch_types::deserr::query_params::Param;
 use meilisearch_types::deserr::{DeserrJsonError, DeserrQueryParamError};
@@ -10,7 +13,11 @@ use meilisearch_types::index_uid::IndexUid;
 use meilisearch_types::locales::Locale;
 use meilisearch_types::milli;
 use meilisearch_types::serde_cs::vec::CS;
+use meilisearch_types::settings::RankingRuleView;
+use meilisearch_types::tasks::TaskId;
+use serde::Serialize;
 use serde_json::Value;
+use tokio::sync::mpsc;
 use tracing::debug;
 
 use crate::analytics::{Analytics, SearchAggregator};
@@ -19,20 +26,27 @@ use crate::extractors::authentication::policies::*;
 use crate::extractors::authentication::GuardedData;
 use crate::extractors::sequential_extractor::SeqHandler;
 use crate::metrics::MEILISEARCH_DEGRADED_SEARCH_REQUESTS;
+use crate::routes::wait_for_consistency;
 use crate::search::{
-    add_search_rules, perform_search, HybridQuery, MatchingStrategy, RankingScoreThreshold,
-    RetrieveVectors, SearchKind, SearchQuery, SemanticRatio, DEFAULT_CROP_LENGTH,
-    DEFAULT_CROP_MARKER, DEFAULT_HIGHLIGHT_POST_TAG, DEFAULT_HIGHLIGHT_PRE_TAG,
+    add_search_rules, perform_search, perform_search_with_partial_hits, rerank_search_result,
+    HybridQuery, MatchingStrategy, PrefixHighlight, QueryLimits, RankingScoreThreshold,
+    RetrieveVectors, SearchHit, SearchKind, SearchQuery, SemanticRatio, DEFAULT_COMPUTE_TOTAL_HITS,
+    DEFAULT_CROP_LENGTH, DEFAULT_CROP_MARKER, DEFAULT_CROSS_ATTRIBUTE_MATCHING,
+    DEFAULT_GROUP_LIMIT, DEFAULT_HIGHLIGHT_POST_TAG, DEFAULT_HIGHLIGHT_PRE_TAG,
     DEFAULT_SEARCH_LIMIT, DEFAULT_SEARCH_OFFSET, DEFAULT_SEMANTIC_RATIO,
+    DEFAULT_SUGGESTION_MAX_HITS,
 };
+use crate::search_log::SearchLog;
 use crate::search_queue::SearchQueue;
+use crate::search_stats::PendingSearchEvent;
 
 pub fn configure(cfg: &mut web::ServiceConfig) {
     cfg.service(
         web::resource("")
             .route(web::get().to(SeqHandler(search_with_url_query)))
             .route(web::post().to(SeqHandler(search_with_post))),
-    );
+    )
+    .service(web::resource("/stream").route(web::post().to(SeqHandler(search_stream))));
 }
 
 #[derive(Debug, deserr::Deserr)]
@@ -50,6 +64,10 @@ pub struct SearchQueryGet {
     page: Option<Param<usize>>,
     #[deserr(default, error = DeserrQueryParamError<InvalidSearchHitsPerPage>)]
     hits_per_page: Option<Param<usize>>,
+    #[deserr(default = Param(DEFAULT_COMPUTE_TOTAL_HITS()), error = DeserrQueryParamError<InvalidSearchComputeTotalHits>)]
+    compute_total_hits: Param<bool>,
+    #[deserr(default, error = DeserrQueryParamError<InvalidSearchCountOnly>)]
+    count_only: Param<bool>,
     #[deserr(default, error = DeserrQueryParamError<InvalidSearchAttributesToRetrieve>)]
     attributes_to_retrieve: Option<CS<String>>,
     #[deserr(default, error = DeserrQueryParamError<InvalidSearchRetrieveVectors>)]
@@ -62,16 +80,36 @@ pub struct SearchQueryGet {
     attributes_to_highlight: Option<CS<String>>,
     #[deserr(default, error = DeserrQueryParamError<InvalidSearchFilter>)]
     filter: Option<String>,
+    #[deserr(default, error = DeserrQueryParamError<InvalidSearchBoostingFilter>)]
+    boosting_filter: Option<String>,
     #[deserr(default, error = DeserrQueryParamError<InvalidSearchSort>)]
     sort: Option<String>,
+    #[deserr(default, error = DeserrQueryParamError<InvalidSearchRankingRules>)]
+    ranking_rules: Option<CS<RankingRuleView>>,
     #[deserr(default, error = DeserrQueryParamError<InvalidSearchDistinct>)]
     distinct: Option<String>,
+    #[deserr(default, error = DeserrQueryParamError<InvalidSearchGroupBy>)]
+    group_by: Option<String>,
+    #[deserr(default = Param(DEFAULT_GROUP_LIMIT()), error = DeserrQueryParamError<InvalidSearchGroupLimit>)]
+    group_limit: Param<usize>,
     #[deserr(default, error = DeserrQueryParamError<InvalidSearchShowMatchesPosition>)]
     show_matches_position: Param<bool>,
+    #[deserr(default, error = DeserrQueryParamError<InvalidSearchShowMatchesInfo>)]
+    show_matches_info: Param<bool>,
+    #[deserr(default, error = DeserrQueryParamError<InvalidSearchShowQueryCoverage>)]
+    show_query_coverage: Param<bool>,
+    #[deserr(default, error = DeserrQueryParamError<InvalidSearchEnableFormatting>)]
+    enable_formatting: Option<Param<bool>>,
     #[deserr(default, error = DeserrQueryParamError<InvalidSearchShowRankingScore>)]
     show_ranking_score: Param<bool>,
     #[deserr(default, error = DeserrQueryParamError<InvalidSearchShowRankingScoreDetails>)]
     show_ranking_score_details: Param<bool>,
+    #[deserr(default, error = DeserrQueryParamError<InvalidSearchShowSuggestion>)]
+    show_suggestion: Param<bool>,
+    #[deserr(default = Param(DEFAULT_SUGGESTION_MAX_HITS()), error = DeserrQueryParamError<InvalidSearchSuggestionMaxHits>)]
+    suggestion_max_hits: Param<usize>,
+    #[deserr(default, error = DeserrQueryParamError<InvalidSearchShowQueryAnalysis>)]
+    show_query_analysis: Param<bool>,
     #[deserr(default, error = DeserrQueryParamError<InvalidSearchFacets>)]
     facets: Option<CS<String>>,
     #[deserr( default = DEFAULT_HIGHLIGHT_PRE_TAG(), error = DeserrQueryParamError<InvalidSearchHighlightPreTag>)]
@@ -80,10 +118,16 @@ pub struct SearchQueryGet {
     highlight_post_tag: String,
     #[deserr(default = DEFAULT_CROP_MARKER(), error = DeserrQueryParamError<InvalidSearchCropMarker>)]
     crop_marker: String,
+    #[deserr(default, error = DeserrQueryParamError<InvalidSearchPrefixHighlight>)]
+    prefix_highlight: PrefixHighlight,
     #[deserr(default, error = DeserrQueryParamError<InvalidSearchMatchingStrategy>)]
     matching_strategy: MatchingStrategy,
+    #[deserr(default, error = DeserrQueryParamError<InvalidSearchMaxSynonymExpansions>)]
+    max_synonym_expansions: Option<Param<usize>>,
     #[deserr(default, error = DeserrQueryParamError<InvalidSearchAttributesToSearchOn>)]
     pub attributes_to_search_on: Option<CS<String>>,
+    #[deserr(default = Param(DEFAULT_CROSS_ATTRIBUTE_MATCHING()), error = DeserrQueryParamError<InvalidSearchCrossAttributeMatching>)]
+    cross_attribute_matching: Param<bool>,
     #[deserr(default, error = DeserrQueryParamError<InvalidEmbedder>)]
     pub hybrid_embedder: Option<String>,
     #[deserr(default, error = DeserrQueryParamError<InvalidSearchSemanticRatio>)]
@@ -92,6 +136,17 @@ pub struct SearchQueryGet {
     pub ranking_score_threshold: Option<RankingScoreThresholdGet>,
     #[deserr(default, error = DeserrQueryParamError<InvalidSearchLocales>)]
     pub locales: Option<CS<Locale>>,
+    #[deserr(default, error = DeserrQueryParamError<InvalidSearchMinConsistency>)]
+    pub min_consistency: Option<Param<TaskId>>,
+}
+
+/// The query parameters of the `POST` search route.
+#[derive(Debug, deserr::Deserr)]
+#[deserr(error = DeserrQueryParamError, rename_all = camelCase, deny_unknown_fields)]
+pub struct SearchPostQuery {
+    /// The `taskUid` of a write the search must reflect, see [`wait_for_consistency`].
+    #[deserr(default, error = DeserrQueryParamError<InvalidSearchMinConsistency>)]
+    pub min_consistency: Option<Param<TaskId>>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, deserr::Deserr)]
@@ -137,6 +192,10 @@ impl From<SearchQueryGet> for SearchQuery {
             },
             None => None,
         };
+        let boosting_filter = other.boosting_filter.map(|f| match serde_json::from_str(&f) {
+            Ok(v) => v,
+            _ => Value::String(f),
+        });
 
         let hybrid = match (other.hybrid_embedder, other.hybrid_semantic_ratio) {
             (None, None) => None,
@@ -159,23 +218,40 @@ impl From<SearchQueryGet> for SearchQuery {
             limit: other.limit.0,
             page: other.page.as_deref().copied(),
             hits_per_page: other.hits_per_page.as_deref().copied(),
+            compute_total_hits: other.compute_total_hits.0,
+            count_only: other.count_only.0,
             attributes_to_retrieve: other.attributes_to_retrieve.map(|o| o.into_iter().collect()),
             retrieve_vectors: other.retrieve_vectors.0,
             attributes_to_crop: other.attributes_to_crop.map(|o| o.into_iter().collect()),
             crop_length: other.crop_length.0,
             attributes_to_highlight: other.attributes_to_highlight.map(|o| o.into_iter().collect()),
             filter,
+            filter_relaxation: None,
+            boosting_filter,
             sort: other.sort.map(|attr| fix_sort_query_parameters(&attr)),
+            ranking_rules: other.ranking_rules.map(|o| o.into_iter().collect()),
             distinct: other.distinct,
+            group_by: other.group_by,
+            group_limit: other.group_limit.0,
             show_matches_position: other.show_matches_position.0,
+            show_matches_info: other.show_matches_info.0,
+            show_query_coverage: other.show_query_coverage.0,
+            enable_formatting: other.enable_formatting.as_deref().copied(),
             show_ranking_score: other.show_ranking_score.0,
             show_ranking_score_details: other.show_ranking_score_details.0,
+            show_suggestion: other.show_suggestion.0,
+            suggestion_max_hits: other.suggestion_max_hits.0,
+            show_query_analysis: other.show_query_analysis.0,
             facets: other.facets.map(|o| o.into_iter().collect()),
             highlight_pre_tag: other.highlight_pre_tag,
             highlight_post_tag: other.highlight_post_tag,
             crop_marker: other.crop_marker,
+            attributes_formatting: None,
+            prefix_highlight: other.prefix_highlight,
             matching_strategy: other.matching_strategy,
+            max_synonym_expansions: other.max_synonym_expansions.as_deref().copied(),
             attributes_to_search_on: other.attributes_to_search_on.map(|o| o.into_iter().collect()),
+            cross_attribute_matching: other.cross_attribute_matching.0,
             hybrid,
             ranking_score_threshold: other.ranking_score_threshold.map(|o| o.0),
             locales: other.locales.map(|o| o.into_iter().collect()),
@@ -211,6 +287,8 @@ fn fix_sort_query_parameters(sort_query: &str) -> Vec<String> {
 pub async fn search_with_url_query(
     index_scheduler: GuardedData<ActionPolicy<{ actions::SEARCH }>, Data<IndexScheduler>>,
     search_queue: web::Data<SearchQueue>,
+    search_log: web::Data<SearchLog>,
+    query_limits: web::Data<QueryLimits>,
     index_uid: web::Path<String>,
     params: AwebQueryParameter<SearchQueryGet, DeserrQueryParamError>,
     req: HttpRequest,
@@ -219,7 +297,11 @@ pub async fn search_with_url_query(
     debug!(parameters = ?params, "Search get");
     let index_uid = IndexUid::try_from(index_uid.into_inner())?;
 
-    let mut query: SearchQuery = params.into_inner().into();
+    let params = params.into_inner();
+    let min_consistency = params.min_consistency.map(|uid| uid.0);
+    let mut query: SearchQuery = params.into();
+    query_limits.check(query.q.as_deref())?;
+    let search_event = PendingSearchEvent::new(&index_scheduler, &query);
 
     // Tenant token search_rules.
     if let Some(search_rules) = index_scheduler.filters().get_index_search_rules(&index_uid) {
@@ -227,19 +309,31 @@ pub async fn search_with_url_query(
     }
 
     let mut aggregate = SearchAggregator::from_query(&query, &req);
+    let logged_query = search_log.is_enabled().then(|| (query.q.clone(), query.filter.clone()));
 
+    wait_for_consistency(&index_scheduler, min_consistency).await?;
     let index = index_scheduler.index(&index_uid)?;
     let features = index_scheduler.features();
 
     let search_kind = search_kind(&query, index_scheduler.get_ref(), &index, features)?;
     let retrieve_vector = RetrieveVectors::new(query.retrieve_vectors, features)?;
-    let _permit = search_queue.try_get_search_permit().await?;
-    let search_result = tokio::task::spawn_blocking(move || {
+    let permit = search_queue.try_get_search_permit().await?;
+    let mut search_result = tokio::task::spawn_blocking(move || {
         perform_search(&index, query, search_kind, retrieve_vector, index_scheduler.features())
     })
     .await?;
+    drop(permit);
+    if let Ok(ref mut search_result) = search_result {
+        rerank_search_result(search_result).await;
+    }
     if let Ok(ref search_result) = search_result {
         aggregate.succeed(search_result);
+        if let Some((q, filter)) = logged_query {
+            search_log.record(&index_uid, q, filter, search_result);
+        }
+        if let Some(search_event) = search_event {
+            search_event.record(&index_uid, search_result);
+        }
     }
     analytics.get_search(aggregate);
 
@@ -252,8 +346,11 @@ pub async fn search_with_url_query(
 pub async fn search_with_post(
     index_scheduler: GuardedData<ActionPolicy<{ actions::SEARCH }>, Data<IndexScheduler>>,
     search_queue: web::Data<SearchQueue>,
+    search_log: web::Data<SearchLog>,
+    query_limits: web::Data<QueryLimits>,
     index_uid: web::Path<String>,
     params: AwebJson<SearchQuery, DeserrJsonError>,
+    consistency: AwebQueryParameter<SearchPostQuery, DeserrQueryParamError>,
     req: HttpRequest,
     analytics: web::Data<dyn Analytics>,
 ) -> Result<HttpResponse, ResponseError> {
@@ -261,6 +358,8 @@ pub async fn search_with_post(
 
     let mut query = params.into_inner();
     debug!(parameters = ?query, "Search post");
+    query_limits.check(query.q.as_deref())?;
+    let search_event = PendingSearchEvent::new(&index_scheduler, &query);
 
     // Tenant token search_rules.
     if let Some(search_rules) = index_scheduler.filters().get_index_search_rules(&index_uid) {
@@ -268,7 +367,9 @@ pub async fn search_with_post(
     }
 
     let mut aggregate = SearchAggregator::from_query(&query, &req);
+    let logged_query = search_log.is_enabled().then(|| (query.q.clone(), query.filter.clone()));
 
+    wait_for_consistency(&index_scheduler, consistency.min_consistency.map(|uid| uid.0)).await?;
     let index = index_scheduler.index(&index_uid)?;
 
     let features = index_scheduler.features();
@@ -276,16 +377,26 @@ pub async fn search_with_post(
     let search_kind = search_kind(&query, index_scheduler.get_ref(), &index, features)?;
     let retrieve_vectors = RetrieveVectors::new(query.retrieve_vectors, features)?;
 
-    let _permit = search_queue.try_get_search_permit().await?;
-    let search_result = tokio::task::spawn_blocking(move || {
+    let permit = search_queue.try_get_search_permit().await?;
+    let mut search_result = tokio::task::spawn_blocking(move || {
         perform_search(&index, query, search_kind, retrieve_vectors, index_scheduler.features())
     })
     .await?;
+    drop(permit);
+    if let Ok(ref mut search_result) = search_result {
+        rerank_search_result(search_result).await;
+    }
     if let Ok(ref search_result) = search_result {
         aggregate.succeed(search_result);
         if search_result.degraded {
             MEILISEARCH_DEGRADED_SEARCH_REQUESTS.inc();
         }
+        if let Some((q, filter)) = logged_query {
+            search_log.record(&index_uid, q, filter, search_result);
+        }
+        if let Some(search_event) = search_event {
+            search_event.record(&index_uid, search_result);
+        }
     }
     analytics.post_search(aggregate);
 
@@ -295,6 +406,117 @@ pub async fn search_with_post(
     Ok(HttpResponse::Ok().json(search_result))
 }
 
+/// The hits sent in the `partial` events of a streamed search.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PartialHits {
+    hits: Vec<SearchHit>,
+    /// The number of hits received so far, this batch included.
+    received_hits: usize,
+}
+
+/// Formats a server-sent event out of its name and JSON data.
+fn sse_event(event: &str, data: &impl Serialize) -> Bytes {
+    let data = serde_json::to_string(data).unwrap();
+    Bytes::from(format!("event: {event}\ndata: {data}\n\n"))
+}
+
+/// Streams the hits of a search as server-sent events.
+///
+/// The hits are sent in `partial` events as soon as the ranking rules buckets containing
+/// them are resolved, then the complete search result is sent in a last `complete` event,
+/// or an `error` event if the search failed.
+pub async fn search_stream(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::SEARCH }>, Data<IndexScheduler>>,
+    search_queue: web::Data<SearchQueue>,
+    search_log: web::Data<SearchLog>,
+    query_limits: web::Data<QueryLimits>,
+    index_uid: web::Path<String>,
+    params: AwebJson<SearchQuery, DeserrJsonError>,
+    consistency: AwebQueryParameter<SearchPostQuery, DeserrQueryParamError>,
+    req: HttpRequest,
+    analytics: web::Data<dyn Analytics>,
+) -> Result<HttpResponse, ResponseError> {
+    let index_uid = IndexUid::try_from(index_uid.into_inner())?;
+
+    let mut query = params.into_inner();
+    debug!(parameters = ?query, "Search stream");
+    query_limits.check(query.q.as_deref())?;
+    let search_event = PendingSearchEvent::new(&index_scheduler, &query);
+
+    // Tenant token search_rules.
+    if let Some(search_rules) = index_scheduler.filters().get_index_search_rules(&index_uid) {
+        add_search_rules(&mut query.filter, search_rules);
+    }
+
+    let mut aggregate = SearchAggregator::from_query(&query, &req);
+    let logged_query = search_log.is_enabled().then(|| (query.q.clone(), query.filter.clone()));
+
+    wait_for_consistency(&index_scheduler, consistency.min_consistency.map(|uid| uid.0)).await?;
+    let index = index_scheduler.index(&index_uid)?;
+
+    let features = index_scheduler.features();
+
+    let search_kind = search_kind(&query, index_scheduler.get_ref(), &index, features)?;
+    let retrieve_vectors = RetrieveVectors::new(query.retrieve_vectors, features)?;
+
+    let permit = search_queue.try_get_search_permit().await?;
+    let (sender, receiver) = mpsc::unbounded_channel();
+    let search = tokio::task::spawn_blocking(move || {
+        let _permit = permit;
+        let received_hits = Cell::new(0);
+        let on_partial_hits: &dyn Fn(Vec<SearchHit>) = &|hits| {
+            received_hits.set(received_hits.get() + hits.len());
+            let partial_hits = PartialHits { hits, received_hits: received_hits.get() };
+            // the search goes on even if the client went away
+            let _ = sender.send(sse_event("partial", &partial_hits));
+        };
+        perform_search_with_partial_hits(
+            &index,
+            query,
+            search_kind,
+            retrieve_vectors,
+            features,
+            Some(on_partial_hits),
+        )
+    });
+
+    let partial_events = futures::stream::unfold(receiver, |mut receiver| async move {
+        receiver.recv().await.map(|event| (event, receiver))
+    });
+    // sent once the search is done, after all the partial events
+    let complete_event = futures::stream::once(async move {
+        let mut search_result = search.await.map_err(ResponseError::from).and_then(|result| result);
+        if let Ok(ref mut search_result) = search_result {
+            rerank_search_result(search_result).await;
+        }
+        if let Ok(ref search_result) = search_result {
+            aggregate.succeed(search_result);
+            if search_result.degraded {
+                MEILISEARCH_DEGRADED_SEARCH_REQUESTS.inc();
+            }
+            if let Some((q, filter)) = logged_query {
+                search_log.record(&index_uid, q, filter, search_result);
+            }
+            if let Some(search_event) = search_event {
+                search_event.record(&index_uid, search_result);
+            }
+        }
+        analytics.post_search(aggregate);
+
+        match search_result {
+            Ok(search_result) => {
+                debug!(returns = ?search_result, "Search stream");
+                sse_event("complete", &search_result)
+            }
+            Err(error) => sse_event("error", &error),
+        }
+    });
+    let stream = partial_events.chain(complete_event).map(Ok::<_, ResponseError>);
+
+    Ok(HttpResponse::Ok().content_type("text/event-stream").streaming(stream))
+}
+
 pub fn search_kind(
     query: &SearchQuery,
     index_scheduler: &IndexScheduler,
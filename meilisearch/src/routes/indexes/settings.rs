@@ -5,14 +5,19 @@ use index_scheduler::IndexScheduler;
 use meilisearch_types::deserr::DeserrJsonError;
 use meilisearch_types::error::ResponseError;
 use meilisearch_types::facet_values_sort::FacetValuesSort;
+use meilisearch_types::heed;
 use meilisearch_types::index_uid::IndexUid;
+use meilisearch_types::milli;
 use meilisearch_types::milli::update::Setting;
-use meilisearch_types::settings::{settings, RankingRuleView, SecretPolicy, Settings, Unchecked};
+use meilisearch_types::settings::{
+    apply_settings_to_builder, settings, RankingRuleView, SecretPolicy, Settings, Unchecked,
+};
 use meilisearch_types::tasks::KindWithContent;
 use serde_json::json;
 use tracing::debug;
 
 use crate::analytics::Analytics;
+use crate::error::MeilisearchHttpError;
 use crate::extractors::authentication::policies::*;
 use crate::extractors::authentication::GuardedData;
 use crate::routes::{get_task_id, is_dry_run, SummarizedTaskView};
@@ -203,6 +208,31 @@ make_setting_route!(
     }
 );
 
+make_setting_route!(
+    "/ignored-attributes",
+    put,
+    std::collections::BTreeSet<String>,
+    meilisearch_types::deserr::DeserrJsonError<
+        meilisearch_types::error::deserr_codes::InvalidSettingsIgnoredAttributes,
+    >,
+    ignored_attributes,
+    "ignoredAttributes",
+    analytics,
+    |setting: &Option<std::collections::BTreeSet<String>>, req: &HttpRequest| {
+        use serde_json::json;
+
+        analytics.publish(
+            "IgnoredAttributes Updated".to_string(),
+            json!({
+                "ignored_attributes": {
+                    "total": setting.as_ref().map(|ignored| ignored.len()),
+                },
+            }),
+            Some(req),
+        );
+    }
+);
+
 make_setting_route!(
     "/displayed-attributes",
     put,
@@ -267,6 +297,7 @@ make_setting_route!(
                             .set()
                             .map(|s| s.two_typos.set()))
                         .flatten(),
+                    "typo_penalty": setting.as_ref().and_then(|s| s.typo_penalty.as_ref().set()),
                 },
             }),
             Some(req),
@@ -519,6 +550,7 @@ make_setting_route!(
                     "attribute_position": setting.as_ref().map(|rr| rr.iter().position(|s| matches!(s, meilisearch_types::settings::RankingRuleView::Attribute))),
                     "sort_position": setting.as_ref().map(|rr| rr.iter().position(|s| matches!(s, meilisearch_types::settings::RankingRuleView::Sort))),
                     "exactness_position": setting.as_ref().map(|rr| rr.iter().position(|s| matches!(s, meilisearch_types::settings::RankingRuleView::Exactness))),
+                    "recency_position": setting.as_ref().map(|rr| rr.iter().position(|s| matches!(s, meilisearch_types::settings::RankingRuleView::Recency))),
                     "values": setting.as_ref().map(|rr| rr.iter().filter(|s| matches!(s, meilisearch_types::settings::RankingRuleView::Asc(_) | meilisearch_types::settings::RankingRuleView::Desc(_)) ).map(|x| x.to_string()).collect::<Vec<_>>().join(", ")),
                 }
             }),
@@ -550,6 +582,7 @@ make_setting_route!(
                         s.sort_facet_values_by.as_ref().set().map(|s| s.iter().any(|(k, v)| k == "*" && v == &FacetValuesSort::Count))
                     }),
                     "sort_facet_values_by_total": setting.as_ref().and_then(|s| s.sort_facet_values_by.as_ref().set().map(|s| s.len())),
+                    "normalization_case_folding": setting.as_ref().and_then(|s| s.normalization.as_ref().set().and_then(|n| n.case_folding.set())),
                 },
             }),
             Some(req),
@@ -664,6 +697,600 @@ make_setting_route!(
     }
 );
 
+make_setting_route!(
+    "/split-and-concat-words",
+    put,
+    bool,
+    meilisearch_types::deserr::DeserrJsonError<
+        meilisearch_types::error::deserr_codes::InvalidSettingsSplitAndConcatWords,
+    >,
+    split_and_concat_words,
+    "splitAndConcatWords",
+    analytics,
+    |setting: &Option<bool>, req: &HttpRequest| {
+        analytics.publish(
+            "Split And Concat Words Updated".to_string(),
+            serde_json::json!({"split_and_concat_words": setting }),
+            Some(req),
+        );
+    }
+);
+
+make_setting_route!(
+    "/max-facet-values-per-attribute",
+    put,
+    u64,
+    meilisearch_types::deserr::DeserrJsonError<
+        meilisearch_types::error::deserr_codes::InvalidSettingsMaxFacetValuesPerAttribute,
+    >,
+    max_facet_values_per_attribute,
+    "maxFacetValuesPerAttribute",
+    analytics,
+    |setting: &Option<u64>, req: &HttpRequest| {
+        analytics.publish(
+            "Max Facet Values Per Attribute Updated".to_string(),
+            serde_json::json!({"max_facet_values_per_attribute": setting }),
+            Some(req),
+        );
+    }
+);
+
+make_setting_route!(
+    "/recency-attribute",
+    put,
+    String,
+    meilisearch_types::deserr::DeserrJsonError<
+        meilisearch_types::error::deserr_codes::InvalidSettingsRecencyAttribute,
+    >,
+    recency_attribute,
+    "recencyAttribute",
+    analytics,
+    |recency: &Option<String>, req: &HttpRequest| {
+        use serde_json::json;
+        analytics.publish(
+            "RecencyAttribute Updated".to_string(),
+            json!({
+                "recency_attribute": {
+                    "set": recency.is_some(),
+                }
+            }),
+            Some(req),
+        );
+    }
+);
+
+make_setting_route!(
+    "/content-types",
+    put,
+    std::collections::BTreeMap<String, meilisearch_types::settings::ContentTypeView>,
+    meilisearch_types::deserr::DeserrJsonError<
+        meilisearch_types::error::deserr_codes::InvalidSettingsContentTypes,
+    >,
+    content_types,
+    "contentTypes",
+    analytics,
+    |content_types: &Option<std::collections::BTreeMap<String, meilisearch_types::settings::ContentTypeView>>, req: &HttpRequest| {
+        use serde_json::json;
+        analytics.publish(
+            "Content Types Updated".to_string(),
+            json!({
+                "content_types": {
+                    "total": content_types.as_ref().map(|content_types| content_types.len()),
+                }
+            }),
+            Some(req),
+        );
+    }
+);
+
+make_setting_route!(
+    "/read-only",
+    put,
+    bool,
+    meilisearch_types::deserr::DeserrJsonError<
+        meilisearch_types::error::deserr_codes::InvalidSettingsReadOnly,
+    >,
+    read_only,
+    "readOnly",
+    analytics,
+    |setting: &Option<bool>, req: &HttpRequest| {
+        analytics.publish(
+            "Read Only Updated".to_string(),
+            serde_json::json!({"read_only": setting }),
+            Some(req),
+        );
+    }
+);
+
+make_setting_route!(
+    "/attribute-transforms",
+    put,
+    std::collections::BTreeMap<String, Vec<meilisearch_types::settings::AttributeTransformView>>,
+    meilisearch_types::deserr::DeserrJsonError<
+        meilisearch_types::error::deserr_codes::InvalidSettingsAttributeTransforms,
+    >,
+    attribute_transforms,
+    "attributeTransforms",
+    analytics,
+    |attribute_transforms: &Option<std::collections::BTreeMap<String, Vec<meilisearch_types::settings::AttributeTransformView>>>, req: &HttpRequest| {
+        use serde_json::json;
+        analytics.publish(
+            "Attribute Transforms Updated".to_string(),
+            json!({
+                "attribute_transforms": {
+                    "total": attribute_transforms.as_ref().map(|attribute_transforms| attribute_transforms.len()),
+                }
+            }),
+            Some(req),
+        );
+    }
+);
+
+make_setting_route!(
+    "/decimal-attributes",
+    put,
+    std::collections::BTreeSet<String>,
+    meilisearch_types::deserr::DeserrJsonError<
+        meilisearch_types::error::deserr_codes::InvalidSettingsDecimalAttributes,
+    >,
+    decimal_attributes,
+    "decimalAttributes",
+    analytics,
+    |setting: &Option<std::collections::BTreeSet<String>>, req: &HttpRequest| {
+        use serde_json::json;
+
+        analytics.publish(
+            "DecimalAttributes Updated".to_string(),
+            json!({
+                "decimal_attributes": {
+                    "total": setting.as_ref().map(|decimal| decimal.len()),
+                },
+            }),
+            Some(req),
+        );
+    }
+);
+
+make_setting_route!(
+    "/attributes-prefix-search",
+    put,
+    std::collections::BTreeMap<String, meilisearch_types::settings::AttributePrefixSearchView>,
+    meilisearch_types::deserr::DeserrJsonError<
+        meilisearch_types::error::deserr_codes::InvalidSettingsAttributesPrefixSearch,
+    >,
+    attributes_prefix_search,
+    "attributesPrefixSearch",
+    analytics,
+    |setting: &Option<std::collections::BTreeMap<String, meilisearch_types::settings::AttributePrefixSearchView>>, req: &HttpRequest| {
+        use serde_json::json;
+
+        analytics.publish(
+            "AttributesPrefixSearch Updated".to_string(),
+            json!({
+                "attributes_prefix_search": {
+                    "total": setting.as_ref().map(|rules| rules.len()),
+                    "disabled": setting.as_ref().map(|rules| rules.values().filter(|rule| !rule.enabled).count()),
+                },
+            }),
+            Some(req),
+        );
+    }
+);
+
+make_setting_route!(
+    "/max-documents",
+    put,
+    u64,
+    meilisearch_types::deserr::DeserrJsonError<
+        meilisearch_types::error::deserr_codes::InvalidSettingsMaxDocuments,
+    >,
+    max_documents,
+    "maxDocuments",
+    analytics,
+    |setting: &Option<u64>, req: &HttpRequest| {
+        analytics.publish(
+            "Max Documents Updated".to_string(),
+            serde_json::json!({"max_documents": setting }),
+            Some(req),
+        );
+    }
+);
+
+make_setting_route!(
+    "/max-prefix-typos",
+    put,
+    u8,
+    meilisearch_types::deserr::DeserrJsonError<
+        meilisearch_types::error::deserr_codes::InvalidSettingsMaxPrefixTypos,
+    >,
+    max_prefix_typos,
+    "maxPrefixTypos",
+    analytics,
+    |setting: &Option<u8>, req: &HttpRequest| {
+        analytics.publish(
+            "Max Prefix Typos Updated".to_string(),
+            serde_json::json!({"max_prefix_typos": setting }),
+            Some(req),
+        );
+    }
+);
+
+make_setting_route!(
+    "/min-prefix-length",
+    put,
+    u8,
+    meilisearch_types::deserr::DeserrJsonError<
+        meilisearch_types::error::deserr_codes::InvalidSettingsMinPrefixLength,
+    >,
+    min_prefix_length,
+    "minPrefixLength",
+    analytics,
+    |setting: &Option<u8>, req: &HttpRequest| {
+        analytics.publish(
+            "Min Prefix Length Updated".to_string(),
+            serde_json::json!({"min_prefix_length": setting }),
+            Some(req),
+        );
+    }
+);
+
+make_setting_route!(
+    "/stemming",
+    put,
+    Vec<meilisearch_types::locales::Locale>,
+    meilisearch_types::deserr::DeserrJsonError<
+        meilisearch_types::error::deserr_codes::InvalidSettingsStemming,
+    >,
+    stemming,
+    "stemming",
+    analytics,
+    |setting: &Option<Vec<meilisearch_types::locales::Locale>>, req: &HttpRequest| {
+        analytics.publish(
+            "Stemming Updated".to_string(),
+            serde_json::json!({"stemming": setting.as_ref().map(|locales| locales.len()) }),
+            Some(req),
+        );
+    }
+);
+
+make_setting_route!(
+    "/max-flatten-depth",
+    put,
+    usize,
+    meilisearch_types::deserr::DeserrJsonError<
+        meilisearch_types::error::deserr_codes::InvalidSettingsMaxFlattenDepth,
+    >,
+    max_flatten_depth,
+    "maxFlattenDepth",
+    analytics,
+    |setting: &Option<usize>, req: &HttpRequest| {
+        analytics.publish(
+            "Max Flatten Depth Updated".to_string(),
+            serde_json::json!({"max_flatten_depth": setting }),
+            Some(req),
+        );
+    }
+);
+
+make_setting_route!(
+    "/default-language",
+    put,
+    meilisearch_types::locales::Locale,
+    meilisearch_types::deserr::DeserrJsonError<
+        meilisearch_types::error::deserr_codes::InvalidSettingsDefaultLanguage,
+    >,
+    default_language,
+    "defaultLanguage",
+    analytics,
+    |setting: &Option<meilisearch_types::locales::Locale>, req: &HttpRequest| {
+        analytics.publish(
+            "Default Language Updated".to_string(),
+            serde_json::json!({"default_language": setting }),
+            Some(req),
+        );
+    }
+);
+
+make_setting_route!(
+    "/language-hint-field",
+    put,
+    String,
+    meilisearch_types::deserr::DeserrJsonError<
+        meilisearch_types::error::deserr_codes::InvalidSettingsLanguageHintField,
+    >,
+    language_hint_field,
+    "languageHintField",
+    analytics,
+    |setting: &Option<String>, req: &HttpRequest| {
+        analytics.publish(
+            "Language Hint Field Updated".to_string(),
+            serde_json::json!({"language_hint_field": setting.is_some() }),
+            Some(req),
+        );
+    }
+);
+
+make_setting_route!(
+    "/duplicate-key-strategy",
+    put,
+    meilisearch_types::settings::DuplicateKeyStrategyView,
+    meilisearch_types::deserr::DeserrJsonError<
+        meilisearch_types::error::deserr_codes::InvalidSettingsDuplicateKeyStrategy,
+    >,
+    duplicate_key_strategy,
+    "duplicateKeyStrategy",
+    analytics,
+    |setting: &Option<meilisearch_types::settings::DuplicateKeyStrategyView>, req: &HttpRequest| {
+        analytics.publish(
+            "Duplicate Key Strategy Updated".to_string(),
+            serde_json::json!({"duplicate_key_strategy": setting.unwrap_or_default() }),
+            Some(req),
+        );
+    }
+);
+
+make_setting_route!(
+    "/tokenizer-profiles",
+    put,
+    std::collections::BTreeMap<String, meilisearch_types::settings::TokenizerProfileView>,
+    meilisearch_types::deserr::DeserrJsonError<
+        meilisearch_types::error::deserr_codes::InvalidSettingsTokenizerProfiles,
+    >,
+    tokenizer_profiles,
+    "tokenizerProfiles",
+    analytics,
+    |tokenizer_profiles: &Option<std::collections::BTreeMap<String, meilisearch_types::settings::TokenizerProfileView>>, req: &HttpRequest| {
+        use serde_json::json;
+        analytics.publish(
+            "Tokenizer Profiles Updated".to_string(),
+            json!({
+                "tokenizer_profiles": {
+                    "total": tokenizer_profiles.as_ref().map(|tokenizer_profiles| tokenizer_profiles.len()),
+                }
+            }),
+            Some(req),
+        );
+    }
+);
+
+make_setting_route!(
+    "/pinned-results",
+    put,
+    Vec<meilisearch_types::settings::PinnedResultsRuleView>,
+    meilisearch_types::deserr::DeserrJsonError<
+        meilisearch_types::error::deserr_codes::InvalidSettingsPinnedResults,
+    >,
+    pinned_results,
+    "pinnedResults",
+    analytics,
+    |pinned_results: &Option<Vec<meilisearch_types::settings::PinnedResultsRuleView>>, req: &HttpRequest| {
+        use serde_json::json;
+        analytics.publish(
+            "Pinned Results Updated".to_string(),
+            json!({
+                "pinned_results": {
+                    "total": pinned_results.as_ref().map(|pinned_results| pinned_results.len()),
+                }
+            }),
+            Some(req),
+        );
+    }
+);
+
+make_setting_route!(
+    "/reserve-document-ids",
+    put,
+    bool,
+    meilisearch_types::deserr::DeserrJsonError<
+        meilisearch_types::error::deserr_codes::InvalidSettingsReserveDocumentIds,
+    >,
+    reserve_document_ids,
+    "reserveDocumentIds",
+    analytics,
+    |setting: &Option<bool>, req: &HttpRequest| {
+        analytics.publish(
+            "Reserve Document Ids Updated".to_string(),
+            serde_json::json!({"reserve_document_ids": setting }),
+            Some(req),
+        );
+    }
+);
+
+make_setting_route!(
+    "/user-metadata",
+    put,
+    serde_json::Value,
+    meilisearch_types::deserr::DeserrJsonError<
+        meilisearch_types::error::deserr_codes::InvalidSettingsUserMetadata,
+    >,
+    user_metadata,
+    "userMetadata",
+    analytics,
+    |setting: &Option<serde_json::Value>, req: &HttpRequest| {
+        analytics.publish(
+            "User Metadata Updated".to_string(),
+            serde_json::json!({"user_metadata": setting.is_some() }),
+            Some(req),
+        );
+    }
+);
+
+make_setting_route!(
+    "/store-max-length",
+    put,
+    std::collections::BTreeMap<String, usize>,
+    meilisearch_types::deserr::DeserrJsonError<
+        meilisearch_types::error::deserr_codes::InvalidSettingsStoreMaxLength,
+    >,
+    store_max_length,
+    "storeMaxLength",
+    analytics,
+    |setting: &Option<std::collections::BTreeMap<String, usize>>, req: &HttpRequest| {
+        analytics.publish(
+            "Store Max Length Updated".to_string(),
+            serde_json::json!({"store_max_length": setting.as_ref().map(|lengths| lengths.len()) }),
+            Some(req),
+        );
+    }
+);
+
+make_setting_route!(
+    "/normalized-facet-attributes",
+    put,
+    std::collections::BTreeSet<String>,
+    meilisearch_types::deserr::DeserrJsonError<
+        meilisearch_types::error::deserr_codes::InvalidSettingsNormalizedFacetAttributes,
+    >,
+    normalized_facet_attributes,
+    "normalizedFacetAttributes",
+    analytics,
+    |setting: &Option<std::collections::BTreeSet<String>>, req: &HttpRequest| {
+        analytics.publish(
+            "Normalized Facet Attributes Updated".to_string(),
+            serde_json::json!({"normalized_facet_attributes": setting.as_ref().map(|attributes| attributes.len()) }),
+            Some(req),
+        );
+    }
+);
+
+make_setting_route!(
+    "/blocked-documents",
+    put,
+    std::collections::BTreeSet<String>,
+    meilisearch_types::deserr::DeserrJsonError<
+        meilisearch_types::error::deserr_codes::InvalidSettingsBlockedDocuments,
+    >,
+    blocked_documents,
+    "blockedDocuments",
+    analytics,
+    |setting: &Option<std::collections::BTreeSet<String>>, req: &HttpRequest| {
+        analytics.publish(
+            "Blocked Documents Updated".to_string(),
+            serde_json::json!({"blocked_documents": setting.as_ref().map(|documents_ids| documents_ids.len()) }),
+            Some(req),
+        );
+    }
+);
+
+make_setting_route!(
+    "/number-tokenization",
+    put,
+    meilisearch_types::settings::NumberTokenizationView,
+    meilisearch_types::deserr::DeserrJsonError<
+        meilisearch_types::error::deserr_codes::InvalidSettingsNumberTokenization,
+    >,
+    number_tokenization,
+    "numberTokenization",
+    analytics,
+    |setting: &Option<meilisearch_types::settings::NumberTokenizationView>, req: &HttpRequest| {
+        analytics.publish(
+            "Number Tokenization Updated".to_string(),
+            serde_json::json!({"number_tokenization": setting.unwrap_or_default() }),
+            Some(req),
+        );
+    }
+);
+
+make_setting_route!(
+    "/enable-formatting",
+    put,
+    bool,
+    meilisearch_types::deserr::DeserrJsonError<
+        meilisearch_types::error::deserr_codes::InvalidSettingsEnableFormatting,
+    >,
+    enable_formatting,
+    "enableFormatting",
+    analytics,
+    |setting: &Option<bool>, req: &HttpRequest| {
+        analytics.publish(
+            "Enable Formatting Updated".to_string(),
+            serde_json::json!({"enable_formatting": setting }),
+            Some(req),
+        );
+    }
+);
+
+make_setting_route!(
+    "/recency-half-life-days",
+    put,
+    u32,
+    meilisearch_types::deserr::DeserrJsonError<
+        meilisearch_types::error::deserr_codes::InvalidSettingsRecencyHalfLifeDays,
+    >,
+    recency_half_life_days,
+    "recencyHalfLifeDays",
+    analytics,
+    |setting: &Option<u32>, req: &HttpRequest| {
+        analytics.publish(
+            "Recency Half Life Days Updated".to_string(),
+            serde_json::json!({"recency_half_life_days": setting }),
+            Some(req),
+        );
+    }
+);
+
+make_setting_route!(
+    "/reranker",
+    put,
+    meilisearch_types::milli::RerankerSettings,
+    meilisearch_types::deserr::DeserrJsonError<
+        meilisearch_types::error::deserr_codes::InvalidSettingsReranker,
+    >,
+    reranker,
+    "reranker",
+    analytics,
+    |setting: &Option<meilisearch_types::milli::RerankerSettings>, req: &HttpRequest| {
+        analytics.publish(
+            "Reranker Updated".to_string(),
+            serde_json::json!({
+                "reranker": {
+                    "set": setting.is_some(),
+                    "candidates": setting.as_ref().map(|reranker| reranker.candidates),
+                },
+            }),
+            Some(req),
+        );
+    }
+);
+
+make_setting_route!(
+    "/document-compression",
+    put,
+    bool,
+    meilisearch_types::deserr::DeserrJsonError<
+        meilisearch_types::error::deserr_codes::InvalidSettingsDocumentCompression,
+    >,
+    document_compression,
+    "documentCompression",
+    analytics,
+    |setting: &Option<bool>, req: &HttpRequest| {
+        analytics.publish(
+            "Document Compression Updated".to_string(),
+            serde_json::json!({"document_compression": setting }),
+            Some(req),
+        );
+    }
+);
+
+make_setting_route!(
+    "/max-synonym-expansions",
+    put,
+    usize,
+    meilisearch_types::deserr::DeserrJsonError<
+        meilisearch_types::error::deserr_codes::InvalidSettingsMaxSynonymExpansions,
+    >,
+    max_synonym_expansions,
+    "maxSynonymExpansions",
+    analytics,
+    |setting: &Option<usize>, req: &HttpRequest| {
+        analytics.publish(
+            "Max Synonym Expansions Updated".to_string(),
+            serde_json::json!({"max_synonym_expansions": setting }),
+            Some(req),
+        );
+    }
+);
+
 macro_rules! generate_configure {
     ($($mod:ident),*) => {
         pub fn configure(cfg: &mut web::ServiceConfig) {
@@ -673,6 +1300,10 @@ macro_rules! generate_configure {
                 .route(web::patch().to(SeqHandler(update_all)))
                 .route(web::get().to(SeqHandler(get_all)))
                 .route(web::delete().to(SeqHandler(delete_all))))
+                .service(web::resource("/export").route(web::get().to(SeqHandler(get_all))))
+                .service(web::resource("/import").route(web::post().to(SeqHandler(import_all))))
+                .service(web::resource("/estimate").route(web::post().to(SeqHandler(estimate_all))))
+                .service(web::resource("/validate").route(web::post().to(SeqHandler(validate_all))))
                 $(.service($mod::resources()))*;
         }
     };
@@ -695,7 +1326,37 @@ generate_configure!(
     pagination,
     faceting,
     embedders,
-    search_cutoff_ms
+    search_cutoff_ms,
+    ignored_attributes,
+    split_and_concat_words,
+    recency_attribute,
+    max_facet_values_per_attribute,
+    content_types,
+    read_only,
+    attribute_transforms,
+    decimal_attributes,
+    attributes_prefix_search,
+    max_documents,
+    max_prefix_typos,
+    min_prefix_length,
+    stemming,
+    max_flatten_depth,
+    default_language,
+    language_hint_field,
+    duplicate_key_strategy,
+    tokenizer_profiles,
+    pinned_results,
+    reserve_document_ids,
+    user_metadata,
+    store_max_length,
+    normalized_facet_attributes,
+    blocked_documents,
+    number_tokenization,
+    enable_formatting,
+    recency_half_life_days,
+    reranker,
+    document_compression,
+    max_synonym_expansions
 );
 
 pub async fn update_all(
@@ -722,6 +1383,7 @@ pub async fn update_all(
                 "attribute_position": new_settings.ranking_rules.as_ref().set().map(|rr| rr.iter().position(|s| matches!(s, RankingRuleView::Attribute))),
                 "sort_position": new_settings.ranking_rules.as_ref().set().map(|rr| rr.iter().position(|s| matches!(s, RankingRuleView::Sort))),
                 "exactness_position": new_settings.ranking_rules.as_ref().set().map(|rr| rr.iter().position(|s| matches!(s, RankingRuleView::Exactness))),
+                "recency_position": new_settings.ranking_rules.as_ref().set().map(|rr| rr.iter().position(|s| matches!(s, RankingRuleView::Recency))),
                 "values": new_settings.ranking_rules.as_ref().set().map(|rr| rr.iter().filter(|s| !matches!(s, RankingRuleView::Asc(_) | RankingRuleView::Desc(_)) ).map(|x| x.to_string()).collect::<Vec<_>>().join(", ")),
             },
             "searchable_attributes": {
@@ -740,9 +1402,15 @@ pub async fn update_all(
                 "total": new_settings.filterable_attributes.as_ref().set().map(|filter| filter.len()),
                 "has_geo": new_settings.filterable_attributes.as_ref().set().map(|filter| filter.iter().any(|s| s == "_geo")),
             },
+           "ignored_attributes": {
+                "total": new_settings.ignored_attributes.as_ref().set().map(|ignored| ignored.len()),
+            },
             "distinct_attribute": {
                 "set": new_settings.distinct_attribute.as_ref().set().is_some()
             },
+            "recency_attribute": {
+                "set": new_settings.recency_attribute.as_ref().set().is_some()
+            },
             "proximity_precision": {
                 "set": new_settings.proximity_precision.as_ref().set().is_some(),
                 "value": new_settings.proximity_precision.as_ref().set().copied().unwrap_or_default()
@@ -777,6 +1445,10 @@ pub async fn update_all(
                         .set()
                         .map(|s| s.two_typos.set()))
                     .flatten(),
+                "typo_penalty": new_settings.typo_tolerance
+                    .as_ref()
+                    .set()
+                    .and_then(|s| s.typo_penalty.as_ref().set()),
             },
             "faceting": {
                 "max_values_per_facet": new_settings.faceting
@@ -793,6 +1465,10 @@ pub async fn update_all(
                     .as_ref()
                     .set()
                     .and_then(|s| s.sort_facet_values_by.as_ref().set().map(|s| s.len())),
+                "normalization_case_folding": new_settings.faceting
+                    .as_ref()
+                    .set()
+                    .and_then(|s| s.normalization.as_ref().set().and_then(|n| n.case_folding.set())),
             },
             "pagination": {
                 "max_total_hits": new_settings.pagination
@@ -808,6 +1484,50 @@ pub async fn update_all(
             },
             "embedders": crate::routes::indexes::settings::embedder_analytics(new_settings.embedders.as_ref().set()),
             "search_cutoff_ms": new_settings.search_cutoff_ms.as_ref().set(),
+            "split_and_concat_words": new_settings.split_and_concat_words.as_ref().set(),
+            "max_facet_values_per_attribute": new_settings.max_facet_values_per_attribute.as_ref().set(),
+            "max_documents": new_settings.max_documents.as_ref().set(),
+            "max_prefix_typos": new_settings.max_prefix_typos.as_ref().set(),
+            "min_prefix_length": new_settings.min_prefix_length.as_ref().set(),
+            "stemming": new_settings.stemming.as_ref().set(),
+            "max_flatten_depth": new_settings.max_flatten_depth.as_ref().set(),
+            "default_language": new_settings.default_language.as_ref().set(),
+            "language_hint_field": new_settings.language_hint_field.as_ref().set(),
+            "duplicate_key_strategy": new_settings.duplicate_key_strategy.as_ref().set(),
+            "tokenizer_profiles": {
+                "total": new_settings.tokenizer_profiles.as_ref().set().map(|profiles| profiles.len()),
+            },
+            "pinned_results": {
+                "total": new_settings.pinned_results.as_ref().set().map(|rules| rules.len()),
+            },
+            "reserve_document_ids": new_settings.reserve_document_ids.as_ref().set(),
+            "user_metadata": new_settings.user_metadata.as_ref().set(),
+            "store_max_length": new_settings.store_max_length.as_ref().set().map(|lengths| lengths.len()),
+            "normalized_facet_attributes": new_settings.normalized_facet_attributes.as_ref().set().map(|attributes| attributes.len()),
+            "blocked_documents": new_settings.blocked_documents.as_ref().set().map(|documents_ids| documents_ids.len()),
+            "number_tokenization": new_settings.number_tokenization.as_ref().set(),
+            "enable_formatting": new_settings.enable_formatting.as_ref().set(),
+            "recency_half_life_days": new_settings.recency_half_life_days.as_ref().set(),
+            "reranker": {
+                "set": new_settings.reranker.as_ref().set().is_some(),
+                "candidates": new_settings.reranker.as_ref().set().map(|reranker| reranker.candidates),
+            },
+            "document_compression": new_settings.document_compression.as_ref().set(),
+            "max_synonym_expansions": new_settings.max_synonym_expansions.as_ref().set(),
+            "content_types": {
+                "total": new_settings.content_types.as_ref().set().map(|content_types| content_types.len()),
+            },
+            "read_only": new_settings.read_only.as_ref().set(),
+            "attribute_transforms": {
+                "total": new_settings.attribute_transforms.as_ref().set().map(|attribute_transforms| attribute_transforms.len()),
+            },
+            "decimal_attributes": {
+                "total": new_settings.decimal_attributes.as_ref().set().map(|decimal| decimal.len()),
+            },
+            "attributes_prefix_search": {
+                "total": new_settings.attributes_prefix_search.as_ref().set().map(|rules| rules.len()),
+                "disabled": new_settings.attributes_prefix_search.as_ref().set().map(|rules| rules.values().filter(|rule| !rule.enabled).count()),
+            },
             "locales": new_settings.localized_attributes.as_ref().set().map(|rules| rules.iter().flat_map(|rule| rule.locales.iter().cloned()).collect::<std::collections::BTreeSet<_>>()),
         }),
         Some(&req),
@@ -874,7 +1594,155 @@ pub async fn delete_all(
     Ok(HttpResponse::Accepted().json(task))
 }
 
-fn validate_settings(
+/// Replaces all the settings of the index by the ones exported from `GET /settings/export`.
+///
+/// The settings missing from the payload are reset, so importing the same settings twice
+/// leaves the index in the same state. The secrets are hidden in the export and must be
+/// provided again, the import is refused if one of them is still hidden.
+pub async fn import_all(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::SETTINGS_UPDATE }>, Data<IndexScheduler>>,
+    index_uid: web::Path<String>,
+    body: AwebJson<Settings<Unchecked>, DeserrJsonError>,
+    req: HttpRequest,
+    opt: web::Data<Opt>,
+    analytics: web::Data<dyn Analytics>,
+) -> Result<HttpResponse, ResponseError> {
+    let index_uid = IndexUid::try_from(index_uid.into_inner())?;
+
+    let new_settings = body.into_inner();
+    debug!(parameters = ?new_settings, "Import all settings");
+    if let Some(field) = new_settings.hidden_secret() {
+        return Err(MeilisearchHttpError::HiddenSecretInImport(field).into());
+    }
+    let new_settings = validate_settings(new_settings.or_reset(), &index_scheduler)?;
+
+    analytics.publish("Settings Imported".to_string(), json!({}), Some(&req));
+
+    let allow_index_creation = index_scheduler.filters().allow_index_creation(&index_uid);
+    let index_uid = index_uid.into_inner();
+    let task = KindWithContent::SettingsUpdate {
+        index_uid,
+        new_settings: Box::new(new_settings),
+        is_deletion: false,
+        allow_index_creation,
+    };
+    let uid = get_task_id(&req, &opt)?;
+    let dry_run = is_dry_run(&req, &opt)?;
+    let task: SummarizedTaskView =
+        tokio::task::spawn_blocking(move || index_scheduler.register(task, uid, dry_run))
+            .await??
+            .into();
+
+    debug!(returns = ?task, "Import all settings");
+    Ok(HttpResponse::Accepted().json(task))
+}
+
+/// Estimates the cost of applying the given settings to the index without applying them.
+///
+/// The report contains the number of documents that would be reindexed and the names of the
+/// databases that would be rebuilt.
+pub async fn estimate_all(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::SETTINGS_UPDATE }>, Data<IndexScheduler>>,
+    index_uid: web::Path<String>,
+    body: AwebJson<Settings<Unchecked>, DeserrJsonError>,
+    req: HttpRequest,
+    analytics: web::Data<dyn Analytics>,
+) -> Result<HttpResponse, ResponseError> {
+    let index_uid = IndexUid::try_from(index_uid.into_inner())?;
+
+    let new_settings = body.into_inner();
+    debug!(parameters = ?new_settings, "Estimate all settings");
+    let new_settings = validate_settings(new_settings, &index_scheduler)?;
+
+    analytics.publish("Settings Estimated".to_string(), json!({}), Some(&req));
+
+    let index = index_scheduler.index(&index_uid)?;
+    let estimate = tokio::task::spawn_blocking(move || -> Result<_, ResponseError> {
+        let rtxn = index.read_txn()?;
+        let estimate = milli::update::estimate_settings_update(
+            &index,
+            &rtxn,
+            index_scheduler.indexer_config(),
+            |builder| apply_settings_to_builder(&new_settings.check(), builder),
+        )?;
+        Ok(estimate)
+    })
+    .await??;
+
+    debug!(returns = ?estimate, "Estimate all settings");
+    Ok(HttpResponse::Ok().json(estimate))
+}
+
+/// The map size of the empty index the settings of a missing index are checked against.
+const VALIDATION_INDEX_MAP_SIZE: usize = 100 * 1024 * 1024; // 100 MiB
+
+/// Checks the settings like a settings update task would, without enqueuing it.
+///
+/// The errors are the ones the task would fail with. The settings of a missing index are
+/// checked against an empty index, like the one the task would create.
+pub async fn validate_all(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::SETTINGS_UPDATE }>, Data<IndexScheduler>>,
+    index_uid: web::Path<String>,
+    body: AwebJson<Settings<Unchecked>, DeserrJsonError>,
+    req: HttpRequest,
+    analytics: web::Data<dyn Analytics>,
+) -> Result<HttpResponse, ResponseError> {
+    let index_uid = IndexUid::try_from(index_uid.into_inner())?;
+
+    let new_settings = body.into_inner();
+    debug!(parameters = ?new_settings, "Validate all settings");
+    let new_settings = validate_settings(new_settings, &index_scheduler)?;
+
+    analytics.publish("Settings Validated".to_string(), json!({}), Some(&req));
+
+    let index = match index_scheduler.index(&index_uid) {
+        Ok(index) => Some(index),
+        Err(index_scheduler::Error::IndexNotFound(_)) => None,
+        Err(e) => return Err(e.into()),
+    };
+    tokio::task::spawn_blocking(move || -> Result<_, ResponseError> {
+        let set_settings = |builder: &mut milli::update::Settings| {
+            apply_settings_to_builder(&new_settings.check(), builder)
+        };
+        let indexer_config = index_scheduler.indexer_config();
+        match index {
+            Some(index) => {
+                let rtxn = index.read_txn()?;
+                milli::update::estimate_settings_update(
+                    &index,
+                    &rtxn,
+                    indexer_config,
+                    set_settings,
+                )?;
+            }
+            None => {
+                let dir = tempfile::tempdir()?;
+                let mut options = heed::EnvOpenOptions::new();
+                options.map_size(VALIDATION_INDEX_MAP_SIZE);
+                let index = milli::Index::new(options, dir.path())?;
+                let rtxn = index.read_txn()?;
+                let result = milli::update::estimate_settings_update(
+                    &index,
+                    &rtxn,
+                    indexer_config,
+                    set_settings,
+                );
+                drop(rtxn);
+                // The temporary index must be closed before its directory is removed.
+                index.prepare_for_closing().wait();
+                drop(dir);
+                result?;
+            }
+        }
+        Ok(())
+    })
+    .await??;
+
+    debug!(returns = "valid", "Validate all settings");
+    Ok(HttpResponse::Ok().json(json!({ "valid": true })))
+}
+
+pub(crate) fn validate_settings(
     settings: Settings<Unchecked>,
     index_scheduler: &IndexScheduler,
 ) -> Result<Settings<Unchecked>, ResponseError> {
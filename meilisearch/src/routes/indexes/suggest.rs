@@ -0,0 +1,129 @@
+use actix_web::web::{self, Data};
+use actix_web::{HttpRequest, HttpResponse};
+use deserr::actix_web::AwebQueryParameter;
+use index_scheduler::IndexScheduler;
+use meilisearch_types::deserr::query_params::Param;
+use meilisearch_types::deserr::DeserrQueryParamError;
+use meilisearch_types::error::deserr_codes::*;
+use meilisearch_types::error::{Code, ResponseError};
+use meilisearch_types::heed::RoTxn;
+use meilisearch_types::index_uid::IndexUid;
+use meilisearch_types::milli::{self, Completion, FieldId};
+use meilisearch_types::Index;
+use serde::Serialize;
+use tracing::debug;
+
+use crate::analytics::Analytics;
+use crate::extractors::authentication::policies::*;
+use crate::extractors::authentication::GuardedData;
+use crate::extractors::sequential_extractor::SeqHandler;
+use crate::search::{add_search_rules, parse_filter};
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(web::resource("").route(web::get().to(SeqHandler(suggest))));
+}
+
+const DEFAULT_SUGGEST_LIMIT: usize = 10;
+
+#[derive(Debug, deserr::Deserr)]
+#[deserr(error = DeserrQueryParamError, rename_all = camelCase, deny_unknown_fields)]
+pub struct SuggestQuery {
+    #[deserr(default, error = DeserrQueryParamError<InvalidSuggestQ>)]
+    q: Option<String>,
+    #[deserr(default, error = DeserrQueryParamError<InvalidSuggestAttribute>)]
+    attribute: Option<String>,
+    #[deserr(default = Param(DEFAULT_SUGGEST_LIMIT), error = DeserrQueryParamError<InvalidSuggestLimit>)]
+    limit: Param<usize>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SuggestResult {
+    q: String,
+    suggestions: Vec<Suggestion>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Suggestion {
+    value: String,
+    frequency: u64,
+}
+
+/// Returns the completions of the last word of the query, the ones appearing in the most
+/// documents first.
+pub async fn suggest(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::SEARCH }>, Data<IndexScheduler>>,
+    index_uid: web::Path<String>,
+    params: AwebQueryParameter<SuggestQuery, DeserrQueryParamError>,
+    req: HttpRequest,
+    analytics: web::Data<dyn Analytics>,
+) -> Result<HttpResponse, ResponseError> {
+    let index_uid = IndexUid::try_from(index_uid.into_inner())?;
+    let SuggestQuery { q, attribute, limit } = params.into_inner();
+    debug!(parameters = ?q, ?attribute, "Suggest");
+
+    analytics.publish(
+        "Suggestions Fetched".to_string(),
+        serde_json::json!({ "with_attribute": attribute.is_some(), "limit": limit.0 }),
+        Some(&req),
+    );
+
+    // Tenant token search_rules.
+    let mut filter = None;
+    if let Some(search_rules) = index_scheduler.filters().get_index_search_rules(&index_uid) {
+        add_search_rules(&mut filter, search_rules);
+    }
+
+    let features = index_scheduler.features();
+    let index = index_scheduler.index(&index_uid)?;
+    let q = q.unwrap_or_default();
+
+    let result = tokio::task::spawn_blocking(move || -> Result<_, ResponseError> {
+        let rtxn = index.read_txn()?;
+        let filter = match &filter {
+            Some(filter) => parse_filter(filter, Code::InvalidSearchFilter, features)?,
+            None => None,
+        };
+        let universe = milli::filtered_universe(&index, &rtxn, &filter)?;
+        let fields_ids = match &attribute {
+            Some(attribute) => Some(attribute_fields_ids(&index, &rtxn, attribute)?),
+            None => None,
+        };
+
+        let completions =
+            milli::complete_prefix(&index, &rtxn, &q, fields_ids.as_deref(), &universe, limit.0)?;
+        let suggestions = completions
+            .into_iter()
+            .map(|Completion { value, frequency }| Suggestion { value, frequency })
+            .collect();
+        Ok(SuggestResult { q, suggestions })
+    })
+    .await??;
+
+    debug!(returns = ?result, "Suggest");
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Returns the ids of the searchable fields that are the attribute or are nested in it.
+fn attribute_fields_ids(
+    index: &Index,
+    rtxn: &RoTxn,
+    attribute: &str,
+) -> Result<Vec<FieldId>, ResponseError> {
+    let searchable_fields = index.searchable_fields(rtxn)?;
+    let fields_ids_map = index.fields_ids_map(rtxn)?;
+
+    let matching: Vec<_> =
+        searchable_fields.iter().filter(|field| milli::is_faceted_by(field, attribute)).collect();
+    if matching.is_empty() {
+        return Err(ResponseError::from_msg(
+            format!(
+                "Attribute `{attribute}` is not searchable. Available searchable attributes are: `{}`.",
+                searchable_fields.join(", ")
+            ),
+            Code::InvalidSuggestAttribute,
+        ));
+    }
+
+    Ok(matching.into_iter().filter_map(|field| fields_ids_map.id(field)).collect())
+}
@@ -0,0 +1,172 @@
+use actix_web::web::{Bytes, Data};
+use actix_web::{web, HttpRequest, HttpResponse};
+use fst::{IntoStreamer, Streamer};
+use index_scheduler::{IndexScheduler, RoFeatures};
+use meilisearch_types::error::{Code, ResponseError};
+use meilisearch_types::heed::RoTxn;
+use meilisearch_types::index_uid::IndexUid;
+use meilisearch_types::milli::{self, Filter};
+use meilisearch_types::Index;
+use roaring::RoaringBitmap;
+use serde::Serialize;
+use serde_json::Value;
+use tokio::sync::{mpsc, oneshot};
+use tracing::debug;
+
+use crate::analytics::Analytics;
+use crate::error::MeilisearchHttpError;
+use crate::extractors::authentication::policies::*;
+use crate::extractors::authentication::GuardedData;
+use crate::extractors::sequential_extractor::SeqHandler;
+use crate::search::{add_search_rules, parse_filter};
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(web::resource("").route(web::get().to(SeqHandler(export_vocabulary))));
+}
+
+/// A line of the exported vocabulary.
+#[derive(Serialize)]
+struct WordFrequency<'a> {
+    word: &'a str,
+    frequency: u64,
+}
+
+/// The number of chunks of words that can be waiting to be sent to the client.
+const VOCABULARY_CHANNEL_CAPACITY: usize = 16;
+/// The size after which a chunk of words is sent to the client.
+const VOCABULARY_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Streams the words of the index as NDJSON, in lexicographic order and along with the number
+/// of documents containing them.
+///
+/// The whole export is read from a single transaction and thus reflects a consistent state of
+/// the index, even if documents are indexed in the meantime.
+pub async fn export_vocabulary(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::SEARCH }>, Data<IndexScheduler>>,
+    index_uid: web::Path<String>,
+    req: HttpRequest,
+    analytics: web::Data<dyn Analytics>,
+) -> Result<HttpResponse, ResponseError> {
+    let index_uid = IndexUid::try_from(index_uid.into_inner())?;
+    debug!(index = %index_uid, "Export vocabulary");
+
+    analytics.publish("Vocabulary Exported".to_string(), serde_json::json!({}), Some(&req));
+
+    // Tenant token search_rules.
+    let mut filter = None;
+    if let Some(search_rules) = index_scheduler.filters().get_index_search_rules(&index_uid) {
+        add_search_rules(&mut filter, search_rules);
+    }
+
+    let features = index_scheduler.features();
+    let index = index_scheduler.index(&index_uid)?;
+
+    let (ready_sender, ready_receiver) = oneshot::channel();
+    let (sender, receiver) = mpsc::channel(VOCABULARY_CHANNEL_CAPACITY);
+    tokio::task::spawn_blocking(move || {
+        vocabulary_into(&index, filter, features, ready_sender, sender)
+    });
+
+    // The errors happening before the first word is sent are returned as a regular error response.
+    ready_receiver.await.map_err(|_| {
+        ResponseError::from_msg(
+            "The vocabulary export stopped unexpectedly".to_string(),
+            Code::Internal,
+        )
+    })??;
+
+    let stream = futures::stream::unfold(receiver, |mut receiver| async move {
+        receiver.recv().await.map(|chunk| (chunk, receiver))
+    });
+
+    Ok(HttpResponse::Ok().content_type("application/x-ndjson").streaming(stream))
+}
+
+/// Sends the words of the index in chunks of NDJSON through the sender.
+/// Stops as soon as the client goes away.
+fn vocabulary_into(
+    index: &Index,
+    filter: Option<Value>,
+    features: RoFeatures,
+    ready: oneshot::Sender<Result<(), ResponseError>>,
+    sender: mpsc::Sender<Result<Bytes, ResponseError>>,
+) {
+    // This read transaction is kept for the whole export.
+    let rtxn = match index.read_txn() {
+        Ok(rtxn) => rtxn,
+        Err(e) => {
+            let _ = ready.send(Err(e.into()));
+            return;
+        }
+    };
+
+    let prepared = filter
+        .as_ref()
+        .map(|filter| parse_filter(filter, Code::InvalidSearchFilter, features))
+        .transpose()
+        .map(Option::flatten)
+        .and_then(|filter: Option<Filter>| {
+            let universe = milli::filtered_universe(index, &rtxn, &filter)?;
+            let words_fst = index.words_fst(&rtxn)?;
+            Ok((universe, words_fst))
+        });
+    let (universe, words_fst) = match prepared {
+        Ok(prepared) => prepared,
+        Err(e) => {
+            let _ = ready.send(Err(e));
+            return;
+        }
+    };
+
+    if ready.send(Ok(())).is_err() {
+        return;
+    }
+
+    let mut chunk = Vec::new();
+    let mut stream = words_fst.into_stream();
+    while let Some(word) = stream.next() {
+        let line = match word_frequency(index, &rtxn, word, &universe) {
+            Ok(Some(line)) => line,
+            Ok(None) => continue,
+            Err(e) => {
+                let _ = sender.blocking_send(Err(e));
+                return;
+            }
+        };
+
+        if let Err(e) = serde_json::to_writer(&mut chunk, &line) {
+            let _ = sender.blocking_send(Err(MeilisearchHttpError::from(e).into()));
+            return;
+        }
+        chunk.push(b'\n');
+
+        if chunk.len() >= VOCABULARY_CHUNK_SIZE
+            && sender.blocking_send(Ok(Bytes::from(std::mem::take(&mut chunk)))).is_err()
+        {
+            // the client is gone
+            return;
+        }
+    }
+
+    if !chunk.is_empty() {
+        let _ = sender.blocking_send(Ok(Bytes::from(chunk)));
+    }
+}
+
+/// Returns the word along with the number of documents of the universe containing it,
+/// or `None` if there is none.
+fn word_frequency<'w>(
+    index: &Index,
+    rtxn: &RoTxn,
+    word: &'w [u8],
+    universe: &RoaringBitmap,
+) -> Result<Option<WordFrequency<'w>>, ResponseError> {
+    let word = std::str::from_utf8(word).map_err(milli::Error::from)?;
+    let mut docids = index.word_docids.get(rtxn, word)?.unwrap_or_default();
+    if let Some(exact_docids) = index.exact_word_docids.get(rtxn, word)? {
+        docids |= exact_docids;
+    }
+
+    let frequency = docids.intersection_len(universe);
+    Ok((frequency > 0).then_some(WordFrequency { word, frequency }))
+}
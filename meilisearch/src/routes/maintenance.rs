@@ -0,0 +1,89 @@
+use actix_web::web::{self, Data};
+use actix_web::{HttpRequest, HttpResponse};
+use deserr::actix_web::AwebJson;
+use deserr::Deserr;
+use index_scheduler::IndexScheduler;
+use meilisearch_types::deserr::DeserrJsonError;
+use meilisearch_types::error::deserr_codes::InvalidMaintenanceEnabled;
+use meilisearch_types::error::ResponseError;
+use meilisearch_types::keys::actions;
+use serde::Serialize;
+use serde_json::json;
+use tracing::debug;
+
+use crate::analytics::Analytics;
+use crate::extractors::authentication::policies::ActionPolicy;
+use crate::extractors::authentication::GuardedData;
+use crate::extractors::sequential_extractor::SeqHandler;
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::resource("")
+            .route(web::get().to(SeqHandler(get_maintenance)))
+            .route(web::patch().to(SeqHandler(patch_maintenance))),
+    );
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MaintenanceView {
+    /// Whether the write operations are refused.
+    pub enabled: bool,
+    /// The number of enqueued and processing tasks, the queue is drained once it reaches zero.
+    pub pending_tasks: u64,
+}
+
+impl MaintenanceView {
+    fn new(index_scheduler: &IndexScheduler) -> Result<Self, ResponseError> {
+        Ok(Self {
+            enabled: index_scheduler.is_in_maintenance(),
+            pending_tasks: index_scheduler.pending_tasks()?,
+        })
+    }
+}
+
+async fn get_maintenance(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::MAINTENANCE_GET }>, Data<IndexScheduler>>,
+    req: HttpRequest,
+    analytics: Data<dyn Analytics>,
+) -> Result<HttpResponse, ResponseError> {
+    analytics.publish("Maintenance Seen".to_string(), json!(null), Some(&req));
+
+    let maintenance = MaintenanceView::new(&index_scheduler)?;
+    debug!(returns = ?maintenance, "Get maintenance");
+    Ok(HttpResponse::Ok().json(maintenance))
+}
+
+#[derive(Debug, Deserr)]
+#[deserr(error = DeserrJsonError, rename_all = camelCase, deny_unknown_fields)]
+pub struct MaintenancePatch {
+    #[deserr(default, error = DeserrJsonError<InvalidMaintenanceEnabled>)]
+    pub enabled: Option<bool>,
+}
+
+async fn patch_maintenance(
+    index_scheduler: GuardedData<
+        ActionPolicy<{ actions::MAINTENANCE_UPDATE }>,
+        Data<IndexScheduler>,
+    >,
+    params: AwebJson<MaintenancePatch, DeserrJsonError>,
+    req: HttpRequest,
+    analytics: Data<dyn Analytics>,
+) -> Result<HttpResponse, ResponseError> {
+    let params = params.into_inner();
+    debug!(parameters = ?params, "Patch maintenance");
+
+    if let Some(enabled) = params.enabled {
+        index_scheduler.set_maintenance(enabled);
+    }
+
+    analytics.publish(
+        "Maintenance Updated".to_string(),
+        json!({ "enabled": params.enabled }),
+        Some(&req),
+    );
+
+    let maintenance = MaintenanceView::new(&index_scheduler)?;
+    debug!(returns = ?maintenance, "Patch maintenance");
+    Ok(HttpResponse::Ok().json(maintenance))
+}
@@ -39,6 +39,12 @@ pub async fn get_metrics(
         crate::metrics::MEILISEARCH_INDEX_DOCS_COUNT
             .with_label_values(&[index])
             .set(value.number_of_documents as i64);
+        crate::metrics::MEILISEARCH_INDEX_SIZE_BYTES
+            .with_label_values(&[index])
+            .set(value.database_size as i64);
+        crate::metrics::MEILISEARCH_INDEX_USED_SIZE_BYTES
+            .with_label_values(&[index])
+            .set(value.used_database_size as i64);
     }
 
     for (kind, value) in index_scheduler.get_stats()? {
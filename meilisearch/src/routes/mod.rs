@@ -1,48 +1,70 @@
 use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
 
 use actix_web::web::Data;
 use actix_web::{web, HttpRequest, HttpResponse};
-use index_scheduler::IndexScheduler;
+use deserr::actix_web::AwebQueryParameter;
+use deserr::Deserr;
+use index_scheduler::{IndexScheduler, Query};
 use meilisearch_auth::AuthController;
+use meilisearch_types::deserr::query_params::Param;
+use meilisearch_types::deserr::DeserrQueryParamError;
+use meilisearch_types::document_formats::SkippedDocument;
+use meilisearch_types::error::deserr_codes::*;
 use meilisearch_types::error::{Code, ResponseError};
+use meilisearch_types::index_uid_pattern::IndexUidPattern;
 use meilisearch_types::settings::{Settings, Unchecked};
+use meilisearch_types::star_or::OptionStarOrList;
+use meilisearch_types::task_view::TaskView;
 use meilisearch_types::tasks::{Kind, Status, Task, TaskId};
 use serde::{Deserialize, Serialize};
 use time::OffsetDateTime;
 use tracing::debug;
 
+use crate::error::MeilisearchHttpError;
 use crate::extractors::authentication::policies::*;
 use crate::extractors::authentication::GuardedData;
-use crate::search_queue::SearchQueue;
+use crate::search_queue::{SearchQueue, SearchQueueStats};
+use crate::search_stats::{SearchStats, DEFAULT_TOP_ZERO_RESULT_QUERIES};
 use crate::Opt;
 
 const PAGINATION_DEFAULT_LIMIT: usize = 20;
 
 mod api_key;
+mod batch_settings;
+mod batches;
 mod dump;
 pub mod features;
+mod health;
 pub mod indexes;
 mod logs;
+mod maintenance;
 mod metrics;
 mod multi_search;
 mod snapshot;
 mod swap_indexes;
 pub mod tasks;
+mod webhooks;
 
 pub fn configure(cfg: &mut web::ServiceConfig) {
     cfg.service(web::scope("/tasks").configure(tasks::configure))
-        .service(web::resource("/health").route(web::get().to(get_health)))
+        .service(web::scope("/batches").configure(batches::configure))
+        .service(web::scope("/health").configure(health::configure))
         .service(web::scope("/logs").configure(logs::configure))
         .service(web::scope("/keys").configure(api_key::configure))
         .service(web::scope("/dumps").configure(dump::configure))
         .service(web::scope("/snapshots").configure(snapshot::configure))
         .service(web::resource("/stats").route(web::get().to(get_stats)))
+        .service(web::resource("/stats/searches").route(web::get().to(get_search_stats)))
         .service(web::resource("/version").route(web::get().to(get_version)))
         .service(web::scope("/indexes").configure(indexes::configure))
+        .service(web::scope("/settings").configure(batch_settings::configure))
         .service(web::scope("/multi-search").configure(multi_search::configure))
         .service(web::scope("/swap-indexes").configure(swap_indexes::configure))
         .service(web::scope("/metrics").configure(metrics::configure))
-        .service(web::scope("/experimental-features").configure(features::configure));
+        .service(web::scope("/experimental-features").configure(features::configure))
+        .service(web::scope("/maintenance").configure(maintenance::configure))
+        .service(web::scope("/webhooks").configure(webhooks::configure));
 }
 
 pub fn get_task_id(req: &HttpRequest, opt: &Opt) -> Result<Option<TaskId>, ResponseError> {
@@ -105,6 +127,16 @@ pub struct SummarizedTaskView {
     kind: Kind,
     #[serde(serialize_with = "time::serde::rfc3339::serialize")]
     enqueued_at: OffsetDateTime,
+    /// The documents left out of the payload because they couldn't be parsed.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    skipped_documents: Vec<SkippedDocument>,
+}
+
+impl SummarizedTaskView {
+    pub fn with_skipped_documents(mut self, skipped_documents: Vec<SkippedDocument>) -> Self {
+        self.skipped_documents = skipped_documents;
+        self
+    }
 }
 
 impl From<Task> for SummarizedTaskView {
@@ -115,8 +147,122 @@ impl From<Task> for SummarizedTaskView {
             status: task.status,
             kind: task.kind.as_kind(),
             enqueued_at: task.enqueued_at,
+            skipped_documents: Vec::new(),
+        }
+    }
+}
+
+/// The time a write route waits for its task to be processed when no `timeoutMs` is given.
+const DEFAULT_WAIT_FOR_COMPLETION_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The query parameters of the write routes making them respond only once their task is processed.
+#[derive(Debug, Deserr)]
+#[deserr(error = DeserrQueryParamError, rename_all = camelCase)]
+pub struct WaitForCompletionQuery {
+    #[deserr(default, error = DeserrQueryParamError<InvalidWaitForCompletion>)]
+    pub wait_for_completion: Param<bool>,
+    #[deserr(default, error = DeserrQueryParamError<InvalidTimeoutMs>)]
+    pub timeout_ms: Option<Param<u64>>,
+}
+
+impl WaitForCompletionQuery {
+    /// Returns how long to wait for the task, or `None` if the route must respond right away.
+    pub fn timeout(&self) -> Option<Duration> {
+        self.wait_for_completion.0.then(|| {
+            self.timeout_ms
+                .map_or(DEFAULT_WAIT_FOR_COMPLETION_TIMEOUT, |ms| Duration::from_millis(ms.0))
+        })
+    }
+}
+
+/// Responds with the enqueued task, after waiting for it to be processed if it was asked to.
+///
+/// A task that reaches a terminal state before the timeout is returned in full with a `200 OK`,
+/// otherwise the summarized task is returned with a `202 Accepted` as if no wait had been asked.
+pub async fn task_response(
+    index_scheduler: &IndexScheduler,
+    task: SummarizedTaskView,
+    timeout: Option<Duration>,
+    dry_run: bool,
+) -> Result<HttpResponse, ResponseError> {
+    // a dry-run task is never registered and thus never processed
+    let timeout = match timeout {
+        Some(timeout) if !dry_run => timeout,
+        _ => return Ok(HttpResponse::Accepted().json(task)),
+    };
+
+    match wait_for_task(index_scheduler, task.task_uid, timeout).await? {
+        WaitedTask::Processed(processed) => {
+            Ok(HttpResponse::Ok().json(TaskView::from_task(&processed)))
+        }
+        WaitedTask::Deleted | WaitedTask::TimedOut => Ok(HttpResponse::Accepted().json(task)),
+    }
+}
+
+/// Waits until the task with the given consistency token is processed before serving a read.
+///
+/// The consistency token of a write is the `taskUid` it responded with. Once its task is
+/// processed, the reads reflect the write whether it succeeded or not.
+pub async fn wait_for_consistency(
+    index_scheduler: &IndexScheduler,
+    min_consistency: Option<TaskId>,
+) -> Result<(), ResponseError> {
+    if let Some(task_uid) = min_consistency {
+        // a deleted task was processed long ago, or will never be
+        let task =
+            wait_for_task(index_scheduler, task_uid, DEFAULT_WAIT_FOR_COMPLETION_TIMEOUT).await?;
+        if let WaitedTask::TimedOut = task {
+            return Err(MeilisearchHttpError::ConsistencyTimeout(task_uid).into());
         }
     }
+    Ok(())
+}
+
+/// How the wait for a task ended.
+enum WaitedTask {
+    /// The task reached a terminal state.
+    Processed(Task),
+    /// The task was registered but doesn't exist anymore.
+    Deleted,
+    /// The timeout was reached first.
+    TimedOut,
+}
+
+/// Waits until the task reaches a terminal state, is deleted or the timeout is reached.
+///
+/// The task is checked again each time the scheduler signals the end of a batch.
+async fn wait_for_task(
+    index_scheduler: &IndexScheduler,
+    task_uid: TaskId,
+    timeout: Duration,
+) -> Result<WaitedTask, ResponseError> {
+    let signal = index_scheduler.processed_batch_signal();
+    let deadline = Instant::now() + timeout;
+    loop {
+        // read before the task so that a batch processed in between is not missed
+        let processed_batches = signal.processed_batches();
+        let query = Query { uids: Some(vec![task_uid]), ..Query::default() };
+        let (tasks, _) = index_scheduler
+            .get_tasks_from_authorized_indexes(query, &meilisearch_auth::AuthFilter::default())?;
+        match tasks.into_iter().next() {
+            Some(task)
+                if matches!(task.status, Status::Succeeded | Status::Failed | Status::Canceled) =>
+            {
+                return Ok(WaitedTask::Processed(task))
+            }
+            Some(_) => (),
+            // a missing task below the next uid was registered and then deleted
+            None if task_uid < index_scheduler.next_task_uid()? => return Ok(WaitedTask::Deleted),
+            None => (),
+        }
+
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            return Ok(WaitedTask::TimedOut);
+        }
+        let signal = signal.clone();
+        tokio::task::spawn_blocking(move || signal.wait(processed_batches, remaining)).await?;
+    }
 }
 
 pub struct Pagination {
@@ -289,20 +435,60 @@ pub struct Stats {
     #[serde(serialize_with = "time::serde::rfc3339::option::serialize")]
     pub last_update: Option<OffsetDateTime>,
     pub indexes: BTreeMap<String, indexes::IndexStats>,
+    /// The state of the search queue, only shown by the `stats` route.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub search_queue: Option<SearchQueueStats>,
 }
 
 async fn get_stats(
     index_scheduler: GuardedData<ActionPolicy<{ actions::STATS_GET }>, Data<IndexScheduler>>,
     auth_controller: GuardedData<ActionPolicy<{ actions::STATS_GET }>, Data<AuthController>>,
+    search_queue: Data<SearchQueue>,
 ) -> Result<HttpResponse, ResponseError> {
     let filters = index_scheduler.filters();
 
-    let stats = create_all_stats((*index_scheduler).clone(), (*auth_controller).clone(), filters)?;
+    let mut stats =
+        create_all_stats((*index_scheduler).clone(), (*auth_controller).clone(), filters)?;
+    stats.search_queue = Some(search_queue.stats());
 
     debug!(returns = ?stats, "Get stats");
     Ok(HttpResponse::Ok().json(stats))
 }
 
+#[derive(Debug, Deserr)]
+#[deserr(error = DeserrQueryParamError, rename_all = camelCase, deny_unknown_fields)]
+pub struct SearchStatsQuery {
+    #[deserr(default, error = DeserrQueryParamError<InvalidIndexUid>)]
+    pub index_uids: OptionStarOrList<IndexUidPattern>,
+    /// The maximum number of zero-result queries listed.
+    #[deserr(default = Param(DEFAULT_TOP_ZERO_RESULT_QUERIES), error = DeserrQueryParamError<InvalidSearchStatsLimit>)]
+    pub limit: Param<usize>,
+}
+
+/// Aggregates the searches recorded on the indexes the key can access.
+async fn get_search_stats(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::STATS_GET }>, Data<IndexScheduler>>,
+    params: AwebQueryParameter<SearchStatsQuery, DeserrQueryParamError>,
+) -> Result<HttpResponse, ResponseError> {
+    if !index_scheduler.search_events_enabled() {
+        return Err(MeilisearchHttpError::SearchStatsDisabled.into());
+    }
+    let params = params.into_inner();
+    let index_uids = params.index_uids.merge_star_and_none();
+    let filters = index_scheduler.filters();
+
+    let events = index_scheduler.search_events()?.into_iter().filter(|event| {
+        filters.is_index_authorized(&event.index_uid)
+            && index_uids.as_ref().map_or(true, |patterns| {
+                patterns.iter().any(|pattern| pattern.matches_str(&event.index_uid))
+            })
+    });
+    let stats = SearchStats::new(events, params.limit.0);
+
+    debug!(returns = ?stats, "Get search stats");
+    Ok(HttpResponse::Ok().json(stats))
+}
+
 pub fn create_all_stats(
     index_scheduler: Data<IndexScheduler>,
     auth_controller: Data<AuthController>,
@@ -336,7 +522,13 @@ pub fn create_all_stats(
     database_size += auth_controller.size()?;
     used_database_size += auth_controller.used_size()?;
 
-    let stats = Stats { database_size, used_database_size, last_update: last_task, indexes };
+    let stats = Stats {
+        database_size,
+        used_database_size,
+        last_update: last_task,
+        indexes,
+        search_queue: None,
+    };
     Ok(stats)
 }
 
@@ -366,15 +558,3 @@ async fn get_version(
         pkg_version: env!("CARGO_PKG_VERSION").to_string(),
     })
 }
-
-pub async fn get_health(
-    index_scheduler: Data<IndexScheduler>,
-    auth_controller: Data<AuthController>,
-    search_queue: Data<SearchQueue>,
-) -> Result<HttpResponse, ResponseError> {
-    search_queue.health().unwrap();
-    index_scheduler.health().unwrap();
-    auth_controller.health().unwrap();
-
-    Ok(HttpResponse::Ok().json(serde_json::json!({ "status": "available" })))
-}
@@ -16,8 +16,8 @@ use crate::extractors::authentication::{AuthenticationError, GuardedData};
 use crate::extractors::sequential_extractor::SeqHandler;
 use crate::routes::indexes::search::search_kind;
 use crate::search::{
-    add_search_rules, perform_federated_search, perform_search, FederatedSearch, RetrieveVectors,
-    SearchQueryWithIndex, SearchResultWithIndex,
+    add_search_rules, perform_federated_search, perform_search, rerank_search_result,
+    FederatedSearch, QueryLimits, RetrieveVectors, SearchQueryWithIndex, SearchResultWithIndex,
 };
 use crate::search_queue::SearchQueue;
 
@@ -33,6 +33,7 @@ struct SearchResults {
 pub async fn multi_search_with_post(
     index_scheduler: GuardedData<ActionPolicy<{ actions::SEARCH }>, Data<IndexScheduler>>,
     search_queue: Data<SearchQueue>,
+    query_limits: Data<QueryLimits>,
     params: AwebJson<FederatedSearch, DeserrJsonError>,
     req: HttpRequest,
     analytics: web::Data<dyn Analytics>,
@@ -49,9 +50,12 @@ pub async fn multi_search_with_post(
 
     let features = index_scheduler.features();
 
-    // regardless of federation, check authorization and apply search rules
+    // regardless of federation, check authorization, the size of the queries and apply search rules
     let auth = 'check_authorization: {
         for (query_index, federated_query) in queries.iter_mut().enumerate() {
+            if let Err(err) = query_limits.check(federated_query.q.as_deref()) {
+                break 'check_authorization Err(err).with_index(query_index);
+            }
             let index_uid = federated_query.index_uid.as_str();
             // Check index from API key
             if !index_scheduler.filters().is_index_authorized(index_uid) {
@@ -134,10 +138,12 @@ pub async fn multi_search_with_post(
                     })
                     .await
                     .with_index(query_index)?;
+                    let mut search_result = search_result.with_index(query_index)?;
+                    rerank_search_result(&mut search_result).await;
 
                     search_results.push(SearchResultWithIndex {
                         index_uid: index_uid.into_inner(),
-                        result: search_result.with_index(query_index)?,
+                        result: search_result,
                     });
                 }
                 Ok(search_results)
@@ -1,20 +1,42 @@
+use std::path::Path;
+
 use actix_web::web::Data;
 use actix_web::{web, HttpRequest, HttpResponse};
-use index_scheduler::IndexScheduler;
-use meilisearch_types::error::ResponseError;
+use deserr::actix_web::{AwebJson, AwebQueryParameter};
+use deserr::Deserr;
+use index_scheduler::{IndexScheduler, MAX_SNAPSHOT_COMPRESSION_LEVEL};
+use meilisearch_types::deserr::{DeserrJsonError, DeserrQueryParamError};
+use meilisearch_types::error::deserr_codes::*;
+use meilisearch_types::error::{Code, ResponseError};
+use meilisearch_types::index_uid::IndexUid;
+use meilisearch_types::milli::update::Setting;
 use meilisearch_types::tasks::KindWithContent;
 use serde_json::json;
 use tracing::debug;
+use uuid::Uuid;
 
 use crate::analytics::Analytics;
 use crate::extractors::authentication::policies::*;
 use crate::extractors::authentication::GuardedData;
+use crate::extractors::payload::Payload;
 use crate::extractors::sequential_extractor::SeqHandler;
+use crate::routes::dump::{
+    archive_upload, check_indexes_write, receive_archive, register_dump_import, ArchiveSource,
+    ArchiveUpload, DocumentsAdder, ImportDumpArchiveQuery, IndexesCreator,
+};
 use crate::routes::{get_task_id, is_dry_run, SummarizedTaskView};
 use crate::Opt;
 
 pub fn configure(cfg: &mut web::ServiceConfig) {
-    cfg.service(web::resource("").route(web::post().to(SeqHandler(create_snapshot))));
+    cfg.service(web::resource("").route(web::post().to(SeqHandler(create_snapshot))))
+        .service(
+            web::resource("/import").route(web::post().to(SeqHandler(import_snapshot_archive))),
+        )
+        .service(
+            web::resource("/settings")
+                .route(web::get().to(SeqHandler(get_snapshot_settings)))
+                .route(web::patch().to(SeqHandler(patch_snapshot_settings))),
+        );
 }
 
 pub async fn create_snapshot(
@@ -36,3 +58,211 @@ pub async fn create_snapshot(
     debug!(returns = ?task, "Create snapshot");
     Ok(HttpResponse::Accepted().json(task))
 }
+
+/// Imports the indexes of a snapshot sent in the body, or as the file of a multipart form,
+/// without interrupting the existing indexes.
+///
+/// The archive is streamed to the dumps directory under a new uid and its version is checked.
+/// Its indexes are then copied as they are by a `dumpImport` task, the tasks and API keys of
+/// the snapshot are not imported. It is kept there once imported, and removed when it can't be.
+pub async fn import_snapshot_archive(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::SNAPSHOTS_CREATE }>, Data<IndexScheduler>>,
+    indexes_creator: IndexesCreator,
+    documents_adder: DocumentsAdder,
+    params: AwebQueryParameter<ImportDumpArchiveQuery, DeserrQueryParamError>,
+    mut body: Payload,
+    req: HttpRequest,
+    opt: web::Data<Opt>,
+    analytics: web::Data<dyn Analytics>,
+) -> Result<HttpResponse, ResponseError> {
+    let namespace = params.into_inner().namespace.map(IndexUid::into_inner);
+    let upload = archive_upload(&req, false)?;
+
+    analytics.publish(
+        "Snapshot Imported".to_string(),
+        json!({
+            "namespace": namespace.is_some(),
+            "multipart": matches!(upload, ArchiveUpload::Multipart { .. }),
+        }),
+        Some(&req),
+    );
+
+    let import_uid = format!("import-{}", Uuid::new_v4());
+    let snapshot_path = opt.dump_dir.join(format!("{import_uid}.snapshot"));
+    let source = match &upload {
+        ArchiveUpload::Multipart { boundary } => ArchiveSource::Multipart(boundary),
+        ArchiveUpload::Raw | ArchiveUpload::Url => ArchiveSource::Body,
+    };
+    let max_size = opt.http_payload_size_limit.as_u64() as usize;
+    receive_archive(&mut body, source, &snapshot_path, max_size).await?;
+
+    let read = {
+        let snapshot_path = snapshot_path.clone();
+        tokio::task::spawn_blocking(move || index_scheduler::snapshot_index_names(&snapshot_path))
+            .await?
+    };
+    let task = match read {
+        Ok(names) => {
+            let index_uids: Vec<_> = names
+                .iter()
+                .map(|name| KindWithContent::dump_import_index_uid(namespace.as_deref(), name))
+                .collect();
+            match check_indexes_write(&index_uids, &indexes_creator, &documents_adder) {
+                Ok(()) => {
+                    register_dump_import(
+                        index_scheduler,
+                        import_uid,
+                        namespace,
+                        index_uids,
+                        false,
+                        &req,
+                        &opt,
+                    )
+                    .await
+                }
+                Err(e) => Err(e),
+            }
+        }
+        Err(e) => Err(ResponseError::from_msg(
+            format!("The snapshot archive is invalid: {e}."),
+            Code::InvalidSnapshotArchive,
+        )),
+    };
+    let task = match task {
+        Ok(task) => task,
+        Err(e) => {
+            let _ = tokio::fs::remove_file(&snapshot_path).await;
+            return Err(e);
+        }
+    };
+
+    debug!(returns = ?task, "Import snapshot archive");
+    Ok(HttpResponse::Accepted().json(task))
+}
+
+async fn get_snapshot_settings(
+    index_scheduler: GuardedData<
+        ActionPolicy<{ actions::SNAPSHOTS_SETTINGS_GET }>,
+        Data<IndexScheduler>,
+    >,
+    req: HttpRequest,
+    analytics: web::Data<dyn Analytics>,
+) -> HttpResponse {
+    analytics.publish("Snapshot Settings Seen".to_string(), json!(null), Some(&req));
+
+    let settings = index_scheduler.snapshot_settings();
+    debug!(returns = ?settings, "Get snapshot settings");
+    HttpResponse::Ok().json(settings)
+}
+
+#[derive(Debug, Deserr)]
+#[deserr(error = DeserrJsonError, rename_all = camelCase, deny_unknown_fields)]
+pub struct SnapshotSettingsPatch {
+    /// `null` disables the scheduled snapshots.
+    #[deserr(default, error = DeserrJsonError<InvalidSnapshotInterval>)]
+    pub interval: Setting<u64>,
+    #[deserr(default, error = DeserrJsonError<InvalidSnapshotDir>)]
+    pub dir: Option<String>,
+    #[deserr(default, error = DeserrJsonError<InvalidSnapshotCompressionLevel>)]
+    pub compression_level: Option<u32>,
+    #[deserr(default, error = DeserrJsonError<InvalidSnapshotRetained>)]
+    pub retained: Option<u32>,
+    /// `null` stops throttling the copy of the databases.
+    #[deserr(default, error = DeserrJsonError<InvalidSnapshotMaxBytesPerSecond>)]
+    pub max_bytes_per_second: Setting<u64>,
+}
+
+/// Changes the settings of the snapshots while Meilisearch is running.
+///
+/// The settings apply to the snapshots created from now on. They are persisted and
+/// take precedence over the launch options when Meilisearch restarts, a warning is logged
+/// when they differ. The directory must be inside the snapshot directory of the launch options.
+async fn patch_snapshot_settings(
+    index_scheduler: GuardedData<
+        ActionPolicy<{ actions::SNAPSHOTS_SETTINGS_UPDATE }>,
+        Data<IndexScheduler>,
+    >,
+    params: AwebJson<SnapshotSettingsPatch, DeserrJsonError>,
+    req: HttpRequest,
+    analytics: web::Data<dyn Analytics>,
+) -> Result<HttpResponse, ResponseError> {
+    let SnapshotSettingsPatch { interval, dir, compression_level, retained, max_bytes_per_second } =
+        params.into_inner();
+    debug!(
+        parameters = ?(&interval, &dir, compression_level, retained, &max_bytes_per_second),
+        "Patch snapshot settings"
+    );
+
+    let mut settings = index_scheduler.snapshot_settings();
+    match interval {
+        Setting::Set(0) => {
+            return Err(ResponseError::from_msg(
+                "`interval` must be a positive number of seconds, or `null` to disable the scheduled snapshots.".to_string(),
+                Code::InvalidSnapshotInterval,
+            ))
+        }
+        Setting::Set(interval) => settings.interval = Some(interval),
+        Setting::Reset => settings.interval = None,
+        Setting::NotSet => (),
+    }
+    if let Some(dir) = dir {
+        if dir.trim().is_empty() {
+            return Err(ResponseError::from_msg(
+                "`dir` must be the path of a directory.".to_string(),
+                Code::InvalidSnapshotDir,
+            ));
+        }
+        settings.dir = index_scheduler.resolve_snapshot_dir(Path::new(&dir)).ok_or_else(|| {
+            ResponseError::from_msg(
+                format!("`dir` must be inside the snapshot directory of the launch options, found `{dir}`."),
+                Code::InvalidSnapshotDir,
+            )
+        })?;
+    }
+    if let Some(compression_level) = compression_level {
+        if compression_level > MAX_SNAPSHOT_COMPRESSION_LEVEL {
+            return Err(ResponseError::from_msg(
+                format!("`compressionLevel` must be between 0 and {MAX_SNAPSHOT_COMPRESSION_LEVEL}, found {compression_level}."),
+                Code::InvalidSnapshotCompressionLevel,
+            ));
+        }
+        settings.compression_level = compression_level;
+    }
+    if let Some(retained) = retained {
+        if retained == 0 {
+            return Err(ResponseError::from_msg(
+                "`retained` must be at least 1, the latest snapshot is always kept.".to_string(),
+                Code::InvalidSnapshotRetained,
+            ));
+        }
+        settings.retained = retained;
+    }
+    match max_bytes_per_second {
+        Setting::Set(0) => {
+            return Err(ResponseError::from_msg(
+                "`maxBytesPerSecond` must be a positive number of bytes, or `null` to stop throttling the snapshots.".to_string(),
+                Code::InvalidSnapshotMaxBytesPerSecond,
+            ))
+        }
+        Setting::Set(max) => settings.max_bytes_per_second = Some(max),
+        Setting::Reset => settings.max_bytes_per_second = None,
+        Setting::NotSet => (),
+    }
+
+    analytics.publish(
+        "Snapshot Settings Updated".to_string(),
+        json!({
+            "scheduled": settings.interval.is_some(),
+            "compression_level": settings.compression_level,
+            "retained": settings.retained,
+            "throttled": settings.max_bytes_per_second.is_some(),
+        }),
+        Some(&req),
+    );
+
+    let updated = settings.clone();
+    tokio::task::spawn_blocking(move || index_scheduler.update_snapshot_settings(updated))
+        .await??;
+    debug!(returns = ?settings, "Patch snapshot settings");
+    Ok(HttpResponse::Ok().json(settings))
+}
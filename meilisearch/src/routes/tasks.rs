@@ -3,14 +3,15 @@ use actix_web::{web, HttpRequest, HttpResponse};
 use deserr::actix_web::AwebQueryParameter;
 use deserr::Deserr;
 use index_scheduler::{IndexScheduler, Query, TaskId};
+use meilisearch_types::batches::BatchId;
 use meilisearch_types::deserr::query_params::Param;
 use meilisearch_types::deserr::DeserrQueryParamError;
 use meilisearch_types::error::deserr_codes::*;
 use meilisearch_types::error::{InvalidTaskDateError, ResponseError};
-use meilisearch_types::index_uid::IndexUid;
+use meilisearch_types::index_uid_pattern::IndexUidPattern;
 use meilisearch_types::star_or::{OptionStarOr, OptionStarOrList};
-use meilisearch_types::task_view::TaskView;
-use meilisearch_types::tasks::{Kind, KindWithContent, Status};
+use meilisearch_types::task_view::{TaskProgressView, TaskView};
+use meilisearch_types::tasks::{Kind, KindWithContent, Status, Task};
 use serde::Serialize;
 use serde_json::json;
 use time::format_description::well_known::Rfc3339;
@@ -34,6 +35,7 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
             .route(web::delete().to(SeqHandler(delete_tasks))),
     )
     .service(web::resource("/cancel").route(web::post().to(SeqHandler(cancel_tasks))))
+    .service(web::resource("/dead-letters").route(web::get().to(SeqHandler(get_dead_letter_tasks))))
     .service(web::resource("/{task_id}").route(web::get().to(SeqHandler(get_task))));
 }
 #[derive(Debug, Deserr)]
@@ -48,12 +50,14 @@ pub struct TasksFilterQuery {
     pub uids: OptionStarOrList<u32>,
     #[deserr(default, error = DeserrQueryParamError<InvalidTaskCanceledBy>)]
     pub canceled_by: OptionStarOrList<u32>,
+    #[deserr(default, error = DeserrQueryParamError<InvalidTaskBatchUids>)]
+    pub batch_uids: OptionStarOrList<BatchId>,
     #[deserr(default, error = DeserrQueryParamError<InvalidTaskTypes>)]
     pub types: OptionStarOrList<Kind>,
     #[deserr(default, error = DeserrQueryParamError<InvalidTaskStatuses>)]
     pub statuses: OptionStarOrList<Status>,
     #[deserr(default, error = DeserrQueryParamError<InvalidIndexUid>)]
-    pub index_uids: OptionStarOrList<IndexUid>,
+    pub index_uids: OptionStarOrList<IndexUidPattern>,
 
     #[deserr(default, error = DeserrQueryParamError<InvalidTaskAfterEnqueuedAt>, try_from(OptionStarOr<String>) = deserialize_date_after -> InvalidTaskDateError)]
     pub after_enqueued_at: OptionStarOr<OffsetDateTime>,
@@ -79,6 +83,7 @@ impl TasksFilterQuery {
             index_uids: self.index_uids.map(|x| x.to_string()).merge_star_and_none(),
             uids: self.uids.merge_star_and_none(),
             canceled_by: self.canceled_by.merge_star_and_none(),
+            batch_uids: self.batch_uids.merge_star_and_none(),
             before_enqueued_at: self.before_enqueued_at.merge_star_and_none(),
             after_enqueued_at: self.after_enqueued_at.merge_star_and_none(),
             before_started_at: self.before_started_at.merge_star_and_none(),
@@ -96,6 +101,7 @@ impl TaskDeletionOrCancelationQuery {
             TaskDeletionOrCancelationQuery {
                 uids: OptionStarOrList::None,
                 canceled_by: OptionStarOrList::None,
+                batch_uids: OptionStarOrList::None,
                 types: OptionStarOrList::None,
                 statuses: OptionStarOrList::None,
                 index_uids: OptionStarOrList::None,
@@ -117,12 +123,14 @@ pub struct TaskDeletionOrCancelationQuery {
     pub uids: OptionStarOrList<u32>,
     #[deserr(default, error = DeserrQueryParamError<InvalidTaskCanceledBy>)]
     pub canceled_by: OptionStarOrList<u32>,
+    #[deserr(default, error = DeserrQueryParamError<InvalidTaskBatchUids>)]
+    pub batch_uids: OptionStarOrList<BatchId>,
     #[deserr(default, error = DeserrQueryParamError<InvalidTaskTypes>)]
     pub types: OptionStarOrList<Kind>,
     #[deserr(default, error = DeserrQueryParamError<InvalidTaskStatuses>)]
     pub statuses: OptionStarOrList<Status>,
     #[deserr(default, error = DeserrQueryParamError<InvalidIndexUid>)]
-    pub index_uids: OptionStarOrList<IndexUid>,
+    pub index_uids: OptionStarOrList<IndexUidPattern>,
 
     #[deserr(default, error = DeserrQueryParamError<InvalidTaskAfterEnqueuedAt>, try_from(OptionStarOr<String>) = deserialize_date_after -> InvalidTaskDateError)]
     pub after_enqueued_at: OptionStarOr<OffsetDateTime>,
@@ -148,6 +156,7 @@ impl TaskDeletionOrCancelationQuery {
             index_uids: self.index_uids.map(|x| x.to_string()).merge_star_and_none(),
             uids: self.uids.merge_star_and_none(),
             canceled_by: self.canceled_by.merge_star_and_none(),
+            batch_uids: self.batch_uids.merge_star_and_none(),
             before_enqueued_at: self.before_enqueued_at.merge_star_and_none(),
             after_enqueued_at: self.after_enqueued_at.merge_star_and_none(),
             before_started_at: self.before_started_at.merge_star_and_none(),
@@ -179,6 +188,7 @@ async fn cancel_tasks(
             "filtered_by_type": params.types.is_some(),
             "filtered_by_status": params.statuses.is_some(),
             "filtered_by_canceled_by": params.canceled_by.is_some(),
+            "filtered_by_batch_uids": params.batch_uids.is_some(),
             "filtered_by_before_enqueued_at": params.before_enqueued_at.is_some(),
             "filtered_by_after_enqueued_at": params.after_enqueued_at.is_some(),
             "filtered_by_before_started_at": params.before_started_at.is_some(),
@@ -230,6 +240,7 @@ async fn delete_tasks(
             "filtered_by_type": params.types.is_some(),
             "filtered_by_status": params.statuses.is_some(),
             "filtered_by_canceled_by": params.canceled_by.is_some(),
+            "filtered_by_batch_uids": params.batch_uids.is_some(),
             "filtered_by_before_enqueued_at": params.before_enqueued_at.is_some(),
             "filtered_by_after_enqueued_at": params.after_enqueued_at.is_some(),
             "filtered_by_before_started_at": params.before_started_at.is_some(),
@@ -271,7 +282,35 @@ async fn get_tasks(
     index_scheduler: GuardedData<ActionPolicy<{ actions::TASKS_GET }>, Data<IndexScheduler>>,
     params: AwebQueryParameter<TasksFilterQuery, DeserrQueryParamError>,
 ) -> Result<HttpResponse, ResponseError> {
+    let tasks = paginate_tasks(&index_scheduler, params.into_inner())?;
+    Ok(HttpResponse::Ok().json(tasks))
+}
+
+/// Returns the tasks that failed once all their retries were exhausted, filtered and paginated
+/// like the other tasks.
+async fn get_dead_letter_tasks(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::TASKS_GET }>, Data<IndexScheduler>>,
+    params: AwebQueryParameter<TasksFilterQuery, DeserrQueryParamError>,
+) -> Result<HttpResponse, ResponseError> {
+    let dead_letters = index_scheduler.dead_letter_tasks(&index_scheduler.read_txn()?)?;
     let mut params = params.into_inner();
+    params.uids = match params.uids {
+        OptionStarOrList::List(uids) => OptionStarOrList::List(
+            uids.into_iter().filter(|uid| dead_letters.contains(*uid)).collect(),
+        ),
+        OptionStarOrList::None | OptionStarOrList::Star => {
+            OptionStarOrList::List(dead_letters.iter().collect())
+        }
+    };
+
+    let tasks = paginate_tasks(&index_scheduler, params)?;
+    Ok(HttpResponse::Ok().json(tasks))
+}
+
+fn paginate_tasks(
+    index_scheduler: &GuardedData<ActionPolicy<{ actions::TASKS_GET }>, Data<IndexScheduler>>,
+    mut params: TasksFilterQuery,
+) -> Result<AllTasks, ResponseError> {
     // We +1 just to know if there is more after this "page" or not.
     params.limit.0 = params.limit.0.saturating_add(1);
     let limit = params.limit.0;
@@ -279,16 +318,15 @@ async fn get_tasks(
 
     let filters = index_scheduler.filters();
     let (tasks, total) = index_scheduler.get_tasks_from_authorized_indexes(query, filters)?;
-    let mut results: Vec<_> = tasks.iter().map(TaskView::from_task).collect();
+    let mut results: Vec<_> =
+        tasks.iter().map(|task| task_view_with_progress(index_scheduler, task)).collect();
 
     // If we were able to fetch the number +1 tasks we asked
     // it means that there is more to come.
     let next = if results.len() == limit as usize { results.pop().map(|t| t.uid) } else { None };
 
     let from = results.first().map(|t| t.uid);
-    let tasks = AllTasks { results, limit: limit.saturating_sub(1), total, from, next };
-
-    Ok(HttpResponse::Ok().json(tasks))
+    Ok(AllTasks { results, limit: limit.saturating_sub(1), total, from, next })
 }
 
 async fn get_task(
@@ -309,13 +347,23 @@ async fn get_task(
     let (tasks, _) = index_scheduler.get_tasks_from_authorized_indexes(query, filters)?;
 
     if let Some(task) = tasks.first() {
-        let task_view = TaskView::from_task(task);
+        let task_view = task_view_with_progress(&index_scheduler, task);
         Ok(HttpResponse::Ok().json(task_view))
     } else {
         Err(index_scheduler::Error::TaskNotFound(task_uid).into())
     }
 }
 
+/// Returns the view of the task, with the progress of its indexing if it is processing.
+fn task_view_with_progress(index_scheduler: &IndexScheduler, task: &Task) -> TaskView {
+    let mut view = TaskView::from_task(task);
+    if task.status == Status::Processing {
+        view.progress =
+            index_scheduler.task_progress(task.uid).map(TaskProgressView::from_indexing_step);
+    }
+    view
+}
+
 pub enum DeserializeDateOption {
     Before,
     After,
@@ -422,7 +470,7 @@ mod tests {
             // Stars are allowed in date fields as well
             let params = "afterEnqueuedAt=*&beforeStartedAt=*&afterFinishedAt=*&beforeFinishedAt=*&afterStartedAt=*&beforeEnqueuedAt=*";
             let query = deserr_query_params::<TaskDeletionOrCancelationQuery>(params).unwrap();
-            snapshot!(format!("{:?}", query), @"TaskDeletionOrCancelationQuery { uids: None, canceled_by: None, types: None, statuses: None, index_uids: None, after_enqueued_at: Star, before_enqueued_at: Star, after_started_at: Star, before_started_at: Star, after_finished_at: Star, before_finished_at: Star }");
+            snapshot!(format!("{:?}", query), @"TaskDeletionOrCancelationQuery { uids: None, canceled_by: None, batch_uids: None, types: None, statuses: None, index_uids: None, after_enqueued_at: Star, before_enqueued_at: Star, after_started_at: Star, before_started_at: Star, after_finished_at: Star, before_finished_at: Star }");
         }
         {
             let params = "afterFinishedAt=2021";
@@ -591,7 +639,7 @@ mod tests {
             let err = deserr_query_params::<TaskDeletionOrCancelationQuery>(params).unwrap_err();
             snapshot!(meili_snap::json_string!(err), @r###"
             {
-              "message": "Invalid value in parameter `types`: `createIndex` is not a valid task type. Available types are `documentAdditionOrUpdate`, `documentEdition`, `documentDeletion`, `settingsUpdate`, `indexCreation`, `indexDeletion`, `indexUpdate`, `indexSwap`, `taskCancelation`, `taskDeletion`, `dumpCreation`, `snapshotCreation`.",
+              "message": "Invalid value in parameter `types`: `createIndex` is not a valid task type. Available types are `documentAdditionOrUpdate`, `documentTransaction`, `documentUrlImport`, `documentEdition`, `documentDeletion`, `settingsUpdate`, `indexCreation`, `indexDeletion`, `indexUpdate`, `indexSwap`, `taskCancelation`, `taskDeletion`, `dumpCreation`, `dumpImport`, `snapshotCreation`.",
               "code": "invalid_task_types",
               "type": "invalid_request",
               "link": "https://docs.meilisearch.com/errors#invalid_task_types"
@@ -604,19 +652,19 @@ mod tests {
         {
             let params = "indexUids=toto,tata-78";
             let query = deserr_query_params::<TaskDeletionOrCancelationQuery>(params).unwrap();
-            snapshot!(format!("{:?}", query.index_uids), @r###"List([IndexUid("toto"), IndexUid("tata-78")])"###);
+            snapshot!(format!("{:?}", query.index_uids), @r###"List([IndexUidPattern("toto"), IndexUidPattern("tata-78")])"###);
         }
         {
             let params = "indexUids=index_a";
             let query = deserr_query_params::<TaskDeletionOrCancelationQuery>(params).unwrap();
-            snapshot!(format!("{:?}", query.index_uids), @r###"List([IndexUid("index_a")])"###);
+            snapshot!(format!("{:?}", query.index_uids), @r###"List([IndexUidPattern("index_a")])"###);
         }
         {
             let params = "indexUids=1,hé";
             let err = deserr_query_params::<TaskDeletionOrCancelationQuery>(params).unwrap_err();
             snapshot!(meili_snap::json_string!(err), @r###"
             {
-              "message": "Invalid value in parameter `indexUids[1]`: `hé` is not a valid index uid. Index uid can be an integer or a string containing only alphanumeric characters, hyphens (-) and underscores (_).",
+              "message": "Invalid value in parameter `indexUids[1]`: `hé` is not a valid index uid pattern. Index uid patterns can be an integer or a string containing only alphanumeric characters, hyphens (-), underscores (_), and optionally end with a star (*).",
               "code": "invalid_index_uid",
               "type": "invalid_request",
               "link": "https://docs.meilisearch.com/errors#invalid_index_uid"
@@ -628,7 +676,7 @@ mod tests {
             let err = deserr_query_params::<TaskDeletionOrCancelationQuery>(params).unwrap_err();
             snapshot!(meili_snap::json_string!(err), @r###"
             {
-              "message": "Invalid value in parameter `indexUids`: `hé` is not a valid index uid. Index uid can be an integer or a string containing only alphanumeric characters, hyphens (-) and underscores (_).",
+              "message": "Invalid value in parameter `indexUids`: `hé` is not a valid index uid pattern. Index uid patterns can be an integer or a string containing only alphanumeric characters, hyphens (-), underscores (_), and optionally end with a star (*).",
               "code": "invalid_index_uid",
               "type": "invalid_request",
               "link": "https://docs.meilisearch.com/errors#invalid_index_uid"
@@ -642,14 +690,14 @@ mod tests {
         {
             let params = "from=12&limit=15&indexUids=toto,tata-78&statuses=succeeded,enqueued&afterEnqueuedAt=2012-04-23&uids=1,2,3";
             let query = deserr_query_params::<TasksFilterQuery>(params).unwrap();
-            snapshot!(format!("{:?}", query), @r###"TasksFilterQuery { limit: Param(15), from: Some(Param(12)), uids: List([1, 2, 3]), canceled_by: None, types: None, statuses: List([Succeeded, Enqueued]), index_uids: List([IndexUid("toto"), IndexUid("tata-78")]), after_enqueued_at: Other(2012-04-24 0:00:00.0 +00:00:00), before_enqueued_at: None, after_started_at: None, before_started_at: None, after_finished_at: None, before_finished_at: None }"###);
+            snapshot!(format!("{:?}", query), @r###"TasksFilterQuery { limit: Param(15), from: Some(Param(12)), uids: List([1, 2, 3]), canceled_by: None, batch_uids: None, types: None, statuses: List([Succeeded, Enqueued]), index_uids: List([IndexUidPattern("toto"), IndexUidPattern("tata-78")]), after_enqueued_at: Other(2012-04-24 0:00:00.0 +00:00:00), before_enqueued_at: None, after_started_at: None, before_started_at: None, after_finished_at: None, before_finished_at: None }"###);
         }
         {
             // Stars should translate to `None` in the query
             // Verify value of the default limit
             let params = "indexUids=*&statuses=succeeded,*&afterEnqueuedAt=2012-04-23&uids=1,2,3";
             let query = deserr_query_params::<TasksFilterQuery>(params).unwrap();
-            snapshot!(format!("{:?}", query), @"TasksFilterQuery { limit: Param(20), from: None, uids: List([1, 2, 3]), canceled_by: None, types: None, statuses: Star, index_uids: Star, after_enqueued_at: Other(2012-04-24 0:00:00.0 +00:00:00), before_enqueued_at: None, after_started_at: None, before_started_at: None, after_finished_at: None, before_finished_at: None }");
+            snapshot!(format!("{:?}", query), @"TasksFilterQuery { limit: Param(20), from: None, uids: List([1, 2, 3]), canceled_by: None, batch_uids: None, types: None, statuses: Star, index_uids: Star, after_enqueued_at: Other(2012-04-24 0:00:00.0 +00:00:00), before_enqueued_at: None, after_started_at: None, before_started_at: None, after_finished_at: None, before_finished_at: None }");
         }
         {
             // Stars should also translate to `None` in task deletion/cancelation queries
@@ -676,7 +724,7 @@ mod tests {
             let err = deserr_query_params::<TaskDeletionOrCancelationQuery>(params).unwrap_err();
             snapshot!(meili_snap::json_string!(err), @r###"
             {
-              "message": "Unknown parameter `from`: expected one of `uids`, `canceledBy`, `types`, `statuses`, `indexUids`, `afterEnqueuedAt`, `beforeEnqueuedAt`, `afterStartedAt`, `beforeStartedAt`, `afterFinishedAt`, `beforeFinishedAt`",
+              "message": "Unknown parameter `from`: expected one of `uids`, `canceledBy`, `batchUids`, `types`, `statuses`, `indexUids`, `afterEnqueuedAt`, `beforeEnqueuedAt`, `afterStartedAt`, `beforeStartedAt`, `afterFinishedAt`, `beforeFinishedAt`",
               "code": "bad_request",
               "type": "invalid_request",
               "link": "https://docs.meilisearch.com/errors#bad_request"
@@ -689,7 +737,7 @@ mod tests {
             let err = deserr_query_params::<TaskDeletionOrCancelationQuery>(params).unwrap_err();
             snapshot!(meili_snap::json_string!(err), @r###"
             {
-              "message": "Unknown parameter `limit`: expected one of `uids`, `canceledBy`, `types`, `statuses`, `indexUids`, `afterEnqueuedAt`, `beforeEnqueuedAt`, `afterStartedAt`, `beforeStartedAt`, `afterFinishedAt`, `beforeFinishedAt`",
+              "message": "Unknown parameter `limit`: expected one of `uids`, `canceledBy`, `batchUids`, `types`, `statuses`, `indexUids`, `afterEnqueuedAt`, `beforeEnqueuedAt`, `afterStartedAt`, `beforeStartedAt`, `afterFinishedAt`, `beforeFinishedAt`",
               "code": "bad_request",
               "type": "invalid_request",
               "link": "https://docs.meilisearch.com/errors#bad_request"
@@ -709,7 +757,7 @@ mod tests {
             let params = "statuses=*";
             let query = deserr_query_params::<TaskDeletionOrCancelationQuery>(params).unwrap();
             assert!(!query.is_empty());
-            snapshot!(format!("{query:?}"), @"TaskDeletionOrCancelationQuery { uids: None, canceled_by: None, types: None, statuses: Star, index_uids: None, after_enqueued_at: None, before_enqueued_at: None, after_started_at: None, before_started_at: None, after_finished_at: None, before_finished_at: None }");
+            snapshot!(format!("{query:?}"), @"TaskDeletionOrCancelationQuery { uids: None, canceled_by: None, batch_uids: None, types: None, statuses: Star, index_uids: None, after_enqueued_at: None, before_enqueued_at: None, after_started_at: None, before_started_at: None, after_finished_at: None, before_finished_at: None }");
         }
     }
 }
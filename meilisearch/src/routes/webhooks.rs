@@ -0,0 +1,121 @@
+use actix_web::web::{self, Data};
+use actix_web::{HttpRequest, HttpResponse};
+use deserr::actix_web::AwebJson;
+use deserr::Deserr;
+use index_scheduler::{IndexScheduler, Webhook};
+use meilisearch_types::deserr::DeserrJsonError;
+use meilisearch_types::error::deserr_codes::InvalidWebhooks;
+use meilisearch_types::error::{Code, ResponseError};
+use meilisearch_types::keys::actions;
+use meilisearch_types::settings::hide_secret;
+use serde::Serialize;
+use serde_json::json;
+use tracing::debug;
+use url::Url;
+
+use crate::analytics::Analytics;
+use crate::extractors::authentication::policies::ActionPolicy;
+use crate::extractors::authentication::GuardedData;
+use crate::extractors::sequential_extractor::SeqHandler;
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::resource("")
+            .route(web::get().to(SeqHandler(get_webhooks)))
+            .route(web::patch().to(SeqHandler(patch_webhooks))),
+    );
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhooksView {
+    /// The webhooks registered through this route, the one of the launch options is not listed.
+    pub webhooks: Vec<Webhook>,
+}
+
+impl WebhooksView {
+    /// Hides the authorization headers of the webhooks, they are secrets.
+    fn new(mut webhooks: Vec<Webhook>) -> Self {
+        for webhook in &mut webhooks {
+            if let Some(authorization) = &mut webhook.authorization {
+                hide_secret(authorization);
+            }
+        }
+        WebhooksView { webhooks }
+    }
+}
+
+async fn get_webhooks(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::WEBHOOKS_GET }>, Data<IndexScheduler>>,
+    req: HttpRequest,
+    analytics: Data<dyn Analytics>,
+) -> HttpResponse {
+    analytics.publish("Webhooks Seen".to_string(), json!(null), Some(&req));
+
+    let webhooks = WebhooksView::new(index_scheduler.webhooks());
+    debug!(returns = ?webhooks, "Get webhooks");
+    HttpResponse::Ok().json(webhooks)
+}
+
+#[derive(Debug, Deserr)]
+#[deserr(error = DeserrJsonError, rename_all = camelCase, deny_unknown_fields)]
+pub struct WebhooksPatch {
+    #[deserr(error = DeserrJsonError<InvalidWebhooks>)]
+    pub webhooks: Vec<WebhookSettings>,
+}
+
+#[derive(Debug, Deserr)]
+#[deserr(error = DeserrJsonError<InvalidWebhooks>, rename_all = camelCase, deny_unknown_fields)]
+pub struct WebhookSettings {
+    pub url: String,
+    #[deserr(default)]
+    pub authorization: Option<String>,
+}
+
+/// Replaces the registered webhooks, an empty list removes all of them.
+///
+/// Every webhook receives the tasks processed by each batch, after the one of the launch options.
+async fn patch_webhooks(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::WEBHOOKS_UPDATE }>, Data<IndexScheduler>>,
+    params: AwebJson<WebhooksPatch, DeserrJsonError>,
+    req: HttpRequest,
+    analytics: Data<dyn Analytics>,
+) -> Result<HttpResponse, ResponseError> {
+    let WebhooksPatch { webhooks } = params.into_inner();
+
+    let mut registered = Vec::with_capacity(webhooks.len());
+    for WebhookSettings { url, authorization } in webhooks {
+        match Url::parse(&url) {
+            Ok(parsed) if matches!(parsed.scheme(), "http" | "https") => (),
+            Ok(_) => {
+                return Err(ResponseError::from_msg(
+                    format!("The webhook URL `{url}` must use the `http` or `https` scheme."),
+                    Code::InvalidWebhooks,
+                ))
+            }
+            Err(error) => {
+                return Err(ResponseError::from_msg(
+                    format!("The webhook URL `{url}` is invalid: {error}."),
+                    Code::InvalidWebhooks,
+                ))
+            }
+        }
+        registered.push(Webhook { url, authorization });
+    }
+
+    analytics.publish(
+        "Webhooks Updated".to_string(),
+        json!({
+            "webhooks": registered.len(),
+            "with_authorization": registered.iter().filter(|w| w.authorization.is_some()).count(),
+        }),
+        Some(&req),
+    );
+
+    let webhooks = registered.clone();
+    tokio::task::spawn_blocking(move || index_scheduler.put_webhooks(webhooks)).await??;
+
+    let webhooks = WebhooksView::new(registered);
+    debug!(returns = ?webhooks, "Patch webhooks");
+    Ok(HttpResponse::Ok().json(webhooks))
+}
@@ -187,9 +187,18 @@ impl<'a> WeightedScore<'a> {
                         }
                     }
                 }
+                (
+                    Some(ScoreValue::BoostingFilter(left)),
+                    Some(ScoreValue::BoostingFilter(right)),
+                ) => match left.cmp(&right) {
+                    Ordering::Equal => continue,
+                    order => return order,
+                },
                 // not comparable details, use global
                 (Some(ScoreValue::Score(_)), Some(_))
                 | (Some(_), Some(ScoreValue::Score(_)))
+                | (Some(ScoreValue::BoostingFilter(_)), Some(_))
+                | (Some(_), Some(ScoreValue::BoostingFilter(_)))
                 | (Some(ScoreValue::GeoSort(_)), Some(ScoreValue::Sort(_)))
                 | (Some(ScoreValue::Sort(_)), Some(ScoreValue::GeoSort(_))) => {
                     let left_count = left_it.count();
@@ -341,6 +350,12 @@ pub fn perform_federated_search(
             )
             .into());
         }
+        if federated_query.has_filter_relaxation() {
+            return Err(MeilisearchHttpError::FilterRelaxationInFederatedQuery(query_index).into());
+        }
+        if federated_query.has_group_by() {
+            return Err(MeilisearchHttpError::GroupByInFederatedQuery(query_index).into());
+        }
 
         let (index_uid, query, federation_options) = federated_query.into_index_query_federation();
 
@@ -378,7 +393,7 @@ pub fn perform_federated_search(
         // Important: this is the only transaction we'll use for this index during this federated search
         let rtxn = index.read_txn()?;
 
-        let criteria = index.criteria(&rtxn)?;
+        let criteria = index.resolved_criteria(&rtxn)?;
 
         let dictionary = index.dictionary(&rtxn)?;
         let dictionary: Option<Vec<_>> =
@@ -427,8 +442,16 @@ pub fn perform_federated_search(
                     None
                 };
 
+                let criteria = match &query.ranking_rules {
+                    Some(ranking_rules) => index.resolve_criteria(
+                        &rtxn,
+                        ranking_rules.iter().cloned().map(milli::Criterion::from).collect(),
+                    )?,
+                    None => criteria.clone(),
+                };
+
                 let ranking_rules = ranking_rules::RankingRules::new(
-                    criteria.clone(),
+                    criteria,
                     sort,
                     query.matching_strategy.into(),
                     canonicalization_kind,
@@ -470,7 +493,7 @@ pub fn perform_federated_search(
                     None => TimeBudget::default(),
                 };
 
-                let (mut search, _is_finite_pagination, _max_total_hits, _offset) =
+                let (mut search, _is_finite_pagination, _max_total_hits, _offset, _limit) =
                     prepare_search(&index, &rtxn, &query, &search_kind, time_budget, features)?;
 
                 search.scoring_strategy(milli::score_details::ScoringStrategy::Detailed);
@@ -485,9 +508,14 @@ pub fn perform_federated_search(
                     attributes_to_crop: query.attributes_to_crop,
                     crop_length: query.crop_length,
                     crop_marker: query.crop_marker,
+                    attributes_formatting: query.attributes_formatting,
+                    prefix_highlight: query.prefix_highlight,
                     highlight_pre_tag: query.highlight_pre_tag,
                     highlight_post_tag: query.highlight_post_tag,
                     show_matches_position: query.show_matches_position,
+                    show_matches_info: query.show_matches_info,
+                    show_query_coverage: query.show_query_coverage,
+                    enable_formatting: query.enable_formatting,
                     sort: query.sort,
                     show_ranking_score: query.show_ranking_score,
                     show_ranking_score_details: query.show_ranking_score_details,
@@ -612,7 +640,7 @@ pub fn perform_federated_search(
         hits_info: HitsInfo::OffsetLimit {
             limit: federation.limit,
             offset: federation.offset,
-            estimated_total_hits,
+            estimated_total_hits: Some(estimated_total_hits),
         },
         semantic_hit_count,
         degraded,
@@ -20,16 +20,18 @@ use meilisearch_types::milli::score_details::{ScoreDetails, ScoringStrategy};
 use meilisearch_types::milli::vector::parsed_vectors::ExplicitVectors;
 use meilisearch_types::milli::vector::Embedder;
 use meilisearch_types::milli::{FacetValueHit, OrderBy, SearchForFacetValues, TimeBudget};
-use meilisearch_types::settings::DEFAULT_PAGINATION_MAX_TOTAL_HITS;
+use meilisearch_types::settings::{RankingRuleView, DEFAULT_PAGINATION_MAX_TOTAL_HITS};
 use meilisearch_types::{milli, Document};
 use milli::tokenizer::{Language, TokenizerBuilder};
 use milli::{
-    AscDesc, FieldId, FieldsIdsMap, Filter, FormatOptions, Index, LocalizedAttributesRule,
-    MatchBounds, MatcherBuilder, SortError, TermsMatchingStrategy, DEFAULT_VALUES_PER_FACET,
+    AscDesc, Criterion, DocumentId, FieldId, FieldsIdsMap, Filter, FormatOptions, FormatTags,
+    Index, LocalizedAttributesRule, MatchBounds, MatcherBuilder, QueryAnalysis, SortError,
+    TermsMatchingStrategy, DEFAULT_VALUES_PER_FACET,
 };
 use regex::Regex;
 use serde::Serialize;
 use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
 
 use crate::error::MeilisearchHttpError;
 
@@ -37,8 +39,13 @@ mod federated;
 pub use federated::{perform_federated_search, FederatedSearch, Federation, FederationOptions};
 
 mod ranking_rules;
+mod reranker;
+pub use reranker::rerank_search_result;
+use reranker::PendingRerank;
 
 type MatchesPosition = BTreeMap<String, Vec<MatchBounds>>;
+/// The number of matches in each attribute of a hit.
+type MatchesInfo = BTreeMap<String, usize>;
 
 pub const DEFAULT_SEARCH_OFFSET: fn() -> usize = || 0;
 pub const DEFAULT_SEARCH_LIMIT: fn() -> usize = || 20;
@@ -47,6 +54,37 @@ pub const DEFAULT_CROP_MARKER: fn() -> String = || "…".to_string();
 pub const DEFAULT_HIGHLIGHT_PRE_TAG: fn() -> String = || "<em>".to_string();
 pub const DEFAULT_HIGHLIGHT_POST_TAG: fn() -> String = || "</em>".to_string();
 pub const DEFAULT_SEMANTIC_RATIO: fn() -> SemanticRatio = || SemanticRatio(0.5);
+pub const DEFAULT_COMPUTE_TOTAL_HITS: fn() -> bool = || true;
+pub const DEFAULT_GROUP_LIMIT: fn() -> usize = || 3;
+pub const DEFAULT_CROSS_ATTRIBUTE_MATCHING: fn() -> bool = || true;
+/// A spelling suggestion is only computed for queries returning at most this number of hits.
+pub const DEFAULT_SUGGESTION_MAX_HITS: fn() -> usize = || 10;
+
+/// The largest queries accepted by the search routes, set by the `--max-query-length`
+/// and `--max-query-terms` options.
+#[derive(Debug, Clone, Copy)]
+pub struct QueryLimits {
+    pub max_length: usize,
+    pub max_terms: usize,
+}
+
+impl QueryLimits {
+    /// Rejects the query before anything is searched if it is too long or made of too many terms.
+    pub fn check(&self, q: Option<&str>) -> Result<(), MeilisearchHttpError> {
+        let Some(q) = q else { return Ok(()) };
+        if q.len() > self.max_length {
+            return Err(MeilisearchHttpError::QueryTooLong {
+                length: q.len(),
+                limit: self.max_length,
+            });
+        }
+        let terms = q.split_whitespace().count();
+        if terms > self.max_terms {
+            return Err(MeilisearchHttpError::TooManyQueryTerms { terms, limit: self.max_terms });
+        }
+        Ok(())
+    }
+}
 
 #[derive(Clone, Default, PartialEq, Deserr)]
 #[deserr(error = DeserrJsonError, rename_all = camelCase, deny_unknown_fields)]
@@ -65,6 +103,10 @@ pub struct SearchQuery {
     pub page: Option<usize>,
     #[deserr(default, error = DeserrJsonError<InvalidSearchHitsPerPage>)]
     pub hits_per_page: Option<usize>,
+    #[deserr(default = DEFAULT_COMPUTE_TOTAL_HITS(), error = DeserrJsonError<InvalidSearchComputeTotalHits>)]
+    pub compute_total_hits: bool,
+    #[deserr(default, error = DeserrJsonError<InvalidSearchCountOnly>, default)]
+    pub count_only: bool,
     #[deserr(default, error = DeserrJsonError<InvalidSearchAttributesToRetrieve>)]
     pub attributes_to_retrieve: Option<BTreeSet<String>>,
     #[deserr(default, error = DeserrJsonError<InvalidSearchRetrieveVectors>)]
@@ -77,16 +119,38 @@ pub struct SearchQuery {
     pub attributes_to_highlight: Option<HashSet<String>>,
     #[deserr(default, error = DeserrJsonError<InvalidSearchShowMatchesPosition>, default)]
     pub show_matches_position: bool,
+    #[deserr(default, error = DeserrJsonError<InvalidSearchShowMatchesInfo>, default)]
+    pub show_matches_info: bool,
+    #[deserr(default, error = DeserrJsonError<InvalidSearchShowQueryCoverage>, default)]
+    pub show_query_coverage: bool,
+    #[deserr(default, error = DeserrJsonError<InvalidSearchEnableFormatting>)]
+    pub enable_formatting: Option<bool>,
     #[deserr(default, error = DeserrJsonError<InvalidSearchShowRankingScore>, default)]
     pub show_ranking_score: bool,
     #[deserr(default, error = DeserrJsonError<InvalidSearchShowRankingScoreDetails>, default)]
     pub show_ranking_score_details: bool,
+    #[deserr(default, error = DeserrJsonError<InvalidSearchShowSuggestion>, default)]
+    pub show_suggestion: bool,
+    #[deserr(default = DEFAULT_SUGGESTION_MAX_HITS(), error = DeserrJsonError<InvalidSearchSuggestionMaxHits>)]
+    pub suggestion_max_hits: usize,
+    #[deserr(default, error = DeserrJsonError<InvalidSearchShowQueryAnalysis>, default)]
+    pub show_query_analysis: bool,
     #[deserr(default, error = DeserrJsonError<InvalidSearchFilter>)]
     pub filter: Option<Value>,
+    #[deserr(default, error = DeserrJsonError<InvalidSearchFilterRelaxation>)]
+    pub filter_relaxation: Option<FilterRelaxation>,
+    #[deserr(default, error = DeserrJsonError<InvalidSearchBoostingFilter>)]
+    pub boosting_filter: Option<Value>,
     #[deserr(default, error = DeserrJsonError<InvalidSearchSort>)]
     pub sort: Option<Vec<String>>,
+    #[deserr(default, error = DeserrJsonError<InvalidSearchRankingRules>)]
+    pub ranking_rules: Option<Vec<RankingRuleView>>,
     #[deserr(default, error = DeserrJsonError<InvalidSearchDistinct>)]
     pub distinct: Option<String>,
+    #[deserr(default, error = DeserrJsonError<InvalidSearchGroupBy>)]
+    pub group_by: Option<String>,
+    #[deserr(default = DEFAULT_GROUP_LIMIT(), error = DeserrJsonError<InvalidSearchGroupLimit>)]
+    pub group_limit: usize,
     #[deserr(default, error = DeserrJsonError<InvalidSearchFacets>)]
     pub facets: Option<Vec<String>>,
     #[deserr(default, error = DeserrJsonError<InvalidSearchHighlightPreTag>, default = DEFAULT_HIGHLIGHT_PRE_TAG())]
@@ -95,10 +159,18 @@ pub struct SearchQuery {
     pub highlight_post_tag: String,
     #[deserr(default, error = DeserrJsonError<InvalidSearchCropMarker>, default = DEFAULT_CROP_MARKER())]
     pub crop_marker: String,
+    #[deserr(default, error = DeserrJsonError<InvalidSearchAttributesFormatting>)]
+    pub attributes_formatting: Option<BTreeMap<String, AttributeFormatting>>,
+    #[deserr(default, error = DeserrJsonError<InvalidSearchPrefixHighlight>, default)]
+    pub prefix_highlight: PrefixHighlight,
     #[deserr(default, error = DeserrJsonError<InvalidSearchMatchingStrategy>, default)]
     pub matching_strategy: MatchingStrategy,
+    #[deserr(default, error = DeserrJsonError<InvalidSearchMaxSynonymExpansions>)]
+    pub max_synonym_expansions: Option<usize>,
     #[deserr(default, error = DeserrJsonError<InvalidSearchAttributesToSearchOn>, default)]
     pub attributes_to_search_on: Option<Vec<String>>,
+    #[deserr(default = DEFAULT_CROSS_ATTRIBUTE_MATCHING(), error = DeserrJsonError<InvalidSearchCrossAttributeMatching>)]
+    pub cross_attribute_matching: bool,
     #[deserr(default, error = DeserrJsonError<InvalidSearchRankingScoreThreshold>, default)]
     pub ranking_score_threshold: Option<RankingScoreThreshold>,
     #[deserr(default, error = DeserrJsonError<InvalidSearchLocales>, default)]
@@ -141,6 +213,31 @@ impl std::convert::TryFrom<f64> for RankingScoreThresholdSimilar {
     }
 }
 
+/// The highlight tags and crop marker of an attribute, replacing the ones of the search.
+///
+/// They also apply to the fields nested in the attribute, the most specific attribute wins.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserr)]
+#[deserr(error = DeserrJsonError<InvalidSearchAttributesFormatting>, rename_all = camelCase, deny_unknown_fields)]
+pub struct AttributeFormatting {
+    #[deserr(default)]
+    pub highlight_pre_tag: Option<String>,
+    #[deserr(default)]
+    pub highlight_post_tag: Option<String>,
+    #[deserr(default)]
+    pub crop_marker: Option<String>,
+}
+
+impl From<AttributeFormatting> for FormatTags {
+    fn from(formatting: AttributeFormatting) -> Self {
+        let AttributeFormatting { highlight_pre_tag, highlight_post_tag, crop_marker } = formatting;
+        FormatTags {
+            highlight_prefix: highlight_pre_tag,
+            highlight_suffix: highlight_post_tag,
+            crop_marker,
+        }
+    }
+}
+
 // Since this structure is logged A LOT we're going to reduce the number of things it logs to the bare minimum.
 // - Only what IS used, we know everything else is set to None so there is no need to print it
 // - Re-order the most important field to debug first
@@ -154,23 +251,40 @@ impl fmt::Debug for SearchQuery {
             limit,
             page,
             hits_per_page,
+            compute_total_hits,
+            count_only,
             attributes_to_retrieve,
             retrieve_vectors,
             attributes_to_crop,
             crop_length,
             attributes_to_highlight,
             show_matches_position,
+            show_matches_info,
+            show_query_coverage,
+            enable_formatting,
             show_ranking_score,
             show_ranking_score_details,
+            show_suggestion,
+            suggestion_max_hits,
+            show_query_analysis,
             filter,
+            filter_relaxation,
+            boosting_filter,
             sort,
+            ranking_rules,
             distinct,
+            group_by,
+            group_limit,
             facets,
             highlight_pre_tag,
             highlight_post_tag,
             crop_marker,
+            attributes_formatting,
+            prefix_highlight,
             matching_strategy,
+            max_synonym_expansions,
             attributes_to_search_on,
+            cross_attribute_matching,
             ranking_score_threshold,
             locales,
         } = self;
@@ -185,6 +299,12 @@ impl fmt::Debug for SearchQuery {
         if let Some(hits_per_page) = hits_per_page {
             debug.field("hits_per_page", &hits_per_page);
         }
+        if !compute_total_hits {
+            debug.field("compute_total_hits", &compute_total_hits);
+        }
+        if *count_only {
+            debug.field("count_only", count_only);
+        }
 
         // Then, everything related to the queries
         if let Some(q) = q {
@@ -209,31 +329,67 @@ impl fmt::Debug for SearchQuery {
         if let Some(attributes_to_search_on) = attributes_to_search_on {
             debug.field("attributes_to_search_on", &attributes_to_search_on);
         }
+        if !cross_attribute_matching {
+            debug.field("cross_attribute_matching", &cross_attribute_matching);
+        }
         if let Some(filter) = filter {
             debug.field("filter", &filter);
         }
+        if let Some(filter_relaxation) = filter_relaxation {
+            debug.field("filter_relaxation", &filter_relaxation);
+        }
+        if let Some(boosting_filter) = boosting_filter {
+            debug.field("boosting_filter", &boosting_filter);
+        }
         if let Some(sort) = sort {
             debug.field("sort", &sort);
         }
+        if let Some(ranking_rules) = ranking_rules {
+            debug.field("ranking_rules", &ranking_rules);
+        }
         if let Some(distinct) = distinct {
             debug.field("distinct", &distinct);
         }
+        if let Some(group_by) = group_by {
+            debug.field("group_by", &group_by).field("group_limit", &group_limit);
+        }
         if let Some(facets) = facets {
             debug.field("facets", &facets);
         }
         debug.field("matching_strategy", &matching_strategy);
+        if let Some(max_synonym_expansions) = max_synonym_expansions {
+            debug.field("max_synonym_expansions", &max_synonym_expansions);
+        }
 
         // Then everything related to the formatting
         debug.field("crop_length", &crop_length);
         if *show_matches_position {
             debug.field("show_matches_position", show_matches_position);
         }
+        if *show_matches_info {
+            debug.field("show_matches_info", show_matches_info);
+        }
+        if *show_query_coverage {
+            debug.field("show_query_coverage", show_query_coverage);
+        }
+        if let Some(enable_formatting) = enable_formatting {
+            debug.field("enable_formatting", enable_formatting);
+        }
         if *show_ranking_score {
             debug.field("show_ranking_score", show_ranking_score);
         }
         if *show_ranking_score_details {
             debug.field("self.show_ranking_score_details", show_ranking_score_details);
         }
+        if *show_suggestion {
+            debug.field("show_suggestion", show_suggestion);
+        }
+        if *suggestion_max_hits != DEFAULT_SUGGESTION_MAX_HITS() {
+            debug.field("suggestion_max_hits", suggestion_max_hits);
+        }
+        if *show_query_analysis {
+            debug.field("show_query_analysis", show_query_analysis);
+        }
         debug.field("crop_length", &crop_length);
         if let Some(facets) = facets {
             debug.field("facets", &facets);
@@ -250,6 +406,12 @@ impl fmt::Debug for SearchQuery {
         debug.field("highlight_pre_tag", &highlight_pre_tag);
         debug.field("highlight_post_tag", &highlight_post_tag);
         debug.field("crop_marker", &crop_marker);
+        if let Some(attributes_formatting) = attributes_formatting {
+            debug.field("attributes_formatting", &attributes_formatting);
+        }
+        if *prefix_highlight != PrefixHighlight::default() {
+            debug.field("prefix_highlight", &prefix_highlight);
+        }
         if let Some(ranking_score_threshold) = ranking_score_threshold {
             debug.field("ranking_score_threshold", &ranking_score_threshold);
         }
@@ -271,6 +433,16 @@ pub struct HybridQuery {
     pub embedder: Option<String>,
 }
 
+/// Progressively drops the clauses of the filter until the search returns enough hits.
+///
+/// The clauses of an array filter are ordered by decreasing importance: the last clause
+/// is the first one to be dropped. A string filter is a single clause.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserr)]
+#[deserr(error = DeserrJsonError<InvalidSearchFilterRelaxation>, rename_all = camelCase, deny_unknown_fields)]
+pub struct FilterRelaxation {
+    pub min_hits: usize,
+}
+
 #[derive(Clone)]
 pub enum SearchKind {
     KeywordOnly,
@@ -372,6 +544,17 @@ impl SearchQuery {
     pub fn is_finite_pagination(&self) -> bool {
         self.page.or(self.hits_per_page).is_some()
     }
+
+    /// Whether the candidates of the search are gathered, even when the total number of hits is
+    /// not returned: they are always needed to paginate by pages, to compute the facets and to
+    /// relax the filter.
+    fn computes_total_hits(&self) -> bool {
+        self.compute_total_hits
+            || self.count_only
+            || self.is_finite_pagination()
+            || self.facets.is_some()
+            || self.filter_relaxation.is_some()
+    }
 }
 
 /// A `SearchQuery` + an index UID and optional FederationOptions.
@@ -397,6 +580,10 @@ pub struct SearchQueryWithIndex {
     pub page: Option<usize>,
     #[deserr(default, error = DeserrJsonError<InvalidSearchHitsPerPage>)]
     pub hits_per_page: Option<usize>,
+    #[deserr(default = DEFAULT_COMPUTE_TOTAL_HITS(), error = DeserrJsonError<InvalidSearchComputeTotalHits>)]
+    pub compute_total_hits: bool,
+    #[deserr(default, error = DeserrJsonError<InvalidSearchCountOnly>, default)]
+    pub count_only: bool,
     #[deserr(default, error = DeserrJsonError<InvalidSearchAttributesToRetrieve>)]
     pub attributes_to_retrieve: Option<BTreeSet<String>>,
     #[deserr(default, error = DeserrJsonError<InvalidSearchRetrieveVectors>)]
@@ -413,12 +600,34 @@ pub struct SearchQueryWithIndex {
     pub show_ranking_score_details: bool,
     #[deserr(default, error = DeserrJsonError<InvalidSearchShowMatchesPosition>, default)]
     pub show_matches_position: bool,
+    #[deserr(default, error = DeserrJsonError<InvalidSearchShowMatchesInfo>, default)]
+    pub show_matches_info: bool,
+    #[deserr(default, error = DeserrJsonError<InvalidSearchShowQueryCoverage>, default)]
+    pub show_query_coverage: bool,
+    #[deserr(default, error = DeserrJsonError<InvalidSearchEnableFormatting>)]
+    pub enable_formatting: Option<bool>,
+    #[deserr(default, error = DeserrJsonError<InvalidSearchShowSuggestion>, default)]
+    pub show_suggestion: bool,
+    #[deserr(default = DEFAULT_SUGGESTION_MAX_HITS(), error = DeserrJsonError<InvalidSearchSuggestionMaxHits>)]
+    pub suggestion_max_hits: usize,
+    #[deserr(default, error = DeserrJsonError<InvalidSearchShowQueryAnalysis>, default)]
+    pub show_query_analysis: bool,
     #[deserr(default, error = DeserrJsonError<InvalidSearchFilter>)]
     pub filter: Option<Value>,
+    #[deserr(default, error = DeserrJsonError<InvalidSearchFilterRelaxation>)]
+    pub filter_relaxation: Option<FilterRelaxation>,
+    #[deserr(default, error = DeserrJsonError<InvalidSearchBoostingFilter>)]
+    pub boosting_filter: Option<Value>,
     #[deserr(default, error = DeserrJsonError<InvalidSearchSort>)]
     pub sort: Option<Vec<String>>,
+    #[deserr(default, error = DeserrJsonError<InvalidSearchRankingRules>)]
+    pub ranking_rules: Option<Vec<RankingRuleView>>,
     #[deserr(default, error = DeserrJsonError<InvalidSearchDistinct>)]
     pub distinct: Option<String>,
+    #[deserr(default, error = DeserrJsonError<InvalidSearchGroupBy>)]
+    pub group_by: Option<String>,
+    #[deserr(default = DEFAULT_GROUP_LIMIT(), error = DeserrJsonError<InvalidSearchGroupLimit>)]
+    pub group_limit: usize,
     #[deserr(default, error = DeserrJsonError<InvalidSearchFacets>)]
     pub facets: Option<Vec<String>>,
     #[deserr(default, error = DeserrJsonError<InvalidSearchHighlightPreTag>, default = DEFAULT_HIGHLIGHT_PRE_TAG())]
@@ -427,10 +636,18 @@ pub struct SearchQueryWithIndex {
     pub highlight_post_tag: String,
     #[deserr(default, error = DeserrJsonError<InvalidSearchCropMarker>, default = DEFAULT_CROP_MARKER())]
     pub crop_marker: String,
+    #[deserr(default, error = DeserrJsonError<InvalidSearchAttributesFormatting>)]
+    pub attributes_formatting: Option<BTreeMap<String, AttributeFormatting>>,
+    #[deserr(default, error = DeserrJsonError<InvalidSearchPrefixHighlight>, default)]
+    pub prefix_highlight: PrefixHighlight,
     #[deserr(default, error = DeserrJsonError<InvalidSearchMatchingStrategy>, default)]
     pub matching_strategy: MatchingStrategy,
+    #[deserr(default, error = DeserrJsonError<InvalidSearchMaxSynonymExpansions>)]
+    pub max_synonym_expansions: Option<usize>,
     #[deserr(default, error = DeserrJsonError<InvalidSearchAttributesToSearchOn>, default)]
     pub attributes_to_search_on: Option<Vec<String>>,
+    #[deserr(default = DEFAULT_CROSS_ATTRIBUTE_MATCHING(), error = DeserrJsonError<InvalidSearchCrossAttributeMatching>)]
+    pub cross_attribute_matching: bool,
     #[deserr(default, error = DeserrJsonError<InvalidSearchRankingScoreThreshold>, default)]
     pub ranking_score_threshold: Option<RankingScoreThreshold>,
     #[deserr(default, error = DeserrJsonError<InvalidSearchLocales>, default)]
@@ -444,6 +661,12 @@ impl SearchQueryWithIndex {
     pub fn has_federation_options(&self) -> bool {
         self.federation_options.is_some()
     }
+    pub fn has_filter_relaxation(&self) -> bool {
+        self.filter_relaxation.is_some()
+    }
+    pub fn has_group_by(&self) -> bool {
+        self.group_by.is_some()
+    }
     pub fn has_pagination(&self) -> Option<&'static str> {
         if self.offset.is_some() {
             Some("offset")
@@ -468,6 +691,8 @@ impl SearchQueryWithIndex {
             limit,
             page,
             hits_per_page,
+            compute_total_hits,
+            count_only,
             attributes_to_retrieve,
             retrieve_vectors,
             attributes_to_crop,
@@ -476,15 +701,30 @@ impl SearchQueryWithIndex {
             show_ranking_score,
             show_ranking_score_details,
             show_matches_position,
+            show_matches_info,
+            show_query_coverage,
+            enable_formatting,
+            show_suggestion,
+            suggestion_max_hits,
+            show_query_analysis,
             filter,
+            filter_relaxation,
+            boosting_filter,
             sort,
+            ranking_rules,
             distinct,
+            group_by,
+            group_limit,
             facets,
             highlight_pre_tag,
             highlight_post_tag,
             crop_marker,
+            attributes_formatting,
+            prefix_highlight,
             matching_strategy,
+            max_synonym_expansions,
             attributes_to_search_on,
+            cross_attribute_matching,
             hybrid,
             ranking_score_threshold,
             locales,
@@ -498,6 +738,8 @@ impl SearchQueryWithIndex {
                 limit: limit.unwrap_or(DEFAULT_SEARCH_LIMIT()),
                 page,
                 hits_per_page,
+                compute_total_hits,
+                count_only,
                 attributes_to_retrieve,
                 retrieve_vectors,
                 attributes_to_crop,
@@ -506,15 +748,30 @@ impl SearchQueryWithIndex {
                 show_ranking_score,
                 show_ranking_score_details,
                 show_matches_position,
+                show_matches_info,
+                show_query_coverage,
+                enable_formatting,
+                show_suggestion,
+                suggestion_max_hits,
+                show_query_analysis,
                 filter,
+                filter_relaxation,
+                boosting_filter,
                 sort,
+                ranking_rules,
                 distinct,
+                group_by,
+                group_limit,
                 facets,
                 highlight_pre_tag,
                 highlight_post_tag,
                 crop_marker,
+                attributes_formatting,
+                prefix_highlight,
                 matching_strategy,
+                max_synonym_expansions,
                 attributes_to_search_on,
+                cross_attribute_matching,
                 hybrid,
                 ranking_score_threshold,
                 locales,
@@ -610,6 +867,29 @@ impl From<MatchingStrategy> for TermsMatchingStrategy {
     }
 }
 
+/// How the words only matched by their prefix are highlighted in the `_formatted` field.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Deserr)]
+#[deserr(rename_all = camelCase)]
+pub enum PrefixHighlight {
+    /// Highlight the matching prefix of the word
+    #[default]
+    Partial,
+    /// Highlight the whole word
+    Word,
+    /// Do not highlight the word
+    None,
+}
+
+impl From<PrefixHighlight> for milli::PrefixHighlight {
+    fn from(other: PrefixHighlight) -> Self {
+        match other {
+            PrefixHighlight::Partial => Self::Partial,
+            PrefixHighlight::Word => Self::Word,
+            PrefixHighlight::None => Self::None,
+        }
+    }
+}
+
 #[derive(Debug, Default, Clone, PartialEq, Eq, Deserr)]
 #[deserr(rename_all = camelCase)]
 pub enum FacetValuesSort {
@@ -638,6 +918,11 @@ pub struct SearchHit {
     pub formatted: Document,
     #[serde(rename = "_matchesPosition", skip_serializing_if = "Option::is_none")]
     pub matches_position: Option<MatchesPosition>,
+    #[serde(rename = "_matchesInfo", skip_serializing_if = "Option::is_none")]
+    pub matches_info: Option<MatchesInfo>,
+    /// The fraction of the distinct query words matched by the displayed attributes of the hit.
+    #[serde(rename = "_queryCoverage", skip_serializing_if = "Option::is_none")]
+    pub query_coverage: Option<f64>,
     #[serde(rename = "_rankingScore", skip_serializing_if = "Option::is_none")]
     pub ranking_score: Option<f64>,
     #[serde(rename = "_rankingScoreDetails", skip_serializing_if = "Option::is_none")]
@@ -656,10 +941,32 @@ pub struct SearchResult {
     pub facet_distribution: Option<BTreeMap<String, IndexMap<String, u64>>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub facet_stats: Option<BTreeMap<String, FacetStats>>,
+    /// Identifies the filter context of the facets, see [`facet_cache_key`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub facet_cache_key: Option<String>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub semantic_hit_count: Option<u32>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suggestion: Option<String>,
+
+    /// The terms searched for once the query has been analyzed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_analysis: Option<QueryAnalysis>,
+
+    /// The filter clauses dropped by the filter relaxation, in the order they were dropped.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dropped_filters: Option<Vec<Value>>,
+
+    /// The hits bucketed by the value of the `groupBy` attribute.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub groups: Option<Vec<HitsGroup>>,
+
+    /// Applied by [`rerank_search_result`] once the search is done.
+    #[serde(skip)]
+    pub rerank: Option<PendingRerank>,
+
     // These fields are only used for analytics purposes
     #[serde(skip)]
     pub degraded: bool,
@@ -676,7 +983,13 @@ impl fmt::Debug for SearchResult {
             hits_info,
             facet_distribution,
             facet_stats,
+            facet_cache_key,
             semantic_hit_count,
+            suggestion,
+            query_analysis,
+            dropped_filters,
+            groups,
+            rerank,
             degraded,
             used_negative_operator,
         } = self;
@@ -699,14 +1012,44 @@ impl fmt::Debug for SearchResult {
         if let Some(facet_stats) = facet_stats {
             debug.field("facet_stats", &facet_stats);
         }
+        if let Some(facet_cache_key) = facet_cache_key {
+            debug.field("facet_cache_key", &facet_cache_key);
+        }
         if let Some(semantic_hit_count) = semantic_hit_count {
             debug.field("semantic_hit_count", &semantic_hit_count);
         }
+        if let Some(suggestion) = suggestion {
+            debug.field("suggestion", &suggestion);
+        }
+        if let Some(query_analysis) = query_analysis {
+            debug.field("query_analysis", &query_analysis);
+        }
+        if let Some(dropped_filters) = dropped_filters {
+            debug.field("dropped_filters", &dropped_filters);
+        }
+        if let Some(groups) = groups {
+            debug.field("groups", &format!("[{} groups returned]", groups.len()));
+        }
+        if let Some(rerank) = rerank {
+            debug.field("rerank", &rerank);
+        }
 
         debug.finish()
     }
 }
 
+/// The best ranked hits sharing the same value for the `groupBy` attribute.
+///
+/// The groups are made from every hit reachable through the pagination, up to the
+/// `maxTotalHits` of the index, whatever the requested page is.
+///
+/// The hits that do not contain the attribute are gathered in an ungrouped bucket with a `null` value.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct HitsGroup {
+    pub value: Option<Value>,
+    pub hits: Vec<SearchHit>,
+}
+
 #[derive(Serialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct SimilarResult {
@@ -731,13 +1074,19 @@ pub enum HitsInfo {
     #[serde(rename_all = "camelCase")]
     Pagination { hits_per_page: usize, page: usize, total_pages: usize, total_hits: usize },
     #[serde(rename_all = "camelCase")]
-    OffsetLimit { limit: usize, offset: usize, estimated_total_hits: usize },
+    OffsetLimit {
+        limit: usize,
+        offset: usize,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        estimated_total_hits: Option<usize>,
+    },
 }
 
 #[derive(Serialize, Debug, Clone, PartialEq)]
 pub struct FacetStats {
     pub min: f64,
     pub max: f64,
+    pub avg: f64,
 }
 
 #[derive(Serialize, Debug, Clone, PartialEq)]
@@ -775,7 +1124,7 @@ fn prepare_search<'t>(
     search_kind: &SearchKind,
     time_budget: TimeBudget,
     features: RoFeatures,
-) -> Result<(milli::Search<'t>, bool, usize, usize), ResponseError> {
+) -> Result<(milli::Search<'t>, bool, usize, usize, usize), ResponseError> {
     let mut search = index.search(rtxn);
     search.time_budget(time_budget);
     if let Some(ranking_score_threshold) = query.ranking_score_threshold {
@@ -823,6 +1172,15 @@ fn prepare_search<'t>(
 
     let is_finite_pagination = query.is_finite_pagination();
     search.terms_matching_strategy(query.matching_strategy.into());
+    if let Some(max_synonym_expansions) = query.max_synonym_expansions {
+        search.max_synonym_expansions(max_synonym_expansions);
+    }
+    search.cross_attribute_matching(query.cross_attribute_matching);
+    search.count_only(query.count_only);
+
+    if let Some(ref ranking_rules) = query.ranking_rules {
+        search.ranking_rules(ranking_rules.iter().cloned().map(Criterion::from).collect());
+    }
 
     let max_total_hits = index
         .pagination_max_total_hits(rtxn)
@@ -830,7 +1188,11 @@ fn prepare_search<'t>(
         .map(|x| x as usize)
         .unwrap_or(DEFAULT_PAGINATION_MAX_TOTAL_HITS);
 
-    search.exhaustive_number_hits(is_finite_pagination);
+    // the filter relaxation compares the exact number of hits to its minimum.
+    let relaxes_filter = query.filter_relaxation.is_some();
+    // a search only counting its hits always returns the exact number of them.
+    search.exhaustive_number_hits(is_finite_pagination || query.count_only || relaxes_filter);
+    search.compute_total_hits(query.computes_total_hits());
     search.scoring_strategy(
         if query.show_ranking_score
             || query.show_ranking_score_details
@@ -867,6 +1229,14 @@ fn prepare_search<'t>(
         }
     }
 
+    if let Some(ref boosting_filter) = query.boosting_filter {
+        if let Some(boosting_filter) =
+            parse_filter(boosting_filter, Code::InvalidSearchBoostingFilter, features)?
+        {
+            search.boosting_filter(boosting_filter);
+        }
+    }
+
     if let Some(ref sort) = query.sort {
         let sort = match sort.iter().map(|s| AscDesc::from_str(s)).collect() {
             Ok(sorts) => sorts,
@@ -882,7 +1252,73 @@ fn prepare_search<'t>(
         search.locales(locales.iter().copied().map(Into::into).collect());
     }
 
-    Ok((search, is_finite_pagination, max_total_hits, offset))
+    Ok((search, is_finite_pagination, max_total_hits, offset, limit))
+}
+
+/// Returns the documents pinned to the top of the results of the query by the first matching rule,
+/// without the ones that don't match the filter of the query.
+fn pinned_documents(
+    index: &Index,
+    rtxn: &RoTxn,
+    query: &SearchQuery,
+    features: RoFeatures,
+) -> Result<Vec<DocumentId>, ResponseError> {
+    let Some(q) = query.q.as_deref().filter(|q| !q.trim().is_empty()) else {
+        return Ok(Vec::new());
+    };
+    let rules = index.pinned_results(rtxn)?;
+    let Some(rule) = rules.iter().find(|rule| rule.match_query(q)) else {
+        return Ok(Vec::new());
+    };
+
+    // the blocked documents can't be pinned either
+    let blocked = index.blocked_documents_ids(rtxn)?;
+    let external_documents_ids = index.external_documents_ids();
+    let mut pinned = Vec::with_capacity(rule.document_ids.len());
+    for external_id in &rule.document_ids {
+        if let Some(docid) = external_documents_ids.get(rtxn, external_id)? {
+            if !pinned.contains(&docid) && !blocked.contains(docid) {
+                pinned.push(docid);
+            }
+        }
+    }
+
+    if let Some(ref filter) = query.filter {
+        if let Some(filter) = parse_filter(filter, Code::InvalidSearchFilter, features)? {
+            let filtered = filter.evaluate(rtxn, index)?;
+            pinned.retain(|docid| filtered.contains(*docid));
+        }
+    }
+
+    Ok(pinned)
+}
+
+/// Puts the pinned documents in front of the other results, which must have been fetched from
+/// the first one, then applies the offset and the limit of the query.
+fn pin_documents(
+    result: &mut milli::SearchResult,
+    pinned: &[DocumentId],
+    offset: usize,
+    limit: usize,
+) {
+    let organic = std::mem::take(&mut result.documents_ids)
+        .into_iter()
+        .zip(std::mem::take(&mut result.document_scores));
+    let (pinned_scores, organic): (Vec<_>, Vec<_>) =
+        organic.partition(|(docid, _)| pinned.contains(docid));
+
+    result.candidates.extend(pinned.iter().copied());
+    let pinned = pinned.iter().map(|docid| {
+        let scores = pinned_scores
+            .iter()
+            .find(|(id, _)| id == docid)
+            .map(|(_, scores)| scores.clone())
+            .unwrap_or_default();
+        (*docid, scores)
+    });
+
+    (result.documents_ids, result.document_scores) =
+        pinned.chain(organic).skip(offset).take(limit).unzip();
 }
 
 pub fn perform_search(
@@ -891,6 +1327,22 @@ pub fn perform_search(
     search_kind: SearchKind,
     retrieve_vectors: RetrieveVectors,
     features: RoFeatures,
+) -> Result<SearchResult, ResponseError> {
+    perform_search_with_partial_hits(index, query, search_kind, retrieve_vectors, features, None)
+}
+
+/// Performs the search like [`perform_search`], calling `on_partial_hits` with the hits of the
+/// ranking rules buckets as soon as they are resolved, before the complete result is returned.
+///
+/// The partial hits are neither formatted nor scored, and are only reported by the keyword
+/// searches without pinned results.
+pub fn perform_search_with_partial_hits(
+    index: &Index,
+    mut query: SearchQuery,
+    search_kind: SearchKind,
+    retrieve_vectors: RetrieveVectors,
+    features: RoFeatures,
+    on_partial_hits: Option<&dyn Fn(Vec<SearchHit>)>,
 ) -> Result<SearchResult, ResponseError> {
     let before_search = Instant::now();
     let rtxn = index.read_txn()?;
@@ -899,9 +1351,65 @@ pub fn perform_search(
         None => TimeBudget::default(),
     };
 
-    let (search, is_finite_pagination, max_total_hits, offset) =
-        prepare_search(index, &rtxn, &query, &search_kind, time_budget, features)?;
+    let partial_hit_maker = match on_partial_hits {
+        Some(_) => {
+            let format = AttributesFormat {
+                attributes_to_retrieve: query.attributes_to_retrieve.clone(),
+                retrieve_vectors,
+                attributes_to_highlight: None,
+                attributes_to_crop: None,
+                crop_length: DEFAULT_CROP_LENGTH(),
+                crop_marker: DEFAULT_CROP_MARKER(),
+                attributes_formatting: None,
+                prefix_highlight: PrefixHighlight::default(),
+                highlight_pre_tag: DEFAULT_HIGHLIGHT_PRE_TAG(),
+                highlight_post_tag: DEFAULT_HIGHLIGHT_POST_TAG(),
+                show_matches_position: false,
+                show_matches_info: false,
+                show_query_coverage: false,
+                enable_formatting: Some(false),
+                sort: query.sort.clone(),
+                show_ranking_score: false,
+                show_ranking_score_details: false,
+                locales: None,
+            };
+            let formatter_builder = HitMaker::formatter_builder(
+                milli::MatchingWords::default(),
+                HitMaker::tokenizer(None, None),
+            );
+            Some(HitMaker::new(index, &rtxn, format, formatter_builder)?)
+        }
+        None => None,
+    };
+    let partial_results = |documents_ids: &[DocumentId]| {
+        let (Some(hit_maker), Some(on_partial_hits)) = (&partial_hit_maker, on_partial_hits) else {
+            return;
+        };
+        let hits: Result<Vec<_>, _> =
+            documents_ids.iter().map(|&id| hit_maker.make_hit(id, &[])).collect();
+        match hits {
+            Ok(hits) => on_partial_hits(hits),
+            Err(error) => tracing::warn!(%error, "Could not make the partial hits of a search"),
+        }
+    };
+
+    // the re-ranker rescores the hits of the searches with a query, unless they are explicitly
+    // sorted, boosted, grouped or only counted
+    let reranker = match query.q.as_deref() {
+        Some(q)
+            if !q.trim().is_empty()
+                && !query.count_only
+                && query.sort.is_none()
+                && query.boosting_filter.is_none()
+                && query.group_by.is_none()
+                && !matches!(search_kind, SearchKind::SemanticOnly { .. }) =>
+        {
+            index.reranker(&rtxn)?
+        }
+        _ => None,
+    };
 
+    let mut dropped_filters = Vec::new();
     let (
         milli::SearchResult {
             documents_ids,
@@ -912,13 +1420,98 @@ pub fn perform_search(
             used_negative_operator,
         },
         semantic_hit_count,
-    ) = search_from_kind(search_kind, search)?;
+        query_analysis,
+        is_finite_pagination,
+        max_total_hits,
+        offset,
+        page_limit,
+        rerank,
+        known_hits,
+    ) = loop {
+        // the relaxed searches share the time budget of the original one
+        let (mut search, is_finite_pagination, max_total_hits, offset, limit) =
+            prepare_search(index, &rtxn, &query, &search_kind, time_budget.clone(), features)?;
+        // no document is returned when only counting the hits, pinned ones included
+        let pinned = if query.count_only {
+            Vec::new()
+        } else {
+            pinned_documents(index, &rtxn, &query, features)?
+        };
+        // the re-ranked documents are kept in the order of the ranking rules below the pinned ones
+        let rerank = reranker.clone().filter(|_| pinned.is_empty() && limit > 0);
+        // the groups are made from every hit reachable through the pagination, not only the page
+        let group_window =
+            (query.group_by.is_some() && !query.count_only).then_some(max_total_hits);
+        if let Some(window) = group_window {
+            search.offset(0);
+            search.limit(window);
+        } else if !pinned.is_empty() {
+            // the pinned documents shift the other results, fetch them from the first one
+            search.offset(0);
+            search.limit(offset + limit);
+        } else if let Some(reranker) = &rerank {
+            // the page is taken once the documents are re-ranked after the search
+            search.offset(0);
+            search.limit((offset + limit).max(reranker.candidates));
+        } else if on_partial_hits.is_some() {
+            search.partial_results(&partial_results);
+        }
+        let query_analysis = if query.show_query_analysis { search.analyze_query()? } else { None };
+        let fetched_from = if group_window.is_some() || !pinned.is_empty() || rerank.is_some() {
+            0
+        } else {
+            offset
+        };
+        let (mut result, semantic_hit_count) = search_from_kind(search_kind.clone(), search)?;
+        // without the candidates, only the hits up to the fetched ones are known to match
+        let known_hits = if query.computes_total_hits() {
+            result.candidates.len() as usize
+        } else {
+            fetched_from + result.documents_ids.len()
+        };
+
+        if let Some(FilterRelaxation { min_hits }) = query.filter_relaxation {
+            if (result.candidates.len() as usize) < min_hits && !result.degraded {
+                if let Some(dropped) = drop_least_important_filter(&mut query.filter) {
+                    dropped_filters.push(dropped);
+                    continue;
+                }
+            }
+        }
+
+        if !pinned.is_empty() {
+            match group_window {
+                Some(window) => pin_documents(&mut result, &pinned, 0, window),
+                None => pin_documents(&mut result, &pinned, offset, limit),
+            }
+        }
+
+        break (
+            result,
+            semantic_hit_count,
+            query_analysis,
+            is_finite_pagination,
+            max_total_hits,
+            offset,
+            limit,
+            rerank.map(|reranker| (reranker, limit)),
+            known_hits,
+        );
+    };
+
+    // computed once the filter relaxation is done, the dropped clauses don't apply to the facets
+    let facet_cache_key = match &query.facets {
+        Some(facets) => Some(facet_cache_key(index, &rtxn, &query, facets)?),
+        None => None,
+    };
 
     let SearchQuery {
         q,
         limit,
         page,
         hits_per_page,
+        compute_total_hits,
+        count_only,
         attributes_to_retrieve,
         // use the enum passed as parameter
         retrieve_vectors: _,
@@ -926,13 +1519,20 @@ pub fn perform_search(
         crop_length,
         attributes_to_highlight,
         show_matches_position,
+        show_matches_info,
+        show_query_coverage,
+        enable_formatting,
         show_ranking_score,
         show_ranking_score_details,
+        show_suggestion,
+        suggestion_max_hits,
         sort,
         facets,
         highlight_pre_tag,
         highlight_post_tag,
         crop_marker,
+        attributes_formatting,
+        prefix_highlight,
         locales,
         // already used in prepare_search
         vector: _,
@@ -940,9 +1540,17 @@ pub fn perform_search(
         offset: _,
         ranking_score_threshold: _,
         matching_strategy: _,
+        max_synonym_expansions: _,
         attributes_to_search_on: _,
+        cross_attribute_matching: _,
+        ranking_rules: _,
+        show_query_analysis: _,
         filter: _,
+        filter_relaxation,
+        boosting_filter: _,
         distinct: _,
+        group_by,
+        group_limit,
     } = query;
 
     let format = AttributesFormat {
@@ -952,24 +1560,79 @@ pub fn perform_search(
         attributes_to_crop,
         crop_length,
         crop_marker,
+        attributes_formatting,
+        prefix_highlight,
         highlight_pre_tag,
         highlight_post_tag,
         show_matches_position,
+        show_matches_info,
+        show_query_coverage,
+        enable_formatting,
         sort,
         show_ranking_score,
         show_ranking_score_details,
         locales: locales.map(|l| l.iter().copied().map(Into::into).collect()),
     };
 
-    let documents = make_hits(
+    let groups = match group_by {
+        Some(group_by) => {
+            let groups =
+                milli::group_documents(index, &rtxn, &group_by, &documents_ids, group_limit)?;
+            Some(groups)
+        }
+        None => None,
+    };
+
+    // when grouping, the documents of every reachable hit were fetched, only the page is returned
+    let page = match groups {
+        Some(_) => {
+            let start = min(offset, documents_ids.len());
+            start..min(start + page_limit, documents_ids.len())
+        }
+        None => 0..documents_ids.len(),
+    };
+    let mut positions: Vec<usize> = page.clone().collect();
+    for group in groups.iter().flatten() {
+        for docid in &group.documents_ids {
+            if let Some(position) = documents_ids.iter().position(|id| id == docid) {
+                if !positions.contains(&position) {
+                    positions.push(position);
+                }
+            }
+        }
+    }
+
+    let mut documents = make_hits(
         index,
         &rtxn,
         format,
         matching_words,
-        documents_ids.iter().copied().zip(document_scores.iter()),
+        positions.iter().map(|&position| (documents_ids[position], &document_scores[position])),
     )?;
 
-    let number_of_hits = min(candidates.len() as usize, max_total_hits);
+    let groups = groups.map(|groups| {
+        groups
+            .into_iter()
+            .map(|milli::DocumentsGroup { value, documents_ids: group_documents_ids }| {
+                let hits = group_documents_ids
+                    .iter()
+                    .filter_map(|docid| {
+                        positions.iter().position(|&position| documents_ids[position] == *docid)
+                    })
+                    .map(|hit| documents[hit].clone())
+                    .collect();
+                HitsGroup { value, hits }
+            })
+            .collect()
+    });
+    documents.truncate(page.len());
+
+    // the count is exact when only counting the hits, no document can be paginated to anyway.
+    let number_of_hits = if count_only {
+        candidates.len() as usize
+    } else {
+        min(candidates.len() as usize, max_total_hits)
+    };
     let hits_info = if is_finite_pagination {
         let hits_per_page = hits_per_page.unwrap_or_else(DEFAULT_SEARCH_LIMIT);
         // If hit_per_page is 0, then pages can't be computed and so we respond 0.
@@ -984,7 +1647,11 @@ pub fn perform_search(
             total_hits: number_of_hits,
         }
     } else {
-        HitsInfo::OffsetLimit { limit, offset, estimated_total_hits: number_of_hits }
+        HitsInfo::OffsetLimit {
+            limit,
+            offset,
+            estimated_total_hits: (compute_total_hits || count_only).then_some(number_of_hits),
+        }
     };
 
     let (facet_distribution, facet_stats) = match facets {
@@ -1018,9 +1685,29 @@ pub fn perform_search(
     };
 
     let facet_stats = facet_stats.map(|stats| {
-        stats.into_iter().map(|(k, (min, max))| (k, FacetStats { min, max })).collect()
+        stats.into_iter().map(|(k, (min, max, avg))| (k, FacetStats { min, max, avg })).collect()
     });
 
+    let rerank = match rerank {
+        Some((settings, page_limit)) => Some(PendingRerank::new(
+            index,
+            &rtxn,
+            settings,
+            q.clone().unwrap_or_default(),
+            &documents_ids,
+            offset,
+            page_limit,
+        )?),
+        None => None,
+    };
+
+    let suggestion = match q.as_deref() {
+        Some(q) if show_suggestion && !degraded && known_hits <= suggestion_max_hits => {
+            milli::suggest_query(index, &rtxn, q)?
+        }
+        _ => None,
+    };
+
     let result = SearchResult {
         hits: documents,
         hits_info,
@@ -1028,13 +1715,75 @@ pub fn perform_search(
         processing_time_ms: before_search.elapsed().as_millis(),
         facet_distribution,
         facet_stats,
+        facet_cache_key,
         degraded,
         used_negative_operator,
         semantic_hit_count,
+        suggestion,
+        query_analysis,
+        dropped_filters: filter_relaxation.map(|_| dropped_filters),
+        groups,
+        rerank,
     };
     Ok(result)
 }
 
+/// Returns a key identifying the context of the facets of a search.
+///
+/// The facets are computed on the candidates of the search, so the key changes with everything
+/// deciding them: the query and how it matches, the filter, the distinct attribute, the requested
+/// facets and every update of the index. A client going through the pages of the same search
+/// can keep displaying the facets it already fetched for the same key.
+fn facet_cache_key(
+    index: &Index,
+    rtxn: &RoTxn,
+    query: &SearchQuery,
+    facets: &[String],
+) -> Result<String, ResponseError> {
+    let updated_at = index.updated_at(rtxn)?;
+
+    let mut hasher = Sha256::new();
+    hasher.update(updated_at.unix_timestamp_nanos().to_be_bytes());
+    hasher.update(query.filter.as_ref().map(Value::to_string).unwrap_or_default());
+    let candidates_context = [
+        format!("{:?}", query.q),
+        format!("{:?}", query.vector),
+        format!("{:?}", query.hybrid),
+        format!("{:?}", query.matching_strategy),
+        format!("{:?}", query.attributes_to_search_on),
+        format!("{:?}", query.cross_attribute_matching),
+        format!("{:?}", query.ranking_score_threshold),
+        format!("{:?}", query.distinct),
+        format!("{:?}", query.locales),
+    ];
+    for context in candidates_context {
+        hasher.update([0]);
+        hasher.update(context);
+    }
+    for facet in facets {
+        // the separator avoids collisions between the lists of facets
+        hasher.update([0]);
+        hasher.update(facet);
+    }
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// Removes the least important clause of the filter and returns it.
+///
+/// Returns `None` when there is no clause left to drop.
+fn drop_least_important_filter(filter: &mut Option<Value>) -> Option<Value> {
+    match filter.take()? {
+        Value::Array(mut clauses) => {
+            let dropped = clauses.pop();
+            if !clauses.is_empty() {
+                *filter = Some(Value::Array(clauses));
+            }
+            dropped
+        }
+        clause => Some(clause),
+    }
+}
+
 pub fn search_from_kind(
     search_kind: SearchKind,
     search: milli::Search<'_>,
@@ -1058,9 +1807,15 @@ struct AttributesFormat {
     attributes_to_crop: Option<Vec<String>>,
     crop_length: usize,
     crop_marker: String,
+    attributes_formatting: Option<BTreeMap<String, AttributeFormatting>>,
+    prefix_highlight: PrefixHighlight,
     highlight_pre_tag: String,
     highlight_post_tag: String,
     show_matches_position: bool,
+    show_matches_info: bool,
+    show_query_coverage: bool,
+    /// Overrides the `enableFormatting` setting of the index when set.
+    enable_formatting: Option<bool>,
     sort: Option<Vec<String>>,
     show_ranking_score: bool,
     show_ranking_score_details: bool,
@@ -1108,11 +1863,18 @@ struct HitMaker<'a> {
     embedding_configs: Vec<milli::index::IndexEmbeddingConfig>,
     formatter_builder: MatcherBuilder<'a>,
     formatted_options: BTreeMap<FieldId, FormatOptions>,
+    /// The tags of the attributes formatted differently from the rest of the document.
+    attributes_tags: Vec<(String, FormatTags)>,
     show_ranking_score: bool,
     show_ranking_score_details: bool,
     sort: Option<Vec<String>>,
     show_matches_position: bool,
+    show_matches_info: bool,
+    show_query_coverage: bool,
+    enable_formatting: bool,
     locales: Option<Vec<Language>>,
+    store_max_length: BTreeMap<String, usize>,
+    primary_key: Option<String>,
 }
 
 impl<'a> HitMaker<'a> {
@@ -1150,6 +1912,7 @@ impl<'a> HitMaker<'a> {
         mut formatter_builder: MatcherBuilder<'a>,
     ) -> Result<Self, MeilisearchHttpError> {
         formatter_builder.crop_marker(format.crop_marker);
+        formatter_builder.prefix_highlight(format.prefix_highlight.into());
         formatter_builder.highlight_prefix(format.highlight_pre_tag);
         formatter_builder.highlight_suffix(format.highlight_post_tag);
 
@@ -1217,7 +1980,18 @@ impl<'a> HitMaker<'a> {
             &displayed_ids,
         );
 
+        let attributes_tags = format
+            .attributes_formatting
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(attribute, formatting)| (attribute, formatting.into()))
+            .collect();
+
         let embedding_configs = index.embedding_configs(rtxn)?;
+        let enable_formatting = match format.enable_formatting {
+            Some(enable_formatting) => enable_formatting,
+            None => index.enable_formatting(rtxn)?,
+        };
 
         Ok(Self {
             index,
@@ -1230,11 +2004,17 @@ impl<'a> HitMaker<'a> {
             embedding_configs,
             formatter_builder,
             formatted_options,
+            attributes_tags,
             show_ranking_score: format.show_ranking_score,
             show_ranking_score_details: format.show_ranking_score_details,
             show_matches_position: format.show_matches_position,
+            show_matches_info: format.show_matches_info,
+            show_query_coverage: format.show_query_coverage,
+            enable_formatting,
             sort: format.sort,
             locales: format.locales,
+            store_max_length: index.store_max_length(rtxn)?,
+            primary_key: index.primary_key(rtxn)?.map(String::from),
         })
     }
 
@@ -1247,7 +2027,13 @@ impl<'a> HitMaker<'a> {
             self.index.iter_documents(self.rtxn, std::iter::once(id))?.next().unwrap()?;
 
         // First generate a document with all the displayed fields
-        let displayed_document = make_document(&self.displayed_ids, &self.fields_ids_map, obkv)?;
+        let mut displayed_document =
+            make_document(&self.displayed_ids, &self.fields_ids_map, obkv.as_obkv())?;
+        milli::truncate_stored_values(
+            &mut displayed_document,
+            &self.store_max_length,
+            self.primary_key.as_deref(),
+        );
 
         let add_vectors_fid =
             self.vectors_fid.filter(|_fid| self.retrieve_vectors == RetrieveVectors::Retrieve);
@@ -1292,17 +2078,51 @@ impl<'a> HitMaker<'a> {
 
         let localized_attributes =
             self.index.localized_attributes_rules(self.rtxn)?.unwrap_or_default();
+        // the whole formatting stage is skipped when disabled, and the values only go through
+        // the matcher when the query coverage is requested
+        let (matches_position, matched_query_words, formatted) = if self.enable_formatting {
+            format_fields(
+                &displayed_document,
+                &self.fields_ids_map,
+                &self.formatter_builder,
+                &self.formatted_options,
+                &self.attributes_tags,
+                self.show_matches_position || self.show_matches_info || self.show_query_coverage,
+                &self.displayed_ids,
+                self.locales.as_deref(),
+                &localized_attributes,
+            )?
+        } else if self.show_query_coverage {
+            let (_, matched_query_words, _) = format_fields(
+                &displayed_document,
+                &self.fields_ids_map,
+                &self.formatter_builder,
+                &BTreeMap::new(),
+                &[],
+                true,
+                &self.displayed_ids,
+                self.locales.as_deref(),
+                &localized_attributes,
+            )?;
+            (None, matched_query_words, Document::new())
+        } else {
+            (None, BTreeSet::new(), Document::new())
+        };
+
+        // the matches info only counts the matches the formatter already computed
+        let matches_info = self.show_matches_info.then(|| {
+            matches_position
+                .iter()
+                .flatten()
+                .map(|(attribute, matches)| (attribute.clone(), matches.len()))
+                .collect()
+        });
+        let matches_position = matches_position.filter(|_| self.show_matches_position);
 
-        let (matches_position, formatted) = format_fields(
-            &displayed_document,
-            &self.fields_ids_map,
-            &self.formatter_builder,
-            &self.formatted_options,
-            self.show_matches_position,
-            &self.displayed_ids,
-            self.locales.as_deref(),
-            &localized_attributes,
-        )?;
+        // a placeholder search has no words to cover
+        let query_word_count = self.formatter_builder.query_word_count();
+        let query_coverage = (self.show_query_coverage && query_word_count != 0)
+            .then(|| matched_query_words.len() as f64 / query_word_count as f64);
 
         if let Some(sort) = self.sort.as_ref() {
             insert_geo_distance(sort, &mut document);
@@ -1317,6 +2137,8 @@ impl<'a> HitMaker<'a> {
             document,
             formatted,
             matches_position,
+            matches_info,
+            query_coverage,
             ranking_score_details,
             ranking_score,
         };
@@ -1358,6 +2180,7 @@ pub fn perform_facet_search(
     search_query: SearchQuery,
     facet_query: Option<String>,
     facet_name: String,
+    fuzzy: bool,
     search_kind: SearchKind,
     features: RoFeatures,
     locales: Option<Vec<Language>>,
@@ -1377,7 +2200,7 @@ pub fn perform_facet_search(
             .map(|attr| attr.locales)
     });
 
-    let (search, _, _, _) =
+    let (search, _, _, _, _) =
         prepare_search(index, &rtxn, &search_query, &search_kind, time_budget, features)?;
     let mut facet_search = SearchForFacetValues::new(
         facet_name,
@@ -1387,6 +2210,7 @@ pub fn perform_facet_search(
     if let Some(facet_query) = &facet_query {
         facet_search.query(facet_query);
     }
+    facet_search.fuzzy(fuzzy);
     if let Some(max_facets) = index.max_values_per_facet(&rtxn)? {
         facet_search.max_values(max_facets as usize);
     }
@@ -1469,9 +2293,14 @@ pub fn perform_similar(
         attributes_to_crop: None,
         crop_length: DEFAULT_CROP_LENGTH(),
         crop_marker: DEFAULT_CROP_MARKER(),
+        attributes_formatting: None,
+        prefix_highlight: PrefixHighlight::default(),
         highlight_pre_tag: DEFAULT_HIGHLIGHT_PRE_TAG(),
         highlight_post_tag: DEFAULT_HIGHLIGHT_POST_TAG(),
         show_matches_position: false,
+        show_matches_info: false,
+        show_query_coverage: false,
+        enable_formatting: Some(false),
         sort: None,
         show_ranking_score,
         show_ranking_score_details,
@@ -1493,7 +2322,8 @@ pub fn perform_similar(
         .unwrap_or(DEFAULT_PAGINATION_MAX_TOTAL_HITS);
 
     let number_of_hits = min(candidates.len() as usize, max_total_hits);
-    let hits_info = HitsInfo::OffsetLimit { limit, offset, estimated_total_hits: number_of_hits };
+    let hits_info =
+        HitsInfo::OffsetLimit { limit, offset, estimated_total_hits: Some(number_of_hits) };
 
     let result = SimilarResult {
         hits,
@@ -1513,9 +2343,13 @@ fn insert_geo_distance(sorts: &[String], document: &mut Document) {
         // TODO: TAMO: milli encountered an internal error, what do we want to do?
         let base = [capture_group[1].parse().unwrap(), capture_group[2].parse().unwrap()];
         let geo_point = &document.get("_geo").unwrap_or(&json!(null));
-        if let Some((lat, lng)) =
-            extract_geo_value(&geo_point["lat"]).zip(extract_geo_value(&geo_point["lng"]))
-        {
+        // GeoJSON points store their coordinates as `[lng, lat]`
+        let (lat, lng) = if geo_point["type"] == "Point" {
+            (&geo_point["coordinates"][1], &geo_point["coordinates"][0])
+        } else {
+            (&geo_point["lat"], &geo_point["lng"])
+        };
+        if let Some((lat, lng)) = extract_geo_value(lat).zip(extract_geo_value(lng)) {
             let distance = milli::distance_between_two_points(&base, &[lat, lng]);
             document.insert("_geoDistance".to_string(), json!(distance.round() as usize));
         }
@@ -1663,12 +2497,14 @@ fn format_fields(
     field_ids_map: &FieldsIdsMap,
     builder: &MatcherBuilder<'_>,
     formatted_options: &BTreeMap<FieldId, FormatOptions>,
+    attributes_tags: &[(String, FormatTags)],
     compute_matches: bool,
     displayable_ids: &BTreeSet<FieldId>,
     locales: Option<&[Language]>,
     localized_attributes: &[LocalizedAttributesRule],
-) -> Result<(Option<MatchesPosition>, Document), MeilisearchHttpError> {
+) -> Result<(Option<MatchesPosition>, BTreeSet<u16>, Document), MeilisearchHttpError> {
     let mut matches_position = compute_matches.then(BTreeMap::new);
+    let mut matched_query_words = BTreeSet::new();
     let mut document = document.clone();
 
     // reduce the formatted option list to the attributes that should be formatted,
@@ -1697,6 +2533,12 @@ fn format_fields(
             })
             .map(|(_, option)| **option)
             .reduce(|acc, option| acc.merge(option));
+        // the tags of the most specific attribute containing the key replace the global ones
+        let tags = attributes_tags
+            .iter()
+            .filter(|(name, _tags)| milli::is_faceted_by(key, name))
+            .max_by_key(|(name, _tags)| name.len())
+            .map(|(_, tags)| tags);
         let mut infos = Vec::new();
 
         // if no locales has been provided, we try to find the locales in the localized_attributes.
@@ -1711,7 +2553,9 @@ fn format_fields(
             std::mem::take(value),
             builder,
             format,
+            tags,
             &mut infos,
+            &mut matched_query_words,
             compute_matches,
             locales,
         );
@@ -1730,23 +2574,29 @@ fn format_fields(
         .map(|&fid| field_ids_map.name(fid).unwrap());
     let document = permissive_json_pointer::select_values(&document, selectors);
 
-    Ok((matches_position, document))
+    Ok((matches_position, matched_query_words, document))
 }
 
 fn format_value(
     value: Value,
     builder: &MatcherBuilder<'_>,
     format_options: Option<FormatOptions>,
+    tags: Option<&FormatTags>,
     infos: &mut Vec<MatchBounds>,
+    matched_query_words: &mut BTreeSet<u16>,
     compute_matches: bool,
     locales: Option<&[Language]>,
 ) -> Value {
     match value {
         Value::String(old_string) => {
             let mut matcher = builder.build(&old_string, locales);
+            if let Some(tags) = tags {
+                matcher.override_tags(tags);
+            }
             if compute_matches {
                 let matches = matcher.matches();
                 infos.extend_from_slice(&matches[..]);
+                matched_query_words.extend(matcher.matched_query_words());
             }
 
             match format_options {
@@ -1768,7 +2618,9 @@ fn format_value(
                             highlight: format_options.highlight,
                             crop: None,
                         }),
+                        tags,
                         infos,
+                        matched_query_words,
                         compute_matches,
                         locales,
                     )
@@ -1788,7 +2640,9 @@ fn format_value(
                                 highlight: format_options.highlight,
                                 crop: None,
                             }),
+                            tags,
                             infos,
+                            matched_query_words,
                             compute_matches,
                             locales,
                         ),
@@ -1800,9 +2654,13 @@ fn format_value(
             let s = number.to_string();
 
             let mut matcher = builder.build(&s, locales);
+            if let Some(tags) = tags {
+                matcher.override_tags(tags);
+            }
             if compute_matches {
                 let matches = matcher.matches();
                 infos.extend_from_slice(&matches[..]);
+                matched_query_words.extend(matcher.matched_query_words());
             }
 
             match format_options {
@@ -1943,6 +2801,38 @@ mod test {
         assert_eq!(document.get("_geoDistance"), None);
     }
 
+    #[test]
+    fn test_insert_geo_distance_with_geojson_point() {
+        let value: Document = serde_json::from_str(
+            r#"{
+              "_geo": {
+                "type": "Point",
+                "coordinates": [3.0569447399419567, 50.629973371633746]
+              },
+              "city": "Lille",
+              "id": "1"
+            }"#,
+        )
+        .unwrap();
+
+        let sorters = &["_geoPoint(50.629973371633746,3.0569447399419567):desc".to_string()];
+        let mut document = value.clone();
+        insert_geo_distance(sorters, &mut document);
+        assert_eq!(document.get("_geoDistance"), Some(&json!(0)));
+
+        // the distance must be the same as the one of the equivalent `_geo` document
+        let sorters = &["_geoPoint(0., 0.):desc".to_string()];
+        let mut document = value;
+        insert_geo_distance(sorters, &mut document);
+        let mut lat_lng_document: Document = serde_json::from_str(
+            r#"{ "_geo": { "lat": 50.629973371633746, "lng": 3.0569447399419567 } }"#,
+        )
+        .unwrap();
+        insert_geo_distance(sorters, &mut lat_lng_document);
+        assert!(document.get("_geoDistance").is_some());
+        assert_eq!(document.get("_geoDistance"), lat_lng_document.get("_geoDistance"));
+    }
+
     #[test]
     fn test_insert_geo_distance_with_coords_as_string() {
         let value: Document = serde_json::from_str(
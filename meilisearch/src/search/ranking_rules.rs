@@ -247,6 +247,8 @@ impl RankingRules {
                         canonical_criteria.push(criterion.clone())
                     }
                 },
+                // `_recency` is resolved into a `Desc` rule by `Index::resolved_criteria`
+                Criterion::Recency => {}
             }
         }
 
@@ -327,6 +329,8 @@ impl RankingRules {
                         canonical_criteria.push(criterion.clone())
                     }
                 },
+                // `_recency` is resolved into a `Desc` rule by `Index::resolved_criteria`
+                Criterion::Recency => {}
             }
         }
 
@@ -461,6 +465,8 @@ impl RankingRules {
                         canonical_criteria.push(criterion)
                     }
                 },
+                // `_recency` is resolved into a `Desc` rule by `Index::resolved_criteria`
+                Criterion::Recency => {}
             }
         }
 
@@ -729,7 +735,7 @@ impl RankingRule {
             Criterion::Asc(_) => RankingRuleKind::AscendingSort,
             Criterion::Desc(s) if s == "_geo" => RankingRuleKind::DescendingGeoSort,
 
-            Criterion::Desc(_) => RankingRuleKind::DescendingSort,
+            Criterion::Desc(_) | Criterion::Recency => RankingRuleKind::DescendingSort,
             Criterion::Sort => {
                 return either::Right(sort.iter().flatten().enumerate().map(
                     move |(rule_index, asc_desc)| {
@@ -0,0 +1,136 @@
+use std::collections::BTreeSet;
+use std::time::{Duration, Instant};
+
+use meilisearch_types::heed::RoTxn;
+use meilisearch_types::milli::vector::parsed_vectors::RESERVED_VECTORS_FIELD_NAME;
+use meilisearch_types::milli::{DocumentId, Index, RerankerSettings};
+use meilisearch_types::Document;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use super::{make_document, SearchResult};
+use crate::error::MeilisearchHttpError;
+
+const RERANKER_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The re-ranking left to do on the hits of a search, once its read transaction is released.
+///
+/// The hits of the search then go from the first one to the end of the requested page, or to
+/// the number of documents re-scored if there are more of them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PendingRerank {
+    settings: RerankerSettings,
+    query: String,
+    /// The displayed attributes of the documents sent to the endpoint, in the order of the hits.
+    documents: Vec<Document>,
+    offset: usize,
+    limit: usize,
+}
+
+impl PendingRerank {
+    /// Reads the displayed attributes of the documents to re-score.
+    pub(super) fn new(
+        index: &Index,
+        rtxn: &RoTxn,
+        settings: RerankerSettings,
+        query: String,
+        documents_ids: &[DocumentId],
+        offset: usize,
+        limit: usize,
+    ) -> Result<Self, MeilisearchHttpError> {
+        let fields_ids_map = index.fields_ids_map(rtxn)?;
+        let displayed_ids: BTreeSet<_> = match index.displayed_fields_ids(rtxn)? {
+            Some(fields) => fields.into_iter().collect(),
+            None => fields_ids_map.iter().map(|(id, _)| id).collect(),
+        };
+
+        let count = documents_ids.len().min(settings.candidates);
+        let mut documents = Vec::with_capacity(count);
+        for document in index.iter_documents(rtxn, documents_ids[..count].iter().copied())? {
+            let (_docid, obkv) = document?;
+            let mut document = make_document(&displayed_ids, &fields_ids_map, obkv.as_obkv())?;
+            document.remove(RESERVED_VECTORS_FIELD_NAME);
+            documents.push(document);
+        }
+
+        Ok(PendingRerank { settings, query, documents, offset, limit })
+    }
+}
+
+#[derive(Serialize)]
+struct RerankRequest<'a> {
+    query: &'a str,
+    documents: &'a [Document],
+}
+
+#[derive(Deserialize)]
+struct RerankResponse {
+    scores: Vec<f64>,
+}
+
+/// Sorts the most relevant hits of the search by the scores returned by the re-ranker of the
+/// index, then keeps the requested page of them.
+///
+/// It must be awaited by the search routes once the blocking search task is done, so that the
+/// request to the endpoint neither holds the read transaction of the index nor a blocking thread.
+/// The re-ranker score is added to the ranking score details of the re-scored hits.
+///
+/// The search must not fail because the endpoint is down or answers nonsense: the hits are then
+/// kept in the order of the ranking rules.
+pub async fn rerank_search_result(result: &mut SearchResult) {
+    let Some(PendingRerank { settings, query, documents, offset, limit }) = result.rerank.take()
+    else {
+        return;
+    };
+    let before_rerank = Instant::now();
+
+    let count = documents.len();
+    if count >= 2 {
+        match request_scores(&settings, &query, &documents).await {
+            Ok(scores) if scores.len() == count => {
+                let mut reranked: Vec<_> = result.hits.drain(..count).zip(scores).collect();
+                // the sort is stable, so equal scores keep the order of the ranking rules
+                reranked.sort_by(|(_, left), (_, right)| right.total_cmp(left));
+
+                let following = std::mem::take(&mut result.hits);
+                for (mut hit, score) in reranked {
+                    if let Some(details) = &mut hit.ranking_score_details {
+                        let order = details.len();
+                        details.insert(
+                            "reranker".to_string(),
+                            json!({ "order": order, "score": score }),
+                        );
+                    }
+                    result.hits.push(hit);
+                }
+                result.hits.extend(following);
+            }
+            Ok(scores) => tracing::warn!(
+                "The reranker returned {} scores for {count} documents, the documents are not re-ranked",
+                scores.len()
+            ),
+            Err(error) => tracing::warn!("While re-ranking the documents: {error}"),
+        }
+    }
+
+    result.hits = std::mem::take(&mut result.hits).into_iter().skip(offset).take(limit).collect();
+    result.processing_time_ms += before_rerank.elapsed().as_millis();
+}
+
+async fn request_scores(
+    settings: &RerankerSettings,
+    query: &str,
+    documents: &[Document],
+) -> Result<Vec<f64>, reqwest::Error> {
+    let mut request = reqwest::Client::new()
+        .post(&settings.url)
+        .timeout(RERANKER_TIMEOUT)
+        .json(&RerankRequest { query, documents });
+    if let Some(api_key) = &settings.api_key {
+        request = request.bearer_auth(api_key);
+    }
+
+    let response = request.send().await?.error_for_status()?;
+    let response: RerankResponse = response.json().await?;
+    Ok(response.scores)
+}
@@ -0,0 +1,151 @@
+//! This file implements an in-memory log of the most recent searches of each index.
+//! It lets you look at what is searched and how many documents are found without sending the
+//! queries to an external logging system.
+//!
+//! The log is disabled unless a size is given with the `--experimental-search-log-size` option.
+//! Only the last searches are kept for each index, the oldest ones are dropped as new ones are recorded.
+//! The size of the log is bounded too: the long queries and filters are truncated and only the
+//! most recently searched indexes are kept.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use serde::Serialize;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use crate::search::{HitsInfo, SearchResult};
+
+/// The number of characters of a query kept in the log, the rest is cut off.
+const MAX_LOGGED_QUERY_CHARS: usize = 256;
+/// The number of bytes of a filter kept in the log, a longer filter is logged as its truncated JSON text.
+const MAX_LOGGED_FILTER_BYTES: usize = 1024;
+/// The number of indexes with a search log, the least recently searched index is forgotten beyond it.
+const MAX_LOGGED_INDEXES: usize = 100;
+
+/// Prefixes the hashed queries, so that they can't be matched against the hashes of guessed queries.
+///
+/// It is drawn once per process, the hash of a query only stays the same until a restart.
+static QUERY_HASH_SALT: Lazy<[u8; 32]> = Lazy::new(rand::random);
+
+#[derive(Debug, Default)]
+pub struct SearchLog {
+    capacity: usize,
+    hash_queries: bool,
+    /// The number of searches recorded so far.
+    recorded: AtomicU64,
+    searches: Mutex<HashMap<String, IndexSearchLog>>,
+}
+
+#[derive(Debug, Default)]
+struct IndexSearchLog {
+    /// The number of searches recorded before the last one of this index, to find the least
+    /// recently searched index.
+    last_searched: u64,
+    searches: VecDeque<LoggedSearch>,
+}
+
+/// A search as it appears in the search log.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LoggedSearch {
+    /// The text of the query, or its salted SHA-256 hash when the queries are hashed.
+    pub q: Option<String>,
+    pub filter: Option<Value>,
+    /// The number of documents matching the search, or the number of hits
+    /// returned when the total number of hits wasn't computed.
+    pub hits_count: usize,
+    pub processing_time_ms: u128,
+}
+
+impl SearchLog {
+    pub fn new(capacity: usize, hash_queries: bool) -> Self {
+        Self { capacity, hash_queries, recorded: AtomicU64::new(0), searches: Mutex::default() }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.capacity != 0
+    }
+
+    /// Records a successful search of the given index, dropping its oldest search if its log is full.
+    pub fn record(
+        &self,
+        index_uid: &str,
+        q: Option<String>,
+        filter: Option<Value>,
+        result: &SearchResult,
+    ) {
+        if !self.is_enabled() {
+            return;
+        }
+
+        let q = if self.hash_queries {
+            q.map(|q| {
+                format!(
+                    "{:x}",
+                    Sha256::new().chain_update(*QUERY_HASH_SALT).chain_update(q).finalize()
+                )
+            })
+        } else {
+            q.map(truncate_query)
+        };
+        let filter = filter.map(truncate_filter);
+        let hits_count = match result.hits_info {
+            HitsInfo::Pagination { total_hits, .. } => total_hits,
+            HitsInfo::OffsetLimit { estimated_total_hits, .. } => {
+                estimated_total_hits.unwrap_or(result.hits.len())
+            }
+        };
+        let search =
+            LoggedSearch { q, filter, hits_count, processing_time_ms: result.processing_time_ms };
+
+        let mut logs = self.searches.lock();
+        let searches_count = self.recorded.fetch_add(1, Ordering::Relaxed);
+        if !logs.contains_key(index_uid) && logs.len() == MAX_LOGGED_INDEXES {
+            let least_recent = logs
+                .iter()
+                .min_by_key(|(_, log)| log.last_searched)
+                .map(|(index_uid, _)| index_uid.clone());
+            if let Some(least_recent) = least_recent {
+                logs.remove(&least_recent);
+            }
+        }
+
+        let log = logs.entry(index_uid.to_string()).or_default();
+        log.last_searched = searches_count;
+        if log.searches.len() == self.capacity {
+            log.searches.pop_back();
+        }
+        log.searches.push_front(search);
+    }
+
+    /// Returns the recent searches of the given index, the newest first.
+    pub fn recent_searches(&self, index_uid: &str) -> Vec<LoggedSearch> {
+        self.searches
+            .lock()
+            .get(index_uid)
+            .map(|log| log.searches.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+}
+
+fn truncate_query(q: String) -> String {
+    match q.char_indices().nth(MAX_LOGGED_QUERY_CHARS) {
+        Some((end, _)) => q[..end].to_string(),
+        None => q,
+    }
+}
+
+fn truncate_filter(filter: Value) -> Value {
+    let text = filter.to_string();
+    if text.len() <= MAX_LOGGED_FILTER_BYTES {
+        return filter;
+    }
+    let mut end = MAX_LOGGED_FILTER_BYTES;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    Value::String(text[..end].to_string())
+}
@@ -18,17 +18,38 @@
 //!                         And should drop the Permit only once you have freed all the RAM consumed by the method.
 
 use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
 
 use rand::rngs::StdRng;
 use rand::{Rng, SeedableRng};
+use serde::Serialize;
 use tokio::sync::{mpsc, oneshot};
 
 use crate::error::MeilisearchHttpError;
+use crate::metrics::{
+    MEILISEARCH_SEARCHES_RUNNING, MEILISEARCH_SEARCHES_WAITING_TO_BE_PROCESSED,
+    MEILISEARCH_SEARCH_QUEUE_SIZE,
+};
 
 #[derive(Debug)]
 pub struct SearchQueue {
     sender: mpsc::Sender<oneshot::Sender<Permit>>,
     capacity: usize,
+    searches_running: Arc<AtomicUsize>,
+    searches_waiting: Arc<AtomicUsize>,
+}
+
+/// The state of the search queue, as shown by the `stats` route.
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchQueueStats {
+    /// The number of searches that can wait for a permit before the oldest ones are refused.
+    pub capacity: usize,
+    /// The number of searches currently holding a permit.
+    pub searches_running: usize,
+    /// The number of searches waiting for a permit.
+    pub searches_waiting: usize,
 }
 
 /// You should only run search requests while holding this permit.
@@ -51,9 +72,17 @@ impl SearchQueue {
         // Search requests are going to wait until we're available anyway,
         // so let's not allocate any RAM and keep a capacity of 1.
         let (sender, receiver) = mpsc::channel(1);
+        let searches_running = Arc::new(AtomicUsize::new(0));
+        let searches_waiting = Arc::new(AtomicUsize::new(0));
 
-        tokio::task::spawn(Self::run(capacity, paralellism, receiver));
-        Self { sender, capacity }
+        tokio::task::spawn(Self::run(
+            capacity,
+            paralellism,
+            receiver,
+            searches_running.clone(),
+            searches_waiting.clone(),
+        ));
+        Self { sender, capacity, searches_running, searches_waiting }
     }
 
     /// This function is the main loop, it's in charge on scheduling which search request should execute first and
@@ -64,14 +93,22 @@ impl SearchQueue {
         capacity: usize,
         parallelism: NonZeroUsize,
         mut receive_new_searches: mpsc::Receiver<oneshot::Sender<Permit>>,
+        running: Arc<AtomicUsize>,
+        waiting: Arc<AtomicUsize>,
     ) {
         let mut queue: Vec<oneshot::Sender<Permit>> = Default::default();
         let mut rng: StdRng = StdRng::from_entropy();
         let mut searches_running: usize = 0;
         // By having a capacity of parallelism we ensures that every time a search finish it can release its RAM asap
         let (sender, mut search_finished) = mpsc::channel(parallelism.into());
+        MEILISEARCH_SEARCH_QUEUE_SIZE.set(capacity as i64);
 
         loop {
+            MEILISEARCH_SEARCHES_RUNNING.set(searches_running as i64);
+            MEILISEARCH_SEARCHES_WAITING_TO_BE_PROCESSED.set(queue.len() as i64);
+            running.store(searches_running, Ordering::Relaxed);
+            waiting.store(queue.len(), Ordering::Relaxed);
+
             tokio::select! {
                 // biased select because we wants to free up space before trying to register new tasks
                 biased;
@@ -124,6 +161,15 @@ impl SearchQueue {
         receiver.await.map_err(|_| MeilisearchHttpError::TooManySearchRequests(self.capacity))
     }
 
+    /// Returns the number of searches running and waiting for a permit.
+    pub fn stats(&self) -> SearchQueueStats {
+        SearchQueueStats {
+            capacity: self.capacity,
+            searches_running: self.searches_running.load(Ordering::Relaxed),
+            searches_waiting: self.searches_waiting.load(Ordering::Relaxed),
+        }
+    }
+
     /// Returns `Ok(())` if everything seems normal.
     /// Returns `Err(MeilisearchHttpError::SearchLimiterIsDown)` if the search limiter seems down.
     pub fn health(&self) -> Result<(), MeilisearchHttpError> {
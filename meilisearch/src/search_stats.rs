@@ -0,0 +1,178 @@
+//! This file implements the search statistics, aggregated out of the search events recorded
+//! by the index scheduler to help tuning the relevancy.
+//!
+//! The searches are only recorded when a size is given with the `--experimental-search-stats-size` option.
+//! The events are stored on disk, only the most recent ones are kept, and they contain nothing
+//! identifying who searched. The text of a query is only kept when it found no documents.
+
+use std::collections::HashMap;
+
+use actix_web::web::Data;
+use index_scheduler::{IndexScheduler, SearchEvent};
+use serde::Serialize;
+use time::OffsetDateTime;
+
+use crate::search::{HitsInfo, SearchQuery, SearchResult};
+
+/// The number of zero-result queries listed when the query parameters don't say otherwise.
+pub const DEFAULT_TOP_ZERO_RESULT_QUERIES: usize = 10;
+
+/// A search whose result must be recorded in the search events once it is done.
+pub struct PendingSearchEvent {
+    index_scheduler: Data<IndexScheduler>,
+    query_length: usize,
+    /// The trimmed and lowercased query, `None` for a placeholder search.
+    query: Option<String>,
+    filtered: bool,
+}
+
+impl PendingSearchEvent {
+    /// Returns `None` when the search events are disabled.
+    ///
+    /// Must be called before the rules of a tenant token are added to the filter of the query.
+    pub fn new(index_scheduler: &Data<IndexScheduler>, query: &SearchQuery) -> Option<Self> {
+        if !index_scheduler.search_events_enabled() {
+            return None;
+        }
+
+        let q = query.q.as_deref().map(str::trim).unwrap_or_default();
+        Some(Self {
+            index_scheduler: index_scheduler.clone(),
+            query_length: q.chars().count(),
+            query: (!q.is_empty()).then(|| q.to_lowercase()),
+            filtered: query.filter.is_some(),
+        })
+    }
+
+    /// Records the search in the background, the search doesn't fail if it can't be recorded.
+    pub fn record(self, index_uid: &str, result: &SearchResult) {
+        let hits_count = match result.hits_info {
+            HitsInfo::Pagination { total_hits, .. } => total_hits,
+            HitsInfo::OffsetLimit { estimated_total_hits, .. } => {
+                estimated_total_hits.unwrap_or(result.hits.len())
+            }
+        };
+        let event = SearchEvent {
+            index_uid: index_uid.to_string(),
+            searched_at: OffsetDateTime::now_utc(),
+            query_length: self.query_length,
+            zero_result_query: self.query.filter(|_| hits_count == 0),
+            filtered: self.filtered,
+            processing_time_ms: result.processing_time_ms as u64,
+            hits_count,
+        };
+
+        self.index_scheduler.record_search_event(event);
+    }
+}
+
+/// The percentiles of a measure of the searches, computed with the nearest-rank method.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Percentiles {
+    pub p50: u64,
+    pub p90: u64,
+    pub p95: u64,
+    pub p99: u64,
+    pub max: u64,
+}
+
+impl Percentiles {
+    /// Returns `None` when there are no values.
+    fn new(mut values: Vec<u64>) -> Option<Self> {
+        values.sort_unstable();
+        let max = *values.last()?;
+        let percentile = |p: usize| values[((values.len() * p + 99) / 100).saturating_sub(1)];
+        Some(Percentiles {
+            p50: percentile(50),
+            p90: percentile(90),
+            p95: percentile(95),
+            p99: percentile(99),
+            max,
+        })
+    }
+}
+
+/// A query that found no documents, along with the number of times it was searched.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ZeroResultQuery {
+    pub q: String,
+    pub count: usize,
+}
+
+/// The statistics of the recorded searches, returned by the `/stats/searches` route.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchStats {
+    pub total_searches: usize,
+    pub zero_result_searches: usize,
+    /// The share of the searches that found no documents, between 0 and 1.
+    pub zero_result_rate: f64,
+    pub filtered_searches: usize,
+    pub processing_time_ms: Option<Percentiles>,
+    pub hits_count: Option<Percentiles>,
+    pub query_length: Option<Percentiles>,
+    /// The most frequent queries among the ones that found no documents.
+    pub top_zero_result_queries: Vec<ZeroResultQuery>,
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub oldest_search_at: Option<OffsetDateTime>,
+}
+
+impl SearchStats {
+    /// Aggregates the events, listing at most `top_queries_limit` zero-result queries.
+    pub fn new(events: impl IntoIterator<Item = SearchEvent>, top_queries_limit: usize) -> Self {
+        let mut total_searches = 0;
+        let mut zero_result_searches = 0;
+        let mut filtered_searches = 0;
+        let mut processing_times = Vec::new();
+        let mut hits_counts = Vec::new();
+        let mut query_lengths = Vec::new();
+        let mut zero_result_queries: HashMap<String, usize> = HashMap::new();
+        let mut oldest_search_at: Option<OffsetDateTime> = None;
+
+        for event in events {
+            total_searches += 1;
+            if event.is_empty() {
+                zero_result_searches += 1;
+            }
+            if event.filtered {
+                filtered_searches += 1;
+            }
+            processing_times.push(event.processing_time_ms);
+            hits_counts.push(event.hits_count as u64);
+            query_lengths.push(event.query_length as u64);
+            if let Some(query) = event.zero_result_query {
+                *zero_result_queries.entry(query).or_default() += 1;
+            }
+            oldest_search_at = Some(
+                oldest_search_at.map_or(event.searched_at, |oldest| oldest.min(event.searched_at)),
+            );
+        }
+
+        let mut top_zero_result_queries: Vec<_> = zero_result_queries
+            .into_iter()
+            .map(|(q, count)| ZeroResultQuery { q, count })
+            .collect();
+        // the most searched first, the ties in alphabetical order
+        top_zero_result_queries.sort_unstable_by(|left, right| {
+            right.count.cmp(&left.count).then(left.q.cmp(&right.q))
+        });
+        top_zero_result_queries.truncate(top_queries_limit);
+
+        let zero_result_rate = match total_searches {
+            0 => 0.0,
+            total => zero_result_searches as f64 / total as f64,
+        };
+
+        SearchStats {
+            total_searches,
+            zero_result_searches,
+            zero_result_rate,
+            filtered_searches,
+            processing_time_ms: Percentiles::new(processing_times),
+            hits_count: Percentiles::new(hits_counts),
+            query_length: Percentiles::new(query_lengths),
+            top_zero_result_queries,
+            oldest_search_at,
+        }
+    }
+}
@@ -421,7 +421,7 @@ async fn error_add_api_key_invalid_parameters_actions() {
     meili_snap::snapshot!(code, @"400 Bad Request");
     meili_snap::snapshot!(meili_snap::json_string!(response, { ".createdAt" => "[ignored]", ".updatedAt" => "[ignored]" }), @r###"
     {
-      "message": "Unknown value `doc.add` at `.actions[0]`: expected one of `*`, `search`, `documents.*`, `documents.add`, `documents.get`, `documents.delete`, `indexes.*`, `indexes.create`, `indexes.get`, `indexes.update`, `indexes.delete`, `indexes.swap`, `tasks.*`, `tasks.cancel`, `tasks.delete`, `tasks.get`, `settings.*`, `settings.get`, `settings.update`, `stats.*`, `stats.get`, `metrics.*`, `metrics.get`, `dumps.*`, `dumps.create`, `snapshots.*`, `snapshots.create`, `version`, `keys.create`, `keys.get`, `keys.update`, `keys.delete`, `experimental.get`, `experimental.update`",
+      "message": "Unknown value `doc.add` at `.actions[0]`: expected one of `*`, `search`, `documents.*`, `documents.add`, `documents.get`, `documents.delete`, `indexes.*`, `indexes.create`, `indexes.get`, `indexes.update`, `indexes.delete`, `indexes.swap`, `tasks.*`, `tasks.cancel`, `tasks.delete`, `tasks.get`, `settings.*`, `settings.get`, `settings.update`, `stats.*`, `stats.get`, `metrics.*`, `metrics.get`, `dumps.*`, `dumps.create`, `snapshots.*`, `snapshots.create`, `version`, `keys.create`, `keys.get`, `keys.update`, `keys.delete`, `experimental.get`, `experimental.update`, `maintenance.get`, `maintenance.update`, `snapshots.settings.get`, `snapshots.settings.update`, `webhooks.get`, `webhooks.update`",
       "code": "invalid_api_key_actions",
       "type": "invalid_request",
       "link": "https://docs.meilisearch.com/errors#invalid_api_key_actions"
@@ -1393,7 +1393,7 @@ async fn error_patch_api_key_indexes() {
     let (response, code) = server.patch_api_key(&uid, content).await;
     meili_snap::snapshot!(meili_snap::json_string!(response, { ".createdAt" => "[ignored]", ".updatedAt" => "[ignored]" }), @r###"
     {
-      "message": "Immutable field `indexes`: expected one of `description`, `name`",
+      "message": "Immutable field `indexes`: expected one of `description`, `name`, `rateLimit`",
       "code": "immutable_api_key_indexes",
       "type": "invalid_request",
       "link": "https://docs.meilisearch.com/errors#immutable_api_key_indexes"
@@ -1470,7 +1470,7 @@ async fn error_patch_api_key_actions() {
     let (response, code) = server.patch_api_key(&uid, content).await;
     meili_snap::snapshot!(meili_snap::json_string!(response, { ".createdAt" => "[ignored]", ".updatedAt" => "[ignored]" }), @r###"
     {
-      "message": "Immutable field `actions`: expected one of `description`, `name`",
+      "message": "Immutable field `actions`: expected one of `description`, `name`, `rateLimit`",
       "code": "immutable_api_key_actions",
       "type": "invalid_request",
       "link": "https://docs.meilisearch.com/errors#immutable_api_key_actions"
@@ -1539,7 +1539,7 @@ async fn error_patch_api_key_expiration_date() {
     let (response, code) = server.patch_api_key(&uid, content).await;
     meili_snap::snapshot!(meili_snap::json_string!(response, { ".createdAt" => "[ignored]", ".updatedAt" => "[ignored]" }), @r###"
     {
-      "message": "Immutable field `expiresAt`: expected one of `description`, `name`",
+      "message": "Immutable field `expiresAt`: expected one of `description`, `name`, `rateLimit`",
       "code": "immutable_api_key_expires_at",
       "type": "invalid_request",
       "link": "https://docs.meilisearch.com/errors#immutable_api_key_expires_at"
@@ -1779,3 +1779,89 @@ async fn error_access_api_key_routes_no_master_key_set() {
     "###);
     meili_snap::snapshot!(code, @"401 Unauthorized");
 }
+
+#[actix_rt::test]
+async fn api_key_rate_limit() {
+    let mut server = Server::new_auth().await;
+    server.use_api_key("MASTER_KEY");
+
+    let content = json!({
+        "uid": "4bc0887a-0e41-4f3b-935d-0c451dcee9c8",
+        "indexes": ["products"],
+        "actions": ["search"],
+        "expiresAt": null,
+        "rateLimit": { "requestsPerSecond": 1, "burst": 2 },
+    });
+    let (response, code) = server.add_api_key(content).await;
+    meili_snap::snapshot!(code, @"201 Created");
+    meili_snap::snapshot!(meili_snap::json_string!(response["rateLimit"]), @r###"
+    {
+      "requestsPerSecond": 1,
+      "burst": 2
+    }
+    "###);
+    let key = response["key"].as_str().unwrap().to_string();
+
+    server.use_api_key(&key);
+    let index = server.index("products");
+    for _ in 0..2 {
+        let (_response, code) = index.search_post(json!({})).await;
+        meili_snap::snapshot!(code, @"404 Not Found");
+    }
+    let (response, code) = index.search_post(json!({})).await;
+    meili_snap::snapshot!(code, @"429 Too Many Requests");
+    meili_snap::snapshot!(meili_snap::json_string!(response), @r###"
+    {
+      "message": "The API key exceeded its rate limit. Retry after 1s.",
+      "code": "api_key_rate_limit_exceeded",
+      "type": "auth",
+      "link": "https://docs.meilisearch.com/errors#api_key_rate_limit_exceeded"
+    }
+    "###);
+
+    // the rate limit can be removed
+    server.use_api_key("MASTER_KEY");
+    let (_response, code) = server.patch_api_key(&key, json!({ "rateLimit": null })).await;
+    meili_snap::snapshot!(code, @"200 OK");
+
+    server.use_api_key(&key);
+    let index = server.index("products");
+    let (_response, code) = index.search_post(json!({})).await;
+    meili_snap::snapshot!(code, @"404 Not Found");
+
+    // and set again, the cached rate limit of the key follows its updates
+    server.use_api_key("MASTER_KEY");
+    let (_response, code) =
+        server.patch_api_key(&key, json!({ "rateLimit": { "requestsPerSecond": 1 } })).await;
+    meili_snap::snapshot!(code, @"200 OK");
+
+    server.use_api_key(&key);
+    let index = server.index("products");
+    let (_response, code) = index.search_post(json!({})).await;
+    meili_snap::snapshot!(code, @"404 Not Found");
+    let (_response, code) = index.search_post(json!({})).await;
+    meili_snap::snapshot!(code, @"429 Too Many Requests");
+}
+
+#[actix_rt::test]
+async fn error_add_api_key_invalid_rate_limit() {
+    let mut server = Server::new_auth().await;
+    server.use_api_key("MASTER_KEY");
+
+    let content = json!({
+        "indexes": ["products"],
+        "actions": ["search"],
+        "expiresAt": null,
+        "rateLimit": { "requestsPerSecond": 0 },
+    });
+    let (response, code) = server.add_api_key(content).await;
+    meili_snap::snapshot!(code, @"400 Bad Request");
+    meili_snap::snapshot!(meili_snap::json_string!(response), @r###"
+    {
+      "message": "Invalid value at `.rateLimit`: `requestsPerSecond` and `burst` must be greater than zero.",
+      "code": "invalid_api_key_rate_limit",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_api_key_rate_limit"
+    }
+    "###);
+}
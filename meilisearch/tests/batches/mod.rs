@@ -0,0 +1,194 @@
+use meili_snap::insta::assert_json_snapshot;
+use meili_snap::{json_string, snapshot};
+
+use crate::common::Server;
+use crate::json;
+
+#[actix_rt::test]
+async fn get_batch() {
+    let server = Server::new().await;
+    let index = server.index("test");
+    let (task, _code) = index.create(None).await;
+    index.wait_task(task.uid()).await.succeeded();
+    let (task, _code) = index.add_documents(json!([{ "id": 1, "content": "foobar" }]), None).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (batch, code) = server.get_batch(1).await;
+    snapshot!(code, @"200 OK");
+    assert_json_snapshot!(batch,
+        {
+            ".duration" => "[duration]",
+            ".startedAt" => "[date]",
+            ".finishedAt" => "[date]",
+            ".steps[].duration" => "[duration]",
+            ".steps[].startedAt" => "[date]",
+            ".steps[].finishedAt" => "[date]",
+        },
+        @r###"
+    {
+      "uid": 1,
+      "progress": null,
+      "stats": {
+        "totalNbTasks": 1,
+        "status": {
+          "succeeded": 1
+        },
+        "types": {
+          "documentAdditionOrUpdate": 1
+        },
+        "indexUids": {
+          "test": 1
+        }
+      },
+      "steps": [
+        {
+          "name": "creating the batch",
+          "duration": "[duration]",
+          "startedAt": "[date]",
+          "finishedAt": "[date]"
+        },
+        {
+          "name": "processing the tasks",
+          "duration": "[duration]",
+          "startedAt": "[date]",
+          "finishedAt": "[date]"
+        },
+        {
+          "name": "remapping the documents",
+          "duration": "[duration]",
+          "startedAt": "[date]",
+          "finishedAt": "[date]"
+        },
+        {
+          "name": "computing the documents ids",
+          "duration": "[duration]",
+          "startedAt": "[date]",
+          "finishedAt": "[date]"
+        },
+        {
+          "name": "indexing the documents",
+          "duration": "[duration]",
+          "startedAt": "[date]",
+          "finishedAt": "[date]"
+        },
+        {
+          "name": "writing the tasks",
+          "duration": "[duration]",
+          "startedAt": "[date]",
+          "finishedAt": "[date]"
+        }
+      ],
+      "duration": "[duration]",
+      "startedAt": "[date]",
+      "finishedAt": "[date]"
+    }
+    "###);
+
+    // the tasks can be filtered by the batch that processed them
+    let (response, code) = server.tasks_filter("batchUids=1").await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response["results"].as_array().unwrap().iter().map(|task| &task["uid"]).collect::<Vec<_>>()), @"[1]");
+    snapshot!(response["results"][0]["batchUid"], @"1");
+}
+
+#[actix_rt::test]
+async fn batches_are_restricted_to_the_authorized_indexes() {
+    let mut server = Server::new_auth().await;
+    server.use_admin_key("MASTER_KEY").await;
+    let (task, _code) = server.index("doggo").create(None).await;
+    server.wait_task(task.uid()).await.succeeded();
+    let (task, _code) = server.index("catto").create(None).await;
+    server.wait_task(task.uid()).await.succeeded();
+
+    let (key, code) = server
+        .add_api_key(json!({ "actions": ["tasks.get"], "indexes": ["catto"], "expiresAt": null }))
+        .await;
+    snapshot!(code, @"201 Created");
+    server.use_api_key(key["key"].as_str().unwrap());
+
+    let (response, code) = server.batches().await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response["results"].as_array().unwrap().iter().map(|batch| &batch["uid"]).collect::<Vec<_>>()), @"[1]");
+    snapshot!(response["total"], @"1");
+
+    let (_response, code) = server.get_batch(0).await;
+    snapshot!(code, @"404 Not Found");
+}
+
+#[actix_rt::test]
+async fn list_batches() {
+    let server = Server::new().await;
+    let index = server.index("test");
+    let (task, _code) = index.create(None).await;
+    index.wait_task(task.uid()).await.succeeded();
+    let (task, _code) = index.add_documents(json!([{ "id": 1, "content": "foobar" }]), None).await;
+    index.wait_task(task.uid()).await.succeeded();
+    let (task, _code) = index.update_settings(json!({ "searchableAttributes": ["content"] })).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (response, code) = server.batches().await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response["results"].as_array().unwrap().iter().map(|batch| &batch["uid"]).collect::<Vec<_>>()), @"[2,1,0]");
+    snapshot!(response["total"], @"3");
+    snapshot!(response["next"], @"null");
+
+    let (response, code) = server.batches_filter("limit=1&from=1").await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response["results"].as_array().unwrap().iter().map(|batch| &batch["uid"]).collect::<Vec<_>>()), @"[1]");
+    snapshot!(response["from"], @"1");
+    snapshot!(response["next"], @"0");
+    snapshot!(response["total"], @"3");
+}
+
+#[actix_rt::test]
+async fn batches_of_deleted_tasks_are_deleted() {
+    let server = Server::new().await;
+    let index = server.index("test");
+    let (task, _code) = index.create(None).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (task, code) = server.delete_tasks("uids=0").await;
+    snapshot!(code, @"200 OK");
+    server.wait_task(task.uid()).await.succeeded();
+
+    let (response, code) = server.get_batch(0).await;
+    snapshot!(code, @"404 Not Found");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "Batch `0` not found.",
+      "code": "batch_not_found",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#batch_not_found"
+    }
+    "###);
+    // the batch of the deletion task itself is kept
+    let (_response, code) = server.get_batch(1).await;
+    snapshot!(code, @"200 OK");
+}
+
+#[actix_rt::test]
+async fn get_batch_with_invalid_uid() {
+    let server = Server::new().await;
+
+    let (response, code) = server.service.get("/batches/doggo").await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "Batch uid `doggo` is invalid. It should only contain numeric characters.",
+      "code": "invalid_batch_uids",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_batch_uids"
+    }
+    "###);
+
+    let (response, code) = server.batches_filter("from=doggo").await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "Invalid value in parameter `from`: could not parse `doggo` as a positive integer",
+      "code": "invalid_batch_from",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_batch_from"
+    }
+    "###);
+}
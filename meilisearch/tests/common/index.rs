@@ -159,6 +159,26 @@ impl<'a> Index<'a, Owned> {
             .await
     }
 
+    pub async fn delete_batch_reporting_outcomes(&self, ids: Vec<u64>) -> (Value, StatusCode) {
+        let url = format!(
+            "/indexes/{}/documents/delete-batch?reportOutcomes=true",
+            urlencode(self.uid.as_ref())
+        );
+        self.service
+            .post_encoded(url, serde_json::to_value(&ids).unwrap().into(), self.encoder)
+            .await
+    }
+
+    pub async fn documents_transaction(&self, body: Value) -> (Value, StatusCode) {
+        let url = format!("/indexes/{}/documents/batch", urlencode(self.uid.as_ref()));
+        self.service.post_encoded(url, body, self.encoder).await
+    }
+
+    pub async fn import_documents_from_url(&self, body: Value) -> (Value, StatusCode) {
+        let url = format!("/indexes/{}/documents/import-url", urlencode(self.uid.as_ref()));
+        self.service.post_encoded(url, body, self.encoder).await
+    }
+
     pub async fn delete_batch_raw(&self, body: Value) -> (Value, StatusCode) {
         let url = format!("/indexes/{}/documents/delete-batch", urlencode(self.uid.as_ref()));
         self.service.post_encoded(url, body, self.encoder).await
@@ -363,11 +383,31 @@ impl<State> Index<'_, State> {
         self.service.get(url).await
     }
 
+    pub async fn get_document_obkv(&self, id: u64) -> (Value, StatusCode) {
+        let url = format!("/indexes/{}/documents/{}/obkv", urlencode(self.uid.as_ref()), id);
+        self.service.get(url).await
+    }
+
     pub async fn get_document_by_filter(&self, payload: Value) -> (Value, StatusCode) {
         let url = format!("/indexes/{}/documents/fetch", urlencode(self.uid.as_ref()));
         self.service.post(url, payload).await
     }
 
+    pub async fn scan_documents(&self, body: Value) -> (String, StatusCode) {
+        let url = format!("/indexes/{}/documents/scan", urlencode(self.uid.as_ref()));
+        self.service.post_raw(url, body).await
+    }
+
+    pub async fn export_facet_distribution(&self, body: Value) -> (String, StatusCode) {
+        let url = format!("/indexes/{}/facet-distribution", urlencode(self.uid.as_ref()));
+        self.service.post_raw(url, body).await
+    }
+
+    pub async fn search_stream(&self, query: Value) -> (String, StatusCode) {
+        let url = format!("/indexes/{}/search/stream", urlencode(self.uid.as_ref()));
+        self.service.post_raw(url, query).await
+    }
+
     pub async fn get_all_documents_raw(&self, options: &str) -> (Value, StatusCode) {
         let url = format!("/indexes/{}/documents{}", urlencode(self.uid.as_ref()), options);
         self.service.get(url).await
@@ -388,11 +428,46 @@ impl<State> Index<'_, State> {
         self.service.get(url).await
     }
 
+    pub async fn export_settings(&self) -> (Value, StatusCode) {
+        let url = format!("/indexes/{}/settings/export", urlencode(self.uid.as_ref()));
+        self.service.get(url).await
+    }
+
+    pub async fn import_settings(&self, settings: Value) -> (Value, StatusCode) {
+        let url = format!("/indexes/{}/settings/import", urlencode(self.uid.as_ref()));
+        self.service.post(url, settings).await
+    }
+
+    pub async fn estimate_settings(&self, settings: Value) -> (Value, StatusCode) {
+        let url = format!("/indexes/{}/settings/estimate", urlencode(self.uid.as_ref()));
+        self.service.post(url, settings).await
+    }
+
+    pub async fn validate_settings(&self, settings: Value) -> (Value, StatusCode) {
+        let url = format!("/indexes/{}/settings/validate", urlencode(self.uid.as_ref()));
+        self.service.post(url, settings).await
+    }
+
     pub async fn stats(&self) -> (Value, StatusCode) {
         let url = format!("/indexes/{}/stats", urlencode(self.uid.as_ref()));
         self.service.get(url).await
     }
 
+    pub async fn schema(&self) -> (Value, StatusCode) {
+        let url = format!("/indexes/{}/schema", urlencode(self.uid.as_ref()));
+        self.service.get(url).await
+    }
+
+    pub async fn warmup(&self) -> (Value, StatusCode) {
+        let url = format!("/indexes/{}/warmup", urlencode(self.uid.as_ref()));
+        self.service.post(url, json!(null)).await
+    }
+
+    pub async fn recent_searches(&self) -> (Value, StatusCode) {
+        let url = format!("/indexes/{}/recent-searches", urlencode(self.uid.as_ref()));
+        self.service.get(url).await
+    }
+
     /// Performs both GET and POST search queries
     pub async fn search(
         &self,
@@ -467,6 +542,16 @@ impl<State> Index<'_, State> {
         self.service.get(url).await
     }
 
+    pub async fn export_vocabulary(&self) -> (String, StatusCode) {
+        let url = format!("/indexes/{}/vocabulary", urlencode(self.uid.as_ref()));
+        self.service.get_raw(url).await
+    }
+
+    pub async fn suggest(&self, query: &str) -> (Value, StatusCode) {
+        let url = format!("/indexes/{}/suggest?{}", urlencode(self.uid.as_ref()), query);
+        self.service.get(url).await
+    }
+
     pub async fn facet_search(&self, query: Value) -> (Value, StatusCode) {
         let url = format!("/indexes/{}/facet-search", urlencode(self.uid.as_ref()));
         self.service.post_encoded(url, query, self.encoder).await
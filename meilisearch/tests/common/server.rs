@@ -157,6 +157,10 @@ impl Server<Owned> {
         self.service.get("/stats").await
     }
 
+    pub async fn search_stats(&self, query_parameters: &str) -> (Value, StatusCode) {
+        self.service.get(format!("/stats/searches?{query_parameters}")).await
+    }
+
     pub async fn tasks(&self) -> (Value, StatusCode) {
         self.service.get("/tasks").await
     }
@@ -165,6 +169,22 @@ impl Server<Owned> {
         self.service.patch("/experimental-features", value).await
     }
 
+    pub async fn get_maintenance(&self) -> (Value, StatusCode) {
+        self.service.get("/maintenance").await
+    }
+
+    pub async fn set_maintenance(&self, value: Value) -> (Value, StatusCode) {
+        self.service.patch("/maintenance", value).await
+    }
+
+    pub async fn get_webhooks(&self) -> (Value, StatusCode) {
+        self.service.get("/webhooks").await
+    }
+
+    pub async fn set_webhooks(&self, value: Value) -> (Value, StatusCode) {
+        self.service.patch("/webhooks", value).await
+    }
+
     pub async fn get_metrics(&self) -> (Value, StatusCode) {
         self.service.get("/metrics").await
     }
@@ -348,6 +368,18 @@ impl<State> Server<State> {
         self.service.get(format!("/tasks?{}", filter)).await
     }
 
+    pub async fn batches(&self) -> (Value, StatusCode) {
+        self.service.get("/batches").await
+    }
+
+    pub async fn batches_filter(&self, filter: &str) -> (Value, StatusCode) {
+        self.service.get(format!("/batches?{}", filter)).await
+    }
+
+    pub async fn get_batch(&self, batch_uid: u32) -> (Value, StatusCode) {
+        self.service.get(format!("/batches/{}", batch_uid)).await
+    }
+
     pub async fn version(&self) -> (Value, StatusCode) {
         self.service.get("/version").await
     }
@@ -356,14 +388,34 @@ impl<State> Server<State> {
         self.service.post("/dumps", json!(null)).await
     }
 
+    pub async fn create_settings_only_dump(&self) -> (Value, StatusCode) {
+        self.service.post("/dumps?settingsOnly=true", json!(null)).await
+    }
+
+    pub async fn create_incremental_dump(&self, since: u32) -> (Value, StatusCode) {
+        self.service.post(format!("/dumps?since={since}"), json!(null)).await
+    }
+
     pub async fn create_snapshot(&self) -> (Value, StatusCode) {
         self.service.post("/snapshots", json!(null)).await
     }
 
+    pub async fn snapshot_settings(&self) -> (Value, StatusCode) {
+        self.service.get("/snapshots/settings").await
+    }
+
+    pub async fn update_snapshot_settings(&self, value: Value) -> (Value, StatusCode) {
+        self.service.patch("/snapshots/settings", value).await
+    }
+
     pub async fn index_swap(&self, value: Value) -> (Value, StatusCode) {
         self.service.post("/swap-indexes", value).await
     }
 
+    pub async fn batch_update_settings(&self, value: Value) -> (Value, StatusCode) {
+        self.service.post("/settings/batch", value).await
+    }
+
     pub async fn cancel_tasks(&self, value: &str) -> (Value, StatusCode) {
         self.service.post(format!("/tasks/cancel?{}", value), json!(null)).await
     }
@@ -416,6 +468,7 @@ pub fn default_settings(dir: impl AsRef<Path>) -> Opt {
             skip_index_budget: true,
             // Having 2 threads makes the tests way faster
             max_indexing_threads: MaxThreads::from_str("2").unwrap(),
+            experimental_index_map_size_hint: None,
         },
         experimental_enable_metrics: false,
         ..Parser::parse_from(None as Option<&str>)
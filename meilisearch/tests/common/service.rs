@@ -106,7 +106,28 @@ impl Service {
         self.request(req).await
     }
 
-    pub async fn request(&self, mut req: test::TestRequest) -> (Value, StatusCode) {
+    /// Send a test post request and returns the body of the response as text.
+    pub async fn post_raw(&self, url: impl AsRef<str>, body: Value) -> (String, StatusCode) {
+        let mut req = test::TestRequest::post().uri(url.as_ref());
+        req = self.encode(req, body, Encoder::Plain);
+        let (body, status_code) = self.call(req).await;
+        (String::from_utf8(body.to_vec()).unwrap(), status_code)
+    }
+
+    /// Send a test get request and returns the body of the response as text.
+    pub async fn get_raw(&self, url: impl AsRef<str>) -> (String, StatusCode) {
+        let req = test::TestRequest::get().uri(url.as_ref());
+        let (body, status_code) = self.call(req).await;
+        (String::from_utf8(body.to_vec()).unwrap(), status_code)
+    }
+
+    pub async fn request(&self, req: test::TestRequest) -> (Value, StatusCode) {
+        let (body, status_code) = self.call(req).await;
+        let response = serde_json::from_slice(&body).unwrap_or_default();
+        (response, status_code)
+    }
+
+    async fn call(&self, mut req: test::TestRequest) -> (actix_web::web::Bytes, StatusCode) {
         let (_route_layer, route_layer_handle) =
             tracing_subscriber::reload::Layer::new(None.with_filter(
                 tracing_subscriber::filter::Targets::new().with_target("", LevelFilter::OFF),
@@ -138,8 +159,7 @@ impl Service {
         let status_code = res.status();
 
         let body = test::read_body(res).await;
-        let response = serde_json::from_slice(&body).unwrap_or_default();
-        (response, status_code)
+        (body, status_code)
     }
 
     fn encode(&self, req: TestRequest, body: Value, encoder: Encoder) -> TestRequest {
@@ -290,13 +290,14 @@ async fn add_csv_document() {
     }
     "###);
     let response = index.wait_task(response["taskUid"].as_u64().unwrap()).await;
-    snapshot!(json_string!(response, { ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]", ".duration" => "[duration]" }), @r###"
+    snapshot!(json_string!(response, { ".batchUid" => "[batch_uid]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]", ".duration" => "[duration]" }), @r###"
     {
       "uid": 0,
       "indexUid": "pets",
       "status": "succeeded",
       "type": "documentAdditionOrUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "receivedDocuments": 2,
         "indexedDocuments": 2
@@ -354,13 +355,14 @@ async fn add_csv_document_with_types() {
     }
     "###);
     let response = index.wait_task(response["taskUid"].as_u64().unwrap()).await;
-    snapshot!(json_string!(response, { ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]", ".duration" => "[duration]" }), @r###"
+    snapshot!(json_string!(response, { ".batchUid" => "[batch_uid]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]", ".duration" => "[duration]" }), @r###"
     {
       "uid": 0,
       "indexUid": "pets",
       "status": "succeeded",
       "type": "documentAdditionOrUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "receivedDocuments": 3,
         "indexedDocuments": 3
@@ -429,13 +431,14 @@ async fn add_csv_document_with_custom_delimiter() {
     }
     "###);
     let response = index.wait_task(response["taskUid"].as_u64().unwrap()).await;
-    snapshot!(json_string!(response, { ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]", ".duration" => "[duration]" }), @r###"
+    snapshot!(json_string!(response, { ".batchUid" => "[batch_uid]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]", ".duration" => "[duration]" }), @r###"
     {
       "uid": 0,
       "indexUid": "pets",
       "status": "succeeded",
       "type": "documentAdditionOrUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "receivedDocuments": 2,
         "indexedDocuments": 2
@@ -981,7 +984,7 @@ async fn add_documents_no_index_creation() {
 
     let (response, code) = index.get_task(0).await;
     snapshot!(code, @"200 OK");
-    snapshot!(json_string!(response, { ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" }),
+    snapshot!(json_string!(response, { ".batchUid" => "[batch_uid]", ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" }),
         @r###"
     {
       "uid": 0,
@@ -989,6 +992,7 @@ async fn add_documents_no_index_creation() {
       "status": "succeeded",
       "type": "documentAdditionOrUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "receivedDocuments": 1,
         "indexedDocuments": 1
@@ -1059,7 +1063,7 @@ async fn document_addition_with_primary_key() {
 
     let (response, code) = index.get_task(0).await;
     snapshot!(code, @"200 OK");
-    snapshot!(json_string!(response, { ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" }),
+    snapshot!(json_string!(response, { ".batchUid" => "[batch_uid]", ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" }),
         @r###"
     {
       "uid": 0,
@@ -1067,6 +1071,7 @@ async fn document_addition_with_primary_key() {
       "status": "succeeded",
       "type": "documentAdditionOrUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "receivedDocuments": 1,
         "indexedDocuments": 1
@@ -1115,6 +1120,7 @@ async fn document_addition_with_huge_int_primary_key() {
       "status": "succeeded",
       "type": "documentAdditionOrUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "receivedDocuments": 1,
         "indexedDocuments": 1
@@ -1179,7 +1185,7 @@ async fn replace_document() {
 
     let (response, code) = index.get_task(1).await;
     snapshot!(code, @"200 OK");
-    snapshot!(json_string!(response, { ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" }),
+    snapshot!(json_string!(response, { ".batchUid" => "[batch_uid]", ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" }),
         @r###"
     {
       "uid": 1,
@@ -1187,6 +1193,7 @@ async fn replace_document() {
       "status": "succeeded",
       "type": "documentAdditionOrUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "receivedDocuments": 1,
         "indexedDocuments": 1
@@ -1267,7 +1274,7 @@ async fn error_add_documents_bad_document_id() {
     index.wait_task(1).await;
     let (response, code) = index.get_task(1).await;
     snapshot!(code, @"200 OK");
-    snapshot!(json_string!(response, { ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" }),
+    snapshot!(json_string!(response, { ".batchUid" => "[batch_uid]", ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" }),
         @r###"
     {
       "uid": 1,
@@ -1275,6 +1282,7 @@ async fn error_add_documents_bad_document_id() {
       "status": "failed",
       "type": "documentAdditionOrUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "receivedDocuments": 1,
         "indexedDocuments": 0
@@ -1308,7 +1316,7 @@ async fn error_add_documents_missing_document_id() {
     index.wait_task(1).await;
     let (response, code) = index.get_task(1).await;
     snapshot!(code, @"200 OK");
-    snapshot!(json_string!(response, { ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" }),
+    snapshot!(json_string!(response, { ".batchUid" => "[batch_uid]", ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" }),
         @r###"
     {
       "uid": 1,
@@ -1316,6 +1324,7 @@ async fn error_add_documents_missing_document_id() {
       "status": "failed",
       "type": "documentAdditionOrUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "receivedDocuments": 1,
         "indexedDocuments": 0
@@ -1365,6 +1374,7 @@ async fn error_document_field_limit_reached_in_one_document() {
       "status": "succeeded",
       "type": "documentAdditionOrUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "receivedDocuments": 1,
         "indexedDocuments": 1
@@ -1407,6 +1417,7 @@ async fn error_document_field_limit_reached_over_multiple_documents() {
       "status": "succeeded",
       "type": "documentAdditionOrUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "receivedDocuments": 1,
         "indexedDocuments": 1
@@ -1441,6 +1452,7 @@ async fn error_document_field_limit_reached_over_multiple_documents() {
       "status": "failed",
       "type": "documentAdditionOrUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "receivedDocuments": 1,
         "indexedDocuments": 0
@@ -1490,6 +1502,7 @@ async fn error_document_field_limit_reached_in_one_nested_document() {
       "status": "succeeded",
       "type": "documentAdditionOrUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "receivedDocuments": 1,
         "indexedDocuments": 1
@@ -1533,6 +1546,7 @@ async fn error_document_field_limit_reached_over_multiple_documents_with_nested_
       "status": "succeeded",
       "type": "documentAdditionOrUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "receivedDocuments": 1,
         "indexedDocuments": 1
@@ -1568,6 +1582,7 @@ async fn error_document_field_limit_reached_over_multiple_documents_with_nested_
       "status": "succeeded",
       "type": "documentAdditionOrUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "receivedDocuments": 1,
         "indexedDocuments": 1
@@ -1607,7 +1622,7 @@ async fn add_documents_with_geo_field() {
 
     index.add_documents(documents, None).await;
     let response = index.wait_task(1).await;
-    snapshot!(json_string!(response, { ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" }),
+    snapshot!(json_string!(response, { ".batchUid" => "[batch_uid]", ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" }),
         @r###"
     {
       "uid": 1,
@@ -1615,6 +1630,7 @@ async fn add_documents_with_geo_field() {
       "status": "succeeded",
       "type": "documentAdditionOrUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "receivedDocuments": 4,
         "indexedDocuments": 4
@@ -1647,7 +1663,7 @@ async fn add_documents_invalid_geo_field() {
     index.wait_task(2).await;
     let (response, code) = index.get_task(2).await;
     snapshot!(code, @"200 OK");
-    snapshot!(json_string!(response, { ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" }),
+    snapshot!(json_string!(response, { ".batchUid" => "[batch_uid]", ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" }),
         @r###"
     {
       "uid": 2,
@@ -1655,6 +1671,7 @@ async fn add_documents_invalid_geo_field() {
       "status": "failed",
       "type": "documentAdditionOrUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "receivedDocuments": 1,
         "indexedDocuments": 0
@@ -1684,7 +1701,7 @@ async fn add_documents_invalid_geo_field() {
     index.wait_task(3).await;
     let (response, code) = index.get_task(3).await;
     snapshot!(code, @"200 OK");
-    snapshot!(json_string!(response, { ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" }),
+    snapshot!(json_string!(response, { ".batchUid" => "[batch_uid]", ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" }),
         @r###"
     {
       "uid": 3,
@@ -1692,6 +1709,7 @@ async fn add_documents_invalid_geo_field() {
       "status": "failed",
       "type": "documentAdditionOrUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "receivedDocuments": 1,
         "indexedDocuments": 0
@@ -1721,7 +1739,7 @@ async fn add_documents_invalid_geo_field() {
     index.wait_task(4).await;
     let (response, code) = index.get_task(4).await;
     snapshot!(code, @"200 OK");
-    snapshot!(json_string!(response, { ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" }),
+    snapshot!(json_string!(response, { ".batchUid" => "[batch_uid]", ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" }),
         @r###"
     {
       "uid": 4,
@@ -1729,6 +1747,7 @@ async fn add_documents_invalid_geo_field() {
       "status": "failed",
       "type": "documentAdditionOrUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "receivedDocuments": 1,
         "indexedDocuments": 0
@@ -1758,7 +1777,7 @@ async fn add_documents_invalid_geo_field() {
     index.wait_task(5).await;
     let (response, code) = index.get_task(5).await;
     snapshot!(code, @"200 OK");
-    snapshot!(json_string!(response, { ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" }),
+    snapshot!(json_string!(response, { ".batchUid" => "[batch_uid]", ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" }),
         @r###"
     {
       "uid": 5,
@@ -1766,6 +1785,7 @@ async fn add_documents_invalid_geo_field() {
       "status": "failed",
       "type": "documentAdditionOrUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "receivedDocuments": 1,
         "indexedDocuments": 0
@@ -1795,7 +1815,7 @@ async fn add_documents_invalid_geo_field() {
     index.wait_task(6).await;
     let (response, code) = index.get_task(6).await;
     snapshot!(code, @"200 OK");
-    snapshot!(json_string!(response, { ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" }),
+    snapshot!(json_string!(response, { ".batchUid" => "[batch_uid]", ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" }),
         @r###"
     {
       "uid": 6,
@@ -1803,6 +1823,7 @@ async fn add_documents_invalid_geo_field() {
       "status": "failed",
       "type": "documentAdditionOrUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "receivedDocuments": 1,
         "indexedDocuments": 0
@@ -1832,7 +1853,7 @@ async fn add_documents_invalid_geo_field() {
     index.wait_task(7).await;
     let (response, code) = index.get_task(7).await;
     snapshot!(code, @"200 OK");
-    snapshot!(json_string!(response, { ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" }),
+    snapshot!(json_string!(response, { ".batchUid" => "[batch_uid]", ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" }),
         @r###"
     {
       "uid": 7,
@@ -1840,6 +1861,7 @@ async fn add_documents_invalid_geo_field() {
       "status": "failed",
       "type": "documentAdditionOrUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "receivedDocuments": 1,
         "indexedDocuments": 0
@@ -1869,7 +1891,7 @@ async fn add_documents_invalid_geo_field() {
     index.wait_task(8).await;
     let (response, code) = index.get_task(8).await;
     snapshot!(code, @"200 OK");
-    snapshot!(json_string!(response, { ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" }),
+    snapshot!(json_string!(response, { ".batchUid" => "[batch_uid]", ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" }),
         @r###"
     {
       "uid": 8,
@@ -1877,6 +1899,7 @@ async fn add_documents_invalid_geo_field() {
       "status": "failed",
       "type": "documentAdditionOrUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "receivedDocuments": 1,
         "indexedDocuments": 0
@@ -1906,7 +1929,7 @@ async fn add_documents_invalid_geo_field() {
     index.wait_task(9).await;
     let (response, code) = index.get_task(9).await;
     snapshot!(code, @"200 OK");
-    snapshot!(json_string!(response, { ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" }),
+    snapshot!(json_string!(response, { ".batchUid" => "[batch_uid]", ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" }),
         @r###"
     {
       "uid": 9,
@@ -1914,6 +1937,7 @@ async fn add_documents_invalid_geo_field() {
       "status": "failed",
       "type": "documentAdditionOrUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "receivedDocuments": 1,
         "indexedDocuments": 0
@@ -1943,7 +1967,7 @@ async fn add_documents_invalid_geo_field() {
     index.wait_task(10).await;
     let (response, code) = index.get_task(10).await;
     snapshot!(code, @"200 OK");
-    snapshot!(json_string!(response, { ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" }),
+    snapshot!(json_string!(response, { ".batchUid" => "[batch_uid]", ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" }),
         @r###"
     {
       "uid": 10,
@@ -1951,6 +1975,7 @@ async fn add_documents_invalid_geo_field() {
       "status": "failed",
       "type": "documentAdditionOrUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "receivedDocuments": 1,
         "indexedDocuments": 0
@@ -1980,7 +2005,7 @@ async fn add_documents_invalid_geo_field() {
     index.wait_task(11).await;
     let (response, code) = index.get_task(11).await;
     snapshot!(code, @"200 OK");
-    snapshot!(json_string!(response, { ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" }),
+    snapshot!(json_string!(response, { ".batchUid" => "[batch_uid]", ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" }),
         @r###"
     {
       "uid": 11,
@@ -1988,6 +2013,7 @@ async fn add_documents_invalid_geo_field() {
       "status": "failed",
       "type": "documentAdditionOrUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "receivedDocuments": 1,
         "indexedDocuments": 0
@@ -2017,7 +2043,7 @@ async fn add_documents_invalid_geo_field() {
     index.wait_task(12).await;
     let (response, code) = index.get_task(12).await;
     snapshot!(code, @"200 OK");
-    snapshot!(json_string!(response, { ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" }),
+    snapshot!(json_string!(response, { ".batchUid" => "[batch_uid]", ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" }),
         @r###"
     {
       "uid": 12,
@@ -2025,6 +2051,7 @@ async fn add_documents_invalid_geo_field() {
       "status": "failed",
       "type": "documentAdditionOrUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "receivedDocuments": 1,
         "indexedDocuments": 0
@@ -2054,7 +2081,7 @@ async fn add_documents_invalid_geo_field() {
     index.wait_task(13).await;
     let (response, code) = index.get_task(13).await;
     snapshot!(code, @"200 OK");
-    snapshot!(json_string!(response, { ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" }),
+    snapshot!(json_string!(response, { ".batchUid" => "[batch_uid]", ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" }),
         @r###"
     {
       "uid": 13,
@@ -2062,6 +2089,7 @@ async fn add_documents_invalid_geo_field() {
       "status": "failed",
       "type": "documentAdditionOrUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "receivedDocuments": 1,
         "indexedDocuments": 0
@@ -2092,7 +2120,7 @@ async fn add_documents_invalid_geo_field() {
     let (response, code) = index.add_documents(documents, None).await;
     snapshot!(code, @"202 Accepted");
     let response = index.wait_task(response.uid()).await;
-    snapshot!(json_string!(response, { ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" }),
+    snapshot!(json_string!(response, { ".batchUid" => "[batch_uid]", ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" }),
         @r###"
     {
       "uid": 14,
@@ -2100,6 +2128,7 @@ async fn add_documents_invalid_geo_field() {
       "status": "failed",
       "type": "documentAdditionOrUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "receivedDocuments": 1,
         "indexedDocuments": 0
@@ -2128,7 +2157,7 @@ async fn add_documents_invalid_geo_field() {
     let (response, code) = index.add_documents(documents, None).await;
     snapshot!(code, @"202 Accepted");
     let response = index.wait_task(response.uid()).await;
-    snapshot!(json_string!(response, { ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" }),
+    snapshot!(json_string!(response, { ".batchUid" => "[batch_uid]", ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" }),
         @r###"
     {
       "uid": 15,
@@ -2136,6 +2165,7 @@ async fn add_documents_invalid_geo_field() {
       "status": "failed",
       "type": "documentAdditionOrUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "receivedDocuments": 1,
         "indexedDocuments": 0
@@ -2164,7 +2194,7 @@ async fn add_documents_invalid_geo_field() {
     let (response, code) = index.add_documents(documents, None).await;
     snapshot!(code, @"202 Accepted");
     let response = index.wait_task(response.uid()).await;
-    snapshot!(json_string!(response, { ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" }),
+    snapshot!(json_string!(response, { ".batchUid" => "[batch_uid]", ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" }),
         @r###"
     {
       "uid": 16,
@@ -2172,6 +2202,7 @@ async fn add_documents_invalid_geo_field() {
       "status": "failed",
       "type": "documentAdditionOrUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "receivedDocuments": 1,
         "indexedDocuments": 0
@@ -2214,6 +2245,7 @@ async fn add_invalid_geo_and_then_settings() {
       "status": "succeeded",
       "type": "documentAdditionOrUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "receivedDocuments": 1,
         "indexedDocuments": 1
@@ -2236,6 +2268,7 @@ async fn add_invalid_geo_and_then_settings() {
       "status": "failed",
       "type": "settingsUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "sortableAttributes": [
           "_geo"
@@ -2299,7 +2332,7 @@ async fn error_primary_key_inference() {
     let (response, code) = index.get_task(0).await;
     assert_eq!(code, 200);
 
-    snapshot!(json_string!(response, { ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" }),
+    snapshot!(json_string!(response, { ".batchUid" => "[batch_uid]", ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" }),
     @r###"
     {
       "uid": 0,
@@ -2307,6 +2340,7 @@ async fn error_primary_key_inference() {
       "status": "failed",
       "type": "documentAdditionOrUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "receivedDocuments": 1,
         "indexedDocuments": 0
@@ -2339,7 +2373,7 @@ async fn error_primary_key_inference() {
     let (response, code) = index.get_task(1).await;
     assert_eq!(code, 200);
 
-    snapshot!(json_string!(response, { ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" }),
+    snapshot!(json_string!(response, { ".batchUid" => "[batch_uid]", ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" }),
     @r###"
     {
       "uid": 1,
@@ -2347,6 +2381,7 @@ async fn error_primary_key_inference() {
       "status": "failed",
       "type": "documentAdditionOrUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "receivedDocuments": 1,
         "indexedDocuments": 0
@@ -2377,7 +2412,7 @@ async fn error_primary_key_inference() {
     let (response, code) = index.get_task(2).await;
     assert_eq!(code, 200);
 
-    snapshot!(json_string!(response, { ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" }),
+    snapshot!(json_string!(response, { ".batchUid" => "[batch_uid]", ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" }),
     @r###"
     {
       "uid": 2,
@@ -2385,6 +2420,7 @@ async fn error_primary_key_inference() {
       "status": "succeeded",
       "type": "documentAdditionOrUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "receivedDocuments": 1,
         "indexedDocuments": 1
@@ -2521,3 +2557,210 @@ async fn dry_register_file() {
     "###);
     snapshot!(code, @"404 Not Found");
 }
+
+#[actix_rt::test]
+async fn add_documents_and_wait_for_completion() {
+    let server = Server::new().await;
+
+    let (response, code) = server
+        .service
+        .post(
+            "/indexes/test/documents?waitForCompletion=true&timeoutMs=30000",
+            json!([{ "id": 1, "content": "Bouvier Bernois" }]),
+        )
+        .await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response, { ".batchUid" => "[batch_uid]", ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" }),
+        @r###"
+    {
+      "uid": 0,
+      "indexUid": "test",
+      "status": "succeeded",
+      "type": "documentAdditionOrUpdate",
+      "canceledBy": null,
+      "batchUid": "[batch_uid]",
+      "details": {
+        "receivedDocuments": 1,
+        "indexedDocuments": 1
+      },
+      "error": null,
+      "duration": "[duration]",
+      "enqueuedAt": "[date]",
+      "startedAt": "[date]",
+      "finishedAt": "[date]"
+    }
+    "###);
+
+    let (response, code) = server
+        .service
+        .post("/indexes/test/documents?waitForCompletion=tamo", json!([{ "id": 2 }]))
+        .await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(response, @r###"
+    {
+      "message": "Invalid value in parameter `waitForCompletion`: could not parse `tamo` as a boolean, expected either `true` or `false`",
+      "code": "invalid_wait_for_completion",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_wait_for_completion"
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn add_documents_beyond_max_documents() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (task, code) = index.update_settings(json!({ "maxDocuments": 2 })).await;
+    snapshot!(code, @"202 Accepted");
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (task, _code) = index.add_documents(json!([{ "id": 1 }, { "id": 2 }]), None).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    // updating the existing documents is still allowed
+    let (task, _code) = index.add_documents(json!([{ "id": 2, "name": "kefir" }]), None).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (task, _code) = index.add_documents(json!([{ "id": 3 }]), None).await;
+    let response = index.wait_task(task.uid()).await;
+    snapshot!(response["status"], @r###""failed""###);
+    snapshot!(response["error"], @r###"
+    {
+      "message": "The index cannot contain more than 2 documents, the update would bring it to 3 documents.",
+      "code": "max_documents_exceeded",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#max_documents_exceeded"
+    }
+    "###);
+
+    let (response, _code) = index.get_all_documents(GetAllDocumentsOptions::default()).await;
+    snapshot!(response["total"], @"2");
+}
+
+#[actix_rt::test]
+async fn add_documents_skipping_the_invalid_lines() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let payload = r#"{ "id": 1, "name": "kefir" }
+not json
+{ "id": 2, "name": "intel" }
+[1, 2]
+
+{ "id": 3, "name": "doggo" }"#;
+    let (response, code) = index
+        .raw_add_documents(
+            payload,
+            vec![("Content-Type", "application/x-ndjson")],
+            "?primaryKey=id&onError=skip",
+        )
+        .await;
+    snapshot!(code, @"202 Accepted");
+    snapshot!(json_string!(response["skippedDocuments"]), @r###"
+    [
+      {
+        "line": 2,
+        "reason": "expected ident at line 1 column 2"
+      },
+      {
+        "line": 4,
+        "reason": "the document is not an object"
+      }
+    ]
+    "###);
+
+    let response = index.wait_task(response.uid()).await;
+    snapshot!(response["status"], @r###""succeeded""###);
+    snapshot!(response["details"]["indexedDocuments"], @"3");
+    // the report is kept in the details of the task
+    snapshot!(json_string!(response["details"]["skippedDocuments"]), @r###"
+    [
+      {
+        "line": 2,
+        "reason": "expected ident at line 1 column 2"
+      },
+      {
+        "line": 4,
+        "reason": "the document is not an object"
+      }
+    ]
+    "###);
+
+    // the whole payload is rejected by default
+    let (response, code) =
+        index.raw_add_documents(payload, vec![("Content-Type", "application/x-ndjson")], "").await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(response["code"], @r###""malformed_payload""###);
+
+    let (response, code) = index
+        .raw_add_documents(payload, vec![("Content-Type", "application/json")], "?onError=skip")
+        .await;
+    snapshot!(code, @"415 Unsupported Media Type");
+    snapshot!(response["code"], @r###""invalid_content_type""###);
+}
+
+#[actix_rt::test]
+async fn add_documents_streamed_with_backpressure() {
+    let mut server = Server::new().await;
+
+    // fill the task queue with additions that aren't subject to any backpressure,
+    // that's the slow indexing the streamed addition below has to wait for.
+    let app = server.init_web_app().await;
+    let mut queued_tasks = Vec::new();
+    for i in 0..20 {
+        let documents: Vec<_> = (0..100)
+            .map(|j| serde_json::json!({ "id": i * 100 + j, "content": "queued" }))
+            .collect();
+        let req = test::TestRequest::post()
+            .uri("/indexes/test/documents")
+            .set_payload(serde_json::to_string(&documents).unwrap())
+            .insert_header(("content-type", "application/json"))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+        assert_eq!(res.status(), 202);
+        let response: Value = serde_json::from_slice(&test::read_body(res).await).unwrap();
+        queued_tasks.push(response.uid());
+    }
+
+    server.service.options.experimental_document_addition_max_pending_tasks =
+        Some(std::num::NonZeroUsize::new(1).unwrap());
+    let app = server.init_web_app().await;
+    let index = server.index("test");
+
+    let chunks = (2000..3000).map(|id| {
+        Ok::<_, actix_http::error::PayloadError>(bytes::Bytes::from(format!(
+            "{{ \"id\": {id}, \"content\": \"streamed\" }}\n"
+        )))
+    });
+    let mut req = test::TestRequest::post()
+        .uri("/indexes/test/documents")
+        .insert_header(("content-type", "application/x-ndjson"))
+        .to_request();
+    *req.payload() =
+        actix_http::Payload::Stream { payload: Box::pin(futures::stream::iter(chunks)) };
+    let res = test::call_service(&app, req).await;
+    let status_code = res.status();
+    let response: Value = serde_json::from_slice(&test::read_body(res).await).unwrap();
+    snapshot!(status_code, @"202 Accepted");
+
+    // all the chunks were part of a single task
+    let response = index.wait_task(response.uid()).await;
+    snapshot!(response["status"], @r###""succeeded""###);
+    snapshot!(response["details"], @r###"{"receivedDocuments":1000,"indexedDocuments":1000}"###);
+
+    // the payload was only read once the task queue was drained
+    let enqueued_at =
+        OffsetDateTime::parse(response["enqueuedAt"].as_str().unwrap(), &Rfc3339).unwrap();
+    for task in queued_tasks {
+        let (task, _) = index.get_task(task).await;
+        snapshot!(task["status"], @r###""succeeded""###);
+        let finished_at =
+            OffsetDateTime::parse(task["finishedAt"].as_str().unwrap(), &Rfc3339).unwrap();
+        assert!(finished_at <= enqueued_at);
+    }
+
+    let (response, code) = index.get_all_documents(GetAllDocumentsOptions::default()).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(response["total"], @"3000");
+}
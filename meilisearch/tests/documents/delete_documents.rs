@@ -122,6 +122,56 @@ async fn delete_batch() {
     assert_eq!(response["results"][0]["id"], json!(3));
 }
 
+#[actix_rt::test]
+async fn delete_batch_reporting_outcomes() {
+    let server = Server::new().await;
+    let index = server.index("test");
+    index.add_documents(json!([{ "id": 1, "content": "foobar" }, { "id": 0, "content": "foobar" }, { "id": 3, "content": "foobar" }]), Some("id")).await;
+    index.wait_task(0).await;
+    let (response, code) = index.delete_batch_reporting_outcomes(vec![1, 42, 3, 7]).await;
+    snapshot!(code, @"202 Accepted");
+
+    let response = index.wait_task(response.uid()).await;
+    snapshot!(response["status"], @r###""succeeded""###);
+    snapshot!(json_string!(response["details"]), @r###"
+    {
+      "providedIds": 4,
+      "deletedDocuments": 2,
+      "outcomes": [
+        {
+          "documentId": "1",
+          "deleted": true
+        },
+        {
+          "documentId": "42",
+          "deleted": false
+        },
+        {
+          "documentId": "3",
+          "deleted": true
+        },
+        {
+          "documentId": "7",
+          "deleted": false
+        }
+      ],
+      "originalFilter": null
+    }
+    "###);
+
+    // Without the parameter the outcomes are not reported
+    let (response, code) = index.delete_batch(vec![0, 42]).await;
+    snapshot!(code, @"202 Accepted");
+    let response = index.wait_task(response.uid()).await;
+    snapshot!(json_string!(response["details"]), @r###"
+    {
+      "providedIds": 2,
+      "deletedDocuments": 1,
+      "originalFilter": null
+    }
+    "###);
+}
+
 #[actix_rt::test]
 async fn delete_no_document_batch() {
     let server = Server::new().await;
@@ -156,14 +206,16 @@ async fn delete_document_by_filter() {
     index.wait_task(1).await;
 
     let (stats, _) = index.stats().await;
-    snapshot!(json_string!(stats), @r###"
+    snapshot!(json_string!(stats, { ".databasesSizes" => "[sizes]", ".avgDocumentSize" => "[size]" }), @r###"
     {
       "numberOfDocuments": 4,
       "isIndexing": false,
       "fieldDistribution": {
         "color": 3,
         "id": 4
-      }
+      },
+      "databasesSizes": "[sizes]",
+      "avgDocumentSize": "[size]"
     }
     "###);
 
@@ -181,13 +233,14 @@ async fn delete_document_by_filter() {
     "###);
 
     let response = index.wait_task(2).await;
-    snapshot!(json_string!(response, { ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]", ".duration" => "[duration]" }), @r###"
+    snapshot!(json_string!(response, { ".batchUid" => "[batch_uid]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]", ".duration" => "[duration]" }), @r###"
     {
       "uid": 2,
       "indexUid": "doggo",
       "status": "succeeded",
       "type": "documentDeletion",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "providedIds": 0,
         "deletedDocuments": 2,
@@ -202,14 +255,16 @@ async fn delete_document_by_filter() {
     "###);
 
     let (stats, _) = index.stats().await;
-    snapshot!(json_string!(stats), @r###"
+    snapshot!(json_string!(stats, { ".databasesSizes" => "[sizes]", ".avgDocumentSize" => "[size]" }), @r###"
     {
       "numberOfDocuments": 2,
       "isIndexing": false,
       "fieldDistribution": {
         "color": 1,
         "id": 2
-      }
+      },
+      "databasesSizes": "[sizes]",
+      "avgDocumentSize": "[size]"
     }
     "###);
 
@@ -246,13 +301,14 @@ async fn delete_document_by_filter() {
     "###);
 
     let response = index.wait_task(3).await;
-    snapshot!(json_string!(response, { ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]", ".duration" => "[duration]" }), @r###"
+    snapshot!(json_string!(response, { ".batchUid" => "[batch_uid]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]", ".duration" => "[duration]" }), @r###"
     {
       "uid": 3,
       "indexUid": "doggo",
       "status": "succeeded",
       "type": "documentDeletion",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "providedIds": 0,
         "deletedDocuments": 1,
@@ -267,14 +323,16 @@ async fn delete_document_by_filter() {
     "###);
 
     let (stats, _) = index.stats().await;
-    snapshot!(json_string!(stats), @r###"
+    snapshot!(json_string!(stats, { ".databasesSizes" => "[sizes]", ".avgDocumentSize" => "[size]" }), @r###"
     {
       "numberOfDocuments": 1,
       "isIndexing": false,
       "fieldDistribution": {
         "color": 1,
         "id": 1
-      }
+      },
+      "databasesSizes": "[sizes]",
+      "avgDocumentSize": "[size]"
     }
     "###);
 
@@ -330,13 +388,14 @@ async fn delete_document_by_complex_filter() {
     "###);
 
     let response = index.wait_task(2).await;
-    snapshot!(json_string!(response, { ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]", ".duration" => "[duration]" }), @r###"
+    snapshot!(json_string!(response, { ".batchUid" => "[batch_uid]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]", ".duration" => "[duration]" }), @r###"
     {
       "uid": 2,
       "indexUid": "doggo",
       "status": "succeeded",
       "type": "documentDeletion",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "providedIds": 0,
         "deletedDocuments": 2,
@@ -388,13 +447,14 @@ async fn delete_document_by_complex_filter() {
     "###);
 
     let response = index.wait_task(3).await;
-    snapshot!(json_string!(response, { ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]", ".duration" => "[duration]" }), @r###"
+    snapshot!(json_string!(response, { ".batchUid" => "[batch_uid]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]", ".duration" => "[duration]" }), @r###"
     {
       "uid": 3,
       "indexUid": "doggo",
       "status": "succeeded",
       "type": "documentDeletion",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "providedIds": 0,
         "deletedDocuments": 2,
@@ -424,3 +484,57 @@ async fn delete_document_by_complex_filter() {
     }
     "###);
 }
+
+#[actix_rt::test]
+async fn delete_documents_and_wait_for_completion() {
+    let server = Server::new().await;
+    let index = server.index("test");
+    let (task, _code) = index.update_settings_filterable_attributes(json!(["color"])).await;
+    index.wait_task(task.uid()).await.succeeded();
+    let (task, _code) = index
+        .add_documents(
+            json!([
+                { "id": 0, "color": "red" },
+                { "id": 1, "color": "blue" },
+                { "id": 2, "color": "green" },
+                { "id": 3, "color": "red" },
+            ]),
+            None,
+        )
+        .await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (response, code) =
+        server.service.delete("/indexes/test/documents/0?waitForCompletion=true").await;
+    snapshot!(code, @"200 OK");
+    snapshot!(response["status"], @r###""succeeded""###);
+
+    let (response, code) = server
+        .service
+        .post("/indexes/test/documents/delete-batch?waitForCompletion=true", json!([1]))
+        .await;
+    snapshot!(code, @"200 OK");
+    snapshot!(response["status"], @r###""succeeded""###);
+
+    let (response, code) = server
+        .service
+        .post(
+            "/indexes/test/documents/delete?waitForCompletion=true",
+            json!({ "filter": "color = green" }),
+        )
+        .await;
+    snapshot!(code, @"200 OK");
+    snapshot!(response["status"], @r###""succeeded""###);
+
+    let (response, code) =
+        server.service.delete("/indexes/test/documents?waitForCompletion=true").await;
+    snapshot!(code, @"200 OK");
+    snapshot!(response["status"], @r###""succeeded""###);
+
+    let (response, _code) = index.get_all_documents(GetAllDocumentsOptions::default()).await;
+    snapshot!(response["total"], @"0");
+
+    // the unknown query parameters are still ignored by the routes that had none
+    let (_response, code) = server.service.delete("/indexes/test/documents?doggo=true").await;
+    snapshot!(code, @"202 Accepted");
+}
@@ -567,6 +567,7 @@ async fn delete_document_by_filter() {
       "status": "failed",
       "type": "documentDeletion",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "providedIds": 0,
         "deletedDocuments": 0,
@@ -596,6 +597,7 @@ async fn delete_document_by_filter() {
       "status": "failed",
       "type": "documentDeletion",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "providedIds": 0,
         "deletedDocuments": 0,
@@ -627,6 +629,7 @@ async fn delete_document_by_filter() {
       "status": "failed",
       "type": "documentDeletion",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "providedIds": 0,
         "deletedDocuments": 0,
@@ -740,3 +740,206 @@ async fn get_document_with_vectors() {
     }
     "###);
 }
+
+#[actix_rt::test]
+async fn get_documents_with_cursor() {
+    let server = Server::new().await;
+    let index = server.index("doggo");
+    index.update_settings_filterable_attributes(json!(["color"])).await;
+    index
+        .add_documents(
+            json!([
+                { "id": 0, "color": "red" },
+                { "id": 1, "color": "blue" },
+                { "id": 2, "color": "blue" },
+                { "id": 3 },
+            ]),
+            Some("id"),
+        )
+        .await;
+    index.wait_task(1).await.succeeded();
+
+    let (response, code) = index.get_document_by_filter(json!({ "from": 0, "limit": 2 })).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response), @r###"
+    {
+      "results": [
+        {
+          "id": 0,
+          "color": "red"
+        },
+        {
+          "id": 1,
+          "color": "blue"
+        }
+      ],
+      "total": 4,
+      "limit": 2,
+      "from": "0",
+      "next": "2"
+    }
+    "###);
+
+    // unlike an offset, the cursor isn't shifted by the deletion of the previous documents, nor
+    // by a new document reusing the internal id of a deleted one
+    let (task, _code) = index.delete_document(0).await;
+    index.wait_task(task.uid()).await.succeeded();
+    let (task, _code) = index.add_documents(json!([{ "id": 4 }]), None).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (response, code) = index.get_document_by_filter(json!({ "from": "2", "limit": 2 })).await;
+    let (response2, code2) = index.get_all_documents_raw("?from=2&limit=2").await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response), @r###"
+    {
+      "results": [
+        {
+          "id": 2,
+          "color": "blue"
+        },
+        {
+          "id": 3
+        }
+      ],
+      "total": 4,
+      "limit": 2,
+      "from": "2",
+      "next": "4"
+    }
+    "###);
+    assert_eq!(code, code2);
+    assert_eq!(response, response2);
+
+    let (response, code) = index
+        .get_document_by_filter(json!({ "from": "", "limit": 1, "filter": "color = blue" }))
+        .await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response), @r###"
+    {
+      "results": [
+        {
+          "id": 1,
+          "color": "blue"
+        }
+      ],
+      "total": 2,
+      "limit": 1,
+      "from": "",
+      "next": "2"
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn get_documents_with_invalid_cursor() {
+    let server = Server::new().await;
+    let index = server.index("doggo");
+    let (task, _code) = index.create(None).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (response, code) = index.get_document_by_filter(json!({ "from": true })).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "Invalid value type at `.from`: expected a string or an integer, but found `true`",
+      "code": "invalid_document_from",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_document_from"
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn scan_documents_with_filter() {
+    let server = Server::new().await;
+    let index = server.index("test");
+    let (task, _code) = index.update_settings_filterable_attributes(json!(["status"])).await;
+    index.wait_task(task.uid()).await;
+
+    let documents: Vec<_> = (0..20_000)
+        .map(|id| {
+            let status = if id % 2 == 0 { "active" } else { "inactive" };
+            serde_json::json!({ "id": id, "status": status })
+        })
+        .collect();
+    let (task, code) = index.add_documents(serde_json::Value::Array(documents).into(), None).await;
+    snapshot!(code, @"202 Accepted");
+    let task = index.wait_task(task.uid()).await;
+    snapshot!(task["status"], @r###""succeeded""###);
+
+    let (body, code) =
+        index.scan_documents(json!({ "filter": "status = active", "fields": ["id"] })).await;
+    snapshot!(code, @"200 OK");
+
+    let ids: Vec<u64> = body
+        .lines()
+        .map(|line| {
+            serde_json::from_str::<serde_json::Value>(line).unwrap()["id"].as_u64().unwrap()
+        })
+        .collect();
+    // every active document is returned exactly once, in internal id order
+    assert_eq!(ids, (0..20_000).step_by(2).collect::<Vec<u64>>());
+
+    let (body, code) = index.scan_documents(json!({})).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(body.lines().count(), @"20000");
+}
+
+#[actix_rt::test]
+async fn scan_documents_with_invalid_filter() {
+    let server = Server::new().await;
+    let index = server.index("test");
+    let (task, _code) = index.add_documents(json!([{ "id": 0, "status": "active" }]), None).await;
+    index.wait_task(task.uid()).await;
+
+    let (body, code) = index.scan_documents(json!({ "filter": "status = active" })).await;
+    snapshot!(code, @"400 Bad Request");
+    let response: serde_json::Value = serde_json::from_str(&body).unwrap();
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "Attribute `status` is not filterable. This index does not have configured filterable attributes.\n1:7 status = active",
+      "code": "invalid_document_filter",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_document_filter"
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn get_document_obkv() {
+    let server = Server::new().await;
+    let index = server.index("test");
+    let (task, _code) = index
+        .add_documents(json!([{ "id": 0, "title": "Captain Marvel", "rating": 4.5 }]), None)
+        .await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (response, code) = index.get_document_obkv(0).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response), @r###"
+    {
+      "internalId": 0,
+      "fields": [
+        {
+          "fieldId": 0,
+          "name": "id",
+          "value": "0"
+        },
+        {
+          "fieldId": 1,
+          "name": "title",
+          "value": "\"Captain Marvel\""
+        },
+        {
+          "fieldId": 2,
+          "name": "rating",
+          "value": "4.5"
+        }
+      ]
+    }
+    "###);
+
+    let (response, code) = index.get_document_obkv(1).await;
+    snapshot!(code, @"404 Not Found");
+    snapshot!(response["code"], @r###""document_not_found""###);
+}
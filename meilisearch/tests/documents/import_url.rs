@@ -0,0 +1,157 @@
+use meili_snap::{json_string, snapshot};
+use meilisearch::Opt;
+use tempfile::TempDir;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use crate::common::{default_settings, Server};
+use crate::json;
+
+async fn server_allowing_localhost(temp: &TempDir) -> Server {
+    Server::new_with_options(Opt {
+        experimental_document_url_import_allowed_hosts: vec!["127.0.0.1".to_string()],
+        ..default_settings(temp.path())
+    })
+    .await
+    .unwrap()
+}
+
+#[actix_rt::test]
+async fn import_ndjson_documents_from_url() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/movies.ndjson"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            "{ \"id\": 1, \"title\": \"Carol\" }\n{ \"id\": 2, \"title\": \"Wonder Woman\" }\n",
+            "application/x-ndjson",
+        ))
+        .mount(&mock_server)
+        .await;
+
+    let temp = TempDir::new().unwrap();
+    let server = server_allowing_localhost(&temp).await;
+    let index = server.index("test");
+
+    let url = format!("{}/movies.ndjson", mock_server.uri());
+    let (task, code) = index.import_documents_from_url(json!({ "url": url })).await;
+    snapshot!(code, @"202 Accepted");
+    let task = index.wait_task(task.uid()).await;
+    task.succeeded();
+    snapshot!(task["type"], @r###""documentUrlImport""###);
+    assert_eq!(task["details"]["url"].as_str(), Some(url.as_str()));
+    assert_eq!(task["details"]["receivedDocuments"].as_u64(), Some(2));
+    assert_eq!(task["details"]["indexedDocuments"].as_u64(), Some(2));
+
+    let (response, code) = index.get_document(2, None).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response), @r###"
+    {
+      "id": 2,
+      "title": "Wonder Woman"
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn import_from_url_reports_failures_in_the_task() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/movies.html"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw("<html></html>", "text/html"))
+        .mount(&mock_server)
+        .await;
+
+    let temp = TempDir::new().unwrap();
+    let server = server_allowing_localhost(&temp).await;
+    let index = server.index("test");
+
+    // bad content type
+    let url = format!("{}/movies.html", mock_server.uri());
+    let (task, code) = index.import_documents_from_url(json!({ "url": url })).await;
+    snapshot!(code, @"202 Accepted");
+    let task = index.wait_task(task.uid()).await;
+    snapshot!(task["status"], @r###""failed""###);
+    snapshot!(task["error"]["code"], @r###""document_url_import_failed""###);
+
+    // file not found on the server
+    let url = format!("{}/movies.ndjson", mock_server.uri());
+    let (task, _code) = index.import_documents_from_url(json!({ "url": url })).await;
+    let task = index.wait_task(task.uid()).await;
+    snapshot!(task["status"], @r###""failed""###);
+    snapshot!(task["error"]["code"], @r###""document_url_import_failed""###);
+}
+
+#[actix_rt::test]
+async fn cancel_a_slow_import_from_url() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/movies.ndjson"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_raw("{ \"id\": 1, \"title\": \"Carol\" }\n", "application/x-ndjson")
+                .set_delay(std::time::Duration::from_secs(5)),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let temp = TempDir::new().unwrap();
+    let server = server_allowing_localhost(&temp).await;
+    let index = server.index("test");
+
+    let url = format!("{}/movies.ndjson", mock_server.uri());
+    let (task, _code) = index.import_documents_from_url(json!({ "url": url })).await;
+    let uid = task.uid();
+    let mut processing = false;
+    for _ in 0..100 {
+        let (task, _code) = index.get_task(uid).await;
+        if task["status"] == "processing" {
+            processing = true;
+            break;
+        }
+        actix_rt::time::sleep(std::time::Duration::from_millis(10)).await;
+    }
+    assert!(processing, "the import was never processed");
+
+    // the download doesn't hold the index, the import is canceled as soon as the server responds
+    let (cancelation, code) = server.cancel_tasks(&format!("uids={uid}")).await;
+    snapshot!(code, @"202 Accepted");
+    server.wait_task(cancelation.uid()).await.succeeded();
+    let (task, _code) = index.get_task(uid).await;
+    snapshot!(task["status"], @r###""canceled""###);
+
+    let (_response, code) = index.get().await;
+    snapshot!(code, @"404 Not Found");
+}
+
+#[actix_rt::test]
+async fn import_from_url_only_accepts_the_allowed_hosts() {
+    let temp = TempDir::new().unwrap();
+    let server = server_allowing_localhost(&temp).await;
+    let index = server.index("test");
+
+    let (response, code) = index
+        .import_documents_from_url(json!({ "url": "http://169.254.169.254/latest/meta-data" }))
+        .await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "The host of the URL `http://169.254.169.254/latest/meta-data` is not allowed. The hosts documents can be imported from are set with the `--experimental-document-url-import-allowed-hosts` option.",
+      "code": "invalid_document_import_url",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_document_import_url"
+    }
+    "###);
+
+    let (response, code) =
+        index.import_documents_from_url(json!({ "url": "file:///etc/passwd" })).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(response["code"], @r###""invalid_document_import_url""###);
+
+    // by default no host is allowed
+    let server = Server::new().await;
+    let index = server.index("test");
+    let (response, code) =
+        index.import_documents_from_url(json!({ "url": "http://127.0.0.1/movies.ndjson" })).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(response["code"], @r###""invalid_document_import_url""###);
+}
@@ -2,4 +2,6 @@ mod add_documents;
 mod delete_documents;
 mod errors;
 mod get_documents;
+mod import_url;
+mod transaction;
 mod update_documents;
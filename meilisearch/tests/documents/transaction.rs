@@ -0,0 +1,124 @@
+use meili_snap::{json_string, snapshot};
+
+use crate::common::index::Index;
+use crate::common::{GetAllDocumentsOptions, Server};
+use crate::json;
+
+async fn documents_ids(index: &Index<'_>) -> Vec<u64> {
+    let (response, _code) = index
+        .get_all_documents(GetAllDocumentsOptions {
+            fields: Some(vec!["id"]),
+            ..Default::default()
+        })
+        .await;
+    let mut ids: Vec<_> = response["results"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|document| document["id"].as_u64().unwrap())
+        .collect();
+    ids.sort_unstable();
+    ids
+}
+
+#[actix_rt::test]
+async fn transaction_is_applied_all_at_once() {
+    let server = Server::new().await;
+    let index = server.index("test");
+    let (task, _code) = index
+        .add_documents(
+            json!([
+                { "id": 1, "title": "one" },
+                { "id": 2, "title": "two" },
+                { "id": 3, "title": "three" },
+                { "id": 4, "title": "four" },
+                { "id": 5, "title": "five" },
+            ]),
+            None,
+        )
+        .await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (task, code) = index
+        .documents_transaction(json!({
+            "operations": [
+                { "action": "delete", "documentIds": [1, 2, "3"] },
+                { "action": "add", "documents": [{ "id": 6, "title": "six" }, { "id": 7, "title": "seven" }] },
+                { "action": "update", "documents": [{ "id": 5, "color": "blue" }] },
+            ]
+        }))
+        .await;
+    snapshot!(code, @"202 Accepted");
+    let task = index.wait_task(task.uid()).await;
+    task.succeeded();
+    snapshot!(task["type"], @r###""documentTransaction""###);
+    snapshot!(json_string!(task["details"]), @r###"
+    {
+      "receivedDocuments": 3,
+      "indexedDocuments": 3,
+      "providedIds": 3,
+      "deletedDocuments": 3
+    }
+    "###);
+
+    snapshot!(format!("{:?}", documents_ids(&index).await), @"[4, 5, 6, 7]");
+    let (document, _code) = index.get_document(5, None).await;
+    snapshot!(json_string!(document), @r###"
+    {
+      "id": 5,
+      "title": "five",
+      "color": "blue"
+    }
+    "###);
+
+    // the invalid document id of the last addition makes the whole transaction fail
+    let (task, code) = index
+        .documents_transaction(json!({
+            "operations": [
+                { "action": "delete", "documentIds": [4, 5] },
+                { "action": "add", "documents": [{ "id": 8, "title": "eight" }] },
+                { "action": "add", "documents": [{ "id": "invalid id!", "title": "nine" }] },
+            ]
+        }))
+        .await;
+    snapshot!(code, @"202 Accepted");
+    let task = index.wait_task(task.uid()).await;
+    snapshot!(task["status"], @r###""failed""###);
+    snapshot!(task["error"]["code"], @r###""invalid_document_id""###);
+    snapshot!(json_string!(task["details"]), @r###"
+    {
+      "receivedDocuments": 2,
+      "indexedDocuments": 0,
+      "providedIds": 2,
+      "deletedDocuments": 0
+    }
+    "###);
+
+    snapshot!(format!("{:?}", documents_ids(&index).await), @"[4, 5, 6, 7]");
+}
+
+#[actix_rt::test]
+async fn invalid_transaction_operations() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (response, code) = index.documents_transaction(json!({ "operations": [] })).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(response["code"], @r###""invalid_document_transaction_operations""###);
+
+    let (response, code) = index
+        .documents_transaction(json!({
+            "operations": [{ "action": "delete", "documents": [{ "id": 1 }] }]
+        }))
+        .await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(response["code"], @r###""invalid_document_transaction_operations""###);
+
+    let (response, code) = index
+        .documents_transaction(json!({
+            "operations": [{ "action": "add", "documents": [1, 2] }]
+        }))
+        .await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(response["code"], @r###""invalid_document_transaction_operations""###);
+}
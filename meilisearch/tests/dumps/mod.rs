@@ -4,9 +4,18 @@ use meili_snap::{json_string, snapshot};
 use meilisearch::Opt;
 
 use self::data::GetDump;
-use crate::common::{default_settings, GetAllDocumentsOptions, Server};
+use crate::common::{default_settings, GetAllDocumentsOptions, Server, Value};
 use crate::json;
 
+/// The sizes of the databases depend on the layout of the LMDB pages, they are left out of the
+/// compared stats.
+fn without_sizes(mut stats: Value) -> Value {
+    let object = stats.as_object_mut().unwrap();
+    object.remove("databasesSizes");
+    object.remove("avgDocumentSize");
+    stats
+}
+
 // all the following test are ignored on windows. See #2364
 #[actix_rt::test]
 #[cfg_attr(target_os = "windows", ignore)]
@@ -28,7 +37,7 @@ async fn import_dump_v1_movie_raw() {
     let (stats, code) = index.stats().await;
     snapshot!(code, @"200 OK");
     assert_eq!(
-        stats,
+        without_sizes(stats),
         json!({ "numberOfDocuments": 53, "isIndexing": false, "fieldDistribution": {"genres": 53, "id": 53, "overview": 53, "poster": 53, "release_date": 53, "title": 53 }})
     );
 
@@ -67,19 +76,53 @@ async fn import_dump_v1_movie_raw() {
           "twoTypos": 9
         },
         "disableOnWords": [],
-        "disableOnAttributes": []
+        "disableOnAttributes": [],
+        "typoPenalty": 1
       },
       "faceting": {
         "maxValuesPerFacet": 100,
         "sortFacetValuesBy": {
           "*": "alpha"
+        },
+        "normalization": {
+          "caseFolding": true
         }
       },
       "pagination": {
         "maxTotalHits": 1000
       },
       "searchCutoffMs": null,
-      "localizedAttributes": null
+      "localizedAttributes": null,
+      "ignoredAttributes": [],
+      "splitAndConcatWords": true,
+      "recencyAttribute": null,
+      "maxFacetValuesPerAttribute": null,
+      "contentTypes": {},
+      "readOnly": false,
+      "attributeTransforms": {},
+      "decimalAttributes": [],
+      "attributesPrefixSearch": {},
+      "maxDocuments": null,
+      "maxPrefixTypos": null,
+      "minPrefixLength": null,
+      "stemming": [],
+      "maxFlattenDepth": null,
+      "defaultLanguage": null,
+      "languageHintField": null,
+      "duplicateKeyStrategy": "lastWins",
+      "tokenizerProfiles": {},
+      "pinnedResults": [],
+      "reserveDocumentIds": false,
+      "userMetadata": null,
+      "storeMaxLength": {},
+      "normalizedFacetAttributes": [],
+      "blockedDocuments": [],
+      "numberTokenization": "split",
+      "enableFormatting": true,
+      "recencyHalfLifeDays": null,
+      "reranker": null,
+      "documentCompression": false,
+      "maxSynonymExpansions": 50
     }
     "###
     );
@@ -88,7 +131,7 @@ async fn import_dump_v1_movie_raw() {
     snapshot!(code, @"200 OK");
     assert_eq!(
         tasks,
-        json!({ "results": [{"uid": 0, "indexUid": "indexUID", "status": "succeeded", "type": "documentAdditionOrUpdate", "canceledBy": null, "details": { "receivedDocuments": 0, "indexedDocuments": 31968 }, "error": null, "duration": "PT9.317060500S", "enqueuedAt": "2021-09-08T09:08:45.153219Z", "startedAt": "2021-09-08T09:08:45.3961665Z", "finishedAt": "2021-09-08T09:08:54.713227Z" }], "total": 1,  "limit": 20, "from": 0, "next": null })
+        json!({ "results": [{"uid": 0, "indexUid": "indexUID", "status": "succeeded", "type": "documentAdditionOrUpdate", "canceledBy": null, "batchUid": null, "details": { "receivedDocuments": 0, "indexedDocuments": 31968 }, "error": null, "duration": "PT9.317060500S", "enqueuedAt": "2021-09-08T09:08:45.153219Z", "startedAt": "2021-09-08T09:08:45.3961665Z", "finishedAt": "2021-09-08T09:08:54.713227Z" }], "total": 1,  "limit": 20, "from": 0, "next": null })
     );
 
     // finally we're just going to check that we can still get a few documents by id
@@ -165,7 +208,7 @@ async fn import_dump_v1_movie_with_settings() {
     let (stats, code) = index.stats().await;
     snapshot!(code, @"200 OK");
     snapshot!(
-        json_string!(stats),
+        json_string!(stats, { ".databasesSizes" => "[sizes]", ".avgDocumentSize" => "[size]" }),
         @r###"
     {
       "numberOfDocuments": 53,
@@ -177,7 +220,9 @@ async fn import_dump_v1_movie_with_settings() {
         "poster": 53,
         "release_date": 53,
         "title": 53
-      }
+      },
+      "databasesSizes": "[sizes]",
+      "avgDocumentSize": "[size]"
     }
     "###
     );
@@ -230,19 +275,53 @@ async fn import_dump_v1_movie_with_settings() {
           "twoTypos": 9
         },
         "disableOnWords": [],
-        "disableOnAttributes": []
+        "disableOnAttributes": [],
+        "typoPenalty": 1
       },
       "faceting": {
         "maxValuesPerFacet": 100,
         "sortFacetValuesBy": {
           "*": "alpha"
+        },
+        "normalization": {
+          "caseFolding": true
         }
       },
       "pagination": {
         "maxTotalHits": 1000
       },
       "searchCutoffMs": null,
-      "localizedAttributes": null
+      "localizedAttributes": null,
+      "ignoredAttributes": [],
+      "splitAndConcatWords": true,
+      "recencyAttribute": null,
+      "maxFacetValuesPerAttribute": null,
+      "contentTypes": {},
+      "readOnly": false,
+      "attributeTransforms": {},
+      "decimalAttributes": [],
+      "attributesPrefixSearch": {},
+      "maxDocuments": null,
+      "maxPrefixTypos": null,
+      "minPrefixLength": null,
+      "stemming": [],
+      "maxFlattenDepth": null,
+      "defaultLanguage": null,
+      "languageHintField": null,
+      "duplicateKeyStrategy": "lastWins",
+      "tokenizerProfiles": {},
+      "pinnedResults": [],
+      "reserveDocumentIds": false,
+      "userMetadata": null,
+      "storeMaxLength": {},
+      "normalizedFacetAttributes": [],
+      "blockedDocuments": [],
+      "numberTokenization": "split",
+      "enableFormatting": true,
+      "recencyHalfLifeDays": null,
+      "reranker": null,
+      "documentCompression": false,
+      "maxSynonymExpansions": 50
     }
     "###
     );
@@ -251,7 +330,7 @@ async fn import_dump_v1_movie_with_settings() {
     snapshot!(code, @"200 OK");
     assert_eq!(
         tasks,
-        json!({ "results": [{ "uid": 1, "indexUid": "indexUID", "status": "succeeded", "type": "settingsUpdate", "canceledBy": null, "details": { "displayedAttributes": ["genres", "id", "overview", "poster", "release_date", "title"], "searchableAttributes": ["title", "overview"], "filterableAttributes": ["genres"], "sortableAttributes": ["genres"], "stopWords": ["of", "the"] }, "error": null, "duration": "PT7.288826907S", "enqueuedAt": "2021-09-08T09:34:40.882977Z", "startedAt": "2021-09-08T09:34:40.883073093Z", "finishedAt": "2021-09-08T09:34:48.1719Z"}, { "uid": 0, "indexUid": "indexUID", "status": "succeeded", "type": "documentAdditionOrUpdate", "canceledBy": null, "details": { "receivedDocuments": 0, "indexedDocuments": 31968 }, "error": null, "duration": "PT9.090735774S", "enqueuedAt": "2021-09-08T09:34:16.036101Z", "startedAt": "2021-09-08T09:34:16.261191226Z", "finishedAt": "2021-09-08T09:34:25.351927Z" }], "total": 2, "limit": 20, "from": 1, "next": null })
+        json!({ "results": [{ "uid": 1, "indexUid": "indexUID", "status": "succeeded", "type": "settingsUpdate", "canceledBy": null, "batchUid": null, "details": { "displayedAttributes": ["genres", "id", "overview", "poster", "release_date", "title"], "searchableAttributes": ["title", "overview"], "filterableAttributes": ["genres"], "sortableAttributes": ["genres"], "stopWords": ["of", "the"] }, "error": null, "duration": "PT7.288826907S", "enqueuedAt": "2021-09-08T09:34:40.882977Z", "startedAt": "2021-09-08T09:34:40.883073093Z", "finishedAt": "2021-09-08T09:34:48.1719Z"}, { "uid": 0, "indexUid": "indexUID", "status": "succeeded", "type": "documentAdditionOrUpdate", "canceledBy": null, "batchUid": null, "details": { "receivedDocuments": 0, "indexedDocuments": 31968 }, "error": null, "duration": "PT9.090735774S", "enqueuedAt": "2021-09-08T09:34:16.036101Z", "startedAt": "2021-09-08T09:34:16.261191226Z", "finishedAt": "2021-09-08T09:34:25.351927Z" }], "total": 2, "limit": 20, "from": 1, "next": null })
     );
 
     // finally we're just going to check that we can still get a few documents by id
@@ -328,7 +407,7 @@ async fn import_dump_v1_rubygems_with_settings() {
     let (stats, code) = index.stats().await;
     snapshot!(code, @"200 OK");
     assert_eq!(
-        stats,
+        without_sizes(stats),
         json!({ "numberOfDocuments": 53, "isIndexing": false, "fieldDistribution": {"description": 53, "id": 53, "name": 53, "summary": 53, "total_downloads": 53, "version": 53 }})
     );
 
@@ -379,19 +458,53 @@ async fn import_dump_v1_rubygems_with_settings() {
           "twoTypos": 9
         },
         "disableOnWords": [],
-        "disableOnAttributes": []
+        "disableOnAttributes": [],
+        "typoPenalty": 1
       },
       "faceting": {
         "maxValuesPerFacet": 100,
         "sortFacetValuesBy": {
           "*": "alpha"
+        },
+        "normalization": {
+          "caseFolding": true
         }
       },
       "pagination": {
         "maxTotalHits": 1000
       },
       "searchCutoffMs": null,
-      "localizedAttributes": null
+      "localizedAttributes": null,
+      "ignoredAttributes": [],
+      "splitAndConcatWords": true,
+      "recencyAttribute": null,
+      "maxFacetValuesPerAttribute": null,
+      "contentTypes": {},
+      "readOnly": false,
+      "attributeTransforms": {},
+      "decimalAttributes": [],
+      "attributesPrefixSearch": {},
+      "maxDocuments": null,
+      "maxPrefixTypos": null,
+      "minPrefixLength": null,
+      "stemming": [],
+      "maxFlattenDepth": null,
+      "defaultLanguage": null,
+      "languageHintField": null,
+      "duplicateKeyStrategy": "lastWins",
+      "tokenizerProfiles": {},
+      "pinnedResults": [],
+      "reserveDocumentIds": false,
+      "userMetadata": null,
+      "storeMaxLength": {},
+      "normalizedFacetAttributes": [],
+      "blockedDocuments": [],
+      "numberTokenization": "split",
+      "enableFormatting": true,
+      "recencyHalfLifeDays": null,
+      "reranker": null,
+      "documentCompression": false,
+      "maxSynonymExpansions": 50
     }
     "###
     );
@@ -400,7 +513,7 @@ async fn import_dump_v1_rubygems_with_settings() {
     snapshot!(code, @"200 OK");
     assert_eq!(
         tasks["results"][0],
-        json!({"uid": 92, "indexUid": "rubygems", "status": "succeeded", "type": "documentAdditionOrUpdate", "canceledBy": null, "details": {"receivedDocuments": 0, "indexedDocuments": 1042}, "error": null, "duration": "PT1.487793839S", "enqueuedAt": "2021-09-08T09:27:01.465296Z", "startedAt": "2021-09-08T09:28:44.882177161Z", "finishedAt": "2021-09-08T09:28:46.369971Z"})
+        json!({"uid": 92, "indexUid": "rubygems", "status": "succeeded", "type": "documentAdditionOrUpdate", "canceledBy": null, "batchUid": null, "details": {"receivedDocuments": 0, "indexedDocuments": 1042}, "error": null, "duration": "PT1.487793839S", "enqueuedAt": "2021-09-08T09:27:01.465296Z", "startedAt": "2021-09-08T09:28:44.882177161Z", "finishedAt": "2021-09-08T09:28:46.369971Z"})
     );
 
     // finally we're just going to check that we can still get a few documents by id
@@ -475,7 +588,7 @@ async fn import_dump_v2_movie_raw() {
     let (stats, code) = index.stats().await;
     snapshot!(code, @"200 OK");
     assert_eq!(
-        stats,
+        without_sizes(stats),
         json!({ "numberOfDocuments": 53, "isIndexing": false, "fieldDistribution": {"genres": 53, "id": 53, "overview": 53, "poster": 53, "release_date": 53, "title": 53 }})
     );
 
@@ -514,19 +627,53 @@ async fn import_dump_v2_movie_raw() {
           "twoTypos": 9
         },
         "disableOnWords": [],
-        "disableOnAttributes": []
+        "disableOnAttributes": [],
+        "typoPenalty": 1
       },
       "faceting": {
         "maxValuesPerFacet": 100,
         "sortFacetValuesBy": {
           "*": "alpha"
+        },
+        "normalization": {
+          "caseFolding": true
         }
       },
       "pagination": {
         "maxTotalHits": 1000
       },
       "searchCutoffMs": null,
-      "localizedAttributes": null
+      "localizedAttributes": null,
+      "ignoredAttributes": [],
+      "splitAndConcatWords": true,
+      "recencyAttribute": null,
+      "maxFacetValuesPerAttribute": null,
+      "contentTypes": {},
+      "readOnly": false,
+      "attributeTransforms": {},
+      "decimalAttributes": [],
+      "attributesPrefixSearch": {},
+      "maxDocuments": null,
+      "maxPrefixTypos": null,
+      "minPrefixLength": null,
+      "stemming": [],
+      "maxFlattenDepth": null,
+      "defaultLanguage": null,
+      "languageHintField": null,
+      "duplicateKeyStrategy": "lastWins",
+      "tokenizerProfiles": {},
+      "pinnedResults": [],
+      "reserveDocumentIds": false,
+      "userMetadata": null,
+      "storeMaxLength": {},
+      "normalizedFacetAttributes": [],
+      "blockedDocuments": [],
+      "numberTokenization": "split",
+      "enableFormatting": true,
+      "recencyHalfLifeDays": null,
+      "reranker": null,
+      "documentCompression": false,
+      "maxSynonymExpansions": 50
     }
     "###
     );
@@ -535,7 +682,7 @@ async fn import_dump_v2_movie_raw() {
     snapshot!(code, @"200 OK");
     assert_eq!(
         tasks,
-        json!({ "results": [{"uid": 0, "indexUid": "indexUID", "status": "succeeded", "type": "documentAdditionOrUpdate", "canceledBy": null, "details": { "receivedDocuments": 0, "indexedDocuments": 31944 }, "error": null, "duration": "PT41.751156S", "enqueuedAt": "2021-09-08T08:30:30.550282Z", "startedAt": "2021-09-08T08:30:30.553012Z", "finishedAt": "2021-09-08T08:31:12.304168Z" }], "total": 1, "limit": 20, "from": 0, "next": null })
+        json!({ "results": [{"uid": 0, "indexUid": "indexUID", "status": "succeeded", "type": "documentAdditionOrUpdate", "canceledBy": null, "batchUid": null, "details": { "receivedDocuments": 0, "indexedDocuments": 31944 }, "error": null, "duration": "PT41.751156S", "enqueuedAt": "2021-09-08T08:30:30.550282Z", "startedAt": "2021-09-08T08:30:30.553012Z", "finishedAt": "2021-09-08T08:31:12.304168Z" }], "total": 1, "limit": 20, "from": 0, "next": null })
     );
 
     // finally we're just going to check that we can still get a few documents by id
@@ -612,7 +759,7 @@ async fn import_dump_v2_movie_with_settings() {
     let (stats, code) = index.stats().await;
     snapshot!(code, @"200 OK");
     assert_eq!(
-        stats,
+        without_sizes(stats),
         json!({ "numberOfDocuments": 53, "isIndexing": false, "fieldDistribution": {"genres": 53, "id": 53, "overview": 53, "poster": 53, "release_date": 53, "title": 53 }})
     );
 
@@ -661,19 +808,53 @@ async fn import_dump_v2_movie_with_settings() {
           "twoTypos": 9
         },
         "disableOnWords": [],
-        "disableOnAttributes": []
+        "disableOnAttributes": [],
+        "typoPenalty": 1
       },
       "faceting": {
         "maxValuesPerFacet": 100,
         "sortFacetValuesBy": {
           "*": "alpha"
+        },
+        "normalization": {
+          "caseFolding": true
         }
       },
       "pagination": {
         "maxTotalHits": 1000
       },
       "searchCutoffMs": null,
-      "localizedAttributes": null
+      "localizedAttributes": null,
+      "ignoredAttributes": [],
+      "splitAndConcatWords": true,
+      "recencyAttribute": null,
+      "maxFacetValuesPerAttribute": null,
+      "contentTypes": {},
+      "readOnly": false,
+      "attributeTransforms": {},
+      "decimalAttributes": [],
+      "attributesPrefixSearch": {},
+      "maxDocuments": null,
+      "maxPrefixTypos": null,
+      "minPrefixLength": null,
+      "stemming": [],
+      "maxFlattenDepth": null,
+      "defaultLanguage": null,
+      "languageHintField": null,
+      "duplicateKeyStrategy": "lastWins",
+      "tokenizerProfiles": {},
+      "pinnedResults": [],
+      "reserveDocumentIds": false,
+      "userMetadata": null,
+      "storeMaxLength": {},
+      "normalizedFacetAttributes": [],
+      "blockedDocuments": [],
+      "numberTokenization": "split",
+      "enableFormatting": true,
+      "recencyHalfLifeDays": null,
+      "reranker": null,
+      "documentCompression": false,
+      "maxSynonymExpansions": 50
     }
     "###
     );
@@ -682,7 +863,7 @@ async fn import_dump_v2_movie_with_settings() {
     snapshot!(code, @"200 OK");
     assert_eq!(
         tasks,
-        json!({ "results": [{ "uid": 1, "indexUid": "indexUID", "status": "succeeded", "type": "settingsUpdate", "canceledBy": null, "details": { "displayedAttributes": ["title", "genres", "overview", "poster", "release_date"], "searchableAttributes": ["title", "overview"], "filterableAttributes": ["genres"], "stopWords": ["of", "the"] }, "error": null, "duration": "PT37.488777S", "enqueuedAt": "2021-09-08T08:24:02.323444Z", "startedAt": "2021-09-08T08:24:02.324145Z", "finishedAt": "2021-09-08T08:24:39.812922Z" }, { "uid": 0, "indexUid": "indexUID", "status": "succeeded", "type": "documentAdditionOrUpdate", "canceledBy": null, "details": { "receivedDocuments": 0, "indexedDocuments": 31944 }, "error": null, "duration": "PT39.941318S", "enqueuedAt": "2021-09-08T08:21:14.742672Z", "startedAt": "2021-09-08T08:21:14.750166Z", "finishedAt": "2021-09-08T08:21:54.691484Z" }], "total": 2, "limit": 20, "from": 1, "next": null })
+        json!({ "results": [{ "uid": 1, "indexUid": "indexUID", "status": "succeeded", "type": "settingsUpdate", "canceledBy": null, "batchUid": null, "details": { "displayedAttributes": ["title", "genres", "overview", "poster", "release_date"], "searchableAttributes": ["title", "overview"], "filterableAttributes": ["genres"], "stopWords": ["of", "the"] }, "error": null, "duration": "PT37.488777S", "enqueuedAt": "2021-09-08T08:24:02.323444Z", "startedAt": "2021-09-08T08:24:02.324145Z", "finishedAt": "2021-09-08T08:24:39.812922Z" }, { "uid": 0, "indexUid": "indexUID", "status": "succeeded", "type": "documentAdditionOrUpdate", "canceledBy": null, "batchUid": null, "details": { "receivedDocuments": 0, "indexedDocuments": 31944 }, "error": null, "duration": "PT39.941318S", "enqueuedAt": "2021-09-08T08:21:14.742672Z", "startedAt": "2021-09-08T08:21:14.750166Z", "finishedAt": "2021-09-08T08:21:54.691484Z" }], "total": 2, "limit": 20, "from": 1, "next": null })
     );
 
     // finally we're just going to check that we can still get a few documents by id
@@ -759,7 +940,7 @@ async fn import_dump_v2_rubygems_with_settings() {
     let (stats, code) = index.stats().await;
     snapshot!(code, @"200 OK");
     assert_eq!(
-        stats,
+        without_sizes(stats),
         json!({ "numberOfDocuments": 53, "isIndexing": false, "fieldDistribution": {"description": 53, "id": 53, "name": 53, "summary": 53, "total_downloads": 53, "version": 53 }})
     );
 
@@ -807,19 +988,53 @@ async fn import_dump_v2_rubygems_with_settings() {
           "twoTypos": 9
         },
         "disableOnWords": [],
-        "disableOnAttributes": []
+        "disableOnAttributes": [],
+        "typoPenalty": 1
       },
       "faceting": {
         "maxValuesPerFacet": 100,
         "sortFacetValuesBy": {
           "*": "alpha"
+        },
+        "normalization": {
+          "caseFolding": true
         }
       },
       "pagination": {
         "maxTotalHits": 1000
       },
       "searchCutoffMs": null,
-      "localizedAttributes": null
+      "localizedAttributes": null,
+      "ignoredAttributes": [],
+      "splitAndConcatWords": true,
+      "recencyAttribute": null,
+      "maxFacetValuesPerAttribute": null,
+      "contentTypes": {},
+      "readOnly": false,
+      "attributeTransforms": {},
+      "decimalAttributes": [],
+      "attributesPrefixSearch": {},
+      "maxDocuments": null,
+      "maxPrefixTypos": null,
+      "minPrefixLength": null,
+      "stemming": [],
+      "maxFlattenDepth": null,
+      "defaultLanguage": null,
+      "languageHintField": null,
+      "duplicateKeyStrategy": "lastWins",
+      "tokenizerProfiles": {},
+      "pinnedResults": [],
+      "reserveDocumentIds": false,
+      "userMetadata": null,
+      "storeMaxLength": {},
+      "normalizedFacetAttributes": [],
+      "blockedDocuments": [],
+      "numberTokenization": "split",
+      "enableFormatting": true,
+      "recencyHalfLifeDays": null,
+      "reranker": null,
+      "documentCompression": false,
+      "maxSynonymExpansions": 50
     }
     "###
     );
@@ -828,7 +1043,7 @@ async fn import_dump_v2_rubygems_with_settings() {
     snapshot!(code, @"200 OK");
     assert_eq!(
         tasks["results"][0],
-        json!({"uid": 92, "indexUid": "rubygems", "status": "succeeded", "type": "documentAdditionOrUpdate", "canceledBy": null, "details": {"receivedDocuments": 0, "indexedDocuments": 1042}, "error": null, "duration": "PT14.034672S", "enqueuedAt": "2021-09-08T08:40:31.390775Z", "startedAt": "2021-09-08T08:51:39.060642Z", "finishedAt": "2021-09-08T08:51:53.095314Z"})
+        json!({"uid": 92, "indexUid": "rubygems", "status": "succeeded", "type": "documentAdditionOrUpdate", "canceledBy": null, "batchUid": null, "details": {"receivedDocuments": 0, "indexedDocuments": 1042}, "error": null, "duration": "PT14.034672S", "enqueuedAt": "2021-09-08T08:40:31.390775Z", "startedAt": "2021-09-08T08:51:39.060642Z", "finishedAt": "2021-09-08T08:51:53.095314Z"})
     );
 
     // finally we're just going to check that we can still get a few documents by id
@@ -903,7 +1118,7 @@ async fn import_dump_v3_movie_raw() {
     let (stats, code) = index.stats().await;
     snapshot!(code, @"200 OK");
     assert_eq!(
-        stats,
+        without_sizes(stats),
         json!({ "numberOfDocuments": 53, "isIndexing": false, "fieldDistribution": {"genres": 53, "id": 53, "overview": 53, "poster": 53, "release_date": 53, "title": 53 }})
     );
 
@@ -942,19 +1157,53 @@ async fn import_dump_v3_movie_raw() {
           "twoTypos": 9
         },
         "disableOnWords": [],
-        "disableOnAttributes": []
+        "disableOnAttributes": [],
+        "typoPenalty": 1
       },
       "faceting": {
         "maxValuesPerFacet": 100,
         "sortFacetValuesBy": {
           "*": "alpha"
+        },
+        "normalization": {
+          "caseFolding": true
         }
       },
       "pagination": {
         "maxTotalHits": 1000
       },
       "searchCutoffMs": null,
-      "localizedAttributes": null
+      "localizedAttributes": null,
+      "ignoredAttributes": [],
+      "splitAndConcatWords": true,
+      "recencyAttribute": null,
+      "maxFacetValuesPerAttribute": null,
+      "contentTypes": {},
+      "readOnly": false,
+      "attributeTransforms": {},
+      "decimalAttributes": [],
+      "attributesPrefixSearch": {},
+      "maxDocuments": null,
+      "maxPrefixTypos": null,
+      "minPrefixLength": null,
+      "stemming": [],
+      "maxFlattenDepth": null,
+      "defaultLanguage": null,
+      "languageHintField": null,
+      "duplicateKeyStrategy": "lastWins",
+      "tokenizerProfiles": {},
+      "pinnedResults": [],
+      "reserveDocumentIds": false,
+      "userMetadata": null,
+      "storeMaxLength": {},
+      "normalizedFacetAttributes": [],
+      "blockedDocuments": [],
+      "numberTokenization": "split",
+      "enableFormatting": true,
+      "recencyHalfLifeDays": null,
+      "reranker": null,
+      "documentCompression": false,
+      "maxSynonymExpansions": 50
     }
     "###
     );
@@ -963,7 +1212,7 @@ async fn import_dump_v3_movie_raw() {
     snapshot!(code, @"200 OK");
     assert_eq!(
         tasks,
-        json!({ "results": [{"uid": 0, "indexUid": "indexUID", "status": "succeeded", "type": "documentAdditionOrUpdate", "canceledBy": null, "details": { "receivedDocuments": 0, "indexedDocuments": 31944 }, "error": null, "duration": "PT41.751156S", "enqueuedAt": "2021-09-08T08:30:30.550282Z", "startedAt": "2021-09-08T08:30:30.553012Z", "finishedAt": "2021-09-08T08:31:12.304168Z" }], "total": 1, "limit": 20, "from": 0, "next": null })
+        json!({ "results": [{"uid": 0, "indexUid": "indexUID", "status": "succeeded", "type": "documentAdditionOrUpdate", "canceledBy": null, "batchUid": null, "details": { "receivedDocuments": 0, "indexedDocuments": 31944 }, "error": null, "duration": "PT41.751156S", "enqueuedAt": "2021-09-08T08:30:30.550282Z", "startedAt": "2021-09-08T08:30:30.553012Z", "finishedAt": "2021-09-08T08:31:12.304168Z" }], "total": 1, "limit": 20, "from": 0, "next": null })
     );
 
     // finally we're just going to check that we can still get a few documents by id
@@ -1040,7 +1289,7 @@ async fn import_dump_v3_movie_with_settings() {
     let (stats, code) = index.stats().await;
     snapshot!(code, @"200 OK");
     assert_eq!(
-        stats,
+        without_sizes(stats),
         json!({ "numberOfDocuments": 53, "isIndexing": false, "fieldDistribution": {"genres": 53, "id": 53, "overview": 53, "poster": 53, "release_date": 53, "title": 53 }})
     );
 
@@ -1089,19 +1338,53 @@ async fn import_dump_v3_movie_with_settings() {
           "twoTypos": 9
         },
         "disableOnWords": [],
-        "disableOnAttributes": []
+        "disableOnAttributes": [],
+        "typoPenalty": 1
       },
       "faceting": {
         "maxValuesPerFacet": 100,
         "sortFacetValuesBy": {
           "*": "alpha"
+        },
+        "normalization": {
+          "caseFolding": true
         }
       },
       "pagination": {
         "maxTotalHits": 1000
       },
       "searchCutoffMs": null,
-      "localizedAttributes": null
+      "localizedAttributes": null,
+      "ignoredAttributes": [],
+      "splitAndConcatWords": true,
+      "recencyAttribute": null,
+      "maxFacetValuesPerAttribute": null,
+      "contentTypes": {},
+      "readOnly": false,
+      "attributeTransforms": {},
+      "decimalAttributes": [],
+      "attributesPrefixSearch": {},
+      "maxDocuments": null,
+      "maxPrefixTypos": null,
+      "minPrefixLength": null,
+      "stemming": [],
+      "maxFlattenDepth": null,
+      "defaultLanguage": null,
+      "languageHintField": null,
+      "duplicateKeyStrategy": "lastWins",
+      "tokenizerProfiles": {},
+      "pinnedResults": [],
+      "reserveDocumentIds": false,
+      "userMetadata": null,
+      "storeMaxLength": {},
+      "normalizedFacetAttributes": [],
+      "blockedDocuments": [],
+      "numberTokenization": "split",
+      "enableFormatting": true,
+      "recencyHalfLifeDays": null,
+      "reranker": null,
+      "documentCompression": false,
+      "maxSynonymExpansions": 50
     }
     "###
     );
@@ -1110,7 +1393,7 @@ async fn import_dump_v3_movie_with_settings() {
     snapshot!(code, @"200 OK");
     assert_eq!(
         tasks,
-        json!({ "results": [{ "uid": 1, "indexUid": "indexUID", "status": "succeeded", "type": "settingsUpdate", "canceledBy": null, "details": { "displayedAttributes": ["title", "genres", "overview", "poster", "release_date"], "searchableAttributes": ["title", "overview"], "filterableAttributes": ["genres"], "stopWords": ["of", "the"] }, "error": null, "duration": "PT37.488777S", "enqueuedAt": "2021-09-08T08:24:02.323444Z", "startedAt": "2021-09-08T08:24:02.324145Z", "finishedAt": "2021-09-08T08:24:39.812922Z" }, { "uid": 0, "indexUid": "indexUID", "status": "succeeded", "type": "documentAdditionOrUpdate", "canceledBy": null, "details": { "receivedDocuments": 0, "indexedDocuments": 31944 }, "error": null, "duration": "PT39.941318S", "enqueuedAt": "2021-09-08T08:21:14.742672Z", "startedAt": "2021-09-08T08:21:14.750166Z", "finishedAt": "2021-09-08T08:21:54.691484Z" }], "total": 2, "limit": 20, "from": 1, "next": null })
+        json!({ "results": [{ "uid": 1, "indexUid": "indexUID", "status": "succeeded", "type": "settingsUpdate", "canceledBy": null, "batchUid": null, "details": { "displayedAttributes": ["title", "genres", "overview", "poster", "release_date"], "searchableAttributes": ["title", "overview"], "filterableAttributes": ["genres"], "stopWords": ["of", "the"] }, "error": null, "duration": "PT37.488777S", "enqueuedAt": "2021-09-08T08:24:02.323444Z", "startedAt": "2021-09-08T08:24:02.324145Z", "finishedAt": "2021-09-08T08:24:39.812922Z" }, { "uid": 0, "indexUid": "indexUID", "status": "succeeded", "type": "documentAdditionOrUpdate", "canceledBy": null, "batchUid": null, "details": { "receivedDocuments": 0, "indexedDocuments": 31944 }, "error": null, "duration": "PT39.941318S", "enqueuedAt": "2021-09-08T08:21:14.742672Z", "startedAt": "2021-09-08T08:21:14.750166Z", "finishedAt": "2021-09-08T08:21:54.691484Z" }], "total": 2, "limit": 20, "from": 1, "next": null })
     );
 
     // finally we're just going to check that we can["results"] still get a few documents by id
@@ -1187,7 +1470,7 @@ async fn import_dump_v3_rubygems_with_settings() {
     let (stats, code) = index.stats().await;
     snapshot!(code, @"200 OK");
     assert_eq!(
-        stats,
+        without_sizes(stats),
         json!({ "numberOfDocuments": 53, "isIndexing": false, "fieldDistribution": {"description": 53, "id": 53, "name": 53, "summary": 53, "total_downloads": 53, "version": 53 }})
     );
 
@@ -1235,19 +1518,53 @@ async fn import_dump_v3_rubygems_with_settings() {
           "twoTypos": 9
         },
         "disableOnWords": [],
-        "disableOnAttributes": []
+        "disableOnAttributes": [],
+        "typoPenalty": 1
       },
       "faceting": {
         "maxValuesPerFacet": 100,
         "sortFacetValuesBy": {
           "*": "alpha"
+        },
+        "normalization": {
+          "caseFolding": true
         }
       },
       "pagination": {
         "maxTotalHits": 1000
       },
       "searchCutoffMs": null,
-      "localizedAttributes": null
+      "localizedAttributes": null,
+      "ignoredAttributes": [],
+      "splitAndConcatWords": true,
+      "recencyAttribute": null,
+      "maxFacetValuesPerAttribute": null,
+      "contentTypes": {},
+      "readOnly": false,
+      "attributeTransforms": {},
+      "decimalAttributes": [],
+      "attributesPrefixSearch": {},
+      "maxDocuments": null,
+      "maxPrefixTypos": null,
+      "minPrefixLength": null,
+      "stemming": [],
+      "maxFlattenDepth": null,
+      "defaultLanguage": null,
+      "languageHintField": null,
+      "duplicateKeyStrategy": "lastWins",
+      "tokenizerProfiles": {},
+      "pinnedResults": [],
+      "reserveDocumentIds": false,
+      "userMetadata": null,
+      "storeMaxLength": {},
+      "normalizedFacetAttributes": [],
+      "blockedDocuments": [],
+      "numberTokenization": "split",
+      "enableFormatting": true,
+      "recencyHalfLifeDays": null,
+      "reranker": null,
+      "documentCompression": false,
+      "maxSynonymExpansions": 50
     }
     "###
     );
@@ -1256,7 +1573,7 @@ async fn import_dump_v3_rubygems_with_settings() {
     snapshot!(code, @"200 OK");
     assert_eq!(
         tasks["results"][0],
-        json!({"uid": 92, "indexUid": "rubygems", "status": "succeeded", "type": "documentAdditionOrUpdate", "canceledBy": null, "details": {"receivedDocuments": 0, "indexedDocuments": 1042}, "error": null, "duration": "PT14.034672S", "enqueuedAt": "2021-09-08T08:40:31.390775Z", "startedAt": "2021-09-08T08:51:39.060642Z", "finishedAt": "2021-09-08T08:51:53.095314Z"})
+        json!({"uid": 92, "indexUid": "rubygems", "status": "succeeded", "type": "documentAdditionOrUpdate", "canceledBy": null, "batchUid": null, "details": {"receivedDocuments": 0, "indexedDocuments": 1042}, "error": null, "duration": "PT14.034672S", "enqueuedAt": "2021-09-08T08:40:31.390775Z", "startedAt": "2021-09-08T08:51:39.060642Z", "finishedAt": "2021-09-08T08:51:53.095314Z"})
     );
 
     // finally we're just going to check that we can still get a few documents by id
@@ -1331,7 +1648,7 @@ async fn import_dump_v4_movie_raw() {
     let (stats, code) = index.stats().await;
     snapshot!(code, @"200 OK");
     assert_eq!(
-        stats,
+        without_sizes(stats),
         json!({ "numberOfDocuments": 53, "isIndexing": false, "fieldDistribution": {"genres": 53, "id": 53, "overview": 53, "poster": 53, "release_date": 53, "title": 53 }})
     );
 
@@ -1370,19 +1687,53 @@ async fn import_dump_v4_movie_raw() {
           "twoTypos": 9
         },
         "disableOnWords": [],
-        "disableOnAttributes": []
+        "disableOnAttributes": [],
+        "typoPenalty": 1
       },
       "faceting": {
         "maxValuesPerFacet": 100,
         "sortFacetValuesBy": {
           "*": "alpha"
+        },
+        "normalization": {
+          "caseFolding": true
         }
       },
       "pagination": {
         "maxTotalHits": 1000
       },
       "searchCutoffMs": null,
-      "localizedAttributes": null
+      "localizedAttributes": null,
+      "ignoredAttributes": [],
+      "splitAndConcatWords": true,
+      "recencyAttribute": null,
+      "maxFacetValuesPerAttribute": null,
+      "contentTypes": {},
+      "readOnly": false,
+      "attributeTransforms": {},
+      "decimalAttributes": [],
+      "attributesPrefixSearch": {},
+      "maxDocuments": null,
+      "maxPrefixTypos": null,
+      "minPrefixLength": null,
+      "stemming": [],
+      "maxFlattenDepth": null,
+      "defaultLanguage": null,
+      "languageHintField": null,
+      "duplicateKeyStrategy": "lastWins",
+      "tokenizerProfiles": {},
+      "pinnedResults": [],
+      "reserveDocumentIds": false,
+      "userMetadata": null,
+      "storeMaxLength": {},
+      "normalizedFacetAttributes": [],
+      "blockedDocuments": [],
+      "numberTokenization": "split",
+      "enableFormatting": true,
+      "recencyHalfLifeDays": null,
+      "reranker": null,
+      "documentCompression": false,
+      "maxSynonymExpansions": 50
     }
     "###
     );
@@ -1391,7 +1742,7 @@ async fn import_dump_v4_movie_raw() {
     snapshot!(code, @"200 OK");
     assert_eq!(
         tasks,
-        json!({ "results": [{"uid": 0, "indexUid": "indexUID", "status": "succeeded", "type": "documentAdditionOrUpdate", "canceledBy": null, "details": { "receivedDocuments": 0, "indexedDocuments": 31944 }, "error": null, "duration": "PT41.751156S", "enqueuedAt": "2021-09-08T08:30:30.550282Z", "startedAt": "2021-09-08T08:30:30.553012Z", "finishedAt": "2021-09-08T08:31:12.304168Z" }], "total": 1, "limit" : 20, "from": 0, "next": null })
+        json!({ "results": [{"uid": 0, "indexUid": "indexUID", "status": "succeeded", "type": "documentAdditionOrUpdate", "canceledBy": null, "batchUid": null, "details": { "receivedDocuments": 0, "indexedDocuments": 31944 }, "error": null, "duration": "PT41.751156S", "enqueuedAt": "2021-09-08T08:30:30.550282Z", "startedAt": "2021-09-08T08:30:30.553012Z", "finishedAt": "2021-09-08T08:31:12.304168Z" }], "total": 1, "limit" : 20, "from": 0, "next": null })
     );
 
     // finally we're just going to check that we can still get a few documents by id
@@ -1468,7 +1819,7 @@ async fn import_dump_v4_movie_with_settings() {
     let (stats, code) = index.stats().await;
     snapshot!(code, @"200 OK");
     assert_eq!(
-        stats,
+        without_sizes(stats),
         json!({ "numberOfDocuments": 53, "isIndexing": false, "fieldDistribution": {"genres": 53, "id": 53, "overview": 53, "poster": 53, "release_date": 53, "title": 53 }})
     );
 
@@ -1517,19 +1868,53 @@ async fn import_dump_v4_movie_with_settings() {
           "twoTypos": 9
         },
         "disableOnWords": [],
-        "disableOnAttributes": []
+        "disableOnAttributes": [],
+        "typoPenalty": 1
       },
       "faceting": {
         "maxValuesPerFacet": 100,
         "sortFacetValuesBy": {
           "*": "alpha"
+        },
+        "normalization": {
+          "caseFolding": true
         }
       },
       "pagination": {
         "maxTotalHits": 1000
       },
       "searchCutoffMs": null,
-      "localizedAttributes": null
+      "localizedAttributes": null,
+      "ignoredAttributes": [],
+      "splitAndConcatWords": true,
+      "recencyAttribute": null,
+      "maxFacetValuesPerAttribute": null,
+      "contentTypes": {},
+      "readOnly": false,
+      "attributeTransforms": {},
+      "decimalAttributes": [],
+      "attributesPrefixSearch": {},
+      "maxDocuments": null,
+      "maxPrefixTypos": null,
+      "minPrefixLength": null,
+      "stemming": [],
+      "maxFlattenDepth": null,
+      "defaultLanguage": null,
+      "languageHintField": null,
+      "duplicateKeyStrategy": "lastWins",
+      "tokenizerProfiles": {},
+      "pinnedResults": [],
+      "reserveDocumentIds": false,
+      "userMetadata": null,
+      "storeMaxLength": {},
+      "normalizedFacetAttributes": [],
+      "blockedDocuments": [],
+      "numberTokenization": "split",
+      "enableFormatting": true,
+      "recencyHalfLifeDays": null,
+      "reranker": null,
+      "documentCompression": false,
+      "maxSynonymExpansions": 50
     }
     "###
     );
@@ -1538,7 +1923,7 @@ async fn import_dump_v4_movie_with_settings() {
     snapshot!(code, @"200 OK");
     assert_eq!(
         tasks,
-        json!({ "results": [{ "uid": 1, "indexUid": "indexUID", "status": "succeeded", "type": "settingsUpdate", "canceledBy": null, "details": { "displayedAttributes": ["title", "genres", "overview", "poster", "release_date"], "searchableAttributes": ["title", "overview"], "filterableAttributes": ["genres"], "stopWords": ["of", "the"] }, "error": null, "duration": "PT37.488777S", "enqueuedAt": "2021-09-08T08:24:02.323444Z", "startedAt": "2021-09-08T08:24:02.324145Z", "finishedAt": "2021-09-08T08:24:39.812922Z" }, { "uid": 0, "indexUid": "indexUID", "status": "succeeded", "type": "documentAdditionOrUpdate", "canceledBy": null, "details": { "receivedDocuments": 0, "indexedDocuments": 31944 }, "error": null, "duration": "PT39.941318S", "enqueuedAt": "2021-09-08T08:21:14.742672Z", "startedAt": "2021-09-08T08:21:14.750166Z", "finishedAt": "2021-09-08T08:21:54.691484Z" }], "total": 2, "limit": 20, "from": 1, "next": null })
+        json!({ "results": [{ "uid": 1, "indexUid": "indexUID", "status": "succeeded", "type": "settingsUpdate", "canceledBy": null, "batchUid": null, "details": { "displayedAttributes": ["title", "genres", "overview", "poster", "release_date"], "searchableAttributes": ["title", "overview"], "filterableAttributes": ["genres"], "stopWords": ["of", "the"] }, "error": null, "duration": "PT37.488777S", "enqueuedAt": "2021-09-08T08:24:02.323444Z", "startedAt": "2021-09-08T08:24:02.324145Z", "finishedAt": "2021-09-08T08:24:39.812922Z" }, { "uid": 0, "indexUid": "indexUID", "status": "succeeded", "type": "documentAdditionOrUpdate", "canceledBy": null, "batchUid": null, "details": { "receivedDocuments": 0, "indexedDocuments": 31944 }, "error": null, "duration": "PT39.941318S", "enqueuedAt": "2021-09-08T08:21:14.742672Z", "startedAt": "2021-09-08T08:21:14.750166Z", "finishedAt": "2021-09-08T08:21:54.691484Z" }], "total": 2, "limit": 20, "from": 1, "next": null })
     );
 
     // finally we're just going to check that we can still get a few documents by id
@@ -1615,7 +2000,7 @@ async fn import_dump_v4_rubygems_with_settings() {
     let (stats, code) = index.stats().await;
     snapshot!(code, @"200 OK");
     assert_eq!(
-        stats,
+        without_sizes(stats),
         json!({ "numberOfDocuments": 53, "isIndexing": false, "fieldDistribution": {"description": 53, "id": 53, "name": 53, "summary": 53, "total_downloads": 53, "version": 53 }})
     );
 
@@ -1663,19 +2048,53 @@ async fn import_dump_v4_rubygems_with_settings() {
           "twoTypos": 9
         },
         "disableOnWords": [],
-        "disableOnAttributes": []
+        "disableOnAttributes": [],
+        "typoPenalty": 1
       },
       "faceting": {
         "maxValuesPerFacet": 100,
         "sortFacetValuesBy": {
           "*": "alpha"
+        },
+        "normalization": {
+          "caseFolding": true
         }
       },
       "pagination": {
         "maxTotalHits": 1000
       },
       "searchCutoffMs": null,
-      "localizedAttributes": null
+      "localizedAttributes": null,
+      "ignoredAttributes": [],
+      "splitAndConcatWords": true,
+      "recencyAttribute": null,
+      "maxFacetValuesPerAttribute": null,
+      "contentTypes": {},
+      "readOnly": false,
+      "attributeTransforms": {},
+      "decimalAttributes": [],
+      "attributesPrefixSearch": {},
+      "maxDocuments": null,
+      "maxPrefixTypos": null,
+      "minPrefixLength": null,
+      "stemming": [],
+      "maxFlattenDepth": null,
+      "defaultLanguage": null,
+      "languageHintField": null,
+      "duplicateKeyStrategy": "lastWins",
+      "tokenizerProfiles": {},
+      "pinnedResults": [],
+      "reserveDocumentIds": false,
+      "userMetadata": null,
+      "storeMaxLength": {},
+      "normalizedFacetAttributes": [],
+      "blockedDocuments": [],
+      "numberTokenization": "split",
+      "enableFormatting": true,
+      "recencyHalfLifeDays": null,
+      "reranker": null,
+      "documentCompression": false,
+      "maxSynonymExpansions": 50
     }
     "###
     );
@@ -1684,7 +2103,7 @@ async fn import_dump_v4_rubygems_with_settings() {
     snapshot!(code, @"200 OK");
     assert_eq!(
         tasks["results"][0],
-        json!({ "uid": 92, "indexUid": "rubygems", "status": "succeeded", "type": "documentAdditionOrUpdate", "canceledBy": null, "details": {"receivedDocuments": 0, "indexedDocuments": 1042}, "error": null, "duration": "PT14.034672S", "enqueuedAt": "2021-09-08T08:40:31.390775Z", "startedAt": "2021-09-08T08:51:39.060642Z", "finishedAt": "2021-09-08T08:51:53.095314Z"})
+        json!({ "uid": 92, "indexUid": "rubygems", "status": "succeeded", "type": "documentAdditionOrUpdate", "canceledBy": null, "batchUid": null, "details": {"receivedDocuments": 0, "indexedDocuments": 1042}, "error": null, "duration": "PT14.034672S", "enqueuedAt": "2021-09-08T08:40:31.390775Z", "startedAt": "2021-09-08T08:51:39.060642Z", "finishedAt": "2021-09-08T08:51:53.095314Z"})
     );
 
     // finally we're just going to check that we can still get a few documents by id
@@ -1788,7 +2207,7 @@ async fn import_dump_v5() {
 
     let (stats, code) = index1.stats().await;
     snapshot!(code, @"200 OK");
-    assert_eq!(stats, expected_stats);
+    assert_eq!(without_sizes(stats), expected_stats);
 
     let (docs, code) = index2.get_all_documents(GetAllDocumentsOptions::default()).await;
     snapshot!(code, @"200 OK");
@@ -1799,7 +2218,7 @@ async fn import_dump_v5() {
 
     let (stats, code) = index2.stats().await;
     snapshot!(code, @"200 OK");
-    assert_eq!(stats, expected_stats);
+    assert_eq!(without_sizes(stats), expected_stats);
 
     let (keys, code) = server.list_api_keys("").await;
     snapshot!(code, @"200 OK");
@@ -1910,19 +2329,53 @@ async fn import_dump_v6_containing_experimental_features() {
           "twoTypos": 9
         },
         "disableOnWords": [],
-        "disableOnAttributes": []
+        "disableOnAttributes": [],
+        "typoPenalty": 1
       },
       "faceting": {
         "maxValuesPerFacet": 100,
         "sortFacetValuesBy": {
           "*": "alpha"
+        },
+        "normalization": {
+          "caseFolding": true
         }
       },
       "pagination": {
         "maxTotalHits": 1000
       },
       "searchCutoffMs": null,
-      "localizedAttributes": null
+      "localizedAttributes": null,
+      "ignoredAttributes": [],
+      "splitAndConcatWords": true,
+      "recencyAttribute": null,
+      "maxFacetValuesPerAttribute": null,
+      "contentTypes": {},
+      "readOnly": false,
+      "attributeTransforms": {},
+      "decimalAttributes": [],
+      "attributesPrefixSearch": {},
+      "maxDocuments": null,
+      "maxPrefixTypos": null,
+      "minPrefixLength": null,
+      "stemming": [],
+      "maxFlattenDepth": null,
+      "defaultLanguage": null,
+      "languageHintField": null,
+      "duplicateKeyStrategy": "lastWins",
+      "tokenizerProfiles": {},
+      "pinnedResults": [],
+      "reserveDocumentIds": false,
+      "userMetadata": null,
+      "storeMaxLength": {},
+      "normalizedFacetAttributes": [],
+      "blockedDocuments": [],
+      "numberTokenization": "split",
+      "enableFormatting": true,
+      "recencyHalfLifeDays": null,
+      "reranker": null,
+      "documentCompression": false,
+      "maxSynonymExpansions": 50
     }
     "###);
 
@@ -2081,12 +2534,16 @@ async fn generate_and_import_dump_containing_vectors() {
           "twoTypos": 9
         },
         "disableOnWords": [],
-        "disableOnAttributes": []
+        "disableOnAttributes": [],
+        "typoPenalty": 1
       },
       "faceting": {
         "maxValuesPerFacet": 100,
         "sortFacetValuesBy": {
           "*": "alpha"
+        },
+        "normalization": {
+          "caseFolding": true
         }
       },
       "pagination": {
@@ -2101,7 +2558,37 @@ async fn generate_and_import_dump_containing_vectors() {
         }
       },
       "searchCutoffMs": null,
-      "localizedAttributes": null
+      "localizedAttributes": null,
+      "ignoredAttributes": [],
+      "splitAndConcatWords": true,
+      "recencyAttribute": null,
+      "maxFacetValuesPerAttribute": null,
+      "contentTypes": {},
+      "readOnly": false,
+      "attributeTransforms": {},
+      "decimalAttributes": [],
+      "attributesPrefixSearch": {},
+      "maxDocuments": null,
+      "maxPrefixTypos": null,
+      "minPrefixLength": null,
+      "stemming": [],
+      "maxFlattenDepth": null,
+      "defaultLanguage": null,
+      "languageHintField": null,
+      "duplicateKeyStrategy": "lastWins",
+      "tokenizerProfiles": {},
+      "pinnedResults": [],
+      "reserveDocumentIds": false,
+      "userMetadata": null,
+      "storeMaxLength": {},
+      "normalizedFacetAttributes": [],
+      "blockedDocuments": [],
+      "numberTokenization": "split",
+      "enableFormatting": true,
+      "recencyHalfLifeDays": null,
+      "reranker": null,
+      "documentCompression": false,
+      "maxSynonymExpansions": 50
     }
     "###);
 
@@ -2165,3 +2652,479 @@ async fn generate_and_import_dump_containing_vectors() {
         })
         .await;
 }
+
+#[actix_rt::test]
+#[cfg_attr(target_os = "windows", ignore)]
+async fn generate_and_import_settings_only_dump() {
+    let temp = tempfile::tempdir().unwrap();
+    let mut opt = default_settings(temp.path());
+    let server = Server::new_with_options(opt.clone()).await.unwrap();
+    let index = server.index("pets");
+    let (response, code) = index
+        .update_settings(json!({ "filterableAttributes": ["doggo"], "stopWords": ["the"] }))
+        .await;
+    snapshot!(code, @"202 Accepted");
+    index.wait_task(response.uid()).await;
+    let (response, code) = index
+        .add_documents(json!([{"id": 0, "doggo": "kefir"}, {"id": 1, "doggo": "echo"}]), None)
+        .await;
+    snapshot!(code, @"202 Accepted");
+    index.wait_task(response.uid()).await;
+
+    let (response, code) = server.create_settings_only_dump().await;
+    snapshot!(code, @"202 Accepted");
+    let response = index.wait_task(response.uid()).await;
+    snapshot!(response["status"], @r###""succeeded""###);
+
+    // ========= We made a dump, now we should clear the DB and try to import our dump
+    drop(server);
+    tokio::fs::remove_dir_all(&opt.db_path).await.unwrap();
+    let dump_name = format!("{}.dump", response["details"]["dumpUid"].as_str().unwrap());
+    let dump_path = opt.dump_dir.join(dump_name);
+    assert!(dump_path.exists(), "path: `{}`", dump_path.display());
+
+    opt.import_dump = Some(dump_path);
+    opt.db_path = temp.path().join("data.ms");
+
+    let mut server = Server::new_auth_with_options(opt, temp).await;
+    server.use_api_key("MASTER_KEY");
+
+    let (indexes, code) = server.list_indexes(None, None).await;
+    assert_eq!(code, 200, "{indexes}");
+    snapshot!(indexes["results"].as_array().unwrap().len(), @"1");
+    snapshot!(indexes["results"][0]["uid"], @r###""pets""###);
+    snapshot!(indexes["results"][0]["primaryKey"], @r###""id""###);
+
+    let index = server.index("pets");
+    let (response, code) = index.settings().await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response["filterableAttributes"]), @r###"
+    [
+      "doggo"
+    ]
+    "###);
+    snapshot!(json_string!(response["stopWords"]), @r###"
+    [
+      "the"
+    ]
+    "###);
+
+    let (documents, code) = index.get_all_documents(GetAllDocumentsOptions::default()).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(documents["results"].as_array().unwrap().len(), @"0");
+    snapshot!(documents["total"], @"0");
+}
+
+#[actix_rt::test]
+async fn create_dump_with_invalid_settings_only() {
+    let server = Server::new().await;
+    let (response, code) = server.service.post("/dumps?settingsOnly=doggo", json!(null)).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "Invalid value in parameter `settingsOnly`: could not parse `doggo` as a boolean, expected either `true` or `false`",
+      "code": "invalid_dump_settings_only",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_dump_settings_only"
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn import_dump_into_running_instance() {
+    let server = Server::new().await;
+    let pets = server.index("pets");
+    let (task, _code) = pets.add_documents(json!([{ "id": 0, "name": "kefir" }]), None).await;
+    pets.wait_task(task.uid()).await.succeeded();
+
+    let (task, code) = server.create_dump().await;
+    snapshot!(code, @"202 Accepted");
+    let task = server.wait_task(task.uid()).await;
+    let dump_uid = task["details"]["dumpUid"].as_str().unwrap().to_string();
+
+    // An index that isn't part of the dump, it must be served during and after the import.
+    let books = server.index("books");
+    let (task, _code) = books.add_documents(json!([{ "id": 0, "title": "dune" }]), None).await;
+    books.wait_task(task.uid()).await.succeeded();
+
+    // Without a namespace the dumped index collides with the existing `pets` index.
+    let (response, code) =
+        server.service.post(format!("/dumps/{dump_uid}/import"), json!({})).await;
+    snapshot!(code, @"409 Conflict");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "Index `pets` already exists.",
+      "code": "index_already_exists",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#index_already_exists"
+    }
+    "###);
+
+    let (task, code) = server
+        .service
+        .post(format!("/dumps/{dump_uid}/import"), json!({ "namespace": "restored" }))
+        .await;
+    snapshot!(code, @"202 Accepted");
+
+    let (response, code) = books.search_post(json!({ "q": "dune" })).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(response["hits"].as_array().unwrap().len(), @"1");
+
+    let task = server.wait_task(task.uid()).await;
+    snapshot!(json_string!(task, { ".batchUid" => "[batch_uid]", ".details.dumpUid" => "[dump_uid]", ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" }), @r###"
+    {
+      "uid": 3,
+      "indexUid": null,
+      "status": "succeeded",
+      "type": "dumpImport",
+      "canceledBy": null,
+      "batchUid": "[batch_uid]",
+      "details": {
+        "dumpUid": "[dump_uid]",
+        "namespace": "restored",
+        "importedIndexes": [
+          "restored-pets"
+        ]
+      },
+      "error": null,
+      "duration": "[duration]",
+      "enqueuedAt": "[date]",
+      "startedAt": "[date]",
+      "finishedAt": "[date]"
+    }
+    "###);
+
+    let (response, code) = books.search_post(json!({ "q": "dune" })).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(response["hits"].as_array().unwrap().len(), @"1");
+
+    let (response, code) = server.index("restored-pets").search_post(json!({ "q": "kefir" })).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response["hits"]), @r###"
+    [
+      {
+        "id": 0,
+        "name": "kefir"
+      }
+    ]
+    "###);
+
+    // The imported index now collides too.
+    let (response, code) = server
+        .service
+        .post(format!("/dumps/{dump_uid}/import"), json!({ "namespace": "restored" }))
+        .await;
+    snapshot!(code, @"409 Conflict");
+    snapshot!(response["message"], @r###""Index `restored-pets` already exists.""###);
+}
+
+#[actix_rt::test]
+async fn import_missing_dump() {
+    let server = Server::new().await;
+    let (response, code) = server.service.post("/dumps/doggo/import", json!({})).await;
+    snapshot!(code, @"404 Not Found");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "Dump `doggo` not found.",
+      "code": "dump_not_found",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#dump_not_found"
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn import_incremental_dump_into_running_instance() {
+    let server = Server::new().await;
+    let pets = server.index("pets");
+    let books = server.index("books");
+    let (task, _code) = pets.add_documents(json!([{ "id": 0, "name": "kefir" }]), None).await;
+    pets.wait_task(task.uid()).await.succeeded();
+    let (task, _code) = books.add_documents(json!([{ "id": 0, "title": "dune" }]), None).await;
+    books.wait_task(task.uid()).await.succeeded();
+    let (task, _code) = pets.add_documents(json!([{ "id": 1, "name": "echo" }]), None).await;
+    pets.wait_task(task.uid()).await.succeeded();
+
+    // Only `pets` was modified after the task 1.
+    let (task, code) = server.create_incremental_dump(1).await;
+    snapshot!(code, @"202 Accepted");
+    let task = server.wait_task(task.uid()).await;
+    task.succeeded();
+    let dump_uid = task["details"]["dumpUid"].as_str().unwrap().to_string();
+
+    let (task, _code) = pets.clear_all_documents().await;
+    pets.wait_task(task.uid()).await.succeeded();
+
+    // The indexes of an incremental dump replace the existing ones.
+    let (task, code) = server.service.post(format!("/dumps/{dump_uid}/import"), json!({})).await;
+    snapshot!(code, @"202 Accepted");
+    let task = server.wait_task(task.uid()).await;
+    task.succeeded();
+    snapshot!(json_string!(task["details"]["importedIndexes"]), @r###"
+    [
+      "pets"
+    ]
+    "###);
+
+    let (documents, code) = pets.get_all_documents(GetAllDocumentsOptions::default()).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(documents["total"], @"2");
+
+    let (documents, code) = books.get_all_documents(GetAllDocumentsOptions::default()).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(documents["results"]), @r###"
+    [
+      {
+        "id": 0,
+        "title": "dune"
+      }
+    ]
+    "###);
+}
+
+#[actix_rt::test]
+async fn import_incremental_dump_with_deleted_index() {
+    let server = Server::new().await;
+    let pets = server.index("pets");
+    let books = server.index("books");
+    let (task, _code) = pets.add_documents(json!([{ "id": 0, "name": "kefir" }]), None).await;
+    pets.wait_task(task.uid()).await.succeeded();
+    let (task, _code) = books.add_documents(json!([{ "id": 0, "title": "dune" }]), None).await;
+    books.wait_task(task.uid()).await.succeeded();
+    let (task, _code) = books.delete().await;
+    books.wait_task(task.uid()).await.succeeded();
+
+    let (task, _code) = server.create_incremental_dump(1).await;
+    let task = server.wait_task(task.uid()).await;
+    task.succeeded();
+    let dump_uid = task["details"]["dumpUid"].as_str().unwrap().to_string();
+
+    let (task, _code) = books.add_documents(json!([{ "id": 1, "title": "hyperion" }]), None).await;
+    books.wait_task(task.uid()).await.succeeded();
+
+    // The index deleted after the checkpoint is deleted again by the import.
+    let (task, code) = server.service.post(format!("/dumps/{dump_uid}/import"), json!({})).await;
+    snapshot!(code, @"202 Accepted");
+    let task = server.wait_task(task.uid()).await;
+    task.succeeded();
+    snapshot!(task["uid"], @"5");
+
+    let (_response, code) = books.get().await;
+    snapshot!(code, @"404 Not Found");
+    let (_response, code) = pets.get().await;
+    snapshot!(code, @"200 OK");
+
+    // The tasks following the checkpoint are appended after the import.
+    let (response, code) = server.tasks_filter("from=7&limit=2").await;
+    snapshot!(code, @"200 OK");
+    let tasks: Vec<_> = response["results"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|task| json!([task["uid"], task["type"], task["indexUid"], task["status"]]))
+        .collect();
+    snapshot!(json_string!(tasks), @r###"
+    [
+      [
+        7,
+        "dumpCreation",
+        null,
+        "succeeded"
+      ],
+      [
+        6,
+        "indexDeletion",
+        "books",
+        "succeeded"
+      ]
+    ]
+    "###);
+}
+
+#[actix_rt::test]
+async fn create_dump_with_invalid_since() {
+    let server = Server::new().await;
+    let (response, code) = server.service.post("/dumps?since=doggo", json!(null)).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "Invalid value in parameter `since`: could not parse `doggo` as a positive integer",
+      "code": "invalid_dump_since",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_dump_since"
+    }
+    "###);
+
+    let (response, code) =
+        server.service.post("/dumps?since=2&settingsOnly=true", json!(null)).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "The `settingsOnly` and `since` parameters can't be used together.",
+      "code": "invalid_dump_since",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_dump_since"
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn import_uploaded_dump() {
+    let server = Server::new().await;
+    let pets = server.index("pets");
+    let (task, _code) = pets.add_documents(json!([{ "id": 0, "name": "kefir" }]), None).await;
+    pets.wait_task(task.uid()).await.succeeded();
+
+    let (task, code) = server.create_dump().await;
+    snapshot!(code, @"202 Accepted");
+    let task = server.wait_task(task.uid()).await;
+    let dump_uid = task["details"]["dumpUid"].as_str().unwrap().to_string();
+    let dump_path = server.service.options.dump_dir.join(format!("{dump_uid}.dump"));
+    let archive = std::fs::read(dump_path).unwrap();
+
+    let req = actix_web::test::TestRequest::post()
+        .uri("/dumps/import?namespace=uploaded")
+        .insert_header(("content-type", "application/gzip"))
+        .set_payload(archive);
+    let (task, code) = server.service.request(req).await;
+    snapshot!(code, @"202 Accepted");
+
+    let task = server.wait_task(task.uid()).await;
+    snapshot!(task["status"], @r###""succeeded""###);
+    snapshot!(json_string!(task["details"]["importedIndexes"]), @r###"
+    [
+      "uploaded-pets"
+    ]
+    "###);
+
+    let (response, code) = server.index("uploaded-pets").search_post(json!({ "q": "kefir" })).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(response["hits"].as_array().unwrap().len(), @"1");
+
+    // An archive that isn't a dump is refused before any task is enqueued.
+    let req = actix_web::test::TestRequest::post()
+        .uri("/dumps/import")
+        .insert_header(("content-type", "application/gzip"))
+        .set_payload("doggo");
+    let (response, code) = server.service.request(req).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(response["code"], @r###""invalid_dump_archive""###);
+
+    // The archive can also be the file of a multipart form.
+    let form = [
+        b"--doggo\r\nContent-Disposition: form-data; name=\"namespace\"\r\n\r\nignored\r\n"
+            .as_slice(),
+        b"--doggo\r\nContent-Disposition: form-data; name=\"dump\"; filename=\"pets.dump\"\r\n",
+        b"Content-Type: application/gzip\r\n\r\n",
+        &archive,
+        b"\r\n--doggo--\r\n",
+    ]
+    .concat();
+    let req = actix_web::test::TestRequest::post()
+        .uri("/dumps/import?namespace=multipart")
+        .insert_header(("content-type", "multipart/form-data; boundary=doggo"))
+        .set_payload(form);
+    let (task, code) = server.service.request(req).await;
+    snapshot!(code, @"202 Accepted");
+    let task = server.wait_task(task.uid()).await;
+    snapshot!(task["status"], @r###""succeeded""###);
+    snapshot!(json_string!(task["details"]["importedIndexes"]), @r###"
+    [
+      "multipart-pets"
+    ]
+    "###);
+
+    let req = actix_web::test::TestRequest::post()
+        .uri("/dumps/import")
+        .insert_header(("content-type", "multipart/form-data; boundary=doggo"))
+        .set_payload("--doggo\r\nContent-Disposition: form-data; name=\"dump\"\r\n\r\nkefir\r\n--doggo--\r\n");
+    let (response, code) = server.service.request(req).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "The multipart payload provided is malformed: no file was sent in the form.",
+      "code": "malformed_payload",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#malformed_payload"
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn import_uploaded_dump_requires_writing_the_indexes() {
+    let mut server = Server::new_auth().await;
+    server.use_api_key("MASTER_KEY");
+    let pets = server.index("pets");
+    let (task, _code) = pets.add_documents(json!([{ "id": 0, "name": "kefir" }]), None).await;
+    pets.wait_task(task.uid()).await.succeeded();
+    let (task, _code) = server.create_dump().await;
+    let task = server.wait_task(task.uid()).await;
+    let dump_uid = task["details"]["dumpUid"].as_str().unwrap().to_string();
+    let dump_path = server.service.options.dump_dir.join(format!("{dump_uid}.dump"));
+    let archive = std::fs::read(dump_path).unwrap();
+
+    let keys = [
+        (json!(["dumps.create"]), json!(["*"]), "403 Forbidden"),
+        (
+            json!(["dumps.create", "indexes.create", "documents.add"]),
+            json!(["pets"]),
+            "403 Forbidden",
+        ),
+        (
+            json!(["dumps.create", "indexes.create", "documents.add"]),
+            json!(["uploaded-*"]),
+            "202 Accepted",
+        ),
+    ];
+    for (actions, indexes, expected) in keys {
+        server.use_api_key("MASTER_KEY");
+        let (key, code) = server
+            .add_api_key(json!({ "actions": actions, "indexes": indexes, "expiresAt": null }))
+            .await;
+        snapshot!(code, @"201 Created");
+        server.use_api_key(key["key"].as_str().unwrap());
+
+        let req = actix_web::test::TestRequest::post()
+            .uri("/dumps/import?namespace=uploaded")
+            .insert_header(("content-type", "application/gzip"))
+            .set_payload(archive.clone());
+        let (response, code) = server.service.request(req).await;
+        assert_eq!(code.to_string(), expected, "{actions:?} on {indexes:?}: {response:?}");
+    }
+}
+
+#[actix_rt::test]
+async fn import_dump_with_bad_payload() {
+    let server = Server::new().await;
+
+    let (response, code) = server
+        .service
+        .post_str("/dumps/import", "doggo", vec![("content-type", "text/plain")])
+        .await;
+    snapshot!(code, @"415 Unsupported Media Type");
+    snapshot!(response["code"], @r###""invalid_content_type""###);
+
+    let (response, code) = server.service.post("/dumps/import", json!({})).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "Missing field `url`",
+      "code": "missing_dump_import_url",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#missing_dump_import_url"
+    }
+    "###);
+
+    // No host is allowed by default.
+    let (response, code) =
+        server.service.post("/dumps/import", json!({ "url": "http://localhost/doggo.dump" })).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "The host of the URL `http://localhost/doggo.dump` is not allowed. The hosts dumps can be imported from are set with the `--experimental-dump-import-allowed-hosts` option.",
+      "code": "invalid_dump_import_url",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_dump_import_url"
+    }
+    "###);
+}
@@ -81,6 +81,29 @@ async fn experimental_features() {
     "###);
 }
 
+#[actix_rt::test]
+async fn metrics_report_the_index_sizes() {
+    let dir = TempDir::new().unwrap();
+    let enable_metrics = Opt { experimental_enable_metrics: true, ..default_settings(dir.path()) };
+    let server = Server::new_with_options(enable_metrics).await.unwrap();
+    // the metrics are shared by the whole process, the index name must be unique to this test
+    let index = server.index("metrics-index-sizes");
+    let (task, _code) = index.add_documents(json!([{ "id": 1, "title": "Carol" }]), None).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (_response, code) = server.get_metrics().await;
+    meili_snap::snapshot!(code, @"200 OK");
+
+    let size = meilisearch::metrics::MEILISEARCH_INDEX_SIZE_BYTES
+        .with_label_values(&["metrics-index-sizes"])
+        .get();
+    let used_size = meilisearch::metrics::MEILISEARCH_INDEX_USED_SIZE_BYTES
+        .with_label_values(&["metrics-index-sizes"])
+        .get();
+    assert!(used_size > 0, "{used_size}");
+    assert!(size >= used_size, "{size} < {used_size}");
+}
+
 #[actix_rt::test]
 async fn experimental_feature_metrics() {
     // instance flag for metrics enables metrics at startup
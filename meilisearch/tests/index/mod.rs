@@ -2,5 +2,7 @@ mod create_index;
 mod delete_index;
 mod errors;
 mod get_index;
+mod schema;
 mod stats;
 mod update_index;
+mod warmup;
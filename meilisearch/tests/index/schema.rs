@@ -0,0 +1,158 @@
+use meili_snap::{json_string, snapshot};
+
+use crate::common::Server;
+use crate::json;
+
+#[actix_rt::test]
+async fn schema_of_heterogeneous_documents() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (task, _code) = index
+        .update_settings(json!({
+            "searchableAttributes": ["title", "author.name"],
+            "filterableAttributes": ["year", "author"],
+            "sortableAttributes": ["year"],
+            "displayedAttributes": ["id", "title", "author"],
+        }))
+        .await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (task, _code) = index
+        .add_documents(
+            json!([
+                {
+                    "id": 1,
+                    "title": "Carol",
+                    "year": 2015,
+                    "tags": ["drama"],
+                    "author": { "name": "Patricia", "age": 40 },
+                },
+                {
+                    "id": "two",
+                    "title": "Wonder Woman",
+                    "year": "2017",
+                    "tags": null,
+                    "author": [{ "name": "William" }],
+                },
+            ]),
+            None,
+        )
+        .await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (response, code) = index.schema().await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response), @r###"
+    {
+      "fields": {
+        "author": {
+          "types": [
+            "array",
+            "object"
+          ],
+          "searchable": false,
+          "filterable": true,
+          "sortable": false,
+          "displayed": true,
+          "sample": {
+            "name": "Patricia",
+            "age": 40
+          }
+        },
+        "author.age": {
+          "types": [
+            "number"
+          ],
+          "searchable": false,
+          "filterable": true,
+          "sortable": false,
+          "displayed": true,
+          "sample": 40
+        },
+        "author.name": {
+          "types": [
+            "string"
+          ],
+          "searchable": true,
+          "filterable": true,
+          "sortable": false,
+          "displayed": true,
+          "sample": "Patricia"
+        },
+        "id": {
+          "types": [
+            "string",
+            "number"
+          ],
+          "searchable": false,
+          "filterable": false,
+          "sortable": false,
+          "displayed": true,
+          "sample": 1
+        },
+        "tags": {
+          "types": [
+            "array",
+            "null"
+          ],
+          "searchable": false,
+          "filterable": false,
+          "sortable": false,
+          "displayed": false,
+          "sample": [
+            "drama"
+          ]
+        },
+        "title": {
+          "types": [
+            "string"
+          ],
+          "searchable": true,
+          "filterable": false,
+          "sortable": false,
+          "displayed": true,
+          "sample": "Carol"
+        },
+        "year": {
+          "types": [
+            "string",
+            "number"
+          ],
+          "searchable": false,
+          "filterable": true,
+          "sortable": true,
+          "displayed": false,
+          "sample": 2015
+        }
+      }
+    }
+    "###);
+
+    // the types of the values of the deleted documents are forgotten
+    let (task, _code) = index.delete_document(1).await;
+    index.wait_task(task.uid()).await.succeeded();
+    let (response, _code) = index.schema().await;
+    snapshot!(json_string!(response["fields"]["year"]), @r###"
+    {
+      "types": [
+        "string"
+      ],
+      "searchable": false,
+      "filterable": true,
+      "sortable": true,
+      "displayed": false,
+      "sample": null
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn schema_of_unexisting_index() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (response, code) = index.schema().await;
+    snapshot!(code, @"404 Not Found");
+    snapshot!(response["code"], @r###""index_not_found""###);
+}
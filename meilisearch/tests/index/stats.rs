@@ -17,6 +17,8 @@ async fn stats() {
     assert_eq!(response["numberOfDocuments"], 0);
     assert!(response["isIndexing"] == false);
     assert!(response["fieldDistribution"].as_object().unwrap().is_empty());
+    assert_eq!(response["databasesSizes"]["documents"], 0);
+    assert_eq!(response["avgDocumentSize"], 0);
 
     let documents = json!([
         {
@@ -43,6 +45,10 @@ async fn stats() {
     assert_eq!(response["fieldDistribution"]["id"], 2);
     assert_eq!(response["fieldDistribution"]["name"], 1);
     assert_eq!(response["fieldDistribution"]["age"], 1);
+    assert!(response["databasesSizes"]["documents"].as_u64().unwrap() > 0);
+    assert!(response["databasesSizes"]["wordDocids"].as_u64().unwrap() > 0);
+    assert_eq!(response["databasesSizes"]["vectors"], 0);
+    assert!(response["avgDocumentSize"].as_u64().unwrap() > 0);
 }
 
 #[actix_rt::test]
@@ -72,6 +72,35 @@ async fn update_nothing() {
     assert_eq!(response["status"], "succeeded");
 }
 
+#[actix_rt::test]
+async fn updates_bump_the_updated_at_date() {
+    let server = Server::new().await;
+    let index = server.index("test");
+    let (task, _code) = index.create(None).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (response, _code) = index.get().await;
+    let created_at =
+        OffsetDateTime::parse(response["createdAt"].as_str().unwrap(), &Rfc3339).unwrap();
+    let initial_updated_at =
+        OffsetDateTime::parse(response["updatedAt"].as_str().unwrap(), &Rfc3339).unwrap();
+    assert_eq!(created_at, initial_updated_at);
+
+    let (task, _code) = index.add_documents(json!([{ "id": 1, "title": "Carol" }]), None).await;
+    index.wait_task(task.uid()).await.succeeded();
+    let (task, _code) = index.update_settings(json!({ "searchableAttributes": ["title"] })).await;
+    index.wait_task(task.uid()).await.succeeded();
+    let (task, _code) = index.delete_document(1).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (response, code) = index.get().await;
+    assert_eq!(code, 200);
+    assert_eq!(response["createdAt"].as_str().unwrap(), created_at.format(&Rfc3339).unwrap());
+    let updated_at =
+        OffsetDateTime::parse(response["updatedAt"].as_str().unwrap(), &Rfc3339).unwrap();
+    assert!(created_at < updated_at);
+}
+
 #[actix_rt::test]
 async fn error_update_existing_primary_key() {
     let server = Server::new().await;
@@ -0,0 +1,39 @@
+use crate::common::Server;
+use crate::json;
+
+#[actix_rt::test]
+async fn warmup_index() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (task, _code) = index
+        .add_documents(
+            json!([
+                { "id": 0, "title": "Captain Marvel", "genre": "action" },
+                { "id": 1, "title": "Escape Room", "genre": "thriller" },
+            ]),
+            None,
+        )
+        .await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (response, code) = index.warmup().await;
+    meili_snap::snapshot!(code, @"200 OK");
+    meili_snap::snapshot!(response["indexUid"], @r###""test""###);
+    assert!(response["bytesRead"].as_u64().unwrap() > 0, "{response}");
+
+    // the index keeps working once it is warm
+    let (response, code) = index.search_post(json!({ "q": "escape" })).await;
+    meili_snap::snapshot!(code, @"200 OK");
+    meili_snap::snapshot!(response["hits"][0]["id"], @"1");
+}
+
+#[actix_rt::test]
+async fn warmup_unexisting_index() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (response, code) = index.warmup().await;
+    meili_snap::snapshot!(code, @"404 Not Found");
+    meili_snap::snapshot!(response["code"], @r###""index_not_found""###);
+}
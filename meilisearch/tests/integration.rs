@@ -1,4 +1,5 @@
 mod auth;
+mod batches;
 mod common;
 mod dashboard;
 mod documents;
@@ -6,6 +7,7 @@ mod dumps;
 mod features;
 mod index;
 mod logs;
+mod maintenance;
 mod search;
 mod settings;
 mod similar;
@@ -0,0 +1,91 @@
+use crate::common::Server;
+use crate::json;
+
+#[actix_rt::test]
+async fn maintenance_blocks_writes_but_not_reads() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (task, code) =
+        index.add_documents(json!([{ "id": 1, "title": "Captain Marvel" }]), None).await;
+    meili_snap::snapshot!(code, @"202 Accepted");
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (response, code) = server.get_maintenance().await;
+    meili_snap::snapshot!(code, @"200 OK");
+    meili_snap::snapshot!(meili_snap::json_string!(response), @r###"
+    {
+      "enabled": false,
+      "pendingTasks": 0
+    }
+    "###);
+
+    let (response, code) = server.set_maintenance(json!({ "enabled": true })).await;
+    meili_snap::snapshot!(code, @"200 OK");
+    meili_snap::snapshot!(meili_snap::json_string!(response), @r###"
+    {
+      "enabled": true,
+      "pendingTasks": 0
+    }
+    "###);
+
+    // the write operations are refused...
+    let (response, code) = index.add_documents(json!([{ "id": 2, "title": "Shazam" }]), None).await;
+    meili_snap::snapshot!(code, @"503 Service Unavailable");
+    meili_snap::snapshot!(meili_snap::json_string!(response), @r###"
+    {
+      "message": "Meilisearch is in maintenance mode and cannot receive write operations. Please retry once the maintenance mode is disabled.",
+      "code": "maintenance_mode",
+      "type": "system",
+      "link": "https://docs.meilisearch.com/errors#maintenance_mode"
+    }
+    "###);
+
+    let (_response, code) =
+        index.update_settings(json!({ "searchableAttributes": ["title"] })).await;
+    meili_snap::snapshot!(code, @"503 Service Unavailable");
+
+    let (_response, code) = index.delete_document(1).await;
+    meili_snap::snapshot!(code, @"503 Service Unavailable");
+
+    // ...while the reads keep working
+    let (response, code) = index.search_post(json!({ "q": "marvel" })).await;
+    meili_snap::snapshot!(code, @"200 OK");
+    meili_snap::snapshot!(meili_snap::json_string!(response["hits"]), @r###"
+    [
+      {
+        "id": 1,
+        "title": "Captain Marvel"
+      }
+    ]
+    "###);
+
+    let (response, code) = index.get_all_documents_raw("").await;
+    meili_snap::snapshot!(code, @"200 OK");
+    meili_snap::snapshot!(response["total"], @"1");
+
+    // once the maintenance is over, the writes are accepted again
+    let (response, code) = server.set_maintenance(json!({ "enabled": false })).await;
+    meili_snap::snapshot!(code, @"200 OK");
+    meili_snap::snapshot!(response["enabled"], @"false");
+
+    let (task, code) = index.add_documents(json!([{ "id": 2, "title": "Shazam" }]), None).await;
+    meili_snap::snapshot!(code, @"202 Accepted");
+    index.wait_task(task.uid()).await.succeeded();
+}
+
+#[actix_rt::test]
+async fn error_maintenance_bad_enabled() {
+    let server = Server::new().await;
+
+    let (response, code) = server.set_maintenance(json!({ "enabled": "yes" })).await;
+    meili_snap::snapshot!(code, @"400 Bad Request");
+    meili_snap::snapshot!(meili_snap::json_string!(response), @r###"
+    {
+      "message": "Invalid value type at `.enabled`: expected a boolean, but found a string: `\"yes\"`",
+      "code": "invalid_maintenance_enabled",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_maintenance_enabled"
+    }
+    "###);
+}
@@ -0,0 +1,60 @@
+use meili_snap::snapshot;
+
+use crate::common::Server;
+use crate::json;
+
+#[actix_rt::test]
+async fn search_reflects_the_write_of_the_consistency_token() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (task, code) = index.add_documents(json!([{ "id": 1, "title": "Dune" }]), None).await;
+    snapshot!(code, @"202 Accepted");
+    let token = task.uid();
+
+    // the task isn't awaited by the test, the token makes the search route wait for it
+    let (response, code) = index.search_get(&format!("?q=dune&minConsistency={token}")).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(response["hits"], @r###"
+    [
+      {
+        "id": 1,
+        "title": "Dune"
+      }
+    ]
+    "###);
+
+    let (task, _code) =
+        index.add_documents(json!([{ "id": 2, "title": "Dune Messiah" }]), None).await;
+    let url = format!("/indexes/test/search?minConsistency={}", task.uid());
+    let (response, code) = index.service.post(url, json!({ "q": "dune" })).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(response["estimatedTotalHits"], @"2");
+}
+
+#[actix_rt::test]
+async fn search_with_the_consistency_token_of_a_deleted_task() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (task, _code) = index.add_documents(json!([{ "id": 1, "title": "Dune" }]), None).await;
+    index.wait_task(task.uid()).await.succeeded();
+    let token = task.uid();
+    let (task, _code) = server.delete_tasks(&format!("uids={token}")).await;
+    server.wait_task(task.uid()).await.succeeded();
+
+    // the write was applied before its task was deleted, the search doesn't wait for it
+    let (response, code) = index.search_get(&format!("?q=dune&minConsistency={token}")).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(response["estimatedTotalHits"], @"1");
+}
+
+#[actix_rt::test]
+async fn search_with_invalid_consistency_token() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (response, code) = index.search_get("?minConsistency=doggo").await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(response["code"], @r###""invalid_search_min_consistency""###);
+}
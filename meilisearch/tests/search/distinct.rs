@@ -304,3 +304,43 @@ async fn distinct_at_search_time() {
     snapshot!(response["totalPages"], @"1");
     snapshot!(response["totalHits"], @"3");
 }
+
+#[actix_rt::test]
+async fn distinct_at_search_time_on_a_non_filterable_attribute() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let documents = DOCUMENTS.clone();
+    let (task, _) = index.add_documents(documents, Some(DOCUMENT_PRIMARY_KEY)).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    fn get_hits(response: &Value) -> Vec<String> {
+        let hits_array = response["hits"]
+            .as_array()
+            .unwrap_or_else(|| panic!("{}", &serde_json::to_string_pretty(&response).unwrap()));
+        hits_array
+            .iter()
+            .map(|h| h[DOCUMENT_PRIMARY_KEY].as_number().unwrap().to_string())
+            .collect::<Vec<_>>()
+    }
+
+    let (response, code) = index
+        .search_post(json!({"page": 1, "hitsPerPage": 10, "distinct": DOCUMENT_DISTINCT_KEY}))
+        .await;
+    let hits = get_hits(&response);
+    snapshot!(code, @"200 OK");
+    snapshot!(format!("{:?}", hits), @r###"["1", "4", "6", "8", "9", "12"]"###);
+    snapshot!(response["totalHits"], @"6");
+
+    let index = server.index("nested");
+    let documents = NESTED_DOCUMENTS.clone();
+    let (task, _) = index.add_documents(documents, Some(DOCUMENT_PRIMARY_KEY)).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (response, code) =
+        index.search_post(json!({"page": 1, "hitsPerPage": 3, "distinct": "color.main"})).await;
+    let hits = get_hits(&response);
+    snapshot!(code, @"200 OK");
+    snapshot!(format!("{:?}", hits), @r###"["1", "2", "3"]"###);
+    snapshot!(response["totalHits"], @"3");
+}
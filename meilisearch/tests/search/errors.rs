@@ -1,6 +1,8 @@
 use meili_snap::*;
+use meilisearch::Opt;
+use tempfile::TempDir;
 
-use crate::common::{shared_does_not_exists_index, Server};
+use crate::common::{default_settings, shared_does_not_exists_index, Server};
 use crate::json;
 
 #[actix_rt::test]
@@ -595,6 +597,79 @@ async fn search_bad_crop_marker() {
     // Can't make the `crop_marker` fail with a get search since it'll accept anything as a strings.
 }
 
+#[actix_rt::test]
+async fn search_bad_ranking_rules() {
+    let server = Server::new_shared();
+    let index = server.unique_index();
+
+    let (response, code) = index.search_post(json!({"rankingRules": "words"})).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "Invalid value type at `.rankingRules`: expected an array, but found a string: `\"words\"`",
+      "code": "invalid_search_ranking_rules",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_search_ranking_rules"
+    }
+    "###);
+
+    let (response, code) = index.search_post(json!({"rankingRules": ["words", "doggo"]})).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "Invalid value at `.rankingRules[1]`: `doggo` ranking rule is invalid. Valid ranking rules are words, typo, sort, proximity, attribute, exactness and custom ranking rules.",
+      "code": "invalid_search_ranking_rules",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_search_ranking_rules"
+    }
+    "###);
+    // Can't make the `rankingRules` fail with a wrong type with a get search since it'll accept anything as a string.
+    let (response, code) = index.search_get("rankingRules=words,doggo").await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "Invalid value in parameter `rankingRules`: `doggo` ranking rule is invalid. Valid ranking rules are words, typo, sort, proximity, attribute, exactness and custom ranking rules.",
+      "code": "invalid_search_ranking_rules",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_search_ranking_rules"
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn search_bad_boosting_filter() {
+    let server = Server::new_shared();
+    let index = server.unique_index();
+    let (task, _code) = index.update_settings(json!({"filterableAttributes": ["title"]})).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (response, code) = index.search_post(json!({ "boostingFilter": true })).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "Invalid syntax for the filter parameter: `expected String, Array, found: true`.",
+      "code": "invalid_search_boosting_filter",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_search_boosting_filter"
+    }
+    "###);
+
+    let (response, code) = index.search_post(json!({ "boostingFilter": "many = Glass" })).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "Attribute `many` is not filterable. Available filterable attributes are: `title`.\n1:5 many = Glass",
+      "code": "invalid_search_boosting_filter",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_search_boosting_filter"
+    }
+    "###);
+
+    let (response, code) = index.search_get("boostingFilter=many%20%3D%20Glass").await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(response["code"], @r###""invalid_search_boosting_filter""###);
+}
+
 #[actix_rt::test]
 async fn search_bad_matching_strategy() {
     let server = Server::new_shared();
@@ -1211,3 +1286,43 @@ async fn search_with_contains_without_enabling_the_feature() {
     }
     "###);
 }
+
+#[actix_rt::test]
+async fn search_with_too_large_query() {
+    let temp = TempDir::new().unwrap();
+    let server = Server::new_with_options(Opt {
+        max_query_length: 20,
+        max_query_terms: 3,
+        ..default_settings(temp.path())
+    })
+    .await
+    .unwrap();
+    let index = server.index("test");
+
+    let (response, code) = index.search_post(json!({ "q": "a query that is way too long" })).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "The query is 28 bytes long, which exceeds the maximum query length of 20 bytes.",
+      "code": "invalid_search_q",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_search_q"
+    }
+    "###);
+
+    let (response, code) = index.search_get("?q=a%20b%20c%20d").await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(response["message"], @r###""The query contains 4 terms, which exceeds the maximum of 3 terms per query.""###);
+
+    let (response, code) =
+        server.multi_search(json!({ "queries": [{ "indexUid": "test", "q": "a b c d" }] })).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(response["message"], @r###""Inside `.queries[0]`: The query contains 4 terms, which exceeds the maximum of 3 terms per query.""###);
+
+    // The queries within the limits are searched as usual.
+    let (task, _code) = index.add_documents(json!([{ "id": 0, "title": "a b c" }]), None).await;
+    index.wait_task(task.uid()).await.succeeded();
+    let (response, code) = index.search_post(json!({ "q": "a b c" })).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(response["hits"].as_array().unwrap().len(), @"1");
+}
@@ -0,0 +1,132 @@
+use std::collections::BTreeMap;
+
+use meili_snap::{json_string, snapshot};
+
+use crate::common::Server;
+use crate::json;
+
+fn parse_distribution(body: &str) -> BTreeMap<(String, String), u64> {
+    body.lines()
+        .map(|line| {
+            let line: serde_json::Value = serde_json::from_str(line).unwrap();
+            let facet = line["facet"].as_str().unwrap().to_string();
+            let value = line["value"].as_str().unwrap().to_string();
+            ((facet, value), line["count"].as_u64().unwrap())
+        })
+        .collect()
+}
+
+#[actix_rt::test]
+async fn export_the_complete_facet_distribution() {
+    let server = Server::new().await;
+    let index = server.index("test");
+    let (task, _code) = index.update_settings_filterable_attributes(json!(["tag", "meta"])).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let documents: Vec<_> = (0..5000)
+        .map(|id| {
+            let color = ["red", "green", "blue"][id % 3];
+            serde_json::json!({ "id": id, "tag": format!("tag-{}", id % 1500), "meta": { "color": color, "size": id % 7 } })
+        })
+        .collect();
+    let (task, _code) = index.add_documents(serde_json::Value::Array(documents).into(), None).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    // far more values than the `maxValuesPerFacet` of the search
+    let (body, code) = index.export_facet_distribution(json!({ "facets": ["tag"] })).await;
+    snapshot!(code, @"200 OK");
+    let mut expected = BTreeMap::new();
+    for id in 0..5000 {
+        *expected.entry(("tag".to_string(), format!("tag-{}", id % 1500))).or_insert(0) += 1;
+    }
+    assert_eq!(parse_distribution(&body), expected);
+
+    // the nested fields of the documents matching the filter
+    let (body, code) = index
+        .export_facet_distribution(json!({ "facets": ["meta"], "filter": "meta.color = red" }))
+        .await;
+    snapshot!(code, @"200 OK");
+    let mut expected = BTreeMap::new();
+    for id in (0..5000).filter(|id| id % 3 == 0) {
+        *expected.entry(("meta.color".to_string(), "red".to_string())).or_insert(0) += 1;
+        *expected.entry(("meta.size".to_string(), (id % 7).to_string())).or_insert(0) += 1;
+    }
+    assert_eq!(parse_distribution(&body), expected);
+}
+
+#[actix_rt::test]
+async fn export_the_distribution_of_a_non_filterable_facet() {
+    let server = Server::new().await;
+    let index = server.index("test");
+    let (task, _code) = index.update_settings_filterable_attributes(json!(["tag"])).await;
+    index.wait_task(task.uid()).await.succeeded();
+    let (task, _code) =
+        index.add_documents(json!([{ "id": 0, "tag": "a", "color": "red" }]), None).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (body, code) = index.export_facet_distribution(json!({ "facets": ["color"] })).await;
+    snapshot!(code, @"400 Bad Request");
+    let response: serde_json::Value = serde_json::from_str(&body).unwrap();
+    snapshot!(response["code"], @r###""invalid_search_facets""###);
+    snapshot!(json_string!(response["message"]), @r###""Invalid facet distribution, attribute `color` is not filterable. The available filterable attribute is `tag`.""###);
+}
+
+#[actix_rt::test]
+async fn facet_cache_key_depends_on_the_candidates_context() {
+    let server = Server::new().await;
+    let index = server.index("test");
+    let (task, _code) = index.update_settings_filterable_attributes(json!(["color"])).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let documents = json!([
+        { "id": 1, "title": "Red shirt", "color": "red" },
+        { "id": 2, "title": "Blue shirt", "color": "blue" },
+        { "id": 3, "title": "Red shoes", "color": "red" },
+    ]);
+    let (task, _code) = index.add_documents(documents, None).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (response, code) = index
+        .search_post(json!({ "q": "shirt", "filter": "color = red", "facets": ["color"] }))
+        .await;
+    snapshot!(code, @"200 OK");
+    let key = response["facetCacheKey"].as_str().unwrap().to_string();
+
+    // only the page changed
+    let (response, code) = index
+        .search_post(
+            json!({ "q": "shirt", "filter": "color = red", "facets": ["color"], "offset": 1 }),
+        )
+        .await;
+    snapshot!(code, @"200 OK");
+    assert_eq!(response["facetCacheKey"].as_str(), Some(key.as_str()));
+
+    // the text of the query changes the candidates, and therefore the facets
+    let (response, code) = index
+        .search_post(json!({ "q": "shoes", "filter": "color = red", "facets": ["color"] }))
+        .await;
+    snapshot!(code, @"200 OK");
+    assert_ne!(response["facetCacheKey"].as_str(), Some(key.as_str()));
+
+    let (response, code) = index
+        .search_post(json!({
+            "q": "shirt",
+            "filter": "color = red",
+            "facets": ["color"],
+            "matchingStrategy": "all",
+        }))
+        .await;
+    snapshot!(code, @"200 OK");
+    assert_ne!(response["facetCacheKey"].as_str(), Some(key.as_str()));
+
+    let (response, code) = index
+        .search_post(json!({ "q": "shirt", "filter": "color = blue", "facets": ["color"] }))
+        .await;
+    snapshot!(code, @"200 OK");
+    assert_ne!(response["facetCacheKey"].as_str(), Some(key.as_str()));
+
+    // the key is only returned along with the facets
+    let (response, code) = index.search_post(json!({ "q": "shoes" })).await;
+    snapshot!(code, @"200 OK");
+    assert!(response["facetCacheKey"].is_null());
+}
@@ -1,4 +1,4 @@
-use meili_snap::snapshot;
+use meili_snap::{json_string, snapshot};
 use once_cell::sync::Lazy;
 
 use crate::common::{Server, Value};
@@ -57,6 +57,48 @@ async fn simple_facet_search() {
     assert_eq!(response["facetHits"].as_array().unwrap().len(), 1);
 }
 
+#[actix_rt::test]
+async fn typo_tolerant_prefix_facet_search_with_filter() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let documents = DOCUMENTS.clone();
+    index.update_settings_filterable_attributes(json!(["genres"])).await;
+    index.add_documents(documents, None).await;
+    index.wait_task(1).await;
+
+    // `thrile` is a prefix of `thriller` with a typo
+    let (response, code) =
+        index.facet_search(json!({"facetName": "genres", "facetQuery": "thrile"})).await;
+
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response["facetHits"]), @r###"
+    [
+      {
+        "value": "Thriller",
+        "count": 2
+      }
+    ]
+    "###);
+
+    // the counts only consider the documents matching the filter
+    let (response, code) = index
+        .facet_search(
+            json!({"facetName": "genres", "facetQuery": "thrile", "filter": "genres = Horror"}),
+        )
+        .await;
+
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response["facetHits"]), @r###"
+    [
+      {
+        "value": "Thriller",
+        "count": 1
+      }
+    ]
+    "###);
+}
+
 #[actix_rt::test]
 async fn advanced_facet_search() {
     let server = Server::new().await;
@@ -200,3 +242,78 @@ async fn simple_facet_search_with_sort_by_count() {
     assert_eq!(hits[0], json!({ "value": "Action", "count": 3 }));
     assert_eq!(hits[1], json!({ "value": "Adventure", "count": 2 }));
 }
+
+#[actix_rt::test]
+async fn fuzzy_facet_search() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let documents = json!([
+        { "id": 1, "brand": "Adidas" },
+        { "id": 2, "brand": "Adidos" },
+        { "id": 3, "brand": "Adidos" },
+        { "id": 4, "brand": "Adidos" },
+        { "id": 5, "brand": "Nike" },
+    ]);
+    index.update_settings_faceting(json!({ "sortFacetValuesBy": { "*": "count" } })).await;
+    index.update_settings_filterable_attributes(json!(["brand"])).await;
+    index.update_settings_typo_tolerance(json!({ "enabled": false })).await;
+    index.add_documents(documents, None).await;
+    index.wait_task(3).await;
+
+    // the typo tolerance is disabled on the index but requested for this search
+    let (response, code) = index
+        .facet_search(json!({"facetName": "brand", "facetQuery": "adiddas", "fuzzy": true}))
+        .await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response["facetHits"]), @r###"
+    [
+      {
+        "value": "Adidas",
+        "count": 1
+      }
+    ]
+    "###);
+
+    let (response, code) =
+        index.facet_search(json!({"facetName": "brand", "facetQuery": "adiddas"})).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response["facetHits"]), @"[]");
+
+    // the exact match is ranked before the more frequent fuzzy one
+    index.update_settings_typo_tolerance(json!({ "enabled": true })).await;
+    index.wait_task(4).await;
+
+    let (response, code) = index
+        .facet_search(json!({"facetName": "brand", "facetQuery": "adidas", "fuzzy": true}))
+        .await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response["facetHits"]), @r###"
+    [
+      {
+        "value": "Adidas",
+        "count": 1
+      },
+      {
+        "value": "Adidos",
+        "count": 3
+      }
+    ]
+    "###);
+
+    let (response, code) =
+        index.facet_search(json!({"facetName": "brand", "facetQuery": "adidas"})).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response["facetHits"]), @r###"
+    [
+      {
+        "value": "Adidos",
+        "count": 3
+      },
+      {
+        "value": "Adidas",
+        "count": 1
+      }
+    ]
+    "###);
+}
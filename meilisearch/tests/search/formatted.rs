@@ -1,4 +1,5 @@
 use insta::{allow_duplicates, assert_json_snapshot};
+use meili_snap::{json_string, snapshot};
 
 use super::*;
 use crate::common::Server;
@@ -568,3 +569,390 @@ async fn test_cjk_highlight() {
         })
         .await;
 }
+
+#[actix_rt::test]
+async fn custom_highlight_tags_and_crop_marker() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let documents = json!([
+        { "id": 0, "title": "the quick brown fox jumps over the lazy dog" },
+    ]);
+    index.add_documents(documents, None).await;
+    index.wait_task(0).await;
+
+    index
+        .search(
+            json!({
+                "q": "fox",
+                "attributesToHighlight": ["title"],
+                "highlightPreTag": "<mark>",
+                "highlightPostTag": "</mark>",
+            }),
+            |response, code| {
+                assert_eq!(code, 200, "{}", response);
+                assert_eq!(
+                    response["hits"][0]["_formatted"]["title"],
+                    json!("the quick brown <mark>fox</mark> jumps over the lazy dog")
+                );
+            },
+        )
+        .await;
+
+    index
+        .search(
+            json!({
+                "q": "fox",
+                "attributesToHighlight": ["title"],
+                "attributesToCrop": ["title"],
+                "cropLength": 2,
+                "highlightPreTag": "**",
+                "highlightPostTag": "**",
+                "cropMarker": "[…]",
+            }),
+            |response, code| {
+                assert_eq!(code, 200, "{}", response);
+                assert_eq!(
+                    response["hits"][0]["_formatted"]["title"],
+                    json!("[…]brown **fox**[…]")
+                );
+            },
+        )
+        .await;
+
+    // empty tags and markers are allowed and don't output anything.
+    index
+        .search(
+            json!({
+                "q": "fox",
+                "attributesToHighlight": ["title"],
+                "attributesToCrop": ["title"],
+                "cropLength": 2,
+                "highlightPreTag": "",
+                "highlightPostTag": "",
+                "cropMarker": "",
+            }),
+            |response, code| {
+                assert_eq!(code, 200, "{}", response);
+                assert_eq!(response["hits"][0]["_formatted"]["title"], json!("brown fox"));
+            },
+        )
+        .await;
+}
+
+#[actix_rt::test]
+async fn highlight_tags_and_crop_marker_per_attribute() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let documents = json!([
+        {
+            "id": 0,
+            "title": "the quick brown fox",
+            "description": "the quick brown fox jumps over the lazy dog",
+        },
+    ]);
+    index.add_documents(documents, None).await;
+    index.wait_task(0).await;
+
+    index
+        .search(
+            json!({
+                "q": "fox",
+                "attributesToHighlight": ["title", "description"],
+                "attributesToCrop": ["description"],
+                "cropLength": 2,
+                "attributesFormatting": {
+                    "title": { "highlightPreTag": "<b>", "highlightPostTag": "</b>" },
+                    "description": { "cropMarker": "[…]" },
+                },
+            }),
+            |response, code| {
+                assert_eq!(code, 200, "{}", response);
+                assert_eq!(
+                    response["hits"][0]["_formatted"]["title"],
+                    json!("the quick brown <b>fox</b>")
+                );
+                // the tags that aren't overridden are the ones of the search
+                assert_eq!(
+                    response["hits"][0]["_formatted"]["description"],
+                    json!("[…]brown <em>fox</em>[…]")
+                );
+            },
+        )
+        .await;
+}
+
+#[actix_rt::test]
+async fn crop_around_the_best_matching_window() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let documents = json!([
+        { "id": 0, "title": "unicorn one two three four five six seven eight nine ten eleven twelve rainbow unicorn thirteen" },
+        { "id": 1, "title": "one two three four five six seven eight nine ten eleven twelve thirteen fourteen fifteen unicorn" },
+    ]);
+    index.add_documents(documents, None).await;
+    index.wait_task(0).await;
+
+    // the crop window is centered on the densest group of matches and not on the first match.
+    index
+        .search(
+            json!({
+                "q": "rainbow unicorn",
+                "attributesToCrop": ["title"],
+                "cropLength": 4,
+            }),
+            |response, code| {
+                assert_eq!(code, 200, "{}", response);
+                assert_eq!(
+                    response["hits"][0]["_formatted"]["title"],
+                    json!("…twelve rainbow unicorn thirteen")
+                );
+            },
+        )
+        .await;
+
+    // a match at the end of the field still produces a full-length window.
+    index
+        .search(
+            json!({
+                "q": "fifteen unicorn",
+                "attributesToCrop": ["title"],
+                "cropLength": 4,
+            }),
+            |response, code| {
+                assert_eq!(code, 200, "{}", response);
+                assert_eq!(
+                    response["hits"][0]["_formatted"]["title"],
+                    json!("…thirteen fourteen fifteen unicorn")
+                );
+            },
+        )
+        .await;
+}
+
+#[actix_rt::test]
+async fn matches_info_per_attribute() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let documents = json!([
+        {
+            "id": 1,
+            "title": "The Batman returns",
+            "overview": "Batman fights the Joker in Gotham. Batman wins.",
+            "genre": "action",
+        },
+    ]);
+    index.add_documents(documents, None).await;
+    index.wait_task(0).await;
+
+    index
+        .search(json!({ "q": "batman joker", "showMatchesInfo": true }), |response, code| {
+            assert_eq!(code, 200, "{}", response);
+            allow_duplicates! {
+                assert_json_snapshot!(response["hits"][0], @r###"
+                {
+                  "id": 1,
+                  "title": "The Batman returns",
+                  "overview": "Batman fights the Joker in Gotham. Batman wins.",
+                  "genre": "action",
+                  "_matchesInfo": {
+                    "overview": 3,
+                    "title": 1
+                  }
+                }
+                "###)
+            }
+        })
+        .await;
+
+    // the matches info is omitted by default
+    index
+        .search(json!({ "q": "batman joker" }), |response, code| {
+            assert_eq!(code, 200, "{}", response);
+            assert!(response["hits"][0].get("_matchesInfo").is_none());
+        })
+        .await;
+}
+
+#[actix_rt::test]
+async fn query_coverage_of_the_hits() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let documents = json!([
+        { "id": 1, "title": "The Batman returns", "overview": "Batman fights the Joker." },
+    ]);
+    index.add_documents(documents, None).await;
+    index.wait_task(0).await;
+
+    index
+        .search(
+            json!({ "q": "batman joker robin", "showQueryCoverage": true }),
+            |response, code| {
+                assert_eq!(code, 200, "{}", response);
+                let coverage = response["hits"][0]["_queryCoverage"].as_f64().unwrap();
+                assert!((coverage - 2.0 / 3.0).abs() < 0.01, "{}", coverage);
+            },
+        )
+        .await;
+
+    // a phrase covers all its words
+    index
+        .search(
+            json!({ "q": "\"fights the joker\"", "showQueryCoverage": true }),
+            |response, code| {
+                assert_eq!(code, 200, "{}", response);
+                assert_eq!(response["hits"][0]["_queryCoverage"].as_f64(), Some(1.0));
+            },
+        )
+        .await;
+
+    // the query coverage is omitted by default
+    index
+        .search(json!({ "q": "batman joker robin" }), |response, code| {
+            assert_eq!(code, 200, "{}", response);
+            assert!(response["hits"][0].get("_queryCoverage").is_none());
+        })
+        .await;
+}
+
+#[actix_rt::test]
+async fn prefix_highlight() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let documents = json!([
+        { "id": 1, "title": "Red shoes and shorts" },
+    ]);
+    index.add_documents(documents, None).await;
+    index.wait_task(0).await;
+
+    // by default only the matching prefix is highlighted
+    index
+        .search(json!({ "q": "red sho", "attributesToHighlight": ["title"] }), |response, code| {
+            assert_eq!(code, 200, "{}", response);
+            snapshot!(response["hits"][0]["_formatted"]["title"], @r###""<em>Red</em> <em>sho</em>es and <em>sho</em>rts""###);
+        })
+        .await;
+
+    index
+        .search(
+            json!({ "q": "red sho", "attributesToHighlight": ["title"], "prefixHighlight": "word" }),
+            |response, code| {
+                assert_eq!(code, 200, "{}", response);
+                snapshot!(response["hits"][0]["_formatted"]["title"], @r###""<em>Red</em> <em>shoes</em> and <em>shorts</em>""###);
+            },
+        )
+        .await;
+
+    // the prefix matches are still part of the matches position
+    index
+        .search(
+            json!({ "q": "red sho", "attributesToHighlight": ["title"], "prefixHighlight": "none", "showMatchesPosition": true }),
+            |response, code| {
+                assert_eq!(code, 200, "{}", response);
+                snapshot!(response["hits"][0]["_formatted"]["title"], @r###""<em>Red</em> shoes and shorts""###);
+                assert_eq!(response["hits"][0]["_matchesPosition"]["title"].as_array().unwrap().len(), 3);
+            },
+        )
+        .await;
+
+    let (response, code) =
+        index.search_post(json!({ "q": "red sho", "prefixHighlight": "fragment" })).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(response["code"], @r###""invalid_search_prefix_highlight""###);
+}
+
+#[actix_rt::test]
+async fn disabled_formatting_skips_the_formatting_stage() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let documents = json!([
+        { "id": 1, "title": "Captain Marvel" },
+        { "id": 2, "title": "Shazam! Captain Marvel" },
+    ]);
+    let (task, _code) = index.add_documents(documents, None).await;
+    index.wait_task(task.uid()).await.succeeded();
+    let (task, code) = index.update_settings(json!({ "enableFormatting": false })).await;
+    snapshot!(code, @"202 Accepted");
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (response, code) = index.settings().await;
+    snapshot!(code, @"200 OK");
+    snapshot!(response["enableFormatting"], @"false");
+
+    let (response, code) = index
+        .search_post(json!({
+            "q": "marvel",
+            "attributesToHighlight": ["*"],
+            "attributesToCrop": ["title"],
+            "showMatchesPosition": true,
+        }))
+        .await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response["hits"]), @r###"
+    [
+      {
+        "id": 1,
+        "title": "Captain Marvel"
+      },
+      {
+        "id": 2,
+        "title": "Shazam! Captain Marvel"
+      }
+    ]
+    "###);
+
+    // the query coverage is still computed without formatting the hits
+    let (response, code) =
+        index.search_post(json!({ "q": "marvel doggo", "showQueryCoverage": true })).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response["hits"]), @r###"
+    [
+      {
+        "id": 1,
+        "title": "Captain Marvel",
+        "_queryCoverage": 0.5
+      },
+      {
+        "id": 2,
+        "title": "Shazam! Captain Marvel",
+        "_queryCoverage": 0.5
+      }
+    ]
+    "###);
+
+    // a search can still opt in to the formatting
+    let (response, code) = index
+        .search_post(json!({
+            "q": "marvel",
+            "attributesToHighlight": ["title"],
+            "enableFormatting": true,
+        }))
+        .await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response["hits"]), @r###"
+    [
+      {
+        "id": 1,
+        "title": "Captain Marvel",
+        "_formatted": {
+          "id": "1",
+          "title": "Captain <em>Marvel</em>"
+        }
+      },
+      {
+        "id": 2,
+        "title": "Shazam! Captain Marvel",
+        "_formatted": {
+          "id": "2",
+          "title": "Shazam! Captain <em>Marvel</em>"
+        }
+      }
+    ]
+    "###);
+}
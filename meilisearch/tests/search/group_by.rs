@@ -0,0 +1,82 @@
+use meili_snap::snapshot;
+
+use crate::common::{Server, Value};
+use crate::json;
+
+fn group_ids(response: &Value) -> Vec<(serde_json::Value, Vec<u64>)> {
+    response["groups"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|group| {
+            let ids = group["hits"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|hit| hit["id"].as_u64().unwrap())
+                .collect();
+            (group["value"].clone(), ids)
+        })
+        .collect()
+}
+
+#[actix_rt::test]
+async fn search_grouped_by_facet_value() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (task, _code) =
+        index.update_settings(json!({ "filterableAttributes": ["category"] })).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let documents = json!([
+        { "id": 1, "title": "Dune", "category": "book" },
+        { "id": 2, "title": "Thriller", "category": "music" },
+        { "id": 3, "title": "Foundation", "category": "book" },
+        { "id": 4, "title": "Gift card" },
+        { "id": 5, "title": "Hyperion", "category": "book" },
+        { "id": 6, "title": "Abbey Road", "category": "music" },
+    ]);
+    let (task, _code) = index.add_documents(documents, Some("id")).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (response, code) =
+        index.search_post(json!({ "groupBy": "category", "groupLimit": 2 })).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(response["hits"].as_array().unwrap().len(), @"6");
+    assert_eq!(
+        group_ids(&response),
+        vec![
+            (serde_json::json!("book"), vec![1, 3]),
+            (serde_json::json!("music"), vec![2, 6]),
+            (serde_json::Value::Null, vec![4]),
+        ]
+    );
+
+    // the default limit keeps the three best ranked documents of each group
+    let (response, code) = index.search_post(json!({ "groupBy": "category" })).await;
+    snapshot!(code, @"200 OK");
+    assert_eq!(group_ids(&response)[0], (serde_json::json!("book"), vec![1, 3, 5]));
+
+    // the groups are made from every reachable hit, not only the requested page
+    let (response, code) =
+        index.search_post(json!({ "groupBy": "category", "groupLimit": 2, "limit": 1 })).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(response["hits"].as_array().unwrap().len(), @"1");
+    assert_eq!(
+        group_ids(&response),
+        vec![
+            (serde_json::json!("book"), vec![1, 3]),
+            (serde_json::json!("music"), vec![2, 6]),
+            (serde_json::Value::Null, vec![4]),
+        ]
+    );
+
+    let (response, code) = index.search_post(json!({ "q": "dune" })).await;
+    snapshot!(code, @"200 OK");
+    assert!(response.get("groups").is_none());
+
+    let (response, code) = index.search_post(json!({ "groupBy": "title" })).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(response["code"], @r###""invalid_search_group_by""###);
+}
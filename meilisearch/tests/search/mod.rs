@@ -1,18 +1,28 @@
 // This modules contains all the test concerning search. Each particular feature of the search
 // should be tested in its own module to isolate tests and keep the tests readable.
 
+mod consistency;
 mod distinct;
 mod errors;
+mod facet_distribution;
 mod facet_search;
 mod formatted;
 mod geo;
+mod group_by;
 mod hybrid;
 mod locales;
 mod matching_strategy;
 mod multi;
 mod pagination;
+mod pinned_results;
+mod reranker;
 mod restrict_searchable;
+mod search_log;
 mod search_queue;
+mod stemming;
+mod stream;
+mod suggest;
+mod vocabulary;
 
 use meilisearch::Opt;
 use tempfile::TempDir;
@@ -61,6 +71,319 @@ async fn simple_search() {
         .await;
 }
 
+#[actix_rt::test]
+async fn search_with_suggestion() {
+    let index = shared_index_with_documents().await;
+    index
+        .search(json!({"q": "Captan Marvel", "showSuggestion": true}), |response, code| {
+            assert_eq!(code, 200, "{}", response);
+            meili_snap::snapshot!(response["suggestion"], @r###""Captain Marvel""###);
+        })
+        .await;
+
+    // the suggestion is only returned when asked for
+    index
+        .search(json!({"q": "Captan Marvel"}), |response, code| {
+            assert_eq!(code, 200, "{}", response);
+            meili_snap::snapshot!(response["suggestion"], @"null");
+        })
+        .await;
+
+    // there is no suggestion when the query is correctly spelled
+    index
+        .search(json!({"q": "Captain Marvel", "showSuggestion": true}), |response, code| {
+            assert_eq!(code, 200, "{}", response);
+            meili_snap::snapshot!(response["suggestion"], @"null");
+        })
+        .await;
+
+    // nor when the query returns more hits than the threshold
+    index
+        .search(
+            json!({"q": "Captan Marvel", "showSuggestion": true, "suggestionMaxHits": 0}),
+            |response, code| {
+                assert_eq!(code, 200, "{}", response);
+                assert!(!response["hits"].as_array().unwrap().is_empty(), "{}", response);
+                meili_snap::snapshot!(response["suggestion"], @"null");
+            },
+        )
+        .await;
+
+    // even when the total number of hits is not computed
+    index
+        .search(
+            json!({
+                "q": "Captan Marvel",
+                "showSuggestion": true,
+                "suggestionMaxHits": 0,
+                "computeTotalHits": false,
+            }),
+            |response, code| {
+                assert_eq!(code, 200, "{}", response);
+                assert!(!response["hits"].as_array().unwrap().is_empty(), "{}", response);
+                meili_snap::snapshot!(response["suggestion"], @"null");
+            },
+        )
+        .await;
+}
+
+#[actix_rt::test]
+async fn search_with_query_analysis() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (task, _code) = index
+        .update_settings(json!({"stopWords": ["the"], "synonyms": {"cafe": ["coffee"]}}))
+        .await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    // the stop word is removed, the accent is normalized and the synonym is expanded
+    index
+        .search(json!({"q": "The Café shop", "showQueryAnalysis": true}), |response, code| {
+            assert_eq!(code, 200, "{}", response);
+            meili_snap::snapshot!(meili_snap::json_string!(response["queryAnalysis"]), @r###"
+            {
+              "terms": [
+                {
+                  "term": "cafe",
+                  "isPhrase": false,
+                  "isPrefix": false,
+                  "synonyms": [
+                    "coffee"
+                  ]
+                },
+                {
+                  "term": "shop",
+                  "isPhrase": false,
+                  "isPrefix": true,
+                  "synonyms": []
+                }
+              ],
+              "negativeTerms": []
+            }
+            "###);
+        })
+        .await;
+
+    // the analysis is only returned when asked for
+    index
+        .search(json!({"q": "The Café shop"}), |response, code| {
+            assert_eq!(code, 200, "{}", response);
+            meili_snap::snapshot!(response["queryAnalysis"], @"null");
+        })
+        .await;
+}
+
+#[actix_rt::test]
+async fn search_without_cross_attribute_matching() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let documents = json!([
+        { "id": 1, "title": "Harry Potter", "body": "and the philosopher's stone" },
+        { "id": 2, "title": "The philosopher's stone", "body": "a story about Harry" },
+        { "id": 3, "title": "Harry Potter and the philosopher's stone", "body": "a novel" },
+    ]);
+    let (task, _code) = index.add_documents(documents, None).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    // the terms can be spread across the title and the body by default
+    index
+        .search(json!({"q": "harry stone", "attributesToRetrieve": ["id"]}), |response, code| {
+            assert_eq!(code, 200, "{}", response);
+            assert_eq!(response["hits"].as_array().unwrap().len(), 3, "{}", response);
+        })
+        .await;
+
+    // only the document containing both terms in its title matches
+    index
+        .search(
+            json!({"q": "harry stone", "crossAttributeMatching": false, "attributesToRetrieve": ["id"]}),
+            |response, code| {
+                assert_eq!(code, 200, "{}", response);
+                meili_snap::snapshot!(meili_snap::json_string!(response["hits"]), @r###"
+                [
+                  {
+                    "id": 3
+                  }
+                ]
+                "###);
+            },
+        )
+        .await;
+}
+
+#[actix_rt::test]
+async fn search_with_ranking_rules() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (task, _code) = index.update_settings(json!({"sortableAttributes": ["price"]})).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let documents = json!([
+        { "id": 1, "title": "red shirt", "price": 30 },
+        { "id": 2, "title": "red shirt", "price": 10 },
+        { "id": 3, "title": "red shirt", "price": 20 },
+    ]);
+    let (task, _code) = index.add_documents(documents, None).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    index
+        .search(
+            json!({"q": "shirt", "rankingRules": ["desc(price)", "words"], "attributesToRetrieve": ["id"]}),
+            |response, code| {
+                assert_eq!(code, 200, "{}", response);
+                meili_snap::snapshot!(meili_snap::json_string!(response["hits"]), @r###"
+                [
+                  {
+                    "id": 1
+                  },
+                  {
+                    "id": 3
+                  },
+                  {
+                    "id": 2
+                  }
+                ]
+                "###);
+            },
+        )
+        .await;
+
+    index
+        .search(
+            json!({"q": "shirt", "rankingRules": ["asc(price)", "words"], "attributesToRetrieve": ["id"]}),
+            |response, code| {
+                assert_eq!(code, 200, "{}", response);
+                meili_snap::snapshot!(meili_snap::json_string!(response["hits"]), @r###"
+                [
+                  {
+                    "id": 2
+                  },
+                  {
+                    "id": 3
+                  },
+                  {
+                    "id": 1
+                  }
+                ]
+                "###);
+            },
+        )
+        .await;
+
+    // the documents can't be sorted on an attribute that is not sortable
+    index
+        .search(json!({"q": "shirt", "rankingRules": ["asc(title)", "words"]}), |response, code| {
+            meili_snap::snapshot!(code, @"400 Bad Request");
+            meili_snap::snapshot!(meili_snap::json_string!(response), @r###"
+            {
+              "message": "Attribute `title` is not sortable. Available sortable attributes are: `price`.",
+              "code": "invalid_search_sort",
+              "type": "invalid_request",
+              "link": "https://docs.meilisearch.com/errors#invalid_search_sort"
+            }
+            "###);
+        })
+        .await;
+}
+
+#[actix_rt::test]
+async fn search_with_boosting_filter() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (task, _code) = index.update_settings(json!({"filterableAttributes": ["color"]})).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let documents = json!([
+        { "id": 1, "title": "red shirt", "color": "red" },
+        { "id": 2, "title": "shirt", "color": "blue" },
+        { "id": 3, "title": "shirt", "color": "green" },
+    ]);
+    let (task, _code) = index.add_documents(documents, None).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    // the boosted documents come first, the others are still returned
+    index
+        .search(
+            json!({"q": "red shirt", "boostingFilter": "color = blue", "attributesToRetrieve": ["id"], "showRankingScoreDetails": true}),
+            |response, code| {
+                assert_eq!(code, 200, "{}", response);
+                meili_snap::snapshot!(response["estimatedTotalHits"], @"3");
+                meili_snap::snapshot!(meili_snap::json_string!(response["hits"], { "[]._rankingScoreDetails" => "[details]" }), @r###"
+                [
+                  {
+                    "id": 2,
+                    "_rankingScoreDetails": "[details]"
+                  },
+                  {
+                    "id": 1,
+                    "_rankingScoreDetails": "[details]"
+                  },
+                  {
+                    "id": 3,
+                    "_rankingScoreDetails": "[details]"
+                  }
+                ]
+                "###);
+                meili_snap::snapshot!(meili_snap::json_string!(response["hits"][0]["_rankingScoreDetails"]["boostingFilter"]), @r###"
+                {
+                  "order": 0,
+                  "matches": true
+                }
+                "###);
+                meili_snap::snapshot!(meili_snap::json_string!(response["hits"][1]["_rankingScoreDetails"]["boostingFilter"]), @r###"
+                {
+                  "order": 0,
+                  "matches": false
+                }
+                "###);
+            },
+        )
+        .await;
+
+    index
+        .search(
+            json!({"boostingFilter": ["color = green OR color = blue"], "attributesToRetrieve": ["id"]}),
+            |response, code| {
+                assert_eq!(code, 200, "{}", response);
+                meili_snap::snapshot!(meili_snap::json_string!(response["hits"]), @r###"
+                [
+                  {
+                    "id": 2
+                  },
+                  {
+                    "id": 3
+                  },
+                  {
+                    "id": 1
+                  }
+                ]
+                "###);
+            },
+        )
+        .await;
+
+    // the boosting filter doesn't lower the ranking score of the other documents
+    index
+        .search(
+            json!({"q": "red shirt", "boostingFilter": "color = blue", "rankingScoreThreshold": 0.9, "attributesToRetrieve": ["id"]}),
+            |response, code| {
+                assert_eq!(code, 200, "{}", response);
+                meili_snap::snapshot!(meili_snap::json_string!(response["hits"]), @r###"
+                [
+                  {
+                    "id": 1
+                  }
+                ]
+                "###);
+            },
+        )
+        .await;
+}
+
 #[actix_rt::test]
 async fn phrase_search_with_stop_word() {
     // related to https://github.com/meilisearch/meilisearch/issues/3521
@@ -338,6 +661,65 @@ async fn search_with_filter_array_notation() {
     assert_eq!(response["hits"].as_array().unwrap().len(), 3);
 }
 
+#[actix_rt::test]
+async fn search_with_filter_relaxation() {
+    let index = shared_index_with_documents().await;
+    let filter = json!(["title = Gläss", "id = 450465", "id = 287947", "title = \"Escape Room\""]);
+
+    let (response, code) = index.search_post(json!({ "filter": filter })).await;
+    assert_eq!(code, 200, "{}", response);
+    assert_eq!(response["hits"].as_array().unwrap().len(), 0);
+    assert!(response.get("droppedFilters").is_none(), "{}", response);
+
+    // the last clauses are dropped one by one until a document matches
+    let (response, code) = index
+        .search_post(json!({
+            "filter": filter,
+            "filterRelaxation": { "minHits": 1 },
+            "attributesToRetrieve": ["id", "title"],
+        }))
+        .await;
+    meili_snap::snapshot!(code, @"200 OK");
+    meili_snap::snapshot!(meili_snap::json_string!(response["hits"]), @r###"
+    [
+      {
+        "title": "Gläss",
+        "id": "450465"
+      }
+    ]
+    "###);
+    meili_snap::snapshot!(meili_snap::json_string!(response["droppedFilters"]), @r###"
+    [
+      "title = \"Escape Room\"",
+      "id = 287947"
+    ]
+    "###);
+
+    // nothing is dropped when the filter returns enough hits
+    let (response, code) = index
+        .search_post(json!({ "filter": "title = Gläss", "filterRelaxation": { "minHits": 1 } }))
+        .await;
+    meili_snap::snapshot!(code, @"200 OK");
+    meili_snap::snapshot!(meili_snap::json_string!(response["droppedFilters"]), @"[]");
+
+    // the hits are counted even when their total isn't returned
+    let (response, code) = index
+        .search_post(json!({
+            "filter": "title = Gläss",
+            "filterRelaxation": { "minHits": 1 },
+            "computeTotalHits": false,
+        }))
+        .await;
+    meili_snap::snapshot!(code, @"200 OK");
+    meili_snap::snapshot!(meili_snap::json_string!(response["droppedFilters"]), @"[]");
+    assert!(response.get("estimatedTotalHits").is_none(), "{}", response);
+
+    let (response, code) =
+        index.search_post(json!({ "filter": filter, "filterRelaxation": { "min": 1 } })).await;
+    meili_snap::snapshot!(code, @"400 Bad Request");
+    meili_snap::snapshot!(response["code"], @r###""invalid_search_filter_relaxation""###);
+}
+
 #[actix_rt::test]
 async fn search_with_contains_filter() {
     let temp = TempDir::new().unwrap();
@@ -434,6 +816,66 @@ async fn search_with_multiple_sort() {
     assert_eq!(response["hits"].as_array().unwrap().len(), 5);
 }
 
+#[actix_rt::test]
+async fn search_facet_stats() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (response, code) =
+        index.update_settings(json!({"filterableAttributes": ["id", "price"]})).await;
+    meili_snap::snapshot!(code, @"202 Accepted");
+    index.wait_task(response.uid()).await;
+
+    let documents = json!([
+        { "id": 1, "price": 10 },
+        { "id": 2, "price": 20 },
+        { "id": 3, "price": 45 },
+        { "id": 4, "price": [5, 100] },
+        { "id": 5, "price": "free" },
+    ]);
+    let (response, code) = index.add_documents(documents, None).await;
+    meili_snap::snapshot!(code, @"202 Accepted");
+    index.wait_task(response.uid()).await;
+
+    index
+        .search(json!({"filter": "id < 4", "facets": ["price"]}), |response, code| {
+            meili_snap::snapshot!(code, @"200 OK");
+            meili_snap::snapshot!(meili_snap::json_string!(response["facetStats"]), @r###"
+            {
+              "price": {
+                "min": 10.0,
+                "max": 45.0,
+                "avg": 25.0
+              }
+            }
+            "###);
+        })
+        .await;
+
+    // every value of an array is part of the stats, strings are ignored.
+    index
+        .search(json!({"filter": "id >= 3", "facets": ["price"]}), |response, code| {
+            meili_snap::snapshot!(code, @"200 OK");
+            meili_snap::snapshot!(meili_snap::json_string!(response["facetStats"]), @r###"
+            {
+              "price": {
+                "min": 5.0,
+                "max": 100.0,
+                "avg": 50.0
+              }
+            }
+            "###);
+        })
+        .await;
+
+    index
+        .search(json!({"filter": "id > 10", "facets": ["price"]}), |response, code| {
+            meili_snap::snapshot!(code, @"200 OK");
+            meili_snap::snapshot!(meili_snap::json_string!(response["facetStats"]["price"]), @"null");
+        })
+        .await;
+}
+
 #[actix_rt::test]
 async fn search_facet_distribution() {
     let index = shared_index_with_documents().await;
@@ -792,6 +1234,74 @@ async fn test_score_details() {
         .await;
 }
 
+#[actix_rt::test]
+async fn test_score_details_with_sort() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (task, _code) = index
+        .update_settings(json!({
+            "rankingRules": ["words", "sort"],
+            "sortableAttributes": ["year"],
+        }))
+        .await;
+    index.wait_task(task.uid()).await.succeeded();
+    let documents = json!([
+        { "id": 1, "title": "How to Train Your Dragon", "year": 2010 },
+        { "id": 2, "title": "How to Train Your Dragon: The Hidden World", "year": 2019 },
+    ]);
+    let (task, _code) = index.add_documents(documents, None).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    index
+        .search(
+            json!({
+                "q": "dragon",
+                "sort": ["year:desc"],
+                "attributesToRetrieve": ["id"],
+                "showRankingScoreDetails": true,
+            }),
+            |response, code| {
+                meili_snap::snapshot!(code, @"200 OK");
+                meili_snap::snapshot!(meili_snap::json_string!(response["hits"]), @r###"
+                [
+                  {
+                    "id": 2,
+                    "_rankingScoreDetails": {
+                      "words": {
+                        "order": 0,
+                        "matchingWords": 1,
+                        "maxMatchingWords": 1,
+                        "score": 1.0
+                      },
+                      "year:desc": {
+                        "order": 1,
+                        "value": 2019.0
+                      }
+                    }
+                  },
+                  {
+                    "id": 1,
+                    "_rankingScoreDetails": {
+                      "words": {
+                        "order": 0,
+                        "matchingWords": 1,
+                        "maxMatchingWords": 1,
+                        "score": 1.0
+                      },
+                      "year:desc": {
+                        "order": 1,
+                        "value": 2010.0
+                      }
+                    }
+                  }
+                ]
+                "###);
+            },
+        )
+        .await;
+}
+
 #[actix_rt::test]
 async fn test_score() {
     let server = Server::new().await;
@@ -660,6 +660,64 @@ async fn federation_two_search_two_indexes() {
     "###);
 }
 
+#[actix_rt::test]
+async fn federation_weights_interleave_two_indexes() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let documents = DOCUMENTS.clone();
+    index.add_documents(documents, None).await;
+    index.wait_task(0).await;
+
+    let index = server.index("nested");
+    let documents = NESTED_DOCUMENTS.clone();
+    index.add_documents(documents, None).await;
+    index.wait_task(1).await;
+
+    let hits_sources = |response: &crate::common::Value| -> Vec<(String, f64)> {
+        response["hits"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|hit| {
+                let federation = &hit["_federation"];
+                // the source index tag matches the document
+                let index_uid = federation["indexUid"].as_str().unwrap().to_string();
+                match index_uid.as_str() {
+                    "test" => assert!(hit.get("title").is_some(), "{hit}"),
+                    "nested" => assert!(hit.get("father").is_some(), "{hit}"),
+                    other => panic!("unexpected index {other}"),
+                }
+                (index_uid, federation["weightedRankingScore"].as_f64().unwrap())
+            })
+            .collect()
+    };
+
+    // the nested documents are boosted above the unweighted one
+    let (response, code) = server
+        .multi_search(json!({"federation": {}, "queries": [
+        {"indexUid" : "test", "q": "glass"},
+        {"indexUid": "nested", "q": "pésti", "federationOptions": {"weight": 2.0}},
+        ]}))
+        .await;
+    snapshot!(code, @"200 OK");
+    let sources = hits_sources(&response);
+    snapshot!(format!("{:?}", sources.iter().map(|(index, _)| index).collect::<Vec<_>>()), @r###"["nested", "nested", "test"]"###);
+    assert!(sources.windows(2).all(|w| w[0].1 >= w[1].1), "{sources:?}");
+
+    // the nested documents are lowered below the unweighted one
+    let (response, code) = server
+        .multi_search(json!({"federation": {}, "queries": [
+        {"indexUid" : "test", "q": "glass"},
+        {"indexUid": "nested", "q": "pésti", "federationOptions": {"weight": 0.5}},
+        ]}))
+        .await;
+    snapshot!(code, @"200 OK");
+    let sources = hits_sources(&response);
+    snapshot!(format!("{:?}", sources.iter().map(|(index, _)| index).collect::<Vec<_>>()), @r###"["test", "nested", "nested"]"###);
+    assert!(sources.windows(2).all(|w| w[0].1 >= w[1].1), "{sources:?}");
+}
+
 #[actix_rt::test]
 async fn federation_multiple_search_multiple_indexes() {
     let server = Server::new().await;
@@ -20,6 +20,74 @@ async fn default_search_should_return_estimated_total_hit() {
         .await;
 }
 
+#[actix_rt::test]
+async fn search_without_total_hits() {
+    let index = shared_index_with_documents().await;
+    index
+        .search(json!({"computeTotalHits": false, "limit": 2, "offset": 1}), |response, code| {
+            assert_eq!(code, 200, "{}", response);
+            assert_eq!(response["hits"].as_array().unwrap().len(), 2);
+            assert_eq!(response["limit"], 2);
+            assert_eq!(response["offset"], 1);
+
+            // the total number of hits isn't computed
+            assert!(response.get("estimatedTotalHits").is_none());
+            assert!(response.get("totalHits").is_none());
+        })
+        .await;
+
+    // the offset is still applied on the same ordering
+    let (all_hits, _code) = index.search_post(json!({"limit": 3})).await;
+    let (page, _code) =
+        index.search_post(json!({"computeTotalHits": false, "limit": 2, "offset": 1})).await;
+    assert_eq!(all_hits["hits"].as_array().unwrap()[1..3], page["hits"].as_array().unwrap()[..]);
+
+    // the total number of hits is always computed when paginating by pages
+    index
+        .search(json!({"computeTotalHits": false, "page": 1}), |response, code| {
+            assert_eq!(code, 200, "{}", response);
+            assert_eq!(response["totalHits"], 5);
+            assert_eq!(response["totalPages"], 1);
+        })
+        .await;
+}
+
+#[actix_rt::test]
+async fn search_count_only() {
+    let index = shared_index_with_documents().await;
+
+    for (q, filter) in [("", "id != 522681"), ("the", "title != 'Shazam!'"), ("", "id = 0")] {
+        let (full, code) = index.search_post(json!({"q": q, "filter": filter, "page": 1})).await;
+        assert_eq!(code, 200, "{}", full);
+        let (count, code) =
+            index.search_post(json!({"q": q, "filter": filter, "countOnly": true})).await;
+        assert_eq!(code, 200, "{}", count);
+
+        // the count is exact and no document is returned
+        assert_eq!(count["estimatedTotalHits"], full["totalHits"], "q: {q}, filter: {filter}");
+        assert!(count["hits"].as_array().unwrap().is_empty());
+    }
+
+    // the count is also returned when paginating by pages or skipping the total hits
+    index
+        .search(
+            json!({"filter": "id != 522681", "countOnly": true, "page": 1}),
+            |response, code| {
+                assert_eq!(code, 200, "{}", response);
+                assert_eq!(response["totalHits"], 4);
+                assert!(response["hits"].as_array().unwrap().is_empty());
+            },
+        )
+        .await;
+    index
+        .search(json!({"countOnly": true, "computeTotalHits": false}), |response, code| {
+            assert_eq!(code, 200, "{}", response);
+            assert_eq!(response["estimatedTotalHits"], 5);
+            assert!(response["hits"].as_array().unwrap().is_empty());
+        })
+        .await;
+}
+
 #[actix_rt::test]
 async fn simple_search() {
     let index = shared_index_with_documents().await;
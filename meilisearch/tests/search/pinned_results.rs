@@ -0,0 +1,52 @@
+use meili_snap::snapshot;
+
+use crate::common::{Server, Value};
+use crate::json;
+
+fn hit_ids(response: &Value) -> Vec<u64> {
+    response["hits"].as_array().unwrap().iter().map(|hit| hit["id"].as_u64().unwrap()).collect()
+}
+
+#[actix_rt::test]
+async fn pinned_documents_come_first() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (task, _code) = index
+        .update_settings(json!({
+            "filterableAttributes": ["brand"],
+            "pinnedResults": [{ "query": "phone*", "documentIds": ["3", "1"] }],
+        }))
+        .await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let documents = json!([
+        { "id": 1, "title": "Phone case", "brand": "acme" },
+        { "id": 2, "title": "Phone", "brand": "acme" },
+        { "id": 3, "title": "Laptop", "brand": "globex" },
+        { "id": 4, "title": "Phone charger", "brand": "acme" },
+    ]);
+    let (task, _code) = index.add_documents(documents, Some("id")).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (response, code) = index.search_post(json!({ "q": "Phone" })).await;
+    snapshot!(code, @"200 OK");
+    assert_eq!(hit_ids(&response), vec![3, 1, 2, 4]);
+
+    // the pinned documents are paginated with the other results
+    let (response, code) =
+        index.search_post(json!({ "q": "phone", "offset": 1, "limit": 2 })).await;
+    snapshot!(code, @"200 OK");
+    assert_eq!(hit_ids(&response), vec![1, 2]);
+
+    // the pinned documents must match the filter
+    let (response, code) =
+        index.search_post(json!({ "q": "phone", "filter": "brand = acme" })).await;
+    snapshot!(code, @"200 OK");
+    assert_eq!(hit_ids(&response), vec![1, 2, 4]);
+
+    // the other queries are left untouched
+    let (response, code) = index.search_post(json!({ "q": "laptop" })).await;
+    snapshot!(code, @"200 OK");
+    assert_eq!(hit_ids(&response), vec![3]);
+}
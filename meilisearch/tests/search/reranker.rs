@@ -0,0 +1,190 @@
+use meili_snap::{json_string, snapshot};
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, Request, ResponseTemplate};
+
+use crate::common::{Server, Value};
+use crate::json;
+
+fn hit_ids(response: &Value) -> Vec<u64> {
+    response["hits"].as_array().unwrap().iter().map(|hit| hit["id"].as_u64().unwrap()).collect()
+}
+
+/// A re-ranker scoring the documents by their `popularity`.
+async fn create_mock() -> MockServer {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/"))
+        .and(header("Authorization", "Bearer kefir"))
+        .respond_with(|req: &Request| {
+            let body: serde_json::Value = req.body_json().unwrap();
+            assert_eq!(body["query"], json!("kefir"));
+            let scores: Vec<_> = body["documents"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|document| document["popularity"].as_f64().unwrap())
+                .collect();
+            ResponseTemplate::new(200).set_body_json(json!({ "scores": scores }))
+        })
+        .mount(&mock_server)
+        .await;
+
+    mock_server
+}
+
+#[actix_rt::test]
+async fn reranker_reorders_the_most_relevant_documents() {
+    let mock_server = create_mock().await;
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let documents = json!([
+        { "id": 1, "title": "kefir", "popularity": 1 },
+        { "id": 2, "title": "kefir the dog", "popularity": 3 },
+        { "id": 3, "title": "kefir the good dog", "popularity": 2 },
+        { "id": 4, "title": "intel", "popularity": 4 },
+    ]);
+    let (task, _code) = index.add_documents(documents, Some("id")).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (response, code) = index.search_post(json!({ "q": "kefir" })).await;
+    snapshot!(code, @"200 OK");
+    assert_eq!(hit_ids(&response), vec![1, 2, 3]);
+
+    let (task, _code) = index
+        .update_settings(json!({
+            "reranker": { "url": mock_server.uri(), "apiKey": "kefir", "candidates": 2 },
+        }))
+        .await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    // only the two most relevant documents are re-scored
+    let (response, code) = index.search_post(json!({ "q": "kefir" })).await;
+    snapshot!(code, @"200 OK");
+    assert_eq!(hit_ids(&response), vec![2, 1, 3]);
+
+    let (response, code) = index.search_post(json!({ "q": "kefir", "offset": 1 })).await;
+    snapshot!(code, @"200 OK");
+    assert_eq!(hit_ids(&response), vec![1, 3]);
+
+    // the explicitly sorted searches and the placeholder searches are left untouched
+    let (response, code) = index.search_post(json!({ "q": "" })).await;
+    snapshot!(code, @"200 OK");
+    assert_eq!(hit_ids(&response), vec![1, 2, 3, 4]);
+
+    let (response, code) = index.settings().await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response["reranker"], { ".url" => "[url]" }), @r###"
+    {
+      "url": "[url]",
+      "apiKey": "XXX...",
+      "candidates": 2
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn reranker_receives_the_displayed_attributes_and_adds_its_score() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/"))
+        .respond_with(|req: &Request| {
+            let body: serde_json::Value = req.body_json().unwrap();
+            let documents = body["documents"].as_array().unwrap();
+            // the hidden attributes are never sent to the endpoint
+            let scores: Vec<_> = documents
+                .iter()
+                .map(|document| match document.get("secret") {
+                    Some(_) => 0.0,
+                    None => document["popularity"].as_f64().unwrap(),
+                })
+                .collect();
+            ResponseTemplate::new(200).set_body_json(json!({ "scores": scores }))
+        })
+        .mount(&mock_server)
+        .await;
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let documents = json!([
+        { "id": 1, "title": "kefir", "popularity": 1, "secret": "a" },
+        { "id": 2, "title": "kefir the dog", "popularity": 3, "secret": "b" },
+    ]);
+    let (task, _code) = index.add_documents(documents, Some("id")).await;
+    index.wait_task(task.uid()).await.succeeded();
+    let (task, _code) = index
+        .update_settings(json!({
+            "displayedAttributes": ["id", "title", "popularity"],
+            "reranker": { "url": mock_server.uri() },
+        }))
+        .await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (response, code) = index
+        .search_post(json!({
+            "q": "kefir",
+            "attributesToRetrieve": ["id"],
+            "showRankingScoreDetails": true,
+        }))
+        .await;
+    snapshot!(code, @"200 OK");
+    assert_eq!(hit_ids(&response), vec![2, 1]);
+    snapshot!(json_string!(response["hits"][0]["_rankingScoreDetails"]["reranker"]), @r###"
+    {
+      "order": 5,
+      "score": 3.0
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn unreachable_reranker_keeps_the_ranking_rules_order() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let documents = json!([
+        { "id": 1, "title": "kefir" },
+        { "id": 2, "title": "kefir the dog" },
+    ]);
+    let (task, _code) = index.add_documents(documents, Some("id")).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (task, _code) =
+        index.update_settings(json!({ "reranker": { "url": "http://127.0.0.1:1/" } })).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (response, code) = index.search_post(json!({ "q": "kefir" })).await;
+    snapshot!(code, @"200 OK");
+    assert_eq!(hit_ids(&response), vec![1, 2]);
+}
+
+#[actix_rt::test]
+async fn error_invalid_reranker() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (task, _code) = index
+        .update_settings(json!({ "reranker": { "url": "http://localhost/", "candidates": 0 } }))
+        .await;
+    let response = index.wait_task(task.uid()).await;
+    snapshot!(json_string!(response["error"]), @r###"
+    {
+      "message": "`reranker` setting is invalid. `candidates` must be between 1 and 1000, found 0.",
+      "code": "invalid_settings_reranker",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_settings_reranker"
+    }
+    "###);
+
+    let (response, code) = index.update_settings(json!({ "reranker": { "uri": "kefir" } })).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "Unknown field `uri` inside `.reranker`: expected one of `url`, `apiKey`, `candidates`",
+      "code": "invalid_settings_reranker",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_settings_reranker"
+    }
+    "###);
+}
@@ -0,0 +1,142 @@
+use meili_snap::{json_string, snapshot};
+use meilisearch::Opt;
+use tempfile::TempDir;
+
+use crate::common::{default_settings, Server, DOCUMENTS};
+use crate::json;
+
+#[actix_rt::test]
+async fn search_log_is_disabled_by_default() {
+    let server = Server::new().await;
+    let index = server.index("test");
+    let (task, _code) = index.add_documents(DOCUMENTS.clone(), None).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (response, code) = index.recent_searches().await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "The search log is disabled. Use the `--experimental-search-log-size` option to enable it.",
+      "code": "feature_not_enabled",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#feature_not_enabled"
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn search_log_lists_the_recent_searches_newest_first() {
+    let temp = TempDir::new().unwrap();
+    let server = Server::new_with_options(Opt {
+        experimental_search_log_size: 3,
+        ..default_settings(temp.path())
+    })
+    .await
+    .unwrap();
+    let index = server.index("test");
+    let (task, _code) = index.update_settings(json!({ "filterableAttributes": ["id"] })).await;
+    index.wait_task(task.uid()).await.succeeded();
+    let (task, _code) = index.add_documents(DOCUMENTS.clone(), None).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (response, code) = index.recent_searches().await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response), @r###"
+    {
+      "results": []
+    }
+    "###);
+
+    for query in [
+        json!({ "q": "dragon" }),
+        json!({ "q": "glass" }),
+        json!({ "q": "", "filter": "id = 450465" }),
+        json!({ "q": "nothing matches this" }),
+    ] {
+        let (_response, code) = index.search_post(query).await;
+        snapshot!(code, @"200 OK");
+    }
+
+    // only the three most recent searches are kept
+    let (response, code) = index.recent_searches().await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response, { ".results[].processingTimeMs" => "[duration]" }), @r###"
+    {
+      "results": [
+        {
+          "q": "nothing matches this",
+          "filter": null,
+          "hitsCount": 0,
+          "processingTimeMs": "[duration]"
+        },
+        {
+          "q": "",
+          "filter": "id = 450465",
+          "hitsCount": 1,
+          "processingTimeMs": "[duration]"
+        },
+        {
+          "q": "glass",
+          "filter": null,
+          "hitsCount": 1,
+          "processingTimeMs": "[duration]"
+        }
+      ]
+    }
+    "###);
+
+    // only the existing indexes have a search log
+    let (response, code) = server.index("other").recent_searches().await;
+    snapshot!(code, @"404 Not Found");
+    snapshot!(response["code"], @r###""index_not_found""###);
+}
+
+#[actix_rt::test]
+async fn search_log_hashes_the_queries() {
+    let temp = TempDir::new().unwrap();
+    let server = Server::new_with_options(Opt {
+        experimental_search_log_size: 10,
+        experimental_search_log_hash_queries: true,
+        ..default_settings(temp.path())
+    })
+    .await
+    .unwrap();
+    let index = server.index("test");
+    let (task, _code) = index.add_documents(DOCUMENTS.clone(), None).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    for _ in 0..2 {
+        let (_response, code) = index.search_post(json!({ "q": "glass" })).await;
+        snapshot!(code, @"200 OK");
+    }
+
+    let (response, code) = index.recent_searches().await;
+    snapshot!(code, @"200 OK");
+    snapshot!(response["results"][0]["hitsCount"], @"1");
+    let hash = response["results"][0]["q"].as_str().unwrap();
+    assert_eq!(hash.len(), 64);
+    assert_eq!(response["results"][1]["q"], hash);
+    // the hash is salted, it isn't the plain SHA-256 of the query
+    assert_ne!(hash, "132a1a391cce181c490a2c4350597221fad9ae207714d1f9a10f67bcb1c8288d");
+}
+
+#[actix_rt::test]
+async fn search_log_truncates_the_long_queries() {
+    let temp = TempDir::new().unwrap();
+    let server = Server::new_with_options(Opt {
+        experimental_search_log_size: 10,
+        ..default_settings(temp.path())
+    })
+    .await
+    .unwrap();
+    let index = server.index("test");
+    let (task, _code) = index.add_documents(DOCUMENTS.clone(), None).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (_response, code) = index.search_post(json!({ "q": "é".repeat(300) })).await;
+    snapshot!(code, @"200 OK");
+
+    let (response, code) = index.recent_searches().await;
+    snapshot!(code, @"200 OK");
+    assert_eq!(response["results"][0]["q"], "é".repeat(256));
+}
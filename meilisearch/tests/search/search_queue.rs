@@ -5,6 +5,10 @@ use std::time::Duration;
 use actix_web::ResponseError;
 use meili_snap::snapshot;
 use meilisearch::search_queue::SearchQueue;
+use meilisearch::{create_search_queue, Opt};
+use tempfile::TempDir;
+
+use crate::common::default_settings;
 
 #[actix_rt::test]
 async fn search_queue_register() {
@@ -182,3 +186,34 @@ async fn works_with_capacity_of_zero() {
         .expect("I should get a permit straight away")
         .unwrap();
 }
+
+#[actix_rt::test]
+async fn refuse_concurrent_searches_above_the_configured_limit() {
+    let temp = TempDir::new().unwrap();
+    let queue = create_search_queue(&Opt {
+        experimental_search_max_concurrency: Some(NonZeroUsize::new(1).unwrap()),
+        experimental_search_queue_size: 0,
+        ..default_settings(temp.path())
+    });
+
+    // a single search at a time is processed...
+    let permit1 = tokio::time::timeout(Duration::from_secs(1), queue.try_get_search_permit())
+        .await
+        .expect("I should get a permit straight away")
+        .unwrap();
+
+    // ...and the searches sent while it is running are refused as there is no room to queue them
+    let permit2 = tokio::time::timeout(Duration::from_secs(1), queue.try_get_search_permit())
+        .await
+        .expect("I should get a result straight away");
+    let err = meilisearch_types::error::ResponseError::from(permit2.unwrap_err());
+    snapshot!(err.error_response().status(), @"503 Service Unavailable");
+    let err = serde_json::to_value(&err).unwrap();
+    snapshot!(err["code"], @r###""too_many_search_requests""###);
+
+    drop(permit1);
+    let _permit3 = tokio::time::timeout(Duration::from_secs(1), queue.try_get_search_permit())
+        .await
+        .expect("I should get a permit straight away")
+        .unwrap();
+}
@@ -0,0 +1,71 @@
+use meili_snap::{json_string, snapshot};
+
+use crate::common::Server;
+use crate::json;
+
+#[actix_rt::test]
+async fn search_with_stemming() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    // without the typo tolerance, only the stemming can make "shoes" match "shoe"
+    let (task, _code) =
+        index.update_settings(json!({ "typoTolerance": { "enabled": false } })).await;
+    index.wait_task(task.uid()).await.succeeded();
+    let (task, _code) = index
+        .add_documents(json!([{ "id": 1, "title": "A shoe for running" }, { "id": 2, "title": "A pair of socks" }]), None)
+        .await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    index
+        .search(json!({ "q": "shoes" }), |response, code| {
+            snapshot!(code, @"200 OK");
+            snapshot!(json_string!(response["hits"]), @"[]");
+        })
+        .await;
+
+    let (task, _code) = index.update_settings(json!({ "stemming": ["eng"] })).await;
+    index.wait_task(task.uid()).await.succeeded();
+    let (response, _code) = index.settings().await;
+    snapshot!(json_string!(response["stemming"]), @r###"
+    [
+      "eng"
+    ]
+    "###);
+
+    index
+        .search(json!({ "q": "shoes" }), |response, code| {
+            snapshot!(code, @"200 OK");
+            snapshot!(json_string!(response["hits"]), @r###"
+            [
+              {
+                "id": 1,
+                "title": "A shoe for running"
+              }
+            ]
+            "###);
+        })
+        .await;
+
+    // disabling the stemming reindexes the original words
+    let (task, _code) = index.update_settings(json!({ "stemming": [] })).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    index
+        .search(json!({ "q": "shoes" }), |response, code| {
+            snapshot!(code, @"200 OK");
+            snapshot!(json_string!(response["hits"]), @"[]");
+        })
+        .await;
+}
+
+#[actix_rt::test]
+async fn stemming_of_an_unsupported_language() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (task, _code) = index.update_settings(json!({ "stemming": ["fra"] })).await;
+    let response = index.wait_task(task.uid()).await;
+    snapshot!(response["status"], @r###""failed""###);
+    snapshot!(response["error"]["code"], @r###""invalid_settings_stemming""###);
+}
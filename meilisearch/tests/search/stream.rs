@@ -0,0 +1,78 @@
+use meili_snap::snapshot;
+
+use crate::common::Server;
+use crate::json;
+
+/// Parses the server-sent events of a streamed search into their names and JSON data.
+fn parse_events(body: &str) -> Vec<(String, serde_json::Value)> {
+    body.split("\n\n")
+        .filter(|event| !event.is_empty())
+        .map(|event| {
+            let mut lines = event.lines();
+            let name = lines.next().unwrap().strip_prefix("event: ").unwrap().to_string();
+            let data = lines.next().unwrap().strip_prefix("data: ").unwrap();
+            (name, serde_json::from_str(data).unwrap())
+        })
+        .collect()
+}
+
+#[actix_rt::test]
+async fn stream_partial_hits_before_the_complete_result() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let documents: Vec<_> = (0..100)
+        .map(|id| {
+            let title = match id % 4 {
+                0 => "the quick brown fox",
+                1 => "the quick fox",
+                2 => "a brown fox jumps",
+                _ => "the quikc fox",
+            };
+            serde_json::json!({ "id": id, "title": title })
+        })
+        .collect();
+    let (task, _code) = index.add_documents(serde_json::Value::Array(documents).into(), None).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (body, code) = index.search_stream(json!({ "q": "quick fox", "limit": 40 })).await;
+    snapshot!(code, @"200 OK");
+    let events = parse_events(&body);
+
+    // at least one partial batch is sent before the complete result, which is sent last
+    let (complete, partials) = events.split_last().unwrap();
+    assert_eq!(complete.0, "complete");
+    assert!(!partials.is_empty());
+    assert!(partials.iter().all(|(name, _)| name == "partial"));
+
+    // the partial batches add up to the hits of the complete result
+    let mut received_hits = 0;
+    let mut partial_ids = Vec::new();
+    for (_, partial) in partials {
+        let hits = partial["hits"].as_array().unwrap();
+        assert!(!hits.is_empty());
+        received_hits += hits.len();
+        assert_eq!(partial["receivedHits"].as_u64(), Some(received_hits as u64));
+        partial_ids.extend(hits.iter().map(|hit| hit["id"].clone()));
+    }
+    let complete_ids: Vec<_> =
+        complete.1["hits"].as_array().unwrap().iter().map(|hit| hit["id"].clone()).collect();
+    assert_eq!(partial_ids, complete_ids);
+    assert_eq!(complete_ids.len(), 40);
+    snapshot!(complete.1["estimatedTotalHits"], @"100");
+}
+
+#[actix_rt::test]
+async fn stream_an_error_event_when_the_search_fails() {
+    let server = Server::new().await;
+    let index = server.index("test");
+    let (task, _code) = index.add_documents(json!([{ "id": 0, "title": "fox" }]), None).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (body, code) = index.search_stream(json!({ "q": "fox", "filter": "title = fox" })).await;
+    snapshot!(code, @"200 OK");
+    let events = parse_events(&body);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].0, "error");
+    snapshot!(events[0].1["code"], @r###""invalid_search_filter""###);
+}
@@ -0,0 +1,111 @@
+use meili_snap::{json_string, snapshot};
+
+use crate::common::Server;
+use crate::json;
+
+async fn index_with_movies(server: &Server) -> crate::common::Index<'_> {
+    let index = server.index("test");
+    let (task, _code) = index
+        .add_documents(
+            json!([
+                { "id": 0, "title": "Star Wars", "overview": "A stadium full of rebels" },
+                { "id": 1, "title": "Star Trek", "overview": "The stadium of the galaxy" },
+                { "id": 2, "title": "Star Wars II", "overview": "Back to the stadium" },
+                { "id": 3, "title": "Stardust", "overview": "A fallen star in a stadium" },
+                { "id": 4, "title": "Starship Troopers", "overview": "Bugs in the stadium" },
+            ]),
+            None,
+        )
+        .await;
+    index.wait_task(task.uid()).await.succeeded();
+    index
+}
+
+#[actix_rt::test]
+async fn suggestions_are_ordered_by_frequency() {
+    let server = Server::new().await;
+    let index = index_with_movies(&server).await;
+
+    let (response, code) = index.suggest("q=sta").await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response), @r###"
+    {
+      "q": "sta",
+      "suggestions": [
+        {
+          "value": "stadium",
+          "frequency": 5
+        },
+        {
+          "value": "star",
+          "frequency": 4
+        },
+        {
+          "value": "stardust",
+          "frequency": 1
+        },
+        {
+          "value": "starship",
+          "frequency": 1
+        }
+      ]
+    }
+    "###);
+
+    // only the last word of the query is completed
+    let (response, code) = index.suggest("q=the%20sta&limit=2").await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response["suggestions"]), @r###"
+    [
+      {
+        "value": "the stadium",
+        "frequency": 5
+      },
+      {
+        "value": "the star",
+        "frequency": 4
+      }
+    ]
+    "###);
+
+    // the last word is complete
+    let (response, code) = index.suggest("q=star%20").await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response["suggestions"]), @"[]");
+}
+
+#[actix_rt::test]
+async fn suggestions_restricted_to_an_attribute() {
+    let server = Server::new().await;
+    let index = index_with_movies(&server).await;
+
+    let (response, code) = index.suggest("q=sta&attribute=title").await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response), @r###"
+    {
+      "q": "sta",
+      "suggestions": [
+        {
+          "value": "star",
+          "frequency": 3
+        },
+        {
+          "value": "stardust",
+          "frequency": 1
+        },
+        {
+          "value": "starship",
+          "frequency": 1
+        }
+      ]
+    }
+    "###);
+
+    let (response, code) = index.suggest("q=sta&attribute=director").await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(response["code"], @r###""invalid_suggest_attribute""###);
+
+    let (response, code) = index.suggest("q=sta&limit=many").await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(response["code"], @r###""invalid_suggest_limit""###);
+}
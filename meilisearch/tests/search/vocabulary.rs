@@ -0,0 +1,53 @@
+use meili_snap::snapshot;
+
+use crate::common::Server;
+use crate::json;
+
+#[actix_rt::test]
+async fn export_the_vocabulary() {
+    let server = Server::new().await;
+    let index = server.index("test");
+    let (task, _code) = index
+        .add_documents(
+            json!([
+                { "id": 0, "title": "The quick fox" },
+                { "id": 1, "title": "The lazy dog" },
+                { "id": 2, "title": "A quick dog, a quick fox" },
+            ]),
+            None,
+        )
+        .await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (body, code) = index.export_vocabulary().await;
+    snapshot!(code, @"200 OK");
+    // the words of the identifiers are indexed too
+    snapshot!(body, @r###"
+    {"word":"0","frequency":1}
+    {"word":"1","frequency":1}
+    {"word":"2","frequency":1}
+    {"word":"a","frequency":1}
+    {"word":"dog","frequency":2}
+    {"word":"fox","frequency":2}
+    {"word":"lazy","frequency":1}
+    {"word":"quick","frequency":2}
+    {"word":"the","frequency":2}
+    "###);
+}
+
+#[actix_rt::test]
+async fn export_the_vocabulary_of_an_empty_index() {
+    let server = Server::new().await;
+    let index = server.index("test");
+    let (task, _code) = index.create(None).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (body, code) = index.export_vocabulary().await;
+    snapshot!(code, @"200 OK");
+    snapshot!(body, @"");
+
+    let (body, code) = server.index("unknown").export_vocabulary().await;
+    snapshot!(code, @"404 Not Found");
+    let body: serde_json::Value = serde_json::from_str(&body).unwrap();
+    snapshot!(body["code"], @r###""index_not_found""###);
+}
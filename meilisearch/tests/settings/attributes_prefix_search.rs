@@ -0,0 +1,56 @@
+use meili_snap::{json_string, snapshot};
+
+use crate::common::Server;
+use crate::json;
+
+#[actix_rt::test]
+async fn prefix_search_disabled_on_attribute() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (task, _code) = index
+        .update_settings(json!({
+            "searchableAttributes": ["title", "description"],
+            "attributesPrefixSearch": { "title": { "enabled": false } },
+        }))
+        .await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (response, _code) = index.settings().await;
+    snapshot!(json_string!(response["attributesPrefixSearch"]), @r###"
+    {
+      "title": {
+        "enabled": false,
+        "penalty": 0
+      }
+    }
+    "###);
+
+    let (task, _code) = index
+        .add_documents(
+            json!([
+                { "id": 0, "title": "autocomplete" },
+                { "id": 1, "description": "autocomplete" },
+            ]),
+            None,
+        )
+        .await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    // the prefix only matches the description
+    let (response, code) = index.search_post(json!({ "q": "auto" })).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response["hits"]), @r###"
+    [
+      {
+        "id": 1,
+        "description": "autocomplete"
+      }
+    ]
+    "###);
+
+    // while the complete term matches both attributes
+    let (response, code) = index.search_post(json!({ "q": "autocomplete" })).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(response["estimatedTotalHits"], @"2");
+}
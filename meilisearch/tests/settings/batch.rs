@@ -0,0 +1,68 @@
+use meili_snap::{json_string, snapshot};
+
+use crate::common::Server;
+use crate::json;
+
+#[actix_rt::test]
+async fn batch_update_settings_on_multiple_indexes() {
+    let server = Server::new().await;
+    server.create_index(json!({ "uid": "tenant1" })).await;
+    server.create_index(json!({ "uid": "tenant2" })).await;
+    server.wait_task(1).await;
+
+    let (response, code) = server
+        .batch_update_settings(json!([
+            { "indexUid": "tenant1", "settings": { "filterableAttributes": ["genre"] } },
+            { "indexUid": "tenant2", "settings": { "filterableAttributes": ["genre"] } },
+            { "indexUid": "missing", "settings": { "filterableAttributes": ["genre"] } },
+        ]))
+        .await;
+    snapshot!(code, @"202 Accepted");
+    snapshot!(json_string!(response, { ".results[].task.enqueuedAt" => "[date]" }), @r###"
+    {
+      "results": [
+        {
+          "indexUid": "tenant1",
+          "task": {
+            "taskUid": 2,
+            "indexUid": "tenant1",
+            "status": "enqueued",
+            "type": "settingsUpdate",
+            "enqueuedAt": "[date]"
+          }
+        },
+        {
+          "indexUid": "tenant2",
+          "task": {
+            "taskUid": 3,
+            "indexUid": "tenant2",
+            "status": "enqueued",
+            "type": "settingsUpdate",
+            "enqueuedAt": "[date]"
+          }
+        },
+        {
+          "indexUid": "missing",
+          "error": {
+            "message": "Index `missing` not found.",
+            "code": "index_not_found",
+            "type": "invalid_request",
+            "link": "https://docs.meilisearch.com/errors#index_not_found"
+          }
+        }
+      ]
+    }
+    "###);
+
+    server.wait_task(3).await;
+
+    for uid in ["tenant1", "tenant2"] {
+        let (response, code) = server.index(uid).settings().await;
+        assert_eq!(code, 200, "{}", response);
+        assert_eq!(response["filterableAttributes"], json!(["genre"]));
+    }
+
+    // the missing index must not have been created
+    let (_, code) = server.index("missing").get().await;
+    snapshot!(code, @"404 Not Found");
+}
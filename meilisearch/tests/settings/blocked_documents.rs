@@ -0,0 +1,74 @@
+use meili_snap::{json_string, snapshot};
+
+use crate::common::Server;
+use crate::json;
+
+#[actix_rt::test]
+async fn blocked_documents_never_appear_in_the_search_results() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let documents = json!([
+        { "id": 1, "title": "Carol" },
+        { "id": 2, "title": "Wonder Woman" },
+        { "id": 3, "title": "Wonder Wall" },
+    ]);
+    let (task, _code) = index.add_documents(documents, None).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    // the ids of documents that don't exist yet can be blocked as well
+    let (task, _code) = index.update_settings(json!({ "blockedDocuments": ["2", "4"] })).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (task, _code) =
+        index.add_documents(json!([{ "id": 4, "title": "Wonder Boys" }]), None).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    index
+        .search(json!({ "q": "wonder" }), |response, code| {
+            snapshot!(code, @"200 OK");
+            snapshot!(json_string!(response["hits"]), @r###"
+            [
+              {
+                "id": 3,
+                "title": "Wonder Wall"
+              }
+            ]
+            "###);
+            snapshot!(response["estimatedTotalHits"], @"1");
+        })
+        .await;
+    index
+        .search(json!({}), |response, _code| {
+            snapshot!(response["estimatedTotalHits"], @"2");
+        })
+        .await;
+
+    // the blocked documents can still be fetched by their id
+    let (response, code) = index.get_document(2, None).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response), @r###"
+    {
+      "id": 2,
+      "title": "Wonder Woman"
+    }
+    "###);
+
+    let (response, _code) = index.settings().await;
+    snapshot!(json_string!(response["blockedDocuments"]), @r###"
+    [
+      "2",
+      "4"
+    ]
+    "###);
+
+    // once reset, the documents are returned again
+    let (task, _code) = index.update_settings(json!({ "blockedDocuments": null })).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    index
+        .search(json!({ "q": "wonder" }), |response, _code| {
+            snapshot!(response["estimatedTotalHits"], @"3");
+        })
+        .await;
+}
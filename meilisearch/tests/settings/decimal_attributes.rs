@@ -0,0 +1,82 @@
+use meili_snap::{json_string, snapshot};
+
+use crate::common::Server;
+use crate::json;
+
+#[actix_rt::test]
+async fn decimals_round_trip() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (task, _code) = index
+        .update_settings(json!({
+            "filterableAttributes": ["price"],
+            "sortableAttributes": ["price"],
+            "decimalAttributes": ["price"],
+        }))
+        .await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (task, _code) = index
+        .add_documents(
+            json!([
+                { "id": 0, "price": 19.99 },
+                { "id": 1, "price": "19.990" },
+                { "id": 2, "price": "12345678901234567.89" },
+                { "id": 3, "price": 0.1 },
+            ]),
+            None,
+        )
+        .await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (response, code) = index.get_document(0, None).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response), @r###"
+    {
+      "id": 0,
+      "price": 19.99
+    }
+    "###);
+
+    let (response, code) = index.get_document(2, None).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response), @r###"
+    {
+      "id": 2,
+      "price": "12345678901234567.89"
+    }
+    "###);
+
+    // the decimal strings are filtered and sorted as numbers but returned as they were sent
+    let (response, code) =
+        index.search_post(json!({ "filter": "price > 19", "sort": ["price:desc"] })).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response["hits"]), @r###"
+    [
+      {
+        "id": 2,
+        "price": "12345678901234567.89"
+      },
+      {
+        "id": 0,
+        "price": 19.99
+      },
+      {
+        "id": 1,
+        "price": "19.990"
+      }
+    ]
+    "###);
+
+    let (response, code) = index.search_post(json!({ "filter": "price < 1" })).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response["hits"]), @r###"
+    [
+      {
+        "id": 3,
+        "price": 0.1
+      }
+    ]
+    "###);
+}
@@ -0,0 +1,39 @@
+use meili_snap::{json_string, snapshot};
+
+use crate::common::{GetAllDocumentsOptions, Server};
+use crate::json;
+
+#[actix_rt::test]
+async fn toggling_the_compression_rewrites_the_stored_documents() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let documents: Vec<_> = (0..100)
+        .map(|id| json!({ "id": id, "description": format!("the {id}th book of the long series") }))
+        .collect();
+    let (task, _code) = index.add_documents(documents.into(), None).await;
+    index.wait_task(task.uid()).await.succeeded();
+    let (before, _code) = index.get_document(42, None).await;
+
+    let (task, _code) = index.update_settings(json!({ "documentCompression": true })).await;
+    index.wait_task(task.uid()).await.succeeded();
+    let (response, _code) = index.settings().await;
+    snapshot!(response["documentCompression"], @"true");
+
+    // the documents are decompressed transparently
+    let (response, code) = index.get_document(42, None).await;
+    snapshot!(code, @"200 OK");
+    assert_eq!(response, before);
+    let (response, _code) = index.get_all_documents(GetAllDocumentsOptions::default()).await;
+    snapshot!(response["total"], @"100");
+
+    let (task, _code) = index.update_settings(json!({ "documentCompression": null })).await;
+    index.wait_task(task.uid()).await.succeeded();
+    let (response, _code) = index.get_document(42, None).await;
+    snapshot!(json_string!(response), @r###"
+    {
+      "id": 42,
+      "description": "the 42th book of the long series"
+    }
+    "###);
+}
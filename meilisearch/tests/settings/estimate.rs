@@ -0,0 +1,61 @@
+use meili_snap::{json_string, snapshot};
+
+use crate::common::Server;
+use crate::json;
+
+#[actix_rt::test]
+async fn estimate_searchable_attributes_update() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (task, _code) = index
+        .add_documents(
+            json!([
+                { "id": 1, "title": "Shazam!", "genres": ["Action"] },
+                { "id": 2, "title": "Captain Marvel", "genres": ["Action", "Adventure"] },
+                { "id": 3, "title": "Escape Room", "genres": ["Horror"] },
+            ]),
+            None,
+        )
+        .await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (response, code) =
+        index.estimate_settings(json!({ "searchableAttributes": ["title"] })).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response), @r###"
+    {
+      "reindexedDocuments": 3,
+      "rebuiltDatabases": [
+        "word-docids",
+        "exact-word-docids",
+        "word-prefix-docids",
+        "exact-word-prefix-docids",
+        "word-position-docids",
+        "word-field-id-docids",
+        "word-prefix-position-docids",
+        "word-prefix-field-id-docids",
+        "field-id-word-count-docids"
+      ]
+    }
+    "###);
+
+    // the estimated settings are not applied
+    let (settings, _code) = index.settings().await;
+    snapshot!(json_string!(settings["searchableAttributes"]), @r###"
+    [
+      "*"
+    ]
+    "###);
+
+    // a setting that doesn't need any reindexing is free
+    let (response, code) =
+        index.estimate_settings(json!({ "displayedAttributes": ["title"] })).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response), @r###"
+    {
+      "reindexedDocuments": 0,
+      "rebuiltDatabases": []
+    }
+    "###);
+}
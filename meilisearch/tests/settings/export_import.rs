@@ -0,0 +1,91 @@
+use crate::common::Server;
+use crate::json;
+
+#[actix_rt::test]
+async fn export_and_import_settings() {
+    let server = Server::new().await;
+    let staging = server.index("staging");
+    let prod = server.index("prod");
+
+    let (task, _code) = staging
+        .update_settings(json!({
+            "searchableAttributes": ["title", "description"],
+            "filterableAttributes": ["genres"],
+            "sortableAttributes": ["price"],
+            "rankingRules": ["words", "typo", "sort", "proximity", "attribute", "exactness"],
+            "stopWords": ["the", "a"],
+            "synonyms": { "tv": ["television"] },
+            "typoTolerance": { "disableOnWords": ["xbox"] },
+            "pagination": { "maxTotalHits": 200 },
+        }))
+        .await;
+    staging.wait_task(task.uid()).await.succeeded();
+
+    // the settings that are only set on the target index are reset by the import
+    let (task, _code) = prod
+        .update_settings(json!({ "distinctAttribute": "sku", "dictionary": ["J. R. R."] }))
+        .await;
+    prod.wait_task(task.uid()).await.succeeded();
+
+    let (exported, code) = staging.export_settings().await;
+    meili_snap::snapshot!(code, @"200 OK");
+    let (settings, _code) = staging.settings().await;
+    assert_eq!(exported, settings);
+
+    let (task, code) = prod.import_settings(exported.clone()).await;
+    meili_snap::snapshot!(code, @"202 Accepted");
+    prod.wait_task(task.uid()).await.succeeded();
+
+    let (imported, _code) = prod.export_settings().await;
+    assert_eq!(imported, exported);
+
+    // importing the same settings again doesn't change anything
+    let (task, _code) = prod.import_settings(exported.clone()).await;
+    prod.wait_task(task.uid()).await.succeeded();
+
+    let (imported, _code) = prod.export_settings().await;
+    assert_eq!(imported, exported);
+}
+
+#[actix_rt::test]
+async fn import_invalid_settings() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (task, _code) = index.update_settings(json!({ "filterableAttributes": ["genres"] })).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    // the settings are validated before any of them is applied
+    let (response, code) = index
+        .import_settings(json!({ "filterableAttributes": [], "rankingRules": ["wrong"] }))
+        .await;
+    meili_snap::snapshot!(code, @"400 Bad Request");
+    meili_snap::snapshot!(response["code"], @r###""invalid_settings_ranking_rules""###);
+
+    let (response, _code) = index.settings().await;
+    assert_eq!(response["filterableAttributes"], json!(["genres"]));
+}
+
+#[actix_rt::test]
+async fn import_hidden_secrets() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    // the secrets are hidden by the export, importing them as-is would break the embedder
+    let (response, code) = index
+        .import_settings(json!({
+            "embedders": {
+                "default": { "source": "openAi", "apiKey": "sk-abXXXXXX...", "dimensions": 3 }
+            }
+        }))
+        .await;
+    meili_snap::snapshot!(code, @"400 Bad Request");
+    meili_snap::snapshot!(meili_snap::json_string!(response), @r###"
+    {
+      "message": "The settings cannot be imported: `embedders.default.apiKey` is a secret hidden by the export.\n Hint: replace it with the actual secret, or remove it from the settings.",
+      "code": "invalid_settings_embedders",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_settings_embedders"
+    }
+    "###);
+}
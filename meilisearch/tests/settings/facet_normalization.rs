@@ -0,0 +1,94 @@
+use meili_snap::{json_string, snapshot};
+
+use crate::common::{Server, Value};
+use crate::json;
+
+fn hit_ids(response: &Value) -> Vec<u64> {
+    response["hits"].as_array().unwrap().iter().map(|hit| hit["id"].as_u64().unwrap()).collect()
+}
+
+#[actix_rt::test]
+async fn facet_normalization_applies_to_the_filters_and_the_facets() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (task, _code) = index.update_settings_filterable_attributes(json!(["brand"])).await;
+    index.wait_task(task.uid()).await.succeeded();
+    let documents = json!([
+        { "id": 0, "brand": "Apple" },
+        { "id": 1, "brand": "apple" },
+        { "id": 2, "brand": "Crème" },
+        { "id": 3, "brand": "creme" },
+    ]);
+    let (task, _code) = index.add_documents(documents, None).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (response, _code) = index.settings().await;
+    snapshot!(json_string!(response["faceting"]["normalization"]), @r###"
+    {
+      "caseFolding": true
+    }
+    "###);
+
+    let (task, _code) =
+        index.update_settings_faceting(json!({ "normalization": { "caseFolding": false } })).await;
+    index.wait_task(task.uid()).await.succeeded();
+    let (response, _code) = index.search_post(json!({ "filter": "brand = Apple" })).await;
+    assert_eq!(hit_ids(&response), vec![0]);
+
+    // the diacritics are removed from the values of the normalized facet attributes
+    let (task, _code) = index
+        .update_settings(json!({
+            "faceting": { "normalization": { "caseFolding": null } },
+            "normalizedFacetAttributes": ["brand"],
+        }))
+        .await;
+    index.wait_task(task.uid()).await.succeeded();
+    let (response, _code) =
+        index.search_post(json!({ "filter": "brand = CREME", "facets": ["brand"] })).await;
+    assert_eq!(hit_ids(&response), vec![2, 3]);
+    snapshot!(json_string!(response["facetDistribution"]), @r###"
+    {
+      "brand": {
+        "Crème": 2
+      }
+    }
+    "###);
+
+    let (task, _code) = index.update_settings(json!({ "normalizedFacetAttributes": null })).await;
+    index.wait_task(task.uid()).await.succeeded();
+    let (response, _code) = index.search_post(json!({ "filter": "brand = creme" })).await;
+    assert_eq!(hit_ids(&response), vec![3]);
+}
+
+#[actix_rt::test]
+async fn facet_normalization_errors() {
+    let server = Server::new_shared();
+    let index = server.unique_index();
+
+    let (response, code) =
+        index.update_settings_faceting(json!({ "normalization": { "caseFolding": "yes" } })).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "Invalid value type at `.normalization.caseFolding`: expected a boolean, but found a string: `\"yes\"`",
+      "code": "invalid_settings_faceting",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_settings_faceting"
+    }
+    "###);
+
+    // the diacritics are only removed through the normalized facet attributes
+    let (response, code) = index
+        .update_settings_faceting(json!({ "normalization": { "removeDiacritics": true } }))
+        .await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "Unknown field `removeDiacritics` inside `.normalization`: expected one of `caseFolding`",
+      "code": "invalid_settings_faceting",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_settings_faceting"
+    }
+    "###);
+}
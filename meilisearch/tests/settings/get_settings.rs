@@ -26,6 +26,9 @@ static DEFAULT_SETTINGS_VALUES: Lazy<HashMap<&'static str, Value>> = Lazy::new(|
             "maxValuesPerFacet": json!(100),
             "sortFacetValuesBy": {
                 "*": "alpha"
+            },
+            "normalization": {
+                "caseFolding": true
             }
         }),
     );
@@ -36,6 +39,36 @@ static DEFAULT_SETTINGS_VALUES: Lazy<HashMap<&'static str, Value>> = Lazy::new(|
         }),
     );
     map.insert("search_cutoff_ms", json!(null));
+    map.insert("ignored_attributes", json!([]));
+    map.insert("split_and_concat_words", json!(true));
+    map.insert("recency_attribute", json!(null));
+    map.insert("max_facet_values_per_attribute", json!(null));
+    map.insert("content_types", json!({}));
+    map.insert("read_only", json!(false));
+    map.insert("attribute_transforms", json!({}));
+    map.insert("decimal_attributes", json!([]));
+    map.insert("attributes_prefix_search", json!({}));
+    map.insert("max_documents", json!(null));
+    map.insert("max_prefix_typos", json!(null));
+    map.insert("min_prefix_length", json!(null));
+    map.insert("stemming", json!([]));
+    map.insert("max_flatten_depth", json!(null));
+    map.insert("default_language", json!(null));
+    map.insert("language_hint_field", json!(null));
+    map.insert("duplicate_key_strategy", json!("lastWins"));
+    map.insert("tokenizer_profiles", json!({}));
+    map.insert("pinned_results", json!([]));
+    map.insert("reserve_document_ids", json!(false));
+    map.insert("user_metadata", json!(null));
+    map.insert("store_max_length", json!({}));
+    map.insert("normalized_facet_attributes", json!([]));
+    map.insert("blocked_documents", json!([]));
+    map.insert("number_tokenization", json!("split"));
+    map.insert("enable_formatting", json!(true));
+    map.insert("recency_half_life_days", json!(null));
+    map.insert("reranker", json!(null));
+    map.insert("document_compression", json!(false));
+    map.insert("max_synonym_expansions", json!(50));
     map
 });
 
@@ -55,7 +88,7 @@ async fn get_settings() {
     let (response, code) = index.settings().await;
     assert_eq!(code, 200);
     let settings = response.as_object().unwrap();
-    assert_eq!(settings.keys().len(), 17);
+    assert_eq!(settings.keys().len(), 47);
     assert_eq!(settings["displayedAttributes"], json!(["*"]));
     assert_eq!(settings["searchableAttributes"], json!(["*"]));
     assert_eq!(settings["filterableAttributes"], json!([]));
@@ -75,6 +108,9 @@ async fn get_settings() {
             "maxValuesPerFacet": 100,
             "sortFacetValuesBy": {
                 "*": "alpha"
+            },
+            "normalization": {
+                "caseFolding": true
             }
         })
     );
@@ -86,6 +122,36 @@ async fn get_settings() {
     );
     assert_eq!(settings["proximityPrecision"], json!("byWord"));
     assert_eq!(settings["searchCutoffMs"], json!(null));
+    assert_eq!(settings["ignoredAttributes"], json!([]));
+    assert_eq!(settings["splitAndConcatWords"], json!(true));
+    assert_eq!(settings["recencyAttribute"], json!(null));
+    assert_eq!(settings["maxFacetValuesPerAttribute"], json!(null));
+    assert_eq!(settings["contentTypes"], json!({}));
+    assert_eq!(settings["readOnly"], json!(false));
+    assert_eq!(settings["attributeTransforms"], json!({}));
+    assert_eq!(settings["decimalAttributes"], json!([]));
+    assert_eq!(settings["attributesPrefixSearch"], json!({}));
+    assert_eq!(settings["maxDocuments"], json!(null));
+    assert_eq!(settings["maxPrefixTypos"], json!(null));
+    assert_eq!(settings["minPrefixLength"], json!(null));
+    assert_eq!(settings["stemming"], json!([]));
+    assert_eq!(settings["maxFlattenDepth"], json!(null));
+    assert_eq!(settings["defaultLanguage"], json!(null));
+    assert_eq!(settings["languageHintField"], json!(null));
+    assert_eq!(settings["duplicateKeyStrategy"], json!("lastWins"));
+    assert_eq!(settings["tokenizerProfiles"], json!({}));
+    assert_eq!(settings["pinnedResults"], json!([]));
+    assert_eq!(settings["reserveDocumentIds"], json!(false));
+    assert_eq!(settings["userMetadata"], json!(null));
+    assert_eq!(settings["storeMaxLength"], json!({}));
+    assert_eq!(settings["normalizedFacetAttributes"], json!([]));
+    assert_eq!(settings["blockedDocuments"], json!([]));
+    assert_eq!(settings["numberTokenization"], json!("split"));
+    assert_eq!(settings["enableFormatting"], json!(true));
+    assert_eq!(settings["recencyHalfLifeDays"], json!(null));
+    assert_eq!(settings["reranker"], json!(null));
+    assert_eq!(settings["documentCompression"], json!(false));
+    assert_eq!(settings["maxSynonymExpansions"], json!(50));
 }
 
 #[actix_rt::test]
@@ -173,12 +239,16 @@ async fn secrets_are_hidden_in_settings() {
           "twoTypos": 9
         },
         "disableOnWords": [],
-        "disableOnAttributes": []
+        "disableOnAttributes": [],
+        "typoPenalty": 1
       },
       "faceting": {
         "maxValuesPerFacet": 100,
         "sortFacetValuesBy": {
           "*": "alpha"
+        },
+        "normalization": {
+          "caseFolding": true
         }
       },
       "pagination": {
@@ -197,7 +267,37 @@ async fn secrets_are_hidden_in_settings() {
         }
       },
       "searchCutoffMs": null,
-      "localizedAttributes": null
+      "localizedAttributes": null,
+      "ignoredAttributes": [],
+      "splitAndConcatWords": true,
+      "recencyAttribute": null,
+      "maxFacetValuesPerAttribute": null,
+      "contentTypes": {},
+      "readOnly": false,
+      "attributeTransforms": {},
+      "decimalAttributes": [],
+      "attributesPrefixSearch": {},
+      "maxDocuments": null,
+      "maxPrefixTypos": null,
+      "minPrefixLength": null,
+      "stemming": [],
+      "maxFlattenDepth": null,
+      "defaultLanguage": null,
+      "languageHintField": null,
+      "duplicateKeyStrategy": "lastWins",
+      "tokenizerProfiles": {},
+      "pinnedResults": [],
+      "reserveDocumentIds": false,
+      "userMetadata": null,
+      "storeMaxLength": {},
+      "normalizedFacetAttributes": [],
+      "blockedDocuments": [],
+      "numberTokenization": "split",
+      "enableFormatting": true,
+      "recencyHalfLifeDays": null,
+      "reranker": null,
+      "documentCompression": false,
+      "maxSynonymExpansions": 50
     }
     "###);
 
@@ -419,7 +519,37 @@ test_setting_routes!(
     synonyms put,
     pagination patch,
     faceting patch,
-    search_cutoff_ms put
+    search_cutoff_ms put,
+    ignored_attributes put,
+    split_and_concat_words put,
+    recency_attribute put,
+    max_facet_values_per_attribute put,
+    content_types put,
+    read_only put,
+    attribute_transforms put,
+    decimal_attributes put,
+    attributes_prefix_search put,
+    max_documents put,
+    max_prefix_typos put,
+    min_prefix_length put,
+    stemming put,
+    max_flatten_depth put,
+    default_language put,
+    language_hint_field put,
+    duplicate_key_strategy put,
+    tokenizer_profiles put,
+    pinned_results put,
+    reserve_document_ids put,
+    user_metadata put,
+    store_max_length put,
+    normalized_facet_attributes put,
+    blocked_documents put,
+    number_tokenization put,
+    enable_formatting put,
+    recency_half_life_days put,
+    reranker put,
+    document_compression put,
+    max_synonym_expansions put
 );
 
 #[actix_rt::test]
@@ -0,0 +1,85 @@
+use meili_snap::{json_string, snapshot};
+
+use crate::common::{Server, Value};
+use crate::json;
+
+fn hit_ids(response: &Value) -> Vec<u64> {
+    let mut ids: Vec<_> = response["hits"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|hit| hit["id"].as_u64().unwrap())
+        .collect();
+    ids.sort_unstable();
+    ids
+}
+
+#[actix_rt::test]
+async fn max_synonym_expansions_bounds_the_synonyms_of_the_search() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (task, _code) =
+        index.update_settings(json!({ "synonyms": { "word": ["gamma", "beta", "alpha"] } })).await;
+    index.wait_task(task.uid()).await.succeeded();
+    let documents = json!([
+        { "id": 0, "text": "alpha" },
+        { "id": 1, "text": "alpha beta" },
+        { "id": 2, "text": "gamma" },
+    ]);
+    let (task, _code) = index.add_documents(documents, None).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (response, _code) = index.search_post(json!({ "q": "word" })).await;
+    assert_eq!(hit_ids(&response), vec![0, 1, 2]);
+
+    let (task, code) = index.update_settings(json!({ "maxSynonymExpansions": 1 })).await;
+    snapshot!(code, @"202 Accepted");
+    index.wait_task(task.uid()).await.succeeded();
+    let (response, _code) = index.settings().await;
+    snapshot!(response["maxSynonymExpansions"], @"1");
+
+    // only the most frequent synonym is kept
+    let (response, _code) = index.search_post(json!({ "q": "word" })).await;
+    assert_eq!(hit_ids(&response), vec![0, 1]);
+
+    // the search overrides the setting, the first defined synonym is kept between equally frequent ones
+    let (response, _code) =
+        index.search_post(json!({ "q": "word", "maxSynonymExpansions": 2 })).await;
+    assert_eq!(hit_ids(&response), vec![0, 1, 2]);
+    let (response, _code) = index.search_get("q=word&maxSynonymExpansions=0").await;
+    assert!(hit_ids(&response).is_empty());
+
+    let (task, _code) = index.update_settings(json!({ "maxSynonymExpansions": null })).await;
+    index.wait_task(task.uid()).await.succeeded();
+    let (response, _code) = index.settings().await;
+    snapshot!(response["maxSynonymExpansions"], @"50");
+}
+
+#[actix_rt::test]
+async fn max_synonym_expansions_errors() {
+    let server = Server::new_shared();
+    let index = server.unique_index();
+
+    let (response, code) = index.update_settings(json!({ "maxSynonymExpansions": "many" })).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "Invalid value type at `.maxSynonymExpansions`: expected a positive integer, but found a string: `\"many\"`",
+      "code": "invalid_settings_max_synonym_expansions",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_settings_max_synonym_expansions"
+    }
+    "###);
+
+    let (response, code) = index.search_post(json!({ "maxSynonymExpansions": "many" })).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "Invalid value type at `.maxSynonymExpansions`: expected a positive integer, but found a string: `\"many\"`",
+      "code": "invalid_search_max_synonym_expansions",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_search_max_synonym_expansions"
+    }
+    "###);
+}
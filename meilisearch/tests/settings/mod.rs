@@ -1,5 +1,19 @@
+mod attributes_prefix_search;
+mod batch;
+mod blocked_documents;
+mod decimal_attributes;
 mod distinct;
+mod document_compression;
 mod errors;
+mod estimate;
+mod export_import;
+mod facet_normalization;
 mod get_settings;
+mod max_synonym_expansions;
 mod proximity_settings;
+mod read_only;
+mod store_max_length;
 mod tokenizer_customization;
+mod tokenizer_profiles;
+mod user_metadata;
+mod validate;
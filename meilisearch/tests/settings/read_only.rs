@@ -0,0 +1,58 @@
+use crate::common::Server;
+use crate::json;
+
+#[actix_rt::test]
+async fn read_only_index_refuses_writes() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (task, _code) =
+        index.add_documents(json!([{ "id": 1, "title": "Captain Marvel" }]), None).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (task, _code) = index.update_settings(json!({ "readOnly": true })).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (response, _) = index.settings().await;
+    assert_eq!(response["readOnly"], json!(true));
+
+    // the documents and the settings can't be modified...
+    let (response, code) = index.add_documents(json!([{ "id": 2, "title": "Shazam" }]), None).await;
+    meili_snap::snapshot!(code, @"403 Forbidden");
+    meili_snap::snapshot!(meili_snap::json_string!(response), @r###"
+    {
+      "message": "Index `test` is read-only. Disable its `readOnly` setting to modify its documents or its settings.",
+      "code": "index_read_only",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#index_read_only"
+    }
+    "###);
+
+    let (_response, code) = index.delete_document(1).await;
+    meili_snap::snapshot!(code, @"403 Forbidden");
+
+    let (_response, code) =
+        index.update_settings(json!({ "searchableAttributes": ["title"] })).await;
+    meili_snap::snapshot!(code, @"403 Forbidden");
+
+    // ...but the index can still be searched
+    let (response, code) = index.search_post(json!({ "q": "marvel" })).await;
+    meili_snap::snapshot!(code, @"200 OK");
+    meili_snap::snapshot!(meili_snap::json_string!(response["hits"]), @r###"
+    [
+      {
+        "id": 1,
+        "title": "Captain Marvel"
+      }
+    ]
+    "###);
+
+    // once the flag is disabled, the index accepts writes again
+    let (task, code) = index.update_settings(json!({ "readOnly": false })).await;
+    meili_snap::snapshot!(code, @"202 Accepted");
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (task, code) = index.add_documents(json!([{ "id": 2, "title": "Shazam" }]), None).await;
+    meili_snap::snapshot!(code, @"202 Accepted");
+    index.wait_task(task.uid()).await.succeeded();
+}
@@ -0,0 +1,82 @@
+use meili_snap::{json_string, snapshot};
+
+use crate::common::Server;
+use crate::json;
+
+#[actix_rt::test]
+async fn stored_values_are_truncated_but_fully_indexed() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (task, _code) =
+        index.update_settings(json!({ "storeMaxLength": { "fullText": 20 } })).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let documents = json!([
+        {
+            "id": 1,
+            "title": "Carol",
+            "fullText": "Thérèse Belivet is a young woman working at a department store in Manhattan"
+        },
+        { "id": 2, "title": "Wonder Woman", "fullText": "Diana is an Amazon" }
+    ]);
+    let (task, _code) = index.add_documents(documents, None).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    // only the first characters of the long value are stored
+    let (response, code) = index.get_document(1, None).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response), @r###"
+    {
+      "id": 1,
+      "title": "Carol",
+      "fullText": "Thérèse Belivet is a"
+    }
+    "###);
+    let (response, _code) = index.get_document(2, None).await;
+    snapshot!(response["fullText"], @r###""Diana is an Amazon""###);
+
+    // but the truncated tail of the value still matches
+    index
+        .search(json!({ "q": "manhattan" }), |response, code| {
+            snapshot!(code, @"200 OK");
+            snapshot!(json_string!(response["hits"]), @r###"
+            [
+              {
+                "id": 1,
+                "title": "Carol",
+                "fullText": "Thérèse Belivet is a"
+              }
+            ]
+            "###);
+        })
+        .await;
+
+    let (response, _code) = index.settings().await;
+    snapshot!(json_string!(response["storeMaxLength"]), @r###"
+    {
+      "fullText": 20
+    }
+    "###);
+
+    // the tail still matches after a reindex, as the full value is stored
+    let (task, _code) =
+        index.update_settings(json!({ "searchableAttributes": ["fullText", "title"] })).await;
+    index.wait_task(task.uid()).await.succeeded();
+    let (response, _code) = index.search_post(json!({ "q": "manhattan" })).await;
+    snapshot!(response["hits"][0]["id"], @"1");
+
+    // replacing the document removes the words of its tail
+    let (task, _code) = index
+        .add_documents(json!([{ "id": 1, "fullText": "Therese is a photographer" }]), None)
+        .await;
+    index.wait_task(task.uid()).await.succeeded();
+    let (response, _code) = index.search_post(json!({ "q": "manhattan" })).await;
+    snapshot!(json_string!(response["hits"]), @"[]");
+
+    // the full values are returned again once the setting is reset
+    let (task, _code) = index.update_settings(json!({ "storeMaxLength": null })).await;
+    index.wait_task(task.uid()).await.succeeded();
+    let (response, _code) = index.get_document(1, None).await;
+    snapshot!(response["fullText"], @r###""Therese is a photographer""###);
+}
@@ -0,0 +1,63 @@
+use meili_snap::{json_string, snapshot};
+
+use crate::common::Server;
+use crate::json;
+
+#[actix_rt::test]
+async fn keyword_matches_only_the_whole_value() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (task, _code) =
+        index.update_settings(json!({ "tokenizerProfiles": { "email": "keyword" } })).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (task, _code) = index
+        .add_documents(
+            json!([
+                { "id": 1, "email": "john.doe@example.com" },
+                { "id": 2, "email": "jane.doe@example.org" },
+            ]),
+            None,
+        )
+        .await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (response, code) = index.search_post(json!({ "q": "Jane.Doe@Example.org" })).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response["hits"]), @r###"
+    [
+      {
+        "id": 2,
+        "email": "jane.doe@example.org"
+      }
+    ]
+    "###);
+
+    // the fragments of the address don't match
+    for q in ["doe", "example", "example.com", "doe@example.com"] {
+        let (response, code) = index.search_post(json!({ "q": q })).await;
+        snapshot!(code, @"200 OK");
+        assert_eq!(response["hits"], json!([]), "{q} matched a fragment of the address");
+    }
+
+    // changing the profile reindexes the attribute
+    let (task, _code) =
+        index.update_settings(json!({ "tokenizerProfiles": { "email": "default" } })).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (response, code) = index.search_post(json!({ "q": "doe" })).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response["hits"]), @r###"
+    [
+      {
+        "id": 1,
+        "email": "john.doe@example.com"
+      },
+      {
+        "id": 2,
+        "email": "jane.doe@example.org"
+      }
+    ]
+    "###);
+}
@@ -0,0 +1,52 @@
+use meili_snap::{json_string, snapshot};
+
+use crate::common::Server;
+use crate::json;
+
+#[actix_rt::test]
+async fn user_metadata_is_stored_and_returned_with_the_index() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let metadata = json!({ "owner": "search-team", "schemaVersion": 3, "tags": ["movies"] });
+    let (task, _code) = index.update_settings(json!({ "userMetadata": metadata })).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (response, code) = index.get().await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response["userMetadata"]), @r###"
+    {
+      "owner": "search-team",
+      "schemaVersion": 3,
+      "tags": [
+        "movies"
+      ]
+    }
+    "###);
+    let (response, _code) = index.settings().await;
+    assert_eq!(response["userMetadata"], metadata);
+
+    // an update of an unrelated setting keeps the metadata
+    let (task, _code) = index.update_settings(json!({ "searchableAttributes": ["title"] })).await;
+    index.wait_task(task.uid()).await.succeeded();
+    let (response, _code) = index.get().await;
+    assert_eq!(response["userMetadata"], metadata);
+
+    // once reset, the metadata is no longer returned with the index
+    let (task, _code) = index.update_settings(json!({ "userMetadata": null })).await;
+    index.wait_task(task.uid()).await.succeeded();
+    let (response, _code) = index.get().await;
+    assert!(response.get("userMetadata").is_none());
+}
+
+#[actix_rt::test]
+async fn user_metadata_size_is_capped() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let metadata = json!({ "blob": "a".repeat(64 * 1024) });
+    let (task, _code) = index.update_settings(json!({ "userMetadata": metadata })).await;
+    let response = index.wait_task(task.uid()).await;
+    snapshot!(response["status"], @r###""failed""###);
+    snapshot!(response["error"]["code"], @r###""invalid_settings_user_metadata""###);
+}
@@ -0,0 +1,92 @@
+use meili_snap::{json_string, snapshot};
+
+use crate::common::Server;
+use crate::json;
+
+#[actix_rt::test]
+async fn validate_valid_settings() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (task, _code) = index.create(None).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (response, code) = index
+        .validate_settings(json!({
+            "searchableAttributes": ["title"],
+            "filterableAttributes": ["genres"],
+        }))
+        .await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response), @r###"
+    {
+      "valid": true
+    }
+    "###);
+
+    // the validated settings are not applied
+    let (settings, _code) = index.settings().await;
+    snapshot!(json_string!(settings["searchableAttributes"]), @r###"
+    [
+      "*"
+    ]
+    "###);
+    snapshot!(json_string!(settings["filterableAttributes"]), @"[]");
+
+    // no task was enqueued
+    let (tasks, _code) = index.list_tasks().await;
+    snapshot!(tasks["results"].as_array().unwrap().len(), @"1");
+}
+
+#[actix_rt::test]
+async fn validate_invalid_settings() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (task, _code) = index.create(None).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    // checked by the engine, the same error as the one of the settings update task
+    let (response, code) = index
+        .validate_settings(json!({ "reranker": { "url": "http://localhost", "candidates": 5000 } }))
+        .await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "`reranker` setting is invalid. `candidates` must be between 1 and 1000, found 5000.",
+      "code": "invalid_settings_reranker",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_settings_reranker"
+    }
+    "###);
+
+    // checked by the route
+    let (response, code) = index.validate_settings(json!({ "rankingRules": ["doggo"] })).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(response["code"], @r###""invalid_settings_ranking_rules""###);
+}
+
+#[actix_rt::test]
+async fn validate_settings_of_missing_index() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (response, code) =
+        index.validate_settings(json!({ "searchableAttributes": ["title"] })).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response), @r###"
+    {
+      "valid": true
+    }
+    "###);
+
+    let (response, code) = index
+        .validate_settings(json!({ "reranker": { "url": "http://localhost", "candidates": 5000 } }))
+        .await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(response["code"], @r###""invalid_settings_reranker""###);
+
+    // the index is not created
+    let (_response, code) = index.get().await;
+    snapshot!(code, @"404 Not Found");
+}
@@ -148,13 +148,14 @@ async fn perform_on_demand_snapshot() {
     }
     "###);
     let task = index.wait_task(task.uid()).await;
-    snapshot!(json_string!(task, { ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]", ".duration" => "[duration]" }), @r###"
+    snapshot!(json_string!(task, { ".batchUid" => "[batch_uid]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]", ".duration" => "[duration]" }), @r###"
     {
       "uid": 4,
       "indexUid": null,
       "status": "succeeded",
       "type": "snapshotCreation",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "error": null,
       "duration": "[duration]",
       "enqueuedAt": "[date]",
@@ -202,3 +203,271 @@ async fn perform_on_demand_snapshot() {
         server.index("doggo").settings(),
     );
 }
+
+#[actix_rt::test]
+#[cfg_attr(target_os = "windows", ignore)]
+async fn update_snapshot_settings_at_runtime() {
+    let temp = tempfile::tempdir().unwrap();
+    let snapshot_dir = tempfile::tempdir().unwrap();
+    let new_snapshot_dir = snapshot_dir.path().join("new");
+
+    let options =
+        Opt { snapshot_dir: snapshot_dir.path().to_owned(), ..default_settings(temp.path()) };
+    let server = Server::new_with_options(options).await.unwrap();
+
+    let (response, code) = server.snapshot_settings().await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response, { ".dir" => "[dir]" }), @r###"
+    {
+      "interval": null,
+      "dir": "[dir]",
+      "compressionLevel": 6,
+      "retained": 1,
+      "maxBytesPerSecond": null
+    }
+    "###);
+
+    let (response, code) = server
+        .update_snapshot_settings(json!({
+            "dir": new_snapshot_dir,
+            "compressionLevel": 1,
+            "retained": 2,
+            "maxBytesPerSecond": 104857600,
+        }))
+        .await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response, { ".dir" => "[dir]" }), @r###"
+    {
+      "interval": null,
+      "dir": "[dir]",
+      "compressionLevel": 1,
+      "retained": 2,
+      "maxBytesPerSecond": 104857600
+    }
+    "###);
+    assert_eq!(response["dir"].as_str().unwrap(), new_snapshot_dir.to_str().unwrap());
+
+    for _ in 0..3 {
+        let (task, code) = server.create_snapshot().await;
+        snapshot!(code, @"202 Accepted");
+        server.wait_task(task.uid()).await.succeeded();
+    }
+
+    // only the latest snapshot and the previous one are kept, in the new directory
+    let mut snapshots: Vec<String> = std::fs::read_dir(&new_snapshot_dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path().file_name().unwrap().to_str().unwrap().to_string())
+        .collect();
+    snapshots.sort();
+    meili_snap::snapshot!(format!("{snapshots:?}"), @r###"["db.snapshot", "db.snapshot.1"]"###);
+    assert_eq!(std::fs::read_dir(&snapshot_dir).unwrap().count(), 1);
+
+    // retaining a single snapshot removes the previous ones on the next snapshot
+    let (_response, code) = server.update_snapshot_settings(json!({ "retained": 1 })).await;
+    snapshot!(code, @"200 OK");
+    let (task, _code) = server.create_snapshot().await;
+    server.wait_task(task.uid()).await.succeeded();
+    let snapshots: Vec<String> = std::fs::read_dir(&new_snapshot_dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path().file_name().unwrap().to_str().unwrap().to_string())
+        .collect();
+    meili_snap::snapshot!(format!("{snapshots:?}"), @r###"["db.snapshot"]"###);
+
+    // scheduling the snapshots at runtime
+    let (response, code) = server.update_snapshot_settings(json!({ "interval": 1 })).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(response["interval"], @"1");
+
+    let next_task = task.uid() + 1;
+    let now = std::time::Instant::now();
+    loop {
+        let (value, code) = server.get_task(next_task).await;
+        if code != 404 && value["status"].as_str() == Some("succeeded") {
+            snapshot!(value["type"], @r###""snapshotCreation""###);
+            break;
+        }
+        if now.elapsed() > Duration::from_secs(30) {
+            panic!("The snapshot didn't schedule in 30s even though it was supposed to be scheduled every second: {}",
+                serde_json::to_string_pretty(&value).unwrap()
+            );
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+
+    let (response, code) = server.update_snapshot_settings(json!({ "interval": null })).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(response["interval"], @"null");
+}
+
+#[actix_rt::test]
+#[cfg_attr(target_os = "windows", ignore)]
+async fn updated_snapshot_settings_survive_a_restart() {
+    let temp = tempfile::tempdir().unwrap();
+    let snapshot_dir = tempfile::tempdir().unwrap();
+
+    let options =
+        Opt { snapshot_dir: snapshot_dir.path().to_owned(), ..default_settings(temp.path()) };
+    let server = Server::new_with_options(options.clone()).await.unwrap();
+
+    let (_response, code) =
+        server.update_snapshot_settings(json!({ "compressionLevel": 1, "retained": 3 })).await;
+    snapshot!(code, @"200 OK");
+    drop(server);
+
+    // the updated settings take precedence over the launch options
+    let server = Server::new_with_options(options).await.unwrap();
+    let (response, code) = server.snapshot_settings().await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response, { ".dir" => "[dir]" }), @r###"
+    {
+      "interval": null,
+      "dir": "[dir]",
+      "compressionLevel": 1,
+      "retained": 3,
+      "maxBytesPerSecond": null
+    }
+    "###);
+}
+
+#[actix_rt::test]
+#[cfg_attr(target_os = "windows", ignore)]
+async fn import_uploaded_snapshot() {
+    let temp = tempfile::tempdir().unwrap();
+    let snapshot_dir = tempfile::tempdir().unwrap();
+    let options =
+        Opt { snapshot_dir: snapshot_dir.path().to_owned(), ..default_settings(temp.path()) };
+    let server = Server::new_with_options(options).await.unwrap();
+
+    let pets = server.index("pets");
+    let (task, _code) = pets.add_documents(json!([{ "id": 0, "name": "kefir" }]), None).await;
+    pets.wait_task(task.uid()).await.succeeded();
+    let (task, code) = server.create_snapshot().await;
+    snapshot!(code, @"202 Accepted");
+    server.wait_task(task.uid()).await.succeeded();
+    let archive = std::fs::read(snapshot_dir.path().join("db.snapshot")).unwrap();
+
+    // the snapshot is imported into a running instance, next to its indexes
+    let server = Server::new().await;
+    let pets = server.index("pets");
+    let (task, _code) = pets.add_documents(json!([{ "id": 1, "name": "intel" }]), None).await;
+    pets.wait_task(task.uid()).await.succeeded();
+
+    let form = [
+        b"--doggo\r\nContent-Disposition: form-data; name=\"snapshot\"; filename=\"db.snapshot\"\r\n\r\n".as_slice(),
+        &archive,
+        b"\r\n--doggo--\r\n",
+    ]
+    .concat();
+    let req = actix_web::test::TestRequest::post()
+        .uri("/snapshots/import?namespace=uploaded")
+        .insert_header(("content-type", "multipart/form-data; boundary=doggo"))
+        .set_payload(form);
+    let (task, code) = server.service.request(req).await;
+    snapshot!(code, @"202 Accepted");
+    let task = server.wait_task(task.uid()).await;
+    snapshot!(task["type"], @r###""dumpImport""###);
+    snapshot!(task["status"], @r###""succeeded""###);
+    snapshot!(json_string!(task["details"]["importedIndexes"]), @r###"
+    [
+      "uploaded-pets"
+    ]
+    "###);
+
+    let (response, code) = server.index("uploaded-pets").search_post(json!({ "q": "kefir" })).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(response["hits"].as_array().unwrap().len(), @"1");
+    let (response, _code) = server.index("pets").search_post(json!({ "q": "intel" })).await;
+    snapshot!(response["hits"].as_array().unwrap().len(), @"1");
+
+    let req = actix_web::test::TestRequest::post()
+        .uri("/snapshots/import")
+        .insert_header(("content-type", "application/gzip"))
+        .set_payload(archive.clone());
+    let (response, code) = server.service.request(req).await;
+    snapshot!(code, @"409 Conflict");
+    snapshot!(response["code"], @r###""index_already_exists""###);
+
+    let req = actix_web::test::TestRequest::post()
+        .uri("/snapshots/import")
+        .insert_header(("content-type", "application/gzip"))
+        .set_payload("doggo");
+    let (response, code) = server.service.request(req).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(response["code"], @r###""invalid_snapshot_archive""###);
+}
+
+#[actix_rt::test]
+async fn update_snapshot_settings_bad_values() {
+    let server = Server::new().await;
+
+    let (response, code) = server.update_snapshot_settings(json!({ "interval": 0 })).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "`interval` must be a positive number of seconds, or `null` to disable the scheduled snapshots.",
+      "code": "invalid_snapshot_interval",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_snapshot_interval"
+    }
+    "###);
+
+    let (response, code) = server.update_snapshot_settings(json!({ "dir": "/" })).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "`dir` must be inside the snapshot directory of the launch options, found `/`.",
+      "code": "invalid_snapshot_dir",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_snapshot_dir"
+    }
+    "###);
+
+    let (response, code) = server.update_snapshot_settings(json!({ "dir": "../doggo" })).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "`dir` must be inside the snapshot directory of the launch options, found `../doggo`.",
+      "code": "invalid_snapshot_dir",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_snapshot_dir"
+    }
+    "###);
+
+    let (response, code) = server.update_snapshot_settings(json!({ "compressionLevel": 10 })).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "`compressionLevel` must be between 0 and 9, found 10.",
+      "code": "invalid_snapshot_compression_level",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_snapshot_compression_level"
+    }
+    "###);
+
+    let (response, code) = server.update_snapshot_settings(json!({ "retained": 0 })).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "`retained` must be at least 1, the latest snapshot is always kept.",
+      "code": "invalid_snapshot_retained",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_snapshot_retained"
+    }
+    "###);
+
+    let (response, code) = server.update_snapshot_settings(json!({ "maxBytesPerSecond": 0 })).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "`maxBytesPerSecond` must be a positive number of bytes, or `null` to stop throttling the snapshots.",
+      "code": "invalid_snapshot_max_bytes_per_second",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_snapshot_max_bytes_per_second"
+    }
+    "###);
+
+    // a refused patch changes nothing
+    let (response, code) = server.snapshot_settings().await;
+    snapshot!(code, @"200 OK");
+    snapshot!(response["retained"], @"1");
+}
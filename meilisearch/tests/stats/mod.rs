@@ -1,3 +1,5 @@
+mod search_stats;
+
 use time::format_description::well_known::Rfc3339;
 use time::OffsetDateTime;
 
@@ -22,6 +24,50 @@ async fn test_healthyness() {
     let (response, status_code) = server.service.get("/health").await;
     assert_eq!(status_code, 200);
     assert_eq!(response["status"], "available");
+    assert!(response.get("reasons").is_none());
+}
+
+#[actix_rt::test]
+async fn health_degraded_in_maintenance() {
+    let server = Server::new().await;
+
+    let (_, code) = server.set_maintenance(json!({ "enabled": true })).await;
+    assert_eq!(code, 200);
+
+    let (response, status_code) = server.service.get("/health").await;
+    assert_eq!(status_code, 200);
+    assert_eq!(response["status"], "degraded");
+    assert_eq!(response["reasons"], json!(["maintenanceMode"]));
+
+    let (_, code) = server.set_maintenance(json!({ "enabled": false })).await;
+    assert_eq!(code, 200);
+
+    let (response, status_code) = server.service.get("/health").await;
+    assert_eq!(status_code, 200);
+    assert_eq!(response["status"], "available");
+}
+
+#[actix_rt::test]
+async fn health_reasons_require_authorization() {
+    let mut server = Server::new_auth().await;
+    server.use_api_key("MASTER_KEY");
+
+    let (_, code) = server.set_maintenance(json!({ "enabled": true })).await;
+    assert_eq!(code, 200);
+
+    let (response, status_code) = server.service.get("/health").await;
+    assert_eq!(status_code, 200);
+    assert_eq!(response, json!({ "status": "degraded", "reasons": ["maintenanceMode"] }));
+
+    server.service.api_key = None;
+    let (response, status_code) = server.service.get("/health").await;
+    assert_eq!(status_code, 200);
+    assert_eq!(response, json!({ "status": "degraded" }));
+
+    server.use_api_key("wrong");
+    let (response, status_code) = server.service.get("/health").await;
+    assert_eq!(status_code, 200);
+    assert_eq!(response, json!({ "status": "degraded" }));
 }
 
 #[actix_rt::test]
@@ -41,6 +87,9 @@ async fn stats() {
     assert!(response["indexes"].get("test").is_some());
     assert_eq!(response["indexes"]["test"]["numberOfDocuments"], 0);
     assert!(response["indexes"]["test"]["isIndexing"] == false);
+    assert_eq!(response["searchQueue"]["capacity"], 1000);
+    assert_eq!(response["searchQueue"]["searchesRunning"], 0);
+    assert_eq!(response["searchQueue"]["searchesWaiting"], 0);
 
     let documents = json!([
         {
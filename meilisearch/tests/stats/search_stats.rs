@@ -0,0 +1,193 @@
+use std::time::Duration;
+
+use meili_snap::{json_string, snapshot};
+use meilisearch::Opt;
+use serde_json::Value;
+use tempfile::TempDir;
+
+use crate::common::{default_settings, Server, DOCUMENTS};
+use crate::json;
+
+/// The searches are recorded in the background, waits until the statistics match the condition.
+async fn wait_for_search_stats(
+    server: &Server,
+    query_parameters: &str,
+    condition: impl Fn(&Value) -> bool,
+) -> Value {
+    let now = std::time::Instant::now();
+    loop {
+        let (response, code) = server.search_stats(query_parameters).await;
+        assert_eq!(code, 200, "{response}");
+        if condition(&response) {
+            return response;
+        }
+        if now.elapsed() > Duration::from_secs(10) {
+            panic!("The searches were not recorded in 10s: {response}");
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+}
+
+#[actix_rt::test]
+async fn search_stats_are_disabled_by_default() {
+    let server = Server::new().await;
+
+    let (response, code) = server.search_stats("").await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "The search statistics are disabled. Use the `--experimental-search-stats-size` option to enable them.",
+      "code": "feature_not_enabled",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#feature_not_enabled"
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn search_stats_aggregate_the_searches() {
+    let temp = TempDir::new().unwrap();
+    let server = Server::new_with_options(Opt {
+        experimental_search_stats_size: 100,
+        ..default_settings(temp.path())
+    })
+    .await
+    .unwrap();
+    let index = server.index("test");
+    let (task, _code) = index.update_settings(json!({ "filterableAttributes": ["id"] })).await;
+    index.wait_task(task.uid()).await.succeeded();
+    let (task, _code) = index.add_documents(DOCUMENTS.clone(), None).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (response, code) = server.search_stats("").await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response), @r###"
+    {
+      "totalSearches": 0,
+      "zeroResultSearches": 0,
+      "zeroResultRate": 0.0,
+      "filteredSearches": 0,
+      "processingTimeMs": null,
+      "hitsCount": null,
+      "queryLength": null,
+      "topZeroResultQueries": [],
+      "oldestSearchAt": null
+    }
+    "###);
+
+    for query in [
+        json!({ "q": "glass" }),
+        json!({ "q": "", "filter": "id = 450465" }),
+        json!({ "q": "zzzz" }),
+        json!({ "q": " ZZZZ " }),
+        json!({ "q": "nothing matches this" }),
+    ] {
+        let (_response, code) = index.search_post(query).await;
+        snapshot!(code, @"200 OK");
+    }
+
+    let response = wait_for_search_stats(&server, "", |stats| stats["totalSearches"] == 5).await;
+    snapshot!(json_string!(response, { ".processingTimeMs" => "[duration]", ".oldestSearchAt" => "[date]" }), @r###"
+    {
+      "totalSearches": 5,
+      "zeroResultSearches": 3,
+      "zeroResultRate": 0.6,
+      "filteredSearches": 1,
+      "processingTimeMs": "[duration]",
+      "hitsCount": {
+        "p50": 0,
+        "p90": 1,
+        "p95": 1,
+        "p99": 1,
+        "max": 1
+      },
+      "queryLength": {
+        "p50": 4,
+        "p90": 20,
+        "p95": 20,
+        "p99": 20,
+        "max": 20
+      },
+      "topZeroResultQueries": [
+        {
+          "q": "zzzz",
+          "count": 2
+        },
+        {
+          "q": "nothing matches this",
+          "count": 1
+        }
+      ],
+      "oldestSearchAt": "[date]"
+    }
+    "###);
+
+    let (response, code) = server.search_stats("limit=1").await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response["topZeroResultQueries"]), @r###"
+    [
+      {
+        "q": "zzzz",
+        "count": 2
+      }
+    ]
+    "###);
+
+    let (response, code) = server.search_stats("limit=doggo").await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "Invalid value in parameter `limit`: could not parse `doggo` as a positive integer",
+      "code": "invalid_search_stats_limit",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_search_stats_limit"
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn search_stats_keep_the_most_recent_searches_of_the_requested_indexes() {
+    let temp = TempDir::new().unwrap();
+    let server = Server::new_with_options(Opt {
+        experimental_search_stats_size: 3,
+        ..default_settings(temp.path())
+    })
+    .await
+    .unwrap();
+    for uid in ["books", "movies"] {
+        let index = server.index(uid);
+        let (task, _code) = index.add_documents(DOCUMENTS.clone(), None).await;
+        index.wait_task(task.uid()).await.succeeded();
+    }
+
+    let searches = [("books", "zzzz"), ("books", "glass"), ("movies", "glass")];
+    for (recorded, (uid, q)) in searches.into_iter().enumerate() {
+        let (_response, code) = server.index(uid).search_post(json!({ "q": q })).await;
+        snapshot!(code, @"200 OK");
+        // waits for each search to be recorded before the next one, to know which one is dropped
+        wait_for_search_stats(&server, "", |stats| stats["totalSearches"] == recorded + 1).await;
+    }
+    let (_response, code) = server.index("books").search_post(json!({ "q": "yyyy" })).await;
+    snapshot!(code, @"200 OK");
+
+    // the first search was dropped
+    let response =
+        wait_for_search_stats(&server, "", |stats| stats["topZeroResultQueries"][0]["q"] == "yyyy")
+            .await;
+    snapshot!(response["totalSearches"], @"3");
+    snapshot!(json_string!(response["topZeroResultQueries"]), @r###"
+    [
+      {
+        "q": "yyyy",
+        "count": 1
+      }
+    ]
+    "###);
+
+    let (response, _code) = server.search_stats("indexUids=books").await;
+    snapshot!(response["totalSearches"], @"2");
+    snapshot!(response["zeroResultSearches"], @"1");
+    let (response, _code) = server.search_stats("indexUids=mov*").await;
+    snapshot!(response["totalSearches"], @"1");
+    snapshot!(response["zeroResultSearches"], @"0");
+}
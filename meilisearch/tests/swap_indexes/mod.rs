@@ -19,7 +19,7 @@ async fn swap_indexes() {
 
     let (tasks, code) = server.tasks().await;
     snapshot!(code, @"200 OK");
-    snapshot!(json_string!(tasks, { ".results[].duration" => "[duration]", ".results[].enqueuedAt" => "[date]", ".results[].startedAt" => "[date]", ".results[].finishedAt" => "[date]" }), @r###"
+    snapshot!(json_string!(tasks, { ".results[].batchUid" => "[batch_uid]", ".results[].duration" => "[duration]", ".results[].enqueuedAt" => "[date]", ".results[].startedAt" => "[date]", ".results[].finishedAt" => "[date]" }), @r###"
     {
       "results": [
         {
@@ -28,6 +28,7 @@ async fn swap_indexes() {
           "status": "succeeded",
           "type": "documentAdditionOrUpdate",
           "canceledBy": null,
+          "batchUid": "[batch_uid]",
           "details": {
             "receivedDocuments": 1,
             "indexedDocuments": 1
@@ -44,6 +45,7 @@ async fn swap_indexes() {
           "status": "succeeded",
           "type": "documentAdditionOrUpdate",
           "canceledBy": null,
+          "batchUid": "[batch_uid]",
           "details": {
             "receivedDocuments": 1,
             "indexedDocuments": 1
@@ -71,7 +73,7 @@ async fn swap_indexes() {
     snapshot!(code, @"200 OK");
 
     // Notice how the task 0 which was initially representing the creation of the index `A` now represents the creation of the index `B`.
-    snapshot!(json_string!(tasks, { ".results[].duration" => "[duration]", ".results[].enqueuedAt" => "[date]", ".results[].startedAt" => "[date]", ".results[].finishedAt" => "[date]" }), @r###"
+    snapshot!(json_string!(tasks, { ".results[].batchUid" => "[batch_uid]", ".results[].duration" => "[duration]", ".results[].enqueuedAt" => "[date]", ".results[].startedAt" => "[date]", ".results[].finishedAt" => "[date]" }), @r###"
     {
       "results": [
         {
@@ -80,6 +82,7 @@ async fn swap_indexes() {
           "status": "succeeded",
           "type": "indexSwap",
           "canceledBy": null,
+          "batchUid": "[batch_uid]",
           "details": {
             "swaps": [
               {
@@ -102,6 +105,7 @@ async fn swap_indexes() {
           "status": "succeeded",
           "type": "documentAdditionOrUpdate",
           "canceledBy": null,
+          "batchUid": "[batch_uid]",
           "details": {
             "receivedDocuments": 1,
             "indexedDocuments": 1
@@ -118,6 +122,7 @@ async fn swap_indexes() {
           "status": "succeeded",
           "type": "documentAdditionOrUpdate",
           "canceledBy": null,
+          "batchUid": "[batch_uid]",
           "details": {
             "receivedDocuments": 1,
             "indexedDocuments": 1
@@ -159,7 +164,7 @@ async fn swap_indexes() {
     // ensure the index creation worked properly
     let (tasks, code) = server.tasks_filter("limit=2").await;
     snapshot!(code, @"200 OK");
-    snapshot!(json_string!(tasks, { ".results[].duration" => "[duration]", ".results[].enqueuedAt" => "[date]", ".results[].startedAt" => "[date]", ".results[].finishedAt" => "[date]" }), @r###"
+    snapshot!(json_string!(tasks, { ".results[].batchUid" => "[batch_uid]", ".results[].duration" => "[duration]", ".results[].enqueuedAt" => "[date]", ".results[].startedAt" => "[date]", ".results[].finishedAt" => "[date]" }), @r###"
     {
       "results": [
         {
@@ -168,6 +173,7 @@ async fn swap_indexes() {
           "status": "succeeded",
           "type": "documentAdditionOrUpdate",
           "canceledBy": null,
+          "batchUid": "[batch_uid]",
           "details": {
             "receivedDocuments": 1,
             "indexedDocuments": 1
@@ -184,6 +190,7 @@ async fn swap_indexes() {
           "status": "succeeded",
           "type": "documentAdditionOrUpdate",
           "canceledBy": null,
+          "batchUid": "[batch_uid]",
           "details": {
             "receivedDocuments": 1,
             "indexedDocuments": 1
@@ -221,7 +228,7 @@ async fn swap_indexes() {
     // 2. stays unchanged
     // 3. now have the indexUid `d` instead of `c`
     // 4. now have the indexUid `c` instead of `d`
-    snapshot!(json_string!(tasks, { ".results[].duration" => "[duration]", ".results[].enqueuedAt" => "[date]", ".results[].startedAt" => "[date]", ".results[].finishedAt" => "[date]" }), @r###"
+    snapshot!(json_string!(tasks, { ".results[].batchUid" => "[batch_uid]", ".results[].duration" => "[duration]", ".results[].enqueuedAt" => "[date]", ".results[].startedAt" => "[date]", ".results[].finishedAt" => "[date]" }), @r###"
     {
       "results": [
         {
@@ -230,6 +237,7 @@ async fn swap_indexes() {
           "status": "succeeded",
           "type": "indexSwap",
           "canceledBy": null,
+          "batchUid": "[batch_uid]",
           "details": {
             "swaps": [
               {
@@ -258,6 +266,7 @@ async fn swap_indexes() {
           "status": "succeeded",
           "type": "documentAdditionOrUpdate",
           "canceledBy": null,
+          "batchUid": "[batch_uid]",
           "details": {
             "receivedDocuments": 1,
             "indexedDocuments": 1
@@ -274,6 +283,7 @@ async fn swap_indexes() {
           "status": "succeeded",
           "type": "documentAdditionOrUpdate",
           "canceledBy": null,
+          "batchUid": "[batch_uid]",
           "details": {
             "receivedDocuments": 1,
             "indexedDocuments": 1
@@ -290,6 +300,7 @@ async fn swap_indexes() {
           "status": "succeeded",
           "type": "indexSwap",
           "canceledBy": null,
+          "batchUid": "[batch_uid]",
           "details": {
             "swaps": [
               {
@@ -312,6 +323,7 @@ async fn swap_indexes() {
           "status": "succeeded",
           "type": "documentAdditionOrUpdate",
           "canceledBy": null,
+          "batchUid": "[batch_uid]",
           "details": {
             "receivedDocuments": 1,
             "indexedDocuments": 1
@@ -328,6 +340,7 @@ async fn swap_indexes() {
           "status": "succeeded",
           "type": "documentAdditionOrUpdate",
           "canceledBy": null,
+          "batchUid": "[batch_uid]",
           "details": {
             "receivedDocuments": 1,
             "indexedDocuments": 1
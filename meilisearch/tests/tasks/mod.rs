@@ -6,7 +6,9 @@ use meili_snap::snapshot;
 use time::format_description::well_known::Rfc3339;
 use time::OffsetDateTime;
 
-use crate::common::Server;
+use meilisearch::Opt;
+
+use crate::common::{default_settings, Server};
 use crate::json;
 
 #[actix_rt::test]
@@ -107,6 +109,62 @@ async fn list_tasks_with_star_filters() {
     assert_eq!(response["results"].as_array().unwrap().len(), 2);
 }
 
+#[actix_rt::test]
+async fn list_tasks_with_index_patterns() {
+    let server = Server::new().await;
+    for uid in ["movies-fr", "movies-en", "books"] {
+        let (task, _code) = server.index(uid).create(None).await;
+        server.wait_task(task.uid()).await.succeeded();
+    }
+
+    let (response, code) = server.service.get("/tasks?indexUids=movies-*").await;
+    assert_eq!(code, 200, "{:?}", response);
+    let index_uids: Vec<_> =
+        response["results"].as_array().unwrap().iter().map(|task| &task["indexUid"]).collect();
+    snapshot!(format!("{index_uids:?}"), @r###"[String("movies-en"), String("movies-fr")]"###);
+
+    let (response, code) = server.service.get("/tasks?indexUids=movies-*,books").await;
+    assert_eq!(code, 200, "{:?}", response);
+    assert_eq!(response["results"].as_array().unwrap().len(), 3);
+
+    let (response, code) = server.service.get("/tasks?indexUids=series-*").await;
+    assert_eq!(code, 200, "{:?}", response);
+    assert_eq!(response["results"].as_array().unwrap().len(), 0);
+}
+
+#[actix_rt::test]
+async fn finished_tasks_are_deleted_after_the_retention_period() {
+    let dir = tempfile::tempdir().unwrap();
+    let options = Opt { experimental_task_retention_days: Some(0), ..default_settings(dir.path()) };
+    let server = Server::new_with_options(options).await.unwrap();
+
+    let (task, code) = server.index("test").create(None).await;
+    assert_eq!(code, 202);
+    assert_eq!(task.uid(), 0);
+
+    // Once the index creation is finished, its deletion is enqueued right away.
+    let mut deletion = None;
+    for _ in 0..100 {
+        let (response, code) = server.get_task(1).await;
+        if code == 200 && response["status"] == "succeeded" {
+            deletion = Some(response);
+            break;
+        }
+        actix_rt::time::sleep(std::time::Duration::from_millis(100)).await;
+    }
+    let deletion = deletion.expect("the expired tasks were not deleted");
+    snapshot!(deletion["type"], @r###""taskDeletion""###);
+    snapshot!(deletion["details"]["deletedTasks"], @"1");
+
+    let (_response, code) = server.get_task(0).await;
+    snapshot!(code, @"404 Not Found");
+
+    // The deletion expires too, but no other deletion is enqueued just for it.
+    let (response, code) = server.service.get("/tasks").await;
+    assert_eq!(code, 200, "{:?}", response);
+    snapshot!(response["results"].as_array().unwrap().len(), @"1");
+}
+
 #[actix_rt::test]
 async fn list_tasks_status_filtered() {
     let server = Server::new().await;
@@ -201,7 +259,7 @@ async fn get_task_filter_error() {
     assert_eq!(code, 400, "{}", response);
     meili_snap::snapshot!(meili_snap::json_string!(response), @r###"
     {
-      "message": "Unknown parameter `lol`: expected one of `limit`, `from`, `uids`, `canceledBy`, `types`, `statuses`, `indexUids`, `afterEnqueuedAt`, `beforeEnqueuedAt`, `afterStartedAt`, `beforeStartedAt`, `afterFinishedAt`, `beforeFinishedAt`",
+      "message": "Unknown parameter `lol`: expected one of `limit`, `from`, `uids`, `canceledBy`, `batchUids`, `types`, `statuses`, `indexUids`, `afterEnqueuedAt`, `beforeEnqueuedAt`, `afterStartedAt`, `beforeStartedAt`, `afterFinishedAt`, `beforeFinishedAt`",
       "code": "bad_request",
       "type": "invalid_request",
       "link": "https://docs.meilisearch.com/errors#bad_request"
@@ -261,7 +319,7 @@ async fn delete_task_filter_error() {
     assert_eq!(code, 400, "{}", response);
     meili_snap::snapshot!(meili_snap::json_string!(response), @r###"
     {
-      "message": "Unknown parameter `lol`: expected one of `uids`, `canceledBy`, `types`, `statuses`, `indexUids`, `afterEnqueuedAt`, `beforeEnqueuedAt`, `afterStartedAt`, `beforeStartedAt`, `afterFinishedAt`, `beforeFinishedAt`",
+      "message": "Unknown parameter `lol`: expected one of `uids`, `canceledBy`, `batchUids`, `types`, `statuses`, `indexUids`, `afterEnqueuedAt`, `beforeEnqueuedAt`, `afterStartedAt`, `beforeStartedAt`, `afterFinishedAt`, `beforeFinishedAt`",
       "code": "bad_request",
       "type": "invalid_request",
       "link": "https://docs.meilisearch.com/errors#bad_request"
@@ -299,7 +357,7 @@ async fn cancel_task_filter_error() {
     assert_eq!(code, 400, "{}", response);
     meili_snap::snapshot!(meili_snap::json_string!(response), @r###"
     {
-      "message": "Unknown parameter `lol`: expected one of `uids`, `canceledBy`, `types`, `statuses`, `indexUids`, `afterEnqueuedAt`, `beforeEnqueuedAt`, `afterStartedAt`, `beforeStartedAt`, `afterFinishedAt`, `beforeFinishedAt`",
+      "message": "Unknown parameter `lol`: expected one of `uids`, `canceledBy`, `batchUids`, `types`, `statuses`, `indexUids`, `afterEnqueuedAt`, `beforeEnqueuedAt`, `afterStartedAt`, `beforeStartedAt`, `afterFinishedAt`, `beforeFinishedAt`",
       "code": "bad_request",
       "type": "invalid_request",
       "link": "https://docs.meilisearch.com/errors#bad_request"
@@ -369,7 +427,7 @@ async fn test_summarized_document_addition_or_update() {
     index.wait_task(0).await;
     let (task, _) = index.get_task(0).await;
     assert_json_snapshot!(task,
-        { ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" },
+        { ".batchUid" => "[batch_uid]", ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" },
         @r###"
     {
       "uid": 0,
@@ -377,6 +435,7 @@ async fn test_summarized_document_addition_or_update() {
       "status": "succeeded",
       "type": "documentAdditionOrUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "receivedDocuments": 1,
         "indexedDocuments": 1
@@ -393,7 +452,7 @@ async fn test_summarized_document_addition_or_update() {
     index.wait_task(1).await;
     let (task, _) = index.get_task(1).await;
     assert_json_snapshot!(task,
-        { ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" },
+        { ".batchUid" => "[batch_uid]", ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" },
         @r###"
     {
       "uid": 1,
@@ -401,6 +460,7 @@ async fn test_summarized_document_addition_or_update() {
       "status": "succeeded",
       "type": "documentAdditionOrUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "receivedDocuments": 1,
         "indexedDocuments": 1
@@ -422,7 +482,7 @@ async fn test_summarized_delete_documents_by_batch() {
     index.wait_task(0).await;
     let (task, _) = index.get_task(0).await;
     assert_json_snapshot!(task,
-        { ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" },
+        { ".batchUid" => "[batch_uid]", ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" },
         @r###"
     {
       "uid": 0,
@@ -430,6 +490,7 @@ async fn test_summarized_delete_documents_by_batch() {
       "status": "failed",
       "type": "documentDeletion",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "providedIds": 3,
         "deletedDocuments": 0,
@@ -453,7 +514,7 @@ async fn test_summarized_delete_documents_by_batch() {
     index.wait_task(2).await;
     let (task, _) = index.get_task(2).await;
     assert_json_snapshot!(task,
-        { ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" },
+        { ".batchUid" => "[batch_uid]", ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" },
         @r###"
     {
       "uid": 2,
@@ -461,6 +522,7 @@ async fn test_summarized_delete_documents_by_batch() {
       "status": "succeeded",
       "type": "documentDeletion",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "providedIds": 1,
         "deletedDocuments": 0,
@@ -484,7 +546,7 @@ async fn test_summarized_delete_documents_by_filter() {
     index.wait_task(0).await;
     let (task, _) = index.get_task(0).await;
     assert_json_snapshot!(task,
-        { ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" },
+        { ".batchUid" => "[batch_uid]", ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" },
         @r###"
     {
       "uid": 0,
@@ -492,6 +554,7 @@ async fn test_summarized_delete_documents_by_filter() {
       "status": "failed",
       "type": "documentDeletion",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "providedIds": 0,
         "deletedDocuments": 0,
@@ -515,7 +578,7 @@ async fn test_summarized_delete_documents_by_filter() {
     index.wait_task(2).await;
     let (task, _) = index.get_task(2).await;
     assert_json_snapshot!(task,
-        { ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" },
+        { ".batchUid" => "[batch_uid]", ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" },
         @r###"
     {
       "uid": 2,
@@ -523,6 +586,7 @@ async fn test_summarized_delete_documents_by_filter() {
       "status": "failed",
       "type": "documentDeletion",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "providedIds": 0,
         "deletedDocuments": 0,
@@ -546,7 +610,7 @@ async fn test_summarized_delete_documents_by_filter() {
     index.wait_task(4).await;
     let (task, _) = index.get_task(4).await;
     assert_json_snapshot!(task,
-        { ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" },
+        { ".batchUid" => "[batch_uid]", ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" },
         @r###"
     {
       "uid": 4,
@@ -554,6 +618,7 @@ async fn test_summarized_delete_documents_by_filter() {
       "status": "succeeded",
       "type": "documentDeletion",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "providedIds": 0,
         "deletedDocuments": 0,
@@ -576,7 +641,7 @@ async fn test_summarized_delete_document_by_id() {
     index.wait_task(0).await;
     let (task, _) = index.get_task(0).await;
     assert_json_snapshot!(task,
-        { ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" },
+        { ".batchUid" => "[batch_uid]", ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" },
         @r###"
     {
       "uid": 0,
@@ -584,6 +649,7 @@ async fn test_summarized_delete_document_by_id() {
       "status": "failed",
       "type": "documentDeletion",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "providedIds": 1,
         "deletedDocuments": 0,
@@ -607,7 +673,7 @@ async fn test_summarized_delete_document_by_id() {
     index.wait_task(2).await;
     let (task, _) = index.get_task(2).await;
     assert_json_snapshot!(task,
-        { ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" },
+        { ".batchUid" => "[batch_uid]", ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" },
         @r###"
     {
       "uid": 2,
@@ -615,6 +681,7 @@ async fn test_summarized_delete_document_by_id() {
       "status": "succeeded",
       "type": "documentDeletion",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "providedIds": 1,
         "deletedDocuments": 0,
@@ -649,7 +716,7 @@ async fn test_summarized_settings_update() {
     index.wait_task(0).await;
     let (task, _) = index.get_task(0).await;
     assert_json_snapshot!(task,
-        { ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" },
+        { ".batchUid" => "[batch_uid]", ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" },
         @r###"
     {
       "uid": 0,
@@ -657,6 +724,7 @@ async fn test_summarized_settings_update() {
       "status": "succeeded",
       "type": "settingsUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "displayedAttributes": [
           "doggos",
@@ -687,7 +755,7 @@ async fn test_summarized_index_creation() {
     index.wait_task(0).await;
     let (task, _) = index.get_task(0).await;
     assert_json_snapshot!(task,
-        { ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" },
+        { ".batchUid" => "[batch_uid]", ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" },
         @r###"
     {
       "uid": 0,
@@ -695,6 +763,7 @@ async fn test_summarized_index_creation() {
       "status": "succeeded",
       "type": "indexCreation",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "primaryKey": null
       },
@@ -710,7 +779,7 @@ async fn test_summarized_index_creation() {
     index.wait_task(1).await;
     let (task, _) = index.get_task(1).await;
     assert_json_snapshot!(task,
-        { ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" },
+        { ".batchUid" => "[batch_uid]", ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" },
         @r###"
     {
       "uid": 1,
@@ -718,6 +787,7 @@ async fn test_summarized_index_creation() {
       "status": "failed",
       "type": "indexCreation",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "primaryKey": "doggos"
       },
@@ -749,6 +819,7 @@ async fn test_summarized_index_deletion() {
       "status": "failed",
       "type": "indexDeletion",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "deletedDocuments": 0
       },
@@ -779,6 +850,7 @@ async fn test_summarized_index_deletion() {
       "status": "succeeded",
       "type": "documentAdditionOrUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "receivedDocuments": 1,
         "indexedDocuments": 1
@@ -801,6 +873,7 @@ async fn test_summarized_index_deletion() {
       "status": "succeeded",
       "type": "indexDeletion",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "deletedDocuments": 1
       },
@@ -823,6 +896,7 @@ async fn test_summarized_index_deletion() {
       "status": "failed",
       "type": "indexDeletion",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "deletedDocuments": 0
       },
@@ -849,7 +923,7 @@ async fn test_summarized_index_update() {
     index.wait_task(0).await;
     let (task, _) = index.get_task(0).await;
     assert_json_snapshot!(task,
-        { ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" },
+        { ".batchUid" => "[batch_uid]", ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" },
         @r###"
     {
       "uid": 0,
@@ -857,6 +931,7 @@ async fn test_summarized_index_update() {
       "status": "failed",
       "type": "indexUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "primaryKey": null
       },
@@ -877,7 +952,7 @@ async fn test_summarized_index_update() {
     index.wait_task(1).await;
     let (task, _) = index.get_task(1).await;
     assert_json_snapshot!(task,
-        { ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" },
+        { ".batchUid" => "[batch_uid]", ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" },
         @r###"
     {
       "uid": 1,
@@ -885,6 +960,7 @@ async fn test_summarized_index_update() {
       "status": "failed",
       "type": "indexUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "primaryKey": "bones"
       },
@@ -908,7 +984,7 @@ async fn test_summarized_index_update() {
     index.wait_task(3).await;
     let (task, _) = index.get_task(3).await;
     assert_json_snapshot!(task,
-        { ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" },
+        { ".batchUid" => "[batch_uid]", ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" },
         @r###"
     {
       "uid": 3,
@@ -916,6 +992,7 @@ async fn test_summarized_index_update() {
       "status": "succeeded",
       "type": "indexUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "primaryKey": null
       },
@@ -931,7 +1008,7 @@ async fn test_summarized_index_update() {
     index.wait_task(4).await;
     let (task, _) = index.get_task(4).await;
     assert_json_snapshot!(task,
-        { ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" },
+        { ".batchUid" => "[batch_uid]", ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" },
         @r###"
     {
       "uid": 4,
@@ -939,6 +1016,7 @@ async fn test_summarized_index_update() {
       "status": "succeeded",
       "type": "indexUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "primaryKey": "bones"
       },
@@ -962,7 +1040,7 @@ async fn test_summarized_index_swap() {
     server.wait_task(0).await;
     let (task, _) = server.get_task(0).await;
     assert_json_snapshot!(task,
-        { ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" },
+        { ".batchUid" => "[batch_uid]", ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" },
         @r###"
     {
       "uid": 0,
@@ -970,6 +1048,7 @@ async fn test_summarized_index_swap() {
       "status": "failed",
       "type": "indexSwap",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "swaps": [
           {
@@ -1003,7 +1082,7 @@ async fn test_summarized_index_swap() {
     server.wait_task(3).await;
     let (task, _) = server.get_task(3).await;
     assert_json_snapshot!(task,
-        { ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" },
+        { ".batchUid" => "[batch_uid]", ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" },
         @r###"
     {
       "uid": 3,
@@ -1011,6 +1090,7 @@ async fn test_summarized_index_swap() {
       "status": "succeeded",
       "type": "indexSwap",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "swaps": [
           {
@@ -1041,7 +1121,7 @@ async fn test_summarized_task_cancelation() {
     index.wait_task(1).await;
     let (task, _) = index.get_task(1).await;
     assert_json_snapshot!(task,
-        { ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" },
+        { ".batchUid" => "[batch_uid]", ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" },
         @r###"
     {
       "uid": 1,
@@ -1049,6 +1129,7 @@ async fn test_summarized_task_cancelation() {
       "status": "succeeded",
       "type": "taskCancelation",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "matchedTasks": 1,
         "canceledTasks": 0,
@@ -1074,7 +1155,7 @@ async fn test_summarized_task_deletion() {
     index.wait_task(1).await;
     let (task, _) = index.get_task(1).await;
     assert_json_snapshot!(task,
-        { ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" },
+        { ".batchUid" => "[batch_uid]", ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" },
         @r###"
     {
       "uid": 1,
@@ -1082,6 +1163,7 @@ async fn test_summarized_task_deletion() {
       "status": "succeeded",
       "type": "taskDeletion",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "matchedTasks": 1,
         "deletedTasks": 1,
@@ -1111,6 +1193,7 @@ async fn test_summarized_dump_creation() {
       "status": "succeeded",
       "type": "dumpCreation",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "dumpUid": "[dumpUid]"
       },
@@ -98,7 +98,7 @@ async fn test_basic_webhook() {
             nb_tasks += 1;
             let json: serde_json::Value = serde_json::from_str(json).unwrap();
             snapshot!(
-                json_string!(json, { ".uid" => "[uid]", ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" }),
+                json_string!(json, { ".batchUid" => "[batch_uid]", ".uid" => "[uid]", ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" }),
             @r###"
             {
               "uid": "[uid]",
@@ -106,6 +106,7 @@ async fn test_basic_webhook() {
               "status": "succeeded",
               "type": "documentAdditionOrUpdate",
               "canceledBy": null,
+              "batchUid": "[batch_uid]",
               "details": {
                 "receivedDocuments": 1,
                 "indexedDocuments": 1
@@ -127,3 +128,128 @@ async fn test_basic_webhook() {
 
     server_handle.abort();
 }
+
+#[actix_web::test]
+async fn webhook_registered_at_runtime() {
+    let WebhookHandle { server_handle, url, mut receiver } = create_webhook_server().await;
+
+    let server = Server::new().await;
+
+    let (response, code) = server.set_webhooks(json!({ "webhooks": [{ "url": url }] })).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response, { ".webhooks[0].url" => "[url]" }), @r###"
+    {
+      "webhooks": [
+        {
+          "url": "[url]"
+        }
+      ]
+    }
+    "###);
+    let (response, code) = server.get_webhooks().await;
+    snapshot!(code, @"200 OK");
+    assert_eq!(response["webhooks"][0]["url"], json!(url));
+
+    let index = server.index("tamo");
+    let (task, _status) = index.add_documents(json!({ "id": 1, "doggo": "bone" }), None).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let payload = receiver.recv().await.unwrap();
+    let payload = String::from_utf8(payload).unwrap();
+    let json: serde_json::Value = serde_json::from_str(payload.lines().next().unwrap()).unwrap();
+    assert_eq!(json["uid"], json!(task.uid()));
+    assert_eq!(json["status"], json!("succeeded"));
+
+    let (response, code) = server.set_webhooks(json!({ "webhooks": [] })).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response), @r###"
+    {
+      "webhooks": []
+    }
+    "###);
+
+    server_handle.abort();
+}
+
+#[actix_web::test]
+async fn webhook_authorization_is_hidden() {
+    let server = Server::new().await;
+
+    let webhooks = json!({ "webhooks": [
+        { "url": "http://localhost:7700/", "authorization": "Bearer a-very-secret-token" },
+    ]});
+    let (response, code) = server.set_webhooks(webhooks).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response), @r###"
+    {
+      "webhooks": [
+        {
+          "url": "http://localhost:7700/",
+          "authorization": "BeaXXXXX..."
+        }
+      ]
+    }
+    "###);
+    let (response, code) = server.get_webhooks().await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response), @r###"
+    {
+      "webhooks": [
+        {
+          "url": "http://localhost:7700/",
+          "authorization": "BeaXXXXX..."
+        }
+      ]
+    }
+    "###);
+}
+
+#[actix_web::test]
+async fn unresponsive_webhook_does_not_delay_the_batches() {
+    // the connections are accepted by the system but never answered
+    let listener = std::net::TcpListener::bind(("127.0.0.1", 0)).unwrap();
+    let url = format!("http://{}/", listener.local_addr().unwrap());
+
+    let server = Server::new().await;
+    let (_response, code) = server.set_webhooks(json!({ "webhooks": [{ "url": url }] })).await;
+    snapshot!(code, @"200 OK");
+
+    let index = server.index("tamo");
+    let now = std::time::Instant::now();
+    for i in 0..3 {
+        let (task, _status) = index.add_documents(json!({ "id": i, "doggo": "bone" }), None).await;
+        server.wait_task(task.uid()).await.succeeded();
+    }
+    // a single notification is waited for 30s before being retried
+    assert!(now.elapsed() < std::time::Duration::from_secs(20), "{:?}", now.elapsed());
+
+    drop(listener);
+}
+
+#[actix_web::test]
+async fn error_register_invalid_webhook() {
+    let server = Server::new().await;
+
+    let (response, code) = server.set_webhooks(json!({ "webhooks": [{ "url": "doggo" }] })).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "The webhook URL `doggo` is invalid: relative URL without a base.",
+      "code": "invalid_webhooks",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_webhooks"
+    }
+    "###);
+
+    let (response, code) =
+        server.set_webhooks(json!({ "webhooks": [{ "url": "ftp://localhost/" }] })).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "The webhook URL `ftp://localhost/` must use the `http` or `https` scheme.",
+      "code": "invalid_webhooks",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_webhooks"
+    }
+    "###);
+}
@@ -226,6 +226,7 @@ async fn user_provided_embeddings_error() {
       "status": "failed",
       "type": "documentAdditionOrUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "receivedDocuments": 1,
         "indexedDocuments": 0
@@ -255,6 +256,7 @@ async fn user_provided_embeddings_error() {
       "status": "failed",
       "type": "documentAdditionOrUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "receivedDocuments": 1,
         "indexedDocuments": 0
@@ -285,6 +287,7 @@ async fn user_provided_embeddings_error() {
       "status": "failed",
       "type": "documentAdditionOrUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "receivedDocuments": 1,
         "indexedDocuments": 0
@@ -314,6 +317,7 @@ async fn user_provided_embeddings_error() {
       "status": "failed",
       "type": "documentAdditionOrUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "receivedDocuments": 1,
         "indexedDocuments": 0
@@ -343,6 +347,7 @@ async fn user_provided_embeddings_error() {
       "status": "failed",
       "type": "documentAdditionOrUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "receivedDocuments": 1,
         "indexedDocuments": 0
@@ -372,6 +377,7 @@ async fn user_provided_embeddings_error() {
       "status": "failed",
       "type": "documentAdditionOrUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "receivedDocuments": 1,
         "indexedDocuments": 0
@@ -413,6 +419,7 @@ async fn user_provided_embeddings_error() {
       "status": "failed",
       "type": "documentAdditionOrUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "receivedDocuments": 1,
         "indexedDocuments": 0
@@ -441,6 +448,7 @@ async fn user_provided_embeddings_error() {
       "status": "failed",
       "type": "documentAdditionOrUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "receivedDocuments": 1,
         "indexedDocuments": 0
@@ -469,6 +477,7 @@ async fn user_provided_embeddings_error() {
       "status": "failed",
       "type": "documentAdditionOrUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "receivedDocuments": 1,
         "indexedDocuments": 0
@@ -509,6 +518,7 @@ async fn user_provided_vectors_error() {
       "status": "failed",
       "type": "documentAdditionOrUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "receivedDocuments": 5,
         "indexedDocuments": 0
@@ -538,6 +548,7 @@ async fn user_provided_vectors_error() {
       "status": "failed",
       "type": "documentAdditionOrUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "receivedDocuments": 1,
         "indexedDocuments": 0
@@ -567,6 +578,7 @@ async fn user_provided_vectors_error() {
       "status": "failed",
       "type": "documentAdditionOrUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "receivedDocuments": 1,
         "indexedDocuments": 0
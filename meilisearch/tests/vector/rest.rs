@@ -901,6 +901,7 @@ async fn bad_settings() {
       "status": "failed",
       "type": "settingsUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "embedders": {
           "rest": {
@@ -946,6 +947,7 @@ async fn bad_settings() {
       "status": "failed",
       "type": "documentAdditionOrUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "receivedDocuments": 1,
         "indexedDocuments": 0
@@ -995,6 +997,7 @@ async fn add_vector_and_user_provided() {
       "status": "succeeded",
       "type": "documentAdditionOrUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "receivedDocuments": 3,
         "indexedDocuments": 3
@@ -1091,6 +1094,7 @@ async fn server_returns_bad_request() {
       "status": "failed",
       "type": "settingsUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "embedders": {
           "rest": {
@@ -1130,6 +1134,7 @@ async fn server_returns_bad_request() {
       "status": "succeeded",
       "type": "settingsUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "embedders": {
           "rest": {
@@ -1159,6 +1164,7 @@ async fn server_returns_bad_request() {
       "status": "failed",
       "type": "documentAdditionOrUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "receivedDocuments": 1,
         "indexedDocuments": 0
@@ -1203,6 +1209,7 @@ async fn server_returns_bad_response() {
       "status": "failed",
       "type": "settingsUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "embedders": {
           "rest": {
@@ -1256,6 +1263,7 @@ async fn server_returns_bad_response() {
       "status": "failed",
       "type": "settingsUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "embedders": {
           "rest": {
@@ -1311,6 +1319,7 @@ async fn server_returns_bad_response() {
       "status": "failed",
       "type": "settingsUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "embedders": {
           "rest": {
@@ -1366,6 +1375,7 @@ async fn server_returns_bad_response() {
       "status": "failed",
       "type": "settingsUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "embedders": {
           "rest": {
@@ -1431,6 +1441,7 @@ async fn server_returns_bad_response() {
       "status": "failed",
       "type": "settingsUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "embedders": {
           "rest": {
@@ -1498,6 +1509,7 @@ async fn server_returns_multiple() {
       "status": "succeeded",
       "type": "documentAdditionOrUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "receivedDocuments": 3,
         "indexedDocuments": 3
@@ -1603,6 +1615,7 @@ async fn server_single_input_returns_in_array() {
       "status": "succeeded",
       "type": "documentAdditionOrUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "receivedDocuments": 3,
         "indexedDocuments": 3
@@ -1708,6 +1721,7 @@ async fn server_raw() {
       "status": "succeeded",
       "type": "documentAdditionOrUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "receivedDocuments": 3,
         "indexedDocuments": 3
@@ -1805,6 +1819,7 @@ async fn server_custom_header() {
       "status": "failed",
       "type": "settingsUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "embedders": {
           "rest": {
@@ -1844,6 +1859,7 @@ async fn server_custom_header() {
       "status": "failed",
       "type": "settingsUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "embedders": {
           "rest": {
@@ -1886,6 +1902,7 @@ async fn server_custom_header() {
       "status": "succeeded",
       "type": "settingsUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "embedders": {
           "rest": {
@@ -48,6 +48,7 @@ async fn update_embedder() {
       "status": "succeeded",
       "type": "settingsUpdate",
       "canceledBy": null,
+      "batchUid": "[batch_uid]",
       "details": {
         "embedders": {
           "manual": {
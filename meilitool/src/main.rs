@@ -282,7 +282,7 @@ fn export_a_dump(
         // 4.1. Dump the documents
         for ret in index.all_documents(&rtxn)? {
             let (_id, doc) = ret?;
-            let document = obkv_to_json(&all_fields, &fields_ids_map, doc)?;
+            let document = obkv_to_json(&all_fields, &fields_ids_map, doc.as_obkv())?;
             index_dumper.push_document(&document)?;
         }
 
@@ -0,0 +1,83 @@
+//! Normalize the values of the attributes of the documents before they are stored and indexed.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Number, Value};
+
+/// A transformation applied to the value of an attribute of the incoming documents.
+///
+/// The strings contained in arrays are transformed one by one,
+/// and the `null` values are left untouched.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum AttributeTransform {
+    /// Removes the leading and trailing whitespaces of the strings.
+    Trim,
+    /// Converts the strings to lowercase.
+    Lowercase,
+    /// Parses the strings as numbers.
+    ToNumber,
+}
+
+impl AttributeTransform {
+    /// Applies the transformation to a value.
+    ///
+    /// Returns `None` if the value cannot be transformed, e.g. a non-numeric string converted to a number.
+    pub fn apply(&self, value: Value) -> Option<Value> {
+        match (self, value) {
+            (_, Value::Null) => Some(Value::Null),
+            (_, Value::Array(values)) => values
+                .into_iter()
+                .map(|value| self.apply(value))
+                .collect::<Option<_>>()
+                .map(Value::Array),
+            (AttributeTransform::Trim, Value::String(s)) => {
+                let trimmed = s.trim();
+                Some(Value::String(if trimmed.len() == s.len() { s } else { trimmed.to_string() }))
+            }
+            (AttributeTransform::Lowercase, Value::String(s)) => {
+                Some(Value::String(s.to_lowercase()))
+            }
+            (AttributeTransform::ToNumber, Value::String(s)) => {
+                let s = s.trim();
+                match s.parse::<i64>() {
+                    Ok(n) => Some(Value::Number(n.into())),
+                    Err(_) => s.parse::<f64>().ok().and_then(Number::from_f64).map(Value::Number),
+                }
+            }
+            (AttributeTransform::ToNumber, value @ Value::Number(_)) => Some(value),
+            (AttributeTransform::ToNumber, _) => None,
+            (AttributeTransform::Trim | AttributeTransform::Lowercase, value) => Some(value),
+        }
+    }
+}
+
+impl fmt::Display for AttributeTransform {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AttributeTransform::Trim => f.write_str("trim"),
+            AttributeTransform::Lowercase => f.write_str("lowercase"),
+            AttributeTransform::ToNumber => f.write_str("toNumber"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::AttributeTransform::*;
+
+    #[test]
+    fn apply_transforms() {
+        assert_eq!(Trim.apply(json!("  AB-12 ")), Some(json!("AB-12")));
+        assert_eq!(Lowercase.apply(json!(["AB-12", "Cd"])), Some(json!(["ab-12", "cd"])));
+        assert_eq!(Lowercase.apply(json!(12)), Some(json!(12)));
+        assert_eq!(ToNumber.apply(json!(" 42 ")), Some(json!(42)));
+        assert_eq!(ToNumber.apply(json!("4.5")), Some(json!(4.5)));
+        assert_eq!(ToNumber.apply(json!(null)), Some(json!(null)));
+        assert_eq!(ToNumber.apply(json!("forty-two")), None);
+        assert_eq!(ToNumber.apply(json!(["1", true])), None);
+    }
+}
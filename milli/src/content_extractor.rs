@@ -0,0 +1,228 @@
+//! Extract the plain text of the attributes containing markup before they are tokenized.
+//!
+//! The documents are stored untouched, only the indexed text is extracted.
+
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+/// The format of the content of an attribute.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum ContentType {
+    Html,
+    Markdown,
+}
+
+impl ContentType {
+    /// Returns the default extractor of this content type.
+    pub fn extractor(&self) -> &'static dyn ContentExtractor {
+        match self {
+            ContentType::Html => &HtmlExtractor,
+            ContentType::Markdown => &MarkdownExtractor,
+        }
+    }
+}
+
+/// Turns the content of an attribute into the plain text to tokenize.
+pub trait ContentExtractor: Send + Sync {
+    fn extract<'a>(&self, content: &'a str) -> Cow<'a, str>;
+}
+
+/// The elements whose content is never displayed as text.
+const HTML_HIDDEN_ELEMENTS: &[&str] = &["script", "style", "template", "noscript"];
+
+/// The elements that separate their content from the surrounding text.
+const HTML_BLOCK_ELEMENTS: &[&str] = &[
+    "address",
+    "article",
+    "aside",
+    "blockquote",
+    "br",
+    "dd",
+    "div",
+    "dl",
+    "dt",
+    "figcaption",
+    "figure",
+    "footer",
+    "form",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "header",
+    "hr",
+    "li",
+    "main",
+    "nav",
+    "ol",
+    "p",
+    "pre",
+    "section",
+    "table",
+    "td",
+    "th",
+    "tr",
+    "ul",
+];
+
+/// Removes the tags, comments and hidden elements of an HTML content and decodes its entities.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HtmlExtractor;
+
+impl ContentExtractor for HtmlExtractor {
+    fn extract<'a>(&self, content: &'a str) -> Cow<'a, str> {
+        if !content.contains(['<', '&']) {
+            return Cow::Borrowed(content);
+        }
+
+        let mut output = String::with_capacity(content.len());
+        let mut rest = content;
+
+        while let Some(index) = rest.find(['<', '&']) {
+            output.push_str(&rest[..index]);
+            rest = &rest[index..];
+
+            if rest.starts_with('&') {
+                let (decoded, len) = decode_entity(rest);
+                output.push_str(decoded.as_deref().unwrap_or("&"));
+                rest = &rest[len..];
+            } else if let Some(comment) = rest.strip_prefix("<!--") {
+                rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+            } else if let Some(end) = rest.find('>') {
+                let tag = &rest[1..end];
+                rest = &rest[end + 1..];
+
+                let name = tag
+                    .trim_start_matches('/')
+                    .split(|c: char| c.is_whitespace() || c == '/')
+                    .next()
+                    .unwrap_or_default()
+                    .to_ascii_lowercase();
+
+                if !tag.starts_with('/') && HTML_HIDDEN_ELEMENTS.contains(&name.as_str()) {
+                    // skip everything up to the closing tag of the hidden element.
+                    let closing = format!("</{name}");
+                    rest = match rest.to_ascii_lowercase().find(&closing) {
+                        Some(start) => {
+                            rest[start..].find('>').map_or("", |end| &rest[start + end + 1..])
+                        }
+                        None => "",
+                    };
+                } else if HTML_BLOCK_ELEMENTS.contains(&name.as_str()) {
+                    output.push('\n');
+                }
+            } else {
+                // an unclosed tag is kept as text.
+                output.push_str(rest);
+                rest = "";
+            }
+        }
+
+        output.push_str(rest);
+        Cow::Owned(output)
+    }
+}
+
+/// Decodes the entity at the start of the text and returns it along with
+/// the length of the text it replaces.
+fn decode_entity(text: &str) -> (Option<String>, usize) {
+    let Some(end) = text[1..].find(';').map(|end| end + 1).filter(|end| *end <= 10) else {
+        return (None, 1);
+    };
+
+    let decoded = match &text[1..end] {
+        "amp" => Some('&'),
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "nbsp" => Some(' '),
+        entity => entity
+            .strip_prefix("#x")
+            .or_else(|| entity.strip_prefix("#X"))
+            .map(|hex| u32::from_str_radix(hex, 16))
+            .or_else(|| entity.strip_prefix('#').map(str::parse))
+            .and_then(Result::ok)
+            .and_then(char::from_u32),
+    };
+
+    match decoded {
+        Some(c) => (Some(c.to_string()), end + 1),
+        None => (None, 1),
+    }
+}
+
+/// Removes the link and image targets, the code fences and the inline HTML of a Markdown content.
+///
+/// The other Markdown markers are punctuation that the tokenizer already considers as separators.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MarkdownExtractor;
+
+impl ContentExtractor for MarkdownExtractor {
+    fn extract<'a>(&self, content: &'a str) -> Cow<'a, str> {
+        let mut output = String::with_capacity(content.len());
+
+        for line in content.lines() {
+            let trimmed = line.trim_start();
+            if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+                output.push('\n');
+                continue;
+            }
+
+            let mut rest = line;
+            // keep the text of the links and images but not their targets.
+            while let Some(start) = rest.find("](") {
+                match rest[start..].find(')') {
+                    Some(end) => {
+                        output.push_str(&rest[..start]);
+                        output.push(' ');
+                        rest = &rest[start + end + 1..];
+                    }
+                    None => break,
+                }
+            }
+            output.push_str(rest);
+            output.push('\n');
+        }
+
+        match HtmlExtractor.extract(&output) {
+            Cow::Borrowed(_) => Cow::Owned(output),
+            Cow::Owned(extracted) => Cow::Owned(extracted),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(text: &str) -> String {
+        text.split_whitespace().collect::<Vec<_>>().join(" ")
+    }
+
+    #[test]
+    fn extract_html() {
+        let html = r#"<div class="title">Hello <b>wor</b>ld</div><p>Tom &amp; Jerry&#33;</p>
+            <script>var hidden = "<div>";</script><STYLE>p { color: red }</STYLE><!-- a comment -->
+            <img src="kitten.png" alt="kitten"/>1 &lt; 2 &unknown; AT&T"#;
+        assert_eq!(
+            words(&HtmlExtractor.extract(html)),
+            "Hello world Tom & Jerry! 1 < 2 &unknown; AT&T"
+        );
+
+        assert!(matches!(HtmlExtractor.extract("plain text"), Cow::Borrowed("plain text")));
+    }
+
+    #[test]
+    fn extract_markdown() {
+        let markdown = "# Title\nSee [the docs](https://example.com/docs) and ![a kitten](kitten.png).\n```rust\nlet code = 1;\n```\n<em>inline</em> html";
+        assert_eq!(
+            words(&MarkdownExtractor.extract(markdown)),
+            "# Title See [the docs and ![a kitten . let code = 1; inline html"
+        );
+    }
+}
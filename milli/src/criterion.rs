@@ -45,6 +45,9 @@ pub enum Criterion {
     Asc(String),
     /// Sorted by the decreasing value of the field specified.
     Desc(String),
+    /// Sorted by the decreasing value of the recency attribute defined in the settings,
+    /// the most recent documents come first. Ignored when no recency attribute is defined.
+    Recency,
 }
 
 impl Criterion {
@@ -68,6 +71,7 @@ impl FromStr for Criterion {
             "attribute" => Ok(Criterion::Attribute),
             "sort" => Ok(Criterion::Sort),
             "exactness" => Ok(Criterion::Exactness),
+            "_recency" => Ok(Criterion::Recency),
             text => match AscDesc::from_str(text)? {
                 AscDesc::Asc(Member::Field(field)) => Ok(Criterion::Asc(field)),
                 AscDesc::Desc(Member::Field(field)) => Ok(Criterion::Desc(field)),
@@ -103,6 +107,7 @@ impl fmt::Display for Criterion {
             Exactness => f.write_str("exactness"),
             Asc(attr) => write!(f, "{}:asc", attr),
             Desc(attr) => write!(f, "{}:desc", attr),
+            Recency => f.write_str("_recency"),
         }
     }
 }
@@ -123,6 +128,7 @@ mod tests {
             ("attribute", Criterion::Attribute),
             ("sort", Criterion::Sort),
             ("exactness", Criterion::Exactness),
+            ("_recency", Criterion::Recency),
             ("price:asc", Criterion::Asc(S("price"))),
             ("price:desc", Criterion::Desc(S("price"))),
             ("price:asc:desc", Criterion::Desc(S("price:asc"))),
@@ -153,6 +159,7 @@ mod tests {
             ("price:aasc", InvalidName { name: S("price:aasc") }),
             ("price:asc and desc", InvalidName { name: S("price:asc and desc") }),
             ("price:asc:truc", InvalidName { name: S("price:asc:truc") }),
+            ("_recency:desc", InvalidName { name: S("_recency:desc") }),
             ("_geo:asc", ReservedName { name: S("_geo") }),
             ("_geoDistance:asc", ReservedName { name: S("_geoDistance") }),
             ("_geoPoint:asc", ReservedNameForSort { name: S("_geoPoint") }),
@@ -11,7 +11,7 @@ use thiserror::Error;
 
 use crate::documents::{self, DocumentsBatchCursorError};
 use crate::thread_pool_no_abort::PanicCatched;
-use crate::{CriterionError, DocumentId, FieldId, Object, SortError};
+use crate::{AttributeTransform, CriterionError, DocumentId, FieldId, Object, SortError};
 
 pub fn is_reserved_keyword(keyword: &str) -> bool {
     ["_geo", "_geoDistance", "_geoPoint", "_geoRadius", "_geoBoundingBox"].contains(&keyword)
@@ -103,12 +103,29 @@ pub enum UserError {
     CriterionError(#[from] CriterionError),
     #[error("Maximum number of documents reached.")]
     DocumentLimitReached,
+    #[error("The index cannot contain more than {max_documents} documents, the update would bring it to {number_of_documents} documents.")]
+    MaxDocumentsExceeded { max_documents: u64, number_of_documents: u64 },
+    #[error(
+        "There is no stemmer for the `{0}` language. The languages with a stemmer are: `eng`."
+    )]
+    UnsupportedStemmingLanguage(String),
     #[error(
         "Document identifier `{}` is invalid. \
 A document identifier can be of type integer or string, \
 only composed of alphanumeric characters (a-z A-Z 0-9), hyphens (-) and underscores (_).", .document_id.to_string()
     )]
     InvalidDocumentId { document_id: Value },
+    #[error("The document with the id: `{document_id}` appears more than once in the payload.")]
+    DuplicateDocumentId { document_id: String },
+    #[error("The `_etag` field in the document with the id: `{document_id}` is invalid. It must be a string, but received: `{value}`.")]
+    InvalidDocumentEtag { document_id: Value, value: Value },
+    #[error("The `{transform}` transform of the `{attribute}` attribute cannot be applied to the document with the id: `{document_id}`, it received: `{value}`.")]
+    InvalidDocumentAttributeTransform {
+        attribute: String,
+        transform: AttributeTransform,
+        document_id: String,
+        value: Value,
+    },
     #[error("Invalid facet distribution, {}", format_invalid_filter_distribution(.invalid_facets_name, .valid_facets_name))]
     InvalidFacetsDistribution {
         invalid_facets_name: BTreeSet<String>,
@@ -124,6 +141,8 @@ only composed of alphanumeric characters (a-z A-Z 0-9), hyphens (-) and undersco
     InvalidVectorsEmbedderConf { document_id: String, error: deserr::errors::JsonError },
     #[error("{0}")]
     InvalidFilter(String),
+    #[error("{0}")]
+    InvalidBoostingFilter(String),
     #[error("Invalid type for filter subexpression: expected: {}, found: {1}.", .0.join(", "))]
     InvalidFilterExpression(&'static [&'static str], Value),
     #[error("Attribute `{}` is not sortable. {}",
@@ -148,6 +167,17 @@ only composed of alphanumeric characters (a-z A-Z 0-9), hyphens (-) and undersco
         }
     )]
     InvalidDistinctAttribute { field: String, valid_fields: BTreeSet<String>, hidden_fields: bool },
+    #[error("Attribute `{}` is not filterable and thus, cannot be used to group the results. {}",
+        .field,
+        match .valid_fields.is_empty() {
+            true => "This index does not have configured filterable attributes.".to_string(),
+            false => format!("Available filterable attributes are: `{}{}`.",
+                    valid_fields.iter().map(AsRef::as_ref).collect::<Vec<&str>>().join(", "),
+                    .hidden_fields.then_some(", <..hidden-attributes>").unwrap_or(""),
+                ),
+        }
+    )]
+    InvalidGroupByAttribute { field: String, valid_fields: BTreeSet<String>, hidden_fields: bool },
     #[error("Attribute `{}` is not facet-searchable. {}",
         .field,
         match .valid_fields.is_empty() {
@@ -193,6 +223,8 @@ only composed of alphanumeric characters (a-z A-Z 0-9), hyphens (-) and undersco
     NoSpaceLeftOnDevice,
     #[error("Index already has a primary key: `{0}`.")]
     PrimaryKeyCannotBeChanged(String),
+    #[error("Cannot diff indexes with different primary keys: `{primary_key}` and `{other_primary_key}`.")]
+    MismatchingPrimaryKeys { primary_key: String, other_primary_key: String },
     #[error(transparent)]
     SerdeJson(serde_json::Error),
     #[error(transparent)]
@@ -201,6 +233,14 @@ only composed of alphanumeric characters (a-z A-Z 0-9), hyphens (-) and undersco
     UnknownInternalDocumentId { document_id: DocumentId },
     #[error("`minWordSizeForTypos` setting is invalid. `oneTypo` and `twoTypos` fields should be between `0` and `255`, and `twoTypos` should be greater or equals to `oneTypo` but found `oneTypo: {0}` and twoTypos: {1}`.")]
     InvalidMinTypoWordLenSetting(u8, u8),
+    #[error("`typoPenalty` setting is invalid. It should be between `1` and `255`.")]
+    InvalidTypoPenaltySetting,
+    #[error("`recencyHalfLifeDays` setting is invalid. It should be a positive number of days.")]
+    InvalidRecencyHalfLifeDaysSetting,
+    #[error("`reranker` setting is invalid. {0}")]
+    InvalidRerankerSetting(String),
+    #[error("`userMetadata` setting is invalid. It should not be larger than {max} bytes once serialized but found {size} bytes.")]
+    InvalidUserMetadataSize { size: usize, max: usize },
     #[error(transparent)]
     VectorEmbeddingError(#[from] crate::vector::Error),
     #[error(transparent)]
@@ -321,6 +361,8 @@ pub enum GeoError {
     BadLatitude { document_id: Value, value: Value },
     #[error("Could not parse longitude in the document with the id: `{document_id}`. Was expecting a finite number but instead got `{value}`.")]
     BadLongitude { document_id: Value, value: Value },
+    #[error("The `_geo` field in the document with the id: `{document_id}` is a GeoJSON `Point` with invalid coordinates. Was expecting an array of the form `[lng, lat]` but instead got `{value}`.")]
+    BadGeoJsonPoint { document_id: Value, value: Value },
 }
 
 fn format_invalid_filter_distribution(
@@ -1,7 +1,8 @@
 use std::collections::HashMap;
+use std::ops::Bound;
 
 use heed::types::Str;
-use heed::{Database, RoIter, RoTxn, RwTxn};
+use heed::{Database, RoIter, RoRange, RoTxn, RwTxn};
 
 use crate::{DocumentId, BEU32};
 
@@ -80,4 +81,13 @@ impl ExternalDocumentsIds {
     pub fn iter<'t>(&self, rtxn: &'t RoTxn<'_>) -> heed::Result<RoIter<'t, Str, BEU32>> {
         self.0.iter(rtxn)
     }
+
+    /// Returns an iterator over the external ids from the given one, in lexicographic order.
+    pub fn iter_from<'t>(
+        &self,
+        rtxn: &'t RoTxn<'_>,
+        from: &str,
+    ) -> heed::Result<RoRange<'t, Str, BEU32>> {
+        self.0.range(rtxn, &(Bound::Included(from), Bound::Unbounded))
+    }
 }
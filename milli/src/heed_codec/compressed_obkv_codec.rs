@@ -0,0 +1,123 @@
+use std::borrow::Cow;
+use std::io::{self, Read};
+
+use heed::BoxedError;
+use obkv::KvReaderU16;
+use zstd::bulk::Compressor;
+use zstd::dict::DecoderDictionary;
+
+use crate::FieldId;
+
+/// The zstd compression level of the documents.
+pub const DOCUMENT_COMPRESSION_LEVEL: i32 = 3;
+
+/// The first bytes of a zstd frame, the documents starting with them are stored compressed.
+///
+/// A raw obkv can't start with them: its first field id would be 10421 and the length
+/// of the value of this field more than 800MiB.
+const ZSTD_MAGIC_NUMBER: [u8; 4] = [0x28, 0xB5, 0x2F, 0xFD];
+
+/// The codec of the documents database, where every document is either a raw obkv
+/// or a compressed one.
+pub struct CompressedObkvCodec;
+
+impl<'a> heed::BytesDecode<'a> for CompressedObkvCodec {
+    type DItem = CompressedObkvU16<'a>;
+
+    fn bytes_decode(bytes: &'a [u8]) -> Result<Self::DItem, BoxedError> {
+        Ok(CompressedObkvU16(bytes))
+    }
+}
+
+impl heed::BytesEncode<'_> for CompressedObkvCodec {
+    type EItem = [u8];
+
+    fn bytes_encode(item: &Self::EItem) -> Result<Cow<'_, [u8]>, BoxedError> {
+        Ok(Cow::Borrowed(item))
+    }
+}
+
+/// A document as it is stored in the documents database.
+#[derive(Debug, Clone, Copy)]
+pub struct CompressedObkvU16<'a>(&'a [u8]);
+
+impl<'a> CompressedObkvU16<'a> {
+    pub fn is_compressed(&self) -> bool {
+        self.0.starts_with(&ZSTD_MAGIC_NUMBER)
+    }
+
+    /// Returns the document, only allocating when it is stored compressed.
+    ///
+    /// The dictionary must be the one of the index the document comes from.
+    pub fn decompress(
+        self,
+        dictionary: Option<&DecoderDictionary<'_>>,
+    ) -> io::Result<DocumentObkv<'a>> {
+        if !self.is_compressed() {
+            return Ok(DocumentObkv(Cow::Borrowed(self.0)));
+        }
+
+        let mut buffer = Vec::new();
+        match dictionary {
+            Some(dictionary) => {
+                zstd::stream::read::Decoder::with_prepared_dictionary(self.0, dictionary)?
+                    .read_to_end(&mut buffer)?
+            }
+            None => zstd::stream::read::Decoder::with_buffer(self.0)?.read_to_end(&mut buffer)?,
+        };
+        Ok(DocumentObkv(Cow::Owned(buffer)))
+    }
+}
+
+/// A document of the documents database, borrowed from it or decompressed.
+#[derive(Debug, Clone)]
+pub struct DocumentObkv<'a>(Cow<'a, [u8]>);
+
+impl DocumentObkv<'_> {
+    pub fn as_obkv(&self) -> KvReaderU16<'_> {
+        KvReaderU16::new(&self.0)
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.0.into_owned()
+    }
+
+    pub fn get(&self, field_id: FieldId) -> Option<&[u8]> {
+        KvReaderU16::new(&self.0).get(field_id)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (FieldId, &[u8])> + '_ {
+        KvReaderU16::new(&self.0).iter()
+    }
+}
+
+/// Compresses the documents written in an index with document compression enabled.
+pub struct DocumentCompressor(Compressor<'static>);
+
+impl DocumentCompressor {
+    /// The documents compressed with a dictionary can only be read back with this dictionary.
+    pub fn new(dictionary: Option<&[u8]>) -> io::Result<Self> {
+        let compressor = match dictionary {
+            Some(dictionary) => {
+                Compressor::with_dictionary(DOCUMENT_COMPRESSION_LEVEL, dictionary)?
+            }
+            None => Compressor::new(DOCUMENT_COMPRESSION_LEVEL)?,
+        };
+        Ok(DocumentCompressor(compressor))
+    }
+
+    /// Returns the document to store, the raw document is kept when compressing it doesn't
+    /// make it smaller.
+    pub fn compress<'a>(&mut self, document: &'a [u8]) -> io::Result<Cow<'a, [u8]>> {
+        let compressed = self.0.compress(document)?;
+        if compressed.len() < document.len() {
+            Ok(Cow::Owned(compressed))
+        } else {
+            Ok(Cow::Borrowed(document))
+        }
+    }
+}
@@ -1,16 +1,23 @@
 use std::borrow::Cow;
+use std::cmp::Ordering;
 use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::convert::TryInto;
 use std::fs::File;
+use std::hash::{Hash, Hasher};
 use std::path::Path;
+use std::sync::{Arc, Mutex};
 
+use charabia::Language;
 use heed::types::*;
 use heed::{CompactionOption, Database, RoTxn, RwTxn, Unspecified};
 use roaring::RoaringBitmap;
 use rstar::RTree;
 use serde::{Deserialize, Serialize};
 use time::OffsetDateTime;
+use zstd::dict::DecoderDictionary;
 
+use crate::attribute_transform::AttributeTransform;
+use crate::content_extractor::ContentType;
 use crate::documents::PrimaryKey;
 use crate::error::{InternalError, UserError};
 use crate::fields_ids_map::FieldsIdsMap;
@@ -18,30 +25,46 @@ use crate::heed_codec::facet::{
     FacetGroupKeyCodec, FacetGroupValueCodec, FieldDocIdFacetF64Codec, FieldDocIdFacetStringCodec,
     FieldIdCodec, OrderedF64Codec,
 };
-use crate::heed_codec::{BEU16StrCodec, FstSetCodec, StrBEU16Codec, StrRefCodec};
+use crate::heed_codec::{
+    BEU16StrCodec, CompressedObkvCodec, DocumentCompressor, DocumentObkv, FstSetCodec,
+    StrBEU16Codec, StrRefCodec,
+};
 use crate::order_by_map::OrderByMap;
+use crate::prefix_search::AttributePrefixSearch;
 use crate::proximity::ProximityPrecision;
+use crate::reranker::RerankerSettings;
+use crate::tokenizer_profile::{NumberTokenization, TokenizerProfile};
+use crate::update::del_add::DelAdd;
+use crate::update::{
+    DatabaseKind, DuplicateKeyStrategy, IndexerConfig, RebuildDatabase, RecompressDocuments,
+};
 use crate::vector::parsed_vectors::RESERVED_VECTORS_FIELD_NAME;
 use crate::vector::{Embedding, EmbeddingConfig};
 use crate::{
     default_criteria, CboRoaringBitmapCodec, Criterion, DocumentId, ExternalDocumentsIds,
-    FacetDistribution, FieldDistribution, FieldId, FieldIdMapMissingEntry, FieldIdWordCountCodec,
-    FieldidsWeightsMap, GeoPoint, LocalizedAttributesRule, ObkvCodec, Result, RoaringBitmapCodec,
-    RoaringBitmapLenCodec, Search, U8StrStrCodec, Weight, BEU16, BEU32, BEU64,
+    FacetDistribution, FacetNormalization, FieldDistribution, FieldId, FieldIdMapMissingEntry,
+    FieldIdWordCountCodec, FieldidsWeightsMap, GeoPoint, LocalizedAttributesRule,
+    PinnedResultsRule, Result, RoaringBitmapCodec, RoaringBitmapLenCodec, Search, U8StrStrCodec,
+    Weight, BEU16, BEU32, BEU64,
 };
 
 pub const DEFAULT_MIN_WORD_LEN_ONE_TYPO: u8 = 5;
 pub const DEFAULT_MIN_WORD_LEN_TWO_TYPOS: u8 = 9;
+pub const DEFAULT_TYPO_PENALTY: u8 = 1;
 
 pub mod main_key {
     pub const CRITERIA_KEY: &str = "criteria";
     pub const DISPLAYED_FIELDS_KEY: &str = "displayed-fields";
     pub const DISTINCT_FIELD_KEY: &str = "distinct-field-key";
+    pub const RECENCY_FIELD_KEY: &str = "recency-field-key";
+    pub const RECENCY_HALF_LIFE_DAYS_KEY: &str = "recency-half-life-days";
+    pub const RERANKER_KEY: &str = "reranker";
     pub const DOCUMENTS_IDS_KEY: &str = "documents-ids";
     pub const HIDDEN_FACETED_FIELDS_KEY: &str = "hidden-faceted-fields";
     pub const FILTERABLE_FIELDS_KEY: &str = "filterable-fields";
     pub const SORTABLE_FIELDS_KEY: &str = "sortable-fields";
     pub const FIELD_DISTRIBUTION_KEY: &str = "fields-distribution";
+    pub const FIELDS_VALUES_KEY: &str = "fields-values";
     pub const FIELDS_IDS_MAP_KEY: &str = "fields-ids-map";
     pub const FIELDIDS_WEIGHTS_MAP_KEY: &str = "fieldids-weights-map";
     pub const GEO_FACETED_DOCUMENTS_IDS_KEY: &str = "geo-faceted-documents-ids";
@@ -60,16 +83,49 @@ pub mod main_key {
     pub const CREATED_AT_KEY: &str = "created-at";
     pub const UPDATED_AT_KEY: &str = "updated-at";
     pub const AUTHORIZE_TYPOS: &str = "authorize-typos";
+    pub const SPLIT_AND_CONCAT_WORDS: &str = "split-and-concat-words";
     pub const ONE_TYPO_WORD_LEN: &str = "one-typo-word-len";
     pub const TWO_TYPOS_WORD_LEN: &str = "two-typos-word-len";
+    pub const TYPO_PENALTY: &str = "typo-penalty";
     pub const EXACT_WORDS: &str = "exact-words";
     pub const EXACT_ATTRIBUTES: &str = "exact-attributes";
+    pub const IGNORED_ATTRIBUTES: &str = "ignored-attributes";
+    pub const CONTENT_TYPES: &str = "content-types";
+    pub const TOKENIZER_PROFILES: &str = "tokenizer-profiles";
+    pub const PINNED_RESULTS: &str = "pinned-results";
+    pub const RESERVE_DOCUMENT_IDS: &str = "reserve-document-ids";
+    pub const RESERVED_DOCUMENTS_IDS_KEY: &str = "reserved-documents-ids";
+    pub const USER_METADATA: &str = "user-metadata";
+    pub const STORE_MAX_LENGTH: &str = "store-max-length";
+    pub const READ_ONLY: &str = "read-only";
+    pub const ATTRIBUTE_TRANSFORMS: &str = "attribute-transforms";
+    pub const DECIMAL_ATTRIBUTES: &str = "decimal-attributes";
+    pub const NORMALIZED_FACET_ATTRIBUTES: &str = "normalized-facet-attributes";
+    pub const FACET_CASE_FOLDING: &str = "facet-case-folding";
+    pub const BLOCKED_DOCUMENTS: &str = "blocked-documents";
+    pub const BLOCKED_DOCUMENTS_IDS_KEY: &str = "blocked-documents-ids";
+    pub const NUMBER_TOKENIZATION: &str = "number-tokenization";
+    pub const ENABLE_FORMATTING: &str = "enable-formatting";
+    pub const DOCUMENT_COMPRESSION: &str = "document-compression";
+    pub const DOCUMENT_COMPRESSION_DICTIONARY: &str = "document-compression-dictionary";
+    pub const ATTRIBUTES_PREFIX_SEARCH: &str = "attributes-prefix-search";
     pub const MAX_VALUES_PER_FACET: &str = "max-values-per-facet";
     pub const SORT_FACET_VALUES_BY: &str = "sort-facet-values-by";
     pub const PAGINATION_MAX_TOTAL_HITS: &str = "pagination-max-total-hits";
+    pub const MAX_SYNONYM_EXPANSIONS: &str = "max-synonym-expansions";
     pub const PROXIMITY_PRECISION: &str = "proximity-precision";
     pub const EMBEDDING_CONFIGS: &str = "embedding_configs";
     pub const SEARCH_CUTOFF: &str = "search_cutoff";
+    pub const MAX_FACET_VALUES_PER_ATTRIBUTE: &str = "max-facet-values-per-attribute";
+    pub const MAX_DOCUMENTS: &str = "max-documents";
+    pub const MAX_PREFIX_TYPOS: &str = "max-prefix-typos";
+    pub const MIN_PREFIX_LENGTH: &str = "min-prefix-length";
+    pub const STEMMING: &str = "stemming";
+    pub const MAX_FLATTEN_DEPTH: &str = "max-flatten-depth";
+    pub const DEFAULT_LANGUAGE: &str = "default-language";
+    pub const LANGUAGE_HINT_FIELD: &str = "language-hint-field";
+    pub const DUPLICATE_KEY_STRATEGY: &str = "duplicate-key-strategy";
+    pub const CAPPED_FACETED_FIELDS_KEY: &str = "capped-faceted-fields";
     pub const LOCALIZED_ATTRIBUTES_RULES: &str = "localized_attributes_rules";
 }
 
@@ -80,6 +136,7 @@ pub mod db_name {
     pub const WORD_PREFIX_DOCIDS: &str = "word-prefix-docids";
     pub const EXACT_WORD_PREFIX_DOCIDS: &str = "exact-word-prefix-docids";
     pub const EXTERNAL_DOCUMENTS_IDS: &str = "external-documents-ids";
+    pub const RESERVED_DOCUMENT_IDS: &str = "reserved-document-ids";
     pub const DOCID_WORD_POSITIONS: &str = "docid-word-positions";
     pub const WORD_PAIR_PROXIMITY_DOCIDS: &str = "word-pair-proximity-docids";
     pub const WORD_POSITION_DOCIDS: &str = "word-position-docids";
@@ -94,6 +151,7 @@ pub mod db_name {
     pub const FACET_ID_STRING_DOCIDS: &str = "facet-id-string-docids";
     pub const FACET_ID_NORMALIZED_STRING_STRINGS: &str = "facet-id-normalized-string-strings";
     pub const FACET_ID_STRING_FST: &str = "facet-id-string-fst";
+    pub const FACET_ID_STRING_ORIGINAL_COUNTS: &str = "facet-id-string-original-counts";
     pub const FIELD_ID_DOCID_FACET_F64S: &str = "field-id-docid-facet-f64s";
     pub const FIELD_ID_DOCID_FACET_STRINGS: &str = "field-id-docid-facet-strings";
     pub const VECTOR_EMBEDDER_CATEGORY_ID: &str = "vector-embedder-category-id";
@@ -101,6 +159,22 @@ pub mod db_name {
     pub const DOCUMENTS: &str = "documents";
 }
 
+/// The size of the pages used by the databases of an index, in bytes, grouped by what they store.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DatabasesSizes {
+    /// The documents themselves.
+    pub documents: u64,
+    /// The databases mapping the words, their prefixes, positions and proximities with the documents.
+    pub word_docids: u64,
+    /// The databases used to filter, sort and count the facet values.
+    pub facets: u64,
+    /// The embeddings of the documents and the ids of the embedders.
+    pub vectors: u64,
+    /// The main database and the external documents ids.
+    pub other: u64,
+}
+
 #[derive(Clone)]
 pub struct Index {
     /// The LMDB environment which this index is associated with.
@@ -112,6 +186,9 @@ pub struct Index {
     /// Maps the external documents ids with the internal document id.
     pub external_documents_ids: Database<Str, BEU32>,
 
+    /// Maps the external ids of the deleted documents with the internal id reserved for them.
+    pub reserved_document_ids: Database<Str, BEU32>,
+
     /// A word and all the documents ids containing the word.
     pub word_docids: Database<Str, CboRoaringBitmapCodec>,
 
@@ -152,6 +229,9 @@ pub struct Index {
     pub facet_id_string_docids: Database<FacetGroupKeyCodec<StrRefCodec>, FacetGroupValueCodec>,
     /// Maps the facet field id of the normalized-for-search string facets with their original versions.
     pub facet_id_normalized_string_strings: Database<BEU16StrCodec, SerdeJson<BTreeSet<String>>>,
+    /// Maps the facet field id and the normalized facet strings of the normalized facet attributes
+    /// to the number of documents of each of their original forms.
+    pub facet_id_string_original_counts: Database<BEU16StrCodec, SerdeJson<BTreeMap<String, u64>>>,
     /// Maps the facet field id of the string facets with an FST containing all the facets values.
     pub facet_id_string_fst: Database<BEU16, FstSetCodec>,
 
@@ -165,8 +245,11 @@ pub struct Index {
     /// Vector store based on arroy™.
     pub vector_arroy: arroy::Database<arroy::distances::Angular>,
 
-    /// Maps the document id to the document as an obkv store.
-    pub(crate) documents: Database<BEU32, ObkvCodec>,
+    /// The last dictionary built to decompress the documents, with the bytes it was built from.
+    decompression_dictionary: Arc<Mutex<Option<(Box<[u8]>, Arc<DecoderDictionary<'static>>)>>>,
+
+    /// Maps the document id to the document as an obkv store, compressed or not.
+    pub(crate) documents: Database<BEU32, CompressedObkvCodec>,
 }
 
 impl Index {
@@ -178,7 +261,17 @@ impl Index {
     ) -> Result<Index> {
         use db_name::*;
 
-        options.max_dbs(25);
+        options.max_dbs(26);
+
+        // An index created before the creation dates were tracked has no dates in its main
+        // database. For those, the modification date of the environment file is the best guess.
+        let path = path.as_ref();
+        let modified_at = path
+            .join("data.mdb")
+            .metadata()
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .map(OffsetDateTime::from);
 
         let env = unsafe { options.open(path) }?;
         let mut wtxn = env.write_txn()?;
@@ -186,6 +279,7 @@ impl Index {
         let word_docids = env.create_database(&mut wtxn, Some(WORD_DOCIDS))?;
         let external_documents_ids =
             env.create_database(&mut wtxn, Some(EXTERNAL_DOCUMENTS_IDS))?;
+        let reserved_document_ids = env.create_database(&mut wtxn, Some(RESERVED_DOCUMENT_IDS))?;
         let exact_word_docids = env.create_database(&mut wtxn, Some(EXACT_WORD_DOCIDS))?;
         let word_prefix_docids = env.create_database(&mut wtxn, Some(WORD_PREFIX_DOCIDS))?;
         let exact_word_prefix_docids =
@@ -206,6 +300,8 @@ impl Index {
         let facet_id_normalized_string_strings =
             env.create_database(&mut wtxn, Some(FACET_ID_NORMALIZED_STRING_STRINGS))?;
         let facet_id_string_fst = env.create_database(&mut wtxn, Some(FACET_ID_STRING_FST))?;
+        let facet_id_string_original_counts =
+            env.create_database(&mut wtxn, Some(FACET_ID_STRING_ORIGINAL_COUNTS))?;
         let facet_id_exists_docids =
             env.create_database(&mut wtxn, Some(FACET_ID_EXISTS_DOCIDS))?;
         let facet_id_is_null_docids =
@@ -224,12 +320,17 @@ impl Index {
         let documents = env.create_database(&mut wtxn, Some(DOCUMENTS))?;
         wtxn.commit()?;
 
+        let (created_at, updated_at) = match modified_at {
+            Some(modified_at) => (modified_at, modified_at),
+            None => (created_at, updated_at),
+        };
         Index::set_creation_dates(&env, main, created_at, updated_at)?;
 
         Ok(Index {
             env,
             main,
             external_documents_ids,
+            reserved_document_ids,
             word_docids,
             exact_word_docids,
             word_prefix_docids,
@@ -244,6 +345,7 @@ impl Index {
             facet_id_string_docids,
             facet_id_normalized_string_strings,
             facet_id_string_fst,
+            facet_id_string_original_counts,
             facet_id_exists_docids,
             facet_id_is_null_docids,
             facet_id_is_empty_docids,
@@ -252,6 +354,7 @@ impl Index {
             vector_arroy,
             embedder_category_id,
             documents,
+            decompression_dictionary: Arc::default(),
         })
     }
 
@@ -267,7 +370,8 @@ impl Index {
         updated_at: OffsetDateTime,
     ) -> heed::Result<()> {
         let mut txn = env.write_txn()?;
-        // The db was just created, we update its metadata with the relevant information.
+        // The db was just created or predates the creation dates, we update its metadata with the
+        // relevant information.
         let main = main.remap_types::<Str, SerdeJson<OffsetDateTime>>();
         if main.get(&txn, main_key::CREATED_AT_KEY)?.is_none() {
             main.put(&mut txn, main_key::UPDATED_AT_KEY, &updated_at)?;
@@ -307,6 +411,83 @@ impl Index {
         Ok(self.env.real_disk_size()?)
     }
 
+    /// Returns the size of the pages used by the databases of the index, grouped by what they store.
+    pub fn databases_sizes(&self, rtxn: &RoTxn) -> Result<DatabasesSizes> {
+        fn size(stat: heed::DatabaseStat) -> u64 {
+            let pages = stat.branch_pages + stat.leaf_pages + stat.overflow_pages;
+            pages as u64 * stat.page_size as u64
+        }
+
+        let Self {
+            env: _,
+            main,
+            external_documents_ids,
+            reserved_document_ids,
+            word_docids,
+            exact_word_docids,
+            word_prefix_docids,
+            exact_word_prefix_docids,
+            word_pair_proximity_docids,
+            word_position_docids,
+            word_fid_docids,
+            field_id_word_count_docids,
+            word_prefix_position_docids,
+            word_prefix_fid_docids,
+            facet_id_exists_docids,
+            facet_id_is_null_docids,
+            facet_id_is_empty_docids,
+            facet_id_f64_docids,
+            facet_id_string_docids,
+            facet_id_normalized_string_strings,
+            facet_id_string_original_counts,
+            facet_id_string_fst,
+            field_id_docid_facet_f64s,
+            field_id_docid_facet_strings,
+            embedder_category_id,
+            vector_arroy,
+            documents,
+        } = self;
+
+        let word_docids = [
+            word_docids.stat(rtxn)?,
+            exact_word_docids.stat(rtxn)?,
+            word_prefix_docids.stat(rtxn)?,
+            exact_word_prefix_docids.stat(rtxn)?,
+            word_pair_proximity_docids.stat(rtxn)?,
+            word_position_docids.stat(rtxn)?,
+            word_fid_docids.stat(rtxn)?,
+            field_id_word_count_docids.stat(rtxn)?,
+            word_prefix_position_docids.stat(rtxn)?,
+            word_prefix_fid_docids.stat(rtxn)?,
+        ];
+        let facets = [
+            facet_id_exists_docids.stat(rtxn)?,
+            facet_id_is_null_docids.stat(rtxn)?,
+            facet_id_is_empty_docids.stat(rtxn)?,
+            facet_id_f64_docids.stat(rtxn)?,
+            facet_id_string_docids.stat(rtxn)?,
+            facet_id_normalized_string_strings.stat(rtxn)?,
+            facet_id_string_original_counts.stat(rtxn)?,
+            facet_id_string_fst.stat(rtxn)?,
+            field_id_docid_facet_f64s.stat(rtxn)?,
+            field_id_docid_facet_strings.stat(rtxn)?,
+        ];
+        let vectors = [embedder_category_id.stat(rtxn)?, vector_arroy.stat(rtxn)?];
+        let other = [
+            main.stat(rtxn)?,
+            external_documents_ids.stat(rtxn)?,
+            reserved_document_ids.stat(rtxn)?,
+        ];
+
+        Ok(DatabasesSizes {
+            documents: size(documents.stat(rtxn)?),
+            word_docids: word_docids.into_iter().map(size).sum(),
+            facets: facets.into_iter().map(size).sum(),
+            vectors: vectors.into_iter().map(size).sum(),
+            other: other.into_iter().map(size).sum(),
+        })
+    }
+
     /// Returns the map size the underlying environment was opened with, in bytes.
     ///
     /// This value does not represent the current on-disk size of the index.
@@ -321,6 +502,50 @@ impl Index {
         self.env.copy_to_file(path, option).map_err(Into::into)
     }
 
+    /// Sequentially reads the databases the search uses the most to load them in the page cache.
+    ///
+    /// It only needs a read transaction and therefore never blocks the writes.
+    /// Returns the number of bytes read, or `None` if `must_stop` returned `true` before the end.
+    pub fn warmup(&self, rtxn: &RoTxn<'_>, must_stop: impl Fn() -> bool) -> Result<Option<u64>> {
+        /// Only a single byte of each page needs to be read for the page to be loaded.
+        const PAGE_SIZE: usize = 4096;
+
+        let databases = [
+            self.main.remap_types::<Bytes, Bytes>(),
+            self.external_documents_ids.remap_types(),
+            self.word_docids.remap_types(),
+            self.exact_word_docids.remap_types(),
+            self.word_prefix_docids.remap_types(),
+            self.exact_word_prefix_docids.remap_types(),
+            self.word_pair_proximity_docids.remap_types(),
+            self.word_position_docids.remap_types(),
+            self.word_fid_docids.remap_types(),
+            self.facet_id_exists_docids.remap_types(),
+            self.facet_id_f64_docids.remap_types(),
+            self.facet_id_string_docids.remap_types(),
+            self.documents.remap_types(),
+        ];
+
+        let mut bytes_read = 0;
+        let mut checksum = 0u8;
+        for database in databases {
+            for result in database.iter(rtxn)? {
+                if must_stop() {
+                    return Ok(None);
+                }
+                let (key, value) = result?;
+                for bytes in [key, value] {
+                    checksum = bytes.iter().step_by(PAGE_SIZE).fold(checksum, |acc, b| acc ^ b);
+                    bytes_read += bytes.len() as u64;
+                }
+            }
+        }
+        // prevents the compiler from optimizing away the reads
+        std::hint::black_box(checksum);
+
+        Ok(Some(bytes_read))
+    }
+
     /// Returns an `EnvClosingEvent` that can be used to wait for the closing event,
     /// multiple threads can wait on this event.
     ///
@@ -570,6 +795,52 @@ impl Index {
             .unwrap_or_default())
     }
 
+    /* fields values */
+
+    /// Writes the types and a sample of the values of every field, described by [`Index::schema`].
+    pub(crate) fn put_fields_values(
+        &self,
+        wtxn: &mut RwTxn<'_>,
+        fields_values: &FieldsValues,
+    ) -> heed::Result<()> {
+        self.main.remap_types::<Str, SerdeJson<FieldsValues>>().put(
+            wtxn,
+            main_key::FIELDS_VALUES_KEY,
+            fields_values,
+        )
+    }
+
+    /// Returns the types and a sample of the values of every field, recorded while indexing
+    /// the documents.
+    ///
+    /// They are computed from the stored documents when the documents were indexed by a version
+    /// that didn't record them.
+    pub fn fields_values(&self, rtxn: &RoTxn<'_>) -> Result<FieldsValues> {
+        if let Some(fields_values) = self
+            .main
+            .remap_types::<Str, SerdeJson<FieldsValues>>()
+            .get(rtxn, main_key::FIELDS_VALUES_KEY)?
+        {
+            return Ok(fields_values);
+        }
+
+        let fields_ids_map = self.fields_ids_map(rtxn)?;
+        let mut fields_values = FieldsValues::new();
+        for result in self.all_documents(rtxn)? {
+            let (_docid, obkv) = result?;
+            for (fid, value) in obkv.iter() {
+                let name = fields_ids_map.name(fid).ok_or(FieldIdMapMissingEntry::FieldId {
+                    field_id: fid,
+                    process: "fields values",
+                })?;
+                let value: serde_json::Value =
+                    serde_json::from_slice(value).map_err(InternalError::SerdeJson)?;
+                count_field_value(&mut fields_values, name, &value, DelAdd::Addition);
+            }
+        }
+        Ok(fields_values)
+    }
+
     /* displayed fields */
 
     /// Writes the fields that must be displayed in the defined order.
@@ -904,12 +1175,13 @@ impl Index {
     /// Returns the user defined faceted fields names.
     ///
     /// The user faceted fields are the union of all the filterable, sortable, distinct, and Asc/Desc fields.
+    /// The recency field is part of the Asc/Desc fields when the `_recency` ranking rule is used.
     pub fn user_defined_faceted_fields(&self, rtxn: &RoTxn<'_>) -> Result<HashSet<String>> {
         let filterable_fields = self.filterable_fields(rtxn)?;
         let sortable_fields = self.sortable_fields(rtxn)?;
         let distinct_field = self.distinct_field(rtxn)?;
         let asc_desc_fields =
-            self.criteria(rtxn)?.into_iter().filter_map(|criterion| match criterion {
+            self.resolved_criteria(rtxn)?.into_iter().filter_map(|criterion| match criterion {
                 Criterion::Asc(field) | Criterion::Desc(field) => Some(field),
                 _otherwise => None,
             });
@@ -995,6 +1267,74 @@ impl Index {
         self.main.remap_key_type::<Str>().delete(wtxn, main_key::DISTINCT_FIELD_KEY)
     }
 
+    /* recency field */
+
+    pub(crate) fn put_recency_field(
+        &self,
+        wtxn: &mut RwTxn<'_>,
+        recency_field: &str,
+    ) -> heed::Result<()> {
+        self.main.remap_types::<Str, Str>().put(wtxn, main_key::RECENCY_FIELD_KEY, recency_field)
+    }
+
+    /// Returns the field holding the timestamp used by the `_recency` ranking rule.
+    pub fn recency_field<'a>(&self, rtxn: &'a RoTxn<'_>) -> heed::Result<Option<&'a str>> {
+        self.main.remap_types::<Str, Str>().get(rtxn, main_key::RECENCY_FIELD_KEY)
+    }
+
+    pub(crate) fn delete_recency_field(&self, wtxn: &mut RwTxn<'_>) -> heed::Result<bool> {
+        self.main.remap_key_type::<Str>().delete(wtxn, main_key::RECENCY_FIELD_KEY)
+    }
+
+    /* recency half-life days */
+
+    pub(crate) fn put_recency_half_life_days(
+        &self,
+        wtxn: &mut RwTxn<'_>,
+        half_life_days: u32,
+    ) -> heed::Result<()> {
+        self.main.remap_types::<Str, BEU32>().put(
+            wtxn,
+            main_key::RECENCY_HALF_LIFE_DAYS_KEY,
+            &half_life_days,
+        )
+    }
+
+    /// Returns the half-life, in days, of the decay applied to the relevance of the documents
+    /// according to the timestamp of their recency field.
+    pub fn recency_half_life_days(&self, rtxn: &RoTxn<'_>) -> heed::Result<Option<u32>> {
+        self.main.remap_types::<Str, BEU32>().get(rtxn, main_key::RECENCY_HALF_LIFE_DAYS_KEY)
+    }
+
+    pub(crate) fn delete_recency_half_life_days(&self, wtxn: &mut RwTxn<'_>) -> heed::Result<bool> {
+        self.main.remap_key_type::<Str>().delete(wtxn, main_key::RECENCY_HALF_LIFE_DAYS_KEY)
+    }
+
+    /* reranker */
+
+    pub(crate) fn put_reranker(
+        &self,
+        wtxn: &mut RwTxn<'_>,
+        reranker: &RerankerSettings,
+    ) -> heed::Result<()> {
+        self.main.remap_types::<Str, SerdeJson<RerankerSettings>>().put(
+            wtxn,
+            main_key::RERANKER_KEY,
+            reranker,
+        )
+    }
+
+    /// Returns the endpoint re-scoring the most relevant documents of the searches with a query.
+    pub fn reranker(&self, rtxn: &RoTxn<'_>) -> heed::Result<Option<RerankerSettings>> {
+        self.main
+            .remap_types::<Str, SerdeJson<RerankerSettings>>()
+            .get(rtxn, main_key::RERANKER_KEY)
+    }
+
+    pub(crate) fn delete_reranker(&self, wtxn: &mut RwTxn<'_>) -> heed::Result<bool> {
+        self.main.remap_key_type::<Str>().delete(wtxn, main_key::RERANKER_KEY)
+    }
+
     /* criteria */
 
     pub(crate) fn put_criteria(
@@ -1024,6 +1364,32 @@ impl Index {
         }
     }
 
+    /// Returns the criteria as they must be applied at search time.
+    ///
+    /// The `_recency` criterion is replaced by a descending sort on the recency field,
+    /// or removed when no recency field is defined.
+    pub fn resolved_criteria(&self, rtxn: &RoTxn<'_>) -> heed::Result<Vec<Criterion>> {
+        let criteria = self.criteria(rtxn)?;
+        self.resolve_criteria(rtxn, criteria)
+    }
+
+    /// Resolves the `_recency` criterion of the given criteria like [`Self::resolved_criteria`] does.
+    pub fn resolve_criteria(
+        &self,
+        rtxn: &RoTxn<'_>,
+        criteria: Vec<Criterion>,
+    ) -> heed::Result<Vec<Criterion>> {
+        let recency_field = self.recency_field(rtxn)?;
+
+        Ok(criteria
+            .into_iter()
+            .filter_map(|criterion| match criterion {
+                Criterion::Recency => recency_field.map(|field| Criterion::Desc(field.to_string())),
+                criterion => Some(criterion),
+            })
+            .collect())
+    }
+
     /* words fst */
 
     /// Writes the FST which is the words dictionary of the engine.
@@ -1257,22 +1623,64 @@ impl Index {
         &'a self,
         rtxn: &'t RoTxn<'t>,
         ids: impl IntoIterator<Item = DocumentId> + 'a,
-    ) -> Result<impl Iterator<Item = Result<(DocumentId, obkv::KvReaderU16<'t>)>> + 'a> {
+    ) -> Result<impl Iterator<Item = Result<(DocumentId, DocumentObkv<'t>)>> + 'a> {
+        let dictionary = self.document_decompression_dictionary(rtxn)?;
         Ok(ids.into_iter().map(move |id| {
             let kv = self
                 .documents
                 .get(rtxn, &id)?
                 .ok_or(UserError::UnknownInternalDocumentId { document_id: id })?;
-            Ok((id, kv))
+            Ok((id, kv.decompress(dictionary.as_deref())?))
         }))
     }
 
+    /// Returns the etag of the requested document, `None` if the document doesn't exist.
+    ///
+    /// A document addition containing this etag in its `_etag` field only succeeds as long as
+    /// the document wasn't modified in between.
+    /// The etag is computed on the decompressed document, it doesn't change when the
+    /// documents are recompressed.
+    ///
+    /// The `dictionary` is the one of [`Self::document_decompression_dictionary`], loaded once
+    /// by the callers computing the etags of many documents.
+    pub fn document_etag(
+        &self,
+        rtxn: &RoTxn<'_>,
+        id: DocumentId,
+        dictionary: Option<&DecoderDictionary<'_>>,
+    ) -> Result<Option<String>> {
+        match self.documents.get(rtxn, &id)? {
+            Some(kv) => {
+                let document = kv.decompress(dictionary)?;
+                Ok(Some(crate::document_etag(document.as_bytes())))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the field ids and values of a document exactly as they are stored in its obkv,
+    /// to debug the indexing. The field ids can be resolved with the [`FieldsIdsMap`].
+    pub fn document_obkv(
+        &self,
+        rtxn: &RoTxn<'_>,
+        id: DocumentId,
+    ) -> Result<Option<Vec<(FieldId, Vec<u8>)>>> {
+        match self.documents.get(rtxn, &id)? {
+            Some(kv) => {
+                let dictionary = self.document_decompression_dictionary(rtxn)?;
+                let obkv = kv.decompress(dictionary.as_deref())?;
+                Ok(Some(obkv.iter().map(|(fid, value)| (fid, value.to_vec())).collect()))
+            }
+            None => Ok(None),
+        }
+    }
+
     /// Returns a [`Vec`] of the requested documents. Returns an error if a document is missing.
     pub fn documents<'t>(
         &self,
         rtxn: &'t RoTxn<'t>,
         ids: impl IntoIterator<Item = DocumentId>,
-    ) -> Result<Vec<(DocumentId, obkv::KvReaderU16<'t>)>> {
+    ) -> Result<Vec<(DocumentId, DocumentObkv<'t>)>> {
         self.iter_documents(rtxn, ids)?.collect()
     }
 
@@ -1280,7 +1688,7 @@ impl Index {
     pub fn all_documents<'a, 't: 'a>(
         &'a self,
         rtxn: &'t RoTxn<'t>,
-    ) -> Result<impl Iterator<Item = Result<(DocumentId, obkv::KvReaderU16<'t>)>> + 'a> {
+    ) -> Result<impl Iterator<Item = Result<(DocumentId, DocumentObkv<'t>)>> + 'a> {
         self.iter_documents(rtxn, self.documents_ids(rtxn)?)
     }
 
@@ -1304,7 +1712,7 @@ impl Index {
         })?;
         Ok(self.iter_documents(rtxn, ids)?.map(move |entry| -> Result<_> {
             let (_docid, obkv) = entry?;
-            match primary_key.document_id(&obkv, &fields)? {
+            match primary_key.document_id(&obkv.as_obkv(), &fields)? {
                 Ok(document_id) => Ok(document_id),
                 Err(_) => Err(InternalError::DocumentsError(
                     crate::documents::Error::InvalidDocumentFormat,
@@ -1314,6 +1722,114 @@ impl Index {
         }))
     }
 
+    /// Compares the documents of this index with the ones of `other`, matching them by external id.
+    ///
+    /// Both indexes are walked in the order of their external ids, one document at a time, so only
+    /// the ids of the differing documents are kept in memory. The documents found in both indexes
+    /// are compared by checksum, which doesn't depend on the field ids each index assigned.
+    pub fn diff(
+        &self,
+        rtxn: &RoTxn<'_>,
+        other: &Index,
+        other_rtxn: &RoTxn<'_>,
+    ) -> Result<IndexDiff> {
+        if let (Some(primary_key), Some(other_primary_key)) =
+            (self.primary_key(rtxn)?, other.primary_key(other_rtxn)?)
+        {
+            if primary_key != other_primary_key {
+                return Err(UserError::MismatchingPrimaryKeys {
+                    primary_key: primary_key.to_string(),
+                    other_primary_key: other_primary_key.to_string(),
+                }
+                .into());
+            }
+        }
+
+        let fields_ids_map = self.fields_ids_map(rtxn)?;
+        let other_fields_ids_map = other.fields_ids_map(other_rtxn)?;
+        let dictionary = self.document_decompression_dictionary(rtxn)?;
+        let other_dictionary = other.document_decompression_dictionary(other_rtxn)?;
+
+        let mut diff = IndexDiff::default();
+        let mut ids = self.external_documents_ids().iter(rtxn)?;
+        let mut other_ids = other.external_documents_ids().iter(other_rtxn)?;
+        let mut entry = ids.next().transpose()?;
+        let mut other_entry = other_ids.next().transpose()?;
+        loop {
+            match (entry, other_entry) {
+                (None, None) => break,
+                (Some((id, _)), None) => {
+                    diff.only_in_self.push(id.to_string());
+                    entry = ids.next().transpose()?;
+                }
+                (None, Some((other_id, _))) => {
+                    diff.only_in_other.push(other_id.to_string());
+                    other_entry = other_ids.next().transpose()?;
+                }
+                (Some((id, docid)), Some((other_id, other_docid))) => match id.cmp(other_id) {
+                    Ordering::Less => {
+                        diff.only_in_self.push(id.to_string());
+                        entry = ids.next().transpose()?;
+                    }
+                    Ordering::Greater => {
+                        diff.only_in_other.push(other_id.to_string());
+                        other_entry = other_ids.next().transpose()?;
+                    }
+                    Ordering::Equal => {
+                        let checksum = self.document_checksum(
+                            rtxn,
+                            &fields_ids_map,
+                            dictionary.as_deref(),
+                            docid,
+                        )?;
+                        let other_checksum = other.document_checksum(
+                            other_rtxn,
+                            &other_fields_ids_map,
+                            other_dictionary.as_deref(),
+                            other_docid,
+                        )?;
+                        if checksum != other_checksum {
+                            diff.differing.push(id.to_string());
+                        }
+                        entry = ids.next().transpose()?;
+                        other_entry = other_ids.next().transpose()?;
+                    }
+                },
+            }
+        }
+
+        Ok(diff)
+    }
+
+    /// Hashes the fields of a document by name, so that the same document gets the same checksum
+    /// in indexes that assigned different field ids to its attributes.
+    fn document_checksum(
+        &self,
+        rtxn: &RoTxn<'_>,
+        fields_ids_map: &FieldsIdsMap,
+        dictionary: Option<&DecoderDictionary<'_>>,
+        docid: DocumentId,
+    ) -> Result<u64> {
+        let obkv = self
+            .documents
+            .get(rtxn, &docid)?
+            .ok_or(UserError::UnknownInternalDocumentId { document_id: docid })?
+            .decompress(dictionary)?;
+        let mut fields = Vec::new();
+        for (field_id, value) in obkv.iter() {
+            let name = fields_ids_map.name(field_id).ok_or(FieldIdMapMissingEntry::FieldId {
+                field_id,
+                process: "document_checksum",
+            })?;
+            fields.push((name, value));
+        }
+        fields.sort_unstable_by_key(|(name, _)| *name);
+
+        let mut hasher = fxhash::FxHasher64::default();
+        fields.hash(&mut hasher);
+        Ok(hasher.finish())
+    }
+
     pub fn facets_distribution<'a>(&'a self, rtxn: &'a RoTxn<'a>) -> FacetDistribution<'a> {
         FacetDistribution::new(rtxn, self)
     }
@@ -1377,6 +1893,50 @@ impl Index {
         Ok(())
     }
 
+    /// Whether the query words can be split in two or concatenated with their neighbours
+    /// to match the documents, e.g. `wifi` matching `wi fi` and the other way around.
+    pub fn split_and_concat_words(&self, txn: &RoTxn<'_>) -> heed::Result<bool> {
+        // We put a u8 instead of a bool, like for the typos. The absence of a value is true,
+        // because by default, we split and concatenate the words.
+        match self.main.remap_types::<Str, U8>().get(txn, main_key::SPLIT_AND_CONCAT_WORDS)? {
+            Some(0) => Ok(false),
+            _ => Ok(true),
+        }
+    }
+
+    pub(crate) fn put_split_and_concat_words(
+        &self,
+        txn: &mut RwTxn<'_>,
+        flag: bool,
+    ) -> heed::Result<()> {
+        self.main.remap_types::<Str, U8>().put(
+            txn,
+            main_key::SPLIT_AND_CONCAT_WORDS,
+            &(flag as u8),
+        )?;
+
+        Ok(())
+    }
+
+    /// Whether the documents and the settings of the index must not be modified.
+    pub fn read_only(&self, txn: &RoTxn<'_>) -> heed::Result<bool> {
+        // We put a u8 instead of a bool, like for the typos. The absence of a value is false.
+        match self.main.remap_types::<Str, U8>().get(txn, main_key::READ_ONLY)? {
+            Some(0) | None => Ok(false),
+            _ => Ok(true),
+        }
+    }
+
+    pub(crate) fn put_read_only(&self, txn: &mut RwTxn<'_>, flag: bool) -> heed::Result<()> {
+        self.main.remap_types::<Str, U8>().put(txn, main_key::READ_ONLY, &(flag as u8))?;
+
+        Ok(())
+    }
+
+    pub(crate) fn delete_read_only(&self, txn: &mut RwTxn<'_>) -> heed::Result<bool> {
+        self.main.remap_key_type::<Str>().delete(txn, main_key::READ_ONLY)
+    }
+
     pub fn min_word_len_one_typo(&self, txn: &RoTxn<'_>) -> heed::Result<u8> {
         // It is not possible to put a bool in heed with OwnedType, so we put a u8 instead. We
         // identify 0 as being false, and anything else as true. The absence of a value is true,
@@ -1423,6 +1983,24 @@ impl Index {
         Ok(())
     }
 
+    /// Returns the ranking cost of each typo, the higher it is the more
+    /// the documents with typos are ranked away from the exact ones.
+    pub fn typo_penalty(&self, txn: &RoTxn<'_>) -> heed::Result<u8> {
+        Ok(self
+            .main
+            .remap_types::<Str, U8>()
+            .get(txn, main_key::TYPO_PENALTY)?
+            .unwrap_or(DEFAULT_TYPO_PENALTY))
+    }
+
+    pub(crate) fn put_typo_penalty(&self, txn: &mut RwTxn<'_>, val: u8) -> heed::Result<()> {
+        self.main.remap_types::<Str, U8>().put(txn, main_key::TYPO_PENALTY, &val)
+    }
+
+    pub(crate) fn delete_typo_penalty(&self, txn: &mut RwTxn<'_>) -> heed::Result<bool> {
+        self.main.remap_key_type::<Str>().delete(txn, main_key::TYPO_PENALTY)
+    }
+
     /// List the words on which typo are not allowed
     pub fn exact_words<'t>(&self, txn: &'t RoTxn<'t>) -> Result<Option<fst::Set<Cow<'t, [u8]>>>> {
         match self.main.remap_types::<Str, Bytes>().get(txn, main_key::EXACT_WORDS)? {
@@ -1475,71 +2053,755 @@ impl Index {
         self.main.remap_key_type::<Str>().delete(txn, main_key::EXACT_ATTRIBUTES)
     }
 
-    pub fn max_values_per_facet(&self, txn: &RoTxn<'_>) -> heed::Result<Option<u64>> {
-        self.main.remap_types::<Str, BEU64>().get(txn, main_key::MAX_VALUES_PER_FACET)
+    /// Returns the ignored attributes: attributes that are stored but never indexed.
+    pub fn ignored_attributes<'t>(&self, txn: &'t RoTxn<'t>) -> Result<Vec<&'t str>> {
+        Ok(self
+            .main
+            .remap_types::<Str, SerdeBincode<Vec<&str>>>()
+            .get(txn, main_key::IGNORED_ATTRIBUTES)?
+            .unwrap_or_default())
     }
 
-    pub(crate) fn put_max_values_per_facet(
-        &self,
-        txn: &mut RwTxn<'_>,
-        val: u64,
-    ) -> heed::Result<()> {
-        self.main.remap_types::<Str, BEU64>().put(txn, main_key::MAX_VALUES_PER_FACET, &val)
+    /// Writes the ignored attributes to the database.
+    pub(crate) fn put_ignored_attributes(&self, txn: &mut RwTxn<'_>, attrs: &[&str]) -> Result<()> {
+        self.main.remap_types::<Str, SerdeBincode<&[&str]>>().put(
+            txn,
+            main_key::IGNORED_ATTRIBUTES,
+            &attrs,
+        )?;
+        Ok(())
     }
 
-    pub(crate) fn delete_max_values_per_facet(&self, txn: &mut RwTxn<'_>) -> heed::Result<bool> {
-        self.main.remap_key_type::<Str>().delete(txn, main_key::MAX_VALUES_PER_FACET)
+    /// Clears the ignored attributes from the store.
+    pub(crate) fn delete_ignored_attributes(&self, txn: &mut RwTxn<'_>) -> heed::Result<bool> {
+        self.main.remap_key_type::<Str>().delete(txn, main_key::IGNORED_ATTRIBUTES)
     }
 
-    pub fn sort_facet_values_by(&self, txn: &RoTxn<'_>) -> heed::Result<OrderByMap> {
-        let orders = self
+    /* content types */
+
+    /// Returns the content type of the attributes containing markup, by attribute name.
+    pub fn content_types(&self, rtxn: &RoTxn<'_>) -> heed::Result<BTreeMap<String, ContentType>> {
+        Ok(self
             .main
-            .remap_types::<Str, SerdeJson<OrderByMap>>()
-            .get(txn, main_key::SORT_FACET_VALUES_BY)?
-            .unwrap_or_default();
-        Ok(orders)
+            .remap_types::<Str, SerdeJson<_>>()
+            .get(rtxn, main_key::CONTENT_TYPES)?
+            .unwrap_or_default())
     }
 
-    pub(crate) fn put_sort_facet_values_by(
+    pub(crate) fn put_content_types(
         &self,
-        txn: &mut RwTxn<'_>,
-        val: &OrderByMap,
+        wtxn: &mut RwTxn<'_>,
+        content_types: &BTreeMap<String, ContentType>,
     ) -> heed::Result<()> {
-        self.main.remap_types::<Str, SerdeJson<_>>().put(txn, main_key::SORT_FACET_VALUES_BY, &val)
+        self.main.remap_types::<Str, SerdeJson<_>>().put(
+            wtxn,
+            main_key::CONTENT_TYPES,
+            content_types,
+        )
     }
 
-    pub(crate) fn delete_sort_facet_values_by(&self, txn: &mut RwTxn<'_>) -> heed::Result<bool> {
-        self.main.remap_key_type::<Str>().delete(txn, main_key::SORT_FACET_VALUES_BY)
+    pub(crate) fn delete_content_types(&self, wtxn: &mut RwTxn<'_>) -> heed::Result<bool> {
+        self.main.remap_key_type::<Str>().delete(wtxn, main_key::CONTENT_TYPES)
     }
 
-    pub fn pagination_max_total_hits(&self, txn: &RoTxn<'_>) -> heed::Result<Option<u64>> {
-        self.main.remap_types::<Str, BEU64>().get(txn, main_key::PAGINATION_MAX_TOTAL_HITS)
+    /* tokenizer profiles */
+
+    /// Returns the way the values of the attributes are split into words, by attribute name.
+    pub fn tokenizer_profiles(
+        &self,
+        rtxn: &RoTxn<'_>,
+    ) -> heed::Result<BTreeMap<String, TokenizerProfile>> {
+        Ok(self
+            .main
+            .remap_types::<Str, SerdeJson<_>>()
+            .get(rtxn, main_key::TOKENIZER_PROFILES)?
+            .unwrap_or_default())
     }
 
-    pub(crate) fn put_pagination_max_total_hits(
+    pub(crate) fn put_tokenizer_profiles(
         &self,
-        txn: &mut RwTxn<'_>,
-        val: u64,
+        wtxn: &mut RwTxn<'_>,
+        tokenizer_profiles: &BTreeMap<String, TokenizerProfile>,
     ) -> heed::Result<()> {
-        self.main.remap_types::<Str, BEU64>().put(txn, main_key::PAGINATION_MAX_TOTAL_HITS, &val)
+        self.main.remap_types::<Str, SerdeJson<_>>().put(
+            wtxn,
+            main_key::TOKENIZER_PROFILES,
+            tokenizer_profiles,
+        )
     }
 
-    pub(crate) fn delete_pagination_max_total_hits(
-        &self,
-        txn: &mut RwTxn<'_>,
-    ) -> heed::Result<bool> {
-        self.main.remap_key_type::<Str>().delete(txn, main_key::PAGINATION_MAX_TOTAL_HITS)
+    pub(crate) fn delete_tokenizer_profiles(&self, wtxn: &mut RwTxn<'_>) -> heed::Result<bool> {
+        self.main.remap_key_type::<Str>().delete(wtxn, main_key::TOKENIZER_PROFILES)
     }
 
-    pub fn proximity_precision(&self, txn: &RoTxn<'_>) -> heed::Result<Option<ProximityPrecision>> {
-        self.main
-            .remap_types::<Str, SerdeBincode<ProximityPrecision>>()
-            .get(txn, main_key::PROXIMITY_PRECISION)
+    /* pinned results */
+
+    /// Returns the rules pinning documents to the top of the results, the first matching rule applies.
+    pub fn pinned_results(&self, rtxn: &RoTxn<'_>) -> heed::Result<Vec<PinnedResultsRule>> {
+        Ok(self
+            .main
+            .remap_types::<Str, SerdeJson<_>>()
+            .get(rtxn, main_key::PINNED_RESULTS)?
+            .unwrap_or_default())
     }
 
-    pub(crate) fn put_proximity_precision(
+    pub(crate) fn put_pinned_results(
         &self,
-        txn: &mut RwTxn<'_>,
+        wtxn: &mut RwTxn<'_>,
+        rules: &[PinnedResultsRule],
+    ) -> heed::Result<()> {
+        self.main.remap_types::<Str, SerdeJson<&[PinnedResultsRule]>>().put(
+            wtxn,
+            main_key::PINNED_RESULTS,
+            &rules,
+        )
+    }
+
+    pub(crate) fn delete_pinned_results(&self, wtxn: &mut RwTxn<'_>) -> heed::Result<bool> {
+        self.main.remap_key_type::<Str>().delete(wtxn, main_key::PINNED_RESULTS)
+    }
+
+    /* reserved document ids */
+
+    /// Returns `true` if the internal ids of the deleted documents are kept for them,
+    /// and given back when documents with the same external ids are added again.
+    pub fn reserve_document_ids(&self, rtxn: &RoTxn<'_>) -> heed::Result<bool> {
+        // We put a u8 instead of a bool, like for the read only flag. The absence of a value is false.
+        match self.main.remap_types::<Str, U8>().get(rtxn, main_key::RESERVE_DOCUMENT_IDS)? {
+            Some(0) | None => Ok(false),
+            _ => Ok(true),
+        }
+    }
+
+    pub(crate) fn put_reserve_document_ids(
+        &self,
+        wtxn: &mut RwTxn<'_>,
+        flag: bool,
+    ) -> heed::Result<()> {
+        self.main.remap_types::<Str, U8>().put(wtxn, main_key::RESERVE_DOCUMENT_IDS, &(flag as u8))
+    }
+
+    pub(crate) fn delete_reserve_document_ids(&self, wtxn: &mut RwTxn<'_>) -> heed::Result<bool> {
+        self.main.remap_key_type::<Str>().delete(wtxn, main_key::RESERVE_DOCUMENT_IDS)
+    }
+
+    /// Returns the internal id reserved for the deleted document with the given external id.
+    pub fn reserved_document_id(
+        &self,
+        rtxn: &RoTxn<'_>,
+        external_id: &str,
+    ) -> heed::Result<Option<DocumentId>> {
+        self.reserved_document_ids.get(rtxn, external_id)
+    }
+
+    /// Returns all the internal ids reserved for deleted documents.
+    pub fn reserved_documents_ids(&self, rtxn: &RoTxn<'_>) -> heed::Result<RoaringBitmap> {
+        Ok(self
+            .main
+            .remap_types::<Str, RoaringBitmapCodec>()
+            .get(rtxn, main_key::RESERVED_DOCUMENTS_IDS_KEY)?
+            .unwrap_or_default())
+    }
+
+    /// Reserves the internal ids of the given external ids, when the ids are reserved for this index.
+    pub(crate) fn reserve_document_ids_of(
+        &self,
+        wtxn: &mut RwTxn<'_>,
+        documents: impl IntoIterator<Item = (String, DocumentId)>,
+    ) -> heed::Result<()> {
+        if !self.reserve_document_ids(wtxn)? {
+            return Ok(());
+        }
+        let mut reserved = self.reserved_documents_ids(wtxn)?;
+        for (external_id, docid) in documents {
+            self.reserved_document_ids.put(wtxn, &external_id, &docid)?;
+            reserved.insert(docid);
+        }
+        self.main.remap_types::<Str, RoaringBitmapCodec>().put(
+            wtxn,
+            main_key::RESERVED_DOCUMENTS_IDS_KEY,
+            &reserved,
+        )
+    }
+
+    /// Releases the internal ids reserved for the given external ids, once they are given back.
+    pub(crate) fn release_reserved_document_ids<'a>(
+        &self,
+        wtxn: &mut RwTxn<'_>,
+        external_ids: impl IntoIterator<Item = &'a str>,
+    ) -> heed::Result<()> {
+        if self.reserved_document_ids.is_empty(wtxn)? {
+            return Ok(());
+        }
+        let mut reserved = self.reserved_documents_ids(wtxn)?;
+        for external_id in external_ids {
+            if let Some(docid) = self.reserved_document_ids.get(wtxn, external_id)? {
+                self.reserved_document_ids.delete(wtxn, external_id)?;
+                reserved.remove(docid);
+            }
+        }
+        self.main.remap_types::<Str, RoaringBitmapCodec>().put(
+            wtxn,
+            main_key::RESERVED_DOCUMENTS_IDS_KEY,
+            &reserved,
+        )
+    }
+
+    pub(crate) fn delete_reserved_document_ids(&self, wtxn: &mut RwTxn<'_>) -> heed::Result<bool> {
+        self.reserved_document_ids.clear(wtxn)?;
+        self.main.remap_key_type::<Str>().delete(wtxn, main_key::RESERVED_DOCUMENTS_IDS_KEY)
+    }
+
+    /* user metadata */
+
+    /// Returns the free-form metadata attached to the index, it is never interpreted by the engine.
+    pub fn user_metadata(&self, rtxn: &RoTxn<'_>) -> heed::Result<Option<serde_json::Value>> {
+        self.main.remap_types::<Str, SerdeJson<_>>().get(rtxn, main_key::USER_METADATA)
+    }
+
+    pub(crate) fn put_user_metadata(
+        &self,
+        wtxn: &mut RwTxn<'_>,
+        metadata: &serde_json::Value,
+    ) -> heed::Result<()> {
+        self.main.remap_types::<Str, SerdeJson<&serde_json::Value>>().put(
+            wtxn,
+            main_key::USER_METADATA,
+            &metadata,
+        )
+    }
+
+    pub(crate) fn delete_user_metadata(&self, wtxn: &mut RwTxn<'_>) -> heed::Result<bool> {
+        self.main.remap_key_type::<Str>().delete(wtxn, main_key::USER_METADATA)
+    }
+
+    /* store max length */
+
+    /// Returns the maximum number of characters of the string values returned when the documents
+    /// are retrieved, by attribute name. The documents are stored and indexed with their full
+    /// values, see [`crate::truncate_stored_values`].
+    pub fn store_max_length(&self, rtxn: &RoTxn<'_>) -> heed::Result<BTreeMap<String, usize>> {
+        Ok(self
+            .main
+            .remap_types::<Str, SerdeJson<_>>()
+            .get(rtxn, main_key::STORE_MAX_LENGTH)?
+            .unwrap_or_default())
+    }
+
+    pub(crate) fn put_store_max_length(
+        &self,
+        wtxn: &mut RwTxn<'_>,
+        store_max_length: &BTreeMap<String, usize>,
+    ) -> heed::Result<()> {
+        self.main.remap_types::<Str, SerdeJson<_>>().put(
+            wtxn,
+            main_key::STORE_MAX_LENGTH,
+            store_max_length,
+        )
+    }
+
+    pub(crate) fn delete_store_max_length(&self, wtxn: &mut RwTxn<'_>) -> heed::Result<bool> {
+        self.main.remap_key_type::<Str>().delete(wtxn, main_key::STORE_MAX_LENGTH)
+    }
+
+    /* blocked documents */
+
+    /// Returns the external ids of the documents excluded from all the search results.
+    ///
+    /// The blocked documents are still stored and can be retrieved by their id.
+    pub fn blocked_documents(&self, rtxn: &RoTxn<'_>) -> heed::Result<BTreeSet<String>> {
+        Ok(self
+            .main
+            .remap_types::<Str, SerdeJson<_>>()
+            .get(rtxn, main_key::BLOCKED_DOCUMENTS)?
+            .unwrap_or_default())
+    }
+
+    /// Writes the external ids of the blocked documents and refreshes their internal ids.
+    pub(crate) fn put_blocked_documents(
+        &self,
+        wtxn: &mut RwTxn<'_>,
+        documents_ids: &BTreeSet<String>,
+    ) -> heed::Result<()> {
+        self.main.remap_types::<Str, SerdeJson<_>>().put(
+            wtxn,
+            main_key::BLOCKED_DOCUMENTS,
+            documents_ids,
+        )?;
+        self.refresh_blocked_documents_ids(wtxn)
+    }
+
+    pub(crate) fn delete_blocked_documents(&self, wtxn: &mut RwTxn<'_>) -> heed::Result<bool> {
+        self.delete_blocked_documents_ids(wtxn)?;
+        self.main.remap_key_type::<Str>().delete(wtxn, main_key::BLOCKED_DOCUMENTS)
+    }
+
+    /// Returns the internal ids of the blocked documents, removed from the candidates of
+    /// every search.
+    pub fn blocked_documents_ids(&self, rtxn: &RoTxn<'_>) -> heed::Result<RoaringBitmap> {
+        Ok(self
+            .main
+            .remap_types::<Str, RoaringBitmapCodec>()
+            .get(rtxn, main_key::BLOCKED_DOCUMENTS_IDS_KEY)?
+            .unwrap_or_default())
+    }
+
+    /// Recomputes the internal ids of the blocked documents from their external ids,
+    /// to be called once documents are added or deleted.
+    pub(crate) fn refresh_blocked_documents_ids(&self, wtxn: &mut RwTxn<'_>) -> heed::Result<()> {
+        let blocked_documents = self.blocked_documents(wtxn)?;
+        if blocked_documents.is_empty() {
+            self.delete_blocked_documents_ids(wtxn)?;
+            return Ok(());
+        }
+
+        let external_documents_ids = self.external_documents_ids();
+        let mut docids = RoaringBitmap::new();
+        for external_id in &blocked_documents {
+            if let Some(docid) = external_documents_ids.get(wtxn, external_id)? {
+                docids.insert(docid);
+            }
+        }
+        self.main.remap_types::<Str, RoaringBitmapCodec>().put(
+            wtxn,
+            main_key::BLOCKED_DOCUMENTS_IDS_KEY,
+            &docids,
+        )
+    }
+
+    pub(crate) fn delete_blocked_documents_ids(&self, wtxn: &mut RwTxn<'_>) -> heed::Result<bool> {
+        self.main.remap_key_type::<Str>().delete(wtxn, main_key::BLOCKED_DOCUMENTS_IDS_KEY)
+    }
+
+    /* number tokenization */
+
+    pub(crate) fn put_number_tokenization(
+        &self,
+        wtxn: &mut RwTxn<'_>,
+        tokenization: NumberTokenization,
+    ) -> heed::Result<()> {
+        self.main.remap_types::<Str, SerdeJson<NumberTokenization>>().put(
+            wtxn,
+            main_key::NUMBER_TOKENIZATION,
+            &tokenization,
+        )
+    }
+
+    /// Whether the decimal and grouped numbers are kept as single words or split.
+    pub fn number_tokenization(&self, rtxn: &RoTxn<'_>) -> heed::Result<NumberTokenization> {
+        Ok(self
+            .main
+            .remap_types::<Str, SerdeJson<NumberTokenization>>()
+            .get(rtxn, main_key::NUMBER_TOKENIZATION)?
+            .unwrap_or_default())
+    }
+
+    pub(crate) fn delete_number_tokenization(&self, wtxn: &mut RwTxn<'_>) -> heed::Result<bool> {
+        self.main.remap_key_type::<Str>().delete(wtxn, main_key::NUMBER_TOKENIZATION)
+    }
+
+    /* enable formatting */
+
+    /// Whether the search results are highlighted, cropped and get their matches position
+    /// unless a query decides otherwise. The absence of a value is true.
+    pub fn enable_formatting(&self, rtxn: &RoTxn<'_>) -> heed::Result<bool> {
+        match self.main.remap_types::<Str, U8>().get(rtxn, main_key::ENABLE_FORMATTING)? {
+            Some(0) => Ok(false),
+            _ => Ok(true),
+        }
+    }
+
+    pub(crate) fn put_enable_formatting(
+        &self,
+        wtxn: &mut RwTxn<'_>,
+        enabled: bool,
+    ) -> heed::Result<()> {
+        self.main.remap_types::<Str, U8>().put(wtxn, main_key::ENABLE_FORMATTING, &(enabled as u8))
+    }
+
+    pub(crate) fn delete_enable_formatting(&self, wtxn: &mut RwTxn<'_>) -> heed::Result<bool> {
+        self.main.remap_key_type::<Str>().delete(wtxn, main_key::ENABLE_FORMATTING)
+    }
+
+    /* document compression */
+
+    /// Whether the new documents are stored compressed. The absence of a value is false.
+    pub fn document_compression(&self, rtxn: &RoTxn<'_>) -> heed::Result<bool> {
+        match self.main.remap_types::<Str, U8>().get(rtxn, main_key::DOCUMENT_COMPRESSION)? {
+            Some(0) | None => Ok(false),
+            _ => Ok(true),
+        }
+    }
+
+    pub(crate) fn put_document_compression(
+        &self,
+        wtxn: &mut RwTxn<'_>,
+        enabled: bool,
+    ) -> heed::Result<()> {
+        self.main.remap_types::<Str, U8>().put(
+            wtxn,
+            main_key::DOCUMENT_COMPRESSION,
+            &(enabled as u8),
+        )
+    }
+
+    pub(crate) fn delete_document_compression(&self, wtxn: &mut RwTxn<'_>) -> heed::Result<bool> {
+        self.main.remap_key_type::<Str>().delete(wtxn, main_key::DOCUMENT_COMPRESSION)
+    }
+
+    /// Returns the zstd dictionary the compressed documents were compressed with, if any.
+    ///
+    /// It is only replaced when all the documents are recompressed.
+    pub fn document_compression_dictionary<'t>(
+        &self,
+        rtxn: &'t RoTxn<'t>,
+    ) -> heed::Result<Option<&'t [u8]>> {
+        self.main.remap_types::<Str, Bytes>().get(rtxn, main_key::DOCUMENT_COMPRESSION_DICTIONARY)
+    }
+
+    pub(crate) fn put_document_compression_dictionary(
+        &self,
+        wtxn: &mut RwTxn<'_>,
+        dictionary: &[u8],
+    ) -> heed::Result<()> {
+        self.main.remap_types::<Str, Bytes>().put(
+            wtxn,
+            main_key::DOCUMENT_COMPRESSION_DICTIONARY,
+            dictionary,
+        )
+    }
+
+    pub(crate) fn delete_document_compression_dictionary(
+        &self,
+        wtxn: &mut RwTxn<'_>,
+    ) -> heed::Result<bool> {
+        self.main.remap_key_type::<Str>().delete(wtxn, main_key::DOCUMENT_COMPRESSION_DICTIONARY)
+    }
+
+    /// Returns the dictionary to decompress the stored documents with.
+    ///
+    /// Building a dictionary is costly, so the last one built is reused
+    /// as long as the dictionary stored in the index doesn't change.
+    pub fn document_decompression_dictionary(
+        &self,
+        rtxn: &RoTxn<'_>,
+    ) -> heed::Result<Option<Arc<DecoderDictionary<'static>>>> {
+        let Some(bytes) = self.document_compression_dictionary(rtxn)? else {
+            return Ok(None);
+        };
+
+        let mut cached = self.decompression_dictionary.lock().unwrap();
+        match &*cached {
+            Some((cached_bytes, dictionary)) if **cached_bytes == *bytes => {
+                Ok(Some(dictionary.clone()))
+            }
+            _ => {
+                let dictionary = Arc::new(DecoderDictionary::copy(bytes));
+                *cached = Some((bytes.into(), dictionary.clone()));
+                Ok(Some(dictionary))
+            }
+        }
+    }
+
+    /// Returns the compressor of the documents written in the index,
+    /// `None` when the documents are stored raw.
+    pub(crate) fn document_compressor(
+        &self,
+        rtxn: &RoTxn<'_>,
+    ) -> Result<Option<DocumentCompressor>> {
+        if !self.document_compression(rtxn)? {
+            return Ok(None);
+        }
+        let dictionary = self.document_compression_dictionary(rtxn)?;
+        Ok(Some(DocumentCompressor::new(dictionary)?))
+    }
+
+    /* attribute transforms */
+
+    /// Returns the transformations applied in order to the values of the incoming documents, by attribute name.
+    pub fn attribute_transforms(
+        &self,
+        rtxn: &RoTxn<'_>,
+    ) -> heed::Result<BTreeMap<String, Vec<AttributeTransform>>> {
+        Ok(self
+            .main
+            .remap_types::<Str, SerdeJson<_>>()
+            .get(rtxn, main_key::ATTRIBUTE_TRANSFORMS)?
+            .unwrap_or_default())
+    }
+
+    pub(crate) fn put_attribute_transforms(
+        &self,
+        wtxn: &mut RwTxn<'_>,
+        attribute_transforms: &BTreeMap<String, Vec<AttributeTransform>>,
+    ) -> heed::Result<()> {
+        self.main.remap_types::<Str, SerdeJson<_>>().put(
+            wtxn,
+            main_key::ATTRIBUTE_TRANSFORMS,
+            attribute_transforms,
+        )
+    }
+
+    pub(crate) fn delete_attribute_transforms(&self, wtxn: &mut RwTxn<'_>) -> heed::Result<bool> {
+        self.main.remap_key_type::<Str>().delete(wtxn, main_key::ATTRIBUTE_TRANSFORMS)
+    }
+
+    /* decimal attributes */
+
+    /// Returns the decimal attributes: attributes whose strings are also indexed as numbers,
+    /// so that the exact representation of the decimals is returned while they can still
+    /// be filtered and sorted as numbers.
+    pub fn decimal_attributes<'t>(&self, txn: &'t RoTxn<'t>) -> Result<Vec<&'t str>> {
+        Ok(self
+            .main
+            .remap_types::<Str, SerdeBincode<Vec<&str>>>()
+            .get(txn, main_key::DECIMAL_ATTRIBUTES)?
+            .unwrap_or_default())
+    }
+
+    /// Writes the decimal attributes to the database.
+    pub(crate) fn put_decimal_attributes(&self, txn: &mut RwTxn<'_>, attrs: &[&str]) -> Result<()> {
+        self.main.remap_types::<Str, SerdeBincode<&[&str]>>().put(
+            txn,
+            main_key::DECIMAL_ATTRIBUTES,
+            &attrs,
+        )?;
+        Ok(())
+    }
+
+    /// Clears the decimal attributes from the store.
+    pub(crate) fn delete_decimal_attributes(&self, txn: &mut RwTxn<'_>) -> heed::Result<bool> {
+        self.main.remap_key_type::<Str>().delete(txn, main_key::DECIMAL_ATTRIBUTES)
+    }
+
+    /* normalized facet attributes */
+
+    /// Returns the normalized facet attributes: attributes whose facet values are grouped
+    /// regardless of their case and diacritics, and displayed with their most frequent form.
+    pub fn normalized_facet_attributes<'t>(&self, txn: &'t RoTxn<'t>) -> Result<Vec<&'t str>> {
+        Ok(self
+            .main
+            .remap_types::<Str, SerdeBincode<Vec<&str>>>()
+            .get(txn, main_key::NORMALIZED_FACET_ATTRIBUTES)?
+            .unwrap_or_default())
+    }
+
+    /// Writes the normalized facet attributes to the database.
+    pub(crate) fn put_normalized_facet_attributes(
+        &self,
+        txn: &mut RwTxn<'_>,
+        attrs: &[&str],
+    ) -> Result<()> {
+        self.main.remap_types::<Str, SerdeBincode<&[&str]>>().put(
+            txn,
+            main_key::NORMALIZED_FACET_ATTRIBUTES,
+            &attrs,
+        )?;
+        Ok(())
+    }
+
+    /// Clears the normalized facet attributes from the store.
+    pub(crate) fn delete_normalized_facet_attributes(
+        &self,
+        txn: &mut RwTxn<'_>,
+    ) -> heed::Result<bool> {
+        self.main.remap_key_type::<Str>().delete(txn, main_key::NORMALIZED_FACET_ATTRIBUTES)
+    }
+
+    /// Whether the facet strings of all the fields are lowercased.
+    pub fn facet_case_folding(&self, txn: &RoTxn<'_>) -> heed::Result<bool> {
+        // We put a u8 instead of a bool, like for the typos. The absence of a value is true,
+        // because by default, the facet strings are lowercased.
+        match self.main.remap_types::<Str, U8>().get(txn, main_key::FACET_CASE_FOLDING)? {
+            Some(0) => Ok(false),
+            _ => Ok(true),
+        }
+    }
+
+    pub(crate) fn put_facet_case_folding(
+        &self,
+        txn: &mut RwTxn<'_>,
+        flag: bool,
+    ) -> heed::Result<()> {
+        self.main.remap_types::<Str, U8>().put(txn, main_key::FACET_CASE_FOLDING, &(flag as u8))?;
+
+        Ok(())
+    }
+
+    /// Returns how the facet strings of the field are normalized, without their diacritics for
+    /// the fields of the normalized facet attributes.
+    pub fn facet_normalization(
+        &self,
+        rtxn: &RoTxn<'_>,
+        field_id: FieldId,
+    ) -> Result<FacetNormalization> {
+        let fields_ids_map = self.fields_ids_map(rtxn)?;
+        let normalized_attributes = self.normalized_facet_attributes(rtxn)?;
+        let remove_diacritics = fields_ids_map.name(field_id).map_or(false, |name| {
+            normalized_attributes.iter().any(|attr| crate::is_faceted_by(name, attr))
+        });
+        Ok(FacetNormalization { case_folding: self.facet_case_folding(rtxn)?, remove_diacritics })
+    }
+
+    /// Returns `true` if the original forms of the facet strings of the field are counted,
+    /// i.e. if it is one of the normalized facet attributes.
+    ///
+    /// The other fields have no canonical facet string to look up.
+    pub fn has_canonical_facet_strings(
+        &self,
+        rtxn: &RoTxn<'_>,
+        field_id: FieldId,
+    ) -> heed::Result<bool> {
+        let mut iter = self
+            .facet_id_string_original_counts
+            .remap_types::<Bytes, DecodeIgnore>()
+            .prefix_iter(rtxn, &field_id.to_be_bytes())?;
+        Ok(iter.next().transpose()?.is_some())
+    }
+
+    /// Returns the most frequent original form of a normalized facet string, the smallest one
+    /// in case of a tie. Only known for the fields of the normalized facet attributes.
+    pub fn canonical_facet_string(
+        &self,
+        rtxn: &RoTxn<'_>,
+        field_id: FieldId,
+        normalized: &str,
+    ) -> heed::Result<Option<String>> {
+        let counts = self.facet_id_string_original_counts.get(rtxn, &(field_id, normalized))?;
+        Ok(counts.and_then(|counts| {
+            // the originals are iterated in order, the first of the most frequent is kept
+            counts
+                .into_iter()
+                .fold(None, |best: Option<(String, u64)>, (original, count)| match best {
+                    Some((_, best_count)) if best_count >= count => best,
+                    _ => Some((original, count)),
+                })
+                .map(|(original, _)| original)
+        }))
+    }
+
+    /* attributes prefix search */
+
+    /// Returns how the prefix of the last query term matches the attributes, by attribute name.
+    ///
+    /// The attributes that are not listed match the prefixes without any penalty.
+    pub fn attributes_prefix_search(
+        &self,
+        rtxn: &RoTxn<'_>,
+    ) -> heed::Result<BTreeMap<String, AttributePrefixSearch>> {
+        Ok(self
+            .main
+            .remap_types::<Str, SerdeJson<_>>()
+            .get(rtxn, main_key::ATTRIBUTES_PREFIX_SEARCH)?
+            .unwrap_or_default())
+    }
+
+    pub(crate) fn put_attributes_prefix_search(
+        &self,
+        wtxn: &mut RwTxn<'_>,
+        attributes_prefix_search: &BTreeMap<String, AttributePrefixSearch>,
+    ) -> heed::Result<()> {
+        self.main.remap_types::<Str, SerdeJson<_>>().put(
+            wtxn,
+            main_key::ATTRIBUTES_PREFIX_SEARCH,
+            attributes_prefix_search,
+        )
+    }
+
+    pub(crate) fn delete_attributes_prefix_search(
+        &self,
+        wtxn: &mut RwTxn<'_>,
+    ) -> heed::Result<bool> {
+        self.main.remap_key_type::<Str>().delete(wtxn, main_key::ATTRIBUTES_PREFIX_SEARCH)
+    }
+
+    pub fn max_values_per_facet(&self, txn: &RoTxn<'_>) -> heed::Result<Option<u64>> {
+        self.main.remap_types::<Str, BEU64>().get(txn, main_key::MAX_VALUES_PER_FACET)
+    }
+
+    pub(crate) fn put_max_values_per_facet(
+        &self,
+        txn: &mut RwTxn<'_>,
+        val: u64,
+    ) -> heed::Result<()> {
+        self.main.remap_types::<Str, BEU64>().put(txn, main_key::MAX_VALUES_PER_FACET, &val)
+    }
+
+    pub(crate) fn delete_max_values_per_facet(&self, txn: &mut RwTxn<'_>) -> heed::Result<bool> {
+        self.main.remap_key_type::<Str>().delete(txn, main_key::MAX_VALUES_PER_FACET)
+    }
+
+    pub fn sort_facet_values_by(&self, txn: &RoTxn<'_>) -> heed::Result<OrderByMap> {
+        let orders = self
+            .main
+            .remap_types::<Str, SerdeJson<OrderByMap>>()
+            .get(txn, main_key::SORT_FACET_VALUES_BY)?
+            .unwrap_or_default();
+        Ok(orders)
+    }
+
+    pub(crate) fn put_sort_facet_values_by(
+        &self,
+        txn: &mut RwTxn<'_>,
+        val: &OrderByMap,
+    ) -> heed::Result<()> {
+        self.main.remap_types::<Str, SerdeJson<_>>().put(txn, main_key::SORT_FACET_VALUES_BY, &val)
+    }
+
+    pub(crate) fn delete_sort_facet_values_by(&self, txn: &mut RwTxn<'_>) -> heed::Result<bool> {
+        self.main.remap_key_type::<Str>().delete(txn, main_key::SORT_FACET_VALUES_BY)
+    }
+
+    pub fn pagination_max_total_hits(&self, txn: &RoTxn<'_>) -> heed::Result<Option<u64>> {
+        self.main.remap_types::<Str, BEU64>().get(txn, main_key::PAGINATION_MAX_TOTAL_HITS)
+    }
+
+    pub(crate) fn put_pagination_max_total_hits(
+        &self,
+        txn: &mut RwTxn<'_>,
+        val: u64,
+    ) -> heed::Result<()> {
+        self.main.remap_types::<Str, BEU64>().put(txn, main_key::PAGINATION_MAX_TOTAL_HITS, &val)
+    }
+
+    pub(crate) fn delete_pagination_max_total_hits(
+        &self,
+        txn: &mut RwTxn<'_>,
+    ) -> heed::Result<bool> {
+        self.main.remap_key_type::<Str>().delete(txn, main_key::PAGINATION_MAX_TOTAL_HITS)
+    }
+
+    /// Returns the maximum number of synonyms a single query term can be expanded to,
+    /// 50 by default.
+    pub fn max_synonym_expansions(&self, txn: &RoTxn<'_>) -> heed::Result<usize> {
+        Ok(self
+            .main
+            .remap_types::<Str, BEU64>()
+            .get(txn, main_key::MAX_SYNONYM_EXPANSIONS)?
+            .map_or(crate::search::new::MAX_SYNONYM_PHRASE_COUNT, |max| max as usize))
+    }
+
+    pub(crate) fn put_max_synonym_expansions(
+        &self,
+        txn: &mut RwTxn<'_>,
+        val: u64,
+    ) -> heed::Result<()> {
+        self.main.remap_types::<Str, BEU64>().put(txn, main_key::MAX_SYNONYM_EXPANSIONS, &val)
+    }
+
+    pub(crate) fn delete_max_synonym_expansions(&self, txn: &mut RwTxn<'_>) -> heed::Result<bool> {
+        self.main.remap_key_type::<Str>().delete(txn, main_key::MAX_SYNONYM_EXPANSIONS)
+    }
+
+    pub fn proximity_precision(&self, txn: &RoTxn<'_>) -> heed::Result<Option<ProximityPrecision>> {
+        self.main
+            .remap_types::<Str, SerdeBincode<ProximityPrecision>>()
+            .get(txn, main_key::PROXIMITY_PRECISION)
+    }
+
+    pub(crate) fn put_proximity_precision(
+        &self,
+        txn: &mut RwTxn<'_>,
         val: ProximityPrecision,
     ) -> heed::Result<()> {
         self.main.remap_types::<Str, SerdeBincode<ProximityPrecision>>().put(
@@ -1637,6 +2899,222 @@ impl Index {
         self.main.remap_key_type::<Str>().delete(wtxn, main_key::SEARCH_CUTOFF)
     }
 
+    pub(crate) fn put_max_facet_values_per_attribute(
+        &self,
+        wtxn: &mut RwTxn<'_>,
+        max: u64,
+    ) -> heed::Result<()> {
+        self.main.remap_types::<Str, BEU64>().put(
+            wtxn,
+            main_key::MAX_FACET_VALUES_PER_ATTRIBUTE,
+            &max,
+        )
+    }
+
+    /// The maximum number of distinct string facet values stored for a single attribute.
+    /// Once reached, the new facet values of the attribute are no longer indexed.
+    pub fn max_facet_values_per_attribute(&self, rtxn: &RoTxn<'_>) -> Result<Option<u64>> {
+        Ok(self
+            .main
+            .remap_types::<Str, BEU64>()
+            .get(rtxn, main_key::MAX_FACET_VALUES_PER_ATTRIBUTE)?)
+    }
+
+    pub(crate) fn delete_max_facet_values_per_attribute(
+        &self,
+        wtxn: &mut RwTxn<'_>,
+    ) -> heed::Result<bool> {
+        self.main.remap_key_type::<Str>().delete(wtxn, main_key::MAX_FACET_VALUES_PER_ATTRIBUTE)
+    }
+
+    pub(crate) fn put_max_documents(&self, wtxn: &mut RwTxn<'_>, max: u64) -> heed::Result<()> {
+        self.main.remap_types::<Str, BEU64>().put(wtxn, main_key::MAX_DOCUMENTS, &max)
+    }
+
+    /// The maximum number of documents the index can contain.
+    /// The document additions that would make the index exceed it are rejected.
+    pub fn max_documents(&self, rtxn: &RoTxn<'_>) -> heed::Result<Option<u64>> {
+        self.main.remap_types::<Str, BEU64>().get(rtxn, main_key::MAX_DOCUMENTS)
+    }
+
+    pub(crate) fn delete_max_documents(&self, wtxn: &mut RwTxn<'_>) -> heed::Result<bool> {
+        self.main.remap_key_type::<Str>().delete(wtxn, main_key::MAX_DOCUMENTS)
+    }
+
+    pub(crate) fn put_max_prefix_typos(&self, wtxn: &mut RwTxn<'_>, max: u8) -> heed::Result<()> {
+        self.main.remap_types::<Str, U8>().put(wtxn, main_key::MAX_PREFIX_TYPOS, &max)
+    }
+
+    /// The maximum number of typos allowed on the query term matched as a prefix.
+    ///
+    /// It bounds the typo tolerance of the prefix lookups in the words FST,
+    /// `0` only matches the words starting exactly with the prefix.
+    pub fn max_prefix_typos(&self, rtxn: &RoTxn<'_>) -> heed::Result<Option<u8>> {
+        self.main.remap_types::<Str, U8>().get(rtxn, main_key::MAX_PREFIX_TYPOS)
+    }
+
+    pub(crate) fn delete_max_prefix_typos(&self, wtxn: &mut RwTxn<'_>) -> heed::Result<bool> {
+        self.main.remap_key_type::<Str>().delete(wtxn, main_key::MAX_PREFIX_TYPOS)
+    }
+
+    pub(crate) fn put_min_prefix_length(&self, wtxn: &mut RwTxn<'_>, min: u8) -> heed::Result<()> {
+        self.main.remap_types::<Str, U8>().put(wtxn, main_key::MIN_PREFIX_LENGTH, &min)
+    }
+
+    /// The minimum length, in bytes, of the prefixes stored in the words prefixes databases.
+    ///
+    /// The shorter prefixes are still matched, by looking up the words FST at search time.
+    pub fn min_prefix_length(&self, rtxn: &RoTxn<'_>) -> heed::Result<Option<u8>> {
+        self.main.remap_types::<Str, U8>().get(rtxn, main_key::MIN_PREFIX_LENGTH)
+    }
+
+    pub(crate) fn delete_min_prefix_length(&self, wtxn: &mut RwTxn<'_>) -> heed::Result<bool> {
+        self.main.remap_key_type::<Str>().delete(wtxn, main_key::MIN_PREFIX_LENGTH)
+    }
+
+    pub(crate) fn put_stemming(
+        &self,
+        wtxn: &mut RwTxn<'_>,
+        languages: &[Language],
+    ) -> heed::Result<()> {
+        self.main.remap_types::<Str, SerdeJson<&[Language]>>().put(
+            wtxn,
+            main_key::STEMMING,
+            &languages,
+        )
+    }
+
+    /// The languages whose stemmer is applied to the words of the documents and of the queries.
+    pub fn stemming(&self, rtxn: &RoTxn<'_>) -> heed::Result<Vec<Language>> {
+        Ok(self
+            .main
+            .remap_types::<Str, SerdeJson<Vec<Language>>>()
+            .get(rtxn, main_key::STEMMING)?
+            .unwrap_or_default())
+    }
+
+    pub(crate) fn delete_stemming(&self, wtxn: &mut RwTxn<'_>) -> heed::Result<bool> {
+        self.main.remap_key_type::<Str>().delete(wtxn, main_key::STEMMING)
+    }
+
+    pub(crate) fn put_max_flatten_depth(
+        &self,
+        wtxn: &mut RwTxn<'_>,
+        depth: usize,
+    ) -> heed::Result<()> {
+        self.main.remap_types::<Str, BEU64>().put(
+            wtxn,
+            main_key::MAX_FLATTEN_DEPTH,
+            &(depth as u64),
+        )
+    }
+
+    /// How many levels of nested objects are indexed, `None` when there is no limit.
+    ///
+    /// The fields nested deeper are stored in the documents but not indexed.
+    pub fn max_flatten_depth(&self, rtxn: &RoTxn<'_>) -> heed::Result<Option<usize>> {
+        Ok(self
+            .main
+            .remap_types::<Str, BEU64>()
+            .get(rtxn, main_key::MAX_FLATTEN_DEPTH)?
+            .map(|depth| depth as usize))
+    }
+
+    pub(crate) fn delete_max_flatten_depth(&self, wtxn: &mut RwTxn<'_>) -> heed::Result<bool> {
+        self.main.remap_key_type::<Str>().delete(wtxn, main_key::MAX_FLATTEN_DEPTH)
+    }
+
+    pub(crate) fn put_default_language(
+        &self,
+        wtxn: &mut RwTxn<'_>,
+        language: Language,
+    ) -> heed::Result<()> {
+        self.main.remap_types::<Str, SerdeJson<Language>>().put(
+            wtxn,
+            main_key::DEFAULT_LANGUAGE,
+            &language,
+        )
+    }
+
+    /// The language the documents and the queries are tokenized with when neither the
+    /// localized attributes rules nor the language hint field of a document tell otherwise,
+    /// `None` when the language is detected.
+    pub fn default_language(&self, rtxn: &RoTxn<'_>) -> heed::Result<Option<Language>> {
+        self.main.remap_types::<Str, SerdeJson<Language>>().get(rtxn, main_key::DEFAULT_LANGUAGE)
+    }
+
+    pub(crate) fn delete_default_language(&self, wtxn: &mut RwTxn<'_>) -> heed::Result<bool> {
+        self.main.remap_key_type::<Str>().delete(wtxn, main_key::DEFAULT_LANGUAGE)
+    }
+
+    pub(crate) fn put_language_hint_field(
+        &self,
+        wtxn: &mut RwTxn<'_>,
+        field: &str,
+    ) -> heed::Result<()> {
+        self.main.remap_types::<Str, Str>().put(wtxn, main_key::LANGUAGE_HINT_FIELD, field)
+    }
+
+    /// The field in which a document can declare the language of its text, as a language code.
+    pub fn language_hint_field<'t>(&self, rtxn: &'t RoTxn<'_>) -> heed::Result<Option<&'t str>> {
+        self.main.remap_types::<Str, Str>().get(rtxn, main_key::LANGUAGE_HINT_FIELD)
+    }
+
+    pub(crate) fn delete_language_hint_field(&self, wtxn: &mut RwTxn<'_>) -> heed::Result<bool> {
+        self.main.remap_key_type::<Str>().delete(wtxn, main_key::LANGUAGE_HINT_FIELD)
+    }
+
+    pub(crate) fn put_duplicate_key_strategy(
+        &self,
+        wtxn: &mut RwTxn<'_>,
+        strategy: DuplicateKeyStrategy,
+    ) -> heed::Result<()> {
+        self.main.remap_types::<Str, SerdeJson<DuplicateKeyStrategy>>().put(
+            wtxn,
+            main_key::DUPLICATE_KEY_STRATEGY,
+            &strategy,
+        )
+    }
+
+    /// How the documents sharing the same primary key in a single document addition are handled.
+    pub fn duplicate_key_strategy(&self, rtxn: &RoTxn<'_>) -> heed::Result<DuplicateKeyStrategy> {
+        Ok(self
+            .main
+            .remap_types::<Str, SerdeJson<DuplicateKeyStrategy>>()
+            .get(rtxn, main_key::DUPLICATE_KEY_STRATEGY)?
+            .unwrap_or_default())
+    }
+
+    pub(crate) fn delete_duplicate_key_strategy(&self, wtxn: &mut RwTxn<'_>) -> heed::Result<bool> {
+        self.main.remap_key_type::<Str>().delete(wtxn, main_key::DUPLICATE_KEY_STRATEGY)
+    }
+
+    pub(crate) fn put_capped_faceted_fields(
+        &self,
+        wtxn: &mut RwTxn<'_>,
+        fields: &BTreeSet<String>,
+    ) -> heed::Result<()> {
+        self.main.remap_types::<Str, SerdeJson<&BTreeSet<String>>>().put(
+            wtxn,
+            main_key::CAPPED_FACETED_FIELDS_KEY,
+            &fields,
+        )
+    }
+
+    /// Returns the attributes that reached the maximum number of facet values per attribute
+    /// and for which new facet values are no longer indexed.
+    pub fn capped_faceted_fields(&self, rtxn: &RoTxn<'_>) -> heed::Result<BTreeSet<String>> {
+        Ok(self
+            .main
+            .remap_types::<Str, SerdeJson<BTreeSet<String>>>()
+            .get(rtxn, main_key::CAPPED_FACETED_FIELDS_KEY)?
+            .unwrap_or_default())
+    }
+
+    pub(crate) fn delete_capped_faceted_fields(&self, wtxn: &mut RwTxn<'_>) -> heed::Result<bool> {
+        self.main.remap_key_type::<Str>().delete(wtxn, main_key::CAPPED_FACETED_FIELDS_KEY)
+    }
+
     pub fn embeddings(
         &self,
         rtxn: &RoTxn<'_>,
@@ -1672,6 +3150,174 @@ impl Index {
         }
         Ok(res)
     }
+
+    /// Clears the given auxiliary database and recomputes it from the stored documents,
+    /// without reindexing the other databases.
+    pub fn rebuild<'i>(
+        &'i self,
+        wtxn: &mut RwTxn<'i>,
+        indexer_config: &IndexerConfig,
+        which: DatabaseKind,
+    ) -> Result<()> {
+        RebuildDatabase::new(wtxn, self, indexer_config).execute(which)
+    }
+
+    /// Rewrites all the stored documents, compressed with a dictionary trained on a sample
+    /// of them when the document compression is enabled, raw otherwise.
+    pub fn recompress_documents(&self, wtxn: &mut RwTxn<'_>) -> Result<()> {
+        RecompressDocuments::new(wtxn, self).execute()
+    }
+
+    /// Describes every field of the fields ids map, with the types of the values the documents
+    /// hold for it and whether it is searchable, filterable, sortable, or displayed.
+    ///
+    /// The types are the ones recorded while indexing the documents, the nested fields are
+    /// reached through the objects and the arrays of objects of their parent fields.
+    pub fn schema(&self, rtxn: &RoTxn<'_>) -> Result<BTreeMap<String, FieldSchema>> {
+        let fields_ids_map = self.fields_ids_map(rtxn)?;
+        let fields_values = self.fields_values(rtxn)?;
+        let searchable_fields = self.searchable_fields(rtxn)?;
+        let filterable_fields = self.filterable_fields(rtxn)?;
+        let sortable_fields = self.sortable_fields(rtxn)?;
+        let displayed_fields = self.displayed_fields(rtxn)?;
+
+        let schema = fields_ids_map
+            .names()
+            .map(|name| {
+                let values = fields_values.get(name);
+                let field = FieldSchema {
+                    types: values
+                        .map(|values| values.types.keys().copied().collect())
+                        .unwrap_or_default(),
+                    searchable: crate::is_faceted(name, &searchable_fields),
+                    filterable: crate::is_faceted(name, &filterable_fields),
+                    sortable: crate::is_faceted(name, &sortable_fields),
+                    displayed: displayed_fields
+                        .as_ref()
+                        .map_or(true, |displayed| crate::is_faceted(name, displayed)),
+                    sample: values.and_then(|values| values.sample.clone()),
+                };
+                (name.to_string(), field)
+            })
+            .collect();
+
+        Ok(schema)
+    }
+}
+
+/// Counts the type of the value of a document in the values of its field, then the ones of its
+/// nested fields, as added or deleted.
+pub(crate) fn count_field_value(
+    fields_values: &mut FieldsValues,
+    name: &str,
+    value: &serde_json::Value,
+    operation: DelAdd,
+) {
+    let field_type = FieldType::from(value);
+    match operation {
+        DelAdd::Addition => {
+            let field = fields_values.entry(name.to_string()).or_default();
+            *field.types.entry(field_type).or_default() += 1;
+            if field.sample.is_none() && !value.is_null() {
+                field.sample = Some(value.clone());
+            }
+        }
+        DelAdd::Deletion => {
+            if let Some(field) = fields_values.get_mut(name) {
+                if field.sample.as_ref() == Some(value) {
+                    field.sample = None;
+                }
+                if let Some(count) = field.types.get_mut(&field_type) {
+                    *count = count.saturating_sub(1);
+                    if *count == 0 {
+                        field.types.remove(&field_type);
+                    }
+                }
+                if field.types.is_empty() {
+                    fields_values.remove(name);
+                }
+            }
+        }
+    }
+
+    match value {
+        serde_json::Value::Object(object) => {
+            for (key, value) in object {
+                count_field_value(fields_values, &format!("{name}.{key}"), value, operation);
+            }
+        }
+        serde_json::Value::Array(values) => {
+            for object in values.iter().filter_map(|value| value.as_object()) {
+                for (key, value) in object {
+                    count_field_value(fields_values, &format!("{name}.{key}"), value, operation);
+                }
+            }
+        }
+        _ => (),
+    }
+}
+
+/// The documents that differ between two indexes, identified by their external ids.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct IndexDiff {
+    /// The documents only found in the diffed index.
+    pub only_in_self: Vec<String>,
+    /// The documents only found in the index it is compared with.
+    pub only_in_other: Vec<String>,
+    /// The documents found in both indexes but with different contents.
+    pub differing: Vec<String>,
+}
+
+/// The values of every field, the nested ones included, recorded while indexing the documents.
+pub type FieldsValues = BTreeMap<String, FieldValues>;
+
+/// The values the documents hold for a field, see [`Index::fields_values`].
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldValues {
+    /// The number of values of each type.
+    pub types: BTreeMap<FieldType, u64>,
+    /// A non-null value indexed for this field, removed with the document it comes from.
+    pub sample: Option<serde_json::Value>,
+}
+
+/// The description of a field returned by [`Index::schema`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldSchema {
+    /// The types of the values held by the documents for this field, a field with mixed values
+    /// has several of them.
+    pub types: BTreeSet<FieldType>,
+    pub searchable: bool,
+    pub filterable: bool,
+    pub sortable: bool,
+    pub displayed: bool,
+    /// A non-null value indexed for this field.
+    pub sample: Option<serde_json::Value>,
+}
+
+/// The JSON type of a value of a field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum FieldType {
+    String,
+    Number,
+    Boolean,
+    Array,
+    Object,
+    Null,
+}
+
+impl From<&serde_json::Value> for FieldType {
+    fn from(value: &serde_json::Value) -> Self {
+        match value {
+            serde_json::Value::String(_) => FieldType::String,
+            serde_json::Value::Number(_) => FieldType::Number,
+            serde_json::Value::Bool(_) => FieldType::Boolean,
+            serde_json::Value::Array(_) => FieldType::Array,
+            serde_json::Value::Object(_) => FieldType::Object,
+            serde_json::Value::Null => FieldType::Null,
+        }
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -1687,13 +3333,17 @@ pub(crate) mod tests {
     use std::ops::Deref;
 
     use big_s::S;
+    use heed::types::Str;
     use heed::{EnvOpenOptions, RwTxn};
     use maplit::{btreemap, hashset};
     use tempfile::TempDir;
+    use time::OffsetDateTime;
 
     use crate::documents::DocumentsBatchReader;
-    use crate::error::{Error, InternalError};
-    use crate::index::{DEFAULT_MIN_WORD_LEN_ONE_TYPO, DEFAULT_MIN_WORD_LEN_TWO_TYPOS};
+    use crate::error::{Error, InternalError, UserError};
+    use crate::index::{
+        main_key, IndexDiff, DEFAULT_MIN_WORD_LEN_ONE_TYPO, DEFAULT_MIN_WORD_LEN_TWO_TYPOS,
+    };
     use crate::update::{
         self, IndexDocuments, IndexDocumentsConfig, IndexDocumentsMethod, IndexerConfig, Setting,
         Settings,
@@ -1915,6 +3565,29 @@ pub(crate) mod tests {
         );
     }
 
+    #[test]
+    fn backfill_creation_dates_of_older_indexes() {
+        let dir = TempDir::new().unwrap();
+        let mut options = EnvOpenOptions::new();
+        options.map_size(10 * 1024 * 1024);
+
+        // an index created before the creation dates were stored
+        let index = Index::new(options.clone(), dir.path()).unwrap();
+        let mut wtxn = index.write_txn().unwrap();
+        let main = index.main.remap_key_type::<Str>();
+        main.delete(&mut wtxn, main_key::CREATED_AT_KEY).unwrap();
+        main.delete(&mut wtxn, main_key::UPDATED_AT_KEY).unwrap();
+        wtxn.commit().unwrap();
+        index.prepare_for_closing().wait();
+
+        let modified_at = dir.path().join("data.mdb").metadata().unwrap().modified().unwrap();
+
+        let index = Index::new(options, dir.path()).unwrap();
+        let rtxn = index.read_txn().unwrap();
+        assert_eq!(index.created_at(&rtxn).unwrap(), OffsetDateTime::from(modified_at));
+        assert_eq!(index.updated_at(&rtxn).unwrap(), OffsetDateTime::from(modified_at));
+    }
+
     #[test]
     fn put_and_retrieve_disable_typo() {
         let index = TempIndex::new();
@@ -2457,8 +4130,9 @@ pub(crate) mod tests {
         "###);
 
         let rtxn = index.read_txn().unwrap();
-        let (_docid, obkv) = index.documents(&rtxn, [0]).unwrap()[0];
-        let json = obkv_to_json(&[0, 1, 2], &index.fields_ids_map(&rtxn).unwrap(), obkv).unwrap();
+        let (_docid, obkv) = index.documents(&rtxn, [0]).unwrap().remove(0);
+        let json = obkv_to_json(&[0, 1, 2], &index.fields_ids_map(&rtxn).unwrap(), obkv.as_obkv())
+            .unwrap();
         insta::assert_debug_snapshot!(json, @r###"
         {
             "primary_key": Number(30),
@@ -2466,8 +4140,9 @@ pub(crate) mod tests {
         "###);
 
         // Furthermore, when we retrieve document 34, it is not the result of merging 35 with 34
-        let (_docid, obkv) = index.documents(&rtxn, [2]).unwrap()[0];
-        let json = obkv_to_json(&[0, 1, 2], &index.fields_ids_map(&rtxn).unwrap(), obkv).unwrap();
+        let (_docid, obkv) = index.documents(&rtxn, [2]).unwrap().remove(0);
+        let json = obkv_to_json(&[0, 1, 2], &index.fields_ids_map(&rtxn).unwrap(), obkv.as_obkv())
+            .unwrap();
         insta::assert_debug_snapshot!(json, @r###"
         {
             "primary_key": Number(34),
@@ -2675,7 +4350,7 @@ pub(crate) mod tests {
         documents_ids.sort_unstable();
         let docs = index.documents(&rtxn, documents_ids).unwrap();
         let mut all_ids = HashSet::new();
-        for (_docid, obkv) in docs {
+        for (_docid, obkv) in &docs {
             let id = obkv.get(primary_key_id).unwrap();
             assert!(all_ids.insert(id));
         }
@@ -2936,4 +4611,97 @@ pub(crate) mod tests {
             .unwrap();
         assert!(results.candidates.is_empty());
     }
+
+    #[test]
+    fn document_obkv() {
+        let index = TempIndex::new();
+        index
+            .add_documents(documents!([{ "id": 1, "title": "Captain Marvel", "rating": 4.5 }]))
+            .unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        let fields_ids_map = index.fields_ids_map(&rtxn).unwrap();
+        let obkv = index.document_obkv(&rtxn, 0).unwrap().unwrap();
+        let obkv: Vec<_> = obkv
+            .into_iter()
+            .map(|(fid, value)| {
+                (fid, fields_ids_map.name(fid).unwrap(), String::from_utf8(value).unwrap())
+            })
+            .collect();
+        assert_eq!(
+            obkv,
+            vec![(0, "id", S("1")), (1, "title", S("\"Captain Marvel\"")), (2, "rating", S("4.5")),]
+        );
+
+        assert_eq!(index.document_obkv(&rtxn, 1).unwrap(), None);
+    }
+
+    #[test]
+    fn warmup() {
+        let index = TempIndex::new();
+        let rtxn = index.read_txn().unwrap();
+        let empty_bytes_read = index.warmup(&rtxn, || false).unwrap().unwrap();
+        drop(rtxn);
+
+        index
+            .add_documents(documents!([
+                { "id": 0, "title": "Captain Marvel", "genre": "action" },
+                { "id": 1, "title": "Escape Room", "genre": "thriller" },
+            ]))
+            .unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        let bytes_read = index.warmup(&rtxn, || false).unwrap().unwrap();
+        assert!(bytes_read > empty_bytes_read);
+
+        // the warmup stops as soon as it is asked to
+        assert_eq!(index.warmup(&rtxn, || true).unwrap(), None);
+    }
+
+    #[test]
+    fn diff() {
+        let index = TempIndex::new();
+        index
+            .add_documents(documents!([
+                { "id": 0, "title": "Captain Marvel", "genre": "action" },
+                { "id": 1, "title": "Escape Room", "genre": "thriller" },
+                { "id": 2, "title": "Gläss", "genre": "drama" },
+                { "id": 3, "title": "How to Train Your Dragon", "genre": "animation" },
+            ]))
+            .unwrap();
+
+        // the fields are declared in another order so that they get other field ids
+        let other = TempIndex::new();
+        other
+            .add_documents(documents!([
+                { "genre": "thriller", "id": 1, "title": "Escape Room" },
+                { "genre": "drama", "id": 2, "title": "Glass" },
+                { "genre": "animation", "id": 3, "title": "How to Train Your Dragon" },
+                { "genre": "romance", "id": 4, "title": "Pride and Prejudice" },
+            ]))
+            .unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        let other_rtxn = other.read_txn().unwrap();
+        let diff = index.diff(&rtxn, &other, &other_rtxn).unwrap();
+        assert_eq!(
+            diff,
+            IndexDiff {
+                only_in_self: vec![S("0")],
+                only_in_other: vec![S("4")],
+                differing: vec![S("2")]
+            }
+        );
+
+        // an index doesn't differ from itself
+        assert_eq!(index.diff(&rtxn, &index, &rtxn).unwrap(), IndexDiff::default());
+        drop(other_rtxn);
+
+        // the primary key is inferred from the `uuid` field
+        let other = TempIndex::new();
+        other.add_documents(documents!([{ "uuid": "a", "title": "Pride and Prejudice" }])).unwrap();
+        let other_rtxn = other.read_txn().unwrap();
+        let err = index.diff(&rtxn, &other, &other_rtxn).unwrap_err();
+        assert!(matches!(err, Error::UserError(UserError::MismatchingPrimaryKeys { .. })));
+    }
 }
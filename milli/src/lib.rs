@@ -9,6 +9,8 @@ pub static ALLOC: mimalloc::MiMalloc = mimalloc::MiMalloc;
 pub mod documents;
 
 mod asc_desc;
+pub mod attribute_transform;
+pub mod content_extractor;
 mod criterion;
 mod error;
 mod external_documents_ids;
@@ -18,11 +20,16 @@ pub mod heed_codec;
 pub mod index;
 mod localized_attributes_rules;
 pub mod order_by_map;
+mod pinned_results;
+pub mod prefix_search;
 pub mod prompt;
 pub mod proximity;
+pub mod reranker;
 pub mod score_details;
 mod search;
+mod stemming;
 mod thread_pool_no_abort;
+pub mod tokenizer_profile;
 pub mod update;
 pub mod vector;
 
@@ -31,24 +38,31 @@ pub mod vector;
 pub mod snapshot_tests;
 mod fieldids_weights_map;
 
+use std::borrow::Cow;
 use std::collections::{BTreeMap, HashMap};
 use std::convert::{TryFrom, TryInto};
 use std::fmt;
 use std::hash::BuildHasherDefault;
 
-use charabia::normalizer::{CharNormalizer, CompatibilityDecompositionNormalizer};
+use charabia::normalizer::{
+    CharNormalizer, CompatibilityDecompositionNormalizer, Normalize, NormalizerOption,
+};
+use charabia::StrDetection;
 pub use filter_parser::{Condition, FilterCondition, Span, Token};
 use fxhash::{FxHasher32, FxHasher64};
 pub use grenad::CompressionType;
 pub use search::new::{
-    execute_search, filtered_universe, DefaultSearchLogger, GeoSortStrategy, SearchContext,
-    SearchLogger, VisualSearchLogger,
+    execute_search, filtered_universe, group_documents, AnalyzedTerm, DefaultSearchLogger,
+    DocumentsGroup, GeoSortStrategy, QueryAnalysis, SearchContext, SearchLogger,
+    VisualSearchLogger,
 };
 use serde_json::Value;
 pub use thread_pool_no_abort::{PanicCatched, ThreadPoolNoAbort, ThreadPoolNoAbortBuilder};
 pub use {charabia as tokenizer, heed, rhai};
 
 pub use self::asc_desc::{AscDesc, AscDescError, Member, SortError};
+pub use self::attribute_transform::AttributeTransform;
+pub use self::content_extractor::{ContentExtractor, ContentType};
 pub use self::criterion::{default_criteria, Criterion, CriterionError};
 pub use self::error::{
     Error, FieldIdMapMissingEntry, InternalError, SerializationError, UserError,
@@ -58,19 +72,28 @@ pub use self::fieldids_weights_map::FieldidsWeightsMap;
 pub use self::fields_ids_map::FieldsIdsMap;
 pub use self::heed_codec::{
     BEU16StrCodec, BEU32StrCodec, BoRoaringBitmapCodec, BoRoaringBitmapLenCodec,
-    CboRoaringBitmapCodec, CboRoaringBitmapLenCodec, FieldIdWordCountCodec, ObkvCodec,
-    RoaringBitmapCodec, RoaringBitmapLenCodec, StrBEU32Codec, U8StrStrCodec,
-    UncheckedU8StrStrCodec,
+    CboRoaringBitmapCodec, CboRoaringBitmapLenCodec, CompressedObkvCodec, DocumentObkv,
+    FieldIdWordCountCodec, ObkvCodec, RoaringBitmapCodec, RoaringBitmapLenCodec, StrBEU32Codec,
+    U8StrStrCodec, UncheckedU8StrStrCodec,
+};
+pub use self::index::{
+    DatabasesSizes, FieldSchema, FieldType, FieldValues, FieldsValues, Index, IndexDiff,
 };
-pub use self::index::Index;
 pub use self::localized_attributes_rules::LocalizedAttributesRule;
 use self::localized_attributes_rules::LocalizedFieldIds;
+pub use self::pinned_results::PinnedResultsRule;
+pub use self::prefix_search::AttributePrefixSearch;
+pub use self::reranker::RerankerSettings;
 pub use self::search::facet::{FacetValueHit, SearchForFacetValues};
 pub use self::search::similar::Similar;
+pub use self::search::suggestion::{complete_prefix, suggest_query, Completion};
 pub use self::search::{
-    FacetDistribution, Filter, FormatOptions, MatchBounds, MatcherBuilder, MatchingWords, OrderBy,
-    Search, SearchResult, SemanticSearch, TermsMatchingStrategy, DEFAULT_VALUES_PER_FACET,
+    FacetDistribution, Filter, FormatOptions, FormatTags, MatchBounds, MatcherBuilder,
+    MatchingWords, OrderBy, PrefixHighlight, Search, SearchResult, SemanticSearch,
+    TermsMatchingStrategy, DEFAULT_VALUES_PER_FACET,
 };
+pub use self::stemming::Stemmer;
+pub use self::tokenizer_profile::{NumberTokenization, TokenizerProfile};
 
 pub type Result<T> = std::result::Result<T, error::Error>;
 
@@ -114,6 +137,10 @@ pub const MAX_WORD_LENGTH: usize = MAX_LMDB_KEY_LENGTH / 2;
 
 pub const MAX_POSITION_PER_ATTRIBUTE: u32 = u16::MAX as u32 + 1;
 
+/// The reserved field that makes the addition of a document conditional:
+/// the document is only written if its value matches the etag of the stored document.
+pub const RESERVED_ETAG_FIELD_NAME: &str = "_etag";
+
 #[derive(Clone)]
 pub struct TimeBudget {
     started_at: std::time::Instant,
@@ -231,6 +258,32 @@ pub fn obkv_to_json(
         .collect()
 }
 
+/// Truncates the string values of a retrieved document to the maximum number of characters of
+/// their attribute, as defined by the `storeMaxLength` setting.
+///
+/// The primary key is never truncated to keep the document identifiable.
+pub fn truncate_stored_values(
+    document: &mut Object,
+    store_max_length: &BTreeMap<String, usize>,
+    primary_key: Option<&str>,
+) {
+    for (name, value) in document.iter_mut() {
+        if primary_key == Some(name.as_str()) {
+            continue;
+        }
+        if let (Some(&max_length), Value::String(string)) = (store_max_length.get(name), value) {
+            if let Some((index, _)) = string.char_indices().nth(max_length) {
+                string.truncate(index);
+            }
+        }
+    }
+}
+
+/// Computes the etag of a raw obkv store, a checksum that changes whenever the document changes.
+pub fn document_etag(obkv: &[u8]) -> String {
+    format!("{:016x}", fxhash::hash64(obkv))
+}
+
 /// Transform every field of a raw obkv store into a JSON Object.
 pub fn all_obkv_to_json(
     obkv: obkv::KvReaderU16<'_>,
@@ -368,6 +421,69 @@ pub fn normalize_facet(original: &str) -> String {
     CompatibilityDecompositionNormalizer.normalize_str(original.trim()).to_lowercase()
 }
 
+/// Normalizes a facet string of the normalized facet attributes, whose values are also grouped
+/// regardless of their diacritics, with the lossy normalization used by the facet search.
+pub fn normalize_facet_without_diacritics(original: &str) -> String {
+    let normalized = normalize_facet(original);
+    let options = NormalizerOption { lossy: true, ..Default::default() };
+    let mut detection = StrDetection::new(&normalized, None);
+    let token = charabia::Token {
+        lemma: Cow::Borrowed(normalized.as_str()),
+        script: detection.script(),
+        language: detection.language(),
+        ..Default::default()
+    };
+    token.normalize(&options).lemma.into_owned()
+}
+
+/// How the strings of a facet field are normalized before being stored in the facet databases,
+/// and therefore which strings are considered equal by the filters, the sort and the facets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FacetNormalization {
+    /// Whether the strings are lowercased.
+    pub case_folding: bool,
+    /// Whether the diacritics of the strings are removed, only for the fields of the normalized
+    /// facet attributes.
+    pub remove_diacritics: bool,
+}
+
+impl Default for FacetNormalization {
+    fn default() -> Self {
+        Self { case_folding: true, remove_diacritics: false }
+    }
+}
+
+impl FacetNormalization {
+    pub fn normalize(&self, original: &str) -> String {
+        match (self.case_folding, self.remove_diacritics) {
+            (true, false) => normalize_facet(original),
+            (true, true) => normalize_facet_without_diacritics(original),
+            (false, false) => {
+                CompatibilityDecompositionNormalizer.normalize_str(original.trim()).to_string()
+            }
+            // the lossy normalization lowercases, so only the combining marks of the
+            // decomposed string are removed
+            (false, true) => CompatibilityDecompositionNormalizer
+                .normalize_str(original.trim())
+                .chars()
+                .filter(|c| !is_combining_mark(*c))
+                .collect(),
+        }
+    }
+}
+
+/// Whether the character belongs to one of the blocks of combining diacritical marks.
+fn is_combining_mark(c: char) -> bool {
+    matches!(
+        c,
+        '\u{0300}'..='\u{036F}'
+            | '\u{1AB0}'..='\u{1AFF}'
+            | '\u{1DC0}'..='\u{1DFF}'
+            | '\u{20D0}'..='\u{20FF}'
+            | '\u{FE20}'..='\u{FE2F}'
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use serde_json::json;
@@ -445,4 +561,29 @@ mod tests {
 
         assert_eq!(&actual, expected);
     }
+
+    #[test]
+    fn truncate_stored_values_keeps_the_primary_key() {
+        let mut document = json!({ "id": "abcdef", "text": "Thérèse Belivet", "count": 12 })
+            .as_object()
+            .unwrap()
+            .clone();
+        let store_max_length =
+            BTreeMap::from([("id".to_string(), 2), ("text".to_string(), 4), ("count".into(), 1)]);
+        truncate_stored_values(&mut document, &store_max_length, Some("id"));
+
+        assert_eq!(Value::Object(document), json!({ "id": "abcdef", "text": "Thér", "count": 12 }));
+    }
+
+    #[test]
+    fn facet_normalization() {
+        let normalize = |case_folding, remove_diacritics, original| {
+            FacetNormalization { case_folding, remove_diacritics }.normalize(original)
+        };
+
+        assert_eq!(normalize(true, false, " Éléphant "), normalize_facet("Éléphant"));
+        assert_eq!(normalize(true, true, "Éléphant"), "elephant");
+        assert_eq!(normalize(false, false, " Éléphant "), "E\u{301}le\u{301}phant");
+        assert_eq!(normalize(false, true, "Éléphant"), "Elephant");
+    }
 }
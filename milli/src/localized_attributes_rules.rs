@@ -34,7 +34,7 @@ impl LocalizedAttributesRule {
     }
 }
 
-fn match_pattern(pattern: &str, str: &str) -> bool {
+pub(crate) fn match_pattern(pattern: &str, str: &str) -> bool {
     if pattern == "*" {
         true
     } else if pattern.starts_with('*') && pattern.ends_with('*') {
@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+
+use crate::localized_attributes_rules::match_pattern;
+
+/// A rule that pins documents to the top of the results of the queries matching a pattern.
+///
+/// The query pattern follows the syntax of the attribute patterns of the localized attributes,
+/// e.g. `phone*` matches any query starting with `phone`.
+/// The queries are compared case-insensitively, ignoring the extra whitespace.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PinnedResultsRule {
+    pub query: String,
+    /// The external ids of the pinned documents, in the order they are shown.
+    pub document_ids: Vec<String>,
+}
+
+impl PinnedResultsRule {
+    pub fn match_query(&self, query: &str) -> bool {
+        match_pattern(&normalize_query(&self.query), &normalize_query(query))
+    }
+}
+
+fn normalize_query(query: &str) -> String {
+    query.split_whitespace().map(str::to_lowercase).collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use big_s::S;
+
+    use super::*;
+
+    #[test]
+    fn match_query() {
+        let rule = PinnedResultsRule { query: S("Smart Phone*"), document_ids: vec![] };
+        assert!(rule.match_query("smart phone"));
+        assert!(rule.match_query("  SMART   phones "));
+        assert!(!rule.match_query("phone"));
+
+        let rule = PinnedResultsRule { query: S("tv"), document_ids: vec![] };
+        assert!(rule.match_query("TV"));
+        assert!(!rule.match_query("tv stand"));
+    }
+}
@@ -0,0 +1,22 @@
+//! Control how the prefix of the last query term matches the attributes of the documents.
+
+use serde::{Deserialize, Serialize};
+
+/// How the words starting with the prefix of the last query term match an attribute.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default, rename_all = "camelCase")]
+pub struct AttributePrefixSearch {
+    /// Whether the words starting with the prefix match the attribute.
+    ///
+    /// When disabled, the attribute only matches the complete query terms.
+    pub enabled: bool,
+    /// The cost added by the attribute ranking rule to the documents
+    /// that only match the attribute through a prefix.
+    pub penalty: u16,
+}
+
+impl Default for AttributePrefixSearch {
+    fn default() -> Self {
+        Self { enabled: true, penalty: 0 }
+    }
+}
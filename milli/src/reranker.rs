@@ -0,0 +1,37 @@
+//! The settings of the external REST endpoint re-scoring the most relevant documents of a search.
+//!
+//! The endpoint is called by the search routes once the search is done. It receives a `POST`
+//! request with the query and the displayed attributes of the documents as JSON:
+//! `{ "query": "...", "documents": [{ ... }, ...] }`, and must answer with one score per
+//! document, in the same order: `{ "scores": [0.9, 0.1, ...] }`. The higher the score,
+//! the more relevant the document.
+
+use deserr::Deserr;
+use serde::{Deserialize, Serialize};
+
+/// The number of documents re-scored when the setting doesn't say otherwise.
+pub const DEFAULT_RERANKER_CANDIDATES: usize = 50;
+/// The highest number of documents that can be sent to the endpoint for a single search.
+pub const MAX_RERANKER_CANDIDATES: usize = 1000;
+
+/// The configuration of the endpoint re-scoring the documents, stored in the index.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Deserr)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[deserr(rename_all = camelCase, deny_unknown_fields)]
+pub struct RerankerSettings {
+    /// The URL the documents are posted to.
+    pub url: String,
+    /// Sent as a bearer token in the `Authorization` header.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[deserr(default)]
+    pub api_key: Option<String>,
+    /// The number of most relevant documents re-scored by the endpoint, the following ones
+    /// keep their rank.
+    #[serde(default = "default_candidates")]
+    #[deserr(default = DEFAULT_RERANKER_CANDIDATES)]
+    pub candidates: usize,
+}
+
+fn default_candidates() -> usize {
+    DEFAULT_RERANKER_CANDIDATES
+}
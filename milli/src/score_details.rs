@@ -17,6 +17,8 @@ pub enum ScoreDetails {
     Sort(Sort),
     Vector(Vector),
     GeoSort(GeoSort),
+    BoostingFilter(BoostingFilter),
+    RecencyDecay(Rank),
 
     /// Returned when we don't have the time to finish applying all the subsequent ranking-rules
     Skipped,
@@ -27,6 +29,9 @@ pub enum ScoreValue<'a> {
     Score(f64),
     Sort(&'a Sort),
     GeoSort(&'a GeoSort),
+    /// Whether the document matches the boosting filter, it orders the documents without
+    /// counting in their ranking score.
+    BoostingFilter(bool),
 }
 
 enum RankOrValue<'a> {
@@ -34,6 +39,7 @@ enum RankOrValue<'a> {
     Sort(&'a Sort),
     GeoSort(&'a GeoSort),
     Score(f64),
+    BoostingFilter(bool),
 }
 
 impl ScoreDetails {
@@ -53,6 +59,8 @@ impl ScoreDetails {
             ScoreDetails::Sort(_) => None,
             ScoreDetails::GeoSort(_) => None,
             ScoreDetails::Vector(_) => None,
+            ScoreDetails::BoostingFilter(_) => None,
+            ScoreDetails::RecencyDecay(details) => Some(*details),
             ScoreDetails::Skipped => Some(Rank { rank: 0, max_rank: 1 }),
         }
     }
@@ -84,6 +92,7 @@ impl ScoreDetails {
                 RankOrValue::Sort(s) => ScoreValue::Sort(s),
                 RankOrValue::GeoSort(g) => ScoreValue::GeoSort(g),
                 RankOrValue::Score(s) => ScoreValue::Score(s),
+                RankOrValue::BoostingFilter(b) => ScoreValue::BoostingFilter(b),
             })
     }
 
@@ -98,6 +107,8 @@ impl ScoreDetails {
             ScoreDetails::ExactWords(e) => RankOrValue::Rank(e.rank()),
             ScoreDetails::Sort(sort) => RankOrValue::Sort(sort),
             ScoreDetails::GeoSort(geosort) => RankOrValue::GeoSort(geosort),
+            ScoreDetails::BoostingFilter(b) => RankOrValue::BoostingFilter(b.matches),
+            ScoreDetails::RecencyDecay(r) => RankOrValue::Rank(*r),
             ScoreDetails::Vector(vector) => {
                 RankOrValue::Score(vector.similarity.as_ref().map(|s| *s as f64).unwrap_or(0.0f64))
             }
@@ -258,6 +269,22 @@ impl ScoreDetails {
                     details_map.insert("vectorSort".into(), details);
                     order += 1;
                 }
+                ScoreDetails::BoostingFilter(boosting_filter) => {
+                    let details = serde_json::json!({
+                        "order": order,
+                        "matches": boosting_filter.matches,
+                    });
+                    details_map.insert("boostingFilter".into(), details);
+                    order += 1;
+                }
+                ScoreDetails::RecencyDecay(recency_decay) => {
+                    let details = serde_json::json!({
+                        "order": order,
+                        "score": recency_decay.local_score(),
+                    });
+                    details_map.insert("recencyDecay".into(), details);
+                    order += 1;
+                }
                 ScoreDetails::Skipped => {
                     details_map
                         .insert("skipped".to_string(), serde_json::json!({ "order": order }));
@@ -333,14 +360,14 @@ impl ExactWords {
 pub struct Typo {
     pub typo_count: u32,
     pub max_typo_count: u32,
+    /// The weight of each typo in the rank, the `typoPenalty` of the index.
+    pub penalty: u32,
 }
 
 impl Typo {
     pub fn rank(&self) -> Rank {
-        Rank {
-            rank: (self.max_typo_count + 1).saturating_sub(self.typo_count),
-            max_rank: (self.max_typo_count + 1),
-        }
+        let max_rank = self.max_typo_count * self.penalty + 1;
+        Rank { rank: max_rank.saturating_sub(self.typo_count * self.penalty), max_rank }
     }
 
     // max_rank = max_typo + 1
@@ -350,10 +377,13 @@ impl Typo {
     // rank = max_rank - 1 - typo + 1
     // rank + typo = max_rank
     // typo = max_rank - rank
-    pub fn from_rank(rank: Rank) -> Typo {
+    //
+    // The penalty is only applied afterward, in `rank`.
+    pub fn from_rank(rank: Rank, penalty: u32) -> Typo {
         Typo {
             typo_count: rank.max_rank.saturating_sub(rank.rank),
             max_typo_count: rank.max_rank.saturating_sub(1),
+            penalty,
         }
     }
 }
@@ -415,6 +445,15 @@ impl ExactAttribute {
     }
 }
 
+/// Whether a document matches the boosting filter of the search.
+///
+/// Like a sort, it orders the documents but doesn't count in their ranking score, so that the
+/// score of a document doesn't depend on the boosting filter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BoostingFilter {
+    pub matches: bool,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct Sort {
     pub field_name: String,
@@ -125,6 +125,8 @@ impl<'a> FacetDistribution<'a> {
                 distribution.extend(lexicographic_distribution);
             }
             FacetType::String => {
+                let canonical_strings =
+                    self.index.has_canonical_facet_strings(self.rtxn, field_id)?;
                 let mut normalized_distribution = BTreeMap::new();
                 let mut key_buffer: Vec<_> = field_id.to_be_bytes().to_vec();
 
@@ -150,10 +152,12 @@ impl<'a> FacetDistribution<'a> {
                     }
                 }
 
-                let iter = normalized_distribution
-                    .into_iter()
-                    .map(|(_normalized, (original, count))| (original.to_string(), count));
-                distribution.extend(iter);
+                for (normalized, (original, count)) in normalized_distribution {
+                    // the normalized facet attributes are displayed with their most frequent form
+                    let canonical =
+                        self.canonical_facet_string(canonical_strings, field_id, normalized)?;
+                    distribution.insert(canonical.unwrap_or_else(|| original.to_string()), count);
+                }
             }
         }
 
@@ -203,6 +207,7 @@ impl<'a> FacetDistribution<'a> {
             OrderBy::Count => count_iterate_over_facet_distribution,
         };
 
+        let canonical_strings = self.index.has_canonical_facet_strings(self.rtxn, field_id)?;
         search_function(
             self.rtxn,
             self.index.facet_id_string_docids.remap_key_type::<FacetGroupKeyCodec<BytesRefCodec>>(),
@@ -211,13 +216,19 @@ impl<'a> FacetDistribution<'a> {
             |facet_key, nbr_docids, any_docid| {
                 let facet_key = StrRefCodec::bytes_decode(facet_key).unwrap();
 
-                let key: (FieldId, _, &str) = (field_id, any_docid, facet_key);
-                let original_string = self
-                    .index
-                    .field_id_docid_facet_strings
-                    .get(self.rtxn, &key)?
-                    .unwrap()
-                    .to_owned();
+                let canonical =
+                    self.canonical_facet_string(canonical_strings, field_id, facet_key)?;
+                let original_string = match canonical {
+                    Some(canonical) => canonical,
+                    None => {
+                        let key: (FieldId, _, &str) = (field_id, any_docid, facet_key);
+                        self.index
+                            .field_id_docid_facet_strings
+                            .get(self.rtxn, &key)?
+                            .unwrap()
+                            .to_owned()
+                    }
+                };
 
                 distribution.insert(original_string, nbr_docids);
                 if distribution.len() == self.max_values_per_facet {
@@ -229,6 +240,21 @@ impl<'a> FacetDistribution<'a> {
         )
     }
 
+    /// Returns the most frequent original form of the normalized facet string, it is only
+    /// looked up when the field has some, as told by `canonical_strings`.
+    fn canonical_facet_string(
+        &self,
+        canonical_strings: bool,
+        field_id: FieldId,
+        normalized: &str,
+    ) -> heed::Result<Option<String>> {
+        if canonical_strings {
+            self.index.canonical_facet_string(self.rtxn, field_id, normalized)
+        } else {
+            Ok(None)
+        }
+    }
+
     fn facet_values(
         &self,
         field_id: FieldId,
@@ -272,7 +298,8 @@ impl<'a> FacetDistribution<'a> {
         Ok(distribution)
     }
 
-    pub fn compute_stats(&self) -> Result<BTreeMap<String, (f64, f64)>> {
+    /// Returns the minimum, maximum and average numeric values of the faceted fields of the candidates.
+    pub fn compute_stats(&self) -> Result<BTreeMap<String, (f64, f64, f64)>> {
         let fields_ids_map = self.index.fields_ids_map(self.rtxn)?;
         let filterable_fields = self.index.filterable_fields(self.rtxn)?;
         let candidates = if let Some(candidates) = self.candidates.clone() {
@@ -324,8 +351,18 @@ impl<'a> FacetDistribution<'a> {
                 } else {
                     continue;
                 };
+                let avg_value = if let Some(avg_value) = crate::search::facet::facet_average_value(
+                    self.index,
+                    self.rtxn,
+                    fid,
+                    &candidates,
+                )? {
+                    avg_value
+                } else {
+                    continue;
+                };
 
-                distribution.insert(name.to_string(), (min_value, max_value));
+                distribution.insert(name.to_string(), (min_value, max_value, avg_value));
             }
         }
 
@@ -334,6 +371,104 @@ impl<'a> FacetDistribution<'a> {
 
     pub fn execute(&self) -> Result<BTreeMap<String, IndexMap<String, u64>>> {
         let fields_ids_map = self.index.fields_ids_map(self.rtxn)?;
+        let fields = self.distribution_fields()?;
+
+        let mut distribution = BTreeMap::new();
+        for (fid, name) in fields_ids_map.iter() {
+            if crate::is_faceted(name, &fields) {
+                let order_by = self
+                    .facets
+                    .as_ref()
+                    .and_then(|facets| facets.get(name).copied())
+                    .unwrap_or(self.default_order_by);
+                let values = self.facet_values(fid, order_by)?;
+                distribution.insert(name.to_string(), values);
+            }
+        }
+
+        Ok(distribution)
+    }
+
+    /// Calls `f` with the field name, the value and the count of every facet value of the
+    /// candidates, in lexicographic order.
+    ///
+    /// Unlike [`Self::execute`] the number of values isn't capped by the `max_values_per_facet`
+    /// and the values are read from the facet levels one by one, without keeping the whole
+    /// distribution in memory. The iteration stops as soon as `f` breaks.
+    pub fn for_each_value(
+        &self,
+        mut f: impl FnMut(&str, &str, u64) -> ControlFlow<()>,
+    ) -> Result<()> {
+        let fields_ids_map = self.index.fields_ids_map(self.rtxn)?;
+        let fields = self.distribution_fields()?;
+
+        let universe;
+        let candidates = match &self.candidates {
+            Some(cnd) => cnd,
+            None => {
+                universe = self.index.documents_ids(self.rtxn)?;
+                &universe
+            }
+        };
+
+        for (fid, name) in fields_ids_map.iter() {
+            if !crate::is_faceted(name, &fields) {
+                continue;
+            }
+
+            let mut stopped = false;
+            lexicographically_iterate_over_facet_distribution(
+                self.rtxn,
+                self.index
+                    .facet_id_f64_docids
+                    .remap_key_type::<FacetGroupKeyCodec<BytesRefCodec>>(),
+                fid,
+                candidates,
+                |facet_key, nbr_docids, _| {
+                    let facet_key = OrderedF64Codec::bytes_decode(facet_key).unwrap();
+                    let flow = f(name, &facet_key.to_string(), nbr_docids);
+                    stopped = flow.is_break();
+                    Ok(flow)
+                },
+            )?;
+            if stopped {
+                return Ok(());
+            }
+
+            let canonical_strings = self.index.has_canonical_facet_strings(self.rtxn, fid)?;
+            lexicographically_iterate_over_facet_distribution(
+                self.rtxn,
+                self.index
+                    .facet_id_string_docids
+                    .remap_key_type::<FacetGroupKeyCodec<BytesRefCodec>>(),
+                fid,
+                candidates,
+                |facet_key, nbr_docids, any_docid| {
+                    let facet_key = StrRefCodec::bytes_decode(facet_key).unwrap();
+                    let canonical =
+                        self.canonical_facet_string(canonical_strings, fid, facet_key)?;
+                    let original_string = match &canonical {
+                        Some(canonical) => canonical.as_str(),
+                        None => {
+                            let key: (FieldId, _, &str) = (fid, any_docid, facet_key);
+                            self.index.field_id_docid_facet_strings.get(self.rtxn, &key)?.unwrap()
+                        }
+                    };
+                    let flow = f(name, original_string, nbr_docids);
+                    stopped = flow.is_break();
+                    Ok(flow)
+                },
+            )?;
+            if stopped {
+                return Ok(());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the fields to compute the distribution of, all of them must be filterable.
+    fn distribution_fields(&self) -> Result<HashSet<String>> {
         let filterable_fields = self.index.filterable_fields(self.rtxn)?;
 
         let fields = match self.facets {
@@ -356,20 +491,7 @@ impl<'a> FacetDistribution<'a> {
             None => filterable_fields,
         };
 
-        let mut distribution = BTreeMap::new();
-        for (fid, name) in fields_ids_map.iter() {
-            if crate::is_faceted(name, &fields) {
-                let order_by = self
-                    .facets
-                    .as_ref()
-                    .and_then(|facets| facets.get(name).copied())
-                    .unwrap_or(self.default_order_by);
-                let values = self.facet_values(fid, order_by)?;
-                distribution.insert(name.to_string(), values);
-            }
-        }
-
-        Ok(distribution)
+        Ok(fields)
     }
 }
 
@@ -666,7 +788,7 @@ mod tests {
             .compute_stats()
             .unwrap();
 
-        milli_snap!(format!("{map:?}"), "candidates_0_1000", @r###"{"colour": (0.0, 999.0)}"###);
+        milli_snap!(format!("{map:?}"), "candidates_0_1000", @r###"{"colour": (0.0, 999.0, 499.5)}"###);
 
         let map = FacetDistribution::new(&txn, &index)
             .facets(iter::once(("colour", OrderBy::default())))
@@ -674,7 +796,42 @@ mod tests {
             .compute_stats()
             .unwrap();
 
-        milli_snap!(format!("{map:?}"), "candidates_217_777", @r###"{"colour": (217.0, 776.0)}"###);
+        milli_snap!(format!("{map:?}"), "candidates_217_777", @r###"{"colour": (217.0, 776.0, 496.5)}"###);
+    }
+
+    #[test]
+    fn facet_stats_many_candidates() {
+        let mut index = TempIndex::new_with_map_size(4096 * 10_000);
+        index.index_documents_config.autogenerate_docids = true;
+
+        index
+            .update_settings(|settings| settings.set_filterable_fields(hashset! { S("colour") }))
+            .unwrap();
+
+        let documents = (0..5000)
+            .map(|i| serde_json::json!({ "colour": i % 100 }).as_object().unwrap().clone())
+            .collect();
+        index.add_documents(documents_batch_reader_from_objects(documents)).unwrap();
+
+        let txn = index.read_txn().unwrap();
+
+        // the average of many candidates is computed from the facet levels
+        let map = FacetDistribution::new(&txn, &index)
+            .facets(iter::once(("colour", OrderBy::default())))
+            .candidates((0..5000).collect())
+            .compute_stats()
+            .unwrap();
+
+        milli_snap!(format!("{map:?}"), "candidates_0_5000", @r###"{"colour": (0.0, 99.0, 49.5)}"###);
+
+        // and from the documents for a few of them, with the same result
+        let map = FacetDistribution::new(&txn, &index)
+            .facets(iter::once(("colour", OrderBy::default())))
+            .candidates((0..100).collect())
+            .compute_stats()
+            .unwrap();
+
+        milli_snap!(format!("{map:?}"), "candidates_0_100", @r###"{"colour": (0.0, 99.0, 49.5)}"###);
     }
 
     #[test]
@@ -718,7 +875,7 @@ mod tests {
             .compute_stats()
             .unwrap();
 
-        milli_snap!(format!("{map:?}"), "candidates_0_1000", @r###"{"colour": (0.0, 1999.0)}"###);
+        milli_snap!(format!("{map:?}"), "candidates_0_1000", @r###"{"colour": (0.0, 1999.0, 999.5)}"###);
 
         let map = FacetDistribution::new(&txn, &index)
             .facets(iter::once(("colour", OrderBy::default())))
@@ -726,7 +883,7 @@ mod tests {
             .compute_stats()
             .unwrap();
 
-        milli_snap!(format!("{map:?}"), "candidates_217_777", @r###"{"colour": (217.0, 1776.0)}"###);
+        milli_snap!(format!("{map:?}"), "candidates_217_777", @r###"{"colour": (217.0, 1776.0, 996.5)}"###);
     }
 
     #[test]
@@ -770,7 +927,7 @@ mod tests {
             .compute_stats()
             .unwrap();
 
-        milli_snap!(format!("{map:?}"), "candidates_0_1000", @r###"{"colour": (0.0, 999.0)}"###);
+        milli_snap!(format!("{map:?}"), "candidates_0_1000", @r###"{"colour": (0.0, 999.0, 499.5)}"###);
 
         let map = FacetDistribution::new(&txn, &index)
             .facets(iter::once(("colour", OrderBy::default())))
@@ -778,7 +935,7 @@ mod tests {
             .compute_stats()
             .unwrap();
 
-        milli_snap!(format!("{map:?}"), "candidates_217_777", @r###"{"colour": (217.0, 776.0)}"###);
+        milli_snap!(format!("{map:?}"), "candidates_217_777", @r###"{"colour": (217.0, 776.0, 496.5)}"###);
     }
 
     #[test]
@@ -826,7 +983,7 @@ mod tests {
             .compute_stats()
             .unwrap();
 
-        milli_snap!(format!("{map:?}"), "candidates_0_1000", @r###"{"colour": (0.0, 1998.0)}"###);
+        milli_snap!(format!("{map:?}"), "candidates_0_1000", @r###"{"colour": (0.0, 1998.0, 999.0)}"###);
 
         let map = FacetDistribution::new(&txn, &index)
             .facets(iter::once(("colour", OrderBy::default())))
@@ -834,6 +991,6 @@ mod tests {
             .compute_stats()
             .unwrap();
 
-        milli_snap!(format!("{map:?}"), "candidates_217_777", @r###"{"colour": (218.0, 1776.0)}"###);
+        milli_snap!(format!("{map:?}"), "candidates_217_777", @r###"{"colour": (218.0, 1776.0, 997.0)}"###);
     }
 }
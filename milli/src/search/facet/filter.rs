@@ -16,8 +16,8 @@ use crate::heed_codec::facet::{
 };
 use crate::index::db_name::FACET_ID_STRING_DOCIDS;
 use crate::{
-    distance_between_two_points, lat_lng_to_xyz, FieldId, Index, InternalError, Result,
-    SerializationError,
+    distance_between_two_points, lat_lng_to_xyz, FacetNormalization, FieldId, Index, InternalError,
+    Result, SerializationError,
 };
 
 /// The maximum number of filters the filter AST can process.
@@ -233,10 +233,12 @@ impl<'a> Filter<'a> {
         self.inner_evaluate(rtxn, index, &filterable_fields, None)
     }
 
+    /// Evaluates a condition on the field, its strings being normalized like the ones of the field.
     fn evaluate_operator(
         rtxn: &heed::RoTxn<'_>,
         index: &Index,
         field_id: FieldId,
+        normalization: FacetNormalization,
         universe: Option<&RoaringBitmap>,
         operator: &Condition<'a>,
     ) -> Result<RoaringBitmap> {
@@ -274,15 +276,9 @@ impl<'a> Filter<'a> {
                 return Ok(exist);
             }
             Condition::Equal(val) => {
+                let value = normalization.normalize(val.value());
                 let string_docids = strings_db
-                    .get(
-                        rtxn,
-                        &FacetGroupKey {
-                            field_id,
-                            level: 0,
-                            left_bound: &crate::normalize_facet(val.value()),
-                        },
-                    )?
+                    .get(rtxn, &FacetGroupKey { field_id, level: 0, left_bound: &value })?
                     .map(|v| v.bitmap)
                     .unwrap_or_default();
                 let number = val.parse_finite_float().ok();
@@ -297,12 +293,13 @@ impl<'a> Filter<'a> {
             }
             Condition::NotEqual(val) => {
                 let operator = Condition::Equal(val.clone());
-                let docids = Self::evaluate_operator(rtxn, index, field_id, None, &operator)?;
+                let docids =
+                    Self::evaluate_operator(rtxn, index, field_id, normalization, None, &operator)?;
                 let all_ids = index.documents_ids(rtxn)?;
                 return Ok(all_ids - docids);
             }
             Condition::Contains { keyword: _, word } => {
-                let value = crate::normalize_facet(word.value());
+                let value = normalization.normalize(word.value());
                 let finder = Finder::new(&value);
                 let base = FacetGroupKey { field_id, level: 0, left_bound: "" };
                 let docids = strings_db
@@ -409,9 +406,19 @@ impl<'a> Filter<'a> {
                 if crate::is_faceted(fid.value(), filterable_fields) {
                     let field_ids_map = index.fields_ids_map(rtxn)?;
                     if let Some(fid) = field_ids_map.id(fid.value()) {
+                        let normalization = index.facet_normalization(rtxn, fid)?;
                         els.iter()
                             .map(|el| Condition::Equal(el.clone()))
-                            .map(|op| Self::evaluate_operator(rtxn, index, fid, universe, &op))
+                            .map(|op| {
+                                Self::evaluate_operator(
+                                    rtxn,
+                                    index,
+                                    fid,
+                                    normalization,
+                                    universe,
+                                    &op,
+                                )
+                            })
                             .union()
                     } else {
                         Ok(RoaringBitmap::new())
@@ -427,7 +434,8 @@ impl<'a> Filter<'a> {
                 if crate::is_faceted(fid.value(), filterable_fields) {
                     let field_ids_map = index.fields_ids_map(rtxn)?;
                     if let Some(fid) = field_ids_map.id(fid.value()) {
-                        Self::evaluate_operator(rtxn, index, fid, universe, op)
+                        let normalization = index.facet_normalization(rtxn, fid)?;
+                        Self::evaluate_operator(rtxn, index, fid, normalization, universe, op)
                     } else {
                         Ok(RoaringBitmap::new())
                     }
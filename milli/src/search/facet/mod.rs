@@ -7,7 +7,9 @@ use roaring::RoaringBitmap;
 pub use self::facet_distribution::{FacetDistribution, OrderBy, DEFAULT_VALUES_PER_FACET};
 pub use self::filter::{BadGeoError, Filter};
 pub use self::search::{FacetValueHit, SearchForFacetValues};
-use crate::heed_codec::facet::{FacetGroupKeyCodec, OrderedF64Codec};
+use crate::heed_codec::facet::{
+    FacetGroupKeyCodec, FacetGroupValue, FieldDocIdFacetF64Codec, OrderedF64Codec,
+};
 use crate::heed_codec::BytesRefCodec;
 use crate::{Index, Result};
 
@@ -53,6 +55,71 @@ pub fn facet_max_value<'t>(
     facet_extreme_value(it)
 }
 
+/// Under this number of candidates, the average is computed from the values of each candidate
+/// rather than from the whole level 0 of the facet database.
+const AVERAGE_FROM_DOCUMENTS_THRESHOLD: u64 = 3000;
+
+/// Returns the average of the numeric facet values of the given field for the candidates.
+///
+/// Every value of a document is taken into account: a document with an array of numbers
+/// counts once for each of them.
+///
+/// The values of a few candidates are read one document at a time. Otherwise the whole level 0
+/// of the field is scanned, which costs one bitmap intersection per distinct value of the field:
+/// it is only computed for the `facetStats` of the searches requesting facets.
+pub fn facet_average_value<'t>(
+    index: &'t Index,
+    rtxn: &'t heed::RoTxn<'t>,
+    field_id: u16,
+    candidates: &RoaringBitmap,
+) -> Result<Option<f64>> {
+    if candidates.len() <= AVERAGE_FROM_DOCUMENTS_THRESHOLD {
+        return facet_average_value_from_documents(index, rtxn, field_id, candidates);
+    }
+
+    let mut level0_prefix = field_id.to_be_bytes().to_vec();
+    level0_prefix.push(0);
+
+    let (mut sum, mut count) = (0.0, 0);
+    let db = index.facet_id_f64_docids.remap_key_type::<Bytes>();
+    for result in db.prefix_iter(rtxn, &level0_prefix)? {
+        let (key, FacetGroupValue { bitmap, .. }) = result?;
+        let matching = bitmap.intersection_len(candidates);
+        if matching != 0 {
+            let key = FacetGroupKeyCodec::<OrderedF64Codec>::bytes_decode(key)
+                .map_err(heed::Error::Decoding)?;
+            sum += key.left_bound * matching as f64;
+            count += matching;
+        }
+    }
+
+    Ok((count != 0).then(|| sum / count as f64))
+}
+
+fn facet_average_value_from_documents(
+    index: &Index,
+    rtxn: &RoTxn<'_>,
+    field_id: u16,
+    candidates: &RoaringBitmap,
+) -> Result<Option<f64>> {
+    let (mut sum, mut count) = (0.0, 0u64);
+    let mut key_buffer = Vec::new();
+    let db = index.field_id_docid_facet_f64s.remap_key_type::<Bytes>();
+    for docid in candidates {
+        key_buffer.clear();
+        key_buffer.extend_from_slice(&field_id.to_be_bytes());
+        key_buffer.extend_from_slice(&docid.to_be_bytes());
+        let iter = db.prefix_iter(rtxn, &key_buffer)?.remap_key_type::<FieldDocIdFacetF64Codec>();
+        for result in iter {
+            let ((_, _, value), ()) = result?;
+            sum += value;
+            count += 1;
+        }
+    }
+
+    Ok((count != 0).then(|| sum / count as f64))
+}
+
 /// Get the first facet value in the facet database
 pub(crate) fn get_first_facet_value<'t, BoundCodec, DC>(
     txn: &'t RoTxn<'t>,
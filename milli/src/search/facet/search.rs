@@ -1,11 +1,12 @@
 use std::cmp::{Ordering, Reverse};
-use std::collections::BinaryHeap;
+use std::collections::{BTreeMap, BinaryHeap};
 use std::ops::ControlFlow;
 
 use charabia::normalizer::NormalizerOption;
 use charabia::{Language, Normalize, StrDetection, Token};
 use fst::automaton::{Automaton, Str};
 use fst::{IntoStreamer, Streamer};
+use levenshtein_automata::DFA;
 use roaring::RoaringBitmap;
 use tracing::error;
 
@@ -24,6 +25,7 @@ pub struct SearchForFacetValues<'a> {
     max_values: usize,
     is_hybrid: bool,
     locales: Option<Vec<Language>>,
+    fuzzy: bool,
 }
 
 impl<'a> SearchForFacetValues<'a> {
@@ -39,6 +41,7 @@ impl<'a> SearchForFacetValues<'a> {
             max_values: DEFAULT_MAX_NUMBER_OF_VALUES_PER_FACET,
             is_hybrid,
             locales: None,
+            fuzzy: false,
         }
     }
 
@@ -57,6 +60,13 @@ impl<'a> SearchForFacetValues<'a> {
         self
     }
 
+    /// Matches the facet values with typo tolerance even when it is disabled on the index,
+    /// and returns the values the closest to the query first.
+    pub fn fuzzy(&mut self, fuzzy: bool) -> &mut Self {
+        self.fuzzy = fuzzy;
+        self
+    }
+
     fn one_original_value_of(
         &self,
         field_id: FieldId,
@@ -109,10 +119,8 @@ impl<'a> SearchForFacetValues<'a> {
                     .is_some(),
         )?;
 
-        let mut results = match index.sort_facet_values_by(rtxn)?.get(&self.facet) {
-            OrderBy::Lexicographic => ValuesCollection::by_lexicographic(self.max_values),
-            OrderBy::Count => ValuesCollection::by_count(self.max_values),
-        };
+        let order_by = index.sort_facet_values_by(rtxn)?.get(&self.facet);
+        let mut results = ValuesCollection::new(order_by, self.max_values);
 
         match self.query.as_ref() {
             Some(query) => {
@@ -123,7 +131,7 @@ impl<'a> SearchForFacetValues<'a> {
                 let field_authorizes_typos =
                     !self.search_query.index.exact_attributes_ids(rtxn)?.contains(&fid);
 
-                if authorize_typos && field_authorizes_typos {
+                if (authorize_typos || self.fuzzy) && field_authorizes_typos {
                     let exact_words_fst = self.search_query.index.exact_words(rtxn)?;
                     if exact_words_fst.map_or(false, |fst| fst.contains(query)) {
                         if fst.contains(query) {
@@ -148,6 +156,17 @@ impl<'a> SearchForFacetValues<'a> {
                             build_dfa(query, 2, is_prefix)
                         };
 
+                        if self.fuzzy {
+                            return self.fetch_closest_facets(
+                                fid,
+                                &fst,
+                                &automaton,
+                                query,
+                                &search_candidates,
+                                order_by,
+                            );
+                        }
+
                         let mut stream = fst.search(automaton).into_stream();
                         while let Some(facet_value) = stream.next() {
                             let value = std::str::from_utf8(facet_value)?;
@@ -206,6 +225,53 @@ impl<'a> SearchForFacetValues<'a> {
         Ok(results.into_sorted_vec())
     }
 
+    /// Returns the facet values matched by the automaton, grouped by increasing number
+    /// of typos and sorted by the requested order inside each group.
+    fn fetch_closest_facets(
+        &self,
+        fid: FieldId,
+        facet_fst: &fst::Set<&[u8]>,
+        automaton: &DFA,
+        query: &str,
+        search_candidates: &RoaringBitmap,
+        order_by: OrderBy,
+    ) -> Result<Vec<FacetValueHit>> {
+        let mut values_by_typos: BTreeMap<u8, Vec<String>> = BTreeMap::new();
+        let mut stream = facet_fst.search_with_state(automaton).into_stream();
+        while let Some((facet_value, state)) = stream.next() {
+            let value = std::str::from_utf8(facet_value)?;
+            let typos = automaton.distance(state).to_u8();
+            values_by_typos.entry(typos).or_default().push(value.to_string());
+        }
+
+        let mut hits = Vec::new();
+        for values in values_by_typos.values() {
+            let remaining = self.max_values.saturating_sub(hits.len());
+            if remaining == 0 {
+                break;
+            }
+
+            let mut results = ValuesCollection::new(order_by, remaining);
+            for value in values {
+                if self
+                    .fetch_original_facets_using_normalized(
+                        fid,
+                        value,
+                        query,
+                        search_candidates,
+                        &mut results,
+                    )?
+                    .is_break()
+                {
+                    break;
+                }
+            }
+            hits.extend(results.into_sorted_vec());
+        }
+
+        Ok(hits)
+    }
+
     fn fetch_original_facets_using_normalized(
         &self,
         fid: FieldId,
@@ -285,6 +351,13 @@ enum ValuesCollection {
 }
 
 impl ValuesCollection {
+    pub fn new(order_by: OrderBy, max: usize) -> Self {
+        match order_by {
+            OrderBy::Lexicographic => ValuesCollection::by_lexicographic(max),
+            OrderBy::Count => ValuesCollection::by_count(max),
+        }
+    }
+
     pub fn by_lexicographic(max: usize) -> Self {
         ValuesCollection::Lexicographic { max, content: Vec::new() }
     }
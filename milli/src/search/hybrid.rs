@@ -53,6 +53,12 @@ fn compare_scores(
                     order => return order,
                 }
             }
+            (Some(ScoreValue::BoostingFilter(left)), Some(ScoreValue::BoostingFilter(right))) => {
+                match left.cmp(&right) {
+                    Ordering::Equal => continue,
+                    order => return order,
+                }
+            }
             (Some(ScoreValue::Score(x)), Some(_)) => {
                 return if x == 0. { Ordering::Less } else { Ordering::Greater }
             }
@@ -64,6 +70,11 @@ fn compare_scores(
             | (Some(ScoreValue::Sort(_)), Some(ScoreValue::GeoSort(_))) => {
                 unreachable!("Unexpected geo and sort comparison")
             }
+            // both searches start with the boosting filter
+            (Some(ScoreValue::BoostingFilter(_)), Some(_))
+            | (Some(_), Some(ScoreValue::BoostingFilter(_))) => {
+                unreachable!("Unexpected boosting filter comparison")
+            }
         }
     }
 }
@@ -159,6 +170,7 @@ impl<'a> Search<'a> {
         let mut search = Search {
             query: self.query.clone(),
             filter: self.filter.clone(),
+            boosting_filter: self.boosting_filter.clone(),
             offset: 0,
             limit: self.limit + self.offset,
             sort_criteria: self.sort_criteria.clone(),
@@ -175,6 +187,13 @@ impl<'a> Search<'a> {
             time_budget: self.time_budget.clone(),
             ranking_score_threshold: self.ranking_score_threshold,
             locales: self.locales.clone(),
+            max_synonym_expansions: self.max_synonym_expansions,
+            compute_total_hits: self.compute_total_hits,
+            cross_attribute_matching: self.cross_attribute_matching,
+            count_only: self.count_only,
+            ranking_rules: self.ranking_rules.clone(),
+            // the keyword results are merged with the semantic ones before being returned
+            partial_results: None,
         };
 
         let semantic = search.semantic.take();
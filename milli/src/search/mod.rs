@@ -7,13 +7,18 @@ use once_cell::sync::Lazy;
 use roaring::bitmap::RoaringBitmap;
 
 pub use self::facet::{FacetDistribution, Filter, OrderBy, DEFAULT_VALUES_PER_FACET};
-pub use self::new::matches::{FormatOptions, MatchBounds, MatcherBuilder, MatchingWords};
-use self::new::{execute_vector_search, PartialSearchResult};
+pub use self::new::matches::{
+    FormatOptions, FormatTags, MatchBounds, MatcherBuilder, MatchingWords, PrefixHighlight,
+};
+use self::new::{
+    execute_vector_search, DocumentDistinct, PartialResultsLogger, PartialSearchResult,
+    QueryAnalysis,
+};
 use crate::score_details::{ScoreDetails, ScoringStrategy};
 use crate::vector::Embedder;
 use crate::{
-    execute_search, filtered_universe, AscDesc, DefaultSearchLogger, DocumentId, Error, Index,
-    Result, SearchContext, TimeBudget, UserError,
+    execute_search, filtered_universe, AscDesc, Criterion, DocumentId, Error, Index, Result,
+    SearchContext, TimeBudget, UserError,
 };
 
 // Building these factories is not free.
@@ -26,6 +31,7 @@ mod fst_utils;
 pub mod hybrid;
 pub mod new;
 pub mod similar;
+pub mod suggestion;
 
 #[derive(Debug, Clone)]
 pub struct SemanticSearch {
@@ -38,6 +44,7 @@ pub struct Search<'a> {
     query: Option<String>,
     // this should be linked to the String in the query
     filter: Option<Filter<'a>>,
+    boosting_filter: Option<Filter<'a>>,
     offset: usize,
     limit: usize,
     sort_criteria: Option<Vec<AscDesc>>,
@@ -54,6 +61,12 @@ pub struct Search<'a> {
     time_budget: TimeBudget,
     ranking_score_threshold: Option<f64>,
     locales: Option<Vec<Language>>,
+    max_synonym_expansions: Option<usize>,
+    compute_total_hits: bool,
+    cross_attribute_matching: bool,
+    count_only: bool,
+    ranking_rules: Option<Vec<Criterion>>,
+    partial_results: Option<&'a dyn Fn(&[DocumentId])>,
 }
 
 impl<'a> Search<'a> {
@@ -61,6 +74,7 @@ impl<'a> Search<'a> {
         Search {
             query: None,
             filter: None,
+            boosting_filter: None,
             offset: 0,
             limit: 20,
             sort_criteria: None,
@@ -77,6 +91,12 @@ impl<'a> Search<'a> {
             locales: None,
             time_budget: TimeBudget::max(),
             ranking_score_threshold: None,
+            max_synonym_expansions: None,
+            compute_total_hits: true,
+            cross_attribute_matching: true,
+            count_only: false,
+            ranking_rules: None,
+            partial_results: None,
         }
     }
 
@@ -168,6 +188,81 @@ impl<'a> Search<'a> {
         self
     }
 
+    /// Bounds the number of synonyms a single query term can be expanded to.
+    ///
+    /// When a term has more synonyms than that, the most frequent ones are kept.
+    pub fn max_synonym_expansions(&mut self, max_synonym_expansions: usize) -> &mut Search<'a> {
+        self.max_synonym_expansions = Some(max_synonym_expansions);
+        self
+    }
+
+    /// Whether the candidates of the search must be gathered to count the total number of hits.
+    ///
+    /// When disabled, the `candidates` of the [`SearchResult`] are left empty.
+    pub fn compute_total_hits(&mut self, compute_total_hits: bool) -> &mut Search<'a> {
+        self.compute_total_hits = compute_total_hits;
+        self
+    }
+
+    /// Whether the query terms can match a document across several of its attributes.
+    ///
+    /// When disabled, a document only matches if one of its searchable attributes contains
+    /// all the query terms.
+    pub fn cross_attribute_matching(&mut self, cross_attribute_matching: bool) -> &mut Search<'a> {
+        self.cross_attribute_matching = cross_attribute_matching;
+        self
+    }
+
+    /// Whether the search only counts the documents matching it.
+    ///
+    /// When enabled, no ranking rule is applied and the `documents_ids` of the [`SearchResult`]
+    /// are left empty, only its `candidates` are filled.
+    pub fn count_only(&mut self, count_only: bool) -> &mut Search<'a> {
+        self.count_only = count_only;
+        self
+    }
+
+    /// Ranks the documents matching the given filter before all the others.
+    ///
+    /// Unlike the filter of the search, the documents not matching it are still returned.
+    pub fn boosting_filter(&mut self, filter: Filter<'a>) -> &mut Search<'a> {
+        self.boosting_filter = Some(filter);
+        self
+    }
+
+    /// Applies the given ranking rules to this search instead of the ones of the settings.
+    pub fn ranking_rules(&mut self, ranking_rules: Vec<Criterion>) -> &mut Search<'a> {
+        self.ranking_rules = Some(ranking_rules);
+        self
+    }
+
+    /// Calls the given function with the documents ids added to the results, in order, as soon
+    /// as the ranking rules buckets containing them are resolved.
+    ///
+    /// The documents are reported before the whole search is done, which lets the slow searches
+    /// return partial results. Not supported by the vector and hybrid searches.
+    pub fn partial_results(&mut self, on_results: &'a dyn Fn(&[DocumentId])) -> &mut Search<'a> {
+        self.partial_results = Some(on_results);
+        self
+    }
+
+    /// Describes how the query of this search is understood by the engine.
+    ///
+    /// Returns `None` when there is no query.
+    pub fn analyze_query(&self) -> Result<Option<QueryAnalysis>> {
+        let query = match self.query.as_deref() {
+            Some(query) => query,
+            None => return Ok(None),
+        };
+
+        let mut ctx = SearchContext::new(self.index, self.rtxn)?;
+        if let Some(max_synonym_expansions) = self.max_synonym_expansions {
+            ctx.max_synonym_expansions = max_synonym_expansions;
+        }
+
+        new::analyze_query(&mut ctx, query, Some(self.words_limit), self.locales.as_ref()).map(Some)
+    }
+
     pub fn execute_for_candidates(&self, has_vector_search: bool) -> Result<RoaringBitmap> {
         if has_vector_search {
             let ctx = SearchContext::new(self.index, self.rtxn)?;
@@ -184,16 +279,43 @@ impl<'a> Search<'a> {
             ctx.attributes_to_search_on(searchable_attributes)?;
         }
 
+        if let Some(max_synonym_expansions) = self.max_synonym_expansions {
+            ctx.max_synonym_expansions = max_synonym_expansions;
+        }
+        ctx.compute_total_hits = self.compute_total_hits;
+        ctx.cross_attribute_matching = self.cross_attribute_matching;
+        ctx.count_only = self.count_only;
+        ctx.ranking_rules.clone_from(&self.ranking_rules);
+        if let Some(boosting_filter) = &self.boosting_filter {
+            let boosted =
+                boosting_filter.evaluate(ctx.txn, ctx.index).map_err(|error| match error {
+                    Error::UserError(UserError::InvalidFilter(error)) => {
+                        Error::UserError(UserError::InvalidBoostingFilter(error))
+                    }
+                    error => error,
+                })?;
+            ctx.boosted_documents = Some(boosted);
+        }
+
         if let Some(distinct) = &self.distinct {
             let filterable_fields = ctx.index.filterable_fields(ctx.txn)?;
             if !crate::is_faceted(distinct, &filterable_fields) {
-                let (valid_fields, hidden_fields) =
-                    ctx.index.remove_hidden_fields(ctx.txn, filterable_fields)?;
-                return Err(Error::UserError(UserError::InvalidDistinctAttribute {
-                    field: distinct.clone(),
-                    valid_fields,
-                    hidden_fields,
-                }));
+                // the values of a field that isn't filterable are read from the documents
+                match ctx.index.fields_ids_map(ctx.txn)?.id(distinct) {
+                    Some(field_id) => {
+                        ctx.document_distinct =
+                            Some(DocumentDistinct::new(ctx.index, ctx.txn, field_id)?);
+                    }
+                    None => {
+                        let (valid_fields, hidden_fields) =
+                            ctx.index.remove_hidden_fields(ctx.txn, filterable_fields)?;
+                        return Err(Error::UserError(UserError::InvalidDistinctAttribute {
+                            field: distinct.clone(),
+                            valid_fields,
+                            hidden_fields,
+                        }));
+                    }
+                }
             }
         }
 
@@ -236,8 +358,8 @@ impl<'a> Search<'a> {
                 self.offset,
                 self.limit,
                 Some(self.words_limit),
-                &mut DefaultSearchLogger,
-                &mut DefaultSearchLogger,
+                &mut PartialResultsLogger::new(self.partial_results),
+                &mut PartialResultsLogger::new(self.partial_results),
                 self.time_budget.clone(),
                 self.ranking_score_threshold,
                 self.locales.as_ref(),
@@ -266,6 +388,7 @@ impl fmt::Debug for Search<'_> {
         let Search {
             query,
             filter,
+            boosting_filter,
             offset,
             limit,
             sort_criteria,
@@ -282,11 +405,18 @@ impl fmt::Debug for Search<'_> {
             time_budget,
             ranking_score_threshold,
             locales,
+            max_synonym_expansions,
+            compute_total_hits,
+            cross_attribute_matching,
+            count_only,
+            ranking_rules,
+            partial_results,
         } = self;
         f.debug_struct("Search")
             .field("query", query)
             .field("vector", &"[...]")
             .field("filter", filter)
+            .field("boosting_filter", boosting_filter)
             .field("offset", offset)
             .field("limit", limit)
             .field("sort_criteria", sort_criteria)
@@ -303,6 +433,12 @@ impl fmt::Debug for Search<'_> {
             .field("time_budget", time_budget)
             .field("ranking_score_threshold", ranking_score_threshold)
             .field("locales", locales)
+            .field("max_synonym_expansions", max_synonym_expansions)
+            .field("compute_total_hits", compute_total_hits)
+            .field("cross_attribute_matching", cross_attribute_matching)
+            .field("count_only", count_only)
+            .field("ranking_rules", ranking_rules)
+            .field("partial_results", &partial_results.is_some())
             .finish()
     }
 }
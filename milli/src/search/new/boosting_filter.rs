@@ -0,0 +1,95 @@
+use roaring::RoaringBitmap;
+
+use super::logger::SearchLogger;
+use super::{RankingRule, RankingRuleOutput, RankingRuleQueryTrait, SearchContext};
+use crate::score_details::{self, ScoreDetails};
+use crate::Result;
+
+/// A ranking rule that produces 2 disjoint buckets:
+///
+/// 1. Documents from the universe matching the boosting filter of the search.
+/// 2. Documents from the universe not in (1).
+pub struct BoostingFilter<Query> {
+    /// The documents of the index matching the boosting filter.
+    boosted: RoaringBitmap,
+    state: State<Query>,
+}
+
+impl<Query> BoostingFilter<Query> {
+    pub fn new(boosted: RoaringBitmap) -> Self {
+        Self { boosted, state: State::Uninitialized }
+    }
+}
+
+/// Inner state of the ranking rule.
+enum State<Query> {
+    /// State between two iterations
+    Uninitialized,
+    /// The next call to `next` will output the documents in the universe matching the filter
+    Boosted(Query),
+    /// The next calls to `next` will output the input universe.
+    NotBoosted(Query),
+}
+
+impl<'ctx, Query: RankingRuleQueryTrait> RankingRule<'ctx, Query> for BoostingFilter<Query> {
+    fn id(&self) -> String {
+        "boosting_filter".to_owned()
+    }
+
+    #[tracing::instrument(level = "trace", skip_all, target = "search::boosting_filter")]
+    fn start_iteration(
+        &mut self,
+        _ctx: &mut SearchContext<'ctx>,
+        _logger: &mut dyn SearchLogger<Query>,
+        _universe: &RoaringBitmap,
+        query: &Query,
+    ) -> Result<()> {
+        self.state = State::Boosted(query.clone());
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "trace", skip_all, target = "search::boosting_filter")]
+    fn next_bucket(
+        &mut self,
+        _ctx: &mut SearchContext<'ctx>,
+        _logger: &mut dyn SearchLogger<Query>,
+        universe: &RoaringBitmap,
+    ) -> Result<Option<RankingRuleOutput<Query>>> {
+        let (state, output) = match std::mem::replace(&mut self.state, State::Uninitialized) {
+            State::Uninitialized => (State::Uninitialized, None),
+            State::Boosted(query) => (
+                State::NotBoosted(query.clone()),
+                Some(RankingRuleOutput {
+                    query,
+                    candidates: universe & &self.boosted,
+                    score: ScoreDetails::BoostingFilter(score_details::BoostingFilter {
+                        matches: true,
+                    }),
+                }),
+            ),
+            // the boosted documents were all output by the previous bucket
+            State::NotBoosted(query) => (
+                State::NotBoosted(query.clone()),
+                Some(RankingRuleOutput {
+                    query,
+                    candidates: universe.clone(),
+                    score: ScoreDetails::BoostingFilter(score_details::BoostingFilter {
+                        matches: false,
+                    }),
+                }),
+            ),
+        };
+        self.state = state;
+
+        Ok(output)
+    }
+
+    #[tracing::instrument(level = "trace", skip_all, target = "search::boosting_filter")]
+    fn end_iteration(
+        &mut self,
+        _ctx: &mut SearchContext<'ctx>,
+        _logger: &mut dyn SearchLogger<Query>,
+    ) {
+        self.state = State::Uninitialized;
+    }
+}
@@ -2,9 +2,12 @@ use roaring::RoaringBitmap;
 
 use super::logger::SearchLogger;
 use super::ranking_rules::{BoxRankingRule, RankingRuleQueryTrait};
+use super::telemetry::TimedSpan;
 use super::SearchContext;
 use crate::score_details::{ScoreDetails, ScoringStrategy};
-use crate::search::new::distinct::{apply_distinct_rule, distinct_single_docid, DistinctOutput};
+use crate::search::new::distinct::{
+    apply_distinct_rule, distinct_single_docid, start_distinct, DistinctOutput,
+};
 use crate::{Result, TimeBudget};
 
 pub struct BucketSortOutput {
@@ -45,12 +48,15 @@ pub fn bucket_sort<'ctx, Q: RankingRuleQueryTrait>(
     } else {
         None
     };
+    start_distinct(ctx);
+
+    let compute_total_hits = ctx.compute_total_hits;
 
     if universe.len() < from as u64 {
         return Ok(BucketSortOutput {
             docids: vec![],
             scores: vec![],
-            all_candidates: universe.clone(),
+            all_candidates: if compute_total_hits { universe.clone() } else { Default::default() },
             degraded: false,
         });
     }
@@ -66,12 +72,18 @@ pub fn bucket_sort<'ctx, Q: RankingRuleQueryTrait>(
                     continue;
                 }
 
-                distinct_single_docid(ctx.index, ctx.txn, distinct_fid, docid, &mut excluded)?;
-                results.push(docid);
+                if distinct_single_docid(ctx, distinct_fid, docid, &mut excluded)? {
+                    results.push(docid);
+                }
             }
 
-            let mut all_candidates = universe - excluded;
-            all_candidates.extend(results.iter().copied());
+            let all_candidates = if compute_total_hits {
+                let mut all_candidates = universe - excluded;
+                all_candidates.extend(results.iter().copied());
+                all_candidates
+            } else {
+                RoaringBitmap::new()
+            };
             // drain the results of the skipped elements
             // this **must** be done **after** writing the entire results in `all_candidates` to ensure
             // e.g. estimatedTotalHits is correct.
@@ -92,7 +104,11 @@ pub fn bucket_sort<'ctx, Q: RankingRuleQueryTrait>(
             return Ok(BucketSortOutput {
                 scores: vec![Default::default(); docids.len()],
                 docids,
-                all_candidates: universe.clone(),
+                all_candidates: if compute_total_hits {
+                    universe.clone()
+                } else {
+                    Default::default()
+                },
                 degraded: false,
             });
         };
@@ -100,9 +116,27 @@ pub fn bucket_sort<'ctx, Q: RankingRuleQueryTrait>(
 
     let ranking_rules_len = ranking_rules.len();
 
+    // The spans are entered each time their ranking rule is called, they record
+    // the number of buckets and documents returned by the ranking rule.
+    let mut ranking_rule_spans: Vec<_> = ranking_rules
+        .iter()
+        .map(|ranking_rule| {
+            TimedSpan::new(tracing::trace_span!(
+                target: "search::ranking_rule",
+                "ranking_rule",
+                ranking_rule = %ranking_rule.id(),
+                buckets = tracing::field::Empty,
+                documents = tracing::field::Empty,
+                duration_us = tracing::field::Empty,
+            ))
+        })
+        .collect();
+    let mut ranking_rule_outputs = vec![(0u64, 0u64); ranking_rules_len];
+
     logger.start_iteration_ranking_rule(0, ranking_rules[0].as_ref(), query, universe);
 
-    ranking_rules[0].start_iteration(ctx, logger, universe, query)?;
+    ranking_rule_spans[0]
+        .in_scope(|| ranking_rules[0].start_iteration(ctx, logger, universe, query))?;
 
     let mut ranking_rule_scores: Vec<ScoreDetails> = vec![];
 
@@ -128,7 +162,8 @@ pub fn bucket_sort<'ctx, Q: RankingRuleQueryTrait>(
                 &ranking_rule_universes[cur_ranking_rule_index],
             );
             ranking_rule_universes[cur_ranking_rule_index].clear();
-            ranking_rules[cur_ranking_rule_index].end_iteration(ctx, logger);
+            ranking_rule_spans[cur_ranking_rule_index]
+                .in_scope(|| ranking_rules[cur_ranking_rule_index].end_iteration(ctx, logger));
             if cur_ranking_rule_index == 0 {
                 break;
             } else {
@@ -140,7 +175,8 @@ pub fn bucket_sort<'ctx, Q: RankingRuleQueryTrait>(
         };
     }
 
-    let mut all_candidates = universe.clone();
+    // `None` when the total number of hits must not be computed.
+    let mut all_candidates = compute_total_hits.then(|| universe.clone());
     let mut valid_docids = vec![];
     let mut valid_scores = vec![];
     let mut cur_offset = 0usize;
@@ -176,7 +212,10 @@ pub fn bucket_sort<'ctx, Q: RankingRuleQueryTrait>(
                 if let Some(ranking_score_threshold) = ranking_score_threshold {
                     let current_score = ScoreDetails::global_score(ranking_rule_scores.iter());
                     if current_score < ranking_score_threshold {
-                        all_candidates -= bucket | &ranking_rule_universes[cur_ranking_rule_index];
+                        if let Some(all_candidates) = &mut all_candidates {
+                            *all_candidates -=
+                                bucket | &ranking_rule_universes[cur_ranking_rule_index];
+                        }
                         back!();
                         continue;
                     }
@@ -196,7 +235,7 @@ pub fn bucket_sort<'ctx, Q: RankingRuleQueryTrait>(
             return Ok(BucketSortOutput {
                 scores: valid_scores,
                 docids: valid_docids,
-                all_candidates,
+                all_candidates: all_candidates.unwrap_or_default(),
                 degraded: true,
             });
         }
@@ -213,16 +252,24 @@ pub fn bucket_sort<'ctx, Q: RankingRuleQueryTrait>(
             continue;
         }
 
-        let Some(next_bucket) = ranking_rules[cur_ranking_rule_index].next_bucket(
-            ctx,
-            logger,
-            &ranking_rule_universes[cur_ranking_rule_index],
-        )?
+        let Some(next_bucket) = ranking_rule_spans[cur_ranking_rule_index].in_scope(|| {
+            ranking_rules[cur_ranking_rule_index].next_bucket(
+                ctx,
+                logger,
+                &ranking_rule_universes[cur_ranking_rule_index],
+            )
+        })?
         else {
             back!();
             continue;
         };
 
+        let (buckets, documents) = &mut ranking_rule_outputs[cur_ranking_rule_index];
+        *buckets += 1;
+        *documents += next_bucket.candidates.len();
+        ranking_rule_spans[cur_ranking_rule_index].record("buckets", *buckets);
+        ranking_rule_spans[cur_ranking_rule_index].record("documents", *documents);
+
         ranking_rule_scores.push(next_bucket.score);
 
         logger.next_bucket_ranking_rule(
@@ -240,8 +287,10 @@ pub fn bucket_sort<'ctx, Q: RankingRuleQueryTrait>(
         if let Some(ranking_score_threshold) = ranking_score_threshold {
             let current_score = ScoreDetails::global_score(ranking_rule_scores.iter());
             if current_score < ranking_score_threshold {
-                all_candidates -=
-                    next_bucket.candidates | &ranking_rule_universes[cur_ranking_rule_index];
+                if let Some(all_candidates) = &mut all_candidates {
+                    *all_candidates -=
+                        next_bucket.candidates | &ranking_rule_universes[cur_ranking_rule_index];
+                }
                 back!();
                 continue;
             }
@@ -266,18 +315,20 @@ pub fn bucket_sort<'ctx, Q: RankingRuleQueryTrait>(
             &next_bucket.query,
             &ranking_rule_universes[cur_ranking_rule_index],
         );
-        ranking_rules[cur_ranking_rule_index].start_iteration(
-            ctx,
-            logger,
-            &next_bucket.candidates,
-            &next_bucket.query,
-        )?;
+        ranking_rule_spans[cur_ranking_rule_index].in_scope(|| {
+            ranking_rules[cur_ranking_rule_index].start_iteration(
+                ctx,
+                logger,
+                &next_bucket.candidates,
+                &next_bucket.query,
+            )
+        })?;
     }
 
     Ok(BucketSortOutput {
         docids: valid_docids,
         scores: valid_scores,
-        all_candidates,
+        all_candidates: all_candidates.unwrap_or_default(),
         degraded: false,
     })
 }
@@ -293,7 +344,7 @@ fn maybe_add_to_results<'ctx, Q: RankingRuleQueryTrait>(
 
     valid_docids: &mut Vec<u32>,
     valid_scores: &mut Vec<Vec<ScoreDetails>>,
-    all_candidates: &mut RoaringBitmap,
+    all_candidates: &mut Option<RoaringBitmap>,
 
     ranking_rule_universes: &mut [RoaringBitmap],
     ranking_rules: &mut [BoxRankingRule<'ctx, Q>],
@@ -312,13 +363,17 @@ fn maybe_add_to_results<'ctx, Q: RankingRuleQueryTrait>(
             apply_distinct_rule(ctx, distinct_fid, &candidates)?;
         for universe in ranking_rule_universes.iter_mut() {
             *universe -= &excluded;
-            *all_candidates -= &excluded;
+            if let Some(all_candidates) = all_candidates {
+                *all_candidates -= &excluded;
+            }
         }
         remaining
     } else {
         candidates.clone()
     };
-    *all_candidates |= &candidates;
+    if let Some(all_candidates) = all_candidates {
+        *all_candidates |= &candidates;
+    }
 
     // if the candidates are empty, there is nothing to do;
     if candidates.is_empty() {
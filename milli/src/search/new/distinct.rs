@@ -1,6 +1,12 @@
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
 use heed::types::{Bytes, Str, Unit};
 use heed::{Database, RoPrefix, RoTxn};
 use roaring::RoaringBitmap;
+use serde_json::Value;
+use zstd::dict::DecoderDictionary;
 
 const FID_SIZE: usize = 2;
 const DOCID_SIZE: usize = 4;
@@ -9,7 +15,10 @@ use crate::heed_codec::facet::{
     FacetGroupKey, FacetGroupKeyCodec, FacetGroupValueCodec, FieldDocIdFacetCodec,
 };
 use crate::heed_codec::BytesRefCodec;
-use crate::{Index, Result, SearchContext};
+use crate::{
+    FacetNormalization, FieldId, FieldsIdsMap, Index, InternalError, Result, SearchContext,
+    UserError,
+};
 
 pub struct DistinctOutput {
     pub remaining: RoaringBitmap,
@@ -33,20 +42,30 @@ pub fn apply_distinct_rule(
         if excluded.contains(docid) {
             continue;
         }
-        distinct_single_docid(ctx.index, ctx.txn, field_id, docid, &mut excluded)?;
-        remaining.push(docid);
+        if distinct_single_docid(ctx, field_id, docid, &mut excluded)? {
+            remaining.push(docid);
+        }
     }
     Ok(DistinctOutput { remaining, excluded })
 }
 
 /// Apply the distinct rule defined by [`apply_distinct_rule`] for a single document id.
+///
+/// Returns whether the document is kept. The values are read from the [`DocumentDistinct`] of
+/// the context when it was built for the field, and from the facet databases otherwise.
 pub fn distinct_single_docid(
-    index: &Index,
-    txn: &RoTxn<'_>,
+    ctx: &mut SearchContext<'_>,
     field_id: u16,
     docid: u32,
     excluded: &mut RoaringBitmap,
-) -> Result<()> {
+) -> Result<bool> {
+    let (index, txn) = (ctx.index, ctx.txn);
+    if let Some(document_distinct) =
+        ctx.document_distinct.as_mut().filter(|distinct| distinct.field_id == field_id)
+    {
+        return document_distinct.keep(index, txn, docid, excluded);
+    }
+
     for item in facet_string_values(docid, field_id, index, txn)? {
         let ((_, _, facet_value), _) = item?;
         if let Some(facet_docids) = facet_value_docids(
@@ -66,7 +85,149 @@ pub fn distinct_single_docid(
             *excluded |= facet_docids;
         }
     }
-    Ok(())
+    Ok(true)
+}
+
+/// Forgets the documents kept by the previous application of the distinct rule, before
+/// applying it to a new set of candidates.
+pub fn start_distinct(ctx: &mut SearchContext<'_>) {
+    if let Some(document_distinct) = &mut ctx.document_distinct {
+        document_distinct.kept_values.clear();
+    }
+}
+
+/// A value of a distinct field read from the documents.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum DistinctValue {
+    String(String),
+    Number(u64),
+}
+
+/// The values of a distinct field that isn't filterable, read from the documents themselves
+/// as the facet databases don't contain them.
+///
+/// Only the documents being checked are read, and their values are cached for the rest of the
+/// search. Strings are normalized like the facet values, so both distinct sources agree on which
+/// documents are duplicates.
+pub struct DocumentDistinct {
+    field_id: FieldId,
+    fields_ids_map: FieldsIdsMap,
+    /// The name of the field when it is nested, it is then only found in the flattened document.
+    nested_name: Option<String>,
+    normalization: FacetNormalization,
+    dictionary: Option<Arc<DecoderDictionary<'static>>>,
+    docid_values: HashMap<u32, Vec<DistinctValue>>,
+    /// The values of the documents kept since the distinct rule started to be applied.
+    kept_values: HashSet<DistinctValue>,
+}
+
+impl DocumentDistinct {
+    pub fn new(index: &Index, txn: &RoTxn<'_>, field_id: FieldId) -> Result<Self> {
+        let fields_ids_map = index.fields_ids_map(txn)?;
+        let nested_name =
+            fields_ids_map.name(field_id).filter(|name| name.contains('.')).map(String::from);
+
+        Ok(Self {
+            field_id,
+            fields_ids_map,
+            nested_name,
+            normalization: index.facet_normalization(txn, field_id)?,
+            dictionary: index.document_decompression_dictionary(txn)?,
+            docid_values: HashMap::new(),
+            kept_values: HashSet::new(),
+        })
+    }
+
+    /// Keeps the document if none of its values is shared with a document kept before,
+    /// excludes it otherwise.
+    fn keep(
+        &mut self,
+        index: &Index,
+        txn: &RoTxn<'_>,
+        docid: u32,
+        excluded: &mut RoaringBitmap,
+    ) -> Result<bool> {
+        let values = match self.docid_values.entry(docid) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                let values = read_distinct_values(
+                    index,
+                    txn,
+                    docid,
+                    self.field_id,
+                    &self.fields_ids_map,
+                    self.nested_name.as_deref(),
+                    self.normalization,
+                    self.dictionary.as_deref(),
+                )?;
+                entry.insert(values)
+            }
+        };
+
+        if values.iter().any(|value| self.kept_values.contains(value)) {
+            excluded.insert(docid);
+            Ok(false)
+        } else {
+            self.kept_values.extend(values.iter().cloned());
+            Ok(true)
+        }
+    }
+}
+
+/// Reads the distinct values of the field in the given document.
+#[allow(clippy::too_many_arguments)]
+fn read_distinct_values(
+    index: &Index,
+    txn: &RoTxn<'_>,
+    docid: u32,
+    field_id: FieldId,
+    fields_ids_map: &FieldsIdsMap,
+    nested_name: Option<&str>,
+    normalization: FacetNormalization,
+    dictionary: Option<&DecoderDictionary<'_>>,
+) -> Result<Vec<DistinctValue>> {
+    let obkv = index
+        .documents
+        .get(txn, &docid)?
+        .ok_or(UserError::UnknownInternalDocumentId { document_id: docid })?
+        .decompress(dictionary)?;
+    let value = match (obkv.get(field_id), nested_name) {
+        (Some(value), _) => Some(serde_json::from_slice(value).map_err(InternalError::SerdeJson)?),
+        (None, Some(name)) => {
+            let document = crate::all_obkv_to_json(obkv.as_obkv(), fields_ids_map)?;
+            flatten_serde_json::flatten(&document).remove(name)
+        }
+        (None, None) => None,
+    };
+
+    let mut values = Vec::new();
+    if let Some(value) = value {
+        distinct_values(value, normalization, &mut values);
+    }
+    Ok(values)
+}
+
+/// Pushes the distinct values of a JSON value, one for each element of an array.
+fn distinct_values(
+    value: Value,
+    normalization: FacetNormalization,
+    values: &mut Vec<DistinctValue>,
+) {
+    match value {
+        Value::String(string) => {
+            values.push(DistinctValue::String(normalization.normalize(&string)))
+        }
+        Value::Bool(boolean) => values.push(DistinctValue::String(boolean.to_string())),
+        Value::Number(number) => {
+            if let Some(number) = number.as_f64() {
+                values.push(DistinctValue::Number(number.to_bits()));
+            }
+        }
+        Value::Array(array) => {
+            array.into_iter().for_each(|value| distinct_values(value, normalization, values))
+        }
+        Value::Null | Value::Object(_) => (),
+    }
 }
 
 /// Return all the docids containing the given value in the given field
@@ -0,0 +1,104 @@
+use heed::types::Bytes;
+use heed::RoTxn;
+use serde_json::{Number, Value};
+
+use super::distinct::facet_string_values;
+use crate::heed_codec::facet::FieldDocIdFacetF64Codec;
+use crate::{DocumentId, Error, FieldId, Index, Result, UserError};
+
+/// A bucket of documents sharing the same value for the grouping field.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DocumentsGroup {
+    /// The value shared by the documents of the group,
+    /// `None` for the documents that do not contain the grouping field.
+    pub value: Option<Value>,
+    /// The documents of the group, in the order they were ranked.
+    pub documents_ids: Vec<DocumentId>,
+}
+
+/// Buckets the given ranked documents by the value they have for the given field.
+///
+/// Each group holds at most `limit_per_group` documents, the best ranked ones.
+/// The groups are ordered by the rank of their first document, and the documents that
+/// do not contain the field are gathered in an ungrouped bucket placed last.
+/// A document with several values for the field is put in the group of its first value.
+pub fn group_documents(
+    index: &Index,
+    rtxn: &RoTxn<'_>,
+    field: &str,
+    documents_ids: &[DocumentId],
+    limit_per_group: usize,
+) -> Result<Vec<DocumentsGroup>> {
+    let filterable_fields = index.filterable_fields(rtxn)?;
+    if !crate::is_faceted(field, &filterable_fields) {
+        let (valid_fields, hidden_fields) = index.remove_hidden_fields(rtxn, filterable_fields)?;
+        return Err(Error::UserError(UserError::InvalidGroupByAttribute {
+            field: field.to_string(),
+            valid_fields,
+            hidden_fields,
+        }));
+    }
+
+    let field_id = index.fields_ids_map(rtxn)?.id(field);
+    let mut groups: Vec<DocumentsGroup> = Vec::new();
+    let mut ungrouped = Vec::new();
+    for &docid in documents_ids {
+        let value = match field_id {
+            Some(field_id) => document_group_value(index, rtxn, field_id, docid)?,
+            None => None,
+        };
+
+        let documents_ids = match value {
+            Some(value) => {
+                let position = groups.iter().position(|group| group.value.as_ref() == Some(&value));
+                let position = position.unwrap_or_else(|| {
+                    groups.push(DocumentsGroup { value: Some(value), documents_ids: Vec::new() });
+                    groups.len() - 1
+                });
+                &mut groups[position].documents_ids
+            }
+            None => &mut ungrouped,
+        };
+        if documents_ids.len() < limit_per_group {
+            documents_ids.push(docid);
+        }
+    }
+
+    if !ungrouped.is_empty() {
+        groups.push(DocumentsGroup { value: None, documents_ids: ungrouped });
+    }
+
+    Ok(groups)
+}
+
+/// Returns the first facet value of the document for the given field, strings first.
+fn document_group_value(
+    index: &Index,
+    rtxn: &RoTxn<'_>,
+    field_id: FieldId,
+    docid: DocumentId,
+) -> Result<Option<Value>> {
+    if let Some(result) = facet_string_values(docid, field_id, index, rtxn)?.next() {
+        let (_, original) = result?;
+        return Ok(Some(Value::String(original.to_string())));
+    }
+
+    let prefix = [field_id.to_be_bytes().as_slice(), docid.to_be_bytes().as_slice()].concat();
+    let mut numbers = index
+        .field_id_docid_facet_f64s
+        .remap_key_type::<Bytes>()
+        .prefix_iter(rtxn, &prefix)?
+        .remap_key_type::<FieldDocIdFacetF64Codec>();
+    match numbers.next() {
+        Some(result) => {
+            let ((_, _, number), ()) = result?;
+            let value = if number.fract() == 0.0 && number.abs() < i64::MAX as f64 {
+                Value::Number((number as i64).into())
+            } else {
+                Number::from_f64(number).map_or(Value::Null, Value::Number)
+            };
+            Ok(Some(value))
+        }
+        None => Ok(None),
+    }
+}
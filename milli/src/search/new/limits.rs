@@ -8,7 +8,10 @@ pub const MAX_ONE_TYPO_COUNT: usize = 150;
 /// Maximum number of words that can be derived from a single word with a distance of two to that word.
 pub const MAX_TWO_TYPOS_COUNT: usize = 50;
 
-/// Maximum amount of synonym phrases that can be derived from a single word.
+/// Default maximum amount of synonym phrases that can be derived from a single word.
+///
+/// It can be lowered or raised by the `maxSynonymExpansions` setting of the index,
+/// or per search with `Search::max_synonym_expansions`.
 pub const MAX_SYNONYM_PHRASE_COUNT: usize = 50;
 
 /// Maximum amount of words inside of all the synonym phrases that can be derived from a single word.
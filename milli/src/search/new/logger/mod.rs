@@ -79,3 +79,33 @@ impl<Q: RankingRuleQueryTrait> SearchLogger<Q> for DefaultSearchLogger {
 
     fn log_internal_state(&mut self, _rr: &dyn Any) {}
 }
+
+/// A [`SearchLogger`] reporting the documents added to the final results, in order,
+/// as soon as the ranking rules bucket containing them is resolved.
+pub struct PartialResultsLogger<'a> {
+    on_results: Option<&'a dyn Fn(&[u32])>,
+}
+
+impl<'a> PartialResultsLogger<'a> {
+    pub fn new(on_results: Option<&'a dyn Fn(&[u32])>) -> Self {
+        PartialResultsLogger { on_results }
+    }
+}
+
+impl<Q: RankingRuleQueryTrait> SearchLogger<Q> for PartialResultsLogger<'_> {
+    fn initial_query(&mut self, _query: &Q) {}
+
+    fn initial_universe(&mut self, _universe: &RoaringBitmap) {}
+
+    fn query_for_initial_universe(&mut self, _query: &Q) {}
+
+    fn ranking_rules(&mut self, _rr: &[BoxRankingRule<'_, Q>]) {}
+
+    fn add_to_results(&mut self, docids: &[u32]) {
+        if let Some(on_results) = self.on_results.filter(|_| !docids.is_empty()) {
+            on_results(docids);
+        }
+    }
+
+    fn log_internal_state(&mut self, _rr: &dyn Any) {}
+}
@@ -1,13 +1,15 @@
+use std::borrow::Cow;
 use std::cmp::Reverse;
+use std::collections::HashSet;
 use std::fmt;
 use std::ops::RangeInclusive;
 
-use charabia::Token;
+use charabia::{Token, TokenKind};
 
 use super::super::interner::Interned;
 use super::super::query_term::LocatedQueryTerm;
 use super::super::{DedupInterner, Phrase};
-use crate::SearchContext;
+use crate::{NumberTokenization, SearchContext, Stemmer};
 
 pub struct LocatedMatchingPhrase {
     pub value: Interned<Phrase>,
@@ -29,6 +31,10 @@ pub struct MatchingWords {
     phrase_interner: DedupInterner<Phrase>,
     phrases: Vec<LocatedMatchingPhrase>,
     words: Vec<LocatedMatchingWords>,
+    /// The words of the query are stemmed, the words of the text must be too to match them.
+    stemmer: Stemmer,
+    /// The numbers of the text must be tokenized like the ones of the query to match them.
+    number_tokenization: NumberTokenization,
 }
 
 impl MatchingWords {
@@ -65,21 +71,36 @@ impl MatchingWords {
             words,
             word_interner: ctx.word_interner,
             phrase_interner: ctx.phrase_interner,
+            stemmer: ctx.stemmer,
+            number_tokenization: ctx.number_tokenization,
         }
     }
 
+    /// Returns the number of distinct query words the terms and phrases are located at.
+    pub fn query_word_count(&self) -> usize {
+        let words = self.words.iter().map(|lmw| &lmw.positions);
+        let phrases = self.phrases.iter().map(|lmp| &lmp.positions);
+        words.chain(phrases).flat_map(|positions| positions.clone()).collect::<HashSet<_>>().len()
+    }
+
+    /// Returns how the numbers of the text must be tokenized to match the terms.
+    pub fn number_tokenization(&self) -> NumberTokenization {
+        self.number_tokenization
+    }
+
     /// Returns an iterator over terms that match or partially match the given token.
     pub fn match_token<'a, 'b>(&'a self, token: &'b Token<'b>) -> MatchesIter<'a, 'b> {
-        MatchesIter { matching_words: self, phrases: Box::new(self.phrases.iter()), token }
+        let lemma = stemmed_lemma(&self.stemmer, token);
+        MatchesIter { matching_words: self, phrases: Box::new(self.phrases.iter()), token, lemma }
     }
 
     /// Try to match the token with one of the located_words.
-    fn match_unique_words<'a>(&'a self, token: &Token<'_>) -> Option<MatchType<'a>> {
+    fn match_unique_words<'a>(&'a self, token: &Token<'_>, lemma: &str) -> Option<MatchType<'a>> {
         for located_words in &self.words {
             for word in &located_words.value {
                 let word = self.word_interner.get(*word);
                 // if the word is a prefix we match using starts_with.
-                if located_words.is_prefix && token.lemma().starts_with(word) {
+                if located_words.is_prefix && lemma.starts_with(word) {
                     let Some((char_index, c)) =
                         word.char_indices().take(located_words.original_char_count).last()
                     else {
@@ -90,7 +111,7 @@ impl MatchingWords {
                     let ids = &located_words.positions;
                     return Some(MatchType::Full { char_len, ids });
                 // else we exact match the token.
-                } else if token.lemma() == word {
+                } else if lemma == word {
                     let char_len = token.char_end - token.char_start;
                     let ids = &located_words.positions;
                     return Some(MatchType::Full { char_len, ids });
@@ -108,6 +129,7 @@ pub struct MatchesIter<'a, 'b> {
     matching_words: &'a MatchingWords,
     phrases: Box<dyn Iterator<Item = &'a LocatedMatchingPhrase> + 'a>,
     token: &'b Token<'b>,
+    lemma: Cow<'b, str>,
 }
 
 impl<'a> Iterator for MatchesIter<'a, '_> {
@@ -130,12 +152,17 @@ impl<'a> Iterator for MatchesIter<'a, '_> {
                         word.map(|word| self.matching_words.word_interner.get(word).as_str())
                     })
                     .collect();
-                let partial = PartialMatch { matching_words: words, ids, char_len: 0 };
+                let partial = PartialMatch {
+                    matching_words: words,
+                    ids,
+                    char_len: 0,
+                    stemmer: &self.matching_words.stemmer,
+                };
 
                 partial.match_token(self.token).or_else(|| self.next())
             }
             // If no phrases matches, try to match uiques words.
-            None => self.matching_words.match_unique_words(self.token),
+            None => self.matching_words.match_unique_words(self.token, &self.lemma),
         }
     }
 }
@@ -159,6 +186,7 @@ pub struct PartialMatch<'a> {
     matching_words: Vec<Option<&'a str>>,
     ids: &'a RangeInclusive<WordId>,
     char_len: usize,
+    stemmer: &'a Stemmer,
 }
 
 impl<'a> PartialMatch<'a> {
@@ -167,10 +195,10 @@ impl<'a> PartialMatch<'a> {
     /// - Partial if the given token matches the partial match but doesn't complete it
     /// - Full if the given token completes the partial match
     pub fn match_token(self, token: &Token<'_>) -> Option<MatchType<'a>> {
-        let Self { mut matching_words, ids, .. } = self;
+        let Self { mut matching_words, ids, stemmer, .. } = self;
 
         let is_matching = match matching_words.first()? {
-            Some(word) => &token.lemma() == word,
+            Some(word) => stemmed_lemma(stemmer, token) == *word,
             // a None value in the phrase corresponds to a stop word,
             // the walue is considered a match if the current token is categorized as a stop word.
             None => token.is_stopword(),
@@ -181,7 +209,7 @@ impl<'a> PartialMatch<'a> {
         // return a new Partial match allowing the highlighter to continue.
         if is_matching && matching_words.len() > 1 {
             matching_words.remove(0);
-            Some(MatchType::Partial(PartialMatch { matching_words, ids, char_len }))
+            Some(MatchType::Partial(PartialMatch { matching_words, ids, char_len, stemmer }))
         // if there is no remaining word to match in the phrase and the current token is matching,
         // return a Full match.
         } else if is_matching {
@@ -197,9 +225,24 @@ impl<'a> PartialMatch<'a> {
     }
 }
 
+/// The lemma of the token, stemmed like the words of the query and of the indexed documents.
+fn stemmed_lemma<'t>(stemmer: &Stemmer, token: &'t Token<'_>) -> Cow<'t, str> {
+    match token.kind {
+        TokenKind::Word => stemmer.stem(token.lemma(), token.language),
+        _ => Cow::Borrowed(token.lemma()),
+    }
+}
+
 impl fmt::Debug for MatchingWords {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let MatchingWords { word_interner, phrase_interner, phrases, words } = self;
+        let MatchingWords {
+            word_interner,
+            phrase_interner,
+            phrases,
+            words,
+            stemmer: _,
+            number_tokenization: _,
+        } = self;
 
         let phrases: Vec<_> = phrases
             .iter()
@@ -5,6 +5,8 @@ pub use matching_words::MatchingWords;
 use matching_words::{MatchType, PartialMatch, WordId};
 use serde::Serialize;
 
+use super::telemetry::TimedSpan;
+
 pub mod matching_words;
 
 const DEFAULT_CROP_MARKER: &str = "…";
@@ -18,6 +20,7 @@ pub struct MatcherBuilder<'m> {
     crop_marker: Option<String>,
     highlight_prefix: Option<String>,
     highlight_suffix: Option<String>,
+    prefix_highlight: PrefixHighlight,
 }
 
 impl<'m> MatcherBuilder<'m> {
@@ -28,6 +31,7 @@ impl<'m> MatcherBuilder<'m> {
             crop_marker: None,
             highlight_prefix: None,
             highlight_suffix: None,
+            prefix_highlight: PrefixHighlight::default(),
         }
     }
 
@@ -46,6 +50,16 @@ impl<'m> MatcherBuilder<'m> {
         self
     }
 
+    pub fn prefix_highlight(&mut self, prefix_highlight: PrefixHighlight) -> &Self {
+        self.prefix_highlight = prefix_highlight;
+        self
+    }
+
+    /// Returns the number of distinct words of the query, the words of a phrase are counted one by one.
+    pub fn query_word_count(&self) -> usize {
+        self.matching_words.query_word_count()
+    }
+
     pub fn build<'t, 'lang>(
         &self,
         text: &'t str,
@@ -71,12 +85,33 @@ impl<'m> MatcherBuilder<'m> {
             crop_marker,
             highlight_prefix,
             highlight_suffix,
+            prefix_highlight: self.prefix_highlight,
             matches: None,
             locales,
         }
     }
 }
 
+/// How the words only matched by their prefix, like `shoes` for the query `sho`, are highlighted.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum PrefixHighlight {
+    /// Only the matching prefix of the word is highlighted.
+    #[default]
+    Partial,
+    /// The whole word is highlighted.
+    Word,
+    /// The word is not highlighted, only the words fully matching a query term are.
+    None,
+}
+
+/// The highlight tags and crop marker of an attribute, replacing the ones of the [`MatcherBuilder`].
+#[derive(Clone, Default, Debug, PartialEq, Eq)]
+pub struct FormatTags {
+    pub highlight_prefix: Option<String>,
+    pub highlight_suffix: Option<String>,
+    pub crop_marker: Option<String>,
+}
+
 #[derive(Copy, Clone, Default, Debug)]
 pub struct FormatOptions {
     pub highlight: bool,
@@ -120,9 +155,25 @@ pub struct Matcher<'t, 'tokenizer, 'b, 'lang> {
     crop_marker: &'b str,
     highlight_prefix: &'b str,
     highlight_suffix: &'b str,
+    prefix_highlight: PrefixHighlight,
     matches: Option<(Vec<Token<'t>>, Vec<Match>)>,
 }
 
+impl<'b> Matcher<'_, '_, 'b, '_> {
+    /// Formats the text with the tags defined in `tags` instead of the ones of the builder.
+    pub fn override_tags(&mut self, tags: &'b FormatTags) {
+        if let Some(prefix) = &tags.highlight_prefix {
+            self.highlight_prefix = prefix.as_str();
+        }
+        if let Some(suffix) = &tags.highlight_suffix {
+            self.highlight_suffix = suffix.as_str();
+        }
+        if let Some(marker) = &tags.crop_marker {
+            self.crop_marker = marker.as_str();
+        }
+    }
+}
+
 impl<'t, 'tokenizer> Matcher<'t, 'tokenizer, '_, '_> {
     /// Iterates over tokens and save any of them that matches the query.
     fn compute_matches(&mut self) -> &mut Self {
@@ -179,8 +230,8 @@ impl<'t, 'tokenizer> Matcher<'t, 'tokenizer, '_, '_> {
             false
         }
 
-        let tokens: Vec<_> =
-            self.tokenizer.tokenize_with_allow_list(self.text, self.locales).collect();
+        let tokens = self.tokenizer.tokenize_with_allow_list(self.text, self.locales);
+        let tokens: Vec<_> = self.matching_words.number_tokenization().tokens(tokens).collect();
         let mut matches = Vec::new();
 
         let mut words_positions = tokens
@@ -250,6 +301,21 @@ impl<'t, 'tokenizer> Matcher<'t, 'tokenizer, '_, '_> {
         }
     }
 
+    /// Returns the positions in the query of the words that match the text.
+    ///
+    /// A synonym or a phrase counts for all the query words it derives from.
+    pub fn matched_query_words(&mut self) -> Vec<WordId> {
+        match &self.matches {
+            None => self.compute_matches().matched_query_words(),
+            Some((_, matches)) => {
+                let mut ids: Vec<_> = matches.iter().flat_map(|m| m.ids.iter().copied()).collect();
+                ids.sort_unstable();
+                ids.dedup();
+                ids
+            }
+        }
+    }
+
     /// Returns the bounds in byte index of the crop window.
     fn crop_bounds(
         &self,
@@ -432,77 +498,102 @@ impl<'t, 'tokenizer> Matcher<'t, 'tokenizer, '_, '_> {
             // compute matches is not needed if no highlight nor crop is requested.
             Cow::Borrowed(self.text)
         } else {
-            match &self.matches {
-                Some((tokens, matches)) => {
-                    // If the text has to be cropped,
-                    // crop around the best interval.
-                    let (byte_start, byte_end) = match format_options.crop {
-                        Some(crop_size) if crop_size > 0 => {
-                            let matches = self.find_best_match_interval(matches, crop_size);
-                            self.crop_bounds(tokens, matches, crop_size)
-                        }
-                        _ => (0, self.text.len()),
-                    };
-
-                    let mut formatted = Vec::new();
+            let mut span = TimedSpan::new(tracing::trace_span!(
+                target: "search::format",
+                "format",
+                matches = tracing::field::Empty,
+                duration_us = tracing::field::Empty,
+            ));
+            let formatted = span.in_scope(|| self.format_matches(format_options));
+            if let Some((_, matches)) = &self.matches {
+                span.record("matches", matches.len() as u64);
+            }
+            formatted
+        }
+    }
 
-                    // push crop marker if it's not the start of the text.
-                    if byte_start > 0 && !self.crop_marker.is_empty() {
-                        formatted.push(self.crop_marker);
+    fn format_matches(&mut self, format_options: FormatOptions) -> Cow<'t, str> {
+        match &self.matches {
+            Some((tokens, matches)) => {
+                // If the text has to be cropped,
+                // crop around the best interval.
+                let (byte_start, byte_end) = match format_options.crop {
+                    Some(crop_size) if crop_size > 0 => {
+                        let matches = self.find_best_match_interval(matches, crop_size);
+                        self.crop_bounds(tokens, matches, crop_size)
                     }
+                    _ => (0, self.text.len()),
+                };
 
-                    let mut byte_index = byte_start;
+                let mut formatted = Vec::new();
 
-                    if format_options.highlight {
-                        // insert highlight markers around matches.
-                        for m in matches {
-                            let token = &tokens[m.token_position];
+                // push crop marker if it's not the start of the text.
+                if byte_start > 0 && !self.crop_marker.is_empty() {
+                    formatted.push(self.crop_marker);
+                }
 
-                            // skip matches out of the crop window.
-                            if token.byte_start < byte_start || token.byte_end > byte_end {
-                                continue;
-                            }
+                let mut byte_index = byte_start;
 
-                            if byte_index < token.byte_start {
-                                formatted.push(&self.text[byte_index..token.byte_start]);
-                            }
+                if format_options.highlight {
+                    // insert highlight markers around matches.
+                    for m in matches {
+                        let token = &tokens[m.token_position];
 
-                            let highlight_byte_index = self.text[token.byte_start..]
-                                .char_indices()
-                                .enumerate()
-                                .find(|(i, _)| *i == m.match_len)
-                                .map_or(token.byte_end, |(_, (i, _))| i + token.byte_start);
-                            formatted.push(self.highlight_prefix);
-                            formatted.push(&self.text[token.byte_start..highlight_byte_index]);
-                            formatted.push(self.highlight_suffix);
-                            // if it's a prefix highlight, we put the end of the word after the highlight marker.
-                            if highlight_byte_index < token.byte_end {
-                                formatted.push(&self.text[highlight_byte_index..token.byte_end]);
+                        // skip matches out of the crop window.
+                        if token.byte_start < byte_start || token.byte_end > byte_end {
+                            continue;
+                        }
+
+                        let mut highlight_byte_index = self.text[token.byte_start..]
+                            .char_indices()
+                            .enumerate()
+                            .find(|(i, _)| *i == m.match_len)
+                            .map_or(token.byte_end, |(_, (i, _))| i + token.byte_start);
+
+                        // the match doesn't cover the whole token, it's a prefix match.
+                        if highlight_byte_index < token.byte_end {
+                            match self.prefix_highlight {
+                                PrefixHighlight::Partial => (),
+                                PrefixHighlight::Word => highlight_byte_index = token.byte_end,
+                                // the token is pushed along with the text following it.
+                                PrefixHighlight::None => continue,
                             }
+                        }
 
-                            byte_index = token.byte_end;
+                        if byte_index < token.byte_start {
+                            formatted.push(&self.text[byte_index..token.byte_start]);
                         }
-                    }
 
-                    // push the rest of the text between last match and the end of crop.
-                    if byte_index < byte_end {
-                        formatted.push(&self.text[byte_index..byte_end]);
-                    }
+                        formatted.push(self.highlight_prefix);
+                        formatted.push(&self.text[token.byte_start..highlight_byte_index]);
+                        formatted.push(self.highlight_suffix);
+                        // if it's a prefix highlight, we put the end of the word after the highlight marker.
+                        if highlight_byte_index < token.byte_end {
+                            formatted.push(&self.text[highlight_byte_index..token.byte_end]);
+                        }
 
-                    // push crop marker if it's not the end of the text.
-                    if byte_end < self.text.len() && !self.crop_marker.is_empty() {
-                        formatted.push(self.crop_marker);
+                        byte_index = token.byte_end;
                     }
+                }
 
-                    if formatted.len() == 1 {
-                        // avoid concatenating if there is already 1 slice.
-                        Cow::Borrowed(&self.text[byte_start..byte_end])
-                    } else {
-                        Cow::Owned(formatted.concat())
-                    }
+                // push the rest of the text between last match and the end of crop.
+                if byte_index < byte_end {
+                    formatted.push(&self.text[byte_index..byte_end]);
+                }
+
+                // push crop marker if it's not the end of the text.
+                if byte_end < self.text.len() && !self.crop_marker.is_empty() {
+                    formatted.push(self.crop_marker);
+                }
+
+                if formatted.len() == 1 {
+                    // avoid concatenating if there is already 1 slice.
+                    Cow::Borrowed(&self.text[byte_start..byte_end])
+                } else {
+                    Cow::Owned(formatted.concat())
                 }
-                None => self.compute_matches().format(format_options),
             }
+            None => self.compute_matches().format_matches(format_options),
         }
     }
 }
@@ -621,6 +712,50 @@ mod tests {
         );
     }
 
+    #[test]
+    fn highlight_stemmed_words() {
+        let temp_index = TempIndex::new();
+        temp_index.update_settings(|settings| settings.set_stemming(vec![Language::Eng])).unwrap();
+        temp_index
+            .add_documents(documents!([
+                { "id": 1, "name": "the shoes of the worlds" },
+            ]))
+            .unwrap();
+        let rtxn = temp_index.read_txn().unwrap();
+        let builder = MatcherBuilder::new_test(&rtxn, &temp_index, "shoe worlds");
+        let format_options = FormatOptions { highlight: true, crop: None };
+
+        // the inflected forms of the words match like they do when searching.
+        let text = "Red shoes for the world";
+        let mut matcher = builder.build(text, None);
+        insta::assert_snapshot!(
+            matcher.format(format_options),
+            @"Red <em>shoes</em> for the <em>world</em>"
+        );
+    }
+
+    #[test]
+    fn highlight_kept_numbers() {
+        let temp_index = TempIndex::new();
+        temp_index
+            .update_settings(|settings| {
+                settings.set_number_tokenization(crate::NumberTokenization::Keep)
+            })
+            .unwrap();
+        temp_index.add_documents(documents!([{ "id": 1, "name": "version 3.5" }])).unwrap();
+        let rtxn = temp_index.read_txn().unwrap();
+        let builder = MatcherBuilder::new_test(&rtxn, &temp_index, "3.5 version");
+        let format_options = FormatOptions { highlight: true, crop: None };
+
+        // the numbers are matched whole, like they are searched.
+        let text = "Released in 3.5, after 3";
+        let mut matcher = builder.build(text, None);
+        insta::assert_snapshot!(
+            matcher.format(format_options),
+            @"Released in <em>3.5</em>, after 3"
+        );
+    }
+
     #[test]
     fn highlight_unicode() {
         let temp_index = temp_index_with_documents();
@@ -659,6 +794,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn highlight_prefix_matches() {
+        let temp_index = temp_index_with_documents();
+        let rtxn = temp_index.read_txn().unwrap();
+        let format_options = FormatOptions { highlight: true, crop: None };
+        let text = "the world of westfalia";
+
+        let builder = MatcherBuilder::new_test(&rtxn, &temp_index, "world westfali");
+        let mut matcher = builder.build(text, None);
+        insta::assert_snapshot!(
+            matcher.format(format_options),
+            @"the <em>world</em> of <em>westfali</em>a"
+        );
+
+        let mut builder = MatcherBuilder::new_test(&rtxn, &temp_index, "world westfali");
+        builder.prefix_highlight(PrefixHighlight::Word);
+        let mut matcher = builder.build(text, None);
+        insta::assert_snapshot!(
+            matcher.format(format_options),
+            @"the <em>world</em> of <em>westfalia</em>"
+        );
+
+        let mut builder = MatcherBuilder::new_test(&rtxn, &temp_index, "world westfali");
+        builder.prefix_highlight(PrefixHighlight::None);
+        let mut matcher = builder.build(text, None);
+        insta::assert_snapshot!(
+            matcher.format(format_options),
+            @"the <em>world</em> of westfalia"
+        );
+        // the positions of the matches are unchanged
+        assert_eq!(matcher.matches().len(), 2);
+    }
+
     #[test]
     fn format_crop() {
         let temp_index = temp_index_with_documents();
@@ -885,6 +1053,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn format_with_overridden_tags() {
+        let temp_index = temp_index_with_documents();
+        let rtxn = temp_index.read_txn().unwrap();
+        let builder = MatcherBuilder::new_test(&rtxn, &temp_index, "world");
+        let format_options = FormatOptions { highlight: true, crop: None };
+        let text = "the world of westfalia";
+
+        let tags = FormatTags {
+            highlight_prefix: Some("<b>".to_string()),
+            highlight_suffix: None,
+            crop_marker: None,
+        };
+        let mut matcher = builder.build(text, None);
+        matcher.override_tags(&tags);
+        // the tags that aren't overridden are the ones of the builder
+        insta::assert_snapshot!(
+            matcher.format(format_options),
+            @"the <b>world</em> of westfalia"
+        );
+    }
+
     #[test]
     fn partial_matches() {
         let temp_index = temp_index_with_documents();
@@ -1,18 +1,23 @@
+mod boosting_filter;
 mod bucket_sort;
 mod db_cache;
 mod distinct;
 mod geo_sort;
 mod graph_based_ranking_rule;
+mod group_by;
 mod interner;
 mod limits;
 mod logger;
 pub mod matches;
+mod query_analysis;
 mod query_graph;
 mod query_term;
 mod ranking_rule_graph;
 mod ranking_rules;
+mod recency_decay;
 mod resolve_query_graph;
 mod small_bitmap;
+mod telemetry;
 
 mod exact_attribute;
 mod sort;
@@ -21,8 +26,10 @@ mod vector_sort;
 #[cfg(test)]
 mod tests;
 
-use std::collections::HashSet;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap, HashSet};
 
+use boosting_filter::BoostingFilter;
 use bucket_sort::{bucket_sort, BucketSortOutput};
 use charabia::{Language, TokenizerBuilder};
 use db_cache::DatabaseCache;
@@ -31,30 +38,39 @@ use graph_based_ranking_rule::{Exactness, Fid, Position, Proximity, Typo};
 use heed::RoTxn;
 use interner::{DedupInterner, Interner};
 pub use logger::visual::VisualSearchLogger;
-pub use logger::{DefaultSearchLogger, SearchLogger};
-use query_graph::{QueryGraph, QueryNode};
+pub use logger::{DefaultSearchLogger, PartialResultsLogger, SearchLogger};
+use query_graph::{QueryGraph, QueryNode, QueryNodeData};
 use query_term::{
     located_query_terms_from_tokens, ExtractedTokens, LocatedQueryTerm, Phrase, QueryTerm,
+    QueryTermSubset,
 };
 use ranking_rules::{
     BoxRankingRule, PlaceholderQuery, RankingRule, RankingRuleOutput, RankingRuleQueryTrait,
 };
-use resolve_query_graph::{compute_query_graph_docids, PhraseDocIdsCache};
+use recency_decay::RecencyDecay;
+use resolve_query_graph::{
+    compute_query_graph_docids, compute_query_term_subset_docids_within_field_id, PhraseDocIdsCache,
+};
 use roaring::RoaringBitmap;
 use sort::Sort;
 
 use self::distinct::facet_string_values;
+pub use self::distinct::DocumentDistinct;
 use self::geo_sort::GeoSort;
 pub use self::geo_sort::Strategy as GeoSortStrategy;
 use self::graph_based_ranking_rule::Words;
+pub use self::group_by::{group_documents, DocumentsGroup};
 use self::interner::Interned;
+pub(crate) use self::limits::MAX_SYNONYM_PHRASE_COUNT;
+pub use self::query_analysis::{analyze_query, AnalyzedTerm, QueryAnalysis};
+use self::telemetry::TimedSpan;
 use self::vector_sort::VectorSort;
 use crate::score_details::{ScoreDetails, ScoringStrategy};
-use crate::search::new::distinct::apply_distinct_rule;
+use crate::search::new::distinct::{apply_distinct_rule, start_distinct};
 use crate::vector::Embedder;
 use crate::{
-    AscDesc, DocumentId, FieldId, Filter, Index, Member, Result, TermsMatchingStrategy, TimeBudget,
-    UserError, Weight,
+    is_faceted_by, AscDesc, AttributePrefixSearch, DocumentId, FieldId, Filter, Index, Member,
+    NumberTokenization, Result, Stemmer, TermsMatchingStrategy, TimeBudget, UserError, Weight,
 };
 
 /// A structure used throughout the execution of a search query.
@@ -67,12 +83,51 @@ pub struct SearchContext<'ctx> {
     pub term_interner: Interner<QueryTerm>,
     pub phrase_docids: PhraseDocIdsCache,
     pub restricted_fids: Option<RestrictedFids>,
+    /// How the prefix of the last query term matches the searchable fields.
+    pub prefix_search: PrefixSearchFids,
+    /// Maximum number of synonym phrases a single query term can be expanded to.
+    pub max_synonym_expansions: usize,
+    /// The synonyms of the index, read once for all the query terms of the search.
+    synonyms: Option<HashMap<Vec<String>, Vec<Vec<String>>>>,
+    /// Whether the candidates matching the search are gathered to be counted.
+    ///
+    /// When disabled, the candidates of the search result are left empty.
+    pub compute_total_hits: bool,
+    /// Whether the query terms can match a document across several of its searchable fields.
+    ///
+    /// When disabled, the documents must contain all the query terms within a single field.
+    pub cross_attribute_matching: bool,
+    /// Whether the search only counts the documents matching it.
+    ///
+    /// When enabled, the candidates are resolved but no ranking rule is applied
+    /// and no document id is returned.
+    pub count_only: bool,
+    /// The ranking rules applied to this search instead of the ones of the settings.
+    pub ranking_rules: Option<Vec<crate::Criterion>>,
+    /// The documents matching the boosting filter of this search, ranked before all the others.
+    pub boosted_documents: Option<RoaringBitmap>,
+    /// The values of the distinct field read from the documents, when it isn't filterable.
+    pub document_distinct: Option<DocumentDistinct>,
+    /// The weight of each typo in the score of the typo ranking rule.
+    pub typo_penalty: u32,
+    /// The stemmer of the `stemming` setting, applied to the words of the query and of the
+    /// highlighted documents like it was to the indexed words.
+    pub stemmer: Stemmer,
+    /// How the numbers of the query and of the highlighted documents are tokenized, like they
+    /// were when indexed.
+    pub number_tokenization: NumberTokenization,
 }
 
 impl<'ctx> SearchContext<'ctx> {
     pub fn new(index: &'ctx Index, txn: &'ctx RoTxn<'ctx>) -> Result<Self> {
         let searchable_fids = index.searchable_fields_and_weights(txn)?;
         let exact_attributes_ids = index.exact_attributes_ids(txn)?;
+        let prefix_search =
+            PrefixSearchFids::new(&index.attributes_prefix_search(txn)?, &searchable_fids);
+        let typo_penalty = index.typo_penalty(txn)? as u32;
+        let stemmer = Stemmer::new(index.stemming(txn)?);
+        let number_tokenization = index.number_tokenization(txn)?;
+        let max_synonym_expansions = index.max_synonym_expansions(txn)?;
 
         let mut exact = Vec::new();
         let mut tolerant = Vec::new();
@@ -93,9 +148,30 @@ impl<'ctx> SearchContext<'ctx> {
             term_interner: <_>::default(),
             phrase_docids: <_>::default(),
             restricted_fids: None,
+            prefix_search,
+            max_synonym_expansions,
+            synonyms: None,
+            compute_total_hits: true,
+            cross_attribute_matching: true,
+            count_only: false,
+            ranking_rules: None,
+            boosted_documents: None,
+            document_distinct: None,
+            typo_penalty,
+            stemmer,
+            number_tokenization,
         })
     }
 
+    /// Returns the synonym phrases of the given words, in the order they were defined.
+    pub(crate) fn synonyms_of(&mut self, words: &[String]) -> Result<Vec<Vec<String>>> {
+        let synonyms = match &mut self.synonyms {
+            Some(synonyms) => synonyms,
+            synonyms => synonyms.insert(self.index.synonyms(self.txn)?),
+        };
+        Ok(synonyms.get(words).cloned().unwrap_or_default())
+    }
+
     pub fn attributes_to_search_on(
         &mut self,
         attributes_to_search_on: &'ctx [String],
@@ -152,6 +228,17 @@ impl<'ctx> SearchContext<'ctx> {
 
         Ok(())
     }
+
+    /// Returns the ranking rules of this search as they must be applied.
+    ///
+    /// See [`Index::resolved_criteria`].
+    pub fn resolved_ranking_rules(&self) -> Result<Vec<crate::Criterion>> {
+        let ranking_rules = match &self.ranking_rules {
+            Some(ranking_rules) => self.index.resolve_criteria(self.txn, ranking_rules.clone())?,
+            None => self.index.resolved_criteria(self.txn)?,
+        };
+        Ok(ranking_rules)
+    }
 }
 
 #[derive(Clone, Copy, PartialEq, PartialOrd, Ord, Eq)]
@@ -181,6 +268,52 @@ impl RestrictedFids {
     }
 }
 
+/// The searchable fields whose prefix search differs from the default one.
+#[derive(Debug, Clone, Default)]
+pub struct PrefixSearchFids {
+    /// The searchable fields matching the prefixes, `None` when all of them do.
+    pub enabled: Option<Vec<FieldId>>,
+    /// The penalty of the prefix matches of the fields, when not null.
+    pub penalties: Vec<(FieldId, u16)>,
+}
+
+impl PrefixSearchFids {
+    fn new(
+        attributes_prefix_search: &BTreeMap<String, AttributePrefixSearch>,
+        searchable_fids: &[(Cow<'_, str>, FieldId, Weight)],
+    ) -> Self {
+        let mut all_enabled = true;
+        let mut enabled = Vec::new();
+        let mut penalties = Vec::new();
+        for (name, fid, _weight) in searchable_fids {
+            let prefix_search = attributes_prefix_search
+                .iter()
+                .find(|(attribute, _)| is_faceted_by(name, attribute))
+                .map(|(_, prefix_search)| *prefix_search)
+                .unwrap_or_default();
+
+            if prefix_search.enabled {
+                enabled.push(*fid);
+            } else {
+                all_enabled = false;
+            }
+            if prefix_search.penalty != 0 {
+                penalties.push((*fid, prefix_search.penalty));
+            }
+        }
+
+        Self { enabled: if all_enabled { None } else { Some(enabled) }, penalties }
+    }
+
+    pub fn is_enabled(&self, fid: FieldId) -> bool {
+        self.enabled.as_ref().map_or(true, |fids| fids.contains(&fid))
+    }
+
+    pub fn penalty(&self, fid: FieldId) -> u16 {
+        self.penalties.iter().find(|(id, _)| *id == fid).map_or(0, |(_, penalty)| *penalty)
+    }
+}
+
 /// Apply the [`TermsMatchingStrategy`] to the query graph and resolve it.
 fn resolve_maximally_reduced_query_graph(
     ctx: &mut SearchContext<'_>,
@@ -189,6 +322,20 @@ fn resolve_maximally_reduced_query_graph(
     matching_strategy: TermsMatchingStrategy,
     logger: &mut dyn SearchLogger<QueryGraph>,
 ) -> Result<RoaringBitmap> {
+    let graph = maximally_reduced_query_graph(ctx, query_graph, matching_strategy)?;
+
+    logger.query_for_initial_universe(&graph);
+    let docids = compute_query_graph_docids(ctx, &graph, universe)?;
+
+    Ok(docids)
+}
+
+/// Removes from the query graph all the nodes the [`TermsMatchingStrategy`] allows to remove.
+fn maximally_reduced_query_graph(
+    ctx: &mut SearchContext<'_>,
+    query_graph: &QueryGraph,
+    matching_strategy: TermsMatchingStrategy,
+) -> Result<QueryGraph> {
     let mut graph = query_graph.clone();
 
     let nodes_to_remove = match matching_strategy {
@@ -206,10 +353,7 @@ fn resolve_maximally_reduced_query_graph(
     };
     graph.remove_nodes_keep_edges(&nodes_to_remove);
 
-    logger.query_for_initial_universe(&graph);
-    let docids = compute_query_graph_docids(ctx, &graph, universe)?;
-
-    Ok(docids)
+    Ok(graph)
 }
 
 #[tracing::instrument(level = "trace", skip_all, target = "search::universe")]
@@ -229,6 +373,63 @@ fn resolve_universe(
     )
 }
 
+/// Returns the documents of the universe containing, within a single searchable field, all the
+/// query terms that the [`TermsMatchingStrategy`] does not allow to remove.
+#[tracing::instrument(level = "trace", skip_all, target = "search::query")]
+fn resolve_single_field_universe(
+    ctx: &mut SearchContext<'_>,
+    universe: &RoaringBitmap,
+    query_graph: &QueryGraph,
+    query_terms: &[LocatedQueryTerm],
+    matching_strategy: TermsMatchingStrategy,
+) -> Result<RoaringBitmap> {
+    let graph = maximally_reduced_query_graph(ctx, query_graph, matching_strategy)?;
+    let kept_terms: HashSet<u8> = graph
+        .nodes
+        .iter()
+        .filter_map(|(_, node)| match &node.data {
+            // the ngrams are alternatives to the terms they are made of
+            QueryNodeData::Term(term) if term.term_ids.start() == term.term_ids.end() => {
+                Some(*term.term_ids.start())
+            }
+            _ => None,
+        })
+        .collect();
+    let query_terms: Vec<_> = query_terms
+        .iter()
+        .enumerate()
+        .filter(|(term_idx, _)| kept_terms.contains(&(*term_idx as u8)))
+        .map(|(_, term)| term)
+        .collect();
+
+    let mut fids = ctx.index.searchable_fields_ids(ctx.txn)?;
+    if let Some(restricted_fids) = &ctx.restricted_fids {
+        fids.retain(|fid| restricted_fids.contains(fid));
+    }
+
+    let mut docids = RoaringBitmap::new();
+    for fid in fids {
+        // the documents already matching within a previous field are not checked again
+        let mut fid_docids = universe - &docids;
+        for term in &query_terms {
+            if fid_docids.is_empty() {
+                break;
+            }
+            let term_subset = QueryTermSubset::full(term.value);
+            fid_docids &= compute_query_term_subset_docids_within_field_id(
+                ctx,
+                Some(&fid_docids),
+                &term_subset,
+                fid,
+                true,
+            )?;
+        }
+        docids |= fid_docids;
+    }
+
+    Ok(docids)
+}
+
 #[tracing::instrument(level = "trace", skip_all, target = "search::query")]
 fn resolve_negative_words(
     ctx: &mut SearchContext<'_>,
@@ -259,6 +460,26 @@ fn resolve_negative_phrases(
     Ok(negative_bitmap)
 }
 
+/// Returns the rule ranking the documents matching the boosting filter first, if there is one.
+fn boosting_filter<'ctx, Query: RankingRuleQueryTrait>(
+    ctx: &SearchContext<'ctx>,
+) -> Vec<BoxRankingRule<'ctx, Query>> {
+    match &ctx.boosted_documents {
+        Some(boosted) => vec![Box::new(BoostingFilter::new(boosted.clone()))],
+        None => vec![],
+    }
+}
+
+/// Returns the rule decaying the relevance of the older documents, if the index defines one.
+///
+/// It must be the last ranking rule of the search.
+fn recency_decay<'ctx, Query: RankingRuleQueryTrait>(
+    ctx: &SearchContext<'ctx>,
+) -> Result<Option<BoxRankingRule<'ctx, Query>>> {
+    Ok(RecencyDecay::from_index(ctx.index, ctx.txn)?
+        .map(|decay| Box::new(decay) as BoxRankingRule<'ctx, Query>))
+}
+
 /// Return the list of initialised ranking rules to be used for a placeholder search.
 fn get_ranking_rules_for_placeholder_search<'ctx>(
     ctx: &SearchContext<'ctx>,
@@ -268,8 +489,8 @@ fn get_ranking_rules_for_placeholder_search<'ctx>(
     let mut sort = false;
     let mut sorted_fields = HashSet::new();
     let mut geo_sorted = false;
-    let mut ranking_rules: Vec<BoxRankingRule<'ctx, PlaceholderQuery>> = vec![];
-    let settings_ranking_rules = ctx.index.criteria(ctx.txn)?;
+    let mut ranking_rules: Vec<BoxRankingRule<'ctx, PlaceholderQuery>> = boosting_filter(ctx);
+    let settings_ranking_rules = ctx.resolved_ranking_rules()?;
     for rr in settings_ranking_rules {
         match rr {
             // These rules need a query to have an effect; ignore them in placeholder search
@@ -306,8 +527,11 @@ fn get_ranking_rules_for_placeholder_search<'ctx>(
                 sorted_fields.insert(field_name.clone());
                 ranking_rules.push(Box::new(Sort::new(ctx.index, ctx.txn, field_name, false)?));
             }
+            // `_recency` is replaced by a `Desc` rule on the recency field by `resolved_criteria`
+            crate::Criterion::Recency => continue,
         }
     }
+    ranking_rules.extend(recency_decay(ctx)?);
     Ok(ranking_rules)
 }
 
@@ -327,9 +551,9 @@ fn get_ranking_rules_for_vector<'ctx>(
     let mut geo_sorted = false;
 
     let mut vector = false;
-    let mut ranking_rules: Vec<BoxRankingRule<'ctx, PlaceholderQuery>> = vec![];
+    let mut ranking_rules: Vec<BoxRankingRule<'ctx, PlaceholderQuery>> = boosting_filter(ctx);
 
-    let settings_ranking_rules = ctx.index.criteria(ctx.txn)?;
+    let settings_ranking_rules = ctx.resolved_ranking_rules()?;
     for rr in settings_ranking_rules {
         match rr {
             crate::Criterion::Words
@@ -379,8 +603,11 @@ fn get_ranking_rules_for_vector<'ctx>(
                 sorted_fields.insert(field_name.clone());
                 ranking_rules.push(Box::new(Sort::new(ctx.index, ctx.txn, field_name, false)?));
             }
+            // `_recency` is replaced by a `Desc` rule on the recency field by `resolved_criteria`
+            crate::Criterion::Recency => continue,
         }
     }
+    ranking_rules.extend(recency_decay(ctx)?);
 
     Ok(ranking_rules)
 }
@@ -407,8 +634,8 @@ fn get_ranking_rules_for_query_graph_search<'ctx>(
         words = true;
     }
 
-    let mut ranking_rules: Vec<BoxRankingRule<'ctx, QueryGraph>> = vec![];
-    let settings_ranking_rules = ctx.index.criteria(ctx.txn)?;
+    let mut ranking_rules: Vec<BoxRankingRule<'ctx, QueryGraph>> = boosting_filter(ctx);
+    let settings_ranking_rules = ctx.resolved_ranking_rules()?;
     for rr in settings_ranking_rules {
         // Add Words before any of: typo, proximity, attribute
         match rr {
@@ -489,8 +716,11 @@ fn get_ranking_rules_for_query_graph_search<'ctx>(
                 sorted_fields.insert(field_name.clone());
                 ranking_rules.push(Box::new(Sort::new(ctx.index, ctx.txn, field_name, false)?));
             }
+            // `_recency` is replaced by a `Desc` rule on the recency field by `resolved_criteria`
+            crate::Criterion::Recency => continue,
         }
     }
+    ranking_rules.extend(recency_decay(ctx)?);
     Ok(ranking_rules)
 }
 
@@ -555,11 +785,13 @@ pub fn filtered_universe(
     txn: &RoTxn<'_>,
     filters: &Option<Filter<'_>>,
 ) -> Result<RoaringBitmap> {
-    Ok(if let Some(filters) = filters {
+    let universe = if let Some(filters) = filters {
         filters.evaluate(txn, index)?
     } else {
         index.documents_ids(txn)?
-    })
+    };
+    // the blocked documents never are candidates of a search
+    Ok(universe - index.blocked_documents_ids(txn)?)
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -580,6 +812,10 @@ pub fn execute_vector_search(
 ) -> Result<PartialSearchResult> {
     check_sort_criteria(ctx, sort_criteria.as_ref())?;
 
+    if ctx.count_only {
+        return count_only_result(ctx, universe, distinct, None, false);
+    }
+
     // FIXME: input universe = universe & documents_with_vectors
     // for now if we're computing embeddings for ALL documents, we can assume that this is just universe
     let ranking_rules = get_ranking_rules_for_vector(
@@ -643,55 +879,42 @@ pub fn execute_search(
 ) -> Result<PartialSearchResult> {
     check_sort_criteria(ctx, sort_criteria.as_ref())?;
 
+    let mut resolve_candidates_span = TimedSpan::new(tracing::trace_span!(
+        target: "search::universe",
+        "resolve_candidates",
+        candidates = tracing::field::Empty,
+        duration_us = tracing::field::Empty,
+    ));
+
     let mut used_negative_operator = false;
     let mut located_query_terms = None;
+    let mut identifiers = Vec::new();
     let query_terms = if let Some(query) = query {
-        let span = tracing::trace_span!(target: "search::tokens", "tokenizer_builder");
-        let entered = span.enter();
-
-        // We make sure that the analyzer is aware of the stop words
-        // this ensures that the query builder is able to properly remove them.
-        let mut tokbuilder = TokenizerBuilder::new();
-        let stop_words = ctx.index.stop_words(ctx.txn)?;
-        if let Some(ref stop_words) = stop_words {
-            tokbuilder.stop_words(stop_words);
-        }
-
-        let separators = ctx.index.allowed_separators(ctx.txn)?;
-        let separators: Option<Vec<_>> =
-            separators.as_ref().map(|x| x.iter().map(String::as_str).collect());
-        if let Some(ref separators) = separators {
-            tokbuilder.separators(separators);
-        }
-
-        let dictionary = ctx.index.dictionary(ctx.txn)?;
-        let dictionary: Option<Vec<_>> =
-            dictionary.as_ref().map(|x| x.iter().map(String::as_str).collect());
-        if let Some(ref dictionary) = dictionary {
-            tokbuilder.words_dict(dictionary);
-        }
-
-        if let Some(locales) = locales {
-            tokbuilder.allow_list(locales);
-        }
-
-        let tokenizer = tokbuilder.build();
-        drop(entered);
-
-        let span = tracing::trace_span!(target: "search::tokens", "tokenize");
-        let entered = span.enter();
-        let tokens = tokenizer.tokenize(query);
-        drop(entered);
-
-        let ExtractedTokens { query_terms, negative_words, negative_phrases } =
-            located_query_terms_from_tokens(ctx, tokens, words_limit)?;
+        let mut parse_query_span = TimedSpan::new(tracing::trace_span!(
+            target: "search::query",
+            "parse_query",
+            terms = tracing::field::Empty,
+            duration_us = tracing::field::Empty,
+        ));
+        let ExtractedTokens {
+            query_terms,
+            negative_words,
+            negative_phrases,
+            identifiers: query_identifiers,
+        } = parse_query_span.in_scope(|| extract_query_terms(ctx, query, words_limit, locales))?;
+        identifiers = query_identifiers;
+        parse_query_span.record("terms", query_terms.len() as u64);
+        drop(parse_query_span);
         used_negative_operator = !negative_words.is_empty() || !negative_phrases.is_empty();
 
-        let ignored_documents = resolve_negative_words(ctx, Some(&universe), &negative_words)?;
-        let ignored_phrases = resolve_negative_phrases(ctx, &negative_phrases)?;
+        resolve_candidates_span.in_scope(|| -> Result<()> {
+            let ignored_documents = resolve_negative_words(ctx, Some(&universe), &negative_words)?;
+            let ignored_phrases = resolve_negative_phrases(ctx, &negative_phrases)?;
 
-        universe -= ignored_documents;
-        universe -= ignored_phrases;
+            universe -= ignored_documents;
+            universe -= ignored_phrases;
+            Ok(())
+        })?;
 
         if query_terms.is_empty() {
             // Do a placeholder search instead
@@ -704,9 +927,42 @@ pub fn execute_search(
     };
 
     let bucket_sort_output = if let Some(query_terms) = query_terms {
-        let (graph, new_located_query_terms) = QueryGraph::from_query(ctx, &query_terms)?;
+        let (graph, new_located_query_terms) =
+            QueryGraph::from_query(ctx, &query_terms, &identifiers)?;
         located_query_terms = Some(new_located_query_terms);
 
+        resolve_candidates_span.in_scope(|| -> Result<()> {
+            universe &= resolve_universe(
+                ctx,
+                &universe,
+                &graph,
+                terms_matching_strategy,
+                query_graph_logger,
+            )?;
+            if !ctx.cross_attribute_matching {
+                universe = resolve_single_field_universe(
+                    ctx,
+                    &universe,
+                    &graph,
+                    &query_terms,
+                    terms_matching_strategy,
+                )?;
+            }
+            Ok(())
+        })?;
+        resolve_candidates_span.record("candidates", universe.len());
+        drop(resolve_candidates_span);
+
+        if ctx.count_only {
+            return count_only_result(
+                ctx,
+                universe,
+                distinct,
+                located_query_terms,
+                used_negative_operator,
+            );
+        }
+
         let ranking_rules = get_ranking_rules_for_query_graph_search(
             ctx,
             sort_criteria,
@@ -714,9 +970,6 @@ pub fn execute_search(
             terms_matching_strategy,
         )?;
 
-        universe &=
-            resolve_universe(ctx, &universe, &graph, terms_matching_strategy, query_graph_logger)?;
-
         bucket_sort(
             ctx,
             ranking_rules,
@@ -731,8 +984,22 @@ pub fn execute_search(
             ranking_score_threshold,
         )?
     } else {
+        resolve_candidates_span.record("candidates", universe.len());
+        drop(resolve_candidates_span);
+
+        if ctx.count_only {
+            return count_only_result(
+                ctx,
+                universe,
+                distinct,
+                located_query_terms,
+                used_negative_operator,
+            );
+        }
+
         let ranking_rules =
             get_ranking_rules_for_placeholder_search(ctx, sort_criteria, geo_strategy)?;
+
         bucket_sort(
             ctx,
             ranking_rules,
@@ -753,7 +1020,7 @@ pub fn execute_search(
 
     // The candidates is the universe unless the exhaustive number of hits
     // is requested and a distinct attribute is set.
-    if exhaustive_number_hits {
+    if exhaustive_number_hits && ctx.compute_total_hits {
         let distinct_field = match distinct.as_deref() {
             Some(distinct) => Some(distinct),
             None => ctx.index.distinct_field(ctx.txn)?,
@@ -761,6 +1028,7 @@ pub fn execute_search(
 
         if let Some(f) = distinct_field {
             if let Some(distinct_fid) = fields_ids_map.id(f) {
+                start_distinct(ctx);
                 all_candidates = apply_distinct_rule(ctx, distinct_fid, &all_candidates)?.remaining;
             }
         }
@@ -776,10 +1044,94 @@ pub fn execute_search(
     })
 }
 
+/// Builds the result of a search only counting its candidates.
+///
+/// The distinct attribute is always applied to the whole universe so that the count is exact.
+fn count_only_result(
+    ctx: &mut SearchContext<'_>,
+    mut universe: RoaringBitmap,
+    distinct: &Option<String>,
+    located_query_terms: Option<Vec<LocatedQueryTerm>>,
+    used_negative_operator: bool,
+) -> Result<PartialSearchResult> {
+    let distinct_field = match distinct.as_deref() {
+        Some(distinct) => Some(distinct),
+        None => ctx.index.distinct_field(ctx.txn)?,
+    };
+
+    if let Some(f) = distinct_field {
+        if let Some(distinct_fid) = ctx.index.fields_ids_map(ctx.txn)?.id(f) {
+            start_distinct(ctx);
+            universe = apply_distinct_rule(ctx, distinct_fid, &universe)?.remaining;
+        }
+    }
+
+    Ok(PartialSearchResult {
+        candidates: universe,
+        document_scores: vec![],
+        documents_ids: vec![],
+        located_query_terms,
+        degraded: false,
+        used_negative_operator,
+    })
+}
+
+/// Tokenizes the query the way the documents were tokenized and parses it into query terms.
+fn extract_query_terms(
+    ctx: &mut SearchContext<'_>,
+    query: &str,
+    words_limit: Option<usize>,
+    locales: Option<&Vec<Language>>,
+) -> Result<ExtractedTokens> {
+    let span = tracing::trace_span!(target: "search::tokens", "tokenizer_builder");
+    let entered = span.enter();
+
+    // We make sure that the analyzer is aware of the stop words
+    // this ensures that the query builder is able to properly remove them.
+    let mut tokbuilder = TokenizerBuilder::new();
+    let stop_words = ctx.index.stop_words(ctx.txn)?;
+    if let Some(ref stop_words) = stop_words {
+        tokbuilder.stop_words(stop_words);
+    }
+
+    let separators = ctx.index.allowed_separators(ctx.txn)?;
+    let separators: Option<Vec<_>> =
+        separators.as_ref().map(|x| x.iter().map(String::as_str).collect());
+    if let Some(ref separators) = separators {
+        tokbuilder.separators(separators);
+    }
+
+    let dictionary = ctx.index.dictionary(ctx.txn)?;
+    let dictionary: Option<Vec<_>> =
+        dictionary.as_ref().map(|x| x.iter().map(String::as_str).collect());
+    if let Some(ref dictionary) = dictionary {
+        tokbuilder.words_dict(dictionary);
+    }
+
+    let default_language = ctx.index.default_language(ctx.txn)?;
+    if let Some(locales) = locales {
+        tokbuilder.allow_list(locales);
+    } else if let Some(default_language) = &default_language {
+        tokbuilder.allow_list(std::slice::from_ref(default_language));
+    }
+
+    let tokenizer = tokbuilder.build();
+    drop(entered);
+
+    let span = tracing::trace_span!(target: "search::tokens", "tokenize");
+    let entered = span.enter();
+    let tokens = tokenizer.tokenize(query);
+    drop(entered);
+
+    located_query_terms_from_tokens(ctx, tokens, words_limit)
+}
+
 fn check_sort_criteria(
     ctx: &SearchContext<'_>,
     sort_criteria: Option<&Vec<AscDesc>>,
 ) -> Result<()> {
+    check_ranking_rules(ctx)?;
+
     let sort_criteria = if let Some(sort_criteria) = sort_criteria {
         sort_criteria
     } else {
@@ -792,7 +1144,8 @@ fn check_sort_criteria(
 
     // We check that the sort ranking rule exists and throw an
     // error if we try to use it and that it doesn't.
-    let sort_ranking_rule_missing = !ctx.index.criteria(ctx.txn)?.contains(&crate::Criterion::Sort);
+    let sort_ranking_rule_missing =
+        !ctx.resolved_ranking_rules()?.contains(&crate::Criterion::Sort);
     if sort_ranking_rule_missing {
         return Err(UserError::SortRankingRuleMissing.into());
     }
@@ -831,6 +1184,44 @@ fn check_sort_criteria(
     Ok(())
 }
 
+/// Checks that the asc/desc ranking rules given at search time sort on the sortable fields,
+/// or on the ones the ranking rules of the settings already sort on.
+///
+/// The other fields are not faceted, the documents could not be sorted on them.
+fn check_ranking_rules(ctx: &SearchContext<'_>) -> Result<()> {
+    let Some(ranking_rules) = &ctx.ranking_rules else {
+        return Ok(());
+    };
+
+    let mut sortable_fields = ctx.index.sortable_fields(ctx.txn)?;
+    sortable_fields.extend(ctx.index.criteria(ctx.txn)?.into_iter().filter_map(|rr| match rr {
+        crate::Criterion::Asc(field) | crate::Criterion::Desc(field) => Some(field),
+        _ => None,
+    }));
+
+    for rr in ranking_rules {
+        match rr {
+            crate::Criterion::Asc(field) | crate::Criterion::Desc(field)
+                if !crate::is_faceted(field, &sortable_fields) =>
+            {
+                let sortable_fields = ctx.index.sortable_fields(ctx.txn)?;
+                let (valid_fields, hidden_fields) =
+                    ctx.index.remove_hidden_fields(ctx.txn, sortable_fields)?;
+
+                return Err(UserError::InvalidSortableAttribute {
+                    field: field.to_string(),
+                    valid_fields,
+                    hidden_fields,
+                }
+                .into());
+            }
+            _ => (),
+        }
+    }
+
+    Ok(())
+}
+
 pub struct PartialSearchResult {
     pub located_query_terms: Option<Vec<LocatedQueryTerm>>,
     pub candidates: RoaringBitmap,
@@ -0,0 +1,62 @@
+use charabia::Language;
+use serde::Serialize;
+
+use super::query_term::{ExtractedTokens, LocatedQueryTerm};
+use super::{extract_query_terms, SearchContext};
+use crate::Result;
+
+/// The terms the engine searches for once the query has been
+/// normalized, stripped of its stop words and expanded with its synonyms.
+#[derive(Debug, Clone, Default, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryAnalysis {
+    /// The terms searched for, in the order they appear in the query.
+    pub terms: Vec<AnalyzedTerm>,
+    /// The words and phrases excluded with the negative operator.
+    pub negative_terms: Vec<String>,
+}
+
+/// A term of the query as understood by the engine.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AnalyzedTerm {
+    /// The normalized word, or the words of the phrase separated by spaces.
+    pub term: String,
+    pub is_phrase: bool,
+    /// Whether the term also matches the words it is a prefix of.
+    pub is_prefix: bool,
+    /// The synonyms the term has been expanded to.
+    pub synonyms: Vec<String>,
+}
+
+/// Tokenizes and parses the query exactly like the search does and describes the resulting terms.
+pub fn analyze_query(
+    ctx: &mut SearchContext<'_>,
+    query: &str,
+    words_limit: Option<usize>,
+    locales: Option<&Vec<Language>>,
+) -> Result<QueryAnalysis> {
+    let ExtractedTokens { query_terms, negative_words, negative_phrases, .. } =
+        extract_query_terms(ctx, query, words_limit, locales)?;
+
+    let terms = query_terms.iter().map(|term| analyzed_term(ctx, term)).collect();
+
+    let negative_words =
+        negative_words.iter().map(|word| ctx.word_interner.get(word.interned()).clone());
+    let negative_phrases =
+        negative_phrases.iter().map(|phrase| analyzed_term(ctx, phrase).term).collect::<Vec<_>>();
+    let negative_terms = negative_words.chain(negative_phrases).collect();
+
+    Ok(QueryAnalysis { terms, negative_terms })
+}
+
+fn analyzed_term(ctx: &SearchContext<'_>, located_term: &LocatedQueryTerm) -> AnalyzedTerm {
+    let term = ctx.term_interner.get(located_term.value);
+    let (term_description, is_phrase) = match term.original_phrase() {
+        Some(phrase) => (phrase.description(ctx), true),
+        None => (term.original_word(ctx), false),
+    };
+    let synonyms = term.synonyms().iter().map(|synonym| synonym.description(ctx)).collect();
+
+    AnalyzedTerm { term: term_description, is_phrase, is_prefix: term.is_prefix(), synonyms }
+}
@@ -96,10 +96,13 @@ impl QueryGraph {
         ctx: &mut SearchContext<'_>,
         // The terms here must be consecutive
         terms: &[LocatedQueryTerm],
+        // The identifiers made of consecutive terms, searched as single words
+        identifiers: &[LocatedQueryTerm],
     ) -> Result<(QueryGraph, Vec<LocatedQueryTerm>)> {
         let mut new_located_query_terms = terms.to_vec();
 
         let nbr_typos = number_of_typos_allowed(ctx)?;
+        let concat_words = ctx.index.split_and_concat_words(ctx.txn)?;
 
         let mut nodes_data: Vec<QueryNodeData> = vec![QueryNodeData::Start, QueryNodeData::End];
         let root_node = 0;
@@ -123,7 +126,7 @@ impl QueryGraph {
             );
             new_nodes.push(new_node_idx);
 
-            if !prev1.is_empty() {
+            if concat_words && !prev1.is_empty() {
                 if let Some(ngram) =
                     query_term::make_ngram(ctx, &terms[term_idx - 1..=term_idx], &nbr_typos)?
                 {
@@ -139,7 +142,7 @@ impl QueryGraph {
                     new_nodes.push(ngram_idx);
                 }
             }
-            if !prev2.is_empty() {
+            if concat_words && !prev2.is_empty() {
                 if let Some(ngram) =
                     query_term::make_ngram(ctx, &terms[term_idx - 2..=term_idx], &nbr_typos)?
                 {
@@ -158,6 +161,32 @@ impl QueryGraph {
             (prev0, prev1, prev2) = (new_nodes, prev0, prev1);
         }
 
+        // An identifier is an alternative to all the terms it is made of.
+        for identifier in identifiers {
+            let first_term = terms
+                .iter()
+                .position(|term| term.positions.start() >= identifier.positions.start());
+            let last_term =
+                terms.iter().rposition(|term| term.positions.end() <= identifier.positions.end());
+            let (Some(first_term), Some(last_term)) = (first_term, last_term) else { continue };
+            // the identifier must cover terms that were all kept, e.g. by the words limit.
+            if first_term > last_term
+                || terms[last_term].positions.end() != identifier.positions.end()
+            {
+                continue;
+            }
+
+            new_located_query_terms.push(identifier.clone());
+            add_node(
+                &mut nodes_data,
+                QueryNodeData::Term(LocatedQueryTermSubset {
+                    term_subset: QueryTermSubset::full(identifier.value),
+                    positions: identifier.positions.clone(),
+                    term_ids: first_term as u8..=last_term as u8,
+                }),
+            );
+        }
+
         let root_node = Interned::from_raw(root_node);
         let end_node = Interned::from_raw(end_node);
         let mut nodes = FixedSizeInterner::new(
@@ -10,7 +10,7 @@ use super::{OneTypoTerm, Phrase, QueryTerm, ZeroTypoTerm};
 use crate::search::fst_utils::{Complement, Intersection, StartsWith, Union};
 use crate::search::new::interner::{DedupInterner, Interned};
 use crate::search::new::query_term::{Lazy, TwoTypoTerm};
-use crate::search::new::{limits, SearchContext};
+use crate::search::new::{limits, SearchContext, Word};
 use crate::search::{build_dfa, get_first};
 use crate::{Result, MAX_WORD_LENGTH};
 
@@ -26,6 +26,39 @@ pub enum ZeroOrOneTypo {
     One,
 }
 
+/// Returns the synonym phrases of the given words, bounded by `ctx.max_synonym_expansions`.
+///
+/// When there are more synonyms than allowed, the ones appearing in the most documents are kept,
+/// the first defined ones between equally frequent synonyms. The kept synonyms are returned in
+/// the order in which they were defined.
+pub(crate) fn bounded_synonyms(
+    ctx: &mut SearchContext<'_>,
+    words: &[String],
+) -> Result<Vec<Vec<String>>> {
+    let synonyms = ctx.synonyms_of(words)?;
+    if synonyms.len() <= ctx.max_synonym_expansions {
+        return Ok(synonyms);
+    }
+
+    let mut ranked_synonyms = Vec::with_capacity(synonyms.len());
+    for (position, phrase) in synonyms.into_iter().enumerate() {
+        // a phrase can't appear in more documents than its least frequent word
+        let mut frequency = u64::MAX;
+        for word in &phrase {
+            let word = ctx.word_interner.insert(word.clone());
+            let docids = ctx.word_docids(None, Word::Original(word))?;
+            frequency = frequency.min(docids.map_or(0, |docids| docids.len()));
+        }
+        ranked_synonyms.push((frequency, position, phrase));
+    }
+
+    // the sort is stable, the definition order is kept for equal frequencies
+    ranked_synonyms.sort_by(|(left, _, _), (right, _, _)| right.cmp(left));
+    ranked_synonyms.truncate(ctx.max_synonym_expansions);
+    ranked_synonyms.sort_unstable_by_key(|(_, position, _)| *position);
+    Ok(ranked_synonyms.into_iter().map(|(_, _, phrase)| phrase).collect())
+}
+
 impl Interned<QueryTerm> {
     pub fn compute_fully_if_needed(self, ctx: &mut SearchContext<'_>) -> Result<()> {
         let s = ctx.term_interner.get_mut(self);
@@ -194,6 +227,12 @@ pub fn partially_initialized_term_from_word(
         });
     }
 
+    // the typos allowed on a prefix are bounded by their own setting
+    let max_typo = match ctx.index.max_prefix_typos(ctx.txn)? {
+        Some(max_prefix_typos) if is_prefix => max_typo.min(max_prefix_typos),
+        _ => max_typo,
+    };
+
     let fst = ctx.index.words_fst(ctx.txn)?;
 
     let use_prefix_db = is_prefix
@@ -234,14 +273,10 @@ pub fn partially_initialized_term_from_word(
             },
         )?;
     }
-    let synonyms = ctx.index.synonyms(ctx.txn)?;
+    let synonyms = bounded_synonyms(ctx, &[word.to_owned()])?;
     let mut synonym_word_count = 0;
     let synonyms = synonyms
-        .get(&vec![word.to_owned()])
-        .cloned()
-        .unwrap_or_default()
         .into_iter()
-        .take(limits::MAX_SYNONYM_PHRASE_COUNT)
         .filter_map(|words| {
             if synonym_word_count + words.len() > limits::MAX_SYNONYM_WORD_COUNT {
                 return None;
@@ -266,6 +301,10 @@ pub fn partially_initialized_term_from_word(
 }
 
 fn find_split_words(ctx: &mut SearchContext<'_>, word: &str) -> Result<Option<Interned<Phrase>>> {
+    if !ctx.index.split_and_concat_words(ctx.txn)? {
+        return Ok(None);
+    }
+
     if let Some((l, r)) = split_best_frequency(ctx, word)? {
         Ok(Some(ctx.phrase_interner.insert(Phrase { words: vec![Some(l), Some(r)] })))
     } else {
@@ -7,6 +7,7 @@ use std::collections::BTreeSet;
 use std::iter::FromIterator;
 use std::ops::RangeInclusive;
 
+pub(crate) use compute_derivations::bounded_synonyms;
 use either::Either;
 pub use ntypo_subset::NTypoTermSubset;
 pub use parse_query::{
@@ -196,6 +197,25 @@ impl QueryTermSubset {
             }
         })
     }
+    /// Returns the words of the subset derived from the prefix of the term,
+    /// i.e. the words starting with the term, without the term itself.
+    pub fn prefix_of_words(&self, ctx: &SearchContext<'_>) -> BTreeSet<Interned<String>> {
+        let original = ctx.term_interner.get(self.original);
+        let prefix_of = &original.zero_typo.prefix_of;
+        match &self.zero_typo_subset {
+            NTypoTermSubset::All => prefix_of.clone(),
+            NTypoTermSubset::Subset { words, phrases: _ } => {
+                prefix_of.intersection(words).copied().collect()
+            }
+            NTypoTermSubset::Nothing => BTreeSet::new(),
+        }
+    }
+
+    /// Whether some of the words of the subset are derived from the prefix of the term.
+    pub fn has_prefix_derivations(&self, ctx: &SearchContext<'_>) -> bool {
+        self.use_prefix_db(ctx).is_some() || !self.prefix_of_words(ctx).is_empty()
+    }
+
     pub fn all_single_words_except_prefix_db(
         &self,
         ctx: &mut SearchContext<'_>,
@@ -485,6 +505,10 @@ impl QueryTerm {
         self.zero_typo.phrase
     }
 
+    pub fn synonyms(&self) -> &BTreeSet<Interned<Phrase>> {
+        &self.zero_typo.synonyms
+    }
+
     pub fn all_computed_derivations(&self) -> (Vec<Interned<String>>, Vec<Interned<Phrase>>) {
         let mut words = BTreeSet::new();
         let mut phrases = BTreeSet::new();
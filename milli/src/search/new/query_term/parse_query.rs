@@ -1,13 +1,14 @@
+use std::borrow::Cow;
 use std::collections::BTreeSet;
 
 use charabia::normalizer::NormalizedTokenIter;
 use charabia::{SeparatorKind, TokenKind};
 
-use super::compute_derivations::partially_initialized_term_from_word;
+use super::compute_derivations::{bounded_synonyms, partially_initialized_term_from_word};
 use super::{LocatedQueryTerm, ZeroTypoTerm};
 use crate::search::new::query_term::{Lazy, Phrase, QueryTerm};
-use crate::search::new::Word;
-use crate::{Result, SearchContext, MAX_WORD_LENGTH};
+use crate::search::new::{Interned, Word};
+use crate::{NumberTokenization, Result, SearchContext, TokenizerProfile, MAX_WORD_LENGTH};
 
 #[derive(Clone)]
 /// Extraction of the content of a query.
@@ -18,6 +19,8 @@ pub struct ExtractedTokens {
     pub negative_words: Vec<Word>,
     /// The phrases that must not appear in the results.
     pub negative_phrases: Vec<LocatedQueryTerm>,
+    /// The identifiers made of consecutive terms, searched as single words.
+    pub identifiers: Vec<LocatedQueryTerm>,
 }
 
 /// Convert the tokenised search query into a list of located query terms.
@@ -38,12 +41,28 @@ pub fn located_query_terms_from_tokens(
     let mut negative_words = Vec::new();
     let mut negative_phrases = Vec::new();
 
+    // the identifiers are only searched when some attributes are indexed as identifiers.
+    let find_identifiers = ctx
+        .index
+        .tokenizer_profiles(ctx.txn)?
+        .values()
+        .any(|profile| *profile != TokenizerProfile::Default);
+    let mut identifiers = Vec::new();
+    let mut identifier = IdentifierBuilder::default();
+    let mut whole_query = Some(IdentifierBuilder::default());
+
     let parts_limit = words_limit.unwrap_or(usize::MAX);
 
     // start with the last position as we will wrap around to position 0 at the beginning of the loop below.
     let mut position = u16::MAX;
 
-    let mut peekable = query.take(super::limits::MAX_TOKEN_COUNT).peekable();
+    // the numbers are kept as single words the same way they were when indexed,
+    // and must then be matched exactly, `3` must not match the documents containing `3.5`.
+    let number_tokenization = ctx.number_tokenization;
+    let keep_numbers = number_tokenization == NumberTokenization::Keep;
+
+    let mut peekable =
+        number_tokenization.tokens(query.take(super::limits::MAX_TOKEN_COUNT)).peekable();
     while let Some(token) = peekable.next() {
         if token.lemma().is_empty() {
             continue;
@@ -51,7 +70,12 @@ pub fn located_query_terms_from_tokens(
 
         // early return if word limit is exceeded
         if query_terms.len() >= parts_limit {
-            return Ok(ExtractedTokens { query_terms, negative_words, negative_phrases });
+            return Ok(ExtractedTokens {
+                query_terms,
+                negative_words,
+                negative_phrases,
+                identifiers,
+            });
         }
 
         match token.kind {
@@ -59,20 +83,39 @@ pub fn located_query_terms_from_tokens(
                 // On first loop, goes from u16::MAX to 0, then normal increment.
                 position = position.wrapping_add(1);
 
+                // the words are stemmed the same way they were when indexed
+                let lemma = match token.kind {
+                    TokenKind::Word => ctx.stemmer.stem(token.lemma(), token.language),
+                    _ => Cow::Borrowed(token.lemma()),
+                };
+
+                // the quoted and negative words are not part of an identifier.
+                if find_identifiers {
+                    if phrase.is_some() || negative_next_token {
+                        identifier = IdentifierBuilder::default();
+                        whole_query = None;
+                    } else {
+                        identifier.push_word(ctx, token.lemma(), position);
+                        if let Some(whole_query) = &mut whole_query {
+                            whole_query.push_word(ctx, token.lemma(), position);
+                        }
+                    }
+                }
+
                 // 1. if the word is quoted we push it in a phrase-buffer waiting for the ending quote,
                 // 2. if the word is not the last token of the query and is not a stop_word we push it as a non-prefix word,
                 // 3. if the word is the last token of the query we push it as a prefix word.
                 if let Some(phrase) = &mut phrase {
-                    phrase.push_word(ctx, &token, position)
+                    phrase.push_word(ctx, &token, &lemma, position)
                 } else if negative_next_token {
-                    let word = token.lemma().to_string();
+                    let word = lemma.to_string();
                     let word = Word::Original(ctx.word_interner.insert(word));
                     negative_words.push(word);
                     negative_next_token = false;
                 } else if peekable.peek().is_some() {
                     match token.kind {
                         TokenKind::Word => {
-                            let word = token.lemma();
+                            let word: &str = &lemma;
                             let term = partially_initialized_term_from_word(
                                 ctx,
                                 word,
@@ -89,12 +132,15 @@ pub fn located_query_terms_from_tokens(
                         TokenKind::StopWord | TokenKind::Separator(_) | TokenKind::Unknown => (),
                     }
                 } else {
-                    let word = token.lemma();
+                    let word: &str = &lemma;
+                    let is_number =
+                        word.bytes().all(|b| b.is_ascii_digit() || b == b'.' || b == b',');
+                    let is_prefix = !(keep_numbers && is_number);
                     let term = partially_initialized_term_from_word(
                         ctx,
                         word,
                         nbr_typos(word),
-                        true,
+                        is_prefix,
                         false,
                     )?;
                     let located_term = LocatedQueryTerm {
@@ -105,6 +151,18 @@ pub fn located_query_terms_from_tokens(
                 }
             }
             TokenKind::Separator(separator_kind) => {
+                // the identifiers end with whitespace.
+                if find_identifiers {
+                    if token.lemma().contains(char::is_whitespace) {
+                        identifiers.extend(std::mem::take(&mut identifier).build(ctx)?);
+                    } else {
+                        identifier.push_separator(token.lemma());
+                    }
+                    if let Some(whole_query) = &mut whole_query {
+                        whole_query.push_separator(token.lemma());
+                    }
+                }
+
                 // add penalty for hard separators
                 if let SeparatorKind::Hard = separator_kind {
                     position = position.wrapping_add(7);
@@ -175,6 +233,18 @@ pub fn located_query_terms_from_tokens(
             token.lemma().chars().last().filter(|c| c.is_whitespace()).is_some();
     }
 
+    if find_identifiers {
+        identifiers.extend(identifier.build(ctx)?);
+        // the whole query is also searched as an identifier, e.g. a keyword containing whitespace.
+        if let Some(whole_query) = whole_query {
+            if let Some(whole_query) = whole_query.build(ctx)? {
+                if identifiers.iter().all(|ident| ident.positions != whole_query.positions) {
+                    identifiers.push(whole_query);
+                }
+            }
+        }
+    }
+
     // If a quote is never closed, we consider all of the end of the query as a phrase.
     if let Some(phrase) = phrase.take() {
         if let Some(located_query_term) = phrase.build(ctx) {
@@ -187,7 +257,7 @@ pub fn located_query_terms_from_tokens(
         }
     }
 
-    Ok(ExtractedTokens { query_terms, negative_words, negative_phrases })
+    Ok(ExtractedTokens { query_terms, negative_words, negative_phrases, identifiers })
 }
 
 pub fn number_of_typos_allowed<'ctx>(
@@ -257,20 +327,18 @@ pub fn make_ngram(
         partially_initialized_term_from_word(ctx, &ngram_str, max_nbr_typos, is_prefix, true)?;
 
     // Now add the synonyms
-    let index_synonyms = ctx.index.synonyms(ctx.txn)?;
+    let index_synonyms = bounded_synonyms(ctx, &words)?;
 
-    term.zero_typo.synonyms.extend(
-        index_synonyms.get(&words).cloned().unwrap_or_default().into_iter().map(|words| {
-            let words = words.into_iter().map(|w| Some(ctx.word_interner.insert(w))).collect();
-            ctx.phrase_interner.insert(Phrase { words })
-        }),
-    );
+    term.zero_typo.synonyms.extend(index_synonyms.into_iter().map(|words| {
+        let words = words.into_iter().map(|w| Some(ctx.word_interner.insert(w))).collect();
+        ctx.phrase_interner.insert(Phrase { words })
+    }));
 
     let term = QueryTerm {
         original: ngram_str_interned,
         ngram_words: Some(words_interned),
         is_prefix,
-        max_levenshtein_distance: max_nbr_typos,
+        max_levenshtein_distance: term.max_levenshtein_distance,
         zero_typo: term.zero_typo,
         one_typo: Lazy::Uninit,
         two_typo: Lazy::Uninit,
@@ -301,6 +369,7 @@ impl PhraseBuilder {
         &mut self,
         ctx: &mut SearchContext<'_>,
         token: &charabia::Token<'_>,
+        word: &str,
         position: u16,
     ) {
         if self.is_empty() {
@@ -311,7 +380,7 @@ impl PhraseBuilder {
             self.words.push(None);
         } else {
             // token has kind Word
-            let word = ctx.word_interner.insert(token.lemma().to_string());
+            let word = ctx.word_interner.insert(word.to_string());
             self.words.push(Some(word));
         }
     }
@@ -345,6 +414,64 @@ impl PhraseBuilder {
     }
 }
 
+/// Builds the identifier made of consecutive words of the query, e.g. an email address.
+///
+/// The identifier is searched as a single word, the way the attributes whose tokenizer profile
+/// isn't the default one are indexed.
+#[derive(Default)]
+struct IdentifierBuilder {
+    identifier: String,
+    // the separators following the last word, only kept if another word follows them.
+    separators: String,
+    words: Vec<Interned<String>>,
+    start: u16,
+    end: u16,
+}
+
+impl IdentifierBuilder {
+    // precondition: token has kind Word or StopWord
+    fn push_word(&mut self, ctx: &mut SearchContext<'_>, word: &str, position: u16) {
+        if self.words.is_empty() {
+            self.start = position;
+        } else {
+            self.identifier.push_str(&self.separators);
+        }
+        self.separators.clear();
+        self.end = position;
+        self.identifier.push_str(word);
+        self.words.push(ctx.word_interner.insert(word.to_string()));
+    }
+
+    fn push_separator(&mut self, separator: &str) {
+        if !self.words.is_empty() {
+            self.separators.push_str(separator);
+        }
+    }
+
+    fn build(self, ctx: &mut SearchContext<'_>) -> Result<Option<LocatedQueryTerm>> {
+        // a single word is already searched as is.
+        if self.words.len() < 2 || self.identifier.len() > MAX_WORD_LENGTH {
+            return Ok(None);
+        }
+
+        let term = partially_initialized_term_from_word(ctx, &self.identifier, 0, false, true)?;
+        let term = QueryTerm {
+            original: ctx.word_interner.insert(self.identifier),
+            ngram_words: Some(self.words),
+            is_prefix: false,
+            max_levenshtein_distance: 0,
+            zero_typo: term.zero_typo,
+            one_typo: Lazy::Init(<_>::default()),
+            two_typo: Lazy::Init(<_>::default()),
+        };
+
+        Ok(Some(LocatedQueryTerm {
+            value: ctx.term_interner.push(term),
+            positions: self.start..=self.end,
+        }))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use charabia::TokenizerBuilder;
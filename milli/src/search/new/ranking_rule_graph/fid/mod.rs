@@ -13,6 +13,8 @@ use crate::{FieldId, InternalError, Result};
 pub struct FidCondition {
     term: LocatedQueryTermSubset,
     fid: Option<FieldId>,
+    /// Whether the words derived from the prefix of the term match.
+    with_prefix: bool,
 }
 
 pub enum FidGraph {}
@@ -26,7 +28,7 @@ impl RankingRuleGraphTrait for FidGraph {
         condition: &Self::Condition,
         universe: &RoaringBitmap,
     ) -> Result<ComputedCondition> {
-        let FidCondition { term, .. } = condition;
+        let FidCondition { term, with_prefix, .. } = condition;
 
         let docids = if let Some(fid) = condition.fid {
             compute_query_term_subset_docids_within_field_id(
@@ -34,6 +36,7 @@ impl RankingRuleGraphTrait for FidGraph {
                 Some(universe),
                 &term.term_subset,
                 fid,
+                *with_prefix,
             )?
         } else {
             RoaringBitmap::new()
@@ -75,16 +78,44 @@ impl RankingRuleGraphTrait for FidGraph {
         }
 
         let weights_map = ctx.index.fieldids_weights_map(ctx.txn)?;
+        let has_prefix_derivations = term.term_subset.has_prefix_derivations(ctx);
 
         let mut edges = vec![];
         for fid in all_fields.iter().copied() {
             let weight = weights_map
                 .weight(fid)
                 .ok_or(InternalError::FieldidsWeightsMapMissingEntry { key: fid })?;
-            edges.push((
-                weight as u32 * term.term_ids.len() as u32,
-                conditions_interner.insert(FidCondition { term: term.clone(), fid: Some(fid) }),
-            ));
+            let cost = weight as u32 * term.term_ids.len() as u32;
+            let penalty = ctx.prefix_search.penalty(fid) as u32;
+
+            if has_prefix_derivations && penalty != 0 {
+                // the documents matching the complete term come before the ones only matching its prefix
+                edges.push((
+                    cost,
+                    conditions_interner.insert(FidCondition {
+                        term: term.clone(),
+                        fid: Some(fid),
+                        with_prefix: false,
+                    }),
+                ));
+                edges.push((
+                    cost + penalty,
+                    conditions_interner.insert(FidCondition {
+                        term: term.clone(),
+                        fid: Some(fid),
+                        with_prefix: true,
+                    }),
+                ));
+            } else {
+                edges.push((
+                    cost,
+                    conditions_interner.insert(FidCondition {
+                        term: term.clone(),
+                        fid: Some(fid),
+                        with_prefix: true,
+                    }),
+                ));
+            }
         }
 
         // always lookup the max_fid if we don't already and add an artificial condition for max scoring
@@ -97,6 +128,7 @@ impl RankingRuleGraphTrait for FidGraph {
                     conditions_interner.insert(FidCondition {
                         term: term.clone(), // TODO remove this ugly clone
                         fid: None,
+                        with_prefix: true,
                     }),
                 ));
             }
@@ -106,7 +138,7 @@ impl RankingRuleGraphTrait for FidGraph {
     }
 
     #[tracing::instrument(level = "trace", skip_all, target = "search::fid")]
-    fn rank_to_score(rank: Rank) -> ScoreDetails {
+    fn rank_to_score(_ctx: &SearchContext<'_>, rank: Rank) -> ScoreDetails {
         ScoreDetails::Fid(rank)
     }
 }
@@ -121,7 +121,7 @@ impl RankingRuleGraphTrait for PositionGraph {
     }
 
     #[tracing::instrument(level = "trace", skip_all, target = "search::position")]
-    fn rank_to_score(rank: Rank) -> ScoreDetails {
+    fn rank_to_score(_ctx: &SearchContext<'_>, rank: Rank) -> ScoreDetails {
         ScoreDetails::Position(rank)
     }
 }
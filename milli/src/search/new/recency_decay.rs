@@ -0,0 +1,129 @@
+use std::collections::BTreeMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use roaring::RoaringBitmap;
+
+use super::logger::SearchLogger;
+use super::{RankingRule, RankingRuleOutput, RankingRuleQueryTrait, SearchContext};
+use crate::score_details::{Rank, ScoreDetails};
+use crate::{FieldId, Index, Result};
+
+const SECONDS_PER_DAY: f64 = 24.0 * 60.0 * 60.0;
+
+/// The number of ranks the decay factors of the documents are rounded up to.
+const DECAY_RANKS: u32 = 100;
+
+/// A ranking rule that sorts the documents of its universe by their exponential time-decay,
+/// computed from the recency field of the index.
+///
+/// It is the last ranking rule of the search, so that the decay orders the documents that are
+/// equally relevant and lowers their ranking score accordingly.
+pub struct RecencyDecay<Query> {
+    field_id: FieldId,
+    half_life_seconds: f64,
+    now: f64,
+    /// The documents of the universe grouped by their rank, from the lowest to the highest one.
+    buckets: Vec<(u32, RoaringBitmap)>,
+    query: Option<Query>,
+}
+
+impl<Query> RecencyDecay<Query> {
+    /// Returns the decay of the index, if both a recency field and a half-life are defined.
+    pub fn from_index(index: &Index, rtxn: &heed::RoTxn<'_>) -> Result<Option<Self>> {
+        let Some(half_life_days) = index.recency_half_life_days(rtxn)? else {
+            return Ok(None);
+        };
+        let Some(field) = index.recency_field(rtxn)? else {
+            return Ok(None);
+        };
+        // the field is unknown until a document defines it, every document is then neutral
+        let Some(field_id) = index.fields_ids_map(rtxn)?.id(field) else {
+            return Ok(None);
+        };
+
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs_f64();
+        Ok(Some(RecencyDecay {
+            field_id,
+            half_life_seconds: half_life_days as f64 * SECONDS_PER_DAY,
+            now,
+            buckets: Vec::new(),
+            query: None,
+        }))
+    }
+
+    /// The rank of a document whose recency field holds the given timestamp, in seconds.
+    ///
+    /// A document loses half of its score every half-life, the documents from the future are
+    /// considered as fresh as the current ones. The documents without a numeric timestamp keep
+    /// the highest rank, which leaves their ranking score as is.
+    fn rank(&self, timestamp: Option<f64>) -> u32 {
+        let Some(timestamp) = timestamp else {
+            return DECAY_RANKS;
+        };
+        let age = (self.now - timestamp).max(0.0);
+        let factor = 0.5f64.powf(age / self.half_life_seconds);
+        // a rank of 0 would mean that the document doesn't match
+        ((factor * DECAY_RANKS as f64).ceil() as u32).clamp(1, DECAY_RANKS)
+    }
+}
+
+impl<'ctx, Query: RankingRuleQueryTrait> RankingRule<'ctx, Query> for RecencyDecay<Query> {
+    fn id(&self) -> String {
+        "recency_decay".to_owned()
+    }
+
+    #[tracing::instrument(level = "trace", skip_all, target = "search::recency_decay")]
+    fn start_iteration(
+        &mut self,
+        ctx: &mut SearchContext<'ctx>,
+        _logger: &mut dyn SearchLogger<Query>,
+        universe: &RoaringBitmap,
+        query: &Query,
+    ) -> Result<()> {
+        let mut buckets: BTreeMap<u32, RoaringBitmap> = BTreeMap::new();
+        for document in ctx.index.iter_documents(ctx.txn, universe.iter())? {
+            let (docid, obkv) = document?;
+            let timestamp = obkv
+                .get(self.field_id)
+                .and_then(|value| serde_json::from_slice::<serde_json::Value>(value).ok())
+                .and_then(|value| value.as_f64());
+            buckets.entry(self.rank(timestamp)).or_default().insert(docid);
+        }
+
+        self.buckets = buckets.into_iter().collect();
+        self.query = Some(query.clone());
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "trace", skip_all, target = "search::recency_decay")]
+    fn next_bucket(
+        &mut self,
+        _ctx: &mut SearchContext<'ctx>,
+        _logger: &mut dyn SearchLogger<Query>,
+        universe: &RoaringBitmap,
+    ) -> Result<Option<RankingRuleOutput<Query>>> {
+        let Some(query) = &self.query else { return Ok(None) };
+        // the documents removed from the universe since, by the distinct rule, are skipped
+        while let Some((rank, candidates)) = self.buckets.pop() {
+            let candidates = candidates & universe;
+            if !candidates.is_empty() {
+                return Ok(Some(RankingRuleOutput {
+                    query: query.clone(),
+                    candidates,
+                    score: ScoreDetails::RecencyDecay(Rank { rank, max_rank: DECAY_RANKS }),
+                }));
+            }
+        }
+        Ok(None)
+    }
+
+    #[tracing::instrument(level = "trace", skip_all, target = "search::recency_decay")]
+    fn end_iteration(
+        &mut self,
+        _ctx: &mut SearchContext<'ctx>,
+        _logger: &mut dyn SearchLogger<Query>,
+    ) {
+        self.buckets.clear();
+        self.query = None;
+    }
+}
@@ -1,6 +1,6 @@
 #![allow(clippy::too_many_arguments)]
 
-use std::collections::VecDeque;
+use std::collections::{BTreeSet, VecDeque};
 
 use fxhash::FxHashMap;
 use roaring::{MultiOps, RoaringBitmap};
@@ -36,10 +36,28 @@ pub fn compute_query_term_subset_docids(
     term: &QueryTermSubset,
 ) -> Result<RoaringBitmap> {
     let mut docids = RoaringBitmap::new();
+    // the words derived from the prefix must only match the fields where the prefix search is enabled
+    let prefix_fids = ctx.prefix_search.enabled.clone();
+    let prefix_of_words =
+        if prefix_fids.is_some() { term.prefix_of_words(ctx) } else { BTreeSet::new() };
+
     // TODO use the MultiOps trait to do large intersections
     for word in term.all_single_words_except_prefix_db(ctx)? {
-        if let Some(word_docids) = ctx.word_docids(universe, word)? {
-            docids |= word_docids;
+        match &prefix_fids {
+            Some(fids) if prefix_of_words.contains(&word.interned()) => {
+                for &fid in fids {
+                    if let Some(word_fid_docids) =
+                        ctx.get_db_word_fid_docids(universe, word.interned(), fid)?
+                    {
+                        docids |= word_fid_docids;
+                    }
+                }
+            }
+            _ => {
+                if let Some(word_docids) = ctx.word_docids(universe, word)? {
+                    docids |= word_docids;
+                }
+            }
         }
     }
     for phrase in term.all_phrases(ctx)? {
@@ -47,8 +65,21 @@ pub fn compute_query_term_subset_docids(
     }
 
     if let Some(prefix) = term.use_prefix_db(ctx) {
-        if let Some(prefix_docids) = ctx.word_prefix_docids(universe, prefix)? {
-            docids |= prefix_docids;
+        match &prefix_fids {
+            Some(fids) => {
+                for &fid in fids {
+                    if let Some(prefix_fid_docids) =
+                        ctx.get_db_word_prefix_fid_docids(universe, prefix.interned(), fid)?
+                    {
+                        docids |= prefix_fid_docids;
+                    }
+                }
+            }
+            None => {
+                if let Some(prefix_docids) = ctx.word_prefix_docids(universe, prefix)? {
+                    docids |= prefix_docids;
+                }
+            }
         }
     }
 
@@ -58,14 +89,25 @@ pub fn compute_query_term_subset_docids(
     }
 }
 
+/// Returns the documents matching the term within the given field.
+///
+/// The words derived from the prefix of the term are only matched when `with_prefix` is `true`
+/// and the prefix search is enabled on the field.
 pub fn compute_query_term_subset_docids_within_field_id(
     ctx: &mut SearchContext<'_>,
     universe: Option<&RoaringBitmap>,
     term: &QueryTermSubset,
     fid: u16,
+    with_prefix: bool,
 ) -> Result<RoaringBitmap> {
+    let with_prefix = with_prefix && ctx.prefix_search.is_enabled(fid);
+    let prefix_of_words = if with_prefix { BTreeSet::new() } else { term.prefix_of_words(ctx) };
+
     let mut docids = RoaringBitmap::new();
     for word in term.all_single_words_except_prefix_db(ctx)? {
+        if prefix_of_words.contains(&word.interned()) {
+            continue;
+        }
         if let Some(word_fid_docids) = ctx.get_db_word_fid_docids(universe, word.interned(), fid)? {
             docids |= word_fid_docids;
         }
@@ -81,7 +123,7 @@ pub fn compute_query_term_subset_docids_within_field_id(
         }
     }
 
-    if let Some(word_prefix) = term.use_prefix_db(ctx) {
+    if let Some(word_prefix) = term.use_prefix_db(ctx).filter(|_| with_prefix) {
         if let Some(word_fid_docids) =
             ctx.get_db_word_prefix_fid_docids(universe, word_prefix.interned(), fid)?
         {
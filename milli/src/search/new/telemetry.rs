@@ -0,0 +1,47 @@
+//! Structured spans describing the steps of a search.
+//!
+//! Each step records the number of documents it handled and the time it took, in microseconds,
+//! in the fields of its span. The clock is only read when a subscriber is interested in the span.
+
+use std::time::{Duration, Instant};
+
+use tracing::Span;
+
+/// A span adding the time spent inside of it to its `duration_us` field, recorded when dropped.
+pub struct TimedSpan {
+    span: Span,
+    /// `None` when no subscriber is interested in the span.
+    elapsed: Option<Duration>,
+}
+
+impl TimedSpan {
+    pub fn new(span: Span) -> Self {
+        let elapsed = (!span.is_disabled()).then(Duration::default);
+        TimedSpan { span, elapsed }
+    }
+
+    /// Runs the function inside of the span, the same span can be entered many times.
+    pub fn in_scope<T>(&mut self, f: impl FnOnce() -> T) -> T {
+        match &mut self.elapsed {
+            Some(elapsed) => {
+                let started = Instant::now();
+                let output = self.span.in_scope(f);
+                *elapsed += started.elapsed();
+                output
+            }
+            None => f(),
+        }
+    }
+
+    pub fn record(&self, field: &str, value: u64) {
+        self.span.record(field, value);
+    }
+}
+
+impl Drop for TimedSpan {
+    fn drop(&mut self) {
+        if let Some(elapsed) = self.elapsed {
+            self.span.record("duration_us", elapsed.as_micros() as u64);
+        }
+    }
+}
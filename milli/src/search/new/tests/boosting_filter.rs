@@ -0,0 +1,110 @@
+/*!
+This module tests the `boosting_filter` search parameter:
+
+1. the documents matching the boosting filter are ranked before all the others
+2. the ranking rules still apply within the boosted and the other documents
+3. the documents not matching the boosting filter are still returned
+4. the boosting filter is the first ranking score detail of the documents
+5. the boosting filter doesn't change the ranking score of the documents
+*/
+
+use big_s::S;
+use maplit::hashset;
+
+use crate::index::tests::TempIndex;
+use crate::score_details::{self, ScoreDetails, ScoringStrategy};
+use crate::{Criterion, Filter, Search, SearchResult, TermsMatchingStrategy};
+
+fn create_index() -> TempIndex {
+    let index = TempIndex::new();
+
+    index
+        .update_settings(|s| {
+            s.set_primary_key("id".to_owned());
+            s.set_searchable_fields(vec!["text".to_owned()]);
+            s.set_filterable_fields(hashset! { S("kind") });
+            s.set_criteria(vec![Criterion::Words]);
+        })
+        .unwrap();
+
+    index
+        .add_documents(documents!([
+            { "id": 0, "text": "the quick brown fox", "kind": "a" },
+            { "id": 1, "text": "the quick brown", "kind": "b" },
+            { "id": 2, "text": "the quick", "kind": "b" },
+            { "id": 3, "text": "the quick brown fox jumps", "kind": "a" },
+        ]))
+        .unwrap();
+    index
+}
+
+fn search(index: &TempIndex, query: Option<&str>, boosting_filter: Option<&str>) -> SearchResult {
+    let txn = index.read_txn().unwrap();
+    let mut s = Search::new(&txn, index);
+    if let Some(query) = query {
+        s.query(query);
+    }
+    s.terms_matching_strategy(TermsMatchingStrategy::Last);
+    s.scoring_strategy(ScoringStrategy::Detailed);
+    if let Some(boosting_filter) = boosting_filter {
+        s.boosting_filter(Filter::from_str(boosting_filter).unwrap().unwrap());
+    }
+    s.execute().unwrap()
+}
+
+#[test]
+fn test_boosting_filter_query() {
+    let index = create_index();
+
+    let SearchResult { documents_ids, .. } = search(&index, Some("quick brown fox"), None);
+    assert_eq!(documents_ids, vec![0, 3, 1, 2]);
+
+    // the boosted documents come first, the ones matching the most words first among them
+    let SearchResult { documents_ids, candidates, .. } =
+        search(&index, Some("quick brown fox"), Some("kind = b"));
+    assert_eq!(documents_ids, vec![1, 2, 0, 3]);
+    assert_eq!(candidates.len(), 4);
+
+    let SearchResult { documents_ids, .. } =
+        search(&index, Some("quick brown fox"), Some("kind = c"));
+    assert_eq!(documents_ids, vec![0, 3, 1, 2]);
+}
+
+#[test]
+fn test_boosting_filter_placeholder() {
+    let index = create_index();
+
+    let SearchResult { documents_ids, .. } = search(&index, None, Some("kind = b"));
+    assert_eq!(documents_ids, vec![1, 2, 0, 3]);
+
+    let SearchResult { documents_ids, .. } = search(&index, None, Some("id = 3 OR id = 1"));
+    assert_eq!(documents_ids, vec![1, 3, 0, 2]);
+}
+
+#[test]
+fn test_boosting_filter_score_details() {
+    let index = create_index();
+
+    let SearchResult { document_scores, .. } =
+        search(&index, Some("quick brown fox"), Some("kind = b"));
+    let boosting: Vec<_> = document_scores.iter().map(|scores| scores[0].clone()).collect();
+    let boosted = ScoreDetails::BoostingFilter(score_details::BoostingFilter { matches: true });
+    let not_boosted =
+        ScoreDetails::BoostingFilter(score_details::BoostingFilter { matches: false });
+    assert_eq!(boosting, vec![boosted.clone(), boosted, not_boosted.clone(), not_boosted]);
+
+    // a boosted document is ranked above the others, but keeps its score
+    let scores: Vec<_> =
+        document_scores.iter().map(|scores| ScoreDetails::global_score(scores.iter())).collect();
+    assert!(scores[1] < scores[2], "{scores:?}");
+
+    let SearchResult { documents_ids, document_scores: unboosted_scores, .. } =
+        search(&index, Some("quick brown fox"), None);
+    assert_eq!(documents_ids, vec![0, 3, 1, 2]);
+    let unboosted_scores: Vec<_> =
+        unboosted_scores.iter().map(|scores| ScoreDetails::global_score(scores.iter())).collect();
+    assert_eq!(
+        scores,
+        vec![unboosted_scores[2], unboosted_scores[3], unboosted_scores[0], unboosted_scores[1]]
+    );
+}
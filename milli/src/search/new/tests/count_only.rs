@@ -0,0 +1,190 @@
+/*!
+This module tests the `count_only` search parameter:
+
+1. the candidates are the same as the ones of the full search, but no document is returned
+2. no ranking rule is run, even to fetch the first bucket
+3. the distinct attribute is applied to the whole set of candidates
+*/
+
+use std::any::Any;
+
+use big_s::S;
+use maplit::hashset;
+use roaring::RoaringBitmap;
+
+use crate::index::tests::TempIndex;
+use crate::score_details::ScoringStrategy;
+use crate::search::new::ranking_rules::{BoxRankingRule, RankingRule, RankingRuleQueryTrait};
+use crate::search::new::{GeoSortStrategy, SearchLogger};
+use crate::{
+    execute_search, filtered_universe, Criterion, Filter, Search, SearchContext, SearchResult,
+    TermsMatchingStrategy, TimeBudget,
+};
+
+fn create_index() -> TempIndex {
+    let index = TempIndex::new();
+
+    index
+        .update_settings(|s| {
+            s.set_primary_key("id".to_owned());
+            s.set_searchable_fields(vec!["text".to_owned()]);
+            s.set_filterable_fields(hashset! { S("kind"), S("color") });
+            s.set_criteria(vec![Criterion::Words, Criterion::Typo, Criterion::Proximity]);
+        })
+        .unwrap();
+
+    index
+        .add_documents(documents!([
+            { "id": 0, "text": "the quick brown fox", "kind": "a", "color": "red" },
+            { "id": 1, "text": "the quick brown dog", "kind": "a", "color": "red" },
+            { "id": 2, "text": "the quikc brown fox", "kind": "b", "color": "blue" },
+            { "id": 3, "text": "the slow brown fox", "kind": "b", "color": "red" },
+            { "id": 4, "text": "the lazy dog", "kind": "c", "color": "blue" },
+            { "id": 5, "text": "a fox and a dog", "kind": "b", "color": "blue" },
+        ]))
+        .unwrap();
+    index
+}
+
+fn search(
+    index: &TempIndex,
+    query: Option<&str>,
+    filter: &str,
+    distinct: Option<&str>,
+    count_only: bool,
+) -> SearchResult {
+    let txn = index.read_txn().unwrap();
+    let mut s = Search::new(&txn, index);
+    if let Some(query) = query {
+        s.query(query);
+    }
+    if let Some(distinct) = distinct {
+        s.distinct(distinct.to_string());
+    }
+    s.filter(Filter::from_str(filter).unwrap().unwrap());
+    s.terms_matching_strategy(TermsMatchingStrategy::Last);
+    s.exhaustive_number_hits(true);
+    s.count_only(count_only);
+    s.execute().unwrap()
+}
+
+/// Records whether the ranking rules were used by the search.
+#[derive(Default)]
+struct RankingRulesLogger {
+    ranking_rules: usize,
+    iterations: usize,
+    results: usize,
+}
+
+impl<Q: RankingRuleQueryTrait> SearchLogger<Q> for RankingRulesLogger {
+    fn initial_query(&mut self, _query: &Q) {}
+
+    fn initial_universe(&mut self, _universe: &RoaringBitmap) {}
+
+    fn query_for_initial_universe(&mut self, _query: &Q) {}
+
+    fn ranking_rules(&mut self, _rr: &[BoxRankingRule<'_, Q>]) {
+        self.ranking_rules += 1;
+    }
+
+    fn start_iteration_ranking_rule(
+        &mut self,
+        _ranking_rule_idx: usize,
+        _ranking_rule: &dyn RankingRule<'_, Q>,
+        _query: &Q,
+        _universe: &RoaringBitmap,
+    ) {
+        self.iterations += 1;
+    }
+
+    fn add_to_results(&mut self, docids: &[u32]) {
+        self.results += docids.len();
+    }
+
+    fn log_internal_state(&mut self, _rr: &dyn Any) {}
+}
+
+fn logged_search(
+    index: &TempIndex,
+    query: Option<&str>,
+    count_only: bool,
+) -> (u64, RankingRulesLogger, RankingRulesLogger) {
+    let txn = index.read_txn().unwrap();
+    let mut ctx = SearchContext::new(index, &txn).unwrap();
+    ctx.count_only = count_only;
+    let filter = Filter::from_str("kind = b").unwrap();
+    let universe = filtered_universe(ctx.index, ctx.txn, &filter).unwrap();
+
+    let mut placeholder_logger = RankingRulesLogger::default();
+    let mut query_graph_logger = RankingRulesLogger::default();
+    let result = execute_search(
+        &mut ctx,
+        query,
+        TermsMatchingStrategy::Last,
+        ScoringStrategy::Skip,
+        true,
+        universe,
+        &None,
+        &None,
+        GeoSortStrategy::default(),
+        0,
+        20,
+        None,
+        &mut placeholder_logger,
+        &mut query_graph_logger,
+        TimeBudget::max(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    (result.candidates.len(), placeholder_logger, query_graph_logger)
+}
+
+#[test]
+fn test_count_only_same_candidates() {
+    let index = create_index();
+
+    for query in [Some("quick fox"), Some("dog"), None] {
+        for filter in ["kind = b", "color = red", "kind = a AND color = blue"] {
+            let full = search(&index, query, filter, None, false);
+            let count = search(&index, query, filter, None, true);
+            assert_eq!(full.candidates, count.candidates, "query: {query:?}, filter: {filter}");
+            assert!(count.documents_ids.is_empty());
+            assert!(count.document_scores.is_empty());
+        }
+    }
+}
+
+#[test]
+fn test_count_only_does_not_rank() {
+    let index = create_index();
+
+    for query in [Some("brown fox"), None] {
+        let (full_count, full_placeholder, full_query_graph) = logged_search(&index, query, false);
+        let (count, placeholder, query_graph) = logged_search(&index, query, true);
+        assert_eq!(full_count, count, "query: {query:?}");
+
+        // the full search does use its ranking rules
+        let full_ranking_rules = full_placeholder.ranking_rules + full_query_graph.ranking_rules;
+        assert_eq!(full_ranking_rules, 1, "query: {query:?}");
+
+        for logger in [placeholder, query_graph] {
+            assert_eq!(logger.ranking_rules, 0, "query: {query:?}");
+            assert_eq!(logger.iterations, 0, "query: {query:?}");
+            assert_eq!(logger.results, 0, "query: {query:?}");
+        }
+    }
+}
+
+#[test]
+fn test_count_only_with_distinct() {
+    let index = create_index();
+
+    // the candidates of the full search are only deduplicated when the search is exhaustive
+    let full = search(&index, Some("fox"), "color = blue OR color = red", Some("kind"), false);
+    let count = search(&index, Some("fox"), "color = blue OR color = red", Some("kind"), true);
+    assert_eq!(full.candidates, count.candidates);
+    assert_eq!(count.candidates.len(), 2);
+    assert!(count.documents_ids.is_empty());
+}
@@ -0,0 +1,116 @@
+/*!
+This module tests the `cross_attribute_matching` search parameter:
+
+1. by default, the query terms can be spread across the searchable attributes of a document
+2. when disabled, a document only matches if one of its attributes contains all the query terms
+3. the derivations of the terms (typos, prefixes) are taken into account within the attribute
+4. only the attributes to search on are considered
+5. the terms the matching strategy allows to remove don't have to be within the attribute
+*/
+
+use crate::index::tests::TempIndex;
+use crate::{Search, SearchResult, TermsMatchingStrategy};
+
+fn create_index() -> TempIndex {
+    let index = TempIndex::new();
+
+    index
+        .update_settings(|s| {
+            s.set_primary_key("id".to_owned());
+            s.set_searchable_fields(vec!["title".to_owned(), "body".to_owned()]);
+        })
+        .unwrap();
+
+    index
+        .add_documents(documents!([
+            { "id": 0, "title": "the quick fox", "body": "jumps over the lazy dog" },
+            { "id": 1, "title": "a quick brown fox", "body": "nothing to see" },
+            { "id": 2, "title": "nothing to see", "body": "the quikc fox" },
+            { "id": 3, "title": "the fox", "body": "quick" },
+        ]))
+        .unwrap();
+    index
+}
+
+fn search(
+    index: &TempIndex,
+    query: &str,
+    attributes_to_search_on: Option<&[String]>,
+    cross_attribute_matching: bool,
+) -> Vec<u32> {
+    search_with_strategy(
+        index,
+        query,
+        attributes_to_search_on,
+        cross_attribute_matching,
+        TermsMatchingStrategy::All,
+    )
+}
+
+fn search_with_strategy(
+    index: &TempIndex,
+    query: &str,
+    attributes_to_search_on: Option<&[String]>,
+    cross_attribute_matching: bool,
+    terms_matching_strategy: TermsMatchingStrategy,
+) -> Vec<u32> {
+    let txn = index.read_txn().unwrap();
+    let mut s = Search::new(&txn, index);
+    s.query(query);
+    s.terms_matching_strategy(terms_matching_strategy);
+    if let Some(attributes_to_search_on) = attributes_to_search_on {
+        s.searchable_attributes(attributes_to_search_on);
+    }
+    s.cross_attribute_matching(cross_attribute_matching);
+    let SearchResult { mut documents_ids, .. } = s.execute().unwrap();
+    documents_ids.sort_unstable();
+    documents_ids
+}
+
+#[test]
+fn test_cross_attribute_matching_enabled_by_default() {
+    let index = create_index();
+
+    assert_eq!(search(&index, "quick fox", None, true), vec![0, 1, 2, 3]);
+    assert_eq!(search(&index, "fox dog", None, true), vec![0]);
+}
+
+#[test]
+fn test_cross_attribute_matching_disabled() {
+    let index = create_index();
+
+    assert_eq!(search(&index, "quick fox", None, false), vec![0, 1, 2]);
+    // "fox" is in the title while "dog" is in the body
+    assert_eq!(search(&index, "fox dog", None, false), Vec::<u32>::new());
+    // "quikc" is a typo of "quick" and "quic" one of its prefixes
+    assert_eq!(search(&index, "fox quikc", None, false), vec![0, 1, 2]);
+    assert_eq!(search(&index, "fox quic", None, false), vec![0, 1]);
+}
+
+#[test]
+fn test_cross_attribute_matching_disabled_with_attributes_to_search_on() {
+    let index = create_index();
+
+    let body = ["body".to_owned()];
+    assert_eq!(search(&index, "quick fox", Some(&body), true), vec![2]);
+    assert_eq!(search(&index, "quick fox", Some(&body), false), vec![2]);
+
+    let title = ["title".to_owned()];
+    assert_eq!(search(&index, "quick fox", Some(&title), false), vec![0, 1]);
+}
+
+#[test]
+fn test_cross_attribute_matching_disabled_with_matching_strategy() {
+    let index = create_index();
+
+    // "dog" can be removed from the end of the query, only "fox" must be within one attribute
+    let last = TermsMatchingStrategy::Last;
+    assert_eq!(search_with_strategy(&index, "fox dog", None, false, last), vec![0, 1, 2, 3]);
+    // "fox" is more frequent than "dog", so "fox" can be removed and "dog" must be kept
+    let frequency = TermsMatchingStrategy::Frequency;
+    assert_eq!(search_with_strategy(&index, "fox dog", None, false, frequency), vec![0]);
+    assert_eq!(
+        search_with_strategy(&index, "fox dog", None, false, TermsMatchingStrategy::All),
+        Vec::<u32>::new()
+    );
+}
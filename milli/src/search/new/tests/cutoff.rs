@@ -116,6 +116,7 @@ fn degraded_search_and_score_details() {
                 Typo {
                     typo_count: 0,
                     max_typo_count: 3,
+                    penalty: 1,
                 },
             ),
         ],
@@ -130,6 +131,7 @@ fn degraded_search_and_score_details() {
                 Typo {
                     typo_count: 1,
                     max_typo_count: 3,
+                    penalty: 1,
                 },
             ),
         ],
@@ -144,6 +146,7 @@ fn degraded_search_and_score_details() {
                 Typo {
                     typo_count: 2,
                     max_typo_count: 3,
+                    penalty: 1,
                 },
             ),
         ],
@@ -158,6 +161,7 @@ fn degraded_search_and_score_details() {
                 Typo {
                     typo_count: 0,
                     max_typo_count: 2,
+                    penalty: 1,
                 },
             ),
         ],
@@ -226,6 +230,7 @@ fn degraded_search_and_score_details() {
                 Typo {
                     typo_count: 0,
                     max_typo_count: 3,
+                    penalty: 1,
                 },
             ),
         ],
@@ -273,6 +278,7 @@ fn degraded_search_and_score_details() {
                 Typo {
                     typo_count: 0,
                     max_typo_count: 3,
+                    penalty: 1,
                 },
             ),
         ],
@@ -287,6 +293,7 @@ fn degraded_search_and_score_details() {
                 Typo {
                     typo_count: 1,
                     max_typo_count: 3,
+                    penalty: 1,
                 },
             ),
         ],
@@ -326,6 +333,7 @@ fn degraded_search_and_score_details() {
                 Typo {
                     typo_count: 0,
                     max_typo_count: 3,
+                    penalty: 1,
                 },
             ),
         ],
@@ -340,6 +348,7 @@ fn degraded_search_and_score_details() {
                 Typo {
                     typo_count: 1,
                     max_typo_count: 3,
+                    penalty: 1,
                 },
             ),
         ],
@@ -354,6 +363,7 @@ fn degraded_search_and_score_details() {
                 Typo {
                     typo_count: 2,
                     max_typo_count: 3,
+                    penalty: 1,
                 },
             ),
         ],
@@ -384,6 +394,7 @@ fn degraded_search_and_score_details() {
                 Typo {
                     typo_count: 0,
                     max_typo_count: 3,
+                    penalty: 1,
                 },
             ),
         ],
@@ -398,6 +409,7 @@ fn degraded_search_and_score_details() {
                 Typo {
                     typo_count: 1,
                     max_typo_count: 3,
+                    penalty: 1,
                 },
             ),
         ],
@@ -412,6 +424,7 @@ fn degraded_search_and_score_details() {
                 Typo {
                     typo_count: 2,
                     max_typo_count: 3,
+                    penalty: 1,
                 },
             ),
         ],
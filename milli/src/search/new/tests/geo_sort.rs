@@ -307,3 +307,62 @@ fn geo_sort_without_any_geo_faceted_documents() {
     insta::assert_snapshot!(format!("{ids:?}"), @"[0, 2, 3]");
     insta::assert_snapshot!(format!("{scores:#?}"));
 }
+
+#[test]
+fn geo_sort_with_geojson_points() {
+    let lat_lng_index = create_index();
+    lat_lng_index
+        .add_documents(documents!([
+            { "id": 2, "_geo": { "lat": 2, "lng": -1 } },
+            { "id": 3, "_geo": { "lat": -2, "lng": -2 } },
+            { "id": 5, "_geo": { "lat": 6, "lng": -5 } },
+            { "id": 4, "_geo": { "lat": 3, "lng": 5 } },
+            { "id": 0, "_geo": { "lat": 0, "lng": 0 } },
+            { "id": 1, "_geo": { "lat": 1, "lng": 1 } },
+            { "id": 6 }, { "id": 8 },
+        ]))
+        .unwrap();
+
+    // the same documents, but the coordinates are expressed as GeoJSON points: `[lng, lat]`
+    let geojson_index = create_index();
+    geojson_index
+        .add_documents(documents!([
+            { "id": 2, "_geo": { "type": "Point", "coordinates": [-1, 2] } },
+            { "id": 3, "_geo": { "type": "Point", "coordinates": [-2, -2] } },
+            { "id": 5, "_geo": { "type": "Point", "coordinates": [-5, 6] } },
+            { "id": 4, "_geo": { "type": "Point", "coordinates": [5, 3] } },
+            { "id": 0, "_geo": { "type": "Point", "coordinates": [0, 0] } },
+            { "id": 1, "_geo": { "type": "Point", "coordinates": [1, 1] } },
+            { "id": 6 }, { "id": 8 },
+        ]))
+        .unwrap();
+
+    let lat_lng_rtxn = lat_lng_index.read_txn().unwrap();
+    let geojson_rtxn = geojson_index.read_txn().unwrap();
+
+    for sort in [
+        AscDesc::Asc(Member::Geo([0., 0.])),
+        AscDesc::Desc(Member::Geo([0., 0.])),
+        AscDesc::Asc(Member::Geo([5., -4.])),
+    ] {
+        let mut s = Search::new(&lat_lng_rtxn, &lat_lng_index);
+        s.scoring_strategy(crate::score_details::ScoringStrategy::Detailed);
+        s.sort_criteria(vec![sort.clone()]);
+        let lat_lng_result =
+            execute_iterative_and_rtree_returns_the_same(&lat_lng_rtxn, &lat_lng_index, &mut s);
+
+        let mut s = Search::new(&geojson_rtxn, &geojson_index);
+        s.scoring_strategy(crate::score_details::ScoringStrategy::Detailed);
+        s.sort_criteria(vec![sort]);
+        let geojson_result =
+            execute_iterative_and_rtree_returns_the_same(&geojson_rtxn, &geojson_index, &mut s);
+
+        assert_eq!(lat_lng_result, geojson_result);
+    }
+
+    let mut s = Search::new(&geojson_rtxn, &geojson_index);
+    s.sort_criteria(vec![AscDesc::Asc(Member::Geo([0., 0.]))]);
+    let (ids, _) =
+        execute_iterative_and_rtree_returns_the_same(&geojson_rtxn, &geojson_index, &mut s);
+    insta::assert_snapshot!(format!("{ids:?}"), @"[0, 1, 2, 3, 4, 5, 6, 8]");
+}
@@ -0,0 +1,88 @@
+/*!
+This module tests the grouping of the ranked documents by a facet value:
+
+1. the documents are bucketed by the value of the grouping field, in the order of their best ranked document
+2. each group holds at most the requested number of documents, the best ranked ones
+3. the documents without the grouping field are gathered in an ungrouped bucket placed last
+4. the grouping field must be filterable
+*/
+
+use maplit::hashset;
+use serde_json::json;
+
+use crate::index::tests::TempIndex;
+use crate::{group_documents, DocumentsGroup, Error, Search, SearchResult, UserError};
+
+fn create_index() -> TempIndex {
+    let index = TempIndex::new();
+
+    index
+        .update_settings(|s| {
+            s.set_primary_key("id".to_owned());
+            s.set_filterable_fields(hashset! { "category".to_owned(), "year".to_owned() });
+        })
+        .unwrap();
+
+    index
+        .add_documents(documents!([
+            { "id": 0, "category": "book", "year": 2020 },
+            { "id": 1, "category": "music", "year": 2021 },
+            { "id": 2, "category": "book", "year": 2020 },
+            { "id": 3 },
+            { "id": 4, "category": "book", "year": 2021 },
+            { "id": 5, "category": "music" },
+            { "id": 6, "category": "movie" },
+        ]))
+        .unwrap();
+    index
+}
+
+fn group(index: &TempIndex, field: &str, limit: usize) -> crate::Result<Vec<DocumentsGroup>> {
+    let txn = index.read_txn().unwrap();
+    let s = Search::new(&txn, index);
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    group_documents(index, &txn, field, &documents_ids, limit)
+}
+
+#[test]
+fn test_group_by_string_facet() {
+    let index = create_index();
+
+    let groups = group(&index, "category", 2).unwrap();
+    assert_eq!(
+        groups,
+        vec![
+            DocumentsGroup { value: Some(json!("book")), documents_ids: vec![0, 2] },
+            DocumentsGroup { value: Some(json!("music")), documents_ids: vec![1, 5] },
+            DocumentsGroup { value: Some(json!("movie")), documents_ids: vec![6] },
+            DocumentsGroup { value: None, documents_ids: vec![3] },
+        ]
+    );
+
+    let groups = group(&index, "category", 1).unwrap();
+    let documents_ids: Vec<_> = groups.into_iter().map(|group| group.documents_ids).collect();
+    assert_eq!(documents_ids, vec![vec![0], vec![1], vec![6], vec![3]]);
+}
+
+#[test]
+fn test_group_by_number_facet() {
+    let index = create_index();
+
+    let groups = group(&index, "year", 10).unwrap();
+    assert_eq!(
+        groups,
+        vec![
+            DocumentsGroup { value: Some(json!(2020)), documents_ids: vec![0, 2] },
+            DocumentsGroup { value: Some(json!(2021)), documents_ids: vec![1, 4] },
+            DocumentsGroup { value: None, documents_ids: vec![3, 5, 6] },
+        ]
+    );
+}
+
+#[test]
+fn test_group_by_not_filterable() {
+    let index = create_index();
+
+    let error = group(&index, "id", 2).unwrap_err();
+    assert!(matches!(error, Error::UserError(UserError::InvalidGroupByAttribute { .. })));
+}
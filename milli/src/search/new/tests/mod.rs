@@ -1,17 +1,27 @@
 pub mod attribute_fid;
 pub mod attribute_position;
+pub mod boosting_filter;
+pub mod count_only;
+pub mod cross_attribute_matching;
 pub mod cutoff;
 pub mod distinct;
 pub mod exactness;
 pub mod geo_sort;
+pub mod group_by;
 pub mod integration;
 #[cfg(feature = "all-tokenizations")]
 pub mod language;
 pub mod ngram_split_words;
+pub mod prefix_search;
 pub mod proximity;
 pub mod proximity_typo;
+pub mod recency;
+pub mod recency_decay;
 pub mod sort;
 pub mod stop_words;
+pub mod synonyms;
+pub mod telemetry;
+pub mod total_hits;
 pub mod typo;
 pub mod typo_proximity;
 pub mod words_tms;
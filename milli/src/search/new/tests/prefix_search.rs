@@ -0,0 +1,96 @@
+/*!
+This module tests the `attributesPrefixSearch` setting:
+
+1. the words starting with the last query term only match the attributes on which the prefix search is enabled
+2. the complete query terms match all the attributes
+3. the prefix matches of an attribute with a penalty are ranked after its complete matches by the `attribute` ranking rule
+*/
+
+use maplit::btreemap;
+
+use crate::index::tests::TempIndex;
+use crate::{AttributePrefixSearch, Criterion, Search, SearchResult};
+
+fn create_index() -> TempIndex {
+    let index = TempIndex::new();
+
+    index
+        .update_settings(|s| {
+            s.set_primary_key("id".to_owned());
+            s.set_searchable_fields(vec!["title".to_owned(), "description".to_owned()]);
+            s.set_criteria(vec![Criterion::Words, Criterion::Attribute]);
+        })
+        .unwrap();
+
+    index
+        .add_documents(documents!([
+            { "id": 0, "title": "autocomplete" },
+            { "id": 1, "description": "autocomplete" },
+            { "id": 2, "title": "auto" },
+        ]))
+        .unwrap();
+    index
+}
+
+fn search(index: &TempIndex, query: &str) -> Vec<u32> {
+    let txn = index.read_txn().unwrap();
+    let mut s = Search::new(&txn, index);
+    s.query(query);
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    documents_ids
+}
+
+#[test]
+fn test_prefix_search_disabled_on_attribute() {
+    let index = create_index();
+    assert_eq!(search(&index, "auto"), vec![0, 2, 1]);
+
+    index
+        .update_settings(|s| {
+            s.set_attributes_prefix_search(btreemap! {
+                "title".to_owned() => AttributePrefixSearch { enabled: false, penalty: 0 },
+            })
+        })
+        .unwrap();
+
+    // the prefix only matches the description, where the prefix search is still enabled
+    assert_eq!(search(&index, "auto"), vec![2, 1]);
+    // the complete terms still match the title
+    assert_eq!(search(&index, "autocomplete"), vec![0, 1]);
+
+    index.update_settings(|s| s.reset_attributes_prefix_search()).unwrap();
+    assert_eq!(search(&index, "auto"), vec![0, 2, 1]);
+}
+
+#[test]
+fn test_prefix_search_penalty() {
+    let index = TempIndex::new();
+
+    index
+        .update_settings(|s| {
+            s.set_primary_key("id".to_owned());
+            s.set_searchable_fields(vec!["title".to_owned()]);
+            s.set_criteria(vec![Criterion::Attribute]);
+        })
+        .unwrap();
+
+    index
+        .add_documents(documents!([
+            { "id": 0, "title": "autocomplete" },
+            { "id": 1, "title": "auto" },
+        ]))
+        .unwrap();
+
+    // without any penalty, the prefix and complete matches are in the same bucket
+    assert_eq!(search(&index, "auto"), vec![0, 1]);
+
+    index
+        .update_settings(|s| {
+            s.set_attributes_prefix_search(btreemap! {
+                "title".to_owned() => AttributePrefixSearch { enabled: true, penalty: 1 },
+            })
+        })
+        .unwrap();
+
+    assert_eq!(search(&index, "auto"), vec![1, 0]);
+}
@@ -0,0 +1,116 @@
+/*!
+This module tests the `_recency` ranking rule:
+
+1. among documents that are equally relevant, the most recent ones come first
+2. the rule has no effect when no recency field is defined in the settings
+3. the rule can be used in a placeholder search
+4. changing the recency field after the documents were indexed is taken into account
+*/
+
+use crate::index::tests::TempIndex;
+use crate::{Criterion, Search, SearchResult, TermsMatchingStrategy};
+
+fn create_index() -> TempIndex {
+    let index = TempIndex::new();
+
+    index
+        .update_settings(|s| {
+            s.set_primary_key("id".to_owned());
+            s.set_searchable_fields(vec!["text".to_owned()]);
+            s.set_recency_field("published_at".to_owned());
+            s.set_criteria(vec![Criterion::Words, Criterion::Recency]);
+        })
+        .unwrap();
+
+    index
+        .add_documents(documents!([
+            {
+                "id": 0,
+                "text": "the quick brown fox",
+                "published_at": 100,
+                "updated_at": 400,
+            },
+            {
+                "id": 1,
+                "text": "the quick brown fox",
+                "published_at": 300,
+                "updated_at": 100,
+            },
+            {
+                "id": 2,
+                "text": "the quick brown fox",
+                "published_at": 200,
+                "updated_at": 300,
+            },
+            {
+                "id": 3,
+                "text": "the quick dog",
+                "published_at": 1000,
+                "updated_at": 200,
+            },
+        ]))
+        .unwrap();
+    index
+}
+
+#[test]
+fn test_recency_words() {
+    let index = create_index();
+
+    let txn = index.read_txn().unwrap();
+
+    let mut s = Search::new(&txn, &index);
+    s.terms_matching_strategy(TermsMatchingStrategy::Last);
+    s.query("quick brown fox");
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[1, 2, 0, 3]");
+}
+
+#[test]
+fn test_recency_without_recency_field() {
+    let index = create_index();
+
+    index
+        .update_settings(|s| {
+            s.reset_recency_field();
+        })
+        .unwrap();
+
+    let txn = index.read_txn().unwrap();
+
+    let mut s = Search::new(&txn, &index);
+    s.terms_matching_strategy(TermsMatchingStrategy::Last);
+    s.query("quick brown fox");
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[0, 1, 2, 3]");
+}
+
+#[test]
+fn test_recency_placeholder() {
+    let index = create_index();
+
+    let txn = index.read_txn().unwrap();
+
+    let mut s = Search::new(&txn, &index);
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[3, 1, 2, 0]");
+}
+
+#[test]
+fn test_recency_change_field() {
+    let index = create_index();
+
+    index
+        .update_settings(|s| {
+            s.set_recency_field("updated_at".to_owned());
+        })
+        .unwrap();
+
+    let txn = index.read_txn().unwrap();
+
+    let mut s = Search::new(&txn, &index);
+    s.terms_matching_strategy(TermsMatchingStrategy::Last);
+    s.query("quick brown fox");
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[0, 2, 1, 3]");
+}
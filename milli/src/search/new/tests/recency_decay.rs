@@ -0,0 +1,128 @@
+/*!
+This module tests the `recencyDecay` ranking rule, applied after all the other ranking rules
+when the `recencyHalfLifeDays` setting is defined:
+
+1. equally relevant documents are ranked from the most recent to the oldest
+2. the half-life decides how much the ranking score of the older documents is lowered
+3. the documents without a timestamp keep their ranking score
+4. the decay doesn't move a document before a more relevant one, whatever the page
+*/
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::index::tests::TempIndex;
+use crate::score_details::{ScoreDetails, ScoringStrategy};
+use crate::{Criterion, Search, SearchResult, TermsMatchingStrategy};
+
+const DAY: u64 = 24 * 60 * 60;
+
+fn create_index(half_life_days: u32) -> TempIndex {
+    let index = TempIndex::new();
+
+    index
+        .update_settings(|s| {
+            s.set_primary_key("id".to_owned());
+            s.set_searchable_fields(vec!["text".to_owned()]);
+            s.set_recency_field("published_at".to_owned());
+            s.set_recency_half_life_days(half_life_days);
+            s.set_criteria(vec![Criterion::Words]);
+        })
+        .unwrap();
+
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+    index
+        .add_documents(documents!([
+            { "id": 0, "text": "the quick brown fox", "published_at": now - 10 * DAY },
+            { "id": 1, "text": "the quick brown fox", "published_at": now - DAY },
+            { "id": 2, "text": "the quick brown", "published_at": now },
+            { "id": 3, "text": "the quick brown fox" },
+        ]))
+        .unwrap();
+    index
+}
+
+fn search(index: &TempIndex, offset: usize, limit: usize) -> Vec<u32> {
+    let txn = index.read_txn().unwrap();
+
+    let mut s = Search::new(&txn, index);
+    s.terms_matching_strategy(TermsMatchingStrategy::Last);
+    s.query("quick brown fox");
+    s.offset(offset);
+    s.limit(limit);
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    documents_ids
+}
+
+/// Returns the ids of the documents with their ranking score.
+fn search_with_scores(index: &TempIndex) -> Vec<(u32, f64)> {
+    let txn = index.read_txn().unwrap();
+
+    let mut s = Search::new(&txn, index);
+    s.terms_matching_strategy(TermsMatchingStrategy::Last);
+    s.scoring_strategy(ScoringStrategy::Detailed);
+    s.query("quick brown fox");
+    let SearchResult { documents_ids, document_scores, .. } = s.execute().unwrap();
+    documents_ids
+        .into_iter()
+        .zip(document_scores.iter().map(|scores| ScoreDetails::global_score(scores.iter())))
+        .collect()
+}
+
+#[test]
+fn test_recency_decay_without_half_life() {
+    let index = create_index(1);
+
+    index
+        .update_settings(|s| {
+            s.reset_recency_half_life_days();
+        })
+        .unwrap();
+
+    insta::assert_snapshot!(format!("{:?}", search(&index, 0, 20)), @"[0, 1, 3, 2]");
+}
+
+#[test]
+fn test_recency_decay_orders_the_equally_relevant_documents() {
+    let index = create_index(30);
+    insta::assert_snapshot!(format!("{:?}", search(&index, 0, 20)), @"[3, 1, 0, 2]");
+
+    // the fresh partial match is still ranked after the complete ones
+    let index = create_index(1);
+    insta::assert_snapshot!(format!("{:?}", search(&index, 0, 20)), @"[3, 1, 0, 2]");
+}
+
+#[test]
+fn test_recency_decay_lowers_the_ranking_score() {
+    let short = search_with_scores(&create_index(1));
+    let long = search_with_scores(&create_index(30));
+
+    // the document without a timestamp and the fresh one keep their relevance
+    assert_eq!(short[0], (3, 1.0));
+    assert_eq!(short[0], long[0]);
+    assert_eq!(short[3].0, 2);
+    assert_eq!(short[3], long[3]);
+
+    // the older documents lose more of their score with a shorter half-life
+    assert!(short[1].1 < long[1].1, "{short:?} {long:?}");
+    assert!(short[2].1 < long[2].1, "{short:?} {long:?}");
+    assert!(short[2].1 < short[1].1, "{short:?}");
+}
+
+#[test]
+fn test_recency_decay_pagination() {
+    let index = create_index(30);
+    insta::assert_snapshot!(format!("{:?}", search(&index, 1, 2)), @"[1, 0]");
+    insta::assert_snapshot!(format!("{:?}", search(&index, 3, 2)), @"[2]");
+}
+
+#[test]
+fn test_recency_decay_invalid_half_life() {
+    let index = TempIndex::new();
+
+    let error = index
+        .update_settings(|s| {
+            s.set_recency_half_life_days(0);
+        })
+        .unwrap_err();
+    insta::assert_snapshot!(error, @"`recencyHalfLifeDays` setting is invalid. It should be a positive number of days.");
+}
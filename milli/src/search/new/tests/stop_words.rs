@@ -98,6 +98,7 @@ fn test_ignore_stop_words() {
                 Typo {
                     typo_count: 0,
                     max_typo_count: 1,
+                    penalty: 1,
                 },
             ),
             Proximity(
@@ -151,6 +152,7 @@ fn test_ignore_stop_words() {
                 Typo {
                     typo_count: 0,
                     max_typo_count: 1,
+                    penalty: 1,
                 },
             ),
             Proximity(
@@ -204,6 +206,7 @@ fn test_ignore_stop_words() {
                 Typo {
                     typo_count: 0,
                     max_typo_count: 1,
+                    penalty: 1,
                 },
             ),
             Proximity(
@@ -256,6 +259,7 @@ fn test_ignore_stop_words() {
                 Typo {
                     typo_count: 0,
                     max_typo_count: 2,
+                    penalty: 1,
                 },
             ),
             Proximity(
@@ -315,6 +319,7 @@ fn test_stop_words_in_phrase() {
                 Typo {
                     typo_count: 0,
                     max_typo_count: 0,
+                    penalty: 1,
                 },
             ),
             Proximity(
@@ -356,6 +361,7 @@ fn test_stop_words_in_phrase() {
                 Typo {
                     typo_count: 0,
                     max_typo_count: 0,
+                    penalty: 1,
                 },
             ),
             Proximity(
@@ -408,6 +414,7 @@ fn test_stop_words_in_phrase() {
                 Typo {
                     typo_count: 0,
                     max_typo_count: 2,
+                    penalty: 1,
                 },
             ),
             Proximity(
@@ -0,0 +1,104 @@
+/*!
+This module tests the following properties about synonyms:
+1. The number of synonyms a query term is expanded to is bounded by `max_synonym_expansions`
+2. When some synonyms must be dropped, the ones appearing in the most documents are kept
+3. The bound can be set by the `maxSynonymExpansions` setting and overridden by the search
+*/
+
+use std::collections::BTreeMap;
+
+use crate::index::tests::TempIndex;
+use crate::search::new::query_term::bounded_synonyms;
+use crate::{Search, SearchContext, SearchResult};
+
+fn create_index() -> TempIndex {
+    let index = TempIndex::new();
+
+    index
+        .update_settings(|s| {
+            s.set_primary_key("id".to_owned());
+            s.set_searchable_fields(vec!["text".to_owned()]);
+
+            let mut synonyms: Vec<_> =
+                ["delta", "gamma", "beta", "alpha"].iter().map(|s| s.to_string()).collect();
+            synonyms.extend((0..100).map(|i| format!("unknown{i}")));
+            s.set_synonyms(BTreeMap::from_iter([("word".to_owned(), synonyms)]));
+        })
+        .unwrap();
+
+    index
+        .add_documents(documents!([
+            { "id": 0, "text": "alpha" },
+            { "id": 1, "text": "alpha" },
+            { "id": 2, "text": "alpha beta" },
+            { "id": 3, "text": "beta" },
+            { "id": 4, "text": "gamma" },
+            { "id": 5, "text": "nothing" },
+        ]))
+        .unwrap();
+
+    index
+}
+
+#[test]
+fn test_synonym_expansions_are_bounded() {
+    let index = create_index();
+    let txn = index.read_txn().unwrap();
+
+    let mut ctx = SearchContext::new(&index, &txn).unwrap();
+    let synonyms = bounded_synonyms(&mut ctx, &["word".to_owned()]).unwrap();
+    assert_eq!(synonyms.len(), 50);
+
+    ctx.max_synonym_expansions = 2;
+    let synonyms = bounded_synonyms(&mut ctx, &["word".to_owned()]).unwrap();
+    // the most frequent synonyms are kept in the order they were defined
+    assert_eq!(synonyms, vec![vec!["beta".to_owned()], vec!["alpha".to_owned()]]);
+
+    ctx.max_synonym_expansions = 0;
+    let synonyms = bounded_synonyms(&mut ctx, &["word".to_owned()]).unwrap();
+    assert!(synonyms.is_empty());
+}
+
+#[test]
+fn test_search_keeps_the_most_frequent_synonyms() {
+    let index = create_index();
+    let txn = index.read_txn().unwrap();
+
+    let mut s = Search::new(&txn, &index);
+    s.query("word");
+    let SearchResult { mut documents_ids, .. } = s.execute().unwrap();
+    documents_ids.sort_unstable();
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[0, 1, 2, 3, 4]");
+
+    let mut s = Search::new(&txn, &index);
+    s.query("word");
+    s.max_synonym_expansions(2);
+    let SearchResult { mut documents_ids, .. } = s.execute().unwrap();
+    documents_ids.sort_unstable();
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[0, 1, 2, 3]");
+}
+
+#[test]
+fn test_max_synonym_expansions_setting() {
+    let index = create_index();
+    index.update_settings(|s| s.set_max_synonym_expansions(1)).unwrap();
+    let txn = index.read_txn().unwrap();
+
+    let mut ctx = SearchContext::new(&index, &txn).unwrap();
+    let synonyms = bounded_synonyms(&mut ctx, &["word".to_owned()]).unwrap();
+    assert_eq!(synonyms, vec![vec!["alpha".to_owned()]]);
+
+    let mut s = Search::new(&txn, &index);
+    s.query("word");
+    let SearchResult { mut documents_ids, .. } = s.execute().unwrap();
+    documents_ids.sort_unstable();
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[0, 1, 2]");
+
+    // the search overrides the setting
+    let mut s = Search::new(&txn, &index);
+    s.query("word");
+    s.max_synonym_expansions(2);
+    let SearchResult { mut documents_ids, .. } = s.execute().unwrap();
+    documents_ids.sort_unstable();
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[0, 1, 2, 3]");
+}
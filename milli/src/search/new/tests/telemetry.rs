@@ -0,0 +1,139 @@
+//! This module tests the spans emitted by the search pipeline:
+//! 1. The query parsing and the candidates resolution report the number of terms and candidates
+//! 2. Each ranking rule reports the buckets and documents it returned
+//! 3. The formatting of a text reports its number of matches
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use charabia::TokenizerBuilder;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::Subscriber;
+use tracing_subscriber::layer::{Context, SubscriberExt};
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::Layer;
+
+use crate::index::tests::TempIndex;
+use crate::score_details::ScoringStrategy;
+use crate::search::new::matches::{FormatOptions, MatcherBuilder};
+use crate::{Criterion, Search};
+
+type Fields = BTreeMap<String, String>;
+
+/// A layer keeping the name and the fields of the spans once they are closed.
+#[derive(Default, Clone)]
+struct CapturedSpans(Arc<Mutex<Vec<(String, Fields)>>>);
+
+impl CapturedSpans {
+    fn named(&self, name: &str) -> Vec<Fields> {
+        let spans = self.0.lock().unwrap();
+        spans.iter().filter(|(n, _)| n == name).map(|(_, fields)| fields.clone()).collect()
+    }
+}
+
+struct FieldsVisitor<'a>(&'a mut Fields);
+
+impl Visit for FieldsVisitor<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.0.insert(field.name().to_string(), format!("{value:?}"));
+    }
+}
+
+impl<S> Layer<S> for CapturedSpans
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let mut fields = Fields::new();
+        attrs.record(&mut FieldsVisitor(&mut fields));
+        ctx.span(id).unwrap().extensions_mut().insert(fields);
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+        let span = ctx.span(id).unwrap();
+        let mut extensions = span.extensions_mut();
+        values.record(&mut FieldsVisitor(extensions.get_mut::<Fields>().unwrap()));
+    }
+
+    fn on_close(&self, id: Id, ctx: Context<'_, S>) {
+        let span = ctx.span(&id).unwrap();
+        let fields = span.extensions_mut().remove::<Fields>().unwrap();
+        self.0.lock().unwrap().push((span.name().to_string(), fields));
+    }
+}
+
+fn create_index() -> TempIndex {
+    let index = TempIndex::new();
+
+    index
+        .update_settings(|s| {
+            s.set_primary_key("id".to_owned());
+            s.set_searchable_fields(vec!["text".to_owned()]);
+            s.set_criteria(vec![Criterion::Words, Criterion::Typo]);
+        })
+        .unwrap();
+
+    index
+        .add_documents(documents!([
+            { "id": 0, "text": "hello puppy kefir" },
+            { "id": 1, "text": "hello puppy" },
+            { "id": 2, "text": "hello" },
+            { "id": 3, "text": "hella puppy kefir" },
+            { "id": 4, "text": "hella puppo kefir" },
+        ]))
+        .unwrap();
+    index
+}
+
+#[test]
+fn search_spans() {
+    let index = create_index();
+    let rtxn = index.read_txn().unwrap();
+
+    let capture = CapturedSpans::default();
+    let subscriber = tracing_subscriber::registry().with(capture.clone());
+    tracing::subscriber::with_default(subscriber, || {
+        let mut search = Search::new(&rtxn, &index);
+        search.query("hello puppy");
+        search.scoring_strategy(ScoringStrategy::Detailed);
+        let result = search.execute().unwrap();
+        assert_eq!(result.documents_ids.len(), 5);
+
+        let builder = MatcherBuilder::new(
+            result.matching_words,
+            TokenizerBuilder::<Vec<u8>>::new().into_tokenizer(),
+        );
+        let format_options = FormatOptions { highlight: true, crop: None };
+        let mut matcher = builder.build("hello puppy kefir", None);
+        assert_eq!(&matcher.format(format_options), "<em>hello</em> <em>puppy</em> kefir");
+    });
+
+    let parse_query = capture.named("parse_query");
+    assert_eq!(parse_query.len(), 1);
+    assert_eq!(parse_query[0]["terms"], "2");
+
+    let resolve_candidates = capture.named("resolve_candidates");
+    assert_eq!(resolve_candidates.len(), 1);
+    assert_eq!(resolve_candidates[0]["candidates"], "5");
+
+    let ranking_rules = capture.named("ranking_rule");
+    assert_eq!(ranking_rules.len(), 2);
+    assert_eq!(ranking_rules[0]["ranking_rule"], "words");
+    assert_eq!(ranking_rules[0]["buckets"], "2");
+    assert_eq!(ranking_rules[0]["documents"], "5");
+    assert_eq!(ranking_rules[1]["ranking_rule"], "typo");
+    assert_eq!(ranking_rules[1]["documents"], "5");
+
+    let format = capture.named("format");
+    assert_eq!(format.len(), 1);
+    assert_eq!(format[0]["matches"], "2");
+
+    // every step reports its duration
+    for fields in parse_query.iter().chain(&resolve_candidates).chain(&ranking_rules).chain(&format)
+    {
+        let duration: u64 = fields["duration_us"].parse().unwrap();
+        assert!(duration < 60_000_000, "{fields:?}");
+    }
+}
@@ -0,0 +1,99 @@
+/*!
+This module tests the `compute_total_hits` search parameter:
+
+1. when disabled, the candidates are not gathered but the requested page of hits is unchanged
+2. the pagination with an offset still works when the total number of hits is not computed
+3. it also applies to the placeholder search and when a distinct attribute is set
+*/
+
+use big_s::S;
+use maplit::hashset;
+
+use crate::index::tests::TempIndex;
+use crate::{Criterion, Search, SearchResult, TermsMatchingStrategy};
+
+fn create_index() -> TempIndex {
+    let index = TempIndex::new();
+
+    index
+        .update_settings(|s| {
+            s.set_primary_key("id".to_owned());
+            s.set_searchable_fields(vec!["text".to_owned()]);
+            s.set_filterable_fields(hashset! { S("kind") });
+            s.set_criteria(vec![Criterion::Words, Criterion::Typo]);
+        })
+        .unwrap();
+
+    index
+        .add_documents(documents!([
+            { "id": 0, "text": "the quick brown fox", "kind": "a" },
+            { "id": 1, "text": "the quick brown dog", "kind": "a" },
+            { "id": 2, "text": "the quikc brown fox", "kind": "b" },
+            { "id": 3, "text": "the slow brown fox", "kind": "b" },
+            { "id": 4, "text": "the lazy dog", "kind": "c" },
+        ]))
+        .unwrap();
+    index
+}
+
+fn search(
+    index: &TempIndex,
+    query: Option<&str>,
+    distinct: Option<&str>,
+    offset: usize,
+    compute_total_hits: bool,
+) -> SearchResult {
+    let txn = index.read_txn().unwrap();
+    let mut s = Search::new(&txn, index);
+    if let Some(query) = query {
+        s.query(query);
+    }
+    if let Some(distinct) = distinct {
+        s.distinct(distinct.to_string());
+    }
+    s.terms_matching_strategy(TermsMatchingStrategy::Last);
+    s.offset(offset);
+    s.limit(2);
+    s.compute_total_hits(compute_total_hits);
+    s.execute().unwrap()
+}
+
+#[test]
+fn test_skip_total_hits() {
+    let index = create_index();
+
+    let with_total = search(&index, Some("quick fox"), None, 0, true);
+    let without_total = search(&index, Some("quick fox"), None, 0, false);
+    assert_eq!(with_total.candidates.len(), 3);
+    assert!(without_total.candidates.is_empty());
+    assert_eq!(with_total.documents_ids, without_total.documents_ids);
+}
+
+#[test]
+fn test_skip_total_hits_with_offset() {
+    let index = create_index();
+
+    for offset in 0..5 {
+        let with_total = search(&index, Some("quick fox"), None, offset, true);
+        let without_total = search(&index, Some("quick fox"), None, offset, false);
+        assert!(without_total.candidates.is_empty());
+        assert_eq!(with_total.documents_ids, without_total.documents_ids, "offset: {offset}");
+    }
+}
+
+#[test]
+fn test_skip_total_hits_placeholder_and_distinct() {
+    let index = create_index();
+
+    let with_total = search(&index, None, None, 1, true);
+    let without_total = search(&index, None, None, 1, false);
+    assert_eq!(with_total.candidates.len(), 5);
+    assert!(without_total.candidates.is_empty());
+    assert_eq!(with_total.documents_ids, without_total.documents_ids);
+
+    let with_total = search(&index, Some("brown"), Some("kind"), 0, true);
+    let without_total = search(&index, Some("brown"), Some("kind"), 0, false);
+    assert_eq!(with_total.candidates.len(), 2);
+    assert!(without_total.candidates.is_empty());
+    assert_eq!(with_total.documents_ids, without_total.documents_ids);
+}
@@ -16,11 +16,13 @@ if `words` doesn't exist before it.
 12. Prefix tolerance costs nothing according to the typo ranking rule
 13. Split words cost 1 typo according to the typo ranking rule
 14. Synonyms cost nothing according to the typo ranking rule
+15. The typos allowed on the prefix term are bounded by the `max_prefix_typos` setting
 */
 
 use std::collections::BTreeMap;
 
 use crate::index::tests::TempIndex;
+use crate::score_details::ScoreDetails;
 use crate::search::new::tests::collect_field_values;
 use crate::{Criterion, Search, SearchResult, TermsMatchingStrategy};
 
@@ -635,3 +637,99 @@ fn test_typo_synonyms() {
     ]
     "###);
 }
+
+#[test]
+fn typo_penalty_widens_the_ranking_gap() {
+    let index = TempIndex::new();
+    index
+        .update_settings(|s| {
+            s.set_primary_key("id".to_owned());
+            s.set_searchable_fields(vec!["text".to_owned()]);
+            s.set_criteria(vec![Criterion::Typo]);
+        })
+        .unwrap();
+    index
+        .add_documents(documents!([
+            { "id": 0, "text": "jumps" },
+            { "id": 1, "text": "jumpz" },
+        ]))
+        .unwrap();
+
+    let scores = |index: &TempIndex| {
+        let txn = index.read_txn().unwrap();
+        let mut s = Search::new(&txn, index);
+        s.scoring_strategy(crate::score_details::ScoringStrategy::Detailed);
+        s.query("jumps");
+        let SearchResult { documents_ids, document_scores, .. } = s.execute().unwrap();
+        assert_eq!(documents_ids, vec![0, 1]);
+        document_scores
+            .iter()
+            .map(|scores| ScoreDetails::global_score(scores.iter()))
+            .collect::<Vec<_>>()
+    };
+
+    let default_scores = scores(&index);
+    assert_eq!(default_scores[0], 1.0);
+
+    index.update_settings(|s| s.set_typo_penalty(3)).unwrap();
+    let penalized_scores = scores(&index);
+    // the exact match keeps the best score while the one-typo match is pushed further away
+    assert_eq!(penalized_scores[0], 1.0);
+    assert!(penalized_scores[1] < default_scores[1], "{penalized_scores:?} {default_scores:?}");
+
+    // the details still report the actual number of typos
+    let txn = index.read_txn().unwrap();
+    let mut s = Search::new(&txn, &index);
+    s.scoring_strategy(crate::score_details::ScoringStrategy::Detailed);
+    s.query("jumps");
+    let SearchResult { document_scores, .. } = s.execute().unwrap();
+    let details = ScoreDetails::to_json_map(document_scores[1].iter());
+    assert_eq!(details["typo"]["typoCount"], 1);
+    assert_eq!(details["typo"]["maxTypoCount"], 1);
+    drop(txn);
+
+    index.update_settings(|s| s.reset_typo_penalty()).unwrap();
+    assert_eq!(scores(&index), default_scores);
+}
+
+#[test]
+fn test_max_prefix_typos() {
+    let index = TempIndex::new();
+    index
+        .update_settings(|s| {
+            s.set_primary_key("id".to_owned());
+            s.set_searchable_fields(vec!["text".to_owned()]);
+            s.set_criteria(vec![Criterion::Words]);
+        })
+        .unwrap();
+    index
+        .add_documents(documents!([
+            { "id": 0, "text": "automobile" },
+            { "id": 1, "text": "autpmobile" },
+        ]))
+        .unwrap();
+
+    let search = |index: &TempIndex, query: &str| {
+        let txn = index.read_txn().unwrap();
+        let mut s = Search::new(&txn, index);
+        s.query(query);
+        let SearchResult { mut documents_ids, .. } = s.execute().unwrap();
+        documents_ids.sort_unstable();
+        documents_ids
+    };
+
+    // by default, the typo'd prefix `autpm` is expanded to the words one typo away from it
+    assert_eq!(search(&index, "autpm"), vec![0, 1]);
+
+    index.update_settings(|s| s.set_max_prefix_typos(1)).unwrap();
+    assert_eq!(search(&index, "autpm"), vec![0, 1]);
+
+    // only the words starting exactly with the prefix are matched
+    index.update_settings(|s| s.set_max_prefix_typos(0)).unwrap();
+    assert_eq!(search(&index, "autpm"), vec![1]);
+    // the complete words are still typo tolerant
+    assert_eq!(search(&index, "autpmobile automobile"), vec![0, 1]);
+
+    index.update_settings(|s| s.reset_max_prefix_typos()).unwrap();
+    assert_eq!(search(&index, "autpm"), vec![0, 1]);
+}
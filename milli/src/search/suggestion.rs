@@ -0,0 +1,302 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use charabia::{Token, Tokenize, TokenizerBuilder};
+use fst::automaton::{Automaton, Str};
+use fst::{IntoStreamer, Streamer};
+use heed::RoTxn;
+use roaring::RoaringBitmap;
+
+use super::{build_dfa, get_first};
+use crate::{CboRoaringBitmapLenCodec, FieldId, Index, Result};
+
+/// How many times more documents a word must appear in to be suggested in place of a query word.
+const SUGGESTION_FREQUENCY_RATIO: u64 = 2;
+
+/// How many words of the index are considered for each requested completion of a prefix.
+const SCANNED_WORDS_BY_COMPLETION: usize = 100;
+
+/// Returns a corrected spelling of the query, using the vocabulary of the index.
+///
+/// Each word of the query is replaced by the most frequent word of the index within its typo
+/// tolerance, as long as this word appears in meaningfully more documents than the original one.
+/// Returns `None` when no word of the query has been replaced.
+pub fn suggest_query(index: &Index, rtxn: &RoTxn<'_>, query: &str) -> Result<Option<String>> {
+    let tokens = tokenize_query(index, rtxn, query)?;
+
+    let words_fst = index.words_fst(rtxn)?;
+    let one_typo = index.min_word_len_one_typo(rtxn)? as usize;
+    let two_typos = index.min_word_len_two_typos(rtxn)? as usize;
+    let word_docids_len = index.word_docids.remap_data_type::<CboRoaringBitmapLenCodec>();
+
+    let mut suggestion = String::with_capacity(query.len());
+    let mut last_byte = 0;
+    let mut corrected = false;
+
+    for (i, token) in tokens.iter().enumerate() {
+        let word = token.lemma();
+        let word_len = word.chars().count();
+        if !token.is_word() || word_len < one_typo {
+            continue;
+        }
+
+        // The last word of the query is searched as a prefix, we must not
+        // correct a word that the user is still typing.
+        let is_last = tokens[i + 1..].iter().all(|token| !token.is_word());
+        if is_last && token.byte_end == query.len() {
+            let prefix = Str::new(word).starts_with();
+            let mut stream = words_fst.search(prefix).into_stream();
+            if stream.next().is_some() {
+                continue;
+            }
+        }
+
+        let frequency = word_docids_len.get(rtxn, word)?.unwrap_or(0);
+        let typos = if word_len < two_typos { 1 } else { 2 };
+        let dfa = build_dfa(word, typos, false);
+        let starts = Str::new(get_first(word)).starts_with();
+        let mut stream = words_fst.search(starts.intersection(&dfa)).into_stream();
+
+        let mut best: Option<(String, u64)> = None;
+        while let Some(derived_word) = stream.next() {
+            let derived_word = std::str::from_utf8(derived_word)?;
+            if derived_word == word {
+                continue;
+            }
+            let derived_frequency = word_docids_len.get(rtxn, derived_word)?.unwrap_or(0);
+            if best.as_ref().map_or(true, |(_, best_frequency)| derived_frequency > *best_frequency)
+            {
+                best = Some((derived_word.to_owned(), derived_frequency));
+            }
+        }
+
+        if let Some((derived_word, derived_frequency)) = best {
+            if derived_frequency > frequency.saturating_mul(SUGGESTION_FREQUENCY_RATIO) {
+                suggestion.push_str(&query[last_byte..token.byte_start]);
+                // the words of the index are normalized, we keep the capitalization of the query
+                let original = &query[token.byte_start..token.byte_end];
+                let mut chars = derived_word.chars();
+                match chars.next() {
+                    Some(first) if original.starts_with(char::is_uppercase) => {
+                        suggestion.extend(first.to_uppercase());
+                        suggestion.push_str(chars.as_str());
+                    }
+                    _ => suggestion.push_str(&derived_word),
+                }
+                last_byte = token.byte_end;
+                corrected = true;
+            }
+        }
+    }
+
+    if !corrected {
+        return Ok(None);
+    }
+
+    suggestion.push_str(&query[last_byte..]);
+    Ok((suggestion != query).then_some(suggestion))
+}
+
+/// A completion of the query, along with the number of documents containing its last word.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Completion {
+    pub value: String,
+    pub frequency: u64,
+}
+
+/// Returns the completions of the word the user is still typing at the end of the query.
+///
+/// The words of the index starting with this prefix are ranked by the number of documents of the
+/// universe containing them, in any of the given fields when there are some. Only the first
+/// words in lexicographic order are considered, [`SCANNED_WORDS_BY_COMPLETION`] by completion.
+/// Returns no completion at all when the query ends with a separator.
+pub fn complete_prefix(
+    index: &Index,
+    rtxn: &RoTxn<'_>,
+    query: &str,
+    fields_ids: Option<&[FieldId]>,
+    universe: &RoaringBitmap,
+    limit: usize,
+) -> Result<Vec<Completion>> {
+    let tokens = tokenize_query(index, rtxn, query)?;
+    let last = match tokens.iter().rev().find(|token| token.is_word()) {
+        Some(token) if token.byte_end == query.len() => token,
+        _ => return Ok(Vec::new()),
+    };
+    if limit == 0 {
+        return Ok(Vec::new());
+    }
+
+    let words_fst = index.words_fst(rtxn)?;
+    let prefix = Str::new(last.lemma()).starts_with();
+    let mut stream = words_fst.search(prefix).into_stream();
+
+    // the least frequent of the best completions found so far is on top
+    let mut completions = BinaryHeap::with_capacity(limit + 1);
+    let mut scanned = 0;
+    while let Some(word) = stream.next() {
+        if scanned == limit.saturating_mul(SCANNED_WORDS_BY_COMPLETION) {
+            break;
+        }
+        scanned += 1;
+
+        let word = std::str::from_utf8(word)?;
+        let docids = match fields_ids {
+            Some(fields_ids) => {
+                let mut docids = RoaringBitmap::new();
+                for &fid in fields_ids {
+                    if let Some(fid_docids) = index.word_fid_docids.get(rtxn, &(word, fid))? {
+                        docids |= fid_docids;
+                    }
+                }
+                docids
+            }
+            None => {
+                let mut docids = index.word_docids.get(rtxn, word)?.unwrap_or_default();
+                if let Some(exact_docids) = index.exact_word_docids.get(rtxn, word)? {
+                    docids |= exact_docids;
+                }
+                docids
+            }
+        };
+
+        let frequency = docids.intersection_len(universe);
+        if frequency > 0 {
+            completions.push((Reverse(frequency), word.to_owned()));
+            if completions.len() > limit {
+                completions.pop();
+            }
+        }
+    }
+
+    let start = &query[..last.byte_start];
+    Ok(completions
+        .into_sorted_vec()
+        .into_iter()
+        .map(|(Reverse(frequency), word)| Completion { value: format!("{start}{word}"), frequency })
+        .collect())
+}
+
+fn tokenize_query<'q>(index: &Index, rtxn: &RoTxn<'_>, query: &'q str) -> Result<Vec<Token<'q>>> {
+    let mut tokbuilder = TokenizerBuilder::new();
+    let separators = index.allowed_separators(rtxn)?;
+    let separators: Option<Vec<_>> =
+        separators.as_ref().map(|x| x.iter().map(String::as_str).collect());
+    if let Some(ref separators) = separators {
+        tokbuilder.separators(separators);
+    }
+
+    let dictionary = index.dictionary(rtxn)?;
+    let dictionary: Option<Vec<_>> =
+        dictionary.as_ref().map(|x| x.iter().map(String::as_str).collect());
+    if let Some(ref dictionary) = dictionary {
+        tokbuilder.words_dict(dictionary);
+    }
+
+    let tokenizer = tokbuilder.build();
+    Ok(tokenizer.tokenize(query).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use big_s::S;
+
+    use super::*;
+    use crate::index::tests::TempIndex;
+
+    fn create_index() -> TempIndex {
+        let index = TempIndex::new();
+        index
+            .add_documents(documents!([
+                { "id": 0, "text": "italian restaurant" },
+                { "id": 1, "text": "chinese restaurant" },
+                { "id": 2, "text": "restaurant guide" },
+                { "id": 3, "text": "cheap restaurants" },
+                { "id": 4, "text": "italian cuisine" },
+            ]))
+            .unwrap();
+        index
+    }
+
+    #[test]
+    fn suggest_misspelled_common_word() {
+        let index = create_index();
+        let rtxn = index.read_txn().unwrap();
+
+        let suggestion = suggest_query(&index, &rtxn, "Italian restaurnt").unwrap();
+        assert_eq!(suggestion.as_deref(), Some("Italian restaurant"));
+
+        let suggestion = suggest_query(&index, &rtxn, "restuarant guide").unwrap();
+        assert_eq!(suggestion.as_deref(), Some("restaurant guide"));
+
+        let suggestion = suggest_query(&index, &rtxn, "Restuarant guide").unwrap();
+        assert_eq!(suggestion.as_deref(), Some("Restaurant guide"));
+    }
+
+    #[test]
+    fn no_suggestion() {
+        let index = create_index();
+        let rtxn = index.read_txn().unwrap();
+
+        // all the words are already the most frequent ones
+        assert_eq!(suggest_query(&index, &rtxn, "italian restaurant").unwrap(), None);
+        // the last word is still being typed
+        assert_eq!(suggest_query(&index, &rtxn, "italian restau").unwrap(), None);
+        // the words are too short to be corrected
+        assert_eq!(suggest_query(&index, &rtxn, "chep").unwrap(), None);
+        // no word of the index is close enough
+        assert_eq!(suggest_query(&index, &rtxn, "pizzeria").unwrap(), None);
+    }
+
+    fn completions(
+        index: &TempIndex,
+        query: &str,
+        fields_ids: Option<&[FieldId]>,
+        limit: usize,
+    ) -> Vec<(String, u64)> {
+        let rtxn = index.read_txn().unwrap();
+        let universe = index.documents_ids(&rtxn).unwrap();
+        complete_prefix(index, &rtxn, query, fields_ids, &universe, limit)
+            .unwrap()
+            .into_iter()
+            .map(|Completion { value, frequency }| (value, frequency))
+            .collect()
+    }
+
+    #[test]
+    fn complete_by_frequency() {
+        let index = create_index();
+
+        assert_eq!(
+            completions(&index, "cheap rest", None, 10),
+            vec![(S("cheap restaurant"), 3), (S("cheap restaurants"), 1)]
+        );
+        assert_eq!(completions(&index, "c", None, 2), vec![(S("cheap"), 1), (S("chinese"), 1)]);
+        // the last word is complete
+        assert!(completions(&index, "italian ", None, 10).is_empty());
+        assert!(completions(&index, "pizz", None, 10).is_empty());
+        assert!(completions(&index, "c", None, 0).is_empty());
+    }
+
+    #[test]
+    fn complete_in_fields() {
+        let index = TempIndex::new();
+        index
+            .add_documents(documents!([
+                { "id": 0, "title": "dune", "overview": "desert planet" },
+                { "id": 1, "title": "desperado", "overview": "dusty desert" },
+                { "id": 2, "title": "dark", "overview": "desert town" },
+            ]))
+            .unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        let title = index.fields_ids_map(&rtxn).unwrap().id("title").unwrap();
+        drop(rtxn);
+
+        assert_eq!(completions(&index, "d", None, 2), vec![(S("desert"), 3), (S("dark"), 1)]);
+        assert_eq!(
+            completions(&index, "d", Some(&[title]), 10),
+            vec![(S("dark"), 1), (S("desperado"), 1), (S("dune"), 1)]
+        );
+    }
+}
@@ -0,0 +1,133 @@
+//! Reduce the inflected forms of the words to a common stem, so that "shoe" and "shoes" match.
+
+use std::borrow::Cow;
+
+use charabia::Language;
+
+/// The stemmers of the languages configured in the `stemming` setting of an index.
+///
+/// The same stemmer is applied to the words of the documents and of the queries.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Stemmer {
+    languages: Vec<Language>,
+}
+
+impl Stemmer {
+    pub fn new(languages: Vec<Language>) -> Self {
+        Stemmer { languages }
+    }
+
+    /// Returns whether a stemmer exists for the language.
+    pub fn is_supported(language: Language) -> bool {
+        matches!(language, Language::Eng)
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        !self.languages.is_empty()
+    }
+
+    /// Reduces the word to its stem.
+    ///
+    /// The word is stemmed with the stemmer of its detected language when it is configured,
+    /// otherwise with the stemmer of the first configured language.
+    pub fn stem<'a>(&self, word: &'a str, language: Option<Language>) -> Cow<'a, str> {
+        let language = match language {
+            Some(language) if self.languages.contains(&language) => language,
+            _ => match self.languages.first() {
+                Some(language) => *language,
+                None => return Cow::Borrowed(word),
+            },
+        };
+
+        match language {
+            Language::Eng => english::stem(word),
+            _ => Cow::Borrowed(word),
+        }
+    }
+}
+
+/// The inflectional steps (1a and 1c) of the Porter2 English stemmer.
+///
+/// The derivational steps are left out: they merge words of quite different meanings
+/// and would make the typo tolerance match unrelated words.
+mod english {
+    use std::borrow::Cow;
+
+    fn is_vowel(c: u8) -> bool {
+        matches!(c, b'a' | b'e' | b'i' | b'o' | b'u' | b'y')
+    }
+
+    pub fn stem(word: &str) -> Cow<'_, str> {
+        if !word.is_ascii() || word.len() <= 2 {
+            return Cow::Borrowed(word);
+        }
+
+        let stem = step_1a(word);
+        match step_1c(&stem) {
+            Some(stem) => Cow::Owned(stem),
+            None => stem,
+        }
+    }
+
+    /// Removes the plural suffixes.
+    fn step_1a(word: &str) -> Cow<'_, str> {
+        if let Some(stem) = word.strip_suffix("sses") {
+            return Cow::Owned(format!("{stem}ss"));
+        }
+        if let Some(stem) = word.strip_suffix("ied").or_else(|| word.strip_suffix("ies")) {
+            // "cries" becomes "cri" while "ties" becomes "tie"
+            return Cow::Owned(if stem.len() > 1 {
+                format!("{stem}i")
+            } else {
+                format!("{stem}ie")
+            });
+        }
+        if word.ends_with("us") || word.ends_with("ss") {
+            return Cow::Borrowed(word);
+        }
+        match word.strip_suffix('s') {
+            // the `s` is only removed if a vowel precedes the letter before it: "gaps" but not "gas"
+            Some(stem) if stem.bytes().rev().skip(1).any(is_vowel) => Cow::Borrowed(stem),
+            _ => Cow::Borrowed(word),
+        }
+    }
+
+    /// Replaces a final `y` preceded by a consonant by an `i`, so that "cry" matches "cries".
+    fn step_1c(word: &str) -> Option<String> {
+        let stem = word.strip_suffix('y')?;
+        let previous = *stem.as_bytes().last()?;
+        (stem.len() > 1 && !is_vowel(previous)).then(|| format!("{stem}i"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use charabia::Language;
+
+    use super::Stemmer;
+
+    #[test]
+    fn english_stems() {
+        let stemmer = Stemmer::new(vec![Language::Eng]);
+        let stem = |word| stemmer.stem(word, None).into_owned();
+
+        assert_eq!(stem("shoes"), "shoe");
+        assert_eq!(stem("shoe"), "shoe");
+        assert_eq!(stem("caresses"), "caress");
+        assert_eq!(stem("ponies"), "poni");
+        assert_eq!(stem("pony"), "poni");
+        assert_eq!(stem("ties"), "tie");
+        assert_eq!(stem("gaps"), "gap");
+        assert_eq!(stem("gas"), "gas");
+        assert_eq!(stem("bus"), "bus");
+        assert_eq!(stem("say"), "say");
+        assert_eq!(stem("café"), "café");
+    }
+
+    #[test]
+    fn disabled_stemmer() {
+        let stemmer = Stemmer::default();
+        assert!(!stemmer.is_enabled());
+        assert_eq!(stemmer.stem("shoes", Some(Language::Eng)), "shoes");
+    }
+}
@@ -0,0 +1,193 @@
+//! Choose how the attributes containing identifiers are split into words.
+//!
+//! Prose is split into words by the tokenizer, but an email address or a path is better indexed
+//! as a whole, so that searching it only matches the documents containing the exact identifier.
+//! The same goes for the decimal and grouped numbers, that can be kept as single words.
+
+use std::borrow::Cow;
+use std::collections::VecDeque;
+
+use charabia::{Token, TokenKind};
+use serde::{Deserialize, Serialize};
+
+/// The way the values of an attribute are split into words.
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum TokenizerProfile {
+    /// The value is split into words by the tokenizer.
+    #[default]
+    Default,
+    /// The whole value is indexed as a single word.
+    Keyword,
+    /// The whole address is indexed as a single word, along with its local part and its domain.
+    Email,
+    /// The whole path is indexed as a single word, along with each of its segments.
+    Path,
+}
+
+impl TokenizerProfile {
+    /// Returns the parts of the value that are indexed as single words,
+    /// or `None` if the value must be split into words by the tokenizer.
+    pub fn identifiers<'a>(&self, value: &'a str) -> Option<Vec<&'a str>> {
+        match self {
+            TokenizerProfile::Default => None,
+            TokenizerProfile::Keyword => Some(vec![value]),
+            TokenizerProfile::Email => match value.rsplit_once('@') {
+                Some((local_part, domain)) => Some(vec![value, local_part, domain]),
+                None => Some(vec![value]),
+            },
+            TokenizerProfile::Path => {
+                let segments = value.split(['/', '\\']).filter(|segment| !segment.is_empty());
+                Some(std::iter::once(value).chain(segments).collect())
+            }
+        }
+    }
+}
+
+/// Concatenates the normalized tokens of an identifier, ignoring the separators surrounding it.
+///
+/// The search query goes through the same normalization, so that an identifier of the query
+/// matches the word indexed for the identifier of the document.
+pub fn normalize_identifier<'a>(tokens: impl Iterator<Item = Token<'a>>) -> String {
+    let mut identifier = String::new();
+    let mut separators = String::new();
+    for token in tokens {
+        if token.is_separator() {
+            if !identifier.is_empty() {
+                separators.push_str(token.lemma());
+            }
+        } else {
+            identifier.push_str(&separators);
+            identifier.push_str(token.lemma());
+            separators.clear();
+        }
+    }
+    identifier
+}
+
+/// The way the numbers are split into words, both in the documents and in the queries.
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum NumberTokenization {
+    /// The numbers are split on their decimal and grouping separators, `3.5` gives `3` and `5`.
+    #[default]
+    Split,
+    /// The decimal and grouped numbers are kept as single words, `3.5` gives `3.5`.
+    Keep,
+}
+
+impl NumberTokenization {
+    /// Wraps the tokens given by the tokenizer, merging back the numbers it split when they
+    /// must be kept as single words.
+    pub fn tokens<'o, I>(self, tokens: I) -> NumberTokens<'o, I>
+    where
+        I: Iterator<Item = Token<'o>>,
+    {
+        NumberTokens { tokens, pending: VecDeque::new(), keep: self == NumberTokenization::Keep }
+    }
+}
+
+/// An iterator over tokens that merges the digits separated by a single dot or comma,
+/// without any space in between, into a single word.
+pub struct NumberTokens<'o, I> {
+    tokens: I,
+    pending: VecDeque<Token<'o>>,
+    keep: bool,
+}
+
+impl<'o, I: Iterator<Item = Token<'o>>> Iterator for NumberTokens<'o, I> {
+    type Item = Token<'o>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut token = self.pending.pop_front().or_else(|| self.tokens.next())?;
+        if !self.keep || !is_digits(&token) {
+            return Some(token);
+        }
+
+        loop {
+            while self.pending.len() < 2 {
+                match self.tokens.next() {
+                    Some(next) => self.pending.push_back(next),
+                    None => break,
+                }
+            }
+
+            match (self.pending.front(), self.pending.get(1)) {
+                (Some(separator), Some(digits))
+                    if is_number_separator(separator)
+                        && is_digits(digits)
+                        && token.byte_end == separator.byte_start
+                        && separator.byte_end == digits.byte_start =>
+                {
+                    let separator = self.pending.pop_front().unwrap();
+                    let digits = self.pending.pop_front().unwrap();
+                    let lemma = format!("{}{}{}", token.lemma(), separator.lemma(), digits.lemma());
+                    token = Token {
+                        kind: TokenKind::Word,
+                        lemma: Cow::Owned(lemma),
+                        char_start: token.char_start,
+                        char_end: digits.char_end,
+                        byte_start: token.byte_start,
+                        byte_end: digits.byte_end,
+                        script: token.script,
+                        language: token.language,
+                        ..Default::default()
+                    };
+                }
+                _ => return Some(token),
+            }
+        }
+    }
+}
+
+fn is_digits(token: &Token<'_>) -> bool {
+    token.is_word()
+        && !token.lemma().is_empty()
+        && token.lemma().bytes().all(|b| b.is_ascii_digit())
+}
+
+fn is_number_separator(token: &Token<'_>) -> bool {
+    token.is_separator() && matches!(token.lemma(), "." | ",")
+}
+
+#[cfg(test)]
+mod tests {
+    use charabia::TokenizerBuilder;
+
+    use super::*;
+
+    #[test]
+    fn identifiers() {
+        assert_eq!(TokenizerProfile::Default.identifiers("john.doe@example.com"), None);
+        assert_eq!(
+            TokenizerProfile::Keyword.identifiers("john.doe@example.com"),
+            Some(vec!["john.doe@example.com"])
+        );
+        assert_eq!(
+            TokenizerProfile::Email.identifiers("john.doe@example.com"),
+            Some(vec!["john.doe@example.com", "john.doe", "example.com"])
+        );
+        assert_eq!(
+            TokenizerProfile::Path.identifiers("/usr/local/bin"),
+            Some(vec!["/usr/local/bin", "usr", "local", "bin"])
+        );
+    }
+
+    #[test]
+    fn keep_numbers() {
+        let tokenizer = TokenizerBuilder::default().into_tokenizer();
+        let words = |tokenization: NumberTokenization, text: &str| -> Vec<String> {
+            tokenization
+                .tokens(tokenizer.tokenize(text))
+                .filter(|token| token.is_word())
+                .map(|token| token.lemma().to_string())
+                .collect()
+        };
+
+        assert_eq!(words(NumberTokenization::Split, "version 3.5"), ["version", "3", "5"]);
+        assert_eq!(words(NumberTokenization::Keep, "version 3.5"), ["version", "3.5"]);
+        assert_eq!(words(NumberTokenization::Keep, "1,000,000 dollars."), ["1,000,000", "dollars"]);
+        // the numbers separated by spaces are not merged
+        assert_eq!(words(NumberTokenization::Keep, "3. 5"), ["3", "5"]);
+    }
+}
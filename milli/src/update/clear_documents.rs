@@ -2,7 +2,7 @@ use heed::RwTxn;
 use roaring::RoaringBitmap;
 use time::OffsetDateTime;
 
-use crate::{FieldDistribution, Index, Result};
+use crate::{FieldDistribution, FieldsValues, Index, Result};
 
 pub struct ClearDocuments<'t, 'i> {
     wtxn: &'t mut RwTxn<'i>,
@@ -26,6 +26,7 @@ impl<'t, 'i> ClearDocuments<'t, 'i> {
             env: _env,
             main: _main,
             external_documents_ids,
+            reserved_document_ids: _,
             word_docids,
             exact_word_docids,
             word_prefix_docids,
@@ -40,6 +41,7 @@ impl<'t, 'i> ClearDocuments<'t, 'i> {
             facet_id_string_docids,
             facet_id_normalized_string_strings,
             facet_id_string_fst,
+            facet_id_string_original_counts,
             facet_id_exists_docids,
             facet_id_is_null_docids,
             facet_id_is_empty_docids,
@@ -60,8 +62,10 @@ impl<'t, 'i> ClearDocuments<'t, 'i> {
         self.index.put_words_prefixes_fst(self.wtxn, &fst::Set::default())?;
         self.index.put_documents_ids(self.wtxn, &empty_roaring)?;
         self.index.put_field_distribution(self.wtxn, &FieldDistribution::default())?;
+        self.index.put_fields_values(self.wtxn, &FieldsValues::default())?;
         self.index.delete_geo_rtree(self.wtxn)?;
         self.index.delete_geo_faceted_documents_ids(self.wtxn)?;
+        self.index.delete_blocked_documents_ids(self.wtxn)?;
 
         // Remove all user-provided bits from the configs
         let mut configs = self.index.embedding_configs(self.wtxn)?;
@@ -70,6 +74,12 @@ impl<'t, 'i> ClearDocuments<'t, 'i> {
         }
         self.index.put_embedding_configs(self.wtxn, configs)?;
 
+        // Keep the internal ids of the documents for when they are added again.
+        if self.index.reserve_document_ids(self.wtxn)? {
+            let documents = external_documents_ids.to_hash_map(self.wtxn)?;
+            self.index.reserve_document_ids_of(self.wtxn, documents)?;
+        }
+
         // Clear the other databases.
         external_documents_ids.clear(self.wtxn)?;
         word_docids.clear(self.wtxn)?;
@@ -85,6 +95,7 @@ impl<'t, 'i> ClearDocuments<'t, 'i> {
         facet_id_f64_docids.clear(self.wtxn)?;
         facet_id_normalized_string_strings.clear(self.wtxn)?;
         facet_id_string_fst.clear(self.wtxn)?;
+        facet_id_string_original_counts.clear(self.wtxn)?;
         facet_id_exists_docids.clear(self.wtxn)?;
         facet_id_is_null_docids.clear(self.wtxn)?;
         facet_id_is_empty_docids.clear(self.wtxn)?;
@@ -102,6 +113,8 @@ impl<'t, 'i> ClearDocuments<'t, 'i> {
 
 #[cfg(test)]
 mod tests {
+    use big_s::S;
+
     use super::*;
     use crate::index::tests::TempIndex;
 
@@ -146,4 +159,55 @@ mod tests {
         assert!(index.field_id_docid_facet_strings.is_empty(&rtxn).unwrap());
         assert!(index.documents.is_empty(&rtxn).unwrap());
     }
+
+    #[test]
+    fn reserved_document_ids_are_given_back() {
+        let index = TempIndex::new();
+        index.update_settings(|settings| settings.set_reserve_document_ids(true)).unwrap();
+
+        index
+            .add_documents(documents!([
+                { "id": "kevin" },
+                { "id": "kevina" },
+                { "id": "benoit" },
+            ]))
+            .unwrap();
+        let rtxn = index.read_txn().unwrap();
+        let before = index.external_documents_ids().to_hash_map(&rtxn).unwrap();
+        drop(rtxn);
+
+        let mut wtxn = index.write_txn().unwrap();
+        ClearDocuments::new(&mut wtxn, &index).execute().unwrap();
+        wtxn.commit().unwrap();
+
+        // the documents are added back in another order along with a new one
+        index
+            .add_documents(documents!([
+                { "id": "tamo" },
+                { "id": "benoit" },
+                { "id": "kevin" },
+            ]))
+            .unwrap();
+        let rtxn = index.read_txn().unwrap();
+        let after = index.external_documents_ids().to_hash_map(&rtxn).unwrap();
+        assert_eq!(after["benoit"], before["benoit"]);
+        assert_eq!(after["kevin"], before["kevin"]);
+        // the id of kevina is still reserved for it
+        assert!(!before.values().any(|docid| *docid == after["tamo"]));
+        assert_eq!(index.reserved_document_id(&rtxn, "kevina").unwrap(), Some(before["kevina"]));
+        // while the ids given back are not reserved anymore
+        assert_eq!(index.reserved_document_id(&rtxn, "kevin").unwrap(), None);
+        assert_eq!(index.reserved_documents_ids(&rtxn).unwrap().len(), 1);
+        drop(rtxn);
+
+        // a deleted document gets its id back too
+        index.delete_documents(vec![S("kevin")]);
+        index.add_documents(documents!([{ "id": "kevina" }, { "id": "kevin" }])).unwrap();
+        let rtxn = index.read_txn().unwrap();
+        let last = index.external_documents_ids().to_hash_map(&rtxn).unwrap();
+        assert_eq!(last["kevin"], before["kevin"]);
+        assert_eq!(last["kevina"], before["kevina"]);
+        assert!(index.reserved_document_ids.is_empty(&rtxn).unwrap());
+        assert!(index.reserved_documents_ids(&rtxn).unwrap().is_empty());
+    }
 }
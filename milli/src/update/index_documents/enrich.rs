@@ -1,3 +1,4 @@
+use std::collections::HashSet;
 use std::fmt;
 use std::io::{BufWriter, Read, Seek};
 use std::result::Result as StdResult;
@@ -10,14 +11,16 @@ use crate::documents::{
     EnrichedDocumentsBatchReader, PrimaryKey, DEFAULT_PRIMARY_KEY,
 };
 use crate::error::{GeoError, InternalError, UserError};
-use crate::update::index_documents::{obkv_to_object, writer_into_reader};
-use crate::{FieldId, Index, Result};
+use crate::update::index_documents::{obkv_to_object, writer_into_reader, DuplicateKeyStrategy};
+use crate::{FieldId, Index, Result, RESERVED_ETAG_FIELD_NAME};
 
 /// This function validates and enrich the documents by checking that:
 ///  - we can infer a primary key,
 ///  - all the documents id exist and are extracted,
 ///  - the validity of them but also,
-///  - the validity of the `_geo` field depending on the settings.
+///  - the validity of the `_geo` field depending on the settings,
+///  - the validity of the `_etag` field,
+///  - that no id appears twice when the duplicate keys are rejected.
 ///
 /// # Panics
 ///
@@ -103,6 +106,15 @@ pub fn enrich_documents_batch<R: Read + Seek>(
         _otherwise => None,
     };
 
+    let etag_field_id = documents_batch_index.id(RESERVED_ETAG_FIELD_NAME);
+
+    // The duplicates are rejected here, before any document reaches the transform, so that
+    // only the document addition containing them fails.
+    let mut retrieved_ids = match index.duplicate_key_strategy(rtxn)? {
+        DuplicateKeyStrategy::Error => Some(HashSet::new()),
+        DuplicateKeyStrategy::LastWins | DuplicateKeyStrategy::FirstWins => None,
+    };
+
     let mut count = 0;
     while let Some(document) = cursor.next_document()? {
         let document_id = match fetch_or_generate_document_id(
@@ -123,6 +135,20 @@ pub fn enrich_documents_batch<R: Read + Seek>(
             }
         }
 
+        if let Some(etag_value) = etag_field_id.and_then(|fid| document.get(fid)) {
+            if let Err(user_error) = validate_etag_from_json(&document_id, etag_value)? {
+                return Ok(Err(user_error));
+            }
+        }
+
+        if let (Some(retrieved_ids), DocumentId::Retrieved { value }) =
+            (&mut retrieved_ids, &document_id)
+        {
+            if !retrieved_ids.insert(value.clone()) {
+                return Ok(Err(UserError::DuplicateDocumentId { document_id: value.clone() }));
+            }
+        }
+
         let document_id = serde_json::to_vec(&document_id).map_err(InternalError::SerdeJson)?;
         external_ids.insert(count.to_be_bytes(), document_id)?;
 
@@ -232,32 +258,90 @@ pub fn extract_finite_float_from_value(value: Value) -> StdResult<f64, Value> {
     }
 }
 
+/// Returns an error if the `_etag` field of a document is not a string.
+pub fn validate_etag_from_json(id: &DocumentId, bytes: &[u8]) -> Result<StdResult<(), UserError>> {
+    match serde_json::from_slice(bytes).map_err(InternalError::SerdeJson)? {
+        Value::String(_) => Ok(Ok(())),
+        value => Ok(Err(UserError::InvalidDocumentEtag {
+            document_id: serde_json::from_slice(id.value().as_bytes())
+                .unwrap_or_else(|_| Value::from(id.debug())),
+            value,
+        })),
+    }
+}
+
 pub fn validate_geo_from_json(id: &DocumentId, bytes: &[u8]) -> Result<StdResult<(), GeoError>> {
     use GeoError::*;
     let debug_id = || {
         serde_json::from_slice(id.value().as_bytes()).unwrap_or_else(|_| Value::from(id.debug()))
     };
     match serde_json::from_slice(bytes).map_err(InternalError::SerdeJson)? {
-        Value::Object(mut object) => match (object.remove("lat"), object.remove("lng")) {
-            (Some(lat), Some(lng)) => {
-                match (extract_finite_float_from_value(lat), extract_finite_float_from_value(lng)) {
-                    (Ok(_), Ok(_)) if !object.is_empty() => Ok(Err(UnexpectedExtraFields {
-                        document_id: debug_id(),
-                        value: object.into(),
-                    })),
-                    (Ok(_), Ok(_)) => Ok(Ok(())),
-                    (Err(value), Ok(_)) => Ok(Err(BadLatitude { document_id: debug_id(), value })),
-                    (Ok(_), Err(value)) => Ok(Err(BadLongitude { document_id: debug_id(), value })),
-                    (Err(lat), Err(lng)) => {
-                        Ok(Err(BadLatitudeAndLongitude { document_id: debug_id(), lat, lng }))
-                    }
-                }
+        Value::Object(object) if is_geojson_point(&object) => {
+            match geojson_point_to_lat_lng(&object) {
+                Some(lat_lng) => Ok(validate_lat_lng_object(lat_lng, debug_id)),
+                None => Ok(Err(BadGeoJsonPoint {
+                    document_id: debug_id(),
+                    value: object.get("coordinates").cloned().unwrap_or_default(),
+                })),
             }
-            (None, Some(_)) => Ok(Err(MissingLatitude { document_id: debug_id() })),
-            (Some(_), None) => Ok(Err(MissingLongitude { document_id: debug_id() })),
-            (None, None) => Ok(Err(MissingLatitudeAndLongitude { document_id: debug_id() })),
-        },
+        }
+        Value::Object(object) => Ok(validate_lat_lng_object(object, debug_id)),
         Value::Null => Ok(Ok(())),
         value => Ok(Err(NotAnObject { document_id: debug_id(), value })),
     }
 }
+
+fn validate_lat_lng_object(
+    mut object: serde_json::Map<String, Value>,
+    debug_id: impl Fn() -> Value,
+) -> StdResult<(), GeoError> {
+    use GeoError::*;
+    match (object.remove("lat"), object.remove("lng")) {
+        (Some(lat), Some(lng)) => {
+            match (extract_finite_float_from_value(lat), extract_finite_float_from_value(lng)) {
+                (Ok(_), Ok(_)) if !object.is_empty() => {
+                    Err(UnexpectedExtraFields { document_id: debug_id(), value: object.into() })
+                }
+                (Ok(_), Ok(_)) => Ok(()),
+                (Err(value), Ok(_)) => Err(BadLatitude { document_id: debug_id(), value }),
+                (Ok(_), Err(value)) => Err(BadLongitude { document_id: debug_id(), value }),
+                (Err(lat), Err(lng)) => {
+                    Err(BadLatitudeAndLongitude { document_id: debug_id(), lat, lng })
+                }
+            }
+        }
+        (None, Some(_)) => Err(MissingLatitude { document_id: debug_id() }),
+        (Some(_), None) => Err(MissingLongitude { document_id: debug_id() }),
+        (None, None) => Err(MissingLatitudeAndLongitude { document_id: debug_id() }),
+    }
+}
+
+/// Returns `true` if the object looks like a GeoJSON `Point`, i.e. `{ "type": "Point", ... }`.
+fn is_geojson_point(object: &serde_json::Map<String, Value>) -> bool {
+    object.get("type").and_then(Value::as_str) == Some("Point")
+}
+
+/// Converts a GeoJSON `Point`, `{ "type": "Point", "coordinates": [lng, lat] }`, into the
+/// `{ "lat": lat, "lng": lng }` object that is expected under the `_geo` field.
+///
+/// Note that GeoJSON stores the longitude *before* the latitude. An optional third
+/// coordinate (the altitude) is accepted and ignored.
+///
+/// Returns `None` if the object is not a GeoJSON `Point` or if its coordinates are malformed.
+pub fn geojson_point_to_lat_lng(
+    object: &serde_json::Map<String, Value>,
+) -> Option<serde_json::Map<String, Value>> {
+    if !is_geojson_point(object) {
+        return None;
+    }
+
+    match object.get("coordinates")?.as_array()?.as_slice() {
+        [lng, lat] | [lng, lat, _] => {
+            let mut lat_lng = serde_json::Map::new();
+            lat_lng.insert("lat".to_string(), lat.clone());
+            lat_lng.insert("lng".to_string(), lng.clone());
+            Some(lat_lng)
+        }
+        _ => None,
+    }
+}
@@ -1,15 +1,17 @@
+use std::borrow::Cow;
 use std::convert::TryInto;
 use std::fs::File;
 use std::io::BufReader;
 use std::{io, mem, str};
 
-use charabia::{SeparatorKind, Token, TokenKind, Tokenizer, TokenizerBuilder};
+use charabia::{Language, SeparatorKind, Token, TokenKind, Tokenizer, TokenizerBuilder};
 use obkv::{KvReader, KvWriterU16};
 use roaring::RoaringBitmap;
 use serde_json::Value;
 
 use super::helpers::{create_sorter, keep_latest_obkv, sorter_into_reader, GrenadParameters};
 use crate::error::{InternalError, SerializationError};
+use crate::tokenizer_profile::{normalize_identifier, TokenizerProfile};
 use crate::update::del_add::{del_add_from_two_obkvs, DelAdd, KvReaderDelAdd};
 use crate::update::settings::{InnerIndexSettings, InnerIndexSettingsDiff};
 use crate::{FieldId, Result, MAX_POSITION_PER_ATTRIBUTE, MAX_WORD_LENGTH};
@@ -150,8 +152,14 @@ fn searchable_fields_changed(
     settings_diff: &InnerIndexSettingsDiff,
 ) -> bool {
     let searchable_fields = &settings_diff.new.searchable_fields_ids;
+    let language_hint_field_id = settings_diff
+        .new
+        .language_hint_field
+        .as_deref()
+        .and_then(|field| settings_diff.new.fields_ids_map.id(field));
     for (field_id, field_bytes) in obkv.iter() {
-        if searchable_fields.contains(&field_id) {
+        // the language hint changes the way all the searchable fields are tokenized.
+        if searchable_fields.contains(&field_id) || language_hint_field_id == Some(field_id) {
             let del_add = KvReaderDelAdd::new(field_bytes);
             match (del_add.get(DelAdd::Deletion), del_add.get(DelAdd::Addition)) {
                 // if both fields are None, check the next field.
@@ -198,6 +206,7 @@ fn tokens_from_document<'a>(
 ) -> Result<&'a [u8]> {
     buffers.obkv_buffer.clear();
     let mut document_writer = KvWriterU16::new(&mut buffers.obkv_buffer);
+    let document_language = hinted_language(obkv, settings, del_add)?.or(settings.default_language);
     for (field_id, field_bytes) in obkv.iter() {
         // if field is searchable.
         if settings.searchable_fields_ids.contains(&field_id) {
@@ -211,17 +220,56 @@ fn tokens_from_document<'a>(
                 buffers.obkv_positions_buffer.clear();
                 let mut writer = KvWriterU16::new(&mut buffers.obkv_positions_buffer);
 
+                // the localized attributes rules take precedence over the document language.
+                let locales = settings
+                    .localized_searchable_fields_ids
+                    .locales(field_id)
+                    .or(document_language.as_ref().map(std::slice::from_ref));
+
+                // index the identifiers as single words instead of tokenizing them as prose.
+                let profile = settings.tokenizer_profile(field_id);
+                if profile != TokenizerProfile::Default {
+                    let mut position: u16 = 0;
+                    for string in identifier_values(&value) {
+                        for identifier in profile.identifiers(&string).unwrap_or_default() {
+                            let tokens = tokenizer.tokenize_with_allow_list(identifier, locales);
+                            let word = normalize_identifier(tokens);
+                            if !word.is_empty()
+                                && word.len() <= MAX_WORD_LENGTH
+                                && u32::from(position) < max_positions_per_attributes
+                            {
+                                writer.insert(position, word.as_bytes())?;
+                                position += 1;
+                            }
+                        }
+                    }
+
+                    let positions = writer.into_inner()?;
+                    document_writer.insert(field_id, positions)?;
+                    continue;
+                }
+
                 // convert json into a unique string.
                 buffers.field_buffer.clear();
                 if let Some(field) = json_to_string(&value, &mut buffers.field_buffer) {
+                    // remove the markup of the field, the document itself is stored untouched.
+                    let field = match settings.content_type(field_id) {
+                        Some(content_type) => content_type.extractor().extract(field),
+                        None => Cow::Borrowed(field),
+                    };
+
                     // create an iterator of token with their positions.
-                    let locales = settings.localized_searchable_fields_ids.locales(field_id);
-                    let tokens = process_tokens(tokenizer.tokenize_with_allow_list(field, locales))
+                    let tokens = tokenizer.tokenize_with_allow_list(&field, locales);
+                    let tokens = process_tokens(settings.number_tokenization.tokens(tokens))
                         .take_while(|(p, _)| (*p as u32) < max_positions_per_attributes);
 
                     for (index, token) in tokens {
+                        let word = match token.kind {
+                            TokenKind::Word => settings.stemmer.stem(token.lemma(), token.language),
+                            _ => Cow::Borrowed(token.lemma()),
+                        };
                         // keep a word only if it is not empty and fit in a LMDB key.
-                        let token = token.lemma().trim();
+                        let token = word.trim();
                         if !token.is_empty() && token.len() <= MAX_WORD_LENGTH {
                             let position: u16 = index
                                 .try_into()
@@ -242,6 +290,41 @@ fn tokens_from_document<'a>(
     Ok(document_writer.into_inner().map(|v| v.as_slice())?)
 }
 
+/// Returns the language declared by the document in the language hint field, if it is a known
+/// language code.
+fn hinted_language(
+    obkv: &KvReader<'_, FieldId>,
+    settings: &InnerIndexSettings,
+    del_add: DelAdd,
+) -> Result<Option<Language>> {
+    let Some(field_id) =
+        settings.language_hint_field.as_deref().and_then(|field| settings.fields_ids_map.id(field))
+    else {
+        return Ok(None);
+    };
+    let Some(field_bytes) =
+        obkv.get(field_id).and_then(|bytes| KvReaderDelAdd::new(bytes).get(del_add))
+    else {
+        return Ok(None);
+    };
+    let value = serde_json::from_slice(field_bytes).map_err(InternalError::SerdeJson)?;
+    Ok(match value {
+        Value::String(code) => Language::from_code(&code),
+        _ => None,
+    })
+}
+
+/// Returns the strings of a JSON value and of the values of a JSON array, each being an identifier.
+fn identifier_values(value: &Value) -> Vec<Cow<'_, str>> {
+    match value {
+        Value::String(string) => vec![Cow::Borrowed(string.as_str())],
+        Value::Bool(boolean) => vec![Cow::Owned(boolean.to_string())],
+        Value::Number(number) => vec![Cow::Owned(number.to_string())],
+        Value::Array(values) => values.iter().flat_map(identifier_values).collect(),
+        Value::Null | Value::Object(_) => Vec::new(),
+    }
+}
+
 /// Transform a JSON value into a string that can be indexed.
 fn json_to_string<'a>(value: &'a Value, buffer: &'a mut String) -> Option<&'a str> {
     fn inner(value: &Value, output: &mut String) -> bool {
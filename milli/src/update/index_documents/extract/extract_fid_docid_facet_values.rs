@@ -20,8 +20,11 @@ use crate::error::InternalError;
 use crate::facet::value_encoding::f64_into_bytes;
 use crate::update::del_add::{DelAdd, KvReaderDelAdd, KvWriterDelAdd};
 use crate::update::index_documents::{create_writer, writer_into_reader};
-use crate::update::settings::InnerIndexSettingsDiff;
-use crate::{CboRoaringBitmapCodec, DocumentId, Error, FieldId, Result, MAX_FACET_VALUE_LENGTH};
+use crate::update::settings::{InnerIndexSettings, InnerIndexSettingsDiff};
+use crate::{
+    CboRoaringBitmapCodec, DocumentId, Error, FacetNormalization, FieldId, Result,
+    MAX_FACET_VALUE_LENGTH,
+};
 
 /// The length of the elements that are always in the buffer when inserting new values.
 const TRUNCATE_SIZE: usize = size_of::<FieldId>() + size_of::<DocumentId>();
@@ -79,8 +82,17 @@ pub fn extract_fid_docid_facet_values<R: io::Read + io::Seek>(
         settings_diff.old.faceted_fields_ids.iter().copied().collect();
     let new_faceted_fids: BTreeSet<_> =
         settings_diff.new.faceted_fields_ids.iter().copied().collect();
-
-    if !settings_diff.settings_update_only || old_faceted_fids != new_faceted_fids {
+    let old_decimal_fids = decimal_fields_ids(&settings_diff.old);
+    let new_decimal_fids = decimal_fields_ids(&settings_diff.new);
+    let old_normalized_fids = settings_diff.old.normalized_facet_fields_ids();
+    let new_normalized_fids = settings_diff.new.normalized_facet_fields_ids();
+
+    if !settings_diff.settings_update_only
+        || old_faceted_fids != new_faceted_fids
+        || old_decimal_fids != new_decimal_fids
+        || old_normalized_fids != new_normalized_fids
+        || settings_diff.old.facet_case_folding != settings_diff.new.facet_case_folding
+    {
         let mut cursor = obkv_documents.into_cursor()?;
         while let Some((docid_bytes, value)) = cursor.move_on_next()? {
             let obkv = obkv::KvReader::new(value);
@@ -113,7 +125,12 @@ pub fn extract_fid_docid_facet_values<R: io::Read + io::Seek>(
                     }
                     EitherOrBoth::Both(&field_id, _) => {
                         // during settings update, recompute the changing settings only.
-                        if settings_diff.settings_update_only {
+                        if settings_diff.settings_update_only
+                            && old_decimal_fids.contains(&field_id)
+                                == new_decimal_fids.contains(&field_id)
+                            && settings_diff.old.facet_normalization(field_id)
+                                == settings_diff.new.facet_normalization(field_id)
+                        {
                             continue;
                         }
 
@@ -163,10 +180,26 @@ pub fn extract_fid_docid_facet_values<R: io::Read + io::Seek>(
                         .new
                         .geo_fields_ids
                         .map_or(false, |(lat, lng)| field_id == lat || field_id == lng);
-                    let del_filterable_values =
-                        del_value.map(|value| extract_facet_values(&value, del_geo_support));
-                    let add_filterable_values =
-                        add_value.map(|value| extract_facet_values(&value, add_geo_support));
+                    let del_decimal = old_decimal_fids.contains(&field_id);
+                    let add_decimal = new_decimal_fids.contains(&field_id);
+                    let del_normalization = settings_diff.old.facet_normalization(field_id);
+                    let add_normalization = settings_diff.new.facet_normalization(field_id);
+                    let del_filterable_values = del_value.map(|value| {
+                        extract_facet_values(
+                            &value,
+                            del_geo_support,
+                            del_decimal,
+                            del_normalization,
+                        )
+                    });
+                    let add_filterable_values = add_value.map(|value| {
+                        extract_facet_values(
+                            &value,
+                            add_geo_support,
+                            add_decimal,
+                            add_normalization,
+                        )
+                    });
 
                     // Those closures are just here to simplify things a bit.
                     let mut insert_numbers_diff = |del_numbers, add_numbers| {
@@ -395,23 +428,36 @@ fn insert_strings_diff<MF>(
 where
     MF: for<'a> Fn(&[u8], &[Cow<'a, [u8]>]) -> StdResult<Cow<'a, [u8]>, Error>,
 {
-    // We sort and dedup the normalized and original strings
+    // We sort the normalized and original strings and only keep
+    // the first original string of each normalized string
     del_strings.sort_unstable();
     add_strings.sort_unstable();
-    del_strings.dedup();
-    add_strings.dedup();
+    del_strings.dedup_by(|(a, _), (b, _)| a == b);
+    add_strings.dedup_by(|(a, _), (b, _)| a == b);
 
     let merged_strings_iter = itertools::merge_join_by(
         del_strings.into_iter().filter(|(n, _)| !n.is_empty()),
         add_strings.into_iter().filter(|(n, _)| !n.is_empty()),
-        |del, add| del.cmp(add),
+        |(del, _), (add, _)| del.cmp(add),
     );
 
     // insert normalized and original facet string in sorter
     for eob in merged_strings_iter {
         key_buffer.truncate(TRUNCATE_SIZE);
         match eob {
-            EitherOrBoth::Both(_, _) => (), // no need to touch anything
+            EitherOrBoth::Both((_, del_original), (_, add_original))
+                if del_original == add_original => {} // no need to touch anything
+            EitherOrBoth::Both((normalized, del_original), (_, add_original)) => {
+                // the original string changed but it is still normalized the same way.
+                let truncated = truncate_string(normalized);
+                key_buffer.extend_from_slice(truncated.as_bytes());
+
+                let mut obkv = KvWriterDelAdd::memory();
+                obkv.insert(DelAdd::Deletion, del_original)?;
+                obkv.insert(DelAdd::Addition, add_original)?;
+                let bytes = obkv.into_inner()?;
+                fid_docid_facet_strings_sorter.insert(&key_buffer, bytes)?;
+            }
             EitherOrBoth::Left((normalized, original)) => {
                 let truncated = truncate_string(normalized);
                 key_buffer.extend_from_slice(truncated.as_bytes());
@@ -446,14 +492,38 @@ enum FilterableValues {
     Values { numbers: Vec<f64>, strings: Vec<(String, String)> },
 }
 
+/// Returns the ids of the faceted fields that are decimal attributes or nested in one of them.
+fn decimal_fields_ids(settings: &InnerIndexSettings) -> BTreeSet<FieldId> {
+    settings
+        .faceted_fields_ids
+        .iter()
+        .copied()
+        .filter(|fid| {
+            settings.fields_ids_map.name(*fid).map_or(false, |name| {
+                settings.decimal_attributes.iter().any(|attr| crate::is_faceted_by(name, attr))
+            })
+        })
+        .collect()
+}
+
 /// Extracts the facet values of a JSON field.
-fn extract_facet_values(value: &Value, geo_field: bool) -> FilterableValues {
+///
+/// The strings of the decimal fields are also extracted as numbers when they can be parsed,
+/// and the strings are normalized according to the facet normalization of the field.
+fn extract_facet_values(
+    value: &Value,
+    geo_field: bool,
+    decimal_field: bool,
+    normalization: FacetNormalization,
+) -> FilterableValues {
     fn inner_extract_facet_values(
         value: &Value,
         can_recurse: bool,
         output_numbers: &mut Vec<f64>,
         output_strings: &mut Vec<(String, String)>,
         geo_field: bool,
+        decimal_field: bool,
+        normalization: FacetNormalization,
     ) {
         match value {
             Value::Null => (),
@@ -474,8 +544,14 @@ fn extract_facet_values(value: &Value, geo_field: bool) -> FilterableValues {
                             "Internal error, could not parse a geofield that has been validated. Please open an issue."
                         )
                     }
+                } else if decimal_field {
+                    if let Some(float) =
+                        original.trim().parse::<f64>().ok().filter(|float| float.is_finite())
+                    {
+                        output_numbers.push(float);
+                    }
                 }
-                let normalized = crate::normalize_facet(original);
+                let normalized = normalization.normalize(original);
                 output_strings.push((normalized, original.clone()));
             }
             Value::Array(values) => {
@@ -487,6 +563,8 @@ fn extract_facet_values(value: &Value, geo_field: bool) -> FilterableValues {
                             output_numbers,
                             output_strings,
                             geo_field,
+                            decimal_field,
+                            normalization,
                         );
                     }
                 }
@@ -503,7 +581,15 @@ fn extract_facet_values(value: &Value, geo_field: bool) -> FilterableValues {
         otherwise => {
             let mut numbers = Vec::new();
             let mut strings = Vec::new();
-            inner_extract_facet_values(otherwise, true, &mut numbers, &mut strings, geo_field);
+            inner_extract_facet_values(
+                otherwise,
+                true,
+                &mut numbers,
+                &mut strings,
+                geo_field,
+                decimal_field,
+                normalization,
+            );
             FilterableValues::Values { numbers, strings }
         }
     }
@@ -23,10 +23,12 @@ use roaring::RoaringBitmap;
 use serde::{Deserialize, Serialize};
 use slice_group_by::GroupBy;
 use tracing::debug;
-use typed_chunk::{write_typed_chunk_into_index, ChunkAccumulator, TypedChunk};
+use typed_chunk::{
+    remove_capped_facet_string_values, write_typed_chunk_into_index, ChunkAccumulator, TypedChunk,
+};
 
 use self::enrich::enrich_documents_batch;
-pub use self::enrich::{extract_finite_float_from_value, DocumentId};
+pub use self::enrich::{extract_finite_float_from_value, geojson_point_to_lat_lng, DocumentId};
 pub use self::helpers::{
     as_cloneable_grenad, create_sorter, create_writer, fst_stream_into_hashset,
     fst_stream_into_vec, merge_cbo_roaring_bitmaps, merge_deladd_cbo_roaring_bitmaps,
@@ -76,6 +78,19 @@ impl Default for IndexDocumentsMethod {
     }
 }
 
+/// How the documents sharing the same primary key in a single document addition are handled.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DuplicateKeyStrategy {
+    /// The last document replaces, or is merged into, the previous ones.
+    #[default]
+    LastWins,
+    /// The first document is kept and the next ones are ignored.
+    FirstWins,
+    /// The document addition is rejected.
+    Error,
+}
+
 pub struct IndexDocuments<'t, 'i, 'a, FP, FA> {
     wtxn: &'t mut heed::RwTxn<'i>,
     index: &'i Index,
@@ -142,12 +157,25 @@ where
     /// Returns the number of documents added to the builder.
     #[tracing::instrument(level = "trace", skip_all, target = "indexing::documents")]
     pub fn add_documents<R: Read + Seek>(
-        mut self,
+        self,
         reader: DocumentsBatchReader<R>,
     ) -> Result<(Self, StdResult<u64, UserError>)> {
+        let (this, result) = self.add_documents_reporting_etag_conflicts(reader)?;
+        Ok((this, result.map(|(indexed_documents, _)| indexed_documents)))
+    }
+
+    /// Adds a batch of documents to the current builder.
+    ///
+    /// Returns the number of documents added to the builder along with the external ids of
+    /// the documents that were rejected because their `_etag` didn't match the stored document.
+    #[tracing::instrument(level = "trace", skip_all, target = "indexing::documents")]
+    pub fn add_documents_reporting_etag_conflicts<R: Read + Seek>(
+        mut self,
+        reader: DocumentsBatchReader<R>,
+    ) -> Result<(Self, StdResult<(u64, Vec<String>), UserError>)> {
         // Early return when there is no document to add
         if reader.is_empty() {
-            return Ok((self, Ok(0)));
+            return Ok((self, Ok((0, Vec::new()))));
         }
 
         // We check for user errors in this validator and if there is one, we can return
@@ -163,17 +191,18 @@ where
             Err(user_error) => return Ok((self, Err(user_error))),
         };
 
-        let indexed_documents =
+        let (indexed_documents, etag_conflicts) =
             self.transform.as_mut().expect("Invalid document addition state").read_documents(
                 enriched_documents_reader,
                 self.wtxn,
                 &self.progress,
                 &self.should_abort,
-            )? as u64;
+            )?;
+        let indexed_documents = indexed_documents as u64;
 
         self.added_documents += indexed_documents;
 
-        Ok((self, Ok(indexed_documents)))
+        Ok((self, Ok((indexed_documents, etag_conflicts))))
     }
 
     #[tracing::instrument(level = "trace", skip_all, target = "indexing::documents")]
@@ -231,6 +260,7 @@ where
 
         let immutable_obkvs = ImmutableObkvs::new(
             self.wtxn,
+            self.index,
             self.index.documents,
             fields_ids_map.clone(),
             documents.clone(),
@@ -321,26 +351,38 @@ where
     /// Returns the number of documents deleted from the builder.
     #[tracing::instrument(level = "trace", skip_all, target = "indexing::documents")]
     pub fn remove_documents(
-        mut self,
+        self,
         to_delete: Vec<String>,
     ) -> Result<(Self, StdResult<u64, UserError>)> {
+        let (this, deleted_ids) = self.remove_documents_reporting_ids(to_delete)?;
+        // Maintains Invariant: remove documents actually always returns Ok for the inner result
+        Ok((this, deleted_ids.map(|ids| ids.len() as u64)))
+    }
+
+    /// Remove a batch of documents from the current builder.
+    ///
+    /// Returns the external ids of the documents that were found and deleted from the builder.
+    #[tracing::instrument(level = "trace", skip_all, target = "indexing::documents")]
+    pub fn remove_documents_reporting_ids(
+        mut self,
+        to_delete: Vec<String>,
+    ) -> Result<(Self, StdResult<Vec<String>, UserError>)> {
         // Early return when there is no document to add
         if to_delete.is_empty() {
             // Maintains Invariant: remove documents actually always returns Ok for the inner result
-            return Ok((self, Ok(0)));
+            return Ok((self, Ok(Vec::new())));
         }
 
-        let deleted_documents = self
+        let deleted_ids = self
             .transform
             .as_mut()
             .expect("Invalid document deletion state")
-            .remove_documents(to_delete, self.wtxn, &self.should_abort)?
-            as u64;
+            .remove_documents(to_delete, self.wtxn, &self.should_abort)?;
 
-        self.deleted_documents += deleted_documents;
+        self.deleted_documents += deleted_ids.len() as u64;
 
         // Maintains Invariant: remove documents actually always returns Ok for the inner result
-        Ok((self, Ok(deleted_documents)))
+        Ok((self, Ok(deleted_ids)))
     }
 
     /// Removes documents from db using their internal document ids.
@@ -414,11 +456,14 @@ where
             primary_key,
             mut settings_diff,
             field_distribution,
+            fields_values,
             documents_count,
             original_documents,
             flattened_documents,
         } = output;
 
+        let previous_number_of_documents = self.index.number_of_documents(self.wtxn)?;
+
         // update the internal facet and searchable list,
         // because they might have changed due to the nested documents flattening.
         settings_diff.new.recompute_facets(self.wtxn, self.index)?;
@@ -671,12 +716,31 @@ where
             Ok(())
         }).map_err(InternalError::from)??;
 
+        // The facet values dropped by the maximum number of facet values per attribute are
+        // only known once all the chunks are written.
+        if self.index.max_facet_values_per_attribute(self.wtxn)?.is_some() {
+            remove_capped_facet_string_values(self.wtxn, self.index, &final_documents_ids)?;
+        }
+
         // We write the field distribution into the main database
         self.index.put_field_distribution(self.wtxn, &field_distribution)?;
+        self.index.put_fields_values(self.wtxn, &fields_values)?;
 
         // We write the primary key field id into the main database
         self.index.put_primary_key(self.wtxn, &primary_key)?;
         let number_of_documents = self.index.number_of_documents(self.wtxn)?;
+
+        // The limit is checked before the update is committed so that a rejected addition
+        // leaves the index untouched. The updates that don't add documents are always accepted.
+        if let Some(max_documents) = self.index.max_documents(self.wtxn)? {
+            if number_of_documents > max_documents
+                && number_of_documents > previous_number_of_documents
+            {
+                return Err(
+                    UserError::MaxDocumentsExceeded { max_documents, number_of_documents }.into()
+                );
+            }
+        }
         let mut rng = rand::rngs::StdRng::seed_from_u64(42);
 
         for (embedder_name, dimension) in dimension {
@@ -753,6 +817,9 @@ where
         if let Some(value) = self.config.words_prefix_threshold {
             builder.threshold(value);
         }
+        if let Some(value) = self.index.min_prefix_length(self.wtxn)? {
+            builder.min_prefix_length(value as usize);
+        }
         if let Some(value) = self.config.max_prefix_length {
             builder.max_prefix_length(value);
         }
@@ -1006,8 +1073,8 @@ mod tests {
         // Check that we get only one document from the database.
         let docs = index.documents(&rtxn, Some(0)).unwrap();
         assert_eq!(docs.len(), 1);
-        let (id, doc) = docs[0];
-        assert_eq!(id, 0);
+        let (id, doc) = &docs[0];
+        assert_eq!(*id, 0);
 
         // Check that this document is equal to the last one sent.
         let mut doc_iter = doc.iter();
@@ -1027,8 +1094,8 @@ mod tests {
         // Check that we get only one document from the database.
         let docs = index.documents(&rtxn, Some(0)).unwrap();
         assert_eq!(docs.len(), 1);
-        let (id, doc) = docs[0];
-        assert_eq!(id, 0);
+        let (id, doc) = &docs[0];
+        assert_eq!(*id, 0);
 
         // Check that this document is equal to the last one sent.
         let mut doc_iter = doc.iter();
@@ -1093,7 +1160,7 @@ mod tests {
         let kevin_position =
             docs.iter().position(|(_, d)| d.get(0).unwrap() == br#""updated kevin""#).unwrap();
         assert_eq!(kevin_position, 2);
-        let (_, doc) = docs[kevin_position];
+        let (_, doc) = &docs[kevin_position];
 
         // Check that this document is equal to the last
         // one sent and that an UUID has been generated.
@@ -2350,6 +2417,223 @@ mod tests {
         assert_eq!(cj_cmn_docs, expected_cj_cmn_docids);
     }
 
+    #[test]
+    fn add_documents_with_etag() {
+        let mut index = TempIndex::new();
+        index.index_documents_config.update_method = IndexDocumentsMethod::UpdateDocuments;
+
+        index
+            .add_documents(documents!([
+                { "id": 1, "doggo": "kevin" },
+                { "id": 2, "doggo": "bob" },
+            ]))
+            .unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        let current_etag = index.document_etag(&rtxn, 0, None).unwrap().unwrap();
+        drop(rtxn);
+
+        let mut wtxn = index.write_txn().unwrap();
+        let builder = IndexDocuments::new(
+            &mut wtxn,
+            &index,
+            &index.indexer_config,
+            index.index_documents_config.clone(),
+            |_| (),
+            || false,
+        )
+        .unwrap();
+
+        // The stale etag and the etag of a document that doesn't exist are rejected.
+        let documents = documents!([
+            { "id": 1, "doggo": "kefir", "_etag": "stale" },
+            { "id": 2, "doggo": "jean", "_etag": current_etag },
+            { "id": 3, "doggo": "max", "_etag": current_etag },
+        ]);
+        let (builder, added) = builder.add_documents_reporting_etag_conflicts(documents).unwrap();
+        insta::assert_debug_snapshot!(added.unwrap(), @r###"
+        (
+            0,
+            [
+                "1",
+                "2",
+                "3",
+            ],
+        )
+        "###);
+
+        // The current etag is applied.
+        let documents = documents!([
+            { "id": 1, "doggo": "kefir", "_etag": current_etag },
+        ]);
+        let (builder, added) = builder.add_documents_reporting_etag_conflicts(documents).unwrap();
+        insta::assert_debug_snapshot!(added.unwrap(), @r###"
+        (
+            1,
+            [],
+        )
+        "###);
+
+        builder.execute().unwrap();
+        wtxn.commit().unwrap();
+
+        // The `_etag` field is never stored.
+        db_snap!(index, documents, @r###"
+        {"id":1,"doggo":"kefir"}
+        {"id":2,"doggo":"bob"}
+        "###);
+
+        let rtxn = index.read_txn().unwrap();
+        assert_ne!(index.document_etag(&rtxn, 0, None).unwrap().unwrap(), current_etag);
+        drop(rtxn);
+
+        let error = index
+            .add_documents(documents!([
+                { "id": 1, "doggo": "kevin", "_etag": 42 },
+            ]))
+            .unwrap_err();
+        insta::assert_snapshot!(error, @"The `_etag` field in the document with the id: `1` is invalid. It must be a string, but received: `42`.");
+    }
+
+    #[test]
+    fn add_documents_with_the_same_etag_in_one_batch() {
+        let mut index = TempIndex::new();
+        index.index_documents_config.update_method = IndexDocumentsMethod::UpdateDocuments;
+
+        index
+            .add_documents(documents!([
+                { "id": 1, "doggo": "kevin" },
+                { "id": 2, "doggo": "bob" },
+            ]))
+            .unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        let first_etag = index.document_etag(&rtxn, 0, None).unwrap().unwrap();
+        let second_etag = index.document_etag(&rtxn, 1, None).unwrap().unwrap();
+        drop(rtxn);
+
+        let mut wtxn = index.write_txn().unwrap();
+        let builder = IndexDocuments::new(
+            &mut wtxn,
+            &index,
+            &index.indexer_config,
+            index.index_documents_config.clone(),
+            |_| (),
+            || false,
+        )
+        .unwrap();
+
+        // Only the first of the two updates of the same payload is applied.
+        let documents = documents!([
+            { "id": 1, "doggo": "kefir", "_etag": first_etag },
+            { "id": 1, "doggo": "max", "_etag": first_etag },
+        ]);
+        let (builder, added) = builder.add_documents_reporting_etag_conflicts(documents).unwrap();
+        insta::assert_debug_snapshot!(added.unwrap(), @r###"
+        (
+            1,
+            [
+                "1",
+            ],
+        )
+        "###);
+
+        // The first payload of the batch wins, the second one is based on a stale version.
+        let documents = documents!([
+            { "id": 2, "doggo": "jean", "_etag": second_etag },
+        ]);
+        let (builder, added) = builder.add_documents_reporting_etag_conflicts(documents).unwrap();
+        insta::assert_debug_snapshot!(added.unwrap(), @r###"
+        (
+            1,
+            [],
+        )
+        "###);
+        let documents = documents!([
+            { "id": 2, "doggo": "bobby", "_etag": second_etag },
+            { "id": 1, "doggo": "max", "_etag": first_etag },
+        ]);
+        let (builder, added) = builder.add_documents_reporting_etag_conflicts(documents).unwrap();
+        insta::assert_debug_snapshot!(added.unwrap(), @r###"
+        (
+            0,
+            [
+                "2",
+                "1",
+            ],
+        )
+        "###);
+
+        builder.execute().unwrap();
+        wtxn.commit().unwrap();
+
+        db_snap!(index, documents, @r###"
+        {"id":1,"doggo":"kefir"}
+        {"id":2,"doggo":"jean"}
+        "###);
+    }
+
+    #[test]
+    fn max_facet_values_per_attribute() {
+        let index = TempIndex::new();
+
+        index
+            .update_settings(|settings| {
+                settings.set_filterable_fields(hashset! { S("tag") });
+                settings.set_max_facet_values_per_attribute(3);
+            })
+            .unwrap();
+
+        index
+            .add_documents(documents!([
+                { "id": 0, "tag": "a" },
+                { "id": 1, "tag": "b" },
+                { "id": 2, "tag": "c" },
+                { "id": 3, "tag": "d" },
+                { "id": 4, "tag": "e" },
+                { "id": 5, "tag": "a" },
+            ]))
+            .unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        let fields_ids_map = index.fields_ids_map(&rtxn).unwrap();
+        let tag_id = fields_ids_map.id("tag").unwrap();
+        let mut prefix = tag_id.to_be_bytes().to_vec();
+        prefix.push(0);
+        let values_count = index
+            .facet_id_string_docids
+            .remap_types::<heed::types::Bytes, heed::types::DecodeIgnore>()
+            .prefix_iter(&rtxn, &prefix)
+            .unwrap()
+            .count();
+        assert_eq!(values_count, 3);
+        assert_eq!(
+            index.capped_faceted_fields(&rtxn).unwrap().into_iter().collect::<Vec<_>>(),
+            vec![S("tag")]
+        );
+
+        // The already indexed facet values are still filterable.
+        let mut search = Search::new(&rtxn, &index);
+        search.filter(Filter::from_str("tag = a").unwrap().unwrap());
+        let crate::SearchResult { documents_ids, .. } = search.execute().unwrap();
+        assert_eq!(documents_ids, vec![0, 5]);
+
+        // The capped facet values are not returned by the facet distribution of a small
+        // set of documents, computed from the facet values of the documents.
+        let distribution = crate::FacetDistribution::new(&rtxn, &index)
+            .facets(std::iter::once(("tag", crate::OrderBy::default())))
+            .candidates((0..6).collect())
+            .execute()
+            .unwrap();
+        insta::assert_snapshot!(format!("{distribution:?}"), @r###"{"tag": {"a": 2, "b": 1, "c": 1}}"###);
+        drop(rtxn);
+
+        // Changing the setting clears the capped attributes.
+        index.update_settings(|settings| settings.reset_max_facet_values_per_attribute()).unwrap();
+        let rtxn = index.read_txn().unwrap();
+        assert!(index.capped_faceted_fields(&rtxn).unwrap().is_empty());
+    }
+
     #[test]
     fn add_and_delete_documents_in_single_transform() {
         let mut index = TempIndex::new();
@@ -3435,4 +3719,118 @@ mod tests {
         let crate::SearchResult { documents_ids, .. } = s.execute().unwrap();
         insta::assert_snapshot!(format!("{documents_ids:?}"), @"[0]");
     }
+
+    #[test]
+    fn max_documents_limit() {
+        let index = TempIndex::new();
+        index.update_settings(|settings| settings.set_max_documents(2)).unwrap();
+
+        index.add_documents(documents!([{ "id": 1 }, { "id": 2 }])).unwrap();
+
+        // updating the existing documents doesn't increase the number of documents
+        index.add_documents(documents!([{ "id": 2, "name": "kefir" }])).unwrap();
+
+        let error = index.add_documents(documents!([{ "id": 3 }, { "id": 1 }])).unwrap_err();
+        assert!(matches!(
+            error,
+            Error::UserError(UserError::MaxDocumentsExceeded {
+                max_documents: 2,
+                number_of_documents: 3
+            })
+        ));
+        insta::assert_snapshot!(error, @"The index cannot contain more than 2 documents, the update would bring it to 3 documents.");
+
+        // the rejected addition left the index in its previous state
+        let rtxn = index.read_txn().unwrap();
+        assert_eq!(index.number_of_documents(&rtxn).unwrap(), 2);
+        assert!(index.external_documents_ids().get(&rtxn, "3").unwrap().is_none());
+        drop(rtxn);
+
+        index.update_settings(|settings| settings.reset_max_documents()).unwrap();
+        index.add_documents(documents!([{ "id": 3 }])).unwrap();
+        let rtxn = index.read_txn().unwrap();
+        assert_eq!(index.number_of_documents(&rtxn).unwrap(), 3);
+    }
+
+    #[test]
+    fn add_documents_with_duplicate_keys() {
+        let index = TempIndex::new();
+
+        // by default the last document wins
+        index
+            .add_documents(documents!([
+                { "id": 1, "doggo": "kevin" },
+                { "id": 1, "doggo": "bob" },
+            ]))
+            .unwrap();
+        db_snap!(index, documents, @r###"
+        {"id":1,"doggo":"bob"}
+        "###);
+
+        index
+            .update_settings(|settings| {
+                settings.set_duplicate_key_strategy(DuplicateKeyStrategy::FirstWins)
+            })
+            .unwrap();
+        index
+            .add_documents(documents!([
+                { "id": 1, "doggo": "kefir" },
+                { "id": 2, "doggo": "jean" },
+                { "id": 1, "doggo": "max" },
+            ]))
+            .unwrap();
+        db_snap!(index, documents, @r###"
+        {"id":1,"doggo":"kefir"}
+        {"id":2,"doggo":"jean"}
+        "###);
+
+        index
+            .update_settings(|settings| {
+                settings.set_duplicate_key_strategy(DuplicateKeyStrategy::Error)
+            })
+            .unwrap();
+        let error = index
+            .add_documents(documents!([
+                { "id": 3, "doggo": "max" },
+                { "id": 3, "doggo": "bob" },
+            ]))
+            .unwrap_err();
+        insta::assert_snapshot!(error, @"The document with the id: `3` appears more than once in the payload.");
+
+        // the rejected addition left the documents untouched
+        db_snap!(index, documents, @r###"
+        {"id":1,"doggo":"kefir"}
+        {"id":2,"doggo":"jean"}
+        "###);
+
+        // only the payload containing the duplicates is rejected, the other payloads of the
+        // same batch are still indexed
+        let mut wtxn = index.write_txn().unwrap();
+        let builder = IndexDocuments::new(
+            &mut wtxn,
+            &index,
+            &index.indexer_config,
+            index.index_documents_config.clone(),
+            |_| (),
+            || false,
+        )
+        .unwrap();
+        let documents = documents!([
+            { "id": 3, "doggo": "max" },
+            { "id": 3, "doggo": "bob" },
+        ]);
+        let (builder, added) = builder.add_documents(documents).unwrap();
+        insta::assert_snapshot!(added.unwrap_err(), @"The document with the id: `3` appears more than once in the payload.");
+        let documents = documents!([{ "id": 4, "doggo": "bobby" }]);
+        let (builder, added) = builder.add_documents(documents).unwrap();
+        assert_eq!(added.unwrap(), 1);
+        builder.execute().unwrap();
+        wtxn.commit().unwrap();
+
+        db_snap!(index, documents, @r###"
+        {"id":1,"doggo":"kefir"}
+        {"id":2,"doggo":"jean"}
+        {"id":4,"doggo":"bobby"}
+        "###);
+    }
 }
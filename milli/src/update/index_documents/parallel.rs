@@ -1,44 +1,49 @@
-use heed::types::Bytes;
 use heed::{Database, RoTxn};
 use obkv::KvReaderU16;
 use roaring::RoaringBitmap;
 
-use crate::{all_obkv_to_json, DocumentId, FieldsIdsMap, Object, ObkvCodec, Result, BEU32};
+use crate::{
+    all_obkv_to_json, CompressedObkvCodec, DocumentId, DocumentObkv, FieldsIdsMap, Index, Object,
+    Result, BEU32,
+};
 
 pub struct ImmutableObkvs<'t> {
     ids: RoaringBitmap,
     fields_ids_map: FieldsIdsMap,
-    slices: Vec<&'t [u8]>,
+    documents: Vec<DocumentObkv<'t>>,
 }
 
 impl<'t> ImmutableObkvs<'t> {
     /// Creates the structure by fetching all the OBKVs
     /// and keeping the transaction making the pointers valid.
+    ///
+    /// The compressed documents are decompressed upfront.
     pub fn new(
         rtxn: &'t RoTxn,
-        documents_database: Database<BEU32, ObkvCodec>,
+        index: &Index,
+        documents_database: Database<BEU32, CompressedObkvCodec>,
         fields_ids_map: FieldsIdsMap,
         subset: RoaringBitmap,
-    ) -> heed::Result<Self> {
-        let mut slices = Vec::new();
-        let documents_database = documents_database.remap_data_type::<Bytes>();
+    ) -> Result<Self> {
+        let dictionary = index.document_decompression_dictionary(rtxn)?;
+        let mut documents = Vec::new();
         for docid in &subset {
-            let slice = documents_database.get(rtxn, &docid)?.unwrap();
-            slices.push(slice);
+            let document = documents_database.get(rtxn, &docid)?.unwrap();
+            documents.push(document.decompress(dictionary.as_deref())?);
         }
 
-        Ok(ImmutableObkvs { ids: subset, fields_ids_map, slices })
+        Ok(ImmutableObkvs { ids: subset, fields_ids_map, documents })
     }
 
     /// Returns the OBKVs identified by the given ID.
-    pub fn obkv(&self, docid: DocumentId) -> heed::Result<Option<KvReaderU16<'t>>> {
+    pub fn obkv(&self, docid: DocumentId) -> heed::Result<Option<KvReaderU16<'_>>> {
         match self
             .ids
             .rank(docid)
             .checked_sub(1)
-            .and_then(|offset| self.slices.get(offset as usize))
+            .and_then(|offset| self.documents.get(offset as usize))
         {
-            Some(bytes) => Ok(Some(KvReaderU16::new(bytes))),
+            Some(document) => Ok(Some(document.as_obkv())),
             None => Ok(None),
         }
     }
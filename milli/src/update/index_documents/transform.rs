@@ -11,15 +11,16 @@ use obkv::{KvReader, KvReaderU16, KvWriter};
 use roaring::RoaringBitmap;
 use serde_json::Value;
 use smartstring::SmartString;
+use zstd::dict::DecoderDictionary;
 
 use super::helpers::{
     create_sorter, create_writer, keep_first, obkvs_keep_last_addition_merge_deletions,
     obkvs_merge_additions_and_deletions, sorter_into_reader, MergeFn,
 };
-use super::{IndexDocumentsMethod, IndexerConfig};
+use super::{geojson_point_to_lat_lng, DuplicateKeyStrategy, IndexDocumentsMethod, IndexerConfig};
 use crate::documents::{DocumentsBatchIndex, EnrichedDocument, EnrichedDocumentsBatchReader};
 use crate::error::{Error, InternalError, UserError};
-use crate::index::{db_name, main_key};
+use crate::index::{count_field_value, db_name, main_key};
 use crate::update::del_add::{
     into_del_add_obkv, into_del_add_obkv_conditional_operation, DelAdd, DelAddOperation,
     KvReaderDelAdd,
@@ -30,13 +31,15 @@ use crate::update::{AvailableDocumentsIds, UpdateIndexingStep};
 use crate::vector::parsed_vectors::{ExplicitVectors, VectorOrArrayOfVectors};
 use crate::vector::settings::{EmbedderAction, WriteBackToDocuments};
 use crate::{
-    is_faceted_by, FieldDistribution, FieldId, FieldIdMapMissingEntry, FieldsIdsMap, Index, Result,
+    is_faceted_by, AttributeTransform, FieldDistribution, FieldId, FieldIdMapMissingEntry,
+    FieldsIdsMap, FieldsValues, Index, Result, RESERVED_ETAG_FIELD_NAME,
 };
 
 pub struct TransformOutput {
     pub primary_key: String,
     pub settings_diff: InnerIndexSettingsDiff,
     pub field_distribution: FieldDistribution,
+    pub fields_values: FieldsValues,
     pub documents_count: usize,
     pub original_documents: Option<File>,
     pub flattened_documents: Option<File>,
@@ -66,6 +69,9 @@ pub struct Transform<'a, 'i> {
     new_documents_ids: RoaringBitmap,
     // To increase the cache locality and decrease the heap usage we use compact smartstring.
     new_external_documents_ids_builder: FxHashMap<SmartString<smartstring::Compact>, u64>,
+    /// The external ids of the documents written or deleted by this transform, their stored
+    /// etag doesn't reflect the version of the document that will be committed.
+    modified_external_ids: HashSet<String>,
     documents_count: usize,
 }
 
@@ -90,6 +96,8 @@ fn create_fields_mapping(
         // we sort by id here to ensure a deterministic mapping of the fields, that preserves
         // the original ordering.
         .sorted_by_key(|(&id, _)| id)
+        // the `_etag` is only used to check the addition and is never stored.
+        .filter(|(_, name)| name.as_str() != RESERVED_ETAG_FIELD_NAME)
         .map(|(field, name)| match index_field_map.id(name) {
             Some(id) => Ok((*field, id)),
             None => index_field_map
@@ -134,7 +142,9 @@ impl<'a, 'i> Transform<'a, 'i> {
             indexer_settings.max_nb_chunks,
             indexer_settings.max_memory.map(|mem| mem / 2),
         );
-        let documents_ids = index.documents_ids(wtxn)?;
+        let mut documents_ids = index.documents_ids(wtxn)?;
+        // the reserved ids are only given back to the documents they were reserved for
+        documents_ids |= index.reserved_documents_ids(wtxn)?;
 
         Ok(Transform {
             index,
@@ -147,10 +157,14 @@ impl<'a, 'i> Transform<'a, 'i> {
             replaced_documents_ids: RoaringBitmap::new(),
             new_documents_ids: RoaringBitmap::new(),
             new_external_documents_ids_builder: FxHashMap::default(),
+            modified_external_ids: HashSet::new(),
             documents_count: 0,
         })
     }
 
+    /// Reads the documents and returns the number of documents read along with
+    /// the external ids of the documents rejected because their `_etag` didn't
+    /// match the etag of the stored document.
     #[tracing::instrument(level = "trace", skip_all, target = "indexing::documents")]
     pub fn read_documents<R, FP, FA>(
         &mut self,
@@ -158,7 +172,7 @@ impl<'a, 'i> Transform<'a, 'i> {
         wtxn: &mut heed::RwTxn<'_>,
         progress_callback: FP,
         should_abort: FA,
-    ) -> Result<usize>
+    ) -> Result<(usize, Vec<String>)>
     where
         R: Read + Seek,
         FP: Fn(UpdateIndexingStep) + Sync,
@@ -167,10 +181,16 @@ impl<'a, 'i> Transform<'a, 'i> {
         let (mut cursor, fields_index) = reader.into_cursor_and_fields_index();
         let external_documents_ids = self.index.external_documents_ids();
         let mapping = create_fields_mapping(&mut self.fields_ids_map, &fields_index)?;
+        let etag_field_id = fields_index.id(RESERVED_ETAG_FIELD_NAME);
 
         let primary_key = cursor.primary_key().to_string();
         let primary_key_id =
             self.fields_ids_map.insert(&primary_key).ok_or(UserError::AttributeLimitReached)?;
+        let attribute_transforms = self.index.attribute_transforms(wtxn)?;
+        let duplicate_key_strategy = self.index.duplicate_key_strategy(wtxn)?;
+        let dictionary = self.index.document_decompression_dictionary(wtxn)?;
+        // The external ids seen in this payload, only tracked when the first document wins.
+        let mut payload_external_ids = HashSet::new();
 
         let mut obkv_buffer = Vec::new();
         let mut document_sorter_value_buffer = Vec::new();
@@ -178,6 +198,7 @@ impl<'a, 'i> Transform<'a, 'i> {
         let mut documents_count = 0;
         let mut docid_buffer: Vec<u8> = Vec::new();
         let mut field_buffer: Vec<(u16, Cow<'_, [u8]>)> = Vec::new();
+        let mut etag_conflicts = Vec::new();
         while let Some(enriched_document) = cursor.next_enriched_document()? {
             let EnrichedDocument { document, document_id } = enriched_document;
 
@@ -203,10 +224,61 @@ impl<'a, 'i> Transform<'a, 'i> {
                 field_buffer_cache.push((primary_key_id, Cow::from(&docid_buffer)));
             }
 
+            let mut etag = None;
             for (k, v) in document.iter() {
+                if Some(k) == etag_field_id {
+                    etag = Some(v);
+                    continue;
+                }
                 let mapped_id =
                     *mapping.get(&k).ok_or(InternalError::FieldIdMappingMissingEntry { key: k })?;
-                field_buffer_cache.push((mapped_id, Cow::from(v)));
+                // the primary key is never transformed to keep the document identifiable
+                let transforms = self
+                    .fields_ids_map
+                    .name(mapped_id)
+                    .filter(|_| mapped_id != primary_key_id)
+                    .and_then(|name| attribute_transforms.get_key_value(name));
+                match transforms {
+                    Some((attribute, transforms)) => {
+                        let value =
+                            apply_attribute_transforms(attribute, transforms, external_id, v)?;
+                        field_buffer_cache.push((mapped_id, Cow::Owned(value)));
+                    }
+                    None => field_buffer_cache.push((mapped_id, Cow::from(v))),
+                }
+            }
+
+            // When an etag is provided, the document is only written if the etag matches
+            // the one of the stored version of the document. A document already written or
+            // deleted by a previous operation of the batch or of this payload no longer matches
+            // the stored version, the etag is necessarily stale.
+            if let Some(etag) = etag {
+                // The etag has already been validated to be a string by `enrich_documents_batch`.
+                let etag: String =
+                    serde_json::from_slice(etag).map_err(InternalError::SerdeJson)?;
+                let stored_etag = match external_documents_ids.get(wtxn, external_id)? {
+                    Some(_) if self.modified_external_ids.contains(external_id) => None,
+                    Some(docid) => self.index.document_etag(wtxn, docid, dictionary.as_deref())?,
+                    None => None,
+                };
+
+                if stored_etag.as_deref() != Some(etag.as_str()) {
+                    etag_conflicts.push(external_id.to_string());
+                    field_buffer = drop_and_reuse(field_buffer_cache);
+                    docid_buffer.clear();
+                    continue;
+                }
+            }
+
+            // The documents of the previous payloads of the batch are still replaced or updated:
+            // only the duplicates of this payload are subject to the strategy. The payloads
+            // containing duplicates are rejected before the transform when they are errors.
+            if duplicate_key_strategy == DuplicateKeyStrategy::FirstWins
+                && !payload_external_ids.insert(external_id.to_string())
+            {
+                field_buffer = drop_and_reuse(field_buffer_cache);
+                docid_buffer.clear();
+                continue;
             }
 
             // Insertion in a obkv need to be done with keys ordered. For now they are ordered
@@ -234,10 +306,13 @@ impl<'a, 'i> Transform<'a, 'i> {
 
                             docid
                         }
-                        None => self
-                            .available_documents_ids
-                            .next()
-                            .ok_or(UserError::DocumentLimitReached)?,
+                        None => match self.index.reserved_document_id(wtxn, entry.key())? {
+                            Some(docid) => docid,
+                            None => self
+                                .available_documents_ids
+                                .next()
+                                .ok_or(UserError::DocumentLimitReached)?,
+                        },
                     };
                     entry.insert(docid as u64);
                     docid
@@ -247,15 +322,16 @@ impl<'a, 'i> Transform<'a, 'i> {
             let mut skip_insertion = false;
             if let Some(original_docid) = original_docid {
                 let original_key = original_docid;
-                let base_obkv = self
+                let base_document = self
                     .index
                     .documents
-                    .remap_data_type::<heed::types::Bytes>()
                     .get(wtxn, &original_key)?
                     .ok_or(InternalError::DatabaseMissingEntry {
                         db_name: db_name::DOCUMENTS,
                         key: None,
-                    })?;
+                    })?
+                    .decompress(dictionary.as_deref())?;
+                let base_obkv = base_document.as_bytes();
 
                 // we check if the two documents are exactly equal. If it's the case we can skip this document entirely
                 if base_obkv == obkv_buffer {
@@ -304,6 +380,7 @@ impl<'a, 'i> Transform<'a, 'i> {
 
             if !skip_insertion {
                 self.new_documents_ids.insert(docid);
+                self.modified_external_ids.insert(external_id.to_string());
 
                 document_sorter_key_buffer.clear();
                 document_sorter_key_buffer.extend_from_slice(&docid.to_be_bytes());
@@ -353,7 +430,7 @@ impl<'a, 'i> Transform<'a, 'i> {
         self.documents_count += documents_count;
         // Now that we have a valid sorter that contains the user id and the obkv we
         // give it to the last transforming function which returns the TransformOutput.
-        Ok(documents_count)
+        Ok((documents_count, etag_conflicts))
     }
 
     /// The counter part of `read_documents` that removes documents either from the transform or the database.
@@ -367,12 +444,13 @@ impl<'a, 'i> Transform<'a, 'i> {
     ///   it's added into the grenad to ensure we don't insert it + removed from the list of new documents ids.
     /// - If the document to remove was not present in either the db or the transform we do nothing.
     #[tracing::instrument(level = "trace", skip_all, target = "indexing::documents")]
+    /// Returns the external ids of the documents that were actually deleted.
     pub fn remove_documents<FA>(
         &mut self,
         mut to_remove: Vec<String>,
         wtxn: &mut heed::RwTxn<'_>,
         should_abort: FA,
-    ) -> Result<usize>
+    ) -> Result<Vec<String>>
     where
         FA: Fn() -> bool + Sync,
     {
@@ -381,8 +459,9 @@ impl<'a, 'i> Transform<'a, 'i> {
         to_remove.dedup();
 
         let external_documents_ids = self.index.external_documents_ids();
+        let dictionary = self.index.document_decompression_dictionary(wtxn)?;
 
-        let mut documents_deleted = 0;
+        let mut documents_deleted = Vec::new();
         let mut document_sorter_value_buffer = Vec::new();
         let mut document_sorter_key_buffer = Vec::new();
         for to_remove in to_remove {
@@ -390,6 +469,8 @@ impl<'a, 'i> Transform<'a, 'i> {
                 return Err(Error::InternalError(InternalError::AbortedIndexation));
             }
 
+            self.modified_external_ids.insert(to_remove.clone());
+
             // Check if the document has been added in the current indexing process.
             let deleted_from_current =
                 match self.new_external_documents_ids_builder.entry((*to_remove).into()) {
@@ -426,8 +507,9 @@ impl<'a, 'i> Transform<'a, 'i> {
                 Some(docid) => {
                     self.remove_document_from_db(
                         docid,
-                        to_remove,
+                        to_remove.clone(),
                         wtxn,
+                        dictionary.as_deref(),
                         &mut document_sorter_key_buffer,
                         &mut document_sorter_value_buffer,
                     )?;
@@ -438,7 +520,7 @@ impl<'a, 'i> Transform<'a, 'i> {
 
             // increase counter only if the document existed somewhere before.
             if deleted_from_current || deleted_from_db {
-                documents_deleted += 1;
+                documents_deleted.push(to_remove);
             }
         }
 
@@ -469,6 +551,7 @@ impl<'a, 'i> Transform<'a, 'i> {
         let mut document_sorter_value_buffer = Vec::new();
         let mut document_sorter_key_buffer = Vec::new();
         let external_ids = self.index.external_id_of(wtxn, to_remove.iter())?;
+        let dictionary = self.index.document_decompression_dictionary(wtxn)?;
 
         for (internal_docid, external_docid) in to_remove.iter().zip(external_ids) {
             let external_docid = external_docid?;
@@ -479,6 +562,7 @@ impl<'a, 'i> Transform<'a, 'i> {
                 internal_docid,
                 external_docid,
                 wtxn,
+                dictionary.as_deref(),
                 &mut document_sorter_key_buffer,
                 &mut document_sorter_value_buffer,
             )?;
@@ -494,6 +578,7 @@ impl<'a, 'i> Transform<'a, 'i> {
         internal_docid: u32,
         external_docid: String,
         txn: &heed::RoTxn<'_>,
+        dictionary: Option<&DecoderDictionary<'_>>,
         document_sorter_key_buffer: &mut Vec<u8>,
         document_sorter_value_buffer: &mut Vec<u8>,
     ) -> Result<()> {
@@ -501,15 +586,13 @@ impl<'a, 'i> Transform<'a, 'i> {
 
         // fetch the obkv document
         let original_key = internal_docid;
-        let base_obkv = self
+        let base_document = self
             .index
             .documents
-            .remap_data_type::<heed::types::Bytes>()
             .get(txn, &original_key)?
-            .ok_or(InternalError::DatabaseMissingEntry {
-                db_name: db_name::DOCUMENTS,
-                key: None,
-            })?;
+            .ok_or(InternalError::DatabaseMissingEntry { db_name: db_name::DOCUMENTS, key: None })?
+            .decompress(dictionary)?;
+        let base_obkv = base_document.as_bytes();
 
         // Key is the concatenation of the internal docid and the external one.
         document_sorter_key_buffer.clear();
@@ -580,8 +663,15 @@ impl<'a, 'i> Transform<'a, 'i> {
                     process: "Flatten from fields ids map.",
                 })?;
 
-                let value = serde_json::from_slice::<Value>(value)
+                let mut value = serde_json::from_slice::<Value>(value)
                     .map_err(crate::error::InternalError::SerdeJson)?;
+                // A GeoJSON `Point` is normalized into the `{ lat, lng }` representation
+                // so that it gets flattened into the `_geo.lat` and `_geo.lng` fields.
+                if key == "_geo" {
+                    if let Some(lat_lng) = value.as_object().and_then(geojson_point_to_lat_lng) {
+                        value = Value::Object(lat_lng);
+                    }
+                }
                 doc.insert(key.to_string(), value);
             } else {
                 key_value.push((key, value.into()));
@@ -703,6 +793,8 @@ impl<'a, 'i> Transform<'a, 'i> {
         // 1. Remove all the deleted documents from the field distribution
         // 2. Add all the new documents to the field distribution
         let mut field_distribution = self.index.field_distribution(wtxn)?;
+        // The types of the values of the fields are counted the same way.
+        let mut fields_values = self.index.fields_values(wtxn)?;
 
         // Here we are going to do the document count + field distribution + `write_into_stream_writer`
         let mut iter = self.original_sorter.into_stream_merger_iter()?;
@@ -722,6 +814,19 @@ impl<'a, 'i> Transform<'a, 'i> {
 
             for (key, value) in KvReader::new(val) {
                 let reader = KvReaderDelAdd::new(value);
+                for operation in [DelAdd::Deletion, DelAdd::Addition] {
+                    if let Some(value) = reader.get(operation) {
+                        let name = self.fields_ids_map.name(key).ok_or(
+                            FieldIdMapMissingEntry::FieldId {
+                                field_id: key,
+                                process: "Computing fields values in transform.",
+                            },
+                        )?;
+                        let value: Value =
+                            serde_json::from_slice(value).map_err(InternalError::SerdeJson)?;
+                        count_field_value(&mut fields_values, name, &value, operation);
+                    }
+                }
                 match (reader.get(DelAdd::Deletion), reader.get(DelAdd::Addition)) {
                     (None, None) => {}
                     (None, Some(_)) => {
@@ -822,6 +927,7 @@ impl<'a, 'i> Transform<'a, 'i> {
             primary_key,
             settings_diff,
             field_distribution,
+            fields_values,
             documents_count: self.documents_count,
             original_documents: Some(
                 original_documents.into_inner().map_err(|err| err.into_error())?,
@@ -858,6 +964,19 @@ impl<'a, 'i> Transform<'a, 'i> {
                     .any(|long| is_faceted_by(long, field_name) || is_faceted_by(field_name, long))
         };
 
+        // Keep the field holding the language hint of the document when its searchable
+        // fields are reindexed, as it changes the way they are tokenized.
+        let must_reindex_searchables = settings_diff.reindex_searchable();
+        let language_hint_field = |id: FieldId| -> bool {
+            let field_name = settings_diff.new.fields_ids_map.name(id).unwrap();
+            must_reindex_searchables
+                && settings_diff
+                    .new
+                    .language_hint_field
+                    .as_deref()
+                    .map_or(false, |hint| is_faceted_by(hint, field_name))
+        };
+
         // Alway provide all fields when vectors are involved because
         // we need the fields for the prompt/templating.
         let reindex_vectors = settings_diff.reindex_vectors();
@@ -907,7 +1026,11 @@ impl<'a, 'i> Transform<'a, 'i> {
                 }
             }
 
-            if is_primary_key(id) || necessary_faceted_field(id) || reindex_vectors {
+            if is_primary_key(id)
+                || necessary_faceted_field(id)
+                || language_hint_field(id)
+                || reindex_vectors
+            {
                 operations.insert(id, DelAddOperation::DeletionAndAddition);
                 obkv_writer.insert(id, val)?;
             } else if let Some(operation) = settings_diff.reindex_searchable_id(id) {
@@ -971,6 +1094,7 @@ impl<'a, 'i> Transform<'a, 'i> {
             })?
             .to_string();
         let field_distribution = self.index.field_distribution(wtxn)?;
+        let fields_values = self.index.fields_values(wtxn)?;
 
         let documents_ids = self.index.documents_ids(wtxn)?;
         let documents_count = documents_ids.len() as usize;
@@ -1038,11 +1162,18 @@ impl<'a, 'i> Transform<'a, 'i> {
             let mut original_obkv_buffer = Vec::new();
             let mut flattened_obkv_buffer = Vec::new();
             let mut document_sorter_key_buffer = Vec::new();
+            let dictionary = self.index.document_decompression_dictionary(wtxn)?;
             for result in self.index.external_documents_ids().iter(wtxn)? {
                 let (external_id, docid) = result?;
-                let old_obkv = self.index.documents.get(wtxn, &docid)?.ok_or(
-                    InternalError::DatabaseMissingEntry { db_name: db_name::DOCUMENTS, key: None },
-                )?;
+                let old_document = self
+                    .index
+                    .documents
+                    .get(wtxn, &docid)?
+                    .ok_or(InternalError::DatabaseMissingEntry {
+                        db_name: db_name::DOCUMENTS,
+                        key: None,
+                    })?
+                    .decompress(dictionary.as_deref())?;
 
                 let injected_vectors: std::result::Result<
                     serde_json::Map<String, serde_json::Value>,
@@ -1083,7 +1214,7 @@ impl<'a, 'i> Transform<'a, 'i> {
                 let injected_vectors = injected_vectors?;
 
                 Self::rebind_existing_document(
-                    old_obkv,
+                    old_document.as_obkv(),
                     &settings_diff,
                     &modified_faceted_fields,
                     injected_vectors,
@@ -1141,6 +1272,7 @@ impl<'a, 'i> Transform<'a, 'i> {
         Ok(TransformOutput {
             primary_key,
             field_distribution,
+            fields_values,
             settings_diff,
             documents_count,
             original_documents: original_documents.map(|od| od.into_inner().into_inner()),
@@ -1160,6 +1292,31 @@ fn drop_and_reuse<U, T>(mut vec: Vec<U>) -> Vec<T> {
     vec.into_iter().map(|_| unreachable!()).collect()
 }
 
+/// Applies in order the transforms of an attribute to its serialized value.
+fn apply_attribute_transforms(
+    attribute: &str,
+    transforms: &[AttributeTransform],
+    document_id: &str,
+    value: &[u8],
+) -> Result<Vec<u8>> {
+    let mut value: Value = serde_json::from_slice(value).map_err(InternalError::SerdeJson)?;
+    for transform in transforms {
+        value = match transform.apply(value.clone()) {
+            Some(transformed) => transformed,
+            None => {
+                return Err(UserError::InvalidDocumentAttributeTransform {
+                    attribute: attribute.to_string(),
+                    transform: *transform,
+                    document_id: document_id.to_string(),
+                    value,
+                }
+                .into())
+            }
+        };
+    }
+    Ok(serde_json::to_vec(&value).map_err(InternalError::SerdeJson)?)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -1,11 +1,11 @@
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::convert::TryInto;
 use std::fs::File;
 use std::io::{self, BufReader};
 
 use bytemuck::allocation::pod_collect_to_vec;
 use grenad::{Merger, MergerBuilder};
-use heed::types::Bytes;
+use heed::types::{Bytes, DecodeIgnore};
 use heed::{BytesDecode, RwTxn};
 use obkv::{KvReader, KvWriter};
 use roaring::RoaringBitmap;
@@ -18,6 +18,7 @@ use super::helpers::{
 use super::MergeFn;
 use crate::external_documents_ids::{DocumentOperation, DocumentOperationKind};
 use crate::facet::FacetType;
+use crate::heed_codec::facet::FieldDocIdFacetStringCodec;
 use crate::index::db_name::DOCUMENTS;
 use crate::index::IndexEmbeddingConfig;
 use crate::proximity::MAX_DISTANCE;
@@ -160,6 +161,7 @@ pub(crate) fn write_typed_chunk_into_index(
                 .map(|IndexEmbeddingConfig { name, .. }| name)
                 .collect();
             let mut vectors_buffer = Vec::new();
+            let mut compressor = index.document_compressor(wtxn)?;
             while let Some((key, reader)) = iter.next()? {
                 let mut writer: KvWriter<_, FieldId> = KvWriter::memory();
                 let reader: KvReader<'_, FieldId> = KvReader::new(reader);
@@ -208,7 +210,13 @@ pub(crate) fn write_typed_chunk_into_index(
                 let db = index.documents.remap_data_type::<Bytes>();
 
                 if !writer.is_empty() {
-                    db.put(wtxn, &docid, &writer.into_inner().unwrap())?;
+                    let document = writer.into_inner().unwrap();
+                    match compressor.as_mut() {
+                        Some(compressor) => {
+                            db.put(wtxn, &docid, &compressor.compress(&document)?)?
+                        }
+                        None => db.put(wtxn, &docid, &document)?,
+                    }
                     operations.push(DocumentOperation {
                         external_id: external_id.to_string(),
                         internal_id: docid,
@@ -225,9 +233,20 @@ pub(crate) fn write_typed_chunk_into_index(
                     docids.remove(docid);
                 }
             }
+            let deleted = operations
+                .iter()
+                .filter(|op| matches!(op.kind, DocumentOperationKind::Delete))
+                .map(|op| (op.external_id.clone(), op.internal_id));
+            index.reserve_document_ids_of(wtxn, deleted)?;
+            let given_back = operations
+                .iter()
+                .filter(|op| matches!(op.kind, DocumentOperationKind::Create))
+                .map(|op| op.external_id.as_str());
+            index.release_reserved_document_ids(wtxn, given_back)?;
             let external_documents_docids = index.external_documents_ids();
             external_documents_docids.apply(wtxn, operations)?;
             index.put_documents_ids(wtxn, &docids)?;
+            index.refresh_blocked_documents_ids(wtxn)?;
         }
         TypedChunk::FieldIdWordCountDocids(_) => {
             let span =
@@ -393,8 +412,19 @@ pub(crate) fn write_typed_chunk_into_index(
                 normalized_facet_id_string_builder
                     .push(normalized_facet_id_string_docids.into_cursor()?);
             }
-            let facet_id_string_merger = facet_id_string_builder.build();
-            let normalized_facet_id_string_merger = normalized_facet_id_string_builder.build();
+            let mut facet_id_string_merger = facet_id_string_builder.build();
+            let mut normalized_facet_id_string_merger = normalized_facet_id_string_builder.build();
+
+            if let Some(max_values) = index.max_facet_values_per_attribute(wtxn)? {
+                (facet_id_string_merger, normalized_facet_id_string_merger, data_size) =
+                    cap_facet_string_values(
+                        wtxn,
+                        index,
+                        facet_id_string_merger,
+                        normalized_facet_id_string_merger,
+                        max_values,
+                    )?;
+            }
 
             let indexer = FacetsUpdate::new(
                 index,
@@ -561,11 +591,37 @@ pub(crate) fn write_typed_chunk_into_index(
 
             let index_fid_docid_facet_strings =
                 index.field_id_docid_facet_strings.remap_types::<Bytes, Bytes>();
+            // The number of documents gained or lost by each original form of the strings
+            // of the normalized facet fields, grouped by field id and normalized string.
+            let normalized_fids = settings_diff.new.normalized_facet_fields_ids();
+            let mut original_counts_diff =
+                BTreeMap::<(FieldId, String), HashMap<String, i64>>::new();
             let mut iter = merger.into_stream_merger_iter()?;
             while let Some((key, value)) = iter.next()? {
                 let reader = KvReaderDelAdd::new(value);
                 if valid_lmdb_key(key) {
-                    match (reader.get(DelAdd::Deletion), reader.get(DelAdd::Addition)) {
+                    let deletion = reader.get(DelAdd::Deletion);
+                    let addition = reader.get(DelAdd::Addition);
+                    let counted_key = FieldDocIdFacetStringCodec::bytes_decode(key)
+                        .ok()
+                        .filter(|(field_id, _, _)| normalized_fids.contains(field_id))
+                        .filter(|_| deletion.is_some() || addition.is_some());
+                    if let Some((field_id, _docid, normalized)) = counted_key {
+                        // the stored original is replaced or removed by this entry.
+                        let counts = original_counts_diff
+                            .entry((field_id, normalized.to_string()))
+                            .or_default();
+                        if let Some(previous) = index_fid_docid_facet_strings.get(wtxn, key)? {
+                            *counts
+                                .entry(std::str::from_utf8(previous)?.to_string())
+                                .or_default() -= 1;
+                        }
+                        if let Some(new) = addition {
+                            *counts.entry(std::str::from_utf8(new)?.to_string()).or_default() += 1;
+                        }
+                    }
+
+                    match (deletion, addition) {
                         (None, None) => {}
                         (None, Some(new)) => index_fid_docid_facet_strings.put(wtxn, key, new)?,
                         (Some(_), None) => {
@@ -577,6 +633,22 @@ pub(crate) fn write_typed_chunk_into_index(
                     }
                 }
             }
+
+            for ((field_id, normalized), diff) in original_counts_diff {
+                let key = (field_id, normalized.as_str());
+                let mut counts =
+                    index.facet_id_string_original_counts.get(wtxn, &key)?.unwrap_or_default();
+                for (original, diff) in diff {
+                    let count = counts.entry(original).or_default();
+                    *count = count.saturating_add_signed(diff);
+                }
+                counts.retain(|_original, count| *count > 0);
+                if counts.is_empty() {
+                    index.facet_id_string_original_counts.delete(wtxn, &key)?;
+                } else {
+                    index.facet_id_string_original_counts.put(wtxn, &key, &counts)?;
+                }
+            }
         }
         TypedChunk::GeoPoints(_) => {
             let span = tracing::trace_span!(target: "indexing::write_db", "geo_points");
@@ -786,6 +858,148 @@ fn extract_geo_point(value: &[u8], docid: DocumentId) -> GeoPoint {
     GeoPoint::new(xyz_point, (docid, point))
 }
 
+/// Drops the new string facet values of the attributes that already reached the maximum number
+/// of facet values per attribute and records these attributes as capped in the index.
+///
+/// Returns the filtered facet values along with their count.
+fn cap_facet_string_values(
+    wtxn: &mut RwTxn<'_>,
+    index: &Index,
+    facet_id_string_merger: Merger<BufReader<File>, MergeFn>,
+    normalized_facet_id_string_merger: Merger<BufReader<File>, MergeFn>,
+    max_values: u64,
+) -> Result<(Merger<BufReader<File>, MergeFn>, Merger<BufReader<File>, MergeFn>, u64)> {
+    let facet_db = index.facet_id_string_docids.remap_types::<Bytes, DecodeIgnore>();
+    let normalized_db =
+        index.facet_id_normalized_string_strings.remap_types::<Bytes, DecodeIgnore>();
+
+    let mut values_count: HashMap<FieldId, u64> = HashMap::new();
+    let mut capped_fields_ids = BTreeSet::new();
+    let mut data_size = 0;
+
+    let mut writer =
+        helpers::create_writer(grenad::CompressionType::None, None, tempfile::tempfile()?);
+    let mut iter = facet_id_string_merger.into_stream_merger_iter()?;
+    while let Some((key, value)) = iter.next()? {
+        let (field_id_bytes, _) = try_split_array_at(key).unwrap();
+        let field_id = FieldId::from_be_bytes(field_id_bytes);
+
+        // The facet values already in the database and the deletions are always kept.
+        let is_new_value = facet_db.get(wtxn, key)?.is_none()
+            && KvReaderDelAdd::new(value).get(DelAdd::Addition).is_some();
+        if is_new_value {
+            let count = match values_count.entry(field_id) {
+                std::collections::hash_map::Entry::Occupied(entry) => entry.into_mut(),
+                std::collections::hash_map::Entry::Vacant(entry) => {
+                    // the level 0 of the tree contains one key per facet value.
+                    let mut prefix = field_id.to_be_bytes().to_vec();
+                    prefix.push(0);
+                    let count = facet_db.prefix_iter(wtxn, &prefix)?.count() as u64;
+                    entry.insert(count)
+                }
+            };
+
+            if *count >= max_values {
+                capped_fields_ids.insert(field_id);
+                continue;
+            }
+            *count += 1;
+        }
+
+        writer.insert(key, value)?;
+        data_size += 1;
+    }
+
+    let mut normalized_writer =
+        helpers::create_writer(grenad::CompressionType::None, None, tempfile::tempfile()?);
+    let mut iter = normalized_facet_id_string_merger.into_stream_merger_iter()?;
+    while let Some((key, value)) = iter.next()? {
+        let (field_id_bytes, _) = try_split_array_at(key).unwrap();
+        let field_id = FieldId::from_be_bytes(field_id_bytes);
+
+        // We do not make the new facet values of the capped attributes searchable.
+        if capped_fields_ids.contains(&field_id) && normalized_db.get(wtxn, key)?.is_none() {
+            continue;
+        }
+
+        normalized_writer.insert(key, value)?;
+    }
+
+    if !capped_fields_ids.is_empty() {
+        let fields_ids_map = index.fields_ids_map(wtxn)?;
+        let mut capped_fields = index.capped_faceted_fields(wtxn)?;
+        for field_id in capped_fields_ids {
+            if let Some(name) = fields_ids_map.name(field_id) {
+                if capped_fields.insert(name.to_string()) {
+                    tracing::warn!(
+                        attribute = name,
+                        max_values,
+                        "The attribute reached the maximum number of facet values, its new facet values are no longer indexed"
+                    );
+                }
+            }
+        }
+        index.put_capped_faceted_fields(wtxn, &capped_fields)?;
+    }
+
+    let mut builder = MergerBuilder::new(merge_deladd_cbo_roaring_bitmaps as MergeFn);
+    builder.push(helpers::writer_into_reader(writer)?.into_cursor()?);
+    let mut normalized_builder = MergerBuilder::new(merge_deladd_btreeset_string as MergeFn);
+    normalized_builder.push(helpers::writer_into_reader(normalized_writer)?.into_cursor()?);
+
+    Ok((builder.build(), normalized_builder.build(), data_size))
+}
+
+/// Removes the string facet values of the given documents that were dropped by
+/// [`cap_facet_string_values`] from the facet values of the documents, so that the
+/// facet distribution computed from the documents doesn't return them either.
+///
+/// Only the capped attributes of the given documents are scanned.
+pub(crate) fn remove_capped_facet_string_values(
+    wtxn: &mut RwTxn<'_>,
+    index: &Index,
+    documents_ids: &RoaringBitmap,
+) -> Result<()> {
+    let capped_fields = index.capped_faceted_fields(wtxn)?;
+    if capped_fields.is_empty() || documents_ids.is_empty() {
+        return Ok(());
+    }
+
+    let fields_ids_map = index.fields_ids_map(wtxn)?;
+    let facet_db = index.facet_id_string_docids.remap_types::<Bytes, DecodeIgnore>();
+    let docid_facet_db = index.field_id_docid_facet_strings.remap_types::<Bytes, DecodeIgnore>();
+
+    let mut prefix = Vec::new();
+    let mut facet_key = Vec::new();
+    let mut capped_keys = Vec::new();
+    for field_id in capped_fields.iter().filter_map(|name| fields_ids_map.id(name)) {
+        for docid in documents_ids {
+            prefix.clear();
+            prefix.extend_from_slice(&field_id.to_be_bytes());
+            prefix.extend_from_slice(&docid.to_be_bytes());
+
+            for result in docid_facet_db.prefix_iter(wtxn, &prefix)? {
+                let (key, ()) = result?;
+                let normalized = &key[prefix.len()..];
+                // the level 0 of the tree contains one key per facet value.
+                facet_key.clear();
+                facet_key.extend_from_slice(&field_id.to_be_bytes());
+                facet_key.push(0);
+                facet_key.extend_from_slice(normalized);
+                if facet_db.get(wtxn, &facet_key)?.is_none() {
+                    capped_keys.push(key.to_vec());
+                }
+            }
+
+            for key in capped_keys.drain(..) {
+                docid_facet_db.delete(wtxn, &key)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
 fn merge_word_docids_reader_into_fst(
     merger: Merger<CursorClonableMmap, MergeFn>,
 ) -> Result<fst::Set<Vec<u8>>> {
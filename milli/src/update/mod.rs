@@ -4,10 +4,15 @@ pub use self::facet::bulk::FacetsUpdateBulk;
 pub use self::facet::incremental::FacetsUpdateIncrementalInner;
 pub use self::index_documents::{
     merge_cbo_roaring_bitmaps, merge_roaring_bitmaps, DocumentAdditionResult, DocumentId,
-    IndexDocuments, IndexDocumentsConfig, IndexDocumentsMethod, MergeFn,
+    DuplicateKeyStrategy, IndexDocuments, IndexDocumentsConfig, IndexDocumentsMethod, MergeFn,
 };
 pub use self::indexer_config::IndexerConfig;
-pub use self::settings::{validate_embedding_settings, Setting, Settings};
+pub use self::rebuild_database::{DatabaseKind, RebuildDatabase};
+pub use self::recompress_documents::RecompressDocuments;
+pub use self::settings::{
+    estimate_settings_update, validate_embedding_settings, Setting, Settings,
+    SettingsUpdateEstimate,
+};
 pub use self::update_step::UpdateIndexingStep;
 pub use self::word_prefix_docids::WordPrefixDocids;
 pub use self::words_prefix_integer_docids::WordPrefixIntegerDocids;
@@ -19,6 +24,8 @@ pub(crate) mod del_add;
 pub(crate) mod facet;
 mod index_documents;
 mod indexer_config;
+mod rebuild_database;
+mod recompress_documents;
 mod settings;
 mod update_step;
 mod word_prefix_docids;
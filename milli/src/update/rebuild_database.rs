@@ -0,0 +1,364 @@
+use std::collections::BTreeMap;
+
+use grenad::MergerBuilder;
+use heed::RwTxn;
+
+use super::index_documents::{
+    merge_deladd_cbo_roaring_bitmaps, IndexDocumentsConfig, IndexDocumentsMethod, MergeFn,
+    Transform,
+};
+use super::settings::{InnerIndexSettings, InnerIndexSettingsDiff};
+use super::{IndexDocuments, IndexerConfig};
+use crate::proximity::ProximityPrecision;
+use crate::{Index, Result};
+
+/// The auxiliary databases that can be rebuilt without reindexing the whole index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DatabaseKind {
+    /// The facet databases, including the geo datastructures, extracted from the stored documents.
+    Facets,
+    /// The words prefixes fst and the prefix databases, derived from the word databases.
+    Prefixes,
+    /// The word pair proximity database, extracted from the searchable fields of the stored documents.
+    Proximity,
+}
+
+/// Clears an auxiliary database and recomputes it from scratch.
+///
+/// The other databases are left untouched, the result is the same as the one of a full reindex.
+pub struct RebuildDatabase<'t, 'i, 'a> {
+    wtxn: &'t mut RwTxn<'i>,
+    index: &'i Index,
+    indexer_config: &'a IndexerConfig,
+}
+
+impl<'t, 'i, 'a> RebuildDatabase<'t, 'i, 'a> {
+    pub fn new(
+        wtxn: &'t mut RwTxn<'i>,
+        index: &'i Index,
+        indexer_config: &'a IndexerConfig,
+    ) -> RebuildDatabase<'t, 'i, 'a> {
+        RebuildDatabase { wtxn, index, indexer_config }
+    }
+
+    #[tracing::instrument(
+        level = "trace",
+        skip(self),
+        target = "indexing::documents",
+        name = "rebuild_database"
+    )]
+    pub fn execute(self, which: DatabaseKind) -> Result<()> {
+        match which {
+            DatabaseKind::Facets => self.rebuild_facets(),
+            DatabaseKind::Prefixes => self.rebuild_prefixes(),
+            DatabaseKind::Proximity => self.rebuild_proximity(),
+        }
+    }
+
+    fn rebuild_facets(self) -> Result<()> {
+        let Index {
+            facet_id_f64_docids,
+            facet_id_string_docids,
+            facet_id_normalized_string_strings,
+            facet_id_string_fst,
+            facet_id_string_original_counts,
+            facet_id_exists_docids,
+            facet_id_is_null_docids,
+            facet_id_is_empty_docids,
+            field_id_docid_facet_f64s,
+            field_id_docid_facet_strings,
+            ..
+        } = self.index;
+
+        self.index.delete_geo_rtree(self.wtxn)?;
+        self.index.delete_geo_faceted_documents_ids(self.wtxn)?;
+        facet_id_f64_docids.clear(self.wtxn)?;
+        facet_id_string_docids.clear(self.wtxn)?;
+        facet_id_normalized_string_strings.clear(self.wtxn)?;
+        facet_id_string_fst.clear(self.wtxn)?;
+        facet_id_string_original_counts.clear(self.wtxn)?;
+        facet_id_exists_docids.clear(self.wtxn)?;
+        facet_id_is_null_docids.clear(self.wtxn)?;
+        facet_id_is_empty_docids.clear(self.wtxn)?;
+        field_id_docid_facet_f64s.clear(self.wtxn)?;
+        field_id_docid_facet_strings.clear(self.wtxn)?;
+
+        // The documents are reindexed as if none of their fields were faceted before,
+        // so that only the additions of the facet values are written in the cleared databases.
+        let mut new_settings = InnerIndexSettings::from_index(self.index, self.wtxn)?;
+        new_settings.recompute_facets(self.wtxn, self.index)?;
+        let mut old_settings = new_settings.clone();
+        old_settings.user_defined_faceted_fields.clear();
+        old_settings.faceted_fields_ids.clear();
+        old_settings.geo_fields_ids = None;
+
+        self.reindex(old_settings, new_settings)
+    }
+
+    fn rebuild_prefixes(self) -> Result<()> {
+        let Index {
+            word_prefix_docids,
+            exact_word_prefix_docids,
+            word_prefix_position_docids,
+            word_prefix_fid_docids,
+            ..
+        } = self.index;
+
+        self.index.put_words_prefixes_fst(self.wtxn, &fst::Set::default())?;
+        word_prefix_docids.clear(self.wtxn)?;
+        exact_word_prefix_docids.clear(self.wtxn)?;
+        word_prefix_position_docids.clear(self.wtxn)?;
+        word_prefix_fid_docids.clear(self.wtxn)?;
+
+        // As the previous words prefixes fst is empty, all the prefixes are considered new
+        // and their documents ids are fetched from the word databases: no new word is merged.
+        let no_new_words =
+            || Some(MergerBuilder::new(merge_deladd_cbo_roaring_bitmaps as MergeFn).build());
+        let indexing_builder = IndexDocuments::new(
+            self.wtxn,
+            self.index,
+            self.indexer_config,
+            IndexDocumentsConfig::default(),
+            |_| (),
+            || false,
+        )?;
+        indexing_builder.execute_prefix_databases(
+            no_new_words(),
+            no_new_words(),
+            no_new_words(),
+            no_new_words(),
+        )
+    }
+
+    fn rebuild_proximity(self) -> Result<()> {
+        self.index.word_pair_proximity_docids.clear(self.wtxn)?;
+
+        let new_settings = InnerIndexSettings::from_index(self.index, self.wtxn)?;
+        // the database is not filled when the proximities are computed by attribute
+        if new_settings.proximity_precision != ProximityPrecision::ByWord {
+            return Ok(());
+        }
+
+        // The documents are reindexed as if no proximity were stored before, so that only
+        // the additions are written in the cleared database. The words extracted from the
+        // searchable fields are identical on both sides and leave the other databases unchanged.
+        let mut old_settings = new_settings.clone();
+        old_settings.proximity_precision = ProximityPrecision::ByAttribute;
+
+        self.reindex(old_settings, new_settings)
+    }
+
+    fn reindex(
+        self,
+        old_settings: InnerIndexSettings,
+        new_settings: InnerIndexSettings,
+    ) -> Result<()> {
+        if self.index.number_of_documents(self.wtxn)? == 0 {
+            return Ok(());
+        }
+
+        let primary_key_id = self
+            .index
+            .primary_key(self.wtxn)?
+            .and_then(|name| new_settings.fields_ids_map.id(name));
+        let settings_diff = InnerIndexSettingsDiff::new(
+            old_settings,
+            new_settings,
+            primary_key_id,
+            BTreeMap::new(),
+            true,
+        );
+
+        let transform = Transform::new(
+            self.wtxn,
+            self.index,
+            self.indexer_config,
+            IndexDocumentsMethod::ReplaceDocuments,
+            false,
+        )?;
+        let output = transform.prepare_for_documents_reindexing(self.wtxn, settings_diff)?;
+
+        let indexing_builder = IndexDocuments::new(
+            self.wtxn,
+            self.index,
+            self.indexer_config,
+            IndexDocumentsConfig::default(),
+            |_| (),
+            || false,
+        )?;
+        indexing_builder.execute_raw(output)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use big_s::S;
+    use heed::types::Bytes;
+    use heed::Database;
+    use maplit::hashset;
+
+    use super::*;
+    use crate::documents::documents_batch_reader_from_objects;
+    use crate::index::tests::TempIndex;
+    use crate::{Filter, Search};
+
+    type Entries = Vec<(Vec<u8>, Vec<u8>)>;
+
+    fn entries<K, V>(index: &Index, database: Database<K, V>) -> Entries {
+        let rtxn = index.read_txn().unwrap();
+        database
+            .remap_types::<Bytes, Bytes>()
+            .iter(&rtxn)
+            .unwrap()
+            .map(|entry| {
+                let (key, value) = entry.unwrap();
+                (key.to_vec(), value.to_vec())
+            })
+            .collect()
+    }
+
+    fn filter(index: &Index, filter: &str) -> Vec<u32> {
+        let rtxn = index.read_txn().unwrap();
+        let mut search = Search::new(&rtxn, index);
+        search.filter(Filter::from_str(filter).unwrap().unwrap());
+        search.execute().unwrap().documents_ids
+    }
+
+    fn rebuild(index: &TempIndex, which: DatabaseKind) {
+        let mut wtxn = index.write_txn().unwrap();
+        index.rebuild(&mut wtxn, &index.indexer_config, which).unwrap();
+        wtxn.commit().unwrap();
+    }
+
+    #[test]
+    fn rebuild_facets() {
+        let index = TempIndex::new();
+        index
+            .update_settings(|settings| {
+                settings.set_filterable_fields(hashset! { S("genre"), S("price"), S("_geo") });
+            })
+            .unwrap();
+        index
+            .add_documents(documents!([
+                { "id": 0, "title": "the hobbit", "genre": "fantasy", "price": 10 },
+                { "id": 1, "title": "dune", "genre": "science fiction", "price": 12.5 },
+                { "id": 2, "title": "the silmarillion", "genre": ["fantasy", "tales"], "price": null },
+                { "id": 3, "title": "carte", "genre": [], "_geo": { "lat": 45.7, "lng": 4.8 } },
+            ]))
+            .unwrap();
+
+        let facet_databases = |index: &TempIndex| {
+            vec![
+                entries(index, index.facet_id_f64_docids),
+                entries(index, index.facet_id_string_docids),
+                entries(index, index.facet_id_normalized_string_strings),
+                entries(index, index.facet_id_string_fst),
+                entries(index, index.facet_id_string_original_counts),
+                entries(index, index.facet_id_exists_docids),
+                entries(index, index.facet_id_is_null_docids),
+                entries(index, index.facet_id_is_empty_docids),
+                entries(index, index.field_id_docid_facet_f64s),
+                entries(index, index.field_id_docid_facet_strings),
+            ]
+        };
+        let other_databases = |index: &TempIndex| {
+            vec![
+                entries(index, index.word_docids),
+                entries(index, index.word_fid_docids),
+                entries(index, index.word_pair_proximity_docids),
+                entries(index, index.documents),
+            ]
+        };
+        let facets = facet_databases(&index);
+        let others = other_databases(&index);
+
+        // corrupt the facet databases
+        let mut wtxn = index.write_txn().unwrap();
+        index.facet_id_string_docids.clear(&mut wtxn).unwrap();
+        index.facet_id_exists_docids.clear(&mut wtxn).unwrap();
+        index.facet_id_f64_docids.remap_types::<Bytes, Bytes>().put(&mut wtxn, b"\0", b"").unwrap();
+        index.delete_geo_faceted_documents_ids(&mut wtxn).unwrap();
+        wtxn.commit().unwrap();
+        assert_eq!(filter(&index, "genre = fantasy"), Vec::<u32>::new());
+        assert_eq!(filter(&index, "genre EXISTS"), Vec::<u32>::new());
+
+        rebuild(&index, DatabaseKind::Facets);
+
+        assert_eq!(filter(&index, "genre = fantasy"), vec![0, 2]);
+        assert_eq!(filter(&index, "genre EXISTS"), vec![0, 1, 2, 3]);
+        assert_eq!(filter(&index, "price > 11"), vec![1]);
+        assert_eq!(filter(&index, "genre IS EMPTY"), vec![3]);
+        assert_eq!(filter(&index, "_geoRadius(45.7, 4.8, 100)"), vec![3]);
+        // the rebuilt databases are identical to the ones built by the indexing
+        assert_eq!(facet_databases(&index), facets);
+        assert_eq!(other_databases(&index), others);
+    }
+
+    #[test]
+    fn rebuild_prefixes() {
+        let index = TempIndex::new();
+
+        // enough words share the same prefixes for them to be part of the prefix databases
+        let documents = (0..120u8).map(|i| {
+            let word = format!("abc{}{}", (b'a' + i / 26) as char, (b'a' + i % 26) as char);
+            serde_json::json!({ "id": i, "text": word }).as_object().unwrap().clone()
+        });
+        index.add_documents(documents_batch_reader_from_objects(documents)).unwrap();
+
+        let prefix_databases = |index: &TempIndex| {
+            vec![
+                entries(index, index.word_prefix_docids),
+                entries(index, index.exact_word_prefix_docids),
+                entries(index, index.word_prefix_position_docids),
+                entries(index, index.word_prefix_fid_docids),
+            ]
+        };
+        let prefixes = prefix_databases(&index);
+        let words_prefixes = |index: &TempIndex| {
+            let rtxn = index.read_txn().unwrap();
+            index.words_prefixes_fst(&rtxn).unwrap().into_stream().into_strs().unwrap()
+        };
+        let prefixes_fst_words = words_prefixes(&index);
+        assert!(prefixes_fst_words.contains(&S("abc")));
+        let word_docids = entries(&index, index.word_docids);
+
+        let mut wtxn = index.write_txn().unwrap();
+        index.word_prefix_docids.clear(&mut wtxn).unwrap();
+        index.word_prefix_fid_docids.clear(&mut wtxn).unwrap();
+        wtxn.commit().unwrap();
+
+        rebuild(&index, DatabaseKind::Prefixes);
+
+        assert_eq!(words_prefixes(&index), prefixes_fst_words);
+        assert_eq!(prefix_databases(&index), prefixes);
+        assert_eq!(entries(&index, index.word_docids), word_docids);
+    }
+
+    #[test]
+    fn rebuild_proximity() {
+        let index = TempIndex::new();
+        index
+            .add_documents(documents!([
+                { "id": 0, "title": "the quick brown fox", "text": "jumps over the lazy dog" },
+                { "id": 1, "title": "the lazy fox", "text": "sleeps" },
+            ]))
+            .unwrap();
+
+        let proximities = entries(&index, index.word_pair_proximity_docids);
+        assert!(!proximities.is_empty());
+        let word_docids = entries(&index, index.word_docids);
+        let word_position_docids = entries(&index, index.word_position_docids);
+
+        let mut wtxn = index.write_txn().unwrap();
+        index.word_pair_proximity_docids.clear(&mut wtxn).unwrap();
+        wtxn.commit().unwrap();
+
+        rebuild(&index, DatabaseKind::Proximity);
+
+        assert_eq!(entries(&index, index.word_pair_proximity_docids), proximities);
+        assert_eq!(entries(&index, index.word_docids), word_docids);
+        assert_eq!(entries(&index, index.word_position_docids), word_position_docids);
+    }
+}
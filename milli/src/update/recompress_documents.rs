@@ -0,0 +1,226 @@
+use heed::{RoTxn, RwTxn};
+use roaring::RoaringBitmap;
+use zstd::dict::DecoderDictionary;
+
+use crate::error::UserError;
+use crate::heed_codec::DocumentCompressor;
+use crate::{Index, Result};
+
+/// The maximum number of documents the dictionary is trained on.
+const DICTIONARY_MAX_SAMPLES: u64 = 10_000;
+/// The maximum size of the dictionary, the one recommended by zstd.
+const DICTIONARY_MAX_SIZE: usize = 110 * 1024;
+
+/// Rewrites all the stored documents, compressed with a new dictionary when the document
+/// compression is enabled, raw otherwise.
+///
+/// The documents written since the compression was enabled are compressed with the
+/// previous dictionary, or without any, this is the only way to train one on them.
+pub struct RecompressDocuments<'t, 'i> {
+    wtxn: &'t mut RwTxn<'i>,
+    index: &'i Index,
+}
+
+impl<'t, 'i> RecompressDocuments<'t, 'i> {
+    pub fn new(wtxn: &'t mut RwTxn<'i>, index: &'i Index) -> RecompressDocuments<'t, 'i> {
+        RecompressDocuments { wtxn, index }
+    }
+
+    #[tracing::instrument(
+        level = "trace",
+        skip(self),
+        target = "indexing::documents",
+        name = "recompress_documents"
+    )]
+    pub fn execute(self) -> Result<()> {
+        let documents_ids = self.index.documents_ids(self.wtxn)?;
+        let old_dictionary = self.index.document_decompression_dictionary(self.wtxn)?;
+
+        let compression = self.index.document_compression(self.wtxn)?;
+        let new_dictionary = if compression {
+            train_dictionary(self.index, self.wtxn, &documents_ids, old_dictionary.as_deref())?
+        } else {
+            None
+        };
+        let mut compressor = match compression {
+            true => Some(DocumentCompressor::new(new_dictionary.as_deref())?),
+            false => None,
+        };
+
+        for docid in &documents_ids {
+            let document = self
+                .index
+                .documents
+                .get(self.wtxn, &docid)?
+                .ok_or(UserError::UnknownInternalDocumentId { document_id: docid })?
+                .decompress(old_dictionary.as_deref())?
+                .into_bytes();
+            match compressor.as_mut() {
+                Some(compressor) => {
+                    let document = compressor.compress(&document)?;
+                    self.index.documents.put(self.wtxn, &docid, &document)?;
+                }
+                None => self.index.documents.put(self.wtxn, &docid, &document)?,
+            }
+        }
+
+        match new_dictionary {
+            Some(dictionary) => {
+                self.index.put_document_compression_dictionary(self.wtxn, &dictionary)?
+            }
+            None => {
+                self.index.delete_document_compression_dictionary(self.wtxn)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Trains a dictionary on documents spread over the whole index.
+///
+/// Returns `None` when zstd can't train a dictionary on the documents, because there are too
+/// few of them or they are too small, they are then compressed without one.
+fn train_dictionary(
+    index: &Index,
+    rtxn: &RoTxn<'_>,
+    documents_ids: &RoaringBitmap,
+    dictionary: Option<&DecoderDictionary<'_>>,
+) -> Result<Option<Vec<u8>>> {
+    let step = (documents_ids.len() / DICTIONARY_MAX_SAMPLES).max(1) as usize;
+    let mut samples = Vec::new();
+    for docid in documents_ids.iter().step_by(step).take(DICTIONARY_MAX_SAMPLES as usize) {
+        let document = index
+            .documents
+            .get(rtxn, &docid)?
+            .ok_or(UserError::UnknownInternalDocumentId { document_id: docid })?
+            .decompress(dictionary)?;
+        samples.push(document.into_bytes());
+    }
+
+    Ok(zstd::dict::from_samples(&samples, DICTIONARY_MAX_SIZE).ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::documents::{documents_batch_reader_from_objects, DocumentsBatchReader};
+    use crate::index::tests::TempIndex;
+    use crate::{all_obkv_to_json, Object, Search};
+
+    fn documents(index: &Index) -> Vec<Object> {
+        let rtxn = index.read_txn().unwrap();
+        let fields_ids_map = index.fields_ids_map(&rtxn).unwrap();
+        index
+            .all_documents(&rtxn)
+            .unwrap()
+            .map(|result| all_obkv_to_json(result.unwrap().1.as_obkv(), &fields_ids_map).unwrap())
+            .collect()
+    }
+
+    fn compressed_documents(index: &Index) -> Vec<bool> {
+        let rtxn = index.read_txn().unwrap();
+        index
+            .documents
+            .iter(&rtxn)
+            .unwrap()
+            .map(|result| result.unwrap().1.is_compressed())
+            .collect()
+    }
+
+    fn etag_of(index: &Index, docid: u32) -> String {
+        let rtxn = index.read_txn().unwrap();
+        let dictionary = index.document_decompression_dictionary(&rtxn).unwrap();
+        index.document_etag(&rtxn, docid, dictionary.as_deref()).unwrap().unwrap()
+    }
+
+    fn recompress(index: &TempIndex) {
+        let mut wtxn = index.write_txn().unwrap();
+        index.recompress_documents(&mut wtxn).unwrap();
+        wtxn.commit().unwrap();
+    }
+
+    /// Documents sharing most of their content, like the ones a dictionary is useful for.
+    fn books(count: u32) -> DocumentsBatchReader<std::io::Cursor<Vec<u8>>> {
+        let documents = (0..count).map(|id| {
+            serde_json::json!({
+                "id": id,
+                "title": format!("the {id}th book of the long series of the same author"),
+                "description": "a long description repeated in every document of the series, \
+                    telling the story of a hero travelling through many lands",
+            })
+            .as_object()
+            .unwrap()
+            .clone()
+        });
+        documents_batch_reader_from_objects(documents)
+    }
+
+    #[test]
+    fn compress_new_documents() {
+        let index = TempIndex::new();
+        index.update_settings(|settings| settings.set_document_compression(true)).unwrap();
+        index.add_documents(books(10)).unwrap();
+
+        // compressed without a dictionary until they are recompressed
+        assert!(compressed_documents(&index).into_iter().all(|compressed| compressed));
+        let rtxn = index.read_txn().unwrap();
+        assert!(index.document_compression_dictionary(&rtxn).unwrap().is_none());
+        drop(rtxn);
+
+        let raw_index = TempIndex::new();
+        raw_index.add_documents(books(10)).unwrap();
+        assert!(compressed_documents(&raw_index).into_iter().all(|compressed| !compressed));
+        assert_eq!(documents(&index), documents(&raw_index));
+
+        let rtxn = index.read_txn().unwrap();
+        let mut search = Search::new(&rtxn, &index);
+        search.query("hero");
+        assert_eq!(search.execute().unwrap().documents_ids.len(), 10);
+    }
+
+    #[test]
+    fn recompress_existing_documents() {
+        let index = TempIndex::new();
+        index.add_documents(books(1000)).unwrap();
+        let raw_documents = documents(&index);
+        let etag = etag_of(&index, 0);
+
+        // enabling the compression rewrites the stored documents
+        index.update_settings(|settings| settings.set_document_compression(true)).unwrap();
+        assert!(compressed_documents(&index).into_iter().all(|compressed| compressed));
+        assert_eq!(documents(&index), raw_documents);
+        assert_eq!(etag_of(&index, 0), etag);
+        let rtxn = index.read_txn().unwrap();
+        assert!(index.document_compression_dictionary(&rtxn).unwrap().is_some());
+        drop(rtxn);
+
+        // the new documents are compressed with the dictionary of the existing ones, if any
+        index
+            .add_documents(documents!([{ "id": 1000, "title": "the last book of the series" }]))
+            .unwrap();
+        assert_eq!(documents(&index).len(), 1001);
+        recompress(&index);
+        assert!(compressed_documents(&index).into_iter().all(|compressed| compressed));
+
+        // disabling the compression rewrites them raw
+        index.update_settings(|settings| settings.reset_document_compression()).unwrap();
+        assert!(compressed_documents(&index).into_iter().all(|compressed| !compressed));
+        let rtxn = index.read_txn().unwrap();
+        assert!(index.document_compression_dictionary(&rtxn).unwrap().is_none());
+        drop(rtxn);
+        assert_eq!(etag_of(&index, 0), etag);
+        assert_eq!(&documents(&index)[..1000], &raw_documents[..]);
+    }
+
+    #[test]
+    fn store_small_documents_raw() {
+        let index = TempIndex::new();
+        index.update_settings(|settings| settings.set_document_compression(true)).unwrap();
+        index.add_documents(documents!([{ "id": 0 }])).unwrap();
+
+        // compressing a tiny document makes it bigger
+        assert_eq!(compressed_documents(&index), vec![false]);
+        assert_eq!(documents(&index).len(), 1);
+    }
+}
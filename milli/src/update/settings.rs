@@ -3,32 +3,48 @@ use std::convert::TryInto;
 use std::result::Result as StdResult;
 use std::sync::Arc;
 
-use charabia::{Normalize, Tokenizer, TokenizerBuilder};
+use charabia::{Language, Normalize, Tokenizer, TokenizerBuilder};
 use deserr::{DeserializeError, Deserr};
+use heed::types::Bytes;
 use itertools::{EitherOrBoth, Itertools};
 use roaring::RoaringBitmap;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use time::OffsetDateTime;
+use url::Url;
 
 use super::del_add::DelAddOperation;
 use super::index_documents::{IndexDocumentsConfig, Transform};
 use super::IndexerConfig;
+use crate::attribute_transform::AttributeTransform;
+use crate::content_extractor::ContentType;
 use crate::criterion::Criterion;
 use crate::error::UserError;
+use crate::heed_codec::facet::FieldDocIdFacetStringCodec;
 use crate::index::{
-    IndexEmbeddingConfig, DEFAULT_MIN_WORD_LEN_ONE_TYPO, DEFAULT_MIN_WORD_LEN_TWO_TYPOS,
+    db_name, IndexEmbeddingConfig, DEFAULT_MIN_WORD_LEN_ONE_TYPO, DEFAULT_MIN_WORD_LEN_TWO_TYPOS,
 };
 use crate::order_by_map::OrderByMap;
+use crate::prefix_search::AttributePrefixSearch;
 use crate::proximity::ProximityPrecision;
+use crate::reranker::{RerankerSettings, MAX_RERANKER_CANDIDATES};
+use crate::tokenizer_profile::{NumberTokenization, TokenizerProfile};
 use crate::update::index_documents::IndexDocumentsMethod;
-use crate::update::{IndexDocuments, UpdateIndexingStep};
+use crate::update::{
+    DatabaseKind, DuplicateKeyStrategy, IndexDocuments, RebuildDatabase, UpdateIndexingStep,
+};
 use crate::vector::parsed_vectors::RESERVED_VECTORS_FIELD_NAME;
 use crate::vector::settings::{
     check_set, check_unset, EmbedderAction, EmbedderSource, EmbeddingSettings, ReindexAction,
     WriteBackToDocuments,
 };
 use crate::vector::{Embedder, EmbeddingConfig, EmbeddingConfigs};
-use crate::{FieldId, FieldsIdsMap, Index, LocalizedAttributesRule, LocalizedFieldIds, Result};
+use crate::{
+    FacetNormalization, FieldId, FieldsIdsMap, Index, LocalizedAttributesRule, LocalizedFieldIds,
+    PinnedResultsRule, Result, Stemmer,
+};
+
+/// The maximum size, in bytes, of the serialized metadata of an index.
+const MAX_USER_METADATA_SIZE: usize = 64 * 1024;
 
 #[derive(Debug, Clone, PartialEq, Eq, Copy)]
 pub enum Setting<T> {
@@ -152,21 +168,66 @@ pub struct Settings<'a, 't, 'i> {
     separator_tokens: Setting<BTreeSet<String>>,
     dictionary: Setting<BTreeSet<String>>,
     distinct_field: Setting<String>,
+    recency_field: Setting<String>,
     synonyms: Setting<BTreeMap<String, Vec<String>>>,
     primary_key: Setting<String>,
     authorize_typos: Setting<bool>,
+    split_and_concat_words: Setting<bool>,
+    read_only: Setting<bool>,
     min_word_len_two_typos: Setting<u8>,
+    typo_penalty: Setting<u8>,
     min_word_len_one_typo: Setting<u8>,
     exact_words: Setting<BTreeSet<String>>,
     /// Attributes on which typo tolerance is disabled.
     exact_attributes: Setting<HashSet<String>>,
+    /// Attributes that are stored in the documents but never indexed.
+    ignored_attributes: Setting<HashSet<String>>,
+    /// The format of the attributes whose markup must be removed before being indexed.
+    content_types: Setting<BTreeMap<String, ContentType>>,
+    tokenizer_profiles: Setting<BTreeMap<String, TokenizerProfile>>,
+    /// The transformations applied to the values of the incoming documents.
+    attribute_transforms: Setting<BTreeMap<String, Vec<AttributeTransform>>>,
+    /// Attributes whose decimal strings are also indexed as numbers.
+    decimal_attributes: Setting<HashSet<String>>,
+    /// Attributes whose facet values are grouped regardless of their case and diacritics.
+    normalized_facet_attributes: Setting<HashSet<String>>,
+    /// Whether the facet strings are lowercased.
+    facet_case_folding: Setting<bool>,
+    /// How the prefix of the last query term matches the attributes.
+    attributes_prefix_search: Setting<BTreeMap<String, AttributePrefixSearch>>,
     max_values_per_facet: Setting<usize>,
     sort_facet_values_by: Setting<OrderByMap>,
     pagination_max_total_hits: Setting<usize>,
     proximity_precision: Setting<ProximityPrecision>,
     embedder_settings: Setting<BTreeMap<String, Setting<EmbeddingSettings>>>,
     search_cutoff: Setting<u64>,
+    max_facet_values_per_attribute: Setting<u64>,
+    max_documents: Setting<u64>,
+    max_prefix_typos: Setting<u8>,
+    min_prefix_length: Setting<u8>,
+    stemming: Setting<Vec<Language>>,
+    max_flatten_depth: Setting<usize>,
+    default_language: Setting<Language>,
+    language_hint_field: Setting<String>,
+    duplicate_key_strategy: Setting<DuplicateKeyStrategy>,
     localized_attributes_rules: Setting<Vec<LocalizedAttributesRule>>,
+    /// The documents pinned to the top of the results of the matching queries.
+    pinned_results: Setting<Vec<PinnedResultsRule>>,
+    /// Whether the internal ids of the deleted documents are kept for when they are added again.
+    reserve_document_ids: Setting<bool>,
+    /// Free-form metadata stored along with the index, never indexed.
+    user_metadata: Setting<serde_json::Value>,
+    /// The maximum number of characters returned for the string values of attributes.
+    store_max_length: Setting<BTreeMap<String, usize>>,
+    /// The external ids of the documents excluded from all the search results.
+    blocked_documents: Setting<BTreeSet<String>>,
+    number_tokenization: Setting<NumberTokenization>,
+    enable_formatting: Setting<bool>,
+    recency_half_life_days: Setting<u32>,
+    reranker: Setting<RerankerSettings>,
+    /// Whether the documents written in the index are stored compressed.
+    document_compression: Setting<bool>,
+    max_synonym_expansions: Setting<usize>,
 }
 
 impl<'a, 't, 'i> Settings<'a, 't, 'i> {
@@ -188,20 +249,52 @@ impl<'a, 't, 'i> Settings<'a, 't, 'i> {
             separator_tokens: Setting::NotSet,
             dictionary: Setting::NotSet,
             distinct_field: Setting::NotSet,
+            recency_field: Setting::NotSet,
             synonyms: Setting::NotSet,
             primary_key: Setting::NotSet,
             authorize_typos: Setting::NotSet,
+            split_and_concat_words: Setting::NotSet,
+            read_only: Setting::NotSet,
             exact_words: Setting::NotSet,
             min_word_len_two_typos: Setting::NotSet,
+            typo_penalty: Setting::NotSet,
             min_word_len_one_typo: Setting::NotSet,
             exact_attributes: Setting::NotSet,
+            ignored_attributes: Setting::NotSet,
+            content_types: Setting::NotSet,
+            tokenizer_profiles: Setting::NotSet,
+            attribute_transforms: Setting::NotSet,
+            decimal_attributes: Setting::NotSet,
+            normalized_facet_attributes: Setting::NotSet,
+            facet_case_folding: Setting::NotSet,
+            attributes_prefix_search: Setting::NotSet,
             max_values_per_facet: Setting::NotSet,
             sort_facet_values_by: Setting::NotSet,
             pagination_max_total_hits: Setting::NotSet,
             proximity_precision: Setting::NotSet,
             embedder_settings: Setting::NotSet,
             search_cutoff: Setting::NotSet,
+            max_facet_values_per_attribute: Setting::NotSet,
+            max_documents: Setting::NotSet,
+            max_prefix_typos: Setting::NotSet,
+            min_prefix_length: Setting::NotSet,
+            stemming: Setting::NotSet,
+            max_flatten_depth: Setting::NotSet,
+            default_language: Setting::NotSet,
+            language_hint_field: Setting::NotSet,
+            duplicate_key_strategy: Setting::NotSet,
             localized_attributes_rules: Setting::NotSet,
+            pinned_results: Setting::NotSet,
+            reserve_document_ids: Setting::NotSet,
+            user_metadata: Setting::NotSet,
+            store_max_length: Setting::NotSet,
+            blocked_documents: Setting::NotSet,
+            number_tokenization: Setting::NotSet,
+            enable_formatting: Setting::NotSet,
+            recency_half_life_days: Setting::NotSet,
+            reranker: Setting::NotSet,
+            document_compression: Setting::NotSet,
+            max_synonym_expansions: Setting::NotSet,
             indexer_config,
         }
     }
@@ -296,6 +389,14 @@ impl<'a, 't, 'i> Settings<'a, 't, 'i> {
         self.distinct_field = Setting::Set(distinct_field);
     }
 
+    pub fn reset_recency_field(&mut self) {
+        self.recency_field = Setting::Reset;
+    }
+
+    pub fn set_recency_field(&mut self, recency_field: String) {
+        self.recency_field = Setting::Set(recency_field);
+    }
+
     pub fn reset_synonyms(&mut self) {
         self.synonyms = Setting::Reset;
     }
@@ -320,6 +421,22 @@ impl<'a, 't, 'i> Settings<'a, 't, 'i> {
         self.authorize_typos = Setting::Reset;
     }
 
+    pub fn set_split_and_concat_words(&mut self, val: bool) {
+        self.split_and_concat_words = Setting::Set(val);
+    }
+
+    pub fn reset_split_and_concat_words(&mut self) {
+        self.split_and_concat_words = Setting::Reset;
+    }
+
+    pub fn set_read_only(&mut self, val: bool) {
+        self.read_only = Setting::Set(val);
+    }
+
+    pub fn reset_read_only(&mut self) {
+        self.read_only = Setting::Reset;
+    }
+
     pub fn set_min_word_len_two_typos(&mut self, val: u8) {
         self.min_word_len_two_typos = Setting::Set(val);
     }
@@ -328,6 +445,14 @@ impl<'a, 't, 'i> Settings<'a, 't, 'i> {
         self.min_word_len_two_typos = Setting::Reset;
     }
 
+    pub fn set_typo_penalty(&mut self, val: u8) {
+        self.typo_penalty = Setting::Set(val);
+    }
+
+    pub fn reset_typo_penalty(&mut self) {
+        self.typo_penalty = Setting::Reset;
+    }
+
     pub fn set_min_word_len_one_typo(&mut self, val: u8) {
         self.min_word_len_one_typo = Setting::Set(val);
     }
@@ -352,6 +477,79 @@ impl<'a, 't, 'i> Settings<'a, 't, 'i> {
         self.exact_attributes = Setting::Reset;
     }
 
+    pub fn set_ignored_attributes(&mut self, attrs: HashSet<String>) {
+        self.ignored_attributes = Setting::Set(attrs);
+    }
+
+    pub fn reset_ignored_attributes(&mut self) {
+        self.ignored_attributes = Setting::Reset;
+    }
+
+    pub fn set_content_types(&mut self, content_types: BTreeMap<String, ContentType>) {
+        self.content_types = Setting::Set(content_types);
+    }
+
+    pub fn reset_content_types(&mut self) {
+        self.content_types = Setting::Reset;
+    }
+
+    pub fn set_tokenizer_profiles(
+        &mut self,
+        tokenizer_profiles: BTreeMap<String, TokenizerProfile>,
+    ) {
+        self.tokenizer_profiles = Setting::Set(tokenizer_profiles);
+    }
+
+    pub fn reset_tokenizer_profiles(&mut self) {
+        self.tokenizer_profiles = Setting::Reset;
+    }
+
+    pub fn set_attribute_transforms(
+        &mut self,
+        attribute_transforms: BTreeMap<String, Vec<AttributeTransform>>,
+    ) {
+        self.attribute_transforms = Setting::Set(attribute_transforms);
+    }
+
+    pub fn reset_attribute_transforms(&mut self) {
+        self.attribute_transforms = Setting::Reset;
+    }
+
+    pub fn set_decimal_attributes(&mut self, attrs: HashSet<String>) {
+        self.decimal_attributes = Setting::Set(attrs);
+    }
+
+    pub fn reset_decimal_attributes(&mut self) {
+        self.decimal_attributes = Setting::Reset;
+    }
+
+    pub fn set_normalized_facet_attributes(&mut self, attrs: HashSet<String>) {
+        self.normalized_facet_attributes = Setting::Set(attrs);
+    }
+
+    pub fn reset_normalized_facet_attributes(&mut self) {
+        self.normalized_facet_attributes = Setting::Reset;
+    }
+
+    pub fn set_facet_case_folding(&mut self, val: bool) {
+        self.facet_case_folding = Setting::Set(val);
+    }
+
+    pub fn reset_facet_case_folding(&mut self) {
+        self.facet_case_folding = Setting::Reset;
+    }
+
+    pub fn set_attributes_prefix_search(
+        &mut self,
+        attributes_prefix_search: BTreeMap<String, AttributePrefixSearch>,
+    ) {
+        self.attributes_prefix_search = Setting::Set(attributes_prefix_search);
+    }
+
+    pub fn reset_attributes_prefix_search(&mut self) {
+        self.attributes_prefix_search = Setting::Reset;
+    }
+
     pub fn set_max_values_per_facet(&mut self, value: usize) {
         self.max_values_per_facet = Setting::Set(value);
     }
@@ -400,6 +598,166 @@ impl<'a, 't, 'i> Settings<'a, 't, 'i> {
         self.search_cutoff = Setting::Reset;
     }
 
+    pub fn set_max_facet_values_per_attribute(&mut self, value: u64) {
+        self.max_facet_values_per_attribute = Setting::Set(value);
+    }
+
+    pub fn reset_max_facet_values_per_attribute(&mut self) {
+        self.max_facet_values_per_attribute = Setting::Reset;
+    }
+
+    pub fn set_max_documents(&mut self, value: u64) {
+        self.max_documents = Setting::Set(value);
+    }
+
+    pub fn reset_max_documents(&mut self) {
+        self.max_documents = Setting::Reset;
+    }
+
+    pub fn set_pinned_results(&mut self, rules: Vec<PinnedResultsRule>) {
+        self.pinned_results = Setting::Set(rules);
+    }
+
+    pub fn reset_pinned_results(&mut self) {
+        self.pinned_results = Setting::Reset;
+    }
+
+    pub fn set_reserve_document_ids(&mut self, flag: bool) {
+        self.reserve_document_ids = Setting::Set(flag);
+    }
+
+    pub fn reset_reserve_document_ids(&mut self) {
+        self.reserve_document_ids = Setting::Reset;
+    }
+
+    pub fn set_user_metadata(&mut self, metadata: serde_json::Value) {
+        self.user_metadata = Setting::Set(metadata);
+    }
+
+    pub fn reset_user_metadata(&mut self) {
+        self.user_metadata = Setting::Reset;
+    }
+
+    pub fn set_store_max_length(&mut self, store_max_length: BTreeMap<String, usize>) {
+        self.store_max_length = Setting::Set(store_max_length);
+    }
+
+    pub fn reset_store_max_length(&mut self) {
+        self.store_max_length = Setting::Reset;
+    }
+
+    pub fn set_blocked_documents(&mut self, documents_ids: BTreeSet<String>) {
+        self.blocked_documents = Setting::Set(documents_ids);
+    }
+
+    pub fn reset_blocked_documents(&mut self) {
+        self.blocked_documents = Setting::Reset;
+    }
+
+    pub fn set_number_tokenization(&mut self, tokenization: NumberTokenization) {
+        self.number_tokenization = Setting::Set(tokenization);
+    }
+
+    pub fn reset_number_tokenization(&mut self) {
+        self.number_tokenization = Setting::Reset;
+    }
+
+    pub fn set_enable_formatting(&mut self, enabled: bool) {
+        self.enable_formatting = Setting::Set(enabled);
+    }
+
+    pub fn reset_enable_formatting(&mut self) {
+        self.enable_formatting = Setting::Reset;
+    }
+
+    pub fn set_recency_half_life_days(&mut self, half_life_days: u32) {
+        self.recency_half_life_days = Setting::Set(half_life_days);
+    }
+
+    pub fn reset_recency_half_life_days(&mut self) {
+        self.recency_half_life_days = Setting::Reset;
+    }
+
+    pub fn set_reranker(&mut self, reranker: RerankerSettings) {
+        self.reranker = Setting::Set(reranker);
+    }
+
+    pub fn reset_reranker(&mut self) {
+        self.reranker = Setting::Reset;
+    }
+
+    pub fn set_document_compression(&mut self, enabled: bool) {
+        self.document_compression = Setting::Set(enabled);
+    }
+
+    pub fn reset_document_compression(&mut self) {
+        self.document_compression = Setting::Reset;
+    }
+
+    pub fn set_max_synonym_expansions(&mut self, value: usize) {
+        self.max_synonym_expansions = Setting::Set(value);
+    }
+
+    pub fn reset_max_synonym_expansions(&mut self) {
+        self.max_synonym_expansions = Setting::Reset;
+    }
+
+    pub fn set_max_prefix_typos(&mut self, value: u8) {
+        self.max_prefix_typos = Setting::Set(value);
+    }
+
+    pub fn reset_max_prefix_typos(&mut self) {
+        self.max_prefix_typos = Setting::Reset;
+    }
+
+    pub fn set_min_prefix_length(&mut self, value: u8) {
+        self.min_prefix_length = Setting::Set(value);
+    }
+
+    pub fn reset_min_prefix_length(&mut self) {
+        self.min_prefix_length = Setting::Reset;
+    }
+
+    pub fn set_stemming(&mut self, languages: Vec<Language>) {
+        self.stemming = Setting::Set(languages);
+    }
+
+    pub fn reset_stemming(&mut self) {
+        self.stemming = Setting::Reset;
+    }
+
+    pub fn set_max_flatten_depth(&mut self, depth: usize) {
+        self.max_flatten_depth = Setting::Set(depth);
+    }
+
+    pub fn reset_max_flatten_depth(&mut self) {
+        self.max_flatten_depth = Setting::Reset;
+    }
+
+    pub fn set_default_language(&mut self, language: Language) {
+        self.default_language = Setting::Set(language);
+    }
+
+    pub fn reset_default_language(&mut self) {
+        self.default_language = Setting::Reset;
+    }
+
+    pub fn set_language_hint_field(&mut self, field: String) {
+        self.language_hint_field = Setting::Set(field);
+    }
+
+    pub fn reset_language_hint_field(&mut self) {
+        self.language_hint_field = Setting::Reset;
+    }
+
+    pub fn set_duplicate_key_strategy(&mut self, strategy: DuplicateKeyStrategy) {
+        self.duplicate_key_strategy = Setting::Set(strategy);
+    }
+
+    pub fn reset_duplicate_key_strategy(&mut self) {
+        self.duplicate_key_strategy = Setting::Reset;
+    }
+
     pub fn set_localized_attributes_rules(&mut self, value: Vec<LocalizedAttributesRule>) {
         self.localized_attributes_rules = Setting::Set(value);
     }
@@ -484,6 +842,19 @@ impl<'a, 't, 'i> Settings<'a, 't, 'i> {
         Ok(true)
     }
 
+    fn update_recency_field(&mut self) -> Result<bool> {
+        match self.recency_field {
+            Setting::Set(ref attr) => {
+                self.index.put_recency_field(self.wtxn, attr)?;
+            }
+            Setting::Reset => {
+                self.index.delete_recency_field(self.wtxn)?;
+            }
+            Setting::NotSet => return Ok(false),
+        }
+        Ok(true)
+    }
+
     /// Updates the index's searchable attributes.
     fn update_searchable(&mut self) -> Result<bool> {
         match self.searchable_fields {
@@ -726,6 +1097,133 @@ impl<'a, 't, 'i> Settings<'a, 't, 'i> {
         }
     }
 
+    fn update_ignored_attributes(&mut self) -> Result<bool> {
+        match self.ignored_attributes {
+            Setting::Set(ref attrs) => {
+                let old_attrs = self.index.ignored_attributes(self.wtxn)?;
+                let old_attrs = old_attrs.into_iter().map(String::from).collect::<HashSet<_>>();
+
+                if attrs != &old_attrs {
+                    let attrs = attrs.iter().map(String::as_str).collect::<Vec<_>>();
+                    self.index.put_ignored_attributes(self.wtxn, &attrs)?;
+                    Ok(true)
+                } else {
+                    Ok(false)
+                }
+            }
+            Setting::Reset => Ok(self.index.delete_ignored_attributes(self.wtxn)?),
+            Setting::NotSet => Ok(false),
+        }
+    }
+
+    fn update_content_types(&mut self) -> Result<bool> {
+        match self.content_types {
+            Setting::Set(ref content_types) => {
+                if content_types != &self.index.content_types(self.wtxn)? {
+                    self.index.put_content_types(self.wtxn, content_types)?;
+                    Ok(true)
+                } else {
+                    Ok(false)
+                }
+            }
+            Setting::Reset => Ok(self.index.delete_content_types(self.wtxn)?),
+            Setting::NotSet => Ok(false),
+        }
+    }
+
+    fn update_tokenizer_profiles(&mut self) -> Result<bool> {
+        match self.tokenizer_profiles {
+            Setting::Set(ref tokenizer_profiles) => {
+                if tokenizer_profiles != &self.index.tokenizer_profiles(self.wtxn)? {
+                    self.index.put_tokenizer_profiles(self.wtxn, tokenizer_profiles)?;
+                    Ok(true)
+                } else {
+                    Ok(false)
+                }
+            }
+            Setting::Reset => Ok(self.index.delete_tokenizer_profiles(self.wtxn)?),
+            Setting::NotSet => Ok(false),
+        }
+    }
+
+    fn update_decimal_attributes(&mut self) -> Result<bool> {
+        match self.decimal_attributes {
+            Setting::Set(ref attrs) => {
+                let old_attrs = self.index.decimal_attributes(self.wtxn)?;
+                let old_attrs = old_attrs.into_iter().map(String::from).collect::<HashSet<_>>();
+
+                if attrs != &old_attrs {
+                    let attrs = attrs.iter().map(String::as_str).collect::<Vec<_>>();
+                    self.index.put_decimal_attributes(self.wtxn, &attrs)?;
+                    Ok(true)
+                } else {
+                    Ok(false)
+                }
+            }
+            Setting::Reset => Ok(self.index.delete_decimal_attributes(self.wtxn)?),
+            Setting::NotSet => Ok(false),
+        }
+    }
+
+    fn update_normalized_facet_attributes(&mut self) -> Result<bool> {
+        match self.normalized_facet_attributes {
+            Setting::Set(ref attrs) => {
+                let old_attrs = self.index.normalized_facet_attributes(self.wtxn)?;
+                let old_attrs = old_attrs.into_iter().map(String::from).collect::<HashSet<_>>();
+
+                if attrs != &old_attrs {
+                    let attrs = attrs.iter().map(String::as_str).collect::<Vec<_>>();
+                    self.index.put_normalized_facet_attributes(self.wtxn, &attrs)?;
+                    Ok(true)
+                } else {
+                    Ok(false)
+                }
+            }
+            Setting::Reset => Ok(self.index.delete_normalized_facet_attributes(self.wtxn)?),
+            Setting::NotSet => Ok(false),
+        }
+    }
+
+    fn update_facet_case_folding(&mut self) -> Result<()> {
+        match self.facet_case_folding {
+            Setting::Set(flag) => {
+                self.index.put_facet_case_folding(self.wtxn, flag)?;
+                Ok(())
+            }
+            Setting::Reset => {
+                self.index.put_facet_case_folding(self.wtxn, true)?;
+                Ok(())
+            }
+            Setting::NotSet => Ok(()),
+        }
+    }
+
+    fn update_attributes_prefix_search(&mut self) -> Result<()> {
+        match self.attributes_prefix_search {
+            Setting::Set(ref attributes_prefix_search) => {
+                self.index.put_attributes_prefix_search(self.wtxn, attributes_prefix_search)?;
+            }
+            Setting::Reset => {
+                self.index.delete_attributes_prefix_search(self.wtxn)?;
+            }
+            Setting::NotSet => (),
+        }
+        Ok(())
+    }
+
+    fn update_attribute_transforms(&mut self) -> Result<()> {
+        match self.attribute_transforms {
+            Setting::Set(ref attribute_transforms) => {
+                self.index.put_attribute_transforms(self.wtxn, attribute_transforms)?;
+            }
+            Setting::Reset => {
+                self.index.delete_attribute_transforms(self.wtxn)?;
+            }
+            Setting::NotSet => (),
+        }
+        Ok(())
+    }
+
     fn update_filterable(&mut self) -> Result<()> {
         match self.filterable_fields {
             Setting::Set(ref fields) => {
@@ -818,31 +1316,353 @@ impl<'a, 't, 'i> Settings<'a, 't, 'i> {
         }
     }
 
-    fn update_min_typo_word_len(&mut self) -> Result<()> {
-        let one = self.min_word_len_one_typo.or_reset(DEFAULT_MIN_WORD_LEN_ONE_TYPO);
-        let two = self.min_word_len_two_typos.or_reset(DEFAULT_MIN_WORD_LEN_TWO_TYPOS);
-        match (one, two) {
-            (Setting::Set(one), Setting::Set(two)) => {
-                if one > two {
-                    return Err(UserError::InvalidMinTypoWordLenSetting(one, two).into());
-                } else {
-                    self.index.put_min_word_len_one_typo(self.wtxn, one)?;
-                    self.index.put_min_word_len_two_typos(self.wtxn, two)?;
-                }
+    fn update_split_and_concat_words(&mut self) -> Result<()> {
+        match self.split_and_concat_words {
+            Setting::Set(flag) => {
+                self.index.put_split_and_concat_words(self.wtxn, flag)?;
+                Ok(())
             }
-            (Setting::Set(one), _) => {
-                let two = self.index.min_word_len_two_typos(self.wtxn)?;
-                if one > two {
-                    return Err(UserError::InvalidMinTypoWordLenSetting(one, two).into());
-                } else {
-                    self.index.put_min_word_len_one_typo(self.wtxn, one)?;
-                }
+            Setting::Reset => {
+                self.index.put_split_and_concat_words(self.wtxn, true)?;
+                Ok(())
             }
-            (_, Setting::Set(two)) => {
-                let one = self.index.min_word_len_one_typo(self.wtxn)?;
-                if one > two {
-                    return Err(UserError::InvalidMinTypoWordLenSetting(one, two).into());
-                } else {
+            Setting::NotSet => Ok(()),
+        }
+    }
+
+    fn update_read_only(&mut self) -> Result<()> {
+        match self.read_only {
+            Setting::Set(flag) => {
+                self.index.put_read_only(self.wtxn, flag)?;
+                Ok(())
+            }
+            Setting::Reset => {
+                self.index.delete_read_only(self.wtxn)?;
+                Ok(())
+            }
+            Setting::NotSet => Ok(()),
+        }
+    }
+
+    fn update_max_documents(&mut self) -> Result<()> {
+        match self.max_documents {
+            Setting::Set(max) => {
+                self.index.put_max_documents(self.wtxn, max)?;
+            }
+            Setting::Reset => {
+                self.index.delete_max_documents(self.wtxn)?;
+            }
+            Setting::NotSet => (),
+        }
+        Ok(())
+    }
+
+    fn update_max_prefix_typos(&mut self) -> Result<()> {
+        match self.max_prefix_typos {
+            Setting::Set(max) => {
+                self.index.put_max_prefix_typos(self.wtxn, max)?;
+            }
+            Setting::Reset => {
+                self.index.delete_max_prefix_typos(self.wtxn)?;
+            }
+            Setting::NotSet => (),
+        }
+        Ok(())
+    }
+
+    fn update_pinned_results(&mut self) -> Result<()> {
+        match &self.pinned_results {
+            Setting::Set(rules) => {
+                self.index.put_pinned_results(self.wtxn, rules)?;
+            }
+            Setting::Reset => {
+                self.index.delete_pinned_results(self.wtxn)?;
+            }
+            Setting::NotSet => (),
+        }
+        Ok(())
+    }
+
+    /// The formatting only happens when the search results are returned, nothing is reindexed.
+    fn update_enable_formatting(&mut self) -> Result<()> {
+        match self.enable_formatting {
+            Setting::Set(enabled) => {
+                self.index.put_enable_formatting(self.wtxn, enabled)?;
+            }
+            Setting::Reset => {
+                self.index.delete_enable_formatting(self.wtxn)?;
+            }
+            Setting::NotSet => (),
+        }
+        Ok(())
+    }
+
+    /// The decay is computed from the stored documents when searching, nothing is reindexed.
+    fn update_recency_half_life_days(&mut self) -> Result<()> {
+        match self.recency_half_life_days {
+            Setting::Set(0) => return Err(UserError::InvalidRecencyHalfLifeDaysSetting.into()),
+            Setting::Set(half_life_days) => {
+                self.index.put_recency_half_life_days(self.wtxn, half_life_days)?;
+            }
+            Setting::Reset => {
+                self.index.delete_recency_half_life_days(self.wtxn)?;
+            }
+            Setting::NotSet => (),
+        }
+        Ok(())
+    }
+
+    /// The documents are re-scored when searching, nothing is reindexed.
+    fn update_reranker(&mut self) -> Result<()> {
+        match &self.reranker {
+            Setting::Set(reranker) => {
+                match Url::parse(&reranker.url) {
+                    Ok(url) if matches!(url.scheme(), "http" | "https") => (),
+                    _ => {
+                        return Err(UserError::InvalidRerankerSetting(format!(
+                            "`url` must be an http or https URL, found `{}`.",
+                            reranker.url
+                        ))
+                        .into())
+                    }
+                }
+                if !(1..=MAX_RERANKER_CANDIDATES).contains(&reranker.candidates) {
+                    return Err(UserError::InvalidRerankerSetting(format!(
+                        "`candidates` must be between 1 and {MAX_RERANKER_CANDIDATES}, found {}.",
+                        reranker.candidates
+                    ))
+                    .into());
+                }
+                self.index.put_reranker(self.wtxn, reranker)?;
+            }
+            Setting::Reset => {
+                self.index.delete_reranker(self.wtxn)?;
+            }
+            Setting::NotSet => (),
+        }
+        Ok(())
+    }
+
+    /// The stored documents are rewritten compressed or raw by [`Index::recompress_documents`]
+    /// when the setting changes.
+    fn update_document_compression(&mut self) -> Result<()> {
+        let old_compression = self.index.document_compression(self.wtxn)?;
+        match self.document_compression {
+            Setting::Set(enabled) => {
+                self.index.put_document_compression(self.wtxn, enabled)?;
+            }
+            Setting::Reset => {
+                self.index.delete_document_compression(self.wtxn)?;
+            }
+            Setting::NotSet => (),
+        }
+        if self.index.document_compression(self.wtxn)? != old_compression {
+            self.index.recompress_documents(self.wtxn)?;
+        }
+        Ok(())
+    }
+
+    /// The synonyms are only expanded at search time, nothing is reindexed.
+    fn update_max_synonym_expansions(&mut self) -> Result<()> {
+        match self.max_synonym_expansions {
+            Setting::Set(max) => {
+                self.index.put_max_synonym_expansions(self.wtxn, max as u64)?;
+            }
+            Setting::Reset => {
+                self.index.delete_max_synonym_expansions(self.wtxn)?;
+            }
+            Setting::NotSet => (),
+        }
+        Ok(())
+    }
+
+    fn update_reserve_document_ids(&mut self) -> Result<()> {
+        match self.reserve_document_ids {
+            Setting::Set(true) => {
+                self.index.put_reserve_document_ids(self.wtxn, true)?;
+            }
+            // the ids reserved so far are released along with the flag
+            Setting::Set(false) | Setting::Reset => {
+                self.index.delete_reserve_document_ids(self.wtxn)?;
+                self.index.delete_reserved_document_ids(self.wtxn)?;
+            }
+            Setting::NotSet => (),
+        }
+        Ok(())
+    }
+
+    fn update_user_metadata(&mut self) -> Result<()> {
+        match &self.user_metadata {
+            Setting::Set(metadata) => {
+                let size = metadata.to_string().len();
+                if size > MAX_USER_METADATA_SIZE {
+                    return Err(UserError::InvalidUserMetadataSize {
+                        size,
+                        max: MAX_USER_METADATA_SIZE,
+                    }
+                    .into());
+                }
+                self.index.put_user_metadata(self.wtxn, metadata)?;
+            }
+            Setting::Reset => {
+                self.index.delete_user_metadata(self.wtxn)?;
+            }
+            Setting::NotSet => (),
+        }
+        Ok(())
+    }
+
+    /// Only the documents indexed after the change are stored with the new lengths.
+    fn update_store_max_length(&mut self) -> Result<()> {
+        match self.store_max_length {
+            Setting::Set(ref store_max_length) => {
+                self.index.put_store_max_length(self.wtxn, store_max_length)?;
+            }
+            Setting::Reset => {
+                self.index.delete_store_max_length(self.wtxn)?;
+            }
+            Setting::NotSet => (),
+        }
+        Ok(())
+    }
+
+    /// The blocked documents are only removed from the search results, nothing is reindexed.
+    fn update_blocked_documents(&mut self) -> Result<()> {
+        match self.blocked_documents {
+            Setting::Set(ref documents_ids) => {
+                self.index.put_blocked_documents(self.wtxn, documents_ids)?;
+            }
+            Setting::Reset => {
+                self.index.delete_blocked_documents(self.wtxn)?;
+            }
+            Setting::NotSet => (),
+        }
+        Ok(())
+    }
+
+    fn update_min_prefix_length(&mut self) -> Result<()> {
+        match self.min_prefix_length {
+            Setting::Set(min) => {
+                self.index.put_min_prefix_length(self.wtxn, min)?;
+            }
+            Setting::Reset => {
+                self.index.delete_min_prefix_length(self.wtxn)?;
+            }
+            Setting::NotSet => (),
+        }
+        Ok(())
+    }
+
+    fn update_stemming(&mut self) -> Result<()> {
+        match &self.stemming {
+            Setting::Set(languages) if languages.is_empty() => {
+                self.index.delete_stemming(self.wtxn)?;
+            }
+            Setting::Set(languages) => {
+                if let Some(language) = languages.iter().find(|l| !Stemmer::is_supported(**l)) {
+                    let language = language.code().to_string();
+                    return Err(UserError::UnsupportedStemmingLanguage(language).into());
+                }
+                self.index.put_stemming(self.wtxn, languages)?;
+            }
+            Setting::Reset => {
+                self.index.delete_stemming(self.wtxn)?;
+            }
+            Setting::NotSet => (),
+        }
+        Ok(())
+    }
+
+    fn update_number_tokenization(&mut self) -> Result<()> {
+        match self.number_tokenization {
+            Setting::Set(tokenization) => {
+                self.index.put_number_tokenization(self.wtxn, tokenization)?;
+            }
+            Setting::Reset => {
+                self.index.delete_number_tokenization(self.wtxn)?;
+            }
+            Setting::NotSet => (),
+        }
+        Ok(())
+    }
+
+    fn update_max_flatten_depth(&mut self) -> Result<()> {
+        match self.max_flatten_depth {
+            Setting::Set(depth) => {
+                self.index.put_max_flatten_depth(self.wtxn, depth)?;
+            }
+            Setting::Reset => {
+                self.index.delete_max_flatten_depth(self.wtxn)?;
+            }
+            Setting::NotSet => (),
+        }
+        Ok(())
+    }
+
+    fn update_default_language(&mut self) -> Result<()> {
+        match self.default_language {
+            Setting::Set(language) => {
+                self.index.put_default_language(self.wtxn, language)?;
+            }
+            Setting::Reset => {
+                self.index.delete_default_language(self.wtxn)?;
+            }
+            Setting::NotSet => (),
+        }
+        Ok(())
+    }
+
+    fn update_language_hint_field(&mut self) -> Result<()> {
+        match self.language_hint_field {
+            Setting::Set(ref field) => {
+                self.index.put_language_hint_field(self.wtxn, field)?;
+            }
+            Setting::Reset => {
+                self.index.delete_language_hint_field(self.wtxn)?;
+            }
+            Setting::NotSet => (),
+        }
+        Ok(())
+    }
+
+    fn update_duplicate_key_strategy(&mut self) -> Result<()> {
+        match self.duplicate_key_strategy {
+            Setting::Set(strategy) => {
+                self.index.put_duplicate_key_strategy(self.wtxn, strategy)?;
+            }
+            Setting::Reset => {
+                self.index.delete_duplicate_key_strategy(self.wtxn)?;
+            }
+            Setting::NotSet => (),
+        }
+        Ok(())
+    }
+
+    fn update_min_typo_word_len(&mut self) -> Result<()> {
+        let one = self.min_word_len_one_typo.or_reset(DEFAULT_MIN_WORD_LEN_ONE_TYPO);
+        let two = self.min_word_len_two_typos.or_reset(DEFAULT_MIN_WORD_LEN_TWO_TYPOS);
+        match (one, two) {
+            (Setting::Set(one), Setting::Set(two)) => {
+                if one > two {
+                    return Err(UserError::InvalidMinTypoWordLenSetting(one, two).into());
+                } else {
+                    self.index.put_min_word_len_one_typo(self.wtxn, one)?;
+                    self.index.put_min_word_len_two_typos(self.wtxn, two)?;
+                }
+            }
+            (Setting::Set(one), _) => {
+                let two = self.index.min_word_len_two_typos(self.wtxn)?;
+                if one > two {
+                    return Err(UserError::InvalidMinTypoWordLenSetting(one, two).into());
+                } else {
+                    self.index.put_min_word_len_one_typo(self.wtxn, one)?;
+                }
+            }
+            (_, Setting::Set(two)) => {
+                let one = self.index.min_word_len_one_typo(self.wtxn)?;
+                if one > two {
+                    return Err(UserError::InvalidMinTypoWordLenSetting(one, two).into());
+                } else {
                     self.index.put_min_word_len_two_typos(self.wtxn, two)?;
                 }
             }
@@ -852,6 +1672,18 @@ impl<'a, 't, 'i> Settings<'a, 't, 'i> {
         Ok(())
     }
 
+    fn update_typo_penalty(&mut self) -> Result<()> {
+        match self.typo_penalty {
+            Setting::Set(0) => return Err(UserError::InvalidTypoPenaltySetting.into()),
+            Setting::Set(penalty) => self.index.put_typo_penalty(self.wtxn, penalty)?,
+            Setting::Reset => {
+                self.index.delete_typo_penalty(self.wtxn)?;
+            }
+            Setting::NotSet => (),
+        }
+        Ok(())
+    }
+
     fn update_exact_words(&mut self) -> Result<()> {
         match self.exact_words {
             Setting::Set(ref mut words) => {
@@ -1135,6 +1967,30 @@ impl<'a, 't, 'i> Settings<'a, 't, 'i> {
         Ok(changed)
     }
 
+    fn update_max_facet_values_per_attribute(&mut self) -> Result<bool> {
+        let changed = match self.max_facet_values_per_attribute {
+            Setting::Set(new) => {
+                let old = self.index.max_facet_values_per_attribute(self.wtxn)?;
+                if old == Some(new) {
+                    false
+                } else {
+                    self.index.put_max_facet_values_per_attribute(self.wtxn, new)?;
+                    true
+                }
+            }
+            Setting::Reset => self.index.delete_max_facet_values_per_attribute(self.wtxn)?,
+            Setting::NotSet => false,
+        };
+
+        // The attributes capped under the previous limit will accept new facet values
+        // at the next indexation, until they reach the new limit.
+        if changed {
+            self.index.delete_capped_faceted_fields(self.wtxn)?;
+        }
+
+        Ok(changed)
+    }
+
     fn update_localized_attributes_rules(&mut self) -> Result<()> {
         match &self.localized_attributes_rules {
             Setting::Set(new) => {
@@ -1157,39 +2013,194 @@ impl<'a, 't, 'i> Settings<'a, 't, 'i> {
         FP: Fn(UpdateIndexingStep) + Sync,
         FA: Fn() -> bool + Sync,
     {
-        self.index.set_updated_at(self.wtxn, &OffsetDateTime::now_utc())?;
+        let inner_settings_diff = self.update_settings()?;
 
-        let old_inner_settings = InnerIndexSettings::from_index(self.index, self.wtxn)?;
+        let new_normalized_fids = inner_settings_diff.new.normalized_facet_fields_ids();
+        // the normalized strings the original forms are counted by depend on the case folding
+        let normalized_fids_changed = inner_settings_diff.old.normalized_facet_fields_ids()
+            != new_normalized_fids
+            || inner_settings_diff.old.facet_case_folding
+                != inner_settings_diff.new.facet_case_folding;
 
-        // never trigger re-indexing
-        self.update_displayed()?;
-        self.update_distinct_field()?;
-        self.update_criteria()?;
-        self.update_primary_key()?;
-        self.update_authorize_typos()?;
-        self.update_min_typo_word_len()?;
-        self.update_exact_words()?;
-        self.update_max_values_per_facet()?;
-        self.update_sort_facet_values_by()?;
-        self.update_pagination_max_total_hits()?;
-        self.update_search_cutoff()?;
+        let rebuild_prefixes = inner_settings_diff.rebuild_prefixes();
 
-        // could trigger re-indexing
-        self.update_filterable()?;
-        self.update_sortable()?;
-        self.update_stop_words()?;
-        self.update_non_separator_tokens()?;
-        self.update_separator_tokens()?;
-        self.update_dictionary()?;
-        self.update_synonyms()?;
-        self.update_searchable()?;
-        self.update_exact_attributes()?;
-        self.update_proximity_precision()?;
-        self.update_localized_attributes_rules()?;
+        if inner_settings_diff.any_reindexing_needed() {
+            self.reindex(&progress_callback, &should_abort, inner_settings_diff)?;
+        }
 
-        let embedding_config_updates = self.update_embedding_configs()?;
+        // the prefixes are derived from the word databases, the documents are not reindexed
+        if rebuild_prefixes {
+            RebuildDatabase::new(&mut *self.wtxn, self.index, self.indexer_config)
+                .execute(DatabaseKind::Prefixes)?;
+        }
 
-        let mut new_inner_settings = InnerIndexSettings::from_index(self.index, self.wtxn)?;
+        if normalized_fids_changed {
+            self.recount_facet_original_strings(&new_normalized_fids)?;
+        }
+
+        Ok(())
+    }
+
+    /// Recounts the original forms of the strings of the normalized facet fields from the facet
+    /// strings of the documents.
+    ///
+    /// The counts are maintained while indexing the documents, but the facet strings that are
+    /// normalized the same way before and after a settings update are not reindexed.
+    fn recount_facet_original_strings(
+        &mut self,
+        normalized_fids: &BTreeSet<FieldId>,
+    ) -> Result<()> {
+        self.index.facet_id_string_original_counts.clear(self.wtxn)?;
+        for &field_id in normalized_fids {
+            let mut counts = BTreeMap::<String, BTreeMap<String, u64>>::new();
+            let iter = self
+                .index
+                .field_id_docid_facet_strings
+                .remap_key_type::<Bytes>()
+                .prefix_iter(self.wtxn, &field_id.to_be_bytes())?
+                .remap_key_type::<FieldDocIdFacetStringCodec>();
+            for result in iter {
+                let ((_field_id, _docid, normalized), original) = result?;
+                let originals = counts.entry(normalized.to_string()).or_default();
+                *originals.entry(original.to_string()).or_default() += 1;
+            }
+            for (normalized, originals) in counts {
+                self.index.facet_id_string_original_counts.put(
+                    self.wtxn,
+                    &(field_id, normalized.as_str()),
+                    &originals,
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Estimates the cost of applying the settings without reindexing the documents.
+    ///
+    /// The settings are still written in the transaction to compute the difference with the
+    /// current ones, the transaction must be aborted once the estimate is computed.
+    pub fn estimate(mut self) -> Result<SettingsUpdateEstimate> {
+        let inner_settings_diff = self.update_settings()?;
+
+        let mut rebuilt_databases = Vec::new();
+        if inner_settings_diff.reindex_searchable() {
+            rebuilt_databases.extend([
+                db_name::WORD_DOCIDS,
+                db_name::EXACT_WORD_DOCIDS,
+                db_name::WORD_PREFIX_DOCIDS,
+                db_name::EXACT_WORD_PREFIX_DOCIDS,
+                db_name::WORD_POSITION_DOCIDS,
+                db_name::WORD_FIELD_ID_DOCIDS,
+                db_name::WORD_PREFIX_POSITION_DOCIDS,
+                db_name::WORD_PREFIX_FIELD_ID_DOCIDS,
+                db_name::FIELD_ID_WORD_COUNT_DOCIDS,
+            ]);
+        }
+        if inner_settings_diff.rebuild_prefixes() && !inner_settings_diff.reindex_searchable() {
+            rebuilt_databases.extend([
+                db_name::WORD_PREFIX_DOCIDS,
+                db_name::EXACT_WORD_PREFIX_DOCIDS,
+                db_name::WORD_PREFIX_POSITION_DOCIDS,
+                db_name::WORD_PREFIX_FIELD_ID_DOCIDS,
+            ]);
+        }
+        if inner_settings_diff.reindex_proximities() {
+            rebuilt_databases.push(db_name::WORD_PAIR_PROXIMITY_DOCIDS);
+        }
+        if inner_settings_diff.reindex_facets() {
+            rebuilt_databases.extend([
+                db_name::FACET_ID_F64_DOCIDS,
+                db_name::FACET_ID_EXISTS_DOCIDS,
+                db_name::FACET_ID_IS_NULL_DOCIDS,
+                db_name::FACET_ID_IS_EMPTY_DOCIDS,
+                db_name::FACET_ID_STRING_DOCIDS,
+                db_name::FACET_ID_NORMALIZED_STRING_STRINGS,
+                db_name::FACET_ID_STRING_FST,
+                db_name::FACET_ID_STRING_ORIGINAL_COUNTS,
+                db_name::FIELD_ID_DOCID_FACET_F64S,
+                db_name::FIELD_ID_DOCID_FACET_STRINGS,
+            ]);
+        }
+        if inner_settings_diff.reindex_vectors() {
+            rebuilt_databases.push(db_name::VECTOR_ARROY);
+        }
+
+        let reindexed_documents = if inner_settings_diff.any_reindexing_needed() {
+            self.index.number_of_documents(self.wtxn)?
+        } else {
+            0
+        };
+
+        Ok(SettingsUpdateEstimate { reindexed_documents, rebuilt_databases })
+    }
+
+    /// Writes the settings in the index and returns the difference with the previous ones.
+    fn update_settings(&mut self) -> Result<InnerIndexSettingsDiff> {
+        self.index.set_updated_at(self.wtxn, &OffsetDateTime::now_utc())?;
+
+        let old_inner_settings = InnerIndexSettings::from_index(self.index, self.wtxn)?;
+
+        // never trigger re-indexing
+        self.update_displayed()?;
+        self.update_distinct_field()?;
+        self.update_recency_field()?;
+        self.update_criteria()?;
+        self.update_primary_key()?;
+        self.update_authorize_typos()?;
+        self.update_split_and_concat_words()?;
+        self.update_read_only()?;
+        self.update_attribute_transforms()?;
+        self.update_attributes_prefix_search()?;
+        self.update_min_typo_word_len()?;
+        self.update_typo_penalty()?;
+        self.update_exact_words()?;
+        self.update_max_values_per_facet()?;
+        self.update_sort_facet_values_by()?;
+        self.update_pagination_max_total_hits()?;
+        self.update_search_cutoff()?;
+        self.update_max_facet_values_per_attribute()?;
+        self.update_max_documents()?;
+        self.update_max_prefix_typos()?;
+        self.update_pinned_results()?;
+        self.update_reserve_document_ids()?;
+        self.update_user_metadata()?;
+        self.update_store_max_length()?;
+        self.update_blocked_documents()?;
+        self.update_enable_formatting()?;
+        self.update_recency_half_life_days()?;
+        self.update_reranker()?;
+        self.update_document_compression()?;
+        self.update_max_synonym_expansions()?;
+
+        // could trigger re-indexing
+        self.update_filterable()?;
+        self.update_sortable()?;
+        self.update_stop_words()?;
+        self.update_non_separator_tokens()?;
+        self.update_separator_tokens()?;
+        self.update_dictionary()?;
+        self.update_synonyms()?;
+        self.update_searchable()?;
+        self.update_exact_attributes()?;
+        self.update_ignored_attributes()?;
+        self.update_decimal_attributes()?;
+        self.update_normalized_facet_attributes()?;
+        self.update_facet_case_folding()?;
+        self.update_content_types()?;
+        self.update_tokenizer_profiles()?;
+        self.update_min_prefix_length()?;
+        self.update_stemming()?;
+        self.update_number_tokenization()?;
+        self.update_max_flatten_depth()?;
+        self.update_default_language()?;
+        self.update_language_hint_field()?;
+        self.update_duplicate_key_strategy()?;
+        self.update_proximity_precision()?;
+        self.update_localized_attributes_rules()?;
+
+        let embedding_config_updates = self.update_embedding_configs()?;
+
+        let mut new_inner_settings = InnerIndexSettings::from_index(self.index, self.wtxn)?;
         new_inner_settings.recompute_facets(self.wtxn, self.index)?;
 
         let primary_key_id = self
@@ -1197,20 +2208,73 @@ impl<'a, 't, 'i> Settings<'a, 't, 'i> {
             .primary_key(self.wtxn)?
             .and_then(|name| new_inner_settings.fields_ids_map.id(name));
         let settings_update_only = true;
-        let inner_settings_diff = InnerIndexSettingsDiff::new(
+        Ok(InnerIndexSettingsDiff::new(
             old_inner_settings,
             new_inner_settings,
             primary_key_id,
             embedding_config_updates,
             settings_update_only,
-        );
+        ))
+    }
+}
 
-        if inner_settings_diff.any_reindexing_needed() {
-            self.reindex(&progress_callback, &should_abort, inner_settings_diff)?;
+/// Checks the settings set by `set_settings` and estimates the cost of applying them to the index,
+/// without writing in it.
+///
+/// The settings and metadata of the index, stored in its main and embedders databases, are copied
+/// with the read transaction into a scratch index the settings are applied to. The index can
+/// therefore keep being written while the settings are checked.
+pub fn estimate_settings_update<F>(
+    index: &Index,
+    rtxn: &heed::RoTxn,
+    indexer_config: &IndexerConfig,
+    set_settings: F,
+) -> Result<SettingsUpdateEstimate>
+where
+    F: FnOnce(&mut Settings),
+{
+    let dir = tempfile::tempdir()?;
+    let mut options = heed::EnvOpenOptions::new();
+    options.map_size(index.map_size());
+    let scratch = Index::new(options, dir.path())?;
+
+    let estimate = (|| -> Result<SettingsUpdateEstimate> {
+        let mut wtxn = scratch.write_txn()?;
+        let databases = [
+            (index.main.remap_types::<Bytes, Bytes>(), scratch.main.remap_types::<Bytes, Bytes>()),
+            (
+                index.embedder_category_id.remap_types::<Bytes, Bytes>(),
+                scratch.embedder_category_id.remap_types::<Bytes, Bytes>(),
+            ),
+        ];
+        for (source, destination) in databases {
+            for entry in source.iter(rtxn)? {
+                let (key, value) = entry?;
+                destination.put(&mut wtxn, key, value)?;
+            }
         }
 
-        Ok(())
-    }
+        let mut builder = Settings::new(&mut wtxn, &scratch, indexer_config);
+        set_settings(&mut builder);
+        let estimate = builder.estimate();
+        wtxn.abort();
+        estimate
+    })();
+
+    // the scratch index must be closed before its directory is removed
+    scratch.prepare_for_closing().wait();
+    drop(dir);
+    estimate
+}
+
+/// The cost of a settings update, as estimated by [`Settings::estimate`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SettingsUpdateEstimate {
+    /// The number of documents that must be reindexed, zero when no reindexing is needed.
+    pub reindexed_documents: u64,
+    /// The names of the databases that must be rebuilt.
+    pub rebuilt_databases: Vec<&'static str>,
 }
 
 pub struct InnerIndexSettingsDiff {
@@ -1230,6 +2294,8 @@ pub struct InnerIndexSettingsDiff {
     pub(crate) cache_user_defined_searchables: bool,
     // Cache the check to see if the exact_attributes are different.
     pub(crate) cache_exact_attributes: bool,
+    // Cache the check to see if the tokenizer_profiles are different.
+    pub(crate) cache_tokenizer_profiles: bool,
 }
 
 impl InnerIndexSettingsDiff {
@@ -1266,10 +2332,20 @@ impl InnerIndexSettingsDiff {
                 || old_settings.proximity_precision != new_settings.proximity_precision
                 || old_settings.localized_searchable_fields_ids
                     != new_settings.localized_searchable_fields_ids
+                || old_settings.ignored_attributes != new_settings.ignored_attributes
+                || old_settings.content_types != new_settings.content_types
+                || old_settings.stemmer != new_settings.stemmer
+                || old_settings.number_tokenization != new_settings.number_tokenization
+                || old_settings.max_flatten_depth != new_settings.max_flatten_depth
+                || old_settings.default_language != new_settings.default_language
+                || old_settings.language_hint_field != new_settings.language_hint_field
         };
 
         let cache_exact_attributes = old_settings.exact_attributes != new_settings.exact_attributes;
 
+        let cache_tokenizer_profiles =
+            old_settings.tokenizer_profiles != new_settings.tokenizer_profiles;
+
         let cache_user_defined_searchables = old_settings.user_defined_searchable_fields
             != new_settings.user_defined_searchable_fields;
 
@@ -1283,6 +2359,7 @@ impl InnerIndexSettingsDiff {
             cache_reindex_searchable_without_user_defined,
             cache_user_defined_searchables,
             cache_exact_attributes,
+            cache_tokenizer_profiles,
         }
     }
 
@@ -1294,11 +2371,19 @@ impl InnerIndexSettingsDiff {
         self.cache_reindex_searchable_without_user_defined
             || self.cache_exact_attributes
             || self.cache_user_defined_searchables
+            || self.cache_tokenizer_profiles
+    }
+
+    /// Whether the prefix databases must be rebuilt, without reindexing the documents.
+    pub fn rebuild_prefixes(&self) -> bool {
+        self.old.min_prefix_length != self.new.min_prefix_length
     }
 
     pub fn reindex_proximities(&self) -> bool {
         // if any searchable settings force the reindexing
-        (self.cache_reindex_searchable_without_user_defined || self.cache_user_defined_searchables)
+        (self.cache_reindex_searchable_without_user_defined
+            || self.cache_user_defined_searchables
+            || self.cache_tokenizer_profiles)
         // and if any settings needs the proximity database created
             && (self.old.proximity_precision == ProximityPrecision::ByAttribute
                 || self.new.proximity_precision == ProximityPrecision::ByAttribute)
@@ -1307,6 +2392,11 @@ impl InnerIndexSettingsDiff {
     pub fn reindex_searchable_id(&self, id: FieldId) -> Option<DelAddOperation> {
         if self.cache_reindex_searchable_without_user_defined || self.cache_exact_attributes {
             Some(DelAddOperation::DeletionAndAddition)
+        } else if self.cache_tokenizer_profiles
+            && self.old.tokenizer_profile(id) != self.new.tokenizer_profile(id)
+        {
+            // only the attributes whose tokenizer profile changed are reindexed.
+            Some(DelAddOperation::DeletionAndAddition)
         } else if let Some(only_additional_fields) = &self.only_additional_fields {
             let additional_field = self.new.fields_ids_map.name(id).unwrap();
             if only_additional_fields.contains(additional_field) {
@@ -1342,6 +2432,11 @@ impl InnerIndexSettingsDiff {
 
         (existing_fields - old_faceted_fields) != (existing_fields - new_faceted_fields)
             || self.old.localized_faceted_fields_ids != self.new.localized_faceted_fields_ids
+            || self.old.ignored_attributes != self.new.ignored_attributes
+            || self.old.decimal_attributes != self.new.decimal_attributes
+            || self.old.normalized_facet_attributes != self.new.normalized_facet_attributes
+            || self.old.facet_case_folding != self.new.facet_case_folding
+            || self.old.max_flatten_depth != self.new.max_flatten_depth
     }
 
     pub fn reindex_vectors(&self) -> bool {
@@ -1373,6 +2468,18 @@ pub(crate) struct InnerIndexSettings {
     pub faceted_fields_ids: HashSet<FieldId>,
     pub searchable_fields_ids: Vec<FieldId>,
     pub exact_attributes: HashSet<FieldId>,
+    pub ignored_attributes: HashSet<String>,
+    pub decimal_attributes: HashSet<String>,
+    pub normalized_facet_attributes: HashSet<String>,
+    pub facet_case_folding: bool,
+    pub content_types: BTreeMap<String, ContentType>,
+    pub tokenizer_profiles: BTreeMap<String, TokenizerProfile>,
+    pub min_prefix_length: u8,
+    pub stemmer: Stemmer,
+    pub number_tokenization: NumberTokenization,
+    pub max_flatten_depth: Option<usize>,
+    pub default_language: Option<Language>,
+    pub language_hint_field: Option<String>,
     pub proximity_precision: ProximityPrecision,
     pub embedding_configs: EmbeddingConfigs,
     pub existing_fields: HashSet<String>,
@@ -1397,6 +2504,21 @@ impl InnerIndexSettings {
         let mut searchable_fields_ids = index.searchable_fields_ids(rtxn)?;
         let mut faceted_fields_ids = index.faceted_fields_ids(rtxn)?;
         let exact_attributes = index.exact_attributes_ids(rtxn)?;
+        let ignored_attributes: HashSet<_> =
+            index.ignored_attributes(rtxn)?.into_iter().map(String::from).collect();
+        let decimal_attributes =
+            index.decimal_attributes(rtxn)?.into_iter().map(String::from).collect();
+        let normalized_facet_attributes =
+            index.normalized_facet_attributes(rtxn)?.into_iter().map(String::from).collect();
+        let facet_case_folding = index.facet_case_folding(rtxn)?;
+        let content_types = index.content_types(rtxn)?;
+        let tokenizer_profiles = index.tokenizer_profiles(rtxn)?;
+        let min_prefix_length = index.min_prefix_length(rtxn)?.unwrap_or(1).max(1);
+        let stemmer = Stemmer::new(index.stemming(rtxn)?);
+        let number_tokenization = index.number_tokenization(rtxn)?;
+        let max_flatten_depth = index.max_flatten_depth(rtxn)?;
+        let default_language = index.default_language(rtxn)?;
+        let language_hint_field = index.language_hint_field(rtxn)?.map(String::from);
         let proximity_precision = index.proximity_precision(rtxn)?.unwrap_or_default();
         let embedding_configs = embedders(index.embedding_configs(rtxn)?)?;
         let existing_fields: HashSet<_> = index
@@ -1406,6 +2528,7 @@ impl InnerIndexSettings {
             .collect();
         // index.fields_ids_map($a)? ==>> fields_ids_map
         let geo_fields_ids = match fields_ids_map.id("_geo") {
+            Some(_) if is_ignored("_geo", &ignored_attributes) => None,
             Some(gfid) => {
                 let is_sortable = index.sortable_fields_ids(rtxn)?.contains(&gfid);
                 let is_filterable = index.filterable_fields_ids(rtxn)?.contains(&gfid);
@@ -1437,6 +2560,18 @@ impl InnerIndexSettings {
         let vectors_fids = fields_ids_map.nested_ids(RESERVED_VECTORS_FIELD_NAME);
         searchable_fields_ids.retain(|id| !vectors_fids.contains(id));
         faceted_fields_ids.retain(|id| !vectors_fids.contains(id));
+        retain_not_ignored(
+            &mut searchable_fields_ids,
+            &fields_ids_map,
+            &ignored_attributes,
+            max_flatten_depth,
+        );
+        faceted_fields_ids.retain(|id| {
+            fields_ids_map.name(*id).map_or(true, |name| {
+                !is_ignored(name, &ignored_attributes)
+                    && !is_too_deep(name, &fields_ids_map, max_flatten_depth)
+            })
+        });
 
         Ok(Self {
             stop_words,
@@ -1448,6 +2583,18 @@ impl InnerIndexSettings {
             faceted_fields_ids,
             searchable_fields_ids,
             exact_attributes,
+            ignored_attributes,
+            decimal_attributes,
+            normalized_facet_attributes,
+            facet_case_folding,
+            content_types,
+            tokenizer_profiles,
+            min_prefix_length,
+            stemmer,
+            number_tokenization,
+            max_flatten_depth,
+            default_language,
+            language_hint_field,
             proximity_precision,
             embedding_configs,
             existing_fields,
@@ -1465,6 +2612,10 @@ impl InnerIndexSettings {
             .fields_ids_map
             .iter()
             .filter(|(fid, _field)| !self.non_faceted_fields_ids.contains(fid))
+            .filter(|(_fid, field)| !is_ignored(field, &self.ignored_attributes))
+            .filter(|(_fid, field)| {
+                !is_too_deep(field, &self.fields_ids_map, self.max_flatten_depth)
+            })
             .filter(|(_fid, field)| crate::is_faceted(field, &self.user_defined_faceted_fields))
             .map(|(_fid, field)| field.to_string())
             .collect();
@@ -1501,6 +2652,12 @@ impl InnerIndexSettings {
             )?;
         }
         self.searchable_fields_ids = index.searchable_fields_ids(wtxn)?;
+        retain_not_ignored(
+            &mut self.searchable_fields_ids,
+            &self.fields_ids_map,
+            &self.ignored_attributes,
+            self.max_flatten_depth,
+        );
         let localized_attributes_rules = index.localized_attributes_rules(wtxn)?;
         self.localized_searchable_fields_ids = LocalizedFieldIds::new(
             &localized_attributes_rules,
@@ -1510,6 +2667,92 @@ impl InnerIndexSettings {
 
         Ok(())
     }
+
+    /// Returns the content type of the field, inherited from the attribute it is nested in.
+    pub fn content_type(&self, field_id: FieldId) -> Option<ContentType> {
+        let name = self.fields_ids_map.name(field_id)?;
+        self.content_types
+            .iter()
+            .find(|(attribute, _)| crate::is_faceted_by(name, attribute))
+            .map(|(_, content_type)| *content_type)
+    }
+
+    /// Returns the ids of the faceted fields whose strings are grouped regardless of their
+    /// diacritics, the fields nested in a normalized attribute included.
+    pub fn normalized_facet_fields_ids(&self) -> BTreeSet<FieldId> {
+        self.faceted_fields_ids
+            .iter()
+            .copied()
+            .filter(|fid| self.facet_normalization(*fid).remove_diacritics)
+            .collect()
+    }
+
+    /// Returns how the facet strings of the field are normalized.
+    pub fn facet_normalization(&self, field_id: FieldId) -> FacetNormalization {
+        let remove_diacritics = self.fields_ids_map.name(field_id).map_or(false, |name| {
+            self.normalized_facet_attributes.iter().any(|attr| crate::is_faceted_by(name, attr))
+        });
+        FacetNormalization { case_folding: self.facet_case_folding, remove_diacritics }
+    }
+
+    /// Returns the tokenizer profile of the field, inherited from the attribute it is nested in.
+    pub fn tokenizer_profile(&self, field_id: FieldId) -> TokenizerProfile {
+        self.fields_ids_map
+            .name(field_id)
+            .and_then(|name| {
+                self.tokenizer_profiles
+                    .iter()
+                    .find(|(attribute, _)| crate::is_faceted_by(name, attribute))
+            })
+            .map_or(TokenizerProfile::Default, |(_, profile)| *profile)
+    }
+}
+
+/// Returns `true` if the field is one of the ignored attributes or nested in one of them.
+fn is_ignored(field: &str, ignored_attributes: &HashSet<String>) -> bool {
+    ignored_attributes.iter().any(|ignored| crate::is_faceted_by(field, ignored))
+}
+
+/// Returns `true` if the field is nested in more objects than the maximum flatten depth allows.
+///
+/// The `_geo` field is never too deep as its `lat` and `lng` are needed by the geosearch.
+fn is_too_deep(
+    field: &str,
+    fields_ids_map: &FieldsIdsMap,
+    max_flatten_depth: Option<usize>,
+) -> bool {
+    match max_flatten_depth {
+        Some(max_depth) => {
+            !crate::is_faceted_by(field, "_geo") && nesting_depth(field, fields_ids_map) > max_depth
+        }
+        None => false,
+    }
+}
+
+/// Returns the number of objects the field is nested in.
+///
+/// The flattening registers every object it goes through as a field, so only the dots that
+/// follow one of these parent fields count: the dots of a key such as `"a.b": 1` are not a level.
+fn nesting_depth(field: &str, fields_ids_map: &FieldsIdsMap) -> usize {
+    field
+        .match_indices('.')
+        .filter(|(position, _)| fields_ids_map.id(&field[..*position]).is_some())
+        .count()
+}
+
+/// Removes the fields that are ignored or nested too deep to be indexed.
+fn retain_not_ignored(
+    fields_ids: &mut Vec<FieldId>,
+    fields_ids_map: &FieldsIdsMap,
+    ignored_attributes: &HashSet<String>,
+    max_flatten_depth: Option<usize>,
+) {
+    fields_ids.retain(|id| {
+        fields_ids_map.name(*id).map_or(true, |name| {
+            !is_ignored(name, ignored_attributes)
+                && !is_too_deep(name, fields_ids_map, max_flatten_depth)
+        })
+    });
 }
 
 fn embedders(embedding_configs: Vec<IndexEmbeddingConfig>) -> Result<EmbeddingConfigs> {
@@ -1738,6 +2981,7 @@ mod tests {
     use meili_snap::snapshot;
 
     use super::*;
+    use crate::documents::documents_batch_reader_from_objects;
     use crate::error::Error;
     use crate::index::tests::TempIndex;
     use crate::update::ClearDocuments;
@@ -1831,6 +3075,43 @@ mod tests {
         assert_eq!(documents[0].1.get(fid_map.id("name").unwrap()), Some(&br#""kevin""#[..]));
     }
 
+    #[test]
+    fn estimate_searchable_fields_update() {
+        let index = TempIndex::new();
+
+        index
+            .add_documents(documents!([
+                { "id": 1, "name": "kevin", "age": 23 },
+                { "id": 2, "name": "kevina", "age": 21 },
+                { "id": 3, "name": "benoit", "age": 34 }
+            ]))
+            .unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        let estimate =
+            estimate_settings_update(&index.inner, &rtxn, &index.indexer_config, |builder| {
+                builder.set_searchable_fields(vec![S("name")]);
+            })
+            .unwrap();
+        drop(rtxn);
+
+        assert_eq!(estimate.reindexed_documents, 3);
+        snapshot!(format!("{:?}", estimate.rebuilt_databases), @r###"["word-docids", "exact-word-docids", "word-prefix-docids", "exact-word-prefix-docids", "word-position-docids", "word-field-id-docids", "word-prefix-position-docids", "word-prefix-field-id-docids", "field-id-word-count-docids"]"###);
+
+        // The settings must not have been applied.
+        let rtxn = index.read_txn().unwrap();
+        let user_defined_searchable_fields = index.user_defined_searchable_fields(&rtxn).unwrap();
+        snapshot!(format!("{user_defined_searchable_fields:?}"), @"None");
+
+        // The primary key can't be changed once the documents are indexed.
+        let error =
+            estimate_settings_update(&index.inner, &rtxn, &index.indexer_config, |builder| {
+                builder.set_primary_key(S("name"));
+            })
+            .unwrap_err();
+        assert!(matches!(error, Error::UserError(UserError::PrimaryKeyCannotBeChanged(_))));
+    }
+
     #[test]
     fn mixup_searchable_with_displayed_fields() {
         let mut index = TempIndex::new();
@@ -1970,8 +3251,12 @@ mod tests {
         let fidmap = index.fields_ids_map(&rtxn).unwrap();
         for document in index.all_documents(&rtxn).unwrap() {
             let document = document.unwrap();
-            let json = crate::obkv_to_json(&fidmap.ids().collect::<Vec<_>>(), &fidmap, document.1)
-                .unwrap();
+            let json = crate::obkv_to_json(
+                &fidmap.ids().collect::<Vec<_>>(),
+                &fidmap,
+                document.1.as_obkv(),
+            )
+            .unwrap();
             println!("json: {:?}", json);
         }
         let count = index
@@ -2068,6 +3353,663 @@ mod tests {
         assert_eq!(count, 5);
     }
 
+    #[test]
+    fn set_and_reset_ignored_attributes() {
+        let index = TempIndex::new();
+
+        index
+            .update_settings(|settings| {
+                settings.set_filterable_fields(hashset! { S("name"), S("secret") });
+                settings.set_ignored_attributes(hashset! { S("secret") });
+            })
+            .unwrap();
+
+        index
+            .add_documents(documents!([
+                { "id": 1, "name": "kevin", "secret": "hidden" },
+                { "id": 2, "name": "bob", "secret": "concealed" },
+            ]))
+            .unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        let fid_map = index.fields_ids_map(&rtxn).unwrap();
+        let secret_fid = fid_map.id("secret").unwrap();
+
+        // The ignored attribute is still stored and can be retrieved.
+        let documents = index.documents(&rtxn, [0]).unwrap();
+        assert_eq!(documents[0].1.get(secret_fid), Some(&br#""hidden""#[..]));
+
+        // But it is neither searchable...
+        assert!(index.word_docids.get(&rtxn, "kevin").unwrap().is_some());
+        assert!(index.word_docids.get(&rtxn, "hidden").unwrap().is_none());
+        let result = index.search(&rtxn).query("hidden").execute().unwrap();
+        assert_eq!(result.documents_ids, Vec::<u32>::new());
+
+        // ...nor faceted.
+        assert!(!index.faceted_fields_ids(&rtxn).unwrap().contains(&secret_fid));
+        let count = index
+            .facet_id_string_docids
+            .remap_key_type::<Bytes>()
+            .prefix_iter(&rtxn, &secret_fid.to_be_bytes())
+            .unwrap()
+            .count();
+        assert_eq!(count, 0);
+        drop(rtxn);
+
+        // Once the attribute isn't ignored anymore, it is indexed again.
+        index
+            .update_settings(|settings| {
+                settings.reset_ignored_attributes();
+            })
+            .unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        assert!(index.word_docids.get(&rtxn, "hidden").unwrap().is_some());
+        assert!(index.faceted_fields_ids(&rtxn).unwrap().contains(&secret_fid));
+        let count = index
+            .facet_id_string_docids
+            .remap_key_type::<Bytes>()
+            .prefix_iter(&rtxn, &secret_fid.to_be_bytes())
+            .unwrap()
+            .count();
+        assert_ne!(count, 0);
+    }
+
+    #[test]
+    fn set_and_reset_content_types() {
+        let index = TempIndex::new();
+
+        index
+            .update_settings(|settings| {
+                settings.set_content_types(btreemap! { S("body") => ContentType::Html });
+            })
+            .unwrap();
+
+        let body = r#"<div class="intro">Hello <b>world</b></div><script>let hidden;</script>"#;
+        index.add_documents(documents!([{ "id": 1, "body": body }])).unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        let fid_map = index.fields_ids_map(&rtxn).unwrap();
+        let body_fid = fid_map.id("body").unwrap();
+
+        // The original content is stored untouched.
+        let documents = index.documents(&rtxn, [0]).unwrap();
+        let stored: String = serde_json::from_slice(documents[0].1.get(body_fid).unwrap()).unwrap();
+        assert_eq!(stored, body);
+
+        // The text matches but the markup doesn't.
+        let result = index.search(&rtxn).query("world").execute().unwrap();
+        assert_eq!(result.documents_ids, vec![0]);
+        for markup in ["div", "class", "intro", "b", "script", "hidden"] {
+            assert!(index.word_docids.get(&rtxn, markup).unwrap().is_none(), "{markup}");
+            let result = index.search(&rtxn).query(markup).execute().unwrap();
+            assert_eq!(result.documents_ids, Vec::<u32>::new(), "{markup}");
+        }
+        drop(rtxn);
+
+        // Once the content type is reset, the markup is indexed as text.
+        index
+            .update_settings(|settings| {
+                settings.reset_content_types();
+            })
+            .unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        assert!(index.word_docids.get(&rtxn, "world").unwrap().is_some());
+        assert!(index.word_docids.get(&rtxn, "intro").unwrap().is_some());
+    }
+
+    #[test]
+    fn set_and_reset_tokenizer_profiles() {
+        let index = TempIndex::new();
+
+        index
+            .update_settings(|settings| {
+                settings.set_tokenizer_profiles(btreemap! {
+                    S("email") => TokenizerProfile::Email,
+                    S("path") => TokenizerProfile::Path,
+                });
+            })
+            .unwrap();
+
+        index
+            .add_documents(documents!([
+                { "id": 1, "email": "John.Doe@example.com", "path": "/usr/local/bin" },
+            ]))
+            .unwrap();
+
+        // The identifiers are indexed as single words.
+        let rtxn = index.read_txn().unwrap();
+        for word in ["john.doe@example.com", "john.doe", "example.com", "usr/local/bin", "local"] {
+            assert!(index.word_docids.get(&rtxn, word).unwrap().is_some(), "{word}");
+        }
+        for fragment in ["doe", "example", "usr/local"] {
+            assert!(index.word_docids.get(&rtxn, fragment).unwrap().is_none(), "{fragment}");
+        }
+        let result = index.search(&rtxn).query("john.doe@example.com").execute().unwrap();
+        assert_eq!(result.documents_ids, vec![0]);
+        drop(rtxn);
+
+        // Once the tokenizer profiles are reset, the identifiers are tokenized as prose.
+        index
+            .update_settings(|settings| {
+                settings.reset_tokenizer_profiles();
+            })
+            .unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        assert!(index.word_docids.get(&rtxn, "doe").unwrap().is_some());
+        assert!(index.word_docids.get(&rtxn, "john.doe").unwrap().is_none());
+    }
+
+    #[test]
+    fn set_and_reset_decimal_attributes() {
+        let index = TempIndex::new();
+
+        index
+            .update_settings(|settings| {
+                settings.set_filterable_fields(hashset! { S("price") });
+                settings.set_decimal_attributes(hashset! { S("price") });
+            })
+            .unwrap();
+
+        index
+            .add_documents(documents!([
+                { "id": 0, "price": "19.990" },
+                { "id": 1, "price": "12345678901234567.89" },
+                { "id": 2, "price": 4.5 },
+                { "id": 3, "price": "free" },
+            ]))
+            .unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        let price_fid = index.fields_ids_map(&rtxn).unwrap().id("price").unwrap();
+
+        // The decimals are stored exactly as they were sent...
+        let documents = index.documents(&rtxn, [0, 1]).unwrap();
+        assert_eq!(documents[0].1.get(price_fid), Some(&br#""19.990""#[..]));
+        assert_eq!(documents[1].1.get(price_fid), Some(&br#""12345678901234567.89""#[..]));
+
+        // ...but can be filtered as numbers.
+        let filter = Filter::from_str("price > 19").unwrap().unwrap();
+        assert_eq!(filter.evaluate(&rtxn, &index).unwrap(), RoaringBitmap::from_iter([0, 1]));
+        let filter = Filter::from_str("price = free").unwrap().unwrap();
+        assert_eq!(filter.evaluate(&rtxn, &index).unwrap(), RoaringBitmap::from_iter([3]));
+        drop(rtxn);
+
+        // Once the attribute isn't a decimal attribute anymore, its strings are only strings.
+        index
+            .update_settings(|settings| {
+                settings.reset_decimal_attributes();
+            })
+            .unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        let filter = Filter::from_str("price > 19").unwrap().unwrap();
+        assert_eq!(filter.evaluate(&rtxn, &index).unwrap(), RoaringBitmap::new());
+        let filter = Filter::from_str("price < 19").unwrap().unwrap();
+        assert_eq!(filter.evaluate(&rtxn, &index).unwrap(), RoaringBitmap::from_iter([2]));
+    }
+
+    #[test]
+    fn set_and_reset_normalized_facet_attributes() {
+        let index = TempIndex::new();
+
+        index
+            .update_settings(|settings| {
+                settings.set_filterable_fields(hashset! { S("brand") });
+            })
+            .unwrap();
+
+        index
+            .add_documents(documents!([
+                { "id": 0, "brand": "Apple" },
+                { "id": 1, "brand": "apple" },
+                { "id": 2, "brand": "Apple" },
+                { "id": 3, "brand": "Crème" },
+                { "id": 4, "brand": "creme" },
+                { "id": 5, "brand": "Crème" },
+            ]))
+            .unwrap();
+
+        let brands = |index: &TempIndex| {
+            let rtxn = index.read_txn().unwrap();
+            let distribution = crate::FacetDistribution::new(&rtxn, index)
+                .facets(std::iter::once(("brand", crate::OrderBy::default())))
+                .execute()
+                .unwrap();
+            distribution["brand"].clone().into_iter().collect::<BTreeMap<_, _>>()
+        };
+        let filter = |index: &TempIndex, filter: &str| {
+            let rtxn = index.read_txn().unwrap();
+            Filter::from_str(filter).unwrap().unwrap().evaluate(&rtxn, index).unwrap()
+        };
+
+        // By default the diacritics are kept.
+        assert_eq!(filter(&index, "brand = creme"), RoaringBitmap::from_iter([4]));
+
+        // The facet values of the normalized attributes are grouped regardless of their case and
+        // diacritics, and displayed with their most frequent form.
+        index
+            .update_settings(|settings| {
+                settings.set_normalized_facet_attributes(hashset! { S("brand") });
+            })
+            .unwrap();
+        assert_eq!(brands(&index), btreemap! { S("Apple") => 3, S("Crème") => 3 });
+        assert_eq!(filter(&index, "brand = APPLE"), RoaringBitmap::from_iter([0, 1, 2]));
+        assert_eq!(filter(&index, "brand = CREME"), RoaringBitmap::from_iter([3, 4, 5]));
+
+        // The most frequent form follows the documents updates.
+        index
+            .add_documents(documents!([
+                { "id": 6, "brand": "apple" },
+                { "id": 2, "brand": "apple" },
+                { "id": 5, "brand": "creme" },
+                { "id": 7, "brand": "creme" },
+            ]))
+            .unwrap();
+        assert_eq!(brands(&index), btreemap! { S("apple") => 4, S("creme") => 4 });
+
+        index
+            .update_settings(|settings| {
+                settings.reset_normalized_facet_attributes();
+            })
+            .unwrap();
+        assert_eq!(filter(&index, "brand = creme"), RoaringBitmap::from_iter([4, 5, 7]));
+        assert_eq!(filter(&index, "brand = crème"), RoaringBitmap::from_iter([3]));
+        let rtxn = index.read_txn().unwrap();
+        assert!(index.facet_id_string_original_counts.is_empty(&rtxn).unwrap());
+    }
+
+    #[test]
+    fn set_and_reset_facet_normalization() {
+        let index = TempIndex::new();
+
+        index
+            .update_settings(|settings| {
+                settings.set_filterable_fields(hashset! { S("brand") });
+            })
+            .unwrap();
+
+        index
+            .add_documents(documents!([
+                { "id": 0, "brand": "Apple" },
+                { "id": 1, "brand": "apple" },
+                { "id": 2, "brand": "Crème" },
+                { "id": 3, "brand": "creme" },
+            ]))
+            .unwrap();
+
+        let filter = |index: &TempIndex, filter: &str| {
+            let rtxn = index.read_txn().unwrap();
+            Filter::from_str(filter).unwrap().unwrap().evaluate(&rtxn, index).unwrap()
+        };
+
+        // By default the facet strings are lowercased and the diacritics are kept.
+        assert_eq!(filter(&index, "brand = APPLE"), RoaringBitmap::from_iter([0, 1]));
+        assert_eq!(filter(&index, "brand = creme"), RoaringBitmap::from_iter([3]));
+
+        index
+            .update_settings(|settings| {
+                settings.set_facet_case_folding(false);
+            })
+            .unwrap();
+        assert_eq!(filter(&index, "brand = apple"), RoaringBitmap::from_iter([1]));
+        assert_eq!(filter(&index, "brand = Apple"), RoaringBitmap::from_iter([0]));
+        assert!(filter(&index, "brand = APPLE").is_empty());
+
+        // The diacritics are removed without lowercasing the strings.
+        index
+            .update_settings(|settings| {
+                settings.set_normalized_facet_attributes(hashset! { S("brand") });
+            })
+            .unwrap();
+        assert_eq!(filter(&index, "brand = Creme"), RoaringBitmap::from_iter([2]));
+        assert_eq!(filter(&index, "brand = creme"), RoaringBitmap::from_iter([3]));
+
+        // All the facet values are now grouped regardless of their case and diacritics.
+        index
+            .update_settings(|settings| {
+                settings.reset_facet_case_folding();
+            })
+            .unwrap();
+        assert_eq!(filter(&index, "brand = CREME"), RoaringBitmap::from_iter([2, 3]));
+        let rtxn = index.read_txn().unwrap();
+        let distribution = crate::FacetDistribution::new(&rtxn, &index)
+            .facets(std::iter::once(("brand", crate::OrderBy::default())))
+            .execute()
+            .unwrap();
+        let brands = distribution["brand"].clone().into_iter().collect::<BTreeMap<_, _>>();
+        assert_eq!(brands, btreemap! { S("Apple") => 2, S("Crème") => 2 });
+        drop(rtxn);
+
+        index
+            .update_settings(|settings| {
+                settings.reset_normalized_facet_attributes();
+            })
+            .unwrap();
+        assert_eq!(filter(&index, "brand = creme"), RoaringBitmap::from_iter([3]));
+        let rtxn = index.read_txn().unwrap();
+        assert!(index.facet_id_string_original_counts.is_empty(&rtxn).unwrap());
+    }
+
+    #[test]
+    fn set_and_reset_attribute_transforms() {
+        let index = TempIndex::new();
+
+        index
+            .update_settings(|settings| {
+                settings.set_attribute_transforms(btreemap! {
+                    S("sku") => vec![AttributeTransform::Trim, AttributeTransform::Lowercase],
+                    S("price") => vec![AttributeTransform::ToNumber],
+                });
+            })
+            .unwrap();
+
+        index.add_documents(documents!([{ "id": 1, "sku": "  ABC-12 ", "price": "4.5" }])).unwrap();
+
+        // The normalized values are stored and indexed.
+        let rtxn = index.read_txn().unwrap();
+        let fid_map = index.fields_ids_map(&rtxn).unwrap();
+        let sku_fid = fid_map.id("sku").unwrap();
+        let price_fid = fid_map.id("price").unwrap();
+        let documents = index.documents(&rtxn, [0]).unwrap();
+        let sku: serde_json::Value =
+            serde_json::from_slice(documents[0].1.get(sku_fid).unwrap()).unwrap();
+        let price: serde_json::Value =
+            serde_json::from_slice(documents[0].1.get(price_fid).unwrap()).unwrap();
+        assert_eq!(sku, serde_json::json!("abc-12"));
+        assert_eq!(price, serde_json::json!(4.5));
+        drop(rtxn);
+
+        // A value that cannot be transformed is reported.
+        let error = index.add_documents(documents!([{ "id": 2, "price": "free" }])).unwrap_err();
+        assert!(matches!(
+            error,
+            Error::UserError(UserError::InvalidDocumentAttributeTransform { .. })
+        ));
+        snapshot!(error, @"The `toNumber` transform of the `price` attribute cannot be applied to the document with the id: `2`, it received: `\"free\"`.");
+
+        // Once the transforms are reset, the values are stored untouched.
+        index
+            .update_settings(|settings| {
+                settings.reset_attribute_transforms();
+            })
+            .unwrap();
+        index.add_documents(documents!([{ "id": 2, "sku": " XYZ ", "price": "free" }])).unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        let documents = index.documents(&rtxn, [1]).unwrap();
+        let sku: serde_json::Value =
+            serde_json::from_slice(documents[0].1.get(sku_fid).unwrap()).unwrap();
+        assert_eq!(sku, serde_json::json!(" XYZ "));
+    }
+
+    #[test]
+    fn set_and_reset_min_prefix_length() {
+        let index = TempIndex::new();
+
+        // enough words share the same prefixes for them to be part of the prefix databases
+        let documents = (0..120u8).map(|i| {
+            let word = format!("abc{}{}", (b'a' + i / 26) as char, (b'a' + i % 26) as char);
+            serde_json::json!({ "id": i, "text": word }).as_object().unwrap().clone()
+        });
+        index.add_documents(documents_batch_reader_from_objects(documents)).unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        assert!(index.word_prefix_docids.get(&rtxn, "ab").unwrap().is_some());
+        assert!(index.word_prefix_docids.get(&rtxn, "abc").unwrap().is_some());
+
+        // only the prefix databases are rebuilt, the documents are not reindexed
+        let estimate =
+            estimate_settings_update(&index.inner, &rtxn, &index.indexer_config, |builder| {
+                builder.set_min_prefix_length(3);
+            })
+            .unwrap();
+        assert_eq!(estimate.reindexed_documents, 0);
+        snapshot!(format!("{:?}", estimate.rebuilt_databases), @r###"["word-prefix-docids", "exact-word-prefix-docids", "word-prefix-position-docids", "word-prefix-field-id-docids"]"###);
+        drop(rtxn);
+
+        index.update_settings(|settings| settings.set_min_prefix_length(3)).unwrap();
+
+        // the 2-char prefixes are no longer part of the prefix databases but are still matched
+        let rtxn = index.read_txn().unwrap();
+        let prefixes_fst = index.words_prefixes_fst(&rtxn).unwrap();
+        assert!(!prefixes_fst.contains("ab"));
+        assert!(prefixes_fst.contains("abc"));
+        assert!(index.word_prefix_docids.get(&rtxn, "a").unwrap().is_none());
+        assert!(index.word_prefix_docids.get(&rtxn, "ab").unwrap().is_none());
+        let docids = index.word_prefix_docids.get(&rtxn, "abc").unwrap().unwrap();
+        assert_eq!(docids.len(), 120);
+        let result = index.search(&rtxn).query("ab").execute().unwrap();
+        assert!(!result.documents_ids.is_empty());
+        drop(rtxn);
+
+        index.update_settings(|settings| settings.reset_min_prefix_length()).unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        assert!(index.words_prefixes_fst(&rtxn).unwrap().contains("ab"));
+        let docids = index.word_prefix_docids.get(&rtxn, "ab").unwrap().unwrap();
+        assert_eq!(docids.len(), 120);
+    }
+
+    #[test]
+    fn set_and_reset_stemming() {
+        let index = TempIndex::new();
+
+        index
+            .add_documents(documents!([
+                { "id": 0, "text": "a pony in the meadow" },
+                { "id": 1, "text": "the ponies are running" },
+            ]))
+            .unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        let result = index.search(&rtxn).query("ponies").execute().unwrap();
+        assert_eq!(result.documents_ids, vec![1]);
+        drop(rtxn);
+
+        // the documents are reindexed with the stems of their words
+        index.update_settings(|settings| settings.set_stemming(vec![Language::Eng])).unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        assert!(index.word_docids.get(&rtxn, "poni").unwrap().is_some());
+        assert!(index.word_docids.get(&rtxn, "ponies").unwrap().is_none());
+        let mut result = index.search(&rtxn).query("ponies").execute().unwrap();
+        result.documents_ids.sort_unstable();
+        assert_eq!(result.documents_ids, vec![0, 1]);
+        drop(rtxn);
+
+        index.update_settings(|settings| settings.reset_stemming()).unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        assert!(index.word_docids.get(&rtxn, "poni").unwrap().is_none());
+        let result = index.search(&rtxn).query("ponies").execute().unwrap();
+        assert_eq!(result.documents_ids, vec![1]);
+        drop(rtxn);
+
+        let error = index
+            .update_settings(|settings| settings.set_stemming(vec![Language::Fra]))
+            .unwrap_err();
+        assert!(matches!(error, Error::UserError(UserError::UnsupportedStemmingLanguage(_))));
+    }
+
+    #[test]
+    fn set_and_reset_number_tokenization() {
+        let index = TempIndex::new();
+
+        index
+            .add_documents(documents!([
+                { "id": 0, "text": "engine model 3.5" },
+                { "id": 1, "text": "3 wheels" },
+                { "id": 2, "text": "5 stars" },
+            ]))
+            .unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        assert!(index.word_docids.get(&rtxn, "3.5").unwrap().is_none());
+        drop(rtxn);
+
+        // the documents are reindexed with their numbers kept as single words
+        index
+            .update_settings(|settings| settings.set_number_tokenization(NumberTokenization::Keep))
+            .unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        assert!(index.word_docids.get(&rtxn, "3.5").unwrap().is_some());
+        let result = index.search(&rtxn).query("3.5").execute().unwrap();
+        assert_eq!(result.documents_ids, vec![0]);
+        let result = index.search(&rtxn).query("3").execute().unwrap();
+        assert_eq!(result.documents_ids, vec![1]);
+        drop(rtxn);
+
+        index.update_settings(|settings| settings.reset_number_tokenization()).unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        assert!(index.word_docids.get(&rtxn, "3.5").unwrap().is_none());
+        let mut result = index.search(&rtxn).query("3").execute().unwrap();
+        result.documents_ids.sort_unstable();
+        assert_eq!(result.documents_ids, vec![0, 1]);
+    }
+
+    #[test]
+    fn set_and_reset_max_flatten_depth() {
+        let index = TempIndex::new();
+
+        index
+            .update_settings(|settings| {
+                settings.set_filterable_fields(hashset! { S("level1") });
+                settings.set_max_flatten_depth(2);
+            })
+            .unwrap();
+
+        index
+            .add_documents(documents!([{
+                "id": 0,
+                "top.level.dotted.key": "dotted",
+                "level1": {
+                    "name": "shallow",
+                    "nested.dotted.key": "nested",
+                    "level2": {
+                        "name": "middle",
+                        "level3": { "level4": "buried" }
+                    }
+                }
+            }]))
+            .unwrap();
+
+        // the fields nested up to two levels deep are searchable and filterable
+        let rtxn = index.read_txn().unwrap();
+        let result = index.search(&rtxn).query("shallow").execute().unwrap();
+        assert_eq!(result.documents_ids, vec![0]);
+        let result = index.search(&rtxn).query("middle").execute().unwrap();
+        assert_eq!(result.documents_ids, vec![0]);
+        let filter = Filter::from_str("level1.level2.name = middle").unwrap().unwrap();
+        let result = index.search(&rtxn).filter(filter).execute().unwrap();
+        assert_eq!(result.documents_ids, vec![0]);
+
+        // the dots of the keys are not nesting levels
+        let result = index.search(&rtxn).query("dotted").execute().unwrap();
+        assert_eq!(result.documents_ids, vec![0]);
+        let result = index.search(&rtxn).query("nested").execute().unwrap();
+        assert_eq!(result.documents_ids, vec![0]);
+        let filter = Filter::from_str("level1.nested.dotted.key = nested").unwrap().unwrap();
+        let result = index.search(&rtxn).filter(filter).execute().unwrap();
+        assert_eq!(result.documents_ids, vec![0]);
+
+        // the deeper ones are stored but not indexed
+        let result = index.search(&rtxn).query("buried").execute().unwrap();
+        assert!(result.documents_ids.is_empty());
+        let filter = Filter::from_str("level1.level2.level3.level4 = buried").unwrap().unwrap();
+        let result = index.search(&rtxn).filter(filter).execute().unwrap();
+        assert!(result.documents_ids.is_empty());
+        let fields_ids_map = index.fields_ids_map(&rtxn).unwrap();
+        let level1 = fields_ids_map.id("level1").unwrap();
+        let (_, document) = index.documents(&rtxn, [0]).unwrap().remove(0);
+        let level1: serde_json::Value =
+            serde_json::from_slice(document.get(level1).unwrap()).unwrap();
+        assert_eq!(level1["level2"]["level3"]["level4"], serde_json::json!("buried"));
+        drop(rtxn);
+
+        // the documents are reindexed without limit once the setting is reset
+        index.update_settings(|settings| settings.reset_max_flatten_depth()).unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        let result = index.search(&rtxn).query("buried").execute().unwrap();
+        assert_eq!(result.documents_ids, vec![0]);
+        let filter = Filter::from_str("level1.level2.level3.level4 = buried").unwrap().unwrap();
+        let result = index.search(&rtxn).filter(filter).execute().unwrap();
+        assert_eq!(result.documents_ids, vec![0]);
+    }
+
+    #[test]
+    fn set_default_language_and_language_hint_field() {
+        // a text written in kanji only is detected as Chinese
+        let text = "東京大学生命科学研究科";
+        let tokenize = |allow_list: Option<&[Language]>| -> BTreeSet<String> {
+            let tokenizer = TokenizerBuilder::<Vec<u8>>::new().into_tokenizer();
+            tokenizer
+                .tokenize_with_allow_list(text, allow_list)
+                .filter(|token| token.is_word())
+                .map(|token| token.lemma().to_string())
+                .collect()
+        };
+        let detected_words = tokenize(None);
+        let japanese_words = tokenize(Some(&[Language::Jpn]));
+        assert_ne!(detected_words, japanese_words);
+
+        let index = TempIndex::new();
+        index.update_settings(|settings| settings.set_language_hint_field(S("lang"))).unwrap();
+        index
+            .add_documents(documents!([
+                { "id": 0, "text": text },
+                { "id": 1, "lang": "jpn", "text": text },
+            ]))
+            .unwrap();
+
+        let word_docids = |word: &str| {
+            let rtxn = index.read_txn().unwrap();
+            index.word_docids.get(&rtxn, word).unwrap().unwrap_or_default()
+        };
+
+        // only the document with a hint is tokenized as Japanese
+        for word in &detected_words {
+            assert!(word_docids(word).contains(0), "{word}");
+        }
+        for word in &japanese_words {
+            assert!(word_docids(word).contains(1), "{word}");
+        }
+        for word in japanese_words.difference(&detected_words) {
+            assert!(!word_docids(word).contains(0), "{word}");
+        }
+
+        // the documents without hint are tokenized with the default language
+        index.update_settings(|settings| settings.set_default_language(Language::Jpn)).unwrap();
+        for word in &japanese_words {
+            assert_eq!(word_docids(word), RoaringBitmap::from_iter([0, 1]), "{word}");
+        }
+        for word in detected_words.difference(&japanese_words) {
+            assert!(word_docids(word).is_empty(), "{word}");
+        }
+
+        // and the queries too
+        let rtxn = index.read_txn().unwrap();
+        let result = index.search(&rtxn).query(text).execute().unwrap();
+        assert_eq!(result.documents_ids, vec![0, 1]);
+        drop(rtxn);
+
+        // the language is detected again once the settings are reset
+        index
+            .update_settings(|settings| {
+                settings.reset_default_language();
+                settings.reset_language_hint_field();
+            })
+            .unwrap();
+        for word in &detected_words {
+            assert_eq!(word_docids(word), RoaringBitmap::from_iter([0, 1]), "{word}");
+        }
+    }
+
     #[test]
     fn set_asc_desc_field() {
         let mut index = TempIndex::new();
@@ -2631,20 +4573,52 @@ mod tests {
                     separator_tokens,
                     dictionary,
                     distinct_field,
+                    recency_field,
                     synonyms,
                     primary_key,
                     authorize_typos,
+                    split_and_concat_words,
                     min_word_len_two_typos,
+                    typo_penalty,
                     min_word_len_one_typo,
                     exact_words,
                     exact_attributes,
+                    ignored_attributes,
+                    content_types,
+                    tokenizer_profiles,
+                    attribute_transforms,
+                    decimal_attributes,
+                    normalized_facet_attributes,
+                    facet_case_folding,
+                    attributes_prefix_search,
+                    read_only,
                     max_values_per_facet,
                     sort_facet_values_by,
                     pagination_max_total_hits,
                     proximity_precision,
                     embedder_settings,
                     search_cutoff,
+                    max_facet_values_per_attribute,
+                    max_documents,
+                    max_prefix_typos,
+                    min_prefix_length,
+                    stemming,
+                    max_flatten_depth,
+                    default_language,
+                    language_hint_field,
+                    duplicate_key_strategy,
                     localized_attributes_rules,
+                    pinned_results,
+                    reserve_document_ids,
+                    user_metadata,
+                    store_max_length,
+                    blocked_documents,
+                    number_tokenization,
+                    enable_formatting,
+                    recency_half_life_days,
+                    reranker,
+                    document_compression,
+                    max_synonym_expansions,
                 } = settings;
                 assert!(matches!(searchable_fields, Setting::NotSet));
                 assert!(matches!(displayed_fields, Setting::NotSet));
@@ -2656,20 +4630,52 @@ mod tests {
                 assert!(matches!(separator_tokens, Setting::NotSet));
                 assert!(matches!(dictionary, Setting::NotSet));
                 assert!(matches!(distinct_field, Setting::NotSet));
+                assert!(matches!(recency_field, Setting::NotSet));
                 assert!(matches!(synonyms, Setting::NotSet));
                 assert!(matches!(primary_key, Setting::NotSet));
                 assert!(matches!(authorize_typos, Setting::NotSet));
+                assert!(matches!(split_and_concat_words, Setting::NotSet));
                 assert!(matches!(min_word_len_two_typos, Setting::NotSet));
+                assert!(matches!(typo_penalty, Setting::NotSet));
                 assert!(matches!(min_word_len_one_typo, Setting::NotSet));
                 assert!(matches!(exact_words, Setting::NotSet));
                 assert!(matches!(exact_attributes, Setting::NotSet));
+                assert!(matches!(ignored_attributes, Setting::NotSet));
+                assert!(matches!(content_types, Setting::NotSet));
+                assert!(matches!(tokenizer_profiles, Setting::NotSet));
+                assert!(matches!(attribute_transforms, Setting::NotSet));
+                assert!(matches!(decimal_attributes, Setting::NotSet));
+                assert!(matches!(normalized_facet_attributes, Setting::NotSet));
+                assert!(matches!(facet_case_folding, Setting::NotSet));
+                assert!(matches!(attributes_prefix_search, Setting::NotSet));
+                assert!(matches!(read_only, Setting::NotSet));
                 assert!(matches!(max_values_per_facet, Setting::NotSet));
                 assert!(matches!(sort_facet_values_by, Setting::NotSet));
                 assert!(matches!(pagination_max_total_hits, Setting::NotSet));
                 assert!(matches!(proximity_precision, Setting::NotSet));
                 assert!(matches!(embedder_settings, Setting::NotSet));
                 assert!(matches!(search_cutoff, Setting::NotSet));
+                assert!(matches!(max_facet_values_per_attribute, Setting::NotSet));
+                assert!(matches!(max_documents, Setting::NotSet));
+                assert!(matches!(max_prefix_typos, Setting::NotSet));
+                assert!(matches!(min_prefix_length, Setting::NotSet));
+                assert!(matches!(stemming, Setting::NotSet));
+                assert!(matches!(max_flatten_depth, Setting::NotSet));
+                assert!(matches!(default_language, Setting::NotSet));
+                assert!(matches!(language_hint_field, Setting::NotSet));
+                assert!(matches!(duplicate_key_strategy, Setting::NotSet));
                 assert!(matches!(localized_attributes_rules, Setting::NotSet));
+                assert!(matches!(pinned_results, Setting::NotSet));
+                assert!(matches!(reserve_document_ids, Setting::NotSet));
+                assert!(matches!(user_metadata, Setting::NotSet));
+                assert!(matches!(store_max_length, Setting::NotSet));
+                assert!(matches!(blocked_documents, Setting::NotSet));
+                assert!(matches!(number_tokenization, Setting::NotSet));
+                assert!(matches!(enable_formatting, Setting::NotSet));
+                assert!(matches!(recency_half_life_days, Setting::NotSet));
+                assert!(matches!(reranker, Setting::NotSet));
+                assert!(matches!(document_compression, Setting::NotSet));
+                assert!(matches!(max_synonym_expansions, Setting::NotSet));
             })
             .unwrap();
     }
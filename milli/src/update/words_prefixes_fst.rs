@@ -10,12 +10,13 @@ pub struct WordsPrefixesFst<'t, 'i> {
     wtxn: &'t mut RwTxn<'i>,
     index: &'i Index,
     threshold: u32,
+    min_prefix_length: usize,
     max_prefix_length: usize,
 }
 
 impl<'t, 'i> WordsPrefixesFst<'t, 'i> {
     pub fn new(wtxn: &'t mut RwTxn<'i>, index: &'i Index) -> WordsPrefixesFst<'t, 'i> {
-        WordsPrefixesFst { wtxn, index, threshold: 100, max_prefix_length: 4 }
+        WordsPrefixesFst { wtxn, index, threshold: 100, min_prefix_length: 1, max_prefix_length: 4 }
     }
 
     /// Set the number of words required to make a prefix be part of the words prefixes
@@ -29,6 +30,15 @@ impl<'t, 'i> WordsPrefixesFst<'t, 'i> {
         self
     }
 
+    /// Set the minimum length of prefixes in bytes.
+    ///
+    /// Default value is `1` byte. The prefixes shorter than this length are never
+    /// part of the words prefixes datastructures.
+    pub fn min_prefix_length(&mut self, value: usize) -> &mut Self {
+        self.min_prefix_length = value.max(1);
+        self
+    }
+
     /// Set the maximum length of prefixes in bytes.
     ///
     /// Default value is `4` bytes. This value must be between 1 and 25 will be clamped
@@ -54,7 +64,7 @@ impl<'t, 'i> WordsPrefixesFst<'t, 'i> {
 
         let mut stream = words_fst.stream();
         while let Some(bytes) = stream.next() {
-            for n in 0..self.max_prefix_length {
+            for n in (self.min_prefix_length - 1)..self.max_prefix_length {
                 let current_prefix = &mut current_prefix[n];
                 let current_prefix_count = &mut current_prefix_count[n];
                 let builder = &mut builders[n];